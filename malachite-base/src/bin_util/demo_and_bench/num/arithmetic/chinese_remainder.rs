@@ -0,0 +1,59 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::vec_len_bucketer;
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_pair_vec_gen_var_1;
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_chinese_remainder);
+
+    register_unsigned_benches!(runner, benchmark_chinese_remainder);
+}
+
+fn demo_chinese_remainder<T: PrimitiveUnsigned>(gm: GenMode, config: &GenConfig, limit: usize) {
+    for congruences in unsigned_pair_vec_gen_var_1::<T>()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "{}::chinese_remainder({:?}) = {:?}",
+            T::NAME,
+            congruences,
+            T::chinese_remainder(congruences.iter().copied())
+        );
+    }
+}
+
+fn benchmark_chinese_remainder<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!(
+            "{}::chinese_remainder(Vec<({}, {})>)",
+            T::NAME,
+            T::NAME,
+            T::NAME
+        ),
+        BenchmarkType::Single,
+        unsigned_pair_vec_gen_var_1::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &vec_len_bucketer(),
+        &mut [("Malachite", &mut |congruences| {
+            no_out!(T::chinese_remainder(congruences))
+        })],
+    );
+}
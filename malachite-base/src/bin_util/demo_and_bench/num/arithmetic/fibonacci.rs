@@ -0,0 +1,105 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::unsigned_direct_bucketer;
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_base::test_util::num::arithmetic::fibonacci::{
+    checked_fibonacci_naive, checked_lucas_naive,
+};
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_checked_fibonacci);
+    register_unsigned_demos!(runner, demo_checked_fibonacci2);
+    register_unsigned_demos!(runner, demo_checked_lucas);
+
+    register_unsigned_benches!(runner, benchmark_checked_fibonacci_algorithms);
+    register_unsigned_benches!(runner, benchmark_checked_fibonacci2);
+    register_unsigned_benches!(runner, benchmark_checked_lucas_algorithms);
+}
+
+fn demo_checked_fibonacci<T: PrimitiveUnsigned>(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5().get(gm, config).take(limit) {
+        println!("F_{} = {:?}", n, T::checked_fibonacci(n));
+    }
+}
+
+fn demo_checked_fibonacci2<T: PrimitiveUnsigned>(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5().get(gm, config).take(limit) {
+        println!("(F_{}, F_{} - 1) = {:?}", n, n, T::checked_fibonacci2(n));
+    }
+}
+
+fn demo_checked_lucas<T: PrimitiveUnsigned>(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5().get(gm, config).take(limit) {
+        println!("L_{} = {:?}", n, T::checked_lucas(n));
+    }
+}
+
+fn benchmark_checked_fibonacci_algorithms<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::checked_fibonacci(u64)", T::NAME),
+        BenchmarkType::Algorithms,
+        unsigned_gen_var_5().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("default", &mut |n| no_out!(T::checked_fibonacci(n))),
+            ("naive", &mut |n| no_out!(checked_fibonacci_naive::<T>(n))),
+        ],
+    );
+}
+
+fn benchmark_checked_fibonacci2<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::checked_fibonacci2(u64)", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen_var_5().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(T::checked_fibonacci2(n)))],
+    );
+}
+
+fn benchmark_checked_lucas_algorithms<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::checked_lucas(u64)", T::NAME),
+        BenchmarkType::Algorithms,
+        unsigned_gen_var_5().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("default", &mut |n| no_out!(T::checked_lucas(n))),
+            ("naive", &mut |n| no_out!(checked_lucas_naive::<T>(n))),
+        ],
+    );
+}
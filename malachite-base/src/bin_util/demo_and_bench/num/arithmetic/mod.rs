@@ -19,6 +19,7 @@ pub(crate) fn register(runner: &mut Runner) {
     checked_add_mul::register(runner);
     checked_square::register(runner);
     checked_sub_mul::register(runner);
+    chinese_remainder::register(runner);
     coprime_with::register(runner);
     div_exact::register(runner);
     div_mod::register(runner);
@@ -29,6 +30,7 @@ pub(crate) fn register(runner: &mut Runner) {
     eq_mod_power_of_2::register(runner);
     extended_gcd::register(runner);
     factorial::register(runner);
+    fibonacci::register(runner);
     floor::register(runner);
     gcd::register(runner);
     is_power_of_2::register(runner);
@@ -57,6 +59,7 @@ pub(crate) fn register(runner: &mut Runner) {
     mod_power_of_2_sub::register(runner);
     mod_shl::register(runner);
     mod_shr::register(runner);
+    mod_sqrt::register(runner);
     mod_square::register(runner);
     mod_sub::register(runner);
     neg::register(runner);
@@ -124,6 +127,7 @@ mod ceiling;
 mod checked_add_mul;
 mod checked_square;
 mod checked_sub_mul;
+mod chinese_remainder;
 mod coprime_with;
 mod div_exact;
 mod div_mod;
@@ -134,6 +138,7 @@ mod eq_mod;
 mod eq_mod_power_of_2;
 mod extended_gcd;
 mod factorial;
+mod fibonacci;
 mod floor;
 mod gcd;
 mod is_power_of_2;
@@ -162,6 +167,7 @@ mod mod_power_of_2_square;
 mod mod_power_of_2_sub;
 mod mod_shl;
 mod mod_shr;
+mod mod_sqrt;
 mod mod_square;
 mod mod_sub;
 mod neg;
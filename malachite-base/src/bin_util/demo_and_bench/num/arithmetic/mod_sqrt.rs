@@ -0,0 +1,53 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::ModSqrt;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::test_util::bench::bucketers::pair_2_bit_bucketer;
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_pair_gen_var_51;
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_generic_demos!(runner, demo_mod_sqrt, u8, u16, u32, u64, usize);
+    register_generic_benches!(runner, benchmark_mod_sqrt, u8, u16, u32, u64, usize);
+}
+
+fn demo_mod_sqrt<T: IsPrime + ModSqrt<T, Output = T> + PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (n, p) in unsigned_pair_gen_var_51::<T>().get(gm, config).take(limit) {
+        if let Some(root) = n.mod_sqrt(p) {
+            println!("√{n} ≡ {root} mod {p}");
+        } else {
+            println!("{n} is not a square mod {p}");
+        }
+    }
+}
+
+fn benchmark_mod_sqrt<T: IsPrime + ModSqrt<T, Output = T> + PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.mod_sqrt({})", T::NAME, T::NAME),
+        BenchmarkType::Single,
+        unsigned_pair_gen_var_51::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_bit_bucketer("p"),
+        &mut [("Malachite", &mut |(n, p)| no_out!(n.mod_sqrt(p)))],
+    );
+}
@@ -9,15 +9,35 @@
 use malachite_base::test_util::runner::Runner;
 
 pub(crate) fn register(runner: &mut Runner) {
+    carmichael_lambda::register(runner);
+    divisor_count::register(runner);
+    divisor_sum::register(runner);
+    divisors::register(runner);
+    euler_totient::register(runner);
     factor::register(runner);
     is_prime::register(runner);
+    mod_log::register(runner);
+    moebius::register(runner);
+    multiplicative_order::register(runner);
+    next_prime::register(runner);
+    prev_prime::register(runner);
     primes::register(runner);
     prime_sieve::register(runner);
     primitive_root_prime::register(runner);
 }
 
+mod carmichael_lambda;
+mod divisor_count;
+mod divisor_sum;
+mod divisors;
+mod euler_totient;
 mod factor;
 mod is_prime;
+mod mod_log;
+mod moebius;
+mod multiplicative_order;
+mod next_prime;
+mod prev_prime;
 mod prime_sieve;
 mod primes;
 mod primitive_root_prime;
@@ -0,0 +1,63 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::MultiplicativeOrder;
+use malachite_base::test_util::bench::bucketers::pair_2_bit_bucketer;
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_pair_gen_var_16;
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_generic_demos!(runner, demo_multiplicative_order, u8, u16, u32, u64, usize);
+    register_generic_benches!(
+        runner,
+        benchmark_multiplicative_order,
+        u8,
+        u16,
+        u32,
+        u64,
+        usize
+    );
+}
+
+fn demo_multiplicative_order<T: MultiplicativeOrder<T, Output = T> + PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (x, m) in unsigned_pair_gen_var_16::<T>().get(gm, config).take(limit) {
+        println!(
+            "{}.multiplicative_order({}) = {:?}",
+            x,
+            m,
+            x.multiplicative_order(m)
+        );
+    }
+}
+
+fn benchmark_multiplicative_order<T: MultiplicativeOrder<T, Output = T> + PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.multiplicative_order({})", T::NAME, T::NAME),
+        BenchmarkType::Single,
+        unsigned_pair_gen_var_16::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_bit_bucketer("m"),
+        &mut [("Malachite", &mut |(x, m)| {
+            no_out!(x.multiplicative_order(m))
+        })],
+    );
+}
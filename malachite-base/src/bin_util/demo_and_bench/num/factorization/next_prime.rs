@@ -0,0 +1,61 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::{IsPrime, NextPrime};
+use malachite_base::test_util::bench::bucketers::unsigned_bit_bucketer;
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_gen;
+use malachite_base::test_util::num::factorization::next_prime::next_prime_naive;
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_generic_demos!(runner, demo_next_prime, u8, u16, u32, u64, usize);
+    register_generic_benches!(
+        runner,
+        benchmark_next_prime_algorithms,
+        u8,
+        u16,
+        u32,
+        u64,
+        usize
+    );
+}
+
+fn demo_next_prime<T: IsPrime + NextPrime<Output = Option<T>> + PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for u in unsigned_gen::<T>().get(gm, config).take(limit) {
+        println!("next_prime({}) = {:?}", u, u.next_prime());
+    }
+}
+
+fn benchmark_next_prime_algorithms<
+    T: IsPrime + NextPrime<Output = Option<T>> + PrimitiveUnsigned,
+>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.next_prime()", T::NAME),
+        BenchmarkType::Algorithms,
+        unsigned_gen::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [
+            ("default", &mut |u| no_out!(u.next_prime())),
+            ("naive", &mut |u| no_out!(next_prime_naive(u))),
+        ],
+    );
+}
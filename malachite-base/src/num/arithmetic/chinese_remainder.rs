@@ -0,0 +1,94 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::arithmetic::traits::ChineseRemainder;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+
+// Combines x = r1 mod m1 and x = r2 mod m2 into a single congruence x = r mod lcm(m1, m2), or
+// returns `None` if the two congruences are inconsistent. The residues must be reduced.
+fn combine_congruences<T: PrimitiveUnsigned>(r1: T, m1: T, r2: T, m2: T) -> Option<(T, T)> {
+    let g = m1.gcd(m2);
+    let (diff, diff_is_negative) = if r2 >= r1 {
+        (r2 - r1, false)
+    } else {
+        (r1 - r2, true)
+    };
+    if !diff.divisible_by(g) {
+        return None;
+    }
+    let m1_reduced = m1 / g;
+    let m2_reduced = m2 / g;
+    let lcm = m1_reduced
+        .checked_mul(m2)
+        .expect("the LCM of the moduli is not representable");
+    if m2_reduced == T::ONE {
+        return Some((r1, lcm));
+    }
+    // x = r1 + m1 k, where (m1 / g) k = (r2 - r1) / g mod (m2 / g)
+    let mut diff = (diff / g) % m2_reduced;
+    if diff_is_negative {
+        diff.mod_neg_assign(m2_reduced);
+    }
+    let k = diff.mod_mul(
+        (m1_reduced % m2_reduced).mod_inverse(m2_reduced).unwrap(),
+        m2_reduced,
+    );
+    // r1 + m1 k <= r1 + m1 (m2 / g - 1) < lcm, so this doesn't overflow.
+    Some((r1 + m1 * k, lcm))
+}
+
+fn chinese_remainder<T: PrimitiveUnsigned, I: IntoIterator<Item = (T, T)>>(
+    congruences: I,
+) -> Option<(T, T)> {
+    let mut r = T::ZERO;
+    let mut m = T::ONE;
+    for (r_i, m_i) in congruences {
+        assert_ne!(m_i, T::ZERO);
+        assert!(r_i < m_i, "residue must be reduced, but {r_i} >= {m_i}");
+        (r, m) = combine_congruences(r, m, r_i, m_i)?;
+    }
+    Some((r, m))
+}
+
+macro_rules! impl_chinese_remainder {
+    ($t:ident) => {
+        impl ChineseRemainder for $t {
+            /// Combines a list of congruences $x \equiv r_i \mod m_i$ into a single congruence $x
+            /// \equiv r \mod m$, where $m = \operatorname{lcm}(m_1, m_2, \ldots, m_k)$ and $0 \leq
+            /// r < m$.
+            ///
+            /// The moduli don't need to be pairwise coprime. If the congruences are inconsistent,
+            /// meaning that no $x$ satisfies all of them, `None` is returned. Otherwise, `Some((r,
+            /// m))` is returned. An empty list of congruences produces `Some((0, 1))`.
+            ///
+            /// The congruences are combined one at a time, using the extended GCD algorithm.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, k) = O(kn^2)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is `Self::WIDTH`, and $k$ is the
+            /// number of congruences.
+            ///
+            /// # Panics
+            /// Panics if any modulus is zero, if any residue is greater than or equal to its
+            /// modulus, or if the LCM of the moduli is not representable.
+            ///
+            /// # Examples
+            /// See [here](super::chinese_remainder#chinese_remainder).
+            #[inline]
+            fn chinese_remainder<I: IntoIterator<Item = ($t, $t)>>(
+                congruences: I,
+            ) -> Option<($t, $t)> {
+                chinese_remainder(congruences)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_chinese_remainder);
@@ -278,6 +278,23 @@ pub mod checked_sub;
 /// assert_eq!((-127i8).checked_sub_mul(2, 100), None);
 /// ```
 pub mod checked_sub_mul;
+/// [`ChineseRemainder`](traits::ChineseRemainder), a trait for combining a list of congruences
+/// using the Chinese remainder theorem.
+///
+/// # chinese_remainder
+/// ```
+/// use malachite_base::num::arithmetic::traits::ChineseRemainder;
+///
+/// assert_eq!(u8::chinese_remainder([]), Some((0, 1)));
+/// assert_eq!(u8::chinese_remainder([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+/// assert_eq!(u32::chinese_remainder([(3, 4), (5, 6)]), Some((11, 12)));
+/// assert_eq!(u32::chinese_remainder([(3, 4), (4, 6)]), None);
+/// assert_eq!(
+///     u64::chinese_remainder([(1, 1000000007), (2, 998244353)]),
+///     Some((993328913953302350, 998244359987710471))
+/// );
+/// ```
+pub mod chinese_remainder;
 /// [`CoprimeWith`](traits::CoprimeWith), a trait for determining whether two numbers are coprime.
 ///
 /// # coprime_with
@@ -896,6 +913,84 @@ pub mod extended_gcd;
 /// assert_eq!(u32::checked_subfactorial(100), None);
 /// ```
 pub mod factorial;
+/// Traits for computing Fibonacci and Lucas numbers. Each function has a trait whose
+/// implementations panic if the result cannot be represented, and a checked trait whose
+/// implementations return `None` in that case. The traits are [`Fibonacci`](traits::Fibonacci),
+/// [`Lucas`](traits::Lucas), [`CheckedFibonacci`](traits::CheckedFibonacci), and
+/// [`CheckedLucas`](traits::CheckedLucas).
+///
+/// # fibonacci
+/// ```
+/// use malachite_base::num::arithmetic::traits::Fibonacci;
+///
+/// assert_eq!(u8::fibonacci(0), 0);
+/// assert_eq!(u8::fibonacci(1), 1);
+/// assert_eq!(u8::fibonacci(2), 1);
+/// assert_eq!(u8::fibonacci(3), 2);
+/// assert_eq!(u8::fibonacci(10), 55);
+/// assert_eq!(u8::fibonacci(13), 233);
+/// assert_eq!(u64::fibonacci(93), 12200160415121876738);
+/// ```
+///
+/// # fibonacci2
+/// ```
+/// use malachite_base::num::arithmetic::traits::Fibonacci;
+///
+/// assert_eq!(u8::fibonacci2(0), (0, 1));
+/// assert_eq!(u8::fibonacci2(1), (1, 0));
+/// assert_eq!(u8::fibonacci2(2), (1, 1));
+/// assert_eq!(u8::fibonacci2(10), (55, 34));
+/// assert_eq!(u32::fibonacci2(40), (102334155, 63245986));
+/// ```
+///
+/// # checked_fibonacci
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedFibonacci;
+///
+/// assert_eq!(u8::checked_fibonacci(0), Some(0));
+/// assert_eq!(u8::checked_fibonacci(1), Some(1));
+/// assert_eq!(u8::checked_fibonacci(10), Some(55));
+/// assert_eq!(u8::checked_fibonacci(13), Some(233));
+/// assert_eq!(u8::checked_fibonacci(14), None);
+/// assert_eq!(u64::checked_fibonacci(93), Some(12200160415121876738));
+/// assert_eq!(u64::checked_fibonacci(94), None);
+/// ```
+///
+/// # checked_fibonacci2
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedFibonacci;
+///
+/// assert_eq!(u8::checked_fibonacci2(0), Some((0, 1)));
+/// assert_eq!(u8::checked_fibonacci2(1), Some((1, 0)));
+/// assert_eq!(u8::checked_fibonacci2(10), Some((55, 34)));
+/// assert_eq!(u8::checked_fibonacci2(13), Some((233, 144)));
+/// assert_eq!(u8::checked_fibonacci2(14), None);
+/// ```
+///
+/// # lucas
+/// ```
+/// use malachite_base::num::arithmetic::traits::Lucas;
+///
+/// assert_eq!(u8::lucas(0), 2);
+/// assert_eq!(u8::lucas(1), 1);
+/// assert_eq!(u8::lucas(2), 3);
+/// assert_eq!(u8::lucas(3), 4);
+/// assert_eq!(u8::lucas(10), 123);
+/// assert_eq!(u64::lucas(92), 16860207025497407047);
+/// ```
+///
+/// # checked_lucas
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedLucas;
+///
+/// assert_eq!(u8::checked_lucas(0), Some(2));
+/// assert_eq!(u8::checked_lucas(1), Some(1));
+/// assert_eq!(u8::checked_lucas(10), Some(123));
+/// assert_eq!(u8::checked_lucas(11), Some(199));
+/// assert_eq!(u8::checked_lucas(12), None);
+/// assert_eq!(u64::checked_lucas(93), None);
+/// ```
+pub mod fibonacci;
 /// [`Floor`](traits::Floor) and [`FloorAssign`](traits::FloorAssign), traits for computing the
 /// floor of a number.
 ///
@@ -2018,6 +2113,30 @@ pub mod mod_shl;
 /// assert_eq!(n, 2);
 /// ```
 pub mod mod_shr;
+/// [`ModSqrt`](traits::ModSqrt) and [`ModPrimePowerSqrt`](traits::ModPrimePowerSqrt), traits for
+/// finding square roots of a number modulo a prime or a prime power.
+///
+/// # mod_sqrt
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModSqrt;
+///
+/// assert_eq!(2u8.mod_sqrt(7), Some(3));
+/// assert_eq!(5u8.mod_sqrt(7), None);
+/// assert_eq!(10u32.mod_sqrt(13), Some(6));
+/// assert_eq!(0u64.mod_sqrt(13), Some(0));
+/// ```
+///
+/// # mod_prime_power_sqrt
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModPrimePowerSqrt;
+///
+/// assert_eq!(2u8.mod_prime_power_sqrt(7, 2), Some(10));
+/// assert_eq!(17u16.mod_prime_power_sqrt(2, 5), Some(7));
+/// assert_eq!(3u32.mod_prime_power_sqrt(2, 3), None);
+/// assert_eq!(18u64.mod_prime_power_sqrt(3, 3), None);
+/// assert_eq!(36u64.mod_prime_power_sqrt(3, 4), Some(6));
+/// ```
+pub mod mod_sqrt;
 /// Traits for squaring a number modulo another number.
 ///
 /// The traits are [`ModSquare`](traits::ModSquare), [`ModSquareAssign`](traits::ModSquareAssign),
@@ -0,0 +1,239 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::arithmetic::traits::{ModPrimePowerSqrt, ModSqrt, Parity};
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::IsPrime;
+use core::cmp::min;
+
+// Finds the smallest square root of x mod p, where p is an odd prime and 0 < x < p, using the
+// Tonelli-Shanks algorithm. If p is not prime, the function may panic.
+fn mod_sqrt_odd_prime<T: IsPrime + PrimitiveUnsigned>(x: T, p: T) -> Option<T> {
+    let p_minus_1 = p - T::ONE;
+    let half: T = p_minus_1 >> 1;
+    // Euler's criterion
+    if x.mod_pow(half.wrapping_into(), p) != T::ONE {
+        return None;
+    }
+    // p - 1 = 2^s q, with q odd
+    let s = p_minus_1.trailing_zeros();
+    let r = if s == 1 {
+        // p is 3 mod 4, so x^((p + 1) / 4) is a root. (p + 1) / 4 is computed without overflow.
+        x.mod_pow(((half + T::ONE) >> 1u32).wrapping_into(), p)
+    } else {
+        let q = p_minus_1 >> s;
+        // Find a quadratic non-residue.
+        let mut z = T::TWO;
+        while z.mod_pow(half.wrapping_into(), p) == T::ONE {
+            z += T::ONE;
+        }
+        let mut m = s;
+        let mut c = z.mod_pow(q.wrapping_into(), p);
+        let mut t = x.mod_pow(q.wrapping_into(), p);
+        let mut r = x.mod_pow(((q + T::ONE) >> 1u32).wrapping_into(), p);
+        while t != T::ONE {
+            // Find the least i such that t^(2^i) = 1.
+            let mut i = 1;
+            let mut t_pow = t.mod_square(p);
+            while t_pow != T::ONE {
+                i += 1;
+                if i == m {
+                    // This can only happen if p is not prime. Confirm this by failing an
+                    // assertion, which will produce an appropriate error message.
+                    assert!(p.is_prime());
+                    unreachable!()
+                }
+                t_pow = t_pow.mod_square(p);
+            }
+            let b = c.mod_pow(1 << (m - i - 1), p);
+            r.mod_mul_assign(b, p);
+            c = b.mod_square(p);
+            t.mod_mul_assign(c, p);
+            m = i;
+        }
+        r
+    };
+    Some(min(r, p - r))
+}
+
+fn mod_sqrt_prime<T: IsPrime + PrimitiveUnsigned>(x: T, p: T) -> Option<T> {
+    assert!(x < p, "x must be reduced mod p, but {x} >= {p}");
+    if x == T::ZERO || p == T::TWO {
+        Some(x)
+    } else {
+        mod_sqrt_odd_prime(x, p)
+    }
+}
+
+// Finds the smallest square root of x mod 2^k, where x is odd and less than 2^k.
+fn mod_power_of_2_sqrt_odd<T: PrimitiveUnsigned>(x: T, k: u64) -> Option<T> {
+    match k {
+        1 => Some(T::ONE),
+        2 => {
+            if x == T::ONE {
+                Some(T::ONE)
+            } else {
+                None
+            }
+        }
+        _ => {
+            if x.mod_power_of_2(3) != T::ONE {
+                return None;
+            }
+            // Lift a root mod 8 to a root mod 2^k, one bit at a time. If r^2 = x mod 2^i, then
+            // either r or r + 2^(i - 1) is a root mod 2^(i + 1).
+            let mut r = T::ONE;
+            for i in 3..k {
+                if r.wrapping_square().wrapping_sub(x).mod_power_of_2(i + 1) != T::ZERO {
+                    r.set_bit(i - 1);
+                }
+            }
+            // The roots are r, -r, r + 2^(k - 1), and -r + 2^(k - 1).
+            Some(min(r, T::power_of_2(k - 1) - r))
+        }
+    }
+}
+
+// Finds the smallest square root of x mod p^k, where p is an odd prime, k > 0, x is less than p^k,
+// and x is not divisible by p. A root mod p is lifted using Newton's method (Hensel lifting).
+fn mod_odd_prime_power_sqrt_unit<T: IsPrime + PrimitiveUnsigned>(
+    x: T,
+    p: T,
+    k: u64,
+    pk: T,
+) -> Option<T> {
+    let mut r = mod_sqrt_odd_prime(x % p, p)?;
+    if k == 1 {
+        return Some(r);
+    }
+    loop {
+        let r_squared = r.mod_square(pk);
+        if r_squared == x {
+            break;
+        }
+        // r <- r - (r^2 - x) / (2r)
+        let inverse = r.mod_add(r, pk).mod_inverse(pk).unwrap();
+        r.mod_sub_assign(r_squared.mod_sub(x, pk).mod_mul(inverse, pk), pk);
+    }
+    Some(min(r, pk - r))
+}
+
+fn mod_prime_power_sqrt<T: IsPrime + PrimitiveUnsigned>(x: T, p: T, k: u64) -> Option<T> {
+    assert_ne!(k, 0);
+    let pk = p.checked_pow(k).expect("p^k is not representable");
+    assert!(x < pk, "x must be reduced mod p^k, but {x} >= {pk}");
+    if x == T::ZERO {
+        return Some(T::ZERO);
+    }
+    // x = p^v u, where u is not divisible by p
+    let mut v = 0;
+    let mut u = x;
+    if p == T::TWO {
+        v = u.trailing_zeros();
+        u >>= v;
+    } else {
+        while u.divisible_by(p) {
+            u /= p;
+            v += 1;
+        }
+    }
+    if v.odd() {
+        return None;
+    }
+    // If x = p^(2j) u, then the roots of x mod p^k are p^j times the roots of u mod p^(k - 2j),
+    // and the smallest root of x is p^j times the smallest root of u.
+    let j = v >> 1;
+    let k = k - v;
+    let root = if p == T::TWO {
+        mod_power_of_2_sqrt_odd(u, k)
+    } else {
+        mod_odd_prime_power_sqrt_unit(u, p, k, p.pow(k))
+    }?;
+    Some(root * p.pow(j))
+}
+macro_rules! impl_mod_sqrt {
+    ($t:ident) => {
+        impl ModSqrt<$t> for $t {
+            type Output = $t;
+
+            /// Computes a square root of a number modulo a prime $p$. The input must be already
+            /// reduced modulo $p$.
+            ///
+            /// Returns `None` if $x$ is not a quadratic residue modulo $p$. Otherwise, there are at
+            /// most two square roots, and the smaller one is returned.
+            ///
+            /// If $p$ is not prime, this function's behavior is unspecified. Since primality
+            /// checking can be expensive, $p$ is not tested for primality.
+            ///
+            /// $f(x, p) = \min \\{ y : 0 \leq y < p \text{ and } y^2 \equiv x \mod p \\}$, or
+            /// `None` if the set is empty.
+            ///
+            /// The Tonelli-Shanks algorithm is used.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `p.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` is greater than or equal to `p`, and possibly panics if `p` is
+            /// not prime.
+            ///
+            /// # Examples
+            /// See [here](super::mod_sqrt#mod_sqrt).
+            #[inline]
+            fn mod_sqrt(self, p: $t) -> Option<$t> {
+                mod_sqrt_prime(self, p)
+            }
+        }
+
+        impl ModPrimePowerSqrt<$t> for $t {
+            type Output = $t;
+
+            /// Computes a square root of a number modulo a prime power $p^k$. The input must be
+            /// already reduced modulo $p^k$.
+            ///
+            /// Returns `None` if $x$ is not a quadratic residue modulo $p^k$. Otherwise, the
+            /// smallest square root is returned.
+            ///
+            /// If $p$ is not prime, this function's behavior is unspecified. Since primality
+            /// checking can be expensive, $p$ is not tested for primality.
+            ///
+            /// $f(x, p, k) = \min \\{ y : 0 \leq y < p^k \text{ and } y^2 \equiv x \mod p^k \\}$,
+            /// or `None` if the set is empty.
+            ///
+            /// A square root modulo $p$ is found using the Tonelli-Shanks algorithm and then lifted
+            /// to a square root modulo $p^k$ using Hensel lifting.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `k * p.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `k` is zero, if $p^k$ is not representable, or if `self` is greater than
+            /// or equal to $p^k$. Possibly panics if `p` is not prime.
+            ///
+            /// # Examples
+            /// See [here](super::mod_sqrt#mod_prime_power_sqrt).
+            #[inline]
+            fn mod_prime_power_sqrt(self, p: $t, k: u64) -> Option<$t> {
+                mod_prime_power_sqrt(self, p, k)
+            }
+        }
+    };
+}
+impl_mod_sqrt!(u8);
+impl_mod_sqrt!(u16);
+impl_mod_sqrt!(u32);
+impl_mod_sqrt!(u64);
+impl_mod_sqrt!(usize);
@@ -43,6 +43,18 @@ pub trait AbsDiffAssign<RHS = Self> {
     fn abs_diff_assign(&mut self, other: RHS);
 }
 
+/// Takes the inverse cosine of a number.
+pub trait Acos {
+    type Output;
+
+    fn acos(self) -> Self::Output;
+}
+
+/// Replaces a number with its inverse cosine.
+pub trait AcosAssign {
+    fn acos_assign(&mut self);
+}
+
 /// Adds a number and the product of two other numbers.
 pub trait AddMul<Y = Self, Z = Self> {
     type Output;
@@ -71,6 +83,43 @@ pub trait ArithmeticCheckedShr<RHS> {
     fn arithmetic_checked_shr(self, other: RHS) -> Option<Self::Output>;
 }
 
+/// Takes the inverse sine of a number.
+pub trait Asin {
+    type Output;
+
+    fn asin(self) -> Self::Output;
+}
+
+/// Replaces a number with its inverse sine.
+pub trait AsinAssign {
+    fn asin_assign(&mut self);
+}
+
+/// Takes the inverse tangent of a number.
+pub trait Atan {
+    type Output;
+
+    fn atan(self) -> Self::Output;
+}
+
+/// Replaces a number with its inverse tangent.
+pub trait AtanAssign {
+    fn atan_assign(&mut self);
+}
+
+/// Computes the angle of the point $(x, y)$, where $y$ is the first number and $x$ is the second,
+/// as $\operatorname{atan2}(y, x)$.
+pub trait Atan2<RHS = Self> {
+    type Output;
+
+    fn atan2(self, other: RHS) -> Self::Output;
+}
+
+/// Replaces a number $y$ with $\operatorname{atan2}(y, x)$, where $x$ is another number.
+pub trait Atan2Assign<RHS = Self> {
+    fn atan2_assign(&mut self, other: RHS);
+}
+
 pub trait BinomialCoefficient<T = Self> {
     fn binomial_coefficient(n: T, k: T) -> Self;
 }
@@ -171,11 +220,31 @@ pub trait CheckedSubMul<Y = Self, Z = Self> {
     fn checked_sub_mul(self, y: Y, z: Z) -> Option<Self::Output>;
 }
 
+/// Combines a list of congruences $x \equiv r_i \mod m_i$ into a single congruence $x \equiv r \mod
+/// m$, returning `None` if the congruences are inconsistent.
+pub trait ChineseRemainder: Sized {
+    fn chinese_remainder<I: IntoIterator<Item = (Self, Self)>>(
+        congruences: I,
+    ) -> Option<(Self, Self)>;
+}
+
 /// Determines whether two numbers are coprime.
 pub trait CoprimeWith<RHS = Self> {
     fn coprime_with(self, other: RHS) -> bool;
 }
 
+/// Takes the cosine of a number.
+pub trait Cos {
+    type Output;
+
+    fn cos(self) -> Self::Output;
+}
+
+/// Replaces a number with its cosine.
+pub trait CosAssign {
+    fn cos_assign(&mut self);
+}
+
 /// Divides two numbers, assuming the first exactly divides the second.
 ///
 /// If it doesn't, the `div_exact` function may panic or return a meaningless result.
@@ -335,6 +404,54 @@ pub trait ExtendedGcd<RHS = Self> {
     fn extended_gcd(self, other: RHS) -> (Self::Gcd, Self::Cofactor, Self::Cofactor);
 }
 
+/// Raises $e$ to the power of a number.
+pub trait Exp {
+    type Output;
+
+    fn exp(self) -> Self::Output;
+}
+
+/// Replaces a number $x$ with $e^x$.
+pub trait ExpAssign {
+    fn exp_assign(&mut self);
+}
+
+/// Raises 2 to the power of a number.
+pub trait Exp2 {
+    type Output;
+
+    fn exp2(self) -> Self::Output;
+}
+
+/// Replaces a number $x$ with $2^x$.
+pub trait Exp2Assign {
+    fn exp2_assign(&mut self);
+}
+
+/// Raises 10 to the power of a number.
+pub trait Exp10 {
+    type Output;
+
+    fn exp10(self) -> Self::Output;
+}
+
+/// Replaces a number $x$ with $10^x$.
+pub trait Exp10Assign {
+    fn exp10_assign(&mut self);
+}
+
+/// Computes $e^x-1$ for a number $x$, which is useful when $x$ is close to zero.
+pub trait ExpM1 {
+    type Output;
+
+    fn expm1(self) -> Self::Output;
+}
+
+/// Replaces a number $x$ with $e^x-1$.
+pub trait ExpM1Assign {
+    fn expm1_assign(&mut self);
+}
+
 /// Computes the factorial of a `u64`.
 pub trait Factorial {
     fn factorial(n: u64) -> Self;
@@ -386,6 +503,32 @@ pub trait CheckedSubfactorial: Sized {
     fn checked_subfactorial(n: u64) -> Option<Self>;
 }
 
+/// Computes the $n$th Fibonacci number $F_n$, or the pair $(F_n, F_{n-1})$, for a `u64` $n$.
+pub trait Fibonacci: Sized {
+    fn fibonacci(n: u64) -> Self;
+
+    fn fibonacci2(n: u64) -> (Self, Self);
+}
+
+/// Computes the $n$th Fibonacci number $F_n$, or the pair $(F_n, F_{n-1})$, for a `u64` $n$,
+/// returning `None` if the result is too large to be represented.
+pub trait CheckedFibonacci: Sized {
+    fn checked_fibonacci(n: u64) -> Option<Self>;
+
+    fn checked_fibonacci2(n: u64) -> Option<(Self, Self)>;
+}
+
+/// Computes the $n$th Lucas number $L_n$ for a `u64` $n$.
+pub trait Lucas {
+    fn lucas(n: u64) -> Self;
+}
+
+/// Computes the $n$th Lucas number $L_n$ for a `u64` $n$, returning `None` if the result is too
+/// large to be represented.
+pub trait CheckedLucas: Sized {
+    fn checked_lucas(n: u64) -> Option<Self>;
+}
+
 /// Takes the floor of a number.
 pub trait Floor {
     type Output;
@@ -434,6 +577,47 @@ pub trait Ln {
     fn ln(self) -> Self::Output;
 }
 
+/// Replaces a number with its natural logarithm.
+pub trait LnAssign {
+    fn ln_assign(&mut self);
+}
+
+/// Computes $\ln(1+x)$ for a number $x$, which is useful when $x$ is close to zero.
+pub trait Ln1p {
+    type Output;
+
+    fn ln1p(self) -> Self::Output;
+}
+
+/// Replaces a number $x$ with $\ln(1+x)$.
+pub trait Ln1pAssign {
+    fn ln1p_assign(&mut self);
+}
+
+/// Takes the base-2 logarithm of a number.
+pub trait Log2 {
+    type Output;
+
+    fn log2(self) -> Self::Output;
+}
+
+/// Replaces a number with its base-2 logarithm.
+pub trait Log2Assign {
+    fn log2_assign(&mut self);
+}
+
+/// Takes the base-10 logarithm of a number.
+pub trait Log10 {
+    type Output;
+
+    fn log10(self) -> Self::Output;
+}
+
+/// Replaces a number with its base-10 logarithm.
+pub trait Log10Assign {
+    fn log10_assign(&mut self);
+}
+
 /// Calculates the LCM (least common multiple) of two numbers, returning `None` if the result is not
 /// representable.
 pub trait CheckedLcm<RHS = Self> {
@@ -820,6 +1004,14 @@ pub trait ModPowerOf2SubAssign<RHS = Self> {
     fn mod_power_of_2_sub_assign(&mut self, other: RHS, pow: u64);
 }
 
+/// Finds a square root of a number modulo a prime power $p^k$. The input must be already reduced
+/// modulo $p^k$.
+pub trait ModPrimePowerSqrt<P = Self> {
+    type Output;
+
+    fn mod_prime_power_sqrt(self, p: P, k: u64) -> Option<Self::Output>;
+}
+
 /// Divides a number by $2^k$, returning just the remainder. The remainder is non-negative.
 ///
 /// If the quotient were computed, the quotient and remainder would satisfy $x = q2^k + r$ and $0
@@ -922,6 +1114,14 @@ pub trait ModShrAssign<RHS, M = Self> {
     fn mod_shr_assign(&mut self, other: RHS, m: M);
 }
 
+/// Finds a square root of a number modulo a prime $p$. The input must be already reduced modulo
+/// $p$.
+pub trait ModSqrt<M = Self> {
+    type Output;
+
+    fn mod_sqrt(self, m: M) -> Option<Self::Output>;
+}
+
 /// Squares a number modulo another number $m$. The input must be already reduced modulo $m$.
 pub trait ModSquare<M = Self> {
     type Output;
@@ -1221,6 +1421,18 @@ pub trait ReciprocalAssign {
     fn reciprocal_assign(&mut self);
 }
 
+/// Finds the reciprocal of the square root of a number.
+pub trait ReciprocalSqrt {
+    type Output;
+
+    fn reciprocal_sqrt(self) -> Self::Output;
+}
+
+/// Replaces a number with the reciprocal of its square root.
+pub trait ReciprocalSqrtAssign {
+    fn reciprocal_sqrt_assign(&mut self);
+}
+
 /// Finds the floor of the $n$th root of a number.
 pub trait FloorRoot<POW> {
     type Output;
@@ -1489,6 +1701,25 @@ pub trait Sign {
     fn sign(&self) -> Ordering;
 }
 
+/// Takes the sine of a number.
+pub trait Sin {
+    type Output;
+
+    fn sin(self) -> Self::Output;
+}
+
+/// Replaces a number with its sine.
+pub trait SinAssign {
+    fn sin_assign(&mut self);
+}
+
+/// Takes the sine and cosine of a number simultaneously.
+pub trait SinCos {
+    type Output;
+
+    fn sin_cos(self) -> (Self::Output, Self::Output);
+}
+
 /// Takes the square root of a number.
 pub trait Sqrt {
     type Output;
@@ -1571,6 +1802,18 @@ pub trait SubMulAssign<Y = Self, Z = Self> {
     fn sub_mul_assign(&mut self, y: Y, z: Z);
 }
 
+/// Takes the tangent of a number.
+pub trait Tan {
+    type Output;
+
+    fn tan(self) -> Self::Output;
+}
+
+/// Replaces a number with its tangent.
+pub trait TanAssign {
+    fn tan_assign(&mut self);
+}
+
 /// Takes the absolute value of a number, wrapping around at the boundary of the type.
 pub trait WrappingAbs {
     type Output;
@@ -0,0 +1,81 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::{CarmichaelLambda, Factor};
+
+fn carmichael_lambda<T: PrimitiveUnsigned + Factor>(n: T) -> T
+where
+    <T as Factor>::FACTORS: IntoIterator<Item = (T, u8)>,
+{
+    n.factor().into_iter().fold(T::ONE, |lambda, (p, e)| {
+        let e = u64::from(e);
+        let lambda_prime_power = if p == T::TWO {
+            // lambda(2) = 1, lambda(4) = 2, and lambda(2^e) = 2^(e - 2) for e >= 3
+            if e < 3 {
+                T::power_of_2(e - 1)
+            } else {
+                T::power_of_2(e - 2)
+            }
+        } else {
+            p.pow(e - 1) * (p - T::ONE)
+        };
+        lambda.lcm(lambda_prime_power)
+    })
+}
+
+macro_rules! impl_carmichael_lambda {
+    ($t:ident) => {
+        impl CarmichaelLambda for $t {
+            type Output = $t;
+
+            /// Computes Carmichael's function of a number; that is, the smallest positive $k$ such
+            /// that $x^k \equiv 1 \mod n$ for every $x$ coprime to $n$. This is the exponent of the
+            /// multiplicative group of integers modulo $n$.
+            ///
+            /// $$
+            /// \lambda(n) = \operatorname{lcm}(\lambda(p_1^{e_1}), \ldots, \lambda(p_k^{e_k})),
+            /// $$
+            /// where $n = \prod_{i=1}^k p_i^{e_i}$ is the prime factorization of $n$,
+            /// $\lambda(p^e) = p^{e - 1}(p - 1)$ for odd $p$, $\lambda(2) = 1$, $\lambda(4) = 2$,
+            /// and $\lambda(2^e) = 2^{e - 2}$ for $e \geq 3$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4})$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// ```
+            /// use malachite_base::num::factorization::traits::CarmichaelLambda;
+            ///
+            /// assert_eq!(1u8.carmichael_lambda(), 1);
+            /// assert_eq!(36u8.carmichael_lambda(), 6);
+            /// assert_eq!(65535u16.carmichael_lambda(), 256);
+            /// assert_eq!(720720u32.carmichael_lambda(), 60);
+            /// assert_eq!(1000000000000u64.carmichael_lambda(), 50000000000);
+            /// assert_eq!(u128::MAX.carmichael_lambda(), 321876063366081731297280);
+            /// ```
+            #[inline]
+            fn carmichael_lambda(&self) -> $t {
+                carmichael_lambda(*self)
+            }
+        }
+    };
+}
+impl_carmichael_lambda!(u8);
+impl_carmichael_lambda!(u16);
+impl_carmichael_lambda!(u32);
+impl_carmichael_lambda!(u64);
+impl_carmichael_lambda!(u128);
+impl_carmichael_lambda!(usize);
@@ -0,0 +1,67 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::{DivisorCount, Factor};
+
+fn divisor_count<T: PrimitiveUnsigned + Factor>(n: T) -> T
+where
+    <T as Factor>::FACTORS: IntoIterator<Item = (T, u8)>,
+{
+    // d(p_1^e_1 ... p_k^e_k) = (e_1 + 1) ... (e_k + 1). Since d(n) <= n, there's no overflow.
+    n.factor()
+        .into_iter()
+        .fold(T::ONE, |d, (_, e)| d * T::from(e + 1))
+}
+
+macro_rules! impl_divisor_count {
+    ($t:ident) => {
+        impl DivisorCount for $t {
+            type Output = $t;
+
+            /// Counts the positive divisors of a number.
+            ///
+            /// $$
+            /// d(n) = \prod_{i=1}^k (e_i + 1),
+            /// $$
+            /// where $n = \prod_{i=1}^k p_i^{e_i}$ is the prime factorization of $n$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4})$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// ```
+            /// use malachite_base::num::factorization::traits::DivisorCount;
+            ///
+            /// assert_eq!(1u8.divisor_count(), 1);
+            /// assert_eq!(36u8.divisor_count(), 9);
+            /// assert_eq!(65535u16.divisor_count(), 16);
+            /// assert_eq!(720720u32.divisor_count(), 240);
+            /// assert_eq!(897612484786617600u64.divisor_count(), 103680);
+            /// assert_eq!(u128::MAX.divisor_count(), 512);
+            /// ```
+            #[inline]
+            fn divisor_count(&self) -> $t {
+                divisor_count(*self)
+            }
+        }
+    };
+}
+impl_divisor_count!(u8);
+impl_divisor_count!(u16);
+impl_divisor_count!(u32);
+impl_divisor_count!(u64);
+impl_divisor_count!(u128);
+impl_divisor_count!(usize);
@@ -0,0 +1,85 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::{DivisorSum, Factor};
+
+fn divisor_sum<T: PrimitiveUnsigned + Factor>(n: T) -> Option<T>
+where
+    <T as Factor>::FACTORS: IntoIterator<Item = (T, u8)>,
+{
+    // sigma(p_1^e_1 ... p_k^e_k) = (1 + p_1 + ... + p_1^e_1) ... (1 + p_k + ... + p_k^e_k)
+    let mut sigma = T::ONE;
+    for (p, e) in n.factor() {
+        // p^e <= n, so only the sum can overflow
+        let mut power = T::ONE;
+        let mut sum = T::ONE;
+        for _ in 0..e {
+            power *= p;
+            sum = sum.checked_add(power)?;
+        }
+        sigma = sigma.checked_mul(sum)?;
+    }
+    Some(sigma)
+}
+
+macro_rules! impl_divisor_sum {
+    ($t:ident) => {
+        impl DivisorSum for $t {
+            type Output = Option<$t>;
+
+            /// Computes the sum of the positive divisors of a number. If the sum is not
+            /// representable, `None` is returned.
+            ///
+            /// $$
+            /// f(n) = \\begin{cases}
+            ///     \operatorname{Some}(\sigma(n)) & \text{if} \\quad \sigma(n) < 2^W, \\\\
+            ///     \operatorname{None} & \text{if} \\quad \sigma(n) \geq 2^W,
+            /// \\end{cases}
+            /// $$
+            /// where $W$ is `Self::WIDTH` and
+            /// $$
+            /// \sigma(n) = \prod_{i=1}^k \sum_{j=0}^{e_i} p_i^j,
+            /// $$
+            /// where $n = \prod_{i=1}^k p_i^{e_i}$ is the prime factorization of $n$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4})$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// ```
+            /// use malachite_base::num::factorization::traits::DivisorSum;
+            ///
+            /// assert_eq!(1u8.divisor_sum(), Some(1));
+            /// assert_eq!(36u8.divisor_sum(), Some(91));
+            /// assert_eq!(255u8.divisor_sum(), None);
+            /// assert_eq!(720720u32.divisor_sum(), Some(3249792));
+            /// assert_eq!(u64::MAX.divisor_sum(), None);
+            /// assert_eq!(1000000000000u64.divisor_sum(), Some(2499694822171));
+            /// assert_eq!(18446744073709551615u128.divisor_sum(), Some(31421980989189888768));
+            /// ```
+            #[inline]
+            fn divisor_sum(&self) -> Option<$t> {
+                divisor_sum(*self)
+            }
+        }
+    };
+}
+impl_divisor_sum!(u8);
+impl_divisor_sum!(u16);
+impl_divisor_sum!(u32);
+impl_divisor_sum!(u64);
+impl_divisor_sum!(u128);
+impl_divisor_sum!(usize);
@@ -0,0 +1,72 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::{Divisors, Factor};
+use alloc::vec::{IntoIter, Vec};
+
+fn divisors<T: PrimitiveUnsigned + Factor>(n: T) -> Vec<T>
+where
+    <T as Factor>::FACTORS: IntoIterator<Item = (T, u8)>,
+{
+    let mut divisors = alloc::vec![T::ONE];
+    for (p, e) in n.factor() {
+        // Multiply each divisor found so far by p, p^2, ..., p^e.
+        let len = divisors.len();
+        for i in 0..usize::from(e) * len {
+            let d = divisors[i] * p;
+            divisors.push(d);
+        }
+    }
+    divisors.sort_unstable();
+    divisors
+}
+
+macro_rules! impl_divisors {
+    ($t:ident) => {
+        impl Divisors for $t {
+            type I = IntoIter<$t>;
+
+            /// Returns an iterator that generates all the positive divisors of a number, in
+            /// ascending order.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4} + d \log d)$
+            ///
+            /// $M(n) = O(d)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and
+            /// $d$ is `self.divisor_count()`.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// ```
+            /// use itertools::Itertools;
+            /// use malachite_base::num::factorization::traits::Divisors;
+            ///
+            /// assert_eq!(1u8.divisors().collect_vec(), &[1]);
+            /// assert_eq!(36u8.divisors().collect_vec(), &[1, 2, 3, 4, 6, 9, 12, 18, 36]);
+            /// assert_eq!(255u16.divisors().collect_vec(), &[1, 3, 5, 15, 17, 51, 85, 255]);
+            /// assert_eq!(1000000007u32.divisors().collect_vec(), &[1, 1000000007]);
+            /// assert_eq!(1506334550815795554361u128.divisors().count(), 8);
+            /// ```
+            #[inline]
+            fn divisors(&self) -> IntoIter<$t> {
+                divisors(*self).into_iter()
+            }
+        }
+    };
+}
+impl_divisors!(u8);
+impl_divisors!(u16);
+impl_divisors!(u32);
+impl_divisors!(u64);
+impl_divisors!(u128);
+impl_divisors!(usize);
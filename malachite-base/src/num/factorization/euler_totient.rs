@@ -0,0 +1,68 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::{EulerTotient, Factor};
+
+fn euler_totient<T: PrimitiveUnsigned + Factor>(n: T) -> T
+where
+    <T as Factor>::FACTORS: IntoIterator<Item = (T, u8)>,
+{
+    // phi(p_1^e_1 ... p_k^e_k) = p_1^(e_1 - 1) (p_1 - 1) ... p_k^(e_k - 1) (p_k - 1)
+    n.factor().into_iter().fold(T::ONE, |phi, (p, e)| {
+        phi * p.pow(u64::from(e - 1)) * (p - T::ONE)
+    })
+}
+
+macro_rules! impl_euler_totient {
+    ($t:ident) => {
+        impl EulerTotient for $t {
+            type Output = $t;
+
+            /// Computes Euler's totient function of a number; that is, the number of integers in
+            /// $[1, n]$ that are coprime to $n$.
+            ///
+            /// $$
+            /// \varphi(n) = \prod_{i=1}^k p_i^{e_i - 1}(p_i - 1),
+            /// $$
+            /// where $n = \prod_{i=1}^k p_i^{e_i}$ is the prime factorization of $n$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4})$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// ```
+            /// use malachite_base::num::factorization::traits::EulerTotient;
+            ///
+            /// assert_eq!(1u8.euler_totient(), 1);
+            /// assert_eq!(36u8.euler_totient(), 12);
+            /// assert_eq!(65535u16.euler_totient(), 32768);
+            /// assert_eq!(1000000007u32.euler_totient(), 1000000006);
+            /// assert_eq!(1000000000000u64.euler_totient(), 400000000000);
+            /// assert_eq!(u128::MAX.euler_totient(), 169875107699410294159549716941399654400);
+            /// ```
+            #[inline]
+            fn euler_totient(&self) -> $t {
+                euler_totient(*self)
+            }
+        }
+    };
+}
+impl_euler_totient!(u8);
+impl_euler_totient!(u16);
+impl_euler_totient!(u32);
+impl_euler_totient!(u64);
+impl_euler_totient!(u128);
+impl_euler_totient!(usize);
@@ -15,16 +15,16 @@
 use crate::num::arithmetic::mod_pow::mul_mod_helper;
 use crate::num::arithmetic::sqrt::{sqrt_rem_2_newton, sqrt_rem_newton};
 use crate::num::arithmetic::traits::{
-    DivMod, FloorRoot, FloorSqrt, Gcd, ModMulPrecomputed, ModSub, ModSubAssign, Parity, PowerOf2,
-    SqrtRem, Square, WrappingAddAssign, WrappingMulAssign, WrappingSquare, WrappingSubAssign,
-    XMulYToZZ, XXDivModYToQR, XXSubYYToZZ,
+    CheckedRoot, DivMod, FloorRoot, FloorSqrt, Gcd, ModAdd, ModInverse, ModMul, ModMulPrecomputed,
+    ModSub, ModSubAssign, Parity, PowerOf2, SqrtRem, Square, WrappingAddAssign, WrappingMulAssign,
+    WrappingSquare, WrappingSubAssign, XMulYToZZ, XXDivModYToQR, XXSubYYToZZ,
 };
 use crate::num::basic::integers::{PrimitiveInt, USIZE_IS_U32};
 use crate::num::basic::unsigneds::PrimitiveUnsigned;
 use crate::num::conversion::traits::{ExactFrom, WrappingFrom};
 use crate::num::factorization::primes::SMALL_PRIMES;
 use crate::num::factorization::traits::{Factor, IsPrime, IsSquare, Primes};
-use crate::num::logic::traits::{LeadingZeros, LowMask, SignificantBits};
+use crate::num::logic::traits::{BitAccess, LeadingZeros, LowMask, SignificantBits};
 use core::mem::swap;
 
 pub(crate) const MAX_FACTORS_IN_U8: usize = 4;
@@ -32,6 +32,7 @@ pub(crate) const MAX_FACTORS_IN_U16: usize = 6;
 pub(crate) const MAX_FACTORS_IN_U32: usize = 9;
 pub(crate) const MAX_FACTORS_IN_U64: usize = 15;
 pub(crate) const MAX_FACTORS_IN_USIZE: usize = 15;
+pub(crate) const MAX_FACTORS_IN_U128: usize = 26;
 
 /// A struct that contains the prime factorization of an integer. See implementations of the
 /// [`Factor`] trait for more information.
@@ -121,6 +122,7 @@ type FactorsU16 = Factors<u16, MAX_FACTORS_IN_U16>;
 type FactorsU32 = Factors<u32, MAX_FACTORS_IN_U32>;
 type FactorsU64 = Factors<u64, MAX_FACTORS_IN_U64>;
 type FactorsUsize = Factors<usize, MAX_FACTORS_IN_USIZE>;
+type FactorsU128 = Factors<u128, MAX_FACTORS_IN_U128>;
 
 // This is n_divrem2_precomp when FLINT64 is false, from ulong_extras/divrem2_precomp.c, FLINT
 // 3.1.2, simplified to only include the branches used when factoring a `u32`.
@@ -1131,3 +1133,480 @@ impl Factor for usize {
         factors
     }
 }
+
+// Arithmetic modulo an odd `u128` $n$ in Montgomery form, where $x$ is represented by $2^{128}x \bmod
+// n$. This avoids the slow double-width division in the inner loops of the factoring methods below.
+// Since $n$ is odd, $x$ and $2^{128}x$ have the same gcd with $n$, so gcds may be taken directly in
+// Montgomery form.
+struct MontgomeryU128 {
+    n: u128,
+    // -n^-1 mod 2^128
+    n_neg_inv: u128,
+    // 2^128 mod n, the Montgomery form of 1
+    one: u128,
+    // 2^256 mod n
+    r_squared: u128,
+}
+
+impl MontgomeryU128 {
+    fn new(n: u128) -> MontgomeryU128 {
+        assert!(n.odd());
+        // Each Newton step doubles the number of correct low bits, starting from 3.
+        let mut inv = n;
+        for _ in 0..6 {
+            inv.wrapping_mul_assign(2u128.wrapping_sub(n.wrapping_mul(inv)));
+        }
+        let one = (u128::MAX % n + 1) % n;
+        MontgomeryU128 {
+            n,
+            n_neg_inv: inv.wrapping_neg(),
+            one,
+            r_squared: one.mod_mul(one, n),
+        }
+    }
+
+    // Returns x * y * 2^-128 mod n, for x and y less than n.
+    fn mul(&self, x: u128, y: u128) -> u128 {
+        let (hi, lo) = u128::x_mul_y_to_zz(x, y);
+        let m = lo.wrapping_mul(self.n_neg_inv);
+        let (mn_hi, mn_lo) = u128::x_mul_y_to_zz(m, self.n);
+        // lo + mn_lo is divisible by 2^128, so it only contributes a carry. The full sum hi + mn_hi
+        // + carry is less than 2n, but may not fit in a u128.
+        let carry = u128::from(lo.overflowing_add(mn_lo).1);
+        let (sum, overflow_1) = hi.overflowing_add(mn_hi);
+        let (sum, overflow_2) = sum.overflowing_add(carry);
+        if overflow_1 || overflow_2 || sum >= self.n {
+            sum.wrapping_sub(self.n)
+        } else {
+            sum
+        }
+    }
+
+    #[inline]
+    fn square(&self, x: u128) -> u128 {
+        self.mul(x, x)
+    }
+
+    #[inline]
+    fn to_montgomery(&self, x: u128) -> u128 {
+        self.mul(x % self.n, self.r_squared)
+    }
+
+    #[inline]
+    fn to_standard(&self, x: u128) -> u128 {
+        self.mul(x, 1)
+    }
+
+    // Returns x^exp, with x and the result in Montgomery form.
+    fn pow(&self, x: u128, exp: u64) -> u128 {
+        if exp == 0 {
+            return self.one;
+        }
+        let mut result = x;
+        for i in (0..exp.significant_bits() - 1).rev() {
+            result = self.square(result);
+            if exp.get_bit(i) {
+                result = self.mul(result, x);
+            }
+        }
+        result
+    }
+}
+
+// Returns $xy2^{-128} \bmod n$, for odd $n$ and $x, y < n$.
+#[doc(hidden)]
+pub fn montgomery_mul_u128(x: u128, y: u128, n: u128) -> u128 {
+    MontgomeryU128::new(n).mul(x, y)
+}
+
+// The maximum number of iterations of Pollard's rho method that are attempted on a `u128` before
+// moving on to the other methods.
+const U128_RHO_MAX_ITERS: u64 = 1 << 16;
+
+// The stage-1 bound of Pollard's $p-1$ method for `u128`s.
+const U128_P_MINUS_1_B1: u64 = 100000;
+
+// Stage-1 bounds and numbers of curves for the elliptic curve method on `u128`s. These are the
+// usual choices for finding factors of up to 15, 20, and 25 digits; the smallest prime factor of a
+// composite `u128` is at most $2^{64}$, so it has at most 20 digits. The stage-2 bound is always
+// `U128_ECM_B2_MULTIPLIER` times the stage-1 bound. If all of these curves fail, the last stage-1
+// bound is repeatedly tripled.
+const U128_ECM_SCHEDULE: [(u64, u64); 3] = [(2000, 25), (11000, 90), (50000, 300)];
+
+const U128_ECM_B2_MULTIPLIER: u64 = 100;
+
+// The giant step size used in stage 2 of the elliptic curve method.
+const U128_ECM_STAGE_2_D: u64 = 210;
+
+// Returns the largest power of the prime $p$ that is less than or equal to $b$, where $p \leq b$.
+const fn largest_power_at_most_u64(p: u64, b: u64) -> u64 {
+    let mut q = p;
+    while let Some(next) = q.checked_mul(p) {
+        if next > b {
+            break;
+        }
+        q = next;
+    }
+    q
+}
+
+// If $g$ is a nontrivial factor of $n$, returns it.
+const fn nontrivial_factor_u128(g: u128, n: u128) -> Option<u128> {
+    if g != 1 && g != n { Some(g) } else { None }
+}
+
+// Attempts to find a nontrivial factor of the odd composite $n$ using Brent's variant of Pollard's
+// rho method with the map $x \mapsto x^2 + c$, performing at most roughly `max_iters` iterations.
+// The iteration and the accumulated product are kept in Montgomery form; this changes the
+// pseudorandom map but not the gcds.
+fn pollard_rho_brent_u128(mont: &MontgomeryU128, c: u128, max_iters: u64) -> Option<u128> {
+    const BATCH_SIZE: u64 = 128;
+    let n = mont.n;
+    let f = |y: u128| mont.square(y).mod_add(c, n);
+    let mut x;
+    let mut y = 2;
+    let mut ys = y;
+    let mut q = mont.one;
+    let mut g = 1;
+    let mut r = 1u64;
+    let mut iters = 0u64;
+    while g == 1 {
+        x = y;
+        for _ in 0..r {
+            y = f(y);
+        }
+        let mut k = 0;
+        while k < r && g == 1 {
+            ys = y;
+            for _ in 0..BATCH_SIZE.min(r - k) {
+                y = f(y);
+                q = mont.mul(q, x.abs_diff(y));
+            }
+            g = q.gcd(n);
+            k += BATCH_SIZE;
+        }
+        iters += r;
+        if g == 1 && iters > max_iters {
+            return None;
+        }
+        r <<= 1;
+        if g == n {
+            // The batch overshot; redo its iterations one at a time.
+            loop {
+                ys = f(ys);
+                g = x.abs_diff(ys).gcd(n);
+                if g != 1 {
+                    break;
+                }
+            }
+        }
+    }
+    nontrivial_factor_u128(g, n)
+}
+
+// Attempts to find a nontrivial factor of $n$ using stage 1 of Pollard's $p-1$ method with bound
+// $B_1$. This succeeds if $n$ has a prime factor $p$ such that $p-1$ is $B_1$-powersmooth.
+fn pollard_p_minus_1_u128(mont: &MontgomeryU128, b1: u64) -> Option<u128> {
+    let n = mont.n;
+    let mut a = mont.to_montgomery(2);
+    for p in u64::primes_less_than_or_equal_to(&b1) {
+        a = mont.pow(a, largest_power_at_most_u64(p, b1));
+    }
+    if a == 0 {
+        return None;
+    }
+    nontrivial_factor_u128(a.mod_sub(mont.one, n).gcd(n), n)
+}
+
+// A point on a Montgomery curve $By^2 = x^3 + Ax^2 + x$ modulo $n$, in projective $(X : Z)$
+// coordinates, with $y$ omitted. The coordinates are in Montgomery form.
+#[derive(Clone, Copy)]
+struct MontgomeryPointU128 {
+    x: u128,
+    z: u128,
+}
+
+// Arithmetic on the Montgomery curve with parameter $a_{24} = (A + 2)/4$, modulo $n$.
+struct MontgomeryCurveU128<'a> {
+    mont: &'a MontgomeryU128,
+    a24: u128,
+}
+
+impl MontgomeryCurveU128<'_> {
+    // Returns $2P$.
+    fn double(&self, p: MontgomeryPointU128) -> MontgomeryPointU128 {
+        let mont = self.mont;
+        let n = mont.n;
+        let sum = mont.square(p.x.mod_add(p.z, n));
+        let difference = mont.square(p.x.mod_sub(p.z, n));
+        let t = sum.mod_sub(difference, n);
+        MontgomeryPointU128 {
+            x: mont.mul(sum, difference),
+            z: mont.mul(mont.mul(self.a24, t).mod_add(difference, n), t),
+        }
+    }
+
+    // Returns $P + Q$, given $P - Q$.
+    fn add(
+        &self,
+        p: MontgomeryPointU128,
+        q: MontgomeryPointU128,
+        difference: MontgomeryPointU128,
+    ) -> MontgomeryPointU128 {
+        let mont = self.mont;
+        let n = mont.n;
+        let u = mont.mul(p.x.mod_sub(p.z, n), q.x.mod_add(q.z, n));
+        let v = mont.mul(p.x.mod_add(p.z, n), q.x.mod_sub(q.z, n));
+        MontgomeryPointU128 {
+            x: mont.mul(difference.z, mont.square(u.mod_add(v, n))),
+            z: mont.mul(difference.x, mont.square(u.mod_sub(v, n))),
+        }
+    }
+
+    // Returns $kP$, where $k > 0$, using the Montgomery ladder.
+    fn multiply(&self, p: MontgomeryPointU128, k: u64) -> MontgomeryPointU128 {
+        let mut r_0 = p;
+        let mut r_1 = self.double(p);
+        for i in (0..k.significant_bits() - 1).rev() {
+            if k.get_bit(i) {
+                r_0 = self.add(r_1, r_0, p);
+                r_1 = self.double(r_1);
+            } else {
+                r_1 = self.add(r_0, r_1, p);
+                r_0 = self.double(r_0);
+            }
+        }
+        r_0
+    }
+}
+
+// Attempts to find a nontrivial factor of $n$ using Lenstra's elliptic curve method with a single
+// curve, using Suyama's parametrization with parameter $\sigma$, stage-1 bound $B_1$, and stage-2
+// bound $B_2$. $B_1$ must be at least `U128_ECM_STAGE_2_D`. This is the same as the method used
+// for `Natural`s, with the arithmetic done in Montgomery form.
+fn ecm_one_curve_u128(mont: &MontgomeryU128, sigma: u64, b1: u64, b2: u64) -> Option<u128> {
+    let n = mont.n;
+    let sigma = mont.to_montgomery(u128::from(sigma));
+    let u = mont.square(sigma).mod_sub(mont.to_montgomery(5), n);
+    let v = mont.mul(sigma, mont.to_montgomery(4));
+    let u_cubed = mont.mul(mont.square(u), u);
+    let v_minus_u = v.mod_sub(u, n);
+    // a24 = (v - u)^3 (3u + v) / (16 u^3 v)
+    let numerator = mont.mul(
+        mont.mul(mont.square(v_minus_u), v_minus_u),
+        u.mod_add(u, n).mod_add(u, n).mod_add(v, n),
+    );
+    let denominator = mont.mul(mont.mul(u_cubed, v), mont.to_montgomery(16));
+    let inverse = match mont.to_standard(denominator).mod_inverse(n) {
+        Some(inverse) => mont.to_montgomery(inverse),
+        None => return nontrivial_factor_u128(denominator.gcd(n), n),
+    };
+    let curve = MontgomeryCurveU128 {
+        mont,
+        a24: mont.mul(numerator, inverse),
+    };
+    let mut q = MontgomeryPointU128 {
+        x: u_cubed,
+        z: mont.mul(mont.square(v), v),
+    };
+    // Stage 1
+    for p in u64::primes_less_than_or_equal_to(&b1) {
+        q = curve.multiply(q, largest_power_at_most_u64(p, b1));
+    }
+    let g = q.z.gcd(n);
+    if g != 1 {
+        return nontrivial_factor_u128(g, n);
+    }
+    // Stage 2. First compute the baby steps jQ for odd j < D/2.
+    let mut baby_steps = [q; (U128_ECM_STAGE_2_D >> 2) as usize];
+    let q_2 = curve.double(q);
+    baby_steps[1] = curve.add(q_2, q, q);
+    for i in 2..baby_steps.len() {
+        baby_steps[i] = curve.add(baby_steps[i - 1], q_2, baby_steps[i - 2]);
+    }
+    let q_d = curve.multiply(q, U128_ECM_STAGE_2_D);
+    let half_d = U128_ECM_STAGE_2_D >> 1;
+    // Choose the first giant step kD so that its window [kD - D/2, kD + D/2] reaches down to B1.
+    let mut k = ((b1 + half_d) / U128_ECM_STAGE_2_D).max(1);
+    let mut previous_giant_step = if k == 1 {
+        None
+    } else {
+        Some(curve.multiply(q, (k - 1) * U128_ECM_STAGE_2_D))
+    };
+    let mut giant_step = curve.multiply(q, k * U128_ECM_STAGE_2_D);
+    let mut product = mont.one;
+    for p in u64::primes_less_than_or_equal_to(&b2) {
+        if p <= b1 {
+            continue;
+        }
+        while p > k * U128_ECM_STAGE_2_D + half_d {
+            // When k = 1, the giant step is DQ itself, so the next one is its double.
+            let next = match previous_giant_step {
+                Some(previous_giant_step) => curve.add(giant_step, q_d, previous_giant_step),
+                None => curve.double(giant_step),
+            };
+            previous_giant_step = Some(giant_step);
+            giant_step = next;
+            k += 1;
+        }
+        let baby_step = baby_steps[usize::exact_from(p.abs_diff(k * U128_ECM_STAGE_2_D) >> 1)];
+        let cross_difference = mont
+            .mul(giant_step.x, baby_step.z)
+            .mod_sub(mont.mul(baby_step.x, giant_step.z), n);
+        product = mont.mul(product, cross_difference);
+    }
+    nontrivial_factor_u128(product.gcd(n), n)
+}
+
+// Returns a nontrivial factor of $n$, which must be composite, odd, not a perfect power, and free of
+// prime factors less than 27449. Pollard's rho method is tried first, since it quickly finds small
+// factors, followed by Pollard's $p-1$ method and then the elliptic curve method, whose running
+// time is subexponential in the size of the smallest factor.
+fn find_nontrivial_factor_u128(n: u128) -> u128 {
+    let mont = MontgomeryU128::new(n);
+    if let Some(f) = pollard_rho_brent_u128(&mont, 1, U128_RHO_MAX_ITERS) {
+        return f;
+    }
+    if let Some(f) = pollard_p_minus_1_u128(&mont, U128_P_MINUS_1_B1) {
+        return f;
+    }
+    let mut sigma = 6;
+    for &(b1, curves) in &U128_ECM_SCHEDULE {
+        for _ in 0..curves {
+            if let Some(f) = ecm_one_curve_u128(&mont, sigma, b1, b1 * U128_ECM_B2_MULTIPLIER) {
+                return f;
+            }
+            sigma += 1;
+        }
+    }
+    let mut b1 = U128_ECM_SCHEDULE.last().unwrap().0;
+    loop {
+        b1 = b1.saturating_mul(3);
+        if let Some(f) =
+            ecm_one_curve_u128(&mont, sigma, b1, b1.saturating_mul(U128_ECM_B2_MULTIPLIER))
+        {
+            return f;
+        }
+        sigma += 1;
+    }
+}
+
+impl Factor for u128 {
+    type FACTORS = FactorsU128;
+
+    /// Returns the prime factorization of a `u128`. The return value is iterable, and produces
+    /// pairs $(p,e)$ of type `(u128, u8)`, where the $p$ is prime and $e$ is the exponent of $p$.
+    /// The primes are in ascending order.
+    ///
+    /// Inputs less than $2^{64}$ are factored using the `u64` implementation. For larger inputs,
+    /// small factors are removed by trial division. Each remaining composite cofactor is split by,
+    /// in order, checking whether it is a perfect power, Brent's variant of Pollard's rho
+    /// algorithm, Pollard's $p-1$ method, and Lenstra's elliptic curve method with increasing
+    /// bounds, until every part either fits in a `u64` or is prime. Primality of the parts larger
+    /// than $2^{64}$ is determined by [`u128::is_prime`](IsPrime::is_prime).
+    ///
+    /// # Worst-case complexity
+    /// The running time depends mostly on the size of the second-largest prime factor $p$ of
+    /// `self`, and is expected to be $O(\exp((\sqrt{2}+o(1))\sqrt{\log p \log\log p}))$.
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $M$ is additional memory and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use itertools::Itertools;
+    /// use malachite_base::num::factorization::traits::Factor;
+    ///
+    /// assert_eq!(
+    ///     340282366920938463463374607431768211297u128
+    ///         .factor()
+    ///         .into_iter()
+    ///         .collect_vec(),
+    ///     &[(340282366920938463463374607431768211297, 1)]
+    /// );
+    /// assert_eq!(
+    ///     u128::MAX.factor().into_iter().collect_vec(),
+    ///     &[
+    ///         (3, 1),
+    ///         (5, 1),
+    ///         (17, 1),
+    ///         (257, 1),
+    ///         (641, 1),
+    ///         (65537, 1),
+    ///         (274177, 1),
+    ///         (6700417, 1),
+    ///         (67280421310721, 1)
+    ///     ]
+    /// );
+    /// ```
+    fn factor(&self) -> FactorsU128 {
+        let n = *self;
+        assert_ne!(n, 0);
+        let mut factors = FactorsU128::new();
+        if let Ok(n) = u64::try_from(n) {
+            for (f, e) in n.factor() {
+                factors.insert(u128::from(f), e);
+            }
+            return factors;
+        }
+        let mut cofactor = n;
+        for p in u64::primes().take(FACTOR_TRIAL_PRIMES) {
+            let p = u128::from(p);
+            let mut exp = 0;
+            while cofactor % p == 0 {
+                cofactor /= p;
+                exp += 1;
+            }
+            if exp != 0 {
+                factors.insert(p, exp);
+            }
+        }
+        let mut factor_arr = [0; MAX_FACTORS_IN_U128];
+        let mut exp_arr = [0; MAX_FACTORS_IN_U128];
+        factor_arr[0] = cofactor;
+        exp_arr[0] = 1;
+        let mut factors_left = 1;
+        while factors_left != 0 {
+            factors_left -= 1;
+            let mut factor = factor_arr[factors_left];
+            let mut exp = exp_arr[factors_left];
+            if factor == 1 {
+                continue;
+            }
+            if let Ok(small_factor) = u64::try_from(factor) {
+                for (f, e) in small_factor.factor() {
+                    factors.insert(u128::from(f), e * exp);
+                }
+                continue;
+            }
+            if factor.is_prime() {
+                factors.insert(factor, exp);
+                continue;
+            }
+            // Every prime factor is at least 27449, so a nontrivial power has an exponent of at
+            // most 8.
+            for k in (2..=8).rev() {
+                if let Some(root) = factor.checked_root(k) {
+                    factor = root;
+                    exp *= u8::wrapping_from(k);
+                    break;
+                }
+            }
+            if factor.is_prime() {
+                factors.insert(factor, exp);
+                continue;
+            }
+            let split = find_nontrivial_factor_u128(factor);
+            factor_arr[factors_left] = split;
+            exp_arr[factors_left] = exp;
+            factor_arr[factors_left + 1] = factor / split;
+            exp_arr[factors_left + 1] = exp;
+            factors_left += 2;
+        }
+        factors
+    }
+}
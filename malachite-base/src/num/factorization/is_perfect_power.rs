@@ -13,7 +13,7 @@
 use crate::num::arithmetic::traits::{RootRem, SqrtRem};
 use crate::num::basic::integers::USIZE_IS_U32;
 use crate::num::conversion::traits::ExactFrom;
-use crate::num::factorization::traits::IsPerfectPower;
+use crate::num::factorization::traits::{ExpressAsPower, IsPerfectPower};
 
 // The following arrays are bitmasks indicating whether an integer is a 2, 3, or 5th power residue.
 // For example, modulo 31 we have:
@@ -621,3 +621,67 @@ macro_rules! impl_signed_32 {
 impl_signed_32!(i8);
 impl_signed_32!(i16);
 impl_signed_32!(i32);
+
+macro_rules! impl_express_as_power_unsigned {
+    ($t: ident) => {
+        impl ExpressAsPower for $t {
+            /// Expresses an integer as a power $b^e$, using the greatest possible exponent.
+            ///
+            /// If the integer is a perfect power, the result is the same as that of
+            /// [`is_perfect_power`](IsPerfectPower::is_perfect_power); otherwise, `(self, 1)` is
+            /// returned.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::is_perfect_power#express_as_power).
+            #[inline]
+            fn express_as_power(&self) -> ($t, u64) {
+                match self.is_perfect_power() {
+                    Some((base, exp)) => (base, u64::from(exp)),
+                    None => (*self, 1),
+                }
+            }
+        }
+    };
+}
+impl_express_as_power_unsigned!(u8);
+impl_express_as_power_unsigned!(u16);
+impl_express_as_power_unsigned!(u32);
+impl_express_as_power_unsigned!(u64);
+impl_express_as_power_unsigned!(usize);
+
+macro_rules! impl_express_as_power_signed {
+    ($t: ident) => {
+        impl ExpressAsPower for $t {
+            /// Expresses an integer as a power $b^e$, using the greatest possible exponent.
+            ///
+            /// If the integer is a perfect power, the result is the same as that of
+            /// [`is_perfect_power`](IsPerfectPower::is_perfect_power); otherwise, `(self, 1)` is
+            /// returned. The exception is $-1$: `is_perfect_power` returns `None` for it, but since
+            /// $-1 = (-1)^k$ for every odd $k$, `(-1, 3)` is returned, as it is for `Integer`.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::is_perfect_power#express_as_power).
+            #[inline]
+            fn express_as_power(&self) -> ($t, u64) {
+                if *self == -1 {
+                    return (-1, 3);
+                }
+                match self.is_perfect_power() {
+                    Some((base, exp)) => (base, u64::from(exp)),
+                    None => (*self, 1),
+                }
+            }
+        }
+    };
+}
+impl_express_as_power_signed!(i8);
+impl_express_as_power_signed!(i16);
+impl_express_as_power_signed!(i32);
+impl_express_as_power_signed!(i64);
+impl_express_as_power_signed!(isize);
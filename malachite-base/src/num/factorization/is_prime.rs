@@ -22,12 +22,14 @@
 
 use crate::num::arithmetic::mod_pow::mul_mod_helper;
 use crate::num::arithmetic::traits::{
-    Gcd, JacobiSymbol, ModAdd, ModInverse, ModMulPrecomputed, ModMulPrecomputedAssign, ModSub,
-    Parity, PowerOf2, WrappingAddAssign, WrappingNegAssign, XMulYToZZ, XXAddYYToZZ,
+    CheckedSqrt, Gcd, JacobiSymbol, ModAdd, ModInverse, ModMul, ModMulPrecomputed,
+    ModMulPrecomputedAssign, ModShl, ModSquare, ModSub, Parity, PowerOf2, WrappingAddAssign,
+    WrappingNegAssign, XMulYToZZ, XXAddYYToZZ,
 };
 use crate::num::basic::integers::{PrimitiveInt, USIZE_IS_U32};
 use crate::num::comparison::traits::PartialOrdAbs;
 use crate::num::conversion::traits::WrappingFrom;
+use crate::num::factorization::primes::SMALL_PRIMES;
 use crate::num::factorization::traits::IsPrime;
 use crate::num::logic::traits::{BitAccess, LeadingZeros, SignificantBits, TrailingZeros};
 
@@ -853,3 +855,135 @@ impl IsPrime for usize {
         }
     }
 }
+
+// Computes $a^e \mod n$, where the exponent may be larger than a `u64`.
+fn mod_pow_u128(mut a: u128, mut exp: u128, n: u128) -> u128 {
+    let mut x = 1;
+    while exp != 0 {
+        if exp.odd() {
+            x = x.mod_mul(a, n);
+        }
+        a = a.mod_square(n);
+        exp >>= 1;
+    }
+    x
+}
+
+// Halves $x$ modulo an odd $n$.
+const fn mod_half_u128(x: u128, n: u128) -> u128 {
+    if x & 1 == 0 {
+        x >> 1
+    } else {
+        (x >> 1) + (n >> 1) + 1
+    }
+}
+
+// Assumes n is odd and n > 2^64.
+fn is_strong_probable_prime_u128(n: u128, a: u128) -> bool {
+    let nm1 = n - 1;
+    let s = TrailingZeros::trailing_zeros(nm1);
+    let mut x = mod_pow_u128(a, nm1 >> s, n);
+    if x == 1 || x == nm1 {
+        return true;
+    }
+    for _ in 1..s {
+        x = x.mod_square(n);
+        if x == nm1 {
+            return true;
+        }
+    }
+    false
+}
+
+// The strong Lucas probable prime test, with parameters chosen using Selfridge's method A. Assumes
+// n is odd, n > 2^64, and n is not a square.
+fn is_strong_lucas_probable_prime_u128(n: u128) -> bool {
+    // Find the first D in 5, -7, 9, -11, ... such that (D/n) = -1.
+    let mut abs_d = 5u128;
+    let mut negative = false;
+    let d = loop {
+        let d = if negative { n - abs_d } else { abs_d };
+        match d.jacobi_symbol(n) {
+            -1 => break d,
+            // n > 2^64 > |D|, so n has a nontrivial factor.
+            0 => return false,
+            _ => {}
+        }
+        abs_d += 2;
+        negative = !negative;
+    };
+    // P = 1, Q = (1 - D) / 4
+    let q = if negative {
+        (abs_d + 1) >> 2
+    } else {
+        n - ((abs_d - 1) >> 2)
+    };
+    let np1 = n + 1;
+    let s = TrailingZeros::trailing_zeros(np1);
+    let k = np1 >> s;
+    // Compute U_k, V_k, and Q^k, scanning k from the most significant bit.
+    let mut u = 1;
+    let mut v = 1;
+    let mut qk = q;
+    for i in (0..k.significant_bits() - 1).rev() {
+        u = u.mod_mul(v, n);
+        v = v.mod_square(n).mod_sub(qk.mod_shl(1, n), n);
+        qk = qk.mod_square(n);
+        if k.get_bit(i) {
+            let new_u = mod_half_u128(u.mod_add(v, n), n);
+            v = mod_half_u128(d.mod_mul(u, n).mod_add(v, n), n);
+            u = new_u;
+            qk = qk.mod_mul(q, n);
+        }
+    }
+    if u == 0 || v == 0 {
+        return true;
+    }
+    for _ in 1..s {
+        v = v.mod_square(n).mod_sub(qk.mod_shl(1, n), n);
+        if v == 0 {
+            return true;
+        }
+        qk = qk.mod_square(n);
+    }
+    false
+}
+
+impl IsPrime for u128 {
+    /// Tests whether a `u128` is prime.
+    ///
+    /// Inputs less than $2^{64}$ are tested using the `u64` implementation, which is proven
+    /// correct. Larger inputs are subjected to a few divisibility checks and then the Baillie–PSW
+    /// test. No composite number is known to pass the Baillie–PSW test, but it has not been
+    /// proven that none exists below $2^{128}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::factorization::traits::IsPrime;
+    ///
+    /// assert_eq!(5u128.is_prime(), true);
+    /// assert_eq!(6u128.is_prime(), false);
+    /// assert_eq!(18446744073709551629u128.is_prime(), true);
+    /// assert_eq!(340282366920938463463374607431768211297u128.is_prime(), true);
+    /// assert_eq!(340282366920938463463374607431768211455u128.is_prime(), false);
+    /// ```
+    fn is_prime(&self) -> bool {
+        let n = *self;
+        if let Ok(n) = u64::try_from(n) {
+            return n.is_prime();
+        }
+        if n.even() || SMALL_PRIMES[1..].iter().any(|&p| n % u128::from(p) == 0) {
+            return false;
+        }
+        is_strong_probable_prime_u128(n, 2)
+            && n.checked_sqrt().is_none()
+            && is_strong_lucas_probable_prime_u128(n)
+    }
+}
@@ -6,9 +6,24 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
+/// [`CarmichaelLambda`](traits::CarmichaelLambda), a trait for computing Carmichael's function of
+/// a number.
+pub mod carmichael_lambda;
+/// [`DivisorCount`](traits::DivisorCount), a trait for counting the positive divisors of a number.
+pub mod divisor_count;
+/// [`DivisorSum`](traits::DivisorSum), a trait for computing the sum of the positive divisors of a
+/// number.
+pub mod divisor_sum;
+/// [`Divisors`](traits::Divisors), a trait for producing iterators of the positive divisors of a
+/// number.
+pub mod divisors;
+/// [`EulerTotient`](traits::EulerTotient), a trait for computing Euler's totient function of a
+/// number.
+pub mod euler_totient;
 /// [`Factor`](traits::Factor), a trait for computing the prime factorization of a number.
 pub mod factor;
-/// [`IsPerfectPower`](traits::IsPerfectPower), a trait for testing if a number is a perfect power.
+/// [`IsPerfectPower`](traits::IsPerfectPower) and [`ExpressAsPower`](traits::ExpressAsPower),
+/// traits for testing if a number is a perfect power and for expressing it as one.
 ///
 /// # is_perfect_power
 /// ```
@@ -19,11 +34,26 @@ pub mod factor;
 /// assert_eq!(36u32.is_perfect_power().unwrap(), (6, 2));
 /// assert_eq!(100u64.is_perfect_power().unwrap(), (10, 2));
 /// assert_eq!(1728u64.is_perfect_power().unwrap(), (12, 3));
+/// assert_eq!((-64i32).is_perfect_power().unwrap(), (-4, 3));
 ///
 /// assert!(0u8.is_perfect_power().is_some());
 /// assert!(1u16.is_perfect_power().is_some());
 /// assert!(2u64.is_perfect_power().is_none());
 /// assert!(3u64.is_perfect_power().is_none());
+/// assert!((-1i8).is_perfect_power().is_none());
+/// ```
+///
+/// # express_as_power
+/// ```
+/// use malachite_base::num::factorization::traits::ExpressAsPower;
+///
+/// assert_eq!(0u8.express_as_power(), (0, 2));
+/// assert_eq!(1u16.express_as_power(), (1, 2));
+/// assert_eq!(1728u64.express_as_power(), (12, 3));
+/// assert_eq!((-64i32).express_as_power(), (-4, 3));
+/// assert_eq!((-1i8).express_as_power(), (-1, 3));
+/// assert_eq!(10u32.express_as_power(), (10, 1));
+/// assert_eq!((-16i64).express_as_power(), (-16, 1));
 /// ```
 pub mod is_perfect_power;
 /// [`IsPrime`](traits::IsPrime), a trait for testing a number for primality.
@@ -45,6 +75,17 @@ pub mod is_prime;
 /// assert!(!128u64.is_square());
 /// ```
 pub mod is_square;
+/// [`ModLog`](traits::ModLog), a trait for computing discrete logarithms.
+pub mod mod_log;
+/// [`Moebius`](traits::Moebius), a trait for computing the Möbius function of a number.
+pub mod moebius;
+/// [`MultiplicativeOrder`](traits::MultiplicativeOrder), a trait for computing the multiplicative
+/// order of a number modulo another number.
+pub mod multiplicative_order;
+/// [`NextPrime`](traits::NextPrime), a trait for finding the smallest prime greater than a number.
+pub mod next_prime;
+/// [`PrevPrime`](traits::PrevPrime), a trait for finding the largest prime less than a number.
+pub mod prev_prime;
 /// An efficient prime sieve.
 pub mod prime_sieve;
 /// [`Primes`](traits::Primes), a trait for generating prime numbers.
@@ -0,0 +1,171 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::exhaustive::primitive_int_increasing_range;
+use crate::num::factorization::multiplicative_order::multiplicative_order_with_factors;
+use crate::num::factorization::traits::{Factor, ModLog};
+use alloc::vec::Vec;
+
+// Finds the k in [0, q) such that g^k = x mod m, where g has order q mod m, using the baby-step
+// giant-step algorithm. Returns `None` if there is no such k.
+fn mod_log_baby_step_giant_step<T: PrimitiveUnsigned>(x: T, g: T, q: T, m: T) -> Option<T> {
+    let steps = q.ceiling_sqrt();
+    // Baby steps: g^j for 0 <= j < steps
+    let mut baby_steps = Vec::new();
+    let mut power = T::ONE;
+    for j in primitive_int_increasing_range(T::ZERO, steps) {
+        baby_steps.push((power, j));
+        power.mod_mul_assign(g, m);
+    }
+    baby_steps.sort_unstable();
+    // Giant steps: x g^(-steps i) for 0 <= i < steps
+    let giant_step = g.mod_pow((q - steps).wrapping_into(), m);
+    let mut y = x;
+    for i in primitive_int_increasing_range(T::ZERO, steps) {
+        if let Ok(index) = baby_steps.binary_search_by(|&(z, _)| z.cmp(&y)) {
+            return Some(i * steps + baby_steps[index].1);
+        }
+        y.mod_mul_assign(giant_step, m);
+    }
+    None
+}
+
+// Finds the smallest k such that g^k = x mod m, where x and g are coprime to m and m > 1, using the
+// Pohlig-Hellman algorithm.
+fn mod_log_coprime<T: PrimitiveUnsigned + Factor>(x: T, g: T, m: T) -> Option<T>
+where
+    <T as Factor>::FACTORS: IntoIterator<Item = (T, u8)>,
+{
+    let (order, factors) = multiplicative_order_with_factors(g, m);
+    let mut congruences = Vec::with_capacity(factors.len());
+    for (q, e) in factors {
+        // Work in the subgroup of order q^e.
+        let qe = q.pow(e);
+        let cofactor: u64 = (order / qe).wrapping_into();
+        let g_sub = g.mod_pow(cofactor, m);
+        let x_sub = x.mod_pow(cofactor, m);
+        // gamma has order q.
+        let gamma = g_sub.mod_pow((qe / q).wrapping_into(), m);
+        // Find the base-q digits of the logarithm, one at a time.
+        let mut k = T::ZERO;
+        let mut q_power = T::ONE;
+        for i in (0..e).rev() {
+            let y = g_sub
+                .mod_pow((qe - k).wrapping_into(), m)
+                .mod_mul(x_sub, m)
+                .mod_pow(q.pow(i).wrapping_into(), m);
+            k += mod_log_baby_step_giant_step(y, gamma, q, m)? * q_power;
+            if i != 0 {
+                q_power *= q;
+            }
+        }
+        congruences.push((k, qe));
+    }
+    let k = T::chinese_remainder(congruences).unwrap().0;
+    // If x is not in the subgroup generated by g, the result is wrong.
+    if g.mod_pow(k.wrapping_into(), m) == x {
+        Some(k)
+    } else {
+        None
+    }
+}
+
+fn mod_log<T: PrimitiveUnsigned + Factor>(x: T, g: T, mut m: T) -> Option<T>
+where
+    <T as Factor>::FACTORS: IntoIterator<Item = (T, u8)>,
+{
+    assert!(x < m, "x must be reduced mod m, but {x} >= {m}");
+    assert!(g < m, "g must be reduced mod m, but {g} >= {m}");
+    // While g and m are not coprime, g^k = x mod m for k > 0 is equivalent to (g / d) g^(k - 1) =
+    // x / d mod m / d, where d = gcd(g, m).
+    let mut x = x;
+    let mut offset = T::ZERO;
+    let mut multiplier = T::ONE % m;
+    loop {
+        let d = g.gcd(m);
+        if d == T::ONE {
+            break;
+        }
+        if x == multiplier {
+            return Some(offset);
+        }
+        if !x.divisible_by(d) {
+            return None;
+        }
+        x /= d;
+        m /= d;
+        offset += T::ONE;
+        multiplier = (multiplier % m).mod_mul((g / d) % m, m);
+    }
+    if m == T::ONE {
+        return Some(offset);
+    }
+    // Now solve multiplier g^k = x mod m. The multiplier is coprime to m.
+    let x = x.mod_mul(multiplier.mod_inverse(m).unwrap(), m);
+    if !x.coprime_with(m) {
+        return None;
+    }
+    Some(mod_log_coprime(x, g % m, m)? + offset)
+}
+
+macro_rules! impl_mod_log {
+    ($t:ident) => {
+        impl ModLog<$t> for $t {
+            type Output = $t;
+
+            /// Computes the discrete logarithm of a number to a given base, modulo another number
+            /// $m$. The number and the base must be already reduced modulo $m$.
+            ///
+            /// Returns the smallest $k \geq 0$ such that $g^k \equiv x \mod m$, where $g$ is the
+            /// base, or `None` if there is no such $k$.
+            ///
+            /// $$
+            /// f(x, g, m) = \min \\{ k : k \geq 0 \text{ and } g^k \equiv x \mod m \\}.
+            /// $$
+            ///
+            /// First, common factors of $g$ and $m$ are removed. Then the multiplicative order of
+            /// $g$ is computed and factored, and the Pohlig-Hellman algorithm reduces the problem
+            /// to logarithms in groups of prime order. These are solved with the baby-step
+            /// giant-step algorithm.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/2} n)$
+            ///
+            /// $M(n) = O(2^{n/2})$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`. The
+            /// time and memory are dominated by the square root of the largest prime factor of the
+            /// order of $g$.
+            ///
+            /// # Panics
+            /// Panics if `self` or `base` is greater than or equal to `m`.
+            ///
+            /// # Examples
+            /// ```
+            /// use malachite_base::num::factorization::traits::ModLog;
+            ///
+            /// assert_eq!(1u8.mod_log(3, 7), Some(0));
+            /// assert_eq!(6u8.mod_log(3, 7), Some(3));
+            /// assert_eq!(3u16.mod_log(2, 7), None);
+            /// assert_eq!(0u16.mod_log(2, 8), Some(3));
+            /// assert_eq!(6u32.mod_log(4, 10), Some(2));
+            /// assert_eq!(12345u64.mod_log(5, 1000000007), Some(775972150));
+            /// ```
+            #[inline]
+            fn mod_log(self, base: $t, m: $t) -> Option<$t> {
+                mod_log(self, base, m)
+            }
+        }
+    };
+}
+impl_mod_log!(u8);
+impl_mod_log!(u16);
+impl_mod_log!(u32);
+impl_mod_log!(u64);
+impl_mod_log!(usize);
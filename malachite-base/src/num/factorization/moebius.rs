@@ -0,0 +1,73 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::{Factor, Moebius};
+
+fn moebius<T: PrimitiveUnsigned + Factor>(n: T) -> i8
+where
+    <T as Factor>::FACTORS: IntoIterator<Item = (T, u8)>,
+{
+    let mut mu = 1;
+    for (_, e) in n.factor() {
+        if e > 1 {
+            return 0;
+        }
+        mu = -mu;
+    }
+    mu
+}
+
+macro_rules! impl_moebius {
+    ($t:ident) => {
+        impl Moebius for $t {
+            /// Computes the Möbius function of a number.
+            ///
+            /// $$
+            /// \mu(n) = \\begin{cases}
+            ///     0 & \text{if} \\quad p^2 \mid n \text{ for some prime } p, \\\\
+            ///     (-1)^k & \text{otherwise},
+            /// \\end{cases}
+            /// $$
+            /// where $k$ is the number of distinct prime factors of $n$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4})$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// ```
+            /// use malachite_base::num::factorization::traits::Moebius;
+            ///
+            /// assert_eq!(1u8.moebius(), 1);
+            /// assert_eq!(30u8.moebius(), -1);
+            /// assert_eq!(36u16.moebius(), 0);
+            /// assert_eq!(65535u16.moebius(), 1);
+            /// assert_eq!(1000000007u32.moebius(), -1);
+            /// assert_eq!(1000000000000u64.moebius(), 0);
+            /// assert_eq!(u128::MAX.moebius(), -1);
+            /// ```
+            #[inline]
+            fn moebius(&self) -> i8 {
+                moebius(*self)
+            }
+        }
+    };
+}
+impl_moebius!(u8);
+impl_moebius!(u16);
+impl_moebius!(u32);
+impl_moebius!(u64);
+impl_moebius!(u128);
+impl_moebius!(usize);
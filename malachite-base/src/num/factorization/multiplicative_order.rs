@@ -0,0 +1,136 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::{Factor, MultiplicativeOrder};
+use alloc::vec::Vec;
+
+// Returns the prime factorization of phi(m), the order of the multiplicative group mod m. m must be
+// greater than 1.
+fn totient_factors<T: PrimitiveUnsigned + Factor>(m: T) -> Vec<(T, u64)>
+where
+    <T as Factor>::FACTORS: IntoIterator<Item = (T, u8)>,
+{
+    // phi(p_1^e_1 ... p_k^e_k) = p_1^(e_1 - 1) (p_1 - 1) ... p_k^(e_k - 1) (p_k - 1)
+    let mut factors: Vec<(T, u64)> = Vec::new();
+    for (p, e) in m.factor() {
+        if e > 1 {
+            factors.push((p, u64::from(e - 1)));
+        }
+        if p != T::TWO {
+            factors.extend(
+                (p - T::ONE)
+                    .factor()
+                    .into_iter()
+                    .map(|(q, f)| (q, u64::from(f))),
+            );
+        }
+    }
+    factors.sort_unstable();
+    let mut merged: Vec<(T, u64)> = Vec::with_capacity(factors.len());
+    for (p, e) in factors {
+        match merged.last_mut() {
+            Some((q, f)) if *q == p => *f += e,
+            _ => merged.push((p, e)),
+        }
+    }
+    merged
+}
+
+// Returns the multiplicative order of x mod m, together with its prime factorization. x must be
+// coprime to m, and m must be greater than 1.
+pub(crate) fn multiplicative_order_with_factors<T: PrimitiveUnsigned + Factor>(
+    x: T,
+    m: T,
+) -> (T, Vec<(T, u64)>)
+where
+    <T as Factor>::FACTORS: IntoIterator<Item = (T, u8)>,
+{
+    let mut factors = totient_factors(m);
+    let mut order = factors.iter().fold(T::ONE, |n, &(p, e)| n * p.pow(e));
+    // Remove each prime factor from the order for as long as x^order is still 1.
+    for (p, e) in &mut factors {
+        while *e != 0 {
+            let smaller = order / *p;
+            if x.mod_pow(smaller.wrapping_into(), m) != T::ONE {
+                break;
+            }
+            order = smaller;
+            *e -= 1;
+        }
+    }
+    factors.retain(|&(_, e)| e != 0);
+    (order, factors)
+}
+
+fn multiplicative_order<T: PrimitiveUnsigned + Factor>(x: T, m: T) -> Option<T>
+where
+    <T as Factor>::FACTORS: IntoIterator<Item = (T, u8)>,
+{
+    assert!(x < m, "x must be reduced mod m, but {x} >= {m}");
+    if m == T::ONE {
+        Some(T::ONE)
+    } else if x.coprime_with(m) {
+        Some(multiplicative_order_with_factors(x, m).0)
+    } else {
+        None
+    }
+}
+
+macro_rules! impl_multiplicative_order {
+    ($t:ident) => {
+        impl MultiplicativeOrder<$t> for $t {
+            type Output = $t;
+
+            /// Computes the multiplicative order of a number modulo another number $m$. The input
+            /// must be already reduced modulo $m$.
+            ///
+            /// The multiplicative order of $x$ is the smallest positive integer $k$ such that
+            /// $x^k \equiv 1 \mod m$. It exists if and only if $x$ and $m$ are coprime; if they
+            /// aren't, `None` is returned.
+            ///
+            /// $$
+            /// f(x, m) = \min \\{ k : k > 0 \text{ and } x^k \equiv 1 \mod m \\}.
+            /// $$
+            ///
+            /// The order divides $\varphi(m)$, so $m$ and $p - 1$, for each prime factor $p$ of
+            /// $m$, are factored. Then prime factors are removed from $\varphi(m)$ for as long as
+            /// the result is still a multiple of the order.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4})$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` is greater than or equal to `m`.
+            ///
+            /// # Examples
+            /// ```
+            /// use malachite_base::num::factorization::traits::MultiplicativeOrder;
+            ///
+            /// assert_eq!(2u8.multiplicative_order(7), Some(3));
+            /// assert_eq!(3u8.multiplicative_order(7), Some(6));
+            /// assert_eq!(2u16.multiplicative_order(8), None);
+            /// assert_eq!(5u32.multiplicative_order(12), Some(2));
+            /// assert_eq!(2u64.multiplicative_order(1000000007), Some(500000003));
+            /// ```
+            #[inline]
+            fn multiplicative_order(self, m: $t) -> Option<$t> {
+                multiplicative_order(self, m)
+            }
+        }
+    };
+}
+impl_multiplicative_order!(u8);
+impl_multiplicative_order!(u16);
+impl_multiplicative_order!(u32);
+impl_multiplicative_order!(u64);
+impl_multiplicative_order!(usize);
@@ -0,0 +1,92 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::prime_sieve::{
+    id_to_n, limbs_prime_sieve_size, limbs_prime_sieve_u64, n_to_bit,
+};
+use crate::num::factorization::primes::{SMALL_PRIMES, limbs_index_of_next_false_bit};
+use crate::num::factorization::traits::{IsPrime, NextPrime};
+
+// For values less than this, the next or previous prime is found using a prime sieve. For larger
+// values, candidates are tested individually using `is_prime`.
+pub(crate) const PRIME_SIEVE_THRESHOLD: u64 = 1 << 12;
+
+// Returns the smallest prime greater than `n`, where `n` is less than `PRIME_SIEVE_THRESHOLD`.
+fn next_prime_small(n: u64) -> u64 {
+    // 1021 is the greatest prime smaller than 2^10.
+    if n < 1021 {
+        return u64::from(SMALL_PRIMES[SMALL_PRIMES.partition_point(|&p| u64::from(p) <= n)]);
+    }
+    // By Bertrand's postulate, there is a prime between n and 2n.
+    let limit = n << 1;
+    let mut sieve = alloc::vec![0; limbs_prime_sieve_size::<u64>(limit)];
+    limbs_prime_sieve_u64(&mut sieve, limit);
+    id_to_n(limbs_index_of_next_false_bit(&sieve, n_to_bit(n) + 1).unwrap() + 1)
+}
+
+fn next_prime<T: IsPrime + PrimitiveUnsigned>(n: T) -> Option<T> {
+    if n < T::saturating_from(PRIME_SIEVE_THRESHOLD) {
+        return T::try_from(next_prime_small(n.wrapping_into())).ok();
+    }
+    let mut candidate = n.checked_add(T::ONE)?;
+    if candidate.even() {
+        candidate = candidate.checked_add(T::ONE)?;
+    }
+    while !candidate.is_prime() {
+        candidate = candidate.checked_add(T::TWO)?;
+    }
+    Some(candidate)
+}
+
+macro_rules! impl_next_prime {
+    ($t:ident) => {
+        impl NextPrime for $t {
+            type Output = Option<$t>;
+
+            /// Finds the smallest prime greater than a number. If there is no such prime that is
+            /// representable as a value of the same type, `None` is returned.
+            ///
+            /// Small values are handled using a prime sieve; for larger values, odd candidates are
+            /// tested using [`is_prime`](IsPrime::is_prime).
+            ///
+            /// $$
+            /// f(n) = \min\\{p : p > n \text{ and } p \text{ is prime}\\}.
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Examples
+            /// ```
+            /// use malachite_base::num::factorization::traits::NextPrime;
+            ///
+            /// assert_eq!(0u8.next_prime(), Some(2));
+            /// assert_eq!(2u8.next_prime(), Some(3));
+            /// assert_eq!(100u8.next_prime(), Some(101));
+            /// assert_eq!(251u8.next_prime(), None);
+            /// assert_eq!(10000u16.next_prime(), Some(10007));
+            /// assert_eq!(1000000u32.next_prime(), Some(1000003));
+            /// assert_eq!(u64::MAX.next_prime(), None);
+            /// ```
+            #[inline]
+            fn next_prime(&self) -> Option<$t> {
+                next_prime(*self)
+            }
+        }
+    };
+}
+impl_next_prime!(u8);
+impl_next_prime!(u16);
+impl_next_prime!(u32);
+impl_next_prime!(u64);
+impl_next_prime!(usize);
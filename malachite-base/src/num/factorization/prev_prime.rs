@@ -0,0 +1,107 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::integers::PrimitiveInt;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::conversion::traits::WrappingFrom;
+use crate::num::factorization::next_prime::PRIME_SIEVE_THRESHOLD;
+use crate::num::factorization::prime_sieve::{
+    id_to_n, limbs_prime_sieve_size, limbs_prime_sieve_u64,
+};
+use crate::num::factorization::primes::SMALL_PRIMES;
+use crate::num::factorization::traits::{IsPrime, PrevPrime};
+use crate::num::logic::traits::LeadingZeros;
+
+// Returns the greatest prime less than `n`, where `n` is less than `PRIME_SIEVE_THRESHOLD`.
+fn prev_prime_small(n: u64) -> Option<u64> {
+    // 1031 is the smallest prime greater than 2^10, so all primes less than it are small primes.
+    if n <= 1031 {
+        let i = SMALL_PRIMES.partition_point(|&p| u64::from(p) < n);
+        return if i == 0 {
+            None
+        } else {
+            Some(u64::from(SMALL_PRIMES[i - 1]))
+        };
+    }
+    let limit = n - 1;
+    let mut sieve = alloc::vec![0; limbs_prime_sieve_size::<u64>(limit)];
+    limbs_prime_sieve_u64(&mut sieve, limit);
+    // The bits corresponding to numbers greater than the limit are set, so the highest false bit
+    // corresponds to the greatest prime less than n.
+    let (i, &x) = sieve
+        .iter()
+        .enumerate()
+        .rev()
+        .find(|&(_, &x)| x != u64::MAX)
+        .unwrap();
+    let bit = (u64::wrapping_from(i) << u64::LOG_WIDTH) + u64::WIDTH
+        - 1
+        - LeadingZeros::leading_zeros(!x);
+    Some(id_to_n(bit + 1))
+}
+
+fn prev_prime<T: IsPrime + PrimitiveUnsigned>(n: T) -> Option<T> {
+    if n < T::saturating_from(PRIME_SIEVE_THRESHOLD) {
+        return prev_prime_small(n.wrapping_into()).map(T::wrapping_from);
+    }
+    let mut candidate = n - T::ONE;
+    if candidate.even() {
+        candidate -= T::ONE;
+    }
+    while !candidate.is_prime() {
+        candidate -= T::TWO;
+    }
+    Some(candidate)
+}
+
+macro_rules! impl_prev_prime {
+    ($t:ident) => {
+        impl PrevPrime for $t {
+            type Output = Option<$t>;
+
+            /// Finds the greatest prime less than a number. If there is no such prime (that is, if
+            /// the number is less than or equal to 2), `None` is returned.
+            ///
+            /// Small values are handled using a prime sieve; for larger values, odd candidates are
+            /// tested using [`is_prime`](IsPrime::is_prime).
+            ///
+            /// $$
+            /// f(n) = \max\\{p : p < n \text{ and } p \text{ is prime}\\}.
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Examples
+            /// ```
+            /// use malachite_base::num::factorization::traits::PrevPrime;
+            ///
+            /// assert_eq!(0u8.prev_prime(), None);
+            /// assert_eq!(2u8.prev_prime(), None);
+            /// assert_eq!(3u8.prev_prime(), Some(2));
+            /// assert_eq!(100u8.prev_prime(), Some(97));
+            /// assert_eq!(10000u16.prev_prime(), Some(9973));
+            /// assert_eq!(1000000u32.prev_prime(), Some(999983));
+            /// assert_eq!(u64::MAX.prev_prime(), Some(18446744073709551557));
+            /// ```
+            #[inline]
+            fn prev_prime(&self) -> Option<$t> {
+                prev_prime(*self)
+            }
+        }
+    };
+}
+impl_prev_prime!(u8);
+impl_prev_prime!(u16);
+impl_prev_prime!(u32);
+impl_prev_prime!(u64);
+impl_prev_prime!(usize);
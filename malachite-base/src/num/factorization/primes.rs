@@ -46,7 +46,10 @@ pub(crate) const SMALL_PRIMES: [u16; NUM_SMALL_PRIMES] = [
 
 // This differs from the identically-named function in malachite-nz; this one returns None if there
 // are no more false bits.
-fn limbs_index_of_next_false_bit<T: PrimitiveUnsigned>(xs: &[T], start: u64) -> Option<u64> {
+pub(crate) fn limbs_index_of_next_false_bit<T: PrimitiveUnsigned>(
+    xs: &[T],
+    start: u64,
+) -> Option<u64> {
     let starting_index = usize::exact_from(start >> T::LOG_WIDTH);
     if starting_index >= xs.len() {
         return None;
@@ -6,6 +6,43 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
+/// A trait for computing Carmichael's function of a number; that is, the smallest positive $k$ such
+/// that $x^k \equiv 1 \mod n$ for every $x$ coprime to $n$.
+pub trait CarmichaelLambda {
+    type Output;
+
+    fn carmichael_lambda(&self) -> Self::Output;
+}
+
+/// A trait for counting the positive divisors of a number.
+pub trait DivisorCount {
+    type Output;
+
+    fn divisor_count(&self) -> Self::Output;
+}
+
+/// A trait for computing the sum of the positive divisors of a number.
+pub trait DivisorSum {
+    type Output;
+
+    fn divisor_sum(&self) -> Self::Output;
+}
+
+/// A trait for producing iterators of the positive divisors of a number.
+pub trait Divisors: Sized {
+    type I: Iterator<Item = Self>;
+
+    fn divisors(&self) -> Self::I;
+}
+
+/// A trait for computing Euler's totient function of a number; that is, the number of integers in
+/// $[1, n]$ that are coprime to $n$.
+pub trait EulerTotient {
+    type Output;
+
+    fn euler_totient(&self) -> Self::Output;
+}
+
 /// A trait for testing whether a number is prime.
 pub trait IsPrime {
     fn is_prime(&self) -> bool;
@@ -16,12 +53,25 @@ pub trait IsSquare {
     fn is_square(&self) -> bool;
 }
 
-/// A trait for testing whether a number is a perfect power.
+/// A trait for testing whether a number is a perfect power, and if so, expressing it as one.
+///
+/// Every implementation returns an [`Option`] that is `Some((base, exp))` for a perfect power. The
+/// exponent is a `u32` for primitive integers and a `u64` for `Natural` and `Integer`.
 pub trait IsPerfectPower {
     type Output;
     fn is_perfect_power(&self) -> Self::Output;
 }
 
+/// A trait for expressing a number as a power $b^e$, using the greatest possible exponent.
+///
+/// Unlike [`IsPerfectPower::is_perfect_power`], this always succeeds: a number that is not a
+/// perfect power is returned as `(n, 1)`. For perfect powers, the results of the two traits agree,
+/// except that the exponent is always a `u64`, and that $-1$ is always expressed as $(-1)^3$, even
+/// though the primitive signed integers' [`IsPerfectPower`] implementations return `None` for it.
+pub trait ExpressAsPower: Sized {
+    fn express_as_power(&self) -> (Self, u64);
+}
+
 /// A trait for finding the prime factorization of a number.
 pub trait Factor {
     type FACTORS;
@@ -29,6 +79,42 @@ pub trait Factor {
     fn factor(&self) -> Self::FACTORS;
 }
 
+/// A trait for computing discrete logarithms. Given $x$, a base $g$, and a modulus $m$, finds the
+/// smallest $k \geq 0$ such that $g^k \equiv x \mod m$.
+pub trait ModLog<B = Self, M = Self> {
+    type Output;
+
+    fn mod_log(self, base: B, m: M) -> Option<Self::Output>;
+}
+
+/// A trait for computing the Möbius function of a number, which is 0 if the number is divisible by
+/// a square greater than 1, and otherwise $(-1)^k$, where $k$ is the number of prime factors.
+pub trait Moebius {
+    fn moebius(&self) -> i8;
+}
+
+/// A trait for computing the multiplicative order of a number modulo another number; that is, the
+/// smallest positive $k$ such that $x^k \equiv 1 \mod m$.
+pub trait MultiplicativeOrder<M = Self> {
+    type Output;
+
+    fn multiplicative_order(self, m: M) -> Option<Self::Output>;
+}
+
+/// A trait for finding the smallest prime greater than a number.
+pub trait NextPrime {
+    type Output;
+
+    fn next_prime(&self) -> Self::Output;
+}
+
+/// A trait for finding the largest prime less than a number.
+pub trait PrevPrime {
+    type Output;
+
+    fn prev_prime(&self) -> Self::Output;
+}
+
 /// A trait for producing iterators of primes.
 pub trait Primes {
     type I: Iterator<Item = Self>;
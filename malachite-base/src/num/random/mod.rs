@@ -29,7 +29,7 @@ use crate::random::{EXAMPLE_SEED, Seed};
 use crate::rounding_modes::RoundingMode::*;
 use crate::vecs::{RandomValuesFromVec, random_values_from_vec};
 use itertools::Itertools;
-use rand::Rng;
+use rand::{Rng, RngCore};
 use rand_chacha::ChaCha20Rng;
 use std::collections::HashMap;
 use std::convert::identity;
@@ -50,7 +50,7 @@ pub trait HasRandomPrimitiveInts {
 
     fn new_state() -> Self::State;
 
-    fn get_random(rng: &mut ChaCha20Rng, state: &mut Self::State) -> Self;
+    fn get_random<R: RngCore + ?Sized>(rng: &mut R, state: &mut Self::State) -> Self;
 }
 
 macro_rules! impl_trivial_random_primitive_ints {
@@ -62,7 +62,7 @@ macro_rules! impl_trivial_random_primitive_ints {
             fn new_state() -> () {}
 
             #[inline]
-            fn get_random(rng: &mut ChaCha20Rng, _state: &mut ()) -> $t {
+            fn get_random<R: RngCore + ?Sized>(rng: &mut R, _state: &mut ()) -> $t {
                 rng.r#gen()
             }
         }
@@ -77,7 +77,10 @@ impl_trivial_random_primitive_ints!(i64);
 impl_trivial_random_primitive_ints!(i128);
 impl_trivial_random_primitive_ints!(isize);
 
-fn get_random<T: PrimitiveInt>(rng: &mut ChaCha20Rng, state: &mut ThriftyRandomState) -> T {
+fn get_random<T: PrimitiveInt, R: RngCore + ?Sized>(
+    rng: &mut R,
+    state: &mut ThriftyRandomState,
+) -> T {
     if state.bits_left == 0 {
         state.x = rng.r#gen();
         state.bits_left = u32::WIDTH - T::WIDTH;
@@ -99,7 +102,7 @@ macro_rules! impl_thrifty_random_primitive_ints {
             }
 
             #[inline]
-            fn get_random(rng: &mut ChaCha20Rng, state: &mut ThriftyRandomState) -> $t {
+            fn get_random<R: RngCore + ?Sized>(rng: &mut R, state: &mut ThriftyRandomState) -> $t {
                 get_random(rng, state)
             }
         }
@@ -380,6 +383,30 @@ pub fn random_primitive_ints<T: PrimitiveInt>(seed: Seed) -> RandomPrimitiveInts
     }
 }
 
+/// Uniformly generates a random primitive integer, using a caller-supplied random number generator.
+///
+/// Any [`RngCore`] may be used: for example, a reproducible PRNG or the operating system's
+/// cryptographically secure RNG.
+///
+/// $P(x) = 2^{-W}$, where $W$ is the width of the type.
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::random::get_random_primitive_int_with_rng;
+/// use malachite_base::random::EXAMPLE_SEED;
+///
+/// let mut rng = EXAMPLE_SEED.get_rng();
+/// assert_eq!(get_random_primitive_int_with_rng::<u8, _>(&mut rng), 113);
+/// assert_eq!(get_random_primitive_int_with_rng::<u32, _>(&mut rng), 2712195812);
+/// ```
+#[inline]
+pub fn get_random_primitive_int_with_rng<T: PrimitiveInt, R: RngCore + ?Sized>(rng: &mut R) -> T {
+    T::get_random(rng, &mut T::new_state())
+}
+
 /// Uniformly generates random positive unsigned integers.
 ///
 /// $$
@@ -680,6 +707,125 @@ pub fn random_unsigned_inclusive_range<T: PrimitiveUnsigned>(
     }
 }
 
+/// Uniformly generates a random unsigned integer less than a positive limit, using a
+/// caller-supplied random number generator.
+///
+/// Any [`RngCore`] may be used: for example, a reproducible PRNG or the operating system's
+/// cryptographically secure RNG.
+///
+/// $$
+/// P(x) = \\begin{cases}
+///     \frac{1}{\\ell} & \text{if} \\quad x < \\ell, \\\\
+///     0 & \\text{otherwise,}
+/// \\end{cases}
+/// $$
+/// where $\ell$ is `limit`.
+///
+/// # Expected complexity
+/// Constant time and additional memory.
+///
+/// # Panics
+/// Panics if `limit` is 0.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::random::get_random_unsigned_less_than_with_rng;
+/// use malachite_base::random::EXAMPLE_SEED;
+///
+/// let mut rng = EXAMPLE_SEED.get_rng();
+/// assert_eq!(get_random_unsigned_less_than_with_rng::<u8, _>(&mut rng, 10), 1);
+/// assert_eq!(get_random_unsigned_less_than_with_rng::<u64, _>(&mut rng, 1000000), 578276);
+/// ```
+pub fn get_random_unsigned_less_than_with_rng<T: PrimitiveUnsigned, R: RngCore + ?Sized>(
+    rng: &mut R,
+    limit: T,
+) -> T {
+    assert_ne!(limit, T::ZERO, "limit cannot be 0.");
+    let bits = limit.ceiling_log_base_2();
+    loop {
+        let x = get_random_primitive_int_with_rng::<T, R>(rng).mod_power_of_2(bits);
+        if x < limit {
+            return x;
+        }
+    }
+}
+
+/// Uniformly generates a random unsigned integer in the half-open interval $[a, b)$, using a
+/// caller-supplied random number generator.
+///
+/// $a$ must be less than $b$. This function cannot generate `T::MAX`; for that, use
+/// [`get_random_unsigned_inclusive_range_with_rng`].
+///
+/// $$
+/// P(x) = \\begin{cases}
+///     \frac{1}{b-a} & \text{if} \\quad a \leq x < b, \\\\
+///     0 & \\text{otherwise.}
+/// \\end{cases}
+/// $$
+///
+/// # Expected complexity
+/// Constant time and additional memory.
+///
+/// # Panics
+/// Panics if $a \geq b$.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::random::get_random_unsigned_range_with_rng;
+/// use malachite_base::random::EXAMPLE_SEED;
+///
+/// let mut rng = EXAMPLE_SEED.get_rng();
+/// assert_eq!(get_random_unsigned_range_with_rng::<u8, _>(&mut rng, 10, 20), 11);
+/// ```
+#[inline]
+pub fn get_random_unsigned_range_with_rng<T: PrimitiveUnsigned, R: RngCore + ?Sized>(
+    rng: &mut R,
+    a: T,
+    b: T,
+) -> T {
+    assert!(a < b, "a must be less than b. a: {a}, b: {b}");
+    a + get_random_unsigned_less_than_with_rng(rng, b - a)
+}
+
+/// Uniformly generates a random unsigned integer in the closed interval $[a, b]$, using a
+/// caller-supplied random number generator.
+///
+/// $a$ must be less than or equal to $b$.
+///
+/// $$
+/// P(x) = \\begin{cases}
+///     \frac{1}{b-a+1} & \text{if} \\quad a \leq x \leq b, \\\\
+///     0 & \\text{otherwise.}
+/// \\end{cases}
+/// $$
+///
+/// # Expected complexity
+/// Constant time and additional memory.
+///
+/// # Panics
+/// Panics if $a > b$.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::random::get_random_unsigned_inclusive_range_with_rng;
+/// use malachite_base::random::EXAMPLE_SEED;
+///
+/// let mut rng = EXAMPLE_SEED.get_rng();
+/// assert_eq!(get_random_unsigned_inclusive_range_with_rng::<u8, _>(&mut rng, 10, 19), 11);
+/// ```
+pub fn get_random_unsigned_inclusive_range_with_rng<T: PrimitiveUnsigned, R: RngCore + ?Sized>(
+    rng: &mut R,
+    a: T,
+    b: T,
+) -> T {
+    assert!(a <= b, "a must be less than or equal to b. a: {a}, b: {b}");
+    if a == T::ZERO && b == T::MAX {
+        get_random_primitive_int_with_rng(rng)
+    } else {
+        a + get_random_unsigned_less_than_with_rng(rng, b - a + T::ONE)
+    }
+}
+
 /// Uniformly generates random signed integers in the half-open interval $[a, b)$.
 ///
 /// $a$ must be less than $b$. This function cannot create a range that includes `T::MAX`; for that,
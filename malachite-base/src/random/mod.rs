@@ -6,10 +6,15 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
-use rand::{RngCore, SeedableRng};
+use rand::SeedableRng;
 use rand_chacha::ChaCha20Rng;
 use sha3::{Digest, Sha3_256};
 
+/// The traits that a caller-supplied random number generator must implement in order to be passed
+/// to [`Seed::from_rng`] or to one of the `_with_rng` functions. They are re-exported from [`rand`]
+/// so that callers can be sure to use the same version that Malachite does.
+pub use rand::{CryptoRng, RngCore};
+
 /// A random seed used for reproducible testing.
 pub const EXAMPLE_SEED: Seed = Seed::from_bytes([
     0xbf, 0x18, 0x11, 0xce, 0x15, 0xee, 0xfd, 0x20, 0x2f, 0xdf, 0x67, 0x6a, 0x6b, 0xba, 0xaf, 0x04,
@@ -39,6 +44,28 @@ impl Seed {
         Seed { bytes }
     }
 
+    /// Creates a `Seed` using bytes taken from a caller-supplied random number generator.
+    ///
+    /// This makes it possible to drive any of Malachite's seeded random generators with an external
+    /// source of randomness. If the source is a reproducible PRNG, so is the output; if it is a
+    /// cryptographically secure RNG, such as the operating system's, so is the output, since the
+    /// `Seed` is expanded using ChaCha20.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::random::{Seed, EXAMPLE_SEED};
+    ///
+    /// assert_eq!(Seed::from_rng(&mut EXAMPLE_SEED.get_rng()), EXAMPLE_SEED.next());
+    /// ```
+    pub fn from_rng<R: RngCore + ?Sized>(rng: &mut R) -> Seed {
+        let mut bytes = [0; 32];
+        rng.fill_bytes(&mut bytes);
+        Seed::from_bytes(bytes)
+    }
+
     /// Creates a PRNG from a slice of 32 bytes.
     ///
     /// # Worst-case complexity
@@ -76,9 +103,7 @@ impl Seed {
     /// ```
     #[inline]
     pub fn next(self) -> Seed {
-        let mut bytes = [0; 32];
-        self.get_rng().fill_bytes(&mut bytes);
-        Seed::from_bytes(bytes)
+        Seed::from_rng(&mut self.get_rng())
     }
 
     /// Generates a new `Seed` from this seed. Passing different `key`s will, with very high
@@ -37,6 +37,7 @@ use crate::num::exhaustive::{
     exhaustive_signed_inclusive_range, exhaustive_signed_range, exhaustive_signeds,
     exhaustive_unsigneds, primitive_int_increasing_inclusive_range, primitive_int_increasing_range,
 };
+use crate::num::factorization::traits::IsPrime;
 use crate::num::float::NiceFloat;
 use crate::num::iterators::{bit_distributor_sequence, ruler_sequence};
 use crate::num::logic::traits::{BitBlockAccess, LeadingZeros};
@@ -2491,6 +2492,13 @@ pub fn exhaustive_unsigned_pair_gen_var_31<T: PrimitiveUnsigned>() -> It<(T, T)>
 
 // vars 32 through 36 are in malachite-nz.
 
+pub fn exhaustive_unsigned_pair_gen_var_37<T: PrimitiveUnsigned + IsPrime>() -> It<(T, T)> {
+    Box::new(
+        exhaustive_ordered_unique_pairs(exhaustive_unsigneds())
+            .filter(|&(_, p): &(T, T)| p.is_prime()),
+    )
+}
+
 // -- (PrimitiveUnsigned, PrimitiveUnsigned, bool) --
 
 pub fn exhaustive_unsigned_unsigned_bool_triple_gen_var_1<T: PrimitiveUnsigned>()
@@ -2881,6 +2889,12 @@ pub fn exhaustive_unsigned_triple_gen_var_21<T: PrimitiveUnsigned, U: PrimitiveU
     ))
 }
 
+pub fn exhaustive_unsigned_triple_gen_var_22<T: PrimitiveUnsigned>() -> It<(T, T, T)> {
+    Box::new(
+        exhaustive_triples_from_single(exhaustive_unsigneds()).filter(|&(x, g, m)| x < m && g < m),
+    )
+}
+
 // -- (PrimitiveUnsigned, PrimitiveUnsigned, PrimitiveUnsigned, PrimitiveUnsigned) --
 
 pub fn exhaustive_unsigned_quadruple_gen_var_1<T: PrimitiveUnsigned, U: PrimitiveUnsigned>()
@@ -4350,6 +4364,18 @@ pub fn exhaustive_unsigned_vec_unsigned_pair_gen_var_22<T: PrimitiveUnsigned, U:
     ))
 }
 
+// -- Vec<(PrimitiveUnsigned, PrimitiveUnsigned)> --
+
+pub fn exhaustive_unsigned_pair_vec_gen_var_1<T: PrimitiveUnsigned>() -> It<Vec<(T, T)>> {
+    Box::new(
+        exhaustive_vecs(exhaustive_ordered_unique_pairs(exhaustive_unsigneds())).filter(|ps| {
+            ps.iter()
+                .try_fold(T::ONE, |m, &(_, y)| m.checked_lcm(y))
+                .is_some()
+        }),
+    )
+}
+
 // -- (Vec<PrimitiveUnsigned>, PrimitiveUnsigned, PrimitiveUnsigned) --
 
 pub fn exhaustive_unsigned_vec_unsigned_unsigned_triple_gen<T: PrimitiveUnsigned>()
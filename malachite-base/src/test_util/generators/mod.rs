@@ -2309,6 +2309,16 @@ pub fn unsigned_pair_gen_var_50<T: PrimitiveUnsigned>() -> Generator<(T, T)> {
     )
 }
 
+// All `(T, T)` where `T` is unsigned, the second element is prime, and the first element is less
+// than the second.
+pub fn unsigned_pair_gen_var_51<T: PrimitiveUnsigned + IsPrime>() -> Generator<(T, T)> {
+    Generator::new(
+        &exhaustive_unsigned_pair_gen_var_37,
+        &random_unsigned_pair_gen_var_39,
+        &special_random_unsigned_pair_gen_var_42,
+    )
+}
+
 // -- (PrimitiveUnsigned, PrimitiveUnsigned, bool) --
 
 // All `(T, u64, `bool) where `T` is unsigned and either the `bool` is false or the `u64` is smaller
@@ -2573,6 +2583,15 @@ pub fn unsigned_triple_gen_var_24<T: PrimitiveUnsigned, U: PrimitiveUnsigned>()
     )
 }
 
+// All `(T, T, T)` where `T` is unsigned, the first two elements are less than the third, and
+// everything is small.
+pub fn unsigned_triple_gen_var_25<T: PrimitiveUnsigned>() -> Generator<(T, T, T)> {
+    Generator::new_no_special(
+        &exhaustive_unsigned_triple_gen_var_22,
+        &random_unsigned_triple_gen_var_12,
+    )
+}
+
 // -- (PrimitiveUnsigned, PrimitiveUnsigned, PrimitiveUnsigned, PrimitiveUnsigned) --
 
 // All `(T, u64, u64, U)` where `T` and `U` are unsigned, both `u64`s are small, and the four values
@@ -3401,6 +3420,18 @@ pub fn unsigned_vec_gen_var_6<T: PrimitiveUnsigned>() -> Generator<Vec<T>> {
     )
 }
 
+// -- Vec<(PrimitiveUnsigned, PrimitiveUnsigned)> --
+
+// All `Vec`s of pairs of unsigneds `(r, m)`, where `r < m` in every pair and the LCM of all the
+// `m`s is representable.
+pub fn unsigned_pair_vec_gen_var_1<T: PrimitiveUnsigned>() -> Generator<Vec<(T, T)>> {
+    Generator::new(
+        &exhaustive_unsigned_pair_vec_gen_var_1,
+        &random_unsigned_pair_vec_gen_var_1,
+        &special_random_unsigned_pair_vec_gen_var_1,
+    )
+}
+
 // -- (Vec<PrimitiveUnsigned>, PrimitiveUnsigned) --
 
 pub fn unsigned_vec_unsigned_pair_gen<T: PrimitiveUnsigned, U: PrimitiveUnsigned>()
@@ -3888,6 +3888,15 @@ pub fn random_unsigned_pair_gen_var_38<T: PrimitiveUnsigned>(_config: &GenConfig
     )))
 }
 
+pub fn random_unsigned_pair_gen_var_39<T: PrimitiveUnsigned + IsPrime>(
+    _config: &GenConfig,
+) -> It<(T, T)> {
+    Box::new(
+        random_ordered_unique_pairs(random_primitive_ints::<T>(EXAMPLE_SEED))
+            .filter(|&(_, p): &(T, T)| p.is_prime()),
+    )
+}
+
 // -- (PrimitiveUnsigned, PrimitiveUnsigned, PrimitiveInt, PrimitiveUnsigned) --
 
 struct ModPowerOf2QuadrupleWithExtraPrimitiveIntGenerator<T: PrimitiveUnsigned, U: PrimitiveInt> {
@@ -4211,6 +4220,19 @@ pub fn random_unsigned_triple_gen_var_11<T: PrimitiveUnsigned, U: PrimitiveUnsig
     ))
 }
 
+pub fn random_unsigned_triple_gen_var_12<T: PrimitiveUnsigned>(
+    config: &GenConfig,
+) -> It<(T, T, T)> {
+    Box::new(
+        random_triples_from_single(geometric_random_unsigneds(
+            EXAMPLE_SEED,
+            config.get_or("mean_small_n", 256),
+            config.get_or("mean_small_d", 1),
+        ))
+        .filter(|&(x, g, m)| x < m && g < m),
+    )
+}
+
 // -- (PrimitiveUnsigned, PrimitiveUnsigned, PrimitiveUnsigned, PrimitiveUnsigned) --
 
 struct ModPowerOf2TripleGenerator<T: PrimitiveUnsigned> {
@@ -6182,6 +6204,32 @@ pub fn random_primitive_int_vec_unsigned_unsigned_triple_gen_var_7<
     ))
 }
 
+// -- Vec<(PrimitiveUnsigned, PrimitiveUnsigned)> --
+
+pub fn random_unsigned_pair_vec_gen_var_1<T: PrimitiveUnsigned>(
+    config: &GenConfig,
+) -> It<Vec<(T, T)>> {
+    Box::new(
+        random_vecs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_ordered_unique_pairs(geometric_random_unsigneds(
+                    seed,
+                    config.get_or("mean_small_unsigned_n", 32),
+                    config.get_or("mean_small_unsigned_d", 1),
+                ))
+            },
+            config.get_or("mean_length_n", 4),
+            config.get_or("mean_length_d", 1),
+        )
+        .filter(|ps| {
+            ps.iter()
+                .try_fold(T::ONE, |m, &(_, y)| m.checked_lcm(y))
+                .is_some()
+        }),
+    )
+}
+
 // -- (Vec<PrimitiveInt>, Vec<PrimitiveInt>) --
 
 pub fn random_primitive_int_vec_pair_gen<T: PrimitiveInt>(
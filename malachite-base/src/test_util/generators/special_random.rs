@@ -87,6 +87,7 @@ use crate::test_util::num::arithmetic::mod_mul::limbs_invert_limb_naive;
 use crate::tuples::random::{random_ordered_unique_pairs, random_pairs, random_pairs_from_single};
 use crate::unions::Union2;
 use crate::unions::random::random_union2s;
+use crate::vecs::random::random_vecs;
 use itertools::Itertools;
 use itertools::repeat_n;
 use std::cmp::{Ordering::*, max, min};
@@ -4274,6 +4275,19 @@ pub fn special_random_unsigned_pair_gen_var_41<T: PrimitiveUnsigned>(
     ))
 }
 
+pub fn special_random_unsigned_pair_gen_var_42<T: PrimitiveUnsigned + IsPrime>(
+    config: &GenConfig,
+) -> It<(T, T)> {
+    Box::new(
+        random_ordered_unique_pairs(striped_random_unsigneds::<T>(
+            EXAMPLE_SEED,
+            config.get_or("mean_stripe_n", T::WIDTH >> 1),
+            config.get_or("mean_stripe_d", 1),
+        ))
+        .filter(|&(_, p): &(T, T)| p.is_prime()),
+    )
+}
+
 // -- (PrimitiveUnsigned, PrimitiveUnsigned, bool) --
 
 pub fn special_random_unsigned_unsigned_bool_triple_gen_var_1<T: PrimitiveUnsigned>(
@@ -7540,6 +7554,32 @@ pub fn special_random_unsigned_vec_unsigned_unsigned_triple_gen_var_13<
 
 // var 14 is in malachite-nz.
 
+// -- Vec<(PrimitiveUnsigned, PrimitiveUnsigned)> --
+
+pub fn special_random_unsigned_pair_vec_gen_var_1<T: PrimitiveUnsigned>(
+    config: &GenConfig,
+) -> It<Vec<(T, T)>> {
+    Box::new(
+        random_vecs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_ordered_unique_pairs(striped_random_unsigneds::<T>(
+                    seed,
+                    config.get_or("mean_stripe_n", T::WIDTH >> 1),
+                    config.get_or("mean_stripe_d", 1),
+                ))
+            },
+            config.get_or("mean_length_n", 4),
+            config.get_or("mean_length_d", 1),
+        )
+        .filter(|ps| {
+            ps.iter()
+                .try_fold(T::ONE, |m, &(_, y)| m.checked_lcm(y))
+                .is_some()
+        }),
+    )
+}
+
 // -- (Vec<PrimitiveUnsigned>, PrimitiveUnsigned, RoundingMode) --
 
 pub fn special_random_unsigned_vec_unsigned_rounding_mode_triple_gen_var_1<T: PrimitiveUnsigned>(
@@ -0,0 +1,17 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::exhaustive::primitive_int_increasing_range;
+
+pub fn chinese_remainder_naive<T: PrimitiveUnsigned>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    let lcm = congruences.iter().fold(T::ONE, |m, &(_, m_i)| m.lcm(m_i));
+    primitive_int_increasing_range(T::ZERO, lcm)
+        .find(|&x| congruences.iter().all(|&(r_i, m_i)| x % m_i == r_i))
+        .map(|r| (r, lcm))
+}
@@ -0,0 +1,28 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+
+fn checked_linear_recurrence_naive<T: PrimitiveUnsigned>(n: u64, x: T, y: T) -> Option<T> {
+    // `None` marks a term that is too large to be represented
+    let (mut x, mut y) = (Some(x), Some(y));
+    for _ in 0..n {
+        let z = x?.checked_add(y?);
+        x = y;
+        y = z;
+    }
+    x
+}
+
+pub fn checked_fibonacci_naive<T: PrimitiveUnsigned>(n: u64) -> Option<T> {
+    checked_linear_recurrence_naive(n, T::ZERO, T::ONE)
+}
+
+pub fn checked_lucas_naive<T: PrimitiveUnsigned>(n: u64) -> Option<T> {
+    checked_linear_recurrence_naive(n, T::TWO, T::ONE)
+}
@@ -6,11 +6,14 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
+pub mod chinese_remainder;
 pub mod extended_gcd;
 pub mod factorial;
+pub mod fibonacci;
 pub mod kronecker_symbol;
 pub mod mod_inverse;
 pub mod mod_mul;
 pub mod mod_pow;
 pub mod mod_power_of_2_inverse;
+pub mod mod_sqrt;
 pub mod primorial;
@@ -0,0 +1,15 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::exhaustive::primitive_int_increasing_range;
+
+pub fn mod_sqrt_naive<T: PrimitiveUnsigned>(x: T, m: T) -> Option<T> {
+    assert!(x < m);
+    primitive_int_increasing_range(T::ZERO, m).find(|&y| y.mod_square(m) == x)
+}
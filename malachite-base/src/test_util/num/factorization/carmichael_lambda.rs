@@ -0,0 +1,22 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::exhaustive::primitive_int_increasing_range;
+use crate::test_util::num::factorization::multiplicative_order::multiplicative_order_naive;
+
+pub fn carmichael_lambda_naive<T: PrimitiveUnsigned>(n: T) -> T {
+    assert_ne!(n, T::ZERO);
+    let mut lambda = T::ONE;
+    for x in primitive_int_increasing_range(T::ZERO, n) {
+        if let Some(order) = multiplicative_order_naive(x, n) {
+            lambda = lambda.lcm(order);
+        }
+    }
+    lambda
+}
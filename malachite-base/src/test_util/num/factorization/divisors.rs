@@ -0,0 +1,18 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::exhaustive::primitive_int_increasing_inclusive_range;
+use alloc::vec::Vec;
+
+pub fn divisors_naive<T: PrimitiveUnsigned>(n: T) -> Vec<T> {
+    assert_ne!(n, T::ZERO);
+    primitive_int_increasing_inclusive_range(T::ONE, n)
+        .filter(|&d| n.divisible_by(d))
+        .collect()
+}
@@ -0,0 +1,21 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::exhaustive::primitive_int_increasing_inclusive_range;
+
+pub fn euler_totient_naive<T: PrimitiveUnsigned>(n: T) -> T {
+    assert_ne!(n, T::ZERO);
+    let mut phi = T::ZERO;
+    for k in primitive_int_increasing_inclusive_range(T::ONE, n) {
+        if k.coprime_with(n) {
+            phi += T::ONE;
+        }
+    }
+    phi
+}
@@ -6,7 +6,16 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
+pub mod carmichael_lambda;
+pub mod divisors;
+pub mod euler_totient;
 pub mod factor;
 pub mod is_prime;
+pub mod mod_log;
+pub mod moebius;
+pub mod multiplicative;
+pub mod multiplicative_order;
+pub mod next_prime;
+pub mod prev_prime;
 pub mod prime_sieve;
 pub mod primes;
@@ -0,0 +1,24 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+
+pub fn mod_log_naive<T: PrimitiveUnsigned>(x: T, g: T, m: T) -> Option<T> {
+    assert!(x < m);
+    assert!(g < m);
+    let mut power = T::ONE % m;
+    let mut k = T::ZERO;
+    while power != x {
+        if k == m {
+            return None;
+        }
+        power.mod_mul_assign(g, m);
+        k += T::ONE;
+    }
+    Some(k)
+}
@@ -0,0 +1,26 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+
+pub fn moebius_naive<T: PrimitiveUnsigned>(mut n: T) -> i8 {
+    assert_ne!(n, T::ZERO);
+    let mut mu = 1;
+    let mut d = T::TWO;
+    while n != T::ONE {
+        if n.divisible_by(d) {
+            n /= d;
+            if n.divisible_by(d) {
+                return 0;
+            }
+            mu = -mu;
+        }
+        d += T::ONE;
+    }
+    mu
+}
@@ -0,0 +1,25 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use core::fmt::Debug;
+use core::ops::Mul;
+
+// Checks that f(mn) = f(m)f(n), if m and n are positive and coprime and mn doesn't overflow.
+pub fn check_multiplicative<T: PrimitiveUnsigned, U: Debug + Eq + Mul<Output = U>>(
+    f: impl Fn(T) -> U,
+    m: T,
+    n: T,
+) {
+    if m == T::ZERO || n == T::ZERO || !m.coprime_with(n) {
+        return;
+    }
+    if let Some(mn) = m.checked_mul(n) {
+        assert_eq!(f(mn), f(m) * f(n));
+    }
+}
@@ -0,0 +1,17 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::IsPrime;
+
+pub fn next_prime_naive<T: IsPrime + PrimitiveUnsigned>(n: T) -> Option<T> {
+    let mut m = n.checked_add(T::ONE)?;
+    while !m.is_prime() {
+        m = m.checked_add(T::ONE)?;
+    }
+    Some(m)
+}
@@ -206,6 +206,7 @@ pub mod num {
         pub mod checked_pow;
         pub mod checked_square;
         pub mod checked_sub_mul;
+        pub mod chinese_remainder;
         pub mod coprime_with;
         pub mod div_exact;
         pub mod div_mod;
@@ -216,6 +217,7 @@ pub mod num {
         pub mod eq_mod_power_of_2;
         pub mod extended_gcd;
         pub mod factorial;
+        pub mod fibonacci;
         pub mod floor;
         pub mod gcd;
         pub mod is_power_of_2;
@@ -244,6 +246,7 @@ pub mod num {
         pub mod mod_power_of_2_sub;
         pub mod mod_shl;
         pub mod mod_shr;
+        pub mod mod_sqrt;
         pub mod mod_square;
         pub mod mod_sub;
         pub mod neg;
@@ -395,10 +398,20 @@ pub mod num {
         pub mod primitive_int_increasing_range;
     }
     pub mod factorization {
+        pub mod carmichael_lambda;
+        pub mod divisor_count;
+        pub mod divisor_sum;
+        pub mod divisors;
+        pub mod euler_totient;
         pub mod factor;
         pub mod is_perfect_power;
         pub mod is_prime;
         pub mod is_square;
+        pub mod mod_log;
+        pub mod moebius;
+        pub mod multiplicative_order;
+        pub mod next_prime;
+        pub mod prev_prime;
         pub mod prime_indicator_sequence;
         pub mod prime_indicator_sequence_less_than;
         pub mod prime_sieve;
@@ -472,6 +485,7 @@ pub mod num {
             pub mod get_geometric_random_signed_from_inclusive_range;
             pub mod mean;
         }
+        pub mod get_random_unsigneds_with_rng;
         pub mod random_finite_primitive_floats;
         pub mod random_highest_bit_set_unsigneds;
         pub mod random_natural_signeds;
@@ -563,6 +577,7 @@ pub mod orderings {
 pub mod random {
     pub mod fork;
     pub mod from_bytes;
+    pub mod from_rng;
     pub mod get_rng;
     pub mod next;
 }
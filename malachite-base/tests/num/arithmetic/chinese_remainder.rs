@@ -0,0 +1,102 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::ChineseRemainder;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::{
+    unsigned_pair_gen_var_16, unsigned_pair_vec_gen_var_1,
+};
+use malachite_base::test_util::num::arithmetic::chinese_remainder::chinese_remainder_naive;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_chinese_remainder() {
+    fn test<T: PrimitiveUnsigned>(congruences: &[(T, T)], out: Option<(T, T)>) {
+        assert_eq!(T::chinese_remainder(congruences.iter().copied()), out);
+    }
+    test::<u8>(&[], Some((0, 1)));
+    test::<u16>(&[(0, 1)], Some((0, 1)));
+    test::<u32>(&[(3, 7)], Some((3, 7)));
+    test::<u64>(&[(2, 3), (3, 5), (2, 7)], Some((23, 105)));
+    test::<u128>(&[(1, 2), (2, 3), (3, 5), (4, 7)], Some((53, 210)));
+    test::<usize>(&[(3, 4), (5, 6)], Some((11, 12)));
+    test::<u8>(&[(3, 4), (4, 6)], None);
+    test::<u16>(&[(1, 6), (3, 10), (13, 15)], Some((13, 30)));
+    test::<u32>(&[(1, 6), (3, 10), (8, 15)], None);
+    test::<u64>(&[(5, 12), (5, 12), (1, 4)], Some((5, 12)));
+    test::<u8>(&[(100, 101), (1, 2)], Some((201, 202)));
+    test::<u64>(
+        &[(1, 1000000007), (2, 998244353)],
+        Some((993328913953302350, 998244359987710471)),
+    );
+    test::<u128>(
+        &[(1, 1000000007), (2, 998244353), (3, 1000000009)],
+        Some((126879769030076278993425315, 998244368971909710889394239)),
+    );
+}
+
+fn chinese_remainder_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::chinese_remainder([(T::ZERO, T::ZERO)]));
+    assert_panic!(T::chinese_remainder([(T::TWO, T::TWO)]));
+    assert_panic!(T::chinese_remainder([(T::ONE, T::MAX), (T::ONE, T::TWO)]));
+}
+
+#[test]
+fn chinese_remainder_fail() {
+    apply_fn_to_unsigneds!(chinese_remainder_fail_helper);
+}
+
+fn chinese_remainder_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_pair_vec_gen_var_1::<T>().test_properties(|congruences| {
+        let result = T::chinese_remainder(congruences.iter().copied());
+        assert_eq!(
+            T::chinese_remainder(congruences.iter().rev().copied()),
+            result
+        );
+        let lcm = congruences.iter().fold(T::ONE, |m, &(_, m_i)| m.lcm(m_i));
+        if let Some((r, m)) = result {
+            assert_eq!(m, lcm);
+            assert!(r < m);
+            for &(r_i, m_i) in &congruences {
+                assert_eq!(r % m_i, r_i);
+            }
+            let mut extended = congruences.clone();
+            extended.push((r, m));
+            assert_eq!(T::chinese_remainder(extended), result);
+        } else {
+            // Some pair of congruences must be inconsistent.
+            assert!(congruences.iter().enumerate().any(|(i, &(r_i, m_i))| {
+                congruences[i + 1..].iter().any(|&(r_j, m_j)| {
+                    let g = m_i.gcd(m_j);
+                    r_i % g != r_j % g
+                })
+            }));
+        }
+    });
+
+    unsigned_pair_gen_var_16::<T>().test_properties(|(r, m)| {
+        assert_eq!(T::chinese_remainder([(r, m)]), Some((r, m)));
+        assert_eq!(T::chinese_remainder([(r, m), (r, m)]), Some((r, m)));
+        assert_eq!(
+            T::chinese_remainder([(r, m), (T::ZERO, T::ONE)]),
+            Some((r, m))
+        );
+    });
+}
+
+#[test]
+fn chinese_remainder_properties() {
+    unsigned_pair_vec_gen_var_1::<u8>().test_properties(|congruences| {
+        assert_eq!(
+            u8::chinese_remainder(congruences.iter().copied()),
+            chinese_remainder_naive(&congruences)
+        );
+    });
+
+    apply_fn_to_unsigneds!(chinese_remainder_properties_helper);
+}
@@ -0,0 +1,212 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::{unsigned_gen, unsigned_gen_var_5};
+use malachite_base::test_util::num::arithmetic::fibonacci::{
+    checked_fibonacci_naive, checked_lucas_naive,
+};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_fibonacci() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: T) {
+        assert_eq!(T::fibonacci(n), out);
+    }
+    test::<u8>(0, 0);
+    test::<u8>(1, 1);
+    test::<u8>(2, 1);
+    test::<u8>(3, 2);
+    test::<u8>(4, 3);
+    test::<u8>(5, 5);
+    test::<u8>(10, 55);
+    test::<u8>(13, 233);
+    test::<u16>(24, 46368);
+    test::<u32>(47, 2971215073);
+    test::<u64>(93, 12200160415121876738);
+    test::<u128>(186, 332825110087067562321196029789634457848);
+}
+
+fn fibonacci_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::fibonacci(200));
+}
+
+#[test]
+fn fibonacci_fail() {
+    apply_fn_to_unsigneds!(fibonacci_fail_helper);
+}
+
+#[test]
+fn test_checked_fibonacci() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: Option<T>) {
+        assert_eq!(T::checked_fibonacci(n), out);
+        assert_eq!(checked_fibonacci_naive(n), out);
+    }
+    test::<u8>(0, Some(0));
+    test::<u8>(1, Some(1));
+    test::<u8>(2, Some(1));
+    test::<u8>(10, Some(55));
+    test::<u8>(13, Some(233));
+    test::<u8>(14, None);
+    test::<u16>(24, Some(46368));
+    test::<u16>(25, None);
+    test::<u32>(47, Some(2971215073));
+    test::<u32>(48, None);
+    test::<u64>(93, Some(12200160415121876738));
+    test::<u64>(94, None);
+    test::<u128>(186, Some(332825110087067562321196029789634457848));
+    test::<u128>(187, None);
+    test::<u128>(u64::MAX, None);
+}
+
+#[test]
+fn test_fibonacci2() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: (T, T)) {
+        assert_eq!(T::fibonacci2(n), out);
+    }
+    test::<u8>(0, (0, 1));
+    test::<u8>(1, (1, 0));
+    test::<u8>(2, (1, 1));
+    test::<u8>(3, (2, 1));
+    test::<u8>(10, (55, 34));
+    test::<u8>(13, (233, 144));
+    test::<u32>(40, (102334155, 63245986));
+    test::<u64>(93, (12200160415121876738, 7540113804746346429));
+}
+
+fn fibonacci2_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::fibonacci2(200));
+}
+
+#[test]
+fn fibonacci2_fail() {
+    apply_fn_to_unsigneds!(fibonacci2_fail_helper);
+}
+
+#[test]
+fn test_checked_fibonacci2() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: Option<(T, T)>) {
+        assert_eq!(T::checked_fibonacci2(n), out);
+    }
+    test::<u8>(0, Some((0, 1)));
+    test::<u8>(1, Some((1, 0)));
+    test::<u8>(10, Some((55, 34)));
+    test::<u8>(13, Some((233, 144)));
+    test::<u8>(14, None);
+    test::<u64>(93, Some((12200160415121876738, 7540113804746346429)));
+    test::<u64>(94, None);
+}
+
+#[test]
+fn test_lucas() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: T) {
+        assert_eq!(T::lucas(n), out);
+    }
+    test::<u8>(0, 2);
+    test::<u8>(1, 1);
+    test::<u8>(2, 3);
+    test::<u8>(3, 4);
+    test::<u8>(4, 7);
+    test::<u8>(5, 11);
+    test::<u8>(10, 123);
+    test::<u8>(11, 199);
+    test::<u16>(23, 64079);
+    test::<u32>(46, 4106118243);
+    test::<u64>(92, 16860207025497407047);
+}
+
+fn lucas_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::lucas(200));
+}
+
+#[test]
+fn lucas_fail() {
+    apply_fn_to_unsigneds!(lucas_fail_helper);
+}
+
+#[test]
+fn test_checked_lucas() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: Option<T>) {
+        assert_eq!(T::checked_lucas(n), out);
+        assert_eq!(checked_lucas_naive(n), out);
+    }
+    test::<u8>(0, Some(2));
+    test::<u8>(1, Some(1));
+    test::<u8>(2, Some(3));
+    test::<u8>(10, Some(123));
+    test::<u8>(11, Some(199));
+    test::<u8>(12, None);
+    test::<u16>(23, Some(64079));
+    test::<u16>(24, None);
+    test::<u32>(46, Some(4106118243));
+    test::<u32>(47, None);
+    test::<u64>(92, Some(16860207025497407047));
+    test::<u64>(93, None);
+    test::<u128>(185, None);
+    test::<u128>(u64::MAX, None);
+}
+
+fn checked_fibonacci_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen().test_properties(|n| {
+        let of = T::checked_fibonacci(n);
+        assert_eq!(checked_fibonacci_naive(n), of);
+        if let Some(f) = of {
+            assert_eq!(T::fibonacci(n), f);
+            let (f_2, g) = T::checked_fibonacci2(n).unwrap();
+            assert_eq!(f_2, f);
+            assert_eq!(T::fibonacci2(n), (f, g));
+            if n != 0 {
+                assert_eq!(T::checked_fibonacci(n - 1), Some(g));
+            }
+        } else {
+            assert!(T::checked_fibonacci2(n).is_none());
+            if n != u64::MAX {
+                assert!(T::checked_fibonacci(n + 1).is_none());
+            }
+        }
+    });
+
+    unsigned_gen_var_5().test_properties(|n| {
+        if let Some(f) = T::checked_fibonacci(n + 2) {
+            assert_eq!(f, T::fibonacci(n + 1) + T::fibonacci(n));
+        }
+        // F_{2n} = F_n * L_n
+        if let Some(f) = T::checked_fibonacci(n << 1) {
+            assert_eq!(f, T::fibonacci(n) * T::lucas(n));
+        }
+    });
+}
+
+#[test]
+fn checked_fibonacci_properties() {
+    apply_fn_to_unsigneds!(checked_fibonacci_properties_helper);
+}
+
+fn checked_lucas_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen().test_properties(|n| {
+        let ol = T::checked_lucas(n);
+        assert_eq!(checked_lucas_naive(n), ol);
+        if let Some(l) = ol {
+            assert_eq!(T::lucas(n), l);
+        } else if n != u64::MAX {
+            assert!(T::checked_lucas(n + 1).is_none());
+        }
+    });
+
+    unsigned_gen_var_5().test_properties(|n| {
+        // L_{n+1} = F_n + F_{n+2}
+        if let Some(l) = T::checked_lucas(n + 1) {
+            assert_eq!(l, T::fibonacci(n) + T::fibonacci(n + 2));
+        }
+    });
+}
+
+#[test]
+fn checked_lucas_properties() {
+    apply_fn_to_unsigneds!(checked_lucas_properties_helper);
+}
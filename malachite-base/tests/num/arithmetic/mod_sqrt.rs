@@ -0,0 +1,197 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{ModPrimePowerSqrt, ModSqrt};
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::test_util::generators::{unsigned_gen_var_29, unsigned_pair_gen_var_51};
+use malachite_base::test_util::num::arithmetic::mod_sqrt::mod_sqrt_naive;
+use std::panic::catch_unwind;
+
+fn mod_sqrt_helper<T: PrimitiveUnsigned + ModSqrt<T, Output = T>>() {
+    let test = |x: u64, p: u64, out: Option<u64>| {
+        if let (Ok(x), Ok(p)) = (T::try_from(x), T::try_from(p)) {
+            assert_eq!(x.mod_sqrt(p), out.map(T::exact_from));
+        }
+    };
+    test(0, 2, Some(0));
+    test(1, 2, Some(1));
+    test(0, 7, Some(0));
+    test(1, 7, Some(1));
+    test(2, 7, Some(3));
+    test(3, 7, None);
+    test(4, 7, Some(2));
+    test(5, 7, None);
+    test(6, 7, None);
+    test(10, 13, Some(6));
+    // p = 1 mod 8, so that the Tonelli-Shanks loop is exercised
+    test(2, 17, Some(6));
+    test(3, 17, None);
+    test(100, 257, Some(10));
+    test(3, 65537, None);
+    test(2, 65537, Some(4080));
+    test(5, 4294967291, Some(65536));
+    test(2, 18446744073709551557, None);
+    test(6, 18446744073709551557, Some(3789919121787743779));
+    test(3, 18446744069414584321, Some(281474976579584));
+    test(7, 18446744069414584321, None);
+}
+
+#[test]
+fn test_mod_sqrt() {
+    mod_sqrt_helper::<u8>();
+    mod_sqrt_helper::<u16>();
+    mod_sqrt_helper::<u32>();
+    mod_sqrt_helper::<u64>();
+    mod_sqrt_helper::<usize>();
+}
+
+fn mod_sqrt_fail_helper<T: PrimitiveUnsigned + ModSqrt<T, Output = T>>() {
+    assert_panic!(T::ZERO.mod_sqrt(T::ZERO));
+    assert_panic!(T::from(7u8).mod_sqrt(T::from(7u8)));
+}
+
+#[test]
+fn mod_sqrt_fail() {
+    mod_sqrt_fail_helper::<u8>();
+    mod_sqrt_fail_helper::<u16>();
+    mod_sqrt_fail_helper::<u32>();
+    mod_sqrt_fail_helper::<u64>();
+    mod_sqrt_fail_helper::<usize>();
+}
+
+fn mod_prime_power_sqrt_helper<T: PrimitiveUnsigned + ModPrimePowerSqrt<T, Output = T>>() {
+    let test = |x: u64, p: u64, k: u64, out: Option<u64>| {
+        if let (Ok(x), Ok(p)) = (T::try_from(x), T::try_from(p)) {
+            if p.checked_pow(k).is_some() {
+                assert_eq!(x.mod_prime_power_sqrt(p, k), out.map(T::exact_from));
+            }
+        }
+    };
+    test(2, 7, 1, Some(3));
+    test(2, 7, 2, Some(10));
+    test(2, 7, 3, Some(108));
+    test(0, 3, 4, Some(0));
+    test(9, 3, 4, Some(3));
+    test(27, 3, 4, None);
+    test(18, 3, 3, None);
+    test(36, 3, 4, Some(6));
+    test(1, 2, 1, Some(1));
+    test(1, 2, 2, Some(1));
+    test(3, 2, 2, None);
+    test(1, 2, 3, Some(1));
+    test(5, 2, 3, None);
+    test(17, 2, 5, Some(7));
+    test(4, 2, 5, Some(2));
+    test(8, 2, 5, None);
+    test(16, 2, 5, Some(4));
+    test(17, 2, 63, Some(405959429219100393));
+    test(2, 7, 22, Some(1830612359265707720));
+}
+
+#[test]
+fn test_mod_prime_power_sqrt() {
+    mod_prime_power_sqrt_helper::<u8>();
+    mod_prime_power_sqrt_helper::<u16>();
+    mod_prime_power_sqrt_helper::<u32>();
+    mod_prime_power_sqrt_helper::<u64>();
+    mod_prime_power_sqrt_helper::<usize>();
+}
+
+fn mod_prime_power_sqrt_fail_helper<T: PrimitiveUnsigned + ModPrimePowerSqrt<T, Output = T>>() {
+    assert_panic!(T::ZERO.mod_prime_power_sqrt(T::TWO, 0));
+    assert_panic!(T::from(8u8).mod_prime_power_sqrt(T::TWO, 3));
+    assert_panic!(T::ZERO.mod_prime_power_sqrt(T::TWO, T::WIDTH));
+}
+
+#[test]
+fn mod_prime_power_sqrt_fail() {
+    mod_prime_power_sqrt_fail_helper::<u8>();
+    mod_prime_power_sqrt_fail_helper::<u16>();
+    mod_prime_power_sqrt_fail_helper::<u32>();
+    mod_prime_power_sqrt_fail_helper::<u64>();
+    mod_prime_power_sqrt_fail_helper::<usize>();
+}
+
+fn mod_sqrt_properties_helper<
+    T: IsPrime + ModPrimePowerSqrt<T, Output = T> + ModSqrt<T, Output = T> + PrimitiveUnsigned,
+>() {
+    unsigned_pair_gen_var_51::<T>().test_properties(|(x, p)| {
+        let root = x.mod_sqrt(p);
+        assert_eq!(x.mod_prime_power_sqrt(p, 1), root);
+        if let Some(root) = root {
+            assert!(root < p);
+            assert_eq!(root.mod_square(p), x);
+            assert!(root <= p - root);
+            assert_eq!(root.mod_neg(p).mod_square(p), x);
+        } else {
+            assert!(p > T::TWO);
+            assert_eq!(x.legendre_symbol(p), -1);
+        }
+        if p < T::from(u8::MAX) {
+            assert_eq!(mod_sqrt_naive(x, p), root);
+        }
+        // x^2 always has a square root
+        let square = x.mod_square(p);
+        let root = square.mod_sqrt(p).unwrap();
+        assert!(root == x || root == x.mod_neg(p));
+    });
+
+    unsigned_gen_var_29::<T>().test_properties(|p| {
+        assert_eq!(T::ZERO.mod_sqrt(p), Some(T::ZERO));
+        assert_eq!(T::ONE.mod_sqrt(p), Some(T::ONE));
+    });
+}
+
+#[test]
+fn mod_sqrt_properties() {
+    mod_sqrt_properties_helper::<u8>();
+    mod_sqrt_properties_helper::<u16>();
+    mod_sqrt_properties_helper::<u32>();
+    mod_sqrt_properties_helper::<u64>();
+    mod_sqrt_properties_helper::<usize>();
+}
+
+fn mod_prime_power_sqrt_properties_helper<
+    T: IsPrime + ModPrimePowerSqrt<T, Output = T> + PrimitiveUnsigned,
+>() {
+    unsigned_pair_gen_var_51::<T>().test_properties(|(x, p)| {
+        for k in 1..4 {
+            let Some(pk) = p.checked_pow(k) else {
+                break;
+            };
+            let root = x.mod_prime_power_sqrt(p, k);
+            if let Some(root) = root {
+                assert!(root < pk);
+                assert_eq!(root.mod_square(pk), x);
+                assert!(root <= pk - root);
+            }
+            if pk < T::from(u8::MAX) {
+                assert_eq!(mod_sqrt_naive(x, pk), root);
+            }
+            let square = x.mod_square(pk);
+            let root = square.mod_prime_power_sqrt(p, k).unwrap();
+            assert!(root <= x);
+            assert_eq!(root.mod_square(pk), square);
+        }
+    });
+
+    unsigned_gen_var_29::<T>().test_properties(|p| {
+        assert_eq!(T::ZERO.mod_prime_power_sqrt(p, 1), Some(T::ZERO));
+        assert_eq!(T::ONE.mod_prime_power_sqrt(p, 1), Some(T::ONE));
+    });
+}
+
+#[test]
+fn mod_prime_power_sqrt_properties() {
+    mod_prime_power_sqrt_properties_helper::<u8>();
+    mod_prime_power_sqrt_properties_helper::<u16>();
+    mod_prime_power_sqrt_properties_helper::<u32>();
+    mod_prime_power_sqrt_properties_helper::<u64>();
+    mod_prime_power_sqrt_properties_helper::<usize>();
+}
@@ -0,0 +1,115 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::{CarmichaelLambda, EulerTotient};
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen_var_16};
+use malachite_base::test_util::num::factorization::carmichael_lambda::carmichael_lambda_naive;
+use std::panic::catch_unwind;
+
+fn carmichael_lambda_helper<T: CarmichaelLambda<Output = T> + PrimitiveUnsigned>() {
+    let test = |n: u64, out: u64| {
+        if let Ok(n) = T::try_from(n) {
+            assert_eq!(n.carmichael_lambda(), T::exact_from(out));
+        }
+    };
+    test(1, 1);
+    test(2, 1);
+    test(4, 2);
+    test(8, 2);
+    test(16, 4);
+    test(10, 4);
+    test(12, 2);
+    test(30, 4);
+    test(36, 6);
+    test(100, 20);
+    test(255, 16);
+    test(65535, 256);
+    test(720720, 60);
+    test(1000000007, 1000000006);
+    test(4294967295, 65536);
+    test(1000000000000, 50000000000);
+    test(897612484786617600, 665280);
+    test(18446744073709551557, 18446744073709551556);
+    test(18446744073709551615, 17153064960);
+}
+
+#[test]
+fn test_carmichael_lambda() {
+    carmichael_lambda_helper::<u8>();
+    carmichael_lambda_helper::<u16>();
+    carmichael_lambda_helper::<u32>();
+    carmichael_lambda_helper::<u64>();
+    carmichael_lambda_helper::<u128>();
+    carmichael_lambda_helper::<usize>();
+
+    let test = |n: u128, out: u128| {
+        assert_eq!(n.carmichael_lambda(), out);
+    };
+    test(u128::MAX, 321876063366081731297280);
+    test(
+        340282366920938463463374607431768211297,
+        340282366920938463463374607431768211296,
+    );
+    test(1506334550815795554361, 37850760);
+    test(
+        223632888043722196120573870901,
+        55908222010821503536842690768,
+    );
+    test(1 << 127, 42535295865117307932921825928971026432);
+}
+
+fn carmichael_lambda_fail_helper<T: CarmichaelLambda + PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.carmichael_lambda());
+}
+
+#[test]
+fn carmichael_lambda_fail() {
+    carmichael_lambda_fail_helper::<u8>();
+    carmichael_lambda_fail_helper::<u16>();
+    carmichael_lambda_fail_helper::<u32>();
+    carmichael_lambda_fail_helper::<u64>();
+    carmichael_lambda_fail_helper::<u128>();
+    carmichael_lambda_fail_helper::<usize>();
+}
+
+fn carmichael_lambda_properties_helper<
+    T: CarmichaelLambda<Output = T> + EulerTotient<Output = T> + PrimitiveUnsigned,
+>() {
+    unsigned_gen_var_1::<T>().test_properties(|n| {
+        let lambda = n.carmichael_lambda();
+        assert_ne!(lambda, T::ZERO);
+        assert!(n.euler_totient().divisible_by(lambda));
+    });
+
+    unsigned_pair_gen_var_16::<T>().test_properties(|(x, m)| {
+        if x.coprime_with(m) {
+            assert_eq!(x.mod_pow(m.carmichael_lambda().exact_into(), m), T::ONE % m);
+        }
+    });
+}
+
+#[test]
+fn carmichael_lambda_properties() {
+    unsigned_gen_var_1::<u8>().test_properties(|n| {
+        assert_eq!(n.carmichael_lambda(), carmichael_lambda_naive(n));
+    });
+
+    carmichael_lambda_properties_helper::<u8>();
+    carmichael_lambda_properties_helper::<u16>();
+    carmichael_lambda_properties_helper::<u32>();
+    carmichael_lambda_properties_helper::<u64>();
+    carmichael_lambda_properties_helper::<usize>();
+
+    unsigned_gen_var_1::<u64>().test_properties(|n| {
+        assert_eq!(
+            u128::from(n).carmichael_lambda(),
+            u128::from(n.carmichael_lambda())
+        );
+    });
+}
@@ -0,0 +1,110 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::{DivisorCount, Divisors, IsPrime};
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen_var_27};
+use malachite_base::test_util::num::factorization::divisors::divisors_naive;
+use malachite_base::test_util::num::factorization::multiplicative::check_multiplicative;
+use std::panic::catch_unwind;
+
+fn divisor_count_helper<T: DivisorCount<Output = T> + PrimitiveUnsigned>() {
+    let test = |n: u64, out: u64| {
+        if let Ok(n) = T::try_from(n) {
+            assert_eq!(n.divisor_count(), T::exact_from(out));
+        }
+    };
+    test(1, 1);
+    test(2, 2);
+    test(10, 4);
+    test(12, 6);
+    test(30, 8);
+    test(36, 9);
+    test(100, 9);
+    test(255, 8);
+    test(65535, 16);
+    test(720720, 240);
+    test(1000000007, 2);
+    test(4294967295, 32);
+    test(1000000000000, 169);
+    test(897612484786617600, 103680);
+    test(18446744073709551557, 2);
+    test(18446744073709551615, 128);
+}
+
+#[test]
+fn test_divisor_count() {
+    divisor_count_helper::<u8>();
+    divisor_count_helper::<u16>();
+    divisor_count_helper::<u32>();
+    divisor_count_helper::<u64>();
+    divisor_count_helper::<u128>();
+    divisor_count_helper::<usize>();
+
+    let test = |n: u128, out: u128| {
+        assert_eq!(n.divisor_count(), out);
+    };
+    test(u128::MAX, 512);
+    test(340282366920938463463374607431768211297, 2);
+    test(1506334550815795554361, 8);
+    test(223632888043722196120573870901, 4);
+    test(1 << 127, 128);
+}
+
+fn divisor_count_fail_helper<T: DivisorCount + PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.divisor_count());
+}
+
+#[test]
+fn divisor_count_fail() {
+    divisor_count_fail_helper::<u8>();
+    divisor_count_fail_helper::<u16>();
+    divisor_count_fail_helper::<u32>();
+    divisor_count_fail_helper::<u64>();
+    divisor_count_fail_helper::<u128>();
+    divisor_count_fail_helper::<usize>();
+}
+
+fn divisor_count_properties_helper<
+    T: DivisorCount<Output = T> + Divisors + IsPrime + PrimitiveUnsigned,
+>() {
+    unsigned_gen_var_1::<T>().test_properties(|n| {
+        let d = n.divisor_count();
+        assert_ne!(d, T::ZERO);
+        assert!(d <= n);
+        assert_eq!(d == T::TWO, n.is_prime());
+        assert_eq!(T::exact_from(n.divisors().count()), d);
+    });
+
+    unsigned_pair_gen_var_27::<T>().test_properties(|(m, n)| {
+        // d is multiplicative
+        check_multiplicative(|x: T| x.divisor_count(), m, n);
+    });
+}
+
+#[test]
+fn divisor_count_properties() {
+    unsigned_gen_var_1::<u8>().test_properties(|n| {
+        assert_eq!(n.divisor_count(), u8::exact_from(divisors_naive(n).len()));
+    });
+
+    unsigned_gen_var_1::<u16>().test_properties(|n| {
+        assert_eq!(n.divisor_count(), u16::exact_from(divisors_naive(n).len()));
+    });
+
+    divisor_count_properties_helper::<u8>();
+    divisor_count_properties_helper::<u16>();
+    divisor_count_properties_helper::<u32>();
+    divisor_count_properties_helper::<u64>();
+    divisor_count_properties_helper::<usize>();
+
+    unsigned_gen_var_1::<u64>().test_properties(|n| {
+        assert_eq!(u128::from(n).divisor_count(), u128::from(n.divisor_count()));
+    });
+}
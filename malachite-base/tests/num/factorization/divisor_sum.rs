@@ -0,0 +1,112 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::{DivisorSum, Divisors, IsPrime};
+use malachite_base::test_util::generators::unsigned_gen_var_1;
+use malachite_base::test_util::num::factorization::divisors::divisors_naive;
+use std::panic::catch_unwind;
+
+fn divisor_sum_helper<T: DivisorSum<Output = Option<T>> + PrimitiveUnsigned>() {
+    let test = |n: u64, out: Option<u64>| {
+        if let Ok(n) = T::try_from(n) {
+            assert_eq!(n.divisor_sum(), out.and_then(|out| T::try_from(out).ok()));
+        }
+    };
+    test(1, Some(1));
+    test(2, Some(3));
+    test(10, Some(18));
+    test(12, Some(28));
+    test(30, Some(72));
+    test(36, Some(91));
+    test(100, Some(217));
+    test(255, Some(432));
+    test(65535, Some(111456));
+    test(720720, Some(3249792));
+    test(1000000007, Some(1000000008));
+    test(4294967295, Some(7304603328));
+    test(1000000000000, Some(2499694822171));
+    test(897612484786617600, Some(5785230588744499200));
+    test(18446744073709551557, Some(18446744073709551558));
+    test(18446744073709551615, None);
+}
+
+#[test]
+fn test_divisor_sum() {
+    divisor_sum_helper::<u8>();
+    divisor_sum_helper::<u16>();
+    divisor_sum_helper::<u32>();
+    divisor_sum_helper::<u64>();
+    divisor_sum_helper::<usize>();
+
+    let test = |n: u128, out: Option<u128>| {
+        assert_eq!(n.divisor_sum(), out);
+    };
+    test(u128::MAX, None);
+    test(
+        340282366920938463463374607431768211297,
+        Some(340282366920938463463374607431768211298),
+    );
+    test(1506334550815795554361, Some(1506334988579252394248));
+    test(
+        223632888043722196120573870901,
+        Some(223632888044158378093776978732),
+    );
+    test(1 << 127, Some(u128::MAX));
+    test(18446744073709551615, Some(31421980989189888768));
+}
+
+fn divisor_sum_fail_helper<T: DivisorSum + PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.divisor_sum());
+}
+
+#[test]
+fn divisor_sum_fail() {
+    divisor_sum_fail_helper::<u8>();
+    divisor_sum_fail_helper::<u16>();
+    divisor_sum_fail_helper::<u32>();
+    divisor_sum_fail_helper::<u64>();
+    divisor_sum_fail_helper::<u128>();
+    divisor_sum_fail_helper::<usize>();
+}
+
+fn divisor_sum_properties_helper<
+    T: DivisorSum<Output = Option<T>> + Divisors + IsPrime + PrimitiveUnsigned,
+>() {
+    unsigned_gen_var_1::<T>().test_properties(|n| {
+        let sigma = n.divisor_sum();
+        let sum = n.divisors().try_fold(T::ZERO, T::checked_add);
+        assert_eq!(sigma, sum);
+        if let Some(sigma) = sigma {
+            assert!(sigma >= n);
+            assert_eq!(sigma == n + T::ONE, n.is_prime());
+        }
+    });
+}
+
+#[test]
+fn divisor_sum_properties() {
+    unsigned_gen_var_1::<u8>().test_properties(|n| {
+        assert_eq!(
+            n.divisor_sum(),
+            divisors_naive(n).into_iter().try_fold(0u8, u8::checked_add)
+        );
+    });
+
+    divisor_sum_properties_helper::<u8>();
+    divisor_sum_properties_helper::<u16>();
+    divisor_sum_properties_helper::<u32>();
+    divisor_sum_properties_helper::<u64>();
+    divisor_sum_properties_helper::<usize>();
+
+    unsigned_gen_var_1::<u64>().test_properties(|n| {
+        if let Some(sum) = n.divisor_sum() {
+            assert_eq!(u128::from(n).divisor_sum(), Some(u128::from(sum)));
+        }
+    });
+}
@@ -0,0 +1,130 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use itertools::Itertools;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::{Divisors, IsPrime};
+use malachite_base::test_util::generators::unsigned_gen_var_1;
+use malachite_base::test_util::num::factorization::divisors::divisors_naive;
+use std::panic::catch_unwind;
+
+fn divisors_helper<T: Divisors + PrimitiveUnsigned>() {
+    let test = |n: u64, out: &[u64]| {
+        if let Ok(n) = T::try_from(n) {
+            assert_eq!(
+                n.divisors().collect_vec(),
+                out.iter().map(|&d| T::exact_from(d)).collect_vec()
+            );
+        }
+    };
+    test(1, &[1]);
+    test(2, &[1, 2]);
+    test(12, &[1, 2, 3, 4, 6, 12]);
+    test(36, &[1, 2, 3, 4, 6, 9, 12, 18, 36]);
+    test(100, &[1, 2, 4, 5, 10, 20, 25, 50, 100]);
+    test(255, &[1, 3, 5, 15, 17, 51, 85, 255]);
+    test(256, &[1, 2, 4, 8, 16, 32, 64, 128, 256]);
+    test(
+        65535,
+        &[1, 3, 5, 15, 17, 51, 85, 255, 257, 771, 1285, 3855, 4369, 13107, 21845, 65535],
+    );
+    test(1000000007, &[1, 1000000007]);
+    test(18446744073709551557, &[1, 18446744073709551557]);
+    test(
+        4294967295,
+        &[
+            1, 3, 5, 15, 17, 51, 85, 255, 257, 771, 1285, 3855, 4369, 13107, 21845, 65535, 65537,
+            196611, 327685, 983055, 1114129, 3342387, 5570645, 16711935, 16843009, 50529027,
+            84215045, 252645135, 286331153, 858993459, 1431655765, 4294967295,
+        ],
+    );
+}
+
+#[test]
+fn test_divisors() {
+    divisors_helper::<u8>();
+    divisors_helper::<u16>();
+    divisors_helper::<u32>();
+    divisors_helper::<u64>();
+    divisors_helper::<u128>();
+    divisors_helper::<usize>();
+
+    let test = |n: u128, out: &[u128]| {
+        assert_eq!(n.divisors().collect_vec(), out);
+    };
+    test(
+        340282366920938463463374607431768211297,
+        &[1, 340282366920938463463374607431768211297],
+    );
+    test(
+        1506334550815795554361,
+        &[
+            1,
+            6308461,
+            12616921,
+            18925381,
+            79593354068581,
+            119390027948641,
+            238780036971901,
+            1506334550815795554361,
+        ],
+    );
+}
+
+fn divisors_fail_helper<T: Divisors + PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.divisors());
+}
+
+#[test]
+fn divisors_fail() {
+    divisors_fail_helper::<u8>();
+    divisors_fail_helper::<u16>();
+    divisors_fail_helper::<u32>();
+    divisors_fail_helper::<u64>();
+    divisors_fail_helper::<u128>();
+    divisors_fail_helper::<usize>();
+}
+
+fn divisors_properties_helper<T: Divisors + IsPrime + PrimitiveUnsigned>() {
+    unsigned_gen_var_1::<T>().test_properties(|n| {
+        let divisors = n.divisors().collect_vec();
+        assert_eq!(divisors[0], T::ONE);
+        assert_eq!(*divisors.last().unwrap(), n);
+        assert!(divisors.iter().tuple_windows().all(|(a, b)| a < b));
+        for &d in &divisors {
+            assert!(n.divisible_by(d));
+            // divisors come in pairs (d, n / d)
+            assert!(divisors.binary_search(&(n / d)).is_ok());
+        }
+        assert_eq!(divisors.len() == 2, n.is_prime());
+    });
+}
+
+#[test]
+fn divisors_properties() {
+    unsigned_gen_var_1::<u8>().test_properties(|n| {
+        assert_eq!(n.divisors().collect_vec(), divisors_naive(n));
+    });
+
+    unsigned_gen_var_1::<u16>().test_properties(|n| {
+        assert_eq!(n.divisors().collect_vec(), divisors_naive(n));
+    });
+
+    divisors_properties_helper::<u8>();
+    divisors_properties_helper::<u16>();
+    divisors_properties_helper::<u32>();
+    divisors_properties_helper::<u64>();
+    divisors_properties_helper::<usize>();
+
+    unsigned_gen_var_1::<u64>().test_properties(|n| {
+        assert_eq!(
+            u128::from(n).divisors().collect_vec(),
+            n.divisors().map(u128::from).collect_vec()
+        );
+    });
+}
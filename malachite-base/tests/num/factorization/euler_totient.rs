@@ -0,0 +1,115 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::{EulerTotient, IsPrime};
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen_var_27};
+use malachite_base::test_util::num::factorization::euler_totient::euler_totient_naive;
+use malachite_base::test_util::num::factorization::multiplicative::check_multiplicative;
+use std::panic::catch_unwind;
+
+fn euler_totient_helper<T: EulerTotient<Output = T> + PrimitiveUnsigned>() {
+    let test = |n: u64, out: u64| {
+        if let Ok(n) = T::try_from(n) {
+            assert_eq!(n.euler_totient(), T::exact_from(out));
+        }
+    };
+    test(1, 1);
+    test(2, 1);
+    test(10, 4);
+    test(12, 4);
+    test(30, 8);
+    test(36, 12);
+    test(100, 40);
+    test(255, 128);
+    test(65535, 32768);
+    test(720720, 138240);
+    test(1000000007, 1000000006);
+    test(4294967295, 2147483648);
+    test(1000000000000, 400000000000);
+    test(897612484786617600, 133493837266944000);
+    test(18446744073709551557, 18446744073709551556);
+    test(18446744073709551615, 9208981628670443520);
+}
+
+#[test]
+fn test_euler_totient() {
+    euler_totient_helper::<u8>();
+    euler_totient_helper::<u16>();
+    euler_totient_helper::<u32>();
+    euler_totient_helper::<u64>();
+    euler_totient_helper::<u128>();
+    euler_totient_helper::<usize>();
+
+    let test = |n: u128, out: u128| {
+        assert_eq!(n.euler_totient(), out);
+    };
+    test(u128::MAX, 169875107699410294159549716941399654400);
+    test(
+        340282366920938463463374607431768211297,
+        340282366920938463463374607431768211296,
+    );
+    test(1506334550815795554361, 1506334113052414416000);
+    test(
+        223632888043722196120573870901,
+        223632888043286014147370763072,
+    );
+    test(1 << 127, 85070591730234615865843651857942052864);
+}
+
+fn euler_totient_fail_helper<T: EulerTotient + PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.euler_totient());
+}
+
+#[test]
+fn euler_totient_fail() {
+    euler_totient_fail_helper::<u8>();
+    euler_totient_fail_helper::<u16>();
+    euler_totient_fail_helper::<u32>();
+    euler_totient_fail_helper::<u64>();
+    euler_totient_fail_helper::<u128>();
+    euler_totient_fail_helper::<usize>();
+}
+
+fn euler_totient_properties_helper<T: EulerTotient<Output = T> + IsPrime + PrimitiveUnsigned>() {
+    unsigned_gen_var_1::<T>().test_properties(|n| {
+        let phi = n.euler_totient();
+        assert_ne!(phi, T::ZERO);
+        assert!(phi <= n);
+        assert_eq!(phi == n - T::ONE, n.is_prime());
+        if n > T::TWO {
+            assert!(phi.even());
+        }
+    });
+
+    unsigned_pair_gen_var_27::<T>().test_properties(|(m, n)| {
+        // phi is multiplicative
+        check_multiplicative(|x: T| x.euler_totient(), m, n);
+    });
+}
+
+#[test]
+fn euler_totient_properties() {
+    unsigned_gen_var_1::<u8>().test_properties(|n| {
+        assert_eq!(n.euler_totient(), euler_totient_naive(n));
+    });
+
+    unsigned_gen_var_1::<u16>().test_properties(|n| {
+        assert_eq!(n.euler_totient(), euler_totient_naive(n));
+    });
+
+    euler_totient_properties_helper::<u8>();
+    euler_totient_properties_helper::<u16>();
+    euler_totient_properties_helper::<u32>();
+    euler_totient_properties_helper::<u64>();
+    euler_totient_properties_helper::<usize>();
+
+    unsigned_gen_var_1::<u64>().test_properties(|n| {
+        assert_eq!(u128::from(n).euler_totient(), u128::from(n.euler_totient()));
+    });
+}
@@ -11,9 +11,12 @@ use malachite_base::num::arithmetic::traits::PowerOf2;
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
 use malachite_base::num::exhaustive::exhaustive_positive_primitive_ints;
+use malachite_base::num::factorization::factor::montgomery_mul_u128;
 use malachite_base::num::factorization::traits::{Factor, IsPrime};
-use malachite_base::test_util::generators::unsigned_gen_var_1;
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen};
 use malachite_base::test_util::num::factorization::factor::factor_naive;
+use std::collections::BTreeMap;
 use std::panic::catch_unwind;
 
 fn factor_helper<T: Factor + PrimitiveUnsigned>()
@@ -715,6 +718,76 @@ fn test_factor() {
     factor_helper::<u32>();
     factor_helper::<u64>();
     factor_helper::<usize>();
+
+    let test = |n: u128, out: &[(u128, u8)]| {
+        assert_eq!(n.factor().into_iter().collect_vec(), out);
+    };
+    // - n > u64::MAX in u128::factor
+    test(
+        340282366920938463463374607431768211297,
+        &[(340282366920938463463374607431768211297, 1)],
+    );
+    test(
+        u128::MAX,
+        &[
+            (3, 1),
+            (5, 1),
+            (17, 1),
+            (257, 1),
+            (641, 1),
+            (65537, 1),
+            (274177, 1),
+            (6700417, 1),
+            (67280421310721, 1),
+        ],
+    );
+    test(1 << 127, &[(2, 127)]);
+    // - perfect power in u128::factor
+    test(
+        340282366920938461286658806734041124249,
+        &[(18446744073709551557, 2)],
+    );
+    test(
+        1506334550815795554361,
+        &[(6308461, 1), (12616921, 1), (18925381, 1)],
+    );
+    test(
+        223632888043722196120573870901,
+        &[(512706082897, 1), (436181460497024933, 1)],
+    );
+    test(
+        9623943982983844860999245586524890,
+        &[(2, 1), (5, 1), (31, 1), (1976926160580341, 1), (15703662184912859, 1)],
+    );
+    // - two prime factors of about 64 bits, found by the elliptic curve method
+    test(
+        340282366920938460843936948965011886881,
+        &[(18446744073709551533, 1), (18446744073709551557, 1)],
+    );
+}
+
+#[test]
+fn test_montgomery_mul_u128() {
+    let test = |x: u128, y: u128, n: u128, out| {
+        assert_eq!(montgomery_mul_u128(x, y, n), out);
+    };
+    test(0, 0, 3, 0);
+    test(1, 1, 3, 1);
+    test(2, 2, 5, 4);
+    test(
+        u128::MAX - 159,
+        u128::MAX - 159,
+        u128::MAX - 158,
+        235415473970460572207366080613172976369,
+    );
+    // - n > 2^127 and the high half of the intermediate sum is u128::MAX, so the final carry
+    //   overflows
+    test(
+        170141183460469231731687303715884105729,
+        2113555074043096046356364021315330819,
+        u128::MAX - 158,
+        159,
+    );
 }
 
 fn factor_fail_helper<T: Factor + PrimitiveUnsigned>() {
@@ -727,6 +800,7 @@ pub fn factor_fail() {
     factor_fail_helper::<u16>();
     factor_fail_helper::<u32>();
     factor_fail_helper::<u64>();
+    factor_fail_helper::<u128>();
     factor_fail_helper::<usize>();
 }
 
@@ -773,4 +847,23 @@ fn factor_properties() {
     factor_properties_helper_1::<u32>();
     factor_properties_helper_1::<u64>();
     factor_properties_helper_1::<usize>();
+
+    // Random u128s may have two large prime factors, so build them from smaller pieces instead.
+    unsigned_pair_gen::<u64, u32>().test_properties_with_limit(SMALL_LIMIT, |(m, n)| {
+        if m == 0 || n == 0 {
+            return;
+        }
+        let mut expected = BTreeMap::new();
+        for (p, e) in m.factor() {
+            *expected.entry(u128::from(p)).or_insert(0) += e;
+        }
+        for (p, e) in n.factor() {
+            *expected.entry(u128::from(p)).or_insert(0) += e;
+        }
+        let product = u128::from(m) * u128::from(n);
+        assert_eq!(
+            product.factor().into_iter().collect_vec(),
+            expected.into_iter().collect_vec()
+        );
+    });
 }
@@ -7,7 +7,7 @@
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
 use malachite_base::num::arithmetic::traits::CheckedSquare;
-use malachite_base::num::factorization::traits::{Factor, IsPerfectPower};
+use malachite_base::num::factorization::traits::{ExpressAsPower, Factor, IsPerfectPower};
 use malachite_base::num::random::random_unsigned_bit_chunks;
 use malachite_base::random::EXAMPLE_SEED;
 
@@ -116,9 +116,48 @@ fn test_signed() {
     assert_eq!(4i32.is_perfect_power().unwrap(), (2, 2));
     assert_eq!(8i64.is_perfect_power().unwrap(), (2, 3));
 
+    assert!((-1i8).is_perfect_power().is_none());
+    assert!((-1i16).is_perfect_power().is_none());
+    assert!((-1i32).is_perfect_power().is_none());
+    assert!((-1i64).is_perfect_power().is_none());
+    assert!((-1isize).is_perfect_power().is_none());
+
     // 64 = 2^6 = 4^3 but 3 is the largest odd exponent, so -64 = (-4)^3
     // where in the unsigned case we expect 2^6. etc.
     assert_eq!((-64i32).is_perfect_power().unwrap(), (-4, 3));
     assert_eq!((-4096i64).is_perfect_power().unwrap(), (-16, 3));
     assert_eq!((-3486784401i64).is_perfect_power().unwrap(), (-81, 5));
 }
+
+#[test]
+fn test_express_as_power() {
+    assert_eq!(0u8.express_as_power(), (0, 2));
+    assert_eq!(1u16.express_as_power(), (1, 2));
+    assert_eq!(10u32.express_as_power(), (10, 1));
+    assert_eq!(1728u64.express_as_power(), (12, 3));
+    assert_eq!(u64::MAX.express_as_power(), (u64::MAX, 1));
+    assert_eq!(64usize.express_as_power(), (2, 6));
+    // -1 = (-1)^k for every odd k, even though is_perfect_power returns None for it
+    assert_eq!((-1i8).express_as_power(), (-1, 3));
+    assert_eq!((-1i16).express_as_power(), (-1, 3));
+    assert_eq!((-1i32).express_as_power(), (-1, 3));
+    assert_eq!((-1i64).express_as_power(), (-1, 3));
+    assert_eq!((-1isize).express_as_power(), (-1, 3));
+    assert_eq!((-16i16).express_as_power(), (-16, 1));
+    assert_eq!((-64i32).express_as_power(), (-4, 3));
+    assert_eq!((-3486784401i64).express_as_power(), (-81, 5));
+    assert_eq!(i64::MIN.express_as_power(), (-2, 63));
+
+    for x in 0u32..10000 {
+        assert_eq!(
+            x.express_as_power(),
+            x.is_perfect_power()
+                .map_or((x, 1), |(base, exp)| (base, u64::from(exp)))
+        );
+    }
+    for x in -10000i32..10000 {
+        let (base, exp) = x.express_as_power();
+        assert_eq!(base.pow(u32::try_from(exp).unwrap()), x);
+        assert_eq!(exp == 1, x != -1 && x.is_perfect_power().is_none());
+    }
+}
@@ -502,6 +502,19 @@ fn test_is_prime() {
     is_prime_helper::<u32>();
     is_prime_helper::<u64>();
     is_prime_helper::<usize>();
+
+    let test = |n: u128, out| {
+        assert_eq!(n.is_prime(), out);
+    };
+    // - n > u64::MAX in u128::is_prime
+    test(18446744073709551629, true);
+    test(18446744073709551631, false);
+    test(340282366920938463463374607431768211297, true);
+    test(u128::MAX, false);
+    // - a Carmichael number that is a strong probable prime to base 2, rejected by the Lucas test
+    test(1506334550815795554361, false);
+    // - the square of a prime
+    test(340282366920938461286658806734041124249, false);
 }
 
 fn is_prime_properties_helper_helper<T: IsPrime + PrimitiveUnsigned>(n: T) {
@@ -540,4 +553,5 @@ fn is_prime_properties() {
 
     is_prime_properties_helper_2::<u16, u32>();
     is_prime_properties_helper_2::<u32, u64>();
+    is_prime_properties_helper_2::<u64, u128>();
 }
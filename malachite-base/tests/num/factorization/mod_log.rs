@@ -0,0 +1,111 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::{ModLog, MultiplicativeOrder};
+use malachite_base::test_util::generators::unsigned_triple_gen_var_25;
+use malachite_base::test_util::num::factorization::mod_log::mod_log_naive;
+use std::panic::catch_unwind;
+
+fn mod_log_helper<T: ModLog<Output = T> + PrimitiveUnsigned>() {
+    let test = |x: u64, g: u64, m: u64, out: Option<u64>| {
+        if let (Ok(x), Ok(g), Ok(m)) = (T::try_from(x), T::try_from(g), T::try_from(m)) {
+            assert_eq!(x.mod_log(g, m), out.map(|out| T::exact_from(out)));
+        }
+    };
+    test(0, 0, 1, Some(0));
+    test(1, 0, 5, Some(0));
+    test(0, 0, 5, Some(1));
+    test(3, 0, 5, None);
+    test(1, 3, 7, Some(0));
+    test(6, 3, 7, Some(3));
+    test(3, 2, 7, None);
+    test(0, 2, 8, Some(3));
+    test(4, 2, 8, Some(2));
+    test(6, 4, 10, Some(2));
+    test(4, 6, 10, None);
+    test(8, 2, 24, Some(3));
+    test(16, 2, 24, Some(4));
+    test(12, 6, 36, None);
+    test(5, 3, 1000, None);
+    test(12345, 5, 1000000007, Some(775972150));
+    test(1234567, 2, 4294967291, Some(1405270687));
+    test(1000000, 3, 4294967296, None);
+    test(1000001, 3, 4294967296, Some(32317904));
+    test(2, 3, 9223372036854775807, None);
+    test(9004364434978461240, 3, 9223372036854775807, Some(3920085));
+    test(10, 3, 18446744073709551557, Some(4388222717587606556));
+}
+
+#[test]
+fn test_mod_log() {
+    mod_log_helper::<u8>();
+    mod_log_helper::<u16>();
+    mod_log_helper::<u32>();
+    mod_log_helper::<u64>();
+    mod_log_helper::<usize>();
+}
+
+fn mod_log_fail_helper<T: ModLog + PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.mod_log(T::ZERO, T::ZERO));
+    assert_panic!(T::TWO.mod_log(T::ONE, T::TWO));
+    assert_panic!(T::ONE.mod_log(T::TWO, T::TWO));
+}
+
+#[test]
+fn mod_log_fail() {
+    mod_log_fail_helper::<u8>();
+    mod_log_fail_helper::<u16>();
+    mod_log_fail_helper::<u32>();
+    mod_log_fail_helper::<u64>();
+    mod_log_fail_helper::<usize>();
+}
+
+fn mod_log_properties_helper<
+    T: ModLog<Output = T> + MultiplicativeOrder<Output = T> + PrimitiveUnsigned,
+>() {
+    unsigned_triple_gen_var_25::<T>().test_properties(|(x, g, m)| {
+        let log = x.mod_log(g, m);
+        if let Some(log) = log {
+            assert!(log < m);
+            assert_eq!(g.mod_pow(log.exact_into(), m), x);
+            if let Some(order) = g.multiplicative_order(m) {
+                assert!(log < order);
+            }
+        }
+        let y = g.mod_pow(x.exact_into(), m);
+        let log = y.mod_log(g, m).unwrap();
+        assert!(log <= x);
+        assert_eq!(g.mod_pow(log.exact_into(), m), y);
+    });
+
+    unsigned_triple_gen_var_25::<T>().test_properties(|(_, g, m)| {
+        assert_eq!((T::ONE % m).mod_log(g, m), Some(T::ZERO));
+        assert_eq!(
+            g.mod_log(g, m),
+            Some(if g == T::ONE % m { T::ZERO } else { T::ONE })
+        );
+    });
+}
+
+#[test]
+fn mod_log_properties() {
+    unsigned_triple_gen_var_25::<u8>().test_properties(|(x, g, m)| {
+        assert_eq!(x.mod_log(g, m), mod_log_naive(x, g, m));
+    });
+
+    unsigned_triple_gen_var_25::<u16>().test_properties(|(x, g, m)| {
+        assert_eq!(x.mod_log(g, m), mod_log_naive(x, g, m));
+    });
+
+    mod_log_properties_helper::<u8>();
+    mod_log_properties_helper::<u16>();
+    mod_log_properties_helper::<u32>();
+    mod_log_properties_helper::<u64>();
+    mod_log_properties_helper::<usize>();
+}
@@ -0,0 +1,110 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::{IsPrime, IsSquare, Moebius};
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen_var_27};
+use malachite_base::test_util::num::factorization::moebius::moebius_naive;
+use malachite_base::test_util::num::factorization::multiplicative::check_multiplicative;
+use std::panic::catch_unwind;
+
+fn moebius_helper<T: Moebius + PrimitiveUnsigned>() {
+    let test = |n: u64, out: i8| {
+        if let Ok(n) = T::try_from(n) {
+            assert_eq!(n.moebius(), out);
+        }
+    };
+    test(1, 1);
+    test(2, -1);
+    test(4, 0);
+    test(6, 1);
+    test(10, 1);
+    test(12, 0);
+    test(30, -1);
+    test(36, 0);
+    test(255, -1);
+    test(65535, 1);
+    test(720720, 0);
+    test(1000000007, -1);
+    test(4294967295, -1);
+    test(1000000000000, 0);
+    test(18446744073709551557, -1);
+    test(18446744073709551615, -1);
+}
+
+#[test]
+fn test_moebius() {
+    moebius_helper::<u8>();
+    moebius_helper::<u16>();
+    moebius_helper::<u32>();
+    moebius_helper::<u64>();
+    moebius_helper::<u128>();
+    moebius_helper::<usize>();
+
+    let test = |n: u128, out: i8| {
+        assert_eq!(n.moebius(), out);
+    };
+    test(u128::MAX, -1);
+    test(340282366920938463463374607431768211297, -1);
+    test(1506334550815795554361, -1);
+    test(223632888043722196120573870901, 1);
+    test(1 << 127, 0);
+}
+
+fn moebius_fail_helper<T: Moebius + PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.moebius());
+}
+
+#[test]
+fn moebius_fail() {
+    moebius_fail_helper::<u8>();
+    moebius_fail_helper::<u16>();
+    moebius_fail_helper::<u32>();
+    moebius_fail_helper::<u64>();
+    moebius_fail_helper::<u128>();
+    moebius_fail_helper::<usize>();
+}
+
+fn moebius_properties_helper<T: IsPrime + IsSquare + Moebius + PrimitiveUnsigned>() {
+    unsigned_gen_var_1::<T>().test_properties(|n| {
+        let mu = n.moebius();
+        assert!(mu == -1 || mu == 0 || mu == 1);
+        if n.is_prime() {
+            assert_eq!(mu, -1);
+        }
+        if n != T::ONE && n.is_square() {
+            assert_eq!(mu, 0);
+        }
+    });
+
+    unsigned_pair_gen_var_27::<T>().test_properties(|(m, n)| {
+        // mu is multiplicative
+        check_multiplicative(|x: T| x.moebius(), m, n);
+    });
+}
+
+#[test]
+fn moebius_properties() {
+    unsigned_gen_var_1::<u8>().test_properties(|n| {
+        assert_eq!(n.moebius(), moebius_naive(n));
+    });
+
+    unsigned_gen_var_1::<u16>().test_properties(|n| {
+        assert_eq!(n.moebius(), moebius_naive(n));
+    });
+
+    moebius_properties_helper::<u8>();
+    moebius_properties_helper::<u16>();
+    moebius_properties_helper::<u32>();
+    moebius_properties_helper::<u64>();
+    moebius_properties_helper::<usize>();
+
+    unsigned_gen_var_1::<u64>().test_properties(|n| {
+        assert_eq!(u128::from(n).moebius(), n.moebius());
+    });
+}
@@ -0,0 +1,104 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::{ModLog, MultiplicativeOrder};
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen_var_16};
+use malachite_base::test_util::num::factorization::multiplicative_order::multiplicative_order_naive;
+use std::panic::catch_unwind;
+
+fn multiplicative_order_helper<
+    T: ModLog<Output = T> + MultiplicativeOrder<Output = T> + PrimitiveUnsigned,
+>() {
+    let test = |x: u64, m: u64, out: Option<u64>| {
+        if let (Ok(x), Ok(m)) = (T::try_from(x), T::try_from(m)) {
+            assert_eq!(x.multiplicative_order(m), out.map(|out| T::exact_from(out)));
+        }
+    };
+    test(0, 1, Some(1));
+    test(1, 2, Some(1));
+    test(2, 7, Some(3));
+    test(3, 7, Some(6));
+    test(6, 7, Some(2));
+    test(2, 8, None);
+    test(3, 8, Some(2));
+    test(5, 12, Some(2));
+    test(10, 21, Some(6));
+    test(3, 100, Some(20));
+    test(0, 100, None);
+    test(2, 1000000007, Some(500000003));
+    test(3, 4294967291, Some(2147483645));
+    test(12345, 4294967296, Some(536870912));
+    test(3, 9223372036854775807, Some(14942088));
+    test(5, 18446744073709551615, None);
+    test(7, 18446744073709551557, Some(18446744073709551556));
+}
+
+#[test]
+fn test_multiplicative_order() {
+    multiplicative_order_helper::<u8>();
+    multiplicative_order_helper::<u16>();
+    multiplicative_order_helper::<u32>();
+    multiplicative_order_helper::<u64>();
+    multiplicative_order_helper::<usize>();
+}
+
+fn multiplicative_order_fail_helper<T: MultiplicativeOrder + PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.multiplicative_order(T::ZERO));
+    assert_panic!(T::TWO.multiplicative_order(T::TWO));
+}
+
+#[test]
+fn multiplicative_order_fail() {
+    multiplicative_order_fail_helper::<u8>();
+    multiplicative_order_fail_helper::<u16>();
+    multiplicative_order_fail_helper::<u32>();
+    multiplicative_order_fail_helper::<u64>();
+    multiplicative_order_fail_helper::<usize>();
+}
+
+fn multiplicative_order_properties_helper<
+    T: ModLog<Output = T> + MultiplicativeOrder<Output = T> + PrimitiveUnsigned,
+>() {
+    unsigned_pair_gen_var_16::<T>().test_properties(|(x, m)| {
+        let order = x.multiplicative_order(m);
+        assert_eq!(order.is_some(), x.coprime_with(m));
+        if let Some(order) = order {
+            assert_ne!(order, T::ZERO);
+            assert!(order < m || m == T::ONE);
+            assert_eq!(x.mod_pow(order.exact_into(), m), T::ONE % m);
+            // The order is the smallest positive k with x^k = 1, so x^(k - 1) = x^(-1).
+            let inverse = x.mod_pow((order - T::ONE).exact_into(), m);
+            assert_eq!(inverse.mod_mul(x, m), T::ONE % m);
+            if m != T::ONE {
+                assert_eq!(
+                    x.mod_inverse(m).unwrap().multiplicative_order(m),
+                    Some(order)
+                );
+            }
+        }
+    });
+
+    unsigned_gen_var_1::<T>().test_properties(|m| {
+        assert_eq!((T::ONE % m).multiplicative_order(m), Some(T::ONE));
+        assert_eq!(T::ZERO.multiplicative_order(m).is_some(), m == T::ONE);
+    });
+}
+
+#[test]
+fn multiplicative_order_properties() {
+    unsigned_pair_gen_var_16::<u8>().test_properties(|(x, m)| {
+        assert_eq!(x.multiplicative_order(m), multiplicative_order_naive(x, m));
+    });
+
+    multiplicative_order_properties_helper::<u8>();
+    multiplicative_order_properties_helper::<u16>();
+    multiplicative_order_properties_helper::<u32>();
+    multiplicative_order_properties_helper::<u64>();
+    multiplicative_order_properties_helper::<usize>();
+}
@@ -0,0 +1,100 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::exhaustive::exhaustive_unsigneds;
+use malachite_base::num::factorization::traits::{IsPrime, NextPrime, PrevPrime};
+use malachite_base::test_util::generators::unsigned_gen;
+use malachite_base::test_util::num::factorization::next_prime::next_prime_naive;
+
+fn next_prime_helper<T: IsPrime + NextPrime<Output = Option<T>> + PrimitiveUnsigned>() {
+    let test = |n: u64, out: Option<u64>| {
+        if let Ok(n) = T::try_from(n) {
+            let out = out.and_then(|p| T::try_from(p).ok());
+            assert_eq!(n.next_prime(), out);
+            assert_eq!(next_prime_naive(n), out);
+        }
+    };
+    test(0, Some(2));
+    test(1, Some(2));
+    test(2, Some(3));
+    test(3, Some(5));
+    test(4, Some(5));
+    test(5, Some(7));
+    test(10, Some(11));
+    test(100, Some(101));
+    test(250, Some(251));
+    test(251, Some(257));
+    test(1020, Some(1021));
+    test(1021, Some(1031));
+    test(1024, Some(1031));
+    test(4095, Some(4099));
+    test(4096, Some(4099));
+    test(10000, Some(10007));
+    test(31397, Some(31469));
+    test(65521, Some(65537));
+    test(1000000, Some(1000003));
+    test(4294967291, Some(4294967311));
+    test(1000000000000, Some(1000000000039));
+    test(18446744073709551556, Some(18446744073709551557));
+    test(18446744073709551557, None);
+    test(u64::MAX, None);
+}
+
+#[test]
+fn test_next_prime() {
+    next_prime_helper::<u8>();
+    next_prime_helper::<u16>();
+    next_prime_helper::<u32>();
+    next_prime_helper::<u64>();
+    next_prime_helper::<usize>();
+}
+
+fn next_prime_properties_helper_helper<
+    T: IsPrime + NextPrime<Output = Option<T>> + PrevPrime<Output = Option<T>> + PrimitiveUnsigned,
+>(
+    n: T,
+) {
+    let p = n.next_prime();
+    assert_eq!(next_prime_naive(n), p);
+    if let Some(p) = p {
+        assert!(p > n);
+        assert!(p.is_prime());
+        assert!(p.prev_prime().is_none_or(|q| q <= n));
+        let mut primes = T::primes();
+        assert!(primes.jump_after(n));
+        assert_eq!(primes.next(), Some(p));
+    } else {
+        assert!(T::primes().all(|p| p <= n));
+    }
+}
+
+fn next_prime_properties_helper<
+    T: IsPrime + NextPrime<Output = Option<T>> + PrevPrime<Output = Option<T>> + PrimitiveUnsigned,
+>() {
+    for n in exhaustive_unsigneds::<T>().take(10_000) {
+        next_prime_properties_helper_helper(n);
+    }
+    unsigned_gen::<T>().test_properties(|n| {
+        let p = n.next_prime();
+        assert_eq!(next_prime_naive(n), p);
+        if let Some(p) = p {
+            assert!(p > n);
+            assert!(p.is_prime());
+            assert!(p.prev_prime().is_none_or(|q| q <= n));
+        }
+    });
+}
+
+#[test]
+fn next_prime_properties() {
+    next_prime_properties_helper::<u8>();
+    next_prime_properties_helper::<u16>();
+    next_prime_properties_helper::<u32>();
+    next_prime_properties_helper::<u64>();
+    next_prime_properties_helper::<usize>();
+}
@@ -0,0 +1,99 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::exhaustive::exhaustive_unsigneds;
+use malachite_base::num::factorization::traits::{IsPrime, NextPrime, PrevPrime};
+use malachite_base::test_util::generators::unsigned_gen;
+use malachite_base::test_util::num::factorization::prev_prime::prev_prime_naive;
+
+fn prev_prime_helper<T: IsPrime + PrevPrime<Output = Option<T>> + PrimitiveUnsigned>() {
+    let test = |n: u64, out: Option<u64>| {
+        if let Ok(n) = T::try_from(n) {
+            let out = out.map(|p| T::exact_from(p));
+            assert_eq!(n.prev_prime(), out);
+            assert_eq!(prev_prime_naive(n), out);
+        }
+    };
+    test(0, None);
+    test(1, None);
+    test(2, None);
+    test(3, Some(2));
+    test(4, Some(3));
+    test(5, Some(3));
+    test(6, Some(5));
+    test(10, Some(7));
+    test(100, Some(97));
+    test(255, Some(251));
+    test(1021, Some(1019));
+    test(1022, Some(1021));
+    test(1031, Some(1021));
+    test(1032, Some(1031));
+    test(4096, Some(4093));
+    test(4100, Some(4099));
+    test(10000, Some(9973));
+    test(31469, Some(31397));
+    test(u64::from(u16::MAX), Some(65521));
+    test(1000000, Some(999983));
+    test(u64::from(u32::MAX), Some(4294967291));
+    test(1000000000000, Some(999999999989));
+    test(u64::MAX, Some(18446744073709551557));
+}
+
+#[test]
+fn test_prev_prime() {
+    prev_prime_helper::<u8>();
+    prev_prime_helper::<u16>();
+    prev_prime_helper::<u32>();
+    prev_prime_helper::<u64>();
+    prev_prime_helper::<usize>();
+}
+
+fn prev_prime_properties_helper_helper<
+    T: IsPrime + NextPrime<Output = Option<T>> + PrevPrime<Output = Option<T>> + PrimitiveUnsigned,
+>(
+    n: T,
+) {
+    let p = n.prev_prime();
+    assert_eq!(prev_prime_naive(n), p);
+    if let Some(p) = p {
+        assert!(p < n);
+        assert!(p.is_prime());
+        assert!(p.next_prime().is_none_or(|q| q >= n));
+        assert_eq!(T::primes_less_than(&n).last(), Some(p));
+    } else {
+        assert!(n <= T::TWO);
+    }
+}
+
+fn prev_prime_properties_helper<
+    T: IsPrime + NextPrime<Output = Option<T>> + PrevPrime<Output = Option<T>> + PrimitiveUnsigned,
+>() {
+    for n in exhaustive_unsigneds::<T>().take(10_000) {
+        prev_prime_properties_helper_helper(n);
+    }
+    unsigned_gen::<T>().test_properties(|n| {
+        let p = n.prev_prime();
+        assert_eq!(prev_prime_naive(n), p);
+        if let Some(p) = p {
+            assert!(p < n);
+            assert!(p.is_prime());
+            assert!(p.next_prime().is_none_or(|q| q >= n));
+        } else {
+            assert!(n <= T::TWO);
+        }
+    });
+}
+
+#[test]
+fn prev_prime_properties() {
+    prev_prime_properties_helper::<u8>();
+    prev_prime_properties_helper::<u16>();
+    prev_prime_properties_helper::<u32>();
+    prev_prime_properties_helper::<u64>();
+    prev_prime_properties_helper::<usize>();
+}
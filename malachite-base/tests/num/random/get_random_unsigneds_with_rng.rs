@@ -0,0 +1,173 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use itertools::Itertools;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::random::{
+    get_random_primitive_int_with_rng, get_random_unsigned_inclusive_range_with_rng,
+    get_random_unsigned_less_than_with_rng, get_random_unsigned_range_with_rng,
+    random_primitive_ints,
+};
+use malachite_base::random::EXAMPLE_SEED;
+use malachite_base::test_util::generators::{
+    unsigned_gen_var_1, unsigned_pair_gen_var_7, unsigned_pair_gen_var_16,
+};
+use rand::rngs::mock::StepRng;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_get_random_primitive_int_with_rng() {
+    // The same RNG that backs `random_primitive_ints` gives the same values
+    let mut rng = EXAMPLE_SEED.get_rng();
+    let xs = (0..10)
+        .map(|_| get_random_primitive_int_with_rng::<u64, _>(&mut rng))
+        .collect_vec();
+    assert_eq!(
+        xs,
+        random_primitive_ints::<u64>(EXAMPLE_SEED)
+            .take(10)
+            .collect_vec()
+    );
+
+    let mut rng = StepRng::new(5, 1);
+    assert_eq!(get_random_primitive_int_with_rng::<u8, _>(&mut rng), 5);
+    assert_eq!(get_random_primitive_int_with_rng::<u64, _>(&mut rng), 6);
+    assert_eq!(get_random_primitive_int_with_rng::<i32, _>(&mut rng), 7);
+    assert_eq!(
+        get_random_primitive_int_with_rng::<u128, _>(&mut rng),
+        (9 << 64) | 8
+    );
+}
+
+fn get_random_unsigned_less_than_with_rng_helper<T: PrimitiveUnsigned>(limit: T, out: &[T]) {
+    let mut rng = EXAMPLE_SEED.get_rng();
+    let xs = (0..10)
+        .map(|_| get_random_unsigned_less_than_with_rng(&mut rng, limit))
+        .collect_vec();
+    assert_eq!(xs, out);
+}
+
+#[test]
+fn test_get_random_unsigned_less_than_with_rng() {
+    get_random_unsigned_less_than_with_rng_helper::<u8>(1, &[0; 10]);
+    get_random_unsigned_less_than_with_rng_helper::<u8>(10, &[1, 4, 7, 5, 7, 7, 8, 9, 2, 7]);
+    get_random_unsigned_less_than_with_rng_helper::<u16>(
+        1000,
+        &[881, 740, 87, 700, 93, 445, 629, 407, 519, 840],
+    );
+    get_random_unsigned_less_than_with_rng_helper::<u64>(
+        1000000000000,
+        &[
+            981069066097,
+            808853577815,
+            813688014941,
+            649750090357,
+            311658882567,
+            53539138537,
+            171651608178,
+            983486965096,
+            859123504619,
+            917729902676,
+        ],
+    );
+
+    // A generator that always returns 0 gives the smallest value
+    assert_eq!(
+        get_random_unsigned_less_than_with_rng(&mut StepRng::new(0, 0), 100u32),
+        0
+    );
+}
+
+fn get_random_unsigned_less_than_with_rng_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(get_random_unsigned_less_than_with_rng(
+        &mut EXAMPLE_SEED.get_rng(),
+        T::ZERO
+    ));
+}
+
+#[test]
+fn get_random_unsigned_less_than_with_rng_fail() {
+    apply_fn_to_unsigneds!(get_random_unsigned_less_than_with_rng_fail_helper);
+}
+
+#[test]
+fn test_get_random_unsigned_range_with_rng() {
+    let mut rng = EXAMPLE_SEED.get_rng();
+    let xs = (0..10)
+        .map(|_| get_random_unsigned_range_with_rng::<u8, _>(&mut rng, 10, 20))
+        .collect_vec();
+    assert_eq!(xs, &[11, 14, 17, 15, 17, 17, 18, 19, 12, 17]);
+}
+
+fn get_random_unsigned_range_with_rng_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(get_random_unsigned_range_with_rng(
+        &mut EXAMPLE_SEED.get_rng(),
+        T::TWO,
+        T::TWO
+    ));
+}
+
+#[test]
+fn get_random_unsigned_range_with_rng_fail() {
+    apply_fn_to_unsigneds!(get_random_unsigned_range_with_rng_fail_helper);
+}
+
+#[test]
+fn test_get_random_unsigned_inclusive_range_with_rng() {
+    let mut rng = EXAMPLE_SEED.get_rng();
+    let xs = (0..10)
+        .map(|_| get_random_unsigned_inclusive_range_with_rng::<u8, _>(&mut rng, 10, 19))
+        .collect_vec();
+    assert_eq!(xs, &[11, 14, 17, 15, 17, 17, 18, 19, 12, 17]);
+    let mut rng = EXAMPLE_SEED.get_rng();
+    let xs = (0..10)
+        .map(|_| get_random_unsigned_inclusive_range_with_rng::<u8, _>(&mut rng, 0, u8::MAX))
+        .collect_vec();
+    assert_eq!(xs, &[113, 228, 87, 188, 93, 189, 117, 151, 7, 72]);
+}
+
+fn get_random_unsigned_inclusive_range_with_rng_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(get_random_unsigned_inclusive_range_with_rng(
+        &mut EXAMPLE_SEED.get_rng(),
+        T::TWO,
+        T::ONE
+    ));
+}
+
+#[test]
+fn get_random_unsigned_inclusive_range_with_rng_fail() {
+    apply_fn_to_unsigneds!(get_random_unsigned_inclusive_range_with_rng_fail_helper);
+}
+
+fn get_random_unsigneds_with_rng_properties_helper<T: PrimitiveUnsigned>() {
+    let mut rng = EXAMPLE_SEED.get_rng();
+    let xs = (0..10)
+        .map(|_| get_random_unsigned_inclusive_range_with_rng::<u8, _>(&mut rng, 0, u8::MAX))
+        .collect_vec();
+    assert_eq!(xs, &[113, 228, 87, 188, 93, 189, 117, 151, 7, 72]);
+    unsigned_gen_var_1::<T>().test_properties(|limit| {
+        assert!(get_random_unsigned_less_than_with_rng(&mut rng, limit) < limit);
+    });
+
+    unsigned_pair_gen_var_16::<T>().test_properties(|(a, b)| {
+        let x = get_random_unsigned_range_with_rng(&mut rng, a, b);
+        assert!(a <= x);
+        assert!(x < b);
+    });
+
+    unsigned_pair_gen_var_7::<T>().test_properties(|(a, b)| {
+        let x = get_random_unsigned_inclusive_range_with_rng(&mut rng, a, b);
+        assert!(a <= x);
+        assert!(x <= b);
+    });
+}
+
+#[test]
+fn get_random_unsigneds_with_rng_properties() {
+    apply_fn_to_unsigneds!(get_random_unsigneds_with_rng_properties_helper);
+}
@@ -0,0 +1,25 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::random::{EXAMPLE_SEED, Seed};
+use rand::rngs::mock::StepRng;
+
+#[test]
+fn test_from_rng() {
+    assert_eq!(
+        Seed::from_rng(&mut EXAMPLE_SEED.get_rng()),
+        EXAMPLE_SEED.next()
+    );
+    assert_eq!(
+        Seed::from_rng(&mut StepRng::new(1, 1)),
+        Seed::from_bytes([
+            1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0,
+            0, 0, 0
+        ])
+    );
+}
@@ -0,0 +1,753 @@
+use alloc::format;
+use core::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+    str::FromStr,
+};
+use malachite_base::{
+    num::{
+        arithmetic::traits::{Abs, Ceiling, Floor, Parity, PowerOf2, Reciprocal},
+        basic::traits::{NegativeOne as _, One as _, Zero as _},
+        comparison::traits::PartialOrdAbs,
+        conversion::traits::{IsInteger, RoundingFrom},
+    },
+    rounding_modes::RoundingMode,
+};
+use malachite_bigint::{BigInt, BigUint};
+use malachite_nz::{integer::Integer, natural::Natural};
+use malachite_q::Rational;
+use num_traits::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, ConstOne, ConstZero, FromPrimitive, Inv, Num,
+    One, Pow, Signed, ToPrimitive, Zero, float::FloatCore,
+};
+use paste::paste;
+
+use crate::{ParseRatioError, once_box::OnceBox};
+
+/// An arbitrary-precision rational number with the API of `num_rational::BigRational`.
+///
+/// The value is always kept in lowest terms with a positive denominator, so unlike
+/// `num_rational::Ratio` there is no way to build an unreduced ratio; [`BigRational::new_raw`]
+/// reduces its arguments. Arithmetic only touches the [`Rational`]; the [`BigInt`] numerator and
+/// denominator are built the first time [`BigRational::numer`] or [`BigRational::denom`] is called,
+/// so that they can return references, as in num-rational.
+pub struct BigRational {
+    value: Rational,
+    // `value`'s numerator, carrying its sign, and its denominator, which is always positive. Empty
+    // until one of them is requested, and emptied whenever `value` changes.
+    parts: Parts,
+}
+
+type Parts = OnceBox<(BigInt, BigInt)>;
+
+impl Clone for BigRational {
+    // The cached numerator and denominator are not cloned; the clone builds its own if needed.
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::from_rational(self.value.clone())
+    }
+}
+
+// The numerator and denominator are determined by the value, so only the value is compared or hashed.
+
+impl PartialEq for BigRational {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for BigRational {}
+
+impl PartialOrd for BigRational {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigRational {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl Hash for BigRational {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl Default for BigRational {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl_from!(BigRational, Rational);
+
+impl From<BigInt> for BigRational {
+    #[inline]
+    fn from(value: BigInt) -> Self {
+        Self::from_integer(value)
+    }
+}
+
+impl From<(BigInt, BigInt)> for BigRational {
+    #[inline]
+    fn from((numer, denom): (BigInt, BigInt)) -> Self {
+        Self::new(numer, denom)
+    }
+}
+
+impl From<BigRational> for (BigInt, BigInt) {
+    #[inline]
+    fn from(value: BigRational) -> Self {
+        value.into_raw()
+    }
+}
+
+impl Neg for BigRational {
+    type Output = BigRational;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::from_rational(-self.value)
+    }
+}
+
+impl Neg for &BigRational {
+    type Output = BigRational;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        BigRational::from_rational(-&self.value)
+    }
+}
+
+impl Inv for BigRational {
+    type Output = BigRational;
+
+    #[inline]
+    fn inv(self) -> Self::Output {
+        self.into_recip()
+    }
+}
+
+impl Inv for &BigRational {
+    type Output = BigRational;
+
+    #[inline]
+    fn inv(self) -> Self::Output {
+        self.recip()
+    }
+}
+
+forward_binary_self!(Add, add);
+forward_binary_self!(Sub, sub);
+forward_binary_self!(Mul, mul);
+forward_binary_self!(Div, div);
+
+forward_binary_bigint!(Add, add);
+forward_binary_bigint!(Sub, sub);
+forward_binary_bigint!(Mul, mul);
+forward_binary_bigint!(Div, div);
+
+forward_assign_self!(AddAssign, add_assign);
+forward_assign_self!(SubAssign, sub_assign);
+forward_assign_self!(MulAssign, mul_assign);
+forward_assign_self!(DivAssign, div_assign);
+
+forward_assign_bigint!(AddAssign, add_assign);
+forward_assign_bigint!(SubAssign, sub_assign);
+forward_assign_bigint!(MulAssign, mul_assign);
+forward_assign_bigint!(DivAssign, div_assign);
+
+/// The remainder of truncating division, which has the same sign as `x`.
+fn rem_trunc(x: &Rational, y: &Rational) -> Rational {
+    let q = Integer::rounding_from(x / y, RoundingMode::Down).0;
+    x - Rational::from(q) * y
+}
+
+/// Raises `x` to the power `exp`. Powers of 0, 1 and -1 are computed exactly for any exponent;
+/// other bases panic if `exp` does not fit in a `u64`, since the result would be too large to
+/// represent.
+fn pow_natural(x: &Rational, exp: &Natural) -> Rational {
+    if let Ok(exp) = u64::try_from(exp) {
+        <&Rational as malachite_base::num::arithmetic::traits::Pow<u64>>::pow(x, exp)
+    } else if *x == 0u32 || *x == 1u32 {
+        x.clone()
+    } else if *x == -1i32 {
+        if exp.even() {
+            Rational::ONE
+        } else {
+            Rational::NEGATIVE_ONE
+        }
+    } else {
+        panic!("exponent too large")
+    }
+}
+
+/// Raises `x` to the power `exp`, with the same special cases as [`pow_natural`].
+///
+/// **Panics if `x` is zero and `exp` is negative.**
+fn pow_integer(x: &Rational, exp: &Integer) -> Rational {
+    if let Ok(exp) = i64::try_from(exp) {
+        <&Rational as malachite_base::num::arithmetic::traits::Pow<i64>>::pow(x, exp)
+    } else if *exp > 0u32 {
+        pow_natural(x, exp.unsigned_abs_ref())
+    } else {
+        pow_natural(&x.reciprocal(), exp.unsigned_abs_ref())
+    }
+}
+
+impl_binary_op!(
+    BigRational,
+    BigRational,
+    BigRational,
+    Rem,
+    rem,
+    |lhs: BigRational, rhs: BigRational| {
+        BigRational::from_rational(rem_trunc(&lhs.value, &rhs.value))
+    }
+);
+impl_binary_op!(
+    &BigRational,
+    BigRational,
+    BigRational,
+    Rem,
+    rem,
+    |lhs: &BigRational, rhs: BigRational| {
+        BigRational::from_rational(rem_trunc(&lhs.value, &rhs.value))
+    }
+);
+impl_binary_op!(
+    BigRational,
+    &BigRational,
+    BigRational,
+    Rem,
+    rem,
+    |lhs: BigRational, rhs: &BigRational| {
+        BigRational::from_rational(rem_trunc(&lhs.value, &rhs.value))
+    }
+);
+impl_binary_op!(
+    &BigRational,
+    &BigRational,
+    BigRational,
+    Rem,
+    rem,
+    |lhs: &BigRational, rhs: &BigRational| {
+        BigRational::from_rational(rem_trunc(&lhs.value, &rhs.value))
+    }
+);
+impl_binary_op!(
+    BigRational,
+    BigInt,
+    BigRational,
+    Rem,
+    rem,
+    |lhs: BigRational, rhs| { lhs % BigRational::from_integer(rhs) }
+);
+impl_binary_op!(
+    &BigRational,
+    BigInt,
+    BigRational,
+    Rem,
+    rem,
+    |lhs: &BigRational, rhs| { lhs % BigRational::from_integer(rhs) }
+);
+impl_binary_op!(
+    BigRational,
+    &BigInt,
+    BigRational,
+    Rem,
+    rem,
+    |lhs: BigRational, rhs: &BigInt| { lhs % BigRational::from_integer(rhs.clone()) }
+);
+impl_binary_op!(
+    &BigRational,
+    &BigInt,
+    BigRational,
+    Rem,
+    rem,
+    |lhs: &BigRational, rhs: &BigInt| { lhs % BigRational::from_integer(rhs.clone()) }
+);
+
+impl_assign_op!(
+    BigRational,
+    BigRational,
+    RemAssign,
+    rem_assign,
+    |lhs: &mut BigRational, rhs| { *lhs = &*lhs % rhs }
+);
+impl_assign_op!(
+    BigRational,
+    &BigRational,
+    RemAssign,
+    rem_assign,
+    |lhs: &mut BigRational, rhs| { *lhs = &*lhs % rhs }
+);
+impl_assign_op!(
+    BigRational,
+    BigInt,
+    RemAssign,
+    rem_assign,
+    |lhs: &mut BigRational, rhs| { *lhs = &*lhs % rhs }
+);
+impl_assign_op!(
+    BigRational,
+    &BigInt,
+    RemAssign,
+    rem_assign,
+    |lhs: &mut BigRational, rhs| { *lhs = &*lhs % rhs }
+);
+
+apply_to_unsigneds!(forward_pow_primitive{_, u64, Natural, pow_natural});
+apply_to_signeds!(forward_pow_primitive{_, i64, Integer, pow_integer});
+
+impl_binary_op!(
+    BigRational,
+    BigUint,
+    BigRational,
+    Pow,
+    pow,
+    |lhs: BigRational, rhs| { Pow::pow(lhs, &rhs) }
+);
+impl_binary_op!(
+    &BigRational,
+    BigUint,
+    BigRational,
+    Pow,
+    pow,
+    |lhs: &BigRational, rhs| { Pow::pow(lhs, &rhs) }
+);
+impl_binary_op!(
+    BigRational,
+    &BigUint,
+    BigRational,
+    Pow,
+    pow,
+    |lhs: BigRational, rhs| { Pow::pow(&lhs, rhs) }
+);
+impl_binary_op!(
+    &BigRational,
+    &BigUint,
+    BigRational,
+    Pow,
+    pow,
+    |lhs: &BigRational, rhs: &BigUint| {
+        BigRational::from_rational(pow_natural(&lhs.value, rhs.into()))
+    }
+);
+impl_binary_op!(
+    BigRational,
+    BigInt,
+    BigRational,
+    Pow,
+    pow,
+    |lhs: BigRational, rhs| { Pow::pow(lhs, &rhs) }
+);
+impl_binary_op!(
+    &BigRational,
+    BigInt,
+    BigRational,
+    Pow,
+    pow,
+    |lhs: &BigRational, rhs| { Pow::pow(lhs, &rhs) }
+);
+impl_binary_op!(
+    BigRational,
+    &BigInt,
+    BigRational,
+    Pow,
+    pow,
+    |lhs: BigRational, rhs| { Pow::pow(&lhs, rhs) }
+);
+impl_binary_op!(
+    &BigRational,
+    &BigInt,
+    BigRational,
+    Pow,
+    pow,
+    |lhs: &BigRational, rhs: &BigInt| {
+        BigRational::from_rational(pow_integer(&lhs.value, rhs.into()))
+    }
+);
+
+impl<T> Sum<T> for BigRational
+where
+    BigRational: Add<T, Output = BigRational>,
+{
+    #[inline]
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(Zero::zero(), <BigRational>::add)
+    }
+}
+
+impl<T> Product<T> for BigRational
+where
+    BigRational: Mul<T, Output = BigRational>,
+{
+    #[inline]
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(One::one(), <BigRational>::mul)
+    }
+}
+
+impl_fmt_ratio!(Display, "", "{}", "{:#}");
+impl_fmt_ratio!(Octal, "0o", "{:o}", "{:#o}");
+impl_fmt_ratio!(Binary, "0b", "{:b}", "{:#b}");
+impl_fmt_ratio!(LowerHex, "0x", "{:x}", "{:#x}");
+impl_fmt_ratio!(UpperHex, "0x", "{:X}", "{:#X}");
+
+impl core::fmt::Debug for BigRational {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Ratio")
+            .field("numer", &self.numer())
+            .field("denom", &self.denom())
+            .finish()
+    }
+}
+
+impl CheckedAdd for BigRational {
+    #[inline]
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        Some(self.add(v))
+    }
+}
+
+impl CheckedSub for BigRational {
+    #[inline]
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        Some(self.sub(v))
+    }
+}
+
+impl CheckedMul for BigRational {
+    #[inline]
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        Some(self.mul(v))
+    }
+}
+
+impl CheckedDiv for BigRational {
+    #[inline]
+    fn checked_div(&self, v: &Self) -> Option<Self> {
+        (!v.is_zero()).then(|| self.div(v))
+    }
+}
+
+impl ToPrimitive for BigRational {
+    apply_to_signeds!(impl_to_primitive_fn_try_into{_});
+    apply_to_unsigneds!(impl_to_primitive_fn_try_into{_});
+    impl_to_primitive_fn_float!(f64);
+}
+
+impl FromPrimitive for BigRational {
+    apply_to_signeds!(impl_from_primitive_fn_infallible{_});
+    apply_to_unsigneds!(impl_from_primitive_fn_infallible{_});
+
+    #[inline]
+    fn from_f32(n: f32) -> Option<Self> {
+        Self::from_float(n)
+    }
+
+    #[inline]
+    fn from_f64(n: f64) -> Option<Self> {
+        Self::from_float(n)
+    }
+}
+
+impl Zero for BigRational {
+    #[inline]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.value == 0u32
+    }
+}
+
+impl ConstZero for BigRational {
+    const ZERO: Self = Self::ZERO;
+}
+
+impl One for BigRational {
+    #[inline]
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        self.value == 1u32
+    }
+}
+
+impl ConstOne for BigRational {
+    const ONE: Self = Self::ONE;
+}
+
+impl Signed for BigRational {
+    #[inline]
+    fn abs(&self) -> Self {
+        Self::from_rational((&self.value).abs())
+    }
+
+    #[inline]
+    fn abs_sub(&self, other: &Self) -> Self {
+        if self <= other {
+            Self::zero()
+        } else {
+            self - other
+        }
+    }
+
+    #[inline]
+    fn signum(&self) -> Self {
+        match self.value.partial_cmp(&0u32) {
+            Some(Ordering::Less) => -Self::one(),
+            Some(Ordering::Greater) => Self::one(),
+            _ => Self::zero(),
+        }
+    }
+
+    #[inline]
+    fn is_positive(&self) -> bool {
+        self.value > 0u32
+    }
+
+    #[inline]
+    fn is_negative(&self) -> bool {
+        self.value < 0u32
+    }
+}
+
+impl Num for BigRational {
+    type FromStrRadixErr = ParseRatioError;
+
+    /// Parses `numer/denom` where the numbers are in base `radix`.
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        let (numer, denom) = s.split_once('/').ok_or_else(ParseRatioError::parse_error)?;
+        let numer =
+            BigInt::from_str_radix(numer, radix).map_err(|_| ParseRatioError::parse_error())?;
+        let denom =
+            BigInt::from_str_radix(denom, radix).map_err(|_| ParseRatioError::parse_error())?;
+        if denom.is_zero() {
+            Err(ParseRatioError::zero_denominator())
+        } else {
+            Ok(Self::new(numer, denom))
+        }
+    }
+}
+
+impl FromStr for BigRational {
+    type Err = ParseRatioError;
+
+    /// Parses `numer/denom` or just `numer`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (numer, denom) = s.split_once('/').unwrap_or((s, "1"));
+        let numer = BigInt::from_str(numer).map_err(|_| ParseRatioError::parse_error())?;
+        let denom = BigInt::from_str(denom).map_err(|_| ParseRatioError::parse_error())?;
+        if denom.is_zero() {
+            Err(ParseRatioError::zero_denominator())
+        } else {
+            Ok(Self::new(numer, denom))
+        }
+    }
+}
+
+impl BigRational {
+    /// A constant `BigRational` 0/1.
+    // Each use gets its own empty cache, which is what we want.
+    #[allow(clippy::declare_interior_mutable_const)]
+    pub const ZERO: Self = Self::from_rational(Rational::ZERO);
+
+    /// A constant `BigRational` 1/1.
+    #[allow(clippy::declare_interior_mutable_const)]
+    pub const ONE: Self = Self::from_rational(Rational::ONE);
+
+    /// Creates a new `BigRational`, reduced to lowest terms.
+    ///
+    /// **Panics if `denom` is zero.**
+    #[inline]
+    pub fn new(numer: BigInt, denom: BigInt) -> Self {
+        assert!(!denom.is_zero(), "denominator == 0");
+        Self::from_rational(Rational::from_integers(numer.into(), denom.into()))
+    }
+
+    /// Creates a new `BigRational`. Unlike `num_rational::Ratio::new_raw`, the result is still
+    /// reduced to lowest terms, since a `BigRational` cannot hold an unreduced ratio.
+    ///
+    /// **Panics if `denom` is zero.**
+    #[inline]
+    pub fn new_raw(numer: BigInt, denom: BigInt) -> Self {
+        Self::new(numer, denom)
+    }
+
+    /// Creates a `BigRational` representing the integer `t`.
+    #[inline]
+    pub fn from_integer(t: BigInt) -> Self {
+        Self::from_rational(Rational::from(Integer::from(t)))
+    }
+
+    /// Gets an immutable reference to the numerator. It carries the sign of the `BigRational`.
+    ///
+    /// The first call to this function or to [`BigRational::denom`] copies the numerator and
+    /// denominator out of the underlying [`Rational`].
+    #[inline]
+    pub fn numer(&self) -> &BigInt {
+        &self.parts().0
+    }
+
+    /// Gets an immutable reference to the denominator, which is always positive.
+    ///
+    /// The first call to this function or to [`BigRational::numer`] copies the numerator and
+    /// denominator out of the underlying [`Rational`].
+    #[inline]
+    pub fn denom(&self) -> &BigInt {
+        &self.parts().1
+    }
+
+    /// Deconstructs the `BigRational` into its numerator and denominator. Nothing is copied.
+    pub fn into_raw(self) -> (BigInt, BigInt) {
+        if let Some(parts) = self.parts.into_inner() {
+            return parts;
+        }
+        let sign = self.value >= 0u32;
+        let (numer, denom) = self.value.into_numerator_and_denominator();
+        (
+            Integer::from_sign_and_abs(sign, numer).into(),
+            Integer::from(denom).into(),
+        )
+    }
+
+    /// Converts to an integer, rounding towards zero.
+    #[inline]
+    pub fn to_integer(&self) -> BigInt {
+        self.truncate().into()
+    }
+
+    /// Returns true if the rational number is an integer (denominator is 1).
+    #[inline]
+    pub fn is_integer(&self) -> bool {
+        (&self.value).is_integer()
+    }
+
+    /// Returns a reduced copy of self. A `BigRational` is always reduced, so this is a clone.
+    #[inline]
+    pub fn reduced(&self) -> Self {
+        self.clone()
+    }
+
+    /// Returns the reciprocal.
+    ///
+    /// **Panics if the `BigRational` is zero.**
+    #[inline]
+    pub fn recip(&self) -> Self {
+        assert!(!self.is_zero(), "division by zero");
+        Self::from_rational((&self.value).reciprocal())
+    }
+
+    #[inline]
+    fn into_recip(self) -> Self {
+        assert!(!self.is_zero(), "division by zero");
+        Self::from_rational(self.value.reciprocal())
+    }
+
+    /// Rounds towards minus infinity.
+    #[inline]
+    pub fn floor(&self) -> Self {
+        Self::from_rational(Rational::from((&self.value).floor()))
+    }
+
+    /// Rounds towards plus infinity.
+    #[inline]
+    pub fn ceil(&self) -> Self {
+        Self::from_rational(Rational::from((&self.value).ceiling()))
+    }
+
+    /// Rounds to the nearest integer. Rounds half-way cases away from zero.
+    #[inline]
+    pub fn round(&self) -> Self {
+        let rm = if *self.value.denominator_ref() == 2u32 {
+            RoundingMode::Up
+        } else {
+            RoundingMode::Nearest
+        };
+        Self::from_rational(Rational::from(Integer::rounding_from(&self.value, rm).0))
+    }
+
+    /// Rounds towards zero.
+    #[inline]
+    pub fn trunc(&self) -> Self {
+        Self::from_rational(Rational::from(self.truncate()))
+    }
+
+    /// Returns the fractional part of a number, with division rounded towards zero.
+    ///
+    /// Satisfies `self == self.trunc() + self.fract()`.
+    #[inline]
+    pub fn fract(&self) -> Self {
+        Self::from_rational(&self.value - Rational::from(self.truncate()))
+    }
+
+    /// Raises the `BigRational` to the power of an exponent.
+    #[inline]
+    pub fn pow(&self, expon: i32) -> Self {
+        Pow::pow(self, expon)
+    }
+
+    /// Converts a float into a rational number. The conversion is exact.
+    pub fn from_float<T: FloatCore>(f: T) -> Option<Self> {
+        if !f.is_finite() {
+            return None;
+        }
+        let (mantissa, exponent, sign) = f.integer_decode();
+        let abs = Rational::from(mantissa) << exponent;
+        Some(Self::from_rational(if sign < 0 { -abs } else { abs }))
+    }
+
+    #[inline]
+    const fn from_rational(value: Rational) -> Self {
+        Self {
+            value,
+            parts: Parts::new(),
+        }
+    }
+
+    fn parts(&self) -> &(BigInt, BigInt) {
+        self.parts.get_or_init(|| {
+            (
+                Integer::from_sign_and_abs_ref(self.value >= 0u32, self.value.numerator_ref())
+                    .into(),
+                Integer::from(self.value.denominator_ref()).into(),
+            )
+        })
+    }
+
+    // Applies `f` to the value, then drops the numerator and denominator, which may be stale.
+    fn update(&mut self, f: impl FnOnce(&mut Rational)) {
+        f(&mut self.value);
+        self.parts.take();
+    }
+
+    #[inline]
+    fn truncate(&self) -> Integer {
+        Integer::rounding_from(&self.value, RoundingMode::Down).0
+    }
+}
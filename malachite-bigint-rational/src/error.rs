@@ -0,0 +1,44 @@
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ParseRatioError {
+    kind: RatioErrorKind,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum RatioErrorKind {
+    ParseError,
+    ZeroDenominator,
+}
+
+impl ParseRatioError {
+    fn __description(&self) -> &str {
+        use RatioErrorKind::*;
+        match self.kind {
+            ParseError => "failed to parse integer",
+            ZeroDenominator => "zero value denominator",
+        }
+    }
+
+    pub(crate) fn parse_error() -> Self {
+        ParseRatioError {
+            kind: RatioErrorKind::ParseError,
+        }
+    }
+
+    pub(crate) fn zero_denominator() -> Self {
+        ParseRatioError {
+            kind: RatioErrorKind::ZeroDenominator,
+        }
+    }
+}
+
+impl core::fmt::Display for ParseRatioError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.__description().fmt(f)
+    }
+}
+
+impl core::error::Error for ParseRatioError {
+    fn description(&self) -> &str {
+        self.__description()
+    }
+}
@@ -0,0 +1,13 @@
+#![no_std]
+extern crate alloc;
+
+#[macro_use]
+mod macros;
+mod bigrational;
+mod error;
+#[cfg(feature = "num-rational")]
+mod num_rational_conversion;
+mod once_box;
+
+pub use bigrational::BigRational;
+pub use error::ParseRatioError;
@@ -0,0 +1,331 @@
+macro_rules! apply_to_unsigneds {
+    ($m:tt{_$(,$args:tt)*}) => {
+        $m!(u8 $(,$args)*);
+        $m!(u16 $(,$args)*);
+        $m!(u32 $(,$args)*);
+        $m!(u64 $(,$args)*);
+        $m!(usize $(,$args)*);
+        $m!(u128 $(,$args)*);
+    };
+}
+
+macro_rules! apply_to_signeds {
+    ($m:tt{_$(,$args:tt)*}) => {
+        $m!(i8 $(,$args)*);
+        $m!(i16 $(,$args)*);
+        $m!(i32 $(,$args)*);
+        $m!(i64 $(,$args)*);
+        $m!(isize $(,$args)*);
+        $m!(i128 $(,$args)*);
+    };
+}
+
+macro_rules! impl_binary_op {
+    ($lhs:ty, $rhs:ty, $output:ty, $trait:tt, $fn:ident, $expr:expr) => {
+        impl $trait<$rhs> for $lhs {
+            type Output = $output;
+
+            #[inline]
+            fn $fn(self, rhs: $rhs) -> Self::Output {
+                $expr(self, rhs)
+            }
+        }
+    };
+}
+
+macro_rules! impl_assign_op {
+    ($lhs:ty, $rhs:ty, $trait:tt, $fn:ident, $expr:expr) => {
+        impl $trait<$rhs> for $lhs {
+            #[inline]
+            fn $fn(&mut self, rhs: $rhs) {
+                $expr(self, rhs)
+            }
+        }
+    };
+}
+
+macro_rules! forward_binary_self {
+    ($trait:tt, $fn:ident) => {
+        impl_binary_op!(
+            BigRational,
+            BigRational,
+            BigRational,
+            $trait,
+            $fn,
+            |lhs: BigRational, rhs: BigRational| {
+                BigRational::from_rational($trait::$fn(lhs.value, rhs.value))
+            }
+        );
+        impl_binary_op!(
+            &BigRational,
+            BigRational,
+            BigRational,
+            $trait,
+            $fn,
+            |lhs: &BigRational, rhs: BigRational| {
+                BigRational::from_rational($trait::$fn(&lhs.value, rhs.value))
+            }
+        );
+        impl_binary_op!(
+            BigRational,
+            &BigRational,
+            BigRational,
+            $trait,
+            $fn,
+            |lhs: BigRational, rhs: &BigRational| {
+                BigRational::from_rational($trait::$fn(lhs.value, &rhs.value))
+            }
+        );
+        impl_binary_op!(
+            &BigRational,
+            &BigRational,
+            BigRational,
+            $trait,
+            $fn,
+            |lhs: &BigRational, rhs: &BigRational| {
+                BigRational::from_rational($trait::$fn(&lhs.value, &rhs.value))
+            }
+        );
+    };
+}
+
+macro_rules! forward_binary_bigint {
+    ($trait:tt, $fn:ident) => {
+        impl_binary_op!(
+            BigRational,
+            BigInt,
+            BigRational,
+            $trait,
+            $fn,
+            |lhs: BigRational, rhs: BigInt| {
+                BigRational::from_rational($trait::$fn(
+                    lhs.value,
+                    Rational::from(Integer::from(rhs)),
+                ))
+            }
+        );
+        impl_binary_op!(
+            &BigRational,
+            BigInt,
+            BigRational,
+            $trait,
+            $fn,
+            |lhs: &BigRational, rhs: BigInt| {
+                BigRational::from_rational($trait::$fn(
+                    &lhs.value,
+                    Rational::from(Integer::from(rhs)),
+                ))
+            }
+        );
+        impl_binary_op!(
+            BigRational,
+            &BigInt,
+            BigRational,
+            $trait,
+            $fn,
+            |lhs: BigRational, rhs: &BigInt| {
+                BigRational::from_rational($trait::$fn(
+                    lhs.value,
+                    Rational::from(<&Integer>::from(rhs)),
+                ))
+            }
+        );
+        impl_binary_op!(
+            &BigRational,
+            &BigInt,
+            BigRational,
+            $trait,
+            $fn,
+            |lhs: &BigRational, rhs: &BigInt| {
+                BigRational::from_rational($trait::$fn(
+                    &lhs.value,
+                    Rational::from(<&Integer>::from(rhs)),
+                ))
+            }
+        );
+    };
+}
+
+macro_rules! forward_assign_self {
+    ($trait:tt, $fn:ident) => {
+        impl_assign_op!(
+            BigRational,
+            BigRational,
+            $trait,
+            $fn,
+            |lhs: &mut BigRational, rhs: BigRational| { lhs.update(|x| $trait::$fn(x, rhs.value)) }
+        );
+        impl_assign_op!(
+            BigRational,
+            &BigRational,
+            $trait,
+            $fn,
+            |lhs: &mut BigRational, rhs: &BigRational| {
+                lhs.update(|x| $trait::$fn(x, &rhs.value))
+            }
+        );
+    };
+}
+
+macro_rules! forward_assign_bigint {
+    ($trait:tt, $fn:ident) => {
+        impl_assign_op!(
+            BigRational,
+            BigInt,
+            $trait,
+            $fn,
+            |lhs: &mut BigRational, rhs: BigInt| {
+                lhs.update(|x| $trait::$fn(x, Rational::from(Integer::from(rhs))))
+            }
+        );
+        impl_assign_op!(
+            BigRational,
+            &BigInt,
+            $trait,
+            $fn,
+            |lhs: &mut BigRational, rhs: &BigInt| {
+                lhs.update(|x| $trait::$fn(x, Rational::from(<&Integer>::from(rhs))))
+            }
+        );
+    };
+}
+
+macro_rules! forward_pow_primitive {
+    ($t:ty, $exp:ty, $big:ty, $pow_big:ident) => {
+        impl_binary_op!(
+            BigRational,
+            $t,
+            BigRational,
+            Pow,
+            pow,
+            |lhs: BigRational, rhs: $t| {
+                BigRational::from_rational(match <$exp>::try_from(rhs) {
+                    Ok(exp) => <_ as malachite_base::num::arithmetic::traits::Pow<$exp>>::pow(
+                        lhs.value, exp,
+                    ),
+                    Err(_) => $pow_big(&lhs.value, &<$big>::from(rhs)),
+                })
+            }
+        );
+        impl_binary_op!(
+            &BigRational,
+            $t,
+            BigRational,
+            Pow,
+            pow,
+            |lhs: &BigRational, rhs: $t| {
+                BigRational::from_rational(match <$exp>::try_from(rhs) {
+                    Ok(exp) => <_ as malachite_base::num::arithmetic::traits::Pow<$exp>>::pow(
+                        &lhs.value, exp,
+                    ),
+                    Err(_) => $pow_big(&lhs.value, &<$big>::from(rhs)),
+                })
+            }
+        );
+        impl_binary_op!(
+            BigRational,
+            &$t,
+            BigRational,
+            Pow,
+            pow,
+            |lhs: BigRational, rhs: &$t| { Pow::pow(lhs, *rhs) }
+        );
+        impl_binary_op!(
+            &BigRational,
+            &$t,
+            BigRational,
+            Pow,
+            pow,
+            |lhs: &BigRational, rhs: &$t| { Pow::pow(lhs, *rhs) }
+        );
+    };
+}
+
+macro_rules! impl_from_primitive_fn_infallible {
+    ($t:ty) => {
+        paste! {
+            #[inline]
+            fn [<from_ $t>](n: $t) -> Option<Self> {
+                Some(Self::from_integer(BigInt::from(n)))
+            }
+        }
+    };
+}
+
+macro_rules! impl_to_primitive_fn_try_into {
+    ($t:ty) => {
+        paste! {
+            #[inline]
+            fn [<to_ $t>](&self) -> Option<$t> {
+                $t::try_from(&self.truncate()).ok()
+            }
+        }
+    };
+}
+
+macro_rules! impl_to_primitive_fn_float {
+    ($t:ty) => {
+        paste! {
+            #[inline]
+            fn [<to_ $t>](&self) -> Option<$t> {
+                // values that are at least halfway between $t::MAX and the next power of 2
+                // overflow to infinity, as in IEEE 754 round-to-nearest
+                let overflows = || {
+                    let max_exp = $t::MAX_EXP as u64;
+                    let half_ulp = max_exp - u64::from($t::MANTISSA_DIGITS) - 1;
+                    let threshold = Natural::power_of_2(max_exp) - Natural::power_of_2(half_ulp);
+                    self.value.ge_abs(&threshold)
+                };
+                Some(match $t::rounding_from(&self.value, RoundingMode::Nearest) {
+                    // returned value is $t::MAX but still less than the original
+                    (val, Ordering::Less) if val == $t::MAX && overflows() => $t::INFINITY,
+                    // returned value is $t::MIN but still greater than the original
+                    (val, Ordering::Greater) if val == $t::MIN && overflows() => $t::NEG_INFINITY,
+                    (val, _) => val,
+                })
+            }
+        }
+    };
+}
+
+macro_rules! impl_fmt_ratio {
+    ($trait:ident, $prefix:literal, $fmt:literal, $fmt_alt:literal) => {
+        impl core::fmt::$trait for BigRational {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let numer = self.value.numerator_ref();
+                let denom = self.value.denominator_ref();
+                let abs = if self.is_integer() {
+                    format!($fmt, numer)
+                } else if f.alternate() {
+                    format!(concat!($fmt, "/", $fmt_alt), numer, denom)
+                } else {
+                    format!(concat!($fmt, "/", $fmt), numer, denom)
+                };
+                f.pad_integral(!self.is_negative(), $prefix, &abs)
+            }
+        }
+    };
+}
+
+macro_rules! impl_from {
+    ($wrapper:ty, $inner: ty) => {
+        impl From<$inner> for $wrapper {
+            #[inline]
+            fn from(value: $inner) -> Self {
+                Self::from_rational(value)
+            }
+        }
+        impl From<$wrapper> for $inner {
+            #[inline]
+            fn from(value: $wrapper) -> Self {
+                value.value
+            }
+        }
+        impl<'a> From<&'a $wrapper> for &'a $inner {
+            #[inline]
+            fn from(value: &'a $wrapper) -> Self {
+                &value.value
+            }
+        }
+    };
+}
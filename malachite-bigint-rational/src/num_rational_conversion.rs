@@ -0,0 +1,36 @@
+use crate::BigRational;
+use malachite_bigint::BigInt;
+
+impl From<num_rational::BigRational> for BigRational {
+    #[inline]
+    fn from(value: num_rational::BigRational) -> Self {
+        let (numer, denom) = value.into_raw();
+        Self::new(numer.into(), denom.into())
+    }
+}
+
+impl From<BigRational> for num_rational::BigRational {
+    #[inline]
+    fn from(value: BigRational) -> Self {
+        let (numer, denom): (BigInt, BigInt) = value.into_raw();
+        // a `BigRational` is always in lowest terms with a positive denominator
+        Self::new_raw(numer.into(), denom.into())
+    }
+}
+
+#[test]
+fn num_rational_conversion_test() {
+    let tester = |val: BigRational| {
+        let numval = num_rational::BigRational::from(val.clone());
+        let val2 = BigRational::from(numval);
+        assert_eq!(val, val2);
+    };
+
+    tester(BigRational::new(50723.into(), 3.into()));
+    tester(BigRational::new(BigInt::from(-52321), 8.into()));
+    tester(BigRational::new(0.into(), 5.into()));
+    tester(BigRational::new(
+        BigInt::from(u64::MAX).pow(1000u32),
+        BigInt::from(i64::MIN).pow(999u32),
+    ));
+}
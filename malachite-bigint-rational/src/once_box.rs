@@ -0,0 +1,76 @@
+use alloc::boxed::Box;
+use core::{
+    marker::PhantomData,
+    ptr,
+    sync::atomic::{
+        AtomicPtr,
+        Ordering::{AcqRel, Acquire},
+    },
+};
+
+/// A cell that is written to at most once through a shared reference, and that is `Sync` with or
+/// without `std`.
+///
+/// Instead of blocking, threads that initialize the cell at the same time race: each builds a
+/// value, the first to finish stores it, and the others drop theirs.
+pub(crate) struct OnceBox<T> {
+    ptr: AtomicPtr<T>,
+    _marker: PhantomData<Box<T>>,
+}
+
+// SAFETY: the stored value is shared between threads, so it must be `Sync`, and it may be dropped
+// by a thread other than the one that built it, so it must be `Send`. This matches `OnceLock`.
+unsafe impl<T: Send + Sync> Sync for OnceBox<T> {}
+
+impl<T> OnceBox<T> {
+    #[inline]
+    pub(crate) const fn new() -> Self {
+        Self {
+            ptr: AtomicPtr::new(ptr::null_mut()),
+            _marker: PhantomData,
+        }
+    }
+
+    pub(crate) fn get_or_init(&self, f: impl FnOnce() -> T) -> &T {
+        let mut p = self.ptr.load(Acquire);
+        if p.is_null() {
+            let new = Box::into_raw(Box::new(f()));
+            p = match self
+                .ptr
+                .compare_exchange(ptr::null_mut(), new, AcqRel, Acquire)
+            {
+                Ok(_) => new,
+                Err(existing) => {
+                    // SAFETY: `new` came from `Box::into_raw` and was never shared.
+                    drop(unsafe { Box::from_raw(new) });
+                    existing
+                }
+            };
+        }
+        // SAFETY: `p` is non-null, so it was stored by `get_or_init`, and it is only freed through
+        // `&mut self`, which can't coexist with the returned reference.
+        unsafe { &*p }
+    }
+
+    pub(crate) fn take(&mut self) -> Option<T> {
+        let p = core::mem::replace(self.ptr.get_mut(), ptr::null_mut());
+        if p.is_null() {
+            None
+        } else {
+            // SAFETY: `p` came from `Box::into_raw`, and the cell no longer refers to it.
+            Some(*unsafe { Box::from_raw(p) })
+        }
+    }
+
+    #[inline]
+    pub(crate) fn into_inner(mut self) -> Option<T> {
+        self.take()
+    }
+}
+
+impl<T> Drop for OnceBox<T> {
+    #[inline]
+    fn drop(&mut self) {
+        self.take();
+    }
+}
@@ -0,0 +1,559 @@
+// Runs malachite-bigint-rational and num-rational side by side on the same inputs and checks that
+// they agree.
+
+use core::str::FromStr;
+use malachite_bigint::BigInt;
+use malachite_bigint_rational::BigRational;
+use num_traits::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, FromPrimitive, Inv, Num, One, Pow, Signed,
+    ToPrimitive, Zero,
+};
+use std::fmt::Debug;
+
+const RATIO_STRS: &[&str] = &[
+    "0",
+    "1",
+    "2",
+    "7",
+    "1/2",
+    "3/2",
+    "5/2",
+    "1/3",
+    "2/3",
+    "4/3",
+    "22/7",
+    "355/113",
+    "255/256",
+    "65537/65536",
+    "4294967297/4294967296",
+    "18446744073709551615",
+    "18446744073709551617/18446744073709551616",
+    "1/340282366920938463463374607431768211456",
+    "340282366920938463463374607431768211457/3",
+    "123456789012345678901234567890/987654321098765432109876543210",
+    "179769313486231570814527423731704356798070567525844996598917476803157260780028538760589558632\
+     766878171540458953514382464234321326889464182768467546703537516986049910576551282076245490090\
+     389328944075868508455133942304583236903222948165808559332123348274797826204144723168738177180\
+     919299881250404026184124858368",
+    "179769313486231580793728971405303415079934132710037826936173778980444968292764750946649017977\
+     587207096330286416692887910946555547851940402630657488671505820681908902000708383676273854845\
+     817711531764475730270069855571366959622842914819860834936475292719074168444365510704342711559\
+     699508093042880177904174497791",
+    "340282356779733661637539395458142568448",
+    "340282366920938463463374607431768211455",
+];
+
+fn ratio_strs() -> Vec<String> {
+    let mut strs = Vec::new();
+    for &s in RATIO_STRS {
+        if s != "0" {
+            strs.push(format!("-{s}"));
+        }
+        strs.push(s.to_string());
+    }
+    strs
+}
+
+fn rationals() -> Vec<(BigRational, num_rational::BigRational)> {
+    ratio_strs()
+        .iter()
+        .map(|s| {
+            (
+                BigRational::from_str(s).unwrap(),
+                num_rational::BigRational::from_str(s).unwrap(),
+            )
+        })
+        .collect()
+}
+
+fn small_rationals() -> Vec<(BigRational, num_rational::BigRational)> {
+    rationals()
+        .into_iter()
+        .filter(|(x, _)| x.numer().bits() <= 200)
+        .collect()
+}
+
+// Compares two values of different types through their `Debug` representations; for the ratio
+// types this shows the numerator and denominator.
+#[track_caller]
+fn same<A: Debug, B: Debug>(a: A, b: B) {
+    assert_eq!(format!("{a:?}"), format!("{b:?}"));
+}
+
+#[test]
+fn test_constants() {
+    same(BigRational::zero(), num_rational::BigRational::zero());
+    same(BigRational::one(), num_rational::BigRational::one());
+    same(BigRational::ZERO, num_rational::BigRational::zero());
+    same(BigRational::ONE, num_rational::BigRational::one());
+    same(BigRational::default(), num_rational::BigRational::default());
+}
+
+#[test]
+fn test_construction() {
+    for (n, d) in [(6, 4), (-6, 4), (6, -4), (-6, -4), (0, -3), (7, 1), (1, 7)] {
+        let a = BigRational::new(BigInt::from(n), BigInt::from(d));
+        let na = num_rational::BigRational::new(n.into(), d.into());
+        same(&a, &na);
+        same(a.numer(), na.numer());
+        same(a.denom(), na.denom());
+        same(a.clone().into_raw(), na.clone().into_raw());
+        same(a.reduced(), na.reduced());
+        same(
+            BigRational::from((BigInt::from(n), BigInt::from(d))),
+            num_rational::BigRational::from((n.into(), d.into())),
+        );
+        same(
+            <(BigInt, BigInt)>::from(a.clone()),
+            <(num_bigint::BigInt, num_bigint::BigInt)>::from(na.clone()),
+        );
+    }
+    same(
+        BigRational::from_integer(BigInt::from(-5)),
+        num_rational::BigRational::from_integer((-5).into()),
+    );
+    same(
+        BigRational::from(BigInt::from(12)),
+        num_rational::BigRational::from(num_bigint::BigInt::from(12)),
+    );
+}
+
+#[test]
+#[should_panic(expected = "denominator == 0")]
+fn new_zero_denominator_fail() {
+    BigRational::new(BigInt::from(1), BigInt::from(0));
+}
+
+#[test]
+fn test_new_raw() {
+    // A `BigRational` is always reduced, so `new_raw` agrees with num-rational's `new`
+    for (n, d) in [(6, 4), (-6, 4), (6, -4), (0, -3), (7, 1)] {
+        same(
+            BigRational::new_raw(BigInt::from(n), BigInt::from(d)),
+            num_rational::BigRational::new(n.into(), d.into()),
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "denominator == 0")]
+fn new_raw_zero_denominator_fail() {
+    BigRational::new_raw(BigInt::from(1), BigInt::from(0));
+}
+
+#[test]
+fn test_numer_and_denom_refs() {
+    for (a, na) in &rationals() {
+        let numer: &BigInt = a.numer();
+        let denom: &BigInt = a.denom();
+        same(numer, na.numer());
+        same(denom, na.denom());
+        assert!(*a.denom() > BigInt::from(0));
+    }
+    // The numerator and denominator are kept up to date by the assignment operators
+    let mut a = BigRational::new(BigInt::from(1), BigInt::from(2));
+    a += BigRational::new(BigInt::from(1), BigInt::from(3));
+    assert_eq!(*a.numer(), BigInt::from(5));
+    assert_eq!(*a.denom(), BigInt::from(6));
+    a *= BigInt::from(-6);
+    assert_eq!(*a.numer(), BigInt::from(-5));
+    assert_eq!(*a.denom(), BigInt::from(1));
+    a /= BigRational::new(BigInt::from(10), BigInt::from(3));
+    assert_eq!(*a.numer(), BigInt::from(-3));
+    assert_eq!(*a.denom(), BigInt::from(2));
+    a %= BigInt::from(1);
+    assert_eq!(*a.numer(), BigInt::from(-1));
+    assert_eq!(*a.denom(), BigInt::from(2));
+
+    // into_raw and clone give the same results whether or not the parts have been requested
+    for (a, na) in &rationals() {
+        same(a.clone().into_raw(), na.clone().into_raw());
+        let b = a.clone();
+        let _ = b.numer();
+        same(b.clone().numer(), na.numer());
+        same(b.into_raw(), na.clone().into_raw());
+    }
+}
+
+#[test]
+fn test_send_and_sync() {
+    fn assert_send_and_sync<T: Send + Sync>() {}
+    assert_send_and_sync::<BigRational>();
+
+    // Threads that request the parts at the same time all see the same values
+    for (a, na) in &rationals() {
+        std::thread::scope(|s| {
+            for _ in 0..4 {
+                s.spawn(|| {
+                    same(a.numer(), na.numer());
+                    same(a.denom(), na.denom());
+                });
+            }
+        });
+    }
+}
+
+#[test]
+#[should_panic(expected = "division by zero")]
+fn recip_zero_fail() {
+    BigRational::zero().recip();
+}
+
+#[test]
+fn test_arithmetic() {
+    let xs = small_rationals();
+    for (a, na) in &xs {
+        same(-a, -na);
+        same(-a.clone(), -na.clone());
+        same(a.abs(), na.abs());
+        same(a.signum(), na.signum());
+        same(a.is_positive(), na.is_positive());
+        same(a.is_negative(), na.is_negative());
+        same(a.is_zero(), na.is_zero());
+        same(a.is_one(), na.is_one());
+        same(a.is_integer(), na.is_integer());
+        same(a.to_integer(), na.to_integer());
+        same(a.floor(), na.floor());
+        same(a.ceil(), na.ceil());
+        same(a.round(), na.round());
+        same(a.trunc(), na.trunc());
+        same(a.fract(), na.fract());
+        if !a.is_zero() {
+            same(a.recip(), na.recip());
+            same(a.inv(), na.inv());
+            same(a.clone().inv(), na.clone().inv());
+        }
+        for (b, nb) in &xs {
+            same(a + b, na + nb);
+            same(a - b, na - nb);
+            same(a * b, na * nb);
+            same(a.clone() + b.clone(), na.clone() + nb.clone());
+            same(a.clone() - b, na.clone() - nb);
+            same(a * b.clone(), na * nb.clone());
+            same(a.checked_add(b), na.checked_add(nb));
+            same(a.checked_sub(b), na.checked_sub(nb));
+            same(a.checked_mul(b), na.checked_mul(nb));
+            same(a.checked_div(b), na.checked_div(nb));
+            same(a.abs_sub(b), na.abs_sub(nb));
+            same(a.cmp(b), na.cmp(nb));
+            same(a == b, na == nb);
+            if !b.is_zero() {
+                same(a / b, na / nb);
+                same(a % b, na % nb);
+                same(a.clone() / b.clone(), na.clone() / nb.clone());
+                same(a.clone() % b.clone(), na.clone() % nb.clone());
+            }
+
+            let mut c = a.clone();
+            let mut nc = na.clone();
+            c += b;
+            nc += nb;
+            same(&c, &nc);
+            c -= b.clone();
+            nc -= nb.clone();
+            same(&c, &nc);
+            c *= b;
+            nc *= nb;
+            same(&c, &nc);
+            if !b.is_zero() {
+                c /= b;
+                nc /= nb;
+                same(&c, &nc);
+                c %= b.clone();
+                nc %= nb.clone();
+                same(&c, &nc);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_bigint_arithmetic() {
+    let ys = [-7i64, -2, -1, 0, 1, 3, 1 << 40];
+    for (a, na) in &small_rationals() {
+        for &y in &ys {
+            let b = BigInt::from(y);
+            let nb = num_bigint::BigInt::from(y);
+            same(a + &b, na + &nb);
+            same(a - &b, na - &nb);
+            same(a * b.clone(), na * nb.clone());
+            same(a.clone() + b.clone(), na.clone() + nb.clone());
+            if y != 0 {
+                same(a / &b, na / &nb);
+                same(a % &b, na % &nb);
+                same(a.clone() % b.clone(), na.clone() % nb.clone());
+            }
+
+            let mut c = a.clone();
+            let mut nc = na.clone();
+            c += &b;
+            nc += &nb;
+            same(&c, &nc);
+            c -= b.clone();
+            nc -= nb.clone();
+            same(&c, &nc);
+            c *= &b;
+            nc *= &nb;
+            same(&c, &nc);
+            if y != 0 {
+                c /= &b;
+                nc /= &nb;
+                same(&c, &nc);
+                c %= b.clone();
+                nc %= nb.clone();
+                same(&c, &nc);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_pow() {
+    for (a, na) in &small_rationals() {
+        for e in [0i32, 1, 2, 5, -1, -3] {
+            if e < 0 && a.is_zero() {
+                continue;
+            }
+            same(a.pow(e), na.pow(e));
+            same(Pow::pow(a, e as i8), Pow::pow(na, e as i8));
+            same(
+                Pow::pow(a.clone(), e as i64),
+                Pow::pow(na.clone(), e as i64),
+            );
+            same(Pow::pow(a, &(e as i128)), Pow::pow(na, &(e as i128)));
+            same(
+                Pow::pow(a, BigInt::from(e)),
+                Pow::pow(na, num_bigint::BigInt::from(e)),
+            );
+            if e >= 0 {
+                let u = e as u32;
+                same(Pow::pow(a, u), Pow::pow(na, u));
+                same(
+                    Pow::pow(a.clone(), &(u as u128)),
+                    Pow::pow(na.clone(), &(u as u128)),
+                );
+                same(
+                    Pow::pow(a, malachite_bigint::BigUint::from(u)),
+                    Pow::pow(na, num_bigint::BigUint::from(u)),
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_pow_large_exponent() {
+    let two_64 = malachite_bigint::BigUint::from(1u8) << 64u32;
+    let two_64_plus_1 = &two_64 + malachite_bigint::BigUint::from(1u8);
+    let zero = BigRational::zero();
+    let one = BigRational::one();
+    let neg_one = -BigRational::one();
+    for (x, even, odd) in [
+        (&zero, &zero, &zero),
+        (&one, &one, &one),
+        (&neg_one, &one, &neg_one),
+    ] {
+        assert_eq!(Pow::pow(x, &two_64), *even);
+        assert_eq!(Pow::pow(x, &two_64_plus_1), *odd);
+        assert_eq!(Pow::pow(x.clone(), two_64.clone()), *even);
+        assert_eq!(Pow::pow(x, BigInt::from(two_64.clone())), *even);
+        assert_eq!(Pow::pow(x, BigInt::from(two_64_plus_1.clone())), *odd);
+        assert_eq!(Pow::pow(x, 1u128 << 64), *even);
+        assert_eq!(Pow::pow(x, (1u128 << 64) + 1), *odd);
+        assert_eq!(Pow::pow(x, 1i128 << 64), *even);
+        if !x.is_zero() {
+            assert_eq!(Pow::pow(x, i128::MIN), *even);
+            assert_eq!(Pow::pow(x, -BigInt::from(two_64.clone())), *even);
+            assert_eq!(Pow::pow(x, -BigInt::from(two_64_plus_1.clone())), *odd);
+            assert_eq!(Pow::pow(x, -(1i128 << 64) - 1), *odd);
+        }
+        // num-rational handles these bases with any exponent, too
+        let nx = num_rational::BigRational::from_str(&x.to_string()).unwrap();
+        let n_two_64 = num_bigint::BigUint::from(1u8) << 64u32;
+        same(Pow::pow(x, &two_64), Pow::pow(&nx, &n_two_64));
+        same(
+            Pow::pow(x, &two_64_plus_1),
+            Pow::pow(&nx, n_two_64 + num_bigint::BigUint::from(1u8)),
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "exponent too large")]
+fn pow_large_biguint_exponent_fail() {
+    Pow::pow(
+        BigRational::from_integer(BigInt::from(2)),
+        malachite_bigint::BigUint::from(1u8) << 64u32,
+    );
+}
+
+#[test]
+#[should_panic(expected = "exponent too large")]
+fn pow_large_bigint_exponent_fail() {
+    Pow::pow(
+        BigRational::new(BigInt::from(1), BigInt::from(2)),
+        -(BigInt::from(1) << 64u32),
+    );
+}
+
+#[test]
+#[should_panic(expected = "exponent too large")]
+fn pow_large_u128_exponent_fail() {
+    Pow::pow(BigRational::from_integer(BigInt::from(2)), 1u128 << 64);
+}
+
+#[test]
+#[should_panic]
+fn pow_zero_large_negative_exponent_fail() {
+    Pow::pow(BigRational::zero(), -(BigInt::from(1) << 64u32));
+}
+
+#[test]
+fn test_sum_and_product() {
+    let xs = small_rationals();
+    let nxs: Vec<_> = xs.iter().map(|(_, nx)| nx.clone()).collect();
+    let xs: Vec<_> = xs.into_iter().map(|(x, _)| x).collect();
+    same(
+        xs.iter().sum::<BigRational>(),
+        nxs.iter().sum::<num_rational::BigRational>(),
+    );
+    same(
+        xs.iter().filter(|x| !x.is_zero()).product::<BigRational>(),
+        nxs.iter()
+            .filter(|x| !x.is_zero())
+            .product::<num_rational::BigRational>(),
+    );
+    same(
+        xs.clone().into_iter().sum::<BigRational>(),
+        nxs.clone().into_iter().sum::<num_rational::BigRational>(),
+    );
+}
+
+#[test]
+fn test_parse() {
+    for s in [
+        "", "/", "1/", "/1", "1/0", "-1/0", "0/0", "1/2/3", "+3/4", "-3/-4", "3/+4", "a/b", " 1/2",
+        "1 /2", "6/8", "10", "-10", "1_000/3",
+    ] {
+        let a = BigRational::from_str(s);
+        let na = num_rational::BigRational::from_str(s);
+        same(a.as_ref().ok(), na.as_ref().ok());
+        same(
+            a.err().map(|e| e.to_string()),
+            na.err().map(|e| e.to_string()),
+        );
+        for radix in [2, 10, 16, 36] {
+            let a = BigRational::from_str_radix(s, radix);
+            let na = num_rational::BigRational::from_str_radix(s, radix);
+            same(a.as_ref().ok(), na.as_ref().ok());
+            same(
+                a.err().map(|e| e.to_string()),
+                na.err().map(|e| e.to_string()),
+            );
+        }
+    }
+    for s in ["ff/10", "-zz/a", "101/11"] {
+        for radix in [16, 36] {
+            same(
+                BigRational::from_str_radix(s, radix).ok(),
+                num_rational::BigRational::from_str_radix(s, radix).ok(),
+            );
+        }
+    }
+}
+
+#[test]
+fn test_formatting() {
+    for (a, na) in &rationals() {
+        assert_eq!(format!("{a}"), format!("{na}"));
+        assert_eq!(format!("{a:?}"), format!("{na:?}"));
+        assert_eq!(format!("{a:x}"), format!("{na:x}"));
+        assert_eq!(format!("{a:#x}"), format!("{na:#x}"));
+        assert_eq!(format!("{a:#X}"), format!("{na:#X}"));
+        assert_eq!(format!("{a:#o}"), format!("{na:#o}"));
+        assert_eq!(format!("{a:b}"), format!("{na:b}"));
+        assert_eq!(format!("{a:#b}"), format!("{na:#b}"));
+        assert_eq!(format!("{a:>50}"), format!("{na:>50}"));
+        assert_eq!(format!("{a:+}"), format!("{na:+}"));
+        assert_eq!(format!("{a:08}"), format!("{na:08}"));
+    }
+}
+
+const FLOATS: &[f64] = &[
+    0.0,
+    -0.0,
+    0.1,
+    0.5,
+    1.0,
+    1.5,
+    -2.5,
+    1.0e10,
+    -1.0e-10,
+    1.0e300,
+    -1.0e300,
+    f64::MAX,
+    f64::MIN,
+    f64::MIN_POSITIVE,
+    5.0e-324,
+    f64::EPSILON,
+    f64::INFINITY,
+    f64::NEG_INFINITY,
+    f64::NAN,
+];
+
+#[test]
+fn test_primitive_conversions() {
+    for (a, na) in &rationals() {
+        same(a.to_u8(), na.to_u8());
+        same(a.to_i32(), na.to_i32());
+        same(a.to_u64(), na.to_u64());
+        same(a.to_i64(), na.to_i64());
+        same(a.to_u128(), na.to_u128());
+        same(a.to_i128(), na.to_i128());
+        same(a.to_usize(), na.to_usize());
+        same(a.to_f32(), na.to_f32());
+        same(a.to_f64(), na.to_f64());
+    }
+    for x in [0i64, 1, -1, i64::MIN, i64::MAX] {
+        same(
+            BigRational::from_i64(x),
+            num_rational::BigRational::from_i64(x),
+        );
+        same(
+            BigRational::from_i8(x as i8),
+            num_rational::BigRational::from_i8(x as i8),
+        );
+        same(
+            BigRational::from_u64(x as u64),
+            num_rational::BigRational::from_u64(x as u64),
+        );
+    }
+    for x in [0u128, 1, u128::MAX] {
+        same(
+            BigRational::from_u128(x),
+            num_rational::BigRational::from_u128(x),
+        );
+        same(
+            BigRational::from_i128(x as i128),
+            num_rational::BigRational::from_i128(x as i128),
+        );
+    }
+    for &x in FLOATS {
+        same(
+            BigRational::from_f64(x),
+            num_rational::BigRational::from_f64(x),
+        );
+        same(
+            BigRational::from_float(x),
+            num_rational::BigRational::from_float(x),
+        );
+        let y = x as f32;
+        same(
+            BigRational::from_f32(y),
+            num_rational::BigRational::from_f32(y),
+        );
+        if let Some(a) = BigRational::from_f64(x) {
+            assert_eq!(a.to_f64(), Some(x));
+        }
+    }
+}
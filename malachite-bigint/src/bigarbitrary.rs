@@ -0,0 +1,133 @@
+#[cfg(feature = "quickcheck")]
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use malachite_base::num::conversion::traits::PowerOf2Digits;
+use malachite_nz::natural::Natural;
+
+use crate::{BigInt, BigUint, Sign};
+
+// Values are generated from vectors of `u64` digits and a sign, whatever the size of malachite's
+// limbs, so that the same input produces the same values as num-bigint does with `u64` digits
+// (which it uses on 64-bit targets).
+
+fn from_u64_digits(digits: Vec<u64>) -> BigUint {
+    // SAFETY: Vec<u64> cannot have any digit greater than 2^64
+    BigUint(unsafe {
+        Natural::from_power_of_2_digits_asc(64, digits.into_iter()).unwrap_unchecked()
+    })
+}
+
+#[cfg(feature = "quickcheck")]
+impl quickcheck::Arbitrary for BigUint {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        from_u64_digits(Vec::<u64>::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(
+            PowerOf2Digits::<u64>::to_power_of_2_digits_asc(&self.0, 64)
+                .shrink()
+                .map(from_u64_digits),
+        )
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl quickcheck::Arbitrary for BigInt {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        let positive = bool::arbitrary(g);
+        let sign = if positive { Sign::Plus } else { Sign::Minus };
+        Self::from_biguint(sign, BigUint::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let sign = self.sign();
+        Box::new(
+            self.magnitude()
+                .shrink()
+                .map(move |x| BigInt::from_biguint(sign, x)),
+        )
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl arbitrary::Arbitrary<'_> for BigUint {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
+        Ok(from_u64_digits(Vec::<u64>::arbitrary(u)?))
+    }
+
+    fn arbitrary_take_rest(u: arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
+        Ok(from_u64_digits(Vec::<u64>::arbitrary_take_rest(u)?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        Vec::<u64>::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl arbitrary::Arbitrary<'_> for BigInt {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
+        let positive = bool::arbitrary(u)?;
+        let sign = if positive { Sign::Plus } else { Sign::Minus };
+        Ok(Self::from_biguint(sign, BigUint::arbitrary(u)?))
+    }
+
+    fn arbitrary_take_rest(mut u: arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
+        let positive = bool::arbitrary(&mut u)?;
+        let sign = if positive { Sign::Plus } else { Sign::Minus };
+        Ok(Self::from_biguint(sign, BigUint::arbitrary_take_rest(u)?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        arbitrary::size_hint::and(bool::size_hint(depth), BigUint::size_hint(depth))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn test_arbitrary_compatibility() {
+        use alloc::string::ToString;
+        use arbitrary::{Arbitrary, Unstructured};
+        let bytes: Vec<u8> = (0..=255u8).cycle().step_by(7).take(4000).collect();
+        for start in 0..100 {
+            let data = &bytes[start * 3..];
+            let mut u = Unstructured::new(data);
+            let mut v = Unstructured::new(data);
+            for _ in 0..5 {
+                let x = BigUint::arbitrary(&mut u).unwrap();
+                let y = num_bigint::BigUint::arbitrary(&mut v).unwrap();
+                assert_eq!(x.to_string(), y.to_string());
+                let x = BigInt::arbitrary(&mut u).unwrap();
+                let y = num_bigint::BigInt::arbitrary(&mut v).unwrap();
+                assert_eq!(x.to_string(), y.to_string());
+            }
+            let x = BigInt::arbitrary_take_rest(Unstructured::new(data)).unwrap();
+            let y = num_bigint::BigInt::arbitrary_take_rest(Unstructured::new(data)).unwrap();
+            assert_eq!(x.to_string(), y.to_string());
+        }
+        assert_eq!(
+            BigInt::size_hint(0),
+            <num_bigint::BigInt as Arbitrary>::size_hint(0)
+        );
+    }
+
+    #[cfg(feature = "quickcheck")]
+    #[test]
+    fn test_quickcheck() {
+        use quickcheck::{Arbitrary, Gen, QuickCheck};
+        fn prop(x: BigInt, y: BigInt) -> bool {
+            &x + &y - &y == x
+        }
+        QuickCheck::new().quickcheck(prop as fn(BigInt, BigInt) -> bool);
+
+        let x = BigInt::from_biguint(Sign::Minus, BigUint::arbitrary(&mut Gen::new(10)) + 1u32);
+        assert!(x.shrink().all(|y| y.sign() != Sign::Plus));
+        let x = from_u64_digits(alloc::vec![1, 2, 3]);
+        assert!(x.shrink().all(|y| y < x));
+    }
+}
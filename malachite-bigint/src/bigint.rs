@@ -1,8 +1,6 @@
 use malachite_base::{
     num::{
-        arithmetic::traits::{
-            Abs, DivRem, DivRound, DivisibleBy, FloorRoot, Mod, Parity, UnsignedAbs,
-        },
+        arithmetic::traits::{Abs, DivRem, DivRound, DivisibleBy, Mod, Parity, UnsignedAbs},
         conversion::traits::{RoundingInto, ToStringBase},
         logic::traits::BitAccess,
     },
@@ -11,8 +9,8 @@ use malachite_base::{
 use malachite_nz::integer::Integer;
 use num_integer::Roots;
 use num_traits::{
-    CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, FromPrimitive, Num, One, Pow, Signed,
-    ToPrimitive, Zero,
+    CheckedAdd, CheckedDiv, CheckedEuclid, CheckedMul, CheckedSub, ConstZero, Euclid, FromBytes,
+    FromPrimitive, Num, One, Pow, Signed, ToBytes, ToPrimitive, Zero,
 };
 use paste::paste;
 use core::{
@@ -26,7 +24,7 @@ use core::{
     },
     str::FromStr,
 };
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 use alloc::string::String;
 
 use crate::{
@@ -63,6 +61,19 @@ impl Neg for Sign {
     }
 }
 
+impl Mul for Sign {
+    type Output = Sign;
+
+    #[inline]
+    fn mul(self, other: Sign) -> Sign {
+        match (self, other) {
+            (NoSign, _) | (_, NoSign) => NoSign,
+            (Plus, Plus) | (Minus, Minus) => Plus,
+            (Plus, Minus) | (Minus, Plus) => Minus,
+        }
+    }
+}
+
 #[repr(transparent)]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct BigInt(Integer);
@@ -72,6 +83,13 @@ apply_to_primitives!(forward_try_into{BigInt, _});
 
 impl_from!(BigInt, Integer);
 
+impl From<bool> for BigInt {
+    #[inline]
+    fn from(x: bool) -> Self {
+        Self(Integer::from(x))
+    }
+}
+
 forward_unary_op!(BigInt, Not, not);
 forward_unary_op!(BigInt, Neg, neg);
 
@@ -124,7 +142,15 @@ apply_to_unsigneds!(forward_pow_primitive{BigInt, _});
 impl_product_iter_type!(BigInt);
 impl_sum_iter_type!(BigInt);
 
-forward_fmt!(BigInt, Debug, Display, Binary, Octal, LowerHex, UpperHex);
+forward_fmt_signed!(
+    BigInt,
+    Debug, "", 10, to_string_base;
+    Display, "", 10, to_string_base;
+    Binary, "0b", 2, to_string_base;
+    Octal, "0o", 8, to_string_base;
+    LowerHex, "0x", 16, to_string_base;
+    UpperHex, "0x", 16, to_string_base_upper
+);
 
 impl CheckedAdd for BigInt {
     #[inline]
@@ -154,6 +180,41 @@ impl CheckedDiv for BigInt {
     }
 }
 
+impl Euclid for BigInt {
+    #[inline]
+    fn div_euclid(&self, v: &Self) -> Self {
+        let rm = if v.is_negative() {
+            RoundingMode::Ceiling
+        } else {
+            RoundingMode::Floor
+        };
+        (&self.0).div_round(&v.0, rm).0.into()
+    }
+
+    #[inline]
+    fn rem_euclid(&self, v: &Self) -> Self {
+        let r = num_integer::Integer::mod_floor(self, v);
+        if r.is_negative() { r - v } else { r }
+    }
+}
+
+impl CheckedEuclid for BigInt {
+    #[inline]
+    fn checked_div_euclid(&self, v: &Self) -> Option<Self> {
+        (!v.is_zero()).then(|| self.div_euclid(v))
+    }
+
+    #[inline]
+    fn checked_rem_euclid(&self, v: &Self) -> Option<Self> {
+        (!v.is_zero()).then(|| self.rem_euclid(v))
+    }
+
+    #[inline]
+    fn checked_div_rem_euclid(&self, v: &Self) -> Option<(Self, Self)> {
+        (!v.is_zero()).then(|| self.div_rem_euclid(v))
+    }
+}
+
 impl ToBigInt for BigInt {
     #[inline]
     fn to_bigint(&self) -> Option<BigInt> {
@@ -199,6 +260,10 @@ impl Zero for BigInt {
     }
 }
 
+impl ConstZero for BigInt {
+    const ZERO: Self = Self::ZERO;
+}
+
 impl One for BigInt {
     #[inline]
     fn one() -> Self {
@@ -311,7 +376,46 @@ impl num_integer::Integer for BigInt {
 impl Roots for BigInt {
     #[inline]
     fn nth_root(&self, n: u32) -> Self {
-        (&self.0).floor_root(n as u64).into()
+        assert!(
+            !(self.is_negative() && n.even()),
+            "root of degree {n} is imaginary"
+        );
+        // the root is truncated towards zero, not floored
+        Self::from_biguint(self.sign(), self.magnitude().nth_root(n))
+    }
+
+    #[inline]
+    fn sqrt(&self) -> Self {
+        assert!(!self.is_negative(), "square root is imaginary");
+        self.magnitude().sqrt().into()
+    }
+}
+
+impl FromBytes for BigInt {
+    type Bytes = [u8];
+
+    #[inline]
+    fn from_be_bytes(bytes: &Self::Bytes) -> Self {
+        Self::from_signed_bytes_be(bytes)
+    }
+
+    #[inline]
+    fn from_le_bytes(bytes: &Self::Bytes) -> Self {
+        Self::from_signed_bytes_le(bytes)
+    }
+}
+
+impl ToBytes for BigInt {
+    type Bytes = Vec<u8>;
+
+    #[inline]
+    fn to_be_bytes(&self) -> Self::Bytes {
+        self.to_signed_bytes_be()
+    }
+
+    #[inline]
+    fn to_le_bytes(&self) -> Self::Bytes {
+        self.to_signed_bytes_le()
     }
 }
 
@@ -325,6 +429,10 @@ impl FromStr for BigInt {
 }
 
 impl BigInt {
+    pub const ZERO: Self = Self(<Integer as malachite_base::num::basic::traits::Zero>::ZERO);
+
+    pub const ONE: Self = Self(<Integer as malachite_base::num::basic::traits::One>::ONE);
+
     #[inline]
     pub fn new(sign: Sign, digits: Vec<u32>) -> Self {
         Self::from_biguint(sign, BigUint::new(digits))
@@ -365,38 +473,12 @@ impl BigInt {
 
     #[inline]
     pub fn from_signed_bytes_be(digits: &[u8]) -> Self {
-        let is_negative = match digits.first().cloned() {
-            Some(x) => x > 0x7f,
-            None => return Self::zero(),
-        };
-
-        if is_negative {
-            let mut v = Vec::from(digits);
-            twos_complement_be(&mut v);
-            let u = BigUint::from_bytes_be(v.as_slice());
-            Self::from_biguint(Minus, u)
-        } else {
-            let u = BigUint::from_bytes_be(digits);
-            Self::from_biguint(Plus, u)
-        }
+        Self(Integer::from_twos_complement_bytes_be(digits))
     }
 
     #[inline]
     pub fn from_signed_bytes_le(digits: &[u8]) -> Self {
-        let is_negative = match digits.last().cloned() {
-            Some(x) => x > 0x7f,
-            None => return Self::zero(),
-        };
-
-        if is_negative {
-            let mut v = Vec::from(digits);
-            twos_complement_le(&mut v);
-            let u = BigUint::from_bytes_le(v.as_slice());
-            Self::from_biguint(Minus, u)
-        } else {
-            let u = BigUint::from_bytes_le(digits);
-            Self::from_biguint(Plus, u)
-        }
+        Self(Integer::from_twos_complement_bytes_le(digits))
     }
 
     #[inline]
@@ -447,36 +529,18 @@ impl BigInt {
 
     #[inline]
     pub fn to_signed_bytes_be(&self) -> Vec<u8> {
-        let mut bytes = self.magnitude().to_bytes_be();
-        let first_byte = bytes.first().cloned().unwrap_or(0);
-        let is_negative = self.is_negative();
-        if first_byte > 0x7f
-            && !(first_byte == 0x80 && bytes.iter().skip(1).all(Zero::is_zero) && is_negative)
-        {
-            // msb used by magnitude, extend by 1 byte
-            bytes.insert(0, 0);
+        if self.is_zero() {
+            return vec![0];
         }
-        if self.is_negative() {
-            twos_complement_be(&mut bytes);
-        }
-        bytes
+        self.0.to_twos_complement_bytes_be()
     }
 
     #[inline]
     pub fn to_signed_bytes_le(&self) -> Vec<u8> {
-        let mut bytes = self.magnitude().to_bytes_le();
-        let is_negative = self.is_negative();
-        let last_byte = bytes.last().cloned().unwrap_or(0);
-        if last_byte > 0x7f
-            && !(last_byte == 0x80 && bytes.iter().rev().skip(1).all(Zero::is_zero) && is_negative)
-        {
-            // msb used by magnitude, extend by 1 byte
-            bytes.push(0);
-        }
-        if self.is_negative() {
-            twos_complement_le(&mut bytes);
+        if self.is_zero() {
+            return vec![0];
         }
-        bytes
+        self.0.to_twos_complement_bytes_le()
     }
 
     #[inline]
@@ -581,6 +645,19 @@ impl BigInt {
         Self::from_biguint(modulus.sign(), abs)
     }
 
+    #[inline]
+    pub fn modinv(&self, modulus: &Self) -> Option<Self> {
+        let result = self.magnitude().modinv(modulus.magnitude())?;
+        // the sign of the result follows the modulus, like `mod_floor`
+        let (sign, abs) = match (self.is_negative(), modulus.is_negative()) {
+            (false, false) => (Plus, result),
+            (true, false) => (Plus, modulus.magnitude() - result),
+            (false, true) => (Minus, modulus.magnitude() - result),
+            (true, true) => (Minus, result),
+        };
+        Some(Self::from_biguint(sign, abs))
+    }
+
     #[inline]
     pub fn sqrt(&self) -> Self {
         Roots::sqrt(self)
@@ -615,36 +692,6 @@ impl BigInt {
         }
     }
 }
-/// Perform in-place two's complement of the given binary representation, in little-endian byte
-/// order.
-#[inline]
-fn twos_complement_le(digits: &mut [u8]) {
-    twos_complement(digits)
-}
-
-/// Perform in-place two's complement of the given binary representation in big-endian byte order.
-#[inline]
-fn twos_complement_be(digits: &mut [u8]) {
-    twos_complement(digits.iter_mut().rev())
-}
-
-/// Perform in-place two's complement of the given digit iterator starting from the least
-/// significant byte.
-#[inline]
-fn twos_complement<'a, I>(digits: I)
-where
-    I: IntoIterator<Item = &'a mut u8>,
-{
-    let mut carry = true;
-    for d in digits {
-        *d = !*d;
-        if carry {
-            *d = d.wrapping_add(1);
-            carry = d.is_zero();
-        }
-    }
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
@@ -0,0 +1,292 @@
+use malachite_nz::{
+    integer::{Integer, random::UniformInteger},
+    natural::random::{
+        UniformNatural, get_random_natural_less_than_with_rng,
+        get_random_natural_with_up_to_bits_with_rng,
+    },
+};
+use rand::{
+    Rng,
+    distributions::{
+        Distribution,
+        uniform::{SampleBorrow, SampleUniform, UniformSampler},
+    },
+};
+
+use crate::{BigInt, BigUint, Sign};
+
+/// A trait for sampling random big integers.
+///
+/// It is implemented for every [`Rng`], as in num-bigint.
+pub trait RandBigInt {
+    /// Generate a random [`BigUint`] of the given bit size.
+    fn gen_biguint(&mut self, bit_size: u64) -> BigUint;
+
+    /// Generate a random [`BigInt`] of the given bit size.
+    fn gen_bigint(&mut self, bit_size: u64) -> BigInt;
+
+    /// Generate a random [`BigUint`] less than the given bound. Fails when the bound is zero.
+    fn gen_biguint_below(&mut self, bound: &BigUint) -> BigUint;
+
+    /// Generate a random [`BigUint`] within the given range. The lower bound is inclusive; the
+    /// upper bound is exclusive. Fails when the upper bound is not greater than the lower bound.
+    fn gen_biguint_range(&mut self, lbound: &BigUint, ubound: &BigUint) -> BigUint;
+
+    /// Generate a random [`BigInt`] within the given range. The lower bound is inclusive; the
+    /// upper bound is exclusive. Fails when the upper bound is not greater than the lower bound.
+    fn gen_bigint_range(&mut self, lbound: &BigInt, ubound: &BigInt) -> BigInt;
+}
+
+impl<R: Rng + ?Sized> RandBigInt for R {
+    #[inline]
+    fn gen_biguint(&mut self, bit_size: u64) -> BigUint {
+        BigUint(get_random_natural_with_up_to_bits_with_rng(self, bit_size))
+    }
+
+    fn gen_bigint(&mut self, bit_size: u64) -> BigInt {
+        loop {
+            // Generate a random BigUint...
+            let biguint = self.gen_biguint(bit_size);
+            // ...and then randomly assign it a Sign...
+            let sign = if biguint.0 == 0u32 {
+                // ...except that if the BigUint is zero, we need to try
+                // again with probability 0.5. This is because otherwise,
+                // the probability of generating a zero BigInt would be
+                // double that of any other number.
+                if self.r#gen() {
+                    continue;
+                } else {
+                    Sign::NoSign
+                }
+            } else if self.r#gen() {
+                Sign::Plus
+            } else {
+                Sign::Minus
+            };
+            return BigInt::from_biguint(sign, biguint);
+        }
+    }
+
+    #[inline]
+    fn gen_biguint_below(&mut self, bound: &BigUint) -> BigUint {
+        BigUint(get_random_natural_less_than_with_rng(self, &bound.0))
+    }
+
+    #[inline]
+    fn gen_biguint_range(&mut self, lbound: &BigUint, ubound: &BigUint) -> BigUint {
+        UniformBigUint::new(lbound, ubound).sample(self)
+    }
+
+    #[inline]
+    fn gen_bigint_range(&mut self, lbound: &BigInt, ubound: &BigInt) -> BigInt {
+        UniformBigInt::new(lbound, ubound).sample(self)
+    }
+}
+
+/// The back-end implementing rand's [`UniformSampler`] for [`BigUint`].
+#[derive(Clone, Debug)]
+pub struct UniformBigUint(UniformNatural);
+
+impl UniformSampler for UniformBigUint {
+    type X = BigUint;
+
+    #[inline]
+    fn new<B1, B2>(low_b: B1, high_b: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self(UniformNatural::new(
+            low_b.borrow().0.clone(),
+            high_b.borrow().0.clone(),
+        ))
+    }
+
+    #[inline]
+    fn new_inclusive<B1, B2>(low_b: B1, high_b: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self(UniformNatural::new_inclusive(
+            low_b.borrow().0.clone(),
+            high_b.borrow().0.clone(),
+        ))
+    }
+
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        BigUint(Distribution::sample(&self.0, rng))
+    }
+}
+
+impl SampleUniform for BigUint {
+    type Sampler = UniformBigUint;
+}
+
+/// The back-end implementing rand's [`UniformSampler`] for [`BigInt`].
+#[derive(Clone, Debug)]
+pub struct UniformBigInt(UniformInteger);
+
+impl UniformSampler for UniformBigInt {
+    type X = BigInt;
+
+    #[inline]
+    fn new<B1, B2>(low_b: B1, high_b: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self(UniformInteger::new(
+            Integer::from(low_b.borrow().clone()),
+            Integer::from(high_b.borrow().clone()),
+        ))
+    }
+
+    #[inline]
+    fn new_inclusive<B1, B2>(low_b: B1, high_b: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self(UniformInteger::new_inclusive(
+            Integer::from(low_b.borrow().clone()),
+            Integer::from(high_b.borrow().clone()),
+        ))
+    }
+
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        BigInt::from(Distribution::sample(&self.0, rng))
+    }
+}
+
+impl SampleUniform for BigInt {
+    type Sampler = UniformBigInt;
+}
+
+/// A random distribution for [`BigUint`] and [`BigInt`] values of a particular bit size.
+#[derive(Clone, Copy, Debug)]
+pub struct RandomBits {
+    bits: u64,
+}
+
+impl RandomBits {
+    #[inline]
+    pub fn new(bits: u64) -> RandomBits {
+        RandomBits { bits }
+    }
+}
+
+impl Distribution<BigUint> for RandomBits {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> BigUint {
+        rng.gen_biguint(self.bits)
+    }
+}
+
+impl Distribution<BigInt> for RandomBits {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> BigInt {
+        rng.gen_bigint(self.bits)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use malachite_base::random::EXAMPLE_SEED;
+    use rand::distributions::Uniform;
+
+    #[test]
+    fn test_gen_biguint() {
+        let mut rng = EXAMPLE_SEED.get_rng();
+        for bits in [0, 1, 10, 64, 65, 1000] {
+            for _ in 0..100 {
+                assert!(rng.gen_biguint(bits).bits() <= bits);
+            }
+        }
+    }
+
+    #[test]
+    fn test_gen_bigint() {
+        let mut rng = EXAMPLE_SEED.get_rng();
+        assert_eq!(rng.gen_bigint(0), BigInt::from(0));
+        let mut seen_negative = false;
+        for bits in [1, 10, 64, 65, 1000] {
+            for _ in 0..100 {
+                let n = rng.gen_bigint(bits);
+                assert!(n.bits() <= bits);
+                seen_negative |= n.sign() == Sign::Minus;
+            }
+        }
+        assert!(seen_negative);
+    }
+
+    #[test]
+    fn test_gen_biguint_below() {
+        let mut rng = EXAMPLE_SEED.get_rng();
+        let bound = BigUint::from(1u64 << 40) * BigUint::from(1000u32);
+        for _ in 0..100 {
+            assert!(rng.gen_biguint_below(&bound) < bound);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn gen_biguint_below_fail() {
+        EXAMPLE_SEED
+            .get_rng()
+            .gen_biguint_below(&BigUint::from(0u32));
+    }
+
+    #[test]
+    fn test_gen_biguint_range() {
+        let mut rng = EXAMPLE_SEED.get_rng();
+        let l = BigUint::from(1u64 << 40);
+        let u = &l * BigUint::from(1000u32);
+        for _ in 0..100 {
+            let n = rng.gen_biguint_range(&l, &u);
+            assert!(l <= n && n < u);
+            let n: BigUint = rng.gen_range(l.clone()..=u.clone());
+            assert!(l <= n && n <= u);
+        }
+        assert_eq!(rng.gen_biguint_range(&l, &(&l + BigUint::from(1u32))), l);
+    }
+
+    #[test]
+    #[should_panic]
+    fn gen_biguint_range_fail() {
+        let x = BigUint::from(10u32);
+        EXAMPLE_SEED.get_rng().gen_biguint_range(&x, &x);
+    }
+
+    #[test]
+    fn test_gen_bigint_range() {
+        let mut rng = EXAMPLE_SEED.get_rng();
+        let l = -BigInt::from(1u64 << 40);
+        let u = BigInt::from(1u64 << 50);
+        for _ in 0..100 {
+            let n = rng.gen_bigint_range(&l, &u);
+            assert!(l <= n && n < u);
+        }
+        let mut saw = [false; 11];
+        let dist = Uniform::new_inclusive(BigInt::from(-5), BigInt::from(5));
+        for _ in 0..1000 {
+            let n = rng.sample(&dist);
+            saw[usize::try_from(n + BigInt::from(5)).unwrap()] = true;
+        }
+        assert!(saw.iter().all(|&b| b));
+    }
+
+    #[test]
+    fn test_random_bits() {
+        let mut rng = EXAMPLE_SEED.get_rng();
+        for _ in 0..100 {
+            let n: BigUint = rng.sample(RandomBits::new(100));
+            assert!(n.bits() <= 100);
+            let n: BigInt = rng.sample(RandomBits::new(100));
+            assert!(n.bits() <= 100);
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use core::{cmp::min, fmt, mem::size_of};
+
+use alloc::vec::Vec;
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{Error, SeqAccess, Unexpected, Visitor},
+};
+
+use crate::{
+    BigInt, BigUint,
+    Sign::{self, Minus, NoSign, Plus},
+};
+
+// The wire format matches num-bigint's: a `BigUint` is a sequence of its base-2^32 digits, least
+// significant first, and a `BigInt` is a `(Sign, BigUint)` tuple with the sign as an `i8`.
+
+impl Serialize for Sign {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            Minus => (-1i8).serialize(serializer),
+            NoSign => 0i8.serialize(serializer),
+            Plus => 1i8.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Sign {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let sign = i8::deserialize(deserializer)?;
+        match sign {
+            -1 => Ok(Minus),
+            0 => Ok(NoSign),
+            1 => Ok(Plus),
+            _ => Err(D::Error::invalid_value(
+                Unexpected::Signed(sign.into()),
+                &"a sign of -1, 0, or 1",
+            )),
+        }
+    }
+}
+
+impl Serialize for BigUint {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter_u32_digits())
+    }
+}
+
+impl<'de> Deserialize<'de> for BigUint {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(U32Visitor)
+    }
+}
+
+struct U32Visitor;
+
+impl<'de> Visitor<'de> for U32Visitor {
+    type Value = BigUint;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a sequence of unsigned 32-bit numbers")
+    }
+
+    fn visit_seq<S: SeqAccess<'de>>(self, mut seq: S) -> Result<Self::Value, S::Error> {
+        // Don't trust the size hint for more than 1 MiB of preallocation
+        let mut digits = Vec::with_capacity(min(
+            seq.size_hint().unwrap_or(0),
+            (1 << 20) / size_of::<u32>(),
+        ));
+        while let Some(digit) = seq.next_element::<u32>()? {
+            digits.push(digit);
+        }
+        Ok(BigUint::new(digits))
+    }
+}
+
+impl Serialize for BigInt {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.sign(), self.magnitude()).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for BigInt {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (sign, data) = Deserialize::deserialize(deserializer)?;
+        Ok(BigInt::from_biguint(sign, data))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, string::String, vec};
+    use core::str::FromStr;
+
+    fn compatible_biguint(s: &str) {
+        let x = BigUint::from_str(s).unwrap();
+        let y = num_bigint::BigUint::from_str(s).unwrap();
+        let json = serde_json::to_string(&x).unwrap();
+        assert_eq!(json, serde_json::to_string(&y).unwrap());
+        assert_eq!(serde_json::from_str::<BigUint>(&json).unwrap(), x);
+        assert_eq!(
+            serde_json::from_str::<num_bigint::BigUint>(&json).unwrap(),
+            y
+        );
+    }
+
+    fn compatible_bigint(s: &str) {
+        let x = BigInt::from_str(s).unwrap();
+        let y = num_bigint::BigInt::from_str(s).unwrap();
+        let json = serde_json::to_string(&x).unwrap();
+        assert_eq!(json, serde_json::to_string(&y).unwrap());
+        assert_eq!(serde_json::from_str::<BigInt>(&json).unwrap(), x);
+        assert_eq!(
+            serde_json::from_str::<num_bigint::BigInt>(&json).unwrap(),
+            y
+        );
+    }
+
+    #[test]
+    fn test_serde_compatibility() {
+        for s in [
+            "0",
+            "1",
+            "4294967295",
+            "4294967296",
+            "18446744073709551615",
+            "18446744073709551616",
+            "340282366920938463463374607431768211455",
+            "1000000000000000000000000000000000000000000000000000",
+        ] {
+            compatible_biguint(s);
+            compatible_bigint(s);
+            compatible_bigint(&format!("-{s}"));
+        }
+    }
+
+    #[test]
+    fn test_serde_format() {
+        let x = BigUint::from_str("18446744073709551616").unwrap();
+        assert_eq!(serde_json::to_string(&x).unwrap(), "[0,0,1]");
+        let x = BigInt::from_str("-4294967296").unwrap();
+        assert_eq!(serde_json::to_string(&x).unwrap(), "[-1,[0,1]]");
+        assert_eq!(serde_json::to_string(&BigInt::from(0)).unwrap(), "[0,[]]");
+    }
+
+    #[test]
+    fn test_deserialize_non_canonical() {
+        // Trailing zero digits are accepted, as in num-bigint
+        assert_eq!(
+            serde_json::from_str::<BigUint>("[5,0,0]").unwrap(),
+            BigUint::from(5u32)
+        );
+        // A zero magnitude always gives a zero BigInt, and a NoSign sign always gives zero
+        assert_eq!(
+            serde_json::from_str::<BigInt>("[-1,[]]").unwrap(),
+            BigInt::from(0)
+        );
+        assert_eq!(
+            serde_json::from_str::<BigInt>("[0,[5]]").unwrap(),
+            BigInt::from(0)
+        );
+        assert!(serde_json::from_str::<BigInt>("[2,[5]]").is_err());
+        assert!(serde_json::from_str::<BigUint>("[4294967296]").is_err());
+        // Odd numbers of digits are combined the same way regardless of the limb size
+        assert_eq!(
+            serde_json::from_str::<BigUint>("[1,2,3]").unwrap(),
+            BigUint::from_str("55340232229718589441").unwrap()
+        );
+        let s: String = serde_json::to_string(&vec![Minus, NoSign, Plus]).unwrap();
+        assert_eq!(s, "[-1,0,1]");
+    }
+}
@@ -1,7 +1,8 @@
 use malachite_base::{
     num::{
         arithmetic::traits::{
-            DivRem, DivRound, DivisibleBy, FloorRoot, Gcd, Lcm, Mod, ModPow, Parity,
+            DivRem, DivRound, DivisibleBy, FloorRoot, FloorSqrt, Gcd, Lcm, Mod, ModInverse, ModPow,
+            Parity,
         },
         conversion::traits::{Digits, FromStringBase, PowerOf2Digits, RoundingInto, ToStringBase},
         logic::traits::{BitAccess, BitIterable, CountOnes, SignificantBits},
@@ -11,8 +12,8 @@ use malachite_base::{
 use malachite_nz::{integer::Integer, natural::Natural};
 use num_integer::Roots;
 use num_traits::{
-    CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, FromPrimitive, Num, One, Pow, ToPrimitive,
-    Unsigned, Zero,
+    CheckedAdd, CheckedDiv, CheckedEuclid, CheckedMul, CheckedSub, ConstZero, Euclid, FromBytes,
+    FromPrimitive, Num, One, Pow, ToBytes, ToPrimitive, Unsigned, Zero,
 };
 use paste::paste;
 use core::{
@@ -24,10 +25,10 @@ use core::{
     },
     str::FromStr,
 };
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 use alloc::string::String;
 
-use crate::{ParseBigIntError, ToBigInt, TryFromBigIntError, U32Digits, U64Digits};
+use crate::{BigInt, ParseBigIntError, ToBigInt, TryFromBigIntError, U32Digits, U64Digits};
 
 pub trait ToBigUint {
     fn to_biguint(&self) -> Option<BigUint>;
@@ -47,6 +48,33 @@ apply_to_primitives!(forward_try_into{BigUint, _});
 
 impl_from!(BigUint, Natural);
 
+impl From<bool> for BigUint {
+    #[inline]
+    fn from(x: bool) -> Self {
+        Self(Natural::from(x))
+    }
+}
+
+impl TryFrom<BigInt> for BigUint {
+    type Error = TryFromBigIntError<BigInt>;
+
+    #[inline]
+    fn try_from(value: BigInt) -> Result<Self, Self::Error> {
+        value
+            .to_biguint()
+            .ok_or_else(|| TryFromBigIntError::new(value))
+    }
+}
+
+impl TryFrom<&BigInt> for BigUint {
+    type Error = TryFromBigIntError<()>;
+
+    #[inline]
+    fn try_from(value: &BigInt) -> Result<Self, Self::Error> {
+        value.to_biguint().ok_or(TryFromBigIntError::new(()))
+    }
+}
+
 forward_binary_self!(BigUint, Add, add);
 forward_binary_self!(BigUint, Sub, sub);
 forward_binary_self!(BigUint, Mul, mul);
@@ -130,6 +158,40 @@ impl CheckedDiv for BigUint {
     }
 }
 
+impl Euclid for BigUint {
+    #[inline]
+    fn div_euclid(&self, v: &Self) -> Self {
+        self / v
+    }
+
+    #[inline]
+    fn rem_euclid(&self, v: &Self) -> Self {
+        self % v
+    }
+
+    #[inline]
+    fn div_rem_euclid(&self, v: &Self) -> (Self, Self) {
+        num_integer::Integer::div_rem(self, v)
+    }
+}
+
+impl CheckedEuclid for BigUint {
+    #[inline]
+    fn checked_div_euclid(&self, v: &Self) -> Option<Self> {
+        (!v.is_zero()).then(|| self.div_euclid(v))
+    }
+
+    #[inline]
+    fn checked_rem_euclid(&self, v: &Self) -> Option<Self> {
+        (!v.is_zero()).then(|| self.rem_euclid(v))
+    }
+
+    #[inline]
+    fn checked_div_rem_euclid(&self, v: &Self) -> Option<(Self, Self)> {
+        (!v.is_zero()).then(|| self.div_rem_euclid(v))
+    }
+}
+
 impl ToBigUint for BigUint {
     #[inline]
     fn to_biguint(&self) -> Option<BigUint> {
@@ -169,6 +231,10 @@ impl Zero for BigUint {
     }
 }
 
+impl ConstZero for BigUint {
+    const ZERO: Self = Self::ZERO;
+}
+
 impl One for BigUint {
     #[inline]
     fn one() -> Self {
@@ -277,6 +343,39 @@ impl Roots for BigUint {
     fn nth_root(&self, n: u32) -> Self {
         (&self.0).floor_root(n as u64).into()
     }
+
+    #[inline]
+    fn sqrt(&self) -> Self {
+        (&self.0).floor_sqrt().into()
+    }
+}
+
+impl FromBytes for BigUint {
+    type Bytes = [u8];
+
+    #[inline]
+    fn from_be_bytes(bytes: &Self::Bytes) -> Self {
+        Self::from_bytes_be(bytes)
+    }
+
+    #[inline]
+    fn from_le_bytes(bytes: &Self::Bytes) -> Self {
+        Self::from_bytes_le(bytes)
+    }
+}
+
+impl ToBytes for BigUint {
+    type Bytes = Vec<u8>;
+
+    #[inline]
+    fn to_be_bytes(&self) -> Self::Bytes {
+        self.to_bytes_be()
+    }
+
+    #[inline]
+    fn to_le_bytes(&self) -> Self::Bytes {
+        self.to_bytes_le()
+    }
 }
 
 impl FromStr for BigUint {
@@ -289,6 +388,8 @@ impl FromStr for BigUint {
 }
 
 impl BigUint {
+    pub const ZERO: Self = Self(<Natural as malachite_base::num::basic::traits::Zero>::ZERO);
+
     #[inline]
     pub fn new(digits: Vec<u32>) -> Self {
         Self::from_slice(digits.as_slice())
@@ -311,18 +412,12 @@ impl BigUint {
 
     #[inline]
     pub fn from_bytes_be(bytes: &[u8]) -> Self {
-        // SAFETY: &[u8] cannot have any digit greater than 2^8
-        Self(unsafe {
-            Natural::from_power_of_2_digits_desc(8, bytes.iter().cloned()).unwrap_unchecked()
-        })
+        Self(Natural::from_bytes_be(bytes))
     }
 
     #[inline]
     pub fn from_bytes_le(bytes: &[u8]) -> Self {
-        // SAFETY: &[u8] cannot have any digit greater than 2^8
-        Self(unsafe {
-            Natural::from_power_of_2_digits_asc(8, bytes.iter().cloned()).unwrap_unchecked()
-        })
+        Self(Natural::from_bytes_le(bytes))
     }
 
     #[inline]
@@ -351,12 +446,18 @@ impl BigUint {
 
     #[inline]
     pub fn to_bytes_be(&self) -> Vec<u8> {
-        self.0.to_power_of_2_digits_desc(8)
+        if self.is_zero() {
+            return vec![0];
+        }
+        self.0.to_bytes_be()
     }
 
     #[inline]
     pub fn to_bytes_le(&self) -> Vec<u8> {
-        self.0.to_power_of_2_digits_asc(8)
+        if self.is_zero() {
+            return vec![0];
+        }
+        self.0.to_bytes_le()
     }
 
     #[inline]
@@ -389,6 +490,8 @@ impl BigUint {
         debug_assert!(radix <= 256);
         if radix == 256 {
             self.to_bytes_be()
+        } else if self.is_zero() {
+            vec![0]
         } else {
             self.0.to_digits_desc(&(radix as u8))
         }
@@ -399,6 +502,8 @@ impl BigUint {
         debug_assert!(radix <= 256);
         if radix == 256 {
             self.to_bytes_le()
+        } else if self.is_zero() {
+            vec![0]
         } else {
             self.0.to_digits_asc(&(radix as u8))
         }
@@ -424,6 +529,31 @@ impl BigUint {
         }
     }
 
+    #[inline]
+    pub fn modinv(&self, modulus: &Self) -> Option<Self> {
+        assert!(
+            !modulus.is_zero(),
+            "attempt to calculate with zero modulus!"
+        );
+        if modulus.is_one() {
+            return Some(Self::zero());
+        }
+        let x = if self >= modulus {
+            self % modulus
+        } else {
+            self.clone()
+        };
+        if x.is_zero() {
+            return None;
+        }
+        x.0.mod_inverse(&modulus.0).map(Self)
+    }
+
+    #[inline]
+    pub fn sqrt(&self) -> Self {
+        Roots::sqrt(self)
+    }
+
     #[inline]
     pub fn cbrt(&self) -> Self {
         Roots::cbrt(self)
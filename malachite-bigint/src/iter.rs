@@ -5,6 +5,7 @@ use malachite_nz::natural::conversion::to_limbs::LimbIterator;
 pub struct U32Digits<'a> {
     iter: LimbIterator<'a>,
     next_hi: Option<u32>,
+    next_lo: Option<u32>,
     last_hi_is_zero: bool,
     len: usize,
 }
@@ -18,6 +19,7 @@ impl<'a> U32Digits<'a> {
         Self {
             iter,
             next_hi: None,
+            next_lo: None,
             last_hi_is_zero,
             len,
         }
@@ -34,13 +36,16 @@ impl Iterator for U32Digits<'_> {
         }
         self.len -= 1;
 
-        self.next_hi.take().or_else(|| {
-            let limb = self.iter.next()?;
-            let hi = (limb >> 32) as u32;
-            let lo = limb as u32;
+        self.next_hi.take().or_else(|| match self.iter.next() {
+            Some(limb) => {
+                let hi = (limb >> 32) as u32;
+                let lo = limb as u32;
 
-            self.next_hi = Some(hi);
-            Some(lo)
+                self.next_hi = Some(hi);
+                Some(lo)
+            }
+            // the low half of the last limb was left behind by `next_back`
+            None => self.next_lo.take(),
         })
     }
 
@@ -56,13 +61,35 @@ impl Iterator for U32Digits<'_> {
     }
 
     #[inline]
-    fn last(self) -> Option<Self::Item> {
-        self.iter.most_significant().map(|limb| {
-            if self.last_hi_is_zero {
-                limb as u32
-            } else {
-                (limb >> 32) as u32
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for U32Digits<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<u32> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+
+        self.next_lo.take().or_else(|| match self.iter.next_back() {
+            Some(limb) => {
+                let hi = (limb >> 32) as u32;
+                let lo = limb as u32;
+
+                if self.last_hi_is_zero {
+                    // the high half of the most significant limb is not a digit
+                    self.last_hi_is_zero = false;
+                    Some(lo)
+                } else {
+                    self.next_lo = Some(lo);
+                    Some(hi)
+                }
             }
+            // the high half of the last limb was left behind by `next`
+            None => self.next_hi.take(),
         })
     }
 }
@@ -74,8 +101,6 @@ impl ExactSizeIterator for U32Digits<'_> {
     }
 }
 
-// TODO: DoubleEndedIterator
-
 impl FusedIterator for U32Digits<'_> {}
 
 pub struct U64Digits<'a> {
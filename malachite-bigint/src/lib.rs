@@ -4,6 +4,12 @@ extern crate alloc;
 #[macro_use]
 mod macros;
 mod bigint;
+#[cfg(any(feature = "arbitrary", feature = "quickcheck"))]
+mod bigarbitrary;
+#[cfg(feature = "rand")]
+mod bigrand;
+#[cfg(feature = "serde")]
+mod bigserde;
 mod biguint;
 mod error;
 mod iter;
@@ -11,6 +17,8 @@ mod iter;
 mod num_bigint_conversion;
 
 pub use bigint::{BigInt, Sign, ToBigInt};
+#[cfg(feature = "rand")]
+pub use bigrand::{RandBigInt, RandomBits, UniformBigInt, UniformBigUint};
 pub use biguint::{BigUint, ToBigUint};
 pub use error::{ParseBigIntError, TryFromBigIntError};
 pub use iter::{U32Digits, U64Digits};
@@ -401,7 +401,9 @@ macro_rules! impl_from_primitive_fn_float {
                 if !n.is_finite() {
                     return None;
                 }
-                Some(Self(n.rounding_into(RoundingMode::Down).0))
+                // go through `Integer` so that a negative value truncating to zero is accepted
+                let x: Integer = n.rounding_into(RoundingMode::Down).0;
+                x.try_into().ok().map(Self)
             }
         }
     };
@@ -436,13 +438,27 @@ macro_rules! impl_to_primitive_fn_float {
         paste! {
             #[inline]
             fn [<to_ $t>](&self) -> Option<$t> {
-                match (&self.0).rounding_into(RoundingMode::Nearest) {
+                // values that are at least halfway between $t::MAX and the next power of 2
+                // overflow to infinity, as in IEEE 754 round-to-nearest
+                let overflows = || {
+                    use malachite_base::num::arithmetic::traits::{PowerOf2, UnsignedAbs};
+                    use malachite_nz::natural::Natural;
+                    let max_exp = $t::MAX_EXP as u64;
+                    let half_ulp = max_exp - u64::from($t::MANTISSA_DIGITS) - 1;
+                    let threshold = Natural::power_of_2(max_exp) - Natural::power_of_2(half_ulp);
+                    Integer::from(self.0.clone()).unsigned_abs() >= threshold
+                };
+                Some(match (&self.0).rounding_into(RoundingMode::Nearest) {
                     // returned value is $t::MAX but still less than the original
-                    (val, core::cmp::Ordering::Less) if val == $t::MAX => None,
+                    (val, core::cmp::Ordering::Less) if val == $t::MAX && overflows() => {
+                        $t::INFINITY
+                    }
                     // returned value is $t::MIN but still greater than the original
-                    (val, core::cmp::Ordering::Greater) if val == $t::MIN => None,
-                    (val, _) => Some(val),
-                }
+                    (val, core::cmp::Ordering::Greater) if val == $t::MIN && overflows() => {
+                        $t::NEG_INFINITY
+                    }
+                    (val, _) => val,
+                })
             }
         }
     };
@@ -458,6 +474,17 @@ macro_rules! forward_fmt {
     }
 }
 
+macro_rules! forward_fmt_signed {
+    ($t:ty, $($trait:ident, $prefix:literal, $radix:literal, $to_string:ident);*) => {
+        $(impl core::fmt::$trait for $t {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let digits = self.magnitude().0.$to_string($radix);
+                f.pad_integral(!self.is_negative(), $prefix, &digits)
+            }
+        })*
+    }
+}
+
 macro_rules! impl_from {
     ($wrapper:ty, $inner: ty) => {
         impl From<$inner> for $wrapper {
@@ -0,0 +1,862 @@
+// Runs malachite-bigint and num-bigint side by side on the same inputs and checks that they agree.
+
+use core::str::FromStr;
+use malachite_bigint::{BigInt, BigUint, Sign, ToBigInt, ToBigUint};
+use num_bigint::{ToBigInt as _, ToBigUint as _};
+use num_integer::{Integer, Roots};
+use num_traits::{
+    CheckedAdd, CheckedDiv, CheckedEuclid, CheckedMul, CheckedSub, ConstZero, Euclid, FromBytes,
+    FromPrimitive, Num, One, Pow, Signed, ToBytes, ToPrimitive, Zero,
+};
+use std::fmt::Debug;
+
+const UNSIGNED_STRS: &[&str] = &[
+    "0",
+    "1",
+    "2",
+    "3",
+    "7",
+    "10",
+    "127",
+    "128",
+    "255",
+    "256",
+    "383",
+    "1000",
+    "65535",
+    "65536",
+    "4294967295",
+    "4294967296",
+    "4294967297",
+    "9223372036854775807",
+    "9223372036854775808",
+    "18446744073709551615",
+    "18446744073709551616",
+    "18446744073709551617",
+    "170141183460469231731687303715884105727",
+    "170141183460469231731687303715884105728",
+    "340282366920938463463374607431768211455",
+    "340282366920938463463374607431768211456",
+    "1000000000000000000000000000000",
+    "123456789012345678901234567890123456789012345678901234567890",
+    "179769313486231570814527423731704356798070567525844996598917476803157260780028538760589558632\
+     766878171540458953514382464234321326889464182768467546703537516986049910576551282076245490090\
+     389328944075868508455133942304583236903222948165808559332123348274797826204144723168738177180\
+     919299881250404026184124858368",
+    "179769313486231570814527423731704356798070567525844996598917476803157260780028538760589558632\
+     766878171540458953514382464234321326889464182768467546703537516986049910576551282076245490090\
+     389328944075868508455133942304583236903222948165808559332123348274797826204144723168738177180\
+     919299881250404026184124858367",
+    "179769313486231580793728971405303415079934132710037826936173778980444968292764750946649017977\
+     587207096330286416692887910946555547851940402630657488671505820681908902000708383676273854845\
+     817711531764475730270069855571366959622842914819860834936475292719074168444365510704342711559\
+     699508093042880177904174497791",
+    "340282356779733661637539395458142568448",
+    "340282366920938463463374607431768211455",
+];
+
+fn unsigned_strs() -> Vec<String> {
+    let mut strs: Vec<String> = UNSIGNED_STRS
+        .iter()
+        .map(|s| s.split_whitespace().collect())
+        .collect();
+    // Add some pseudorandom values of assorted sizes
+    let mut state = 0x2545f4914f6cdd1du64;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+    for len in [1, 2, 3, 5, 8, 17] {
+        for _ in 0..3 {
+            let mut x = num_bigint::BigUint::ZERO;
+            for _ in 0..len {
+                x = (x << 64u32) + next();
+            }
+            // Also include values with long runs of zeros or ones
+            strs.push(x.to_string());
+            strs.push((&x << 100u32).to_string());
+            let ones: num_bigint::BigUint = (num_bigint::BigUint::one() << (len * 64)) - 1u32;
+            strs.push(ones.to_string());
+        }
+    }
+    strs
+}
+
+fn signed_strs() -> Vec<String> {
+    let mut strs = Vec::new();
+    for s in unsigned_strs() {
+        if s != "0" {
+            strs.push(format!("-{s}"));
+        }
+        strs.push(s);
+    }
+    strs
+}
+
+fn naturals() -> Vec<(BigUint, num_bigint::BigUint)> {
+    unsigned_strs()
+        .iter()
+        .map(|s| {
+            (
+                BigUint::from_str(s).unwrap(),
+                num_bigint::BigUint::from_str(s).unwrap(),
+            )
+        })
+        .collect()
+}
+
+fn integers() -> Vec<(BigInt, num_bigint::BigInt)> {
+    signed_strs()
+        .iter()
+        .map(|s| {
+            (
+                BigInt::from_str(s).unwrap(),
+                num_bigint::BigInt::from_str(s).unwrap(),
+            )
+        })
+        .collect()
+}
+
+fn small_naturals() -> Vec<(BigUint, num_bigint::BigUint)> {
+    naturals()
+        .into_iter()
+        .filter(|(x, _)| x.bits() <= 200)
+        .collect()
+}
+
+fn small_integers() -> Vec<(BigInt, num_bigint::BigInt)> {
+    integers()
+        .into_iter()
+        .filter(|(x, _)| x.bits() <= 200)
+        .collect()
+}
+
+// Compares two values of different types through their `Debug` representations; for the big
+// integer types this is the same as the decimal representation.
+#[track_caller]
+fn same<A: Debug, B: Debug>(a: A, b: B) {
+    assert_eq!(format!("{a:?}"), format!("{b:?}"));
+}
+
+fn sign(s: Sign) -> num_bigint::Sign {
+    match s {
+        Sign::Minus => num_bigint::Sign::Minus,
+        Sign::NoSign => num_bigint::Sign::NoSign,
+        Sign::Plus => num_bigint::Sign::Plus,
+    }
+}
+
+#[test]
+fn test_constants() {
+    same(BigUint::ZERO, num_bigint::BigUint::ZERO);
+    same(BigInt::ZERO, num_bigint::BigInt::ZERO);
+    same(<BigUint as ConstZero>::ZERO, <num_bigint::BigUint as ConstZero>::ZERO);
+    same(<BigInt as ConstZero>::ZERO, <num_bigint::BigInt as ConstZero>::ZERO);
+    same(BigUint::zero(), num_bigint::BigUint::zero());
+    same(BigUint::one(), num_bigint::BigUint::one());
+    same(BigInt::zero(), num_bigint::BigInt::zero());
+    same(BigInt::one(), num_bigint::BigInt::one());
+    same(BigUint::default(), num_bigint::BigUint::default());
+    same(BigInt::default(), num_bigint::BigInt::default());
+    same(BigUint::from(true), num_bigint::BigUint::from(true));
+    same(BigUint::from(false), num_bigint::BigUint::from(false));
+    same(BigInt::from(true), num_bigint::BigInt::from(true));
+    same(BigInt::from(false), num_bigint::BigInt::from(false));
+}
+
+#[test]
+fn test_sign() {
+    for a in [Sign::Minus, Sign::NoSign, Sign::Plus] {
+        same(-a, -sign(a));
+        for b in [Sign::Minus, Sign::NoSign, Sign::Plus] {
+            same(a * b, sign(a) * sign(b));
+        }
+    }
+}
+
+#[test]
+fn test_biguint_arithmetic() {
+    let xs = naturals();
+    for (a, na) in &xs {
+        for (b, nb) in &xs {
+            same(a + b, na + nb);
+            same(a * b, na * nb);
+            same(a & b, na & nb);
+            same(a | b, na | nb);
+            same(a ^ b, na ^ nb);
+            same(a.cmp(b), na.cmp(nb));
+            same(a.checked_add(b), na.checked_add(nb));
+            same(a.checked_sub(b), na.checked_sub(nb));
+            same(a.checked_mul(b), na.checked_mul(nb));
+            same(a.checked_div(b), na.checked_div(nb));
+            same(a.checked_div_euclid(b), na.checked_div_euclid(nb));
+            same(a.checked_rem_euclid(b), na.checked_rem_euclid(nb));
+            if a >= b {
+                same(a - b, na - nb);
+            }
+            if !b.is_zero() {
+                same(a / b, na / nb);
+                same(a % b, na % nb);
+                same(a.div_rem(b), na.div_rem(nb));
+                same(a.div_floor(b), na.div_floor(nb));
+                same(a.mod_floor(b), na.mod_floor(nb));
+                same(a.div_ceil(b), na.div_ceil(nb));
+                same(a.div_mod_floor(b), na.div_mod_floor(nb));
+                same(a.div_euclid(b), na.div_euclid(nb));
+                same(a.rem_euclid(b), na.rem_euclid(nb));
+                same(a.div_rem_euclid(b), na.div_rem_euclid(nb));
+                same(a.next_multiple_of(b), na.next_multiple_of(nb));
+                same(a.prev_multiple_of(b), na.prev_multiple_of(nb));
+            }
+            same(a.gcd(b), na.gcd(nb));
+            same(a.lcm(b), na.lcm(nb));
+            same(a.gcd_lcm(b), na.gcd_lcm(nb));
+            same(a.is_multiple_of(b), na.is_multiple_of(nb));
+        }
+        same(a.is_even(), na.is_even());
+        same(a.is_odd(), na.is_odd());
+        same(a.is_zero(), na.is_zero());
+        same(a.is_one(), na.is_one());
+        let mut c = a.clone();
+        c.inc();
+        let mut nc = na.clone();
+        nc.inc();
+        same(&c, &nc);
+        c.dec();
+        nc.dec();
+        same(c, nc);
+    }
+}
+
+#[test]
+fn test_biguint_primitive_arithmetic() {
+    for (a, na) in &naturals() {
+        for y in [0u64, 1, 2, 3, 10, 255, 1 << 32, u64::MAX] {
+            same(a + y, na + y);
+            same(y + a, y + na);
+            same(a * y, na * y);
+            same(y * a, y * na);
+            if *a >= BigUint::from(y) {
+                same(a - y, na - y);
+            } else {
+                same(y - a, y - na);
+            }
+            if y != 0 {
+                same(a / y, na / y);
+                same(a % y, na % y);
+            }
+            if !a.is_zero() {
+                same(y / a, y / na);
+                same(y % a, y % na);
+            }
+            let y = u128::from(y) << 64 | 5;
+            same(a + y, na + y);
+            same(a * y, na * y);
+            same(a / y, na / y);
+            same(a % y, na % y);
+            let y = y as u32;
+            same(a + y, na + y);
+            same(a * y, na * y);
+            if y != 0 {
+                same(a / y, na / y);
+                same(a % y, na % y);
+            }
+            let mut c = a.clone();
+            let mut nc = na.clone();
+            c += y;
+            nc += y;
+            c *= y;
+            nc *= y;
+            same(c, nc);
+        }
+    }
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn test_biguint_shifts() {
+    for (a, na) in &naturals() {
+        for s in [0u32, 1, 7, 31, 32, 33, 63, 64, 65, 100, 200] {
+            same(a << s, na << s);
+            same(a >> s, na >> s);
+            same(a << (s as u8), na << (s as u8));
+            same(a >> (s as u16), na >> (s as u16));
+            same(a << (s as u64), na << (s as u64));
+            same(a >> (s as usize), na >> (s as usize));
+            same(a << (s as u128), na << (s as u128));
+            same(a >> ((s % 128) as i8), na >> ((s % 128) as i8));
+            same(a << (s as i16), na << (s as i16));
+            same(a >> (s as i32), na >> (s as i32));
+            same(a << (s as i64), na << (s as i64));
+            same(a >> (s as isize), na >> (s as isize));
+            same(a << (s as i128), na << (s as i128));
+            same(a << &s, na << &s);
+            same(a.clone() >> &(s as i64), na.clone() >> &(s as i64));
+            let mut c = a.clone();
+            let mut nc = na.clone();
+            c <<= s;
+            nc <<= s;
+            c >>= s as i32 + 1;
+            nc >>= s as i32 + 1;
+            same(c, nc);
+        }
+    }
+}
+
+#[test]
+fn test_biguint_powers_and_roots() {
+    for (a, na) in &naturals() {
+        same(a.sqrt(), na.sqrt());
+        same(a.cbrt(), na.cbrt());
+        same(Roots::sqrt(a), Roots::sqrt(na));
+        same(Roots::cbrt(a), Roots::cbrt(na));
+        for n in [1, 2, 3, 4, 5, 7, 64, 100] {
+            same(a.nth_root(n), na.nth_root(n));
+        }
+    }
+    for (a, na) in &small_naturals() {
+        for e in [0u32, 1, 2, 3, 10] {
+            same(a.pow(e), na.pow(e));
+            same(Pow::pow(a, e as u8), Pow::pow(na, e as u8));
+            same(Pow::pow(a, &(e as u64)), Pow::pow(na, &(e as u64)));
+            same(Pow::pow(a, BigUint::from(e)), Pow::pow(na, num_bigint::BigUint::from(e)));
+        }
+    }
+}
+
+#[test]
+fn test_biguint_modular() {
+    let xs = small_naturals();
+    for (a, na) in &xs {
+        for (m, nm) in &xs {
+            if m.is_zero() {
+                continue;
+            }
+            same(a.modinv(m), na.modinv(nm));
+            for (e, ne) in xs.iter().take(12) {
+                same(a.modpow(e, m), na.modpow(ne, nm));
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn biguint_modpow_fail() {
+    BigUint::from(3u32).modpow(&BigUint::from(3u32), &BigUint::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn biguint_modinv_fail() {
+    BigUint::from(3u32).modinv(&BigUint::ZERO);
+}
+
+#[test]
+fn test_biguint_bits() {
+    for (a, na) in &naturals() {
+        same(a.bits(), na.bits());
+        same(a.trailing_zeros(), na.trailing_zeros());
+        same(a.trailing_ones(), na.trailing_ones());
+        same(a.count_ones(), na.count_ones());
+        for i in [0, 1, 5, 31, 32, 63, 64, 65, 127, 128, 1000] {
+            same(a.bit(i), na.bit(i));
+            for v in [false, true] {
+                let mut c = a.clone();
+                let mut nc = na.clone();
+                c.set_bit(i, v);
+                nc.set_bit(i, v);
+                same(c, nc);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_biguint_digits_and_bytes() {
+    for (a, na) in &naturals() {
+        same(a.to_bytes_be(), na.to_bytes_be());
+        same(a.to_bytes_le(), na.to_bytes_le());
+        same(a.to_u32_digits(), na.to_u32_digits());
+        same(a.to_u64_digits(), na.to_u64_digits());
+        same(
+            a.iter_u32_digits().collect::<Vec<_>>(),
+            na.iter_u32_digits().collect::<Vec<_>>(),
+        );
+        same(
+            a.iter_u32_digits().rev().collect::<Vec<_>>(),
+            na.iter_u32_digits().rev().collect::<Vec<_>>(),
+        );
+        same(
+            a.iter_u64_digits().rev().collect::<Vec<_>>(),
+            na.iter_u64_digits().rev().collect::<Vec<_>>(),
+        );
+        let mut it = a.iter_u32_digits();
+        let mut nit = na.iter_u32_digits();
+        same(it.next_back(), nit.next_back());
+        same(it.len(), nit.len());
+        same(it.next(), nit.next());
+        same(it.len(), nit.len());
+        same(it.next_back(), nit.next_back());
+        same(it.collect::<Vec<_>>(), nit.collect::<Vec<_>>());
+        same(ToBytes::to_be_bytes(a), ToBytes::to_be_bytes(na));
+        same(ToBytes::to_le_bytes(a), ToBytes::to_le_bytes(na));
+        let bytes = a.to_bytes_le();
+        same(
+            <BigUint as FromBytes>::from_le_bytes(&bytes),
+            <num_bigint::BigUint as FromBytes>::from_le_bytes(&bytes),
+        );
+        same(BigUint::from_bytes_le(&bytes), na);
+        same(BigUint::from_slice(&a.to_u32_digits()), na);
+        same(BigUint::new(na.to_u32_digits()), na);
+        for radix in [2, 3, 7, 8, 10, 16, 36, 100, 255, 256] {
+            let digits = a.to_radix_be(radix);
+            same(&digits, na.to_radix_be(radix));
+            same(a.to_radix_le(radix), na.to_radix_le(radix));
+            same(
+                BigUint::from_radix_be(&digits, radix),
+                num_bigint::BigUint::from_radix_be(&digits, radix),
+            );
+            if radix <= 36 {
+                let s = a.to_str_radix(radix);
+                same(&s, na.to_str_radix(radix));
+                same(BigUint::from_str_radix(&s, radix), Ok::<_, ()>(na));
+                same(
+                    BigUint::parse_bytes(s.as_bytes(), radix),
+                    num_bigint::BigUint::parse_bytes(s.as_bytes(), radix),
+                );
+            }
+        }
+    }
+    for bytes in [&[][..], &[0], &[0, 0, 1], &[1, 0, 0], &[255; 20]] {
+        same(BigUint::from_bytes_be(bytes), num_bigint::BigUint::from_bytes_be(bytes));
+        same(BigUint::from_bytes_le(bytes), num_bigint::BigUint::from_bytes_le(bytes));
+    }
+    // Out-of-range digits
+    same(
+        BigUint::from_radix_be(&[1, 10], 10),
+        num_bigint::BigUint::from_radix_be(&[1, 10], 10),
+    );
+    same(
+        BigUint::from_radix_le(&[1, 2, 3], 3),
+        num_bigint::BigUint::from_radix_le(&[1, 2, 3], 3),
+    );
+}
+
+#[test]
+fn test_biguint_parse() {
+    for (s, radix) in [
+        ("", 10),
+        ("0", 10),
+        ("+5", 10),
+        ("++5", 10),
+        ("-5", 10),
+        ("-0", 10),
+        ("1_000", 10),
+        ("_1", 10),
+        ("1_", 10),
+        ("1__0", 10),
+        ("z", 36),
+        ("Z", 36),
+        ("ff", 16),
+        ("FF", 16),
+        ("fg", 16),
+        ("12", 2),
+        (" 1", 10),
+        ("1 ", 10),
+        ("١", 10),
+        ("123456789012345678901234567890", 10),
+    ] {
+        same(
+            BigUint::from_str_radix(s, radix).ok(),
+            num_bigint::BigUint::from_str_radix(s, radix).ok(),
+        );
+        same(
+            BigInt::from_str_radix(s, radix).ok(),
+            num_bigint::BigInt::from_str_radix(s, radix).ok(),
+        );
+        let neg = format!("-{s}");
+        same(
+            BigInt::from_str_radix(&neg, radix).ok(),
+            num_bigint::BigInt::from_str_radix(&neg, radix).ok(),
+        );
+    }
+    same(
+        BigUint::from_str("").unwrap_err().to_string(),
+        num_bigint::BigUint::from_str("").unwrap_err().to_string(),
+    );
+    same(
+        BigUint::from_str("x").unwrap_err().to_string(),
+        num_bigint::BigUint::from_str("x").unwrap_err().to_string(),
+    );
+}
+
+#[test]
+fn test_biguint_formatting() {
+    for (a, na) in &naturals() {
+        assert_eq!(format!("{a}"), format!("{na}"));
+        assert_eq!(format!("{a:?}"), format!("{na:?}"));
+        assert_eq!(format!("{a:x}"), format!("{na:x}"));
+        assert_eq!(format!("{a:#X}"), format!("{na:#X}"));
+        assert_eq!(format!("{a:#o}"), format!("{na:#o}"));
+        assert_eq!(format!("{a:#b}"), format!("{na:#b}"));
+        assert_eq!(format!("{a:>50}"), format!("{na:>50}"));
+        assert_eq!(format!("{a:+}"), format!("{na:+}"));
+        assert_eq!(format!("{a:08}"), format!("{na:08}"));
+    }
+}
+
+#[test]
+fn test_biguint_primitive_conversions() {
+    for (a, na) in &naturals() {
+        same(a.to_u8(), na.to_u8());
+        same(a.to_u32(), na.to_u32());
+        same(a.to_u64(), na.to_u64());
+        same(a.to_u128(), na.to_u128());
+        same(a.to_usize(), na.to_usize());
+        same(a.to_i8(), na.to_i8());
+        same(a.to_i64(), na.to_i64());
+        same(a.to_i128(), na.to_i128());
+        same(a.to_f32(), na.to_f32());
+        same(a.to_f64(), na.to_f64());
+        same(u64::try_from(a).ok(), u64::try_from(na).ok());
+        same(i128::try_from(a.clone()).ok(), i128::try_from(na.clone()).ok());
+        same(a.to_bigint(), na.to_bigint());
+        same(a.to_biguint(), na.to_biguint());
+        same(BigInt::from(a.clone()), num_bigint::BigInt::from(na.clone()));
+    }
+    for x in [0i64, 1, -1, i64::MIN, i64::MAX] {
+        same(BigUint::from_i64(x), num_bigint::BigUint::from_i64(x));
+        same(BigUint::try_from(x).ok(), num_bigint::BigUint::try_from(x).ok());
+        same(ToBigUint::to_biguint(&x), num_bigint::ToBigUint::to_biguint(&x));
+        same(ToBigInt::to_bigint(&x), num_bigint::ToBigInt::to_bigint(&x));
+    }
+    for x in [0u128, 1, u128::MAX] {
+        same(BigUint::from(x), num_bigint::BigUint::from(x));
+        same(BigUint::from_u128(x), num_bigint::BigUint::from_u128(x));
+    }
+}
+
+const FLOATS: &[f64] = &[
+    0.0,
+    -0.0,
+    0.4,
+    0.5,
+    0.6,
+    1.0,
+    1.5,
+    2.5,
+    -0.4,
+    -0.5,
+    -1.0,
+    -1.5,
+    -2.5,
+    1.0e10,
+    -1.0e10,
+    4294967296.5,
+    1.0e20,
+    -1.0e20,
+    1.0e300,
+    -1.0e300,
+    f64::MAX,
+    f64::MIN,
+    f64::MIN_POSITIVE,
+    f64::EPSILON,
+    f64::INFINITY,
+    f64::NEG_INFINITY,
+    f64::NAN,
+];
+
+#[test]
+fn test_float_conversions() {
+    for &x in FLOATS {
+        same(BigUint::from_f64(x), num_bigint::BigUint::from_f64(x));
+        same(BigInt::from_f64(x), num_bigint::BigInt::from_f64(x));
+        same(ToBigUint::to_biguint(&x), num_bigint::ToBigUint::to_biguint(&x));
+        same(ToBigInt::to_bigint(&x), num_bigint::ToBigInt::to_bigint(&x));
+        let y = x as f32;
+        same(BigUint::from_f32(y), num_bigint::BigUint::from_f32(y));
+        same(BigInt::from_f32(y), num_bigint::BigInt::from_f32(y));
+        same(ToBigUint::to_biguint(&y), num_bigint::ToBigUint::to_biguint(&y));
+        same(ToBigInt::to_bigint(&y), num_bigint::ToBigInt::to_bigint(&y));
+    }
+}
+
+#[test]
+fn test_bigint_arithmetic() {
+    let xs = small_integers();
+    for (a, na) in &xs {
+        for (b, nb) in &xs {
+            same(a + b, na + nb);
+            same(a - b, na - nb);
+            same(a * b, na * nb);
+            same(a.cmp(b), na.cmp(nb));
+            same(a.checked_add(b), na.checked_add(nb));
+            same(a.checked_sub(b), na.checked_sub(nb));
+            same(a.checked_mul(b), na.checked_mul(nb));
+            same(a.checked_div(b), na.checked_div(nb));
+            same(CheckedAdd::checked_add(a, b), CheckedAdd::checked_add(na, nb));
+            same(CheckedDiv::checked_div(a, b), CheckedDiv::checked_div(na, nb));
+            same(a.checked_div_euclid(b), na.checked_div_euclid(nb));
+            same(a.checked_rem_euclid(b), na.checked_rem_euclid(nb));
+            same(a.abs_sub(b), na.abs_sub(nb));
+            if !b.is_zero() {
+                same(a / b, na / nb);
+                same(a % b, na % nb);
+                same(a.div_rem(b), na.div_rem(nb));
+                same(a.div_floor(b), na.div_floor(nb));
+                same(a.mod_floor(b), na.mod_floor(nb));
+                same(a.div_ceil(b), na.div_ceil(nb));
+                same(a.div_mod_floor(b), na.div_mod_floor(nb));
+                same(a.div_euclid(b), na.div_euclid(nb));
+                same(a.rem_euclid(b), na.rem_euclid(nb));
+                same(a.div_rem_euclid(b), na.div_rem_euclid(nb));
+                same(a.next_multiple_of(b), na.next_multiple_of(nb));
+                same(a.prev_multiple_of(b), na.prev_multiple_of(nb));
+            }
+            same(a.gcd(b), na.gcd(nb));
+            same(a.lcm(b), na.lcm(nb));
+            same(a.gcd_lcm(b), na.gcd_lcm(nb));
+            let (e, l) = a.extended_gcd_lcm(b);
+            let (ne, nl) = na.extended_gcd_lcm(nb);
+            same((e.gcd, e.x, e.y, l), (ne.gcd, ne.x, ne.y, nl));
+            same(a.is_multiple_of(b), na.is_multiple_of(nb));
+        }
+        same(-a, -na);
+        same(a.abs(), na.abs());
+        same(a.signum(), na.signum());
+        same(a.is_positive(), na.is_positive());
+        same(a.is_negative(), na.is_negative());
+        same(a.is_even(), na.is_even());
+        same(a.sign(), na.sign());
+        same(a.magnitude(), na.magnitude());
+        let (s, m) = a.clone().into_parts();
+        let (ns, nm) = na.clone().into_parts();
+        same((s, m), (ns, nm));
+        same(a.to_biguint(), na.to_biguint());
+        same(BigUint::try_from(a).ok(), num_bigint::BigUint::try_from(na).ok());
+        same(BigUint::try_from(a.clone()).ok(), num_bigint::BigUint::try_from(na.clone()).ok());
+        let mut c = a.clone();
+        c.inc();
+        let mut nc = na.clone();
+        nc.inc();
+        same(&c, &nc);
+        c.dec();
+        nc.dec();
+        same(c, nc);
+    }
+}
+
+#[test]
+fn test_bigint_primitive_arithmetic() {
+    for (a, na) in &small_integers() {
+        for y in [0i64, 1, -1, 2, -3, 10, i64::MIN, i64::MAX] {
+            same(a + y, na + y);
+            same(y - a, y - na);
+            same(a * y, na * y);
+            if y != 0 {
+                same(a / y, na / y);
+                same(a % y, na % y);
+            }
+            if !a.is_zero() {
+                same(y / a, y / na);
+                same(y % a, y % na);
+            }
+            let z = i128::from(y) << 64;
+            same(a - z, na - z);
+            same(z * a, z * na);
+            let z = y as u32;
+            same(a - z, na - z);
+            same(a * z, na * z);
+        }
+    }
+}
+
+#[test]
+fn test_bigint_bitwise() {
+    let xs = small_integers();
+    for (a, na) in &xs {
+        for (b, nb) in &xs {
+            same(a & b, na & nb);
+            same(a | b, na | nb);
+            same(a ^ b, na ^ nb);
+            let mut c = a.clone();
+            let mut nc = na.clone();
+            c &= b;
+            nc &= nb;
+            c |= b;
+            nc |= nb;
+            c ^= b;
+            nc ^= nb;
+            same(c, nc);
+        }
+        same(!a, !na);
+        same(!a.clone(), !na.clone());
+        same(a.bits(), na.bits());
+        same(a.trailing_zeros(), na.trailing_zeros());
+        for i in [0, 1, 5, 31, 32, 63, 64, 65, 127, 128, 1000] {
+            same(a.bit(i), na.bit(i));
+            for v in [false, true] {
+                let mut c = a.clone();
+                let mut nc = na.clone();
+                c.set_bit(i, v);
+                nc.set_bit(i, v);
+                same(c, nc);
+            }
+        }
+        for s in [0u32, 1, 7, 32, 63, 64, 65, 100, 300] {
+            same(a << s, na << s);
+            same(a >> s, na >> s);
+            same(a >> ((s % 128) as i8), na >> ((s % 128) as i8));
+            same(a >> (s as u128), na >> (s as u128));
+            same(a << (s as isize), na << (s as isize));
+        }
+    }
+}
+
+#[test]
+fn test_bigint_powers_and_roots() {
+    for (a, na) in &integers() {
+        if !a.is_negative() {
+            same(a.sqrt(), na.sqrt());
+        }
+        same(a.cbrt(), na.cbrt());
+        for n in [1, 3, 5, 7, 101] {
+            same(a.nth_root(n), na.nth_root(n));
+        }
+        if !a.is_negative() {
+            for n in [2, 4, 64] {
+                same(a.nth_root(n), na.nth_root(n));
+            }
+        }
+    }
+    for (a, na) in &small_integers() {
+        for e in [0u32, 1, 2, 3, 10] {
+            same(a.pow(e), na.pow(e));
+            same(Pow::pow(a, BigUint::from(e)), Pow::pow(na, num_bigint::BigUint::from(e)));
+        }
+    }
+}
+
+#[test]
+fn test_bigint_modular() {
+    let xs = small_integers();
+    let exponents: Vec<_> = small_naturals()
+        .into_iter()
+        .take(12)
+        .map(|(e, ne)| (BigInt::from(e), num_bigint::BigInt::from(ne)))
+        .collect();
+    for (a, na) in &xs {
+        for (m, nm) in &xs {
+            if m.is_zero() {
+                continue;
+            }
+            same(a.modinv(m), na.modinv(nm));
+            for (e, ne) in &exponents {
+                same(a.modpow(e, m), na.modpow(ne, nm));
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn bigint_modpow_fail() {
+    BigInt::from(3).modpow(&BigInt::from(-1), &BigInt::from(5));
+}
+
+#[test]
+fn test_bigint_bytes() {
+    for (a, na) in &integers() {
+        same(a.to_bytes_be(), na.to_bytes_be());
+        same(a.to_bytes_le(), na.to_bytes_le());
+        same(a.to_signed_bytes_be(), na.to_signed_bytes_be());
+        same(a.to_signed_bytes_le(), na.to_signed_bytes_le());
+        same(ToBytes::to_be_bytes(a), ToBytes::to_be_bytes(na));
+        same(ToBytes::to_le_bytes(a), ToBytes::to_le_bytes(na));
+        same(a.to_u32_digits(), na.to_u32_digits());
+        same(a.to_u64_digits(), na.to_u64_digits());
+        same(
+            a.iter_u32_digits().rev().collect::<Vec<_>>(),
+            na.iter_u32_digits().rev().collect::<Vec<_>>(),
+        );
+        let bytes = a.to_signed_bytes_be();
+        same(BigInt::from_signed_bytes_be(&bytes), na);
+        same(<BigInt as FromBytes>::from_be_bytes(&bytes), na);
+        let bytes = a.to_signed_bytes_le();
+        same(BigInt::from_signed_bytes_le(&bytes), na);
+        same(<BigInt as FromBytes>::from_le_bytes(&bytes), na);
+        for radix in [2, 10, 16, 36] {
+            same(a.to_str_radix(radix), na.to_str_radix(radix));
+        }
+        for radix in [2, 10, 256] {
+            same(a.to_radix_be(radix), na.to_radix_be(radix));
+            same(a.to_radix_le(radix), na.to_radix_le(radix));
+        }
+        let (s, digits) = a.to_u32_digits();
+        same(BigInt::new(s, digits.clone()), na);
+        same(BigInt::from_slice(s, &digits), na);
+        same(
+            BigInt::from_biguint(Sign::NoSign, a.magnitude().clone()),
+            num_bigint::BigInt::from_biguint(num_bigint::Sign::NoSign, na.magnitude().clone()),
+        );
+        same(
+            BigInt::from_biguint(Sign::Minus, a.magnitude().clone()),
+            num_bigint::BigInt::from_biguint(num_bigint::Sign::Minus, na.magnitude().clone()),
+        );
+    }
+    for bytes in [&[][..], &[0], &[0x80], &[0xff], &[0x7f, 0xff], &[0xff, 0, 0], &[0x80, 0, 0]] {
+        same(BigInt::from_signed_bytes_be(bytes), num_bigint::BigInt::from_signed_bytes_be(bytes));
+        same(BigInt::from_signed_bytes_le(bytes), num_bigint::BigInt::from_signed_bytes_le(bytes));
+    }
+}
+
+#[test]
+fn test_bigint_formatting_and_conversions() {
+    for (a, na) in &integers() {
+        assert_eq!(format!("{a}"), format!("{na}"));
+        assert_eq!(format!("{a:?}"), format!("{na:?}"));
+        assert_eq!(format!("{a:#x}"), format!("{na:#x}"));
+        assert_eq!(format!("{a:b}"), format!("{na:b}"));
+        assert_eq!(format!("{a:+}"), format!("{na:+}"));
+        assert_eq!(format!("{a:>60}"), format!("{na:>60}"));
+        same(a.to_i8(), na.to_i8());
+        same(a.to_i64(), na.to_i64());
+        same(a.to_u64(), na.to_u64());
+        same(a.to_i128(), na.to_i128());
+        same(a.to_u128(), na.to_u128());
+        same(a.to_f32(), na.to_f32());
+        same(a.to_f64(), na.to_f64());
+        same(i64::try_from(a).ok(), i64::try_from(na).ok());
+        same(u8::try_from(a.clone()).ok(), u8::try_from(na.clone()).ok());
+        same(a.to_biguint(), num_bigint::ToBigUint::to_biguint(na));
+    }
+    for x in [i128::MIN, -1, 0, 1, i128::MAX] {
+        same(BigInt::from(x), num_bigint::BigInt::from(x));
+        same(BigInt::from_i128(x), num_bigint::BigInt::from_i128(x));
+    }
+}
+
+#[test]
+fn test_sum_and_product() {
+    let xs = small_integers();
+    same(
+        xs.iter().map(|(x, _)| x).sum::<BigInt>(),
+        xs.iter().map(|(_, x)| x).sum::<num_bigint::BigInt>(),
+    );
+    same(
+        xs.iter().take(20).map(|(x, _)| x.clone()).product::<BigInt>(),
+        xs.iter().take(20).map(|(_, x)| x.clone()).product::<num_bigint::BigInt>(),
+    );
+    let xs = small_naturals();
+    same(
+        xs.iter().map(|(x, _)| x).sum::<BigUint>(),
+        xs.iter().map(|(_, x)| x).sum::<num_bigint::BigUint>(),
+    );
+    same(
+        xs.iter().skip(1).take(20).map(|(x, _)| x).product::<BigUint>(),
+        xs.iter().skip(1).take(20).map(|(_, x)| x).product::<num_bigint::BigUint>(),
+    );
+}
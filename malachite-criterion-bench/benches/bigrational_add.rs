@@ -0,0 +1,66 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+extern crate criterion;
+extern crate malachite_base;
+extern crate malachite_bigint_rational;
+extern crate malachite_nz;
+extern crate malachite_q;
+
+use criterion::*;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::random::random_primitive_ints;
+use malachite_base::random::{EXAMPLE_SEED, Seed};
+use malachite_bigint_rational::BigRational;
+use malachite_nz::natural::Natural;
+use malachite_nz::natural::random::get_random_natural_with_bits;
+use malachite_q::Rational;
+
+fn random_rational_with_bits(seed: Seed, bits: u64) -> Rational {
+    let n = get_random_natural_with_bits(&mut random_primitive_ints(seed.fork("n")), bits);
+    let d = get_random_natural_with_bits(&mut random_primitive_ints(seed.fork("d")), bits);
+    Rational::from_naturals(n, d + Natural::ONE)
+}
+
+// Compares `BigRational` arithmetic with the `Rational` arithmetic it wraps, to measure the
+// overhead of the wrapper.
+fn bench_add(c: &mut Criterion) {
+    let mut group = c.benchmark_group("BigRational + BigRational");
+    let plot_config = PlotConfiguration::default().summary_scale(AxisScale::Logarithmic);
+    group.plot_config(plot_config);
+    let sizes = [1u64, 10, 100, 1000, 10000, 100000, 1000000];
+    for &i in sizes.iter() {
+        let x = random_rational_with_bits(EXAMPLE_SEED.fork("a"), i);
+        let y = random_rational_with_bits(EXAMPLE_SEED.fork("b"), i);
+        let x_big = BigRational::from(x.clone());
+        let y_big = BigRational::from(y.clone());
+        group.bench_function(BenchmarkId::new("Rational", i), |b| {
+            b.iter_with_setup(|| (x.clone(), y.clone()), |(x, y)| x + y)
+        });
+        group.bench_function(BenchmarkId::new("BigRational", i), |b| {
+            b.iter_with_setup(|| (x_big.clone(), y_big.clone()), |(x, y)| x + y)
+        });
+        group.bench_function(BenchmarkId::new("BigRational then numer", i), |b| {
+            b.iter_with_setup(
+                || (x_big.clone(), y_big.clone()),
+                |(x, y)| {
+                    let z = x + y;
+                    z.numer().bits()
+                },
+            )
+        });
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().significance_level(0.1).sample_size(10);
+    targets = bench_add
+}
+criterion_main!(benches);
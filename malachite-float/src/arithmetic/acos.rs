@@ -0,0 +1,829 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::arithmetic::asin::sqrt_one_minus_square_fixed_point;
+use crate::arithmetic::atan::{atan_fixed_point, pi_multiple_prec_round};
+use crate::arithmetic::exp::{float_to_fixed_point, round_fixed_point_ziv};
+use crate::arithmetic::ln::ln_cancelled_bits;
+use crate::constants::pi::pi_fixed_point;
+use crate::{Float, float_nan, float_zero};
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{Acos, AcosAssign, IsPowerOf2};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+use malachite_nz::platform::Limb;
+
+// Given a finite [`Float`] $x$ with $|x| < 1$, returns an approximation $a$ of $2^s \arccos x$,
+// where $s$ is `scale`, with $|a - 2^s \arccos x| < 16$.
+//
+// If $|x| \leq 1/\sqrt{2}$, $\arccos x = \pi/2 - \arctan(x/\sqrt{1-x^2})$. Otherwise, $\arccos x =
+// \arctan(\sqrt{1-x^2}/x)$ if $x > 0$ and $\arccos x = \pi - \arctan(\sqrt{1-x^2}/|x|)$ if $x < 0$.
+fn acos_fixed_point(x: &Float, scale: u64) -> Integer {
+    let t = float_to_fixed_point(x, scale);
+    let y = Integer::from(sqrt_one_minus_square_fixed_point(x, scale));
+    if *t.unsigned_abs_ref() <= y {
+        Integer::from(pi_fixed_point(scale) >> 1u32) - atan_fixed_point(&((t << scale) / y), scale)
+    } else if t > 0u32 {
+        atan_fixed_point(&((y << scale) / t), scale)
+    } else {
+        Integer::from(pi_fixed_point(scale)) - atan_fixed_point(&((y << scale) / -t), scale)
+    }
+}
+
+impl Float {
+    /// Computes the arccosine of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded arccosine is less than, equal to, or greater than
+    /// the exact arccosine. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p+1}$.
+    /// - If $\arccos x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(1.0,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::acos_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::acos_round`] instead. If both of these things are true, consider using
+    /// [`Float::acos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact arccosine.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (acos, o) = Float::from(0.1).acos_prec_round(5, Floor);
+    /// assert_eq!(acos.to_string(), "1.44");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (acos, o) = Float::from(0.1).acos_prec_round(5, Ceiling);
+    /// assert_eq!(acos.to_string(), "1.5");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acos, o) = Float::from(0.1).acos_prec_round(5, Nearest);
+    /// assert_eq!(acos.to_string(), "1.5");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acos, o) = Float::from(0.1).acos_prec_round(20, Floor);
+    /// assert_eq!(acos.to_string(), "1.470629");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (acos, o) = Float::from(0.1).acos_prec_round(20, Ceiling);
+    /// assert_eq!(acos.to_string(), "1.470631");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acos, o) = Float::from(0.1).acos_prec_round(20, Nearest);
+    /// assert_eq!(acos.to_string(), "1.470629");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn acos_prec_round(mut self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        let o = self.acos_prec_round_assign(prec, rm);
+        (self, o)
+    }
+
+    /// Computes the arccosine of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded arccosine is less than, equal to, or greater
+    /// than the exact arccosine. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p+1}$.
+    /// - If $\arccos x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(1.0,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::acos_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::acos_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float)::acos()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact arccosine.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (acos, o) = Float::from(0.1).acos_prec_round_ref(5, Floor);
+    /// assert_eq!(acos.to_string(), "1.44");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (acos, o) = Float::from(0.1).acos_prec_round_ref(5, Ceiling);
+    /// assert_eq!(acos.to_string(), "1.5");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acos, o) = Float::from(0.1).acos_prec_round_ref(5, Nearest);
+    /// assert_eq!(acos.to_string(), "1.5");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acos, o) = Float::from(0.1).acos_prec_round_ref(20, Floor);
+    /// assert_eq!(acos.to_string(), "1.470629");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (acos, o) = Float::from(0.1).acos_prec_round_ref(20, Ceiling);
+    /// assert_eq!(acos.to_string(), "1.470631");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acos, o) = Float::from(0.1).acos_prec_round_ref(20, Nearest);
+    /// assert_eq!(acos.to_string(), "1.470629");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn acos_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(NaN | Infinity { .. }) => (float_nan!(), Equal),
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) if *exponent > 0 => {
+                if *exponent > 1 || !significand.is_power_of_2() {
+                    (float_nan!(), Equal)
+                } else if *sign {
+                    (float_zero!(), Equal)
+                } else {
+                    assert!(rm != Exact, "Inexact float arccosine");
+                    pi_multiple_prec_round(1, 0, prec, rm)
+                }
+            }
+            _ => {
+                assert!(rm != Exact, "Inexact float arccosine");
+                // When x is close to 1, arccos x is close to sqrt(2(1 - x)), so about half of the
+                // cancelled bits are lost
+                let cancelled = if *self > 0u32 {
+                    ln_cancelled_bits(self) >> 1
+                } else {
+                    0
+                };
+                round_fixed_point_ziv(prec + Limb::WIDTH + cancelled, prec, rm, |scale| {
+                    (acos_fixed_point(self, scale), 4)
+                })
+            }
+        }
+    }
+
+    /// Computes the arccosine of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded arccosine is less than, equal to, or greater than the exact
+    /// arccosine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the arccosine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arccos
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(1.0,p)=0.0$
+    /// - $f(x,p)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acos_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Float::acos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (acos, o) = Float::from(0.1).acos_prec(5);
+    /// assert_eq!(acos.to_string(), "1.5");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acos, o) = Float::from(0.1).acos_prec(20);
+    /// assert_eq!(acos.to_string(), "1.470629");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn acos_prec(self, prec: u64) -> (Float, Ordering) {
+        self.acos_prec_round(prec, Nearest)
+    }
+
+    /// Computes the arccosine of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded arccosine is less than, equal to, or greater than the exact
+    /// arccosine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the arccosine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arccos
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(1.0,p)=0.0$
+    /// - $f(x,p)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acos_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float)::acos()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (acos, o) = Float::from(0.1).acos_prec_ref(5);
+    /// assert_eq!(acos.to_string(), "1.5");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acos, o) = Float::from(0.1).acos_prec_ref(20);
+    /// assert_eq!(acos.to_string(), "1.470629");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn acos_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.acos_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes the arccosine of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded arccosine is less than, equal to, or greater than the exact arccosine. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\arccos x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(1.0,m)=0.0$
+    /// - $f(x,m)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::acos_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::acos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (acos, o) = Float::from(0.1).acos_round(Floor);
+    /// assert_eq!(acos.to_string(), "1.4706289056333368");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (acos, o) = Float::from(0.1).acos_round(Ceiling);
+    /// assert_eq!(acos.to_string(), "1.4706289056333373");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acos, o) = Float::from(0.1).acos_round(Nearest);
+    /// assert_eq!(acos.to_string(), "1.4706289056333368");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn acos_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.acos_prec_round(prec, rm)
+    }
+
+    /// Computes the arccosine of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded arccosine is less than, equal to, or greater than the exact arccosine. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\arccos x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(1.0,m)=0.0$
+    /// - $f(x,m)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::acos_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float)::acos()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (acos, o) = Float::from(0.1).acos_round_ref(Floor);
+    /// assert_eq!(acos.to_string(), "1.4706289056333368");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (acos, o) = Float::from(0.1).acos_round_ref(Ceiling);
+    /// assert_eq!(acos.to_string(), "1.4706289056333373");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acos, o) = Float::from(0.1).acos_round_ref(Nearest);
+    /// assert_eq!(acos.to_string(), "1.4706289056333368");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn acos_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.acos_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the arccosine of a [`Float`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded arccosine is less than, equal to, or greater than the exact arccosine.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p+1}$.
+    /// - If $\arccos x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::acos_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::acos_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::acos_round_assign`] instead. If both of these things are true, consider using
+    /// [`Float::acos_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact arccosine.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.acos_prec_round_assign(5, Floor), Less);
+    /// assert_eq!(x.to_string(), "1.44");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.acos_prec_round_assign(5, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.5");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.acos_prec_round_assign(5, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "1.5");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.acos_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "1.470629");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.acos_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.470631");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.acos_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "1.470629");
+    /// ```
+    pub fn acos_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let o;
+        (*self, o) = self.acos_prec_round_ref(prec, rm);
+        o
+    }
+
+    /// Computes the arccosine of a [`Float`] in place, rounding the result to the nearest value of
+    /// the specified precision. An [`Ordering`] is returned, indicating whether the rounded
+    /// arccosine is less than, equal to, or greater than the exact arccosine. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// If the arccosine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arccos
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::acos_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acos_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::acos_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.acos_prec_assign(5), Greater);
+    /// assert_eq!(x.to_string(), "1.5");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.acos_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "1.470629");
+    /// ```
+    #[inline]
+    pub fn acos_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.acos_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes the arccosine of a [`Float`] in place, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded arccosine is less
+    /// than, equal to, or greater than the exact arccosine. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\arccos x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::acos_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::acos_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::acos_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.acos_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "1.4706289056333368");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.acos_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.4706289056333373");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.acos_round_assign(Nearest), Less);
+    /// assert_eq!(x.to_string(), "1.4706289056333368");
+    /// ```
+    #[inline]
+    pub fn acos_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.acos_prec_round_assign(prec, rm)
+    }
+}
+
+impl Acos for Float {
+    type Output = Float;
+
+    /// Computes the arccosine of a [`Float`], taking it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the arccosine is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arccos
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=\text{NaN}$
+    /// - $f(1.0)=0.0$
+    /// - $f(x)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::acos_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::acos_round`].
+    /// If you want both of these things, consider using [`Float::acos_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Acos;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.acos().is_nan());
+    /// assert!(Float::INFINITY.acos().is_nan());
+    /// assert!(Float::NEGATIVE_INFINITY.acos().is_nan());
+    /// assert_eq!(Float::from(0.5).acos().to_string(), "1.0");
+    /// assert_eq!(Float::from(-0.5).acos().to_string(), "2.0");
+    /// ```
+    #[inline]
+    fn acos(self) -> Float {
+        let prec = self.significant_bits();
+        self.acos_prec_round(prec, Nearest).0
+    }
+}
+
+impl Acos for &Float {
+    type Output = Float;
+
+    /// Computes the arccosine of a [`Float`], taking it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the arccosine is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arccos
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=\text{NaN}$
+    /// - $f(1.0)=0.0$
+    /// - $f(x)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acos_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::acos_round_ref`]. If you want both of these things, consider using
+    /// [`Float::acos_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Acos;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).acos().is_nan());
+    /// assert!((&Float::INFINITY).acos().is_nan());
+    /// assert!((&Float::NEGATIVE_INFINITY).acos().is_nan());
+    /// assert_eq!((&Float::from(0.5)).acos().to_string(), "1.0");
+    /// assert_eq!((&Float::from(-0.5)).acos().to_string(), "2.0");
+    /// ```
+    #[inline]
+    fn acos(self) -> Float {
+        let prec = self.significant_bits();
+        self.acos_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl AcosAssign for Float {
+    /// Computes the arccosine of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the arccosine is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arccos
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::acos`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acos_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::acos_round_assign`]. If you want both of these things, consider using
+    /// [`Float::acos_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::AcosAssign;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::NAN;
+    /// x.acos_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x.acos_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x.acos_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(0.5);
+    /// x.acos_assign();
+    /// assert_eq!(x.to_string(), "1.0");
+    ///
+    /// let mut x = Float::from(-0.5);
+    /// x.acos_assign();
+    /// assert_eq!(x.to_string(), "2.0");
+    /// ```
+    #[inline]
+    fn acos_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.acos_prec_round_assign(prec, Nearest);
+    }
+}
@@ -0,0 +1,866 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::arithmetic::atan::{atan_fixed_point, pi_multiple_prec_round};
+use crate::arithmetic::exp::{float_to_fixed_point, round_fixed_point_ziv, round_near_float};
+use crate::constants::pi::pi_fixed_point;
+use crate::{Float, float_either_zero, float_nan};
+use core::cmp::Ordering::{self, *};
+use core::cmp::max;
+use malachite_base::num::arithmetic::traits::{
+    Abs, Asin, AsinAssign, FloorSqrt, IsPowerOf2, PowerOf2, Square,
+};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+
+// Given a finite [`Float`] $x$ with $|x| \leq 1$, returns an approximation $a$ of $2^s
+// \sqrt{1-x^2}$, where $s$ is `scale`, with $|a - 2^s \sqrt{1-x^2}| < 2$.
+pub(crate) fn sqrt_one_minus_square_fixed_point(x: &Float, scale: u64) -> Natural {
+    let one = Natural::power_of_2(scale << 1);
+    let x_squared = match x {
+        Float(Finite {
+            exponent,
+            significand,
+            ..
+        }) => {
+            // x^2 2^(2 * scale) = significand^2 2^shift
+            let shift = (i128::from(scale) + i128::from(*exponent)
+                - i128::from(significand.significant_bits()))
+                << 1;
+            let square = significand.square();
+            if shift >= 0 {
+                square << u64::exact_from(shift)
+            } else {
+                square >> u64::exact_from(-shift)
+            }
+        }
+        _ => return Natural::power_of_2(scale),
+    };
+    (one - x_squared).floor_sqrt()
+}
+
+// Given a finite, nonzero [`Float`] $x$ with $|x| \leq 1$, returns an approximation $a$ of $2^s
+// \arcsin x$, where $s$ is `scale`, with $|a - 2^s \arcsin x| < 16$.
+//
+// If $|x| \leq 1/\sqrt{2}$, $\arcsin x = \arctan(x/\sqrt{1-x^2})$; otherwise, $\arcsin x =
+// \operatorname{sgn}(x)(\pi/2 - \arctan(\sqrt{1-x^2}/|x|))$. In both cases the argument of the
+// arctangent has an error less than 6.
+fn asin_fixed_point(x: &Float, scale: u64) -> Integer {
+    let t = float_to_fixed_point(x, scale);
+    let y = Integer::from(sqrt_one_minus_square_fixed_point(x, scale));
+    if *t.unsigned_abs_ref() <= y {
+        atan_fixed_point(&((t << scale) / y), scale)
+    } else {
+        let sign = t > 0u32;
+        let a = Integer::from(pi_fixed_point(scale) >> 1u32)
+            - atan_fixed_point(&((y << scale) / t.abs()), scale);
+        if sign { a } else { -a }
+    }
+}
+
+impl Float {
+    /// Computes the arcsine of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded arcsine is less than, equal to, or greater than the
+    /// exact arcsine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p+1}$.
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::asin_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::asin_round`] instead. If both of these things are true, consider using
+    /// [`Float::asin`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact arcsine.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asin, o) = Float::from(0.1).asin_prec_round(5, Floor);
+    /// assert_eq!(asin.to_string(), "0.098");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asin, o) = Float::from(0.1).asin_prec_round(5, Ceiling);
+    /// assert_eq!(asin.to_string(), "0.1");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(0.1).asin_prec_round(5, Nearest);
+    /// assert_eq!(asin.to_string(), "0.1");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(0.1).asin_prec_round(20, Floor);
+    /// assert_eq!(asin.to_string(), "0.1001674");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asin, o) = Float::from(0.1).asin_prec_round(20, Ceiling);
+    /// assert_eq!(asin.to_string(), "0.1001675");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(0.1).asin_prec_round(20, Nearest);
+    /// assert_eq!(asin.to_string(), "0.1001674");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn asin_prec_round(mut self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        let o = self.asin_prec_round_assign(prec, rm);
+        (self, o)
+    }
+
+    /// Computes the arcsine of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded arcsine is less than, equal to, or greater than the
+    /// exact arcsine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p+1}$.
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::asin_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::asin_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float)::asin()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact arcsine.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asin, o) = Float::from(0.1).asin_prec_round_ref(5, Floor);
+    /// assert_eq!(asin.to_string(), "0.098");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asin, o) = Float::from(0.1).asin_prec_round_ref(5, Ceiling);
+    /// assert_eq!(asin.to_string(), "0.1");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(0.1).asin_prec_round_ref(5, Nearest);
+    /// assert_eq!(asin.to_string(), "0.1");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(0.1).asin_prec_round_ref(20, Floor);
+    /// assert_eq!(asin.to_string(), "0.1001674");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asin, o) = Float::from(0.1).asin_prec_round_ref(20, Ceiling);
+    /// assert_eq!(asin.to_string(), "0.1001675");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(0.1).asin_prec_round_ref(20, Nearest);
+    /// assert_eq!(asin.to_string(), "0.1001674");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn asin_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(NaN | Infinity { .. }) => (float_nan!(), Equal),
+            float_either_zero!() => (self.clone(), Equal),
+            _ => {
+                let exponent = self.get_exponent().unwrap();
+                if exponent > 1 || exponent == 1 && !self.significand_ref().unwrap().is_power_of_2()
+                {
+                    return (float_nan!(), Equal);
+                }
+                assert!(rm != Exact, "Inexact float arcsine");
+                if exponent == 1 {
+                    return pi_multiple_prec_round(if *self > 0u32 { 1 } else { -1 }, 1, prec, rm);
+                }
+                if exponent < 0
+                    && u64::from(exponent.unsigned_abs()) << 1
+                        >= max(prec, self.get_prec().unwrap()) + 2
+                {
+                    // |arcsin x - x| < |x|^3 / 3
+                    return round_near_float(
+                        self,
+                        if *self > 0u32 { Greater } else { Less },
+                        prec,
+                        rm,
+                    );
+                }
+                let scale = prec + Limb::WIDTH + u64::from(exponent.min(0).unsigned_abs());
+                round_fixed_point_ziv(scale, prec, rm, |scale| (asin_fixed_point(self, scale), 4))
+            }
+        }
+    }
+
+    /// Computes the arcsine of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded arcsine is less than, equal to, or greater than the exact
+    /// arcsine. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// If the arcsine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arcsin
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    /// - $f(x,p)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asin_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Float::asin`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asin, o) = Float::from(0.1).asin_prec(5);
+    /// assert_eq!(asin.to_string(), "0.1");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(0.1).asin_prec(20);
+    /// assert_eq!(asin.to_string(), "0.1001674");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn asin_prec(self, prec: u64) -> (Float, Ordering) {
+        self.asin_prec_round(prec, Nearest)
+    }
+
+    /// Computes the arcsine of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded arcsine is less than, equal to, or greater than the exact
+    /// arcsine. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// If the arcsine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arcsin
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    /// - $f(x,p)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asin_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float)::asin()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asin, o) = Float::from(0.1).asin_prec_ref(5);
+    /// assert_eq!(asin.to_string(), "0.1");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(0.1).asin_prec_ref(20);
+    /// assert_eq!(asin.to_string(), "0.1001674");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn asin_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.asin_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes the arcsine of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded arcsine is less than, equal to, or greater than the exact arcsine. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    /// - $f(x,m)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::asin_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::asin`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asin, o) = Float::from(0.1).asin_round(Floor);
+    /// assert_eq!(asin.to_string(), "0.1001674211615598");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asin, o) = Float::from(0.1).asin_round(Ceiling);
+    /// assert_eq!(asin.to_string(), "0.10016742116155983");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(0.1).asin_round(Nearest);
+    /// assert_eq!(asin.to_string(), "0.1001674211615598");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn asin_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.asin_prec_round(prec, rm)
+    }
+
+    /// Computes the arcsine of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded arcsine is less than, equal to, or greater than the exact arcsine. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    /// - $f(x,m)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::asin_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float)::asin()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asin, o) = Float::from(0.1).asin_round_ref(Floor);
+    /// assert_eq!(asin.to_string(), "0.1001674211615598");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asin, o) = Float::from(0.1).asin_round_ref(Ceiling);
+    /// assert_eq!(asin.to_string(), "0.10016742116155983");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(0.1).asin_round_ref(Nearest);
+    /// assert_eq!(asin.to_string(), "0.1001674211615598");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn asin_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.asin_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the arcsine of a [`Float`] in place, rounding the result to the specified precision
+    /// and with the specified rounding mode. An [`Ordering`] is returned, indicating whether the
+    /// rounded arcsine is less than, equal to, or greater than the exact arcsine. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it
+    /// also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p+1}$.
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::asin_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::asin_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::asin_round_assign`] instead. If both of these things are true, consider using
+    /// [`Float::asin_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact arcsine.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.asin_prec_round_assign(5, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.098");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.asin_prec_round_assign(5, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.1");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.asin_prec_round_assign(5, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "0.1");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.asin_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.1001674");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.asin_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.1001675");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.asin_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "0.1001674");
+    /// ```
+    pub fn asin_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let o;
+        (*self, o) = self.asin_prec_round_ref(prec, rm);
+        o
+    }
+
+    /// Computes the arcsine of a [`Float`] in place, rounding the result to the nearest value of
+    /// the specified precision. An [`Ordering`] is returned, indicating whether the rounded arcsine
+    /// is less than, equal to, or greater than the exact arcsine. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// If the arcsine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arcsin
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::asin_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asin_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::asin_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.asin_prec_assign(5), Greater);
+    /// assert_eq!(x.to_string(), "0.1");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.asin_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "0.1001674");
+    /// ```
+    #[inline]
+    pub fn asin_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.asin_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes the arcsine of a [`Float`] in place, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded arcsine is less
+    /// than, equal to, or greater than the exact arcsine. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::asin_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::asin_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::asin_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.asin_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "0.1001674211615598");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.asin_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.10016742116155983");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.asin_round_assign(Nearest), Less);
+    /// assert_eq!(x.to_string(), "0.1001674211615598");
+    /// ```
+    #[inline]
+    pub fn asin_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.asin_prec_round_assign(prec, rm)
+    }
+}
+
+impl Asin for Float {
+    type Output = Float;
+
+    /// Computes the arcsine of a [`Float`], taking it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the arcsine is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arcsin
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=\text{NaN}$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    /// - $f(x)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::asin_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::asin_round`].
+    /// If you want both of these things, consider using [`Float::asin_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Asin;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.asin().is_nan());
+    /// assert!(Float::INFINITY.asin().is_nan());
+    /// assert!(Float::NEGATIVE_INFINITY.asin().is_nan());
+    /// assert_eq!(Float::from(0.5).asin().to_string(), "0.5");
+    /// assert_eq!(Float::from(-0.5).asin().to_string(), "-0.5");
+    /// ```
+    #[inline]
+    fn asin(self) -> Float {
+        let prec = self.significant_bits();
+        self.asin_prec_round(prec, Nearest).0
+    }
+}
+
+impl Asin for &Float {
+    type Output = Float;
+
+    /// Computes the arcsine of a [`Float`], taking it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the arcsine is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arcsin
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=\text{NaN}$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    /// - $f(x)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asin_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::asin_round_ref`]. If you want both of these things, consider using
+    /// [`Float::asin_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Asin;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).asin().is_nan());
+    /// assert!((&Float::INFINITY).asin().is_nan());
+    /// assert!((&Float::NEGATIVE_INFINITY).asin().is_nan());
+    /// assert_eq!((&Float::from(0.5)).asin().to_string(), "0.5");
+    /// assert_eq!((&Float::from(-0.5)).asin().to_string(), "-0.5");
+    /// ```
+    #[inline]
+    fn asin(self) -> Float {
+        let prec = self.significant_bits();
+        self.asin_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl AsinAssign for Float {
+    /// Computes the arcsine of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the arcsine is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arcsin
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::asin`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asin_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::asin_round_assign`]. If you want both of these things, consider using
+    /// [`Float::asin_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::AsinAssign;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::NAN;
+    /// x.asin_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x.asin_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x.asin_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(0.5);
+    /// x.asin_assign();
+    /// assert_eq!(x.to_string(), "0.5");
+    ///
+    /// let mut x = Float::from(-0.5);
+    /// x.asin_assign();
+    /// assert_eq!(x.to_string(), "-0.5");
+    /// ```
+    #[inline]
+    fn asin_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.asin_prec_round_assign(prec, Nearest);
+    }
+}
@@ -0,0 +1,899 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::arithmetic::exp::{float_to_fixed_point, round_fixed_point_ziv, round_near_float};
+use crate::constants::pi::pi_fixed_point;
+use crate::{Float, float_either_zero, float_nan};
+use core::cmp::Ordering::{self, *};
+use core::cmp::max;
+use malachite_base::num::arithmetic::traits::{Atan, AtanAssign, FloorSqrt, PowerOf2, Square};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::Zero as ZeroTrait;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+
+// Returns $n\pi/2^k$ rounded to `prec` bits using `rm`, where $n$ is `numerator` and $k$ is
+// `log_denominator`. $|n|$ must be at most $2^k + 2^{k-1}$, so that the error of the fixed-point
+// approximation is less than 4.
+pub(crate) fn pi_multiple_prec_round(
+    numerator: i64,
+    log_denominator: u64,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    round_fixed_point_ziv(prec + Limb::WIDTH, prec, rm, |scale| {
+        (
+            (Integer::from(pi_fixed_point(scale)) * Integer::from(numerator)) >> log_denominator,
+            2,
+        )
+    })
+}
+
+// Given $t$ with $|t| \leq 2^s$, where $s$ is `scale`, returns an approximation $a$ of $2^s
+// \arctan(t/2^s)$ with $|a - 2^s \arctan(t/2^s)| < 2$.
+//
+// The argument is reduced $k \approx \sqrt{s}/2$ times using $\arctan x = 2 \arctan(x/(1 +
+// \sqrt{1+x^2}))$, and the Taylor series is summed at a slightly higher scale; the result is then
+// multiplied by $2^k$.
+pub(crate) fn atan_fixed_point(t: &Integer, scale: u64) -> Integer {
+    let halvings = max(scale.floor_sqrt() >> 1, 2);
+    let guard = scale.significant_bits() + 4;
+    let working_scale = scale + halvings + guard;
+    let one = Natural::power_of_2(working_scale);
+    let one_squared = Natural::power_of_2(working_scale << 1);
+    let mut u = t.unsigned_abs_ref() << (halvings + guard);
+    for _ in 0..halvings {
+        let root = ((&u).square() + &one_squared).floor_sqrt();
+        u = (u << working_scale) / (root + &one);
+    }
+    let u_squared = (&u).square() >> working_scale;
+    let mut sum = Integer::ZERO;
+    let mut term = u;
+    let mut d = 1u64;
+    let mut positive = true;
+    while term != 0u32 {
+        let q = Integer::from(&term / Natural::from(d));
+        if positive {
+            sum += q;
+        } else {
+            sum -= q;
+        }
+        positive = !positive;
+        term *= &u_squared;
+        term >>= working_scale;
+        d += 2;
+    }
+    if *t < 0u32 {
+        sum = -sum;
+    }
+    // arctan(t / 2^scale) = 2^halvings * sum / 2^working_scale
+    sum >> guard
+}
+
+// Returns $\lfloor 2^s/|x| \rfloor$, where $s$ is `scale` and $x$ is a nonzero finite [`Float`].
+fn reciprocal_fixed_point(x: &Float, scale: u64) -> Integer {
+    let Float(Finite {
+        exponent,
+        significand,
+        ..
+    }) = x
+    else {
+        panic!("Cannot take the reciprocal of {x}");
+    };
+    let shift =
+        i128::from(scale) + i128::from(significand.significant_bits()) - i128::from(*exponent);
+    if shift < 0 {
+        Integer::ZERO
+    } else {
+        Integer::from(Natural::power_of_2(u64::exact_from(shift)) / significand)
+    }
+}
+
+// Computes $\arctan x$ for a finite, nonzero `x`. If $|x| < 1$, the series is applied to $x$
+// directly; otherwise, $\arctan x = \operatorname{sgn}(x)\pi/2 - \arctan(1/x)$ is used.
+fn atan_prec_round_normal(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let exponent = x.get_exponent().unwrap();
+    let x_prec = x.get_prec().unwrap();
+    if exponent < 0 && u64::from(exponent.unsigned_abs()) << 1 >= max(prec, x_prec) + 2 {
+        // |arctan x - x| < |x|^3 / 3
+        return round_near_float(x, if *x > 0u32 { Less } else { Greater }, prec, rm);
+    }
+    let sign = *x > 0u32;
+    let scale = prec + Limb::WIDTH + u64::from(exponent.min(0).unsigned_abs());
+    round_fixed_point_ziv(scale, prec, rm, |scale| {
+        if exponent <= 0 {
+            // The error of the fixed-point argument is less than 1, so the total error is less
+            // than 3.
+            (atan_fixed_point(&float_to_fixed_point(x, scale), scale), 2)
+        } else {
+            // The errors of the argument, of pi / 2, and of the arctangent are less than 1, 2,
+            // and 2, so the total error is less than 5.
+            let a = Integer::from(pi_fixed_point(scale) >> 1u32)
+                - atan_fixed_point(&reciprocal_fixed_point(x, scale), scale);
+            (if sign { a } else { -a }, 3)
+        }
+    })
+}
+
+impl Float {
+    /// Computes the arctangent of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded arctangent is less than, equal to, or greater than
+    /// the exact arctangent. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p+1}$.
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::atan_round`] instead. If both of these things are true, consider using
+    /// [`Float::atan`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact arctangent.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec_round(5, Floor);
+    /// assert_eq!(atan.to_string(), "1.25");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec_round(5, Ceiling);
+    /// assert_eq!(atan.to_string(), "1.3");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec_round(5, Nearest);
+    /// assert_eq!(atan.to_string(), "1.25");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec_round(20, Floor);
+    /// assert_eq!(atan.to_string(), "1.262627");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec_round(20, Ceiling);
+    /// assert_eq!(atan.to_string(), "1.262629");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec_round(20, Nearest);
+    /// assert_eq!(atan.to_string(), "1.262627");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan_prec_round(mut self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        let o = self.atan_prec_round_assign(prec, rm);
+        (self, o)
+    }
+
+    /// Computes the arctangent of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded arctangent is less than, equal to, or greater
+    /// than the exact arctangent. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p+1}$.
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::atan_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float)::atan()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact arctangent.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec_round_ref(5, Floor);
+    /// assert_eq!(atan.to_string(), "1.25");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec_round_ref(5, Ceiling);
+    /// assert_eq!(atan.to_string(), "1.3");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec_round_ref(5, Nearest);
+    /// assert_eq!(atan.to_string(), "1.25");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec_round_ref(20, Floor);
+    /// assert_eq!(atan.to_string(), "1.262627");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec_round_ref(20, Ceiling);
+    /// assert_eq!(atan.to_string(), "1.262629");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec_round_ref(20, Nearest);
+    /// assert_eq!(atan.to_string(), "1.262627");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn atan_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            float_nan!() => (float_nan!(), Equal),
+            float_either_zero!() => (self.clone(), Equal),
+            Float(Infinity { sign }) => {
+                assert!(rm != Exact, "Inexact float arctangent");
+                pi_multiple_prec_round(if *sign { 1 } else { -1 }, 1, prec, rm)
+            }
+            _ => {
+                assert!(rm != Exact, "Inexact float arctangent");
+                atan_prec_round_normal(self, prec, rm)
+            }
+        }
+    }
+
+    /// Computes the arctangent of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded arctangent is less than, equal to, or greater than the exact
+    /// arctangent. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arctan
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Float::atan`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec(5);
+    /// assert_eq!(atan.to_string(), "1.25");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec(20);
+    /// assert_eq!(atan.to_string(), "1.262627");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan_prec(self, prec: u64) -> (Float, Ordering) {
+        self.atan_prec_round(prec, Nearest)
+    }
+
+    /// Computes the arctangent of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded arctangent is less than, equal to, or greater than the exact
+    /// arctangent. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arctan
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float)::atan()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec_ref(5);
+    /// assert_eq!(atan.to_string(), "1.25");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec_ref(20);
+    /// assert_eq!(atan.to_string(), "1.262627");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.atan_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes the arctangent of a [`Float`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether
+    /// the rounded arctangent is less than, equal to, or greater than the exact arctangent.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::atan_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::atan`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan, o) = Float::from(PI).atan_round(Floor);
+    /// assert_eq!(atan.to_string(), "1.262627255678911");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_round(Ceiling);
+    /// assert_eq!(atan.to_string(), "1.262627255678913");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_round(Nearest);
+    /// assert_eq!(atan.to_string(), "1.262627255678911");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.atan_prec_round(prec, rm)
+    }
+
+    /// Computes the arctangent of a [`Float`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded arctangent is less than, equal to, or greater than the exact arctangent.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::atan_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float)::atan()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan, o) = Float::from(PI).atan_round_ref(Floor);
+    /// assert_eq!(atan.to_string(), "1.262627255678911");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_round_ref(Ceiling);
+    /// assert_eq!(atan.to_string(), "1.262627255678913");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_round_ref(Nearest);
+    /// assert_eq!(atan.to_string(), "1.262627255678911");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.atan_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the arctangent of a [`Float`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded arctangent is less than, equal to, or greater than the exact arctangent.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p+1}$.
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::atan_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::atan_round_assign`] instead. If both of these things are true, consider using
+    /// [`Float::atan_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact arctangent.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_prec_round_assign(5, Floor), Less);
+    /// assert_eq!(x.to_string(), "1.25");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_prec_round_assign(5, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.3");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_prec_round_assign(5, Nearest), Less);
+    /// assert_eq!(x.to_string(), "1.25");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "1.262627");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.262629");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "1.262627");
+    /// ```
+    pub fn atan_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let o;
+        (*self, o) = self.atan_prec_round_ref(prec, rm);
+        o
+    }
+
+    /// Computes the arctangent of a [`Float`] in place, rounding the result to the nearest value of
+    /// the specified precision. An [`Ordering`] is returned, indicating whether the rounded
+    /// arctangent is less than, equal to, or greater than the exact arctangent. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arctan
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::atan_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::atan_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_prec_assign(5), Less);
+    /// assert_eq!(x.to_string(), "1.25");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "1.262627");
+    /// ```
+    #[inline]
+    pub fn atan_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.atan_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes the arctangent of a [`Float`] in place, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded arctangent is
+    /// less than, equal to, or greater than the exact arctangent. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::atan_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::atan_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::atan_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "1.262627255678911");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.262627255678913");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_round_assign(Nearest), Less);
+    /// assert_eq!(x.to_string(), "1.262627255678911");
+    /// ```
+    #[inline]
+    pub fn atan_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.atan_prec_round_assign(prec, rm)
+    }
+}
+
+impl Atan for Float {
+    type Output = Float;
+
+    /// Computes the arctangent of a [`Float`], taking it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the arctangent is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arctan
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::atan_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::atan_round`].
+    /// If you want both of these things, consider using [`Float::atan_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Atan;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.atan().is_nan());
+    /// assert_eq!(Float::INFINITY.atan().to_string(), "2.0");
+    /// assert_eq!(Float::NEGATIVE_INFINITY.atan().to_string(), "-2.0");
+    /// assert_eq!(Float::from(1.5).atan().to_string(), "1.0");
+    /// assert_eq!(Float::from(-1.5).atan().to_string(), "-1.0");
+    /// ```
+    #[inline]
+    fn atan(self) -> Float {
+        let prec = self.significant_bits();
+        self.atan_prec_round(prec, Nearest).0
+    }
+}
+
+impl Atan for &Float {
+    type Output = Float;
+
+    /// Computes the arctangent of a [`Float`], taking it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the arctangent is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arctan
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::atan_round_ref`]. If you want both of these things, consider using
+    /// [`Float::atan_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Atan;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).atan().is_nan());
+    /// assert_eq!((&Float::INFINITY).atan().to_string(), "2.0");
+    /// assert_eq!((&Float::NEGATIVE_INFINITY).atan().to_string(), "-2.0");
+    /// assert_eq!((&Float::from(1.5)).atan().to_string(), "1.0");
+    /// assert_eq!((&Float::from(-1.5)).atan().to_string(), "-1.0");
+    /// ```
+    #[inline]
+    fn atan(self) -> Float {
+        let prec = self.significant_bits();
+        self.atan_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl AtanAssign for Float {
+    /// Computes the arctangent of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the arctangent is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arctan
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::atan`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::atan_round_assign`]. If you want both of these things, consider using
+    /// [`Float::atan_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::AtanAssign;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::NAN;
+    /// x.atan_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x.atan_assign();
+    /// assert_eq!(x.to_string(), "2.0");
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x.atan_assign();
+    /// assert_eq!(x.to_string(), "-2.0");
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.atan_assign();
+    /// assert_eq!(x.to_string(), "1.0");
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x.atan_assign();
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// ```
+    #[inline]
+    fn atan_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.atan_prec_round_assign(prec, Nearest);
+    }
+}
@@ -0,0 +1,2133 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::arithmetic::atan::pi_multiple_prec_round;
+use crate::{Float, float_either_zero, float_infinity, float_nan, float_negative_infinity};
+use core::cmp::Ordering::{self, *};
+use core::cmp::max;
+use malachite_base::num::arithmetic::traits::{Atan2, Atan2Assign, CeilingLogBase2};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{NegativeZero, Zero as ZeroTrait};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::natural::arithmetic::float_extras::float_can_round;
+use malachite_nz::platform::Limb;
+
+// Computes $\operatorname{atan2}(y, x)$ for finite, nonzero `y` and `x`. This follows MPFR's
+// implementation: if $x > 0$ the result is $\arctan(y/x)$, and otherwise it is
+// $\operatorname{sgn}(y)(\pi - \arctan|y/x|)$. Both are evaluated at an increasing working
+// precision until the result can be correctly rounded.
+//
+// If $y/x$ is outside the range of `Float`s, the result is determined from the exponents and
+// significands of the inputs instead.
+fn atan2_prec_round_normal(y: &Float, x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let (
+        Float(Finite {
+            sign: y_sign,
+            exponent: y_exponent,
+            significand: y_significand,
+            ..
+        }),
+        Float(Finite {
+            sign: x_sign,
+            exponent: x_exponent,
+            significand: x_significand,
+            ..
+        }),
+    ) = (y, x)
+    else {
+        unreachable!()
+    };
+    let y_sign = *y_sign;
+    // |y/x| is in [2^(d - 1), 2^(d + 1))
+    let d = i64::from(*y_exponent) - i64::from(*x_exponent);
+    if d >= i64::from(Float::MAX_EXPONENT) - 1 {
+        // |y/x| >= 2^(2^30 - 3), so the result is much too close to +/-pi/2 to round differently
+        return pi_multiple_prec_round(if y_sign { 1 } else { -1 }, 1, prec, rm);
+    }
+    let min_exponent = i64::from(Float::MIN_EXPONENT);
+    if d < min_exponent {
+        if !*x_sign {
+            // |y/x| < 2^(-2^30 + 1), so the result is much too close to +/-pi to round differently
+            return pi_multiple_prec_round(if y_sign { 1 } else { -1 }, 0, prec, rm);
+        }
+        // |y/x| = q 2^(m - k), where m is the exponent of the smallest positive Float and 1/2 < q
+        // < 2. Since |arctan t| < |t|, and arctan t is extremely close to t, the result
+        // underflows exactly when |y/x| does.
+        let k = min_exponent - 1 - d;
+        let q_cmp_1 = (y_significand << x_significand.significant_bits())
+            .cmp(&(x_significand << y_significand.significant_bits()));
+        if k > 0 || q_cmp_1 == Less {
+            // Whether |y/x| is greater than half of the smallest positive Float
+            let round_up = k == 0 || k == 1 && q_cmp_1 == Greater;
+            return match (y_sign, rm) {
+                (_, Exact) => unreachable!(),
+                (true, Floor | Down) => (Float::ZERO, Less),
+                (true, Nearest) if !round_up => (Float::ZERO, Less),
+                (true, Ceiling | Up | Nearest) => (Float::min_positive_value_prec(prec), Greater),
+                (false, Ceiling | Down) => (Float::NEGATIVE_ZERO, Greater),
+                (false, Nearest) if !round_up => (Float::NEGATIVE_ZERO, Greater),
+                (false, Floor | Up | Nearest) => (-Float::min_positive_value_prec(prec), Less),
+            };
+        }
+    }
+    let mut working_prec = prec + 3 + prec.ceiling_log_base_2();
+    let mut increment = Limb::WIDTH;
+    if *x_sign {
+        loop {
+            let (q, o) = y.div_prec_round_ref_ref(x, working_prec, Nearest);
+            if o == Equal {
+                return q.atan_prec_round(prec, rm);
+            }
+            // The error of the arctangent is at most 2 ulps
+            let (mut a, _) = q.atan_prec_round(working_prec, Nearest);
+            if float_can_round(a.significand_ref().unwrap(), working_prec - 2, prec, rm) {
+                let o = a.set_prec_round(prec, rm);
+                return (a, o);
+            }
+            working_prec += increment;
+            increment = working_prec >> 1;
+        }
+    } else {
+        loop {
+            let (mut q, _) = y.div_prec_round_ref_ref(x, working_prec, Nearest);
+            if q < 0u32 {
+                q = -q;
+            }
+            let (a, _) = q.atan_prec_round(working_prec, Nearest);
+            let (pi, _) = pi_multiple_prec_round(1, 0, working_prec, Nearest);
+            let a_exponent = a
+                .get_exponent()
+                .map_or(i64::from(Float::MIN_EXPONENT) - 1, i64::from);
+            let (mut result, _) = pi.sub_prec(a, working_prec);
+            let result_exponent = i64::from(result.get_exponent().unwrap());
+            let err = max(
+                max(1 - result_exponent, a_exponent - result_exponent + 1),
+                -1,
+            ) + 2;
+            if float_can_round(
+                result.significand_ref().unwrap(),
+                working_prec - u64::try_from(err).unwrap(),
+                prec,
+                rm,
+            ) {
+                if !y_sign {
+                    result = -result;
+                }
+                let o = result.set_prec_round(prec, rm);
+                return (result, o);
+            }
+            working_prec += increment;
+            increment = working_prec >> 1;
+        }
+    }
+}
+
+impl Float {
+    /// Computes $\operatorname{atan2}(y,x)$, the angle of the point $(x,y)$ in the plane, where $y$
+    /// is `self` and $x$ is `other`, rounding the result to the specified precision and with the
+    /// specified rounding mode. Both [`Float`]s are taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded angle is less than, equal to, or greater than the
+    /// exact angle. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(y,x,p,m) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(y,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(0.0,x,p,m)=0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(0.0,x,p,m)=\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(-0.0,x,p,m)=-\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(\infty,\infty,p,m)=\pi/4$
+    /// - $f(-\infty,\infty,p,m)=-\pi/4$
+    /// - $f(\infty,-\infty,p,m)=3\pi/4$
+    /// - $f(-\infty,-\infty,p,m)=-3\pi/4$
+    /// - $f(\infty,x,p,m)=\pi/2$ if $x$ is finite
+    /// - $f(-\infty,x,p,m)=-\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0,p,m)=\pi/2$ if $0.0<y<\infty$
+    /// - $f(y,\pm0.0,p,m)=-\pi/2$ if $-\infty<y<0.0$
+    /// - $f(y,\infty,p,m)=0.0$ if $0.0<y<\infty$
+    /// - $f(y,\infty,p,m)=-0.0$ if $-\infty<y<0.0$
+    /// - $f(y,-\infty,p,m)=\pi$ if $0.0<y<\infty$
+    /// - $f(y,-\infty,p,m)=-\pi$ if $-\infty<y<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan2_prec`] instead. If you
+    /// know that your target precision is the maximum of the precisions of the two inputs, consider
+    /// using [`Float::atan2_round`] instead. If both of these things are true, consider using
+    /// [`Atan2::atan2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact arctangent.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round(Float::from(E), 5, Floor);
+    /// assert_eq!(atan2.to_string(), "0.84");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round(Float::from(E), 5, Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.88");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round(Float::from(E), 5, Nearest);
+    /// assert_eq!(atan2.to_string(), "0.84");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round(Float::from(E), 20, Floor);
+    /// assert_eq!(atan2.to_string(), "0.8575115");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round(Float::from(E), 20, Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.8575125");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round(Float::from(E), 20, Nearest);
+    /// assert_eq!(atan2.to_string(), "0.8575115");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn atan2_prec_round(self, other: Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        self.atan2_prec_round_ref_ref(&other, prec, rm)
+    }
+
+    /// Computes $\operatorname{atan2}(y,x)$, the angle of the point $(x,y)$ in the plane, where $y$
+    /// is `self` and $x$ is `other`, rounding the result to the specified precision and with the
+    /// specified rounding mode. The first [`Float`] is taken by value and the second by reference.
+    /// An [`Ordering`] is also returned, indicating whether the rounded angle is less than, equal
+    /// to, or greater than the exact angle. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(y,x,p,m) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(y,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(0.0,x,p,m)=0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(0.0,x,p,m)=\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(-0.0,x,p,m)=-\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(\infty,\infty,p,m)=\pi/4$
+    /// - $f(-\infty,\infty,p,m)=-\pi/4$
+    /// - $f(\infty,-\infty,p,m)=3\pi/4$
+    /// - $f(-\infty,-\infty,p,m)=-3\pi/4$
+    /// - $f(\infty,x,p,m)=\pi/2$ if $x$ is finite
+    /// - $f(-\infty,x,p,m)=-\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0,p,m)=\pi/2$ if $0.0<y<\infty$
+    /// - $f(y,\pm0.0,p,m)=-\pi/2$ if $-\infty<y<0.0$
+    /// - $f(y,\infty,p,m)=0.0$ if $0.0<y<\infty$
+    /// - $f(y,\infty,p,m)=-0.0$ if $-\infty<y<0.0$
+    /// - $f(y,-\infty,p,m)=\pi$ if $0.0<y<\infty$
+    /// - $f(y,-\infty,p,m)=-\pi$ if $-\infty<y<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan2_prec_val_ref`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::atan2_round_val_ref`] instead. If both of these things are true,
+    /// consider using [`Atan2::atan2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact arctangent.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 5, Floor);
+    /// assert_eq!(atan2.to_string(), "0.84");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 5, Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.88");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 5, Nearest);
+    /// assert_eq!(atan2.to_string(), "0.84");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 20, Floor);
+    /// assert_eq!(atan2.to_string(), "0.8575115");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 20, Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.8575125");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 20, Nearest);
+    /// assert_eq!(atan2.to_string(), "0.8575115");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan2_prec_round_val_ref(
+        self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        self.atan2_prec_round_ref_ref(other, prec, rm)
+    }
+
+    /// Computes $\operatorname{atan2}(y,x)$, the angle of the point $(x,y)$ in the plane, where $y$
+    /// is `self` and $x$ is `other`, rounding the result to the specified precision and with the
+    /// specified rounding mode. The first [`Float`] is taken by reference and the second by value.
+    /// An [`Ordering`] is also returned, indicating whether the rounded angle is less than, equal
+    /// to, or greater than the exact angle. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(y,x,p,m) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(y,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(0.0,x,p,m)=0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(0.0,x,p,m)=\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(-0.0,x,p,m)=-\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(\infty,\infty,p,m)=\pi/4$
+    /// - $f(-\infty,\infty,p,m)=-\pi/4$
+    /// - $f(\infty,-\infty,p,m)=3\pi/4$
+    /// - $f(-\infty,-\infty,p,m)=-3\pi/4$
+    /// - $f(\infty,x,p,m)=\pi/2$ if $x$ is finite
+    /// - $f(-\infty,x,p,m)=-\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0,p,m)=\pi/2$ if $0.0<y<\infty$
+    /// - $f(y,\pm0.0,p,m)=-\pi/2$ if $-\infty<y<0.0$
+    /// - $f(y,\infty,p,m)=0.0$ if $0.0<y<\infty$
+    /// - $f(y,\infty,p,m)=-0.0$ if $-\infty<y<0.0$
+    /// - $f(y,-\infty,p,m)=\pi$ if $0.0<y<\infty$
+    /// - $f(y,-\infty,p,m)=-\pi$ if $-\infty<y<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan2_prec_ref_val`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::atan2_round_ref_val`] instead. If both of these things are true,
+    /// consider using [`Atan2::atan2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact arctangent.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 5, Floor);
+    /// assert_eq!(atan2.to_string(), "0.84");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 5, Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.88");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 5, Nearest);
+    /// assert_eq!(atan2.to_string(), "0.84");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 20, Floor);
+    /// assert_eq!(atan2.to_string(), "0.8575115");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 20, Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.8575125");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 20, Nearest);
+    /// assert_eq!(atan2.to_string(), "0.8575115");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn atan2_prec_round_ref_val(
+        &self,
+        other: Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        self.atan2_prec_round_ref_ref(&other, prec, rm)
+    }
+
+    /// Computes $\operatorname{atan2}(y,x)$, the angle of the point $(x,y)$ in the plane, where $y$
+    /// is `self` and $x$ is `other`, rounding the result to the specified precision and with the
+    /// specified rounding mode. Both [`Float`]s are taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded angle is less than, equal to, or greater than the
+    /// exact angle. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(y,x,p,m) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(y,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(0.0,x,p,m)=0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(0.0,x,p,m)=\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(-0.0,x,p,m)=-\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(\infty,\infty,p,m)=\pi/4$
+    /// - $f(-\infty,\infty,p,m)=-\pi/4$
+    /// - $f(\infty,-\infty,p,m)=3\pi/4$
+    /// - $f(-\infty,-\infty,p,m)=-3\pi/4$
+    /// - $f(\infty,x,p,m)=\pi/2$ if $x$ is finite
+    /// - $f(-\infty,x,p,m)=-\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0,p,m)=\pi/2$ if $0.0<y<\infty$
+    /// - $f(y,\pm0.0,p,m)=-\pi/2$ if $-\infty<y<0.0$
+    /// - $f(y,\infty,p,m)=0.0$ if $0.0<y<\infty$
+    /// - $f(y,\infty,p,m)=-0.0$ if $-\infty<y<0.0$
+    /// - $f(y,-\infty,p,m)=\pi$ if $0.0<y<\infty$
+    /// - $f(y,-\infty,p,m)=-\pi$ if $-\infty<y<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan2_prec_ref_ref`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::atan2_round_ref_ref`] instead. If both of these things are true,
+    /// consider using [`Atan2::atan2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact arctangent.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 5, Floor);
+    /// assert_eq!(atan2.to_string(), "0.84");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 5, Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.88");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 5, Nearest);
+    /// assert_eq!(atan2.to_string(), "0.84");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 20, Floor);
+    /// assert_eq!(atan2.to_string(), "0.8575115");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 20, Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.8575125");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 20, Nearest);
+    /// assert_eq!(atan2.to_string(), "0.8575115");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn atan2_prec_round_ref_ref(
+        &self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match (self, other) {
+            (float_nan!(), _) | (_, float_nan!()) => (float_nan!(), Equal),
+            (Float(Zero { .. }), x) if x.is_sign_positive() => (self.clone(), Equal),
+            (Float(Zero { sign }), _)
+            | (Float(Finite { sign, .. }), float_negative_infinity!()) => {
+                assert!(rm != Exact, "Inexact float atan2");
+                pi_multiple_prec_round(if *sign { 1 } else { -1 }, 0, prec, rm)
+            }
+            (Float(Infinity { sign }), Float(Infinity { sign: x_sign })) => {
+                assert!(rm != Exact, "Inexact float atan2");
+                let numerator = if *x_sign { 1 } else { 3 };
+                pi_multiple_prec_round(if *sign { numerator } else { -numerator }, 2, prec, rm)
+            }
+            (Float(Infinity { sign } | Finite { sign, .. }), float_either_zero!())
+            | (Float(Infinity { sign }), _) => {
+                assert!(rm != Exact, "Inexact float atan2");
+                pi_multiple_prec_round(if *sign { 1 } else { -1 }, 1, prec, rm)
+            }
+            (Float(Finite { sign, .. }), float_infinity!()) => (
+                if *sign {
+                    Float::ZERO
+                } else {
+                    Float::NEGATIVE_ZERO
+                },
+                Equal,
+            ),
+            _ => {
+                assert!(rm != Exact, "Inexact float atan2");
+                atan2_prec_round_normal(self, other, prec, rm)
+            }
+        }
+    }
+
+    /// Computes $\operatorname{atan2}(y,x)$, the angle of the point $(x,y)$ in the plane, where $y$
+    /// is `self` and $x$ is `other`, rounding the result to the nearest value of the specified
+    /// precision. Both [`Float`]s are taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded angle is less than, equal to, or greater than the exact angle. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// If the angle is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(y,x,p) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(y,\text{NaN},p)=\text{NaN}$
+    /// - $f(0.0,x,p)=0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(-0.0,x,p)=-0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(0.0,x,p)=\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(-0.0,x,p)=-\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(\infty,\infty,p)=\pi/4$
+    /// - $f(-\infty,\infty,p)=-\pi/4$
+    /// - $f(\infty,-\infty,p)=3\pi/4$
+    /// - $f(-\infty,-\infty,p)=-3\pi/4$
+    /// - $f(\infty,x,p)=\pi/2$ if $x$ is finite
+    /// - $f(-\infty,x,p)=-\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0,p)=\pi/2$ if $0.0<y<\infty$
+    /// - $f(y,\pm0.0,p)=-\pi/2$ if $-\infty<y<0.0$
+    /// - $f(y,\infty,p)=0.0$ if $0.0<y<\infty$
+    /// - $f(y,\infty,p)=-0.0$ if $-\infty<y<0.0$
+    /// - $f(y,-\infty,p)=\pi$ if $0.0<y<\infty$
+    /// - $f(y,-\infty,p)=-\pi$ if $-\infty<y<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_round`] instead. If you know that your target precision is the maximum
+    /// of the precisions of the two inputs, consider using [`Atan2::atan2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec(Float::from(E), 5);
+    /// assert_eq!(atan2.to_string(), "0.84");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec(Float::from(E), 20);
+    /// assert_eq!(atan2.to_string(), "0.8575115");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn atan2_prec(self, other: Float, prec: u64) -> (Float, Ordering) {
+        self.atan2_prec_round(other, prec, Nearest)
+    }
+
+    /// Computes $\operatorname{atan2}(y,x)$, the angle of the point $(x,y)$ in the plane, where $y$
+    /// is `self` and $x$ is `other`, rounding the result to the nearest value of the specified
+    /// precision. The first [`Float`] is taken by value and the second by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded angle is less than, equal to,
+    /// or greater than the exact angle. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the angle is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(y,x,p) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(y,\text{NaN},p)=\text{NaN}$
+    /// - $f(0.0,x,p)=0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(-0.0,x,p)=-0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(0.0,x,p)=\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(-0.0,x,p)=-\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(\infty,\infty,p)=\pi/4$
+    /// - $f(-\infty,\infty,p)=-\pi/4$
+    /// - $f(\infty,-\infty,p)=3\pi/4$
+    /// - $f(-\infty,-\infty,p)=-3\pi/4$
+    /// - $f(\infty,x,p)=\pi/2$ if $x$ is finite
+    /// - $f(-\infty,x,p)=-\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0,p)=\pi/2$ if $0.0<y<\infty$
+    /// - $f(y,\pm0.0,p)=-\pi/2$ if $-\infty<y<0.0$
+    /// - $f(y,\infty,p)=0.0$ if $0.0<y<\infty$
+    /// - $f(y,\infty,p)=-0.0$ if $-\infty<y<0.0$
+    /// - $f(y,-\infty,p)=\pi$ if $0.0<y<\infty$
+    /// - $f(y,-\infty,p)=-\pi$ if $-\infty<y<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_round_val_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using [`Atan2::atan2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_val_ref(&Float::from(E), 5);
+    /// assert_eq!(atan2.to_string(), "0.84");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_val_ref(&Float::from(E), 20);
+    /// assert_eq!(atan2.to_string(), "0.8575115");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan2_prec_val_ref(self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.atan2_prec_round_val_ref(other, prec, Nearest)
+    }
+
+    /// Computes $\operatorname{atan2}(y,x)$, the angle of the point $(x,y)$ in the plane, where $y$
+    /// is `self` and $x$ is `other`, rounding the result to the nearest value of the specified
+    /// precision. The first [`Float`] is taken by reference and the second by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded angle is less than, equal to,
+    /// or greater than the exact angle. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the angle is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(y,x,p) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(y,\text{NaN},p)=\text{NaN}$
+    /// - $f(0.0,x,p)=0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(-0.0,x,p)=-0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(0.0,x,p)=\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(-0.0,x,p)=-\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(\infty,\infty,p)=\pi/4$
+    /// - $f(-\infty,\infty,p)=-\pi/4$
+    /// - $f(\infty,-\infty,p)=3\pi/4$
+    /// - $f(-\infty,-\infty,p)=-3\pi/4$
+    /// - $f(\infty,x,p)=\pi/2$ if $x$ is finite
+    /// - $f(-\infty,x,p)=-\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0,p)=\pi/2$ if $0.0<y<\infty$
+    /// - $f(y,\pm0.0,p)=-\pi/2$ if $-\infty<y<0.0$
+    /// - $f(y,\infty,p)=0.0$ if $0.0<y<\infty$
+    /// - $f(y,\infty,p)=-0.0$ if $-\infty<y<0.0$
+    /// - $f(y,-\infty,p)=\pi$ if $0.0<y<\infty$
+    /// - $f(y,-\infty,p)=-\pi$ if $-\infty<y<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_round_ref_val`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using [`Atan2::atan2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_ref_val(Float::from(E), 5);
+    /// assert_eq!(atan2.to_string(), "0.84");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_ref_val(Float::from(E), 20);
+    /// assert_eq!(atan2.to_string(), "0.8575115");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn atan2_prec_ref_val(&self, other: Float, prec: u64) -> (Float, Ordering) {
+        self.atan2_prec_round_ref_val(other, prec, Nearest)
+    }
+
+    /// Computes $\operatorname{atan2}(y,x)$, the angle of the point $(x,y)$ in the plane, where $y$
+    /// is `self` and $x$ is `other`, rounding the result to the nearest value of the specified
+    /// precision. Both [`Float`]s are taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded angle is less than, equal to, or greater than the exact
+    /// angle. Although `NaN`s are not comparable to any [`Float`], whenever this function returns a
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// If the angle is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(y,x,p) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(y,\text{NaN},p)=\text{NaN}$
+    /// - $f(0.0,x,p)=0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(-0.0,x,p)=-0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(0.0,x,p)=\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(-0.0,x,p)=-\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(\infty,\infty,p)=\pi/4$
+    /// - $f(-\infty,\infty,p)=-\pi/4$
+    /// - $f(\infty,-\infty,p)=3\pi/4$
+    /// - $f(-\infty,-\infty,p)=-3\pi/4$
+    /// - $f(\infty,x,p)=\pi/2$ if $x$ is finite
+    /// - $f(-\infty,x,p)=-\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0,p)=\pi/2$ if $0.0<y<\infty$
+    /// - $f(y,\pm0.0,p)=-\pi/2$ if $-\infty<y<0.0$
+    /// - $f(y,\infty,p)=0.0$ if $0.0<y<\infty$
+    /// - $f(y,\infty,p)=-0.0$ if $-\infty<y<0.0$
+    /// - $f(y,-\infty,p)=\pi$ if $0.0<y<\infty$
+    /// - $f(y,-\infty,p)=-\pi$ if $-\infty<y<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_round_ref_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using [`Atan2::atan2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_ref_ref(&Float::from(E), 5);
+    /// assert_eq!(atan2.to_string(), "0.84");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_ref_ref(&Float::from(E), 20);
+    /// assert_eq!(atan2.to_string(), "0.8575115");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan2_prec_ref_ref(&self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.atan2_prec_round_ref_ref(other, prec, Nearest)
+    }
+
+    /// Computes $\operatorname{atan2}(y,x)$, the angle of the point $(x,y)$ in the plane, where $y$
+    /// is `self` and $x$ is `other`, rounding the result with the specified rounding mode. Both
+    /// [`Float`]s are taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded angle is less than, equal to, or greater than the exact angle. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(y,x,m) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(y,\text{NaN},m)=\text{NaN}$
+    /// - $f(0.0,x,m)=0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(-0.0,x,m)=-0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(0.0,x,m)=\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(-0.0,x,m)=-\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(\infty,\infty,m)=\pi/4$
+    /// - $f(-\infty,\infty,m)=-\pi/4$
+    /// - $f(\infty,-\infty,m)=3\pi/4$
+    /// - $f(-\infty,-\infty,m)=-3\pi/4$
+    /// - $f(\infty,x,m)=\pi/2$ if $x$ is finite
+    /// - $f(-\infty,x,m)=-\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0,m)=\pi/2$ if $0.0<y<\infty$
+    /// - $f(y,\pm0.0,m)=-\pi/2$ if $-\infty<y<0.0$
+    /// - $f(y,\infty,m)=0.0$ if $0.0<y<\infty$
+    /// - $f(y,\infty,m)=-0.0$ if $-\infty<y<0.0$
+    /// - $f(y,-\infty,m)=\pi$ if $0.0<y<\infty$
+    /// - $f(y,-\infty,m)=-\pi$ if $-\infty<y<0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::atan2_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Atan2::atan2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round(Float::from(E), Floor);
+    /// assert_eq!(atan2.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round(Float::from(E), Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.8575117863558464");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round(Float::from(E), Nearest);
+    /// assert_eq!(atan2.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn atan2_round(self, other: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round(other, prec, rm)
+    }
+
+    /// Computes $\operatorname{atan2}(y,x)$, the angle of the point $(x,y)$ in the plane, where $y$
+    /// is `self` and $x$ is `other`, rounding the result with the specified rounding mode. The
+    /// first [`Float`] is taken by value and the second by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded angle is less than, equal to, or greater than the
+    /// exact angle. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(y,x,m) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(y,\text{NaN},m)=\text{NaN}$
+    /// - $f(0.0,x,m)=0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(-0.0,x,m)=-0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(0.0,x,m)=\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(-0.0,x,m)=-\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(\infty,\infty,m)=\pi/4$
+    /// - $f(-\infty,\infty,m)=-\pi/4$
+    /// - $f(\infty,-\infty,m)=3\pi/4$
+    /// - $f(-\infty,-\infty,m)=-3\pi/4$
+    /// - $f(\infty,x,m)=\pi/2$ if $x$ is finite
+    /// - $f(-\infty,x,m)=-\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0,m)=\pi/2$ if $0.0<y<\infty$
+    /// - $f(y,\pm0.0,m)=-\pi/2$ if $-\infty<y<0.0$
+    /// - $f(y,\infty,m)=0.0$ if $0.0<y<\infty$
+    /// - $f(y,\infty,m)=-0.0$ if $-\infty<y<0.0$
+    /// - $f(y,-\infty,m)=\pi$ if $0.0<y<\infty$
+    /// - $f(y,-\infty,m)=-\pi$ if $-\infty<y<0.0$
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::atan2_prec_round_val_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Atan2::atan2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round_val_ref(&Float::from(E), Floor);
+    /// assert_eq!(atan2.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round_val_ref(&Float::from(E), Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.8575117863558464");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round_val_ref(&Float::from(E), Nearest);
+    /// assert_eq!(atan2.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan2_round_val_ref(self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round_val_ref(other, prec, rm)
+    }
+
+    /// Computes $\operatorname{atan2}(y,x)$, the angle of the point $(x,y)$ in the plane, where $y$
+    /// is `self` and $x$ is `other`, rounding the result with the specified rounding mode. The
+    /// first [`Float`] is taken by reference and the second by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded angle is less than, equal to, or greater than the
+    /// exact angle. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(y,x,m) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(y,\text{NaN},m)=\text{NaN}$
+    /// - $f(0.0,x,m)=0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(-0.0,x,m)=-0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(0.0,x,m)=\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(-0.0,x,m)=-\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(\infty,\infty,m)=\pi/4$
+    /// - $f(-\infty,\infty,m)=-\pi/4$
+    /// - $f(\infty,-\infty,m)=3\pi/4$
+    /// - $f(-\infty,-\infty,m)=-3\pi/4$
+    /// - $f(\infty,x,m)=\pi/2$ if $x$ is finite
+    /// - $f(-\infty,x,m)=-\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0,m)=\pi/2$ if $0.0<y<\infty$
+    /// - $f(y,\pm0.0,m)=-\pi/2$ if $-\infty<y<0.0$
+    /// - $f(y,\infty,m)=0.0$ if $0.0<y<\infty$
+    /// - $f(y,\infty,m)=-0.0$ if $-\infty<y<0.0$
+    /// - $f(y,-\infty,m)=\pi$ if $0.0<y<\infty$
+    /// - $f(y,-\infty,m)=-\pi$ if $-\infty<y<0.0$
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::atan2_prec_round_ref_val`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Atan2::atan2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round_ref_val(Float::from(E), Floor);
+    /// assert_eq!(atan2.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round_ref_val(Float::from(E), Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.8575117863558464");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round_ref_val(Float::from(E), Nearest);
+    /// assert_eq!(atan2.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn atan2_round_ref_val(&self, other: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round_ref_val(other, prec, rm)
+    }
+
+    /// Computes $\operatorname{atan2}(y,x)$, the angle of the point $(x,y)$ in the plane, where $y$
+    /// is `self` and $x$ is `other`, rounding the result with the specified rounding mode. Both
+    /// [`Float`]s are taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded angle is less than, equal to, or greater than the exact angle. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(y,x,m) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(y,\text{NaN},m)=\text{NaN}$
+    /// - $f(0.0,x,m)=0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(-0.0,x,m)=-0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(0.0,x,m)=\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(-0.0,x,m)=-\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(\infty,\infty,m)=\pi/4$
+    /// - $f(-\infty,\infty,m)=-\pi/4$
+    /// - $f(\infty,-\infty,m)=3\pi/4$
+    /// - $f(-\infty,-\infty,m)=-3\pi/4$
+    /// - $f(\infty,x,m)=\pi/2$ if $x$ is finite
+    /// - $f(-\infty,x,m)=-\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0,m)=\pi/2$ if $0.0<y<\infty$
+    /// - $f(y,\pm0.0,m)=-\pi/2$ if $-\infty<y<0.0$
+    /// - $f(y,\infty,m)=0.0$ if $0.0<y<\infty$
+    /// - $f(y,\infty,m)=-0.0$ if $-\infty<y<0.0$
+    /// - $f(y,-\infty,m)=\pi$ if $0.0<y<\infty$
+    /// - $f(y,-\infty,m)=-\pi$ if $-\infty<y<0.0$
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::atan2_prec_round_ref_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Atan2::atan2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round_ref_ref(&Float::from(E), Floor);
+    /// assert_eq!(atan2.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round_ref_ref(&Float::from(E), Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.8575117863558464");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round_ref_ref(&Float::from(E), Nearest);
+    /// assert_eq!(atan2.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan2_round_ref_ref(&self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round_ref_ref(other, prec, rm)
+    }
+
+    /// Replaces a [`Float`] $y$ with $\operatorname{atan2}(y,x)$, the angle of the point $(x,y)$ in
+    /// the plane, where $x$ is `other`, rounding the result to the specified precision and with the
+    /// specified rounding mode. The [`Float`] on the right-hand side is taken by value. An
+    /// [`Ordering`] is returned, indicating whether the rounded angle is less than, equal to, or
+    /// greater than the exact angle. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// y \gets \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan2_prec_assign`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::atan2_round_assign`] instead. If both of these things are true,
+    /// consider using [`Atan2Assign::atan2_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact arctangent.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan2_prec_round_assign(Float::from(E), 5, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.84");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign(Float::from(E), 5, Ceiling),
+    ///     Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.88");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan2_prec_round_assign(Float::from(E), 5, Nearest), Less);
+    /// assert_eq!(x.to_string(), "0.84");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan2_prec_round_assign(Float::from(E), 20, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.8575115");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign(Float::from(E), 20, Ceiling),
+    ///     Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.8575125");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan2_prec_round_assign(Float::from(E), 20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "0.8575115");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn atan2_prec_round_assign(
+        &mut self,
+        other: Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let o;
+        (*self, o) = self.atan2_prec_round_ref_ref(&other, prec, rm);
+        o
+    }
+
+    /// Replaces a [`Float`] $y$ with $\operatorname{atan2}(y,x)$, the angle of the point $(x,y)$ in
+    /// the plane, where $x$ is `other`, rounding the result to the specified precision and with the
+    /// specified rounding mode. The [`Float`] on the right-hand side is taken by reference. An
+    /// [`Ordering`] is returned, indicating whether the rounded angle is less than, equal to, or
+    /// greater than the exact angle. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// y \gets \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan2_prec_assign_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the two
+    /// inputs, consider using [`Float::atan2_round_assign_ref`] instead. If both of these things
+    /// are true, consider using [`Atan2Assign::atan2_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact arctangent.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign_ref(&Float::from(E), 5, Floor),
+    ///     Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.84");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign_ref(&Float::from(E), 5, Ceiling),
+    ///     Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.88");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign_ref(&Float::from(E), 5, Nearest),
+    ///     Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.84");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign_ref(&Float::from(E), 20, Floor),
+    ///     Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.8575115");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign_ref(&Float::from(E), 20, Ceiling),
+    ///     Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.8575125");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign_ref(&Float::from(E), 20, Nearest),
+    ///     Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.8575115");
+    /// ```
+    #[inline]
+    pub fn atan2_prec_round_assign_ref(
+        &mut self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let o;
+        (*self, o) = self.atan2_prec_round_ref_ref(other, prec, rm);
+        o
+    }
+
+    /// Replaces a [`Float`] $y$ with $\operatorname{atan2}(y,x)$, the angle of the point $(x,y)$ in
+    /// the plane, where $x$ is `other`, rounding the result to the nearest value of the specified
+    /// precision. The [`Float`] on the right-hand side is taken by value. An [`Ordering`] is
+    /// returned, indicating whether the rounded angle is less than, equal to, or greater than the
+    /// exact angle. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the angle is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// y \gets \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::atan2_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_round_assign`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using [`Atan2Assign::atan2_assign`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan2_prec_assign(Float::from(E), 5), Less);
+    /// assert_eq!(x.to_string(), "0.84");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan2_prec_assign(Float::from(E), 20), Less);
+    /// assert_eq!(x.to_string(), "0.8575115");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn atan2_prec_assign(&mut self, other: Float, prec: u64) -> Ordering {
+        self.atan2_prec_round_assign(other, prec, Nearest)
+    }
+
+    /// Replaces a [`Float`] $y$ with $\operatorname{atan2}(y,x)$, the angle of the point $(x,y)$ in
+    /// the plane, where $x$ is `other`, rounding the result to the nearest value of the specified
+    /// precision. The [`Float`] on the right-hand side is taken by reference. An [`Ordering`] is
+    /// returned, indicating whether the rounded angle is less than, equal to, or greater than the
+    /// exact angle. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the angle is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// y \gets \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::atan2_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_round_assign_ref`] instead. If you know that your target precision is
+    /// the maximum of the precisions of the two inputs, consider using
+    /// [`Atan2Assign::atan2_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan2_prec_assign_ref(&Float::from(E), 5), Less);
+    /// assert_eq!(x.to_string(), "0.84");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan2_prec_assign_ref(&Float::from(E), 20), Less);
+    /// assert_eq!(x.to_string(), "0.8575115");
+    /// ```
+    #[inline]
+    pub fn atan2_prec_assign_ref(&mut self, other: &Float, prec: u64) -> Ordering {
+        self.atan2_prec_round_assign_ref(other, prec, Nearest)
+    }
+
+    /// Replaces a [`Float`] $y$ with $\operatorname{atan2}(y,x)$, the angle of the point $(x,y)$ in
+    /// the plane, where $x$ is `other`, rounding the result with the specified rounding mode. The
+    /// [`Float`] on the right-hand side is taken by value. An [`Ordering`] is returned, indicating
+    /// whether the rounded angle is less than, equal to, or greater than the exact angle. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// y \gets \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::atan2_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::atan2_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Atan2Assign::atan2_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan2_round_assign(Float::from(E), Floor), Less);
+    /// assert_eq!(x.to_string(), "0.8575117863558462");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan2_round_assign(Float::from(E), Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.8575117863558464");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan2_round_assign(Float::from(E), Nearest), Less);
+    /// assert_eq!(x.to_string(), "0.8575117863558462");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn atan2_round_assign(&mut self, other: Float, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round_assign(other, prec, rm)
+    }
+
+    /// Replaces a [`Float`] $y$ with $\operatorname{atan2}(y,x)$, the angle of the point $(x,y)$ in
+    /// the plane, where $x$ is `other`, rounding the result with the specified rounding mode. The
+    /// [`Float`] on the right-hand side is taken by reference. An [`Ordering`] is returned,
+    /// indicating whether the rounded angle is less than, equal to, or greater than the exact
+    /// angle. Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// y \gets \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::atan2_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::atan2_prec_round_assign_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Atan2Assign::atan2_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan2_round_assign_ref(&Float::from(E), Floor), Less);
+    /// assert_eq!(x.to_string(), "0.8575117863558462");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan2_round_assign_ref(&Float::from(E), Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.8575117863558464");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan2_round_assign_ref(&Float::from(E), Nearest), Less);
+    /// assert_eq!(x.to_string(), "0.8575117863558462");
+    /// ```
+    #[inline]
+    pub fn atan2_round_assign_ref(&mut self, other: &Float, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round_assign_ref(other, prec, rm)
+    }
+}
+
+impl Atan2<Float> for Float {
+    type Output = Float;
+
+    /// Computes $\operatorname{atan2}(y,x)$, the angle of the point $(x,y)$ in the plane, where $y$
+    /// is `self` and $x$ is `other`, taking both by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// angle is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(y,x) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(y,\text{NaN})=\text{NaN}$
+    /// - $f(0.0,x)=0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(-0.0,x)=-0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(0.0,x)=\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(-0.0,x)=-\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(\infty,\infty)=\pi/4$
+    /// - $f(-\infty,\infty)=-\pi/4$
+    /// - $f(\infty,-\infty)=3\pi/4$
+    /// - $f(-\infty,-\infty)=-3\pi/4$
+    /// - $f(\infty,x)=\pi/2$ if $x$ is finite
+    /// - $f(-\infty,x)=-\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0)=\pi/2$ if $0.0<y<\infty$
+    /// - $f(y,\pm0.0)=-\pi/2$ if $-\infty<y<0.0$
+    /// - $f(y,\infty)=0.0$ if $0.0<y<\infty$
+    /// - $f(y,\infty)=-0.0$ if $-\infty<y<0.0$
+    /// - $f(y,-\infty)=\pi$ if $0.0<y<\infty$
+    /// - $f(y,-\infty)=-\pi$ if $-\infty<y<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::atan2_round`]. If you want both of these things, consider using
+    /// [`Float::atan2_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Atan2;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, One, Zero,
+    /// };
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.atan2(Float::ONE).is_nan());
+    /// assert_eq!(Float::ZERO.atan2(Float::ONE).to_string(), "0.0");
+    /// assert_eq!(Float::ZERO.atan2(Float::NEGATIVE_ONE).to_string(), "4.0");
+    /// assert_eq!(Float::INFINITY.atan2(Float::ONE).to_string(), "2.0");
+    /// assert_eq!(
+    ///     Float::ONE.atan2(Float::NEGATIVE_INFINITY).to_string(),
+    ///     "4.0"
+    /// );
+    /// assert_eq!(Float::from(1.5).atan2(Float::from(2.5)).to_string(), "0.5");
+    /// assert_eq!(Float::from(1.5).atan2(Float::from(-2.5)).to_string(), "2.5");
+    /// assert_eq!(
+    ///     Float::from(-1.5).atan2(Float::from(2.5)).to_string(),
+    ///     "-0.5"
+    /// );
+    /// assert_eq!(
+    ///     Float::from(-1.5).atan2(Float::from(-2.5)).to_string(),
+    ///     "-2.5"
+    /// );
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn atan2(self, other: Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round_ref_ref(&other, prec, Nearest).0
+    }
+}
+
+impl Atan2<&Float> for Float {
+    type Output = Float;
+
+    /// Computes $\operatorname{atan2}(y,x)$, the angle of the point $(x,y)$ in the plane, where $y$
+    /// is `self` and $x$ is `other`, taking the first by value and the second by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// angle is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(y,x) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(y,\text{NaN})=\text{NaN}$
+    /// - $f(0.0,x)=0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(-0.0,x)=-0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(0.0,x)=\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(-0.0,x)=-\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(\infty,\infty)=\pi/4$
+    /// - $f(-\infty,\infty)=-\pi/4$
+    /// - $f(\infty,-\infty)=3\pi/4$
+    /// - $f(-\infty,-\infty)=-3\pi/4$
+    /// - $f(\infty,x)=\pi/2$ if $x$ is finite
+    /// - $f(-\infty,x)=-\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0)=\pi/2$ if $0.0<y<\infty$
+    /// - $f(y,\pm0.0)=-\pi/2$ if $-\infty<y<0.0$
+    /// - $f(y,\infty)=0.0$ if $0.0<y<\infty$
+    /// - $f(y,\infty)=-0.0$ if $-\infty<y<0.0$
+    /// - $f(y,-\infty)=\pi$ if $0.0<y<\infty$
+    /// - $f(y,-\infty)=-\pi$ if $-\infty<y<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::atan2_round`]. If you want both of these things, consider using
+    /// [`Float::atan2_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Atan2;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, One, Zero,
+    /// };
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.atan2(&Float::ONE).is_nan());
+    /// assert_eq!(Float::ZERO.atan2(&Float::ONE).to_string(), "0.0");
+    /// assert_eq!(Float::ZERO.atan2(&Float::NEGATIVE_ONE).to_string(), "4.0");
+    /// assert_eq!(Float::INFINITY.atan2(&Float::ONE).to_string(), "2.0");
+    /// assert_eq!(
+    ///     Float::ONE.atan2(&Float::NEGATIVE_INFINITY).to_string(),
+    ///     "4.0"
+    /// );
+    /// assert_eq!(Float::from(1.5).atan2(&Float::from(2.5)).to_string(), "0.5");
+    /// assert_eq!(
+    ///     Float::from(1.5).atan2(&Float::from(-2.5)).to_string(),
+    ///     "2.5"
+    /// );
+    /// assert_eq!(
+    ///     Float::from(-1.5).atan2(&Float::from(2.5)).to_string(),
+    ///     "-0.5"
+    /// );
+    /// assert_eq!(
+    ///     Float::from(-1.5).atan2(&Float::from(-2.5)).to_string(),
+    ///     "-2.5"
+    /// );
+    /// ```
+    #[inline]
+    fn atan2(self, other: &Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round_ref_ref(other, prec, Nearest).0
+    }
+}
+
+impl Atan2<Float> for &Float {
+    type Output = Float;
+
+    /// Computes $\operatorname{atan2}(y,x)$, the angle of the point $(x,y)$ in the plane, where $y$
+    /// is `self` and $x$ is `other`, taking the first by reference and the second by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// angle is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(y,x) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(y,\text{NaN})=\text{NaN}$
+    /// - $f(0.0,x)=0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(-0.0,x)=-0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(0.0,x)=\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(-0.0,x)=-\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(\infty,\infty)=\pi/4$
+    /// - $f(-\infty,\infty)=-\pi/4$
+    /// - $f(\infty,-\infty)=3\pi/4$
+    /// - $f(-\infty,-\infty)=-3\pi/4$
+    /// - $f(\infty,x)=\pi/2$ if $x$ is finite
+    /// - $f(-\infty,x)=-\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0)=\pi/2$ if $0.0<y<\infty$
+    /// - $f(y,\pm0.0)=-\pi/2$ if $-\infty<y<0.0$
+    /// - $f(y,\infty)=0.0$ if $0.0<y<\infty$
+    /// - $f(y,\infty)=-0.0$ if $-\infty<y<0.0$
+    /// - $f(y,-\infty)=\pi$ if $0.0<y<\infty$
+    /// - $f(y,-\infty)=-\pi$ if $-\infty<y<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::atan2_round`]. If you want both of these things, consider using
+    /// [`Float::atan2_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Atan2;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, One, Zero,
+    /// };
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).atan2(Float::ONE).is_nan());
+    /// assert_eq!((&Float::ZERO).atan2(Float::ONE).to_string(), "0.0");
+    /// assert_eq!((&Float::ZERO).atan2(Float::NEGATIVE_ONE).to_string(), "4.0");
+    /// assert_eq!((&Float::INFINITY).atan2(Float::ONE).to_string(), "2.0");
+    /// assert_eq!(
+    ///     (&Float::ONE).atan2(Float::NEGATIVE_INFINITY).to_string(),
+    ///     "4.0"
+    /// );
+    /// assert_eq!(
+    ///     (&Float::from(1.5)).atan2(Float::from(2.5)).to_string(),
+    ///     "0.5"
+    /// );
+    /// assert_eq!(
+    ///     (&Float::from(1.5)).atan2(Float::from(-2.5)).to_string(),
+    ///     "2.5"
+    /// );
+    /// assert_eq!(
+    ///     (&Float::from(-1.5)).atan2(Float::from(2.5)).to_string(),
+    ///     "-0.5"
+    /// );
+    /// assert_eq!(
+    ///     (&Float::from(-1.5)).atan2(Float::from(-2.5)).to_string(),
+    ///     "-2.5"
+    /// );
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn atan2(self, other: Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round_ref_ref(&other, prec, Nearest).0
+    }
+}
+
+impl Atan2<&Float> for &Float {
+    type Output = Float;
+
+    /// Computes $\operatorname{atan2}(y,x)$, the angle of the point $(x,y)$ in the plane, where $y$
+    /// is `self` and $x$ is `other`, taking both by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// angle is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(y,x) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(y,\text{NaN})=\text{NaN}$
+    /// - $f(0.0,x)=0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(-0.0,x)=-0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(0.0,x)=\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(-0.0,x)=-\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(\infty,\infty)=\pi/4$
+    /// - $f(-\infty,\infty)=-\pi/4$
+    /// - $f(\infty,-\infty)=3\pi/4$
+    /// - $f(-\infty,-\infty)=-3\pi/4$
+    /// - $f(\infty,x)=\pi/2$ if $x$ is finite
+    /// - $f(-\infty,x)=-\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0)=\pi/2$ if $0.0<y<\infty$
+    /// - $f(y,\pm0.0)=-\pi/2$ if $-\infty<y<0.0$
+    /// - $f(y,\infty)=0.0$ if $0.0<y<\infty$
+    /// - $f(y,\infty)=-0.0$ if $-\infty<y<0.0$
+    /// - $f(y,-\infty)=\pi$ if $0.0<y<\infty$
+    /// - $f(y,-\infty)=-\pi$ if $-\infty<y<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::atan2_round`]. If you want both of these things, consider using
+    /// [`Float::atan2_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Atan2;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, One, Zero,
+    /// };
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).atan2(&Float::ONE).is_nan());
+    /// assert_eq!((&Float::ZERO).atan2(&Float::ONE).to_string(), "0.0");
+    /// assert_eq!(
+    ///     (&Float::ZERO).atan2(&Float::NEGATIVE_ONE).to_string(),
+    ///     "4.0"
+    /// );
+    /// assert_eq!((&Float::INFINITY).atan2(&Float::ONE).to_string(), "2.0");
+    /// assert_eq!(
+    ///     (&Float::ONE).atan2(&Float::NEGATIVE_INFINITY).to_string(),
+    ///     "4.0"
+    /// );
+    /// assert_eq!(
+    ///     (&Float::from(1.5)).atan2(&Float::from(2.5)).to_string(),
+    ///     "0.5"
+    /// );
+    /// assert_eq!(
+    ///     (&Float::from(1.5)).atan2(&Float::from(-2.5)).to_string(),
+    ///     "2.5"
+    /// );
+    /// assert_eq!(
+    ///     (&Float::from(-1.5)).atan2(&Float::from(2.5)).to_string(),
+    ///     "-0.5"
+    /// );
+    /// assert_eq!(
+    ///     (&Float::from(-1.5)).atan2(&Float::from(-2.5)).to_string(),
+    ///     "-2.5"
+    /// );
+    /// ```
+    #[inline]
+    fn atan2(self, other: &Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round_ref_ref(other, prec, Nearest).0
+    }
+}
+
+impl Atan2Assign<Float> for Float {
+    /// Replaces a [`Float`] $y$ with $\operatorname{atan2}(y,x)$, the angle of the point $(x,y)$ in
+    /// the plane, where $x$ is `other`. The [`Float`] on the right-hand side is taken by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// angle is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// y \gets \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the [`Atan2::atan2`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::atan2_round_assign`]. If you want both of these things, consider using
+    /// [`Float::atan2_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Atan2Assign;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, One, Zero,
+    /// };
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::NAN;
+    /// x.atan2_assign(Float::ONE);
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::ZERO;
+    /// x.atan2_assign(Float::ONE);
+    /// assert_eq!(x.to_string(), "0.0");
+    ///
+    /// let mut x = Float::ZERO;
+    /// x.atan2_assign(Float::NEGATIVE_ONE);
+    /// assert_eq!(x.to_string(), "4.0");
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x.atan2_assign(Float::ONE);
+    /// assert_eq!(x.to_string(), "2.0");
+    ///
+    /// let mut x = Float::ONE;
+    /// x.atan2_assign(Float::NEGATIVE_INFINITY);
+    /// assert_eq!(x.to_string(), "4.0");
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.atan2_assign(Float::from(2.5));
+    /// assert_eq!(x.to_string(), "0.5");
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.atan2_assign(Float::from(-2.5));
+    /// assert_eq!(x.to_string(), "2.5");
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x.atan2_assign(Float::from(2.5));
+    /// assert_eq!(x.to_string(), "-0.5");
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x.atan2_assign(Float::from(-2.5));
+    /// assert_eq!(x.to_string(), "-2.5");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn atan2_assign(&mut self, other: Float) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        *self = self.atan2_prec_round_ref_ref(&other, prec, Nearest).0;
+    }
+}
+
+impl Atan2Assign<&Float> for Float {
+    /// Replaces a [`Float`] $y$ with $\operatorname{atan2}(y,x)$, the angle of the point $(x,y)$ in
+    /// the plane, where $x$ is `other`. The [`Float`] on the right-hand side is taken by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// angle is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// y \gets \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the [`Atan2::atan2`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::atan2_round_assign`]. If you want both of these things, consider using
+    /// [`Float::atan2_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Atan2Assign;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, One, Zero,
+    /// };
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::NAN;
+    /// x.atan2_assign(&Float::ONE);
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::ZERO;
+    /// x.atan2_assign(&Float::ONE);
+    /// assert_eq!(x.to_string(), "0.0");
+    ///
+    /// let mut x = Float::ZERO;
+    /// x.atan2_assign(&Float::NEGATIVE_ONE);
+    /// assert_eq!(x.to_string(), "4.0");
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x.atan2_assign(&Float::ONE);
+    /// assert_eq!(x.to_string(), "2.0");
+    ///
+    /// let mut x = Float::ONE;
+    /// x.atan2_assign(&Float::NEGATIVE_INFINITY);
+    /// assert_eq!(x.to_string(), "4.0");
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.atan2_assign(&Float::from(2.5));
+    /// assert_eq!(x.to_string(), "0.5");
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.atan2_assign(&Float::from(-2.5));
+    /// assert_eq!(x.to_string(), "2.5");
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x.atan2_assign(&Float::from(2.5));
+    /// assert_eq!(x.to_string(), "-0.5");
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x.atan2_assign(&Float::from(-2.5));
+    /// assert_eq!(x.to_string(), "-2.5");
+    /// ```
+    #[inline]
+    fn atan2_assign(&mut self, other: &Float) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        *self = self.atan2_prec_round_ref_ref(other, prec, Nearest).0;
+    }
+}
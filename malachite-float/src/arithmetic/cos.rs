@@ -0,0 +1,787 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Infinity, NaN, Zero};
+use crate::arithmetic::exp::{round_fixed_point_ziv, round_near_float};
+use crate::arithmetic::sin::sin_cos_reduced_fixed_point;
+use crate::{Float, float_either_zero, float_nan};
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{Cos, CosAssign};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::platform::Limb;
+
+impl Float {
+    /// Computes the cosine of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded cosine is less than, equal to, or greater than the
+    /// exact cosine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$.
+    /// - If $\cos x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(\pm0.0,p,m)=1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::cos_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::cos_round`] instead. If both of these things are true, consider using
+    /// [`Float::cos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if the exponent is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact cosine.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec_round(5, Floor);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec_round(5, Ceiling);
+    /// assert_eq!(cos.to_string(), "-0.97");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec_round(5, Nearest);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec_round(20, Floor);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec_round(20, Ceiling);
+    /// assert_eq!(cos.to_string(), "-0.999999");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec_round(20, Nearest);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn cos_prec_round(mut self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        let o = self.cos_prec_round_assign(prec, rm);
+        (self, o)
+    }
+
+    /// Computes the cosine of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded cosine is less than, equal to, or greater than the
+    /// exact cosine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$.
+    /// - If $\cos x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(\pm0.0,p,m)=1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::cos_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::cos_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float)::cos()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if the exponent is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact cosine.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec_round_ref(5, Floor);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec_round_ref(5, Ceiling);
+    /// assert_eq!(cos.to_string(), "-0.97");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec_round_ref(5, Nearest);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec_round_ref(20, Floor);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec_round_ref(20, Ceiling);
+    /// assert_eq!(cos.to_string(), "-0.999999");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec_round_ref(20, Nearest);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn cos_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(NaN | Infinity { .. }) => (float_nan!(), Equal),
+            float_either_zero!() => (Float::one_prec(prec), Equal),
+            _ => {
+                assert!(rm != Exact, "Inexact float cosine");
+                let exponent = self.get_exponent().unwrap();
+                if exponent < 0 && u64::from(exponent.unsigned_abs()) << 1 >= prec + 2 {
+                    // |cos x - 1| < x^2 / 2
+                    return round_near_float(&Float::ONE, Less, prec, rm);
+                }
+                round_fixed_point_ziv(prec + Limb::WIDTH, prec, rm, |scale| {
+                    (sin_cos_reduced_fixed_point(self, scale).1, 2)
+                })
+            }
+        }
+    }
+
+    /// Computes the cosine of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded cosine is less than, equal to, or greater than the exact
+    /// cosine. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// If the cosine is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\cos
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(\pm0.0,p)=1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::cos_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Float::cos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if the exponent is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec(5);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec(20);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn cos_prec(self, prec: u64) -> (Float, Ordering) {
+        self.cos_prec_round(prec, Nearest)
+    }
+
+    /// Computes the cosine of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded cosine is less than, equal to, or greater than the exact
+    /// cosine. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// If the cosine is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\cos
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(\pm0.0,p)=1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::cos_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float)::cos()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if the exponent is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec_ref(5);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec_ref(20);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn cos_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.cos_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes the cosine of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded cosine is less than, equal to, or greater than the exact cosine. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\cos x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(\pm0.0,m)=1.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::cos_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::cos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if the exponent is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (cos, o) = Float::from(PI).cos_round(Floor);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_round(Ceiling);
+    /// assert_eq!(cos.to_string(), "-0.999999999999999");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_round(Nearest);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn cos_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.cos_prec_round(prec, rm)
+    }
+
+    /// Computes the cosine of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded cosine is less than, equal to, or greater than the exact cosine. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\cos x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(\pm0.0,m)=1.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::cos_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float)::cos()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if the exponent is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (cos, o) = Float::from(PI).cos_round_ref(Floor);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_round_ref(Ceiling);
+    /// assert_eq!(cos.to_string(), "-0.999999999999999");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_round_ref(Nearest);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn cos_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.cos_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the cosine of a [`Float`] in place, rounding the result to the specified precision
+    /// and with the specified rounding mode. An [`Ordering`] is returned, indicating whether the
+    /// rounded cosine is less than, equal to, or greater than the exact cosine. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$.
+    /// - If $\cos x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::cos_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::cos_prec_assign`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::cos_round_assign`] instead. If both of these things are true, consider using
+    /// [`Float::cos_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if the exponent is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact cosine.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_prec_round_assign(5, Floor), Less);
+    /// assert_eq!(x.to_string(), "-1.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_prec_round_assign(5, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "-0.97");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_prec_round_assign(5, Nearest), Less);
+    /// assert_eq!(x.to_string(), "-1.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "-1.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "-0.999999");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// ```
+    pub fn cos_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let o;
+        (*self, o) = self.cos_prec_round_ref(prec, rm);
+        o
+    }
+
+    /// Computes the cosine of a [`Float`] in place, rounding the result to the nearest value of the
+    /// specified precision. An [`Ordering`] is returned, indicating whether the rounded cosine is
+    /// less than, equal to, or greater than the exact cosine. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the cosine is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\cos
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::cos_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::cos_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::cos_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if the exponent is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_prec_assign(5), Less);
+    /// assert_eq!(x.to_string(), "-1.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// ```
+    #[inline]
+    pub fn cos_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.cos_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes the cosine of a [`Float`] in place, rounding the result with the specified rounding
+    /// mode. An [`Ordering`] is returned, indicating whether the rounded cosine is less than, equal
+    /// to, or greater than the exact cosine. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\cos x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::cos_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::cos_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::cos_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if the exponent is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "-1.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "-0.999999999999999");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_round_assign(Nearest), Less);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// ```
+    #[inline]
+    pub fn cos_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.cos_prec_round_assign(prec, rm)
+    }
+}
+
+impl Cos for Float {
+    type Output = Float;
+
+    /// Computes the cosine of a [`Float`], taking it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the cosine is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\cos
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=\text{NaN}$
+    /// - $f(\pm0.0)=1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::cos_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::cos_round`].
+    /// If you want both of these things, consider using [`Float::cos_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if the exponent is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Cos;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.cos().is_nan());
+    /// assert!(Float::INFINITY.cos().is_nan());
+    /// assert!(Float::NEGATIVE_INFINITY.cos().is_nan());
+    /// assert_eq!(Float::from(1.5).cos().to_string(), "0.06");
+    /// assert_eq!(Float::from(-1.5).cos().to_string(), "0.06");
+    /// ```
+    #[inline]
+    fn cos(self) -> Float {
+        let prec = self.significant_bits();
+        self.cos_prec_round(prec, Nearest).0
+    }
+}
+
+impl Cos for &Float {
+    type Output = Float;
+
+    /// Computes the cosine of a [`Float`], taking it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the cosine is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\cos
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=\text{NaN}$
+    /// - $f(\pm0.0)=1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::cos_prec_ref`] instead. If you want to specify the output precision, consider using
+    /// [`Float::cos_round_ref`]. If you want both of these things, consider using
+    /// [`Float::cos_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if the exponent is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Cos;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).cos().is_nan());
+    /// assert!((&Float::INFINITY).cos().is_nan());
+    /// assert!((&Float::NEGATIVE_INFINITY).cos().is_nan());
+    /// assert_eq!((&Float::from(1.5)).cos().to_string(), "0.06");
+    /// assert_eq!((&Float::from(-1.5)).cos().to_string(), "0.06");
+    /// ```
+    #[inline]
+    fn cos(self) -> Float {
+        let prec = self.significant_bits();
+        self.cos_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl CosAssign for Float {
+    /// Computes the cosine of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the cosine is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\cos
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::cos`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::cos_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::cos_round_assign`]. If you want both of these things, consider using
+    /// [`Float::cos_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if the exponent is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CosAssign;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::NAN;
+    /// x.cos_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x.cos_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x.cos_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.cos_assign();
+    /// assert_eq!(x.to_string(), "0.06");
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x.cos_assign();
+    /// assert_eq!(x.to_string(), "0.06");
+    /// ```
+    #[inline]
+    fn cos_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.cos_prec_round_assign(prec, Nearest);
+    }
+}
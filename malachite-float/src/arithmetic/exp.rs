@@ -0,0 +1,1040 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::arithmetic::shl_round::shl_prec_round_assign_helper;
+use crate::constants::ln_2::ln_2_fixed_point;
+use crate::{
+    Float, float_either_zero, float_infinity, float_nan, float_negative_infinity, float_zero,
+};
+use core::cmp::Ordering::{self, *};
+use core::cmp::max;
+use malachite_base::num::arithmetic::traits::{Exp, ExpAssign, FloorSqrt, PowerOf2, SquareAssign};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{NegativeZero, One, Zero as ZeroTrait};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::arithmetic::float_extras::float_can_round;
+use malachite_nz::platform::Limb;
+
+// Returns $\lfloor x2^s \rfloor$, where $x$ is a finite [`Float`] and $s$ is `scale`. In other
+// words, this returns the fixed-point representation of `x` with `scale` fractional bits.
+pub(crate) fn float_to_fixed_point(x: &Float, scale: u64) -> Integer {
+    match x {
+        Float(Zero { .. }) => Integer::ZERO,
+        Float(Finite {
+            sign,
+            exponent,
+            significand,
+            ..
+        }) => {
+            let n = Integer::from_sign_and_abs_ref(*sign, significand);
+            let shift = i128::from(*exponent) + i128::from(scale)
+                - i128::from(significand.significant_bits());
+            if shift >= 0 {
+                n << u64::exact_from(shift)
+            } else {
+                n >> u64::exact_from(-shift)
+            }
+        }
+        _ => panic!("Cannot convert {x} to a fixed-point number"),
+    }
+}
+
+// Given $a$ with $|a - 2^s y| \leq 2^e$, where $s$ is `scale` and $e$ is `err_log`, returns $y$
+// rounded to `prec` bits using `rm`, together with the usual `Ordering`. If the error bound is too
+// large to determine the rounded value, `None` is returned instead, and the caller should try again
+// with a larger scale.
+//
+// The exponent of the result is not checked, so the caller must ensure that $y$ is well within
+// the range of representable `Float`s.
+pub(crate) fn round_fixed_point(
+    a: Integer,
+    scale: u64,
+    err_log: u64,
+    prec: u64,
+    rm: RoundingMode,
+) -> Option<(Float, Ordering)> {
+    let bits = a.significant_bits();
+    if bits <= err_log + 1 {
+        return None;
+    }
+    let mut y = Float::from_integer_prec(a, bits).0;
+    if float_can_round(y.significand_ref().unwrap(), bits - err_log, prec, rm) {
+        let o = y.set_prec_round(prec, rm);
+        y >>= scale;
+        Some((y, o))
+    } else {
+        None
+    }
+}
+
+// Repeatedly calls `f` with increasing scales, starting with `scale`, until its fixed-point result
+// can be correctly rounded to `prec` bits. `f` returns the fixed-point approximation along with the
+// base-2 logarithm of its maximum error, measured in units of $2^{-s}$.
+//
+// This must not be used if the exact result is representable with `prec + 1` bits, since the loop
+// would never terminate.
+pub(crate) fn round_fixed_point_ziv<F: FnMut(u64) -> (Integer, u64)>(
+    mut scale: u64,
+    prec: u64,
+    rm: RoundingMode,
+    mut f: F,
+) -> (Float, Ordering) {
+    let mut increment = Limb::WIDTH;
+    loop {
+        let (a, err_log) = f(scale);
+        if let Some(result) = round_fixed_point(a, scale, err_log, prec, rm) {
+            return result;
+        }
+        scale += increment;
+        increment = scale >> 1;
+    }
+}
+
+// Rounds a value $y$ that is very close to, but not equal to, a nonzero finite [`Float`] $x$. `o`
+// must be `Less` if $y < x$ and `Greater` if $y > x$, and `rm` must not be `Exact`. The result is
+// correct as long as $|y - x| < 2^{e-p-3}$, where $e$ is the exponent of $x$ and $p$ is the maximum
+// of `prec` and the precision of $x$.
+//
+// Replacing $y$ by $x \pm 2^{e-p-3}$ does not change its rounding, since no `Float` with precision
+// `prec + 1` lies between them.
+pub(crate) fn round_near_float(
+    x: &Float,
+    o: Ordering,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    let mut y = x.clone();
+    y.set_prec(max(prec, x.get_prec().unwrap()) + 3);
+    if o == Greater {
+        y.increment();
+    } else {
+        y.decrement();
+    }
+    if y == 0u32 {
+        // x is the smallest positive (or largest negative) Float, and y is between it and zero,
+        // but closer to it.
+        return match (x.is_sign_positive(), rm) {
+            (_, Exact) => unreachable!(),
+            (true, Floor | Down) => (Float::ZERO, Less),
+            (true, Ceiling | Up | Nearest) => (Float::min_positive_value_prec(prec), Greater),
+            (false, Ceiling | Down) => (Float::NEGATIVE_ZERO, Greater),
+            (false, Floor | Up | Nearest) => (-Float::min_positive_value_prec(prec), Less),
+        };
+    }
+    let o = y.set_prec_round(prec, rm);
+    (y, o)
+}
+
+// Given $r$ with $|r| \leq 2^s$, where $s$ is `scale`, returns an approximation $a$ of
+// $2^s e^{r/2^s}$ with $|a - 2^s e^{r/2^s}| \leq 2$.
+//
+// The argument is divided by $2^k$, with $k \approx \sqrt{s}/2$, and the Taylor series is summed at
+// a slightly higher scale; the result is then squared $k$ times.
+pub(crate) fn exp_fixed_point(r: &Integer, scale: u64) -> Integer {
+    let squarings = scale.floor_sqrt() >> 1;
+    let guard = scale.significant_bits() + 8;
+    let working_scale = scale + squarings + guard;
+    // y = r / 2^squarings, at scale working_scale
+    let y = r << guard;
+    let one = Integer::power_of_2(working_scale);
+    let mut sum = one.clone();
+    let mut term = one;
+    let mut k = 1u64;
+    loop {
+        term *= &y;
+        term >>= working_scale;
+        term /= Integer::from(k);
+        if term == 0u32 {
+            break;
+        }
+        sum += &term;
+        k += 1;
+    }
+    for _ in 0..squarings {
+        sum.square_assign();
+        sum >>= working_scale;
+    }
+    sum >> (squarings + guard)
+}
+
+// Computes $e^x$ for a finite, nonzero `x` whose exponent is at most 32. The result is first
+// computed as $2^k e^r$, where $k$ is an integer close to $x/\log 2$ and $|r| \leq \log 2$; $e^r$
+// is rounded, and then multiplied by $2^k$ using a shift that handles overflow and underflow.
+fn exp_prec_round_normal(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let k = i64::rounding_from(
+        f64::rounding_from(x, Nearest).0 / core::f64::consts::LN_2,
+        Nearest,
+    )
+    .0;
+    let k_abs = k.unsigned_abs();
+    let guard = k_abs.significant_bits() + 2;
+    let (mut y, o) = round_fixed_point_ziv(prec + Limb::WIDTH, prec, rm, |scale| {
+        // |r - 2^scale (x - k log 2)| <= 3
+        let mut r = float_to_fixed_point(x, scale);
+        if k != 0 {
+            r -= (Integer::from(k) * Integer::from(ln_2_fixed_point(scale + guard))) >> guard;
+        }
+        // Since e^r < 3/2, the error is at most 2 + 3 * 3/2 < 2^3
+        (exp_fixed_point(&r, scale), 3)
+    });
+    let o = shl_prec_round_assign_helper(&mut y, k, prec, rm, o);
+    (y, o)
+}
+
+impl Float {
+    /// Raises $e$ to the power of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded exponential is less than, equal to, or greater than
+    /// the exact exponential. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 e^x\rfloor-p+1}$.
+    /// - If $e^x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   e^x\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=0.0$
+    /// - $f(0.0,p,m)=1.0$
+    /// - $f(-0.0,p,m)=1.0$
+    ///
+    /// Overflow and underflow:
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    ///   returned instead.
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
+    ///   returned instead, where `p` is `prec`.
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
+    ///   instead.
+    /// - If $0<f(x,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
+    ///   instead.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::exp_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::exp_round`] instead. If both of these things are true, consider using
+    /// [`Float::exp`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact exponential.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec_round(5, Floor);
+    /// assert_eq!(exp.to_string(), "23.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec_round(5, Ceiling);
+    /// assert_eq!(exp.to_string(), "24.0");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec_round(5, Nearest);
+    /// assert_eq!(exp.to_string(), "23.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec_round(20, Floor);
+    /// assert_eq!(exp.to_string(), "23.14069");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec_round(20, Ceiling);
+    /// assert_eq!(exp.to_string(), "23.14072");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec_round(20, Nearest);
+    /// assert_eq!(exp.to_string(), "23.14069");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn exp_prec_round(mut self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        let o = self.exp_prec_round_assign(prec, rm);
+        (self, o)
+    }
+
+    /// Raises $e$ to the power of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded exponential is less than, equal to, or greater
+    /// than the exact exponential. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 e^x\rfloor-p+1}$.
+    /// - If $e^x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   e^x\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=0.0$
+    /// - $f(0.0,p,m)=1.0$
+    /// - $f(-0.0,p,m)=1.0$
+    ///
+    /// Overflow and underflow:
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    ///   returned instead.
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
+    ///   returned instead, where `p` is `prec`.
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
+    ///   instead.
+    /// - If $0<f(x,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
+    ///   instead.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::exp_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::exp_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float)::exp()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact exponential.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec_round_ref(5, Floor);
+    /// assert_eq!(exp.to_string(), "23.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec_round_ref(5, Ceiling);
+    /// assert_eq!(exp.to_string(), "24.0");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec_round_ref(5, Nearest);
+    /// assert_eq!(exp.to_string(), "23.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec_round_ref(20, Floor);
+    /// assert_eq!(exp.to_string(), "23.14069");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec_round_ref(20, Ceiling);
+    /// assert_eq!(exp.to_string(), "23.14072");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec_round_ref(20, Nearest);
+    /// assert_eq!(exp.to_string(), "23.14069");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn exp_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            float_nan!() => (float_nan!(), Equal),
+            float_infinity!() => (float_infinity!(), Equal),
+            float_negative_infinity!() => (float_zero!(), Equal),
+            float_either_zero!() => (Float::one_prec(prec), Equal),
+            Float(Finite { sign, exponent, .. }) => {
+                assert!(rm != Exact, "Inexact float exponential");
+                if *exponent < 0 && u64::from(exponent.unsigned_abs()) >= prec + 3 {
+                    // |e^x - 1| < 2^(-prec - 2)
+                    round_near_float(&Float::ONE, if *sign { Greater } else { Less }, prec, rm)
+                } else if *exponent > 32 {
+                    // |x| >= 2^31, so e^x overflows or underflows
+                    Float::power_of_2_prec_round(if *sign { i64::MAX } else { i64::MIN }, prec, rm)
+                } else {
+                    exp_prec_round_normal(self, prec, rm)
+                }
+            }
+        }
+    }
+
+    /// Raises $e$ to the power of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded exponential is less than, equal to, or greater than the exact
+    /// exponential. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the exponential is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 e^x\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=0.0$
+    /// - $f(0.0,p)=1.0$
+    /// - $f(-0.0,p)=1.0$
+    ///
+    /// Overflow and underflow:
+    /// - If $f(x,p)\geq 2^{2^{30}-1}$, $\infty$ is returned instead.
+    /// - If $0<f(x,p)\leq2^{-2^{30}-1}$, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,p)<2^{-2^{30}}$, $2^{-2^{30}}$ is returned instead.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Float::exp`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec(5);
+    /// assert_eq!(exp.to_string(), "23.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec(20);
+    /// assert_eq!(exp.to_string(), "23.14069");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn exp_prec(self, prec: u64) -> (Float, Ordering) {
+        self.exp_prec_round(prec, Nearest)
+    }
+
+    /// Raises $e$ to the power of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded exponential is less than, equal to, or greater than the exact
+    /// exponential. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the exponential is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 e^x\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=0.0$
+    /// - $f(0.0,p)=1.0$
+    /// - $f(-0.0,p)=1.0$
+    ///
+    /// Overflow and underflow:
+    /// - If $f(x,p)\geq 2^{2^{30}-1}$, $\infty$ is returned instead.
+    /// - If $0<f(x,p)\leq2^{-2^{30}-1}$, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,p)<2^{-2^{30}}$, $2^{-2^{30}}$ is returned instead.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float)::exp()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec_ref(5);
+    /// assert_eq!(exp.to_string(), "23.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec_ref(20);
+    /// assert_eq!(exp.to_string(), "23.14069");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn exp_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.exp_prec_round_ref(prec, Nearest)
+    }
+
+    /// Raises $e$ to the power of a [`Float`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether
+    /// the rounded exponential is less than, equal to, or greater than the exact exponential.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 e^x\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $e^x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   e^x\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=0.0$
+    /// - $f(0.0,m)=1.0$
+    /// - $f(-0.0,m)=1.0$
+    ///
+    /// Overflow and underflow:
+    /// - If $f(x,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    ///   returned instead.
+    /// - If $f(x,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
+    ///   returned instead, where `p` is the precision of the input.
+    /// - If $0<f(x,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
+    ///   instead.
+    /// - If $0<f(x,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
+    ///   instead.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::exp_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::exp`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (exp, o) = Float::from(PI).exp_round(Floor);
+    /// assert_eq!(exp.to_string(), "23.14069263277926");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_round(Ceiling);
+    /// assert_eq!(exp.to_string(), "23.14069263277929");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_round(Nearest);
+    /// assert_eq!(exp.to_string(), "23.14069263277926");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn exp_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.exp_prec_round(prec, rm)
+    }
+
+    /// Raises $e$ to the power of a [`Float`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded exponential is less than, equal to, or greater than the exact
+    /// exponential. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 e^x\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $e^x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   e^x\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=0.0$
+    /// - $f(0.0,m)=1.0$
+    /// - $f(-0.0,m)=1.0$
+    ///
+    /// Overflow and underflow:
+    /// - If $f(x,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    ///   returned instead.
+    /// - If $f(x,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
+    ///   returned instead, where `p` is the precision of the input.
+    /// - If $0<f(x,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
+    ///   instead.
+    /// - If $0<f(x,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
+    ///   instead.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::exp_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float)::exp()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (exp, o) = Float::from(PI).exp_round_ref(Floor);
+    /// assert_eq!(exp.to_string(), "23.14069263277926");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_round_ref(Ceiling);
+    /// assert_eq!(exp.to_string(), "23.14069263277929");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_round_ref(Nearest);
+    /// assert_eq!(exp.to_string(), "23.14069263277926");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn exp_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.exp_prec_round_ref(prec, rm)
+    }
+
+    /// Raises $e$ to the power of a [`Float`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded exponential is less than, equal to, or greater than the exact
+    /// exponential. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 e^x\rfloor-p+1}$.
+    /// - If $e^x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   e^x\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::exp_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::exp_prec_assign`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::exp_round_assign`] instead. If both of these things are true, consider using
+    /// [`Float::exp_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact exponential.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_prec_round_assign(5, Floor), Less);
+    /// assert_eq!(x.to_string(), "23.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_prec_round_assign(5, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "24.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_prec_round_assign(5, Nearest), Less);
+    /// assert_eq!(x.to_string(), "23.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "23.14069");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "23.14072");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "23.14069");
+    /// ```
+    pub fn exp_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let o;
+        (*self, o) = self.exp_prec_round_ref(prec, rm);
+        o
+    }
+
+    /// Raises $e$ to the power of a [`Float`] in place, rounding the result to the nearest value of
+    /// the specified precision. An [`Ordering`] is returned, indicating whether the rounded
+    /// exponential is less than, equal to, or greater than the exact exponential. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it
+    /// also returns `Equal`.
+    ///
+    /// If the exponential is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 e^x\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::exp_prec`] documentation for information on special cases, overflow, and
+    /// underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::exp_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_prec_assign(5), Less);
+    /// assert_eq!(x.to_string(), "23.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "23.14069");
+    /// ```
+    #[inline]
+    pub fn exp_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.exp_prec_round_assign(prec, Nearest)
+    }
+
+    /// Raises $e$ to the power of a [`Float`] in place, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded exponential is
+    /// less than, equal to, or greater than the exact exponential. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 e^x\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $e^x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   e^x\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::exp_round`] documentation for information on special cases, overflow, and
+    /// underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::exp_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::exp_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "23.14069263277926");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "23.14069263277929");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_round_assign(Nearest), Less);
+    /// assert_eq!(x.to_string(), "23.14069263277926");
+    /// ```
+    #[inline]
+    pub fn exp_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.exp_prec_round_assign(prec, rm)
+    }
+}
+
+impl Exp for Float {
+    type Output = Float;
+
+    /// Raises $e$ to the power of a [`Float`], taking it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the exponential is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 e^x\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=0.0$
+    /// - $f(0.0)=1.0$
+    /// - $f(-0.0)=1.0$
+    ///
+    /// Overflow and underflow:
+    /// - If $f(x)\geq 2^{2^{30}-1}$, $\infty$ is returned instead.
+    /// - If $0<f(x)\leq2^{-2^{30}-1}$, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x)<2^{-2^{30}}$, $2^{-2^{30}}$ is returned instead.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::exp_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::exp_round`].
+    /// If you want both of these things, consider using [`Float::exp_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Exp;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.exp().is_nan());
+    /// assert_eq!(Float::INFINITY.exp().to_string(), "Infinity");
+    /// assert_eq!(Float::NEGATIVE_INFINITY.exp().to_string(), "0.0");
+    /// assert_eq!(Float::from(1.5).exp().to_string(), "4.0");
+    /// assert_eq!(Float::from(-1.5).exp().to_string(), "0.2");
+    /// ```
+    #[inline]
+    fn exp(self) -> Float {
+        let prec = self.significant_bits();
+        self.exp_prec_round(prec, Nearest).0
+    }
+}
+
+impl Exp for &Float {
+    type Output = Float;
+
+    /// Raises $e$ to the power of a [`Float`], taking it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the exponential is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 e^x\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=0.0$
+    /// - $f(0.0)=1.0$
+    /// - $f(-0.0)=1.0$
+    ///
+    /// Overflow and underflow:
+    /// - If $f(x)\geq 2^{2^{30}-1}$, $\infty$ is returned instead.
+    /// - If $0<f(x)\leq2^{-2^{30}-1}$, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x)<2^{-2^{30}}$, $2^{-2^{30}}$ is returned instead.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp_prec_ref`] instead. If you want to specify the output precision, consider using
+    /// [`Float::exp_round_ref`]. If you want both of these things, consider using
+    /// [`Float::exp_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Exp;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).exp().is_nan());
+    /// assert_eq!((&Float::INFINITY).exp().to_string(), "Infinity");
+    /// assert_eq!((&Float::NEGATIVE_INFINITY).exp().to_string(), "0.0");
+    /// assert_eq!((&Float::from(1.5)).exp().to_string(), "4.0");
+    /// assert_eq!((&Float::from(-1.5)).exp().to_string(), "0.2");
+    /// ```
+    #[inline]
+    fn exp(self) -> Float {
+        let prec = self.significant_bits();
+        self.exp_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl ExpAssign for Float {
+    /// Raises $e$ to the power of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the exponential is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 e^x\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::exp`] documentation for information on special cases, overflow, and
+    /// underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::exp_round_assign`]. If you want both of these things, consider using
+    /// [`Float::exp_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ExpAssign;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::NAN;
+    /// x.exp_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x.exp_assign();
+    /// assert_eq!(x.to_string(), "Infinity");
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x.exp_assign();
+    /// assert_eq!(x.to_string(), "0.0");
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.exp_assign();
+    /// assert_eq!(x.to_string(), "4.0");
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x.exp_assign();
+    /// assert_eq!(x.to_string(), "0.2");
+    /// ```
+    #[inline]
+    fn exp_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.exp_prec_round_assign(prec, Nearest);
+    }
+}
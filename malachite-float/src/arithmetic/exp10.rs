@@ -0,0 +1,919 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::arithmetic::exp::{
+    exp_fixed_point, float_to_fixed_point, round_fixed_point_ziv, round_near_float,
+};
+use crate::arithmetic::ln::ln_fixed_point;
+use crate::arithmetic::shl_round::shl_prec_round_assign_helper;
+use crate::constants::ln_2::ln_2_fixed_point;
+use crate::{
+    Float, float_either_zero, float_infinity, float_nan, float_negative_infinity, float_zero,
+};
+use core::cmp::Ordering::{self, *};
+use core::cmp::max;
+use malachite_base::num::arithmetic::traits::{Exp10, Exp10Assign, Pow};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::{ExactFrom, IsInteger, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+
+// Computes $10^x$ for a finite `x` whose exponent is at most 30, where $10^x$ is not exactly
+// representable with `prec` bits. We write $10^x = 2^k e^r$, where $k$ is an integer close to $x
+// \log_2 10$ and $r = x \log 10 - k \log 2$.
+fn exp10_prec_round_normal(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let k = i64::rounding_from(
+        f64::rounding_from(x, Nearest).0 * core::f64::consts::LOG2_10,
+        Nearest,
+    )
+    .0;
+    let k_guard = k.unsigned_abs().significant_bits() + 2;
+    let x_guard = u64::exact_from(max(x.get_exponent().unwrap(), 0)) + 3;
+    let (mut y, o) = round_fixed_point_ziv(prec + Limb::WIDTH, prec, rm, |scale| {
+        // |l - 2^(scale + x_guard) log 10| <= 4
+        let l = ln_fixed_point(&Float::from(10u32), scale + x_guard);
+        // |r - 2^scale x log 10| <= 1/2 + log 10 + 1
+        let mut r = (float_to_fixed_point(x, scale) * l) >> (scale + x_guard);
+        if k != 0 {
+            r -= (Integer::from(k) * Integer::from(ln_2_fixed_point(scale + k_guard))) >> k_guard;
+        }
+        // |r - 2^scale (x log 10 - k log 2)| <= 6, and e^r < 3/2, so the error is at most 2 + 3/2
+        // * 6 < 2^4
+        (exp_fixed_point(&r, scale), 4)
+    });
+    let o = shl_prec_round_assign_helper(&mut y, k, prec, rm, o);
+    (y, o)
+}
+
+impl Float {
+    /// Raises 10 to the power of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded power is less than, equal to, or greater than the
+    /// exact power. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = 10^x+\varepsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 10^x\rfloor-p+1}$.
+    /// - If $10^x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   10^x\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=0.0$
+    /// - $f(0.0,p,m)=1.0$
+    /// - $f(-0.0,p,m)=1.0$
+    ///
+    /// Overflow and underflow:
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    ///   returned instead.
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
+    ///   returned instead, where `p` is `prec`.
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
+    ///   instead.
+    /// - If $0<f(x,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
+    ///   instead.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::exp10_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::exp10_round`] instead. If both of these things are true, consider using
+    /// [`Float::exp10`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact power.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (exp10, o) = Float::from(PI).exp10_prec_round(5, Floor);
+    /// assert_eq!(exp10.to_string(), "1.34e3");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp10, o) = Float::from(PI).exp10_prec_round(5, Ceiling);
+    /// assert_eq!(exp10.to_string(), "1.4e3");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (exp10, o) = Float::from(PI).exp10_prec_round(5, Nearest);
+    /// assert_eq!(exp10.to_string(), "1.4e3");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (exp10, o) = Float::from(PI).exp10_prec_round(20, Floor);
+    /// assert_eq!(exp10.to_string(), "1385.455");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp10, o) = Float::from(PI).exp10_prec_round(20, Ceiling);
+    /// assert_eq!(exp10.to_string(), "1385.457");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (exp10, o) = Float::from(PI).exp10_prec_round(20, Nearest);
+    /// assert_eq!(exp10.to_string(), "1385.455");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn exp10_prec_round(mut self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        let o = self.exp10_prec_round_assign(prec, rm);
+        (self, o)
+    }
+
+    /// Raises 10 to the power of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded power is less than, equal to, or greater than
+    /// the exact power. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = 10^x+\varepsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 10^x\rfloor-p+1}$.
+    /// - If $10^x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   10^x\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=0.0$
+    /// - $f(0.0,p,m)=1.0$
+    /// - $f(-0.0,p,m)=1.0$
+    ///
+    /// Overflow and underflow:
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    ///   returned instead.
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
+    ///   returned instead, where `p` is `prec`.
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
+    ///   instead.
+    /// - If $0<f(x,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
+    ///   instead.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::exp10_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::exp10_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float)::exp10()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact power.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (exp10, o) = Float::from(PI).exp10_prec_round_ref(5, Floor);
+    /// assert_eq!(exp10.to_string(), "1.34e3");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp10, o) = Float::from(PI).exp10_prec_round_ref(5, Ceiling);
+    /// assert_eq!(exp10.to_string(), "1.4e3");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (exp10, o) = Float::from(PI).exp10_prec_round_ref(5, Nearest);
+    /// assert_eq!(exp10.to_string(), "1.4e3");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (exp10, o) = Float::from(PI).exp10_prec_round_ref(20, Floor);
+    /// assert_eq!(exp10.to_string(), "1385.455");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp10, o) = Float::from(PI).exp10_prec_round_ref(20, Ceiling);
+    /// assert_eq!(exp10.to_string(), "1385.457");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (exp10, o) = Float::from(PI).exp10_prec_round_ref(20, Nearest);
+    /// assert_eq!(exp10.to_string(), "1385.455");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn exp10_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            float_nan!() => (float_nan!(), Equal),
+            float_infinity!() => (float_infinity!(), Equal),
+            float_negative_infinity!() => (float_zero!(), Equal),
+            float_either_zero!() => (Float::one_prec(prec), Equal),
+            Float(Finite { sign, exponent, .. }) => {
+                if *exponent > 30 {
+                    // |x| >= 2^30, so 10^x overflows or underflows
+                    assert!(rm != Exact, "Inexact float power");
+                    return Float::power_of_2_prec_round(
+                        if *sign { i64::MAX } else { i64::MIN },
+                        prec,
+                        rm,
+                    );
+                }
+                if *sign && self.is_integer() {
+                    // 10^x = 5^x * 2^x. If x > prec, 5^x has more than prec significant bits, so the
+                    // result is not exact and we use the general method.
+                    let x = u64::exact_from(self);
+                    if x <= prec {
+                        let (mut y, o) =
+                            Float::from_natural_prec_round(Natural::from(5u32).pow(x), prec, rm);
+                        let o = shl_prec_round_assign_helper(&mut y, x, prec, rm, o);
+                        return (y, o);
+                    }
+                }
+                assert!(rm != Exact, "Inexact float power");
+                if *exponent < 0 && u64::from(exponent.unsigned_abs()) >= prec + 4 {
+                    // |10^x - 1| < 2^(-prec - 2)
+                    round_near_float(&Float::ONE, if *sign { Greater } else { Less }, prec, rm)
+                } else {
+                    exp10_prec_round_normal(self, prec, rm)
+                }
+            }
+        }
+    }
+
+    /// Raises 10 to the power of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded power is less than, equal to, or greater than the exact
+    /// power. Although `NaN`s are not comparable to any [`Float`], whenever this function returns a
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// If the power is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = 10^x+\varepsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 10^x\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=0.0$
+    /// - $f(0.0,p)=1.0$
+    /// - $f(-0.0,p)=1.0$
+    ///
+    /// Overflow and underflow:
+    /// - If $f(x,p)\geq 2^{2^{30}-1}$, $\infty$ is returned instead.
+    /// - If $0<f(x,p)\leq2^{-2^{30}-1}$, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,p)<2^{-2^{30}}$, $2^{-2^{30}}$ is returned instead.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp10_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Float::exp10`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (exp10, o) = Float::from(PI).exp10_prec(5);
+    /// assert_eq!(exp10.to_string(), "1.4e3");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (exp10, o) = Float::from(PI).exp10_prec(20);
+    /// assert_eq!(exp10.to_string(), "1385.455");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn exp10_prec(self, prec: u64) -> (Float, Ordering) {
+        self.exp10_prec_round(prec, Nearest)
+    }
+
+    /// Raises 10 to the power of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded power is less than, equal to, or greater than the exact
+    /// power. Although `NaN`s are not comparable to any [`Float`], whenever this function returns a
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// If the power is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = 10^x+\varepsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 10^x\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=0.0$
+    /// - $f(0.0,p)=1.0$
+    /// - $f(-0.0,p)=1.0$
+    ///
+    /// Overflow and underflow:
+    /// - If $f(x,p)\geq 2^{2^{30}-1}$, $\infty$ is returned instead.
+    /// - If $0<f(x,p)\leq2^{-2^{30}-1}$, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,p)<2^{-2^{30}}$, $2^{-2^{30}}$ is returned instead.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp10_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float)::exp10()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (exp10, o) = Float::from(PI).exp10_prec_ref(5);
+    /// assert_eq!(exp10.to_string(), "1.4e3");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (exp10, o) = Float::from(PI).exp10_prec_ref(20);
+    /// assert_eq!(exp10.to_string(), "1385.455");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn exp10_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.exp10_prec_round_ref(prec, Nearest)
+    }
+
+    /// Raises 10 to the power of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded power is less than, equal to, or greater than the exact power. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = 10^x+\varepsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 10^x\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $10^x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   10^x\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=0.0$
+    /// - $f(0.0,m)=1.0$
+    /// - $f(-0.0,m)=1.0$
+    ///
+    /// Overflow and underflow:
+    /// - If $f(x,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    ///   returned instead.
+    /// - If $f(x,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
+    ///   returned instead, where `p` is the precision of the input.
+    /// - If $0<f(x,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
+    ///   instead.
+    /// - If $0<f(x,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
+    ///   instead.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::exp10_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::exp10`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (exp10, o) = Float::from(PI).exp10_round(Floor);
+    /// assert_eq!(exp10.to_string(), "1385.45573136701");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp10, o) = Float::from(PI).exp10_round(Ceiling);
+    /// assert_eq!(exp10.to_string(), "1385.455731367012");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (exp10, o) = Float::from(PI).exp10_round(Nearest);
+    /// assert_eq!(exp10.to_string(), "1385.45573136701");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn exp10_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.exp10_prec_round(prec, rm)
+    }
+
+    /// Raises 10 to the power of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded power is less than, equal to, or greater than the exact power. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = 10^x+\varepsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 10^x\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $10^x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   10^x\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=0.0$
+    /// - $f(0.0,m)=1.0$
+    /// - $f(-0.0,m)=1.0$
+    ///
+    /// Overflow and underflow:
+    /// - If $f(x,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    ///   returned instead.
+    /// - If $f(x,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
+    ///   returned instead, where `p` is the precision of the input.
+    /// - If $0<f(x,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
+    ///   instead.
+    /// - If $0<f(x,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
+    ///   instead.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::exp10_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float)::exp10()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (exp10, o) = Float::from(PI).exp10_round_ref(Floor);
+    /// assert_eq!(exp10.to_string(), "1385.45573136701");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp10, o) = Float::from(PI).exp10_round_ref(Ceiling);
+    /// assert_eq!(exp10.to_string(), "1385.455731367012");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (exp10, o) = Float::from(PI).exp10_round_ref(Nearest);
+    /// assert_eq!(exp10.to_string(), "1385.45573136701");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn exp10_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.exp10_prec_round_ref(prec, rm)
+    }
+
+    /// Raises 10 to the power of a [`Float`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded power is less than, equal to, or greater than the exact power. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets 10^x+\varepsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 10^x\rfloor-p+1}$.
+    /// - If $10^x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   10^x\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::exp10_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::exp10_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::exp10_round_assign`] instead. If both of these things are true, consider using
+    /// [`Float::exp10_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact power.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp10_prec_round_assign(5, Floor), Less);
+    /// assert_eq!(x.to_string(), "1.34e3");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp10_prec_round_assign(5, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.4e3");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp10_prec_round_assign(5, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "1.4e3");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp10_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "1385.455");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp10_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1385.457");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp10_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "1385.455");
+    /// ```
+    pub fn exp10_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let o;
+        (*self, o) = self.exp10_prec_round_ref(prec, rm);
+        o
+    }
+
+    /// Raises 10 to the power of a [`Float`] in place, rounding the result to the nearest value of
+    /// the specified precision. An [`Ordering`] is returned, indicating whether the rounded power
+    /// is less than, equal to, or greater than the exact power. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the power is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets 10^x+\varepsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 10^x\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::exp10_prec`] documentation for information on special cases, overflow, and
+    /// underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp10_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::exp10_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp10_prec_assign(5), Greater);
+    /// assert_eq!(x.to_string(), "1.4e3");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp10_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "1385.455");
+    /// ```
+    #[inline]
+    pub fn exp10_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.exp10_prec_round_assign(prec, Nearest)
+    }
+
+    /// Raises 10 to the power of a [`Float`] in place, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded power is less
+    /// than, equal to, or greater than the exact power. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets 10^x+\varepsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 10^x\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $10^x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   10^x\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::exp10_round`] documentation for information on special cases, overflow, and
+    /// underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::exp10_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Float::exp10_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp10_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "1385.45573136701");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp10_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1385.455731367012");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp10_round_assign(Nearest), Less);
+    /// assert_eq!(x.to_string(), "1385.45573136701");
+    /// ```
+    #[inline]
+    pub fn exp10_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.exp10_prec_round_assign(prec, rm)
+    }
+}
+
+impl Exp10 for Float {
+    type Output = Float;
+
+    /// Raises 10 to the power of a [`Float`], taking it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the power is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x) = 10^x+\varepsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 10^x\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=0.0$
+    /// - $f(0.0)=1.0$
+    /// - $f(-0.0)=1.0$
+    ///
+    /// Overflow and underflow:
+    /// - If $f(x)\geq 2^{2^{30}-1}$, $\infty$ is returned instead.
+    /// - If $0<f(x)\leq2^{-2^{30}-1}$, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x)<2^{-2^{30}}$, $2^{-2^{30}}$ is returned instead.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp10_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::exp10_round`]. If you want both of these things, consider using
+    /// [`Float::exp10_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Exp10;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.exp10().is_nan());
+    /// assert_eq!(Float::INFINITY.exp10().to_string(), "Infinity");
+    /// assert_eq!(Float::NEGATIVE_INFINITY.exp10().to_string(), "0.0");
+    /// assert_eq!(Float::from(1.5).exp10().to_string(), "3.0e1");
+    /// assert_eq!(Float::from(-1.5).exp10().to_string(), "0.03");
+    /// ```
+    #[inline]
+    fn exp10(self) -> Float {
+        let prec = self.significant_bits();
+        self.exp10_prec_round(prec, Nearest).0
+    }
+}
+
+impl Exp10 for &Float {
+    type Output = Float;
+
+    /// Raises 10 to the power of a [`Float`], taking it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the power is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x) = 10^x+\varepsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 10^x\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=0.0$
+    /// - $f(0.0)=1.0$
+    /// - $f(-0.0)=1.0$
+    ///
+    /// Overflow and underflow:
+    /// - If $f(x)\geq 2^{2^{30}-1}$, $\infty$ is returned instead.
+    /// - If $0<f(x)\leq2^{-2^{30}-1}$, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x)<2^{-2^{30}}$, $2^{-2^{30}}$ is returned instead.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp10_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::exp10_round_ref`]. If you want both of these things, consider using
+    /// [`Float::exp10_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Exp10;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).exp10().is_nan());
+    /// assert_eq!((&Float::INFINITY).exp10().to_string(), "Infinity");
+    /// assert_eq!((&Float::NEGATIVE_INFINITY).exp10().to_string(), "0.0");
+    /// assert_eq!((&Float::from(1.5)).exp10().to_string(), "3.0e1");
+    /// assert_eq!((&Float::from(-1.5)).exp10().to_string(), "0.03");
+    /// ```
+    #[inline]
+    fn exp10(self) -> Float {
+        let prec = self.significant_bits();
+        self.exp10_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl Exp10Assign for Float {
+    /// Raises 10 to the power of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the power is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x\gets 10^x+\varepsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 10^x\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::exp10`] documentation for information on special cases, overflow, and
+    /// underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp10_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::exp10_round_assign`]. If you want both of these things, consider using
+    /// [`Float::exp10_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Exp10Assign;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::NAN;
+    /// x.exp10_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x.exp10_assign();
+    /// assert_eq!(x.to_string(), "Infinity");
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x.exp10_assign();
+    /// assert_eq!(x.to_string(), "0.0");
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.exp10_assign();
+    /// assert_eq!(x.to_string(), "3.0e1");
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x.exp10_assign();
+    /// assert_eq!(x.to_string(), "0.03");
+    /// ```
+    #[inline]
+    fn exp10_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.exp10_prec_round_assign(prec, Nearest);
+    }
+}
@@ -0,0 +1,895 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::arithmetic::exp::{
+    exp_fixed_point, float_to_fixed_point, round_fixed_point_ziv, round_near_float,
+};
+use crate::arithmetic::shl_round::shl_prec_round_assign_helper;
+use crate::constants::ln_2::ln_2_fixed_point;
+use crate::{
+    Float, float_infinity, float_nan, float_negative_infinity, float_negative_zero, float_zero,
+};
+use core::cmp::Ordering::{self, *};
+use core::cmp::max;
+use malachite_base::num::arithmetic::traits::{ExpM1, ExpM1Assign, PowerOf2};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::NegativeOne;
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+use malachite_nz::platform::Limb;
+
+// Computes $e^x-1$ for a finite `x` with $-p-2 \leq x < 2^{32}$, where $p$ is `prec`. We write
+// $e^x = 2^k e^r$, where $k$ is an integer close to $x/\log 2$ and $|r| \leq \log 2$, and
+// subtract 1 in fixed point. When $x$ is small, the subtraction cancels many bits, so the initial
+// scale is increased accordingly.
+fn expm1_prec_round_normal(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let k = i64::rounding_from(
+        f64::rounding_from(x, Nearest).0 / core::f64::consts::LN_2,
+        Nearest,
+    )
+    .0;
+    let guard = k.unsigned_abs().significant_bits() + 2;
+    let x_exp = x.get_exponent().unwrap();
+    let cancelled = if x_exp < 0 {
+        u64::from(x_exp.unsigned_abs())
+    } else {
+        0
+    };
+    let (mut y, o) = round_fixed_point_ziv(prec + Limb::WIDTH + cancelled, prec, rm, |scale| {
+        let mut r = float_to_fixed_point(x, scale);
+        if k != 0 {
+            r -= (Integer::from(k) * Integer::from(ln_2_fixed_point(scale + guard))) >> guard;
+        }
+        // |e - 2^scale e^r| <= 2 + 3/2 * 3 < 7
+        let e = exp_fixed_point(&r, scale);
+        // If k >= 0, the result is a * 2^k, and otherwise it is a.
+        let a = if k >= 0 {
+            let k = k.unsigned_abs();
+            if k <= scale {
+                e - Integer::power_of_2(scale - k)
+            } else {
+                e
+            }
+        } else {
+            (e >> k.unsigned_abs()) - Integer::power_of_2(scale)
+        };
+        (a, 3)
+    });
+    let o = if k > 0 {
+        shl_prec_round_assign_helper(&mut y, k, prec, rm, o)
+    } else {
+        o
+    };
+    (y, o)
+}
+
+impl Float {
+    /// Computes $e^x-1$ for a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than the
+    /// exact result. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |e^x-1|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=-1.0$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// Overflow:
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    ///   returned instead.
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
+    ///   returned instead, where `p` is `prec`.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::expm1_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::expm1_round`] instead. If both of these things are true, consider using
+    /// [`Float::expm1`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact result.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (expm1, o) = Float::from(PI).expm1_prec_round(5, Floor);
+    /// assert_eq!(expm1.to_string(), "22.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (expm1, o) = Float::from(PI).expm1_prec_round(5, Ceiling);
+    /// assert_eq!(expm1.to_string(), "23.0");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (expm1, o) = Float::from(PI).expm1_prec_round(5, Nearest);
+    /// assert_eq!(expm1.to_string(), "22.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (expm1, o) = Float::from(PI).expm1_prec_round(20, Floor);
+    /// assert_eq!(expm1.to_string(), "22.14069");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (expm1, o) = Float::from(PI).expm1_prec_round(20, Ceiling);
+    /// assert_eq!(expm1.to_string(), "22.14072");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (expm1, o) = Float::from(PI).expm1_prec_round(20, Nearest);
+    /// assert_eq!(expm1.to_string(), "22.14069");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn expm1_prec_round(mut self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        let o = self.expm1_prec_round_assign(prec, rm);
+        (self, o)
+    }
+
+    /// Computes $e^x-1$ for a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than the
+    /// exact result. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |e^x-1|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=-1.0$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// Overflow:
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    ///   returned instead.
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
+    ///   returned instead, where `p` is `prec`.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::expm1_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::expm1_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float)::expm1()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact result.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (expm1, o) = Float::from(PI).expm1_prec_round_ref(5, Floor);
+    /// assert_eq!(expm1.to_string(), "22.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (expm1, o) = Float::from(PI).expm1_prec_round_ref(5, Ceiling);
+    /// assert_eq!(expm1.to_string(), "23.0");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (expm1, o) = Float::from(PI).expm1_prec_round_ref(5, Nearest);
+    /// assert_eq!(expm1.to_string(), "22.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (expm1, o) = Float::from(PI).expm1_prec_round_ref(20, Floor);
+    /// assert_eq!(expm1.to_string(), "22.14069");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (expm1, o) = Float::from(PI).expm1_prec_round_ref(20, Ceiling);
+    /// assert_eq!(expm1.to_string(), "22.14072");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (expm1, o) = Float::from(PI).expm1_prec_round_ref(20, Nearest);
+    /// assert_eq!(expm1.to_string(), "22.14069");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn expm1_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            float_nan!() => (float_nan!(), Equal),
+            float_infinity!() => (float_infinity!(), Equal),
+            float_negative_infinity!() => Float::from_signed_prec_round(-1i8, prec, rm),
+            float_zero!() => (float_zero!(), Equal),
+            float_negative_zero!() => (float_negative_zero!(), Equal),
+            Float(Finite {
+                sign,
+                exponent,
+                precision,
+                ..
+            }) => {
+                assert!(rm != Exact, "Inexact float expm1");
+                if *exponent < 0 && u64::from(exponent.unsigned_abs()) >= max(prec, *precision) + 3
+                {
+                    // |e^x - 1 - x| < 2^(2 * exponent - 1)
+                    round_near_float(self, Greater, prec, rm)
+                } else if *sign && *exponent > 32 {
+                    // x >= 2^31, so e^x - 1 overflows
+                    Float::power_of_2_prec_round(i64::MAX, prec, rm)
+                } else if !*sign && *self < -i64::exact_from(prec) - 2 {
+                    // 0 < e^x < 2^(-prec - 2)
+                    round_near_float(&Float::NEGATIVE_ONE, Greater, prec, rm)
+                } else {
+                    expm1_prec_round_normal(self, prec, rm)
+                }
+            }
+        }
+    }
+
+    /// Computes $e^x-1$ for a [`Float`], rounding the result to the nearest value of the specified
+    /// precision. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded result is less than, equal to, or greater than the exact result.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |e^x-1|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=-1.0$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// Overflow:
+    /// - If $f(x,p)\geq 2^{2^{30}-1}$, $\infty$ is returned instead.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::expm1_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Float::expm1`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (expm1, o) = Float::from(PI).expm1_prec(5);
+    /// assert_eq!(expm1.to_string(), "22.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (expm1, o) = Float::from(PI).expm1_prec(20);
+    /// assert_eq!(expm1.to_string(), "22.14069");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn expm1_prec(self, prec: u64) -> (Float, Ordering) {
+        self.expm1_prec_round(prec, Nearest)
+    }
+
+    /// Computes $e^x-1$ for a [`Float`], rounding the result to the nearest value of the specified
+    /// precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded result is less than, equal to, or greater than the exact result.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |e^x-1|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=-1.0$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// Overflow:
+    /// - If $f(x,p)\geq 2^{2^{30}-1}$, $\infty$ is returned instead.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::expm1_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float)::expm1()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (expm1, o) = Float::from(PI).expm1_prec_ref(5);
+    /// assert_eq!(expm1.to_string(), "22.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (expm1, o) = Float::from(PI).expm1_prec_ref(20);
+    /// assert_eq!(expm1.to_string(), "22.14069");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn expm1_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.expm1_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes $e^x-1$ for a [`Float`], rounding the result with the specified rounding mode. The
+    /// [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded result is less than, equal to, or greater than the exact result. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |e^x-1|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=-1.0$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// Overflow:
+    /// - If $f(x,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    ///   returned instead.
+    /// - If $f(x,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
+    ///   returned instead, where `p` is the precision of the input.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::expm1_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::expm1`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (expm1, o) = Float::from(PI).expm1_round(Floor);
+    /// assert_eq!(expm1.to_string(), "22.14069263277926");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (expm1, o) = Float::from(PI).expm1_round(Ceiling);
+    /// assert_eq!(expm1.to_string(), "22.14069263277929");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (expm1, o) = Float::from(PI).expm1_round(Nearest);
+    /// assert_eq!(expm1.to_string(), "22.14069263277926");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn expm1_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.expm1_prec_round(prec, rm)
+    }
+
+    /// Computes $e^x-1$ for a [`Float`], rounding the result with the specified rounding mode. The
+    /// [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded result is less than, equal to, or greater than the exact result. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |e^x-1|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=-1.0$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// Overflow:
+    /// - If $f(x,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    ///   returned instead.
+    /// - If $f(x,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
+    ///   returned instead, where `p` is the precision of the input.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::expm1_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float)::expm1()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (expm1, o) = Float::from(PI).expm1_round_ref(Floor);
+    /// assert_eq!(expm1.to_string(), "22.14069263277926");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (expm1, o) = Float::from(PI).expm1_round_ref(Ceiling);
+    /// assert_eq!(expm1.to_string(), "22.14069263277929");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (expm1, o) = Float::from(PI).expm1_round_ref(Nearest);
+    /// assert_eq!(expm1.to_string(), "22.14069263277926");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn expm1_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.expm1_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $e^x-1$ for a [`Float`] in place, rounding the result to the specified precision
+    /// and with the specified rounding mode. An [`Ordering`] is returned, indicating whether the
+    /// rounded result is less than, equal to, or greater than the exact result. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |e^x-1|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::expm1_prec_round`] documentation for information on special cases and
+    /// overflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::expm1_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::expm1_round_assign`] instead. If both of these things are true, consider using
+    /// [`Float::expm1_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact result.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.expm1_prec_round_assign(5, Floor), Less);
+    /// assert_eq!(x.to_string(), "22.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.expm1_prec_round_assign(5, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "23.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.expm1_prec_round_assign(5, Nearest), Less);
+    /// assert_eq!(x.to_string(), "22.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.expm1_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "22.14069");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.expm1_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "22.14072");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.expm1_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "22.14069");
+    /// ```
+    pub fn expm1_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let o;
+        (*self, o) = self.expm1_prec_round_ref(prec, rm);
+        o
+    }
+
+    /// Computes $e^x-1$ for a [`Float`] in place, rounding the result to the nearest value of the
+    /// specified precision. An [`Ordering`] is returned, indicating whether the rounded result is
+    /// less than, equal to, or greater than the exact result. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |e^x-1|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::expm1_prec`] documentation for information on special cases and overflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::expm1_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::expm1_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.expm1_prec_assign(5), Less);
+    /// assert_eq!(x.to_string(), "22.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.expm1_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "22.14069");
+    /// ```
+    #[inline]
+    pub fn expm1_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.expm1_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes $e^x-1$ for a [`Float`] in place, rounding the result with the specified rounding
+    /// mode. An [`Ordering`] is returned, indicating whether the rounded result is less than, equal
+    /// to, or greater than the exact result. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |e^x-1|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::expm1_round`] documentation for information on special cases and overflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::expm1_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Float::expm1_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.expm1_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "22.14069263277926");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.expm1_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "22.14069263277929");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.expm1_round_assign(Nearest), Less);
+    /// assert_eq!(x.to_string(), "22.14069263277926");
+    /// ```
+    #[inline]
+    pub fn expm1_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.expm1_prec_round_assign(prec, rm)
+    }
+}
+
+impl ExpM1 for Float {
+    type Output = Float;
+
+    /// Computes $e^x-1$ for a [`Float`], taking it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the result is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |e^x-1|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=-1.0$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// Overflow:
+    /// - If $f(x)\geq 2^{2^{30}-1}$, $\infty$ is returned instead.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::expm1_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::expm1_round`]. If you want both of these things, consider using
+    /// [`Float::expm1_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ExpM1;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.expm1().is_nan());
+    /// assert_eq!(Float::INFINITY.expm1().to_string(), "Infinity");
+    /// assert_eq!(Float::NEGATIVE_INFINITY.expm1().to_string(), "-1.0");
+    /// assert_eq!(Float::from(1.5).expm1().to_string(), "3.0");
+    /// assert_eq!(Float::from(-1.5).expm1().to_string(), "-0.8");
+    /// ```
+    #[inline]
+    fn expm1(self) -> Float {
+        let prec = self.significant_bits();
+        self.expm1_prec_round(prec, Nearest).0
+    }
+}
+
+impl ExpM1 for &Float {
+    type Output = Float;
+
+    /// Computes $e^x-1$ for a [`Float`], taking it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the result is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |e^x-1|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=-1.0$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// Overflow:
+    /// - If $f(x)\geq 2^{2^{30}-1}$, $\infty$ is returned instead.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::expm1_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::expm1_round_ref`]. If you want both of these things, consider using
+    /// [`Float::expm1_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ExpM1;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).expm1().is_nan());
+    /// assert_eq!((&Float::INFINITY).expm1().to_string(), "Infinity");
+    /// assert_eq!((&Float::NEGATIVE_INFINITY).expm1().to_string(), "-1.0");
+    /// assert_eq!((&Float::from(1.5)).expm1().to_string(), "3.0");
+    /// assert_eq!((&Float::from(-1.5)).expm1().to_string(), "-0.8");
+    /// ```
+    #[inline]
+    fn expm1(self) -> Float {
+        let prec = self.significant_bits();
+        self.expm1_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl ExpM1Assign for Float {
+    /// Computes $e^x-1$ for a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the result is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |e^x-1|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::expm1`] documentation for information on special cases and overflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::expm1_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::expm1_round_assign`]. If you want both of these things, consider using
+    /// [`Float::expm1_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ExpM1Assign;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::NAN;
+    /// x.expm1_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x.expm1_assign();
+    /// assert_eq!(x.to_string(), "Infinity");
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x.expm1_assign();
+    /// assert_eq!(x.to_string(), "-1.0");
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.expm1_assign();
+    /// assert_eq!(x.to_string(), "3.0");
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x.expm1_assign();
+    /// assert_eq!(x.to_string(), "-0.8");
+    /// ```
+    #[inline]
+    fn expm1_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.expm1_prec_round_assign(prec, Nearest);
+    }
+}
@@ -0,0 +1,883 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::arithmetic::exp::round_fixed_point_ziv;
+use crate::constants::ln_2::ln_2_fixed_point;
+use crate::{
+    Float, float_either_zero, float_infinity, float_nan, float_negative_infinity, float_zero,
+};
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{
+    FloorSqrt, FloorSqrtAssign, IsPowerOf2, Ln, LnAssign, PowerOf2, Square,
+};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{One, Zero as ZeroTrait};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+
+// Given a positive finite [`Float`] $x$, returns an approximation $a$ of $2^s \log x$, where $s$ is
+// `scale`, with $|a - 2^s \log x| \leq 4$.
+//
+// Write $x = m2^e$ with $1/\sqrt{2} \leq m < \sqrt{2}$. Then $\log x = \log m + e \log 2$. To compute
+// $\log m$, we take $k \approx \sqrt{s}/2$ square roots of $m$, obtaining some $u$ close to 1, and
+// then use $\log m = 2^{k+1} \operatorname{artanh}((u-1)/(u+1))$.
+pub(crate) fn ln_fixed_point(x: &Float, scale: u64) -> Integer {
+    let Float(Finite {
+        sign: true,
+        exponent,
+        significand,
+        ..
+    }) = x
+    else {
+        panic!("Cannot take the logarithm of {x}");
+    };
+    let roots = scale.floor_sqrt() >> 1;
+    let guard = scale.significant_bits() + 4;
+    let working_scale = scale + roots + guard;
+    let bits = significand.significant_bits();
+    let mut e = i64::from(*exponent);
+    // m = significand / 2^bits is in [1/2, 1). If m < 1/sqrt(2), double it.
+    let top = u64::exact_from(&(significand >> (bits - 32)));
+    let mut shift = i128::from(working_scale) - i128::from(bits);
+    if top * top < 1 << 63 {
+        shift += 1;
+        e -= 1;
+    }
+    let mut m = if shift >= 0 {
+        significand << u64::exact_from(shift)
+    } else {
+        significand >> u64::exact_from(-shift)
+    };
+    for _ in 0..roots {
+        m <<= working_scale;
+        m.floor_sqrt_assign();
+    }
+    let one = Natural::power_of_2(working_scale);
+    // t = |u - 1| / (u + 1)
+    let sign = m >= one;
+    let numerator = if sign { &m - &one } else { &one - &m };
+    let t = (numerator << working_scale) / (m + one);
+    let t_squared = (&t).square() >> working_scale;
+    let mut sum = Natural::ZERO;
+    let mut term = t;
+    let mut d = 1u64;
+    while term != 0u32 {
+        sum += &term / Natural::from(d);
+        term *= &t_squared;
+        term >>= working_scale;
+        d += 2;
+    }
+    let sum = Integer::from_sign_and_abs(sign, sum);
+    // log m = 2^(roots + 1) * sum / 2^working_scale
+    let mut result = sum >> (guard - 1);
+    if e != 0 {
+        let guard = e.unsigned_abs().significant_bits() + 2;
+        result += (Integer::from(e) * Integer::from(ln_2_fixed_point(scale + guard))) >> guard;
+    }
+    result
+}
+
+// Returns the number of leading bits of `x` that cancel when computing $\log x$, which is
+// $\max(0, -e)$, where $e$ is the exponent of $x-1$. `x` must be positive, finite, and not equal
+// to 1.
+pub(crate) fn ln_cancelled_bits(x: &Float) -> u64 {
+    let exponent = x.sub_prec_ref_val(Float::ONE, 1).0.get_exponent().unwrap();
+    if exponent < 0 {
+        u64::from(exponent.unsigned_abs())
+    } else {
+        0
+    }
+}
+
+impl Float {
+    /// Takes the natural logarithm of a [`Float`], rounding the result to the specified precision
+    /// and with the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is
+    /// also returned, indicating whether the rounded logarithm is less than, equal to, or greater
+    /// than the exact logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \log x+\varepsilon.
+    /// $$
+    /// - If $\log x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log x|\rfloor-p+1}$.
+    /// - If $\log x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(\pm0.0,p,m)=-\infty$
+    /// - $f(1.0,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::ln_round`] instead. If both of these things are true, consider using [`Float::ln`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact logarithm.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec_round(5, Floor);
+    /// assert_eq!(ln.to_string(), "1.12");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec_round(5, Ceiling);
+    /// assert_eq!(ln.to_string(), "1.19");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec_round(5, Nearest);
+    /// assert_eq!(ln.to_string(), "1.12");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec_round(20, Floor);
+    /// assert_eq!(ln.to_string(), "1.14473");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec_round(20, Ceiling);
+    /// assert_eq!(ln.to_string(), "1.144732");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec_round(20, Nearest);
+    /// assert_eq!(ln.to_string(), "1.14473");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn ln_prec_round(mut self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        let o = self.ln_prec_round_assign(prec, rm);
+        (self, o)
+    }
+
+    /// Takes the natural logarithm of a [`Float`], rounding the result to the specified precision
+    /// and with the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`]
+    /// is also returned, indicating whether the rounded logarithm is less than, equal to, or
+    /// greater than the exact logarithm. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \log x+\varepsilon.
+    /// $$
+    /// - If $\log x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log x|\rfloor-p+1}$.
+    /// - If $\log x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(\pm0.0,p,m)=-\infty$
+    /// - $f(1.0,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_prec_ref`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::ln_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float)::ln()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact logarithm.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec_round_ref(5, Floor);
+    /// assert_eq!(ln.to_string(), "1.12");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec_round_ref(5, Ceiling);
+    /// assert_eq!(ln.to_string(), "1.19");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec_round_ref(5, Nearest);
+    /// assert_eq!(ln.to_string(), "1.12");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec_round_ref(20, Floor);
+    /// assert_eq!(ln.to_string(), "1.14473");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec_round_ref(20, Ceiling);
+    /// assert_eq!(ln.to_string(), "1.144732");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec_round_ref(20, Nearest);
+    /// assert_eq!(ln.to_string(), "1.14473");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn ln_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(NaN | Infinity { sign: false } | Finite { sign: false, .. }) => {
+                (float_nan!(), Equal)
+            }
+            float_infinity!() => (float_infinity!(), Equal),
+            float_either_zero!() => (float_negative_infinity!(), Equal),
+            Float(Finite {
+                exponent: 1,
+                significand,
+                ..
+            }) if significand.is_power_of_2() => (float_zero!(), Equal),
+            _ => {
+                assert!(rm != Exact, "Inexact float logarithm");
+                let scale = prec + Limb::WIDTH + ln_cancelled_bits(self);
+                round_fixed_point_ziv(scale, prec, rm, |scale| (ln_fixed_point(self, scale), 2))
+            }
+        }
+    }
+
+    /// Takes the natural logarithm of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded logarithm is less than, equal to, or greater than the exact
+    /// logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \log x+\varepsilon.
+    /// $$
+    /// - If $\log x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\log x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(\pm0.0,p)=-\infty$
+    /// - $f(1.0,p)=0.0$
+    /// - $f(x,p)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_prec_round`] instead. If you know that your target precision is the precision of
+    /// the input, consider using [`Float::ln`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec(5);
+    /// assert_eq!(ln.to_string(), "1.12");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec(20);
+    /// assert_eq!(ln.to_string(), "1.14473");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn ln_prec(self, prec: u64) -> (Float, Ordering) {
+        self.ln_prec_round(prec, Nearest)
+    }
+
+    /// Takes the natural logarithm of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded logarithm is less than, equal to, or greater than the exact
+    /// logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \log x+\varepsilon.
+    /// $$
+    /// - If $\log x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\log x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(\pm0.0,p)=-\infty$
+    /// - $f(1.0,p)=0.0$
+    /// - $f(x,p)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float)::ln()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec_ref(5);
+    /// assert_eq!(ln.to_string(), "1.12");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec_ref(20);
+    /// assert_eq!(ln.to_string(), "1.14473");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn ln_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.ln_prec_round_ref(prec, Nearest)
+    }
+
+    /// Takes the natural logarithm of a [`Float`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether
+    /// the rounded logarithm is less than, equal to, or greater than the exact logarithm. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \log x+\varepsilon.
+    /// $$
+    /// - If $\log x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\log x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(\pm0.0,m)=-\infty$
+    /// - $f(1.0,m)=0.0$
+    /// - $f(x,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::ln_prec_round`] instead.
+    /// If you know you'll be using the `Nearest` rounding mode, consider using [`Float::ln`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln, o) = Float::from(PI).ln_round(Floor);
+    /// assert_eq!(ln.to_string(), "1.144729885849399");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_round(Ceiling);
+    /// assert_eq!(ln.to_string(), "1.144729885849401");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_round(Nearest);
+    /// assert_eq!(ln.to_string(), "1.144729885849401");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn ln_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.ln_prec_round(prec, rm)
+    }
+
+    /// Takes the natural logarithm of a [`Float`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded logarithm is less than, equal to, or greater than the exact logarithm.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \log x+\varepsilon.
+    /// $$
+    /// - If $\log x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\log x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(\pm0.0,m)=-\infty$
+    /// - $f(1.0,m)=0.0$
+    /// - $f(x,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::ln_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float)::ln()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln, o) = Float::from(PI).ln_round_ref(Floor);
+    /// assert_eq!(ln.to_string(), "1.144729885849399");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_round_ref(Ceiling);
+    /// assert_eq!(ln.to_string(), "1.144729885849401");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_round_ref(Nearest);
+    /// assert_eq!(ln.to_string(), "1.144729885849401");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn ln_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.ln_prec_round_ref(prec, rm)
+    }
+
+    /// Takes the natural logarithm of a [`Float`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded logarithm is less than, equal to, or greater than the exact logarithm.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \log x+\varepsilon.
+    /// $$
+    /// - If $\log x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log x|\rfloor-p+1}$.
+    /// - If $\log x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::ln_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_prec_assign`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::ln_round_assign`] instead. If both of these things are true, consider using
+    /// [`Float::ln_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact logarithm.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_prec_round_assign(5, Floor), Less);
+    /// assert_eq!(x.to_string(), "1.12");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_prec_round_assign(5, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.19");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_prec_round_assign(5, Nearest), Less);
+    /// assert_eq!(x.to_string(), "1.12");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "1.14473");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.144732");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "1.14473");
+    /// ```
+    pub fn ln_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let o;
+        (*self, o) = self.ln_prec_round_ref(prec, rm);
+        o
+    }
+
+    /// Takes the natural logarithm of a [`Float`] in place, rounding the result to the nearest
+    /// value of the specified precision. An [`Ordering`] is returned, indicating whether the
+    /// rounded logarithm is less than, equal to, or greater than the exact logarithm. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets \log x+\varepsilon.
+    /// $$
+    /// - If $\log x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\log x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::ln_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::ln_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_prec_assign(5), Less);
+    /// assert_eq!(x.to_string(), "1.12");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "1.14473");
+    /// ```
+    #[inline]
+    pub fn ln_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.ln_prec_round_assign(prec, Nearest)
+    }
+
+    /// Takes the natural logarithm of a [`Float`] in place, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded logarithm is less
+    /// than, equal to, or greater than the exact logarithm. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \log x+\varepsilon.
+    /// $$
+    /// - If $\log x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\log x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::ln_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::ln_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::ln_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "1.144729885849399");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.144729885849401");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_round_assign(Nearest), Greater);
+    /// assert_eq!(x.to_string(), "1.144729885849401");
+    /// ```
+    #[inline]
+    pub fn ln_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.ln_prec_round_assign(prec, rm)
+    }
+}
+
+impl Ln for Float {
+    type Output = Float;
+
+    /// Takes the natural logarithm of a [`Float`], taking it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the logarithm is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \log x+\varepsilon.
+    /// $$
+    /// - If $\log x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\log x|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=\text{NaN}$
+    /// - $f(\pm0.0)=-\infty$
+    /// - $f(1.0)=0.0$
+    /// - $f(x)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::ln_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::ln_round`]. If
+    /// you want both of these things, consider using [`Float::ln_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Ln;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.ln().is_nan());
+    /// assert_eq!(Float::INFINITY.ln().to_string(), "Infinity");
+    /// assert!(Float::NEGATIVE_INFINITY.ln().is_nan());
+    /// assert_eq!(Float::from(1.5).ln().to_string(), "0.4");
+    /// assert!(Float::from(-1.5).ln().is_nan());
+    /// ```
+    #[inline]
+    fn ln(self) -> Float {
+        let prec = self.significant_bits();
+        self.ln_prec_round(prec, Nearest).0
+    }
+}
+
+impl Ln for &Float {
+    type Output = Float;
+
+    /// Takes the natural logarithm of a [`Float`], taking it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the logarithm is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \log x+\varepsilon.
+    /// $$
+    /// - If $\log x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\log x|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=\text{NaN}$
+    /// - $f(\pm0.0)=-\infty$
+    /// - $f(1.0)=0.0$
+    /// - $f(x)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_prec_ref`] instead. If you want to specify the output precision, consider using
+    /// [`Float::ln_round_ref`]. If you want both of these things, consider using
+    /// [`Float::ln_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Ln;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).ln().is_nan());
+    /// assert_eq!((&Float::INFINITY).ln().to_string(), "Infinity");
+    /// assert!((&Float::NEGATIVE_INFINITY).ln().is_nan());
+    /// assert_eq!((&Float::from(1.5)).ln().to_string(), "0.4");
+    /// assert!((&Float::from(-1.5)).ln().is_nan());
+    /// ```
+    #[inline]
+    fn ln(self) -> Float {
+        let prec = self.significant_bits();
+        self.ln_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl LnAssign for Float {
+    /// Takes the natural logarithm of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the logarithm is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets \log x+\varepsilon.
+    /// $$
+    /// - If $\log x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\log x|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::ln`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::ln_round_assign`]. If you want both of these things, consider using
+    /// [`Float::ln_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::LnAssign;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::NAN;
+    /// x.ln_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x.ln_assign();
+    /// assert_eq!(x.to_string(), "Infinity");
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x.ln_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.ln_assign();
+    /// assert_eq!(x.to_string(), "0.4");
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x.ln_assign();
+    /// assert!(x.is_nan());
+    /// ```
+    #[inline]
+    fn ln_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.ln_prec_round_assign(prec, Nearest);
+    }
+}
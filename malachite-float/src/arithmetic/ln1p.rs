@@ -0,0 +1,855 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::arithmetic::exp::{round_fixed_point_ziv, round_near_float};
+use crate::arithmetic::ln::ln_fixed_point;
+use crate::{
+    Float, float_infinity, float_nan, float_negative_infinity, float_negative_zero, float_zero,
+};
+use core::cmp::Ordering::{self, *};
+use core::cmp::{max, min};
+use malachite_base::num::arithmetic::traits::{IsPowerOf2, Ln1p, Ln1pAssign};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::platform::Limb;
+
+// Computes $\log(1+x)$ for a finite `x` with $x > -1$ and $|x| \geq 2^{-p-3}$, where $p$ is the
+// maximum of `prec` and the precision of `x`. If $x$ is large compared to the working scale $s$,
+// then $|\log(1+x) - \log x| < 1/x \leq 2^{-s-1}$; otherwise, $1+x$ is computed exactly.
+fn ln1p_prec_round_normal(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let x_exp = i64::from(x.get_exponent().unwrap());
+    let cancelled = if x_exp < 0 { x_exp.unsigned_abs() } else { 0 };
+    let mut one_plus_x = None;
+    round_fixed_point_ziv(prec + Limb::WIDTH + cancelled, prec, rm, |scale| {
+        if x_exp >= i64::exact_from(scale) + 2 {
+            return (ln_fixed_point(x, scale), 3);
+        }
+        let y = one_plus_x.get_or_insert_with(|| {
+            // The lowest possible nonzero bit of 1 + x has position min(x_exp - x_prec, 0), and
+            // the highest has position max(x_exp, 1).
+            let x_prec = i64::exact_from(x.get_prec().unwrap());
+            let prec = u64::exact_from(max(x_exp, 1) + 1 - min(x_exp - x_prec, 0));
+            let (y, o) = x.add_prec_ref_val(Float::ONE, prec);
+            assert_eq!(o, Equal);
+            y
+        });
+        (ln_fixed_point(y, scale), 2)
+    })
+}
+
+impl Float {
+    /// Computes $\log(1+x)$ for a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than the
+    /// exact result. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \log(1+x)+\varepsilon.
+    /// $$
+    /// - If $\log(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log(1+x)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log(1+x)|\rfloor-p+1}$.
+    /// - If $\log(1+x)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log(1+x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    /// - $f(-1.0,p,m)=-\infty$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<-1$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln1p_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::ln1p_round`] instead. If both of these things are true, consider using
+    /// [`Float::ln1p`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact result.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln1p, o) = Float::from(PI).ln1p_prec_round(5, Floor);
+    /// assert_eq!(ln1p.to_string(), "1.38");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln1p, o) = Float::from(PI).ln1p_prec_round(5, Ceiling);
+    /// assert_eq!(ln1p.to_string(), "1.44");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln1p, o) = Float::from(PI).ln1p_prec_round(5, Nearest);
+    /// assert_eq!(ln1p.to_string(), "1.44");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln1p, o) = Float::from(PI).ln1p_prec_round(20, Floor);
+    /// assert_eq!(ln1p.to_string(), "1.42108");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln1p, o) = Float::from(PI).ln1p_prec_round(20, Ceiling);
+    /// assert_eq!(ln1p.to_string(), "1.421082");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln1p, o) = Float::from(PI).ln1p_prec_round(20, Nearest);
+    /// assert_eq!(ln1p.to_string(), "1.42108");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn ln1p_prec_round(mut self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        let o = self.ln1p_prec_round_assign(prec, rm);
+        (self, o)
+    }
+
+    /// Computes $\log(1+x)$ for a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded result is less than, equal to, or greater than
+    /// the exact result. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \log(1+x)+\varepsilon.
+    /// $$
+    /// - If $\log(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log(1+x)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log(1+x)|\rfloor-p+1}$.
+    /// - If $\log(1+x)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log(1+x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    /// - $f(-1.0,p,m)=-\infty$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<-1$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln1p_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::ln1p_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float)::ln1p()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact result.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln1p, o) = Float::from(PI).ln1p_prec_round_ref(5, Floor);
+    /// assert_eq!(ln1p.to_string(), "1.38");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln1p, o) = Float::from(PI).ln1p_prec_round_ref(5, Ceiling);
+    /// assert_eq!(ln1p.to_string(), "1.44");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln1p, o) = Float::from(PI).ln1p_prec_round_ref(5, Nearest);
+    /// assert_eq!(ln1p.to_string(), "1.44");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln1p, o) = Float::from(PI).ln1p_prec_round_ref(20, Floor);
+    /// assert_eq!(ln1p.to_string(), "1.42108");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln1p, o) = Float::from(PI).ln1p_prec_round_ref(20, Ceiling);
+    /// assert_eq!(ln1p.to_string(), "1.421082");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln1p, o) = Float::from(PI).ln1p_prec_round_ref(20, Nearest);
+    /// assert_eq!(ln1p.to_string(), "1.42108");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn ln1p_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(NaN | Infinity { sign: false }) => (float_nan!(), Equal),
+            float_infinity!() => (float_infinity!(), Equal),
+            float_zero!() => (float_zero!(), Equal),
+            float_negative_zero!() => (float_negative_zero!(), Equal),
+            Float(Finite {
+                sign: false,
+                exponent: 1,
+                significand,
+                ..
+            }) if significand.is_power_of_2() => (float_negative_infinity!(), Equal),
+            Float(Finite {
+                sign: false,
+                exponent,
+                ..
+            }) if *exponent >= 1 => (float_nan!(), Equal),
+            Float(Finite {
+                exponent,
+                precision,
+                ..
+            }) => {
+                assert!(rm != Exact, "Inexact float ln1p");
+                if *exponent < 0 && u64::from(exponent.unsigned_abs()) >= max(prec, *precision) + 3
+                {
+                    // |log(1 + x) - x| < 2^(2 * exponent - 1)
+                    round_near_float(self, Less, prec, rm)
+                } else {
+                    ln1p_prec_round_normal(self, prec, rm)
+                }
+            }
+        }
+    }
+
+    /// Computes $\log(1+x)$ for a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded result is less than, equal to, or greater than the exact
+    /// result. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \log(1+x)+\varepsilon.
+    /// $$
+    /// - If $\log(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log(1+x)$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\log(1+x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    /// - $f(-1.0,p)=-\infty$
+    /// - $f(x,p)=\text{NaN}$ if $x<-1$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln1p_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Float::ln1p`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln1p, o) = Float::from(PI).ln1p_prec(5);
+    /// assert_eq!(ln1p.to_string(), "1.44");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln1p, o) = Float::from(PI).ln1p_prec(20);
+    /// assert_eq!(ln1p.to_string(), "1.42108");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn ln1p_prec(self, prec: u64) -> (Float, Ordering) {
+        self.ln1p_prec_round(prec, Nearest)
+    }
+
+    /// Computes $\log(1+x)$ for a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded result is less than, equal to, or greater than the exact
+    /// result. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \log(1+x)+\varepsilon.
+    /// $$
+    /// - If $\log(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log(1+x)$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\log(1+x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    /// - $f(-1.0,p)=-\infty$
+    /// - $f(x,p)=\text{NaN}$ if $x<-1$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln1p_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float)::ln1p()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln1p, o) = Float::from(PI).ln1p_prec_ref(5);
+    /// assert_eq!(ln1p.to_string(), "1.44");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln1p, o) = Float::from(PI).ln1p_prec_ref(20);
+    /// assert_eq!(ln1p.to_string(), "1.42108");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn ln1p_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.ln1p_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes $\log(1+x)$ for a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded result is less than, equal to, or greater than the exact result. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \log(1+x)+\varepsilon.
+    /// $$
+    /// - If $\log(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log(1+x)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log(1+x)|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\log(1+x)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log(1+x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    /// - $f(-1.0,m)=-\infty$
+    /// - $f(x,m)=\text{NaN}$ if $x<-1$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::ln1p_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::ln1p`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln1p, o) = Float::from(PI).ln1p_round(Floor);
+    /// assert_eq!(ln1p.to_string(), "1.421080412794291");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln1p, o) = Float::from(PI).ln1p_round(Ceiling);
+    /// assert_eq!(ln1p.to_string(), "1.421080412794293");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln1p, o) = Float::from(PI).ln1p_round(Nearest);
+    /// assert_eq!(ln1p.to_string(), "1.421080412794293");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn ln1p_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.ln1p_prec_round(prec, rm)
+    }
+
+    /// Computes $\log(1+x)$ for a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded result is less than, equal to, or greater than the exact result. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \log(1+x)+\varepsilon.
+    /// $$
+    /// - If $\log(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log(1+x)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log(1+x)|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\log(1+x)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log(1+x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    /// - $f(-1.0,m)=-\infty$
+    /// - $f(x,m)=\text{NaN}$ if $x<-1$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::ln1p_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float)::ln1p()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln1p, o) = Float::from(PI).ln1p_round_ref(Floor);
+    /// assert_eq!(ln1p.to_string(), "1.421080412794291");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln1p, o) = Float::from(PI).ln1p_round_ref(Ceiling);
+    /// assert_eq!(ln1p.to_string(), "1.421080412794293");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln1p, o) = Float::from(PI).ln1p_round_ref(Nearest);
+    /// assert_eq!(ln1p.to_string(), "1.421080412794293");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn ln1p_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.ln1p_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\log(1+x)$ for a [`Float`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded result is less than, equal to, or greater than the exact result.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \log(1+x)+\varepsilon.
+    /// $$
+    /// - If $\log(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log(1+x)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log(1+x)|\rfloor-p+1}$.
+    /// - If $\log(1+x)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log(1+x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::ln1p_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln1p_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::ln1p_round_assign`] instead. If both of these things are true, consider using
+    /// [`Float::ln1p_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact result.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln1p_prec_round_assign(5, Floor), Less);
+    /// assert_eq!(x.to_string(), "1.38");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln1p_prec_round_assign(5, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.44");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln1p_prec_round_assign(5, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "1.44");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln1p_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "1.42108");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln1p_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.421082");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln1p_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "1.42108");
+    /// ```
+    pub fn ln1p_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let o;
+        (*self, o) = self.ln1p_prec_round_ref(prec, rm);
+        o
+    }
+
+    /// Computes $\log(1+x)$ for a [`Float`] in place, rounding the result to the nearest value of
+    /// the specified precision. An [`Ordering`] is returned, indicating whether the rounded result
+    /// is less than, equal to, or greater than the exact result. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets \log(1+x)+\varepsilon.
+    /// $$
+    /// - If $\log(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log(1+x)$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\log(1+x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::ln1p_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln1p_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::ln1p_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln1p_prec_assign(5), Greater);
+    /// assert_eq!(x.to_string(), "1.44");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln1p_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "1.42108");
+    /// ```
+    #[inline]
+    pub fn ln1p_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.ln1p_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes $\log(1+x)$ for a [`Float`] in place, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded result is less
+    /// than, equal to, or greater than the exact result. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \log(1+x)+\varepsilon.
+    /// $$
+    /// - If $\log(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log(1+x)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log(1+x)|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\log(1+x)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log(1+x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::ln1p_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::ln1p_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::ln1p_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln1p_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "1.421080412794291");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln1p_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.421080412794293");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln1p_round_assign(Nearest), Greater);
+    /// assert_eq!(x.to_string(), "1.421080412794293");
+    /// ```
+    #[inline]
+    pub fn ln1p_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.ln1p_prec_round_assign(prec, rm)
+    }
+}
+
+impl Ln1p for Float {
+    type Output = Float;
+
+    /// Computes $\log(1+x)$ for a [`Float`], taking it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the result is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \log(1+x)+\varepsilon.
+    /// $$
+    /// - If $\log(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log(1+x)$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\log(1+x)|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=\text{NaN}$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    /// - $f(-1.0)=-\infty$
+    /// - $f(x)=\text{NaN}$ if $x<-1$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::ln1p_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::ln1p_round`].
+    /// If you want both of these things, consider using [`Float::ln1p_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Ln1p;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.ln1p().is_nan());
+    /// assert_eq!(Float::INFINITY.ln1p().to_string(), "Infinity");
+    /// assert!(Float::NEGATIVE_INFINITY.ln1p().is_nan());
+    /// assert_eq!(Float::from(1.5).ln1p().to_string(), "1.0");
+    /// assert!(Float::from(-1.5).ln1p().is_nan());
+    /// ```
+    #[inline]
+    fn ln1p(self) -> Float {
+        let prec = self.significant_bits();
+        self.ln1p_prec_round(prec, Nearest).0
+    }
+}
+
+impl Ln1p for &Float {
+    type Output = Float;
+
+    /// Computes $\log(1+x)$ for a [`Float`], taking it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the result is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \log(1+x)+\varepsilon.
+    /// $$
+    /// - If $\log(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log(1+x)$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\log(1+x)|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=\text{NaN}$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    /// - $f(-1.0)=-\infty$
+    /// - $f(x)=\text{NaN}$ if $x<-1$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln1p_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::ln1p_round_ref`]. If you want both of these things, consider using
+    /// [`Float::ln1p_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Ln1p;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).ln1p().is_nan());
+    /// assert_eq!((&Float::INFINITY).ln1p().to_string(), "Infinity");
+    /// assert!((&Float::NEGATIVE_INFINITY).ln1p().is_nan());
+    /// assert_eq!((&Float::from(1.5)).ln1p().to_string(), "1.0");
+    /// assert!((&Float::from(-1.5)).ln1p().is_nan());
+    /// ```
+    #[inline]
+    fn ln1p(self) -> Float {
+        let prec = self.significant_bits();
+        self.ln1p_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl Ln1pAssign for Float {
+    /// Computes $\log(1+x)$ for a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the result is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets \log(1+x)+\varepsilon.
+    /// $$
+    /// - If $\log(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log(1+x)$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\log(1+x)|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::ln1p`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln1p_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::ln1p_round_assign`]. If you want both of these things, consider using
+    /// [`Float::ln1p_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Ln1pAssign;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::NAN;
+    /// x.ln1p_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x.ln1p_assign();
+    /// assert_eq!(x.to_string(), "Infinity");
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x.ln1p_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.ln1p_assign();
+    /// assert_eq!(x.to_string(), "1.0");
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x.ln1p_assign();
+    /// assert!(x.is_nan());
+    /// ```
+    #[inline]
+    fn ln1p_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.ln1p_prec_round_assign(prec, Nearest);
+    }
+}
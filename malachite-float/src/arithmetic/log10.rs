@@ -0,0 +1,842 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::arithmetic::exp::round_fixed_point_ziv;
+use crate::arithmetic::ln::{ln_cancelled_bits, ln_fixed_point};
+use crate::{Float, float_either_zero, float_infinity, float_nan, float_negative_infinity};
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{Log10, Log10Assign, Pow};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+
+// If the positive finite [`Float`] `x` is an integer power of 10, returns the exponent; otherwise,
+// returns `None`.
+fn log_base_10_exact(x: &Float) -> Option<u64> {
+    let Float(Finite {
+        exponent,
+        significand,
+        ..
+    }) = x
+    else {
+        unreachable!()
+    };
+    // x = odd * 2^pow, and x = 10^pow iff odd = 5^pow
+    let zeros = significand.trailing_zeros().unwrap();
+    let pow = i64::from(*exponent) - i64::exact_from(significand.significant_bits())
+        + i64::exact_from(zeros);
+    if pow < 0 {
+        return None;
+    }
+    let pow = pow.unsigned_abs();
+    let odd = significand >> zeros;
+    // 2^(2 * pow) <= 5^pow < 2^(3 * pow)
+    let odd_bits = odd.significant_bits();
+    if odd_bits <= pow << 1 || odd_bits > 3 * pow + 1 {
+        return None;
+    }
+    if odd == Natural::from(5u32).pow(pow) {
+        Some(pow)
+    } else {
+        None
+    }
+}
+
+impl Float {
+    /// Takes the base-10 logarithm of a [`Float`], rounding the result to the specified precision
+    /// and with the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is
+    /// also returned, indicating whether the rounded logarithm is less than, equal to, or greater
+    /// than the exact logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \log_{10} x+\varepsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log_{10} x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p+1}$.
+    /// - If $\log_{10} x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(\pm0.0,p,m)=-\infty$
+    /// - $f(1.0,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::log10_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::log10_round`] instead. If both of these things are true, consider using
+    /// [`Float::log10`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact logarithm.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (log10, o) = Float::from(PI).log10_prec_round(5, Floor);
+    /// assert_eq!(log10.to_string(), "0.48");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (log10, o) = Float::from(PI).log10_prec_round(5, Ceiling);
+    /// assert_eq!(log10.to_string(), "0.5");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (log10, o) = Float::from(PI).log10_prec_round(5, Nearest);
+    /// assert_eq!(log10.to_string(), "0.5");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (log10, o) = Float::from(PI).log10_prec_round(20, Floor);
+    /// assert_eq!(log10.to_string(), "0.4971495");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (log10, o) = Float::from(PI).log10_prec_round(20, Ceiling);
+    /// assert_eq!(log10.to_string(), "0.4971499");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (log10, o) = Float::from(PI).log10_prec_round(20, Nearest);
+    /// assert_eq!(log10.to_string(), "0.4971499");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn log10_prec_round(mut self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        let o = self.log10_prec_round_assign(prec, rm);
+        (self, o)
+    }
+
+    /// Takes the base-10 logarithm of a [`Float`], rounding the result to the specified precision
+    /// and with the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`]
+    /// is also returned, indicating whether the rounded logarithm is less than, equal to, or
+    /// greater than the exact logarithm. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \log_{10} x+\varepsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log_{10} x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p+1}$.
+    /// - If $\log_{10} x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(\pm0.0,p,m)=-\infty$
+    /// - $f(1.0,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::log10_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::log10_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float)::log10()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact logarithm.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (log10, o) = Float::from(PI).log10_prec_round_ref(5, Floor);
+    /// assert_eq!(log10.to_string(), "0.48");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (log10, o) = Float::from(PI).log10_prec_round_ref(5, Ceiling);
+    /// assert_eq!(log10.to_string(), "0.5");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (log10, o) = Float::from(PI).log10_prec_round_ref(5, Nearest);
+    /// assert_eq!(log10.to_string(), "0.5");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (log10, o) = Float::from(PI).log10_prec_round_ref(20, Floor);
+    /// assert_eq!(log10.to_string(), "0.4971495");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (log10, o) = Float::from(PI).log10_prec_round_ref(20, Ceiling);
+    /// assert_eq!(log10.to_string(), "0.4971499");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (log10, o) = Float::from(PI).log10_prec_round_ref(20, Nearest);
+    /// assert_eq!(log10.to_string(), "0.4971499");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn log10_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(NaN | Infinity { sign: false } | Finite { sign: false, .. }) => {
+                (float_nan!(), Equal)
+            }
+            float_infinity!() => (float_infinity!(), Equal),
+            float_either_zero!() => (float_negative_infinity!(), Equal),
+            _ => {
+                if let Some(pow) = log_base_10_exact(self) {
+                    return Float::from_unsigned_prec_round(pow, prec, rm);
+                }
+                assert!(rm != Exact, "Inexact float logarithm");
+                let scale = prec + Limb::WIDTH + ln_cancelled_bits(self);
+                round_fixed_point_ziv(scale, prec, rm, |scale| {
+                    // |log_10 x| < 2^30, so the error is at most 4 / log 10 + 2^30 * 4 / 2^34 + 1 <
+                    // 2^2
+                    let l = ln_fixed_point(&Float::from(10u32), scale + 34);
+                    ((ln_fixed_point(self, scale) << (scale + 34)) / l, 2)
+                })
+            }
+        }
+    }
+
+    /// Takes the base-10 logarithm of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded logarithm is less than, equal to, or greater than the exact
+    /// logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \log_{10} x+\varepsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log_{10} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\log_{10}
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(\pm0.0,p)=-\infty$
+    /// - $f(1.0,p)=0.0$
+    /// - $f(x,p)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::log10_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Float::log10`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (log10, o) = Float::from(PI).log10_prec(5);
+    /// assert_eq!(log10.to_string(), "0.5");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (log10, o) = Float::from(PI).log10_prec(20);
+    /// assert_eq!(log10.to_string(), "0.4971499");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn log10_prec(self, prec: u64) -> (Float, Ordering) {
+        self.log10_prec_round(prec, Nearest)
+    }
+
+    /// Takes the base-10 logarithm of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded logarithm is less than, equal to, or greater than the exact
+    /// logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \log_{10} x+\varepsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log_{10} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\log_{10}
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(\pm0.0,p)=-\infty$
+    /// - $f(1.0,p)=0.0$
+    /// - $f(x,p)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::log10_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float)::log10()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (log10, o) = Float::from(PI).log10_prec_ref(5);
+    /// assert_eq!(log10.to_string(), "0.5");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (log10, o) = Float::from(PI).log10_prec_ref(20);
+    /// assert_eq!(log10.to_string(), "0.4971499");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn log10_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.log10_prec_round_ref(prec, Nearest)
+    }
+
+    /// Takes the base-10 logarithm of a [`Float`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether
+    /// the rounded logarithm is less than, equal to, or greater than the exact logarithm. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \log_{10} x+\varepsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log_{10} x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\log_{10} x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(\pm0.0,m)=-\infty$
+    /// - $f(1.0,m)=0.0$
+    /// - $f(x,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::log10_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::log10`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (log10, o) = Float::from(PI).log10_round(Floor);
+    /// assert_eq!(log10.to_string(), "0.4971498726941337");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (log10, o) = Float::from(PI).log10_round(Ceiling);
+    /// assert_eq!(log10.to_string(), "0.4971498726941341");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (log10, o) = Float::from(PI).log10_round(Nearest);
+    /// assert_eq!(log10.to_string(), "0.4971498726941337");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn log10_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.log10_prec_round(prec, rm)
+    }
+
+    /// Takes the base-10 logarithm of a [`Float`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded logarithm is less than, equal to, or greater than the exact logarithm.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \log_{10} x+\varepsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log_{10} x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\log_{10} x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(\pm0.0,m)=-\infty$
+    /// - $f(1.0,m)=0.0$
+    /// - $f(x,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::log10_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float)::log10()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (log10, o) = Float::from(PI).log10_round_ref(Floor);
+    /// assert_eq!(log10.to_string(), "0.4971498726941337");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (log10, o) = Float::from(PI).log10_round_ref(Ceiling);
+    /// assert_eq!(log10.to_string(), "0.4971498726941341");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (log10, o) = Float::from(PI).log10_round_ref(Nearest);
+    /// assert_eq!(log10.to_string(), "0.4971498726941337");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn log10_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.log10_prec_round_ref(prec, rm)
+    }
+
+    /// Takes the base-10 logarithm of a [`Float`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded logarithm is less than, equal to, or greater than the exact logarithm.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \log_{10} x+\varepsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log_{10} x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p+1}$.
+    /// - If $\log_{10} x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::log10_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::log10_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::log10_round_assign`] instead. If both of these things are true, consider using
+    /// [`Float::log10_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact logarithm.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.log10_prec_round_assign(5, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.48");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.log10_prec_round_assign(5, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.log10_prec_round_assign(5, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "0.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.log10_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.4971495");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.log10_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.4971499");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.log10_prec_round_assign(20, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "0.4971499");
+    /// ```
+    pub fn log10_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let o;
+        (*self, o) = self.log10_prec_round_ref(prec, rm);
+        o
+    }
+
+    /// Takes the base-10 logarithm of a [`Float`] in place, rounding the result to the nearest
+    /// value of the specified precision. An [`Ordering`] is returned, indicating whether the
+    /// rounded logarithm is less than, equal to, or greater than the exact logarithm. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets \log_{10} x+\varepsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log_{10} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\log_{10}
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::log10_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::log10_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::log10_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.log10_prec_assign(5), Greater);
+    /// assert_eq!(x.to_string(), "0.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.log10_prec_assign(20), Greater);
+    /// assert_eq!(x.to_string(), "0.4971499");
+    /// ```
+    #[inline]
+    pub fn log10_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.log10_prec_round_assign(prec, Nearest)
+    }
+
+    /// Takes the base-10 logarithm of a [`Float`] in place, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded logarithm is less
+    /// than, equal to, or greater than the exact logarithm. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \log_{10} x+\varepsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log_{10} x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\log_{10} x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::log10_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::log10_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Float::log10_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.log10_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "0.4971498726941337");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.log10_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.4971498726941341");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.log10_round_assign(Nearest), Less);
+    /// assert_eq!(x.to_string(), "0.4971498726941337");
+    /// ```
+    #[inline]
+    pub fn log10_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.log10_prec_round_assign(prec, rm)
+    }
+}
+
+impl Log10 for Float {
+    type Output = Float;
+
+    /// Takes the base-10 logarithm of a [`Float`], taking it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the logarithm is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \log_{10} x+\varepsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log_{10} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\log_{10} x|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=\text{NaN}$
+    /// - $f(\pm0.0)=-\infty$
+    /// - $f(1.0)=0.0$
+    /// - $f(x)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::log10_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::log10_round`]. If you want both of these things, consider using
+    /// [`Float::log10_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Log10;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.log10().is_nan());
+    /// assert_eq!(Float::INFINITY.log10().to_string(), "Infinity");
+    /// assert!(Float::NEGATIVE_INFINITY.log10().is_nan());
+    /// assert_eq!(Float::from(1.5).log10().to_string(), "0.19");
+    /// assert!(Float::from(-1.5).log10().is_nan());
+    /// ```
+    #[inline]
+    fn log10(self) -> Float {
+        let prec = self.significant_bits();
+        self.log10_prec_round(prec, Nearest).0
+    }
+}
+
+impl Log10 for &Float {
+    type Output = Float;
+
+    /// Takes the base-10 logarithm of a [`Float`], taking it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the logarithm is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \log_{10} x+\varepsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log_{10} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\log_{10} x|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=\text{NaN}$
+    /// - $f(\pm0.0)=-\infty$
+    /// - $f(1.0)=0.0$
+    /// - $f(x)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::log10_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::log10_round_ref`]. If you want both of these things, consider using
+    /// [`Float::log10_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Log10;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).log10().is_nan());
+    /// assert_eq!((&Float::INFINITY).log10().to_string(), "Infinity");
+    /// assert!((&Float::NEGATIVE_INFINITY).log10().is_nan());
+    /// assert_eq!((&Float::from(1.5)).log10().to_string(), "0.19");
+    /// assert!((&Float::from(-1.5)).log10().is_nan());
+    /// ```
+    #[inline]
+    fn log10(self) -> Float {
+        let prec = self.significant_bits();
+        self.log10_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl Log10Assign for Float {
+    /// Takes the base-10 logarithm of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the logarithm is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets \log_{10} x+\varepsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\log_{10} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\log_{10} x|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::log10`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::log10_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::log10_round_assign`]. If you want both of these things, consider using
+    /// [`Float::log10_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Log10Assign;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::NAN;
+    /// x.log10_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x.log10_assign();
+    /// assert_eq!(x.to_string(), "Infinity");
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x.log10_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.log10_assign();
+    /// assert_eq!(x.to_string(), "0.19");
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x.log10_assign();
+    /// assert!(x.is_nan());
+    /// ```
+    #[inline]
+    fn log10_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.log10_prec_round_assign(prec, Nearest);
+    }
+}
@@ -8,16 +8,68 @@
 
 /// Absolute value of [`Float`](super::Float)s.
 pub mod abs;
+/// Implementations of [`Acos`](malachite_base::num::arithmetic::traits::Acos) and
+/// [`AcosAssign`](malachite_base::num::arithmetic::traits::AcosAssign), traits for computing the
+/// arccosine of a number.
+pub mod acos;
 /// Addition of [`Float`](super::Float)s, and of [`Float`](super::Float)s with
 /// [`Rational`](malachite_q::Rational)s.
 pub mod add;
+/// Implementations of [`Asin`](malachite_base::num::arithmetic::traits::Asin) and
+/// [`AsinAssign`](malachite_base::num::arithmetic::traits::AsinAssign), traits for computing the
+/// arcsine of a number.
+pub mod asin;
+/// Implementations of [`Atan`](malachite_base::num::arithmetic::traits::Atan) and
+/// [`AtanAssign`](malachite_base::num::arithmetic::traits::AtanAssign), traits for computing the
+/// arctangent of a number.
+pub mod atan;
+/// Implementations of [`Atan2`](malachite_base::num::arithmetic::traits::Atan2) and
+/// [`Atan2Assign`](malachite_base::num::arithmetic::traits::Atan2Assign), traits for computing the
+/// angle of a point in the plane, given its coordinates.
+pub mod atan2;
+/// Implementations of [`Cos`](malachite_base::num::arithmetic::traits::Cos) and
+/// [`CosAssign`](malachite_base::num::arithmetic::traits::CosAssign), traits for computing the
+/// cosine of a number.
+pub mod cos;
 /// Division of [`Float`](super::Float)s, of [`Float`](super::Float)s by
 /// [`Rational`](malachite_q::Rational)s, and of [`Rational`](malachite_q::Rational)s by
 /// [`Float`](super::Float)s.
 pub mod div;
+/// Implementations of [`Exp`](malachite_base::num::arithmetic::traits::Exp) and
+/// [`ExpAssign`](malachite_base::num::arithmetic::traits::ExpAssign), traits for raising $e$ to the
+/// power of a number.
+pub mod exp;
+/// Implementations of [`Exp10`](malachite_base::num::arithmetic::traits::Exp10) and
+/// [`Exp10Assign`](malachite_base::num::arithmetic::traits::Exp10Assign), traits for raising 10 to
+/// the power of a number.
+pub mod exp10;
+/// Implementations of [`Exp2`](malachite_base::num::arithmetic::traits::Exp2) and
+/// [`Exp2Assign`](malachite_base::num::arithmetic::traits::Exp2Assign), traits for raising 2 to the
+/// power of a number.
+pub mod exp2;
+/// Implementations of [`ExpM1`](malachite_base::num::arithmetic::traits::ExpM1) and
+/// [`ExpM1Assign`](malachite_base::num::arithmetic::traits::ExpM1Assign), traits for computing
+/// $e^x-1$.
+pub mod expm1;
 /// An implementations of [`IsPowerOf2`](malachite_base::num::arithmetic::traits::IsPowerOf2), a
 /// trait for determining whether a number is an integer power of 2.
 pub mod is_power_of_2;
+/// Implementations of [`Ln`](malachite_base::num::arithmetic::traits::Ln) and
+/// [`LnAssign`](malachite_base::num::arithmetic::traits::LnAssign), traits for taking the natural
+/// logarithm of a number.
+pub mod ln;
+/// Implementations of [`Ln1p`](malachite_base::num::arithmetic::traits::Ln1p) and
+/// [`Ln1pAssign`](malachite_base::num::arithmetic::traits::Ln1pAssign), traits for computing
+/// $\log(1+x)$.
+pub mod ln1p;
+/// Implementations of [`Log10`](malachite_base::num::arithmetic::traits::Log10) and
+/// [`Log10Assign`](malachite_base::num::arithmetic::traits::Log10Assign), traits for taking the
+/// base-10 logarithm of a number.
+pub mod log10;
+/// Implementations of [`Log2`](malachite_base::num::arithmetic::traits::Log2) and
+/// [`Log2Assign`](malachite_base::num::arithmetic::traits::Log2Assign), traits for taking the
+/// base-2 logarithm of a number.
+pub mod log2;
 /// Multiplication of [`Float`](super::Float)s, and of [`Float`](super::Float)s with
 /// [`Rational`](malachite_q::Rational)s.
 pub mod mul;
@@ -30,6 +82,10 @@ pub mod power_of_2;
 /// [`ReciprocalAssign`](malachite_base::num::arithmetic::traits::ReciprocalAssign), traits for
 /// computing the reciprocal of a number.
 pub mod reciprocal;
+/// Implementations of [`ReciprocalSqrt`](malachite_base::num::arithmetic::traits::ReciprocalSqrt)
+/// and [`ReciprocalSqrtAssign`](malachite_base::num::arithmetic::traits::ReciprocalSqrtAssign),
+/// traits for computing the reciprocal of the square root of a number.
+pub mod reciprocal_sqrt;
 /// Left-shifting a [`Float`](super::Float) (multiplying it by a power of 2).
 ///
 /// # shl
@@ -277,9 +333,24 @@ pub mod shr_round;
 /// An implementation of [`Sign`](malachite_base::num::arithmetic::traits::Sign), a trait for
 /// determining the sign of a number.
 pub mod sign;
+/// Implementations of [`Sin`](malachite_base::num::arithmetic::traits::Sin) and
+/// [`SinAssign`](malachite_base::num::arithmetic::traits::SinAssign), traits for computing the sine
+/// of a number.
+pub mod sin;
+/// An implementation of [`SinCos`](malachite_base::num::arithmetic::traits::SinCos), a trait for
+/// computing the sine and cosine of a number simultaneously.
+pub mod sin_cos;
+/// Implementations of [`Sqrt`](malachite_base::num::arithmetic::traits::Sqrt) and
+/// [`SqrtAssign`](malachite_base::num::arithmetic::traits::SqrtAssign), traits for computing the
+/// square root of a number.
+pub mod sqrt;
 /// Squaring of [`Float`](super::Float)s.
 pub mod square;
 /// Subtraction of [`Float`](super::Float)s, of [`Float`](super::Float)s by
 /// [`Rational`](malachite_q::Rational)s, and of [`Rational`](malachite_q::Rational)s by
 /// [`Float`](super::Float)s.
 pub mod sub;
+/// Implementations of [`Tan`](malachite_base::num::arithmetic::traits::Tan) and
+/// [`TanAssign`](malachite_base::num::arithmetic::traits::TanAssign), traits for computing the
+/// tangent of a number.
+pub mod tan;
@@ -0,0 +1,886 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::conversion::from_natural::from_natural_prec_round_zero_exponent;
+use crate::{Float, float_either_zero, float_infinity, float_nan, float_zero};
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{
+    DivRem, Parity, PowerOf2, ReciprocalSqrt, ReciprocalSqrtAssign, SqrtRem,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::natural::Natural;
+
+// Takes the reciprocal of the square root of the positive finite `Float` whose significand is `x`
+// and whose exponent is `x_exp`, returning the significand and exponent of the result rounded to
+// `prec` bits, along with an `Ordering` comparing the rounded result to the exact one.
+//
+// If $x = n2^e$ with $e$ even, then $1/\sqrt{x} = 2^{-k-e/2}\sqrt{2^{2k}/n}$. We choose $k$ so that
+// $\lfloor \sqrt{\lfloor 2^{2k}/n \rfloor} \rfloor$ has at least `prec + 2` significant bits, and
+// append a sticky 1 bit if either the division or the square root was inexact.
+pub(crate) fn reciprocal_sqrt_float_significand_ref(
+    x: &Natural,
+    x_exp: i32,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Natural, i32, Ordering) {
+    let bits = x.significant_bits();
+    let zeros = x.trailing_zeros().unwrap();
+    let mut n = x >> zeros;
+    let mut e = i64::from(x_exp) - i64::exact_from(bits) + i64::exact_from(zeros);
+    if e.odd() {
+        n <<= 1u32;
+        e -= 1;
+    }
+    let k = prec + 3 + (n.significant_bits() >> 1);
+    let (q, r) = Natural::power_of_2(k << 1).div_rem(n);
+    let (mut root, rem) = q.sqrt_rem();
+    let mut root_exp = -(e >> 1) - i64::exact_from(k);
+    if r != 0u32 || rem != 0u32 {
+        assert!(rm != Exact, "Inexact float reciprocal square root");
+        root <<= 1u32;
+        root.set_bit(0);
+        root_exp -= 1;
+    }
+    let root_bits = i64::exact_from(root.significant_bits());
+    let (root, o) = from_natural_prec_round_zero_exponent(root, prec, rm);
+    let Float(Finite {
+        exponent,
+        significand,
+        ..
+    }) = root
+    else {
+        unreachable!()
+    };
+    (
+        significand,
+        i32::exact_from(i64::from(exponent) + root_bits + root_exp),
+        o,
+    )
+}
+
+impl Float {
+    /// Takes the reciprocal of the square root of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded reciprocal square root is less
+    /// than, equal to, or greater than the exact reciprocal square root. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = 1/\sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 (1/\sqrt{x})\rfloor-p+1}$.
+    /// - If $1/\sqrt{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 (1/\sqrt{x})\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=0.0$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=\infty$
+    /// - $f(-0.0,p,m)=\infty$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::reciprocal_sqrt_prec`]
+    /// instead. If you know that your target precision is the precision of the input, consider
+    /// using [`Float::reciprocal_sqrt_round`] instead. If both of these things are true, consider
+    /// using [`Float::reciprocal_sqrt`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact reciprocal square root.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (reciprocal_sqrt, o) = Float::from(PI).reciprocal_sqrt_prec_round(5, Floor);
+    /// assert_eq!(reciprocal_sqrt.to_string(), "0.56");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (reciprocal_sqrt, o) = Float::from(PI).reciprocal_sqrt_prec_round(5, Ceiling);
+    /// assert_eq!(reciprocal_sqrt.to_string(), "0.59");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (reciprocal_sqrt, o) = Float::from(PI).reciprocal_sqrt_prec_round(5, Nearest);
+    /// assert_eq!(reciprocal_sqrt.to_string(), "0.56");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (reciprocal_sqrt, o) = Float::from(PI).reciprocal_sqrt_prec_round(20, Floor);
+    /// assert_eq!(reciprocal_sqrt.to_string(), "0.564189");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (reciprocal_sqrt, o) = Float::from(PI).reciprocal_sqrt_prec_round(20, Ceiling);
+    /// assert_eq!(reciprocal_sqrt.to_string(), "0.56419");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (reciprocal_sqrt, o) = Float::from(PI).reciprocal_sqrt_prec_round(20, Nearest);
+    /// assert_eq!(reciprocal_sqrt.to_string(), "0.56419");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn reciprocal_sqrt_prec_round(mut self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        let o = self.reciprocal_sqrt_prec_round_assign(prec, rm);
+        (self, o)
+    }
+
+    /// Takes the reciprocal of the square root of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded reciprocal square root is less
+    /// than, equal to, or greater than the exact reciprocal square root. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = 1/\sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 (1/\sqrt{x})\rfloor-p+1}$.
+    /// - If $1/\sqrt{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 (1/\sqrt{x})\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=0.0$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=\infty$
+    /// - $f(-0.0,p,m)=\infty$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::reciprocal_sqrt_prec_ref`]
+    /// instead. If you know that your target precision is the precision of the input, consider
+    /// using [`Float::reciprocal_sqrt_round_ref`] instead. If both of these things are true,
+    /// consider using `(&Float)::reciprocal_sqrt()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact reciprocal square root.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (reciprocal_sqrt, o) = Float::from(PI).reciprocal_sqrt_prec_round_ref(5, Floor);
+    /// assert_eq!(reciprocal_sqrt.to_string(), "0.56");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (reciprocal_sqrt, o) = Float::from(PI).reciprocal_sqrt_prec_round_ref(5, Ceiling);
+    /// assert_eq!(reciprocal_sqrt.to_string(), "0.59");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (reciprocal_sqrt, o) = Float::from(PI).reciprocal_sqrt_prec_round_ref(5, Nearest);
+    /// assert_eq!(reciprocal_sqrt.to_string(), "0.56");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (reciprocal_sqrt, o) = Float::from(PI).reciprocal_sqrt_prec_round_ref(20, Floor);
+    /// assert_eq!(reciprocal_sqrt.to_string(), "0.564189");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (reciprocal_sqrt, o) = Float::from(PI).reciprocal_sqrt_prec_round_ref(20, Ceiling);
+    /// assert_eq!(reciprocal_sqrt.to_string(), "0.56419");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (reciprocal_sqrt, o) = Float::from(PI).reciprocal_sqrt_prec_round_ref(20, Nearest);
+    /// assert_eq!(reciprocal_sqrt.to_string(), "0.56419");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn reciprocal_sqrt_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(NaN | Infinity { sign: false } | Finite { sign: false, .. }) => {
+                (float_nan!(), Equal)
+            }
+            float_infinity!() => (float_zero!(), Equal),
+            float_either_zero!() => (float_infinity!(), Equal),
+            Float(Finite {
+                exponent: x_exp,
+                significand: x,
+                ..
+            }) => {
+                let (root, exp, o) = reciprocal_sqrt_float_significand_ref(x, *x_exp, prec, rm);
+                (
+                    Float(Finite {
+                        sign: true,
+                        exponent: exp,
+                        precision: prec,
+                        significand: root,
+                    }),
+                    o,
+                )
+            }
+        }
+    }
+
+    /// Takes the reciprocal of the square root of a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded reciprocal square root is less than, equal to, or
+    /// greater than the exact reciprocal square root. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the reciprocal square root is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = 1/\sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   (1/\sqrt{x})\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=0.0$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=\infty$
+    /// - $f(-0.0,p)=\infty$
+    /// - $f(x,p)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::reciprocal_sqrt_prec_round`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::reciprocal_sqrt`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (reciprocal_sqrt, o) = Float::from(PI).reciprocal_sqrt_prec(5);
+    /// assert_eq!(reciprocal_sqrt.to_string(), "0.56");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (reciprocal_sqrt, o) = Float::from(PI).reciprocal_sqrt_prec(20);
+    /// assert_eq!(reciprocal_sqrt.to_string(), "0.56419");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn reciprocal_sqrt_prec(self, prec: u64) -> (Float, Ordering) {
+        self.reciprocal_sqrt_prec_round(prec, Nearest)
+    }
+
+    /// Takes the reciprocal of the square root of a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded reciprocal square root is less than, equal to,
+    /// or greater than the exact reciprocal square root. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the reciprocal square root is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = 1/\sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   (1/\sqrt{x})\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=0.0$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=\infty$
+    /// - $f(-0.0,p)=\infty$
+    /// - $f(x,p)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::reciprocal_sqrt_prec_round_ref`] instead. If you know that your target precision is
+    /// the precision of the input, consider using `(&Float)::reciprocal_sqrt()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (reciprocal_sqrt, o) = Float::from(PI).reciprocal_sqrt_prec_ref(5);
+    /// assert_eq!(reciprocal_sqrt.to_string(), "0.56");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (reciprocal_sqrt, o) = Float::from(PI).reciprocal_sqrt_prec_ref(20);
+    /// assert_eq!(reciprocal_sqrt.to_string(), "0.56419");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn reciprocal_sqrt_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.reciprocal_sqrt_prec_round_ref(prec, Nearest)
+    }
+
+    /// Takes the reciprocal of the square root of a [`Float`], rounding the result with the
+    /// specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded reciprocal square root is less than, equal to, or greater
+    /// than the exact reciprocal square root. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = 1/\sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 (1/\sqrt{x})\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $1/\sqrt{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 (1/\sqrt{x})\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=0.0$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=\infty$
+    /// - $f(-0.0,m)=\infty$
+    /// - $f(x,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::reciprocal_sqrt_prec_round`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Float::reciprocal_sqrt`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (reciprocal_sqrt, o) = Float::from(PI).reciprocal_sqrt_round(Floor);
+    /// assert_eq!(reciprocal_sqrt.to_string(), "0.564189583547756");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (reciprocal_sqrt, o) = Float::from(PI).reciprocal_sqrt_round(Ceiling);
+    /// assert_eq!(reciprocal_sqrt.to_string(), "0.564189583547757");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (reciprocal_sqrt, o) = Float::from(PI).reciprocal_sqrt_round(Nearest);
+    /// assert_eq!(reciprocal_sqrt.to_string(), "0.564189583547757");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn reciprocal_sqrt_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.reciprocal_sqrt_prec_round(prec, rm)
+    }
+
+    /// Takes the reciprocal of the square root of a [`Float`], rounding the result with the
+    /// specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded reciprocal square root is less than, equal to, or
+    /// greater than the exact reciprocal square root. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = 1/\sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 (1/\sqrt{x})\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $1/\sqrt{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 (1/\sqrt{x})\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=0.0$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=\infty$
+    /// - $f(-0.0,m)=\infty$
+    /// - $f(x,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::reciprocal_sqrt_prec_round_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using `(&Float)::reciprocal_sqrt()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (reciprocal_sqrt, o) = Float::from(PI).reciprocal_sqrt_round_ref(Floor);
+    /// assert_eq!(reciprocal_sqrt.to_string(), "0.564189583547756");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (reciprocal_sqrt, o) = Float::from(PI).reciprocal_sqrt_round_ref(Ceiling);
+    /// assert_eq!(reciprocal_sqrt.to_string(), "0.564189583547757");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (reciprocal_sqrt, o) = Float::from(PI).reciprocal_sqrt_round_ref(Nearest);
+    /// assert_eq!(reciprocal_sqrt.to_string(), "0.564189583547757");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn reciprocal_sqrt_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.reciprocal_sqrt_prec_round_ref(prec, rm)
+    }
+
+    /// Takes the reciprocal of the square root of a [`Float`] in place, rounding the result to the
+    /// specified precision and with the specified rounding mode. An [`Ordering`] is returned,
+    /// indicating whether the rounded reciprocal square root is less than, equal to, or greater
+    /// than the exact reciprocal square root. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets 1/\sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 (1/\sqrt{x})\rfloor-p+1}$.
+    /// - If $1/\sqrt{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 (1/\sqrt{x})\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::reciprocal_sqrt_prec_round`] documentation for information on special
+    /// cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::reciprocal_sqrt_prec_assign`]
+    /// instead. If you know that your target precision is the precision of the input, consider
+    /// using [`Float::reciprocal_sqrt_round_assign`] instead. If both of these things are true,
+    /// consider using [`Float::reciprocal_sqrt_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact reciprocal square root.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.reciprocal_sqrt_prec_round_assign(5, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.56");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.reciprocal_sqrt_prec_round_assign(5, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.59");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.reciprocal_sqrt_prec_round_assign(5, Nearest), Less);
+    /// assert_eq!(x.to_string(), "0.56");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.reciprocal_sqrt_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.564189");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.reciprocal_sqrt_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.56419");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.reciprocal_sqrt_prec_round_assign(20, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "0.56419");
+    /// ```
+    pub fn reciprocal_sqrt_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        assert_ne!(prec, 0);
+        match &mut *self {
+            float_nan!() => Equal,
+            Float(Infinity { sign: false } | Finite { sign: false, .. }) => {
+                *self = float_nan!();
+                Equal
+            }
+            float_infinity!() => {
+                *self = float_zero!();
+                Equal
+            }
+            float_either_zero!() => {
+                *self = float_infinity!();
+                Equal
+            }
+            Float(Finite {
+                exponent: x_exp,
+                precision: x_prec,
+                significand: x,
+                ..
+            }) => {
+                let o;
+                (*x, *x_exp, o) = reciprocal_sqrt_float_significand_ref(x, *x_exp, prec, rm);
+                *x_prec = prec;
+                o
+            }
+        }
+    }
+
+    /// Takes the reciprocal of the square root of a [`Float`] in place, rounding the result to the
+    /// nearest value of the specified precision. An [`Ordering`] is returned, indicating whether
+    /// the rounded reciprocal square root is less than, equal to, or greater than the exact
+    /// reciprocal square root. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the reciprocal square root is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets 1/\sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   (1/\sqrt{x})\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::reciprocal_sqrt_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::reciprocal_sqrt_prec_round_assign`] instead. If you know that your target precision
+    /// is the precision of the input, consider using [`Float::reciprocal_sqrt_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.reciprocal_sqrt_prec_assign(5), Less);
+    /// assert_eq!(x.to_string(), "0.56");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.reciprocal_sqrt_prec_assign(20), Greater);
+    /// assert_eq!(x.to_string(), "0.56419");
+    /// ```
+    #[inline]
+    pub fn reciprocal_sqrt_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.reciprocal_sqrt_prec_round_assign(prec, Nearest)
+    }
+
+    /// Takes the reciprocal of the square root of a [`Float`] in place, rounding the result with
+    /// the specified rounding mode. An [`Ordering`] is returned, indicating whether the rounded
+    /// reciprocal square root is less than, equal to, or greater than the exact reciprocal square
+    /// root. Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets 1/\sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 (1/\sqrt{x})\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $1/\sqrt{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 (1/\sqrt{x})\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::reciprocal_sqrt_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::reciprocal_sqrt_prec_round_assign`] instead. If you know you'll be using the
+    /// `Nearest` rounding mode, consider using [`Float::reciprocal_sqrt_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.reciprocal_sqrt_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "0.564189583547756");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.reciprocal_sqrt_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.564189583547757");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.reciprocal_sqrt_round_assign(Nearest), Greater);
+    /// assert_eq!(x.to_string(), "0.564189583547757");
+    /// ```
+    #[inline]
+    pub fn reciprocal_sqrt_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.reciprocal_sqrt_prec_round_assign(prec, rm)
+    }
+}
+
+impl ReciprocalSqrt for Float {
+    type Output = Float;
+
+    /// Takes the reciprocal of the square root of a [`Float`], taking it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the reciprocal square
+    /// root is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x) = 1/\sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 (1/\sqrt{x})\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=0.0$
+    /// - $f(-\infty)=\text{NaN}$
+    /// - $f(0.0)=\infty$
+    /// - $f(-0.0)=\infty$
+    /// - $f(x)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::reciprocal_sqrt_prec`] instead. If you want to specify the output precision,
+    /// consider using [`Float::reciprocal_sqrt_round`]. If you want both of these things, consider
+    /// using [`Float::reciprocal_sqrt_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ReciprocalSqrt;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.reciprocal_sqrt().is_nan());
+    /// assert_eq!(Float::INFINITY.reciprocal_sqrt().to_string(), "0.0");
+    /// assert!(Float::NEGATIVE_INFINITY.reciprocal_sqrt().is_nan());
+    /// assert_eq!(Float::from(1.5).reciprocal_sqrt().to_string(), "0.8");
+    /// assert!(Float::from(-1.5).reciprocal_sqrt().is_nan());
+    /// ```
+    #[inline]
+    fn reciprocal_sqrt(self) -> Float {
+        let prec = self.significant_bits();
+        self.reciprocal_sqrt_prec_round(prec, Nearest).0
+    }
+}
+
+impl ReciprocalSqrt for &Float {
+    type Output = Float;
+
+    /// Takes the reciprocal of the square root of a [`Float`], taking it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the reciprocal square
+    /// root is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x) = 1/\sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 (1/\sqrt{x})\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=0.0$
+    /// - $f(-\infty)=\text{NaN}$
+    /// - $f(0.0)=\infty$
+    /// - $f(-0.0)=\infty$
+    /// - $f(x)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::reciprocal_sqrt_prec_ref`] instead. If you want to specify the output precision,
+    /// consider using [`Float::reciprocal_sqrt_round_ref`]. If you want both of these things,
+    /// consider using [`Float::reciprocal_sqrt_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ReciprocalSqrt;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).reciprocal_sqrt().is_nan());
+    /// assert_eq!((&Float::INFINITY).reciprocal_sqrt().to_string(), "0.0");
+    /// assert!((&Float::NEGATIVE_INFINITY).reciprocal_sqrt().is_nan());
+    /// assert_eq!((&Float::from(1.5)).reciprocal_sqrt().to_string(), "0.8");
+    /// assert!((&Float::from(-1.5)).reciprocal_sqrt().is_nan());
+    /// ```
+    #[inline]
+    fn reciprocal_sqrt(self) -> Float {
+        let prec = self.significant_bits();
+        self.reciprocal_sqrt_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl ReciprocalSqrtAssign for Float {
+    /// Takes the reciprocal of the square root of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the reciprocal square
+    /// root is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x\gets 1/\sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 (1/\sqrt{x})\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::reciprocal_sqrt`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::reciprocal_sqrt_prec_assign`] instead. If you want to specify the output precision,
+    /// consider using [`Float::reciprocal_sqrt_round_assign`]. If you want both of these things,
+    /// consider using [`Float::reciprocal_sqrt_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ReciprocalSqrtAssign;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::NAN;
+    /// x.reciprocal_sqrt_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x.reciprocal_sqrt_assign();
+    /// assert_eq!(x.to_string(), "0.0");
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x.reciprocal_sqrt_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.reciprocal_sqrt_assign();
+    /// assert_eq!(x.to_string(), "0.8");
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x.reciprocal_sqrt_assign();
+    /// assert!(x.is_nan());
+    /// ```
+    #[inline]
+    fn reciprocal_sqrt_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.reciprocal_sqrt_prec_round_assign(prec, Nearest);
+    }
+}
@@ -0,0 +1,869 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Infinity, NaN, Zero};
+use crate::arithmetic::exp::{float_to_fixed_point, round_fixed_point_ziv, round_near_float};
+use crate::constants::pi::pi_fixed_point;
+use crate::{Float, float_either_zero, float_nan};
+use core::cmp::Ordering::{self, *};
+use core::cmp::max;
+use malachite_base::num::arithmetic::traits::{
+    DivRound, FloorSqrt, ModPowerOf2, PowerOf2, Sin, SinAssign,
+};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::Zero as ZeroTrait;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+use malachite_nz::platform::Limb;
+
+// Given $r$ with $|r| \leq 2^s$, where $s$ is `scale`, returns approximations of $2^s
+// \sin(r/2^s)$ and $2^s \cos(r/2^s)$, each with an error less than 2.
+//
+// The argument is divided by $2^k$, with $k \approx \sqrt{s}/2$, and the Taylor series for both
+// functions are summed at a slightly higher scale; the double-angle formulas are then applied $k$
+// times.
+pub(crate) fn sin_cos_fixed_point(r: &Integer, scale: u64) -> (Integer, Integer) {
+    let doublings = scale.floor_sqrt() >> 1;
+    let guard = scale.significant_bits() + 4;
+    let working_scale = scale + (doublings << 1) + guard;
+    // u = r / 2^doublings, at scale working_scale
+    let u = r << (doublings + guard);
+    let mut sin = Integer::ZERO;
+    let mut cos = Integer::power_of_2(working_scale);
+    let mut term = cos.clone();
+    let mut k = 1u64;
+    loop {
+        term *= &u;
+        term >>= working_scale;
+        term /= Integer::from(k);
+        if term == 0u32 {
+            break;
+        }
+        match k & 3 {
+            0 => cos += &term,
+            1 => sin += &term,
+            2 => cos -= &term,
+            _ => sin -= &term,
+        }
+        k += 1;
+    }
+    for _ in 0..doublings {
+        let new_sin = (&sin * &cos) >> (working_scale - 1);
+        cos = ((&cos - &sin) * (&cos + &sin)) >> working_scale;
+        sin = new_sin;
+    }
+    let shift = (doublings << 1) + guard;
+    (sin >> shift, cos >> shift)
+}
+
+// Given a finite [`Float`] $x$, returns approximations of $2^s \sin x$ and $2^s \cos x$, where $s$
+// is `scale`, each with an error less than 4.
+//
+// The argument is reduced to $r = x - k\pi/2$, where $k$ is the integer closest to $2x/\pi$, so
+// that $|r| \leq \pi/4$. Since $x$ is exact, the reduction only requires $\pi$ to have about as
+// many extra bits as the exponent of $x$, however large that is.
+pub(crate) fn sin_cos_reduced_fixed_point(x: &Float, scale: u64) -> (Integer, Integer) {
+    let extra = u64::exact_from(max(x.get_exponent().unwrap_or(0), 0)) + 4;
+    let working_scale = scale + extra;
+    let x = float_to_fixed_point(x, working_scale);
+    let half_pi = Integer::from(pi_fixed_point(working_scale) >> 1u32);
+    let k = ((&x << 1u32) + &half_pi)
+        .div_round(&half_pi << 1u32, Floor)
+        .0;
+    // |k| <= 2^(extra - 4), so the error of r is less than 1 + 2^(-3) + 2^(-4) < 2
+    let r = (x - &k * half_pi) >> extra;
+    let (sin, cos) = sin_cos_fixed_point(&r, scale);
+    match u64::exact_from(&k.mod_power_of_2(2)) {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    }
+}
+
+impl Float {
+    /// Computes the sine of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded sine is less than, equal to, or greater than the
+    /// exact sine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$.
+    /// - If $\sin x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sin_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::sin_round`] instead. If both of these things are true, consider using
+    /// [`Float::sin`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if the exponent is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact sine.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec_round(5, Floor);
+    /// assert_eq!(sin.to_string(), "1.18e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec_round(5, Ceiling);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec_round(5, Nearest);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec_round(20, Floor);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec_round(20, Ceiling);
+    /// assert_eq!(sin.to_string(), "1.224649e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec_round(20, Nearest);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn sin_prec_round(mut self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        let o = self.sin_prec_round_assign(prec, rm);
+        (self, o)
+    }
+
+    /// Computes the sine of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded sine is less than, equal to, or greater than the
+    /// exact sine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$.
+    /// - If $\sin x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sin_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::sin_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float)::sin()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if the exponent is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact sine.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec_round_ref(5, Floor);
+    /// assert_eq!(sin.to_string(), "1.18e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec_round_ref(5, Ceiling);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec_round_ref(5, Nearest);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec_round_ref(20, Floor);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec_round_ref(20, Ceiling);
+    /// assert_eq!(sin.to_string(), "1.224649e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec_round_ref(20, Nearest);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn sin_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(NaN | Infinity { .. }) => (float_nan!(), Equal),
+            float_either_zero!() => (self.clone(), Equal),
+            _ => {
+                assert!(rm != Exact, "Inexact float sine");
+                let exponent = self.get_exponent().unwrap();
+                if exponent < 0
+                    && u64::from(exponent.unsigned_abs()) << 1
+                        >= max(prec, self.get_prec().unwrap()) + 2
+                {
+                    // |sin x - x| < |x|^3 / 6
+                    return round_near_float(
+                        self,
+                        if *self > 0u32 { Less } else { Greater },
+                        prec,
+                        rm,
+                    );
+                }
+                let scale = prec + Limb::WIDTH + u64::from(exponent.min(0).unsigned_abs());
+                round_fixed_point_ziv(scale, prec, rm, |scale| {
+                    (sin_cos_reduced_fixed_point(self, scale).0, 2)
+                })
+            }
+        }
+    }
+
+    /// Computes the sine of a [`Float`], rounding the result to the nearest value of the specified
+    /// precision. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded sine is less than, equal to, or greater than the exact sine. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// If the sine is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\sin
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Float::sin`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if the exponent is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec(5);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec(20);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn sin_prec(self, prec: u64) -> (Float, Ordering) {
+        self.sin_prec_round(prec, Nearest)
+    }
+
+    /// Computes the sine of a [`Float`], rounding the result to the nearest value of the specified
+    /// precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded sine is less than, equal to, or greater than the exact sine. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// If the sine is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\sin
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float)::sin()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if the exponent is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec_ref(5);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec_ref(20);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn sin_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.sin_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes the sine of a [`Float`], rounding the result with the specified rounding mode. The
+    /// [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded sine is less than, equal to, or greater than the exact sine. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\sin x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sin_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::sin`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if the exponent is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sin, o) = Float::from(PI).sin_round(Floor);
+    /// assert_eq!(sin.to_string(), "1.224646799147351e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_round(Ceiling);
+    /// assert_eq!(sin.to_string(), "1.224646799147353e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_round(Nearest);
+    /// assert_eq!(sin.to_string(), "1.224646799147353e-16");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn sin_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.sin_prec_round(prec, rm)
+    }
+
+    /// Computes the sine of a [`Float`], rounding the result with the specified rounding mode. The
+    /// [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded sine is less than, equal to, or greater than the exact sine. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\sin x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sin_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float)::sin()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if the exponent is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sin, o) = Float::from(PI).sin_round_ref(Floor);
+    /// assert_eq!(sin.to_string(), "1.224646799147351e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_round_ref(Ceiling);
+    /// assert_eq!(sin.to_string(), "1.224646799147353e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_round_ref(Nearest);
+    /// assert_eq!(sin.to_string(), "1.224646799147353e-16");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn sin_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.sin_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the sine of a [`Float`] in place, rounding the result to the specified precision
+    /// and with the specified rounding mode. An [`Ordering`] is returned, indicating whether the
+    /// rounded sine is less than, equal to, or greater than the exact sine. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$.
+    /// - If $\sin x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::sin_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sin_prec_assign`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::sin_round_assign`] instead. If both of these things are true, consider using
+    /// [`Float::sin_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if the exponent is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact sine.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_prec_round_assign(5, Floor), Less);
+    /// assert_eq!(x.to_string(), "1.18e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_prec_round_assign(5, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.25e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_prec_round_assign(5, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "1.25e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "1.224647e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.224649e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "1.224647e-16");
+    /// ```
+    pub fn sin_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let o;
+        (*self, o) = self.sin_prec_round_ref(prec, rm);
+        o
+    }
+
+    /// Computes the sine of a [`Float`] in place, rounding the result to the nearest value of the
+    /// specified precision. An [`Ordering`] is returned, indicating whether the rounded sine is
+    /// less than, equal to, or greater than the exact sine. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the sine is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\sin
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::sin_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::sin_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if the exponent is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_prec_assign(5), Greater);
+    /// assert_eq!(x.to_string(), "1.25e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "1.224647e-16");
+    /// ```
+    #[inline]
+    pub fn sin_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.sin_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes the sine of a [`Float`] in place, rounding the result with the specified rounding
+    /// mode. An [`Ordering`] is returned, indicating whether the rounded sine is less than, equal
+    /// to, or greater than the exact sine. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\sin x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::sin_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sin_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::sin_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if the exponent is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "1.224646799147351e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.224646799147353e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_round_assign(Nearest), Greater);
+    /// assert_eq!(x.to_string(), "1.224646799147353e-16");
+    /// ```
+    #[inline]
+    pub fn sin_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.sin_prec_round_assign(prec, rm)
+    }
+}
+
+impl Sin for Float {
+    type Output = Float;
+
+    /// Computes the sine of a [`Float`], taking it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the sine is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x) = \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\sin
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=\text{NaN}$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::sin_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::sin_round`].
+    /// If you want both of these things, consider using [`Float::sin_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if the exponent is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Sin;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.sin().is_nan());
+    /// assert!(Float::INFINITY.sin().is_nan());
+    /// assert!(Float::NEGATIVE_INFINITY.sin().is_nan());
+    /// assert_eq!(Float::from(1.5).sin().to_string(), "1.0");
+    /// assert_eq!(Float::from(-1.5).sin().to_string(), "-1.0");
+    /// ```
+    #[inline]
+    fn sin(self) -> Float {
+        let prec = self.significant_bits();
+        self.sin_prec_round(prec, Nearest).0
+    }
+}
+
+impl Sin for &Float {
+    type Output = Float;
+
+    /// Computes the sine of a [`Float`], taking it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the sine is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x) = \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\sin
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=\text{NaN}$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_prec_ref`] instead. If you want to specify the output precision, consider using
+    /// [`Float::sin_round_ref`]. If you want both of these things, consider using
+    /// [`Float::sin_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if the exponent is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Sin;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).sin().is_nan());
+    /// assert!((&Float::INFINITY).sin().is_nan());
+    /// assert!((&Float::NEGATIVE_INFINITY).sin().is_nan());
+    /// assert_eq!((&Float::from(1.5)).sin().to_string(), "1.0");
+    /// assert_eq!((&Float::from(-1.5)).sin().to_string(), "-1.0");
+    /// ```
+    #[inline]
+    fn sin(self) -> Float {
+        let prec = self.significant_bits();
+        self.sin_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl SinAssign for Float {
+    /// Computes the sine of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the sine is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x\gets \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\sin
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::sin`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::sin_round_assign`]. If you want both of these things, consider using
+    /// [`Float::sin_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if the exponent is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::SinAssign;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::NAN;
+    /// x.sin_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x.sin_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x.sin_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.sin_assign();
+    /// assert_eq!(x.to_string(), "1.0");
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x.sin_assign();
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// ```
+    #[inline]
+    fn sin_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.sin_prec_round_assign(prec, Nearest);
+    }
+}
@@ -0,0 +1,685 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Infinity, NaN, Zero};
+use crate::arithmetic::exp::{round_fixed_point, round_near_float};
+use crate::arithmetic::sin::sin_cos_reduced_fixed_point;
+use crate::{Float, float_either_zero, float_nan};
+use core::cmp::Ordering::{self, *};
+use core::cmp::max;
+use malachite_base::num::arithmetic::traits::SinCos;
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::platform::Limb;
+
+impl Float {
+    /// Computes the sine and cosine of a [`Float`] simultaneously, rounding the results to the
+    /// specified precision and with the specified rounding mode. The [`Float`] is taken by value.
+    /// Two [`Ordering`]s are also returned, indicating whether the rounded sine and cosine are less
+    /// than, equal to, or greater than the exact sine and cosine, respectively. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = (\sin x+\varepsilon_1, \cos x+\varepsilon_2).
+    /// $$
+    /// - If $\sin x$ is zero or `NaN`, $\varepsilon_1$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon_1| < 2^{\lfloor\log_2
+    ///   |\sin x|\rfloor-p+1}$.
+    /// - If $\sin x$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon_1| < 2^{\lfloor\log_2
+    ///   |\sin x|\rfloor-p}$.
+    /// - If $\cos x$ is zero or `NaN`, $\varepsilon_2$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon_2| < 2^{\lfloor\log_2
+    ///   |\cos x|\rfloor-p+1}$.
+    /// - If $\cos x$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon_2| < 2^{\lfloor\log_2
+    ///   |\cos x|\rfloor-p}$.
+    ///
+    /// If the outputs have a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=f(\pm\infty,p,m)=(\text{NaN},\text{NaN})$
+    /// - $f(0.0,p,m)=(0.0,1.0)$
+    /// - $f(-0.0,p,m)=(-0.0,1.0)$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sin_cos_prec`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::sin_cos_round`] instead. If both of these things are true, consider using
+    /// [`SinCos::sin_cos`] instead.
+    ///
+    /// Computing both values at once is faster than calling [`Float::sin_prec_round`] and
+    /// [`Float::cos_prec_round`] separately, since the argument reduction is shared.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`, plus the exponent of `self`, if the exponent is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact sine and cosine.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_prec_round(5, Floor);
+    /// assert_eq!(sin.to_string(), "1.18e-16");
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_prec_round(5, Ceiling);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(cos.to_string(), "-0.97");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(o_cos, Greater);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_prec_round(5, Nearest);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_prec_round(20, Floor);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_prec_round(20, Ceiling);
+    /// assert_eq!(sin.to_string(), "1.224649e-16");
+    /// assert_eq!(cos.to_string(), "-0.999999");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(o_cos, Greater);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_prec_round(20, Nearest);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(o_cos, Less);
+    /// ```
+    #[inline]
+    pub fn sin_cos_prec_round(
+        self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Float, Ordering, Ordering) {
+        self.sin_cos_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the sine and cosine of a [`Float`] simultaneously, rounding the results to the
+    /// specified precision and with the specified rounding mode. The [`Float`] is taken by
+    /// reference. Two [`Ordering`]s are also returned, indicating whether the rounded sine and
+    /// cosine are less than, equal to, or greater than the exact sine and cosine, respectively.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = (\sin x+\varepsilon_1, \cos x+\varepsilon_2).
+    /// $$
+    /// - If $\sin x$ is zero or `NaN`, $\varepsilon_1$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon_1| < 2^{\lfloor\log_2
+    ///   |\sin x|\rfloor-p+1}$.
+    /// - If $\sin x$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon_1| < 2^{\lfloor\log_2
+    ///   |\sin x|\rfloor-p}$.
+    /// - If $\cos x$ is zero or `NaN`, $\varepsilon_2$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon_2| < 2^{\lfloor\log_2
+    ///   |\cos x|\rfloor-p+1}$.
+    /// - If $\cos x$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon_2| < 2^{\lfloor\log_2
+    ///   |\cos x|\rfloor-p}$.
+    ///
+    /// If the outputs have a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=f(\pm\infty,p,m)=(\text{NaN},\text{NaN})$
+    /// - $f(0.0,p,m)=(0.0,1.0)$
+    /// - $f(-0.0,p,m)=(-0.0,1.0)$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sin_cos_prec_ref`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::sin_cos_round_ref`] instead. If both of these things are true, consider using
+    /// [`SinCos::sin_cos`] instead.
+    ///
+    /// Computing both values at once is faster than calling [`Float::sin_prec_round`] and
+    /// [`Float::cos_prec_round`] separately, since the argument reduction is shared.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`, plus the exponent of `self`, if the exponent is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact sine and cosine.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sin, cos, o_sin, o_cos) = (&Float::from(PI)).sin_cos_prec_round_ref(5, Floor);
+    /// assert_eq!(sin.to_string(), "1.18e-16");
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = (&Float::from(PI)).sin_cos_prec_round_ref(5, Ceiling);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(cos.to_string(), "-0.97");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(o_cos, Greater);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = (&Float::from(PI)).sin_cos_prec_round_ref(5, Nearest);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = (&Float::from(PI)).sin_cos_prec_round_ref(20, Floor);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = (&Float::from(PI)).sin_cos_prec_round_ref(20, Ceiling);
+    /// assert_eq!(sin.to_string(), "1.224649e-16");
+    /// assert_eq!(cos.to_string(), "-0.999999");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(o_cos, Greater);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = (&Float::from(PI)).sin_cos_prec_round_ref(20, Nearest);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(o_cos, Less);
+    /// ```
+    pub fn sin_cos_prec_round_ref(
+        &self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Float, Ordering, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(NaN | Infinity { .. }) => (float_nan!(), float_nan!(), Equal, Equal),
+            float_either_zero!() => (self.clone(), Float::one_prec(prec), Equal, Equal),
+            _ => {
+                assert!(rm != Exact, "Inexact float sine and cosine");
+                let exponent = self.get_exponent().unwrap();
+                let cancelled = u64::from(exponent.min(0).unsigned_abs()) << 1;
+                let mut sin = if cancelled >= max(prec, self.get_prec().unwrap()) + 2 {
+                    // |sin x - x| < |x|^3 / 6
+                    Some(round_near_float(
+                        self,
+                        if *self > 0u32 { Less } else { Greater },
+                        prec,
+                        rm,
+                    ))
+                } else {
+                    None
+                };
+                let mut cos = if cancelled >= prec + 2 {
+                    // |cos x - 1| < x^2 / 2
+                    Some(round_near_float(&Float::ONE, Less, prec, rm))
+                } else {
+                    None
+                };
+                // The sine and cosine share a single argument reduction at each scale
+                let mut scale = prec + Limb::WIDTH + (cancelled >> 1);
+                let mut increment = Limb::WIDTH;
+                while sin.is_none() || cos.is_none() {
+                    let (s, c) = sin_cos_reduced_fixed_point(self, scale);
+                    if sin.is_none() {
+                        sin = round_fixed_point(s, scale, 2, prec, rm);
+                    }
+                    if cos.is_none() {
+                        cos = round_fixed_point(c, scale, 2, prec, rm);
+                    }
+                    scale += increment;
+                    increment = scale >> 1;
+                }
+                let (sin, o_sin) = sin.unwrap();
+                let (cos, o_cos) = cos.unwrap();
+                (sin, cos, o_sin, o_cos)
+            }
+        }
+    }
+
+    /// Computes the sine and cosine of a [`Float`] simultaneously, rounding the results to the
+    /// nearest value of the specified precision. The [`Float`] is taken by value. Two [`Ordering`]s
+    /// are also returned, indicating whether the rounded sine and cosine are less than, equal to,
+    /// or greater than the exact sine and cosine, respectively. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the sine or cosine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = (\sin x+\varepsilon_1, \cos x+\varepsilon_2).
+    /// $$
+    /// - If $\sin x$ is zero or `NaN`, $\varepsilon_1$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is nonzero, then $|\varepsilon_1| < 2^{\lfloor\log_2 |\sin x|\rfloor-p}$.
+    /// - If $\cos x$ is zero or `NaN`, $\varepsilon_2$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is nonzero, then $|\varepsilon_2| < 2^{\lfloor\log_2 |\cos x|\rfloor-p}$.
+    ///
+    /// If the outputs have a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=f(\pm\infty,p)=(\text{NaN},\text{NaN})$
+    /// - $f(0.0,p)=(0.0,1.0)$
+    /// - $f(-0.0,p)=(-0.0,1.0)$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_cos_prec_round`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`SinCos::sin_cos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`, plus the exponent of `self`, if the exponent is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_prec(5);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_prec(20);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(o_cos, Less);
+    /// ```
+    #[inline]
+    pub fn sin_cos_prec(self, prec: u64) -> (Float, Float, Ordering, Ordering) {
+        self.sin_cos_prec_round(prec, Nearest)
+    }
+
+    /// Computes the sine and cosine of a [`Float`] simultaneously, rounding the results to the
+    /// nearest value of the specified precision. The [`Float`] is taken by reference. Two
+    /// [`Ordering`]s are also returned, indicating whether the rounded sine and cosine are less
+    /// than, equal to, or greater than the exact sine and cosine, respectively. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the sine or cosine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = (\sin x+\varepsilon_1, \cos x+\varepsilon_2).
+    /// $$
+    /// - If $\sin x$ is zero or `NaN`, $\varepsilon_1$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is nonzero, then $|\varepsilon_1| < 2^{\lfloor\log_2 |\sin x|\rfloor-p}$.
+    /// - If $\cos x$ is zero or `NaN`, $\varepsilon_2$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is nonzero, then $|\varepsilon_2| < 2^{\lfloor\log_2 |\cos x|\rfloor-p}$.
+    ///
+    /// If the outputs have a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=f(\pm\infty,p)=(\text{NaN},\text{NaN})$
+    /// - $f(0.0,p)=(0.0,1.0)$
+    /// - $f(-0.0,p)=(-0.0,1.0)$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_cos_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`SinCos::sin_cos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`, plus the exponent of `self`, if the exponent is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sin, cos, o_sin, o_cos) = (&Float::from(PI)).sin_cos_prec_ref(5);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = (&Float::from(PI)).sin_cos_prec_ref(20);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(o_cos, Less);
+    /// ```
+    #[inline]
+    pub fn sin_cos_prec_ref(&self, prec: u64) -> (Float, Float, Ordering, Ordering) {
+        self.sin_cos_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes the sine and cosine of a [`Float`] simultaneously, rounding the results with the
+    /// specified rounding mode. The [`Float`] is taken by value. Two [`Ordering`]s are also
+    /// returned, indicating whether the rounded sine and cosine are less than, equal to, or greater
+    /// than the exact sine and cosine, respectively. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the outputs is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = (\sin x+\varepsilon_1, \cos x+\varepsilon_2).
+    /// $$
+    /// - If $\sin x$ is zero or `NaN`, $\varepsilon_1$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon_1| < 2^{\lfloor\log_2
+    ///   |\sin x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\sin x$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon_1| < 2^{\lfloor\log_2
+    ///   |\sin x|\rfloor-p}$, where $p$ is the precision of the input.
+    /// - If $\cos x$ is zero or `NaN`, $\varepsilon_2$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon_2| < 2^{\lfloor\log_2
+    ///   |\cos x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\cos x$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon_2| < 2^{\lfloor\log_2
+    ///   |\cos x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the outputs have a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=f(\pm\infty,m)=(\text{NaN},\text{NaN})$
+    /// - $f(0.0,m)=(0.0,1.0)$
+    /// - $f(-0.0,m)=(-0.0,1.0)$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sin_cos_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`SinCos::sin_cos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`, plus the
+    /// exponent of `self`, if the exponent is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// outputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_round(Floor);
+    /// assert_eq!(sin.to_string(), "1.224646799147351e-16");
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_round(Ceiling);
+    /// assert_eq!(sin.to_string(), "1.224646799147353e-16");
+    /// assert_eq!(cos.to_string(), "-0.999999999999999");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(o_cos, Greater);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_round(Nearest);
+    /// assert_eq!(sin.to_string(), "1.224646799147353e-16");
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(o_cos, Less);
+    /// ```
+    #[inline]
+    pub fn sin_cos_round(self, rm: RoundingMode) -> (Float, Float, Ordering, Ordering) {
+        let prec = self.significant_bits();
+        self.sin_cos_prec_round(prec, rm)
+    }
+
+    /// Computes the sine and cosine of a [`Float`] simultaneously, rounding the results with the
+    /// specified rounding mode. The [`Float`] is taken by reference. Two [`Ordering`]s are also
+    /// returned, indicating whether the rounded sine and cosine are less than, equal to, or greater
+    /// than the exact sine and cosine, respectively. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the outputs is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = (\sin x+\varepsilon_1, \cos x+\varepsilon_2).
+    /// $$
+    /// - If $\sin x$ is zero or `NaN`, $\varepsilon_1$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon_1| < 2^{\lfloor\log_2
+    ///   |\sin x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\sin x$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon_1| < 2^{\lfloor\log_2
+    ///   |\sin x|\rfloor-p}$, where $p$ is the precision of the input.
+    /// - If $\cos x$ is zero or `NaN`, $\varepsilon_2$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon_2| < 2^{\lfloor\log_2
+    ///   |\cos x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\cos x$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon_2| < 2^{\lfloor\log_2
+    ///   |\cos x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the outputs have a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=f(\pm\infty,m)=(\text{NaN},\text{NaN})$
+    /// - $f(0.0,m)=(0.0,1.0)$
+    /// - $f(-0.0,m)=(-0.0,1.0)$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sin_cos_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`SinCos::sin_cos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`, plus the
+    /// exponent of `self`, if the exponent is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// outputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sin, cos, o_sin, o_cos) = (&Float::from(PI)).sin_cos_round_ref(Floor);
+    /// assert_eq!(sin.to_string(), "1.224646799147351e-16");
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = (&Float::from(PI)).sin_cos_round_ref(Ceiling);
+    /// assert_eq!(sin.to_string(), "1.224646799147353e-16");
+    /// assert_eq!(cos.to_string(), "-0.999999999999999");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(o_cos, Greater);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = (&Float::from(PI)).sin_cos_round_ref(Nearest);
+    /// assert_eq!(sin.to_string(), "1.224646799147353e-16");
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(o_cos, Less);
+    /// ```
+    #[inline]
+    pub fn sin_cos_round_ref(&self, rm: RoundingMode) -> (Float, Float, Ordering, Ordering) {
+        let prec = self.significant_bits();
+        self.sin_cos_prec_round_ref(prec, rm)
+    }
+}
+
+impl SinCos for Float {
+    type Output = Float;
+
+    /// Computes the sine and cosine of a [`Float`] simultaneously, taking it by value.
+    ///
+    /// If the outputs have a precision, it is the precision of the input. If the sine or cosine is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = (\sin x+\varepsilon_1, \cos x+\varepsilon_2).
+    /// $$
+    /// - If $\sin x$ is zero or `NaN`, $\varepsilon_1$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is nonzero, then $|\varepsilon_1| < 2^{\lfloor\log_2 |\sin x|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    /// - If $\cos x$ is zero or `NaN`, $\varepsilon_2$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is nonzero, then $|\varepsilon_2| < 2^{\lfloor\log_2 |\cos x|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(\pm\infty)=(\text{NaN},\text{NaN})$
+    /// - $f(0.0)=(0.0,1.0)$
+    /// - $f(-0.0)=(-0.0,1.0)$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_cos_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::sin_cos_round`]. If you want both of these things, consider using
+    /// [`Float::sin_cos_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`, plus the
+    /// exponent of `self`, if the exponent is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::SinCos;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// let (sin, cos) = Float::NAN.sin_cos();
+    /// assert!(sin.is_nan());
+    /// assert!(cos.is_nan());
+    ///
+    /// let (sin, cos) = Float::INFINITY.sin_cos();
+    /// assert!(sin.is_nan());
+    /// assert!(cos.is_nan());
+    ///
+    /// let (sin, cos) = Float::ZERO.sin_cos();
+    /// assert_eq!(sin.to_string(), "0.0");
+    /// assert_eq!(cos.to_string(), "1.0");
+    ///
+    /// let (sin, cos) = Float::from(1.5).sin_cos();
+    /// assert_eq!(sin.to_string(), "1.0");
+    /// assert_eq!(cos.to_string(), "0.06");
+    ///
+    /// let (sin, cos) = Float::from(-1.5).sin_cos();
+    /// assert_eq!(sin.to_string(), "-1.0");
+    /// assert_eq!(cos.to_string(), "0.06");
+    /// ```
+    #[inline]
+    fn sin_cos(self) -> (Float, Float) {
+        let prec = self.significant_bits();
+        let (sin, cos, _, _) = self.sin_cos_prec_round(prec, Nearest);
+        (sin, cos)
+    }
+}
+
+impl SinCos for &Float {
+    type Output = Float;
+
+    /// Computes the sine and cosine of a [`Float`] simultaneously, taking it by reference.
+    ///
+    /// If the outputs have a precision, it is the precision of the input. If the sine or cosine is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = (\sin x+\varepsilon_1, \cos x+\varepsilon_2).
+    /// $$
+    /// - If $\sin x$ is zero or `NaN`, $\varepsilon_1$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is nonzero, then $|\varepsilon_1| < 2^{\lfloor\log_2 |\sin x|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    /// - If $\cos x$ is zero or `NaN`, $\varepsilon_2$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is nonzero, then $|\varepsilon_2| < 2^{\lfloor\log_2 |\cos x|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(\pm\infty)=(\text{NaN},\text{NaN})$
+    /// - $f(0.0)=(0.0,1.0)$
+    /// - $f(-0.0)=(-0.0,1.0)$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_cos_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::sin_cos_round`]. If you want both of these things, consider using
+    /// [`Float::sin_cos_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`, plus the
+    /// exponent of `self`, if the exponent is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::SinCos;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// let (sin, cos) = (&Float::NAN).sin_cos();
+    /// assert!(sin.is_nan());
+    /// assert!(cos.is_nan());
+    ///
+    /// let (sin, cos) = (&Float::INFINITY).sin_cos();
+    /// assert!(sin.is_nan());
+    /// assert!(cos.is_nan());
+    ///
+    /// let (sin, cos) = (&Float::ZERO).sin_cos();
+    /// assert_eq!(sin.to_string(), "0.0");
+    /// assert_eq!(cos.to_string(), "1.0");
+    ///
+    /// let (sin, cos) = (&Float::from(1.5)).sin_cos();
+    /// assert_eq!(sin.to_string(), "1.0");
+    /// assert_eq!(cos.to_string(), "0.06");
+    ///
+    /// let (sin, cos) = (&Float::from(-1.5)).sin_cos();
+    /// assert_eq!(sin.to_string(), "-1.0");
+    /// assert_eq!(cos.to_string(), "0.06");
+    /// ```
+    #[inline]
+    fn sin_cos(self) -> (Float, Float) {
+        let prec = self.significant_bits();
+        let (sin, cos, _, _) = self.sin_cos_prec_round_ref(prec, Nearest);
+        (sin, cos)
+    }
+}
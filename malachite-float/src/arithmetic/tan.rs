@@ -0,0 +1,823 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Infinity, NaN, Zero};
+use crate::arithmetic::exp::{round_fixed_point_ziv, round_near_float};
+use crate::arithmetic::sin::sin_cos_reduced_fixed_point;
+use crate::{Float, float_either_zero, float_nan};
+use core::cmp::Ordering::{self, *};
+use core::cmp::max;
+use malachite_base::num::arithmetic::traits::{Tan, TanAssign};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::Zero as ZeroTrait;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+
+// Given a finite [`Float`] $x$, returns an approximation $a$ of $2^s \tan x$, where $s$ is
+// `scale`, together with the base-2 logarithm of an upper bound on $|a - 2^s \tan x|$.
+//
+// The sine and cosine each have an error less than 4, so if $|c| \geq 2^{-k}$, where $c$ is the
+// approximation of $\cos x$, the error of the quotient is less than $12 \cdot 2^{2k}$. If the
+// cosine is too close to zero for this bound to be useful, an error bound that forces a retry is
+// returned.
+fn tan_fixed_point(x: &Float, scale: u64) -> (Integer, u64) {
+    let (sin, cos) = sin_cos_reduced_fixed_point(x, scale);
+    let cos_abs = cos.unsigned_abs_ref();
+    if *cos_abs < 16u32 {
+        return (Integer::ZERO, scale);
+    }
+    let k = scale + 1 - (cos_abs - Natural::from(4u32)).significant_bits();
+    ((sin << scale) / cos, 4 + (k << 1))
+}
+
+impl Float {
+    /// Computes the tangent of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded tangent is less than, equal to, or greater than the
+    /// exact tangent. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \tan x+\varepsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p+1}$.
+    /// - If $\tan x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::tan_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::tan_round`] instead. If both of these things are true, consider using
+    /// [`Float::tan`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if the exponent is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact tangent.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec_round(5, Floor);
+    /// assert_eq!(tan.to_string(), "-1.25e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec_round(5, Ceiling);
+    /// assert_eq!(tan.to_string(), "-1.18e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec_round(5, Nearest);
+    /// assert_eq!(tan.to_string(), "-1.25e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec_round(20, Floor);
+    /// assert_eq!(tan.to_string(), "-1.224649e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec_round(20, Ceiling);
+    /// assert_eq!(tan.to_string(), "-1.224647e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec_round(20, Nearest);
+    /// assert_eq!(tan.to_string(), "-1.224647e-16");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn tan_prec_round(mut self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        let o = self.tan_prec_round_assign(prec, rm);
+        (self, o)
+    }
+
+    /// Computes the tangent of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded tangent is less than, equal to, or greater than the
+    /// exact tangent. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \tan x+\varepsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p+1}$.
+    /// - If $\tan x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::tan_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::tan_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float)::tan()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if the exponent is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact tangent.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec_round_ref(5, Floor);
+    /// assert_eq!(tan.to_string(), "-1.25e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec_round_ref(5, Ceiling);
+    /// assert_eq!(tan.to_string(), "-1.18e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec_round_ref(5, Nearest);
+    /// assert_eq!(tan.to_string(), "-1.25e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec_round_ref(20, Floor);
+    /// assert_eq!(tan.to_string(), "-1.224649e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec_round_ref(20, Ceiling);
+    /// assert_eq!(tan.to_string(), "-1.224647e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec_round_ref(20, Nearest);
+    /// assert_eq!(tan.to_string(), "-1.224647e-16");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn tan_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(NaN | Infinity { .. }) => (float_nan!(), Equal),
+            float_either_zero!() => (self.clone(), Equal),
+            _ => {
+                assert!(rm != Exact, "Inexact float tangent");
+                let exponent = self.get_exponent().unwrap();
+                if exponent < 0
+                    && u64::from(exponent.unsigned_abs()) << 1
+                        >= max(prec, self.get_prec().unwrap()) + 2
+                {
+                    // |tan x - x| < |x|^3 / 2
+                    return round_near_float(
+                        self,
+                        if *self > 0u32 { Greater } else { Less },
+                        prec,
+                        rm,
+                    );
+                }
+                let scale = prec + Limb::WIDTH + u64::from(exponent.min(0).unsigned_abs());
+                round_fixed_point_ziv(scale, prec, rm, |scale| tan_fixed_point(self, scale))
+            }
+        }
+    }
+
+    /// Computes the tangent of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded tangent is less than, equal to, or greater than the exact
+    /// tangent. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// If the tangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \tan x+\varepsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\tan
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::tan_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Float::tan`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if the exponent is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec(5);
+    /// assert_eq!(tan.to_string(), "-1.25e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec(20);
+    /// assert_eq!(tan.to_string(), "-1.224647e-16");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn tan_prec(self, prec: u64) -> (Float, Ordering) {
+        self.tan_prec_round(prec, Nearest)
+    }
+
+    /// Computes the tangent of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded tangent is less than, equal to, or greater than the exact
+    /// tangent. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// If the tangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \tan x+\varepsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\tan
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::tan_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float)::tan()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if the exponent is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec_ref(5);
+    /// assert_eq!(tan.to_string(), "-1.25e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec_ref(20);
+    /// assert_eq!(tan.to_string(), "-1.224647e-16");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn tan_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.tan_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes the tangent of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded tangent is less than, equal to, or greater than the exact tangent. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \tan x+\varepsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\tan x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::tan_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::tan`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if the exponent is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (tan, o) = Float::from(PI).tan_round(Floor);
+    /// assert_eq!(tan.to_string(), "-1.224646799147353e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_round(Ceiling);
+    /// assert_eq!(tan.to_string(), "-1.224646799147351e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_round(Nearest);
+    /// assert_eq!(tan.to_string(), "-1.224646799147353e-16");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn tan_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.tan_prec_round(prec, rm)
+    }
+
+    /// Computes the tangent of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded tangent is less than, equal to, or greater than the exact tangent. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \tan x+\varepsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\tan x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::tan_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float)::tan()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if the exponent is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (tan, o) = Float::from(PI).tan_round_ref(Floor);
+    /// assert_eq!(tan.to_string(), "-1.224646799147353e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_round_ref(Ceiling);
+    /// assert_eq!(tan.to_string(), "-1.224646799147351e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_round_ref(Nearest);
+    /// assert_eq!(tan.to_string(), "-1.224646799147353e-16");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn tan_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.tan_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the tangent of a [`Float`] in place, rounding the result to the specified precision
+    /// and with the specified rounding mode. An [`Ordering`] is returned, indicating whether the
+    /// rounded tangent is less than, equal to, or greater than the exact tangent. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it
+    /// also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \tan x+\varepsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p+1}$.
+    /// - If $\tan x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::tan_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::tan_prec_assign`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::tan_round_assign`] instead. If both of these things are true, consider using
+    /// [`Float::tan_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if the exponent is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact tangent.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_prec_round_assign(5, Floor), Less);
+    /// assert_eq!(x.to_string(), "-1.25e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_prec_round_assign(5, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "-1.18e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_prec_round_assign(5, Nearest), Less);
+    /// assert_eq!(x.to_string(), "-1.25e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "-1.224649e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "-1.224647e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_prec_round_assign(20, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "-1.224647e-16");
+    /// ```
+    pub fn tan_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let o;
+        (*self, o) = self.tan_prec_round_ref(prec, rm);
+        o
+    }
+
+    /// Computes the tangent of a [`Float`] in place, rounding the result to the nearest value of
+    /// the specified precision. An [`Ordering`] is returned, indicating whether the rounded tangent
+    /// is less than, equal to, or greater than the exact tangent. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// If the tangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets \tan x+\varepsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\tan
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::tan_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::tan_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::tan_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if the exponent is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_prec_assign(5), Less);
+    /// assert_eq!(x.to_string(), "-1.25e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_prec_assign(20), Greater);
+    /// assert_eq!(x.to_string(), "-1.224647e-16");
+    /// ```
+    #[inline]
+    pub fn tan_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.tan_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes the tangent of a [`Float`] in place, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded tangent is less
+    /// than, equal to, or greater than the exact tangent. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \tan x+\varepsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\tan x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::tan_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::tan_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::tan_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if the exponent is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "-1.224646799147353e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "-1.224646799147351e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_round_assign(Nearest), Less);
+    /// assert_eq!(x.to_string(), "-1.224646799147353e-16");
+    /// ```
+    #[inline]
+    pub fn tan_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.tan_prec_round_assign(prec, rm)
+    }
+}
+
+impl Tan for Float {
+    type Output = Float;
+
+    /// Computes the tangent of a [`Float`], taking it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the tangent is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \tan x+\varepsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\tan
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=\text{NaN}$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::tan_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::tan_round`].
+    /// If you want both of these things, consider using [`Float::tan_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if the exponent is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Tan;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.tan().is_nan());
+    /// assert!(Float::INFINITY.tan().is_nan());
+    /// assert!(Float::NEGATIVE_INFINITY.tan().is_nan());
+    /// assert_eq!(Float::from(1.5).tan().to_string(), "16.0");
+    /// assert_eq!(Float::from(-1.5).tan().to_string(), "-16.0");
+    /// ```
+    #[inline]
+    fn tan(self) -> Float {
+        let prec = self.significant_bits();
+        self.tan_prec_round(prec, Nearest).0
+    }
+}
+
+impl Tan for &Float {
+    type Output = Float;
+
+    /// Computes the tangent of a [`Float`], taking it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the tangent is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \tan x+\varepsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\tan
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=\text{NaN}$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::tan_prec_ref`] instead. If you want to specify the output precision, consider using
+    /// [`Float::tan_round_ref`]. If you want both of these things, consider using
+    /// [`Float::tan_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if the exponent is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Tan;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).tan().is_nan());
+    /// assert!((&Float::INFINITY).tan().is_nan());
+    /// assert!((&Float::NEGATIVE_INFINITY).tan().is_nan());
+    /// assert_eq!((&Float::from(1.5)).tan().to_string(), "16.0");
+    /// assert_eq!((&Float::from(-1.5)).tan().to_string(), "-16.0");
+    /// ```
+    #[inline]
+    fn tan(self) -> Float {
+        let prec = self.significant_bits();
+        self.tan_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl TanAssign for Float {
+    /// Computes the tangent of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the tangent is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets \tan x+\varepsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\tan
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::tan`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::tan_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::tan_round_assign`]. If you want both of these things, consider using
+    /// [`Float::tan_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if the exponent is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::TanAssign;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::NAN;
+    /// x.tan_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x.tan_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x.tan_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.tan_assign();
+    /// assert_eq!(x.to_string(), "16.0");
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x.tan_assign();
+    /// assert_eq!(x.to_string(), "-16.0");
+    /// ```
+    #[inline]
+    fn tan_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.tan_prec_round_assign(prec, Nearest);
+    }
+}
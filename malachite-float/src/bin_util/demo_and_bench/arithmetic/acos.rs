@@ -0,0 +1,640 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Acos, AcosAssign};
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_float::test_util::arithmetic::acos::{
+    rug_acos, rug_acos_prec, rug_acos_prec_round, rug_acos_round,
+};
+use malachite_float::test_util::bench::bucketers::{
+    float_complexity_bucketer, pair_1_float_complexity_bucketer, pair_2_float_complexity_bucketer,
+    pair_2_pair_1_float_complexity_bucketer,
+    pair_2_pair_float_primitive_int_max_complexity_bucketer,
+    pair_2_triple_1_2_float_primitive_int_max_complexity_bucketer,
+    pair_float_primitive_int_max_complexity_bucketer,
+    triple_1_2_float_primitive_int_max_complexity_bucketer,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_gen_rm, float_rounding_mode_pair_gen_var_25,
+    float_rounding_mode_pair_gen_var_25_rm, float_unsigned_pair_gen_var_1,
+    float_unsigned_pair_gen_var_1_rm, float_unsigned_rounding_mode_triple_gen_var_14,
+    float_unsigned_rounding_mode_triple_gen_var_14_rm,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef};
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_float_acos);
+    register_demo!(runner, demo_float_acos_debug);
+    register_demo!(runner, demo_float_acos_ref);
+    register_demo!(runner, demo_float_acos_ref_debug);
+    register_demo!(runner, demo_float_acos_assign);
+    register_demo!(runner, demo_float_acos_assign_debug);
+    register_demo!(runner, demo_float_acos_prec);
+    register_demo!(runner, demo_float_acos_prec_debug);
+    register_demo!(runner, demo_float_acos_prec_ref);
+    register_demo!(runner, demo_float_acos_prec_ref_debug);
+    register_demo!(runner, demo_float_acos_prec_assign);
+    register_demo!(runner, demo_float_acos_prec_assign_debug);
+    register_demo!(runner, demo_float_acos_round);
+    register_demo!(runner, demo_float_acos_round_debug);
+    register_demo!(runner, demo_float_acos_round_ref);
+    register_demo!(runner, demo_float_acos_round_ref_debug);
+    register_demo!(runner, demo_float_acos_round_assign);
+    register_demo!(runner, demo_float_acos_round_assign_debug);
+    register_demo!(runner, demo_float_acos_prec_round);
+    register_demo!(runner, demo_float_acos_prec_round_debug);
+    register_demo!(runner, demo_float_acos_prec_round_ref);
+    register_demo!(runner, demo_float_acos_prec_round_ref_debug);
+    register_demo!(runner, demo_float_acos_prec_round_assign);
+    register_demo!(runner, demo_float_acos_prec_round_assign_debug);
+
+    register_bench!(runner, benchmark_float_acos_evaluation_strategy);
+    register_bench!(runner, benchmark_float_acos_library_comparison);
+    register_bench!(runner, benchmark_float_acos_assign);
+    register_bench!(runner, benchmark_float_acos_prec_evaluation_strategy);
+    register_bench!(runner, benchmark_float_acos_prec_library_comparison);
+    register_bench!(runner, benchmark_float_acos_prec_assign);
+    register_bench!(runner, benchmark_float_acos_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_acos_round_library_comparison);
+    register_bench!(runner, benchmark_float_acos_round_assign);
+    register_bench!(runner, benchmark_float_acos_prec_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_acos_prec_round_library_comparison);
+    register_bench!(runner, benchmark_float_acos_prec_round_assign);
+}
+
+fn demo_float_acos(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        println!("({}).acos() = {}", x_old, x.acos());
+    }
+}
+
+fn demo_float_acos_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        println!(
+            "({:#x}).acos() = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(x.acos())
+        );
+    }
+}
+
+fn demo_float_acos_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen().get(gm, config).take(limit) {
+        println!("(&{}).acos() = {}", x, (&x).acos());
+    }
+}
+
+fn demo_float_acos_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen().get(gm, config).take(limit) {
+        println!(
+            "(&{:#x}).acos() = {:#x}",
+            ComparableFloatRef(&x),
+            ComparableFloat((&x).acos())
+        );
+    }
+}
+
+fn demo_float_acos_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for mut x in float_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.acos_assign();
+        println!("x := {x_old}; x.acos_assign(); x = {x}");
+    }
+}
+
+fn demo_float_acos_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for mut x in float_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.acos_assign();
+        println!(
+            "x := {:#x}; x.acos_assign(); x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_acos_prec(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        println!("({}).acos_prec({}) = {:?}", x_old, prec, x.acos_prec(prec));
+    }
+}
+
+fn demo_float_acos_prec_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        let (sum, o) = x.acos_prec(prec);
+        println!(
+            "({:#x}).acos_prec({}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            prec,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_acos_prec_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        println!(
+            "(&{}).acos_prec_ref({}) = {:?}",
+            x,
+            prec,
+            x.acos_prec_ref(prec)
+        );
+    }
+}
+
+fn demo_float_acos_prec_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        let (sum, o) = x.acos_prec_ref(prec);
+        println!(
+            "(&{:#x}).acos_prec_ref({}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            prec,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_acos_prec_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.acos_prec_assign(prec);
+        println!("x := {x_old}; x.acos_prec_assign({prec}); x = {x}");
+    }
+}
+
+fn demo_float_acos_prec_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        let o = x.acos_prec_assign(prec);
+        println!(
+            "x := {:#x}; x.acos_prec_assign({}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            prec,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_acos_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_25()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!("({}).acos_round({}) = {:?}", x_old, rm, x.acos_round(rm));
+    }
+}
+
+fn demo_float_acos_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_25()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let (sum, o) = x.acos_round(rm);
+        println!(
+            "({:#x}).acos_round({}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            rm,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_acos_round_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_25()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).acos_round_ref({}) = {:?}",
+            x,
+            rm,
+            x.acos_round_ref(rm)
+        );
+    }
+}
+
+fn demo_float_acos_round_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_25()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sum, o) = x.acos_round_ref(rm);
+        println!(
+            "(&{:#x}).acos_round_ref({}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            rm,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_acos_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, rm) in float_rounding_mode_pair_gen_var_25()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        x.acos_round_assign(rm);
+        println!("x := {x_old}; x.acos_round_assign({rm}); x = {x}");
+    }
+}
+
+fn demo_float_acos_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, rm) in float_rounding_mode_pair_gen_var_25()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.acos_round_assign(rm);
+        println!(
+            "x := {:#x}; x.acos_round_assign({}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_acos_prec_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_14()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!(
+            "({}).acos_prec_round({}, {}) = {:?}",
+            x_old,
+            prec,
+            rm,
+            x.acos_prec_round(prec, rm)
+        );
+    }
+}
+
+fn demo_float_acos_prec_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_14()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let (sum, o) = x.acos_prec_round(prec, rm);
+        println!(
+            "({:#x}).acos_prec_round({}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            prec,
+            rm,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_acos_prec_round_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_14()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).acos_prec_round_ref({}, {}) = {:?}",
+            x,
+            prec,
+            rm,
+            x.acos_prec_round_ref(prec, rm)
+        );
+    }
+}
+
+fn demo_float_acos_prec_round_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_14()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sum, o) = x.acos_prec_round_ref(prec, rm);
+        println!(
+            "({:#x}).acos_prec_round_ref({}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            prec,
+            rm,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_acos_prec_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_14()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.acos_prec_round_assign(prec, rm);
+        println!("x := {x_old}; x.acos_prec_round({prec}, {rm}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_acos_prec_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_14()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.acos_prec_round_assign(prec, rm);
+        println!(
+            "x := {:#x}; x.acos_prec_round({}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            prec,
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn benchmark_float_acos_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.acos()",
+        BenchmarkType::EvaluationStrategy,
+        float_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &float_complexity_bucketer("x"),
+        &mut [
+            ("Float.acos()", &mut |x| no_out!(x.acos())),
+            ("(&Float).acos()", &mut |x| no_out!((&x).acos())),
+        ],
+    );
+}
+
+fn benchmark_float_acos_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.acos()",
+        BenchmarkType::LibraryComparison,
+        float_gen_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_float_complexity_bucketer("x"),
+        &mut [
+            ("Malachite", &mut |(_, x)| no_out!((&x).acos())),
+            ("rug", &mut |(x, _)| no_out!(rug_acos(&x))),
+        ],
+    );
+}
+
+fn benchmark_float_acos_assign(gm: GenMode, config: &GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Float.acos_assign()",
+        BenchmarkType::Single,
+        float_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &float_complexity_bucketer("x"),
+        &mut [("Float.acos_assign()", &mut |mut x| x.acos_assign())],
+    );
+}
+
+fn benchmark_float_acos_prec_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.acos_prec(u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_pair_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [
+            ("Float.acos_prec(u64)", &mut |(x, prec)| {
+                no_out!(x.acos_prec(prec))
+            }),
+            ("(&Float).acos_prec_ref(u64)", &mut |(x, prec)| {
+                no_out!(x.acos_prec_ref(prec))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_acos_prec_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.acos_prec(u64)",
+        BenchmarkType::LibraryComparison,
+        float_unsigned_pair_gen_var_1_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_pair_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, prec))| {
+                no_out!(x.acos_prec_ref(prec))
+            }),
+            ("rug", &mut |((x, prec), _)| {
+                no_out!(rug_acos_prec(&x, prec))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_acos_prec_assign(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.acos_prec_assign(u64)",
+        BenchmarkType::Single,
+        float_unsigned_pair_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [("Float.acos_prec_assign(u64)", &mut |(mut x, prec)| {
+            no_out!(x.acos_prec_assign(prec))
+        })],
+    );
+}
+
+fn benchmark_float_acos_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.acos_round(RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_rounding_mode_pair_gen_var_25().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_float_complexity_bucketer("x"),
+        &mut [
+            ("Float.acos_round(RoundingMode)", &mut |(x, rm)| {
+                no_out!(x.acos_round(rm))
+            }),
+            ("(&Float).acos_round_ref(RoundingMode)", &mut |(x, rm)| {
+                no_out!(x.acos_round_ref(rm))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_acos_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.acos_round(u64, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_rounding_mode_pair_gen_var_25_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_pair_1_float_complexity_bucketer("x"),
+        &mut [
+            ("Malachite", &mut |(_, (x, rm))| {
+                no_out!(x.acos_round_ref(rm))
+            }),
+            ("rug", &mut |((x, rm), _)| no_out!(rug_acos_round(&x, rm))),
+        ],
+    );
+}
+
+fn benchmark_float_acos_round_assign(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.acos_round_assign(RoundingMode)",
+        BenchmarkType::Single,
+        float_rounding_mode_pair_gen_var_25().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_float_complexity_bucketer("x"),
+        &mut [("Float.acos_round_assign(RoundingMode)", &mut |(
+            mut x,
+            rm,
+        )| {
+            no_out!(x.acos_round_assign(rm))
+        })],
+    );
+}
+
+fn benchmark_float_acos_prec_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.acos_prec_round(u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_rounding_mode_triple_gen_var_14().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [
+            (
+                "Float.acos_prec_round(u64, RoundingMode)",
+                &mut |(x, prec, rm)| no_out!(x.acos_prec_round(prec, rm)),
+            ),
+            (
+                "(&Float).acos_prec_round_ref(u64, RoundingMode)",
+                &mut |(x, prec, rm)| no_out!(x.acos_prec_round_ref(prec, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_acos_prec_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.acos_prec_round(u64, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_unsigned_rounding_mode_triple_gen_var_14_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_triple_1_2_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, prec, rm))| {
+                no_out!(x.acos_prec_round_ref(prec, rm))
+            }),
+            ("rug", &mut |((x, prec, rm), _)| {
+                no_out!(rug_acos_prec_round(&x, prec, rm))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_acos_prec_round_assign(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.acos_prec_round_assign(u64, RoundingMode)",
+        BenchmarkType::Single,
+        float_unsigned_rounding_mode_triple_gen_var_14().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [(
+            "Float.acos_prec_round_assign(u64, RoundingMode)",
+            &mut |(mut x, prec, rm)| no_out!(x.acos_prec_round_assign(prec, rm)),
+        )],
+    );
+}
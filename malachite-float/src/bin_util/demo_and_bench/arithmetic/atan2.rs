@@ -0,0 +1,479 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Atan2, Atan2Assign};
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_float::test_util::arithmetic::atan2::{
+    rug_atan2, rug_atan2_prec, rug_atan2_prec_round, rug_atan2_round,
+};
+use malachite_float::test_util::bench::bucketers::{
+    pair_2_pair_float_max_complexity_bucketer,
+    pair_2_quadruple_1_2_3_float_float_primitive_int_max_complexity_bucketer,
+    pair_2_triple_1_2_float_max_complexity_bucketer,
+    pair_2_triple_float_float_primitive_int_max_complexity_bucketer,
+    pair_float_max_complexity_bucketer,
+    quadruple_1_2_3_float_float_primitive_int_max_complexity_bucketer,
+    triple_1_2_float_max_complexity_bucketer,
+    triple_float_float_primitive_int_max_complexity_bucketer,
+};
+use malachite_float::test_util::generators::{
+    float_float_rounding_mode_triple_gen_var_33, float_float_rounding_mode_triple_gen_var_33_rm,
+    float_float_unsigned_rounding_mode_quadruple_gen_var_9,
+    float_float_unsigned_rounding_mode_quadruple_gen_var_9_rm,
+    float_float_unsigned_triple_gen_var_1, float_float_unsigned_triple_gen_var_1_rm,
+    float_pair_gen, float_pair_gen_rm,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef};
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_float_atan2);
+    register_demo!(runner, demo_float_atan2_debug);
+    register_demo!(runner, demo_float_atan2_ref_ref);
+    register_demo!(runner, demo_float_atan2_ref_ref_debug);
+    register_demo!(runner, demo_float_atan2_assign);
+    register_demo!(runner, demo_float_atan2_assign_debug);
+    register_demo!(runner, demo_float_atan2_prec);
+    register_demo!(runner, demo_float_atan2_prec_debug);
+    register_demo!(runner, demo_float_atan2_round);
+    register_demo!(runner, demo_float_atan2_round_debug);
+    register_demo!(runner, demo_float_atan2_prec_round);
+    register_demo!(runner, demo_float_atan2_prec_round_debug);
+
+    register_bench!(runner, benchmark_float_atan2_evaluation_strategy);
+    register_bench!(runner, benchmark_float_atan2_library_comparison);
+    register_bench!(runner, benchmark_float_atan2_assign_evaluation_strategy);
+    register_bench!(runner, benchmark_float_atan2_prec_evaluation_strategy);
+    register_bench!(runner, benchmark_float_atan2_prec_library_comparison);
+    register_bench!(runner, benchmark_float_atan2_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_atan2_round_library_comparison);
+    register_bench!(runner, benchmark_float_atan2_prec_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_atan2_prec_round_library_comparison);
+}
+
+fn demo_float_atan2(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (y, x) in float_pair_gen().get(gm, config).take(limit) {
+        let y_old = y.clone();
+        let x_old = x.clone();
+        println!("({}).atan2({}) = {}", y_old, x_old, y.atan2(x));
+    }
+}
+
+fn demo_float_atan2_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (y, x) in float_pair_gen().get(gm, config).take(limit) {
+        let y_old = y.clone();
+        let x_old = x.clone();
+        println!(
+            "({:#x}).atan2({:#x}) = {:#x}",
+            ComparableFloat(y_old),
+            ComparableFloat(x_old),
+            ComparableFloat(y.atan2(x))
+        );
+    }
+}
+
+fn demo_float_atan2_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (y, x) in float_pair_gen().get(gm, config).take(limit) {
+        println!("(&{}).atan2(&{}) = {}", y, x, (&y).atan2(&x));
+    }
+}
+
+fn demo_float_atan2_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (y, x) in float_pair_gen().get(gm, config).take(limit) {
+        println!(
+            "(&{:#x}).atan2(&{:#x}) = {:#x}",
+            ComparableFloatRef(&y),
+            ComparableFloatRef(&x),
+            ComparableFloat((&y).atan2(&x))
+        );
+    }
+}
+
+fn demo_float_atan2_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut y, x) in float_pair_gen().get(gm, config).take(limit) {
+        let y_old = y.clone();
+        y.atan2_assign(&x);
+        println!("y := {y_old}; y.atan2_assign(&{x}); y = {y}");
+    }
+}
+
+fn demo_float_atan2_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut y, x) in float_pair_gen().get(gm, config).take(limit) {
+        let y_old = y.clone();
+        y.atan2_assign(&x);
+        println!(
+            "y := {:#x}; y.atan2_assign(&{:#x}); y = {:#x}",
+            ComparableFloat(y_old),
+            ComparableFloat(x),
+            ComparableFloat(y)
+        );
+    }
+}
+
+fn demo_float_atan2_prec(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (y, x, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        let x_old = x.clone();
+        println!(
+            "({}).atan2_prec({}, {}) = {:?}",
+            y_old,
+            x_old,
+            prec,
+            y.atan2_prec(x, prec)
+        );
+    }
+}
+
+fn demo_float_atan2_prec_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (y, x, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        let x_old = x.clone();
+        let (atan2, o) = y.atan2_prec(x, prec);
+        println!(
+            "({:#x}).atan2_prec({:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloat(y_old),
+            ComparableFloat(x_old),
+            prec,
+            ComparableFloat(atan2),
+            o
+        );
+    }
+}
+
+fn demo_float_atan2_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (y, x, rm) in float_float_rounding_mode_triple_gen_var_33()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        let x_old = x.clone();
+        println!(
+            "({}).atan2_round({}, {}) = {:?}",
+            y_old,
+            x_old,
+            rm,
+            y.atan2_round(x, rm)
+        );
+    }
+}
+
+fn demo_float_atan2_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (y, x, rm) in float_float_rounding_mode_triple_gen_var_33()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        let x_old = x.clone();
+        let (atan2, o) = y.atan2_round(x, rm);
+        println!(
+            "({:#x}).atan2_round({:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloat(y_old),
+            ComparableFloat(x_old),
+            rm,
+            ComparableFloat(atan2),
+            o
+        );
+    }
+}
+
+fn demo_float_atan2_prec_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (y, x, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_9()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        let x_old = x.clone();
+        println!(
+            "({}).atan2_prec_round({}, {}, {}) = {:?}",
+            y_old,
+            x_old,
+            prec,
+            rm,
+            y.atan2_prec_round(x, prec, rm)
+        );
+    }
+}
+
+fn demo_float_atan2_prec_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (y, x, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_9()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        let x_old = x.clone();
+        let (atan2, o) = y.atan2_prec_round(x, prec, rm);
+        println!(
+            "({:#x}).atan2_prec_round({:#x}, {}, {}) = ({:#x}, {:?})",
+            ComparableFloat(y_old),
+            ComparableFloat(x_old),
+            prec,
+            rm,
+            ComparableFloat(atan2),
+            o
+        );
+    }
+}
+
+fn benchmark_float_atan2_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2(Float)",
+        BenchmarkType::EvaluationStrategy,
+        float_pair_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_float_max_complexity_bucketer("y", "x"),
+        &mut [
+            ("Float.atan2(Float)", &mut |(y, x)| no_out!(y.atan2(x))),
+            ("Float.atan2(&Float)", &mut |(y, x)| no_out!(y.atan2(&x))),
+            ("(&Float).atan2(Float)", &mut |(y, x)| {
+                no_out!((&y).atan2(x))
+            }),
+            ("(&Float).atan2(&Float)", &mut |(y, x)| {
+                no_out!((&y).atan2(&x))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_atan2_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2(Float)",
+        BenchmarkType::LibraryComparison,
+        float_pair_gen_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_pair_float_max_complexity_bucketer("y", "x"),
+        &mut [
+            ("Malachite", &mut |(_, (y, x))| no_out!((&y).atan2(&x))),
+            ("rug", &mut |((y, x), _)| no_out!(rug_atan2(&y, &x))),
+        ],
+    );
+}
+
+fn benchmark_float_atan2_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2_assign(Float)",
+        BenchmarkType::EvaluationStrategy,
+        float_pair_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_float_max_complexity_bucketer("y", "x"),
+        &mut [
+            ("Float.atan2_assign(Float)", &mut |(mut y, x)| {
+                y.atan2_assign(x)
+            }),
+            ("Float.atan2_assign(&Float)", &mut |(mut y, x)| {
+                y.atan2_assign(&x)
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_atan2_prec_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2_prec(Float, u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_unsigned_triple_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_float_float_primitive_int_max_complexity_bucketer("y", "x", "prec"),
+        &mut [
+            ("Float.atan2_prec(Float, u64)", &mut |(y, x, prec)| {
+                no_out!(y.atan2_prec(x, prec))
+            }),
+            (
+                "Float.atan2_prec_val_ref(&Float, u64)",
+                &mut |(y, x, prec)| no_out!(y.atan2_prec_val_ref(&x, prec)),
+            ),
+            (
+                "(&Float).atan2_prec_ref_val(Float, u64)",
+                &mut |(y, x, prec)| no_out!(y.atan2_prec_ref_val(x, prec)),
+            ),
+            (
+                "(&Float).atan2_prec_ref_ref(&Float, u64)",
+                &mut |(y, x, prec)| no_out!(y.atan2_prec_ref_ref(&x, prec)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_atan2_prec_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2_prec(Float, u64)",
+        BenchmarkType::LibraryComparison,
+        float_float_unsigned_triple_gen_var_1_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_triple_float_float_primitive_int_max_complexity_bucketer("y", "x", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (y, x, prec))| {
+                no_out!(y.atan2_prec_ref_ref(&x, prec))
+            }),
+            ("rug", &mut |((y, x, prec), _)| {
+                no_out!(rug_atan2_prec(&y, &x, prec))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_atan2_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2_round(Float, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_rounding_mode_triple_gen_var_33().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_float_max_complexity_bucketer("y", "x"),
+        &mut [
+            (
+                "Float.atan2_round(Float, RoundingMode)",
+                &mut |(y, x, rm)| no_out!(y.atan2_round(x, rm)),
+            ),
+            (
+                "Float.atan2_round_val_ref(&Float, RoundingMode)",
+                &mut |(y, x, rm)| no_out!(y.atan2_round_val_ref(&x, rm)),
+            ),
+            (
+                "(&Float).atan2_round_ref_val(Float, RoundingMode)",
+                &mut |(y, x, rm)| no_out!(y.atan2_round_ref_val(x, rm)),
+            ),
+            (
+                "(&Float).atan2_round_ref_ref(&Float, RoundingMode)",
+                &mut |(y, x, rm)| no_out!(y.atan2_round_ref_ref(&x, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_atan2_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2_round(Float, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_float_rounding_mode_triple_gen_var_33_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_triple_1_2_float_max_complexity_bucketer("y", "x"),
+        &mut [
+            ("Malachite", &mut |(_, (y, x, rm))| {
+                no_out!(y.atan2_round_ref_ref(&x, rm))
+            }),
+            ("rug", &mut |((y, x, rm), _)| {
+                no_out!(rug_atan2_round(&y, &x, rm))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_atan2_prec_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2_prec_round(Float, u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_unsigned_rounding_mode_quadruple_gen_var_9().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &quadruple_1_2_3_float_float_primitive_int_max_complexity_bucketer("y", "x", "prec"),
+        &mut [
+            (
+                "Float.atan2_prec_round(Float, u64, RoundingMode)",
+                &mut |(y, x, prec, rm)| no_out!(y.atan2_prec_round(x, prec, rm)),
+            ),
+            (
+                "Float.atan2_prec_round_val_ref(&Float, u64, RoundingMode)",
+                &mut |(y, x, prec, rm)| no_out!(y.atan2_prec_round_val_ref(&x, prec, rm)),
+            ),
+            (
+                "(&Float).atan2_prec_round_ref_val(Float, u64, RoundingMode)",
+                &mut |(y, x, prec, rm)| no_out!(y.atan2_prec_round_ref_val(x, prec, rm)),
+            ),
+            (
+                "(&Float).atan2_prec_round_ref_ref(&Float, u64, RoundingMode)",
+                &mut |(y, x, prec, rm)| no_out!(y.atan2_prec_round_ref_ref(&x, prec, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_atan2_prec_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2_prec_round(Float, u64, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_float_unsigned_rounding_mode_quadruple_gen_var_9_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_quadruple_1_2_3_float_float_primitive_int_max_complexity_bucketer("y", "x", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (y, x, prec, rm))| {
+                no_out!(y.atan2_prec_round_ref_ref(&x, prec, rm))
+            }),
+            ("rug", &mut |((y, x, prec, rm), _)| {
+                no_out!(rug_atan2_prec_round(&y, &x, prec, rm))
+            }),
+        ],
+    );
+}
@@ -0,0 +1,640 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Log2, Log2Assign};
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_float::test_util::arithmetic::log2::{
+    rug_log2, rug_log2_prec, rug_log2_prec_round, rug_log2_round,
+};
+use malachite_float::test_util::bench::bucketers::{
+    float_complexity_bucketer, pair_1_float_complexity_bucketer, pair_2_float_complexity_bucketer,
+    pair_2_pair_1_float_complexity_bucketer,
+    pair_2_pair_float_primitive_int_max_complexity_bucketer,
+    pair_2_triple_1_2_float_primitive_int_max_complexity_bucketer,
+    pair_float_primitive_int_max_complexity_bucketer,
+    triple_1_2_float_primitive_int_max_complexity_bucketer,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_gen_rm, float_rounding_mode_pair_gen_var_25,
+    float_rounding_mode_pair_gen_var_25_rm, float_unsigned_pair_gen_var_1,
+    float_unsigned_pair_gen_var_1_rm, float_unsigned_rounding_mode_triple_gen_var_14,
+    float_unsigned_rounding_mode_triple_gen_var_14_rm,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef};
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_float_log2);
+    register_demo!(runner, demo_float_log2_debug);
+    register_demo!(runner, demo_float_log2_ref);
+    register_demo!(runner, demo_float_log2_ref_debug);
+    register_demo!(runner, demo_float_log2_assign);
+    register_demo!(runner, demo_float_log2_assign_debug);
+    register_demo!(runner, demo_float_log2_prec);
+    register_demo!(runner, demo_float_log2_prec_debug);
+    register_demo!(runner, demo_float_log2_prec_ref);
+    register_demo!(runner, demo_float_log2_prec_ref_debug);
+    register_demo!(runner, demo_float_log2_prec_assign);
+    register_demo!(runner, demo_float_log2_prec_assign_debug);
+    register_demo!(runner, demo_float_log2_round);
+    register_demo!(runner, demo_float_log2_round_debug);
+    register_demo!(runner, demo_float_log2_round_ref);
+    register_demo!(runner, demo_float_log2_round_ref_debug);
+    register_demo!(runner, demo_float_log2_round_assign);
+    register_demo!(runner, demo_float_log2_round_assign_debug);
+    register_demo!(runner, demo_float_log2_prec_round);
+    register_demo!(runner, demo_float_log2_prec_round_debug);
+    register_demo!(runner, demo_float_log2_prec_round_ref);
+    register_demo!(runner, demo_float_log2_prec_round_ref_debug);
+    register_demo!(runner, demo_float_log2_prec_round_assign);
+    register_demo!(runner, demo_float_log2_prec_round_assign_debug);
+
+    register_bench!(runner, benchmark_float_log2_evaluation_strategy);
+    register_bench!(runner, benchmark_float_log2_library_comparison);
+    register_bench!(runner, benchmark_float_log2_assign);
+    register_bench!(runner, benchmark_float_log2_prec_evaluation_strategy);
+    register_bench!(runner, benchmark_float_log2_prec_library_comparison);
+    register_bench!(runner, benchmark_float_log2_prec_assign);
+    register_bench!(runner, benchmark_float_log2_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_log2_round_library_comparison);
+    register_bench!(runner, benchmark_float_log2_round_assign);
+    register_bench!(runner, benchmark_float_log2_prec_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_log2_prec_round_library_comparison);
+    register_bench!(runner, benchmark_float_log2_prec_round_assign);
+}
+
+fn demo_float_log2(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        println!("({}).log2() = {}", x_old, x.log2());
+    }
+}
+
+fn demo_float_log2_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        println!(
+            "({:#x}).log2() = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(x.log2())
+        );
+    }
+}
+
+fn demo_float_log2_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen().get(gm, config).take(limit) {
+        println!("(&{}).log2() = {}", x, (&x).log2());
+    }
+}
+
+fn demo_float_log2_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen().get(gm, config).take(limit) {
+        println!(
+            "(&{:#x}).log2() = {:#x}",
+            ComparableFloatRef(&x),
+            ComparableFloat((&x).log2())
+        );
+    }
+}
+
+fn demo_float_log2_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for mut x in float_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.log2_assign();
+        println!("x := {x_old}; x.log2_assign(); x = {x}");
+    }
+}
+
+fn demo_float_log2_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for mut x in float_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.log2_assign();
+        println!(
+            "x := {:#x}; x.log2_assign(); x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_log2_prec(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        println!("({}).log2_prec({}) = {:?}", x_old, prec, x.log2_prec(prec));
+    }
+}
+
+fn demo_float_log2_prec_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        let (sum, o) = x.log2_prec(prec);
+        println!(
+            "({:#x}).log2_prec({}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            prec,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_log2_prec_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        println!(
+            "(&{}).log2_prec_ref({}) = {:?}",
+            x,
+            prec,
+            x.log2_prec_ref(prec)
+        );
+    }
+}
+
+fn demo_float_log2_prec_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        let (sum, o) = x.log2_prec_ref(prec);
+        println!(
+            "(&{:#x}).log2_prec_ref({}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            prec,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_log2_prec_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.log2_prec_assign(prec);
+        println!("x := {x_old}; x.log2_prec_assign({prec}); x = {x}");
+    }
+}
+
+fn demo_float_log2_prec_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        let o = x.log2_prec_assign(prec);
+        println!(
+            "x := {:#x}; x.log2_prec_assign({}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            prec,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_log2_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_25()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!("({}).log2_round({}) = {:?}", x_old, rm, x.log2_round(rm));
+    }
+}
+
+fn demo_float_log2_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_25()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let (sum, o) = x.log2_round(rm);
+        println!(
+            "({:#x}).log2_round({}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            rm,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_log2_round_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_25()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).log2_round_ref({}) = {:?}",
+            x,
+            rm,
+            x.log2_round_ref(rm)
+        );
+    }
+}
+
+fn demo_float_log2_round_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_25()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sum, o) = x.log2_round_ref(rm);
+        println!(
+            "(&{:#x}).log2_round_ref({}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            rm,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_log2_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, rm) in float_rounding_mode_pair_gen_var_25()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        x.log2_round_assign(rm);
+        println!("x := {x_old}; x.log2_round_assign({rm}); x = {x}");
+    }
+}
+
+fn demo_float_log2_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, rm) in float_rounding_mode_pair_gen_var_25()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.log2_round_assign(rm);
+        println!(
+            "x := {:#x}; x.log2_round_assign({}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_log2_prec_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_14()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!(
+            "({}).log2_prec_round({}, {}) = {:?}",
+            x_old,
+            prec,
+            rm,
+            x.log2_prec_round(prec, rm)
+        );
+    }
+}
+
+fn demo_float_log2_prec_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_14()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let (sum, o) = x.log2_prec_round(prec, rm);
+        println!(
+            "({:#x}).log2_prec_round({}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            prec,
+            rm,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_log2_prec_round_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_14()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).log2_prec_round_ref({}, {}) = {:?}",
+            x,
+            prec,
+            rm,
+            x.log2_prec_round_ref(prec, rm)
+        );
+    }
+}
+
+fn demo_float_log2_prec_round_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_14()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sum, o) = x.log2_prec_round_ref(prec, rm);
+        println!(
+            "({:#x}).log2_prec_round_ref({}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            prec,
+            rm,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_log2_prec_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_14()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.log2_prec_round_assign(prec, rm);
+        println!("x := {x_old}; x.log2_prec_round({prec}, {rm}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_log2_prec_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_14()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.log2_prec_round_assign(prec, rm);
+        println!(
+            "x := {:#x}; x.log2_prec_round({}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            prec,
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn benchmark_float_log2_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.log2()",
+        BenchmarkType::EvaluationStrategy,
+        float_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &float_complexity_bucketer("x"),
+        &mut [
+            ("Float.log2()", &mut |x| no_out!(x.log2())),
+            ("(&Float).log2()", &mut |x| no_out!((&x).log2())),
+        ],
+    );
+}
+
+fn benchmark_float_log2_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.log2()",
+        BenchmarkType::LibraryComparison,
+        float_gen_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_float_complexity_bucketer("x"),
+        &mut [
+            ("Malachite", &mut |(_, x)| no_out!((&x).log2())),
+            ("rug", &mut |(x, _)| no_out!(rug_log2(&x))),
+        ],
+    );
+}
+
+fn benchmark_float_log2_assign(gm: GenMode, config: &GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Float.log2_assign()",
+        BenchmarkType::Single,
+        float_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &float_complexity_bucketer("x"),
+        &mut [("Float.log2_assign()", &mut |mut x| x.log2_assign())],
+    );
+}
+
+fn benchmark_float_log2_prec_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.log2_prec(u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_pair_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [
+            ("Float.log2_prec(u64)", &mut |(x, prec)| {
+                no_out!(x.log2_prec(prec))
+            }),
+            ("(&Float).log2_prec_ref(u64)", &mut |(x, prec)| {
+                no_out!(x.log2_prec_ref(prec))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_log2_prec_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.log2_prec(u64)",
+        BenchmarkType::LibraryComparison,
+        float_unsigned_pair_gen_var_1_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_pair_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, prec))| {
+                no_out!(x.log2_prec_ref(prec))
+            }),
+            ("rug", &mut |((x, prec), _)| {
+                no_out!(rug_log2_prec(&x, prec))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_log2_prec_assign(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.log2_prec_assign(u64)",
+        BenchmarkType::Single,
+        float_unsigned_pair_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [("Float.log2_prec_assign(u64)", &mut |(mut x, prec)| {
+            no_out!(x.log2_prec_assign(prec))
+        })],
+    );
+}
+
+fn benchmark_float_log2_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.log2_round(RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_rounding_mode_pair_gen_var_25().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_float_complexity_bucketer("x"),
+        &mut [
+            ("Float.log2_round(RoundingMode)", &mut |(x, rm)| {
+                no_out!(x.log2_round(rm))
+            }),
+            ("(&Float).log2_round_ref(RoundingMode)", &mut |(x, rm)| {
+                no_out!(x.log2_round_ref(rm))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_log2_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.log2_round(u64, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_rounding_mode_pair_gen_var_25_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_pair_1_float_complexity_bucketer("x"),
+        &mut [
+            ("Malachite", &mut |(_, (x, rm))| {
+                no_out!(x.log2_round_ref(rm))
+            }),
+            ("rug", &mut |((x, rm), _)| no_out!(rug_log2_round(&x, rm))),
+        ],
+    );
+}
+
+fn benchmark_float_log2_round_assign(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.log2_round_assign(RoundingMode)",
+        BenchmarkType::Single,
+        float_rounding_mode_pair_gen_var_25().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_float_complexity_bucketer("x"),
+        &mut [("Float.log2_round_assign(RoundingMode)", &mut |(
+            mut x,
+            rm,
+        )| {
+            no_out!(x.log2_round_assign(rm))
+        })],
+    );
+}
+
+fn benchmark_float_log2_prec_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.log2_prec_round(u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_rounding_mode_triple_gen_var_14().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [
+            (
+                "Float.log2_prec_round(u64, RoundingMode)",
+                &mut |(x, prec, rm)| no_out!(x.log2_prec_round(prec, rm)),
+            ),
+            (
+                "(&Float).log2_prec_round_ref(u64, RoundingMode)",
+                &mut |(x, prec, rm)| no_out!(x.log2_prec_round_ref(prec, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_log2_prec_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.log2_prec_round(u64, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_unsigned_rounding_mode_triple_gen_var_14_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_triple_1_2_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, prec, rm))| {
+                no_out!(x.log2_prec_round_ref(prec, rm))
+            }),
+            ("rug", &mut |((x, prec, rm), _)| {
+                no_out!(rug_log2_prec_round(&x, prec, rm))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_log2_prec_round_assign(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.log2_prec_round_assign(u64, RoundingMode)",
+        BenchmarkType::Single,
+        float_unsigned_rounding_mode_triple_gen_var_14().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [(
+            "Float.log2_prec_round_assign(u64, RoundingMode)",
+            &mut |(mut x, prec, rm)| no_out!(x.log2_prec_round_assign(prec, rm)),
+        )],
+    );
+}
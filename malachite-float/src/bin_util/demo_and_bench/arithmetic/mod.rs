@@ -10,34 +10,70 @@ use malachite_base::test_util::runner::Runner;
 
 pub(crate) fn register(runner: &mut Runner) {
     abs::register(runner);
+    acos::register(runner);
     add::register(runner);
+    asin::register(runner);
+    atan::register(runner);
+    atan2::register(runner);
+    cos::register(runner);
     div::register(runner);
+    exp::register(runner);
+    exp10::register(runner);
+    exp2::register(runner);
+    expm1::register(runner);
     is_power_of_2::register(runner);
+    ln::register(runner);
+    ln1p::register(runner);
+    log10::register(runner);
+    log2::register(runner);
     mul::register(runner);
     neg::register(runner);
     power_of_2::register(runner);
     reciprocal::register(runner);
+    reciprocal_sqrt::register(runner);
     shl::register(runner);
     shl_round::register(runner);
     shr::register(runner);
     shr_round::register(runner);
     sign::register(runner);
+    sin::register(runner);
+    sin_cos::register(runner);
+    sqrt::register(runner);
     square::register(runner);
     sub::register(runner);
+    tan::register(runner);
 }
 
 mod abs;
+mod acos;
 mod add;
+mod asin;
+mod atan;
+mod atan2;
+mod cos;
 mod div;
+mod exp;
+mod exp10;
+mod exp2;
+mod expm1;
 mod is_power_of_2;
+mod ln;
+mod ln1p;
+mod log10;
+mod log2;
 mod mul;
 mod neg;
 mod power_of_2;
 mod reciprocal;
+mod reciprocal_sqrt;
 mod shl;
 mod shl_round;
 mod shr;
 mod shr_round;
 mod sign;
+mod sin;
+mod sin_cos;
+mod sqrt;
 mod square;
 mod sub;
+mod tan;
@@ -0,0 +1,640 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Sqrt, SqrtAssign};
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_float::test_util::arithmetic::sqrt::{
+    rug_sqrt, rug_sqrt_prec, rug_sqrt_prec_round, rug_sqrt_round,
+};
+use malachite_float::test_util::bench::bucketers::{
+    float_complexity_bucketer, pair_1_float_complexity_bucketer, pair_2_float_complexity_bucketer,
+    pair_2_pair_1_float_complexity_bucketer,
+    pair_2_pair_float_primitive_int_max_complexity_bucketer,
+    pair_2_triple_1_2_float_primitive_int_max_complexity_bucketer,
+    pair_float_primitive_int_max_complexity_bucketer,
+    triple_1_2_float_primitive_int_max_complexity_bucketer,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_gen_rm, float_rounding_mode_pair_gen_var_23,
+    float_rounding_mode_pair_gen_var_23_rm, float_unsigned_pair_gen_var_1,
+    float_unsigned_pair_gen_var_1_rm, float_unsigned_rounding_mode_triple_gen_var_12,
+    float_unsigned_rounding_mode_triple_gen_var_12_rm,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef};
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_float_sqrt);
+    register_demo!(runner, demo_float_sqrt_debug);
+    register_demo!(runner, demo_float_sqrt_ref);
+    register_demo!(runner, demo_float_sqrt_ref_debug);
+    register_demo!(runner, demo_float_sqrt_assign);
+    register_demo!(runner, demo_float_sqrt_assign_debug);
+    register_demo!(runner, demo_float_sqrt_prec);
+    register_demo!(runner, demo_float_sqrt_prec_debug);
+    register_demo!(runner, demo_float_sqrt_prec_ref);
+    register_demo!(runner, demo_float_sqrt_prec_ref_debug);
+    register_demo!(runner, demo_float_sqrt_prec_assign);
+    register_demo!(runner, demo_float_sqrt_prec_assign_debug);
+    register_demo!(runner, demo_float_sqrt_round);
+    register_demo!(runner, demo_float_sqrt_round_debug);
+    register_demo!(runner, demo_float_sqrt_round_ref);
+    register_demo!(runner, demo_float_sqrt_round_ref_debug);
+    register_demo!(runner, demo_float_sqrt_round_assign);
+    register_demo!(runner, demo_float_sqrt_round_assign_debug);
+    register_demo!(runner, demo_float_sqrt_prec_round);
+    register_demo!(runner, demo_float_sqrt_prec_round_debug);
+    register_demo!(runner, demo_float_sqrt_prec_round_ref);
+    register_demo!(runner, demo_float_sqrt_prec_round_ref_debug);
+    register_demo!(runner, demo_float_sqrt_prec_round_assign);
+    register_demo!(runner, demo_float_sqrt_prec_round_assign_debug);
+
+    register_bench!(runner, benchmark_float_sqrt_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sqrt_library_comparison);
+    register_bench!(runner, benchmark_float_sqrt_assign);
+    register_bench!(runner, benchmark_float_sqrt_prec_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sqrt_prec_library_comparison);
+    register_bench!(runner, benchmark_float_sqrt_prec_assign);
+    register_bench!(runner, benchmark_float_sqrt_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sqrt_round_library_comparison);
+    register_bench!(runner, benchmark_float_sqrt_round_assign);
+    register_bench!(runner, benchmark_float_sqrt_prec_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sqrt_prec_round_library_comparison);
+    register_bench!(runner, benchmark_float_sqrt_prec_round_assign);
+}
+
+fn demo_float_sqrt(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        println!("({}).sqrt() = {}", x_old, x.sqrt());
+    }
+}
+
+fn demo_float_sqrt_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        println!(
+            "({:#x}).sqrt() = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(x.sqrt())
+        );
+    }
+}
+
+fn demo_float_sqrt_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen().get(gm, config).take(limit) {
+        println!("(&{}).sqrt() = {}", x, (&x).sqrt());
+    }
+}
+
+fn demo_float_sqrt_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen().get(gm, config).take(limit) {
+        println!(
+            "(&{:#x}).sqrt() = {:#x}",
+            ComparableFloatRef(&x),
+            ComparableFloat((&x).sqrt())
+        );
+    }
+}
+
+fn demo_float_sqrt_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for mut x in float_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.sqrt_assign();
+        println!("x := {x_old}; x.sqrt_assign(); x = {x}");
+    }
+}
+
+fn demo_float_sqrt_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for mut x in float_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.sqrt_assign();
+        println!(
+            "x := {:#x}; x.sqrt_assign(); x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_sqrt_prec(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        println!("({}).sqrt_prec({}) = {:?}", x_old, prec, x.sqrt_prec(prec));
+    }
+}
+
+fn demo_float_sqrt_prec_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        let (sum, o) = x.sqrt_prec(prec);
+        println!(
+            "({:#x}).sqrt_prec({}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            prec,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_sqrt_prec_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        println!(
+            "(&{}).sqrt_prec_ref({}) = {:?}",
+            x,
+            prec,
+            x.sqrt_prec_ref(prec)
+        );
+    }
+}
+
+fn demo_float_sqrt_prec_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        let (sum, o) = x.sqrt_prec_ref(prec);
+        println!(
+            "(&{:#x}).sqrt_prec_ref({}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            prec,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_sqrt_prec_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.sqrt_prec_assign(prec);
+        println!("x := {x_old}; x.sqrt_prec_assign({prec}); x = {x}");
+    }
+}
+
+fn demo_float_sqrt_prec_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        let o = x.sqrt_prec_assign(prec);
+        println!(
+            "x := {:#x}; x.sqrt_prec_assign({}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            prec,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_sqrt_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_23()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!("({}).sqrt_round({}) = {:?}", x_old, rm, x.sqrt_round(rm));
+    }
+}
+
+fn demo_float_sqrt_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_23()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let (sum, o) = x.sqrt_round(rm);
+        println!(
+            "({:#x}).sqrt_round({}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            rm,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_sqrt_round_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_23()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).sqrt_round_ref({}) = {:?}",
+            x,
+            rm,
+            x.sqrt_round_ref(rm)
+        );
+    }
+}
+
+fn demo_float_sqrt_round_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_23()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sum, o) = x.sqrt_round_ref(rm);
+        println!(
+            "(&{:#x}).sqrt_round_ref({}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            rm,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_sqrt_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, rm) in float_rounding_mode_pair_gen_var_23()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        x.sqrt_round_assign(rm);
+        println!("x := {x_old}; x.sqrt_round_assign({rm}); x = {x}");
+    }
+}
+
+fn demo_float_sqrt_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, rm) in float_rounding_mode_pair_gen_var_23()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sqrt_round_assign(rm);
+        println!(
+            "x := {:#x}; x.sqrt_round_assign({}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_sqrt_prec_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_12()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!(
+            "({}).sqrt_prec_round({}, {}) = {:?}",
+            x_old,
+            prec,
+            rm,
+            x.sqrt_prec_round(prec, rm)
+        );
+    }
+}
+
+fn demo_float_sqrt_prec_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_12()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let (sum, o) = x.sqrt_prec_round(prec, rm);
+        println!(
+            "({:#x}).sqrt_prec_round({}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            prec,
+            rm,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_sqrt_prec_round_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_12()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).sqrt_prec_round_ref({}, {}) = {:?}",
+            x,
+            prec,
+            rm,
+            x.sqrt_prec_round_ref(prec, rm)
+        );
+    }
+}
+
+fn demo_float_sqrt_prec_round_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_12()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sum, o) = x.sqrt_prec_round_ref(prec, rm);
+        println!(
+            "({:#x}).sqrt_prec_round_ref({}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            prec,
+            rm,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_sqrt_prec_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_12()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sqrt_prec_round_assign(prec, rm);
+        println!("x := {x_old}; x.sqrt_prec_round({prec}, {rm}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_sqrt_prec_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_12()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sqrt_prec_round_assign(prec, rm);
+        println!(
+            "x := {:#x}; x.sqrt_prec_round({}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            prec,
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn benchmark_float_sqrt_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sqrt()",
+        BenchmarkType::EvaluationStrategy,
+        float_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &float_complexity_bucketer("x"),
+        &mut [
+            ("Float.sqrt()", &mut |x| no_out!(x.sqrt())),
+            ("(&Float).sqrt()", &mut |x| no_out!((&x).sqrt())),
+        ],
+    );
+}
+
+fn benchmark_float_sqrt_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sqrt()",
+        BenchmarkType::LibraryComparison,
+        float_gen_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_float_complexity_bucketer("x"),
+        &mut [
+            ("Malachite", &mut |(_, x)| no_out!((&x).sqrt())),
+            ("rug", &mut |(x, _)| no_out!(rug_sqrt(&x))),
+        ],
+    );
+}
+
+fn benchmark_float_sqrt_assign(gm: GenMode, config: &GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Float.sqrt_assign()",
+        BenchmarkType::Single,
+        float_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &float_complexity_bucketer("x"),
+        &mut [("Float.sqrt_assign()", &mut |mut x| x.sqrt_assign())],
+    );
+}
+
+fn benchmark_float_sqrt_prec_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sqrt_prec(u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_pair_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [
+            ("Float.sqrt_prec(u64)", &mut |(x, prec)| {
+                no_out!(x.sqrt_prec(prec))
+            }),
+            ("(&Float).sqrt_prec_ref(u64)", &mut |(x, prec)| {
+                no_out!(x.sqrt_prec_ref(prec))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_sqrt_prec_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sqrt_prec(u64)",
+        BenchmarkType::LibraryComparison,
+        float_unsigned_pair_gen_var_1_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_pair_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, prec))| {
+                no_out!(x.sqrt_prec_ref(prec))
+            }),
+            ("rug", &mut |((x, prec), _)| {
+                no_out!(rug_sqrt_prec(&x, prec))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_sqrt_prec_assign(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sqrt_prec_assign(u64)",
+        BenchmarkType::Single,
+        float_unsigned_pair_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [("Float.sqrt_prec_assign(u64)", &mut |(mut x, prec)| {
+            no_out!(x.sqrt_prec_assign(prec))
+        })],
+    );
+}
+
+fn benchmark_float_sqrt_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sqrt_round(RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_rounding_mode_pair_gen_var_23().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_float_complexity_bucketer("x"),
+        &mut [
+            ("Float.sqrt_round(RoundingMode)", &mut |(x, rm)| {
+                no_out!(x.sqrt_round(rm))
+            }),
+            ("(&Float).sqrt_round_ref(RoundingMode)", &mut |(x, rm)| {
+                no_out!(x.sqrt_round_ref(rm))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_sqrt_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sqrt_round(u64, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_rounding_mode_pair_gen_var_23_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_pair_1_float_complexity_bucketer("x"),
+        &mut [
+            ("Malachite", &mut |(_, (x, rm))| {
+                no_out!(x.sqrt_round_ref(rm))
+            }),
+            ("rug", &mut |((x, rm), _)| no_out!(rug_sqrt_round(&x, rm))),
+        ],
+    );
+}
+
+fn benchmark_float_sqrt_round_assign(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sqrt_round_assign(RoundingMode)",
+        BenchmarkType::Single,
+        float_rounding_mode_pair_gen_var_23().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_float_complexity_bucketer("x"),
+        &mut [("Float.sqrt_round_assign(RoundingMode)", &mut |(
+            mut x,
+            rm,
+        )| {
+            no_out!(x.sqrt_round_assign(rm))
+        })],
+    );
+}
+
+fn benchmark_float_sqrt_prec_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sqrt_prec_round(u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_rounding_mode_triple_gen_var_12().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [
+            (
+                "Float.sqrt_prec_round(u64, RoundingMode)",
+                &mut |(x, prec, rm)| no_out!(x.sqrt_prec_round(prec, rm)),
+            ),
+            (
+                "(&Float).sqrt_prec_round_ref(u64, RoundingMode)",
+                &mut |(x, prec, rm)| no_out!(x.sqrt_prec_round_ref(prec, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_sqrt_prec_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sqrt_prec_round(u64, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_unsigned_rounding_mode_triple_gen_var_12_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_triple_1_2_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, prec, rm))| {
+                no_out!(x.sqrt_prec_round_ref(prec, rm))
+            }),
+            ("rug", &mut |((x, prec, rm), _)| {
+                no_out!(rug_sqrt_prec_round(&x, prec, rm))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_sqrt_prec_round_assign(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sqrt_prec_round_assign(u64, RoundingMode)",
+        BenchmarkType::Single,
+        float_unsigned_rounding_mode_triple_gen_var_12().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [(
+            "Float.sqrt_prec_round_assign(u64, RoundingMode)",
+            &mut |(mut x, prec, rm)| no_out!(x.sqrt_prec_round_assign(prec, rm)),
+        )],
+    );
+}
@@ -9,9 +9,19 @@
 use malachite_base::test_util::runner::Runner;
 
 pub(crate) fn register(runner: &mut Runner) {
+    catalan::register(runner);
+    e::register(runner);
+    euler_gamma::register(runner);
+    ln_2::register(runner);
+    pi::register(runner);
     prime_constant::register(runner);
     thue_morse_constant::register(runner);
 }
 
+mod catalan;
+mod e;
+mod euler_gamma;
+mod ln_2;
+mod pi;
 mod prime_constant;
 mod thue_morse_constant;
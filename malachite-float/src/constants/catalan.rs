@@ -0,0 +1,156 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use crate::arithmetic::exp::round_fixed_point_ziv;
+use crate::constants::{FixedPointCache, binary_splitting};
+use core::cmp::Ordering;
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+
+pub(crate) static CATALAN_CACHE: FixedPointCache = FixedPointCache::new();
+
+// Returns an approximation $a$ of $2^s G$, where $s$ is `scale` and $G$ is Catalan's constant, with
+// $|a - 2^s G| < 2$.
+//
+// This uses Lupas's series,
+// $$
+// G = \frac{1}{64} \sum_{k=1}^\infty \frac{(-1)^{k-1} 2^{8k} (40k^2-24k+3) ((2k)!)^3 (k!)^2}
+// {k^3 (2k-1) ((4k)!)^2},
+// $$
+// evaluated using binary splitting. The ratio of consecutive terms approaches $-1/4$, and the $k$th
+// term (including the factor of $1/64$) has absolute value less than $4.23 \cdot 4^{-k}$. Summing
+// the terms with $k < \lfloor s/2 \rfloor + 2$ makes the truncation error less than 1, and the
+// final division contributes less than 1.
+pub(crate) fn catalan_fixed_point(scale: u64) -> Natural {
+    CATALAN_CACHE.get(scale, |scale| {
+        let (_, q, b, t) = binary_splitting(
+            &|k| {
+                let k_2_m_1 = Natural::from((k << 1) - 1);
+                let k_4 = k << 2;
+                (
+                    -(Integer::from(Natural::from(k).pow(3) * &k_2_m_1) << 5u32),
+                    (Natural::from(k_4 - 1) * Natural::from(k_4 - 3)).pow(2),
+                    Integer::from(40 * k * k - 24 * k + 3),
+                    Natural::from(k).pow(3) * k_2_m_1,
+                )
+            },
+            1,
+            (scale >> 1) + 2,
+        );
+        // Since p(k) < 0, the computed sum is -64G, so T is negative.
+        Natural::exact_from((-t << scale) / Integer::from((b * q) << 6u32))
+    })
+}
+
+impl Float {
+    /// Returns an approximation to Catalan's constant, with the given precision and rounded using
+    /// the given [`RoundingMode`]. An [`Ordering`] is also returned, indicating whether the rounded
+    /// value is less than or greater than the exact value of the constant. (Since the constant is
+    /// believed to be irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// Catalan's constant is $G = \sum_{k=0}^\infty (-1)^k/(2k+1)^2$. It is computed using Lupas's
+    /// series,
+    /// $$
+    /// G = \frac{1}{64} \sum_{k=1}^\infty \frac{(-1)^{k-1} 2^{8k} (40k^2-24k+3) ((2k)!)^3 (k!)^2}
+    /// {k^3 (2k-1) ((4k)!)^2},
+    /// $$
+    /// which is evaluated using binary splitting. The most precise approximation computed so far is
+    /// cached, so repeated calls with the same or a lower precision are cheap.
+    ///
+    /// It is not known whether the constant is irrational, although it is widely believed to be.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or if `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (catalan, o) = Float::catalan_prec_round(100, Floor);
+    /// assert_eq!(catalan.to_string(), "0.915965594177219015054603514932");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (catalan, o) = Float::catalan_prec_round(100, Ceiling);
+    /// assert_eq!(catalan.to_string(), "0.9159655941772190150546035149325");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn catalan_prec_round(prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        assert_ne!(rm, Exact);
+        round_fixed_point_ziv(prec + Limb::WIDTH, prec, rm, |scale| {
+            (Integer::from(catalan_fixed_point(scale)), 1)
+        })
+    }
+
+    /// Returns an approximation to Catalan's constant, with the given precision and rounded to the
+    /// nearest [`Float`] of that precision. An [`Ordering`] is also returned, indicating whether
+    /// the rounded value is less than or greater than the exact value of the constant. (Since the
+    /// constant is believed to be irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// Catalan's constant is $G = \sum_{k=0}^\infty (-1)^k/(2k+1)^2$. It is computed using Lupas's
+    /// series,
+    /// $$
+    /// G = \frac{1}{64} \sum_{k=1}^\infty \frac{(-1)^{k-1} 2^{8k} (40k^2-24k+3) ((2k)!)^3 (k!)^2}
+    /// {k^3 (2k-1) ((4k)!)^2},
+    /// $$
+    /// which is evaluated using binary splitting. The most precise approximation computed so far is
+    /// cached, so repeated calls with the same or a lower precision are cheap.
+    ///
+    /// It is not known whether the constant is irrational, although it is widely believed to be.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (catalan, o) = Float::catalan_prec(1);
+    /// assert_eq!(catalan.to_string(), "1.0");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (catalan, o) = Float::catalan_prec(10);
+    /// assert_eq!(catalan.to_string(), "0.916");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (catalan, o) = Float::catalan_prec(100);
+    /// assert_eq!(catalan.to_string(), "0.9159655941772190150546035149325");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn catalan_prec(prec: u64) -> (Float, Ordering) {
+        Float::catalan_prec_round(prec, Nearest)
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use crate::arithmetic::exp::round_fixed_point_ziv;
+use crate::constants::{FixedPointCache, binary_splitting};
+use core::cmp::Ordering;
+use malachite_base::num::arithmetic::traits::FloorLogBase2;
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+
+pub(crate) static E_CACHE: FixedPointCache = FixedPointCache::new();
+
+// Returns an approximation $a$ of $2^s e$, where $s$ is `scale`, with $|a - 2^s e| < 2$.
+//
+// This uses $e = \sum_{k=0}^\infty 1/k!$, evaluated using binary splitting. The number of terms $K$
+// is chosen so that $K! \geq 2^{s+1}$; then the truncation error is less than $2/K! \leq 2^{-s}$,
+// and the final division contributes less than 1.
+pub(crate) fn e_fixed_point(scale: u64) -> Natural {
+    E_CACHE.get(scale, |scale| {
+        // Since floor(log_2 k) <= log_2 k, `bits` is a lower bound for log_2 K!.
+        let mut terms = 1u64;
+        let mut bits = 0;
+        while bits <= scale {
+            terms += 1;
+            bits += terms.floor_log_base_2();
+        }
+        let (_, q, _, t) = binary_splitting(
+            &|k| {
+                (
+                    Integer::ONE,
+                    if k == 0 {
+                        Natural::ONE
+                    } else {
+                        Natural::from(k)
+                    },
+                    Integer::ONE,
+                    Natural::ONE,
+                )
+            },
+            0,
+            terms,
+        );
+        Natural::exact_from((t << scale) / Integer::from(q))
+    })
+}
+
+impl Float {
+    /// Returns an approximation to $e$, with the given precision and rounded using the given
+    /// [`RoundingMode`]. An [`Ordering`] is also returned, indicating whether the rounded value is
+    /// less than or greater than the exact value of the constant. (Since the constant is
+    /// irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// $e$ is the base of the natural logarithm. It is computed using
+    /// $$
+    /// e = \sum_{k=0}^\infty \frac{1}{k!},
+    /// $$
+    /// which is evaluated using binary splitting. The most precise approximation computed so far is
+    /// cached, so repeated calls with the same or a lower precision are cheap.
+    ///
+    /// The constant is irrational and transcendental.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or if `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (e, o) = Float::e_prec_round(100, Floor);
+    /// assert_eq!(e.to_string(), "2.718281828459045235360287471351");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (e, o) = Float::e_prec_round(100, Ceiling);
+    /// assert_eq!(e.to_string(), "2.718281828459045235360287471354");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn e_prec_round(prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        assert_ne!(rm, Exact);
+        round_fixed_point_ziv(prec + Limb::WIDTH, prec, rm, |scale| {
+            (Integer::from(e_fixed_point(scale)), 1)
+        })
+    }
+
+    /// Returns an approximation to $e$, with the given precision and rounded to the nearest
+    /// [`Float`] of that precision. An [`Ordering`] is also returned, indicating whether the
+    /// rounded value is less than or greater than the exact value of the constant. (Since the
+    /// constant is irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// $e$ is the base of the natural logarithm. It is computed using
+    /// $$
+    /// e = \sum_{k=0}^\infty \frac{1}{k!},
+    /// $$
+    /// which is evaluated using binary splitting. The most precise approximation computed so far is
+    /// cached, so repeated calls with the same or a lower precision are cheap.
+    ///
+    /// The constant is irrational and transcendental.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (e, o) = Float::e_prec(1);
+    /// assert_eq!(e.to_string(), "2.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (e, o) = Float::e_prec(10);
+    /// assert_eq!(e.to_string(), "2.719");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (e, o) = Float::e_prec(100);
+    /// assert_eq!(e.to_string(), "2.718281828459045235360287471351");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn e_prec(prec: u64) -> (Float, Ordering) {
+        Float::e_prec_round(prec, Nearest)
+    }
+}
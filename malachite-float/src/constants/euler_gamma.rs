@@ -0,0 +1,189 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use crate::arithmetic::exp::round_fixed_point_ziv;
+use crate::arithmetic::ln::ln_fixed_point;
+use crate::constants::FixedPointCache;
+use core::cmp::Ordering;
+use malachite_base::num::arithmetic::traits::Square;
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+
+pub(crate) static EULER_GAMMA_CACHE: FixedPointCache = FixedPointCache::new();
+
+// Given $n^2$, returns $(P, Q, D, C, T, U)$ for the range $[l, h)$, where $l$ is `lo` and $h$ is
+// `hi`, such that
+// - $P = n^{2(h-l)}$,
+// - $Q = \prod_{k=l}^{h-1} k^2$,
+// - $D = \prod_{k=l}^{h-1} k$,
+// - $C/D = \sum_{k=l}^{h-1} 1/k$,
+// - $T/Q = \sum_{k=l}^{h-1} \prod_{j=l}^k n^2/j^2$, and
+// - $U/(QD) = \sum_{k=l}^{h-1} (\prod_{j=l}^k n^2/j^2) \sum_{j=l}^k 1/j$.
+#[allow(clippy::type_complexity)]
+fn euler_gamma_binary_splitting(
+    n_squared: &Natural,
+    lo: u64,
+    hi: u64,
+) -> (Natural, Natural, Natural, Natural, Natural, Natural) {
+    if hi - lo == 1 {
+        (
+            n_squared.clone(),
+            Natural::from(lo).square(),
+            Natural::from(lo),
+            Natural::ONE,
+            n_squared.clone(),
+            n_squared.clone(),
+        )
+    } else {
+        let mid = lo + ((hi - lo) >> 1);
+        let (p_1, q_1, d_1, c_1, t_1, u_1) = euler_gamma_binary_splitting(n_squared, lo, mid);
+        let (p_2, q_2, d_2, c_2, t_2, u_2) = euler_gamma_binary_splitting(n_squared, mid, hi);
+        let u = &u_1 * &q_2 * &d_2 + &p_1 * (&c_1 * &t_2 * &d_2 + u_2 * &d_1);
+        let t = t_1 * &q_2 + &p_1 * t_2;
+        let c = c_1 * &d_2 + c_2 * &d_1;
+        (p_1 * p_2, q_1 * q_2, d_1 * d_2, c, t, u)
+    }
+}
+
+// Returns an approximation $a$ of $2^s \gamma$, where $s$ is `scale`, with $|a - 2^s \gamma| < 2$.
+//
+// This uses the Brent-McMillan algorithm. Let
+// $$
+// V = \sum_{k=0}^\infty \left ( \frac{n^k}{k!} \right )^2 \quad \text{and} \quad U =
+// \sum_{k=0}^\infty \left ( \frac{n^k}{k!} \right )^2 H_k,
+// $$
+// where $H_k$ is the $k$th harmonic number. Then $\gamma = U/V - \log n + O(e^{-4n})$, where the
+// implied constant is at most $\pi$. We choose $n$ so that $4n \geq (w+2)\log 2$, where $w$ is the
+// working scale, and sum $5n$ terms, which makes both the truncation error and the error of the
+// approximation negligible. The series are evaluated using binary splitting.
+//
+// At the working scale, the division contributes an error of less than 1 and the logarithm an error
+// of at most 4. We use 3 guard bits, so the final error is less than $5/8 + 1 < 2$.
+pub(crate) fn euler_gamma_fixed_point(scale: u64) -> Natural {
+    EULER_GAMMA_CACHE.get(scale, |scale| {
+        const GUARD: u64 = 3;
+        let working_scale = scale + GUARD;
+        // 1733/10000 > (log 2)/4
+        let n = (working_scale + 2) * 1733 / 10000 + 1;
+        let (_, q, d, _, t, u) =
+            euler_gamma_binary_splitting(&Natural::from(n).square(), 1, 5 * n + 1);
+        let gamma = Integer::from((u << working_scale) / (d * (q + t)))
+            - ln_fixed_point(&Float::from(n), working_scale);
+        Natural::exact_from(gamma >> GUARD)
+    })
+}
+
+impl Float {
+    /// Returns an approximation to the Euler-Mascheroni constant $\gamma$, with the given precision
+    /// and rounded using the given [`RoundingMode`]. An [`Ordering`] is also returned, indicating
+    /// whether the rounded value is less than or greater than the exact value of the constant.
+    /// (Since the constant is believed to be irrational, the rounded value is never equal to the
+    /// exact value.)
+    ///
+    /// The Euler-Mascheroni constant is
+    /// $$
+    /// \gamma = \lim_{n \to \infty} \left ( \left ( \sum_{k=1}^n \frac{1}{k} \right ) - \log n
+    /// \right ).
+    /// $$
+    /// It is computed using the Brent-McMillan algorithm, whose series are evaluated using binary
+    /// splitting. The most precise approximation computed so far is cached, so repeated calls with
+    /// the same or a lower precision are cheap.
+    ///
+    /// It is not known whether the constant is irrational, although it is widely believed to be.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or if `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::euler_gamma_prec_round(100, Floor);
+    /// assert_eq!(gamma.to_string(), "0.5772156649015328606065120900823");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::euler_gamma_prec_round(100, Ceiling);
+    /// assert_eq!(gamma.to_string(), "0.577215664901532860606512090083");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn euler_gamma_prec_round(prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        assert_ne!(rm, Exact);
+        round_fixed_point_ziv(prec + Limb::WIDTH, prec, rm, |scale| {
+            (Integer::from(euler_gamma_fixed_point(scale)), 1)
+        })
+    }
+
+    /// Returns an approximation to the Euler-Mascheroni constant $\gamma$, with the given precision
+    /// and rounded to the nearest [`Float`] of that precision. An [`Ordering`] is also returned,
+    /// indicating whether the rounded value is less than or greater than the exact value of the
+    /// constant. (Since the constant is believed to be irrational, the rounded value is never equal
+    /// to the exact value.)
+    ///
+    /// The Euler-Mascheroni constant is
+    /// $$
+    /// \gamma = \lim_{n \to \infty} \left ( \left ( \sum_{k=1}^n \frac{1}{k} \right ) - \log n
+    /// \right ).
+    /// $$
+    /// It is computed using the Brent-McMillan algorithm, whose series are evaluated using binary
+    /// splitting. The most precise approximation computed so far is cached, so repeated calls with
+    /// the same or a lower precision are cheap.
+    ///
+    /// It is not known whether the constant is irrational, although it is widely believed to be.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::euler_gamma_prec(1);
+    /// assert_eq!(gamma.to_string(), "0.5");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::euler_gamma_prec(10);
+    /// assert_eq!(gamma.to_string(), "0.577");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::euler_gamma_prec(100);
+    /// assert_eq!(gamma.to_string(), "0.5772156649015328606065120900823");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn euler_gamma_prec(prec: u64) -> (Float, Ordering) {
+        Float::euler_gamma_prec_round(prec, Nearest)
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use crate::arithmetic::exp::round_fixed_point_ziv;
+use crate::constants::{FixedPointCache, binary_splitting};
+use core::cmp::Ordering;
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+
+pub(crate) static LN_2_CACHE: FixedPointCache = FixedPointCache::new();
+
+// Returns an approximation $a$ of $2^s \log 2$, where $s$ is `scale`, with $|a - 2^s \log 2| < 2$.
+//
+// This uses $\log 2 = 2 \operatorname{artanh}(1/3) = \frac{2}{3} \sum_{k=0}^\infty 1/((2k+1)9^k)$,
+// evaluated using binary splitting. After $K$ terms the truncation error is less than $9^{-K}$, and
+// the final division contributes less than 1.
+pub(crate) fn ln_2_fixed_point(scale: u64) -> Natural {
+    LN_2_CACHE.get(scale, |scale| {
+        let (_, q, b, t) = binary_splitting(
+            &|k| {
+                (
+                    Integer::ONE,
+                    if k == 0 {
+                        Natural::ONE
+                    } else {
+                        Natural::from(9u32)
+                    },
+                    Integer::ONE,
+                    Natural::from((k << 1) + 1),
+                )
+            },
+            0,
+            scale / 3 + 1,
+        );
+        Natural::exact_from((t << (scale + 1)) / Integer::from(b * q * Natural::from(3u32)))
+    })
+}
+
+impl Float {
+    /// Returns an approximation to $\log 2$, with the given precision and rounded using the given
+    /// [`RoundingMode`]. An [`Ordering`] is also returned, indicating whether the rounded value is
+    /// less than or greater than the exact value of the constant. (Since the constant is
+    /// irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// $\log 2$ is the natural logarithm of 2. It is computed using
+    /// $$
+    /// \log 2 = 2 \operatorname{artanh} \frac{1}{3} = \frac{2}{3} \sum_{k=0}^\infty
+    /// \frac{1}{(2k+1)9^k},
+    /// $$
+    /// which is evaluated using binary splitting. The most precise approximation computed so far is
+    /// cached, so repeated calls with the same or a lower precision are cheap.
+    ///
+    /// The constant is irrational and transcendental.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or if `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln_2, o) = Float::ln_2_prec_round(100, Floor);
+    /// assert_eq!(ln_2.to_string(), "0.693147180559945309417232121458");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln_2, o) = Float::ln_2_prec_round(100, Ceiling);
+    /// assert_eq!(ln_2.to_string(), "0.693147180559945309417232121459");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn ln_2_prec_round(prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        assert_ne!(rm, Exact);
+        round_fixed_point_ziv(prec + Limb::WIDTH, prec, rm, |scale| {
+            (Integer::from(ln_2_fixed_point(scale)), 1)
+        })
+    }
+
+    /// Returns an approximation to $\log 2$, with the given precision and rounded to the nearest
+    /// [`Float`] of that precision. An [`Ordering`] is also returned, indicating whether the
+    /// rounded value is less than or greater than the exact value of the constant. (Since the
+    /// constant is irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// $\log 2$ is the natural logarithm of 2. It is computed using
+    /// $$
+    /// \log 2 = 2 \operatorname{artanh} \frac{1}{3} = \frac{2}{3} \sum_{k=0}^\infty
+    /// \frac{1}{(2k+1)9^k},
+    /// $$
+    /// which is evaluated using binary splitting. The most precise approximation computed so far is
+    /// cached, so repeated calls with the same or a lower precision are cheap.
+    ///
+    /// The constant is irrational and transcendental.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln_2, o) = Float::ln_2_prec(1);
+    /// assert_eq!(ln_2.to_string(), "0.5");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln_2, o) = Float::ln_2_prec(10);
+    /// assert_eq!(ln_2.to_string(), "0.693");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln_2, o) = Float::ln_2_prec(100);
+    /// assert_eq!(ln_2.to_string(), "0.693147180559945309417232121458");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn ln_2_prec(prec: u64) -> (Float, Ordering) {
+        Float::ln_2_prec_round(prec, Nearest)
+    }
+}
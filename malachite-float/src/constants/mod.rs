@@ -6,9 +6,181 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
+use core::cell::UnsafeCell;
+use core::hint::spin_loop;
+use core::sync::atomic::AtomicBool;
+use core::sync::atomic::Ordering::{Acquire, Relaxed, Release};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+
+/// Functions for approximating Catalan's constant.
+pub mod catalan;
+/// Functions for approximating $e$, the base of the natural logarithm.
+pub mod e;
+/// Functions for approximating the Euler-Mascheroni constant $\gamma$.
+pub mod euler_gamma;
+/// Functions for approximating $\log 2$.
+pub mod ln_2;
+/// Functions for approximating $\pi$.
+pub mod pi;
 /// Functions for approximating the prime constant (the constant whose $n$th bit is 1 if and only if
 /// $n$ is prime).
 pub mod prime_constant;
 /// Functions for approximating the Thue-Morse constant (the constant whose bits are the Thue-Morse
 /// sequence).
 pub mod thue_morse_constant;
+
+// Evaluates the series
+// $$
+// S = \sum_{k=l}^{h-1} \frac{a(k)}{b(k)} \prod_{j=l}^k \frac{p(j)}{q(j)}
+// $$
+// using binary splitting, where $l$ is `lo`, $h$ is `hi`, and `term` maps $k$ to $(p(k), q(k),
+// a(k), b(k))$. Returns $(P, Q, B, T)$, where $P = \prod_{k=l}^{h-1} p(k)$, $Q = \prod_{k=l}^{h-1}
+// q(k)$, $B = \prod_{k=l}^{h-1} b(k)$, and $T = BQS$; all of these are integers.
+//
+// This is the method described in Haible and Papanikolaou, "Fast multiprecision evaluation of
+// series of rational numbers". `hi` must be greater than `lo`.
+pub(crate) fn binary_splitting<F: Fn(u64) -> (Integer, Natural, Integer, Natural)>(
+    term: &F,
+    lo: u64,
+    hi: u64,
+) -> (Integer, Natural, Natural, Integer) {
+    if hi - lo == 1 {
+        let (p, q, a, b) = term(lo);
+        let t = a * &p;
+        (p, q, b, t)
+    } else {
+        let mid = lo + ((hi - lo) >> 1);
+        let (p_1, q_1, b_1, t_1) = binary_splitting(term, lo, mid);
+        let (p_2, q_2, b_2, t_2) = binary_splitting(term, mid, hi);
+        let t = Integer::from(&b_2 * &q_2) * t_1 + Integer::from(&b_1) * &p_1 * t_2;
+        (p_1 * p_2, q_1 * q_2, b_1 * b_2, t)
+    }
+}
+
+// Stores a fixed-point approximation of a constant $c$ at the largest scale computed so far, so
+// that repeated evaluations at the same or smaller scales are cheap.
+//
+// If the stored approximation $a$ at scale $S$ satisfies $|a - 2^S c| < 2$, then for any $s \leq S$
+// we also have $|\lfloor a/2^{S-s} \rfloor - 2^s c| < 2$, so the cached value can be shifted right
+// without weakening the error bound.
+//
+// The cache is guarded by a flag rather than a mutex, so that it is available without `std`. The
+// flag is only held while the cached value is read or replaced, never while the constant is being
+// computed, and it is released by a guard, so a panic can't leave the cache locked. A thread that
+// finds the cache in use computes the approximation itself instead of waiting.
+#[doc(hidden)]
+pub struct FixedPointCache {
+    in_use: AtomicBool,
+    value: UnsafeCell<Option<(u64, Natural)>>,
+}
+
+// SAFETY: `value` is only accessed by the thread that has set `in_use`.
+unsafe impl Sync for FixedPointCache {}
+
+struct FixedPointCacheGuard<'a>(&'a FixedPointCache);
+
+impl FixedPointCacheGuard<'_> {
+    fn value(&mut self) -> &mut Option<(u64, Natural)> {
+        // SAFETY: the guard's existence means that this thread has set `in_use`, so no other
+        // thread can access `value` until the guard is dropped.
+        unsafe { &mut *self.0.value.get() }
+    }
+}
+
+impl Drop for FixedPointCacheGuard<'_> {
+    fn drop(&mut self) {
+        self.0.in_use.store(false, Release);
+    }
+}
+
+impl Default for FixedPointCache {
+    fn default() -> FixedPointCache {
+        FixedPointCache::new()
+    }
+}
+
+impl FixedPointCache {
+    #[doc(hidden)]
+    pub const fn new() -> FixedPointCache {
+        FixedPointCache {
+            in_use: AtomicBool::new(false),
+            value: UnsafeCell::new(None),
+        }
+    }
+
+    fn try_lock(&self) -> Option<FixedPointCacheGuard<'_>> {
+        self.in_use
+            .compare_exchange(false, true, Acquire, Relaxed)
+            .ok()
+            .map(|_| FixedPointCacheGuard(self))
+    }
+
+    // Returns an approximation at scale `scale`, either from the cache or by calling `f`. `f` must
+    // return an approximation $a$ of $2^s c$ with $|a - 2^s c| < 2$, where $s$ is its argument.
+    //
+    // If two threads compute the constant at the same time, the one with the larger scale is kept.
+    #[doc(hidden)]
+    pub fn get<F: FnOnce(u64) -> Natural>(&self, scale: u64, f: F) -> Natural {
+        if let Some(mut guard) = self.try_lock() {
+            if let Some((cached_scale, a)) = guard.value() {
+                if *cached_scale >= scale {
+                    return &*a >> (*cached_scale - scale);
+                }
+            }
+        }
+        let a = f(scale);
+        if let Some(mut guard) = self.try_lock() {
+            let value = guard.value();
+            if value
+                .as_ref()
+                .is_none_or(|(cached_scale, _)| *cached_scale < scale)
+            {
+                *value = Some((scale, a.clone()));
+            }
+        }
+        a
+    }
+
+    // Empties the cache. Unlike `get`, this waits for the flag, which is only ever held briefly.
+    #[doc(hidden)]
+    pub fn clear(&self) {
+        loop {
+            if let Some(mut guard) = self.try_lock() {
+                *guard.value() = None;
+                return;
+            }
+            spin_loop();
+        }
+    }
+}
+
+/// Frees the memory used to cache approximations of $\pi$, $e$, $\log 2$, $\gamma$, and Catalan's
+/// constant.
+///
+/// Each of these constants keeps the most precise approximation computed so far, so that computing
+/// it again at the same or a lower precision is cheap. This function discards those
+/// approximations; it is similar to MPFR's `mpfr_free_cache`.
+///
+/// Computing a constant after calling this function gives the same result as before, but may be
+/// slower.
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Examples
+/// ```
+/// use malachite_float::Float;
+/// use malachite_float::constants::free_constant_caches;
+///
+/// let pi = Float::pi_prec(1000).0;
+/// free_constant_caches();
+/// assert_eq!(Float::pi_prec(1000).0, pi);
+/// ```
+pub fn free_constant_caches() {
+    catalan::CATALAN_CACHE.clear();
+    e::E_CACHE.clear();
+    euler_gamma::EULER_GAMMA_CACHE.clear();
+    ln_2::LN_2_CACHE.clear();
+    pi::PI_CACHE.clear();
+}
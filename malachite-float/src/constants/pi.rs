@@ -0,0 +1,170 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use crate::arithmetic::exp::round_fixed_point_ziv;
+use crate::constants::{FixedPointCache, binary_splitting};
+use core::cmp::Ordering;
+use malachite_base::num::arithmetic::traits::{FloorSqrt, Pow};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+
+pub(crate) static PI_CACHE: FixedPointCache = FixedPointCache::new();
+
+const CHUDNOVSKY_A: u64 = 13591409;
+const CHUDNOVSKY_B: u64 = 545140134;
+// 640320^3 / 24
+const CHUDNOVSKY_C_CUBED_OVER_24: u64 = 10939058860032000;
+
+// Returns an approximation $a$ of $2^s \pi$, where $s$ is `scale`, with $|a - 2^s \pi| < 2$.
+//
+// This uses the Chudnovsky series,
+// $$
+// \frac{1}{\pi} = \frac{12}{640320^{3/2}} \sum_{k=0}^\infty
+// \frac{(-1)^k(6k)!(13591409+545140134k)}{(3k)!(k!)^3 640320^{3k}},
+// $$
+// evaluated using binary splitting. Each term contributes slightly more than 47 bits. The
+// truncation error is negligible, the error of the square root contributes less than 1/30, and the
+// final division contributes less than 1.
+pub(crate) fn pi_fixed_point(scale: u64) -> Natural {
+    PI_CACHE.get(scale, |scale| {
+        let (_, q, _, t) = binary_splitting(
+            &|k| {
+                if k == 0 {
+                    (
+                        Integer::ONE,
+                        Natural::ONE,
+                        Integer::from(CHUDNOVSKY_A),
+                        Natural::ONE,
+                    )
+                } else {
+                    (
+                        -(Integer::from(6 * k - 5)
+                            * Integer::from((k << 1) - 1)
+                            * Integer::from(6 * k - 1)),
+                        Natural::from(k).pow(3) * Natural::from(CHUDNOVSKY_C_CUBED_OVER_24),
+                        Integer::from(CHUDNOVSKY_A)
+                            + Integer::from(CHUDNOVSKY_B) * Integer::from(k),
+                        Natural::ONE,
+                    )
+                }
+            },
+            0,
+            scale / 47 + 2,
+        );
+        let sqrt = (Natural::from(10005u32) << (scale << 1)).floor_sqrt();
+        Natural::exact_from(Integer::from(sqrt * Natural::from(426880u32) * q) / t)
+    })
+}
+
+impl Float {
+    /// Returns an approximation to $\pi$, with the given precision and rounded using the given
+    /// [`RoundingMode`]. An [`Ordering`] is also returned, indicating whether the rounded value is
+    /// less than or greater than the exact value of the constant. (Since the constant is
+    /// irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// $\pi$ is the ratio of a circle's circumference to its diameter. It is computed using the
+    /// Chudnovsky series,
+    /// $$
+    /// \frac{1}{\pi} = \frac{12}{640320^{3/2}} \sum_{k=0}^\infty
+    /// \frac{(-1)^k(6k)!(13591409+545140134k)}{(3k)!(k!)^3 640320^{3k}},
+    /// $$
+    /// which is evaluated using binary splitting. The most precise approximation computed so far is
+    /// cached, so repeated calls with the same or a lower precision are cheap.
+    ///
+    /// The constant is irrational and transcendental.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or if `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (pi, o) = Float::pi_prec_round(100, Floor);
+    /// assert_eq!(pi.to_string(), "3.141592653589793238462643383279");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pi, o) = Float::pi_prec_round(100, Ceiling);
+    /// assert_eq!(pi.to_string(), "3.141592653589793238462643383282");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn pi_prec_round(prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        assert_ne!(rm, Exact);
+        round_fixed_point_ziv(prec + Limb::WIDTH, prec, rm, |scale| {
+            (Integer::from(pi_fixed_point(scale)), 1)
+        })
+    }
+
+    /// Returns an approximation to $\pi$, with the given precision and rounded to the nearest
+    /// [`Float`] of that precision. An [`Ordering`] is also returned, indicating whether the
+    /// rounded value is less than or greater than the exact value of the constant. (Since the
+    /// constant is irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// $\pi$ is the ratio of a circle's circumference to its diameter. It is computed using the
+    /// Chudnovsky series,
+    /// $$
+    /// \frac{1}{\pi} = \frac{12}{640320^{3/2}} \sum_{k=0}^\infty
+    /// \frac{(-1)^k(6k)!(13591409+545140134k)}{(3k)!(k!)^3 640320^{3k}},
+    /// $$
+    /// which is evaluated using binary splitting. The most precise approximation computed so far is
+    /// cached, so repeated calls with the same or a lower precision are cheap.
+    ///
+    /// The constant is irrational and transcendental.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (pi, o) = Float::pi_prec(1);
+    /// assert_eq!(pi.to_string(), "4.0");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pi, o) = Float::pi_prec(10);
+    /// assert_eq!(pi.to_string(), "3.141");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pi, o) = Float::pi_prec(100);
+    /// assert_eq!(pi.to_string(), "3.141592653589793238462643383279");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn pi_prec(prec: u64) -> (Float, Ordering) {
+        Float::pi_prec_round(prec, Nearest)
+    }
+}
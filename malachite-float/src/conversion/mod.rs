@@ -711,6 +711,9 @@ pub mod primitive_float_from_float;
 /// assert_eq!(i8::convertible_from(&Float::NAN), false);
 /// ```
 pub mod primitive_int_from_float;
+/// Implementations of traits for conversions between Python floats and [`Float`](crate::Float)s
+/// using [pyo3](https://pyo3.rs/).
+pub mod pyo3;
 /// Implementations of traits for converting a [`Float`](crate::Float) to a
 /// [`Rational`](malachite_q::Rational).
 ///
@@ -718,6 +721,9 @@ pub mod primitive_int_from_float;
 /// [`ConvertibleFrom`](malachite_base::num::conversion::traits::ConvertibleFrom), and
 /// [`RoundingFrom`](malachite_base::num::conversion::traits::RoundingFrom).
 pub mod rational_from_float;
+/// Implementations of traits for serialization and deserialization using
+/// [serde](https://serde.rs/).
+pub mod serde;
 /// Implementations of traits for converting [`Float`](crate::Float)s to and from [`String`]s.
 ///
 /// Warning: these implementations are unstable and will definitely be changed in the future.
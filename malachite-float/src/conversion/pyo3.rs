@@ -0,0 +1,252 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "enable_pyo3")]
+
+//!
+//! A [`Float`](crate::Float) is converted to and from Python's `float`.
+//!
+//! Converting a Python `float` to a [`Float`](crate::Float) is always exact, and so is converting a
+//! Python `int`: its precision is the smallest one that represents the integer exactly, and
+//! integers too large for any [`Float`](crate::Float) raise `OverflowError`. Any other object is
+//! converted through its `__float__` method.
+//!
+//! Converting a [`Float`](crate::Float) to a Python `float` is exact whenever the value fits in an
+//! `f64`; otherwise it is rounded to the nearest `f64`, overflowing to an infinity if necessary.
+//!
+//! To use this enable the `enable_pyo3` feature.
+//!
+//! ## Examples
+//!
+//! Using [`Float`](crate::Float) to compute a square root with more precision than a Python
+//! `float` has, before rounding the result back.
+//! ```rust
+//! use malachite::Float;
+//! use pyo3::prelude::*;
+//!
+//! #[pyfunction]
+//! fn precise_sqrt(x: Float) -> Float {
+//!     x.sqrt_prec(200).0
+//! }
+//!
+//! #[pymodule]
+//! fn my_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+//!     m.add_function(wrap_pyfunction!(precise_sqrt, m)?)?;
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Python code:
+//! ```python
+//! from my_module import precise_sqrt
+//!
+//! assert precise_sqrt(2.0) == 2.0 ** 0.5
+//! ```
+
+use crate::Float;
+use core::cmp::Ordering::*;
+use core::convert::Infallible;
+use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::num::comparison::traits::PartialOrdAbs;
+use malachite_base::num::conversion::traits::RoundingFrom;
+use malachite_base::rounding_modes::RoundingMode::*;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use pyo3::{
+    Bound, FromPyObject, IntoPyObject, PyResult, Python, exceptions::PyOverflowError, types::*,
+};
+
+/// Rounds a [`Float`] to the nearest `f64`.
+///
+/// `f64::rounding_from` saturates at `f64::MAX`, but here values that are at least halfway between
+/// `f64::MAX` and $2^{1024}$ overflow to an infinity, as they do in IEEE 754.
+fn f64_nearest(x: &Float) -> f64 {
+    let overflows = || {
+        let max_exp = f64::MAX_EXP as u64;
+        let half_ulp = max_exp - u64::from(f64::MANTISSA_DIGITS) - 1;
+        let threshold = Natural::power_of_2(max_exp) - Natural::power_of_2(half_ulp);
+        x.ge_abs(&Float::from_natural_prec(threshold, max_exp - half_ulp).0)
+    };
+    match f64::rounding_from(x, Nearest) {
+        (y, Less) if y == f64::MAX && overflows() => f64::INFINITY,
+        (y, Greater) if y == f64::MIN && overflows() => f64::NEG_INFINITY,
+        (y, _) => y,
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "enable_pyo3")))]
+impl<'source> FromPyObject<'source> for Float {
+    fn extract_bound(ob: &Bound<'source, PyAny>) -> PyResult<Float> {
+        if let Ok(float) = ob.downcast::<PyFloat>() {
+            return Ok(Float::from(float.value()));
+        }
+
+        // ints are converted exactly, rather than through an f64
+        if let Ok(long) = ob.downcast::<PyInt>() {
+            return Float::try_from(long.extract::<Integer>()?)
+                .map_err(|_| PyOverflowError::new_err("int too large to convert to Float"));
+        }
+
+        Ok(Float::from(ob.extract::<f64>()?))
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "enable_pyo3")))]
+impl<'py> IntoPyObject<'py> for Float {
+    type Target = PyFloat;
+    type Output = Bound<'py, Self::Target>;
+    type Error = Infallible;
+
+    #[inline]
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        (&self).into_pyobject(py)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "enable_pyo3")))]
+impl<'py> IntoPyObject<'py> for &Float {
+    type Target = PyFloat;
+    type Output = Bound<'py, Self::Target>;
+    type Error = Infallible;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        Ok(PyFloat::new(py, f64_nearest(self)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ComparableFloat;
+    use malachite_base::num::basic::traits::{One, Two};
+    use malachite_base::num::conversion::traits::ExactFrom;
+    use malachite_q::Rational;
+
+    /// Prepare Python
+    fn prepare_python() {
+        pyo3::prepare_freethreaded_python();
+    }
+
+    /// Evaluates a Python expression
+    fn eval<'py>(py: Python<'py>, expr: &core::ffi::CStr) -> Bound<'py, PyAny> {
+        py.eval(expr, None, None).unwrap()
+    }
+
+    /// Test conversion to and from f64 values, including the special ones
+    #[test]
+    fn convert_float() {
+        prepare_python();
+        Python::with_gil(|py| {
+            for x in [
+                0.0,
+                -0.0,
+                1.0,
+                -1.5,
+                0.1,
+                1.0 / 3.0,
+                1.0e300,
+                -1.0e-300,
+                f64::MAX,
+                f64::MIN,
+                f64::MIN_POSITIVE,
+                5.0e-324,
+                f64::INFINITY,
+                f64::NEG_INFINITY,
+            ] {
+                let py_value = PyFloat::new(py, x);
+                // Python -> Rust
+                let rs_value: Float = py_value.extract().unwrap();
+                assert_eq!(
+                    ComparableFloat(rs_value.clone()),
+                    ComparableFloat(Float::from(x))
+                );
+                // Rust -> Python
+                let roundtrip = (&rs_value).into_pyobject(py).unwrap().value();
+                assert_eq!(roundtrip.to_bits(), x.to_bits());
+                let roundtrip = rs_value.into_pyobject(py).unwrap().value();
+                assert_eq!(roundtrip.to_bits(), x.to_bits());
+            }
+
+            let nan: Float = eval(py, c"float('nan')").extract().unwrap();
+            assert!(nan.is_nan());
+            assert!(nan.into_pyobject(py).unwrap().value().is_nan());
+        });
+    }
+
+    /// Test that precise Floats are rounded to the nearest f64
+    #[test]
+    fn round_to_nearest() {
+        prepare_python();
+        Python::with_gil(|py| {
+            let third = Float::from_rational_prec(Rational::from_signeds(1, 3), 200).0;
+            assert_eq!(third.into_pyobject(py).unwrap().value(), 1.0 / 3.0);
+
+            let almost_one = Float::from_rational_prec(
+                Rational::ONE - Rational::exact_from(&(Float::ONE >> 100u64)),
+                200,
+            )
+            .0;
+            assert_eq!(almost_one.into_pyobject(py).unwrap().value(), 1.0);
+
+            let huge = Float::TWO << 5000u64;
+            assert_eq!(huge.into_pyobject(py).unwrap().value(), f64::INFINITY);
+            let huge = -(Float::TWO << 5000u64);
+            assert_eq!(huge.into_pyobject(py).unwrap().value(), f64::NEG_INFINITY);
+
+            // the boundary between rounding to f64::MAX and overflowing to infinity
+            let max = Float::from(f64::MAX);
+            let half_ulp = Float::ONE << 970u64;
+            let below = Float::from_rational_prec(
+                Rational::exact_from(&max) + Rational::exact_from(&half_ulp)
+                    - Rational::exact_from(&Float::ONE),
+                2000,
+            )
+            .0;
+            assert_eq!(below.into_pyobject(py).unwrap().value(), f64::MAX);
+            let halfway = Float::from_rational_prec(
+                Rational::exact_from(&max) + Rational::exact_from(&half_ulp),
+                2000,
+            )
+            .0;
+            assert_eq!(halfway.into_pyobject(py).unwrap().value(), f64::INFINITY);
+            let tiny = -(Float::ONE >> 5000u64);
+            let tiny = tiny.into_pyobject(py).unwrap().value();
+            assert_eq!(tiny, 0.0);
+            assert!(tiny.is_sign_negative());
+        });
+    }
+
+    /// Test that Python ints are converted exactly
+    #[test]
+    fn convert_int() {
+        prepare_python();
+        Python::with_gil(|py| {
+            let x: Float = eval(py, c"(1 << 100) + 1").extract().unwrap();
+            assert_eq!(
+                ComparableFloat(x),
+                ComparableFloat(
+                    Float::try_from((Integer::from(1) << 100u64) + Integer::from(1)).unwrap()
+                )
+            );
+
+            let x: Float = eval(py, c"-7").extract().unwrap();
+            assert_eq!(ComparableFloat(x), ComparableFloat(Float::from(-7)));
+
+            let x: Float = eval(py, c"0").extract().unwrap();
+            assert_eq!(ComparableFloat(x), ComparableFloat(Float::from(0)));
+
+            // objects that define __float__ are accepted as well
+            let x: Float = eval(py, c"__import__('fractions').Fraction(1, 4)")
+                .extract()
+                .unwrap();
+            assert_eq!(ComparableFloat(x), ComparableFloat(Float::from(0.25)));
+
+            assert!(eval(py, c"'1.0'").extract::<Float>().is_err());
+        });
+    }
+}
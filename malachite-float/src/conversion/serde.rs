@@ -0,0 +1,169 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+#[cfg(feature = "serde")]
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+#[cfg(feature = "serde")]
+use crate::significand_bits;
+use crate::{ComparableFloatRef, Float, SerdeFloat};
+use alloc::format;
+use alloc::string::String;
+use core::convert::TryFrom;
+#[cfg(feature = "serde")]
+use malachite_base::num::arithmetic::traits::RoundToMultipleOfPowerOf2;
+#[cfg(feature = "serde")]
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::conversion::traits::FromStringBase;
+#[cfg(feature = "serde")]
+use malachite_base::num::logic::traits::SignificantBits;
+#[cfg(feature = "serde")]
+use malachite_base::rounding_modes::RoundingMode::*;
+#[cfg(feature = "serde")]
+use malachite_nz::natural::conversion::serde::CompactNatural;
+#[cfg(feature = "serde")]
+use malachite_nz::platform::Limb;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+impl From<Float> for SerdeFloat {
+    #[inline]
+    fn from(x: Float) -> SerdeFloat {
+        SerdeFloat(format!("{:#x}", ComparableFloatRef(&x)))
+    }
+}
+
+impl TryFrom<SerdeFloat> for Float {
+    type Error = String;
+
+    #[inline]
+    fn try_from(s: SerdeFloat) -> Result<Float, String> {
+        Float::from_string_base(16, &s.0).ok_or_else(|| format!("Unrecognized Float {}", s.0))
+    }
+}
+
+/// A wrapper around a [`Float`] that is serialized compactly.
+///
+/// A `CompactFloat` is serialized as an enum with four variants: `NaN`; `Infinity` and `Zero`, each
+/// holding a `bool` that is `true` if and only if the sign is positive; and `Finite`, holding the
+/// sign, the exponent as an `i32`, the precision as a `u64`, and the significand. The significand
+/// is stored as a [`CompactNatural`] containing exactly `precision` bits, not the zero-padded
+/// [`Limb`](malachite_nz#limbs)s that make up the [`Float`] internally, so the encoding does not
+/// depend on the [`Limb`](malachite_nz#limbs) size.
+///
+/// Deserialization rejects significands that don't have exactly `precision` significant bits and
+/// exponents that are out of range, so it always produces a valid [`Float`].
+///
+/// To serialize a [`Float`] without taking ownership of it, use [`CompactFloatRef`].
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::basic::traits::Infinity;
+/// use malachite_float::conversion::serde::CompactFloat;
+/// use malachite_float::{ComparableFloat, Float};
+///
+/// let x = CompactFloat(Float::from(-1.5));
+/// let s = serde_json::to_string(&x).unwrap();
+/// assert_eq!(s, "{\"Finite\":[false,1,2,[3]]}");
+/// assert_eq!(
+///     ComparableFloat(serde_json::from_str::<CompactFloat>(&s).unwrap().0),
+///     ComparableFloat(x.0)
+/// );
+///
+/// assert_eq!(
+///     serde_json::to_string(&CompactFloat(Float::INFINITY)).unwrap(),
+///     "{\"Infinity\":true}"
+/// );
+/// ```
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+#[derive(Clone, Debug)]
+pub struct CompactFloat(pub Float);
+
+/// A wrapper around a reference to a [`Float`] that is serialized compactly, in the same format as
+/// a [`CompactFloat`].
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+#[derive(Clone, Copy, Debug)]
+pub struct CompactFloatRef<'a>(pub &'a Float);
+
+#[cfg(feature = "serde")]
+#[derive(Deserialize, Serialize)]
+enum CompactFloatRepr {
+    NaN,
+    Infinity(bool),
+    Zero(bool),
+    Finite(bool, i32, u64, CompactNatural),
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for CompactFloatRef<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            Float(NaN) => CompactFloatRepr::NaN,
+            Float(Infinity { sign }) => CompactFloatRepr::Infinity(*sign),
+            Float(Zero { sign }) => CompactFloatRepr::Zero(*sign),
+            Float(Finite {
+                sign,
+                exponent,
+                precision,
+                significand,
+            }) => CompactFloatRepr::Finite(
+                *sign,
+                *exponent,
+                *precision,
+                CompactNatural(significand >> (significand_bits(significand) - precision)),
+            ),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for CompactFloat {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        CompactFloatRef(&self.0).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for CompactFloat {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(CompactFloat(Float(
+            match CompactFloatRepr::deserialize(deserializer)? {
+                CompactFloatRepr::NaN => NaN,
+                CompactFloatRepr::Infinity(sign) => Infinity { sign },
+                CompactFloatRepr::Zero(sign) => Zero { sign },
+                CompactFloatRepr::Finite(
+                    sign,
+                    exponent,
+                    precision,
+                    CompactNatural(significand),
+                ) => {
+                    if precision == 0 || significand.significant_bits() != precision {
+                        return Err(D::Error::custom(
+                            "a Float's significand must have exactly as many bits as its precision",
+                        ));
+                    }
+                    if !(Float::MIN_EXPONENT..=Float::MAX_EXPONENT).contains(&exponent) {
+                        return Err(D::Error::custom("a Float's exponent is out of range"));
+                    }
+                    let bits = precision
+                        .round_to_multiple_of_power_of_2(Limb::LOG_WIDTH, Ceiling)
+                        .0;
+                    Finite {
+                        sign,
+                        exponent,
+                        precision,
+                        significand: significand << (bits - precision),
+                    }
+                }
+            },
+        )))
+    }
+}
@@ -8,73 +8,92 @@
 
 use crate::Float;
 use alloc::string::{String, ToString};
+use core::cmp::Ordering::*;
 use core::str::FromStr;
 use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
 use malachite_base::num::conversion::string::options::FromSciStringOptions;
-use malachite_base::num::conversion::traits::{FromSciString, FromStringBase};
+use malachite_base::num::conversion::traits::{ExactFrom, FromSciString, FromStringBase};
 use malachite_base::rounding_modes::RoundingMode::*;
 use malachite_q::Rational;
 
-fn reduce_exponent_in_hex_string(s: &str) -> Option<(String, i32)> {
-    if let Some(exp_index) = s.find('E') {
-        let tail = &s[exp_index + 1..];
-        let hash_index = tail.find('#').unwrap();
-        let tail = &tail[..hash_index];
-        let original_exponent = i32::from_str(tail).unwrap();
+// If the hex string has a large exponent, returns an equivalent string with a smaller exponent,
+// together with the power of 2 that the value of the new string must be multiplied by. Returns
+// `None` if the exponent can't be parsed, or is too large to fit in an `i32` once scaled.
+fn reduce_exponent_in_hex_string(s: &str, hash_index: usize) -> Option<(String, i32)> {
+    if let Some(exp_index) = s[..hash_index].find('E') {
+        let original_exponent = i32::from_str(&s[exp_index + 1..hash_index]).ok()?;
         if original_exponent.unsigned_abs() < 20 {
-            return None;
+            return Some((s.to_string(), 0));
         }
         let mut new_s = s[..=exp_index].to_string();
         new_s += "+20";
-        new_s += &s[exp_index + hash_index + 1..];
-        Some((new_s, (original_exponent << 2) - 80))
+        new_s += &s[hash_index..];
+        Some((new_s, original_exponent.checked_mul(4)? - 80))
     } else {
-        None
+        Some((s.to_string(), 0))
     }
 }
 
-fn from_hex_string(s: &str) -> Float {
+fn from_hex_string(s: &str) -> Option<Float> {
     match s {
-        "NaN" => Float::NAN,
-        "Infinity" => Float::INFINITY,
-        "-Infinity" => Float::NEGATIVE_INFINITY,
-        "0x0.0" => Float::ZERO,
-        "-0x0.0" => Float::NEGATIVE_ZERO,
+        "NaN" => Some(Float::NAN),
+        "Infinity" => Some(Float::INFINITY),
+        "-Infinity" => Some(Float::NEGATIVE_INFINITY),
+        "0x0.0" => Some(Float::ZERO),
+        "-0x0.0" => Some(Float::NEGATIVE_ZERO),
         s => {
             let (s, sign) = if let Some(s) = s.strip_prefix('-') {
                 (s, false)
             } else {
                 (s, true)
             };
-            let s = s.strip_prefix("0x").unwrap();
-            let hash_index = s.find('#').unwrap();
-            let precision = u64::from_str(&s[hash_index + 1..]).unwrap();
+            let s = s.strip_prefix("0x")?;
+            let hash_index = s.find('#')?;
+            let precision = u64::from_str(&s[hash_index + 1..]).ok()?;
+            // A valid string has at least one hex digit for every 4 bits of precision, so this
+            // rejects inputs that would allocate a huge significand for a short string
+            if precision == 0 || precision > (u64::exact_from(hash_index) + 1) << 2 {
+                return None;
+            }
+            let (s, exp_offset) = reduce_exponent_in_hex_string(s, hash_index)?;
+            let hash_index = s.find('#')?;
             let mut options = FromSciStringOptions::default();
             options.set_base(16);
-            let x = if let Some((alt_s, exp_offset)) = reduce_exponent_in_hex_string(s) {
-                let hash_index = alt_s.find('#').unwrap();
-                Float::from_rational_prec_round(
-                    Rational::from_sci_string_with_options(&alt_s[..hash_index], options).unwrap(),
-                    precision,
-                    Exact,
-                )
-                .0 << exp_offset
-            } else {
-                Float::from_rational_prec_round(
-                    Rational::from_sci_string_with_options(&s[..hash_index], options).unwrap(),
-                    precision,
-                    Exact,
-                )
-                .0
-            };
-            if sign { x } else { -x }
+            let (x, o) = Float::from_rational_prec_round(
+                Rational::from_sci_string_with_options(&s[..hash_index], options)?,
+                precision,
+                Floor,
+            );
+            if o != Equal {
+                return None;
+            }
+            // Shifting would silently overflow to infinity or underflow to zero, so reject
+            // exponents that no `Float` can have
+            if let Some(exponent) = x.get_exponent() {
+                let exponent = i64::from(exponent) + i64::from(exp_offset);
+                if exponent < i64::from(Float::MIN_EXPONENT)
+                    || exponent > i64::from(Float::MAX_EXPONENT)
+                {
+                    return None;
+                }
+            }
+            let x = x << exp_offset;
+            Some(if sign { x } else { -x })
         }
     }
 }
 
 impl FromStringBase for Float {
+    /// Converts a string in base 16, in the format produced by formatting a
+    /// [`ComparableFloat`](crate::ComparableFloat) with `{:#x}`, to a [`Float`].
+    ///
+    /// If the string is not in that format, or does not represent a [`Float`] exactly at the given
+    /// precision, `None` is returned.
+    ///
+    /// # Panics
+    /// Panics if `base` is not 16.
     fn from_string_base(base: u8, s: &str) -> Option<Self> {
         assert_eq!(base, 16);
-        Some(from_hex_string(s))
+        from_hex_string(s)
     }
 }
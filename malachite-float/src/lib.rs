@@ -87,12 +87,16 @@ extern crate alloc;
 
 #[macro_use]
 extern crate malachite_base;
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
 
 #[cfg(feature = "test_build")]
 extern crate itertools;
 
 #[cfg(feature = "test_build")]
 use crate::InnerFloat::Finite;
+use alloc::string::String;
 use core::ops::Deref;
 #[cfg(feature = "test_build")]
 use malachite_base::num::arithmetic::traits::DivisibleByPowerOf2;
@@ -155,7 +159,14 @@ use malachite_nz::platform::Limb;
 ///
 /// `Float`s whose precision is 64 bits or less can be represented without any memory allocation.
 /// (Unless Malachite is compiled with `32_bit_limbs`, in which case the limit is 32).
+///
+/// With the `enable_serde` feature, a `Float` is serialized as the string produced by formatting a
+/// [`ComparableFloat`] with `{:#x}`, which keeps the sign, exponent, precision, and significand, so
+/// that deserialization recovers an identical `Float`. For a smaller binary encoding, wrap the
+/// `Float` in a [`CompactFloat`](conversion::serde::CompactFloat).
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "SerdeFloat", into = "SerdeFloat"))]
 pub struct Float(pub(crate) InnerFloat);
 
 // We want to limit the visibility of the `NaN`, `Zero`, `Infinity`, and `Finite` constructors to
@@ -177,6 +188,10 @@ pub(crate) enum InnerFloat {
     },
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub(crate) struct SerdeFloat(String);
+
 #[inline]
 pub(crate) fn significand_bits(significand: &Natural) -> u64 {
     significand.limb_count() << Limb::LOG_WIDTH
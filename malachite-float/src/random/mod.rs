@@ -14,20 +14,26 @@ use malachite_base::bools::random::{
 use malachite_base::iterators::{WithSpecialValues, with_special_values};
 use malachite_base::num::arithmetic::traits::{NegModPowerOf2, PowerOf2};
 use malachite_base::num::basic::integers::PrimitiveInt;
-use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Zero,
+};
 use malachite_base::num::logic::traits::{LowMask, SignificantBits};
 use malachite_base::num::random::geometric::{
     GeometricRandomNaturalValues, GeometricRandomSignedRange,
     geometric_random_signed_inclusive_range,
 };
 use malachite_base::random::Seed;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
 use malachite_nz::natural::Natural;
 use malachite_nz::natural::random::{
     RandomNaturals, StripedRandomNaturalInclusiveRange, StripedRandomNaturals,
-    UniformRandomNaturalRange, random_positive_naturals, striped_random_natural_inclusive_range,
-    striped_random_positive_naturals, uniform_random_natural_inclusive_range,
+    UniformRandomNaturalRange, get_random_natural_with_bits_with_rng, random_positive_naturals,
+    striped_random_natural_inclusive_range, striped_random_positive_naturals,
+    uniform_random_natural_inclusive_range,
 };
 use malachite_nz::platform::Limb;
+use rand::Rng;
+use rand::distributions::Distribution;
 
 /// Generates random positive finite [`Float`]s.
 ///
@@ -1652,3 +1658,147 @@ pub fn striped_random_floats(
         },
     )
 }
+
+/// A distribution that samples uniformly random [`Float`]s from $[0, 1]$ with a given precision.
+///
+/// Conceptually, a real number is chosen uniformly from $[0, 1)$ and then rounded to the given
+/// precision using the given [`RoundingMode`]. This means that every [`Float`] $x$ with the given
+/// precision is sampled with probability proportional to the width of the interval of reals that
+/// round to $x$. Rounding up may produce 1. 0 is only produced on underflow, which happens with
+/// probability $2^{-2^{30}}$.
+///
+/// This is the same distribution that MPFR's `mpfr_urandom` samples from, though the random bits
+/// are consumed differently.
+///
+/// This implements [`rand`]'s [`Distribution`] trait, so it may be used with any [`Rng`].
+///
+/// Sampling takes expected $O(n)$ time and additional memory, where $n$ is the precision.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct UniformFloat {
+    prec: u64,
+    rm: RoundingMode,
+}
+
+impl UniformFloat {
+    /// Creates a distribution that samples uniformly random [`Float`]s from $[0, 1]$ with a given
+    /// precision, rounding to the nearest representable value.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use itertools::Itertools;
+    /// use malachite_base::random::EXAMPLE_SEED;
+    /// use malachite_float::random::UniformFloat;
+    /// use malachite_float::ComparableFloat;
+    /// use rand::Rng;
+    ///
+    /// // The number after the '#' is the precision.
+    /// assert_eq!(
+    ///     EXAMPLE_SEED
+    ///         .get_rng()
+    ///         .sample_iter(UniformFloat::new(10))
+    ///         .take(5)
+    ///         .map(|f| ComparableFloat(f).to_string())
+    ///         .collect_vec(),
+    ///     &["0.585#10", "0.4492#10", "0.612#10", "0.984#10", "0.652#10"]
+    /// );
+    /// ```
+    #[inline]
+    pub fn new(prec: u64) -> UniformFloat {
+        UniformFloat::new_round(prec, Nearest)
+    }
+
+    /// Creates a distribution that samples uniformly random [`Float`]s from $[0, 1]$ with a given
+    /// precision, rounding using the given [`RoundingMode`].
+    ///
+    /// Since the real number being rounded is almost surely not exactly representable, `Floor` and
+    /// `Down` are equivalent, and so are `Ceiling` and `Up`.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use itertools::Itertools;
+    /// use malachite_base::random::EXAMPLE_SEED;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::random::UniformFloat;
+    /// use malachite_float::ComparableFloat;
+    /// use rand::Rng;
+    ///
+    /// // The number after the '#' is the precision.
+    /// assert_eq!(
+    ///     EXAMPLE_SEED
+    ///         .get_rng()
+    ///         .sample_iter(UniformFloat::new_round(10, Floor))
+    ///         .take(5)
+    ///         .map(|f| ComparableFloat(f).to_string())
+    ///         .collect_vec(),
+    ///     &["0.585#10", "0.614#10", "0.978#10", "0.852#10", "0.9795#10"]
+    /// );
+    /// ```
+    pub fn new_round(prec: u64, rm: RoundingMode) -> UniformFloat {
+        assert_ne!(prec, 0);
+        assert_ne!(rm, Exact, "Inexact UniformFloat sampling");
+        UniformFloat { prec, rm }
+    }
+}
+
+impl Distribution<Float> for UniformFloat {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Float {
+        // The sampled real is in [2^(-z - 1), 2^(-z)), where z is the number of leading zeros of
+        // its binary expansion.
+        let max_zeros = -i64::from(Float::MIN_EXPONENT);
+        let mut zeros = 0i64;
+        loop {
+            let word = rng.next_u64();
+            if word != 0 {
+                zeros += i64::from(word.leading_zeros());
+                break;
+            }
+            zeros += 64;
+            if zeros > max_zeros {
+                break;
+            }
+        }
+        if zeros > max_zeros {
+            return match self.rm {
+                Ceiling | Up => Float::min_positive_value_prec(self.prec),
+                _ => Float::ZERO,
+            };
+        }
+        let mut exponent = i32::try_from(-zeros).unwrap();
+        // The leading bit of the significand is 1, and the remaining bits are independent of the
+        // bits that were used to find the exponent.
+        let mut significand = get_random_natural_with_bits_with_rng(rng, self.prec);
+        // The remaining bits are almost surely not all zero, so the rounding direction only
+        // depends on the first of them.
+        let round_up = match self.rm {
+            Nearest => rng.next_u32() >> 31 != 0,
+            Ceiling | Up => true,
+            Floor | Down => false,
+            Exact => unreachable!(),
+        };
+        if round_up {
+            significand += Natural::ONE;
+            if significand.significant_bits() > self.prec {
+                significand >>= 1;
+                exponent += 1;
+            }
+        }
+        Float(Finite {
+            sign: true,
+            exponent,
+            precision: self.prec,
+            significand: significand << self.prec.neg_mod_power_of_2(Limb::LOG_WIDTH),
+        })
+    }
+}
@@ -6,14 +6,32 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
+pub mod acos;
 pub mod add;
+pub mod asin;
+pub mod atan;
+pub mod atan2;
+pub mod cos;
 pub mod div;
+pub mod exp;
+pub mod exp10;
+pub mod exp2;
+pub mod expm1;
+pub mod ln;
+pub mod ln1p;
+pub mod log10;
+pub mod log2;
 pub mod mul;
 pub mod power_of_2;
 pub mod reciprocal;
+pub mod reciprocal_sqrt;
 pub mod shl;
 pub mod shl_round;
 pub mod shr;
 pub mod shr_round;
+pub mod sin;
+pub mod sin_cos;
+pub mod sqrt;
 pub mod square;
 pub mod sub;
+pub mod tan;
@@ -0,0 +1,22 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering;
+use malachite_base::num::conversion::traits::ExactFrom;
+use rug::float::{Constant, Round};
+use rug::ops::AssignRound;
+
+pub fn rug_catalan_prec_round(prec: u64, rm: Round) -> (rug::Float, Ordering) {
+    let mut x = rug::Float::new(u32::exact_from(prec));
+    let o = x.assign_round(Constant::Catalan, rm);
+    (x, o)
+}
+
+pub fn rug_catalan_prec(prec: u64) -> (rug::Float, Ordering) {
+    rug_catalan_prec_round(prec, Round::Nearest)
+}
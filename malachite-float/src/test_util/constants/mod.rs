@@ -6,5 +6,10 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
+pub mod catalan;
+pub mod e;
+pub mod euler_gamma;
+pub mod ln_2;
+pub mod pi;
 pub mod prime_constant;
 pub mod thue_morse_constant;
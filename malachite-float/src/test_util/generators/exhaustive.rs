@@ -19,7 +19,7 @@ use crate::{Float, significand_bits};
 use alloc::vec::IntoIter;
 use core::cmp::Ordering::*;
 use malachite_base::iterators::bit_distributor::BitDistributorOutputType;
-use malachite_base::num::arithmetic::traits::{IsPowerOf2, Reciprocal, Square};
+use malachite_base::num::arithmetic::traits::{CheckedSqrt, IsPowerOf2, Reciprocal, Square};
 use malachite_base::num::basic::floats::PrimitiveFloat;
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::signeds::PrimitiveSigned;
@@ -882,6 +882,20 @@ pub fn exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_8()
     )
 }
 
+pub fn exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_9()
+-> It<(Float, Float, u64, RoundingMode)> {
+    reshape_3_1_to_4(Box::new(lex_pairs(
+        exhaustive_triples_xxy_custom_output(
+            exhaustive_floats(),
+            exhaustive_positive_primitive_ints::<u64>(),
+            BitDistributorOutputType::normal(1),
+            BitDistributorOutputType::normal(1),
+            BitDistributorOutputType::tiny(),
+        ),
+        exhaustive_rounding_modes().filter(|&rm| rm != Exact),
+    )))
+}
+
 // -- (Float, Float, Rational) --
 
 pub fn exhaustive_float_float_rational_triple_gen() -> It<(Float, Float, Rational)> {
@@ -1304,6 +1318,14 @@ pub fn exhaustive_float_float_rounding_mode_triple_gen_var_32() -> It<(Float, Fl
     )
 }
 
+pub fn exhaustive_float_float_rounding_mode_triple_gen_var_33() -> It<(Float, Float, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(lex_pairs(
+        exhaustive_pairs_from_single(exhaustive_floats()),
+        exhaustive_rounding_modes().filter(|&rm| rm != Exact),
+    )))
+}
+
 // -- (Float, Integer) --
 
 pub fn exhaustive_float_integer_pair_gen() -> It<(Float, Integer)> {
@@ -1747,6 +1769,68 @@ pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_11() -> It<(Float,
     ))
 }
 
+pub fn sqrt_prec_round_valid(x: &Float, prec: u64, rm: RoundingMode) -> bool {
+    if rm != Exact || *x <= 0u32 || !x.is_finite() {
+        true
+    } else if let Some(sqrt) = Rational::exact_from(x).checked_sqrt() {
+        if let Some(min_prec) = Float::exact_from(sqrt).get_min_prec() {
+            prec >= min_prec
+        } else {
+            true
+        }
+    } else {
+        false
+    }
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_12() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| sqrt_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn reciprocal_sqrt_prec_round_valid(x: &Float, prec: u64, rm: RoundingMode) -> bool {
+    if rm != Exact || *x <= 0u32 || !x.is_finite() {
+        true
+    } else if let Some(sqrt) = Rational::exact_from(x).checked_sqrt() {
+        if let Ok(reciprocal_sqrt) = Float::try_from(sqrt.reciprocal()) {
+            if let Some(min_prec) = reciprocal_sqrt.get_min_prec() {
+                prec >= min_prec
+            } else {
+                true
+            }
+        } else {
+            false
+        }
+    } else {
+        false
+    }
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_13() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| reciprocal_sqrt_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_14() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(lex_pairs(
+        exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+        exhaustive_rounding_modes().filter(|&rm| rm != Exact),
+    )))
+}
+
 // -- (Float, Rational) --
 
 pub fn exhaustive_float_rational_pair_gen() -> It<(Float, Rational)> {
@@ -2552,6 +2636,37 @@ pub fn exhaustive_float_rounding_mode_pair_gen_var_22() -> It<(Float, RoundingMo
     )
 }
 
+pub(crate) fn sqrt_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    x.get_prec()
+        .is_none_or(|prec| sqrt_prec_round_valid(x, prec, rm))
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_23() -> It<(Float, RoundingMode)> {
+    Box::new(
+        lex_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(f, rm)| sqrt_round_valid(f, *rm)),
+    )
+}
+
+pub(crate) fn reciprocal_sqrt_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    x.get_prec()
+        .is_none_or(|prec| reciprocal_sqrt_prec_round_valid(x, prec, rm))
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_24() -> It<(Float, RoundingMode)> {
+    Box::new(
+        lex_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(f, rm)| reciprocal_sqrt_round_valid(f, *rm)),
+    )
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_25() -> It<(Float, RoundingMode)> {
+    Box::new(lex_pairs(
+        exhaustive_floats(),
+        exhaustive_rounding_modes().filter(|&rm| rm != Exact),
+    ))
+}
+
 // -- (Integer, PrimitiveUnsigned, RoundingMode) --
 
 // vars 1 through 2 are in malachite-nz.
@@ -568,6 +568,39 @@ pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_8()
     )
 }
 
+// All `(Float, Float, u64, RoundingMode)`, excluding those with `Exact`.
+pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_9()
+-> Generator<(Float, Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_9,
+        &random_float_float_unsigned_rounding_mode_quadruple_gen_var_9,
+        &special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_9,
+    )
+}
+
+pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_9_rm() -> Generator<(
+    (rug::Float, rug::Float, u64, rug::float::Round),
+    (Float, Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_float_anything_rounding_mode_quadruple_rm(
+                exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_9(),
+            )
+        },
+        &|config| {
+            float_float_anything_rounding_mode_quadruple_rm(
+                random_float_float_unsigned_rounding_mode_quadruple_gen_var_9(config),
+            )
+        },
+        &|config| {
+            float_float_anything_rounding_mode_quadruple_rm(
+                special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_9(config),
+            )
+        },
+    )
+}
+
 // -- (Float, Float, Rational) --
 
 pub fn float_float_rational_triple_gen() -> Generator<(Float, Float, Rational)> {
@@ -989,6 +1022,38 @@ pub fn float_float_rounding_mode_triple_gen_var_32() -> Generator<(Float, Float,
     )
 }
 
+// All `(Float, Float, RoundingMode)`, excluding those with `Exact`.
+pub fn float_float_rounding_mode_triple_gen_var_33() -> Generator<(Float, Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_rounding_mode_triple_gen_var_33,
+        &random_float_float_rounding_mode_triple_gen_var_33,
+        &special_random_float_float_rounding_mode_triple_gen_var_33,
+    )
+}
+
+pub fn float_float_rounding_mode_triple_gen_var_33_rm() -> Generator<(
+    (rug::Float, rug::Float, rug::float::Round),
+    (Float, Float, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_float_rounding_mode_triple_rm(
+                exhaustive_float_float_rounding_mode_triple_gen_var_33(),
+            )
+        },
+        &|config| {
+            float_float_rounding_mode_triple_rm(random_float_float_rounding_mode_triple_gen_var_33(
+                config,
+            ))
+        },
+        &|config| {
+            float_float_rounding_mode_triple_rm(
+                special_random_float_float_rounding_mode_triple_gen_var_33(config),
+            )
+        },
+    )
+}
+
 // -- (Float, Integer) --
 
 pub fn float_integer_pair_gen() -> Generator<(Float, Integer)> {
@@ -1629,6 +1694,102 @@ pub fn float_unsigned_rounding_mode_triple_gen_var_11() -> Generator<(Float, u64
     )
 }
 
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float.sqrt_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_12() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_12,
+        &random_float_unsigned_rounding_mode_triple_gen_var_12,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_12,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_12_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_t_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_12(),
+            )
+        },
+        &|config| {
+            float_t_rounding_mode_triple_rm(random_float_unsigned_rounding_mode_triple_gen_var_12(
+                config,
+            ))
+        },
+        &|config| {
+            float_t_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_12(config),
+            )
+        },
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float.reciprocal_sqrt_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_13() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_13,
+        &random_float_unsigned_rounding_mode_triple_gen_var_13,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_13,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_13_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_t_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_13(),
+            )
+        },
+        &|config| {
+            float_t_rounding_mode_triple_rm(random_float_unsigned_rounding_mode_triple_gen_var_13(
+                config,
+            ))
+        },
+        &|config| {
+            float_t_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_13(config),
+            )
+        },
+    )
+}
+
+// All `(Float, u64, RoundingMode)`, excluding those with `Exact`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_14() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_14,
+        &random_float_unsigned_rounding_mode_triple_gen_var_14,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_14,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_14_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_t_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_14(),
+            )
+        },
+        &|config| {
+            float_t_rounding_mode_triple_rm(random_float_unsigned_rounding_mode_triple_gen_var_14(
+                config,
+            ))
+        },
+        &|config| {
+            float_t_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_14(config),
+            )
+        },
+    )
+}
+
 // -- (Float, Rational) --
 
 pub fn float_rational_pair_gen() -> Generator<(Float, Rational)> {
@@ -2307,6 +2468,66 @@ pub fn float_rounding_mode_pair_gen_var_22() -> Generator<(Float, RoundingMode)>
     )
 }
 
+// All `(Float, RoundingMode)` that are valid inputs to `sqrt_round`.
+pub fn float_rounding_mode_pair_gen_var_23() -> Generator<(Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_rounding_mode_pair_gen_var_23,
+        &random_float_rounding_mode_pair_gen_var_23,
+        &special_random_float_rounding_mode_pair_gen_var_23,
+    )
+}
+
+pub fn float_rounding_mode_pair_gen_var_23_rm()
+-> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
+    Generator::new(
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_23()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_23(config)),
+        &|config| {
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_23(config))
+        },
+    )
+}
+
+// All `(Float, RoundingMode)` that are valid inputs to `reciprocal_sqrt_round`.
+pub fn float_rounding_mode_pair_gen_var_24() -> Generator<(Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_rounding_mode_pair_gen_var_24,
+        &random_float_rounding_mode_pair_gen_var_24,
+        &special_random_float_rounding_mode_pair_gen_var_24,
+    )
+}
+
+pub fn float_rounding_mode_pair_gen_var_24_rm()
+-> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
+    Generator::new(
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_24()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_24(config)),
+        &|config| {
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_24(config))
+        },
+    )
+}
+
+// All `(Float, RoundingMode)`, excluding those with `Exact`.
+pub fn float_rounding_mode_pair_gen_var_25() -> Generator<(Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_rounding_mode_pair_gen_var_25,
+        &random_float_rounding_mode_pair_gen_var_25,
+        &special_random_float_rounding_mode_pair_gen_var_25,
+    )
+}
+
+pub fn float_rounding_mode_pair_gen_var_25_rm()
+-> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
+    Generator::new(
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_25()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_25(config)),
+        &|config| {
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_25(config))
+        },
+    )
+}
+
 // -- (Integer, PrimitiveUnsigned, RoundingMode) --
 
 // vars 1 and 2 are in malachite-nz.
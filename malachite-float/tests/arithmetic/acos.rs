@@ -0,0 +1,1338 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{Acos, AcosAssign};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, One, OneHalf, Two, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::{
+    rounding_mode_gen, unsigned_gen_var_11, unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::test_util::arithmetic::acos::{
+    rug_acos, rug_acos_prec, rug_acos_prec_round, rug_acos_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_gen_var_6, float_gen_var_7, float_gen_var_8, float_gen_var_11,
+    float_rounding_mode_pair_gen_var_25, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_14,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_nz::platform::Limb;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_acos() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let acos = x.clone().acos();
+        assert!(acos.is_valid());
+
+        assert_eq!(acos.to_string(), out);
+        assert_eq!(to_hex_string(&acos), out_hex);
+
+        let acos_alt = (&x).acos();
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+
+        let mut acos_alt = x.clone();
+        acos_alt.acos_assign();
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+
+        let rug_acos = rug_acos(&rug::Float::exact_from(&x));
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acos)),
+            ComparableFloatRef(&acos),
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "NaN", "NaN");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "2.0", "0x2.0#1");
+    test("-0.0", "-0x0.0", "2.0", "0x2.0#1");
+    test("1.0", "0x1.0#1", "0.0", "0x0.0");
+    test("-1.0", "-0x1.0#1", "4.0", "0x4.0#1");
+    test("2.0", "0x2.0#1", "NaN", "NaN");
+    test("-2.0", "-0x2.0#1", "NaN", "NaN");
+    test("0.5", "0x0.8#1", "1.0", "0x1.0#1");
+    test("-0.5", "-0x0.8#1", "2.0", "0x2.0#1");
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        "1.4706289056333368",
+        "0x1.787b22ce3f590#53",
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        "1.6709637479564565",
+        "0x1.abc447ba464a1#53",
+    );
+    test("0.99998", "0x0.ffff#16", "0.0055243", "0x0.016a0a#16");
+    test("-0.99998", "-0x0.ffff#16", "3.13605", "0x3.22d4#16");
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        "0.7853981633974483",
+        "0x0.c90fdaa22168c0#53",
+    );
+    test("9.0e-13", "0x1.0E-10#1", "2.0", "0x2.0#1");
+    test("-9.0e-13", "-0x1.0E-10#1", "2.0", "0x2.0#1");
+    test("123.0", "0x7b.0#7", "NaN", "NaN");
+}
+
+#[test]
+fn test_acos_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (acos, o) = x.clone().acos_prec(prec);
+        assert!(acos.is_valid());
+
+        assert_eq!(acos.to_string(), out);
+        assert_eq!(to_hex_string(&acos), out_hex);
+        assert_eq!(o, o_out);
+
+        let (acos_alt, o_alt) = x.acos_prec_ref(prec);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut acos_alt = x.clone();
+        let o_alt = acos_alt.acos_prec_assign(prec);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_acos, rug_o) = rug_acos_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acos)),
+            ComparableFloatRef(&acos),
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 100, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 100, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, "2.0", "0x2.0#1", Greater);
+    test("0.0", "0x0.0", 10, "1.57", "0x1.920#10", Less);
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        "1.57079632679489661923132169164",
+        "0x1.921fb54442d18469898cc5170#100",
+        Less,
+    );
+    test("-0.0", "-0x0.0", 1, "2.0", "0x2.0#1", Greater);
+    test("-0.0", "-0x0.0", 10, "1.57", "0x1.920#10", Less);
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        "1.57079632679489661923132169164",
+        "0x1.921fb54442d18469898cc5170#100",
+        Less,
+    );
+    test("1.0", "0x1.0#1", 1, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 100, "0.0", "0x0.0", Equal);
+    test("-1.0", "-0x1.0#1", 1, "4.0", "0x4.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 10, "3.141", "0x3.24#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "3.141592653589793238462643383279",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Less,
+    );
+    test("2.0", "0x2.0#1", 1, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", 10, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", 100, "NaN", "NaN", Equal);
+    test("0.5", "0x0.8#1", 1, "1.0", "0x1.0#1", Less);
+    test("0.5", "0x0.8#1", 10, "1.047", "0x1.0c0#10", Less);
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "1.047197551196597746154214461094",
+        "0x1.0c152382d73658465bb32e0f6#100",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        10,
+        "1.471",
+        "0x1.788#10",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        100,
+        "1.470628905633336817306717896588",
+        "0x1.787b22ce3f59004264d1e6790#100",
+        Less,
+    );
+    test("0.99998", "0x0.ffff#16", 1, "0.004", "0x0.01#1", Less);
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        10,
+        "0.005524",
+        "0x0.016a0#10",
+        Less,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        100,
+        "0.005524278752518771079740470854067",
+        "0x0.016a0a0493786493270c3d21afe#100",
+        Greater,
+    );
+    test("9.0e-13", "0x1.0E-10#1", 1, "2.0", "0x2.0#1", Greater);
+    test("9.0e-13", "0x1.0E-10#1", 10, "1.57", "0x1.920#10", Less);
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        100,
+        "1.570796326793987124529548763402",
+        "0x1.921fb54441d18469898cc5170#100",
+        Less,
+    );
+}
+
+#[test]
+fn acos_prec_fail() {
+    assert_panic!(Float::NAN.acos_prec(0));
+    assert_panic!(Float::NAN.acos_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.acos_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_acos_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (acos, o) = x.clone().acos_round(rm);
+        assert!(acos.is_valid());
+
+        assert_eq!(acos.to_string(), out);
+        assert_eq!(to_hex_string(&acos), out_hex);
+        assert_eq!(o, o_out);
+
+        let (acos_alt, o_alt) = x.acos_round_ref(rm);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut acos_alt = x.clone();
+        let o_alt = acos_alt.acos_round_assign(rm);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_acos, rug_o) = rug_acos_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_acos)),
+                ComparableFloatRef(&acos),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "1.0", "0x1.0#1", Less);
+    test("-0.0", "-0x0.0", Floor, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", Floor, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Ceiling, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Down, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Up, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Nearest, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Exact, "0.0", "0x0.0", Equal);
+    test("-1.0", "-0x1.0#1", Floor, "2.0", "0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "4.0", "0x4.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "2.0", "0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", Up, "4.0", "0x4.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Nearest, "4.0", "0x4.0#1", Greater);
+    test("2.0", "0x2.0#1", Floor, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", Ceiling, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", Down, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", Up, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", Nearest, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#1", Floor, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#1", Ceiling, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#1", Down, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#1", Up, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#1", Nearest, "NaN", "NaN", Equal);
+    test("0.5", "0x0.8#1", Floor, "1.0", "0x1.0#1", Less);
+    test("0.5", "0x0.8#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("0.5", "0x0.8#1", Down, "1.0", "0x1.0#1", Less);
+    test("0.5", "0x0.8#1", Up, "2.0", "0x2.0#1", Greater);
+    test("0.5", "0x0.8#1", Nearest, "1.0", "0x1.0#1", Less);
+    test("-0.5", "-0x0.8#1", Floor, "2.0", "0x2.0#1", Less);
+    test("-0.5", "-0x0.8#1", Ceiling, "4.0", "0x4.0#1", Greater);
+    test("-0.5", "-0x0.8#1", Down, "2.0", "0x2.0#1", Less);
+    test("-0.5", "-0x0.8#1", Up, "4.0", "0x4.0#1", Greater);
+    test("-0.5", "-0x0.8#1", Nearest, "2.0", "0x2.0#1", Less);
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        Floor,
+        "1.4706289056333368",
+        "0x1.787b22ce3f590#53",
+        Less,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        Ceiling,
+        "1.470628905633337",
+        "0x1.787b22ce3f591#53",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        Down,
+        "1.4706289056333368",
+        "0x1.787b22ce3f590#53",
+        Less,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        Up,
+        "1.470628905633337",
+        "0x1.787b22ce3f591#53",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        Nearest,
+        "1.4706289056333368",
+        "0x1.787b22ce3f590#53",
+        Less,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        Floor,
+        "1.6709637479564563",
+        "0x1.abc447ba464a0#53",
+        Less,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        Ceiling,
+        "1.6709637479564565",
+        "0x1.abc447ba464a1#53",
+        Greater,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        Down,
+        "1.6709637479564563",
+        "0x1.abc447ba464a0#53",
+        Less,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        Up,
+        "1.6709637479564565",
+        "0x1.abc447ba464a1#53",
+        Greater,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        Nearest,
+        "1.6709637479564565",
+        "0x1.abc447ba464a1#53",
+        Greater,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        Floor,
+        "0.0055243",
+        "0x0.016a0a#16",
+        Less,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        Ceiling,
+        "0.0055244",
+        "0x0.016a0c#16",
+        Greater,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        Down,
+        "0.0055243",
+        "0x0.016a0a#16",
+        Less,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        Up,
+        "0.0055244",
+        "0x0.016a0c#16",
+        Greater,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        Nearest,
+        "0.0055243",
+        "0x0.016a0a#16",
+        Less,
+    );
+    test(
+        "-0.99998",
+        "-0x0.ffff#16",
+        Floor,
+        "3.13605",
+        "0x3.22d4#16",
+        Less,
+    );
+    test(
+        "-0.99998",
+        "-0x0.ffff#16",
+        Ceiling,
+        "3.1361",
+        "0x3.22d8#16",
+        Greater,
+    );
+    test(
+        "-0.99998",
+        "-0x0.ffff#16",
+        Down,
+        "3.13605",
+        "0x3.22d4#16",
+        Less,
+    );
+    test(
+        "-0.99998",
+        "-0x0.ffff#16",
+        Up,
+        "3.1361",
+        "0x3.22d8#16",
+        Greater,
+    );
+    test(
+        "-0.99998",
+        "-0x0.ffff#16",
+        Nearest,
+        "3.13605",
+        "0x3.22d4#16",
+        Less,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        Floor,
+        "0.7853981633974482",
+        "0x0.c90fdaa22168b8#53",
+        Less,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        Ceiling,
+        "0.7853981633974483",
+        "0x0.c90fdaa22168c0#53",
+        Greater,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        Down,
+        "0.7853981633974482",
+        "0x0.c90fdaa22168b8#53",
+        Less,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        Up,
+        "0.7853981633974483",
+        "0x0.c90fdaa22168c0#53",
+        Greater,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        Nearest,
+        "0.7853981633974483",
+        "0x0.c90fdaa22168c0#53",
+        Greater,
+    );
+    test("9.0e-13", "0x1.0E-10#1", Floor, "1.0", "0x1.0#1", Less);
+    test("9.0e-13", "0x1.0E-10#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("9.0e-13", "0x1.0E-10#1", Down, "1.0", "0x1.0#1", Less);
+    test("9.0e-13", "0x1.0E-10#1", Up, "2.0", "0x2.0#1", Greater);
+    test("9.0e-13", "0x1.0E-10#1", Nearest, "2.0", "0x2.0#1", Greater);
+    test("-9.0e-13", "-0x1.0E-10#1", Floor, "1.0", "0x1.0#1", Less);
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test("-9.0e-13", "-0x1.0E-10#1", Down, "1.0", "0x1.0#1", Less);
+    test("-9.0e-13", "-0x1.0E-10#1", Up, "2.0", "0x2.0#1", Greater);
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", Floor, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", Ceiling, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", Down, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", Up, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", Nearest, "NaN", "NaN", Equal);
+}
+
+#[test]
+fn acos_round_fail() {
+    assert_panic!(Float::ONE_HALF.acos_round(Exact));
+    assert_panic!(Float::ONE_HALF.acos_round_ref(Exact));
+    assert_panic!({
+        let mut x = Float::ONE_HALF;
+        x.acos_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_acos_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (acos, o) = x.clone().acos_prec_round(prec, rm);
+        assert!(acos.is_valid());
+
+        assert_eq!(acos.to_string(), out);
+        assert_eq!(to_hex_string(&acos), out_hex);
+        assert_eq!(o, o_out);
+
+        let (acos_alt, o_alt) = x.acos_prec_round_ref(prec, rm);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut acos_alt = x.clone();
+        let o_alt = acos_alt.acos_prec_round_assign(prec, rm);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_acos, rug_o) = rug_acos_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_acos)),
+                ComparableFloatRef(&acos),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("-0.0", "-0x0.0", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", 1, Exact, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Exact, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 100, Exact, "0.0", "0x0.0", Equal);
+    test("0.5", "0x0.8#1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("0.5", "0x0.8#1", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("0.5", "0x0.8#1", 1, Down, "1.0", "0x1.0#1", Less);
+    test("0.5", "0x0.8#1", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("0.5", "0x0.8#1", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("0.5", "0x0.8#1", 10, Floor, "1.047", "0x1.0c0#10", Less);
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        Ceiling,
+        "1.049",
+        "0x1.0c8#10",
+        Greater,
+    );
+    test("0.5", "0x0.8#1", 10, Down, "1.047", "0x1.0c0#10", Less);
+    test("0.5", "0x0.8#1", 10, Up, "1.049", "0x1.0c8#10", Greater);
+    test("0.5", "0x0.8#1", 10, Nearest, "1.047", "0x1.0c0#10", Less);
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        Floor,
+        "1.047197551196597746154214461092",
+        "0x1.0c152382d73658465bb32e0f4#100",
+        Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        Ceiling,
+        "1.047197551196597746154214461094",
+        "0x1.0c152382d73658465bb32e0f6#100",
+        Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        Down,
+        "1.047197551196597746154214461092",
+        "0x1.0c152382d73658465bb32e0f4#100",
+        Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        Up,
+        "1.047197551196597746154214461094",
+        "0x1.0c152382d73658465bb32e0f6#100",
+        Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        Nearest,
+        "1.047197551196597746154214461094",
+        "0x1.0c152382d73658465bb32e0f6#100",
+        Greater,
+    );
+    test("-0.5", "-0x0.8#1", 1, Floor, "2.0", "0x2.0#1", Less);
+    test("-0.5", "-0x0.8#1", 1, Ceiling, "4.0", "0x4.0#1", Greater);
+    test("-0.5", "-0x0.8#1", 1, Down, "2.0", "0x2.0#1", Less);
+    test("-0.5", "-0x0.8#1", 1, Up, "4.0", "0x4.0#1", Greater);
+    test("-0.5", "-0x0.8#1", 1, Nearest, "2.0", "0x2.0#1", Less);
+    test("-0.5", "-0x0.8#1", 10, Floor, "2.094", "0x2.18#10", Less);
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        Ceiling,
+        "2.098",
+        "0x2.19#10",
+        Greater,
+    );
+    test("-0.5", "-0x0.8#1", 10, Down, "2.094", "0x2.18#10", Less);
+    test("-0.5", "-0x0.8#1", 10, Up, "2.098", "0x2.19#10", Greater);
+    test("-0.5", "-0x0.8#1", 10, Nearest, "2.094", "0x2.18#10", Less);
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        100,
+        Floor,
+        "2.094395102393195492308428922184",
+        "0x2.182a4705ae6cb08cb7665c1e8#100",
+        Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        100,
+        Ceiling,
+        "2.094395102393195492308428922187",
+        "0x2.182a4705ae6cb08cb7665c1ec#100",
+        Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        100,
+        Down,
+        "2.094395102393195492308428922184",
+        "0x2.182a4705ae6cb08cb7665c1e8#100",
+        Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        100,
+        Up,
+        "2.094395102393195492308428922187",
+        "0x2.182a4705ae6cb08cb7665c1ec#100",
+        Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        100,
+        Nearest,
+        "2.094395102393195492308428922187",
+        "0x2.182a4705ae6cb08cb7665c1ec#100",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        10,
+        Floor,
+        "1.469",
+        "0x1.780#10",
+        Less,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        10,
+        Ceiling,
+        "1.471",
+        "0x1.788#10",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        10,
+        Down,
+        "1.469",
+        "0x1.780#10",
+        Less,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        10,
+        Up,
+        "1.471",
+        "0x1.788#10",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        10,
+        Nearest,
+        "1.471",
+        "0x1.788#10",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        100,
+        Floor,
+        "1.470628905633336817306717896588",
+        "0x1.787b22ce3f59004264d1e6790#100",
+        Less,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        100,
+        Ceiling,
+        "1.470628905633336817306717896589",
+        "0x1.787b22ce3f59004264d1e6792#100",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        100,
+        Down,
+        "1.470628905633336817306717896588",
+        "0x1.787b22ce3f59004264d1e6790#100",
+        Less,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        100,
+        Up,
+        "1.470628905633336817306717896589",
+        "0x1.787b22ce3f59004264d1e6792#100",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        100,
+        Nearest,
+        "1.470628905633336817306717896588",
+        "0x1.787b22ce3f59004264d1e6790#100",
+        Less,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        1,
+        Floor,
+        "0.004",
+        "0x0.01#1",
+        Less,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        1,
+        Ceiling,
+        "0.008",
+        "0x0.02#1",
+        Greater,
+    );
+    test("0.99998", "0x0.ffff#16", 1, Down, "0.004", "0x0.01#1", Less);
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        1,
+        Up,
+        "0.008",
+        "0x0.02#1",
+        Greater,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        1,
+        Nearest,
+        "0.004",
+        "0x0.01#1",
+        Less,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        10,
+        Floor,
+        "0.005524",
+        "0x0.016a0#10",
+        Less,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        10,
+        Ceiling,
+        "0.00553",
+        "0x0.016a8#10",
+        Greater,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        10,
+        Down,
+        "0.005524",
+        "0x0.016a0#10",
+        Less,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        10,
+        Up,
+        "0.00553",
+        "0x0.016a8#10",
+        Greater,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        10,
+        Nearest,
+        "0.005524",
+        "0x0.016a0#10",
+        Less,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        100,
+        Floor,
+        "0.00552427875251877107974047085406",
+        "0x0.016a0a0493786493270c3d21afc#100",
+        Less,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        100,
+        Ceiling,
+        "0.005524278752518771079740470854067",
+        "0x0.016a0a0493786493270c3d21afe#100",
+        Greater,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        100,
+        Down,
+        "0.00552427875251877107974047085406",
+        "0x0.016a0a0493786493270c3d21afc#100",
+        Less,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        100,
+        Up,
+        "0.005524278752518771079740470854067",
+        "0x0.016a0a0493786493270c3d21afe#100",
+        Greater,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        100,
+        Nearest,
+        "0.005524278752518771079740470854067",
+        "0x0.016a0a0493786493270c3d21afe#100",
+        Greater,
+    );
+}
+
+#[test]
+fn acos_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).acos_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).acos_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.acos_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(Float::ONE_HALF.acos_prec_round(1, Exact));
+    assert_panic!(Float::ONE_HALF.acos_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = Float::ONE_HALF;
+        x.acos_prec_round_assign(1, Exact)
+    });
+}
+
+fn acos_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (acos, o) = x.clone().acos_prec_round(prec, rm);
+    assert!(acos.is_valid());
+    let (acos_alt, o_alt) = x.acos_prec_round_ref(prec, rm);
+    assert!(acos_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.acos_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&acos));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_acos, rug_o) = rug_acos_prec_round(&rug::Float::exact_from(&x), prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acos)),
+            ComparableFloatRef(&acos),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if acos.is_normal() {
+        assert_eq!(acos.get_prec(), Some(prec));
+    }
+
+    if x > 1u32 || x < -1i32 {
+        assert!(acos.is_nan());
+    } else if !x.is_nan() {
+        assert!(acos >= 0u32);
+    }
+
+    match rm {
+        Floor => assert_ne!(o, Greater),
+        Ceiling => assert_ne!(o, Less),
+        Down => {
+            if acos.is_sign_positive() {
+                assert_ne!(o, Greater);
+            } else {
+                assert_ne!(o, Less);
+            }
+        }
+        Up => {
+            if acos.is_sign_positive() {
+                assert_ne!(o, Less);
+            } else {
+                assert_ne!(o, Greater);
+            }
+        }
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.acos_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(acos.clone()));
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.acos_prec_round(prec, Exact));
+    }
+}
+
+#[test]
+fn acos_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties(|(x, prec, rm)| {
+        acos_prec_round_properties_helper(x, prec, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties_with_config(
+        &config,
+        |(x, prec, rm)| {
+            acos_prec_round_properties_helper(x, prec, rm);
+        },
+    );
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (acos, o) = Float::NAN.acos_prec_round(prec, rm);
+        assert!(acos.is_nan());
+        assert_eq!(o, Equal);
+        let (acos, o) = Float::INFINITY.acos_prec_round(prec, rm);
+        assert!(acos.is_nan());
+        assert_eq!(o, Equal);
+        let (acos, o) = Float::NEGATIVE_INFINITY.acos_prec_round(prec, rm);
+        assert!(acos.is_nan());
+        assert_eq!(o, Equal);
+        let (acos, o) = Float::ONE.acos_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(acos), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (acos, o) = Float::TWO.acos_prec_round(prec, rm);
+        assert!(acos.is_nan());
+        assert_eq!(o, Equal);
+        let (acos, o) = (-Float::TWO).acos_prec_round(prec, rm);
+        assert!(acos.is_nan());
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn acos_prec_properties_helper(x: Float, prec: u64) {
+    let (acos, o) = x.clone().acos_prec(prec);
+    assert!(acos.is_valid());
+    let (acos_alt, o_alt) = x.acos_prec_ref(prec);
+    assert!(acos_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.acos_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&acos));
+    assert_eq!(o_alt, o);
+
+    let (rug_acos, rug_o) = rug_acos_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_acos)),
+        ComparableFloatRef(&acos),
+    );
+    assert_eq!(rug_o, o);
+
+    let (acos_alt, o_alt) = x.acos_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+    assert_eq!(o_alt, o);
+
+    if acos.is_normal() {
+        assert_eq!(acos.get_prec(), Some(prec));
+    }
+
+    if x > 1u32 || x < -1i32 {
+        assert!(acos.is_nan());
+    } else if !x.is_nan() {
+        assert!(acos >= 0u32);
+    }
+}
+
+#[test]
+fn acos_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        acos_prec_properties_helper(x, prec);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_pair_gen_var_1().test_properties_with_config(&config, |(x, prec)| {
+        acos_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (acos, o) = Float::NAN.acos_prec(prec);
+        assert!(acos.is_nan());
+        assert_eq!(o, Equal);
+        let (acos, o) = Float::INFINITY.acos_prec(prec);
+        assert!(acos.is_nan());
+        assert_eq!(o, Equal);
+        let (acos, o) = Float::NEGATIVE_INFINITY.acos_prec(prec);
+        assert!(acos.is_nan());
+        assert_eq!(o, Equal);
+        let (acos, o) = Float::ONE.acos_prec(prec);
+        assert_eq!(ComparableFloat(acos), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (acos, o) = Float::TWO.acos_prec(prec);
+        assert!(acos.is_nan());
+        assert_eq!(o, Equal);
+        let (acos, o) = (-Float::TWO).acos_prec(prec);
+        assert!(acos.is_nan());
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn acos_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (acos, o) = x.clone().acos_round(rm);
+    assert!(acos.is_valid());
+    let (acos_alt, o_alt) = x.acos_round_ref(rm);
+    assert!(acos_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.acos_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&acos));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_acos, rug_o) = rug_acos_round(&rug::Float::exact_from(&x), rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acos)),
+            ComparableFloatRef(&acos),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    let (acos_alt, o_alt) = x.acos_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+    assert_eq!(o_alt, o);
+
+    if acos.is_normal() {
+        assert_eq!(acos.get_prec(), Some(x.significant_bits()));
+    }
+
+    if x > 1u32 || x < -1i32 {
+        assert!(acos.is_nan());
+    } else if !x.is_nan() {
+        assert!(acos >= 0u32);
+    }
+
+    match rm {
+        Floor => assert_ne!(o, Greater),
+        Ceiling => assert_ne!(o, Less),
+        Down => {
+            if acos.is_sign_positive() {
+                assert_ne!(o, Greater);
+            } else {
+                assert_ne!(o, Less);
+            }
+        }
+        Up => {
+            if acos.is_sign_positive() {
+                assert_ne!(o, Less);
+            } else {
+                assert_ne!(o, Greater);
+            }
+        }
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+}
+
+#[test]
+fn acos_round_properties() {
+    float_rounding_mode_pair_gen_var_25().test_properties(|(x, rm)| {
+        acos_round_properties_helper(x, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_rounding_mode_pair_gen_var_25().test_properties_with_config(&config, |(x, rm)| {
+        acos_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (acos, o) = Float::NAN.acos_round(rm);
+        assert!(acos.is_nan());
+        assert_eq!(o, Equal);
+        let (acos, o) = Float::INFINITY.acos_round(rm);
+        assert!(acos.is_nan());
+        assert_eq!(o, Equal);
+        let (acos, o) = Float::NEGATIVE_INFINITY.acos_round(rm);
+        assert!(acos.is_nan());
+        assert_eq!(o, Equal);
+        let (acos, o) = Float::ONE.acos_round(rm);
+        assert_eq!(ComparableFloat(acos), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (acos, o) = Float::TWO.acos_round(rm);
+        assert!(acos.is_nan());
+        assert_eq!(o, Equal);
+        let (acos, o) = (-Float::TWO).acos_round(rm);
+        assert!(acos.is_nan());
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn acos_properties_helper(x: Float) {
+    let acos = x.clone().acos();
+    assert!(acos.is_valid());
+
+    let acos_alt = (&x).acos();
+    assert!(acos_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+
+    let mut x_alt = x.clone();
+    x_alt.acos_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&acos));
+
+    let rug_acos = rug_acos(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_acos)),
+        ComparableFloatRef(&acos),
+    );
+
+    assert_eq!(
+        ComparableFloatRef(&x.acos_round_ref(Nearest).0),
+        ComparableFloatRef(&acos)
+    );
+
+    if x > 1u32 || x < -1i32 {
+        assert!(acos.is_nan());
+    } else if !x.is_nan() {
+        assert!(acos >= 0u32);
+    }
+}
+
+#[test]
+fn acos_properties() {
+    float_gen().test_properties(|x| {
+        acos_properties_helper(x);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_gen().test_properties_with_config(&config, |x| {
+        acos_properties_helper(x);
+    });
+
+    float_gen_var_6().test_properties(|x| {
+        acos_properties_helper(x);
+    });
+
+    float_gen_var_7().test_properties(|x| {
+        acos_properties_helper(x);
+    });
+
+    float_gen_var_8().test_properties(|x| {
+        acos_properties_helper(x);
+    });
+
+    float_gen_var_11().test_properties(|x| {
+        acos_properties_helper(x);
+    });
+}
@@ -0,0 +1,1428 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{Asin, AsinAssign};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, OneHalf, Two, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::{
+    rounding_mode_gen, unsigned_gen_var_11, unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::test_util::arithmetic::asin::{
+    rug_asin, rug_asin_prec, rug_asin_prec_round, rug_asin_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_gen_var_6, float_gen_var_7, float_gen_var_8, float_gen_var_11,
+    float_rounding_mode_pair_gen_var_25, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_14,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_nz::platform::Limb;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_asin() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let asin = x.clone().asin();
+        assert!(asin.is_valid());
+
+        assert_eq!(asin.to_string(), out);
+        assert_eq!(to_hex_string(&asin), out_hex);
+
+        let asin_alt = (&x).asin();
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+
+        let mut asin_alt = x.clone();
+        asin_alt.asin_assign();
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+
+        let rug_asin = rug_asin(&rug::Float::exact_from(&x));
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asin)),
+            ComparableFloatRef(&asin),
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "NaN", "NaN");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "2.0", "0x2.0#1");
+    test("-1.0", "-0x1.0#1", "-2.0", "-0x2.0#1");
+    test("2.0", "0x2.0#1", "NaN", "NaN");
+    test("-2.0", "-0x2.0#1", "NaN", "NaN");
+    test("0.5", "0x0.8#1", "0.5", "0x0.8#1");
+    test("-0.5", "-0x0.8#1", "-0.5", "-0x0.8#1");
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        "0.1001674211615598",
+        "0x0.19a49276037884#53",
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        "-0.1001674211615598",
+        "-0x0.19a49276037884#53",
+    );
+    test("0.99998", "0x0.ffff#16", "1.56528", "0x1.90b6#16");
+    test("-0.99998", "-0x0.ffff#16", "-1.56528", "-0x1.90b6#16");
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        "0.7853981633974484",
+        "0x0.c90fdaa22168c8#53",
+    );
+    test("9.0e-13", "0x1.0E-10#1", "9.0e-13", "0x1.0E-10#1");
+    test("-9.0e-13", "-0x1.0E-10#1", "-9.0e-13", "-0x1.0E-10#1");
+    test("123.0", "0x7b.0#7", "NaN", "NaN");
+}
+
+#[test]
+fn test_asin_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (asin, o) = x.clone().asin_prec(prec);
+        assert!(asin.is_valid());
+
+        assert_eq!(asin.to_string(), out);
+        assert_eq!(to_hex_string(&asin), out_hex);
+        assert_eq!(o, o_out);
+
+        let (asin_alt, o_alt) = x.asin_prec_ref(prec);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut asin_alt = x.clone();
+        let o_alt = asin_alt.asin_prec_assign(prec);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_asin, rug_o) = rug_asin_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asin)),
+            ComparableFloatRef(&asin),
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 100, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 100, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 100, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 100, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, "1.57", "0x1.920#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.57079632679489661923132169164",
+        "0x1.921fb54442d18469898cc5170#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", 10, "-1.57", "-0x1.920#10", Greater);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-1.57079632679489661923132169164",
+        "-0x1.921fb54442d18469898cc5170#100",
+        Greater,
+    );
+    test("2.0", "0x2.0#1", 1, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", 10, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", 100, "NaN", "NaN", Equal);
+    test("0.5", "0x0.8#1", 1, "0.5", "0x0.8#1", Less);
+    test("0.5", "0x0.8#1", 10, "0.523", "0x0.860#10", Less);
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "0.523598775598298873077107230547",
+        "0x0.860a91c16b9b2c232dd99707b#100",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        1,
+        "0.1",
+        "0x0.2#1",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        10,
+        "0.1002",
+        "0x0.19a8#10",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        100,
+        "0.1001674211615598019246037950514",
+        "0x0.19a492760378842724bade9df4#100",
+        Less,
+    );
+    test("0.99998", "0x0.ffff#16", 1, "2.0", "0x2.0#1", Greater);
+    test("0.99998", "0x0.ffff#16", 10, "1.564", "0x1.908#10", Less);
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        100,
+        "1.565272048042377848151581220786",
+        "0x1.90b5ab3faf591fd6628087f56#100",
+        Greater,
+    );
+    test("9.0e-13", "0x1.0E-10#1", 1, "9.0e-13", "0x1.0E-10#1", Less);
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        "9.09e-13",
+        "0x1.000E-10#10",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        100,
+        "9.09494701772928237915039187886e-13",
+        "0x1.000000000000000000002aaaaE-10#100",
+        Less,
+    );
+}
+
+#[test]
+fn asin_prec_fail() {
+    assert_panic!(Float::NAN.asin_prec(0));
+    assert_panic!(Float::NAN.asin_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.asin_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_asin_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (asin, o) = x.clone().asin_round(rm);
+        assert!(asin.is_valid());
+
+        assert_eq!(asin.to_string(), out);
+        assert_eq!(to_hex_string(&asin), out_hex);
+        assert_eq!(o, o_out);
+
+        let (asin_alt, o_alt) = x.asin_round_ref(rm);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut asin_alt = x.clone();
+        let o_alt = asin_alt.asin_round_assign(rm);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_asin, rug_o) = rug_asin_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_asin)),
+                ComparableFloatRef(&asin),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Down, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Up, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Nearest, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", Floor, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", Down, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", Up, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "2.0", "0x2.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Floor, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "-1.0", "-0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "-1.0", "-0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Up, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", Nearest, "-2.0", "-0x2.0#1", Less);
+    test("2.0", "0x2.0#1", Floor, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", Ceiling, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", Down, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", Up, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", Nearest, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#1", Floor, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#1", Ceiling, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#1", Down, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#1", Up, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#1", Nearest, "NaN", "NaN", Equal);
+    test("0.5", "0x0.8#1", Floor, "0.5", "0x0.8#1", Less);
+    test("0.5", "0x0.8#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("0.5", "0x0.8#1", Down, "0.5", "0x0.8#1", Less);
+    test("0.5", "0x0.8#1", Up, "1.0", "0x1.0#1", Greater);
+    test("0.5", "0x0.8#1", Nearest, "0.5", "0x0.8#1", Less);
+    test("-0.5", "-0x0.8#1", Floor, "-1.0", "-0x1.0#1", Less);
+    test("-0.5", "-0x0.8#1", Ceiling, "-0.5", "-0x0.8#1", Greater);
+    test("-0.5", "-0x0.8#1", Down, "-0.5", "-0x0.8#1", Greater);
+    test("-0.5", "-0x0.8#1", Up, "-1.0", "-0x1.0#1", Less);
+    test("-0.5", "-0x0.8#1", Nearest, "-0.5", "-0x0.8#1", Greater);
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        Floor,
+        "0.1001674211615598",
+        "0x0.19a49276037884#53",
+        Less,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        Ceiling,
+        "0.10016742116155981",
+        "0x0.19a49276037885#53",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        Down,
+        "0.1001674211615598",
+        "0x0.19a49276037884#53",
+        Less,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        Up,
+        "0.10016742116155981",
+        "0x0.19a49276037885#53",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        Nearest,
+        "0.1001674211615598",
+        "0x0.19a49276037884#53",
+        Less,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        Floor,
+        "-0.10016742116155981",
+        "-0x0.19a49276037885#53",
+        Less,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        Ceiling,
+        "-0.1001674211615598",
+        "-0x0.19a49276037884#53",
+        Greater,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        Down,
+        "-0.1001674211615598",
+        "-0x0.19a49276037884#53",
+        Greater,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        Up,
+        "-0.10016742116155981",
+        "-0x0.19a49276037885#53",
+        Less,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        Nearest,
+        "-0.1001674211615598",
+        "-0x0.19a49276037884#53",
+        Greater,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        Floor,
+        "1.56525",
+        "0x1.90b4#16",
+        Less,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        Ceiling,
+        "1.56528",
+        "0x1.90b6#16",
+        Greater,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        Down,
+        "1.56525",
+        "0x1.90b4#16",
+        Less,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        Up,
+        "1.56528",
+        "0x1.90b6#16",
+        Greater,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        Nearest,
+        "1.56528",
+        "0x1.90b6#16",
+        Greater,
+    );
+    test(
+        "-0.99998",
+        "-0x0.ffff#16",
+        Floor,
+        "-1.56528",
+        "-0x1.90b6#16",
+        Less,
+    );
+    test(
+        "-0.99998",
+        "-0x0.ffff#16",
+        Ceiling,
+        "-1.56525",
+        "-0x1.90b4#16",
+        Greater,
+    );
+    test(
+        "-0.99998",
+        "-0x0.ffff#16",
+        Down,
+        "-1.56525",
+        "-0x1.90b4#16",
+        Greater,
+    );
+    test(
+        "-0.99998",
+        "-0x0.ffff#16",
+        Up,
+        "-1.56528",
+        "-0x1.90b6#16",
+        Less,
+    );
+    test(
+        "-0.99998",
+        "-0x0.ffff#16",
+        Nearest,
+        "-1.56528",
+        "-0x1.90b6#16",
+        Less,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        Floor,
+        "0.7853981633974483",
+        "0x0.c90fdaa22168c0#53",
+        Less,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        Ceiling,
+        "0.7853981633974484",
+        "0x0.c90fdaa22168c8#53",
+        Greater,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        Down,
+        "0.7853981633974483",
+        "0x0.c90fdaa22168c0#53",
+        Less,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        Up,
+        "0.7853981633974484",
+        "0x0.c90fdaa22168c8#53",
+        Greater,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        Nearest,
+        "0.7853981633974484",
+        "0x0.c90fdaa22168c8#53",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Floor,
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Ceiling,
+        "2.0e-12",
+        "0x2.0E-10#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Down,
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Up,
+        "2.0e-12",
+        "0x2.0E-10#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Nearest,
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Less,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Floor,
+        "-2.0e-12",
+        "-0x2.0E-10#1",
+        Less,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Ceiling,
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Greater,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Down,
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Greater,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Up,
+        "-2.0e-12",
+        "-0x2.0E-10#1",
+        Less,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Nearest,
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", Floor, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", Ceiling, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", Down, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", Up, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", Nearest, "NaN", "NaN", Equal);
+}
+
+#[test]
+fn asin_round_fail() {
+    assert_panic!(Float::ONE_HALF.asin_round(Exact));
+    assert_panic!(Float::ONE_HALF.asin_round_ref(Exact));
+    assert_panic!({
+        let mut x = Float::ONE_HALF;
+        x.asin_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_asin_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (asin, o) = x.clone().asin_prec_round(prec, rm);
+        assert!(asin.is_valid());
+
+        assert_eq!(asin.to_string(), out);
+        assert_eq!(to_hex_string(&asin), out_hex);
+        assert_eq!(o, o_out);
+
+        let (asin_alt, o_alt) = x.asin_prec_round_ref(prec, rm);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut asin_alt = x.clone();
+        let o_alt = asin_alt.asin_prec_round_assign(prec, rm);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_asin, rug_o) = rug_asin_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_asin)),
+                ComparableFloatRef(&asin),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, Floor, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Exact, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, Exact, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 100, Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Exact, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Exact, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 100, Exact, "-0.0", "-0x0.0", Equal);
+    test("0.5", "0x0.8#1", 1, Floor, "0.5", "0x0.8#1", Less);
+    test("0.5", "0x0.8#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("0.5", "0x0.8#1", 1, Down, "0.5", "0x0.8#1", Less);
+    test("0.5", "0x0.8#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("0.5", "0x0.8#1", 1, Nearest, "0.5", "0x0.8#1", Less);
+    test("0.5", "0x0.8#1", 10, Floor, "0.523", "0x0.860#10", Less);
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        Ceiling,
+        "0.524",
+        "0x0.864#10",
+        Greater,
+    );
+    test("0.5", "0x0.8#1", 10, Down, "0.523", "0x0.860#10", Less);
+    test("0.5", "0x0.8#1", 10, Up, "0.524", "0x0.864#10", Greater);
+    test("0.5", "0x0.8#1", 10, Nearest, "0.523", "0x0.860#10", Less);
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        Floor,
+        "0.523598775598298873077107230546",
+        "0x0.860a91c16b9b2c232dd99707a#100",
+        Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        Ceiling,
+        "0.523598775598298873077107230547",
+        "0x0.860a91c16b9b2c232dd99707b#100",
+        Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        Down,
+        "0.523598775598298873077107230546",
+        "0x0.860a91c16b9b2c232dd99707a#100",
+        Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        Up,
+        "0.523598775598298873077107230547",
+        "0x0.860a91c16b9b2c232dd99707b#100",
+        Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        Nearest,
+        "0.523598775598298873077107230547",
+        "0x0.860a91c16b9b2c232dd99707b#100",
+        Greater,
+    );
+    test("-0.5", "-0x0.8#1", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("-0.5", "-0x0.8#1", 1, Ceiling, "-0.5", "-0x0.8#1", Greater);
+    test("-0.5", "-0x0.8#1", 1, Down, "-0.5", "-0x0.8#1", Greater);
+    test("-0.5", "-0x0.8#1", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("-0.5", "-0x0.8#1", 1, Nearest, "-0.5", "-0x0.8#1", Greater);
+    test("-0.5", "-0x0.8#1", 10, Floor, "-0.524", "-0x0.864#10", Less);
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        Ceiling,
+        "-0.523",
+        "-0x0.860#10",
+        Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        Down,
+        "-0.523",
+        "-0x0.860#10",
+        Greater,
+    );
+    test("-0.5", "-0x0.8#1", 10, Up, "-0.524", "-0x0.864#10", Less);
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        Nearest,
+        "-0.523",
+        "-0x0.860#10",
+        Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        100,
+        Floor,
+        "-0.523598775598298873077107230547",
+        "-0x0.860a91c16b9b2c232dd99707b#100",
+        Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        100,
+        Ceiling,
+        "-0.523598775598298873077107230546",
+        "-0x0.860a91c16b9b2c232dd99707a#100",
+        Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        100,
+        Down,
+        "-0.523598775598298873077107230546",
+        "-0x0.860a91c16b9b2c232dd99707a#100",
+        Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        100,
+        Up,
+        "-0.523598775598298873077107230547",
+        "-0x0.860a91c16b9b2c232dd99707b#100",
+        Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        100,
+        Nearest,
+        "-0.523598775598298873077107230547",
+        "-0x0.860a91c16b9b2c232dd99707b#100",
+        Less,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        1,
+        Floor,
+        "0.06",
+        "0x0.1#1",
+        Less,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        1,
+        Ceiling,
+        "0.1",
+        "0x0.2#1",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        1,
+        Down,
+        "0.06",
+        "0x0.1#1",
+        Less,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        1,
+        Up,
+        "0.1",
+        "0x0.2#1",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        1,
+        Nearest,
+        "0.1",
+        "0x0.2#1",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        10,
+        Floor,
+        "0.1001",
+        "0x0.19a0#10",
+        Less,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        10,
+        Ceiling,
+        "0.1002",
+        "0x0.19a8#10",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        10,
+        Down,
+        "0.1001",
+        "0x0.19a0#10",
+        Less,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        10,
+        Up,
+        "0.1002",
+        "0x0.19a8#10",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        10,
+        Nearest,
+        "0.1002",
+        "0x0.19a8#10",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        100,
+        Floor,
+        "0.1001674211615598019246037950514",
+        "0x0.19a492760378842724bade9df4#100",
+        Less,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        100,
+        Ceiling,
+        "0.1001674211615598019246037950515",
+        "0x0.19a492760378842724bade9df6#100",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        100,
+        Down,
+        "0.1001674211615598019246037950514",
+        "0x0.19a492760378842724bade9df4#100",
+        Less,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        100,
+        Up,
+        "0.1001674211615598019246037950515",
+        "0x0.19a492760378842724bade9df6#100",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        100,
+        Nearest,
+        "0.1001674211615598019246037950514",
+        "0x0.19a492760378842724bade9df4#100",
+        Less,
+    );
+    test("0.99998", "0x0.ffff#16", 1, Floor, "1.0", "0x1.0#1", Less);
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test("0.99998", "0x0.ffff#16", 1, Down, "1.0", "0x1.0#1", Less);
+    test("0.99998", "0x0.ffff#16", 1, Up, "2.0", "0x2.0#1", Greater);
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        10,
+        Floor,
+        "1.564",
+        "0x1.908#10",
+        Less,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        10,
+        Ceiling,
+        "1.566",
+        "0x1.910#10",
+        Greater,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        10,
+        Down,
+        "1.564",
+        "0x1.908#10",
+        Less,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        10,
+        Up,
+        "1.566",
+        "0x1.910#10",
+        Greater,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        10,
+        Nearest,
+        "1.564",
+        "0x1.908#10",
+        Less,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        100,
+        Floor,
+        "1.565272048042377848151581220785",
+        "0x1.90b5ab3faf591fd6628087f54#100",
+        Less,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        100,
+        Ceiling,
+        "1.565272048042377848151581220786",
+        "0x1.90b5ab3faf591fd6628087f56#100",
+        Greater,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        100,
+        Down,
+        "1.565272048042377848151581220785",
+        "0x1.90b5ab3faf591fd6628087f54#100",
+        Less,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        100,
+        Up,
+        "1.565272048042377848151581220786",
+        "0x1.90b5ab3faf591fd6628087f56#100",
+        Greater,
+    );
+    test(
+        "0.99998",
+        "0x0.ffff#16",
+        100,
+        Nearest,
+        "1.565272048042377848151581220786",
+        "0x1.90b5ab3faf591fd6628087f56#100",
+        Greater,
+    );
+}
+
+#[test]
+fn asin_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).asin_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).asin_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.asin_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(Float::ONE_HALF.asin_prec_round(1, Exact));
+    assert_panic!(Float::ONE_HALF.asin_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = Float::ONE_HALF;
+        x.asin_prec_round_assign(1, Exact)
+    });
+}
+
+fn asin_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (asin, o) = x.clone().asin_prec_round(prec, rm);
+    assert!(asin.is_valid());
+    let (asin_alt, o_alt) = x.asin_prec_round_ref(prec, rm);
+    assert!(asin_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.asin_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&asin));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_asin, rug_o) = rug_asin_prec_round(&rug::Float::exact_from(&x), prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asin)),
+            ComparableFloatRef(&asin),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if asin.is_normal() {
+        assert_eq!(asin.get_prec(), Some(prec));
+    }
+
+    if x > 1u32 || x < -1i32 {
+        assert!(asin.is_nan());
+    } else if x > 0u32 {
+        assert!(asin >= 0u32);
+    } else if x < 0u32 {
+        assert!(asin <= 0u32);
+    }
+
+    let (neg_asin, neg_o) = (-&x).asin_prec_round(prec, -rm);
+    assert_eq!(ComparableFloat(-neg_asin), ComparableFloat(asin.clone()));
+    assert_eq!(neg_o.reverse(), o);
+
+    match rm {
+        Floor => assert_ne!(o, Greater),
+        Ceiling => assert_ne!(o, Less),
+        Down => {
+            if asin.is_sign_positive() {
+                assert_ne!(o, Greater);
+            } else {
+                assert_ne!(o, Less);
+            }
+        }
+        Up => {
+            if asin.is_sign_positive() {
+                assert_ne!(o, Less);
+            } else {
+                assert_ne!(o, Greater);
+            }
+        }
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.asin_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(asin.clone()));
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.asin_prec_round(prec, Exact));
+    }
+}
+
+#[test]
+fn asin_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties(|(x, prec, rm)| {
+        asin_prec_round_properties_helper(x, prec, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties_with_config(
+        &config,
+        |(x, prec, rm)| {
+            asin_prec_round_properties_helper(x, prec, rm);
+        },
+    );
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (asin, o) = Float::NAN.asin_prec_round(prec, rm);
+        assert!(asin.is_nan());
+        assert_eq!(o, Equal);
+        let (asin, o) = Float::INFINITY.asin_prec_round(prec, rm);
+        assert!(asin.is_nan());
+        assert_eq!(o, Equal);
+        let (asin, o) = Float::NEGATIVE_INFINITY.asin_prec_round(prec, rm);
+        assert!(asin.is_nan());
+        assert_eq!(o, Equal);
+        let (asin, o) = Float::ZERO.asin_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(asin), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (asin, o) = Float::NEGATIVE_ZERO.asin_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(asin), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+        let (asin, o) = Float::TWO.asin_prec_round(prec, rm);
+        assert!(asin.is_nan());
+        assert_eq!(o, Equal);
+        let (asin, o) = (-Float::TWO).asin_prec_round(prec, rm);
+        assert!(asin.is_nan());
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn asin_prec_properties_helper(x: Float, prec: u64) {
+    let (asin, o) = x.clone().asin_prec(prec);
+    assert!(asin.is_valid());
+    let (asin_alt, o_alt) = x.asin_prec_ref(prec);
+    assert!(asin_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.asin_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&asin));
+    assert_eq!(o_alt, o);
+
+    let (rug_asin, rug_o) = rug_asin_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_asin)),
+        ComparableFloatRef(&asin),
+    );
+    assert_eq!(rug_o, o);
+
+    let (asin_alt, o_alt) = x.asin_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+    assert_eq!(o_alt, o);
+
+    if asin.is_normal() {
+        assert_eq!(asin.get_prec(), Some(prec));
+    }
+
+    if x > 1u32 || x < -1i32 {
+        assert!(asin.is_nan());
+    } else if x > 0u32 {
+        assert!(asin >= 0u32);
+    } else if x < 0u32 {
+        assert!(asin <= 0u32);
+    }
+
+    let (neg_asin, neg_o) = (-&x).asin_prec(prec);
+    assert_eq!(ComparableFloat(-neg_asin), ComparableFloat(asin.clone()));
+    assert_eq!(neg_o.reverse(), o);
+}
+
+#[test]
+fn asin_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        asin_prec_properties_helper(x, prec);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_pair_gen_var_1().test_properties_with_config(&config, |(x, prec)| {
+        asin_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (asin, o) = Float::NAN.asin_prec(prec);
+        assert!(asin.is_nan());
+        assert_eq!(o, Equal);
+        let (asin, o) = Float::INFINITY.asin_prec(prec);
+        assert!(asin.is_nan());
+        assert_eq!(o, Equal);
+        let (asin, o) = Float::NEGATIVE_INFINITY.asin_prec(prec);
+        assert!(asin.is_nan());
+        assert_eq!(o, Equal);
+        let (asin, o) = Float::ZERO.asin_prec(prec);
+        assert_eq!(ComparableFloat(asin), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (asin, o) = Float::NEGATIVE_ZERO.asin_prec(prec);
+        assert_eq!(ComparableFloat(asin), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+        let (asin, o) = Float::TWO.asin_prec(prec);
+        assert!(asin.is_nan());
+        assert_eq!(o, Equal);
+        let (asin, o) = (-Float::TWO).asin_prec(prec);
+        assert!(asin.is_nan());
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn asin_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (asin, o) = x.clone().asin_round(rm);
+    assert!(asin.is_valid());
+    let (asin_alt, o_alt) = x.asin_round_ref(rm);
+    assert!(asin_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.asin_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&asin));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_asin, rug_o) = rug_asin_round(&rug::Float::exact_from(&x), rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asin)),
+            ComparableFloatRef(&asin),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    let (asin_alt, o_alt) = x.asin_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+    assert_eq!(o_alt, o);
+
+    if asin.is_normal() {
+        assert_eq!(asin.get_prec(), Some(x.significant_bits()));
+    }
+
+    if x > 1u32 || x < -1i32 {
+        assert!(asin.is_nan());
+    } else if x > 0u32 {
+        assert!(asin >= 0u32);
+    } else if x < 0u32 {
+        assert!(asin <= 0u32);
+    }
+
+    let (neg_asin, neg_o) = (-&x).asin_round(-rm);
+    assert_eq!(ComparableFloat(-neg_asin), ComparableFloat(asin.clone()));
+    assert_eq!(neg_o.reverse(), o);
+
+    match rm {
+        Floor => assert_ne!(o, Greater),
+        Ceiling => assert_ne!(o, Less),
+        Down => {
+            if asin.is_sign_positive() {
+                assert_ne!(o, Greater);
+            } else {
+                assert_ne!(o, Less);
+            }
+        }
+        Up => {
+            if asin.is_sign_positive() {
+                assert_ne!(o, Less);
+            } else {
+                assert_ne!(o, Greater);
+            }
+        }
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+}
+
+#[test]
+fn asin_round_properties() {
+    float_rounding_mode_pair_gen_var_25().test_properties(|(x, rm)| {
+        asin_round_properties_helper(x, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_rounding_mode_pair_gen_var_25().test_properties_with_config(&config, |(x, rm)| {
+        asin_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (asin, o) = Float::NAN.asin_round(rm);
+        assert!(asin.is_nan());
+        assert_eq!(o, Equal);
+        let (asin, o) = Float::INFINITY.asin_round(rm);
+        assert!(asin.is_nan());
+        assert_eq!(o, Equal);
+        let (asin, o) = Float::NEGATIVE_INFINITY.asin_round(rm);
+        assert!(asin.is_nan());
+        assert_eq!(o, Equal);
+        let (asin, o) = Float::ZERO.asin_round(rm);
+        assert_eq!(ComparableFloat(asin), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (asin, o) = Float::NEGATIVE_ZERO.asin_round(rm);
+        assert_eq!(ComparableFloat(asin), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+        let (asin, o) = Float::TWO.asin_round(rm);
+        assert!(asin.is_nan());
+        assert_eq!(o, Equal);
+        let (asin, o) = (-Float::TWO).asin_round(rm);
+        assert!(asin.is_nan());
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn asin_properties_helper(x: Float) {
+    let asin = x.clone().asin();
+    assert!(asin.is_valid());
+
+    let asin_alt = (&x).asin();
+    assert!(asin_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+
+    let mut x_alt = x.clone();
+    x_alt.asin_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&asin));
+
+    let rug_asin = rug_asin(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_asin)),
+        ComparableFloatRef(&asin),
+    );
+
+    assert_eq!(
+        ComparableFloatRef(&x.asin_round_ref(Nearest).0),
+        ComparableFloatRef(&asin)
+    );
+
+    if x > 1u32 || x < -1i32 {
+        assert!(asin.is_nan());
+    } else if x > 0u32 {
+        assert!(asin >= 0u32);
+    } else if x < 0u32 {
+        assert!(asin <= 0u32);
+    }
+}
+
+#[test]
+fn asin_properties() {
+    float_gen().test_properties(|x| {
+        asin_properties_helper(x);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_gen().test_properties_with_config(&config, |x| {
+        asin_properties_helper(x);
+    });
+
+    float_gen_var_6().test_properties(|x| {
+        asin_properties_helper(x);
+    });
+
+    float_gen_var_7().test_properties(|x| {
+        asin_properties_helper(x);
+    });
+
+    float_gen_var_8().test_properties(|x| {
+        asin_properties_helper(x);
+    });
+
+    float_gen_var_11().test_properties(|x| {
+        asin_properties_helper(x);
+    });
+}
@@ -0,0 +1,1338 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{Atan, AtanAssign};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{NaN, NegativeZero, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::{
+    rounding_mode_gen, unsigned_gen_var_11, unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::test_util::arithmetic::atan::{
+    rug_atan, rug_atan_prec, rug_atan_prec_round, rug_atan_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_gen_var_6, float_gen_var_7, float_gen_var_8, float_gen_var_11,
+    float_rounding_mode_pair_gen_var_25, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_14,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_nz::platform::Limb;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_atan() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let atan = x.clone().atan();
+        assert!(atan.is_valid());
+
+        assert_eq!(atan.to_string(), out);
+        assert_eq!(to_hex_string(&atan), out_hex);
+
+        let atan_alt = (&x).atan();
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+
+        let mut atan_alt = x.clone();
+        atan_alt.atan_assign();
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+
+        let rug_atan = rug_atan(&rug::Float::exact_from(&x));
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan)),
+            ComparableFloatRef(&atan),
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "2.0", "0x2.0#1");
+    test("-Infinity", "-Infinity", "-2.0", "-0x2.0#1");
+    test("0.0", "0x0.0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1");
+    test("-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1");
+    test("2.0", "0x2.0#1", "1.0", "0x1.0#1");
+    test("0.5", "0x0.8#1", "0.5", "0x0.8#1");
+    test("-0.5", "-0x0.8#1", "-0.5", "-0x0.8#1");
+    test("123.0", "0x7b.0#7", "1.56", "0x1.90#7");
+    test("-123.0", "-0x7b.0#7", "-1.56", "-0x1.90#7");
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "0.9553166181245093",
+        "0x0.f48fa1402b8ae8#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.2626272556789118",
+        "0x1.433b8a322ddd3#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-1.2626272556789118",
+        "-0x1.433b8a322ddd3#53",
+    );
+    test("9.0e-13", "0x1.0E-10#1", "9.0e-13", "0x1.0E-10#1");
+    test("-9.0e-13", "-0x1.0E-10#1", "-9.0e-13", "-0x1.0E-10#1");
+    test("1000.0", "0x3e8.0#10", "1.57", "0x1.920#10");
+    test("4.0e9", "0x1.0E+8#1", "2.0", "0x2.0#1");
+    test("-4.0e9", "-0x1.0E+8#1", "-2.0", "-0x2.0#1");
+}
+
+#[test]
+fn test_atan_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (atan, o) = x.clone().atan_prec(prec);
+        assert!(atan.is_valid());
+
+        assert_eq!(atan.to_string(), out);
+        assert_eq!(to_hex_string(&atan), out_hex);
+        assert_eq!(o, o_out);
+
+        let (atan_alt, o_alt) = x.atan_prec_ref(prec);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan_alt = x.clone();
+        let o_alt = atan_alt.atan_prec_assign(prec);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_atan, rug_o) = rug_atan_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan)),
+            ComparableFloatRef(&atan),
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "2.0", "0x2.0#1", Greater);
+    test("Infinity", "Infinity", 10, "1.57", "0x1.920#10", Less);
+    test(
+        "Infinity",
+        "Infinity",
+        100,
+        "1.57079632679489661923132169164",
+        "0x1.921fb54442d18469898cc5170#100",
+        Less,
+    );
+    test("-Infinity", "-Infinity", 1, "-2.0", "-0x2.0#1", Less);
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        "-1.57",
+        "-0x1.920#10",
+        Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        100,
+        "-1.57079632679489661923132169164",
+        "-0x1.921fb54442d18469898cc5170#100",
+        Greater,
+    );
+    test("0.0", "0x0.0", 1, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 100, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 100, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, "0.785", "0x0.c90#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 10, "-0.785", "-0x0.c90#10", Greater);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-0.78539816339744830961566084582",
+        "-0x0.c90fdaa22168c234c4c6628b8#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "1.262",
+        "0x1.430#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1.262627255678911672177612146816",
+        "0x1.433b8a322ddd29618168a21ca#100",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        1,
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        "9.09e-13",
+        "0x1.000E-10#10",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        100,
+        "9.09494701772928237915038811728e-13",
+        "0xf.fffffffffffffffffffaaaabE-11#100",
+        Greater,
+    );
+    test("4.0e9", "0x1.0E+8#1", 1, "2.0", "0x2.0#1", Greater);
+    test("4.0e9", "0x1.0E+8#1", 10, "1.57", "0x1.920#10", Less);
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        100,
+        "1.570796326562065975577452062738",
+        "0x1.921fb54342d18469898cc5176#100",
+        Greater,
+    );
+}
+
+#[test]
+fn atan_prec_fail() {
+    assert_panic!(Float::NAN.atan_prec(0));
+    assert_panic!(Float::NAN.atan_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.atan_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_atan_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (atan, o) = x.clone().atan_round(rm);
+        assert!(atan.is_valid());
+
+        assert_eq!(atan.to_string(), out);
+        assert_eq!(to_hex_string(&atan), out_hex);
+        assert_eq!(o, o_out);
+
+        let (atan_alt, o_alt) = x.atan_round_ref(rm);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan_alt = x.clone();
+        let o_alt = atan_alt.atan_round_assign(rm);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atan, rug_o) = rug_atan_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atan)),
+                ComparableFloatRef(&atan),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "1.0", "0x1.0#1", Less);
+    test("-Infinity", "-Infinity", Floor, "-2.0", "-0x2.0#1", Less);
+    test("0.0", "0x0.0", Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Down, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Up, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Nearest, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", Floor, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Down, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "-0.5", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "-0.5", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", Up, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", Nearest, "-1.0", "-0x1.0#1", Less);
+    test("2.0", "0x2.0#1", Floor, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("2.0", "0x2.0#1", Down, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", Up, "2.0", "0x2.0#1", Greater);
+    test("2.0", "0x2.0#1", Nearest, "1.0", "0x1.0#1", Less);
+    test("0.5", "0x0.8#1", Floor, "0.2", "0x0.4#1", Less);
+    test("0.5", "0x0.8#1", Ceiling, "0.5", "0x0.8#1", Greater);
+    test("0.5", "0x0.8#1", Down, "0.2", "0x0.4#1", Less);
+    test("0.5", "0x0.8#1", Up, "0.5", "0x0.8#1", Greater);
+    test("0.5", "0x0.8#1", Nearest, "0.5", "0x0.8#1", Greater);
+    test("-0.5", "-0x0.8#1", Floor, "-0.5", "-0x0.8#1", Less);
+    test("-0.5", "-0x0.8#1", Ceiling, "-0.2", "-0x0.4#1", Greater);
+    test("-0.5", "-0x0.8#1", Down, "-0.2", "-0x0.4#1", Greater);
+    test("-0.5", "-0x0.8#1", Up, "-0.5", "-0x0.8#1", Less);
+    test("-0.5", "-0x0.8#1", Nearest, "-0.5", "-0x0.8#1", Less);
+    test("123.0", "0x7b.0#7", Floor, "1.56", "0x1.90#7", Less);
+    test("123.0", "0x7b.0#7", Ceiling, "1.58", "0x1.94#7", Greater);
+    test("123.0", "0x7b.0#7", Down, "1.56", "0x1.90#7", Less);
+    test("123.0", "0x7b.0#7", Up, "1.58", "0x1.94#7", Greater);
+    test("123.0", "0x7b.0#7", Nearest, "1.56", "0x1.90#7", Less);
+    test("-123.0", "-0x7b.0#7", Floor, "-1.58", "-0x1.94#7", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Ceiling,
+        "-1.56",
+        "-0x1.90#7",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", Down, "-1.56", "-0x1.90#7", Greater);
+    test("-123.0", "-0x7b.0#7", Up, "-1.58", "-0x1.94#7", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Nearest,
+        "-1.56",
+        "-0x1.90#7",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        Floor,
+        "0.9553166181245093",
+        "0x0.f48fa1402b8ae8#53",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        Ceiling,
+        "0.9553166181245094",
+        "0x0.f48fa1402b8af0#53",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        Down,
+        "0.9553166181245093",
+        "0x0.f48fa1402b8ae8#53",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        Up,
+        "0.9553166181245094",
+        "0x0.f48fa1402b8af0#53",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        Nearest,
+        "0.9553166181245093",
+        "0x0.f48fa1402b8ae8#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Floor,
+        "1.2626272556789115",
+        "0x1.433b8a322ddd2#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ceiling,
+        "1.2626272556789118",
+        "0x1.433b8a322ddd3#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Down,
+        "1.2626272556789115",
+        "0x1.433b8a322ddd2#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Up,
+        "1.2626272556789118",
+        "0x1.433b8a322ddd3#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Nearest,
+        "1.2626272556789118",
+        "0x1.433b8a322ddd3#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Floor,
+        "-1.2626272556789118",
+        "-0x1.433b8a322ddd3#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Ceiling,
+        "-1.2626272556789115",
+        "-0x1.433b8a322ddd2#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Down,
+        "-1.2626272556789115",
+        "-0x1.433b8a322ddd2#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Up,
+        "-1.2626272556789118",
+        "-0x1.433b8a322ddd3#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Nearest,
+        "-1.2626272556789118",
+        "-0x1.433b8a322ddd3#53",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Floor,
+        "5.0e-13",
+        "0x8.0E-11#1",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Ceiling,
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Down,
+        "5.0e-13",
+        "0x8.0E-11#1",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Up,
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Nearest,
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Greater,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Floor,
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Less,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Ceiling,
+        "-5.0e-13",
+        "-0x8.0E-11#1",
+        Greater,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Down,
+        "-5.0e-13",
+        "-0x8.0E-11#1",
+        Greater,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Up,
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Less,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Nearest,
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Less,
+    );
+    test("1000.0", "0x3e8.0#10", Floor, "1.568", "0x1.918#10", Less);
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Ceiling,
+        "1.57",
+        "0x1.920#10",
+        Greater,
+    );
+    test("1000.0", "0x3e8.0#10", Down, "1.568", "0x1.918#10", Less);
+    test("1000.0", "0x3e8.0#10", Up, "1.57", "0x1.920#10", Greater);
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Nearest,
+        "1.57",
+        "0x1.920#10",
+        Greater,
+    );
+    test("4.0e9", "0x1.0E+8#1", Floor, "1.0", "0x1.0#1", Less);
+    test("4.0e9", "0x1.0E+8#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("4.0e9", "0x1.0E+8#1", Down, "1.0", "0x1.0#1", Less);
+    test("4.0e9", "0x1.0E+8#1", Up, "2.0", "0x2.0#1", Greater);
+    test("4.0e9", "0x1.0E+8#1", Nearest, "2.0", "0x2.0#1", Greater);
+    test("-4.0e9", "-0x1.0E+8#1", Floor, "-2.0", "-0x2.0#1", Less);
+    test(
+        "-4.0e9",
+        "-0x1.0E+8#1",
+        Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Greater,
+    );
+    test("-4.0e9", "-0x1.0E+8#1", Down, "-1.0", "-0x1.0#1", Greater);
+    test("-4.0e9", "-0x1.0E+8#1", Up, "-2.0", "-0x2.0#1", Less);
+    test("-4.0e9", "-0x1.0E+8#1", Nearest, "-2.0", "-0x2.0#1", Less);
+}
+
+#[test]
+fn atan_round_fail() {
+    const THREE: Float = Float::const_from_unsigned(3);
+    assert_panic!(THREE.atan_round(Exact));
+    assert_panic!(THREE.atan_round_ref(Exact));
+    assert_panic!({
+        let mut x = THREE;
+        x.atan_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_atan_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (atan, o) = x.clone().atan_prec_round(prec, rm);
+        assert!(atan.is_valid());
+
+        assert_eq!(atan.to_string(), out);
+        assert_eq!(to_hex_string(&atan), out_hex);
+        assert_eq!(o, o_out);
+
+        let (atan_alt, o_alt) = x.atan_prec_round_ref(prec, rm);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan_alt = x.clone();
+        let o_alt = atan_alt.atan_prec_round_assign(prec, rm);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atan, rug_o) = rug_atan_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atan)),
+                ComparableFloatRef(&atan),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("-Infinity", "-Infinity", 1, Floor, "-2.0", "-0x2.0#1", Less);
+    test("0.0", "0x0.0", 1, Floor, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Exact, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, Exact, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 100, Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Exact, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Exact, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 100, Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, Floor, "0.785", "0x0.c90#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.786",
+        "0x0.c94#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Down, "0.785", "0x0.c90#10", Less);
+    test("1.0", "0x1.0#1", 10, Up, "0.786", "0x0.c94#10", Greater);
+    test("1.0", "0x1.0#1", 10, Nearest, "0.785", "0x0.c90#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Floor,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Ceiling,
+        "0.7853981633974483096156608458206",
+        "0x0.c90fdaa22168c234c4c6628b9#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Down,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Up,
+        "0.7853981633974483096156608458206",
+        "0x0.c90fdaa22168c234c4c6628b9#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Nearest,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "-0.5", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Down, "-0.5", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 10, Floor, "-0.786", "-0x0.c94#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "-0.785",
+        "-0x0.c90#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Down,
+        "-0.785",
+        "-0x0.c90#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 10, Up, "-0.786", "-0x0.c94#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "-0.785",
+        "-0x0.c90#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Floor,
+        "-0.7853981633974483096156608458206",
+        "-0x0.c90fdaa22168c234c4c6628b9#100",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Ceiling,
+        "-0.78539816339744830961566084582",
+        "-0x0.c90fdaa22168c234c4c6628b8#100",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Down,
+        "-0.78539816339744830961566084582",
+        "-0x0.c90fdaa22168c234c4c6628b8#100",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Up,
+        "-0.7853981633974483096156608458206",
+        "-0x0.c90fdaa22168c234c4c6628b9#100",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Nearest,
+        "-0.78539816339744830961566084582",
+        "-0x0.c90fdaa22168c234c4c6628b8#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "1.262",
+        "0x1.430#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "1.264",
+        "0x1.438#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Down,
+        "1.262",
+        "0x1.430#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Up,
+        "1.264",
+        "0x1.438#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "1.262",
+        "0x1.430#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Floor,
+        "1.262627255678911672177612146814",
+        "0x1.433b8a322ddd29618168a21c8#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Ceiling,
+        "1.262627255678911672177612146816",
+        "0x1.433b8a322ddd29618168a21ca#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Down,
+        "1.262627255678911672177612146814",
+        "0x1.433b8a322ddd29618168a21c8#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Up,
+        "1.262627255678911672177612146816",
+        "0x1.433b8a322ddd29618168a21ca#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Nearest,
+        "1.262627255678911672177612146816",
+        "0x1.433b8a322ddd29618168a21ca#100",
+        Greater,
+    );
+    test("4.0e9", "0x1.0E+8#1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("4.0e9", "0x1.0E+8#1", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("4.0e9", "0x1.0E+8#1", 1, Down, "1.0", "0x1.0#1", Less);
+    test("4.0e9", "0x1.0E+8#1", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("4.0e9", "0x1.0E+8#1", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("4.0e9", "0x1.0E+8#1", 10, Floor, "1.57", "0x1.920#10", Less);
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        10,
+        Ceiling,
+        "1.572",
+        "0x1.928#10",
+        Greater,
+    );
+    test("4.0e9", "0x1.0E+8#1", 10, Down, "1.57", "0x1.920#10", Less);
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        10,
+        Up,
+        "1.572",
+        "0x1.928#10",
+        Greater,
+    );
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        10,
+        Nearest,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        100,
+        Floor,
+        "1.570796326562065975577452062737",
+        "0x1.921fb54342d18469898cc5174#100",
+        Less,
+    );
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        100,
+        Ceiling,
+        "1.570796326562065975577452062738",
+        "0x1.921fb54342d18469898cc5176#100",
+        Greater,
+    );
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        100,
+        Down,
+        "1.570796326562065975577452062737",
+        "0x1.921fb54342d18469898cc5174#100",
+        Less,
+    );
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        100,
+        Up,
+        "1.570796326562065975577452062738",
+        "0x1.921fb54342d18469898cc5176#100",
+        Greater,
+    );
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        100,
+        Nearest,
+        "1.570796326562065975577452062738",
+        "0x1.921fb54342d18469898cc5176#100",
+        Greater,
+    );
+}
+
+#[test]
+fn atan_prec_round_fail() {
+    const THREE: Float = Float::const_from_unsigned(3);
+    assert_panic!(Float::one_prec(1).atan_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).atan_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.atan_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(THREE.atan_prec_round(1, Exact));
+    assert_panic!(THREE.atan_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = THREE;
+        x.atan_prec_round_assign(1, Exact)
+    });
+}
+
+fn atan_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (atan, o) = x.clone().atan_prec_round(prec, rm);
+    assert!(atan.is_valid());
+    let (atan_alt, o_alt) = x.atan_prec_round_ref(prec, rm);
+    assert!(atan_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.atan_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&atan));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_atan, rug_o) = rug_atan_prec_round(&rug::Float::exact_from(&x), prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan)),
+            ComparableFloatRef(&atan),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if atan.is_normal() {
+        assert_eq!(atan.get_prec(), Some(prec));
+    }
+
+    if x > 0u32 {
+        assert!(atan >= 0u32);
+    } else if x < 0u32 {
+        assert!(atan <= 0u32);
+    }
+
+    let (neg_atan, neg_o) = (-&x).atan_prec_round(prec, -rm);
+    assert_eq!(ComparableFloat(-neg_atan), ComparableFloat(atan.clone()));
+    assert_eq!(neg_o.reverse(), o);
+
+    match rm {
+        Floor => assert_ne!(o, Greater),
+        Ceiling => assert_ne!(o, Less),
+        Down => {
+            if atan.is_sign_positive() {
+                assert_ne!(o, Greater);
+            } else {
+                assert_ne!(o, Less);
+            }
+        }
+        Up => {
+            if atan.is_sign_positive() {
+                assert_ne!(o, Less);
+            } else {
+                assert_ne!(o, Greater);
+            }
+        }
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.atan_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(atan.clone()));
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.atan_prec_round(prec, Exact));
+    }
+}
+
+#[test]
+fn atan_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties(|(x, prec, rm)| {
+        atan_prec_round_properties_helper(x, prec, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties_with_config(
+        &config,
+        |(x, prec, rm)| {
+            atan_prec_round_properties_helper(x, prec, rm);
+        },
+    );
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (atan, o) = Float::NAN.atan_prec_round(prec, rm);
+        assert!(atan.is_nan());
+        assert_eq!(o, Equal);
+        let (atan, o) = Float::ZERO.atan_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(atan), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (atan, o) = Float::NEGATIVE_ZERO.atan_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(atan), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn atan_prec_properties_helper(x: Float, prec: u64) {
+    let (atan, o) = x.clone().atan_prec(prec);
+    assert!(atan.is_valid());
+    let (atan_alt, o_alt) = x.atan_prec_ref(prec);
+    assert!(atan_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.atan_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&atan));
+    assert_eq!(o_alt, o);
+
+    let (rug_atan, rug_o) = rug_atan_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_atan)),
+        ComparableFloatRef(&atan),
+    );
+    assert_eq!(rug_o, o);
+
+    let (atan_alt, o_alt) = x.atan_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+    assert_eq!(o_alt, o);
+
+    if atan.is_normal() {
+        assert_eq!(atan.get_prec(), Some(prec));
+    }
+
+    if x > 0u32 {
+        assert!(atan >= 0u32);
+    } else if x < 0u32 {
+        assert!(atan <= 0u32);
+    }
+
+    let (neg_atan, neg_o) = (-&x).atan_prec(prec);
+    assert_eq!(ComparableFloat(-neg_atan), ComparableFloat(atan.clone()));
+    assert_eq!(neg_o.reverse(), o);
+}
+
+#[test]
+fn atan_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        atan_prec_properties_helper(x, prec);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_pair_gen_var_1().test_properties_with_config(&config, |(x, prec)| {
+        atan_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (atan, o) = Float::NAN.atan_prec(prec);
+        assert!(atan.is_nan());
+        assert_eq!(o, Equal);
+        let (atan, o) = Float::ZERO.atan_prec(prec);
+        assert_eq!(ComparableFloat(atan), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (atan, o) = Float::NEGATIVE_ZERO.atan_prec(prec);
+        assert_eq!(ComparableFloat(atan), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn atan_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (atan, o) = x.clone().atan_round(rm);
+    assert!(atan.is_valid());
+    let (atan_alt, o_alt) = x.atan_round_ref(rm);
+    assert!(atan_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.atan_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&atan));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_atan, rug_o) = rug_atan_round(&rug::Float::exact_from(&x), rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan)),
+            ComparableFloatRef(&atan),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    let (atan_alt, o_alt) = x.atan_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+    assert_eq!(o_alt, o);
+
+    if atan.is_normal() {
+        assert_eq!(atan.get_prec(), Some(x.significant_bits()));
+    }
+
+    if x > 0u32 {
+        assert!(atan >= 0u32);
+    } else if x < 0u32 {
+        assert!(atan <= 0u32);
+    }
+
+    let (neg_atan, neg_o) = (-&x).atan_round(-rm);
+    assert_eq!(ComparableFloat(-neg_atan), ComparableFloat(atan.clone()));
+    assert_eq!(neg_o.reverse(), o);
+
+    match rm {
+        Floor => assert_ne!(o, Greater),
+        Ceiling => assert_ne!(o, Less),
+        Down => {
+            if atan.is_sign_positive() {
+                assert_ne!(o, Greater);
+            } else {
+                assert_ne!(o, Less);
+            }
+        }
+        Up => {
+            if atan.is_sign_positive() {
+                assert_ne!(o, Less);
+            } else {
+                assert_ne!(o, Greater);
+            }
+        }
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+}
+
+#[test]
+fn atan_round_properties() {
+    float_rounding_mode_pair_gen_var_25().test_properties(|(x, rm)| {
+        atan_round_properties_helper(x, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_rounding_mode_pair_gen_var_25().test_properties_with_config(&config, |(x, rm)| {
+        atan_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (atan, o) = Float::NAN.atan_round(rm);
+        assert!(atan.is_nan());
+        assert_eq!(o, Equal);
+        let (atan, o) = Float::ZERO.atan_round(rm);
+        assert_eq!(ComparableFloat(atan), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (atan, o) = Float::NEGATIVE_ZERO.atan_round(rm);
+        assert_eq!(ComparableFloat(atan), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn atan_properties_helper(x: Float) {
+    let atan = x.clone().atan();
+    assert!(atan.is_valid());
+
+    let atan_alt = (&x).atan();
+    assert!(atan_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+
+    let mut x_alt = x.clone();
+    x_alt.atan_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&atan));
+
+    let rug_atan = rug_atan(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_atan)),
+        ComparableFloatRef(&atan),
+    );
+
+    assert_eq!(
+        ComparableFloatRef(&x.atan_round_ref(Nearest).0),
+        ComparableFloatRef(&atan)
+    );
+
+    if x > 0u32 {
+        assert!(atan >= 0u32);
+    } else if x < 0u32 {
+        assert!(atan <= 0u32);
+    }
+}
+
+#[test]
+fn atan_properties() {
+    float_gen().test_properties(|x| {
+        atan_properties_helper(x);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_gen().test_properties_with_config(&config, |x| {
+        atan_properties_helper(x);
+    });
+
+    float_gen_var_6().test_properties(|x| {
+        atan_properties_helper(x);
+    });
+
+    float_gen_var_7().test_properties(|x| {
+        atan_properties_helper(x);
+    });
+
+    float_gen_var_8().test_properties(|x| {
+        atan_properties_helper(x);
+    });
+
+    float_gen_var_11().test_properties(|x| {
+        atan_properties_helper(x);
+    });
+}
@@ -0,0 +1,2779 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::cmp::max;
+use malachite_base::num::arithmetic::traits::{Atan, Atan2, Atan2Assign};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{NaN, NegativeZero, One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_float::test_util::arithmetic::atan2::{
+    rug_atan2, rug_atan2_prec, rug_atan2_prec_round, rug_atan2_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_float_rounding_mode_triple_gen_var_33,
+    float_float_unsigned_rounding_mode_quadruple_gen_var_9, float_float_unsigned_triple_gen_var_1,
+    float_gen, float_pair_gen, float_unsigned_rounding_mode_triple_gen_var_14,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_nz::platform::Limb;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_atan2() {
+    let test = |s, s_hex, t, t_hex, out: &str, out_hex: &str| {
+        let y = parse_hex_string(s_hex);
+        assert_eq!(y.to_string(), s);
+        let x = parse_hex_string(t_hex);
+        assert_eq!(x.to_string(), t);
+
+        let atan2 = y.clone().atan2(x.clone());
+        assert!(atan2.is_valid());
+
+        assert_eq!(atan2.to_string(), out);
+        assert_eq!(to_hex_string(&atan2), out_hex);
+
+        let atan2_alt = y.clone().atan2(&x);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+
+        let atan2_alt = (&y).atan2(x.clone());
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+
+        let atan2_alt = (&y).atan2(&x);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+
+        let mut atan2_alt = y.clone();
+        atan2_alt.atan2_assign(x.clone());
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+
+        let mut atan2_alt = y.clone();
+        atan2_alt.atan2_assign(&x);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan2(
+                &rug::Float::exact_from(&y),
+                &rug::Float::exact_from(&x)
+            ))),
+            ComparableFloatRef(&atan2),
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN", "NaN", "NaN");
+    test("NaN", "NaN", "Infinity", "Infinity", "NaN", "NaN");
+    test("NaN", "NaN", "-Infinity", "-Infinity", "NaN", "NaN");
+    test("NaN", "NaN", "0.0", "0x0.0", "NaN", "NaN");
+    test("NaN", "NaN", "-0.0", "-0x0.0", "NaN", "NaN");
+    test("NaN", "NaN", "1.0", "0x1.0#1", "NaN", "NaN");
+    test("NaN", "NaN", "-1.0", "-0x1.0#1", "NaN", "NaN");
+    test("Infinity", "Infinity", "NaN", "NaN", "NaN", "NaN");
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", "1.0", "0x1.0#1",
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        "2.0",
+        "0x2.0#1",
+    );
+    test("Infinity", "Infinity", "0.0", "0x0.0", "2.0", "0x2.0#1");
+    test("Infinity", "Infinity", "-0.0", "-0x0.0", "2.0", "0x2.0#1");
+    test("Infinity", "Infinity", "1.0", "0x1.0#1", "2.0", "0x2.0#1");
+    test("Infinity", "Infinity", "-1.0", "-0x1.0#1", "2.0", "0x2.0#1");
+    test("-Infinity", "-Infinity", "NaN", "NaN", "NaN", "NaN");
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        "-1.0",
+        "-0x1.0#1",
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-2.0",
+        "-0x2.0#1",
+    );
+    test("-Infinity", "-Infinity", "0.0", "0x0.0", "-2.0", "-0x2.0#1");
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        "-2.0",
+        "-0x2.0#1",
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        "-2.0",
+        "-0x2.0#1",
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        "-2.0",
+        "-0x2.0#1",
+    );
+    test("0.0", "0x0.0", "NaN", "NaN", "NaN", "NaN");
+    test("0.0", "0x0.0", "Infinity", "Infinity", "0.0", "0x0.0");
+    test("0.0", "0x0.0", "-Infinity", "-Infinity", "4.0", "0x4.0#1");
+    test("0.0", "0x0.0", "0.0", "0x0.0", "0.0", "0x0.0");
+    test("0.0", "0x0.0", "-0.0", "-0x0.0", "4.0", "0x4.0#1");
+    test("0.0", "0x0.0", "1.0", "0x1.0#1", "0.0", "0x0.0");
+    test("0.0", "0x0.0", "-1.0", "-0x1.0#1", "4.0", "0x4.0#1");
+    test("-0.0", "-0x0.0", "NaN", "NaN", "NaN", "NaN");
+    test("-0.0", "-0x0.0", "Infinity", "Infinity", "-0.0", "-0x0.0");
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        "-4.0",
+        "-0x4.0#1",
+    );
+    test("-0.0", "-0x0.0", "0.0", "0x0.0", "-0.0", "-0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0", "-4.0", "-0x4.0#1");
+    test("-0.0", "-0x0.0", "1.0", "0x1.0#1", "-0.0", "-0x0.0");
+    test("-0.0", "-0x0.0", "-1.0", "-0x1.0#1", "-4.0", "-0x4.0#1");
+    test("1.0", "0x1.0#1", "NaN", "NaN", "NaN", "NaN");
+    test("1.0", "0x1.0#1", "Infinity", "Infinity", "0.0", "0x0.0");
+    test("1.0", "0x1.0#1", "-Infinity", "-Infinity", "4.0", "0x4.0#1");
+    test("1.0", "0x1.0#1", "0.0", "0x0.0", "2.0", "0x2.0#1");
+    test("1.0", "0x1.0#1", "-0.0", "-0x0.0", "2.0", "0x2.0#1");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "-1.0", "-0x1.0#1", "2.0", "0x2.0#1");
+    test("-1.0", "-0x1.0#1", "NaN", "NaN", "NaN", "NaN");
+    test("-1.0", "-0x1.0#1", "Infinity", "Infinity", "-0.0", "-0x0.0");
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        "-4.0",
+        "-0x4.0#1",
+    );
+    test("-1.0", "-0x1.0#1", "0.0", "0x0.0", "-2.0", "-0x2.0#1");
+    test("-1.0", "-0x1.0#1", "-0.0", "-0x0.0", "-2.0", "-0x2.0#1");
+    test("-1.0", "-0x1.0#1", "1.0", "0x1.0#1", "-1.0", "-0x1.0#1");
+    test("-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", "-2.0", "-0x2.0#1");
+    test("2.0", "0x2.0#1", "1.0", "0x1.0#1", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "2.0", "0x2.0#1", "0.5", "0x0.8#1");
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "456.0",
+        "0x1c8.0#9",
+        "0.264",
+        "0x0.438#9",
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        "456.0",
+        "0x1c8.0#9",
+        "-0.264",
+        "-0x0.438#9",
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-456.0",
+        "-0x1c8.0#9",
+        "2.875",
+        "0x2.e0#9",
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        "-456.0",
+        "-0x1c8.0#9",
+        "-2.875",
+        "-0x2.e0#9",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.8575117863558462",
+        "0x0.db85e4765538d8#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "-0.8575117863558462",
+        "-0x0.db85e4765538d8#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.2840808672339468",
+        "0x2.48b98612306a2#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "-2.2840808672339468",
+        "-0x2.48b98612306a2#53",
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "0.42298544273789301",
+        "0x0.6c48c6233e4af4#53",
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "1.0",
+        "0x1.0#1",
+        "9.0e-13",
+        "0x1.0E-10#1",
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "-1.0",
+        "-0x1.0#1",
+        "4.0",
+        "0x4.0#1",
+    );
+    test("1.0", "0x1.0#1", "9.0e-13", "0x1.0E-10#1", "2.0", "0x2.0#1");
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "-2.0",
+        "-0x2.0#1",
+    );
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "2.0",
+        "0x2.0#1",
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "4.0e9",
+        "0x1.0E+8#1",
+        "2.0e-22",
+        "0x1.0E-18#1",
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        "-4.0e9",
+        "-0x1.0E+8#1",
+        "-4.0",
+        "-0x4.0#1",
+    );
+    test(
+        "8.0e-1205",
+        "0x1.0E-1000#1",
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        "6.0e-2409",
+        "0x1.0E-2000#1",
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        "-8.0e-1205",
+        "-0x1.0E-1000#1",
+        "2.0",
+        "0x2.0#1",
+    );
+}
+
+#[test]
+fn test_atan2_prec() {
+    let test = |s, s_hex, t, t_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let y = parse_hex_string(s_hex);
+        assert_eq!(y.to_string(), s);
+        let x = parse_hex_string(t_hex);
+        assert_eq!(x.to_string(), t);
+
+        let (atan2, o) = y.clone().atan2_prec(x.clone(), prec);
+        assert!(atan2.is_valid());
+
+        assert_eq!(atan2.to_string(), out);
+        assert_eq!(to_hex_string(&atan2), out_hex);
+        assert_eq!(o, o_out);
+
+        let (atan2_alt, o_alt) = y.clone().atan2_prec_val_ref(&x, prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let (atan2_alt, o_alt) = y.atan2_prec_ref_val(x.clone(), prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let (atan2_alt, o_alt) = y.atan2_prec_ref_ref(&x, prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let mut atan2_alt = y.clone();
+        let o_alt = atan2_alt.atan2_prec_assign(x.clone(), prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let mut atan2_alt = y.clone();
+        let o_alt = atan2_alt.atan2_prec_assign_ref(&x, prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let (rug_atan2, rug_o) = rug_atan2_prec(
+            &rug::Float::exact_from(&y),
+            &rug::Float::exact_from(&x),
+            prec,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan2)),
+            ComparableFloatRef(&atan2),
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", "1.0", "0x1.0#1", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "1.0", "0x1.0#1", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "1.0", "0x1.0#1", 100, "NaN", "NaN", Equal);
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", 1, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        "0.785",
+        "0x0.c90#10",
+        Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        100,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        10,
+        "-2.355",
+        "-0x2.5b#10",
+        Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        100,
+        "-2.356194490192344928846982537459",
+        "-0x2.5b2f8fe6643a469e4e5327a28#100",
+        Greater,
+    );
+    test(
+        "0.0", "0x0.0", "-1.0", "-0x1.0#1", 1, "4.0", "0x4.0#1", Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "3.141",
+        "0x3.24#10",
+        Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "3.141592653589793238462643383279",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Less,
+    );
+    test(
+        "-0.0", "-0x0.0", "-0.0", "-0x0.0", 1, "-4.0", "-0x4.0#1", Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "-3.141",
+        "-0x3.24#10",
+        Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        100,
+        "-3.141592653589793238462643383279",
+        "-0x3.243f6a8885a308d313198a2e0#100",
+        Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "0.0", "0x0.0", 1, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        10,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        100,
+        "1.57079632679489661923132169164",
+        "0x1.921fb54442d18469898cc5170#100",
+        Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "0.785",
+        "0x0.c90#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", 1, "2.0", "0x2.0#1", Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "2.355",
+        "0x2.5b#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "2.356194490192344928846982537459",
+        "0x2.5b2f8fe6643a469e4e5327a28#100",
+        Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", 1, "-2.0", "-0x2.0#1", Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-2.355",
+        "-0x2.5b#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-2.356194490192344928846982537459",
+        "-0x2.5b2f8fe6643a469e4e5327a28#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.857",
+        "0x0.db8#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.8575117863558462720334763011",
+        "0x0.db85e4765538da5947cfa3e54#100",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        "-2.285",
+        "-0x2.49#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        100,
+        "-2.284080867233946966429167082179",
+        "-0x2.48b98612306a2e79cb49e648c#100",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "4.0e9",
+        "0x1.0E+8#1",
+        1,
+        "2.0e-22",
+        "0x1.0E-18#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "4.0e9",
+        "0x1.0E+8#1",
+        10,
+        "2.118e-22",
+        "0x1.000E-18#10",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "4.0e9",
+        "0x1.0E+8#1",
+        100,
+        "2.117582368135750847670806251699e-22",
+        "0x1.0000000000000000000000000E-18#100",
+        Greater,
+    );
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        10,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        100,
+        "1.570796326794896619231533449876",
+        "0x1.921fb54442d184698a8cc5170#100",
+        Less,
+    );
+}
+
+#[test]
+fn atan2_prec_fail() {
+    assert_panic!(Float::ONE.atan2_prec(Float::ONE, 0));
+    assert_panic!(Float::ONE.atan2_prec_val_ref(&Float::ONE, 0));
+    assert_panic!(Float::ONE.atan2_prec_ref_val(Float::ONE, 0));
+    assert_panic!(Float::ONE.atan2_prec_ref_ref(&Float::ONE, 0));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.atan2_prec_assign(Float::ONE, 0)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.atan2_prec_assign_ref(&Float::ONE, 0)
+    });
+}
+
+#[test]
+fn test_atan2_round() {
+    let test = |s, s_hex, t, t_hex, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let y = parse_hex_string(s_hex);
+        assert_eq!(y.to_string(), s);
+        let x = parse_hex_string(t_hex);
+        assert_eq!(x.to_string(), t);
+
+        let (atan2, o) = y.clone().atan2_round(x.clone(), rm);
+        assert!(atan2.is_valid());
+
+        assert_eq!(atan2.to_string(), out);
+        assert_eq!(to_hex_string(&atan2), out_hex);
+        assert_eq!(o, o_out);
+
+        let (atan2_alt, o_alt) = y.clone().atan2_round_val_ref(&x, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let (atan2_alt, o_alt) = y.atan2_round_ref_val(x.clone(), rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let (atan2_alt, o_alt) = y.atan2_round_ref_ref(&x, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let mut atan2_alt = y.clone();
+        let o_alt = atan2_alt.atan2_round_assign(x.clone(), rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let mut atan2_alt = y.clone();
+        let o_alt = atan2_alt.atan2_round_assign_ref(&x, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atan2, rug_o) =
+                rug_atan2_round(&rug::Float::exact_from(&y), &rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atan2)),
+                ComparableFloatRef(&atan2),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", "1.0", "0x1.0#1", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "1.0", "0x1.0#1", Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "1.0", "0x1.0#1", Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "1.0", "0x1.0#1", Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "1.0", "0x1.0#1", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "1.0", "0x1.0#1", Exact, "NaN", "NaN", Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        Down,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        Up,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.0", "0x0.0", "-1.0", "-0x1.0#1", Floor, "2.0", "0x2.0#1", Less,
+    );
+    test(
+        "0.0", "0x0.0", "-1.0", "-0x1.0#1", Ceiling, "4.0", "0x4.0#1", Greater,
+    );
+    test(
+        "0.0", "0x0.0", "-1.0", "-0x1.0#1", Down, "2.0", "0x2.0#1", Less,
+    );
+    test(
+        "0.0", "0x0.0", "-1.0", "-0x1.0#1", Up, "4.0", "0x4.0#1", Greater,
+    );
+    test(
+        "0.0", "0x0.0", "-1.0", "-0x1.0#1", Nearest, "4.0", "0x4.0#1", Greater,
+    );
+    test(
+        "-0.0", "-0x0.0", "1.0", "0x1.0#1", Floor, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "1.0", "0x1.0#1", Ceiling, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "1.0", "0x1.0#1", Down, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "1.0", "0x1.0#1", Up, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "1.0", "0x1.0#1", Nearest, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "1.0", "0x1.0#1", Exact, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", Floor, "0.5", "0x0.8#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", Ceiling, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", Down, "0.5", "0x0.8#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", Up, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", Nearest, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", Floor, "2.0", "0x2.0#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", Ceiling, "4.0", "0x4.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", Down, "2.0", "0x2.0#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", Up, "4.0", "0x4.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", Nearest, "2.0", "0x2.0#1", Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.8575117863558462",
+        "0x0.db85e4765538d8#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "0.8575117863558464",
+        "0x0.db85e4765538e0#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "0.8575117863558462",
+        "0x0.db85e4765538d8#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "0.8575117863558464",
+        "0x0.db85e4765538e0#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.8575117863558462",
+        "0x0.db85e4765538d8#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        Floor,
+        "-2.2840808672339472",
+        "-0x2.48b98612306a4#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        Ceiling,
+        "-2.2840808672339468",
+        "-0x2.48b98612306a2#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        Down,
+        "-2.2840808672339468",
+        "-0x2.48b98612306a2#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        Up,
+        "-2.2840808672339472",
+        "-0x2.48b98612306a4#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        Nearest,
+        "-2.2840808672339468",
+        "-0x2.48b98612306a2#53",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Floor,
+        "0.42298544273789296",
+        "0x0.6c48c6233e4af0#53",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ceiling,
+        "0.42298544273789301",
+        "0x0.6c48c6233e4af4#53",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Down,
+        "0.42298544273789296",
+        "0x0.6c48c6233e4af0#53",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Up,
+        "0.42298544273789301",
+        "0x0.6c48c6233e4af4#53",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Nearest,
+        "0.42298544273789301",
+        "0x0.6c48c6233e4af4#53",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "4.0e9",
+        "0x1.0E+8#1",
+        Floor,
+        "1.0e-22",
+        "0x8.0E-19#1",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "4.0e9",
+        "0x1.0E+8#1",
+        Ceiling,
+        "2.0e-22",
+        "0x1.0E-18#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "4.0e9",
+        "0x1.0E+8#1",
+        Down,
+        "1.0e-22",
+        "0x8.0E-19#1",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "4.0e9",
+        "0x1.0E+8#1",
+        Up,
+        "2.0e-22",
+        "0x1.0E-18#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "4.0e9",
+        "0x1.0E+8#1",
+        Nearest,
+        "2.0e-22",
+        "0x1.0E-18#1",
+        Greater,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        "-4.0e9",
+        "-0x1.0E+8#1",
+        Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Less,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        "-4.0e9",
+        "-0x1.0E+8#1",
+        Ceiling,
+        "-2.0",
+        "-0x2.0#1",
+        Greater,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        "-4.0e9",
+        "-0x1.0E+8#1",
+        Down,
+        "-2.0",
+        "-0x2.0#1",
+        Greater,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        "-4.0e9",
+        "-0x1.0E+8#1",
+        Up,
+        "-4.0",
+        "-0x4.0#1",
+        Less,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        "-4.0e9",
+        "-0x1.0E+8#1",
+        Nearest,
+        "-4.0",
+        "-0x4.0#1",
+        Less,
+    );
+}
+
+#[test]
+fn atan2_round_fail() {
+    assert_panic!(Float::ONE.atan2_round(Float::ONE, Exact));
+    assert_panic!(Float::ONE.atan2_round_val_ref(&Float::ONE, Exact));
+    assert_panic!(Float::ONE.atan2_round_ref_val(Float::ONE, Exact));
+    assert_panic!(Float::ONE.atan2_round_ref_ref(&Float::ONE, Exact));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.atan2_round_assign(Float::ONE, Exact)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.atan2_round_assign_ref(&Float::ONE, Exact)
+    });
+}
+
+#[test]
+fn test_atan2_prec_round() {
+    let test = |s, s_hex, t, t_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let y = parse_hex_string(s_hex);
+        assert_eq!(y.to_string(), s);
+        let x = parse_hex_string(t_hex);
+        assert_eq!(x.to_string(), t);
+
+        let (atan2, o) = y.clone().atan2_prec_round(x.clone(), prec, rm);
+        assert!(atan2.is_valid());
+
+        assert_eq!(atan2.to_string(), out);
+        assert_eq!(to_hex_string(&atan2), out_hex);
+        assert_eq!(o, o_out);
+
+        let (atan2_alt, o_alt) = y.clone().atan2_prec_round_val_ref(&x, prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let (atan2_alt, o_alt) = y.atan2_prec_round_ref_val(x.clone(), prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let (atan2_alt, o_alt) = y.atan2_prec_round_ref_ref(&x, prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let mut atan2_alt = y.clone();
+        let o_alt = atan2_alt.atan2_prec_round_assign(x.clone(), prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let mut atan2_alt = y.clone();
+        let o_alt = atan2_alt.atan2_prec_round_assign_ref(&x, prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atan2, rug_o) = rug_atan2_prec_round(
+                &rug::Float::exact_from(&y),
+                &rug::Float::exact_from(&x),
+                prec,
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atan2)),
+                ComparableFloatRef(&atan2),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN", "NaN", "1.0", "0x1.0#1", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", "1.0", "0x1.0#1", 1, Ceiling, "NaN", "NaN", Equal,
+    );
+    test("NaN", "NaN", "1.0", "0x1.0#1", 1, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "1.0", "0x1.0#1", 1, Up, "NaN", "NaN", Equal);
+    test(
+        "NaN", "NaN", "1.0", "0x1.0#1", 1, Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", "1.0", "0x1.0#1", 1, Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", "1.0", "0x1.0#1", 10, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", "1.0", "0x1.0#1", 10, Ceiling, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", "1.0", "0x1.0#1", 10, Down, "NaN", "NaN", Equal,
+    );
+    test("NaN", "NaN", "1.0", "0x1.0#1", 10, Up, "NaN", "NaN", Equal);
+    test(
+        "NaN", "NaN", "1.0", "0x1.0#1", 10, Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", "1.0", "0x1.0#1", 10, Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", "1.0", "0x1.0#1", 100, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", "1.0", "0x1.0#1", 100, Ceiling, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", "1.0", "0x1.0#1", 100, Down, "NaN", "NaN", Equal,
+    );
+    test("NaN", "NaN", "1.0", "0x1.0#1", 100, Up, "NaN", "NaN", Equal);
+    test(
+        "NaN", "NaN", "1.0", "0x1.0#1", 100, Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", "1.0", "0x1.0#1", 100, Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", 1, Floor, "0.5", "0x0.8#1", Less,
+    );
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", 1, Ceiling, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", 1, Down, "0.5", "0x0.8#1", Less,
+    );
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", 1, Up, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", 1, Nearest, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        Floor,
+        "0.785",
+        "0x0.c90#10",
+        Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        Ceiling,
+        "0.786",
+        "0x0.c94#10",
+        Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        Down,
+        "0.785",
+        "0x0.c90#10",
+        Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        Up,
+        "0.786",
+        "0x0.c94#10",
+        Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        Nearest,
+        "0.785",
+        "0x0.c90#10",
+        Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        100,
+        Floor,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        100,
+        Ceiling,
+        "0.7853981633974483096156608458206",
+        "0x0.c90fdaa22168c234c4c6628b9#100",
+        Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        100,
+        Down,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        100,
+        Up,
+        "0.7853981633974483096156608458206",
+        "0x0.c90fdaa22168c234c4c6628b9#100",
+        Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        100,
+        Nearest,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Less,
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", 1, Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", 1, Ceiling, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", 1, Down, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", 1, Up, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", 1, Nearest, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", 1, Exact, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", 10, Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", 10, Ceiling, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", 10, Down, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", 10, Up, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", 10, Nearest, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", 10, Exact, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", 100, Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", 100, Ceiling, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", 100, Down, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", 100, Up, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", 100, Nearest, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", 100, Exact, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "-1.0", "-0x1.0#1", 1, Floor, "2.0", "0x2.0#1", Less,
+    );
+    test(
+        "0.0", "0x0.0", "-1.0", "-0x1.0#1", 1, Ceiling, "4.0", "0x4.0#1", Greater,
+    );
+    test(
+        "0.0", "0x0.0", "-1.0", "-0x1.0#1", 1, Down, "2.0", "0x2.0#1", Less,
+    );
+    test(
+        "0.0", "0x0.0", "-1.0", "-0x1.0#1", 1, Up, "4.0", "0x4.0#1", Greater,
+    );
+    test(
+        "0.0", "0x0.0", "-1.0", "-0x1.0#1", 1, Nearest, "4.0", "0x4.0#1", Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "3.141",
+        "0x3.24#10",
+        Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "3.145",
+        "0x3.25#10",
+        Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Down,
+        "3.141",
+        "0x3.24#10",
+        Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Up,
+        "3.145",
+        "0x3.25#10",
+        Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "3.141",
+        "0x3.24#10",
+        Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Floor,
+        "3.141592653589793238462643383279",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Ceiling,
+        "3.141592653589793238462643383282",
+        "0x3.243f6a8885a308d313198a2e4#100",
+        Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Down,
+        "3.141592653589793238462643383279",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Up,
+        "3.141592653589793238462643383282",
+        "0x3.243f6a8885a308d313198a2e4#100",
+        Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Nearest,
+        "3.141592653589793238462643383279",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Less,
+    );
+    test(
+        "-0.0", "-0x0.0", "-1.0", "-0x1.0#1", 1, Floor, "-4.0", "-0x4.0#1", Less,
+    );
+    test(
+        "-0.0", "-0x0.0", "-1.0", "-0x1.0#1", 1, Ceiling, "-2.0", "-0x2.0#1", Greater,
+    );
+    test(
+        "-0.0", "-0x0.0", "-1.0", "-0x1.0#1", 1, Down, "-2.0", "-0x2.0#1", Greater,
+    );
+    test(
+        "-0.0", "-0x0.0", "-1.0", "-0x1.0#1", 1, Up, "-4.0", "-0x4.0#1", Less,
+    );
+    test(
+        "-0.0", "-0x0.0", "-1.0", "-0x1.0#1", 1, Nearest, "-4.0", "-0x4.0#1", Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "-3.145",
+        "-0x3.25#10",
+        Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "-3.141",
+        "-0x3.24#10",
+        Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Down,
+        "-3.141",
+        "-0x3.24#10",
+        Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Up,
+        "-3.145",
+        "-0x3.25#10",
+        Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "-3.141",
+        "-0x3.24#10",
+        Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Floor,
+        "-3.141592653589793238462643383282",
+        "-0x3.243f6a8885a308d313198a2e4#100",
+        Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Ceiling,
+        "-3.141592653589793238462643383279",
+        "-0x3.243f6a8885a308d313198a2e0#100",
+        Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Down,
+        "-3.141592653589793238462643383279",
+        "-0x3.243f6a8885a308d313198a2e0#100",
+        Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Up,
+        "-3.141592653589793238462643383282",
+        "-0x3.243f6a8885a308d313198a2e4#100",
+        Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Nearest,
+        "-3.141592653589793238462643383279",
+        "-0x3.243f6a8885a308d313198a2e0#100",
+        Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", 1, Floor, "0.5", "0x0.8#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", 1, Down, "0.5", "0x0.8#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", 1, Up, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", 1, Nearest, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Floor,
+        "0.785",
+        "0x0.c90#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.786",
+        "0x0.c94#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Down,
+        "0.785",
+        "0x0.c90#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Up,
+        "0.786",
+        "0x0.c94#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "0.785",
+        "0x0.c90#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        Floor,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        Ceiling,
+        "0.7853981633974483096156608458206",
+        "0x0.c90fdaa22168c234c4c6628b9#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        Down,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        Up,
+        "0.7853981633974483096156608458206",
+        "0x0.c90fdaa22168c234c4c6628b9#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        Nearest,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", 1, Floor, "-4.0", "-0x4.0#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", 1, Ceiling, "-2.0", "-0x2.0#1", Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", 1, Down, "-2.0", "-0x2.0#1", Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", 1, Up, "-4.0", "-0x4.0#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", 1, Nearest, "-2.0", "-0x2.0#1", Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "-2.359",
+        "-0x2.5c#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "-2.355",
+        "-0x2.5b#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Down,
+        "-2.355",
+        "-0x2.5b#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Up,
+        "-2.359",
+        "-0x2.5c#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "-2.355",
+        "-0x2.5b#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Floor,
+        "-2.356194490192344928846982537463",
+        "-0x2.5b2f8fe6643a469e4e5327a2c#100",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Ceiling,
+        "-2.356194490192344928846982537459",
+        "-0x2.5b2f8fe6643a469e4e5327a28#100",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Down,
+        "-2.356194490192344928846982537459",
+        "-0x2.5b2f8fe6643a469e4e5327a28#100",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Up,
+        "-2.356194490192344928846982537463",
+        "-0x2.5b2f8fe6643a469e4e5327a2c#100",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Nearest,
+        "-2.356194490192344928846982537459",
+        "-0x2.5b2f8fe6643a469e4e5327a28#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "0.5",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "0.5",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "0.857",
+        "0x0.db8#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "0.858",
+        "0x0.dbc#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "0.857",
+        "0x0.db8#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "0.858",
+        "0x0.dbc#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "0.857",
+        "0x0.db8#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Floor,
+        "0.8575117863558462720334763011",
+        "0x0.db85e4765538da5947cfa3e54#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Ceiling,
+        "0.8575117863558462720334763011007",
+        "0x0.db85e4765538da5947cfa3e55#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Down,
+        "0.8575117863558462720334763011",
+        "0x0.db85e4765538da5947cfa3e54#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Up,
+        "0.8575117863558462720334763011007",
+        "0x0.db85e4765538da5947cfa3e55#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Nearest,
+        "0.8575117863558462720334763011",
+        "0x0.db85e4765538da5947cfa3e54#100",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "-2.0",
+        "-0x2.0#1",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "-2.0",
+        "-0x2.0#1",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "-4.0",
+        "-0x4.0#1",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "-2.0",
+        "-0x2.0#1",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "-2.285",
+        "-0x2.49#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "-2.281",
+        "-0x2.48#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "-2.281",
+        "-0x2.48#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "-2.285",
+        "-0x2.49#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "-2.285",
+        "-0x2.49#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        100,
+        Floor,
+        "-2.284080867233946966429167082179",
+        "-0x2.48b98612306a2e79cb49e648c#100",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        100,
+        Ceiling,
+        "-2.284080867233946966429167082176",
+        "-0x2.48b98612306a2e79cb49e6488#100",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        100,
+        Down,
+        "-2.284080867233946966429167082176",
+        "-0x2.48b98612306a2e79cb49e6488#100",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        100,
+        Up,
+        "-2.284080867233946966429167082179",
+        "-0x2.48b98612306a2e79cb49e648c#100",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        100,
+        Nearest,
+        "-2.284080867233946966429167082179",
+        "-0x2.48b98612306a2e79cb49e648c#100",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "4.0e9",
+        "0x1.0E+8#1",
+        1,
+        Floor,
+        "1.0e-22",
+        "0x8.0E-19#1",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "4.0e9",
+        "0x1.0E+8#1",
+        1,
+        Ceiling,
+        "2.0e-22",
+        "0x1.0E-18#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "4.0e9",
+        "0x1.0E+8#1",
+        1,
+        Down,
+        "1.0e-22",
+        "0x8.0E-19#1",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "4.0e9",
+        "0x1.0E+8#1",
+        1,
+        Up,
+        "2.0e-22",
+        "0x1.0E-18#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "4.0e9",
+        "0x1.0E+8#1",
+        1,
+        Nearest,
+        "2.0e-22",
+        "0x1.0E-18#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "4.0e9",
+        "0x1.0E+8#1",
+        10,
+        Floor,
+        "2.116e-22",
+        "0xf.fcE-19#10",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "4.0e9",
+        "0x1.0E+8#1",
+        10,
+        Ceiling,
+        "2.118e-22",
+        "0x1.000E-18#10",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "4.0e9",
+        "0x1.0E+8#1",
+        10,
+        Down,
+        "2.116e-22",
+        "0xf.fcE-19#10",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "4.0e9",
+        "0x1.0E+8#1",
+        10,
+        Up,
+        "2.118e-22",
+        "0x1.000E-18#10",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "4.0e9",
+        "0x1.0E+8#1",
+        10,
+        Nearest,
+        "2.118e-22",
+        "0x1.000E-18#10",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "4.0e9",
+        "0x1.0E+8#1",
+        100,
+        Floor,
+        "2.117582368135750847670806251697e-22",
+        "0xf.ffffffffffffffffffffffffE-19#100",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "4.0e9",
+        "0x1.0E+8#1",
+        100,
+        Ceiling,
+        "2.117582368135750847670806251699e-22",
+        "0x1.0000000000000000000000000E-18#100",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "4.0e9",
+        "0x1.0E+8#1",
+        100,
+        Down,
+        "2.117582368135750847670806251697e-22",
+        "0xf.ffffffffffffffffffffffffE-19#100",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "4.0e9",
+        "0x1.0E+8#1",
+        100,
+        Up,
+        "2.117582368135750847670806251699e-22",
+        "0x1.0000000000000000000000000E-18#100",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "4.0e9",
+        "0x1.0E+8#1",
+        100,
+        Nearest,
+        "2.117582368135750847670806251699e-22",
+        "0x1.0000000000000000000000000E-18#100",
+        Greater,
+    );
+}
+
+#[test]
+fn atan2_prec_round_fail() {
+    assert_panic!(Float::ONE.atan2_prec_round(Float::ONE, 0, Floor));
+    assert_panic!(Float::ONE.atan2_prec_round_val_ref(&Float::ONE, 0, Floor));
+    assert_panic!(Float::ONE.atan2_prec_round_ref_val(Float::ONE, 0, Floor));
+    assert_panic!(Float::ONE.atan2_prec_round_ref_ref(&Float::ONE, 0, Floor));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.atan2_prec_round_assign(Float::ONE, 0, Floor)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.atan2_prec_round_assign_ref(&Float::ONE, 0, Floor)
+    });
+    assert_panic!(Float::ONE.atan2_prec_round(Float::ONE, 1, Exact));
+    assert_panic!(Float::ONE.atan2_prec_round_val_ref(&Float::ONE, 1, Exact));
+    assert_panic!(Float::ONE.atan2_prec_round_ref_val(Float::ONE, 1, Exact));
+    assert_panic!(Float::ONE.atan2_prec_round_ref_ref(&Float::ONE, 1, Exact));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.atan2_prec_round_assign(Float::ONE, 1, Exact)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.atan2_prec_round_assign_ref(&Float::ONE, 1, Exact)
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn atan2_prec_round_properties_helper(y: Float, x: Float, prec: u64, rm: RoundingMode) {
+    let (atan2, o) = y.clone().atan2_prec_round(x.clone(), prec, rm);
+    assert!(atan2.is_valid());
+    let (atan2_alt, o_alt) = y.clone().atan2_prec_round_val_ref(&x, prec, rm);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    let (atan2_alt, o_alt) = y.atan2_prec_round_ref_val(x.clone(), prec, rm);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    let (atan2_alt, o_alt) = y.atan2_prec_round_ref_ref(&x, prec, rm);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    let mut atan2_alt = y.clone();
+    let o_alt = atan2_alt.atan2_prec_round_assign(x.clone(), prec, rm);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    let mut atan2_alt = y.clone();
+    let o_alt = atan2_alt.atan2_prec_round_assign_ref(&x, prec, rm);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_atan2, rug_o) = rug_atan2_prec_round(
+            &rug::Float::exact_from(&y),
+            &rug::Float::exact_from(&x),
+            prec,
+            rm,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan2)),
+            ComparableFloatRef(&atan2),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if atan2.is_normal() {
+        assert_eq!(atan2.get_prec(), Some(prec));
+    }
+
+    if y.is_sign_positive() {
+        assert!(atan2.is_nan() || atan2.is_sign_positive());
+    } else {
+        assert!(atan2.is_nan() || atan2.is_sign_negative());
+    }
+
+    let (neg_atan2, neg_o) = (-&y).atan2_prec_round_val_ref(&x, prec, -rm);
+    assert_eq!(ComparableFloat(-neg_atan2), ComparableFloat(atan2.clone()));
+    assert_eq!(neg_o.reverse(), o);
+
+    match rm {
+        Floor => assert_ne!(o, Greater),
+        Ceiling => assert_ne!(o, Less),
+        Down => {
+            if atan2.is_sign_positive() {
+                assert_ne!(o, Greater);
+            } else {
+                assert_ne!(o, Less);
+            }
+        }
+        Up => {
+            if atan2.is_sign_positive() {
+                assert_ne!(o, Less);
+            } else {
+                assert_ne!(o, Greater);
+            }
+        }
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = y.atan2_prec_round_ref_ref(&x, prec, rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(atan2.clone()));
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(y.atan2_prec_round_ref_ref(&x, prec, Exact));
+    }
+}
+
+#[test]
+fn atan2_prec_round_properties() {
+    float_float_unsigned_rounding_mode_quadruple_gen_var_9().test_properties(|(y, x, prec, rm)| {
+        atan2_prec_round_properties_helper(y, x, prec, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_float_unsigned_rounding_mode_quadruple_gen_var_9().test_properties_with_config(
+        &config,
+        |(y, x, prec, rm)| {
+            atan2_prec_round_properties_helper(y, x, prec, rm);
+        },
+    );
+
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties(|(x, prec, rm)| {
+        let (atan2, o) = Float::NAN.atan2_prec_round_val_ref(&x, prec, rm);
+        assert!(atan2.is_nan());
+        assert_eq!(o, Equal);
+        let (atan2, o) = x.atan2_prec_round_ref_val(Float::NAN, prec, rm);
+        assert!(atan2.is_nan());
+        assert_eq!(o, Equal);
+        if x.is_sign_positive() {
+            let (atan2, o) = Float::ZERO.atan2_prec_round_val_ref(&x, prec, rm);
+            assert_eq!(ComparableFloat(atan2), ComparableFloat(Float::ZERO));
+            assert_eq!(o, Equal);
+            let (atan2, o) = Float::NEGATIVE_ZERO.atan2_prec_round_val_ref(&x, prec, rm);
+            assert_eq!(
+                ComparableFloat(atan2),
+                ComparableFloat(Float::NEGATIVE_ZERO)
+            );
+            assert_eq!(o, Equal);
+        }
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn atan2_prec_properties_helper(y: Float, x: Float, prec: u64) {
+    let (atan2, o) = y.clone().atan2_prec(x.clone(), prec);
+    assert!(atan2.is_valid());
+    let (atan2_alt, o_alt) = y.clone().atan2_prec_val_ref(&x, prec);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    let (atan2_alt, o_alt) = y.atan2_prec_ref_val(x.clone(), prec);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    let (atan2_alt, o_alt) = y.atan2_prec_ref_ref(&x, prec);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    let mut atan2_alt = y.clone();
+    let o_alt = atan2_alt.atan2_prec_assign(x.clone(), prec);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    let mut atan2_alt = y.clone();
+    let o_alt = atan2_alt.atan2_prec_assign_ref(&x, prec);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    let (rug_atan2, rug_o) = rug_atan2_prec(
+        &rug::Float::exact_from(&y),
+        &rug::Float::exact_from(&x),
+        prec,
+    );
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_atan2)),
+        ComparableFloatRef(&atan2),
+    );
+    assert_eq!(rug_o, o);
+
+    let (atan2_alt, o_alt) = y.atan2_prec_round_ref_ref(&x, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    if atan2.is_normal() {
+        assert_eq!(atan2.get_prec(), Some(prec));
+    }
+
+    let (neg_atan2, neg_o) = (-&y).atan2_prec_val_ref(&x, prec);
+    assert_eq!(ComparableFloat(-neg_atan2), ComparableFloat(atan2.clone()));
+    assert_eq!(neg_o.reverse(), o);
+}
+
+#[test]
+fn atan2_prec_properties() {
+    float_float_unsigned_triple_gen_var_1().test_properties(|(y, x, prec)| {
+        atan2_prec_properties_helper(y, x, prec);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_float_unsigned_triple_gen_var_1().test_properties_with_config(&config, |(y, x, prec)| {
+        atan2_prec_properties_helper(y, x, prec);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn atan2_round_properties_helper(y: Float, x: Float, rm: RoundingMode) {
+    let (atan2, o) = y.clone().atan2_round(x.clone(), rm);
+    assert!(atan2.is_valid());
+    let (atan2_alt, o_alt) = y.clone().atan2_round_val_ref(&x, rm);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    let (atan2_alt, o_alt) = y.atan2_round_ref_val(x.clone(), rm);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    let (atan2_alt, o_alt) = y.atan2_round_ref_ref(&x, rm);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    let mut atan2_alt = y.clone();
+    let o_alt = atan2_alt.atan2_round_assign(x.clone(), rm);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    let mut atan2_alt = y.clone();
+    let o_alt = atan2_alt.atan2_round_assign_ref(&x, rm);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_atan2, rug_o) =
+            rug_atan2_round(&rug::Float::exact_from(&y), &rug::Float::exact_from(&x), rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan2)),
+            ComparableFloatRef(&atan2),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    let prec = max(y.significant_bits(), x.significant_bits());
+    let (atan2_alt, o_alt) = y.atan2_prec_round_ref_ref(&x, prec, rm);
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    if atan2.is_normal() {
+        assert_eq!(atan2.get_prec(), Some(prec));
+    }
+
+    let (neg_atan2, neg_o) = (-&y).atan2_round_val_ref(&x, -rm);
+    assert_eq!(ComparableFloat(-neg_atan2), ComparableFloat(atan2.clone()));
+    assert_eq!(neg_o.reverse(), o);
+}
+
+#[test]
+fn atan2_round_properties() {
+    float_float_rounding_mode_triple_gen_var_33().test_properties(|(y, x, rm)| {
+        atan2_round_properties_helper(y, x, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_float_rounding_mode_triple_gen_var_33().test_properties_with_config(
+        &config,
+        |(y, x, rm)| {
+            atan2_round_properties_helper(y, x, rm);
+        },
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn atan2_properties_helper(y: Float, x: Float) {
+    let atan2 = y.clone().atan2(x.clone());
+    assert!(atan2.is_valid());
+    let atan2_alt = y.clone().atan2(&x);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    let atan2_alt = (&y).atan2(x.clone());
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    let atan2_alt = (&y).atan2(&x);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+
+    let mut y_alt = y.clone();
+    y_alt.atan2_assign(x.clone());
+    assert!(y_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&atan2));
+    let mut y_alt = y.clone();
+    y_alt.atan2_assign(&x);
+    assert!(y_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&atan2));
+
+    let rug_atan2 = rug_atan2(&rug::Float::exact_from(&y), &rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_atan2)),
+        ComparableFloatRef(&atan2),
+    );
+
+    assert_eq!(
+        ComparableFloatRef(&y.atan2_round_ref_ref(&x, Nearest).0),
+        ComparableFloatRef(&atan2)
+    );
+}
+
+#[test]
+fn atan2_properties() {
+    float_pair_gen().test_properties(|(y, x)| {
+        atan2_properties_helper(y, x);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_pair_gen().test_properties_with_config(&config, |(y, x)| {
+        atan2_properties_helper(y, x);
+    });
+
+    float_gen().test_properties(|y| {
+        assert_eq!(
+            ComparableFloat((&y).atan2(Float::ONE)),
+            ComparableFloat(y.atan())
+        );
+    });
+}
@@ -0,0 +1,1372 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{Cos, CosAssign};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Zero,
+};
+use malachite_base::num::comparison::traits::PartialOrdAbs;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::{
+    rounding_mode_gen, unsigned_gen_var_11, unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::test_util::arithmetic::cos::{
+    rug_cos, rug_cos_prec, rug_cos_prec_round, rug_cos_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_gen_var_6, float_gen_var_7, float_gen_var_8, float_gen_var_11,
+    float_rounding_mode_pair_gen_var_25, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_14,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_nz::platform::Limb;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_cos() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let cos = x.clone().cos();
+        assert!(cos.is_valid());
+
+        assert_eq!(cos.to_string(), out);
+        assert_eq!(to_hex_string(&cos), out_hex);
+
+        let cos_alt = (&x).cos();
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+
+        let mut cos_alt = x.clone();
+        cos_alt.cos_assign();
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+
+        let rug_cos = rug_cos(&rug::Float::exact_from(&x));
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos),
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "NaN", "NaN");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "1.0", "0x1.0#1");
+    test("-0.0", "-0x0.0", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "0.5", "0x0.8#1");
+    test("-1.0", "-0x1.0#1", "0.5", "0x0.8#1");
+    test("2.0", "0x2.0#1", "-0.5", "-0x0.8#1");
+    test("0.5", "0x0.8#1", "1.0", "0x1.0#1");
+    test("-0.5", "-0x0.8#1", "1.0", "0x1.0#1");
+    test("123.0", "0x7b.0#7", "-0.89", "-0x0.e4#7");
+    test("-123.0", "-0x7b.0#7", "-0.89", "-0x0.e4#7");
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "0.15594369476537437",
+        "0x0.27ebed0d08e166#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.0",
+        "-0x1.0000000000000#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-1.0",
+        "-0x1.0000000000000#53",
+    );
+    test("9.0e-13", "0x1.0E-10#1", "1.0", "0x1.0#1");
+    test("-9.0e-13", "-0x1.0E-10#1", "1.0", "0x1.0#1");
+    test("1000.0", "0x3e8.0#10", "0.5625", "0x0.900#10");
+    test("4.0e9", "0x1.0E+8#1", "-1.0", "-0x1.0#1");
+    test("-4.0e9", "-0x1.0E+8#1", "-1.0", "-0x1.0#1");
+    test("3.0e120", "0x1.0E+100#1", "-1.0", "-0x1.0#1");
+    test("1.0e1204", "0x1.0E+1000#1", "-0.2", "-0x0.4#1");
+}
+
+#[test]
+fn test_cos_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (cos, o) = x.clone().cos_prec(prec);
+        assert!(cos.is_valid());
+
+        assert_eq!(cos.to_string(), out);
+        assert_eq!(to_hex_string(&cos), out_hex);
+        assert_eq!(o, o_out);
+
+        let (cos_alt, o_alt) = x.cos_prec_ref(prec);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut cos_alt = x.clone();
+        let o_alt = cos_alt.cos_prec_assign(prec);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_cos, rug_o) = rug_cos_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos),
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 100, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 100, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 10, "1.0", "0x1.000#10", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 1, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 10, "1.0", "0x1.000#10", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", 1, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 10, "0.54", "0x0.8a4#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, "0.5", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", 10, "0.54", "0x0.8a4#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "-1.0",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test("9.0e-13", "0x1.0E-10#1", 1, "1.0", "0x1.0#1", Greater);
+    test("9.0e-13", "0x1.0E-10#1", 10, "1.0", "0x1.000#10", Greater);
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        100,
+        "0.9999999999999999999999995864097",
+        "0x0.ffffffffffffffffffff80000#100",
+        Less,
+    );
+    test("4.0e9", "0x1.0E+8#1", 1, "-1.0", "-0x1.0#1", Less);
+    test("4.0e9", "0x1.0E+8#1", 10, "-0.887", "-0x0.e30#10", Greater);
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        100,
+        "-0.886886915197821070108942264506",
+        "-0x0.e30b055806643a836f3c82f0f#100",
+        Less,
+    );
+    test("1.0e1204", "0x1.0E+1000#1", 1, "-0.2", "-0x0.4#1", Less);
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        10,
+        "-0.2327",
+        "-0x0.3b9#10",
+        Greater,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        100,
+        "-0.2326852859117505651367208221739",
+        "-0x0.3b91434d405a52af20d8eb0a80#100",
+        Greater,
+    );
+}
+
+#[test]
+fn cos_prec_fail() {
+    assert_panic!(Float::NAN.cos_prec(0));
+    assert_panic!(Float::NAN.cos_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.cos_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_cos_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (cos, o) = x.clone().cos_round(rm);
+        assert!(cos.is_valid());
+
+        assert_eq!(cos.to_string(), out);
+        assert_eq!(to_hex_string(&cos), out_hex);
+        assert_eq!(o, o_out);
+
+        let (cos_alt, o_alt) = x.cos_round_ref(rm);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut cos_alt = x.clone();
+        let o_alt = cos_alt.cos_round_assign(rm);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_cos, rug_o) = rug_cos_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Ceiling, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Down, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Up, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Nearest, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Exact, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Floor, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Ceiling, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Down, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Up, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Nearest, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Exact, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", Floor, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Down, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "0.5", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", Floor, "0.5", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "0.5", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", Up, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Nearest, "0.5", "0x0.8#1", Less);
+    test("2.0", "0x2.0#1", Floor, "-0.5", "-0x0.8#1", Less);
+    test("2.0", "0x2.0#1", Ceiling, "-0.2", "-0x0.4#1", Greater);
+    test("2.0", "0x2.0#1", Down, "-0.2", "-0x0.4#1", Greater);
+    test("2.0", "0x2.0#1", Up, "-0.5", "-0x0.8#1", Less);
+    test("2.0", "0x2.0#1", Nearest, "-0.5", "-0x0.8#1", Less);
+    test("0.5", "0x0.8#1", Floor, "0.5", "0x0.8#1", Less);
+    test("0.5", "0x0.8#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("0.5", "0x0.8#1", Down, "0.5", "0x0.8#1", Less);
+    test("0.5", "0x0.8#1", Up, "1.0", "0x1.0#1", Greater);
+    test("0.5", "0x0.8#1", Nearest, "1.0", "0x1.0#1", Greater);
+    test("-0.5", "-0x0.8#1", Floor, "0.5", "0x0.8#1", Less);
+    test("-0.5", "-0x0.8#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("-0.5", "-0x0.8#1", Down, "0.5", "0x0.8#1", Less);
+    test("-0.5", "-0x0.8#1", Up, "1.0", "0x1.0#1", Greater);
+    test("-0.5", "-0x0.8#1", Nearest, "1.0", "0x1.0#1", Greater);
+    test("123.0", "0x7b.0#7", Floor, "-0.89", "-0x0.e4#7", Less);
+    test("123.0", "0x7b.0#7", Ceiling, "-0.883", "-0x0.e2#7", Greater);
+    test("123.0", "0x7b.0#7", Down, "-0.883", "-0x0.e2#7", Greater);
+    test("123.0", "0x7b.0#7", Up, "-0.89", "-0x0.e4#7", Less);
+    test("123.0", "0x7b.0#7", Nearest, "-0.89", "-0x0.e4#7", Less);
+    test("-123.0", "-0x7b.0#7", Floor, "-0.89", "-0x0.e4#7", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Ceiling,
+        "-0.883",
+        "-0x0.e2#7",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", Down, "-0.883", "-0x0.e2#7", Greater);
+    test("-123.0", "-0x7b.0#7", Up, "-0.89", "-0x0.e4#7", Less);
+    test("-123.0", "-0x7b.0#7", Nearest, "-0.89", "-0x0.e4#7", Less);
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        Floor,
+        "0.15594369476537437",
+        "0x0.27ebed0d08e166#53",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        Ceiling,
+        "0.1559436947653744",
+        "0x0.27ebed0d08e168#53",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        Down,
+        "0.15594369476537437",
+        "0x0.27ebed0d08e166#53",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        Up,
+        "0.1559436947653744",
+        "0x0.27ebed0d08e168#53",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        Nearest,
+        "0.15594369476537437",
+        "0x0.27ebed0d08e166#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Floor,
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ceiling,
+        "-0.9999999999999999",
+        "-0x0.fffffffffffff8#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Down,
+        "-0.9999999999999999",
+        "-0x0.fffffffffffff8#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Up,
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Nearest,
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Floor,
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Ceiling,
+        "-0.9999999999999999",
+        "-0x0.fffffffffffff8#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Down,
+        "-0.9999999999999999",
+        "-0x0.fffffffffffff8#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Up,
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Nearest,
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Less,
+    );
+    test("9.0e-13", "0x1.0E-10#1", Floor, "0.5", "0x0.8#1", Less);
+    test("9.0e-13", "0x1.0E-10#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("9.0e-13", "0x1.0E-10#1", Down, "0.5", "0x0.8#1", Less);
+    test("9.0e-13", "0x1.0E-10#1", Up, "1.0", "0x1.0#1", Greater);
+    test("9.0e-13", "0x1.0E-10#1", Nearest, "1.0", "0x1.0#1", Greater);
+    test("-9.0e-13", "-0x1.0E-10#1", Floor, "0.5", "0x0.8#1", Less);
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test("-9.0e-13", "-0x1.0E-10#1", Down, "0.5", "0x0.8#1", Less);
+    test("-9.0e-13", "-0x1.0E-10#1", Up, "1.0", "0x1.0#1", Greater);
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test("1000.0", "0x3e8.0#10", Floor, "0.5615", "0x0.8fc#10", Less);
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Ceiling,
+        "0.5625",
+        "0x0.900#10",
+        Greater,
+    );
+    test("1000.0", "0x3e8.0#10", Down, "0.5615", "0x0.8fc#10", Less);
+    test("1000.0", "0x3e8.0#10", Up, "0.5625", "0x0.900#10", Greater);
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Nearest,
+        "0.5625",
+        "0x0.900#10",
+        Greater,
+    );
+    test("4.0e9", "0x1.0E+8#1", Floor, "-1.0", "-0x1.0#1", Less);
+    test("4.0e9", "0x1.0E+8#1", Ceiling, "-0.5", "-0x0.8#1", Greater);
+    test("4.0e9", "0x1.0E+8#1", Down, "-0.5", "-0x0.8#1", Greater);
+    test("4.0e9", "0x1.0E+8#1", Up, "-1.0", "-0x1.0#1", Less);
+    test("4.0e9", "0x1.0E+8#1", Nearest, "-1.0", "-0x1.0#1", Less);
+    test("-4.0e9", "-0x1.0E+8#1", Floor, "-1.0", "-0x1.0#1", Less);
+    test(
+        "-4.0e9",
+        "-0x1.0E+8#1",
+        Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Greater,
+    );
+    test("-4.0e9", "-0x1.0E+8#1", Down, "-0.5", "-0x0.8#1", Greater);
+    test("-4.0e9", "-0x1.0E+8#1", Up, "-1.0", "-0x1.0#1", Less);
+    test("-4.0e9", "-0x1.0E+8#1", Nearest, "-1.0", "-0x1.0#1", Less);
+    test("3.0e120", "0x1.0E+100#1", Floor, "-1.0", "-0x1.0#1", Less);
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Greater,
+    );
+    test("3.0e120", "0x1.0E+100#1", Down, "-0.5", "-0x0.8#1", Greater);
+    test("3.0e120", "0x1.0E+100#1", Up, "-1.0", "-0x1.0#1", Less);
+    test("3.0e120", "0x1.0E+100#1", Nearest, "-1.0", "-0x1.0#1", Less);
+    test("1.0e1204", "0x1.0E+1000#1", Floor, "-0.2", "-0x0.4#1", Less);
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        Ceiling,
+        "-0.1",
+        "-0x0.2#1",
+        Greater,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        Down,
+        "-0.1",
+        "-0x0.2#1",
+        Greater,
+    );
+    test("1.0e1204", "0x1.0E+1000#1", Up, "-0.2", "-0x0.4#1", Less);
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        Nearest,
+        "-0.2",
+        "-0x0.4#1",
+        Less,
+    );
+}
+
+#[test]
+fn cos_round_fail() {
+    const THREE: Float = Float::const_from_unsigned(3);
+    assert_panic!(THREE.cos_round(Exact));
+    assert_panic!(THREE.cos_round_ref(Exact));
+    assert_panic!({
+        let mut x = THREE;
+        x.cos_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_cos_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (cos, o) = x.clone().cos_prec_round(prec, rm);
+        assert!(cos.is_valid());
+
+        assert_eq!(cos.to_string(), out);
+        assert_eq!(to_hex_string(&cos), out_hex);
+        assert_eq!(o, o_out);
+
+        let (cos_alt, o_alt) = x.cos_prec_round_ref(prec, rm);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut cos_alt = x.clone();
+        let o_alt = cos_alt.cos_prec_round_assign(prec, rm);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_cos, rug_o) = rug_cos_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 10, Exact, "1.0", "0x1.000#10", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        Exact,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 10, Exact, "1.0", "0x1.000#10", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        Exact,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", 1, Floor, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 10, Floor, "0.54", "0x0.8a4#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.541",
+        "0x0.8a8#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Down, "0.54", "0x0.8a4#10", Less);
+    test("1.0", "0x1.0#1", 10, Up, "0.541", "0x0.8a8#10", Greater);
+    test("1.0", "0x1.0#1", 10, Nearest, "0.54", "0x0.8a4#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Floor,
+        "0.5403023058681397174009366074426",
+        "0x0.8a51407da8345c91c2466d976#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Ceiling,
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Down,
+        "0.5403023058681397174009366074426",
+        "0x0.8a51407da8345c91c2466d976#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Up,
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Nearest,
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, Floor, "0.5", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Down, "0.5", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "0.5", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", 10, Floor, "0.54", "0x0.8a4#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "0.541",
+        "0x0.8a8#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 10, Down, "0.54", "0x0.8a4#10", Less);
+    test("-1.0", "-0x1.0#1", 10, Up, "0.541", "0x0.8a8#10", Greater);
+    test("-1.0", "-0x1.0#1", 10, Nearest, "0.54", "0x0.8a4#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Floor,
+        "0.5403023058681397174009366074426",
+        "0x0.8a51407da8345c91c2466d976#100",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Ceiling,
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Down,
+        "0.5403023058681397174009366074426",
+        "0x0.8a51407da8345c91c2466d976#100",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Up,
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Nearest,
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Down,
+        "-0.5",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Up,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "-1.0",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "-0.999",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Down,
+        "-0.999",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Up,
+        "-1.0",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "-1.0",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Floor,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Ceiling,
+        "-0.999999999999999999999999999999",
+        "-0x0.fffffffffffffffffffffffff#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Down,
+        "-0.999999999999999999999999999999",
+        "-0x0.fffffffffffffffffffffffff#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Up,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Nearest,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        1,
+        Floor,
+        "-0.2",
+        "-0x0.4#1",
+        Less,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        1,
+        Ceiling,
+        "-0.1",
+        "-0x0.2#1",
+        Greater,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        1,
+        Down,
+        "-0.1",
+        "-0x0.2#1",
+        Greater,
+    );
+    test("1.0e1204", "0x1.0E+1000#1", 1, Up, "-0.2", "-0x0.4#1", Less);
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        1,
+        Nearest,
+        "-0.2",
+        "-0x0.4#1",
+        Less,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        10,
+        Floor,
+        "-0.2329",
+        "-0x0.3ba#10",
+        Less,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        10,
+        Ceiling,
+        "-0.2327",
+        "-0x0.3b9#10",
+        Greater,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        10,
+        Down,
+        "-0.2327",
+        "-0x0.3b9#10",
+        Greater,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        10,
+        Up,
+        "-0.2329",
+        "-0x0.3ba#10",
+        Less,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        10,
+        Nearest,
+        "-0.2327",
+        "-0x0.3b9#10",
+        Greater,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        100,
+        Floor,
+        "-0.2326852859117505651367208221741",
+        "-0x0.3b91434d405a52af20d8eb0a84#100",
+        Less,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        100,
+        Ceiling,
+        "-0.2326852859117505651367208221739",
+        "-0x0.3b91434d405a52af20d8eb0a80#100",
+        Greater,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        100,
+        Down,
+        "-0.2326852859117505651367208221739",
+        "-0x0.3b91434d405a52af20d8eb0a80#100",
+        Greater,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        100,
+        Up,
+        "-0.2326852859117505651367208221741",
+        "-0x0.3b91434d405a52af20d8eb0a84#100",
+        Less,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        100,
+        Nearest,
+        "-0.2326852859117505651367208221739",
+        "-0x0.3b91434d405a52af20d8eb0a80#100",
+        Greater,
+    );
+}
+
+#[test]
+fn cos_prec_round_fail() {
+    const THREE: Float = Float::const_from_unsigned(3);
+    assert_panic!(Float::one_prec(1).cos_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).cos_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.cos_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(THREE.cos_prec_round(1, Exact));
+    assert_panic!(THREE.cos_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = THREE;
+        x.cos_prec_round_assign(1, Exact)
+    });
+}
+
+fn cos_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (cos, o) = x.clone().cos_prec_round(prec, rm);
+    assert!(cos.is_valid());
+    let (cos_alt, o_alt) = x.cos_prec_round_ref(prec, rm);
+    assert!(cos_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.cos_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_cos, rug_o) = rug_cos_prec_round(&rug::Float::exact_from(&x), prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if cos.is_normal() {
+        assert_eq!(cos.get_prec(), Some(prec));
+    }
+
+    if cos.is_finite() {
+        assert!(cos.le_abs(&1u32));
+    }
+
+    let (neg_cos, neg_o) = (-&x).cos_prec_round(prec, rm);
+    assert_eq!(ComparableFloatRef(&neg_cos), ComparableFloatRef(&cos));
+    assert_eq!(neg_o, o);
+
+    match rm {
+        Floor => assert_ne!(o, Greater),
+        Ceiling => assert_ne!(o, Less),
+        Down => {
+            if cos.is_sign_positive() {
+                assert_ne!(o, Greater);
+            } else {
+                assert_ne!(o, Less);
+            }
+        }
+        Up => {
+            if cos.is_sign_positive() {
+                assert_ne!(o, Less);
+            } else {
+                assert_ne!(o, Greater);
+            }
+        }
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.cos_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(cos.clone()));
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.cos_prec_round(prec, Exact));
+    }
+}
+
+#[test]
+fn cos_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties(|(x, prec, rm)| {
+        cos_prec_round_properties_helper(x, prec, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties_with_config(
+        &config,
+        |(x, prec, rm)| {
+            cos_prec_round_properties_helper(x, prec, rm);
+        },
+    );
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (cos, o) = Float::NAN.cos_prec_round(prec, rm);
+        assert!(cos.is_nan());
+        assert_eq!(o, Equal);
+        let (cos, o) = Float::INFINITY.cos_prec_round(prec, rm);
+        assert!(cos.is_nan());
+        assert_eq!(o, Equal);
+        let (cos, o) = Float::NEGATIVE_INFINITY.cos_prec_round(prec, rm);
+        assert!(cos.is_nan());
+        assert_eq!(o, Equal);
+        let (cos, o) = Float::ZERO.cos_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(cos), ComparableFloat(Float::one_prec(prec)));
+        assert_eq!(o, Equal);
+        let (cos, o) = Float::NEGATIVE_ZERO.cos_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(cos), ComparableFloat(Float::one_prec(prec)));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn cos_prec_properties_helper(x: Float, prec: u64) {
+    let (cos, o) = x.clone().cos_prec(prec);
+    assert!(cos.is_valid());
+    let (cos_alt, o_alt) = x.cos_prec_ref(prec);
+    assert!(cos_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.cos_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_alt, o);
+
+    let (rug_cos, rug_o) = rug_cos_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_cos)),
+        ComparableFloatRef(&cos),
+    );
+    assert_eq!(rug_o, o);
+
+    let (cos_alt, o_alt) = x.cos_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_alt, o);
+
+    if cos.is_normal() {
+        assert_eq!(cos.get_prec(), Some(prec));
+    }
+
+    if cos.is_finite() {
+        assert!(cos.le_abs(&1u32));
+    }
+
+    let (neg_cos, neg_o) = (-&x).cos_prec(prec);
+    assert_eq!(ComparableFloatRef(&neg_cos), ComparableFloatRef(&cos));
+    assert_eq!(neg_o, o);
+}
+
+#[test]
+fn cos_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        cos_prec_properties_helper(x, prec);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_pair_gen_var_1().test_properties_with_config(&config, |(x, prec)| {
+        cos_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (cos, o) = Float::NAN.cos_prec(prec);
+        assert!(cos.is_nan());
+        assert_eq!(o, Equal);
+        let (cos, o) = Float::INFINITY.cos_prec(prec);
+        assert!(cos.is_nan());
+        assert_eq!(o, Equal);
+        let (cos, o) = Float::NEGATIVE_INFINITY.cos_prec(prec);
+        assert!(cos.is_nan());
+        assert_eq!(o, Equal);
+        let (cos, o) = Float::ZERO.cos_prec(prec);
+        assert_eq!(ComparableFloat(cos), ComparableFloat(Float::one_prec(prec)));
+        assert_eq!(o, Equal);
+        let (cos, o) = Float::NEGATIVE_ZERO.cos_prec(prec);
+        assert_eq!(ComparableFloat(cos), ComparableFloat(Float::one_prec(prec)));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn cos_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (cos, o) = x.clone().cos_round(rm);
+    assert!(cos.is_valid());
+    let (cos_alt, o_alt) = x.cos_round_ref(rm);
+    assert!(cos_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.cos_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_cos, rug_o) = rug_cos_round(&rug::Float::exact_from(&x), rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    let (cos_alt, o_alt) = x.cos_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_alt, o);
+
+    if cos.is_normal() {
+        assert_eq!(cos.get_prec(), Some(x.significant_bits()));
+    }
+
+    if cos.is_finite() {
+        assert!(cos.le_abs(&1u32));
+    }
+
+    let (neg_cos, neg_o) = (-&x).cos_round(rm);
+    assert_eq!(ComparableFloatRef(&neg_cos), ComparableFloatRef(&cos));
+    assert_eq!(neg_o, o);
+
+    match rm {
+        Floor => assert_ne!(o, Greater),
+        Ceiling => assert_ne!(o, Less),
+        Down => {
+            if cos.is_sign_positive() {
+                assert_ne!(o, Greater);
+            } else {
+                assert_ne!(o, Less);
+            }
+        }
+        Up => {
+            if cos.is_sign_positive() {
+                assert_ne!(o, Less);
+            } else {
+                assert_ne!(o, Greater);
+            }
+        }
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+}
+
+#[test]
+fn cos_round_properties() {
+    float_rounding_mode_pair_gen_var_25().test_properties(|(x, rm)| {
+        cos_round_properties_helper(x, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_rounding_mode_pair_gen_var_25().test_properties_with_config(&config, |(x, rm)| {
+        cos_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (cos, o) = Float::NAN.cos_round(rm);
+        assert!(cos.is_nan());
+        assert_eq!(o, Equal);
+        let (cos, o) = Float::INFINITY.cos_round(rm);
+        assert!(cos.is_nan());
+        assert_eq!(o, Equal);
+        let (cos, o) = Float::NEGATIVE_INFINITY.cos_round(rm);
+        assert!(cos.is_nan());
+        assert_eq!(o, Equal);
+        let (cos, o) = Float::ZERO.cos_round(rm);
+        assert_eq!(ComparableFloat(cos), ComparableFloat(Float::ONE));
+        assert_eq!(o, Equal);
+        let (cos, o) = Float::NEGATIVE_ZERO.cos_round(rm);
+        assert_eq!(ComparableFloat(cos), ComparableFloat(Float::ONE));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn cos_properties_helper(x: Float) {
+    let cos = x.clone().cos();
+    assert!(cos.is_valid());
+
+    let cos_alt = (&x).cos();
+    assert!(cos_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+
+    let mut x_alt = x.clone();
+    x_alt.cos_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&cos));
+
+    let rug_cos = rug_cos(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_cos)),
+        ComparableFloatRef(&cos),
+    );
+
+    assert_eq!(
+        ComparableFloatRef(&x.cos_round_ref(Nearest).0),
+        ComparableFloatRef(&cos)
+    );
+
+    if cos.is_finite() {
+        assert!(cos.le_abs(&1u32));
+    }
+}
+
+#[test]
+fn cos_properties() {
+    float_gen().test_properties(|x| {
+        cos_properties_helper(x);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_gen().test_properties_with_config(&config, |x| {
+        cos_properties_helper(x);
+    });
+
+    float_gen_var_6().test_properties(|x| {
+        cos_properties_helper(x);
+    });
+
+    float_gen_var_7().test_properties(|x| {
+        cos_properties_helper(x);
+    });
+
+    float_gen_var_8().test_properties(|x| {
+        cos_properties_helper(x);
+    });
+
+    float_gen_var_11().test_properties(|x| {
+        cos_properties_helper(x);
+    });
+}
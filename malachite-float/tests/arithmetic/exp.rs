@@ -0,0 +1,1129 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{Exp, ExpAssign};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::{
+    rounding_mode_gen, unsigned_gen_var_11, unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::test_util::arithmetic::exp::{
+    rug_exp, rug_exp_prec, rug_exp_prec_round, rug_exp_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_gen_var_6, float_gen_var_7, float_gen_var_8, float_gen_var_11,
+    float_rounding_mode_pair_gen_var_25, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_14,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_nz::platform::Limb;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_exp() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let exp = x.clone().exp();
+        assert!(exp.is_valid());
+
+        assert_eq!(exp.to_string(), out);
+        assert_eq!(to_hex_string(&exp), out_hex);
+
+        let exp_alt = (&x).exp();
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp), ComparableFloatRef(&exp_alt));
+
+        let mut exp_alt = x.clone();
+        exp_alt.exp_assign();
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp), ComparableFloatRef(&exp_alt));
+
+        let rug_exp = rug_exp(&rug::Float::exact_from(&x));
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_exp)),
+            ComparableFloatRef(&exp),
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", "0.0", "0x0.0");
+    test("0.0", "0x0.0", "1.0", "0x1.0#1");
+    test("-0.0", "-0x0.0", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "2.0", "0x2.0#1");
+    test("-1.0", "-0x1.0#1", "0.2", "0x0.4#1");
+    test("2.0", "0x2.0#1", "8.0", "0x8.0#1");
+    test("0.5", "0x0.8#1", "2.0", "0x2.0#1");
+    test("-0.5", "-0x0.8#1", "0.5", "0x0.8#1");
+    test("123.0", "0x7b.0#7", "2.63e53", "0x2.c0E+44#7");
+    test("-123.0", "-0x7b.0#7", "3.83e-54", "0x5.eE-45#7");
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "4.1132503787829275",
+        "0x4.1cfdfa1121dd8#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "23.140692632779267",
+        "0x17.24046eb09339#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "0.043213918263772258",
+        "0x0.0b10113dacc77d0#53",
+    );
+    test("9.0e-13", "0x1.0E-10#1", "1.0", "0x1.0#1");
+    test("-9.0e-13", "-0x1.0E-10#1", "1.0", "0x1.0#1");
+    test("1000.0", "0x3e8.0#10", "1.97e434", "0x6.7aE+360#10");
+    test("4.0e9", "0x1.0E+8#1", "Infinity", "Infinity");
+    test("-4.0e9", "-0x1.0E+8#1", "0.0", "0x0.0");
+}
+
+#[test]
+fn test_exp_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp, o) = x.clone().exp_prec(prec);
+        assert!(exp.is_valid());
+
+        assert_eq!(exp.to_string(), out);
+        assert_eq!(to_hex_string(&exp), out_hex);
+        assert_eq!(o, o_out);
+
+        let (exp_alt, o_alt) = x.exp_prec_ref(prec);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp), ComparableFloatRef(&exp_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp_alt = x.clone();
+        let o_alt = exp_alt.exp_prec_assign(prec);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp), ComparableFloatRef(&exp_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_exp, rug_o) = rug_exp_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_exp)),
+            ComparableFloatRef(&exp),
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", 10, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", 100, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", 1, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 10, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 100, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 10, "1.0", "0x1.000#10", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 1, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 10, "1.0", "0x1.000#10", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", 1, "2.0", "0x2.0#1", Less);
+    test("1.0", "0x1.0#1", 10, "2.719", "0x2.b8#10", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "2.718281828459045235360287471351",
+        "0x2.b7e151628aed2a6abf7158808#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, "0.2", "0x0.4#1", Less);
+    test("-1.0", "-0x1.0#1", 10, "0.3677", "0x0.5e2#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "0.3678794411714423215955237701614",
+        "0x0.5e2d58d8b3bcdf1abadec78290#100",
+        Less,
+    );
+    test("2.0", "0x2.0#1", 1, "8.0", "0x8.0#1", Greater);
+    test("2.0", "0x2.0#1", 10, "7.39", "0x7.64#10", Greater);
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        "7.389056098930650227230427460577",
+        "0x7.63992e35376b730ce8ee881b0#100",
+        Greater,
+    );
+    test("0.5", "0x0.8#1", 1, "2.0", "0x2.0#1", Greater);
+    test("0.5", "0x0.8#1", 10, "1.648", "0x1.a60#10", Less);
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "1.648721270700128146848650787814",
+        "0x1.a61298e1e069bc972dfefab6e#100",
+        Greater,
+    );
+    test("-0.5", "-0x0.8#1", 1, "0.5", "0x0.8#1", Less);
+    test("-0.5", "-0x0.8#1", 10, "0.606", "0x0.9b4#10", Less);
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        100,
+        "0.606530659712633423603799534991",
+        "0x0.9b4597e37cb04ff3d675a3553#100",
+        Less,
+    );
+    test("123.0", "0x7b.0#7", 1, "2.0e53", "0x2.0E+44#1", Less);
+    test("123.0", "0x7b.0#7", 10, "2.619e53", "0x2.bcE+44#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "2.619517318749062676188981025375e53",
+        "0x2.bc22a5f3502327274223b3e68E+44#100",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", 1, "3.0e-54", "0x4.0E-45#1", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        "3.818e-54",
+        "0x5.daE-45#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        "3.817497188671174625733113216891e-54",
+        "0x5.d9ad177613c5f1acc3ba61090E-45#100",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        10,
+        "4.11",
+        "0x4.1c#10",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        100,
+        "4.113250378782927914813560648797",
+        "0x4.1cfdfa1121dd9aef738ad9460#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "23.16",
+        "0x17.28#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "23.14069263277926617181157008936",
+        "0x17.24046eb09338d2991a30893e#100",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "0.03",
+        "0x0.08#1",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "0.04321",
+        "0x0.0b10#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        "0.04321391826377225506659640520612",
+        "0x0.0b10113dacc77cca4f307226e2#100",
+        Less,
+    );
+    test("9.0e-13", "0x1.0E-10#1", 1, "1.0", "0x1.0#1", Less);
+    test("9.0e-13", "0x1.0E-10#1", 10, "1.0", "0x1.000#10", Less);
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        100,
+        "1.000000000000909494701773341828",
+        "0x1.0000000001000000000080000#100",
+        Less,
+    );
+    test("-9.0e-13", "-0x1.0E-10#1", 1, "1.0", "0x1.0#1", Greater);
+    test("-9.0e-13", "-0x1.0E-10#1", 10, "1.0", "0x1.000#10", Greater);
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        100,
+        "0.9999999999990905052982274853524",
+        "0x0.ffffffffff000000000080000#100",
+        Greater,
+    );
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        1,
+        "2.0e434",
+        "0x8.0E+360#1",
+        Greater,
+    );
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        10,
+        "1.97e434",
+        "0x6.7aE+360#10",
+        Greater,
+    );
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        100,
+        "1.970071114017046993888879352244e434",
+        "0x6.79c8de6bb5ceb60158acfea90E+360#100",
+        Greater,
+    );
+    test("4.0e9", "0x1.0E+8#1", 1, "Infinity", "Infinity", Greater);
+    test("4.0e9", "0x1.0E+8#1", 10, "Infinity", "Infinity", Greater);
+    test("4.0e9", "0x1.0E+8#1", 100, "Infinity", "Infinity", Greater);
+    test("-4.0e9", "-0x1.0E+8#1", 1, "0.0", "0x0.0", Less);
+    test("-4.0e9", "-0x1.0E+8#1", 10, "0.0", "0x0.0", Less);
+    test("-4.0e9", "-0x1.0E+8#1", 100, "0.0", "0x0.0", Less);
+}
+
+#[test]
+fn exp_prec_fail() {
+    assert_panic!(Float::NAN.exp_prec(0));
+    assert_panic!(Float::NAN.exp_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.exp_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_exp_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp, o) = x.clone().exp_round(rm);
+        assert!(exp.is_valid());
+
+        assert_eq!(exp.to_string(), out);
+        assert_eq!(to_hex_string(&exp), out_hex);
+        assert_eq!(o, o_out);
+
+        let (exp_alt, o_alt) = x.exp_round_ref(rm);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp), ComparableFloatRef(&exp_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp_alt = x.clone();
+        let o_alt = exp_alt.exp_round_assign(rm);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp), ComparableFloatRef(&exp_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_exp, rug_o) = rug_exp_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp)),
+                ComparableFloatRef(&exp),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Floor, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Floor, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", Floor, "2.0", "0x2.0#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "4.0", "0x4.0#1", Greater);
+    test("1.0", "0x1.0#1", Down, "2.0", "0x2.0#1", Less);
+    test("1.0", "0x1.0#1", Up, "4.0", "0x4.0#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "2.0", "0x2.0#1", Less);
+    test("2.0", "0x2.0#1", Floor, "4.0", "0x4.0#1", Less);
+    test("2.0", "0x2.0#1", Ceiling, "8.0", "0x8.0#1", Greater);
+    test("2.0", "0x2.0#1", Down, "4.0", "0x4.0#1", Less);
+    test("2.0", "0x2.0#1", Up, "8.0", "0x8.0#1", Greater);
+    test("2.0", "0x2.0#1", Nearest, "8.0", "0x8.0#1", Greater);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Floor,
+        "23.140692632779263",
+        "0x17.24046eb09338#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ceiling,
+        "23.140692632779267",
+        "0x17.24046eb09339#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Down,
+        "23.140692632779263",
+        "0x17.24046eb09338#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Up,
+        "23.140692632779267",
+        "0x17.24046eb09339#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Nearest,
+        "23.140692632779267",
+        "0x17.24046eb09339#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Floor,
+        "0.04321391826377225",
+        "0x0.0b10113dacc77c8#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Ceiling,
+        "0.043213918263772258",
+        "0x0.0b10113dacc77d0#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Down,
+        "0.04321391826377225",
+        "0x0.0b10113dacc77c8#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Up,
+        "0.043213918263772258",
+        "0x0.0b10113dacc77d0#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Nearest,
+        "0.043213918263772258",
+        "0x0.0b10113dacc77d0#53",
+        Greater,
+    );
+    test("9.0e-13", "0x1.0E-10#1", Floor, "1.0", "0x1.0#1", Less);
+    test("9.0e-13", "0x1.0E-10#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("9.0e-13", "0x1.0E-10#1", Down, "1.0", "0x1.0#1", Less);
+    test("9.0e-13", "0x1.0E-10#1", Up, "2.0", "0x2.0#1", Greater);
+    test("9.0e-13", "0x1.0E-10#1", Nearest, "1.0", "0x1.0#1", Less);
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Floor,
+        "1.968e434",
+        "0x6.78E+360#10",
+        Less,
+    );
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Ceiling,
+        "1.97e434",
+        "0x6.7aE+360#10",
+        Greater,
+    );
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Down,
+        "1.968e434",
+        "0x6.78E+360#10",
+        Less,
+    );
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Up,
+        "1.97e434",
+        "0x6.7aE+360#10",
+        Greater,
+    );
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Nearest,
+        "1.97e434",
+        "0x6.7aE+360#10",
+        Greater,
+    );
+}
+
+#[test]
+fn exp_round_fail() {
+    const THREE: Float = Float::const_from_unsigned(3);
+    assert_panic!(THREE.exp_round(Exact));
+    assert_panic!(THREE.exp_round_ref(Exact));
+    assert_panic!({
+        let mut x = THREE;
+        x.exp_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_exp_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp, o) = x.clone().exp_prec_round(prec, rm);
+        assert!(exp.is_valid());
+
+        assert_eq!(exp.to_string(), out);
+        assert_eq!(to_hex_string(&exp), out_hex);
+        assert_eq!(o, o_out);
+
+        let (exp_alt, o_alt) = x.exp_prec_round_ref(prec, rm);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp), ComparableFloatRef(&exp_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp_alt = x.clone();
+        let o_alt = exp_alt.exp_prec_round_assign(prec, rm);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp), ComparableFloatRef(&exp_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_exp, rug_o) = rug_exp_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp)),
+                ComparableFloatRef(&exp),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test(
+        "Infinity", "Infinity", 1, Floor, "Infinity", "Infinity", Equal,
+    );
+    test("-Infinity", "-Infinity", 1, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "2.0", "0x2.0#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "4.0", "0x4.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "2.0", "0x2.0#1", Less);
+    test("1.0", "0x1.0#1", 10, Floor, "2.715", "0x2.b7#10", Less);
+    test("1.0", "0x1.0#1", 10, Ceiling, "2.719", "0x2.b8#10", Greater);
+    test("1.0", "0x1.0#1", 10, Nearest, "2.719", "0x2.b8#10", Greater);
+    test("2.0", "0x2.0#1", 1, Floor, "4.0", "0x4.0#1", Less);
+    test("2.0", "0x2.0#1", 1, Ceiling, "8.0", "0x8.0#1", Greater);
+    test("2.0", "0x2.0#1", 1, Nearest, "8.0", "0x8.0#1", Greater);
+    test("2.0", "0x2.0#1", 10, Floor, "7.383", "0x7.62#10", Less);
+    test("2.0", "0x2.0#1", 10, Ceiling, "7.39", "0x7.64#10", Greater);
+    test("2.0", "0x2.0#1", 10, Nearest, "7.39", "0x7.64#10", Greater);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Floor,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Ceiling,
+        "3.0e1",
+        "0x2.0E+1#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Nearest,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "23.12",
+        "0x17.20#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "23.16",
+        "0x17.28#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "23.16",
+        "0x17.28#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        Floor,
+        "0.03",
+        "0x0.08#1",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        Ceiling,
+        "0.06",
+        "0x0.1#1",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        Nearest,
+        "0.03",
+        "0x0.08#1",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "0.04321",
+        "0x0.0b10#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "0.04327",
+        "0x0.0b14#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "0.04321",
+        "0x0.0b10#10",
+        Less,
+    );
+    test("9.0e-13", "0x1.0E-10#1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test("9.0e-13", "0x1.0E-10#1", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        Floor,
+        "1.0",
+        "0x1.000#10",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        Ceiling,
+        "1.002",
+        "0x1.008#10",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        Nearest,
+        "1.0",
+        "0x1.000#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Floor,
+        "23.14069263277926617181157008936",
+        "0x17.24046eb09338d2991a30893e#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Ceiling,
+        "23.14069263277926617181157008938",
+        "0x17.24046eb09338d2991a308940#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Down,
+        "23.14069263277926617181157008936",
+        "0x17.24046eb09338d2991a30893e#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Up,
+        "23.14069263277926617181157008938",
+        "0x17.24046eb09338d2991a308940#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Nearest,
+        "23.14069263277926617181157008936",
+        "0x17.24046eb09338d2991a30893e#100",
+        Less,
+    );
+}
+
+#[test]
+fn exp_prec_round_fail() {
+    const THREE: Float = Float::const_from_unsigned(3);
+    assert_panic!(Float::one_prec(1).exp_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).exp_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.exp_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(THREE.exp_prec_round(1, Exact));
+    assert_panic!(THREE.exp_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = THREE;
+        x.exp_prec_round_assign(1, Exact)
+    });
+}
+
+fn exp_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (exp, o) = x.clone().exp_prec_round(prec, rm);
+    assert!(exp.is_valid());
+    let (exp_alt, o_alt) = x.exp_prec_round_ref(prec, rm);
+    assert!(exp_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&exp_alt), ComparableFloatRef(&exp));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.exp_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_exp, rug_o) = rug_exp_prec_round(&rug::Float::exact_from(&x), prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_exp)),
+            ComparableFloatRef(&exp),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if exp.is_normal() {
+        assert_eq!(exp.get_prec(), Some(prec));
+    }
+
+    if !x.is_nan() {
+        assert!(exp >= 0u32);
+    }
+
+    match rm {
+        Floor => assert_ne!(o, Greater),
+        Ceiling => assert_ne!(o, Less),
+        Down => {
+            if exp.is_sign_positive() {
+                assert_ne!(o, Greater);
+            } else {
+                assert_ne!(o, Less);
+            }
+        }
+        Up => {
+            if exp.is_sign_positive() {
+                assert_ne!(o, Less);
+            } else {
+                assert_ne!(o, Greater);
+            }
+        }
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.exp_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(exp.clone()));
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.exp_prec_round(prec, Exact));
+    }
+}
+
+#[test]
+fn exp_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties(|(x, prec, rm)| {
+        exp_prec_round_properties_helper(x, prec, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties_with_config(
+        &config,
+        |(x, prec, rm)| {
+            exp_prec_round_properties_helper(x, prec, rm);
+        },
+    );
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (exp, o) = Float::NAN.exp_prec_round(prec, rm);
+        assert!(exp.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(
+            Float::INFINITY.exp_prec_round(prec, rm),
+            (Float::INFINITY, Equal)
+        );
+        let (exp, o) = Float::NEGATIVE_INFINITY.exp_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(exp), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        assert_eq!(
+            Float::ZERO.exp_prec_round(prec, rm),
+            (Float::one_prec(prec), Equal)
+        );
+        assert_eq!(
+            Float::NEGATIVE_ZERO.exp_prec_round(prec, rm),
+            (Float::one_prec(prec), Equal)
+        );
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn exp_prec_properties_helper(x: Float, prec: u64) {
+    let (exp, o) = x.clone().exp_prec(prec);
+    assert!(exp.is_valid());
+    let (exp_alt, o_alt) = x.exp_prec_ref(prec);
+    assert!(exp_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&exp_alt), ComparableFloatRef(&exp));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.exp_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp));
+    assert_eq!(o_alt, o);
+
+    let (rug_exp, rug_o) = rug_exp_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_exp)),
+        ComparableFloatRef(&exp),
+    );
+    assert_eq!(rug_o, o);
+
+    let (exp_alt, o_alt) = x.exp_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&exp_alt), ComparableFloatRef(&exp));
+    assert_eq!(o_alt, o);
+
+    if exp.is_normal() {
+        assert_eq!(exp.get_prec(), Some(prec));
+    }
+
+    if !x.is_nan() {
+        assert!(exp >= 0u32);
+    }
+}
+
+#[test]
+fn exp_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        exp_prec_properties_helper(x, prec);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_pair_gen_var_1().test_properties_with_config(&config, |(x, prec)| {
+        exp_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (exp, o) = Float::NAN.exp_prec(prec);
+        assert!(exp.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(Float::INFINITY.exp_prec(prec), (Float::INFINITY, Equal));
+        let (exp, o) = Float::NEGATIVE_INFINITY.exp_prec(prec);
+        assert_eq!(ComparableFloat(exp), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        assert_eq!(Float::ZERO.exp_prec(prec), (Float::one_prec(prec), Equal));
+        assert_eq!(
+            Float::NEGATIVE_ZERO.exp_prec(prec),
+            (Float::one_prec(prec), Equal)
+        );
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn exp_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (exp, o) = x.clone().exp_round(rm);
+    assert!(exp.is_valid());
+    let (exp_alt, o_alt) = x.exp_round_ref(rm);
+    assert!(exp_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&exp_alt), ComparableFloatRef(&exp));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.exp_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_exp, rug_o) = rug_exp_round(&rug::Float::exact_from(&x), rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_exp)),
+            ComparableFloatRef(&exp),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    let (exp_alt, o_alt) = x.exp_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&exp_alt), ComparableFloatRef(&exp));
+    assert_eq!(o_alt, o);
+
+    if exp.is_normal() {
+        assert_eq!(exp.get_prec(), Some(x.significant_bits()));
+    }
+
+    if !x.is_nan() {
+        assert!(exp >= 0u32);
+    }
+
+    match rm {
+        Floor => assert_ne!(o, Greater),
+        Ceiling => assert_ne!(o, Less),
+        Down => {
+            if exp.is_sign_positive() {
+                assert_ne!(o, Greater);
+            } else {
+                assert_ne!(o, Less);
+            }
+        }
+        Up => {
+            if exp.is_sign_positive() {
+                assert_ne!(o, Less);
+            } else {
+                assert_ne!(o, Greater);
+            }
+        }
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+}
+
+#[test]
+fn exp_round_properties() {
+    float_rounding_mode_pair_gen_var_25().test_properties(|(x, rm)| {
+        exp_round_properties_helper(x, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_rounding_mode_pair_gen_var_25().test_properties_with_config(&config, |(x, rm)| {
+        exp_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (exp, o) = Float::NAN.exp_round(rm);
+        assert!(exp.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(Float::INFINITY.exp_round(rm), (Float::INFINITY, Equal));
+        let (exp, o) = Float::NEGATIVE_INFINITY.exp_round(rm);
+        assert_eq!(ComparableFloat(exp), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        assert_eq!(Float::ZERO.exp_round(rm), (Float::ONE, Equal));
+        assert_eq!(Float::NEGATIVE_ZERO.exp_round(rm), (Float::ONE, Equal));
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn exp_properties_helper(x: Float) {
+    let exp = x.clone().exp();
+    assert!(exp.is_valid());
+
+    let exp_alt = (&x).exp();
+    assert!(exp_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&exp_alt), ComparableFloatRef(&exp));
+
+    let mut x_alt = x.clone();
+    x_alt.exp_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp));
+
+    let rug_exp = rug_exp(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_exp)),
+        ComparableFloatRef(&exp),
+    );
+
+    assert_eq!(
+        ComparableFloatRef(&x.exp_round_ref(Nearest).0),
+        ComparableFloatRef(&exp)
+    );
+
+    if !x.is_nan() {
+        assert!(exp >= 0u32);
+    }
+}
+
+#[test]
+fn exp_properties() {
+    float_gen().test_properties(|x| {
+        exp_properties_helper(x);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_gen().test_properties_with_config(&config, |x| {
+        exp_properties_helper(x);
+    });
+
+    float_gen_var_6().test_properties(|x| {
+        exp_properties_helper(x);
+    });
+
+    float_gen_var_7().test_properties(|x| {
+        exp_properties_helper(x);
+    });
+
+    float_gen_var_8().test_properties(|x| {
+        exp_properties_helper(x);
+    });
+
+    float_gen_var_11().test_properties(|x| {
+        exp_properties_helper(x);
+    });
+}
@@ -0,0 +1,1159 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{Exp10, Exp10Assign};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, OneHalf, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::{
+    rounding_mode_gen, unsigned_gen_var_11, unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::test_util::arithmetic::exp10::{
+    rug_exp10, rug_exp10_prec, rug_exp10_prec_round, rug_exp10_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_gen_var_6, float_gen_var_7, float_gen_var_8, float_gen_var_11,
+    float_rounding_mode_pair_gen_var_25, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_14,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_nz::platform::Limb;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_exp10() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let exp10 = x.clone().exp10();
+        assert!(exp10.is_valid());
+
+        assert_eq!(exp10.to_string(), out);
+        assert_eq!(to_hex_string(&exp10), out_hex);
+
+        let exp10_alt = (&x).exp10();
+        assert!(exp10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp10), ComparableFloatRef(&exp10_alt));
+
+        let mut exp10_alt = x.clone();
+        exp10_alt.exp10_assign();
+        assert!(exp10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp10), ComparableFloatRef(&exp10_alt));
+
+        let rug_exp10 = rug_exp10(&rug::Float::exact_from(&x));
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_exp10)),
+            ComparableFloatRef(&exp10),
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", "0.0", "0x0.0");
+    test("0.0", "0x0.0", "1.0", "0x1.0#1");
+    test("-0.0", "-0x0.0", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "8.0", "0x8.0#1");
+    test("-1.0", "-0x1.0#1", "0.1", "0x0.2#1");
+    test("2.0", "0x2.0#1", "1.0e2", "0x8.0E+1#1");
+    test("0.5", "0x0.8#1", "4.0", "0x4.0#1");
+    test("-0.5", "-0x0.8#1", "0.2", "0x0.4#1");
+    test("123.0", "0x7b.0#7", "1.0e123", "0x1.84E+102#7");
+    test("-123.0", "-0x7b.0#7", "1.0e-123", "0xa.aE-103#7");
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "25.954553519470085",
+        "0x19.f45d9e9467dd#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1385.4557313670107",
+        "0x569.74aacf95128#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "0.0007217841590747279",
+        "0x0.002f4d875ba5adf2#53",
+    );
+    test("9.0e-13", "0x1.0E-10#1", "1.0", "0x1.0#1");
+    test("-9.0e-13", "-0x1.0E-10#1", "1.0", "0x1.0#1");
+    test("1000.0", "0x3e8.0#10", "9.998e999", "0x3.ceE+830#10");
+    test("4.0e9", "0x1.0E+8#1", "Infinity", "Infinity");
+    test("-4.0e9", "-0x1.0E+8#1", "0.0", "0x0.0");
+    test("3.0", "0x3.0#2", "1.0e3", "0x4.0E+2#2");
+}
+
+#[test]
+fn test_exp10_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp10, o) = x.clone().exp10_prec(prec);
+        assert!(exp10.is_valid());
+
+        assert_eq!(exp10.to_string(), out);
+        assert_eq!(to_hex_string(&exp10), out_hex);
+        assert_eq!(o, o_out);
+
+        let (exp10_alt, o_alt) = x.exp10_prec_ref(prec);
+        assert!(exp10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp10), ComparableFloatRef(&exp10_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp10_alt = x.clone();
+        let o_alt = exp10_alt.exp10_prec_assign(prec);
+        assert!(exp10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp10), ComparableFloatRef(&exp10_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_exp10, rug_o) = rug_exp10_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_exp10)),
+            ComparableFloatRef(&exp10),
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", 10, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", 100, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", 1, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 10, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 100, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 10, "1.0", "0x1.000#10", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 1, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 10, "1.0", "0x1.000#10", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", 1, "8.0", "0x8.0#1", Less);
+    test("1.0", "0x1.0#1", 10, "10.0", "0xa.00#10", Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "10.0",
+        "0xa.000000000000000000000000#100",
+        Equal,
+    );
+    test("-1.0", "-0x1.0#1", 1, "0.1", "0x0.2#1", Greater);
+    test("-1.0", "-0x1.0#1", 10, "0.09998", "0x0.1998#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "0.1",
+        "0x0.1999999999999999999999999a#100",
+        Greater,
+    );
+    test("2.0", "0x2.0#1", 1, "1.0e2", "0x8.0E+1#1", Greater);
+    test("2.0", "0x2.0#1", 10, "100.0", "0x64.0#10", Equal);
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        "100.0",
+        "0x64.000000000000000000000000#100",
+        Equal,
+    );
+    test("0.5", "0x0.8#1", 1, "4.0", "0x4.0#1", Greater);
+    test("0.5", "0x0.8#1", 10, "3.164", "0x3.2a#10", Greater);
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "3.162277660168379331998893544431",
+        "0x3.298b075b4b6a5240945790618#100",
+        Less,
+    );
+    test("-0.5", "-0x0.8#1", 1, "0.2", "0x0.4#1", Less);
+    test("-0.5", "-0x0.8#1", 10, "0.3164", "0x0.510#10", Greater);
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        100,
+        "0.3162277660168379331998893544434",
+        "0x0.50f44d8921243b6cdba25b3cf8#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, "1.0e123", "0x2.0E+102#1", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "1.001e123",
+        "0x1.838E+102#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "9.999999999999999999999999999997e122",
+        "0x1.83425a5f872f126e00a5ad62cE+102#100",
+        Less,
+    );
+    test("-123.0", "-0x7b.0#7", 1, "8.0e-124", "0x8.0E-103#1", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        "1.0e-123",
+        "0xa.94E-103#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        "1.0e-123",
+        "0xa.93af6c6c79b5d2dd598e40d4E-103#100",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        1,
+        "3.0e1",
+        "0x2.0E+1#1",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        10,
+        "25.97",
+        "0x19.f8#10",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        100,
+        "25.95455351947008675540457141522",
+        "0x19.f45d9e9467dd635c5a834d04#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "1.0e3",
+        "0x4.0E+2#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "1386.0",
+        "0x56a.0#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1385.455731367010698462705619402",
+        "0x569.74aacf951283c59a6deee40#100",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "0.0005",
+        "0x0.002#1",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "0.000722",
+        "0x0.002f5#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        "0.000721784159074727947145550098821",
+        "0x0.002f4d875ba5adf20a2136d311e0#100",
+        Greater,
+    );
+    test("9.0e-13", "0x1.0E-10#1", 1, "1.0", "0x1.0#1", Less);
+    test("9.0e-13", "0x1.0E-10#1", 10, "1.0", "0x1.000#10", Less);
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        100,
+        "1.000000000002094188942461602627",
+        "0x1.00000000024d763776ad49550#100",
+        Greater,
+    );
+    test("-9.0e-13", "-0x1.0E-10#1", 1, "1.0", "0x1.0#1", Greater);
+    test("-9.0e-13", "-0x1.0E-10#1", 10, "1.0", "0x1.000#10", Greater);
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        100,
+        "0.999999999997905811057542783001",
+        "0x0.fffffffffdb289c8895803f44#100",
+        Greater,
+    );
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        1,
+        "1.0e1000",
+        "0x4.0E+830#1",
+        Greater,
+    );
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        10,
+        "9.998e999",
+        "0x3.ceE+830#10",
+        Less,
+    );
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        100,
+        "1.0e1000",
+        "0x3.ce36c7e774f6b015e34a5a550E+830#100",
+        Greater,
+    );
+    test("4.0e9", "0x1.0E+8#1", 1, "Infinity", "Infinity", Greater);
+    test("4.0e9", "0x1.0E+8#1", 10, "Infinity", "Infinity", Greater);
+    test("4.0e9", "0x1.0E+8#1", 100, "Infinity", "Infinity", Greater);
+    test("-4.0e9", "-0x1.0E+8#1", 1, "0.0", "0x0.0", Less);
+    test("-4.0e9", "-0x1.0E+8#1", 10, "0.0", "0x0.0", Less);
+    test("-4.0e9", "-0x1.0E+8#1", 100, "0.0", "0x0.0", Less);
+    test("3.0", "0x3.0#2", 1, "1.0e3", "0x4.0E+2#1", Greater);
+    test("3.0", "0x3.0#2", 10, "1000.0", "0x3e8.0#10", Equal);
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        "1000.0",
+        "0x3e8.00000000000000000000000#100",
+        Equal,
+    );
+}
+
+#[test]
+fn exp10_prec_fail() {
+    assert_panic!(Float::NAN.exp10_prec(0));
+    assert_panic!(Float::NAN.exp10_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.exp10_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_exp10_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp10, o) = x.clone().exp10_round(rm);
+        assert!(exp10.is_valid());
+
+        assert_eq!(exp10.to_string(), out);
+        assert_eq!(to_hex_string(&exp10), out_hex);
+        assert_eq!(o, o_out);
+
+        let (exp10_alt, o_alt) = x.exp10_round_ref(rm);
+        assert!(exp10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp10), ComparableFloatRef(&exp10_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp10_alt = x.clone();
+        let o_alt = exp10_alt.exp10_round_assign(rm);
+        assert!(exp10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp10), ComparableFloatRef(&exp10_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_exp10, rug_o) = rug_exp10_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp10)),
+                ComparableFloatRef(&exp10),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Floor, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Floor, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", Floor, "8.0", "0x8.0#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "2.0e1", "0x1.0E+1#1", Greater);
+    test("1.0", "0x1.0#1", Down, "8.0", "0x8.0#1", Less);
+    test("1.0", "0x1.0#1", Up, "2.0e1", "0x1.0E+1#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "8.0", "0x8.0#1", Less);
+    test("2.0", "0x2.0#1", Floor, "6.0e1", "0x4.0E+1#1", Less);
+    test("2.0", "0x2.0#1", Ceiling, "1.0e2", "0x8.0E+1#1", Greater);
+    test("2.0", "0x2.0#1", Down, "6.0e1", "0x4.0E+1#1", Less);
+    test("2.0", "0x2.0#1", Up, "1.0e2", "0x8.0E+1#1", Greater);
+    test("2.0", "0x2.0#1", Nearest, "1.0e2", "0x8.0E+1#1", Greater);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Floor,
+        "1385.4557313670107",
+        "0x569.74aacf95128#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ceiling,
+        "1385.4557313670109",
+        "0x569.74aacf9512c#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Down,
+        "1385.4557313670107",
+        "0x569.74aacf95128#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Up,
+        "1385.4557313670109",
+        "0x569.74aacf9512c#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Nearest,
+        "1385.4557313670107",
+        "0x569.74aacf95128#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Floor,
+        "0.0007217841590747279",
+        "0x0.002f4d875ba5adf2#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Ceiling,
+        "0.0007217841590747281",
+        "0x0.002f4d875ba5adf4#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Down,
+        "0.0007217841590747279",
+        "0x0.002f4d875ba5adf2#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Up,
+        "0.0007217841590747281",
+        "0x0.002f4d875ba5adf4#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Nearest,
+        "0.0007217841590747279",
+        "0x0.002f4d875ba5adf2#53",
+        Less,
+    );
+    test("9.0e-13", "0x1.0E-10#1", Floor, "1.0", "0x1.0#1", Less);
+    test("9.0e-13", "0x1.0E-10#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("9.0e-13", "0x1.0E-10#1", Down, "1.0", "0x1.0#1", Less);
+    test("9.0e-13", "0x1.0E-10#1", Up, "2.0", "0x2.0#1", Greater);
+    test("9.0e-13", "0x1.0E-10#1", Nearest, "1.0", "0x1.0#1", Less);
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Floor,
+        "9.998e999",
+        "0x3.ceE+830#10",
+        Less,
+    );
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Ceiling,
+        "1.001e1000",
+        "0x3.cfE+830#10",
+        Greater,
+    );
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Down,
+        "9.998e999",
+        "0x3.ceE+830#10",
+        Less,
+    );
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Up,
+        "1.001e1000",
+        "0x3.cfE+830#10",
+        Greater,
+    );
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Nearest,
+        "9.998e999",
+        "0x3.ceE+830#10",
+        Less,
+    );
+    test("3.0", "0x3.0#2", Floor, "8.0e2", "0x3.0E+2#2", Less);
+    test("3.0", "0x3.0#2", Ceiling, "1.0e3", "0x4.0E+2#2", Greater);
+    test("3.0", "0x3.0#2", Down, "8.0e2", "0x3.0E+2#2", Less);
+    test("3.0", "0x3.0#2", Up, "1.0e3", "0x4.0E+2#2", Greater);
+    test("3.0", "0x3.0#2", Nearest, "1.0e3", "0x4.0E+2#2", Greater);
+}
+
+#[test]
+fn exp10_round_fail() {
+    assert_panic!(Float::ONE_HALF.exp10_round(Exact));
+    assert_panic!(Float::ONE_HALF.exp10_round_ref(Exact));
+    assert_panic!({
+        let mut x = Float::ONE_HALF;
+        x.exp10_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_exp10_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp10, o) = x.clone().exp10_prec_round(prec, rm);
+        assert!(exp10.is_valid());
+
+        assert_eq!(exp10.to_string(), out);
+        assert_eq!(to_hex_string(&exp10), out_hex);
+        assert_eq!(o, o_out);
+
+        let (exp10_alt, o_alt) = x.exp10_prec_round_ref(prec, rm);
+        assert!(exp10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp10), ComparableFloatRef(&exp10_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp10_alt = x.clone();
+        let o_alt = exp10_alt.exp10_prec_round_assign(prec, rm);
+        assert!(exp10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp10), ComparableFloatRef(&exp10_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_exp10, rug_o) = rug_exp10_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp10)),
+                ComparableFloatRef(&exp10),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test(
+        "Infinity", "Infinity", 1, Floor, "Infinity", "Infinity", Equal,
+    );
+    test("-Infinity", "-Infinity", 1, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "8.0", "0x8.0#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "2.0e1", "0x1.0E+1#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "8.0", "0x8.0#1", Less);
+    test("1.0", "0x1.0#1", 10, Floor, "10.0", "0xa.00#10", Equal);
+    test("1.0", "0x1.0#1", 10, Ceiling, "10.0", "0xa.00#10", Equal);
+    test("1.0", "0x1.0#1", 10, Nearest, "10.0", "0xa.00#10", Equal);
+    test("1.0", "0x1.0#1", 10, Exact, "10.0", "0xa.00#10", Equal);
+    test("2.0", "0x2.0#1", 1, Floor, "6.0e1", "0x4.0E+1#1", Less);
+    test("2.0", "0x2.0#1", 1, Ceiling, "1.0e2", "0x8.0E+1#1", Greater);
+    test("2.0", "0x2.0#1", 1, Nearest, "1.0e2", "0x8.0E+1#1", Greater);
+    test("2.0", "0x2.0#1", 10, Floor, "100.0", "0x64.0#10", Equal);
+    test("2.0", "0x2.0#1", 10, Ceiling, "100.0", "0x64.0#10", Equal);
+    test("2.0", "0x2.0#1", 10, Nearest, "100.0", "0x64.0#10", Equal);
+    test("2.0", "0x2.0#1", 10, Exact, "100.0", "0x64.0#10", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Floor,
+        "1.0e3",
+        "0x4.0E+2#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Ceiling,
+        "2.0e3",
+        "0x8.0E+2#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Nearest,
+        "1.0e3",
+        "0x4.0E+2#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "1384.0",
+        "0x568.0#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "1386.0",
+        "0x56a.0#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "1386.0",
+        "0x56a.0#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        Floor,
+        "0.0005",
+        "0x0.002#1",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        Ceiling,
+        "0.001",
+        "0x0.004#1",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        Nearest,
+        "0.0005",
+        "0x0.002#1",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "0.000721",
+        "0x0.002f4#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "0.000722",
+        "0x0.002f5#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "0.000722",
+        "0x0.002f5#10",
+        Greater,
+    );
+    test("9.0e-13", "0x1.0E-10#1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test("9.0e-13", "0x1.0E-10#1", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        Floor,
+        "1.0",
+        "0x1.000#10",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        Ceiling,
+        "1.002",
+        "0x1.008#10",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        Nearest,
+        "1.0",
+        "0x1.000#10",
+        Less,
+    );
+    test("3.0", "0x3.0#2", 1, Floor, "5.0e2", "0x2.0E+2#1", Less);
+    test("3.0", "0x3.0#2", 1, Ceiling, "1.0e3", "0x4.0E+2#1", Greater);
+    test("3.0", "0x3.0#2", 1, Nearest, "1.0e3", "0x4.0E+2#1", Greater);
+    test("3.0", "0x3.0#2", 10, Floor, "1000.0", "0x3e8.0#10", Equal);
+    test("3.0", "0x3.0#2", 10, Ceiling, "1000.0", "0x3e8.0#10", Equal);
+    test("3.0", "0x3.0#2", 10, Nearest, "1000.0", "0x3e8.0#10", Equal);
+    test("3.0", "0x3.0#2", 10, Exact, "1000.0", "0x3e8.0#10", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Floor,
+        "1385.455731367010698462705619402",
+        "0x569.74aacf951283c59a6deee40#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Ceiling,
+        "1385.455731367010698462705619404",
+        "0x569.74aacf951283c59a6deee48#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Down,
+        "1385.455731367010698462705619402",
+        "0x569.74aacf951283c59a6deee40#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Up,
+        "1385.455731367010698462705619404",
+        "0x569.74aacf951283c59a6deee48#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Nearest,
+        "1385.455731367010698462705619402",
+        "0x569.74aacf951283c59a6deee40#100",
+        Less,
+    );
+}
+
+#[test]
+fn exp10_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).exp10_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).exp10_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.exp10_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(Float::ONE_HALF.exp10_prec_round(1, Exact));
+    assert_panic!(Float::ONE_HALF.exp10_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = Float::ONE_HALF;
+        x.exp10_prec_round_assign(1, Exact)
+    });
+}
+
+fn exp10_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (exp10, o) = x.clone().exp10_prec_round(prec, rm);
+    assert!(exp10.is_valid());
+    let (exp10_alt, o_alt) = x.exp10_prec_round_ref(prec, rm);
+    assert!(exp10_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&exp10_alt), ComparableFloatRef(&exp10));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.exp10_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp10));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_exp10, rug_o) = rug_exp10_prec_round(&rug::Float::exact_from(&x), prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_exp10)),
+            ComparableFloatRef(&exp10),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if exp10.is_normal() {
+        assert_eq!(exp10.get_prec(), Some(prec));
+    }
+
+    if !x.is_nan() {
+        assert!(exp10 >= 0u32);
+    }
+
+    match rm {
+        Floor => assert_ne!(o, Greater),
+        Ceiling => assert_ne!(o, Less),
+        Down => {
+            if exp10.is_sign_positive() {
+                assert_ne!(o, Greater);
+            } else {
+                assert_ne!(o, Less);
+            }
+        }
+        Up => {
+            if exp10.is_sign_positive() {
+                assert_ne!(o, Less);
+            } else {
+                assert_ne!(o, Greater);
+            }
+        }
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.exp10_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(exp10.clone()));
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.exp10_prec_round(prec, Exact));
+    }
+}
+
+#[test]
+fn exp10_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties(|(x, prec, rm)| {
+        exp10_prec_round_properties_helper(x, prec, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties_with_config(
+        &config,
+        |(x, prec, rm)| {
+            exp10_prec_round_properties_helper(x, prec, rm);
+        },
+    );
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (exp10, o) = Float::NAN.exp10_prec_round(prec, rm);
+        assert!(exp10.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(
+            Float::INFINITY.exp10_prec_round(prec, rm),
+            (Float::INFINITY, Equal)
+        );
+        let (exp10, o) = Float::NEGATIVE_INFINITY.exp10_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(exp10), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        assert_eq!(
+            Float::ZERO.exp10_prec_round(prec, rm),
+            (Float::one_prec(prec), Equal)
+        );
+        assert_eq!(
+            Float::NEGATIVE_ZERO.exp10_prec_round(prec, rm),
+            (Float::one_prec(prec), Equal)
+        );
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn exp10_prec_properties_helper(x: Float, prec: u64) {
+    let (exp10, o) = x.clone().exp10_prec(prec);
+    assert!(exp10.is_valid());
+    let (exp10_alt, o_alt) = x.exp10_prec_ref(prec);
+    assert!(exp10_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&exp10_alt), ComparableFloatRef(&exp10));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.exp10_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp10));
+    assert_eq!(o_alt, o);
+
+    let (rug_exp10, rug_o) = rug_exp10_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_exp10)),
+        ComparableFloatRef(&exp10),
+    );
+    assert_eq!(rug_o, o);
+
+    let (exp10_alt, o_alt) = x.exp10_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&exp10_alt), ComparableFloatRef(&exp10));
+    assert_eq!(o_alt, o);
+
+    if exp10.is_normal() {
+        assert_eq!(exp10.get_prec(), Some(prec));
+    }
+
+    if !x.is_nan() {
+        assert!(exp10 >= 0u32);
+    }
+}
+
+#[test]
+fn exp10_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        exp10_prec_properties_helper(x, prec);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_pair_gen_var_1().test_properties_with_config(&config, |(x, prec)| {
+        exp10_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (exp10, o) = Float::NAN.exp10_prec(prec);
+        assert!(exp10.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(Float::INFINITY.exp10_prec(prec), (Float::INFINITY, Equal));
+        let (exp10, o) = Float::NEGATIVE_INFINITY.exp10_prec(prec);
+        assert_eq!(ComparableFloat(exp10), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        assert_eq!(Float::ZERO.exp10_prec(prec), (Float::one_prec(prec), Equal));
+        assert_eq!(
+            Float::NEGATIVE_ZERO.exp10_prec(prec),
+            (Float::one_prec(prec), Equal)
+        );
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn exp10_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (exp10, o) = x.clone().exp10_round(rm);
+    assert!(exp10.is_valid());
+    let (exp10_alt, o_alt) = x.exp10_round_ref(rm);
+    assert!(exp10_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&exp10_alt), ComparableFloatRef(&exp10));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.exp10_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp10));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_exp10, rug_o) = rug_exp10_round(&rug::Float::exact_from(&x), rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_exp10)),
+            ComparableFloatRef(&exp10),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    let (exp10_alt, o_alt) = x.exp10_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&exp10_alt), ComparableFloatRef(&exp10));
+    assert_eq!(o_alt, o);
+
+    if exp10.is_normal() {
+        assert_eq!(exp10.get_prec(), Some(x.significant_bits()));
+    }
+
+    if !x.is_nan() {
+        assert!(exp10 >= 0u32);
+    }
+
+    match rm {
+        Floor => assert_ne!(o, Greater),
+        Ceiling => assert_ne!(o, Less),
+        Down => {
+            if exp10.is_sign_positive() {
+                assert_ne!(o, Greater);
+            } else {
+                assert_ne!(o, Less);
+            }
+        }
+        Up => {
+            if exp10.is_sign_positive() {
+                assert_ne!(o, Less);
+            } else {
+                assert_ne!(o, Greater);
+            }
+        }
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+}
+
+#[test]
+fn exp10_round_properties() {
+    float_rounding_mode_pair_gen_var_25().test_properties(|(x, rm)| {
+        exp10_round_properties_helper(x, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_rounding_mode_pair_gen_var_25().test_properties_with_config(&config, |(x, rm)| {
+        exp10_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (exp10, o) = Float::NAN.exp10_round(rm);
+        assert!(exp10.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(Float::INFINITY.exp10_round(rm), (Float::INFINITY, Equal));
+        let (exp10, o) = Float::NEGATIVE_INFINITY.exp10_round(rm);
+        assert_eq!(ComparableFloat(exp10), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        assert_eq!(Float::ZERO.exp10_round(rm), (Float::ONE, Equal));
+        assert_eq!(Float::NEGATIVE_ZERO.exp10_round(rm), (Float::ONE, Equal));
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn exp10_properties_helper(x: Float) {
+    let exp10 = x.clone().exp10();
+    assert!(exp10.is_valid());
+
+    let exp10_alt = (&x).exp10();
+    assert!(exp10_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&exp10_alt), ComparableFloatRef(&exp10));
+
+    let mut x_alt = x.clone();
+    x_alt.exp10_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp10));
+
+    let rug_exp10 = rug_exp10(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_exp10)),
+        ComparableFloatRef(&exp10),
+    );
+
+    assert_eq!(
+        ComparableFloatRef(&x.exp10_round_ref(Nearest).0),
+        ComparableFloatRef(&exp10)
+    );
+
+    if !x.is_nan() {
+        assert!(exp10 >= 0u32);
+    }
+}
+
+#[test]
+fn exp10_properties() {
+    float_gen().test_properties(|x| {
+        exp10_properties_helper(x);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_gen().test_properties_with_config(&config, |x| {
+        exp10_properties_helper(x);
+    });
+
+    float_gen_var_6().test_properties(|x| {
+        exp10_properties_helper(x);
+    });
+
+    float_gen_var_7().test_properties(|x| {
+        exp10_properties_helper(x);
+    });
+
+    float_gen_var_8().test_properties(|x| {
+        exp10_properties_helper(x);
+    });
+
+    float_gen_var_11().test_properties(|x| {
+        exp10_properties_helper(x);
+    });
+}
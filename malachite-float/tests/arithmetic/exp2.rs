@@ -0,0 +1,1168 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{Exp2, Exp2Assign};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, OneHalf, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::{
+    rounding_mode_gen, unsigned_gen_var_11, unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::test_util::arithmetic::exp2::{
+    rug_exp2, rug_exp2_prec, rug_exp2_prec_round, rug_exp2_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_gen_var_6, float_gen_var_7, float_gen_var_8, float_gen_var_11,
+    float_rounding_mode_pair_gen_var_25, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_14,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_nz::platform::Limb;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_exp2() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let exp2 = x.clone().exp2();
+        assert!(exp2.is_valid());
+
+        assert_eq!(exp2.to_string(), out);
+        assert_eq!(to_hex_string(&exp2), out_hex);
+
+        let exp2_alt = (&x).exp2();
+        assert!(exp2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp2), ComparableFloatRef(&exp2_alt));
+
+        let mut exp2_alt = x.clone();
+        exp2_alt.exp2_assign();
+        assert!(exp2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp2), ComparableFloatRef(&exp2_alt));
+
+        let rug_exp2 = rug_exp2(&rug::Float::exact_from(&x));
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_exp2)),
+            ComparableFloatRef(&exp2),
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", "0.0", "0x0.0");
+    test("0.0", "0x0.0", "1.0", "0x1.0#1");
+    test("-0.0", "-0x0.0", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "2.0", "0x2.0#1");
+    test("-1.0", "-0x1.0#1", "0.5", "0x0.8#1");
+    test("2.0", "0x2.0#1", "4.0", "0x4.0#1");
+    test("0.5", "0x0.8#1", "1.0", "0x1.0#1");
+    test("-0.5", "-0x0.8#1", "0.5", "0x0.8#1");
+    test("123.0", "0x7b.0#7", "1.06e37", "0x8.0E+30#7");
+    test("-123.0", "-0x7b.0#7", "9.4e-38", "0x2.00E-31#7");
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "2.6651441426902251",
+        "0x2.aa46e2f3fb006#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "8.824977827076287",
+        "0x8.d331bf3337c18#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "0.11331473229676088",
+        "0x0.1d0231bd5e9cfd#53",
+    );
+    test("9.0e-13", "0x1.0E-10#1", "1.0", "0x1.0#1");
+    test("-9.0e-13", "-0x1.0E-10#1", "1.0", "0x1.0#1");
+    test("1000.0", "0x3e8.0#10", "1.072e301", "0x1.000E+250#10");
+    test("4.0e9", "0x1.0E+8#1", "Infinity", "Infinity");
+    test("-4.0e9", "-0x1.0E+8#1", "0.0", "0x0.0");
+    test("10.0", "0xa.0#4", "1.0e3", "0x4.0E+2#4");
+}
+
+#[test]
+fn test_exp2_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp2, o) = x.clone().exp2_prec(prec);
+        assert!(exp2.is_valid());
+
+        assert_eq!(exp2.to_string(), out);
+        assert_eq!(to_hex_string(&exp2), out_hex);
+        assert_eq!(o, o_out);
+
+        let (exp2_alt, o_alt) = x.exp2_prec_ref(prec);
+        assert!(exp2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp2), ComparableFloatRef(&exp2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp2_alt = x.clone();
+        let o_alt = exp2_alt.exp2_prec_assign(prec);
+        assert!(exp2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp2), ComparableFloatRef(&exp2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_exp2, rug_o) = rug_exp2_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_exp2)),
+            ComparableFloatRef(&exp2),
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", 10, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", 100, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", 1, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 10, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 100, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 10, "1.0", "0x1.000#10", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 1, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 10, "1.0", "0x1.000#10", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", 1, "2.0", "0x2.0#1", Equal);
+    test("1.0", "0x1.0#1", 10, "2.0", "0x2.00#10", Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Equal,
+    );
+    test("-1.0", "-0x1.0#1", 1, "0.5", "0x0.8#1", Equal);
+    test("-1.0", "-0x1.0#1", 10, "0.5", "0x0.800#10", Equal);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "0.5",
+        "0x0.8000000000000000000000000#100",
+        Equal,
+    );
+    test("2.0", "0x2.0#1", 1, "4.0", "0x4.0#1", Equal);
+    test("2.0", "0x2.0#1", 10, "4.0", "0x4.00#10", Equal);
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        "4.0",
+        "0x4.0000000000000000000000000#100",
+        Equal,
+    );
+    test("0.5", "0x0.8#1", 1, "1.0", "0x1.0#1", Less);
+    test("0.5", "0x0.8#1", 10, "1.414", "0x1.6a0#10", Less);
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "1.414213562373095048801688724209",
+        "0x1.6a09e667f3bcc908b2fb1366e#100",
+        Less,
+    );
+    test("-0.5", "-0x0.8#1", 1, "0.5", "0x0.8#1", Less);
+    test("-0.5", "-0x0.8#1", 10, "0.707", "0x0.b50#10", Less);
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        100,
+        "0.7071067811865475244008443621046",
+        "0x0.b504f333f9de6484597d89b37#100",
+        Less,
+    );
+    test("123.0", "0x7b.0#7", 1, "1.0e37", "0x8.0E+30#1", Equal);
+    test("123.0", "0x7b.0#7", 10, "1.063e37", "0x8.00E+30#10", Equal);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "1.063382396627932698323045648224e37",
+        "0x8.000000000000000000000000E+30#100",
+        Equal,
+    );
+    test("-123.0", "-0x7b.0#7", 1, "9.0e-38", "0x2.0E-31#1", Equal);
+    test("-123.0", "-0x7b.0#7", 10, "9.4e-38", "0x2.00E-31#10", Equal);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        "9.40395480657830006374989229778e-38",
+        "0x2.0000000000000000000000000E-31#100",
+        Equal,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        10,
+        "2.664",
+        "0x2.aa#10",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        100,
+        "2.665144142690225367237797863598",
+        "0x2.aa46e2f3fb006fc17266bc46c#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "8.83",
+        "0x8.d4#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "8.82497782707628687473894169658",
+        "0x8.d331bf3337c170dfcdee149f#100",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "0.1",
+        "0x0.2#1",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "0.1133",
+        "0x0.1d00#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        "0.1133147322967608826391219318593",
+        "0x0.1d0231bd5e9cfd1c56d8c57fba#100",
+        Greater,
+    );
+    test("9.0e-13", "0x1.0E-10#1", 1, "1.0", "0x1.0#1", Less);
+    test("9.0e-13", "0x1.0E-10#1", 10, "1.0", "0x1.000#10", Less);
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        100,
+        "1.000000000000630413688268312211",
+        "0x1.0000000000b17217f7d20cf92#100",
+        Less,
+    );
+    test("-9.0e-13", "-0x1.0E-10#1", 1, "1.0", "0x1.0#1", Greater);
+    test("-9.0e-13", "-0x1.0E-10#1", 10, "1.0", "0x1.000#10", Greater);
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        100,
+        "0.99999999999936958631173208521",
+        "0x0.ffffffffff4e8de8082e6e05d#100",
+        Less,
+    );
+    test("1000.0", "0x3e8.0#10", 1, "1.0e301", "0x1.0E+250#1", Equal);
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        10,
+        "1.072e301",
+        "0x1.000E+250#10",
+        Equal,
+    );
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        100,
+        "1.07150860718626732094842504906e301",
+        "0x1.0000000000000000000000000E+250#100",
+        Equal,
+    );
+    test("4.0e9", "0x1.0E+8#1", 1, "Infinity", "Infinity", Greater);
+    test("4.0e9", "0x1.0E+8#1", 10, "Infinity", "Infinity", Greater);
+    test("4.0e9", "0x1.0E+8#1", 100, "Infinity", "Infinity", Greater);
+    test("-4.0e9", "-0x1.0E+8#1", 1, "0.0", "0x0.0", Less);
+    test("-4.0e9", "-0x1.0E+8#1", 10, "0.0", "0x0.0", Less);
+    test("-4.0e9", "-0x1.0E+8#1", 100, "0.0", "0x0.0", Less);
+    test("10.0", "0xa.0#4", 1, "1.0e3", "0x4.0E+2#1", Equal);
+    test("10.0", "0xa.0#4", 10, "1024.0", "0x400.0#10", Equal);
+    test(
+        "10.0",
+        "0xa.0#4",
+        100,
+        "1024.0",
+        "0x400.00000000000000000000000#100",
+        Equal,
+    );
+}
+
+#[test]
+fn exp2_prec_fail() {
+    assert_panic!(Float::NAN.exp2_prec(0));
+    assert_panic!(Float::NAN.exp2_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.exp2_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_exp2_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp2, o) = x.clone().exp2_round(rm);
+        assert!(exp2.is_valid());
+
+        assert_eq!(exp2.to_string(), out);
+        assert_eq!(to_hex_string(&exp2), out_hex);
+        assert_eq!(o, o_out);
+
+        let (exp2_alt, o_alt) = x.exp2_round_ref(rm);
+        assert!(exp2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp2), ComparableFloatRef(&exp2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp2_alt = x.clone();
+        let o_alt = exp2_alt.exp2_round_assign(rm);
+        assert!(exp2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp2), ComparableFloatRef(&exp2_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_exp2, rug_o) = rug_exp2_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp2)),
+                ComparableFloatRef(&exp2),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Floor, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Floor, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", Floor, "2.0", "0x2.0#1", Equal);
+    test("1.0", "0x1.0#1", Ceiling, "2.0", "0x2.0#1", Equal);
+    test("1.0", "0x1.0#1", Down, "2.0", "0x2.0#1", Equal);
+    test("1.0", "0x1.0#1", Up, "2.0", "0x2.0#1", Equal);
+    test("1.0", "0x1.0#1", Nearest, "2.0", "0x2.0#1", Equal);
+    test("1.0", "0x1.0#1", Exact, "2.0", "0x2.0#1", Equal);
+    test("2.0", "0x2.0#1", Floor, "4.0", "0x4.0#1", Equal);
+    test("2.0", "0x2.0#1", Ceiling, "4.0", "0x4.0#1", Equal);
+    test("2.0", "0x2.0#1", Down, "4.0", "0x4.0#1", Equal);
+    test("2.0", "0x2.0#1", Up, "4.0", "0x4.0#1", Equal);
+    test("2.0", "0x2.0#1", Nearest, "4.0", "0x4.0#1", Equal);
+    test("2.0", "0x2.0#1", Exact, "4.0", "0x4.0#1", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Floor,
+        "8.824977827076285",
+        "0x8.d331bf3337c10#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ceiling,
+        "8.824977827076287",
+        "0x8.d331bf3337c18#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Down,
+        "8.824977827076285",
+        "0x8.d331bf3337c10#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Up,
+        "8.824977827076287",
+        "0x8.d331bf3337c18#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Nearest,
+        "8.824977827076287",
+        "0x8.d331bf3337c18#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Floor,
+        "0.11331473229676088",
+        "0x0.1d0231bd5e9cfd#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Ceiling,
+        "0.11331473229676089",
+        "0x0.1d0231bd5e9cfe#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Down,
+        "0.11331473229676088",
+        "0x0.1d0231bd5e9cfd#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Up,
+        "0.11331473229676089",
+        "0x0.1d0231bd5e9cfe#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Nearest,
+        "0.11331473229676088",
+        "0x0.1d0231bd5e9cfd#53",
+        Less,
+    );
+    test("9.0e-13", "0x1.0E-10#1", Floor, "1.0", "0x1.0#1", Less);
+    test("9.0e-13", "0x1.0E-10#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("9.0e-13", "0x1.0E-10#1", Down, "1.0", "0x1.0#1", Less);
+    test("9.0e-13", "0x1.0E-10#1", Up, "2.0", "0x2.0#1", Greater);
+    test("9.0e-13", "0x1.0E-10#1", Nearest, "1.0", "0x1.0#1", Less);
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Floor,
+        "1.072e301",
+        "0x1.000E+250#10",
+        Equal,
+    );
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Ceiling,
+        "1.072e301",
+        "0x1.000E+250#10",
+        Equal,
+    );
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Down,
+        "1.072e301",
+        "0x1.000E+250#10",
+        Equal,
+    );
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Up,
+        "1.072e301",
+        "0x1.000E+250#10",
+        Equal,
+    );
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Nearest,
+        "1.072e301",
+        "0x1.000E+250#10",
+        Equal,
+    );
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Exact,
+        "1.072e301",
+        "0x1.000E+250#10",
+        Equal,
+    );
+    test("10.0", "0xa.0#4", Floor, "1.0e3", "0x4.0E+2#4", Equal);
+    test("10.0", "0xa.0#4", Ceiling, "1.0e3", "0x4.0E+2#4", Equal);
+    test("10.0", "0xa.0#4", Down, "1.0e3", "0x4.0E+2#4", Equal);
+    test("10.0", "0xa.0#4", Up, "1.0e3", "0x4.0E+2#4", Equal);
+    test("10.0", "0xa.0#4", Nearest, "1.0e3", "0x4.0E+2#4", Equal);
+    test("10.0", "0xa.0#4", Exact, "1.0e3", "0x4.0E+2#4", Equal);
+}
+
+#[test]
+fn exp2_round_fail() {
+    assert_panic!(Float::ONE_HALF.exp2_round(Exact));
+    assert_panic!(Float::ONE_HALF.exp2_round_ref(Exact));
+    assert_panic!({
+        let mut x = Float::ONE_HALF;
+        x.exp2_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_exp2_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp2, o) = x.clone().exp2_prec_round(prec, rm);
+        assert!(exp2.is_valid());
+
+        assert_eq!(exp2.to_string(), out);
+        assert_eq!(to_hex_string(&exp2), out_hex);
+        assert_eq!(o, o_out);
+
+        let (exp2_alt, o_alt) = x.exp2_prec_round_ref(prec, rm);
+        assert!(exp2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp2), ComparableFloatRef(&exp2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp2_alt = x.clone();
+        let o_alt = exp2_alt.exp2_prec_round_assign(prec, rm);
+        assert!(exp2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp2), ComparableFloatRef(&exp2_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_exp2, rug_o) = rug_exp2_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp2)),
+                ComparableFloatRef(&exp2),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test(
+        "Infinity", "Infinity", 1, Floor, "Infinity", "Infinity", Equal,
+    );
+    test("-Infinity", "-Infinity", 1, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "2.0", "0x2.0#1", Equal);
+    test("1.0", "0x1.0#1", 1, Ceiling, "2.0", "0x2.0#1", Equal);
+    test("1.0", "0x1.0#1", 1, Nearest, "2.0", "0x2.0#1", Equal);
+    test("1.0", "0x1.0#1", 1, Exact, "2.0", "0x2.0#1", Equal);
+    test("1.0", "0x1.0#1", 10, Floor, "2.0", "0x2.00#10", Equal);
+    test("1.0", "0x1.0#1", 10, Ceiling, "2.0", "0x2.00#10", Equal);
+    test("1.0", "0x1.0#1", 10, Nearest, "2.0", "0x2.00#10", Equal);
+    test("1.0", "0x1.0#1", 10, Exact, "2.0", "0x2.00#10", Equal);
+    test("2.0", "0x2.0#1", 1, Floor, "4.0", "0x4.0#1", Equal);
+    test("2.0", "0x2.0#1", 1, Ceiling, "4.0", "0x4.0#1", Equal);
+    test("2.0", "0x2.0#1", 1, Nearest, "4.0", "0x4.0#1", Equal);
+    test("2.0", "0x2.0#1", 1, Exact, "4.0", "0x4.0#1", Equal);
+    test("2.0", "0x2.0#1", 10, Floor, "4.0", "0x4.00#10", Equal);
+    test("2.0", "0x2.0#1", 10, Ceiling, "4.0", "0x4.00#10", Equal);
+    test("2.0", "0x2.0#1", 10, Nearest, "4.0", "0x4.00#10", Equal);
+    test("2.0", "0x2.0#1", 10, Exact, "4.0", "0x4.00#10", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Floor,
+        "8.0",
+        "0x8.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Ceiling,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Nearest,
+        "8.0",
+        "0x8.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "8.81",
+        "0x8.d0#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "8.83",
+        "0x8.d4#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "8.83",
+        "0x8.d4#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        Floor,
+        "0.06",
+        "0x0.1#1",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        Ceiling,
+        "0.1",
+        "0x0.2#1",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        Nearest,
+        "0.1",
+        "0x0.2#1",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "0.1133",
+        "0x0.1d00#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "0.1134",
+        "0x0.1d08#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "0.1133",
+        "0x0.1d00#10",
+        Less,
+    );
+    test("9.0e-13", "0x1.0E-10#1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test("9.0e-13", "0x1.0E-10#1", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        Floor,
+        "1.0",
+        "0x1.000#10",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        Ceiling,
+        "1.002",
+        "0x1.008#10",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        Nearest,
+        "1.0",
+        "0x1.000#10",
+        Less,
+    );
+    test("10.0", "0xa.0#4", 1, Floor, "1.0e3", "0x4.0E+2#1", Equal);
+    test("10.0", "0xa.0#4", 1, Ceiling, "1.0e3", "0x4.0E+2#1", Equal);
+    test("10.0", "0xa.0#4", 1, Nearest, "1.0e3", "0x4.0E+2#1", Equal);
+    test("10.0", "0xa.0#4", 1, Exact, "1.0e3", "0x4.0E+2#1", Equal);
+    test("10.0", "0xa.0#4", 10, Floor, "1024.0", "0x400.0#10", Equal);
+    test(
+        "10.0",
+        "0xa.0#4",
+        10,
+        Ceiling,
+        "1024.0",
+        "0x400.0#10",
+        Equal,
+    );
+    test(
+        "10.0",
+        "0xa.0#4",
+        10,
+        Nearest,
+        "1024.0",
+        "0x400.0#10",
+        Equal,
+    );
+    test("10.0", "0xa.0#4", 10, Exact, "1024.0", "0x400.0#10", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Floor,
+        "8.82497782707628687473894169658",
+        "0x8.d331bf3337c170dfcdee149f#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Ceiling,
+        "8.82497782707628687473894169659",
+        "0x8.d331bf3337c170dfcdee14a0#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Down,
+        "8.82497782707628687473894169658",
+        "0x8.d331bf3337c170dfcdee149f#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Up,
+        "8.82497782707628687473894169659",
+        "0x8.d331bf3337c170dfcdee14a0#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Nearest,
+        "8.82497782707628687473894169658",
+        "0x8.d331bf3337c170dfcdee149f#100",
+        Less,
+    );
+}
+
+#[test]
+fn exp2_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).exp2_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).exp2_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.exp2_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(Float::ONE_HALF.exp2_prec_round(1, Exact));
+    assert_panic!(Float::ONE_HALF.exp2_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = Float::ONE_HALF;
+        x.exp2_prec_round_assign(1, Exact)
+    });
+}
+
+fn exp2_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (exp2, o) = x.clone().exp2_prec_round(prec, rm);
+    assert!(exp2.is_valid());
+    let (exp2_alt, o_alt) = x.exp2_prec_round_ref(prec, rm);
+    assert!(exp2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&exp2_alt), ComparableFloatRef(&exp2));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.exp2_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp2));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_exp2, rug_o) = rug_exp2_prec_round(&rug::Float::exact_from(&x), prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_exp2)),
+            ComparableFloatRef(&exp2),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if exp2.is_normal() {
+        assert_eq!(exp2.get_prec(), Some(prec));
+    }
+
+    if !x.is_nan() {
+        assert!(exp2 >= 0u32);
+    }
+
+    match rm {
+        Floor => assert_ne!(o, Greater),
+        Ceiling => assert_ne!(o, Less),
+        Down => {
+            if exp2.is_sign_positive() {
+                assert_ne!(o, Greater);
+            } else {
+                assert_ne!(o, Less);
+            }
+        }
+        Up => {
+            if exp2.is_sign_positive() {
+                assert_ne!(o, Less);
+            } else {
+                assert_ne!(o, Greater);
+            }
+        }
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.exp2_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(exp2.clone()));
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.exp2_prec_round(prec, Exact));
+    }
+}
+
+#[test]
+fn exp2_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties(|(x, prec, rm)| {
+        exp2_prec_round_properties_helper(x, prec, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties_with_config(
+        &config,
+        |(x, prec, rm)| {
+            exp2_prec_round_properties_helper(x, prec, rm);
+        },
+    );
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (exp2, o) = Float::NAN.exp2_prec_round(prec, rm);
+        assert!(exp2.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(
+            Float::INFINITY.exp2_prec_round(prec, rm),
+            (Float::INFINITY, Equal)
+        );
+        let (exp2, o) = Float::NEGATIVE_INFINITY.exp2_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(exp2), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        assert_eq!(
+            Float::ZERO.exp2_prec_round(prec, rm),
+            (Float::one_prec(prec), Equal)
+        );
+        assert_eq!(
+            Float::NEGATIVE_ZERO.exp2_prec_round(prec, rm),
+            (Float::one_prec(prec), Equal)
+        );
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn exp2_prec_properties_helper(x: Float, prec: u64) {
+    let (exp2, o) = x.clone().exp2_prec(prec);
+    assert!(exp2.is_valid());
+    let (exp2_alt, o_alt) = x.exp2_prec_ref(prec);
+    assert!(exp2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&exp2_alt), ComparableFloatRef(&exp2));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.exp2_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp2));
+    assert_eq!(o_alt, o);
+
+    let (rug_exp2, rug_o) = rug_exp2_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_exp2)),
+        ComparableFloatRef(&exp2),
+    );
+    assert_eq!(rug_o, o);
+
+    let (exp2_alt, o_alt) = x.exp2_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&exp2_alt), ComparableFloatRef(&exp2));
+    assert_eq!(o_alt, o);
+
+    if exp2.is_normal() {
+        assert_eq!(exp2.get_prec(), Some(prec));
+    }
+
+    if !x.is_nan() {
+        assert!(exp2 >= 0u32);
+    }
+}
+
+#[test]
+fn exp2_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        exp2_prec_properties_helper(x, prec);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_pair_gen_var_1().test_properties_with_config(&config, |(x, prec)| {
+        exp2_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (exp2, o) = Float::NAN.exp2_prec(prec);
+        assert!(exp2.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(Float::INFINITY.exp2_prec(prec), (Float::INFINITY, Equal));
+        let (exp2, o) = Float::NEGATIVE_INFINITY.exp2_prec(prec);
+        assert_eq!(ComparableFloat(exp2), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        assert_eq!(Float::ZERO.exp2_prec(prec), (Float::one_prec(prec), Equal));
+        assert_eq!(
+            Float::NEGATIVE_ZERO.exp2_prec(prec),
+            (Float::one_prec(prec), Equal)
+        );
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn exp2_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (exp2, o) = x.clone().exp2_round(rm);
+    assert!(exp2.is_valid());
+    let (exp2_alt, o_alt) = x.exp2_round_ref(rm);
+    assert!(exp2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&exp2_alt), ComparableFloatRef(&exp2));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.exp2_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp2));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_exp2, rug_o) = rug_exp2_round(&rug::Float::exact_from(&x), rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_exp2)),
+            ComparableFloatRef(&exp2),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    let (exp2_alt, o_alt) = x.exp2_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&exp2_alt), ComparableFloatRef(&exp2));
+    assert_eq!(o_alt, o);
+
+    if exp2.is_normal() {
+        assert_eq!(exp2.get_prec(), Some(x.significant_bits()));
+    }
+
+    if !x.is_nan() {
+        assert!(exp2 >= 0u32);
+    }
+
+    match rm {
+        Floor => assert_ne!(o, Greater),
+        Ceiling => assert_ne!(o, Less),
+        Down => {
+            if exp2.is_sign_positive() {
+                assert_ne!(o, Greater);
+            } else {
+                assert_ne!(o, Less);
+            }
+        }
+        Up => {
+            if exp2.is_sign_positive() {
+                assert_ne!(o, Less);
+            } else {
+                assert_ne!(o, Greater);
+            }
+        }
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+}
+
+#[test]
+fn exp2_round_properties() {
+    float_rounding_mode_pair_gen_var_25().test_properties(|(x, rm)| {
+        exp2_round_properties_helper(x, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_rounding_mode_pair_gen_var_25().test_properties_with_config(&config, |(x, rm)| {
+        exp2_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (exp2, o) = Float::NAN.exp2_round(rm);
+        assert!(exp2.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(Float::INFINITY.exp2_round(rm), (Float::INFINITY, Equal));
+        let (exp2, o) = Float::NEGATIVE_INFINITY.exp2_round(rm);
+        assert_eq!(ComparableFloat(exp2), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        assert_eq!(Float::ZERO.exp2_round(rm), (Float::ONE, Equal));
+        assert_eq!(Float::NEGATIVE_ZERO.exp2_round(rm), (Float::ONE, Equal));
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn exp2_properties_helper(x: Float) {
+    let exp2 = x.clone().exp2();
+    assert!(exp2.is_valid());
+
+    let exp2_alt = (&x).exp2();
+    assert!(exp2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&exp2_alt), ComparableFloatRef(&exp2));
+
+    let mut x_alt = x.clone();
+    x_alt.exp2_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp2));
+
+    let rug_exp2 = rug_exp2(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_exp2)),
+        ComparableFloatRef(&exp2),
+    );
+
+    assert_eq!(
+        ComparableFloatRef(&x.exp2_round_ref(Nearest).0),
+        ComparableFloatRef(&exp2)
+    );
+
+    if !x.is_nan() {
+        assert!(exp2 >= 0u32);
+    }
+}
+
+#[test]
+fn exp2_properties() {
+    float_gen().test_properties(|x| {
+        exp2_properties_helper(x);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_gen().test_properties_with_config(&config, |x| {
+        exp2_properties_helper(x);
+    });
+
+    float_gen_var_6().test_properties(|x| {
+        exp2_properties_helper(x);
+    });
+
+    float_gen_var_7().test_properties(|x| {
+        exp2_properties_helper(x);
+    });
+
+    float_gen_var_8().test_properties(|x| {
+        exp2_properties_helper(x);
+    });
+
+    float_gen_var_11().test_properties(|x| {
+        exp2_properties_helper(x);
+    });
+}
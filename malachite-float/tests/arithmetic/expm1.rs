@@ -0,0 +1,1192 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{ExpM1, ExpM1Assign};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::{
+    rounding_mode_gen, unsigned_gen_var_11, unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::test_util::arithmetic::expm1::{
+    rug_expm1, rug_expm1_prec, rug_expm1_prec_round, rug_expm1_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_gen_var_6, float_gen_var_7, float_gen_var_8, float_gen_var_11,
+    float_rounding_mode_pair_gen_var_25, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_14,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_nz::platform::Limb;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_expm1() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let expm1 = x.clone().expm1();
+        assert!(expm1.is_valid());
+
+        assert_eq!(expm1.to_string(), out);
+        assert_eq!(to_hex_string(&expm1), out_hex);
+
+        let expm1_alt = (&x).expm1();
+        assert!(expm1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&expm1), ComparableFloatRef(&expm1_alt));
+
+        let mut expm1_alt = x.clone();
+        expm1_alt.expm1_assign();
+        assert!(expm1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&expm1), ComparableFloatRef(&expm1_alt));
+
+        let rug_expm1 = rug_expm1(&rug::Float::exact_from(&x));
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_expm1)),
+            ComparableFloatRef(&expm1),
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", "-1.0", "-0x1.0#1");
+    test("0.0", "0x0.0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "2.0", "0x2.0#1");
+    test("-1.0", "-0x1.0#1", "-0.5", "-0x0.8#1");
+    test("2.0", "0x2.0#1", "8.0", "0x8.0#1");
+    test("0.5", "0x0.8#1", "0.5", "0x0.8#1");
+    test("-0.5", "-0x0.8#1", "-0.5", "-0x0.8#1");
+    test("123.0", "0x7b.0#7", "2.63e53", "0x2.c0E+44#7");
+    test("-123.0", "-0x7b.0#7", "-1.0", "-0x1.00#7");
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "3.113250378782928",
+        "0x3.1cfdfa1121dda#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "22.140692632779267",
+        "0x16.24046eb09339#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-0.9567860817362277",
+        "-0x0.f4efeec2533880#53",
+    );
+    test("9.0e-13", "0x1.0E-10#1", "9.0e-13", "0x1.0E-10#1");
+    test("-9.0e-13", "-0x1.0E-10#1", "-9.0e-13", "-0x1.0E-10#1");
+    test("1000.0", "0x3e8.0#10", "1.97e434", "0x6.7aE+360#10");
+    test("4.0e9", "0x1.0E+8#1", "Infinity", "Infinity");
+    test("-4.0e9", "-0x1.0E+8#1", "-1.0", "-0x1.0#1");
+}
+
+#[test]
+fn test_expm1_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (expm1, o) = x.clone().expm1_prec(prec);
+        assert!(expm1.is_valid());
+
+        assert_eq!(expm1.to_string(), out);
+        assert_eq!(to_hex_string(&expm1), out_hex);
+        assert_eq!(o, o_out);
+
+        let (expm1_alt, o_alt) = x.expm1_prec_ref(prec);
+        assert!(expm1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&expm1), ComparableFloatRef(&expm1_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut expm1_alt = x.clone();
+        let o_alt = expm1_alt.expm1_prec_assign(prec);
+        assert!(expm1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&expm1), ComparableFloatRef(&expm1_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_expm1, rug_o) = rug_expm1_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_expm1)),
+            ComparableFloatRef(&expm1),
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", 10, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", 100, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", 1, "-1.0", "-0x1.0#1", Equal);
+    test("-Infinity", "-Infinity", 10, "-1.0", "-0x1.000#10", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        100,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("0.0", "0x0.0", 1, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 100, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 100, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, "1.719", "0x1.b80#10", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.718281828459045235360287471353",
+        "0x1.b7e151628aed2a6abf715880a#100",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-0.5", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", 10, "-0.632", "-0x0.a1c#10", Greater);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-0.6321205588285576784044762298386",
+        "-0x0.a1d2a7274c4320e54521387d7#100",
+        Less,
+    );
+    test("2.0", "0x2.0#1", 1, "8.0", "0x8.0#1", Greater);
+    test("2.0", "0x2.0#1", 10, "6.39", "0x6.64#10", Greater);
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        "6.389056098930650227230427460577",
+        "0x6.63992e35376b730ce8ee881b0#100",
+        Greater,
+    );
+    test("0.5", "0x0.8#1", 1, "0.5", "0x0.8#1", Less);
+    test("0.5", "0x0.8#1", 10, "0.648", "0x0.a60#10", Less);
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "0.648721270700128146848650787814",
+        "0x0.a61298e1e069bc972dfefab6e#100",
+        Greater,
+    );
+    test("-0.5", "-0x0.8#1", 1, "-0.5", "-0x0.8#1", Less);
+    test("-0.5", "-0x0.8#1", 10, "-0.3936", "-0x0.64c#10", Less);
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        100,
+        "-0.3934693402873665763962004650089",
+        "-0x0.64ba681c834fb00c298a5caad0#100",
+        Less,
+    );
+    test("123.0", "0x7b.0#7", 1, "2.0e53", "0x2.0E+44#1", Less);
+    test("123.0", "0x7b.0#7", 10, "2.619e53", "0x2.bcE+44#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "2.619517318749062676188981025375e53",
+        "0x2.bc22a5f3502327274223b3e68E+44#100",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", 1, "-1.0", "-0x1.0#1", Less);
+    test("-123.0", "-0x7b.0#7", 10, "-1.0", "-0x1.000#10", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        10,
+        "3.113",
+        "0x3.1d#10",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        100,
+        "3.113250378782927914813560648797",
+        "0x3.1cfdfa1121dd9aef738ad9460#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "22.16",
+        "0x16.28#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "22.14069263277926617181157008936",
+        "0x16.24046eb09338d2991a30893e#100",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "-0.957",
+        "-0x0.f50#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        "-0.956786081736227744933403594794",
+        "-0x0.f4efeec253388335b0cf8dd92#100",
+        Less,
+    );
+    test("9.0e-13", "0x1.0E-10#1", 1, "9.0e-13", "0x1.0E-10#1", Less);
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        "9.09e-13",
+        "0x1.000E-10#10",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        100,
+        "9.09494701773341828221315701723e-13",
+        "0x1.000000000080000000002aaaaE-10#100",
+        Less,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        1,
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Less,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        10,
+        "-9.09e-13",
+        "-0x1.000E-10#10",
+        Less,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        100,
+        "-9.09494701772514647608762674049e-13",
+        "-0xf.fffffffff80000000002aaabE-11#100",
+        Less,
+    );
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        1,
+        "2.0e434",
+        "0x8.0E+360#1",
+        Greater,
+    );
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        10,
+        "1.97e434",
+        "0x6.7aE+360#10",
+        Greater,
+    );
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        100,
+        "1.970071114017046993888879352244e434",
+        "0x6.79c8de6bb5ceb60158acfea90E+360#100",
+        Greater,
+    );
+    test("4.0e9", "0x1.0E+8#1", 1, "Infinity", "Infinity", Greater);
+    test("4.0e9", "0x1.0E+8#1", 10, "Infinity", "Infinity", Greater);
+    test("4.0e9", "0x1.0E+8#1", 100, "Infinity", "Infinity", Greater);
+    test("-4.0e9", "-0x1.0E+8#1", 1, "-1.0", "-0x1.0#1", Less);
+    test("-4.0e9", "-0x1.0E+8#1", 10, "-1.0", "-0x1.000#10", Less);
+    test(
+        "-4.0e9",
+        "-0x1.0E+8#1",
+        100,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Less,
+    );
+}
+
+#[test]
+fn expm1_prec_fail() {
+    assert_panic!(Float::NAN.expm1_prec(0));
+    assert_panic!(Float::NAN.expm1_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.expm1_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_expm1_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (expm1, o) = x.clone().expm1_round(rm);
+        assert!(expm1.is_valid());
+
+        assert_eq!(expm1.to_string(), out);
+        assert_eq!(to_hex_string(&expm1), out_hex);
+        assert_eq!(o, o_out);
+
+        let (expm1_alt, o_alt) = x.expm1_round_ref(rm);
+        assert!(expm1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&expm1), ComparableFloatRef(&expm1_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut expm1_alt = x.clone();
+        let o_alt = expm1_alt.expm1_round_assign(rm);
+        assert!(expm1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&expm1), ComparableFloatRef(&expm1_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_expm1, rug_o) = rug_expm1_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_expm1)),
+                ComparableFloatRef(&expm1),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", Floor, "-1.0", "-0x1.0#1", Equal);
+    test("0.0", "0x0.0", Floor, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", Floor, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", Floor, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", Down, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", Up, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "2.0", "0x2.0#1", Greater);
+    test("2.0", "0x2.0#1", Floor, "4.0", "0x4.0#1", Less);
+    test("2.0", "0x2.0#1", Ceiling, "8.0", "0x8.0#1", Greater);
+    test("2.0", "0x2.0#1", Down, "4.0", "0x4.0#1", Less);
+    test("2.0", "0x2.0#1", Up, "8.0", "0x8.0#1", Greater);
+    test("2.0", "0x2.0#1", Nearest, "8.0", "0x8.0#1", Greater);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Floor,
+        "22.140692632779263",
+        "0x16.24046eb09338#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ceiling,
+        "22.140692632779267",
+        "0x16.24046eb09339#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Down,
+        "22.140692632779263",
+        "0x16.24046eb09338#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Up,
+        "22.140692632779267",
+        "0x16.24046eb09339#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Nearest,
+        "22.140692632779267",
+        "0x16.24046eb09339#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Floor,
+        "-0.9567860817362278",
+        "-0x0.f4efeec2533888#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Ceiling,
+        "-0.9567860817362277",
+        "-0x0.f4efeec2533880#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Down,
+        "-0.9567860817362277",
+        "-0x0.f4efeec2533880#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Up,
+        "-0.9567860817362278",
+        "-0x0.f4efeec2533888#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Nearest,
+        "-0.9567860817362277",
+        "-0x0.f4efeec2533880#53",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Floor,
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Ceiling,
+        "2.0e-12",
+        "0x2.0E-10#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Down,
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Up,
+        "2.0e-12",
+        "0x2.0E-10#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Nearest,
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Less,
+    );
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Floor,
+        "1.968e434",
+        "0x6.78E+360#10",
+        Less,
+    );
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Ceiling,
+        "1.97e434",
+        "0x6.7aE+360#10",
+        Greater,
+    );
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Down,
+        "1.968e434",
+        "0x6.78E+360#10",
+        Less,
+    );
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Up,
+        "1.97e434",
+        "0x6.7aE+360#10",
+        Greater,
+    );
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Nearest,
+        "1.97e434",
+        "0x6.7aE+360#10",
+        Greater,
+    );
+}
+
+#[test]
+fn expm1_round_fail() {
+    const THREE: Float = Float::const_from_unsigned(3);
+    assert_panic!(THREE.expm1_round(Exact));
+    assert_panic!(THREE.expm1_round_ref(Exact));
+    assert_panic!({
+        let mut x = THREE;
+        x.expm1_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_expm1_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (expm1, o) = x.clone().expm1_prec_round(prec, rm);
+        assert!(expm1.is_valid());
+
+        assert_eq!(expm1.to_string(), out);
+        assert_eq!(to_hex_string(&expm1), out_hex);
+        assert_eq!(o, o_out);
+
+        let (expm1_alt, o_alt) = x.expm1_prec_round_ref(prec, rm);
+        assert!(expm1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&expm1), ComparableFloatRef(&expm1_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut expm1_alt = x.clone();
+        let o_alt = expm1_alt.expm1_prec_round_assign(prec, rm);
+        assert!(expm1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&expm1), ComparableFloatRef(&expm1_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_expm1, rug_o) = rug_expm1_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_expm1)),
+                ComparableFloatRef(&expm1),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test(
+        "Infinity", "Infinity", 1, Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Equal,
+    );
+    test("0.0", "0x0.0", 1, Floor, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, Floor, "1.717", "0x1.b78#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "1.719",
+        "0x1.b80#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "1.719",
+        "0x1.b80#10",
+        Greater,
+    );
+    test("2.0", "0x2.0#1", 1, Floor, "4.0", "0x4.0#1", Less);
+    test("2.0", "0x2.0#1", 1, Ceiling, "8.0", "0x8.0#1", Greater);
+    test("2.0", "0x2.0#1", 1, Nearest, "8.0", "0x8.0#1", Greater);
+    test("2.0", "0x2.0#1", 10, Floor, "6.383", "0x6.62#10", Less);
+    test("2.0", "0x2.0#1", 10, Ceiling, "6.39", "0x6.64#10", Greater);
+    test("2.0", "0x2.0#1", 10, Nearest, "6.39", "0x6.64#10", Greater);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Floor,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Ceiling,
+        "3.0e1",
+        "0x2.0E+1#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Nearest,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "22.12",
+        "0x16.20#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "22.16",
+        "0x16.28#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "22.16",
+        "0x16.28#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "-0.957",
+        "-0x0.f50#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "-0.956",
+        "-0x0.f4c#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "-0.957",
+        "-0x0.f50#10",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        1,
+        Floor,
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        1,
+        Ceiling,
+        "2.0e-12",
+        "0x2.0E-10#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        1,
+        Nearest,
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        Floor,
+        "9.09e-13",
+        "0x1.000E-10#10",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        Ceiling,
+        "9.11e-13",
+        "0x1.008E-10#10",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        Nearest,
+        "9.09e-13",
+        "0x1.000E-10#10",
+        Less,
+    );
+}
+
+#[test]
+fn expm1_prec_round_fail() {
+    const THREE: Float = Float::const_from_unsigned(3);
+    assert_panic!(Float::one_prec(1).expm1_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).expm1_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.expm1_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(THREE.expm1_prec_round(1, Exact));
+    assert_panic!(THREE.expm1_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = THREE;
+        x.expm1_prec_round_assign(1, Exact)
+    });
+}
+
+fn expm1_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (expm1, o) = x.clone().expm1_prec_round(prec, rm);
+    assert!(expm1.is_valid());
+    let (expm1_alt, o_alt) = x.expm1_prec_round_ref(prec, rm);
+    assert!(expm1_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&expm1_alt), ComparableFloatRef(&expm1));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.expm1_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&expm1));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_expm1, rug_o) = rug_expm1_prec_round(&rug::Float::exact_from(&x), prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_expm1)),
+            ComparableFloatRef(&expm1),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if expm1.is_normal() {
+        assert_eq!(expm1.get_prec(), Some(prec));
+    }
+
+    if !x.is_nan() {
+        assert!(expm1 >= -1i32);
+        assert_eq!(expm1.is_sign_positive(), x.is_sign_positive());
+    }
+
+    match rm {
+        Floor => assert_ne!(o, Greater),
+        Ceiling => assert_ne!(o, Less),
+        Down => {
+            if expm1.is_sign_positive() {
+                assert_ne!(o, Greater);
+            } else {
+                assert_ne!(o, Less);
+            }
+        }
+        Up => {
+            if expm1.is_sign_positive() {
+                assert_ne!(o, Less);
+            } else {
+                assert_ne!(o, Greater);
+            }
+        }
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.expm1_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(expm1.clone()));
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.expm1_prec_round(prec, Exact));
+    }
+}
+
+#[test]
+fn expm1_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties(|(x, prec, rm)| {
+        expm1_prec_round_properties_helper(x, prec, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties_with_config(
+        &config,
+        |(x, prec, rm)| {
+            expm1_prec_round_properties_helper(x, prec, rm);
+        },
+    );
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (expm1, o) = Float::NAN.expm1_prec_round(prec, rm);
+        assert!(expm1.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(
+            Float::INFINITY.expm1_prec_round(prec, rm),
+            (Float::INFINITY, Equal)
+        );
+        assert_eq!(
+            Float::NEGATIVE_INFINITY.expm1_prec_round(prec, rm),
+            (Float::negative_one_prec(prec), Equal)
+        );
+        let (expm1, o) = Float::ZERO.expm1_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(expm1), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (expm1, o) = Float::NEGATIVE_ZERO.expm1_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloat(expm1),
+            ComparableFloat(Float::NEGATIVE_ZERO)
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn expm1_prec_properties_helper(x: Float, prec: u64) {
+    let (expm1, o) = x.clone().expm1_prec(prec);
+    assert!(expm1.is_valid());
+    let (expm1_alt, o_alt) = x.expm1_prec_ref(prec);
+    assert!(expm1_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&expm1_alt), ComparableFloatRef(&expm1));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.expm1_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&expm1));
+    assert_eq!(o_alt, o);
+
+    let (rug_expm1, rug_o) = rug_expm1_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_expm1)),
+        ComparableFloatRef(&expm1),
+    );
+    assert_eq!(rug_o, o);
+
+    let (expm1_alt, o_alt) = x.expm1_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&expm1_alt), ComparableFloatRef(&expm1));
+    assert_eq!(o_alt, o);
+
+    if expm1.is_normal() {
+        assert_eq!(expm1.get_prec(), Some(prec));
+    }
+
+    if !x.is_nan() {
+        assert!(expm1 >= -1i32);
+        assert_eq!(expm1.is_sign_positive(), x.is_sign_positive());
+    }
+}
+
+#[test]
+fn expm1_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        expm1_prec_properties_helper(x, prec);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_pair_gen_var_1().test_properties_with_config(&config, |(x, prec)| {
+        expm1_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (expm1, o) = Float::NAN.expm1_prec(prec);
+        assert!(expm1.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(Float::INFINITY.expm1_prec(prec), (Float::INFINITY, Equal));
+        assert_eq!(
+            Float::NEGATIVE_INFINITY.expm1_prec(prec),
+            (Float::negative_one_prec(prec), Equal)
+        );
+        let (expm1, o) = Float::ZERO.expm1_prec(prec);
+        assert_eq!(ComparableFloat(expm1), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (expm1, o) = Float::NEGATIVE_ZERO.expm1_prec(prec);
+        assert_eq!(
+            ComparableFloat(expm1),
+            ComparableFloat(Float::NEGATIVE_ZERO)
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn expm1_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (expm1, o) = x.clone().expm1_round(rm);
+    assert!(expm1.is_valid());
+    let (expm1_alt, o_alt) = x.expm1_round_ref(rm);
+    assert!(expm1_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&expm1_alt), ComparableFloatRef(&expm1));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.expm1_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&expm1));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_expm1, rug_o) = rug_expm1_round(&rug::Float::exact_from(&x), rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_expm1)),
+            ComparableFloatRef(&expm1),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    let (expm1_alt, o_alt) = x.expm1_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&expm1_alt), ComparableFloatRef(&expm1));
+    assert_eq!(o_alt, o);
+
+    if expm1.is_normal() {
+        assert_eq!(expm1.get_prec(), Some(x.significant_bits()));
+    }
+
+    if !x.is_nan() {
+        assert!(expm1 >= -1i32);
+        assert_eq!(expm1.is_sign_positive(), x.is_sign_positive());
+    }
+
+    match rm {
+        Floor => assert_ne!(o, Greater),
+        Ceiling => assert_ne!(o, Less),
+        Down => {
+            if expm1.is_sign_positive() {
+                assert_ne!(o, Greater);
+            } else {
+                assert_ne!(o, Less);
+            }
+        }
+        Up => {
+            if expm1.is_sign_positive() {
+                assert_ne!(o, Less);
+            } else {
+                assert_ne!(o, Greater);
+            }
+        }
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+}
+
+#[test]
+fn expm1_round_properties() {
+    float_rounding_mode_pair_gen_var_25().test_properties(|(x, rm)| {
+        expm1_round_properties_helper(x, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_rounding_mode_pair_gen_var_25().test_properties_with_config(&config, |(x, rm)| {
+        expm1_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (expm1, o) = Float::NAN.expm1_round(rm);
+        assert!(expm1.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(Float::INFINITY.expm1_round(rm), (Float::INFINITY, Equal));
+        assert_eq!(
+            Float::NEGATIVE_INFINITY.expm1_round(rm),
+            (Float::NEGATIVE_ONE, Equal)
+        );
+        let (expm1, o) = Float::ZERO.expm1_round(rm);
+        assert_eq!(ComparableFloat(expm1), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (expm1, o) = Float::NEGATIVE_ZERO.expm1_round(rm);
+        assert_eq!(
+            ComparableFloat(expm1),
+            ComparableFloat(Float::NEGATIVE_ZERO)
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn expm1_properties_helper(x: Float) {
+    let expm1 = x.clone().expm1();
+    assert!(expm1.is_valid());
+
+    let expm1_alt = (&x).expm1();
+    assert!(expm1_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&expm1_alt), ComparableFloatRef(&expm1));
+
+    let mut x_alt = x.clone();
+    x_alt.expm1_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&expm1));
+
+    let rug_expm1 = rug_expm1(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_expm1)),
+        ComparableFloatRef(&expm1),
+    );
+
+    assert_eq!(
+        ComparableFloatRef(&x.expm1_round_ref(Nearest).0),
+        ComparableFloatRef(&expm1)
+    );
+
+    if !x.is_nan() {
+        assert!(expm1 >= -1i32);
+        assert_eq!(expm1.is_sign_positive(), x.is_sign_positive());
+    }
+}
+
+#[test]
+fn expm1_properties() {
+    float_gen().test_properties(|x| {
+        expm1_properties_helper(x);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_gen().test_properties_with_config(&config, |x| {
+        expm1_properties_helper(x);
+    });
+
+    float_gen_var_6().test_properties(|x| {
+        expm1_properties_helper(x);
+    });
+
+    float_gen_var_7().test_properties(|x| {
+        expm1_properties_helper(x);
+    });
+
+    float_gen_var_8().test_properties(|x| {
+        expm1_properties_helper(x);
+    });
+
+    float_gen_var_11().test_properties(|x| {
+        expm1_properties_helper(x);
+    });
+}
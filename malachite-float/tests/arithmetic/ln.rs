@@ -0,0 +1,1166 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{Ln, LnAssign};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::{
+    rounding_mode_gen, unsigned_gen_var_11, unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::test_util::arithmetic::ln::{
+    rug_ln, rug_ln_prec, rug_ln_prec_round, rug_ln_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_gen_var_6, float_gen_var_7, float_gen_var_8, float_gen_var_11,
+    float_rounding_mode_pair_gen_var_25, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_14,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_nz::platform::Limb;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_ln() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let ln = x.clone().ln();
+        assert!(ln.is_valid());
+
+        assert_eq!(ln.to_string(), out);
+        assert_eq!(to_hex_string(&ln), out_hex);
+
+        let ln_alt = (&x).ln();
+        assert!(ln_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln), ComparableFloatRef(&ln_alt));
+
+        let mut ln_alt = x.clone();
+        ln_alt.ln_assign();
+        assert!(ln_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln), ComparableFloatRef(&ln_alt));
+
+        let rug_ln = rug_ln(&rug::Float::exact_from(&x));
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_ln)),
+            ComparableFloatRef(&ln),
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "-Infinity", "-Infinity");
+    test("-0.0", "-0x0.0", "-Infinity", "-Infinity");
+    test("1.0", "0x1.0#1", "0.0", "0x0.0");
+    test("-1.0", "-0x1.0#1", "NaN", "NaN");
+    test("2.0", "0x2.0#1", "0.5", "0x0.8#1");
+    test("0.5", "0x0.8#1", "-0.5", "-0x0.8#1");
+    test("-0.5", "-0x0.8#1", "NaN", "NaN");
+    test("123.0", "0x7b.0#7", "4.81", "0x4.d#7");
+    test("-123.0", "-0x7b.0#7", "NaN", "NaN");
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "0.3465735902799727",
+        "0x0.58b90bfbe8e7c0#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.1447298858494002",
+        "0x1.250d048e7a1bd#53",
+    );
+    test("-3.1415926535897931", "-0x3.243f6a8885a30#53", "NaN", "NaN");
+    test("9.0e-13", "0x1.0E-10#1", "-3.0e1", "-0x2.0E+1#1");
+    test("-9.0e-13", "-0x1.0E-10#1", "NaN", "NaN");
+    test("1000.0", "0x3e8.0#10", "6.906", "0x6.e8#10");
+    test("4.0e9", "0x1.0E+8#1", "2.0e1", "0x1.0E+1#1");
+    test("-4.0e9", "-0x1.0E+8#1", "NaN", "NaN");
+}
+
+#[test]
+fn test_ln_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (ln, o) = x.clone().ln_prec(prec);
+        assert!(ln.is_valid());
+
+        assert_eq!(ln.to_string(), out);
+        assert_eq!(to_hex_string(&ln), out_hex);
+        assert_eq!(o, o_out);
+
+        let (ln_alt, o_alt) = x.ln_prec_ref(prec);
+        assert!(ln_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln), ComparableFloatRef(&ln_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut ln_alt = x.clone();
+        let o_alt = ln_alt.ln_prec_assign(prec);
+        assert!(ln_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln), ComparableFloatRef(&ln_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_ln, rug_o) = rug_ln_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_ln)),
+            ComparableFloatRef(&ln),
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", 10, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", 100, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 100, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 10, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 100, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 10, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 100, "-Infinity", "-Infinity", Equal);
+    test("1.0", "0x1.0#1", 1, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 100, "0.0", "0x0.0", Equal);
+    test("-1.0", "-0x1.0#1", 1, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 100, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", 1, "0.5", "0x0.8#1", Less);
+    test("2.0", "0x2.0#1", 10, "0.693", "0x0.b18#10", Greater);
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        "0.693147180559945309417232121458",
+        "0x0.b17217f7d1cf79abc9e3b3980#100",
+        Less,
+    );
+    test("0.5", "0x0.8#1", 1, "-0.5", "-0x0.8#1", Greater);
+    test("0.5", "0x0.8#1", 10, "-0.693", "-0x0.b18#10", Less);
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "-0.693147180559945309417232121458",
+        "-0x0.b17217f7d1cf79abc9e3b3980#100",
+        Greater,
+    );
+    test("-0.5", "-0x0.8#1", 1, "NaN", "NaN", Equal);
+    test("-0.5", "-0x0.8#1", 10, "NaN", "NaN", Equal);
+    test("-0.5", "-0x0.8#1", 100, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 1, "4.0", "0x4.0#1", Less);
+    test("123.0", "0x7b.0#7", 10, "4.81", "0x4.d0#10", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "4.812184355372417495262008609963",
+        "0x4.cfeb505ca5ba5eec4018e7bc8#100",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", 1, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 10, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 100, "NaN", "NaN", Equal);
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        1,
+        "0.2",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        10,
+        "0.3467",
+        "0x0.58c#10",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        100,
+        "0.3465735902799727230667026373485",
+        "0x0.58b90bfbe8e7c1c2e0e1b15490#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "1.145",
+        "0x1.250#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1.144729885849400135161709026159",
+        "0x1.250d048e7a1bcdee499fa5b0c#100",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("9.0e-13", "0x1.0E-10#1", 1, "-3.0e1", "-0x2.0E+1#1", Less);
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        "-27.72",
+        "-0x1b.b8#10",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        100,
+        "-27.72588722239781237668928485832",
+        "-0x1b.b9d3beb8c86b02d78b940fc0#100",
+        Greater,
+    );
+    test("-9.0e-13", "-0x1.0E-10#1", 1, "NaN", "NaN", Equal);
+    test("-9.0e-13", "-0x1.0E-10#1", 10, "NaN", "NaN", Equal);
+    test("-9.0e-13", "-0x1.0E-10#1", 100, "NaN", "NaN", Equal);
+    test("1000.0", "0x3e8.0#10", 1, "8.0", "0x8.0#1", Greater);
+    test("1000.0", "0x3e8.0#10", 10, "6.906", "0x6.e8#10", Less);
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        100,
+        "6.907755278982137052053974364051",
+        "0x6.e862a663ffe81112fc120a0a0#100",
+        Less,
+    );
+    test("4.0e9", "0x1.0E+8#1", 1, "2.0e1", "0x1.0E+1#1", Less);
+    test("4.0e9", "0x1.0E+8#1", 10, "22.19", "0x16.30#10", Greater);
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        100,
+        "22.18070977791824990135142788666",
+        "0x16.2e42fefa39ef35793c767300#100",
+        Less,
+    );
+    test("-4.0e9", "-0x1.0E+8#1", 1, "NaN", "NaN", Equal);
+    test("-4.0e9", "-0x1.0E+8#1", 10, "NaN", "NaN", Equal);
+    test("-4.0e9", "-0x1.0E+8#1", 100, "NaN", "NaN", Equal);
+}
+
+#[test]
+fn ln_prec_fail() {
+    assert_panic!(Float::NAN.ln_prec(0));
+    assert_panic!(Float::NAN.ln_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.ln_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_ln_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (ln, o) = x.clone().ln_round(rm);
+        assert!(ln.is_valid());
+
+        assert_eq!(ln.to_string(), out);
+        assert_eq!(to_hex_string(&ln), out_hex);
+        assert_eq!(o, o_out);
+
+        let (ln_alt, o_alt) = x.ln_round_ref(rm);
+        assert!(ln_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln), ComparableFloatRef(&ln_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut ln_alt = x.clone();
+        let o_alt = ln_alt.ln_round_assign(rm);
+        assert!(ln_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln), ComparableFloatRef(&ln_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_ln, rug_o) = rug_ln_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_ln)),
+                ComparableFloatRef(&ln),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Floor, "-Infinity", "-Infinity", Equal);
+    test("1.0", "0x1.0#1", Floor, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Ceiling, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Down, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Up, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Nearest, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Exact, "0.0", "0x0.0", Equal);
+    test("2.0", "0x2.0#1", Floor, "0.5", "0x0.8#1", Less);
+    test("2.0", "0x2.0#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("2.0", "0x2.0#1", Down, "0.5", "0x0.8#1", Less);
+    test("2.0", "0x2.0#1", Up, "1.0", "0x1.0#1", Greater);
+    test("2.0", "0x2.0#1", Nearest, "0.5", "0x0.8#1", Less);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Floor,
+        "1.1447298858493999",
+        "0x1.250d048e7a1bc#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ceiling,
+        "1.1447298858494002",
+        "0x1.250d048e7a1bd#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Down,
+        "1.1447298858493999",
+        "0x1.250d048e7a1bc#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Up,
+        "1.1447298858494002",
+        "0x1.250d048e7a1bd#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Nearest,
+        "1.1447298858494002",
+        "0x1.250d048e7a1bd#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Down,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Up,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Floor,
+        "-3.0e1",
+        "-0x2.0E+1#1",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Ceiling,
+        "-2.0e1",
+        "-0x1.0E+1#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Down,
+        "-2.0e1",
+        "-0x1.0E+1#1",
+        Greater,
+    );
+    test("9.0e-13", "0x1.0E-10#1", Up, "-3.0e1", "-0x2.0E+1#1", Less);
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Nearest,
+        "-3.0e1",
+        "-0x2.0E+1#1",
+        Less,
+    );
+    test("1000.0", "0x3e8.0#10", Floor, "6.906", "0x6.e8#10", Less);
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Ceiling,
+        "6.914",
+        "0x6.ea#10",
+        Greater,
+    );
+    test("1000.0", "0x3e8.0#10", Down, "6.906", "0x6.e8#10", Less);
+    test("1000.0", "0x3e8.0#10", Up, "6.914", "0x6.ea#10", Greater);
+    test("1000.0", "0x3e8.0#10", Nearest, "6.906", "0x6.e8#10", Less);
+}
+
+#[test]
+fn ln_round_fail() {
+    const THREE: Float = Float::const_from_unsigned(3);
+    assert_panic!(THREE.ln_round(Exact));
+    assert_panic!(THREE.ln_round_ref(Exact));
+    assert_panic!({
+        let mut x = THREE;
+        x.ln_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_ln_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (ln, o) = x.clone().ln_prec_round(prec, rm);
+        assert!(ln.is_valid());
+
+        assert_eq!(ln.to_string(), out);
+        assert_eq!(to_hex_string(&ln), out_hex);
+        assert_eq!(o, o_out);
+
+        let (ln_alt, o_alt) = x.ln_prec_round_ref(prec, rm);
+        assert!(ln_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln), ComparableFloatRef(&ln_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut ln_alt = x.clone();
+        let o_alt = ln_alt.ln_prec_round_assign(prec, rm);
+        assert!(ln_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln), ComparableFloatRef(&ln_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_ln, rug_o) = rug_ln_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_ln)),
+                ComparableFloatRef(&ln),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test(
+        "Infinity", "Infinity", 1, Floor, "Infinity", "Infinity", Equal,
+    );
+    test("-Infinity", "-Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, Floor, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "-Infinity", "-Infinity", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Exact, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Floor, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Exact, "0.0", "0x0.0", Equal);
+    test("2.0", "0x2.0#1", 1, Floor, "0.5", "0x0.8#1", Less);
+    test("2.0", "0x2.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("2.0", "0x2.0#1", 1, Nearest, "0.5", "0x0.8#1", Less);
+    test("2.0", "0x2.0#1", 10, Floor, "0.692", "0x0.b14#10", Less);
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        Ceiling,
+        "0.693",
+        "0x0.b18#10",
+        Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        Nearest,
+        "0.693",
+        "0x0.b18#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "1.145",
+        "0x1.250#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "1.146",
+        "0x1.258#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "1.145",
+        "0x1.250#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        1,
+        Floor,
+        "-3.0e1",
+        "-0x2.0E+1#1",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        1,
+        Ceiling,
+        "-2.0e1",
+        "-0x1.0E+1#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        1,
+        Nearest,
+        "-3.0e1",
+        "-0x2.0E+1#1",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        Floor,
+        "-27.75",
+        "-0x1b.c0#10",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        Ceiling,
+        "-27.72",
+        "-0x1b.b8#10",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        Nearest,
+        "-27.72",
+        "-0x1b.b8#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Floor,
+        "1.144729885849400135161709026159",
+        "0x1.250d048e7a1bcdee499fa5b0c#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Ceiling,
+        "1.14472988584940013516170902616",
+        "0x1.250d048e7a1bcdee499fa5b0e#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Down,
+        "1.144729885849400135161709026159",
+        "0x1.250d048e7a1bcdee499fa5b0c#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Up,
+        "1.14472988584940013516170902616",
+        "0x1.250d048e7a1bcdee499fa5b0e#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Nearest,
+        "1.144729885849400135161709026159",
+        "0x1.250d048e7a1bcdee499fa5b0c#100",
+        Less,
+    );
+}
+
+#[test]
+fn ln_prec_round_fail() {
+    const THREE: Float = Float::const_from_unsigned(3);
+    assert_panic!(Float::one_prec(1).ln_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).ln_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.ln_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(THREE.ln_prec_round(1, Exact));
+    assert_panic!(THREE.ln_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = THREE;
+        x.ln_prec_round_assign(1, Exact)
+    });
+}
+
+fn ln_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (ln, o) = x.clone().ln_prec_round(prec, rm);
+    assert!(ln.is_valid());
+    let (ln_alt, o_alt) = x.ln_prec_round_ref(prec, rm);
+    assert!(ln_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&ln_alt), ComparableFloatRef(&ln));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.ln_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&ln));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_ln, rug_o) = rug_ln_prec_round(&rug::Float::exact_from(&x), prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_ln)),
+            ComparableFloatRef(&ln),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if ln.is_normal() {
+        assert_eq!(ln.get_prec(), Some(prec));
+    }
+
+    if x < 0u32 {
+        assert!(ln.is_nan());
+    } else if x > 1u32 {
+        assert!(ln >= 0u32);
+    } else if x > 0u32 && x < 1u32 {
+        assert!(ln <= 0u32);
+    }
+
+    match rm {
+        Floor => assert_ne!(o, Greater),
+        Ceiling => assert_ne!(o, Less),
+        Down => {
+            if ln.is_sign_positive() {
+                assert_ne!(o, Greater);
+            } else {
+                assert_ne!(o, Less);
+            }
+        }
+        Up => {
+            if ln.is_sign_positive() {
+                assert_ne!(o, Less);
+            } else {
+                assert_ne!(o, Greater);
+            }
+        }
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.ln_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(ln.clone()));
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.ln_prec_round(prec, Exact));
+    }
+}
+
+#[test]
+fn ln_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties(|(x, prec, rm)| {
+        ln_prec_round_properties_helper(x, prec, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties_with_config(
+        &config,
+        |(x, prec, rm)| {
+            ln_prec_round_properties_helper(x, prec, rm);
+        },
+    );
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (ln, o) = Float::NAN.ln_prec_round(prec, rm);
+        assert!(ln.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(
+            Float::INFINITY.ln_prec_round(prec, rm),
+            (Float::INFINITY, Equal)
+        );
+        let (ln, o) = Float::NEGATIVE_INFINITY.ln_prec_round(prec, rm);
+        assert!(ln.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(
+            Float::ZERO.ln_prec_round(prec, rm),
+            (Float::NEGATIVE_INFINITY, Equal)
+        );
+        assert_eq!(
+            Float::NEGATIVE_ZERO.ln_prec_round(prec, rm),
+            (Float::NEGATIVE_INFINITY, Equal)
+        );
+        let (ln, o) = Float::ONE.ln_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(ln), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (ln, o) = Float::NEGATIVE_ONE.ln_prec_round(prec, rm);
+        assert!(ln.is_nan());
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn ln_prec_properties_helper(x: Float, prec: u64) {
+    let (ln, o) = x.clone().ln_prec(prec);
+    assert!(ln.is_valid());
+    let (ln_alt, o_alt) = x.ln_prec_ref(prec);
+    assert!(ln_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&ln_alt), ComparableFloatRef(&ln));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.ln_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&ln));
+    assert_eq!(o_alt, o);
+
+    let (rug_ln, rug_o) = rug_ln_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_ln)),
+        ComparableFloatRef(&ln),
+    );
+    assert_eq!(rug_o, o);
+
+    let (ln_alt, o_alt) = x.ln_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&ln_alt), ComparableFloatRef(&ln));
+    assert_eq!(o_alt, o);
+
+    if ln.is_normal() {
+        assert_eq!(ln.get_prec(), Some(prec));
+    }
+
+    if x < 0u32 {
+        assert!(ln.is_nan());
+    } else if x > 1u32 {
+        assert!(ln >= 0u32);
+    } else if x > 0u32 && x < 1u32 {
+        assert!(ln <= 0u32);
+    }
+}
+
+#[test]
+fn ln_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        ln_prec_properties_helper(x, prec);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_pair_gen_var_1().test_properties_with_config(&config, |(x, prec)| {
+        ln_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (ln, o) = Float::NAN.ln_prec(prec);
+        assert!(ln.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(Float::INFINITY.ln_prec(prec), (Float::INFINITY, Equal));
+        let (ln, o) = Float::NEGATIVE_INFINITY.ln_prec(prec);
+        assert!(ln.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(Float::ZERO.ln_prec(prec), (Float::NEGATIVE_INFINITY, Equal));
+        assert_eq!(
+            Float::NEGATIVE_ZERO.ln_prec(prec),
+            (Float::NEGATIVE_INFINITY, Equal)
+        );
+        let (ln, o) = Float::ONE.ln_prec(prec);
+        assert_eq!(ComparableFloat(ln), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (ln, o) = Float::NEGATIVE_ONE.ln_prec(prec);
+        assert!(ln.is_nan());
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn ln_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (ln, o) = x.clone().ln_round(rm);
+    assert!(ln.is_valid());
+    let (ln_alt, o_alt) = x.ln_round_ref(rm);
+    assert!(ln_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&ln_alt), ComparableFloatRef(&ln));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.ln_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&ln));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_ln, rug_o) = rug_ln_round(&rug::Float::exact_from(&x), rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_ln)),
+            ComparableFloatRef(&ln),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    let (ln_alt, o_alt) = x.ln_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&ln_alt), ComparableFloatRef(&ln));
+    assert_eq!(o_alt, o);
+
+    if ln.is_normal() {
+        assert_eq!(ln.get_prec(), Some(x.significant_bits()));
+    }
+
+    if x < 0u32 {
+        assert!(ln.is_nan());
+    } else if x > 1u32 {
+        assert!(ln >= 0u32);
+    } else if x > 0u32 && x < 1u32 {
+        assert!(ln <= 0u32);
+    }
+
+    match rm {
+        Floor => assert_ne!(o, Greater),
+        Ceiling => assert_ne!(o, Less),
+        Down => {
+            if ln.is_sign_positive() {
+                assert_ne!(o, Greater);
+            } else {
+                assert_ne!(o, Less);
+            }
+        }
+        Up => {
+            if ln.is_sign_positive() {
+                assert_ne!(o, Less);
+            } else {
+                assert_ne!(o, Greater);
+            }
+        }
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+}
+
+#[test]
+fn ln_round_properties() {
+    float_rounding_mode_pair_gen_var_25().test_properties(|(x, rm)| {
+        ln_round_properties_helper(x, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_rounding_mode_pair_gen_var_25().test_properties_with_config(&config, |(x, rm)| {
+        ln_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (ln, o) = Float::NAN.ln_round(rm);
+        assert!(ln.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(Float::INFINITY.ln_round(rm), (Float::INFINITY, Equal));
+        let (ln, o) = Float::NEGATIVE_INFINITY.ln_round(rm);
+        assert!(ln.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(Float::ZERO.ln_round(rm), (Float::NEGATIVE_INFINITY, Equal));
+        assert_eq!(
+            Float::NEGATIVE_ZERO.ln_round(rm),
+            (Float::NEGATIVE_INFINITY, Equal)
+        );
+        let (ln, o) = Float::ONE.ln_round(rm);
+        assert_eq!(ComparableFloat(ln), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (ln, o) = Float::NEGATIVE_ONE.ln_round(rm);
+        assert!(ln.is_nan());
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn ln_properties_helper(x: Float) {
+    let ln = x.clone().ln();
+    assert!(ln.is_valid());
+
+    let ln_alt = (&x).ln();
+    assert!(ln_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&ln_alt), ComparableFloatRef(&ln));
+
+    let mut x_alt = x.clone();
+    x_alt.ln_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&ln));
+
+    let rug_ln = rug_ln(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_ln)),
+        ComparableFloatRef(&ln),
+    );
+
+    assert_eq!(
+        ComparableFloatRef(&x.ln_round_ref(Nearest).0),
+        ComparableFloatRef(&ln)
+    );
+
+    if x < 0u32 {
+        assert!(ln.is_nan());
+    } else if x > 1u32 {
+        assert!(ln >= 0u32);
+    } else if x > 0u32 && x < 1u32 {
+        assert!(ln <= 0u32);
+    }
+}
+
+#[test]
+fn ln_properties() {
+    float_gen().test_properties(|x| {
+        ln_properties_helper(x);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_gen().test_properties_with_config(&config, |x| {
+        ln_properties_helper(x);
+    });
+
+    float_gen_var_6().test_properties(|x| {
+        ln_properties_helper(x);
+    });
+
+    float_gen_var_7().test_properties(|x| {
+        ln_properties_helper(x);
+    });
+
+    float_gen_var_8().test_properties(|x| {
+        ln_properties_helper(x);
+    });
+
+    float_gen_var_11().test_properties(|x| {
+        ln_properties_helper(x);
+    });
+}
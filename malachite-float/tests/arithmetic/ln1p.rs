@@ -0,0 +1,1322 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{Ln1p, Ln1pAssign};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::{
+    rounding_mode_gen, unsigned_gen_var_11, unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::test_util::arithmetic::ln1p::{
+    rug_ln1p, rug_ln1p_prec, rug_ln1p_prec_round, rug_ln1p_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_gen_var_6, float_gen_var_7, float_gen_var_8, float_gen_var_11,
+    float_rounding_mode_pair_gen_var_25, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_14,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_nz::platform::Limb;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_ln1p() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let ln1p = x.clone().ln1p();
+        assert!(ln1p.is_valid());
+
+        assert_eq!(ln1p.to_string(), out);
+        assert_eq!(to_hex_string(&ln1p), out_hex);
+
+        let ln1p_alt = (&x).ln1p();
+        assert!(ln1p_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln1p), ComparableFloatRef(&ln1p_alt));
+
+        let mut ln1p_alt = x.clone();
+        ln1p_alt.ln1p_assign();
+        assert!(ln1p_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln1p), ComparableFloatRef(&ln1p_alt));
+
+        let rug_ln1p = rug_ln1p(&rug::Float::exact_from(&x));
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_ln1p)),
+            ComparableFloatRef(&ln1p),
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "0.5", "0x0.8#1");
+    test("-1.0", "-0x1.0#1", "-Infinity", "-Infinity");
+    test("2.0", "0x2.0#1", "1.0", "0x1.0#1");
+    test("0.5", "0x0.8#1", "0.5", "0x0.8#1");
+    test("-0.5", "-0x0.8#1", "-0.5", "-0x0.8#1");
+    test("123.0", "0x7b.0#7", "4.81", "0x4.d#7");
+    test("-123.0", "-0x7b.0#7", "NaN", "NaN");
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "0.881373587019543",
+        "0x0.e1a1b30bcea138#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.4210804127942926",
+        "0x1.6bcbed09f00af#53",
+    );
+    test("-3.1415926535897931", "-0x3.243f6a8885a30#53", "NaN", "NaN");
+    test("9.0e-13", "0x1.0E-10#1", "9.0e-13", "0x1.0E-10#1");
+    test("-9.0e-13", "-0x1.0E-10#1", "-9.0e-13", "-0x1.0E-10#1");
+    test("1000.0", "0x3e8.0#10", "6.906", "0x6.e8#10");
+    test("4.0e9", "0x1.0E+8#1", "2.0e1", "0x1.0E+1#1");
+    test("-4.0e9", "-0x1.0E+8#1", "NaN", "NaN");
+    test(
+        "0.999999999999999999999999",
+        "0x0.ffffffffffffffffffff#80",
+        "0.6931471805599453094172315",
+        "0x0.b17217f7d1cf79abc9e3#80",
+    );
+}
+
+#[test]
+fn test_ln1p_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (ln1p, o) = x.clone().ln1p_prec(prec);
+        assert!(ln1p.is_valid());
+
+        assert_eq!(ln1p.to_string(), out);
+        assert_eq!(to_hex_string(&ln1p), out_hex);
+        assert_eq!(o, o_out);
+
+        let (ln1p_alt, o_alt) = x.ln1p_prec_ref(prec);
+        assert!(ln1p_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln1p), ComparableFloatRef(&ln1p_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut ln1p_alt = x.clone();
+        let o_alt = ln1p_alt.ln1p_prec_assign(prec);
+        assert!(ln1p_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln1p), ComparableFloatRef(&ln1p_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_ln1p, rug_o) = rug_ln1p_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_ln1p)),
+            ComparableFloatRef(&ln1p),
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", 10, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", 100, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 100, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 100, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 100, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 10, "0.693", "0x0.b18#10", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.693147180559945309417232121458",
+        "0x0.b17217f7d1cf79abc9e3b3980#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-Infinity", "-Infinity", Equal);
+    test("-1.0", "-0x1.0#1", 10, "-Infinity", "-Infinity", Equal);
+    test("-1.0", "-0x1.0#1", 100, "-Infinity", "-Infinity", Equal);
+    test("2.0", "0x2.0#1", 1, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", 10, "1.098", "0x1.190#10", Less);
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        "1.098612288668109691395245236922",
+        "0x1.193ea7aad030a976a4198d550#100",
+        Less,
+    );
+    test("0.5", "0x0.8#1", 1, "0.5", "0x0.8#1", Greater);
+    test("0.5", "0x0.8#1", 10, "0.4053", "0x0.67c#10", Less);
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "0.4054651081081643819780131154643",
+        "0x0.67cc8fb2fe612fcada35d9bd00#100",
+        Less,
+    );
+    test("-0.5", "-0x0.8#1", 1, "-0.5", "-0x0.8#1", Greater);
+    test("-0.5", "-0x0.8#1", 10, "-0.693", "-0x0.b18#10", Less);
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        100,
+        "-0.693147180559945309417232121458",
+        "-0x0.b17217f7d1cf79abc9e3b3980#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, "4.0", "0x4.0#1", Less);
+    test("123.0", "0x7b.0#7", 10, "4.82", "0x4.d2#10", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "4.820281565605036864763628567456",
+        "0x4.d1fdf901c90a317a799bae3d8#100",
+        Less,
+    );
+    test("-123.0", "-0x7b.0#7", 1, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 10, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 100, "NaN", "NaN", Equal);
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        10,
+        "0.882",
+        "0x0.e1c#10",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        100,
+        "0.881373587019543065275849343689",
+        "0x0.e1a1b30bcea1394383d46105d#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "1.422",
+        "0x1.6c0#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1.421080412794292603484306978989",
+        "0x1.6bcbed09f00aece2ea800b6b0#100",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        1,
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        "9.09e-13",
+        "0x1.000E-10#10",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        100,
+        "9.094947017725146476087627994345e-13",
+        "0xf.fffffffff800000000055555E-11#100",
+        Less,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        1,
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Greater,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        10,
+        "-9.09e-13",
+        "-0x1.000E-10#10",
+        Greater,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        100,
+        "-9.0949470177334182822131582711e-13",
+        "-0x1.0000000000800000000055556E-10#100",
+        Less,
+    );
+    test("1000.0", "0x3e8.0#10", 1, "8.0", "0x8.0#1", Greater);
+    test("1000.0", "0x3e8.0#10", 10, "6.906", "0x6.e8#10", Less);
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        100,
+        "6.908754779315220585220783762972",
+        "0x6.e8a427393e190c0f51e66f788#100",
+        Less,
+    );
+    test("4.0e9", "0x1.0E+8#1", 1, "2.0e1", "0x1.0E+1#1", Less);
+    test("4.0e9", "0x1.0E+8#1", 10, "22.19", "0x16.30#10", Greater);
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        100,
+        "22.18070977815108054497819246125",
+        "0x16.2e42fefb39ef3578bc767300#100",
+        Less,
+    );
+    test("-4.0e9", "-0x1.0E+8#1", 1, "NaN", "NaN", Equal);
+    test("-4.0e9", "-0x1.0E+8#1", 10, "NaN", "NaN", Equal);
+    test("-4.0e9", "-0x1.0E+8#1", 100, "NaN", "NaN", Equal);
+    test(
+        "0.999999999999999999999999",
+        "0x0.ffffffffffffffffffff#80",
+        1,
+        "0.5",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "0.999999999999999999999999",
+        "0x0.ffffffffffffffffffff#80",
+        10,
+        "0.693",
+        "0x0.b18#10",
+        Greater,
+    );
+    test(
+        "0.999999999999999999999999",
+        "0x0.ffffffffffffffffffff#80",
+        100,
+        "0.6931471805599453094172317078677",
+        "0x0.b17217f7d1cf79abc9e333980#100",
+        Less,
+    );
+}
+
+#[test]
+fn ln1p_prec_fail() {
+    assert_panic!(Float::NAN.ln1p_prec(0));
+    assert_panic!(Float::NAN.ln1p_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.ln1p_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_ln1p_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (ln1p, o) = x.clone().ln1p_round(rm);
+        assert!(ln1p.is_valid());
+
+        assert_eq!(ln1p.to_string(), out);
+        assert_eq!(to_hex_string(&ln1p), out_hex);
+        assert_eq!(o, o_out);
+
+        let (ln1p_alt, o_alt) = x.ln1p_round_ref(rm);
+        assert!(ln1p_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln1p), ComparableFloatRef(&ln1p_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut ln1p_alt = x.clone();
+        let o_alt = ln1p_alt.ln1p_round_assign(rm);
+        assert!(ln1p_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln1p), ComparableFloatRef(&ln1p_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_ln1p, rug_o) = rug_ln1p_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_ln1p)),
+                ComparableFloatRef(&ln1p),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", Floor, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", Floor, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Down, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "0.5", "0x0.8#1", Less);
+    test("2.0", "0x2.0#1", Floor, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("2.0", "0x2.0#1", Down, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", Up, "2.0", "0x2.0#1", Greater);
+    test("2.0", "0x2.0#1", Nearest, "1.0", "0x1.0#1", Less);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Floor,
+        "1.4210804127942924",
+        "0x1.6bcbed09f00ae#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ceiling,
+        "1.4210804127942926",
+        "0x1.6bcbed09f00af#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Down,
+        "1.4210804127942924",
+        "0x1.6bcbed09f00ae#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Up,
+        "1.4210804127942926",
+        "0x1.6bcbed09f00af#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Nearest,
+        "1.4210804127942926",
+        "0x1.6bcbed09f00af#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Down,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Up,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Floor,
+        "5.0e-13",
+        "0x8.0E-11#1",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Ceiling,
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Down,
+        "5.0e-13",
+        "0x8.0E-11#1",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Up,
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Nearest,
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Greater,
+    );
+    test("1000.0", "0x3e8.0#10", Floor, "6.906", "0x6.e8#10", Less);
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Ceiling,
+        "6.914",
+        "0x6.ea#10",
+        Greater,
+    );
+    test("1000.0", "0x3e8.0#10", Down, "6.906", "0x6.e8#10", Less);
+    test("1000.0", "0x3e8.0#10", Up, "6.914", "0x6.ea#10", Greater);
+    test("1000.0", "0x3e8.0#10", Nearest, "6.906", "0x6.e8#10", Less);
+    test(
+        "0.999999999999999999999999",
+        "0x0.ffffffffffffffffffff#80",
+        Floor,
+        "0.6931471805599453094172315",
+        "0x0.b17217f7d1cf79abc9e3#80",
+        Less,
+    );
+    test(
+        "0.999999999999999999999999",
+        "0x0.ffffffffffffffffffff#80",
+        Ceiling,
+        "0.6931471805599453094172324",
+        "0x0.b17217f7d1cf79abc9e4#80",
+        Greater,
+    );
+    test(
+        "0.999999999999999999999999",
+        "0x0.ffffffffffffffffffff#80",
+        Down,
+        "0.6931471805599453094172315",
+        "0x0.b17217f7d1cf79abc9e3#80",
+        Less,
+    );
+    test(
+        "0.999999999999999999999999",
+        "0x0.ffffffffffffffffffff#80",
+        Up,
+        "0.6931471805599453094172324",
+        "0x0.b17217f7d1cf79abc9e4#80",
+        Greater,
+    );
+    test(
+        "0.999999999999999999999999",
+        "0x0.ffffffffffffffffffff#80",
+        Nearest,
+        "0.6931471805599453094172315",
+        "0x0.b17217f7d1cf79abc9e3#80",
+        Less,
+    );
+}
+
+#[test]
+fn ln1p_round_fail() {
+    const THREE: Float = Float::const_from_unsigned(3);
+    assert_panic!(THREE.ln1p_round(Exact));
+    assert_panic!(THREE.ln1p_round_ref(Exact));
+    assert_panic!({
+        let mut x = THREE;
+        x.ln1p_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_ln1p_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (ln1p, o) = x.clone().ln1p_prec_round(prec, rm);
+        assert!(ln1p.is_valid());
+
+        assert_eq!(ln1p.to_string(), out);
+        assert_eq!(to_hex_string(&ln1p), out_hex);
+        assert_eq!(o, o_out);
+
+        let (ln1p_alt, o_alt) = x.ln1p_prec_round_ref(prec, rm);
+        assert!(ln1p_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln1p), ComparableFloatRef(&ln1p_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut ln1p_alt = x.clone();
+        let o_alt = ln1p_alt.ln1p_prec_round_assign(prec, rm);
+        assert!(ln1p_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln1p), ComparableFloatRef(&ln1p_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_ln1p, rug_o) = rug_ln1p_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_ln1p)),
+                ComparableFloatRef(&ln1p),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test(
+        "Infinity", "Infinity", 1, Floor, "Infinity", "Infinity", Equal,
+    );
+    test("-Infinity", "-Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, Floor, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 10, Floor, "0.692", "0x0.b14#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.693",
+        "0x0.b18#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "0.693",
+        "0x0.b18#10",
+        Greater,
+    );
+    test("2.0", "0x2.0#1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("2.0", "0x2.0#1", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", 10, Floor, "1.098", "0x1.190#10", Less);
+    test("2.0", "0x2.0#1", 10, Ceiling, "1.1", "0x1.198#10", Greater);
+    test("2.0", "0x2.0#1", 10, Nearest, "1.098", "0x1.190#10", Less);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "1.42",
+        "0x1.6b8#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "1.422",
+        "0x1.6c0#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "1.422",
+        "0x1.6c0#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        1,
+        Floor,
+        "5.0e-13",
+        "0x8.0E-11#1",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        1,
+        Ceiling,
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        1,
+        Nearest,
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        Floor,
+        "9.086e-13",
+        "0xf.fcE-11#10",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        Ceiling,
+        "9.09e-13",
+        "0x1.000E-10#10",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        Nearest,
+        "9.09e-13",
+        "0x1.000E-10#10",
+        Greater,
+    );
+    test(
+        "0.999999999999999999999999",
+        "0x0.ffffffffffffffffffff#80",
+        1,
+        Floor,
+        "0.5",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "0.999999999999999999999999",
+        "0x0.ffffffffffffffffffff#80",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "0.999999999999999999999999",
+        "0x0.ffffffffffffffffffff#80",
+        1,
+        Nearest,
+        "0.5",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "0.999999999999999999999999",
+        "0x0.ffffffffffffffffffff#80",
+        10,
+        Floor,
+        "0.692",
+        "0x0.b14#10",
+        Less,
+    );
+    test(
+        "0.999999999999999999999999",
+        "0x0.ffffffffffffffffffff#80",
+        10,
+        Ceiling,
+        "0.693",
+        "0x0.b18#10",
+        Greater,
+    );
+    test(
+        "0.999999999999999999999999",
+        "0x0.ffffffffffffffffffff#80",
+        10,
+        Nearest,
+        "0.693",
+        "0x0.b18#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Floor,
+        "1.421080412794292603484306978988",
+        "0x1.6bcbed09f00aece2ea800b6ae#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Ceiling,
+        "1.421080412794292603484306978989",
+        "0x1.6bcbed09f00aece2ea800b6b0#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Down,
+        "1.421080412794292603484306978988",
+        "0x1.6bcbed09f00aece2ea800b6ae#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Up,
+        "1.421080412794292603484306978989",
+        "0x1.6bcbed09f00aece2ea800b6b0#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Nearest,
+        "1.421080412794292603484306978989",
+        "0x1.6bcbed09f00aece2ea800b6b0#100",
+        Greater,
+    );
+}
+
+#[test]
+fn ln1p_prec_round_fail() {
+    const THREE: Float = Float::const_from_unsigned(3);
+    assert_panic!(Float::one_prec(1).ln1p_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).ln1p_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.ln1p_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(THREE.ln1p_prec_round(1, Exact));
+    assert_panic!(THREE.ln1p_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = THREE;
+        x.ln1p_prec_round_assign(1, Exact)
+    });
+}
+
+fn ln1p_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (ln1p, o) = x.clone().ln1p_prec_round(prec, rm);
+    assert!(ln1p.is_valid());
+    let (ln1p_alt, o_alt) = x.ln1p_prec_round_ref(prec, rm);
+    assert!(ln1p_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&ln1p_alt), ComparableFloatRef(&ln1p));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.ln1p_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&ln1p));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_ln1p, rug_o) = rug_ln1p_prec_round(&rug::Float::exact_from(&x), prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_ln1p)),
+            ComparableFloatRef(&ln1p),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if ln1p.is_normal() {
+        assert_eq!(ln1p.get_prec(), Some(prec));
+    }
+
+    if x < -1i32 {
+        assert!(ln1p.is_nan());
+    } else if x > -1i32 && !x.is_nan() {
+        assert_eq!(ln1p.is_sign_positive(), x.is_sign_positive());
+    }
+
+    match rm {
+        Floor => assert_ne!(o, Greater),
+        Ceiling => assert_ne!(o, Less),
+        Down => {
+            if ln1p.is_sign_positive() {
+                assert_ne!(o, Greater);
+            } else {
+                assert_ne!(o, Less);
+            }
+        }
+        Up => {
+            if ln1p.is_sign_positive() {
+                assert_ne!(o, Less);
+            } else {
+                assert_ne!(o, Greater);
+            }
+        }
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.ln1p_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(ln1p.clone()));
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.ln1p_prec_round(prec, Exact));
+    }
+}
+
+#[test]
+fn ln1p_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties(|(x, prec, rm)| {
+        ln1p_prec_round_properties_helper(x, prec, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties_with_config(
+        &config,
+        |(x, prec, rm)| {
+            ln1p_prec_round_properties_helper(x, prec, rm);
+        },
+    );
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (ln1p, o) = Float::NAN.ln1p_prec_round(prec, rm);
+        assert!(ln1p.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(
+            Float::INFINITY.ln1p_prec_round(prec, rm),
+            (Float::INFINITY, Equal)
+        );
+        let (ln1p, o) = Float::NEGATIVE_INFINITY.ln1p_prec_round(prec, rm);
+        assert!(ln1p.is_nan());
+        assert_eq!(o, Equal);
+        let (ln1p, o) = Float::ZERO.ln1p_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(ln1p), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (ln1p, o) = Float::NEGATIVE_ZERO.ln1p_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(ln1p), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+        assert_eq!(
+            Float::NEGATIVE_ONE.ln1p_prec_round(prec, rm),
+            (Float::NEGATIVE_INFINITY, Equal)
+        );
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn ln1p_prec_properties_helper(x: Float, prec: u64) {
+    let (ln1p, o) = x.clone().ln1p_prec(prec);
+    assert!(ln1p.is_valid());
+    let (ln1p_alt, o_alt) = x.ln1p_prec_ref(prec);
+    assert!(ln1p_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&ln1p_alt), ComparableFloatRef(&ln1p));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.ln1p_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&ln1p));
+    assert_eq!(o_alt, o);
+
+    let (rug_ln1p, rug_o) = rug_ln1p_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_ln1p)),
+        ComparableFloatRef(&ln1p),
+    );
+    assert_eq!(rug_o, o);
+
+    let (ln1p_alt, o_alt) = x.ln1p_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&ln1p_alt), ComparableFloatRef(&ln1p));
+    assert_eq!(o_alt, o);
+
+    if ln1p.is_normal() {
+        assert_eq!(ln1p.get_prec(), Some(prec));
+    }
+
+    if x < -1i32 {
+        assert!(ln1p.is_nan());
+    } else if x > -1i32 && !x.is_nan() {
+        assert_eq!(ln1p.is_sign_positive(), x.is_sign_positive());
+    }
+}
+
+#[test]
+fn ln1p_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        ln1p_prec_properties_helper(x, prec);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_pair_gen_var_1().test_properties_with_config(&config, |(x, prec)| {
+        ln1p_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (ln1p, o) = Float::NAN.ln1p_prec(prec);
+        assert!(ln1p.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(Float::INFINITY.ln1p_prec(prec), (Float::INFINITY, Equal));
+        let (ln1p, o) = Float::NEGATIVE_INFINITY.ln1p_prec(prec);
+        assert!(ln1p.is_nan());
+        assert_eq!(o, Equal);
+        let (ln1p, o) = Float::ZERO.ln1p_prec(prec);
+        assert_eq!(ComparableFloat(ln1p), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (ln1p, o) = Float::NEGATIVE_ZERO.ln1p_prec(prec);
+        assert_eq!(ComparableFloat(ln1p), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+        assert_eq!(
+            Float::NEGATIVE_ONE.ln1p_prec(prec),
+            (Float::NEGATIVE_INFINITY, Equal)
+        );
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn ln1p_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (ln1p, o) = x.clone().ln1p_round(rm);
+    assert!(ln1p.is_valid());
+    let (ln1p_alt, o_alt) = x.ln1p_round_ref(rm);
+    assert!(ln1p_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&ln1p_alt), ComparableFloatRef(&ln1p));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.ln1p_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&ln1p));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_ln1p, rug_o) = rug_ln1p_round(&rug::Float::exact_from(&x), rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_ln1p)),
+            ComparableFloatRef(&ln1p),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    let (ln1p_alt, o_alt) = x.ln1p_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&ln1p_alt), ComparableFloatRef(&ln1p));
+    assert_eq!(o_alt, o);
+
+    if ln1p.is_normal() {
+        assert_eq!(ln1p.get_prec(), Some(x.significant_bits()));
+    }
+
+    if x < -1i32 {
+        assert!(ln1p.is_nan());
+    } else if x > -1i32 && !x.is_nan() {
+        assert_eq!(ln1p.is_sign_positive(), x.is_sign_positive());
+    }
+
+    match rm {
+        Floor => assert_ne!(o, Greater),
+        Ceiling => assert_ne!(o, Less),
+        Down => {
+            if ln1p.is_sign_positive() {
+                assert_ne!(o, Greater);
+            } else {
+                assert_ne!(o, Less);
+            }
+        }
+        Up => {
+            if ln1p.is_sign_positive() {
+                assert_ne!(o, Less);
+            } else {
+                assert_ne!(o, Greater);
+            }
+        }
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+}
+
+#[test]
+fn ln1p_round_properties() {
+    float_rounding_mode_pair_gen_var_25().test_properties(|(x, rm)| {
+        ln1p_round_properties_helper(x, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_rounding_mode_pair_gen_var_25().test_properties_with_config(&config, |(x, rm)| {
+        ln1p_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (ln1p, o) = Float::NAN.ln1p_round(rm);
+        assert!(ln1p.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(Float::INFINITY.ln1p_round(rm), (Float::INFINITY, Equal));
+        let (ln1p, o) = Float::NEGATIVE_INFINITY.ln1p_round(rm);
+        assert!(ln1p.is_nan());
+        assert_eq!(o, Equal);
+        let (ln1p, o) = Float::ZERO.ln1p_round(rm);
+        assert_eq!(ComparableFloat(ln1p), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (ln1p, o) = Float::NEGATIVE_ZERO.ln1p_round(rm);
+        assert_eq!(ComparableFloat(ln1p), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+        assert_eq!(
+            Float::NEGATIVE_ONE.ln1p_round(rm),
+            (Float::NEGATIVE_INFINITY, Equal)
+        );
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn ln1p_properties_helper(x: Float) {
+    let ln1p = x.clone().ln1p();
+    assert!(ln1p.is_valid());
+
+    let ln1p_alt = (&x).ln1p();
+    assert!(ln1p_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&ln1p_alt), ComparableFloatRef(&ln1p));
+
+    let mut x_alt = x.clone();
+    x_alt.ln1p_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&ln1p));
+
+    let rug_ln1p = rug_ln1p(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_ln1p)),
+        ComparableFloatRef(&ln1p),
+    );
+
+    assert_eq!(
+        ComparableFloatRef(&x.ln1p_round_ref(Nearest).0),
+        ComparableFloatRef(&ln1p)
+    );
+
+    if x < -1i32 {
+        assert!(ln1p.is_nan());
+    } else if x > -1i32 && !x.is_nan() {
+        assert_eq!(ln1p.is_sign_positive(), x.is_sign_positive());
+    }
+}
+
+#[test]
+fn ln1p_properties() {
+    float_gen().test_properties(|x| {
+        ln1p_properties_helper(x);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_gen().test_properties_with_config(&config, |x| {
+        ln1p_properties_helper(x);
+    });
+
+    float_gen_var_6().test_properties(|x| {
+        ln1p_properties_helper(x);
+    });
+
+    float_gen_var_7().test_properties(|x| {
+        ln1p_properties_helper(x);
+    });
+
+    float_gen_var_8().test_properties(|x| {
+        ln1p_properties_helper(x);
+    });
+
+    float_gen_var_11().test_properties(|x| {
+        ln1p_properties_helper(x);
+    });
+}
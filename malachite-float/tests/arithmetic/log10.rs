@@ -0,0 +1,1177 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{Log10, Log10Assign};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::{
+    rounding_mode_gen, unsigned_gen_var_11, unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::test_util::arithmetic::log10::{
+    rug_log10, rug_log10_prec, rug_log10_prec_round, rug_log10_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_gen_var_6, float_gen_var_7, float_gen_var_8, float_gen_var_11,
+    float_rounding_mode_pair_gen_var_25, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_14,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_nz::platform::Limb;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_log10() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let log10 = x.clone().log10();
+        assert!(log10.is_valid());
+
+        assert_eq!(log10.to_string(), out);
+        assert_eq!(to_hex_string(&log10), out_hex);
+
+        let log10_alt = (&x).log10();
+        assert!(log10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&log10), ComparableFloatRef(&log10_alt));
+
+        let mut log10_alt = x.clone();
+        log10_alt.log10_assign();
+        assert!(log10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&log10), ComparableFloatRef(&log10_alt));
+
+        let rug_log10 = rug_log10(&rug::Float::exact_from(&x));
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_log10)),
+            ComparableFloatRef(&log10),
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "-Infinity", "-Infinity");
+    test("-0.0", "-0x0.0", "-Infinity", "-Infinity");
+    test("1.0", "0x1.0#1", "0.0", "0x0.0");
+    test("-1.0", "-0x1.0#1", "NaN", "NaN");
+    test("2.0", "0x2.0#1", "0.2", "0x0.4#1");
+    test("0.5", "0x0.8#1", "-0.2", "-0x0.4#1");
+    test("-0.5", "-0x0.8#1", "NaN", "NaN");
+    test("123.0", "0x7b.0#7", "2.09", "0x2.18#7");
+    test("-123.0", "-0x7b.0#7", "NaN", "NaN");
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "0.15051499783199063",
+        "0x0.268826a13ef400#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "0.49714987269413385",
+        "0x0.7f4536cc6e8eec#53",
+    );
+    test("-3.1415926535897931", "-0x3.243f6a8885a30#53", "NaN", "NaN");
+    test("9.0e-13", "0x1.0E-10#1", "-2.0e1", "-0x1.0E+1#1");
+    test("-9.0e-13", "-0x1.0E-10#1", "NaN", "NaN");
+    test("1000.0", "0x3e8.0#10", "3.0", "0x3.00#10");
+    test("4.0e9", "0x1.0E+8#1", "8.0", "0x8.0#1");
+    test("-4.0e9", "-0x1.0E+8#1", "NaN", "NaN");
+    test("10.0", "0xa.0#4", "1.0", "0x1.0#4");
+}
+
+#[test]
+fn test_log10_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (log10, o) = x.clone().log10_prec(prec);
+        assert!(log10.is_valid());
+
+        assert_eq!(log10.to_string(), out);
+        assert_eq!(to_hex_string(&log10), out_hex);
+        assert_eq!(o, o_out);
+
+        let (log10_alt, o_alt) = x.log10_prec_ref(prec);
+        assert!(log10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&log10), ComparableFloatRef(&log10_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut log10_alt = x.clone();
+        let o_alt = log10_alt.log10_prec_assign(prec);
+        assert!(log10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&log10), ComparableFloatRef(&log10_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_log10, rug_o) = rug_log10_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_log10)),
+            ComparableFloatRef(&log10),
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", 10, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", 100, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 100, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 10, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 100, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 10, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 100, "-Infinity", "-Infinity", Equal);
+    test("1.0", "0x1.0#1", 1, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 100, "0.0", "0x0.0", Equal);
+    test("-1.0", "-0x1.0#1", 1, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 100, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", 1, "0.2", "0x0.4#1", Less);
+    test("2.0", "0x2.0#1", 10, "0.3013", "0x0.4d2#10", Greater);
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        "0.3010299956639811952137388947246",
+        "0x0.4d104d427de7fbcc47c4acd608#100",
+        Greater,
+    );
+    test("0.5", "0x0.8#1", 1, "-0.2", "-0x0.4#1", Greater);
+    test("0.5", "0x0.8#1", 10, "-0.3013", "-0x0.4d2#10", Less);
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "-0.3010299956639811952137388947246",
+        "-0x0.4d104d427de7fbcc47c4acd608#100",
+        Less,
+    );
+    test("-0.5", "-0x0.8#1", 1, "NaN", "NaN", Equal);
+    test("-0.5", "-0x0.8#1", 10, "NaN", "NaN", Equal);
+    test("-0.5", "-0x0.8#1", 100, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 1, "2.0", "0x2.0#1", Less);
+    test("123.0", "0x7b.0#7", 10, "2.09", "0x2.17#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "2.089905111439397931804439753222",
+        "0x2.17040579601d77164f7c690d4#100",
+        Less,
+    );
+    test("-123.0", "-0x7b.0#7", 1, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 10, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 100, "NaN", "NaN", Equal);
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        1,
+        "0.1",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        10,
+        "0.1506",
+        "0x0.269#10",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        100,
+        "0.1505149978319906272944092410526",
+        "0x0.268826a13ef40009c753b70a34#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "0.5",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "0.4971",
+        "0x0.7f4#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "0.4971498726941338374217231245522",
+        "0x0.7f4536cc6e8eeacae4696b9e08#100",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("9.0e-13", "0x1.0E-10#1", 1, "-2.0e1", "-0x1.0E+1#1", Less);
+    test("9.0e-13", "0x1.0E-10#1", 10, "-12.05", "-0xc.0c#10", Less);
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        100,
+        "-12.04119982655924780854955578898",
+        "-0xc.0a8c1263ac3f57eb36bb0171#100",
+        Less,
+    );
+    test("-9.0e-13", "-0x1.0E-10#1", 1, "NaN", "NaN", Equal);
+    test("-9.0e-13", "-0x1.0E-10#1", 10, "NaN", "NaN", Equal);
+    test("-9.0e-13", "-0x1.0E-10#1", 100, "NaN", "NaN", Equal);
+    test("1000.0", "0x3e8.0#10", 1, "4.0", "0x4.0#1", Greater);
+    test("1000.0", "0x3e8.0#10", 10, "3.0", "0x3.00#10", Equal);
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        100,
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        Equal,
+    );
+    test("4.0e9", "0x1.0E+8#1", 1, "8.0", "0x8.0#1", Less);
+    test("4.0e9", "0x1.0E+8#1", 10, "9.64", "0x9.a4#10", Greater);
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        100,
+        "9.63295986124739824683964463119",
+        "0x9.a209a84fbcff7988f8959ac1#100",
+        Greater,
+    );
+    test("-4.0e9", "-0x1.0E+8#1", 1, "NaN", "NaN", Equal);
+    test("-4.0e9", "-0x1.0E+8#1", 10, "NaN", "NaN", Equal);
+    test("-4.0e9", "-0x1.0E+8#1", 100, "NaN", "NaN", Equal);
+    test("10.0", "0xa.0#4", 1, "1.0", "0x1.0#1", Equal);
+    test("10.0", "0xa.0#4", 10, "1.0", "0x1.000#10", Equal);
+    test(
+        "10.0",
+        "0xa.0#4",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+}
+
+#[test]
+fn log10_prec_fail() {
+    assert_panic!(Float::NAN.log10_prec(0));
+    assert_panic!(Float::NAN.log10_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.log10_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_log10_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (log10, o) = x.clone().log10_round(rm);
+        assert!(log10.is_valid());
+
+        assert_eq!(log10.to_string(), out);
+        assert_eq!(to_hex_string(&log10), out_hex);
+        assert_eq!(o, o_out);
+
+        let (log10_alt, o_alt) = x.log10_round_ref(rm);
+        assert!(log10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&log10), ComparableFloatRef(&log10_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut log10_alt = x.clone();
+        let o_alt = log10_alt.log10_round_assign(rm);
+        assert!(log10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&log10), ComparableFloatRef(&log10_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_log10, rug_o) = rug_log10_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_log10)),
+                ComparableFloatRef(&log10),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Floor, "-Infinity", "-Infinity", Equal);
+    test("1.0", "0x1.0#1", Floor, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Ceiling, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Down, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Up, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Nearest, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Exact, "0.0", "0x0.0", Equal);
+    test("2.0", "0x2.0#1", Floor, "0.2", "0x0.4#1", Less);
+    test("2.0", "0x2.0#1", Ceiling, "0.5", "0x0.8#1", Greater);
+    test("2.0", "0x2.0#1", Down, "0.2", "0x0.4#1", Less);
+    test("2.0", "0x2.0#1", Up, "0.5", "0x0.8#1", Greater);
+    test("2.0", "0x2.0#1", Nearest, "0.2", "0x0.4#1", Less);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Floor,
+        "0.4971498726941338",
+        "0x0.7f4536cc6e8ee8#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ceiling,
+        "0.49714987269413385",
+        "0x0.7f4536cc6e8eec#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Down,
+        "0.4971498726941338",
+        "0x0.7f4536cc6e8ee8#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Up,
+        "0.49714987269413385",
+        "0x0.7f4536cc6e8eec#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Nearest,
+        "0.49714987269413385",
+        "0x0.7f4536cc6e8eec#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Down,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Up,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Floor,
+        "-2.0e1",
+        "-0x1.0E+1#1",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Ceiling,
+        "-8.0",
+        "-0x8.0#1",
+        Greater,
+    );
+    test("9.0e-13", "0x1.0E-10#1", Down, "-8.0", "-0x8.0#1", Greater);
+    test("9.0e-13", "0x1.0E-10#1", Up, "-2.0e1", "-0x1.0E+1#1", Less);
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Nearest,
+        "-2.0e1",
+        "-0x1.0E+1#1",
+        Less,
+    );
+    test("1000.0", "0x3e8.0#10", Floor, "3.0", "0x3.00#10", Equal);
+    test("1000.0", "0x3e8.0#10", Ceiling, "3.0", "0x3.00#10", Equal);
+    test("1000.0", "0x3e8.0#10", Down, "3.0", "0x3.00#10", Equal);
+    test("1000.0", "0x3e8.0#10", Up, "3.0", "0x3.00#10", Equal);
+    test("1000.0", "0x3e8.0#10", Nearest, "3.0", "0x3.00#10", Equal);
+    test("1000.0", "0x3e8.0#10", Exact, "3.0", "0x3.00#10", Equal);
+    test("10.0", "0xa.0#4", Floor, "1.0", "0x1.0#4", Equal);
+    test("10.0", "0xa.0#4", Ceiling, "1.0", "0x1.0#4", Equal);
+    test("10.0", "0xa.0#4", Down, "1.0", "0x1.0#4", Equal);
+    test("10.0", "0xa.0#4", Up, "1.0", "0x1.0#4", Equal);
+    test("10.0", "0xa.0#4", Nearest, "1.0", "0x1.0#4", Equal);
+    test("10.0", "0xa.0#4", Exact, "1.0", "0x1.0#4", Equal);
+}
+
+#[test]
+fn log10_round_fail() {
+    const THREE: Float = Float::const_from_unsigned(3);
+    assert_panic!(THREE.log10_round(Exact));
+    assert_panic!(THREE.log10_round_ref(Exact));
+    assert_panic!({
+        let mut x = THREE;
+        x.log10_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_log10_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (log10, o) = x.clone().log10_prec_round(prec, rm);
+        assert!(log10.is_valid());
+
+        assert_eq!(log10.to_string(), out);
+        assert_eq!(to_hex_string(&log10), out_hex);
+        assert_eq!(o, o_out);
+
+        let (log10_alt, o_alt) = x.log10_prec_round_ref(prec, rm);
+        assert!(log10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&log10), ComparableFloatRef(&log10_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut log10_alt = x.clone();
+        let o_alt = log10_alt.log10_prec_round_assign(prec, rm);
+        assert!(log10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&log10), ComparableFloatRef(&log10_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_log10, rug_o) = rug_log10_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_log10)),
+                ComparableFloatRef(&log10),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test(
+        "Infinity", "Infinity", 1, Floor, "Infinity", "Infinity", Equal,
+    );
+    test("-Infinity", "-Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, Floor, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "-Infinity", "-Infinity", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Exact, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Floor, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Exact, "0.0", "0x0.0", Equal);
+    test("2.0", "0x2.0#1", 1, Floor, "0.2", "0x0.4#1", Less);
+    test("2.0", "0x2.0#1", 1, Ceiling, "0.5", "0x0.8#1", Greater);
+    test("2.0", "0x2.0#1", 1, Nearest, "0.2", "0x0.4#1", Less);
+    test("2.0", "0x2.0#1", 10, Floor, "0.3008", "0x0.4d0#10", Less);
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        Ceiling,
+        "0.3013",
+        "0x0.4d2#10",
+        Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        Nearest,
+        "0.3013",
+        "0x0.4d2#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Floor,
+        "0.2",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Nearest,
+        "0.5",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "0.4971",
+        "0x0.7f4#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "0.4976",
+        "0x0.7f6#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "0.4971",
+        "0x0.7f4#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        1,
+        Floor,
+        "-2.0e1",
+        "-0x1.0E+1#1",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        1,
+        Ceiling,
+        "-8.0",
+        "-0x8.0#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        1,
+        Nearest,
+        "-2.0e1",
+        "-0x1.0E+1#1",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        Floor,
+        "-12.05",
+        "-0xc.0c#10",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        Ceiling,
+        "-12.03",
+        "-0xc.08#10",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        Nearest,
+        "-12.05",
+        "-0xc.0c#10",
+        Less,
+    );
+    test("10.0", "0xa.0#4", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("10.0", "0xa.0#4", 1, Ceiling, "1.0", "0x1.0#1", Equal);
+    test("10.0", "0xa.0#4", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test("10.0", "0xa.0#4", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test("10.0", "0xa.0#4", 10, Floor, "1.0", "0x1.000#10", Equal);
+    test("10.0", "0xa.0#4", 10, Ceiling, "1.0", "0x1.000#10", Equal);
+    test("10.0", "0xa.0#4", 10, Nearest, "1.0", "0x1.000#10", Equal);
+    test("10.0", "0xa.0#4", 10, Exact, "1.0", "0x1.000#10", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Floor,
+        "0.4971498726941338374217231245522",
+        "0x0.7f4536cc6e8eeacae4696b9e08#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Ceiling,
+        "0.4971498726941338374217231245526",
+        "0x0.7f4536cc6e8eeacae4696b9e10#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Down,
+        "0.4971498726941338374217231245522",
+        "0x0.7f4536cc6e8eeacae4696b9e08#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Up,
+        "0.4971498726941338374217231245526",
+        "0x0.7f4536cc6e8eeacae4696b9e10#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Nearest,
+        "0.4971498726941338374217231245522",
+        "0x0.7f4536cc6e8eeacae4696b9e08#100",
+        Less,
+    );
+}
+
+#[test]
+fn log10_prec_round_fail() {
+    const THREE: Float = Float::const_from_unsigned(3);
+    assert_panic!(Float::one_prec(1).log10_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).log10_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.log10_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(THREE.log10_prec_round(1, Exact));
+    assert_panic!(THREE.log10_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = THREE;
+        x.log10_prec_round_assign(1, Exact)
+    });
+}
+
+fn log10_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (log10, o) = x.clone().log10_prec_round(prec, rm);
+    assert!(log10.is_valid());
+    let (log10_alt, o_alt) = x.log10_prec_round_ref(prec, rm);
+    assert!(log10_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&log10_alt), ComparableFloatRef(&log10));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.log10_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&log10));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_log10, rug_o) = rug_log10_prec_round(&rug::Float::exact_from(&x), prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_log10)),
+            ComparableFloatRef(&log10),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if log10.is_normal() {
+        assert_eq!(log10.get_prec(), Some(prec));
+    }
+
+    if x < 0u32 {
+        assert!(log10.is_nan());
+    } else if x > 1u32 {
+        assert!(log10 >= 0u32);
+    } else if x > 0u32 && x < 1u32 {
+        assert!(log10 <= 0u32);
+    }
+
+    match rm {
+        Floor => assert_ne!(o, Greater),
+        Ceiling => assert_ne!(o, Less),
+        Down => {
+            if log10.is_sign_positive() {
+                assert_ne!(o, Greater);
+            } else {
+                assert_ne!(o, Less);
+            }
+        }
+        Up => {
+            if log10.is_sign_positive() {
+                assert_ne!(o, Less);
+            } else {
+                assert_ne!(o, Greater);
+            }
+        }
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.log10_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(log10.clone()));
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.log10_prec_round(prec, Exact));
+    }
+}
+
+#[test]
+fn log10_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties(|(x, prec, rm)| {
+        log10_prec_round_properties_helper(x, prec, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties_with_config(
+        &config,
+        |(x, prec, rm)| {
+            log10_prec_round_properties_helper(x, prec, rm);
+        },
+    );
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (log10, o) = Float::NAN.log10_prec_round(prec, rm);
+        assert!(log10.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(
+            Float::INFINITY.log10_prec_round(prec, rm),
+            (Float::INFINITY, Equal)
+        );
+        let (log10, o) = Float::NEGATIVE_INFINITY.log10_prec_round(prec, rm);
+        assert!(log10.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(
+            Float::ZERO.log10_prec_round(prec, rm),
+            (Float::NEGATIVE_INFINITY, Equal)
+        );
+        assert_eq!(
+            Float::NEGATIVE_ZERO.log10_prec_round(prec, rm),
+            (Float::NEGATIVE_INFINITY, Equal)
+        );
+        let (log10, o) = Float::ONE.log10_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(log10), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (log10, o) = Float::NEGATIVE_ONE.log10_prec_round(prec, rm);
+        assert!(log10.is_nan());
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn log10_prec_properties_helper(x: Float, prec: u64) {
+    let (log10, o) = x.clone().log10_prec(prec);
+    assert!(log10.is_valid());
+    let (log10_alt, o_alt) = x.log10_prec_ref(prec);
+    assert!(log10_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&log10_alt), ComparableFloatRef(&log10));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.log10_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&log10));
+    assert_eq!(o_alt, o);
+
+    let (rug_log10, rug_o) = rug_log10_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_log10)),
+        ComparableFloatRef(&log10),
+    );
+    assert_eq!(rug_o, o);
+
+    let (log10_alt, o_alt) = x.log10_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&log10_alt), ComparableFloatRef(&log10));
+    assert_eq!(o_alt, o);
+
+    if log10.is_normal() {
+        assert_eq!(log10.get_prec(), Some(prec));
+    }
+
+    if x < 0u32 {
+        assert!(log10.is_nan());
+    } else if x > 1u32 {
+        assert!(log10 >= 0u32);
+    } else if x > 0u32 && x < 1u32 {
+        assert!(log10 <= 0u32);
+    }
+}
+
+#[test]
+fn log10_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        log10_prec_properties_helper(x, prec);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_pair_gen_var_1().test_properties_with_config(&config, |(x, prec)| {
+        log10_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (log10, o) = Float::NAN.log10_prec(prec);
+        assert!(log10.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(Float::INFINITY.log10_prec(prec), (Float::INFINITY, Equal));
+        let (log10, o) = Float::NEGATIVE_INFINITY.log10_prec(prec);
+        assert!(log10.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(
+            Float::ZERO.log10_prec(prec),
+            (Float::NEGATIVE_INFINITY, Equal)
+        );
+        assert_eq!(
+            Float::NEGATIVE_ZERO.log10_prec(prec),
+            (Float::NEGATIVE_INFINITY, Equal)
+        );
+        let (log10, o) = Float::ONE.log10_prec(prec);
+        assert_eq!(ComparableFloat(log10), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (log10, o) = Float::NEGATIVE_ONE.log10_prec(prec);
+        assert!(log10.is_nan());
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn log10_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (log10, o) = x.clone().log10_round(rm);
+    assert!(log10.is_valid());
+    let (log10_alt, o_alt) = x.log10_round_ref(rm);
+    assert!(log10_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&log10_alt), ComparableFloatRef(&log10));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.log10_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&log10));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_log10, rug_o) = rug_log10_round(&rug::Float::exact_from(&x), rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_log10)),
+            ComparableFloatRef(&log10),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    let (log10_alt, o_alt) = x.log10_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&log10_alt), ComparableFloatRef(&log10));
+    assert_eq!(o_alt, o);
+
+    if log10.is_normal() {
+        assert_eq!(log10.get_prec(), Some(x.significant_bits()));
+    }
+
+    if x < 0u32 {
+        assert!(log10.is_nan());
+    } else if x > 1u32 {
+        assert!(log10 >= 0u32);
+    } else if x > 0u32 && x < 1u32 {
+        assert!(log10 <= 0u32);
+    }
+
+    match rm {
+        Floor => assert_ne!(o, Greater),
+        Ceiling => assert_ne!(o, Less),
+        Down => {
+            if log10.is_sign_positive() {
+                assert_ne!(o, Greater);
+            } else {
+                assert_ne!(o, Less);
+            }
+        }
+        Up => {
+            if log10.is_sign_positive() {
+                assert_ne!(o, Less);
+            } else {
+                assert_ne!(o, Greater);
+            }
+        }
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+}
+
+#[test]
+fn log10_round_properties() {
+    float_rounding_mode_pair_gen_var_25().test_properties(|(x, rm)| {
+        log10_round_properties_helper(x, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_rounding_mode_pair_gen_var_25().test_properties_with_config(&config, |(x, rm)| {
+        log10_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (log10, o) = Float::NAN.log10_round(rm);
+        assert!(log10.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(Float::INFINITY.log10_round(rm), (Float::INFINITY, Equal));
+        let (log10, o) = Float::NEGATIVE_INFINITY.log10_round(rm);
+        assert!(log10.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(
+            Float::ZERO.log10_round(rm),
+            (Float::NEGATIVE_INFINITY, Equal)
+        );
+        assert_eq!(
+            Float::NEGATIVE_ZERO.log10_round(rm),
+            (Float::NEGATIVE_INFINITY, Equal)
+        );
+        let (log10, o) = Float::ONE.log10_round(rm);
+        assert_eq!(ComparableFloat(log10), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (log10, o) = Float::NEGATIVE_ONE.log10_round(rm);
+        assert!(log10.is_nan());
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn log10_properties_helper(x: Float) {
+    let log10 = x.clone().log10();
+    assert!(log10.is_valid());
+
+    let log10_alt = (&x).log10();
+    assert!(log10_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&log10_alt), ComparableFloatRef(&log10));
+
+    let mut x_alt = x.clone();
+    x_alt.log10_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&log10));
+
+    let rug_log10 = rug_log10(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_log10)),
+        ComparableFloatRef(&log10),
+    );
+
+    assert_eq!(
+        ComparableFloatRef(&x.log10_round_ref(Nearest).0),
+        ComparableFloatRef(&log10)
+    );
+
+    if x < 0u32 {
+        assert!(log10.is_nan());
+    } else if x > 1u32 {
+        assert!(log10 >= 0u32);
+    } else if x > 0u32 && x < 1u32 {
+        assert!(log10 <= 0u32);
+    }
+}
+
+#[test]
+fn log10_properties() {
+    float_gen().test_properties(|x| {
+        log10_properties_helper(x);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_gen().test_properties_with_config(&config, |x| {
+        log10_properties_helper(x);
+    });
+
+    float_gen_var_6().test_properties(|x| {
+        log10_properties_helper(x);
+    });
+
+    float_gen_var_7().test_properties(|x| {
+        log10_properties_helper(x);
+    });
+
+    float_gen_var_8().test_properties(|x| {
+        log10_properties_helper(x);
+    });
+
+    float_gen_var_11().test_properties(|x| {
+        log10_properties_helper(x);
+    });
+}
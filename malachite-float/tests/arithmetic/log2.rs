@@ -0,0 +1,1198 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{Log2, Log2Assign};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::{
+    rounding_mode_gen, unsigned_gen_var_11, unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::test_util::arithmetic::log2::{
+    rug_log2, rug_log2_prec, rug_log2_prec_round, rug_log2_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_gen_var_6, float_gen_var_7, float_gen_var_8, float_gen_var_11,
+    float_rounding_mode_pair_gen_var_25, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_14,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_nz::platform::Limb;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_log2() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let log2 = x.clone().log2();
+        assert!(log2.is_valid());
+
+        assert_eq!(log2.to_string(), out);
+        assert_eq!(to_hex_string(&log2), out_hex);
+
+        let log2_alt = (&x).log2();
+        assert!(log2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&log2), ComparableFloatRef(&log2_alt));
+
+        let mut log2_alt = x.clone();
+        log2_alt.log2_assign();
+        assert!(log2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&log2), ComparableFloatRef(&log2_alt));
+
+        let rug_log2 = rug_log2(&rug::Float::exact_from(&x));
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_log2)),
+            ComparableFloatRef(&log2),
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "-Infinity", "-Infinity");
+    test("-0.0", "-0x0.0", "-Infinity", "-Infinity");
+    test("1.0", "0x1.0#1", "0.0", "0x0.0");
+    test("-1.0", "-0x1.0#1", "NaN", "NaN");
+    test("2.0", "0x2.0#1", "1.0", "0x1.0#1");
+    test("0.5", "0x0.8#1", "-1.0", "-0x1.0#1");
+    test("-0.5", "-0x0.8#1", "NaN", "NaN");
+    test("123.0", "0x7b.0#7", "6.94", "0x6.f#7");
+    test("-123.0", "-0x7b.0#7", "NaN", "NaN");
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "0.5000000000000001",
+        "0x0.80000000000008#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.6514961294723187",
+        "0x1.a6c873498ddf7#53",
+    );
+    test("-3.1415926535897931", "-0x3.243f6a8885a30#53", "NaN", "NaN");
+    test("9.0e-13", "0x1.0E-10#1", "-3.0e1", "-0x2.0E+1#1");
+    test("-9.0e-13", "-0x1.0E-10#1", "NaN", "NaN");
+    test("1000.0", "0x3e8.0#10", "9.97", "0x9.f8#10");
+    test("4.0e9", "0x1.0E+8#1", "3.0e1", "0x2.0E+1#1");
+    test("-4.0e9", "-0x1.0E+8#1", "NaN", "NaN");
+    test("8.0", "0x8.0#1", "4.0", "0x4.0#1");
+}
+
+#[test]
+fn test_log2_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (log2, o) = x.clone().log2_prec(prec);
+        assert!(log2.is_valid());
+
+        assert_eq!(log2.to_string(), out);
+        assert_eq!(to_hex_string(&log2), out_hex);
+        assert_eq!(o, o_out);
+
+        let (log2_alt, o_alt) = x.log2_prec_ref(prec);
+        assert!(log2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&log2), ComparableFloatRef(&log2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut log2_alt = x.clone();
+        let o_alt = log2_alt.log2_prec_assign(prec);
+        assert!(log2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&log2), ComparableFloatRef(&log2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_log2, rug_o) = rug_log2_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_log2)),
+            ComparableFloatRef(&log2),
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", 10, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", 100, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 100, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 10, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 100, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 10, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 100, "-Infinity", "-Infinity", Equal);
+    test("1.0", "0x1.0#1", 1, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 100, "0.0", "0x0.0", Equal);
+    test("-1.0", "-0x1.0#1", 1, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 100, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", 1, "1.0", "0x1.0#1", Equal);
+    test("2.0", "0x2.0#1", 10, "1.0", "0x1.000#10", Equal);
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("0.5", "0x0.8#1", 1, "-1.0", "-0x1.0#1", Equal);
+    test("0.5", "0x0.8#1", 10, "-1.0", "-0x1.000#10", Equal);
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("-0.5", "-0x0.8#1", 1, "NaN", "NaN", Equal);
+    test("-0.5", "-0x0.8#1", 10, "NaN", "NaN", Equal);
+    test("-0.5", "-0x0.8#1", 100, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 1, "8.0", "0x8.0#1", Greater);
+    test("123.0", "0x7b.0#7", 10, "6.945", "0x6.f2#10", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "6.942514505339239874619710251472",
+        "0x6.f148a170700a00fdd5533f1e0#100",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", 1, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 10, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 100, "NaN", "NaN", Equal);
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        1,
+        "0.5",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        10,
+        "0.5",
+        "0x0.800#10",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        100,
+        "0.5000000000000000986198725087473",
+        "0x0.800000000000071b372e33f31#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "1.652",
+        "0x1.a70#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1.65149612947231874180454758202",
+        "0x1.a6c873498ddf71a36f477a776#100",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        1,
+        "-3.0e1",
+        "-0x2.0E+1#1",
+        Greater,
+    );
+    test("9.0e-13", "0x1.0E-10#1", 10, "-40.0", "-0x28.0#10", Equal);
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        100,
+        "-40.0",
+        "-0x28.000000000000000000000000#100",
+        Equal,
+    );
+    test("-9.0e-13", "-0x1.0E-10#1", 1, "NaN", "NaN", Equal);
+    test("-9.0e-13", "-0x1.0E-10#1", 10, "NaN", "NaN", Equal);
+    test("-9.0e-13", "-0x1.0E-10#1", 100, "NaN", "NaN", Equal);
+    test("1000.0", "0x3e8.0#10", 1, "8.0", "0x8.0#1", Less);
+    test("1000.0", "0x3e8.0#10", 10, "9.97", "0x9.f8#10", Greater);
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        100,
+        "9.96578428466208704361095828847",
+        "0x9.f73da38d9d4a83eb6e0f93f8#100",
+        Greater,
+    );
+    test("4.0e9", "0x1.0E+8#1", 1, "3.0e1", "0x2.0E+1#1", Equal);
+    test("4.0e9", "0x1.0E+8#1", 10, "32.0", "0x20.0#10", Equal);
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        100,
+        "32.0",
+        "0x20.000000000000000000000000#100",
+        Equal,
+    );
+    test("-4.0e9", "-0x1.0E+8#1", 1, "NaN", "NaN", Equal);
+    test("-4.0e9", "-0x1.0E+8#1", 10, "NaN", "NaN", Equal);
+    test("-4.0e9", "-0x1.0E+8#1", 100, "NaN", "NaN", Equal);
+    test("8.0", "0x8.0#1", 1, "4.0", "0x4.0#1", Greater);
+    test("8.0", "0x8.0#1", 10, "3.0", "0x3.00#10", Equal);
+    test(
+        "8.0",
+        "0x8.0#1",
+        100,
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        Equal,
+    );
+}
+
+#[test]
+fn log2_prec_fail() {
+    assert_panic!(Float::NAN.log2_prec(0));
+    assert_panic!(Float::NAN.log2_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.log2_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_log2_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (log2, o) = x.clone().log2_round(rm);
+        assert!(log2.is_valid());
+
+        assert_eq!(log2.to_string(), out);
+        assert_eq!(to_hex_string(&log2), out_hex);
+        assert_eq!(o, o_out);
+
+        let (log2_alt, o_alt) = x.log2_round_ref(rm);
+        assert!(log2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&log2), ComparableFloatRef(&log2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut log2_alt = x.clone();
+        let o_alt = log2_alt.log2_round_assign(rm);
+        assert!(log2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&log2), ComparableFloatRef(&log2_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_log2, rug_o) = rug_log2_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_log2)),
+                ComparableFloatRef(&log2),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Floor, "-Infinity", "-Infinity", Equal);
+    test("1.0", "0x1.0#1", Floor, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Ceiling, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Down, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Up, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Nearest, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Exact, "0.0", "0x0.0", Equal);
+    test("2.0", "0x2.0#1", Floor, "1.0", "0x1.0#1", Equal);
+    test("2.0", "0x2.0#1", Ceiling, "1.0", "0x1.0#1", Equal);
+    test("2.0", "0x2.0#1", Down, "1.0", "0x1.0#1", Equal);
+    test("2.0", "0x2.0#1", Up, "1.0", "0x1.0#1", Equal);
+    test("2.0", "0x2.0#1", Nearest, "1.0", "0x1.0#1", Equal);
+    test("2.0", "0x2.0#1", Exact, "1.0", "0x1.0#1", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Floor,
+        "1.6514961294723187",
+        "0x1.a6c873498ddf7#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ceiling,
+        "1.6514961294723189",
+        "0x1.a6c873498ddf8#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Down,
+        "1.6514961294723187",
+        "0x1.a6c873498ddf7#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Up,
+        "1.6514961294723189",
+        "0x1.a6c873498ddf8#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Nearest,
+        "1.6514961294723187",
+        "0x1.a6c873498ddf7#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Down,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Up,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Floor,
+        "-6.0e1",
+        "-0x4.0E+1#1",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Ceiling,
+        "-3.0e1",
+        "-0x2.0E+1#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Down,
+        "-3.0e1",
+        "-0x2.0E+1#1",
+        Greater,
+    );
+    test("9.0e-13", "0x1.0E-10#1", Up, "-6.0e1", "-0x4.0E+1#1", Less);
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Nearest,
+        "-3.0e1",
+        "-0x2.0E+1#1",
+        Greater,
+    );
+    test("1000.0", "0x3e8.0#10", Floor, "9.95", "0x9.f4#10", Less);
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Ceiling,
+        "9.97",
+        "0x9.f8#10",
+        Greater,
+    );
+    test("1000.0", "0x3e8.0#10", Down, "9.95", "0x9.f4#10", Less);
+    test("1000.0", "0x3e8.0#10", Up, "9.97", "0x9.f8#10", Greater);
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Nearest,
+        "9.97",
+        "0x9.f8#10",
+        Greater,
+    );
+    test("8.0", "0x8.0#1", Floor, "2.0", "0x2.0#1", Less);
+    test("8.0", "0x8.0#1", Ceiling, "4.0", "0x4.0#1", Greater);
+    test("8.0", "0x8.0#1", Down, "2.0", "0x2.0#1", Less);
+    test("8.0", "0x8.0#1", Up, "4.0", "0x4.0#1", Greater);
+    test("8.0", "0x8.0#1", Nearest, "4.0", "0x4.0#1", Greater);
+}
+
+#[test]
+fn log2_round_fail() {
+    const THREE: Float = Float::const_from_unsigned(3);
+    assert_panic!(THREE.log2_round(Exact));
+    assert_panic!(THREE.log2_round_ref(Exact));
+    assert_panic!({
+        let mut x = THREE;
+        x.log2_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_log2_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (log2, o) = x.clone().log2_prec_round(prec, rm);
+        assert!(log2.is_valid());
+
+        assert_eq!(log2.to_string(), out);
+        assert_eq!(to_hex_string(&log2), out_hex);
+        assert_eq!(o, o_out);
+
+        let (log2_alt, o_alt) = x.log2_prec_round_ref(prec, rm);
+        assert!(log2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&log2), ComparableFloatRef(&log2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut log2_alt = x.clone();
+        let o_alt = log2_alt.log2_prec_round_assign(prec, rm);
+        assert!(log2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&log2), ComparableFloatRef(&log2_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_log2, rug_o) = rug_log2_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_log2)),
+                ComparableFloatRef(&log2),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test(
+        "Infinity", "Infinity", 1, Floor, "Infinity", "Infinity", Equal,
+    );
+    test("-Infinity", "-Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, Floor, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "-Infinity", "-Infinity", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Exact, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Floor, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Exact, "0.0", "0x0.0", Equal);
+    test("2.0", "0x2.0#1", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("2.0", "0x2.0#1", 1, Ceiling, "1.0", "0x1.0#1", Equal);
+    test("2.0", "0x2.0#1", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test("2.0", "0x2.0#1", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test("2.0", "0x2.0#1", 10, Floor, "1.0", "0x1.000#10", Equal);
+    test("2.0", "0x2.0#1", 10, Ceiling, "1.0", "0x1.000#10", Equal);
+    test("2.0", "0x2.0#1", 10, Nearest, "1.0", "0x1.000#10", Equal);
+    test("2.0", "0x2.0#1", 10, Exact, "1.0", "0x1.000#10", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "1.65",
+        "0x1.a68#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "1.652",
+        "0x1.a70#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "1.652",
+        "0x1.a70#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        1,
+        Floor,
+        "-6.0e1",
+        "-0x4.0E+1#1",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        1,
+        Ceiling,
+        "-3.0e1",
+        "-0x2.0E+1#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        1,
+        Nearest,
+        "-3.0e1",
+        "-0x2.0E+1#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        Floor,
+        "-40.0",
+        "-0x28.0#10",
+        Equal,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        Ceiling,
+        "-40.0",
+        "-0x28.0#10",
+        Equal,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        Nearest,
+        "-40.0",
+        "-0x28.0#10",
+        Equal,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        Exact,
+        "-40.0",
+        "-0x28.0#10",
+        Equal,
+    );
+    test("8.0", "0x8.0#1", 1, Floor, "2.0", "0x2.0#1", Less);
+    test("8.0", "0x8.0#1", 1, Ceiling, "4.0", "0x4.0#1", Greater);
+    test("8.0", "0x8.0#1", 1, Nearest, "4.0", "0x4.0#1", Greater);
+    test("8.0", "0x8.0#1", 10, Floor, "3.0", "0x3.00#10", Equal);
+    test("8.0", "0x8.0#1", 10, Ceiling, "3.0", "0x3.00#10", Equal);
+    test("8.0", "0x8.0#1", 10, Nearest, "3.0", "0x3.00#10", Equal);
+    test("8.0", "0x8.0#1", 10, Exact, "3.0", "0x3.00#10", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Floor,
+        "1.65149612947231874180454758202",
+        "0x1.a6c873498ddf71a36f477a776#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Ceiling,
+        "1.651496129472318741804547582021",
+        "0x1.a6c873498ddf71a36f477a778#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Down,
+        "1.65149612947231874180454758202",
+        "0x1.a6c873498ddf71a36f477a776#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Up,
+        "1.651496129472318741804547582021",
+        "0x1.a6c873498ddf71a36f477a778#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Nearest,
+        "1.65149612947231874180454758202",
+        "0x1.a6c873498ddf71a36f477a776#100",
+        Less,
+    );
+}
+
+#[test]
+fn log2_prec_round_fail() {
+    const THREE: Float = Float::const_from_unsigned(3);
+    assert_panic!(Float::one_prec(1).log2_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).log2_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.log2_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(THREE.log2_prec_round(1, Exact));
+    assert_panic!(THREE.log2_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = THREE;
+        x.log2_prec_round_assign(1, Exact)
+    });
+}
+
+fn log2_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (log2, o) = x.clone().log2_prec_round(prec, rm);
+    assert!(log2.is_valid());
+    let (log2_alt, o_alt) = x.log2_prec_round_ref(prec, rm);
+    assert!(log2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&log2_alt), ComparableFloatRef(&log2));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.log2_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&log2));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_log2, rug_o) = rug_log2_prec_round(&rug::Float::exact_from(&x), prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_log2)),
+            ComparableFloatRef(&log2),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if log2.is_normal() {
+        assert_eq!(log2.get_prec(), Some(prec));
+    }
+
+    if x < 0u32 {
+        assert!(log2.is_nan());
+    } else if x > 1u32 {
+        assert!(log2 >= 0u32);
+    } else if x > 0u32 && x < 1u32 {
+        assert!(log2 <= 0u32);
+    }
+
+    match rm {
+        Floor => assert_ne!(o, Greater),
+        Ceiling => assert_ne!(o, Less),
+        Down => {
+            if log2.is_sign_positive() {
+                assert_ne!(o, Greater);
+            } else {
+                assert_ne!(o, Less);
+            }
+        }
+        Up => {
+            if log2.is_sign_positive() {
+                assert_ne!(o, Less);
+            } else {
+                assert_ne!(o, Greater);
+            }
+        }
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.log2_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(log2.clone()));
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.log2_prec_round(prec, Exact));
+    }
+}
+
+#[test]
+fn log2_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties(|(x, prec, rm)| {
+        log2_prec_round_properties_helper(x, prec, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties_with_config(
+        &config,
+        |(x, prec, rm)| {
+            log2_prec_round_properties_helper(x, prec, rm);
+        },
+    );
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (log2, o) = Float::NAN.log2_prec_round(prec, rm);
+        assert!(log2.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(
+            Float::INFINITY.log2_prec_round(prec, rm),
+            (Float::INFINITY, Equal)
+        );
+        let (log2, o) = Float::NEGATIVE_INFINITY.log2_prec_round(prec, rm);
+        assert!(log2.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(
+            Float::ZERO.log2_prec_round(prec, rm),
+            (Float::NEGATIVE_INFINITY, Equal)
+        );
+        assert_eq!(
+            Float::NEGATIVE_ZERO.log2_prec_round(prec, rm),
+            (Float::NEGATIVE_INFINITY, Equal)
+        );
+        let (log2, o) = Float::ONE.log2_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(log2), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (log2, o) = Float::NEGATIVE_ONE.log2_prec_round(prec, rm);
+        assert!(log2.is_nan());
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn log2_prec_properties_helper(x: Float, prec: u64) {
+    let (log2, o) = x.clone().log2_prec(prec);
+    assert!(log2.is_valid());
+    let (log2_alt, o_alt) = x.log2_prec_ref(prec);
+    assert!(log2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&log2_alt), ComparableFloatRef(&log2));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.log2_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&log2));
+    assert_eq!(o_alt, o);
+
+    let (rug_log2, rug_o) = rug_log2_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_log2)),
+        ComparableFloatRef(&log2),
+    );
+    assert_eq!(rug_o, o);
+
+    let (log2_alt, o_alt) = x.log2_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&log2_alt), ComparableFloatRef(&log2));
+    assert_eq!(o_alt, o);
+
+    if log2.is_normal() {
+        assert_eq!(log2.get_prec(), Some(prec));
+    }
+
+    if x < 0u32 {
+        assert!(log2.is_nan());
+    } else if x > 1u32 {
+        assert!(log2 >= 0u32);
+    } else if x > 0u32 && x < 1u32 {
+        assert!(log2 <= 0u32);
+    }
+}
+
+#[test]
+fn log2_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        log2_prec_properties_helper(x, prec);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_pair_gen_var_1().test_properties_with_config(&config, |(x, prec)| {
+        log2_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (log2, o) = Float::NAN.log2_prec(prec);
+        assert!(log2.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(Float::INFINITY.log2_prec(prec), (Float::INFINITY, Equal));
+        let (log2, o) = Float::NEGATIVE_INFINITY.log2_prec(prec);
+        assert!(log2.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(
+            Float::ZERO.log2_prec(prec),
+            (Float::NEGATIVE_INFINITY, Equal)
+        );
+        assert_eq!(
+            Float::NEGATIVE_ZERO.log2_prec(prec),
+            (Float::NEGATIVE_INFINITY, Equal)
+        );
+        let (log2, o) = Float::ONE.log2_prec(prec);
+        assert_eq!(ComparableFloat(log2), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (log2, o) = Float::NEGATIVE_ONE.log2_prec(prec);
+        assert!(log2.is_nan());
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn log2_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (log2, o) = x.clone().log2_round(rm);
+    assert!(log2.is_valid());
+    let (log2_alt, o_alt) = x.log2_round_ref(rm);
+    assert!(log2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&log2_alt), ComparableFloatRef(&log2));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.log2_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&log2));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_log2, rug_o) = rug_log2_round(&rug::Float::exact_from(&x), rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_log2)),
+            ComparableFloatRef(&log2),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    let (log2_alt, o_alt) = x.log2_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&log2_alt), ComparableFloatRef(&log2));
+    assert_eq!(o_alt, o);
+
+    if log2.is_normal() {
+        assert_eq!(log2.get_prec(), Some(x.significant_bits()));
+    }
+
+    if x < 0u32 {
+        assert!(log2.is_nan());
+    } else if x > 1u32 {
+        assert!(log2 >= 0u32);
+    } else if x > 0u32 && x < 1u32 {
+        assert!(log2 <= 0u32);
+    }
+
+    match rm {
+        Floor => assert_ne!(o, Greater),
+        Ceiling => assert_ne!(o, Less),
+        Down => {
+            if log2.is_sign_positive() {
+                assert_ne!(o, Greater);
+            } else {
+                assert_ne!(o, Less);
+            }
+        }
+        Up => {
+            if log2.is_sign_positive() {
+                assert_ne!(o, Less);
+            } else {
+                assert_ne!(o, Greater);
+            }
+        }
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+}
+
+#[test]
+fn log2_round_properties() {
+    float_rounding_mode_pair_gen_var_25().test_properties(|(x, rm)| {
+        log2_round_properties_helper(x, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_rounding_mode_pair_gen_var_25().test_properties_with_config(&config, |(x, rm)| {
+        log2_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (log2, o) = Float::NAN.log2_round(rm);
+        assert!(log2.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(Float::INFINITY.log2_round(rm), (Float::INFINITY, Equal));
+        let (log2, o) = Float::NEGATIVE_INFINITY.log2_round(rm);
+        assert!(log2.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(
+            Float::ZERO.log2_round(rm),
+            (Float::NEGATIVE_INFINITY, Equal)
+        );
+        assert_eq!(
+            Float::NEGATIVE_ZERO.log2_round(rm),
+            (Float::NEGATIVE_INFINITY, Equal)
+        );
+        let (log2, o) = Float::ONE.log2_round(rm);
+        assert_eq!(ComparableFloat(log2), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (log2, o) = Float::NEGATIVE_ONE.log2_round(rm);
+        assert!(log2.is_nan());
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn log2_properties_helper(x: Float) {
+    let log2 = x.clone().log2();
+    assert!(log2.is_valid());
+
+    let log2_alt = (&x).log2();
+    assert!(log2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&log2_alt), ComparableFloatRef(&log2));
+
+    let mut x_alt = x.clone();
+    x_alt.log2_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&log2));
+
+    let rug_log2 = rug_log2(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_log2)),
+        ComparableFloatRef(&log2),
+    );
+
+    assert_eq!(
+        ComparableFloatRef(&x.log2_round_ref(Nearest).0),
+        ComparableFloatRef(&log2)
+    );
+
+    if x < 0u32 {
+        assert!(log2.is_nan());
+    } else if x > 1u32 {
+        assert!(log2 >= 0u32);
+    } else if x > 0u32 && x < 1u32 {
+        assert!(log2 <= 0u32);
+    }
+}
+
+#[test]
+fn log2_properties() {
+    float_gen().test_properties(|x| {
+        log2_properties_helper(x);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_gen().test_properties_with_config(&config, |x| {
+        log2_properties_helper(x);
+    });
+
+    float_gen_var_6().test_properties(|x| {
+        log2_properties_helper(x);
+    });
+
+    float_gen_var_7().test_properties(|x| {
+        log2_properties_helper(x);
+    });
+
+    float_gen_var_8().test_properties(|x| {
+        log2_properties_helper(x);
+    });
+
+    float_gen_var_11().test_properties(|x| {
+        log2_properties_helper(x);
+    });
+}
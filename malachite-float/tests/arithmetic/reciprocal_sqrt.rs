@@ -0,0 +1,969 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{ReciprocalSqrt, ReciprocalSqrtAssign, Square};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::{
+    rounding_mode_gen, unsigned_gen_var_11, unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::test_util::arithmetic::reciprocal_sqrt::{
+    rug_reciprocal_sqrt, rug_reciprocal_sqrt_prec, rug_reciprocal_sqrt_prec_round,
+    rug_reciprocal_sqrt_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_gen_var_6, float_gen_var_7, float_gen_var_8, float_gen_var_11,
+    float_rounding_mode_pair_gen_var_24, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_13,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_nz::platform::Limb;
+use malachite_q::Rational;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_reciprocal_sqrt() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let reciprocal_sqrt = x.clone().reciprocal_sqrt();
+        assert!(reciprocal_sqrt.is_valid());
+
+        assert_eq!(reciprocal_sqrt.to_string(), out);
+        assert_eq!(to_hex_string(&reciprocal_sqrt), out_hex);
+
+        let reciprocal_sqrt_alt = (&x).reciprocal_sqrt();
+        assert!(reciprocal_sqrt_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt),
+            ComparableFloatRef(&reciprocal_sqrt_alt)
+        );
+
+        let mut reciprocal_sqrt_alt = x.clone();
+        reciprocal_sqrt_alt.reciprocal_sqrt_assign();
+        assert!(reciprocal_sqrt_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt),
+            ComparableFloatRef(&reciprocal_sqrt_alt)
+        );
+
+        let rug_reciprocal_sqrt = rug_reciprocal_sqrt(&rug::Float::exact_from(&x));
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_reciprocal_sqrt)),
+            ComparableFloatRef(&reciprocal_sqrt),
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "0.0", "0x0.0");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "Infinity", "Infinity");
+    test("-0.0", "-0x0.0", "Infinity", "Infinity");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1");
+    test("-1.0", "-0x1.0#1", "NaN", "NaN");
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+    );
+    test("2.0", "0x2.0#1", "0.5", "0x0.8#1");
+    test("4.0", "0x4.0#1", "0.5", "0x0.8#1");
+    test("9.0", "0x9.0#4", "0.34", "0x0.58#4");
+    test("123.0", "0x7b.0#7", "0.09", "0x0.170#7");
+    test("-123.0", "-0x7b.0#7", "NaN", "NaN");
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "0.8408964152537145",
+        "0x0.d744fccad69d68#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "0.5641895835477563",
+        "0x0.906eba8214db68#53",
+    );
+    test("1.0e12", "0x1.0E+10#1", "1.0e-6", "0x0.00001#1");
+    test("1.0e-13", "0x2.0E-11#1", "2.0e6", "0x2.0E+5#1");
+    test("4.0139e7", "0x2.6478E+6#15", "0.00015784", "0x0.000a582#15");
+    test(
+        "1.4904942e-19",
+        "0x2.bfddbE-16#22",
+        "2.590209e9",
+        "0x9.a6374E+7#22",
+    );
+    test(
+        "4.850638409455617268748732e-12",
+        "0x5.5555555555555552aaabE-10#83",
+        "454046.72689933576924061235",
+        "0x6ed9e.ba16132a9cee5138#83",
+    );
+    test(
+        "9.4039548064414545106e-38",
+        "0x1.ffffffffe0000000E-31#64",
+        "3260954456356922119.5",
+        "0x2d413cccffe1a307.8#64",
+    );
+    test(
+        "1.809457589959748038781206513903043742e-25",
+        "0x3.800000000000000000000000000000E-21#121",
+        "2350854745092.520403544228816366597496",
+        "0x22359dcac04.85392aab2f6ced2a1e10#121",
+    );
+}
+
+#[test]
+fn test_reciprocal_sqrt_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (reciprocal_sqrt, o) = x.clone().reciprocal_sqrt_prec(prec);
+        assert!(reciprocal_sqrt.is_valid());
+
+        assert_eq!(reciprocal_sqrt.to_string(), out);
+        assert_eq!(to_hex_string(&reciprocal_sqrt), out_hex);
+        assert_eq!(o, o_out);
+
+        let (reciprocal_sqrt_alt, o_alt) = x.reciprocal_sqrt_prec_ref(prec);
+        assert!(reciprocal_sqrt_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt),
+            ComparableFloatRef(&reciprocal_sqrt_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut reciprocal_sqrt_alt = x.clone();
+        let o_alt = reciprocal_sqrt_alt.reciprocal_sqrt_prec_assign(prec);
+        assert!(reciprocal_sqrt_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt),
+            ComparableFloatRef(&reciprocal_sqrt_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (rug_reciprocal_sqrt, rug_o) =
+            rug_reciprocal_sqrt_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_reciprocal_sqrt)),
+            ComparableFloatRef(&reciprocal_sqrt),
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, "Infinity", "Infinity", Equal);
+    test("1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", 10, "1.0", "0x1.000#10", Equal);
+    test("-1.0", "-0x1.0#1", 1, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", 1, "0.5", "0x0.8#1", Less);
+    test("2.0", "0x2.0#1", 10, "0.707", "0x0.b50#10", Less);
+    test("123.0", "0x7b.0#7", 1, "0.06", "0x0.1#1", Less);
+    test("123.0", "0x7b.0#7", 10, "0.0902", "0x0.1718#10", Greater);
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        10,
+        "0.841",
+        "0x0.d74#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "0.5",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "0.564",
+        "0x0.908#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "0.564189583547756297944619165494",
+        "0x0.906eba8214db69584b8553dd8#100",
+        Less,
+    );
+    test("9.0", "0x9.0#4", 1, "0.2", "0x0.4#1", Less);
+    test(
+        "9.0",
+        "0x9.0#4",
+        100,
+        "0.3333333333333333333333333333335",
+        "0x0.55555555555555555555555558#100",
+        Greater,
+    );
+    test(
+        "1.4904942e-19",
+        "0x2.bfddbE-16#22",
+        10,
+        "2.592e9",
+        "0x9.a8E+7#10",
+        Greater,
+    );
+}
+
+#[test]
+fn reciprocal_sqrt_prec_fail() {
+    assert_panic!(Float::NAN.reciprocal_sqrt_prec(0));
+    assert_panic!(Float::NAN.reciprocal_sqrt_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.reciprocal_sqrt_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_reciprocal_sqrt_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (reciprocal_sqrt, o) = x.clone().reciprocal_sqrt_round(rm);
+        assert!(reciprocal_sqrt.is_valid());
+
+        assert_eq!(reciprocal_sqrt.to_string(), out);
+        assert_eq!(to_hex_string(&reciprocal_sqrt), out_hex);
+        assert_eq!(o, o_out);
+
+        let (reciprocal_sqrt_alt, o_alt) = x.reciprocal_sqrt_round_ref(rm);
+        assert!(reciprocal_sqrt_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt),
+            ComparableFloatRef(&reciprocal_sqrt_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut reciprocal_sqrt_alt = x.clone();
+        let o_alt = reciprocal_sqrt_alt.reciprocal_sqrt_round_assign(rm);
+        assert!(reciprocal_sqrt_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt),
+            ComparableFloatRef(&reciprocal_sqrt_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_reciprocal_sqrt, rug_o) =
+                rug_reciprocal_sqrt_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_reciprocal_sqrt)),
+                ComparableFloatRef(&reciprocal_sqrt),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", Floor, "Infinity", "Infinity", Equal);
+    test("1.0", "0x1.0#1", Exact, "1.0", "0x1.0#1", Equal);
+    test("-1.0", "-0x1.0#1", Floor, "NaN", "NaN", Equal);
+    test("4.0", "0x4.0#1", Exact, "0.5", "0x0.8#1", Equal);
+    test("2.0", "0x2.0#1", Floor, "0.5", "0x0.8#1", Less);
+    test("2.0", "0x2.0#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("2.0", "0x2.0#1", Down, "0.5", "0x0.8#1", Less);
+    test("2.0", "0x2.0#1", Up, "1.0", "0x1.0#1", Greater);
+    test("2.0", "0x2.0#1", Nearest, "0.5", "0x0.8#1", Less);
+    test("123.0", "0x7b.0#7", Floor, "0.09", "0x0.170#7", Less);
+    test("123.0", "0x7b.0#7", Ceiling, "0.091", "0x0.174#7", Greater);
+    test("123.0", "0x7b.0#7", Down, "0.09", "0x0.170#7", Less);
+    test("123.0", "0x7b.0#7", Up, "0.091", "0x0.174#7", Greater);
+    test("123.0", "0x7b.0#7", Nearest, "0.09", "0x0.170#7", Less);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Floor,
+        "0.5641895835477563",
+        "0x0.906eba8214db68#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ceiling,
+        "0.5641895835477564",
+        "0x0.906eba8214db70#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Down,
+        "0.5641895835477563",
+        "0x0.906eba8214db68#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Up,
+        "0.5641895835477564",
+        "0x0.906eba8214db70#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Nearest,
+        "0.5641895835477563",
+        "0x0.906eba8214db68#53",
+        Less,
+    );
+    test(
+        "1.4904942e-19",
+        "0x2.bfddbE-16#22",
+        Floor,
+        "2.590209e9",
+        "0x9.a6374E+7#22",
+        Less,
+    );
+    test(
+        "1.4904942e-19",
+        "0x2.bfddbE-16#22",
+        Ceiling,
+        "2.59021e9",
+        "0x9.a6378E+7#22",
+        Greater,
+    );
+    test(
+        "1.4904942e-19",
+        "0x2.bfddbE-16#22",
+        Nearest,
+        "2.590209e9",
+        "0x9.a6374E+7#22",
+        Less,
+    );
+}
+
+#[test]
+fn reciprocal_sqrt_round_fail() {
+    const THREE: Float = Float::const_from_unsigned(3);
+    assert_panic!(THREE.reciprocal_sqrt_round(Exact));
+    assert_panic!(THREE.reciprocal_sqrt_round_ref(Exact));
+    assert_panic!({
+        let mut x = THREE;
+        x.reciprocal_sqrt_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_reciprocal_sqrt_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (reciprocal_sqrt, o) = x.clone().reciprocal_sqrt_prec_round(prec, rm);
+        assert!(reciprocal_sqrt.is_valid());
+
+        assert_eq!(reciprocal_sqrt.to_string(), out);
+        assert_eq!(to_hex_string(&reciprocal_sqrt), out_hex);
+        assert_eq!(o, o_out);
+
+        let (reciprocal_sqrt_alt, o_alt) = x.reciprocal_sqrt_prec_round_ref(prec, rm);
+        assert!(reciprocal_sqrt_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt),
+            ComparableFloatRef(&reciprocal_sqrt_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut reciprocal_sqrt_alt = x.clone();
+        let o_alt = reciprocal_sqrt_alt.reciprocal_sqrt_prec_round_assign(prec, rm);
+        assert!(reciprocal_sqrt_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt),
+            ComparableFloatRef(&reciprocal_sqrt_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_reciprocal_sqrt, rug_o) =
+                rug_reciprocal_sqrt_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_reciprocal_sqrt)),
+                ComparableFloatRef(&reciprocal_sqrt),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Floor, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, Floor, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "Infinity", "Infinity", Equal);
+    test("1.0", "0x1.0#1", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", 10, Exact, "1.0", "0x1.000#10", Equal);
+    test("-1.0", "-0x1.0#1", 1, Floor, "NaN", "NaN", Equal);
+    test("4.0", "0x4.0#1", 1, Exact, "0.5", "0x0.8#1", Equal);
+    test("4.0", "0x4.0#1", 10, Exact, "0.5", "0x0.800#10", Equal);
+    test("9.0", "0x9.0#4", 1, Floor, "0.2", "0x0.4#1", Less);
+    test("9.0", "0x9.0#4", 1, Ceiling, "0.5", "0x0.8#1", Greater);
+    test("9.0", "0x9.0#4", 1, Nearest, "0.2", "0x0.4#1", Less);
+    test("2.0", "0x2.0#1", 1, Floor, "0.5", "0x0.8#1", Less);
+    test("2.0", "0x2.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("2.0", "0x2.0#1", 1, Down, "0.5", "0x0.8#1", Less);
+    test("2.0", "0x2.0#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("2.0", "0x2.0#1", 1, Nearest, "0.5", "0x0.8#1", Less);
+    test("2.0", "0x2.0#1", 10, Floor, "0.707", "0x0.b50#10", Less);
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        Ceiling,
+        "0.708",
+        "0x0.b54#10",
+        Greater,
+    );
+    test("2.0", "0x2.0#1", 10, Down, "0.707", "0x0.b50#10", Less);
+    test("2.0", "0x2.0#1", 10, Up, "0.708", "0x0.b54#10", Greater);
+    test("2.0", "0x2.0#1", 10, Nearest, "0.707", "0x0.b50#10", Less);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "0.563",
+        "0x0.904#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "0.564",
+        "0x0.908#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Down,
+        "0.563",
+        "0x0.904#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Up,
+        "0.564",
+        "0x0.908#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "0.564",
+        "0x0.908#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Floor,
+        "0.564189583547756297944619165494",
+        "0x0.906eba8214db69584b8553dd8#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Ceiling,
+        "0.564189583547756297944619165495",
+        "0x0.906eba8214db69584b8553dd9#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Nearest,
+        "0.564189583547756297944619165494",
+        "0x0.906eba8214db69584b8553dd8#100",
+        Less,
+    );
+}
+
+#[test]
+fn reciprocal_sqrt_prec_round_fail() {
+    const THREE: Float = Float::const_from_unsigned(3);
+    assert_panic!(Float::one_prec(1).reciprocal_sqrt_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).reciprocal_sqrt_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.reciprocal_sqrt_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(THREE.reciprocal_sqrt_prec_round(1, Exact));
+    assert_panic!(THREE.reciprocal_sqrt_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = THREE;
+        x.reciprocal_sqrt_prec_round_assign(1, Exact)
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn reciprocal_sqrt_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (reciprocal_sqrt, o) = x.clone().reciprocal_sqrt_prec_round(prec, rm);
+    assert!(reciprocal_sqrt.is_valid());
+    let (reciprocal_sqrt_alt, o_alt) = x.reciprocal_sqrt_prec_round_ref(prec, rm);
+    assert!(reciprocal_sqrt_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&reciprocal_sqrt_alt),
+        ComparableFloatRef(&reciprocal_sqrt)
+    );
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.reciprocal_sqrt_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&x_alt),
+        ComparableFloatRef(&reciprocal_sqrt)
+    );
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_reciprocal_sqrt, rug_o) =
+            rug_reciprocal_sqrt_prec_round(&rug::Float::exact_from(&x), prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_reciprocal_sqrt)),
+            ComparableFloatRef(&reciprocal_sqrt),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if reciprocal_sqrt.is_finite() && x.is_finite() {
+        if reciprocal_sqrt.is_normal() {
+            assert_eq!(reciprocal_sqrt.get_prec(), Some(prec));
+        }
+        // x * y^2 is monotone in y for positive x and y, so comparing it to 1 compares y to the
+        // exact reciprocal square root
+        let r_x = Rational::exact_from(&x);
+        assert_eq!(
+            (Rational::exact_from(&reciprocal_sqrt).square() * &r_x).partial_cmp(&1u32),
+            Some(o)
+        );
+        if o == Less {
+            let mut next = reciprocal_sqrt.clone();
+            next.increment();
+            assert!(Rational::exact_from(&next).square() * &r_x > 1u32);
+        } else if o == Greater {
+            let mut next = reciprocal_sqrt.clone();
+            next.decrement();
+            assert!(Rational::exact_from(&next).square() * &r_x < 1u32);
+        }
+    } else {
+        assert_eq!(o, Equal);
+    }
+
+    match rm {
+        Floor | Down => assert_ne!(o, Greater),
+        Ceiling | Up => assert_ne!(o, Less),
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.reciprocal_sqrt_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(reciprocal_sqrt.clone()));
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.reciprocal_sqrt_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn reciprocal_sqrt_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_13().test_properties(|(x, prec, rm)| {
+        reciprocal_sqrt_prec_round_properties_helper(x, prec, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_rounding_mode_triple_gen_var_13().test_properties_with_config(
+        &config,
+        |(x, prec, rm)| {
+            reciprocal_sqrt_prec_round_properties_helper(x, prec, rm);
+        },
+    );
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    config.insert("mean_small_n", 2048);
+    float_unsigned_rounding_mode_triple_gen_var_13().test_properties_with_config(
+        &config,
+        |(x, prec, rm)| {
+            reciprocal_sqrt_prec_round_properties_helper(x, prec, rm);
+        },
+    );
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (reciprocal_sqrt, o) = Float::NAN.reciprocal_sqrt_prec_round(prec, rm);
+        assert!(reciprocal_sqrt.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(
+            Float::INFINITY.reciprocal_sqrt_prec_round(prec, rm),
+            (Float::ZERO, Equal)
+        );
+        let (reciprocal_sqrt, o) = Float::NEGATIVE_INFINITY.reciprocal_sqrt_prec_round(prec, rm);
+        assert!(reciprocal_sqrt.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(
+            Float::ZERO.reciprocal_sqrt_prec_round(prec, rm),
+            (Float::INFINITY, Equal)
+        );
+        assert_eq!(
+            Float::NEGATIVE_ZERO.reciprocal_sqrt_prec_round(prec, rm),
+            (Float::INFINITY, Equal)
+        );
+        assert_eq!(
+            Float::ONE.reciprocal_sqrt_prec_round(prec, rm),
+            (Float::one_prec(prec), Equal)
+        );
+        let (reciprocal_sqrt, o) = Float::NEGATIVE_ONE.reciprocal_sqrt_prec_round(prec, rm);
+        assert!(reciprocal_sqrt.is_nan());
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn reciprocal_sqrt_prec_properties_helper(x: Float, prec: u64) {
+    let (reciprocal_sqrt, o) = x.clone().reciprocal_sqrt_prec(prec);
+    assert!(reciprocal_sqrt.is_valid());
+    let (reciprocal_sqrt_alt, o_alt) = x.reciprocal_sqrt_prec_ref(prec);
+    assert!(reciprocal_sqrt_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&reciprocal_sqrt_alt),
+        ComparableFloatRef(&reciprocal_sqrt)
+    );
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.reciprocal_sqrt_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&x_alt),
+        ComparableFloatRef(&reciprocal_sqrt)
+    );
+    assert_eq!(o_alt, o);
+
+    let (rug_reciprocal_sqrt, rug_o) = rug_reciprocal_sqrt_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_reciprocal_sqrt)),
+        ComparableFloatRef(&reciprocal_sqrt),
+    );
+    assert_eq!(rug_o, o);
+
+    let (reciprocal_sqrt_alt, o_alt) = x.reciprocal_sqrt_prec_round_ref(prec, Nearest);
+    assert_eq!(
+        ComparableFloatRef(&reciprocal_sqrt_alt),
+        ComparableFloatRef(&reciprocal_sqrt)
+    );
+    assert_eq!(o_alt, o);
+
+    if reciprocal_sqrt.is_finite() && x.is_finite() {
+        if reciprocal_sqrt.is_normal() {
+            assert_eq!(reciprocal_sqrt.get_prec(), Some(prec));
+        }
+        let r_x = Rational::exact_from(&x);
+        assert_eq!(
+            (Rational::exact_from(&reciprocal_sqrt).square() * &r_x).partial_cmp(&1u32),
+            Some(o)
+        );
+    } else {
+        assert_eq!(o, Equal);
+    }
+}
+
+#[test]
+fn reciprocal_sqrt_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        reciprocal_sqrt_prec_properties_helper(x, prec);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_pair_gen_var_1().test_properties_with_config(&config, |(x, prec)| {
+        reciprocal_sqrt_prec_properties_helper(x, prec);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    config.insert("mean_small_n", 2048);
+    float_unsigned_pair_gen_var_1().test_properties_with_config(&config, |(x, prec)| {
+        reciprocal_sqrt_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (reciprocal_sqrt, o) = Float::NAN.reciprocal_sqrt_prec(prec);
+        assert!(reciprocal_sqrt.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(
+            Float::INFINITY.reciprocal_sqrt_prec(prec),
+            (Float::ZERO, Equal)
+        );
+        let (reciprocal_sqrt, o) = Float::NEGATIVE_INFINITY.reciprocal_sqrt_prec(prec);
+        assert!(reciprocal_sqrt.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(
+            Float::ZERO.reciprocal_sqrt_prec(prec),
+            (Float::INFINITY, Equal)
+        );
+        assert_eq!(
+            Float::NEGATIVE_ZERO.reciprocal_sqrt_prec(prec),
+            (Float::INFINITY, Equal)
+        );
+        assert_eq!(
+            Float::ONE.reciprocal_sqrt_prec(prec),
+            (Float::one_prec(prec), Equal)
+        );
+        let (reciprocal_sqrt, o) = Float::NEGATIVE_ONE.reciprocal_sqrt_prec(prec);
+        assert!(reciprocal_sqrt.is_nan());
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn reciprocal_sqrt_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (reciprocal_sqrt, o) = x.clone().reciprocal_sqrt_round(rm);
+    assert!(reciprocal_sqrt.is_valid());
+    let (reciprocal_sqrt_alt, o_alt) = x.reciprocal_sqrt_round_ref(rm);
+    assert!(reciprocal_sqrt_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&reciprocal_sqrt_alt),
+        ComparableFloatRef(&reciprocal_sqrt)
+    );
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.reciprocal_sqrt_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&x_alt),
+        ComparableFloatRef(&reciprocal_sqrt)
+    );
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_reciprocal_sqrt, rug_o) =
+            rug_reciprocal_sqrt_round(&rug::Float::exact_from(&x), rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_reciprocal_sqrt)),
+            ComparableFloatRef(&reciprocal_sqrt),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    let (reciprocal_sqrt_alt, o_alt) = x.reciprocal_sqrt_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(
+        ComparableFloatRef(&reciprocal_sqrt_alt),
+        ComparableFloatRef(&reciprocal_sqrt)
+    );
+    assert_eq!(o_alt, o);
+
+    if reciprocal_sqrt.is_finite() && x.is_finite() {
+        if reciprocal_sqrt.is_normal() {
+            assert_eq!(reciprocal_sqrt.get_prec(), Some(x.significant_bits()));
+        }
+        let r_x = Rational::exact_from(&x);
+        assert_eq!(
+            (Rational::exact_from(&reciprocal_sqrt).square() * &r_x).partial_cmp(&1u32),
+            Some(o)
+        );
+        if o == Less {
+            let mut next = reciprocal_sqrt.clone();
+            next.increment();
+            assert!(Rational::exact_from(&next).square() * &r_x > 1u32);
+        } else if o == Greater {
+            let mut next = reciprocal_sqrt.clone();
+            next.decrement();
+            assert!(Rational::exact_from(&next).square() * &r_x < 1u32);
+        }
+    } else {
+        assert_eq!(o, Equal);
+    }
+
+    match rm {
+        Floor | Down => assert_ne!(o, Greater),
+        Ceiling | Up => assert_ne!(o, Less),
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.reciprocal_sqrt_round_ref(rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(reciprocal_sqrt.clone()));
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.reciprocal_sqrt_round_ref(Exact));
+    }
+}
+
+#[test]
+fn reciprocal_sqrt_round_properties() {
+    float_rounding_mode_pair_gen_var_24().test_properties(|(x, rm)| {
+        reciprocal_sqrt_round_properties_helper(x, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_rounding_mode_pair_gen_var_24().test_properties_with_config(&config, |(x, rm)| {
+        reciprocal_sqrt_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (reciprocal_sqrt, o) = Float::NAN.reciprocal_sqrt_round(rm);
+        assert!(reciprocal_sqrt.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(
+            Float::INFINITY.reciprocal_sqrt_round(rm),
+            (Float::ZERO, Equal)
+        );
+        let (reciprocal_sqrt, o) = Float::NEGATIVE_INFINITY.reciprocal_sqrt_round(rm);
+        assert!(reciprocal_sqrt.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(
+            Float::ZERO.reciprocal_sqrt_round(rm),
+            (Float::INFINITY, Equal)
+        );
+        assert_eq!(
+            Float::NEGATIVE_ZERO.reciprocal_sqrt_round(rm),
+            (Float::INFINITY, Equal)
+        );
+        assert_eq!(Float::ONE.reciprocal_sqrt_round(rm), (Float::ONE, Equal));
+        let (reciprocal_sqrt, o) = Float::NEGATIVE_ONE.reciprocal_sqrt_round(rm);
+        assert!(reciprocal_sqrt.is_nan());
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn reciprocal_sqrt_properties_helper(x: Float) {
+    let reciprocal_sqrt = x.clone().reciprocal_sqrt();
+    assert!(reciprocal_sqrt.is_valid());
+
+    let reciprocal_sqrt_alt = (&x).reciprocal_sqrt();
+    assert!(reciprocal_sqrt_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&reciprocal_sqrt_alt),
+        ComparableFloatRef(&reciprocal_sqrt)
+    );
+
+    let mut x_alt = x.clone();
+    x_alt.reciprocal_sqrt_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&x_alt),
+        ComparableFloatRef(&reciprocal_sqrt)
+    );
+
+    let rug_reciprocal_sqrt = rug_reciprocal_sqrt(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_reciprocal_sqrt)),
+        ComparableFloatRef(&reciprocal_sqrt),
+    );
+
+    assert_eq!(
+        ComparableFloatRef(&x.reciprocal_sqrt_round_ref(Nearest).0),
+        ComparableFloatRef(&reciprocal_sqrt)
+    );
+
+    if reciprocal_sqrt.is_normal() && x.is_finite() {
+        assert_eq!(reciprocal_sqrt.get_prec(), Some(x.significant_bits()));
+    }
+
+    if x < 0u32 {
+        assert!(reciprocal_sqrt.is_nan());
+    }
+
+    if x.is_infinite() && x > 0u32 {
+        assert_eq!(
+            ComparableFloat(reciprocal_sqrt),
+            ComparableFloat(Float::ZERO)
+        );
+    }
+}
+
+#[test]
+fn reciprocal_sqrt_properties() {
+    float_gen().test_properties(|x| {
+        reciprocal_sqrt_properties_helper(x);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_gen().test_properties_with_config(&config, |x| {
+        reciprocal_sqrt_properties_helper(x);
+    });
+
+    float_gen_var_6().test_properties(|x| {
+        reciprocal_sqrt_properties_helper(x);
+    });
+
+    float_gen_var_7().test_properties(|x| {
+        reciprocal_sqrt_properties_helper(x);
+    });
+
+    float_gen_var_8().test_properties(|x| {
+        reciprocal_sqrt_properties_helper(x);
+    });
+
+    float_gen_var_11().test_properties(|x| {
+        reciprocal_sqrt_properties_helper(x);
+    });
+}
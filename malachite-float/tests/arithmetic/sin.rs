@@ -0,0 +1,1413 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{Sin, SinAssign};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+use malachite_base::num::comparison::traits::PartialOrdAbs;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::{
+    rounding_mode_gen, unsigned_gen_var_11, unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::test_util::arithmetic::sin::{
+    rug_sin, rug_sin_prec, rug_sin_prec_round, rug_sin_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_gen_var_6, float_gen_var_7, float_gen_var_8, float_gen_var_11,
+    float_rounding_mode_pair_gen_var_25, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_14,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_nz::platform::Limb;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_sin() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let sin = x.clone().sin();
+        assert!(sin.is_valid());
+
+        assert_eq!(sin.to_string(), out);
+        assert_eq!(to_hex_string(&sin), out_hex);
+
+        let sin_alt = (&x).sin();
+        assert!(sin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+
+        let mut sin_alt = x.clone();
+        sin_alt.sin_assign();
+        assert!(sin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+
+        let rug_sin = rug_sin(&rug::Float::exact_from(&x));
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sin)),
+            ComparableFloatRef(&sin),
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "NaN", "NaN");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1");
+    test("-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1");
+    test("2.0", "0x2.0#1", "1.0", "0x1.0#1");
+    test("0.5", "0x0.8#1", "0.5", "0x0.8#1");
+    test("-0.5", "-0x0.8#1", "-0.5", "-0x0.8#1");
+    test("123.0", "0x7b.0#7", "-0.461", "-0x0.76#7");
+    test("-123.0", "-0x7b.0#7", "0.461", "0x0.76#7");
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "0.9877659459927356",
+        "0x0.fcde3aa2242c58#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.2246467991473532e-16",
+        "0x8.d313198a2e038E-14#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-1.2246467991473532e-16",
+        "-0x8.d313198a2e038E-14#53",
+    );
+    test("9.0e-13", "0x1.0E-10#1", "9.0e-13", "0x1.0E-10#1");
+    test("-9.0e-13", "-0x1.0E-10#1", "-9.0e-13", "-0x1.0E-10#1");
+    test("1000.0", "0x3e8.0#10", "0.827", "0x0.d3c#10");
+    test("4.0e9", "0x1.0E+8#1", "-0.5", "-0x0.8#1");
+    test("-4.0e9", "-0x1.0E+8#1", "0.5", "0x0.8#1");
+    test("3.0e120", "0x1.0E+100#1", "0.1", "0x0.2#1");
+    test("1.0e1204", "0x1.0E+1000#1", "1.0", "0x1.0#1");
+}
+
+#[test]
+fn test_sin_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sin, o) = x.clone().sin_prec(prec);
+        assert!(sin.is_valid());
+
+        assert_eq!(sin.to_string(), out);
+        assert_eq!(to_hex_string(&sin), out_hex);
+        assert_eq!(o, o_out);
+
+        let (sin_alt, o_alt) = x.sin_prec_ref(prec);
+        assert!(sin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sin_alt = x.clone();
+        let o_alt = sin_alt.sin_prec_assign(prec);
+        assert!(sin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_sin, rug_o) = rug_sin_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sin)),
+            ComparableFloatRef(&sin),
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 100, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 100, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 100, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 100, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, "0.842", "0x0.d78#10", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.84147098480789650665250232163",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 10, "-0.842", "-0x0.d78#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-0.84147098480789650665250232163",
+        "-0x0.d76aa47848677020c6e9e909c#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "1.0e-16",
+        "0x8.0E-14#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "1.225e-16",
+        "0x8.d4E-14#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1.224646799147353177226065932275e-16",
+        "0x8.d313198a2e03707344a40938E-14#100",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        1,
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        "9.09e-13",
+        "0x1.000E-10#10",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        100,
+        "9.094947017729282379150389371137e-13",
+        "0xf.fffffffffffffffffffd5555E-11#100",
+        Less,
+    );
+    test("4.0e9", "0x1.0E+8#1", 1, "-0.5", "-0x0.8#1", Less);
+    test("4.0e9", "0x1.0E+8#1", 10, "-0.4619", "-0x0.764#10", Greater);
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        100,
+        "-0.4619865795138349446673032422649",
+        "-0x0.7644c0a233ec513f1b30c876e8#100",
+        Less,
+    );
+    test("1.0e1204", "0x1.0E+1000#1", 1, "1.0", "0x1.0#1", Greater);
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        10,
+        "0.973",
+        "0x0.f90#10",
+        Greater,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        100,
+        "0.972552084836676957484769874207",
+        "0x0.f8f92c6607b795bdf019397bf#100",
+        Less,
+    );
+}
+
+#[test]
+fn sin_prec_fail() {
+    assert_panic!(Float::NAN.sin_prec(0));
+    assert_panic!(Float::NAN.sin_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.sin_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_sin_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sin, o) = x.clone().sin_round(rm);
+        assert!(sin.is_valid());
+
+        assert_eq!(sin.to_string(), out);
+        assert_eq!(to_hex_string(&sin), out_hex);
+        assert_eq!(o, o_out);
+
+        let (sin_alt, o_alt) = x.sin_round_ref(rm);
+        assert!(sin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sin_alt = x.clone();
+        let o_alt = sin_alt.sin_round_assign(rm);
+        assert!(sin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sin, rug_o) = rug_sin_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sin)),
+                ComparableFloatRef(&sin),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Down, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Up, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Nearest, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", Floor, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Down, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "-0.5", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "-0.5", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", Up, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", Nearest, "-1.0", "-0x1.0#1", Less);
+    test("2.0", "0x2.0#1", Floor, "0.5", "0x0.8#1", Less);
+    test("2.0", "0x2.0#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("2.0", "0x2.0#1", Down, "0.5", "0x0.8#1", Less);
+    test("2.0", "0x2.0#1", Up, "1.0", "0x1.0#1", Greater);
+    test("2.0", "0x2.0#1", Nearest, "1.0", "0x1.0#1", Greater);
+    test("0.5", "0x0.8#1", Floor, "0.2", "0x0.4#1", Less);
+    test("0.5", "0x0.8#1", Ceiling, "0.5", "0x0.8#1", Greater);
+    test("0.5", "0x0.8#1", Down, "0.2", "0x0.4#1", Less);
+    test("0.5", "0x0.8#1", Up, "0.5", "0x0.8#1", Greater);
+    test("0.5", "0x0.8#1", Nearest, "0.5", "0x0.8#1", Greater);
+    test("-0.5", "-0x0.8#1", Floor, "-0.5", "-0x0.8#1", Less);
+    test("-0.5", "-0x0.8#1", Ceiling, "-0.2", "-0x0.4#1", Greater);
+    test("-0.5", "-0x0.8#1", Down, "-0.2", "-0x0.4#1", Greater);
+    test("-0.5", "-0x0.8#1", Up, "-0.5", "-0x0.8#1", Less);
+    test("-0.5", "-0x0.8#1", Nearest, "-0.5", "-0x0.8#1", Less);
+    test("123.0", "0x7b.0#7", Floor, "-0.461", "-0x0.76#7", Less);
+    test("123.0", "0x7b.0#7", Ceiling, "-0.457", "-0x0.75#7", Greater);
+    test("123.0", "0x7b.0#7", Down, "-0.457", "-0x0.75#7", Greater);
+    test("123.0", "0x7b.0#7", Up, "-0.461", "-0x0.76#7", Less);
+    test("123.0", "0x7b.0#7", Nearest, "-0.461", "-0x0.76#7", Less);
+    test("-123.0", "-0x7b.0#7", Floor, "0.457", "0x0.75#7", Less);
+    test("-123.0", "-0x7b.0#7", Ceiling, "0.461", "0x0.76#7", Greater);
+    test("-123.0", "-0x7b.0#7", Down, "0.457", "0x0.75#7", Less);
+    test("-123.0", "-0x7b.0#7", Up, "0.461", "0x0.76#7", Greater);
+    test("-123.0", "-0x7b.0#7", Nearest, "0.461", "0x0.76#7", Greater);
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        Floor,
+        "0.9877659459927355",
+        "0x0.fcde3aa2242c50#53",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        Ceiling,
+        "0.9877659459927356",
+        "0x0.fcde3aa2242c58#53",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        Down,
+        "0.9877659459927355",
+        "0x0.fcde3aa2242c50#53",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        Up,
+        "0.9877659459927356",
+        "0x0.fcde3aa2242c58#53",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        Nearest,
+        "0.9877659459927356",
+        "0x0.fcde3aa2242c58#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Floor,
+        "1.224646799147353e-16",
+        "0x8.d313198a2e030E-14#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ceiling,
+        "1.2246467991473532e-16",
+        "0x8.d313198a2e038E-14#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Down,
+        "1.224646799147353e-16",
+        "0x8.d313198a2e030E-14#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Up,
+        "1.2246467991473532e-16",
+        "0x8.d313198a2e038E-14#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Nearest,
+        "1.2246467991473532e-16",
+        "0x8.d313198a2e038E-14#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Floor,
+        "-1.2246467991473532e-16",
+        "-0x8.d313198a2e038E-14#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Ceiling,
+        "-1.224646799147353e-16",
+        "-0x8.d313198a2e030E-14#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Down,
+        "-1.224646799147353e-16",
+        "-0x8.d313198a2e030E-14#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Up,
+        "-1.2246467991473532e-16",
+        "-0x8.d313198a2e038E-14#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Nearest,
+        "-1.2246467991473532e-16",
+        "-0x8.d313198a2e038E-14#53",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Floor,
+        "5.0e-13",
+        "0x8.0E-11#1",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Ceiling,
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Down,
+        "5.0e-13",
+        "0x8.0E-11#1",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Up,
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Nearest,
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Greater,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Floor,
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Less,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Ceiling,
+        "-5.0e-13",
+        "-0x8.0E-11#1",
+        Greater,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Down,
+        "-5.0e-13",
+        "-0x8.0E-11#1",
+        Greater,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Up,
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Less,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Nearest,
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Less,
+    );
+    test("1000.0", "0x3e8.0#10", Floor, "0.826", "0x0.d38#10", Less);
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Ceiling,
+        "0.827",
+        "0x0.d3c#10",
+        Greater,
+    );
+    test("1000.0", "0x3e8.0#10", Down, "0.826", "0x0.d38#10", Less);
+    test("1000.0", "0x3e8.0#10", Up, "0.827", "0x0.d3c#10", Greater);
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Nearest,
+        "0.827",
+        "0x0.d3c#10",
+        Greater,
+    );
+    test("4.0e9", "0x1.0E+8#1", Floor, "-0.5", "-0x0.8#1", Less);
+    test("4.0e9", "0x1.0E+8#1", Ceiling, "-0.2", "-0x0.4#1", Greater);
+    test("4.0e9", "0x1.0E+8#1", Down, "-0.2", "-0x0.4#1", Greater);
+    test("4.0e9", "0x1.0E+8#1", Up, "-0.5", "-0x0.8#1", Less);
+    test("4.0e9", "0x1.0E+8#1", Nearest, "-0.5", "-0x0.8#1", Less);
+    test("-4.0e9", "-0x1.0E+8#1", Floor, "0.2", "0x0.4#1", Less);
+    test("-4.0e9", "-0x1.0E+8#1", Ceiling, "0.5", "0x0.8#1", Greater);
+    test("-4.0e9", "-0x1.0E+8#1", Down, "0.2", "0x0.4#1", Less);
+    test("-4.0e9", "-0x1.0E+8#1", Up, "0.5", "0x0.8#1", Greater);
+    test("-4.0e9", "-0x1.0E+8#1", Nearest, "0.5", "0x0.8#1", Greater);
+    test("3.0e120", "0x1.0E+100#1", Floor, "0.1", "0x0.2#1", Less);
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        Ceiling,
+        "0.2",
+        "0x0.4#1",
+        Greater,
+    );
+    test("3.0e120", "0x1.0E+100#1", Down, "0.1", "0x0.2#1", Less);
+    test("3.0e120", "0x1.0E+100#1", Up, "0.2", "0x0.4#1", Greater);
+    test("3.0e120", "0x1.0E+100#1", Nearest, "0.1", "0x0.2#1", Less);
+    test("1.0e1204", "0x1.0E+1000#1", Floor, "0.5", "0x0.8#1", Less);
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test("1.0e1204", "0x1.0E+1000#1", Down, "0.5", "0x0.8#1", Less);
+    test("1.0e1204", "0x1.0E+1000#1", Up, "1.0", "0x1.0#1", Greater);
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+}
+
+#[test]
+fn sin_round_fail() {
+    const THREE: Float = Float::const_from_unsigned(3);
+    assert_panic!(THREE.sin_round(Exact));
+    assert_panic!(THREE.sin_round_ref(Exact));
+    assert_panic!({
+        let mut x = THREE;
+        x.sin_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_sin_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sin, o) = x.clone().sin_prec_round(prec, rm);
+        assert!(sin.is_valid());
+
+        assert_eq!(sin.to_string(), out);
+        assert_eq!(to_hex_string(&sin), out_hex);
+        assert_eq!(o, o_out);
+
+        let (sin_alt, o_alt) = x.sin_prec_round_ref(prec, rm);
+        assert!(sin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sin_alt = x.clone();
+        let o_alt = sin_alt.sin_prec_round_assign(prec, rm);
+        assert!(sin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sin, rug_o) = rug_sin_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sin)),
+                ComparableFloatRef(&sin),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, Floor, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Exact, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, Exact, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 100, Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Exact, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Exact, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 100, Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, Floor, "0.841", "0x0.d74#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.842",
+        "0x0.d78#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Down, "0.841", "0x0.d74#10", Less);
+    test("1.0", "0x1.0#1", 10, Up, "0.842", "0x0.d78#10", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "0.842",
+        "0x0.d78#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Floor,
+        "0.84147098480789650665250232163",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Ceiling,
+        "0.841470984807896506652502321631",
+        "0x0.d76aa47848677020c6e9e909d#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Down,
+        "0.84147098480789650665250232163",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Up,
+        "0.841470984807896506652502321631",
+        "0x0.d76aa47848677020c6e9e909d#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Nearest,
+        "0.84147098480789650665250232163",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "-0.5", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Down, "-0.5", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 10, Floor, "-0.842", "-0x0.d78#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "-0.841",
+        "-0x0.d74#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Down,
+        "-0.841",
+        "-0x0.d74#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 10, Up, "-0.842", "-0x0.d78#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "-0.842",
+        "-0x0.d78#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Floor,
+        "-0.841470984807896506652502321631",
+        "-0x0.d76aa47848677020c6e9e909d#100",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Ceiling,
+        "-0.84147098480789650665250232163",
+        "-0x0.d76aa47848677020c6e9e909c#100",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Down,
+        "-0.84147098480789650665250232163",
+        "-0x0.d76aa47848677020c6e9e909c#100",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Up,
+        "-0.841470984807896506652502321631",
+        "-0x0.d76aa47848677020c6e9e909d#100",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Nearest,
+        "-0.84147098480789650665250232163",
+        "-0x0.d76aa47848677020c6e9e909c#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Floor,
+        "1.0e-16",
+        "0x8.0E-14#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Ceiling,
+        "2.0e-16",
+        "0x1.0E-13#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Down,
+        "1.0e-16",
+        "0x8.0E-14#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Up,
+        "2.0e-16",
+        "0x1.0E-13#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Nearest,
+        "1.0e-16",
+        "0x8.0E-14#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "1.223e-16",
+        "0x8.d0E-14#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "1.225e-16",
+        "0x8.d4E-14#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Down,
+        "1.223e-16",
+        "0x8.d0E-14#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Up,
+        "1.225e-16",
+        "0x8.d4E-14#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "1.225e-16",
+        "0x8.d4E-14#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Floor,
+        "1.224646799147353177226065932275e-16",
+        "0x8.d313198a2e03707344a40938E-14#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Ceiling,
+        "1.224646799147353177226065932277e-16",
+        "0x8.d313198a2e03707344a40939E-14#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Down,
+        "1.224646799147353177226065932275e-16",
+        "0x8.d313198a2e03707344a40938E-14#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Up,
+        "1.224646799147353177226065932277e-16",
+        "0x8.d313198a2e03707344a40939E-14#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Nearest,
+        "1.224646799147353177226065932275e-16",
+        "0x8.d313198a2e03707344a40938E-14#100",
+        Less,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        1,
+        Floor,
+        "0.5",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test("1.0e1204", "0x1.0E+1000#1", 1, Down, "0.5", "0x0.8#1", Less);
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        10,
+        Floor,
+        "0.972",
+        "0x0.f8c#10",
+        Less,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        10,
+        Ceiling,
+        "0.973",
+        "0x0.f90#10",
+        Greater,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        10,
+        Down,
+        "0.972",
+        "0x0.f8c#10",
+        Less,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        10,
+        Up,
+        "0.973",
+        "0x0.f90#10",
+        Greater,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        10,
+        Nearest,
+        "0.973",
+        "0x0.f90#10",
+        Greater,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        100,
+        Floor,
+        "0.972552084836676957484769874207",
+        "0x0.f8f92c6607b795bdf019397bf#100",
+        Less,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        100,
+        Ceiling,
+        "0.9725520848366769574847698742077",
+        "0x0.f8f92c6607b795bdf019397c0#100",
+        Greater,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        100,
+        Down,
+        "0.972552084836676957484769874207",
+        "0x0.f8f92c6607b795bdf019397bf#100",
+        Less,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        100,
+        Up,
+        "0.9725520848366769574847698742077",
+        "0x0.f8f92c6607b795bdf019397c0#100",
+        Greater,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        100,
+        Nearest,
+        "0.972552084836676957484769874207",
+        "0x0.f8f92c6607b795bdf019397bf#100",
+        Less,
+    );
+}
+
+#[test]
+fn sin_prec_round_fail() {
+    const THREE: Float = Float::const_from_unsigned(3);
+    assert_panic!(Float::one_prec(1).sin_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).sin_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.sin_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(THREE.sin_prec_round(1, Exact));
+    assert_panic!(THREE.sin_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = THREE;
+        x.sin_prec_round_assign(1, Exact)
+    });
+}
+
+fn sin_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (sin, o) = x.clone().sin_prec_round(prec, rm);
+    assert!(sin.is_valid());
+    let (sin_alt, o_alt) = x.sin_prec_round_ref(prec, rm);
+    assert!(sin_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.sin_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sin));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_sin, rug_o) = rug_sin_prec_round(&rug::Float::exact_from(&x), prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sin)),
+            ComparableFloatRef(&sin),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if sin.is_normal() {
+        assert_eq!(sin.get_prec(), Some(prec));
+    }
+
+    if sin.is_finite() {
+        assert!(sin.le_abs(&1u32));
+    }
+
+    let (neg_sin, neg_o) = (-&x).sin_prec_round(prec, -rm);
+    assert_eq!(ComparableFloat(-neg_sin), ComparableFloat(sin.clone()));
+    assert_eq!(neg_o.reverse(), o);
+
+    match rm {
+        Floor => assert_ne!(o, Greater),
+        Ceiling => assert_ne!(o, Less),
+        Down => {
+            if sin.is_sign_positive() {
+                assert_ne!(o, Greater);
+            } else {
+                assert_ne!(o, Less);
+            }
+        }
+        Up => {
+            if sin.is_sign_positive() {
+                assert_ne!(o, Less);
+            } else {
+                assert_ne!(o, Greater);
+            }
+        }
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.sin_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(sin.clone()));
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.sin_prec_round(prec, Exact));
+    }
+}
+
+#[test]
+fn sin_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties(|(x, prec, rm)| {
+        sin_prec_round_properties_helper(x, prec, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties_with_config(
+        &config,
+        |(x, prec, rm)| {
+            sin_prec_round_properties_helper(x, prec, rm);
+        },
+    );
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (sin, o) = Float::NAN.sin_prec_round(prec, rm);
+        assert!(sin.is_nan());
+        assert_eq!(o, Equal);
+        let (sin, o) = Float::INFINITY.sin_prec_round(prec, rm);
+        assert!(sin.is_nan());
+        assert_eq!(o, Equal);
+        let (sin, o) = Float::NEGATIVE_INFINITY.sin_prec_round(prec, rm);
+        assert!(sin.is_nan());
+        assert_eq!(o, Equal);
+        let (sin, o) = Float::ZERO.sin_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(sin), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (sin, o) = Float::NEGATIVE_ZERO.sin_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(sin), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sin_prec_properties_helper(x: Float, prec: u64) {
+    let (sin, o) = x.clone().sin_prec(prec);
+    assert!(sin.is_valid());
+    let (sin_alt, o_alt) = x.sin_prec_ref(prec);
+    assert!(sin_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.sin_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sin));
+    assert_eq!(o_alt, o);
+
+    let (rug_sin, rug_o) = rug_sin_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_sin)),
+        ComparableFloatRef(&sin),
+    );
+    assert_eq!(rug_o, o);
+
+    let (sin_alt, o_alt) = x.sin_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(o_alt, o);
+
+    if sin.is_normal() {
+        assert_eq!(sin.get_prec(), Some(prec));
+    }
+
+    if sin.is_finite() {
+        assert!(sin.le_abs(&1u32));
+    }
+
+    let (neg_sin, neg_o) = (-&x).sin_prec(prec);
+    assert_eq!(ComparableFloat(-neg_sin), ComparableFloat(sin.clone()));
+    assert_eq!(neg_o.reverse(), o);
+}
+
+#[test]
+fn sin_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        sin_prec_properties_helper(x, prec);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_pair_gen_var_1().test_properties_with_config(&config, |(x, prec)| {
+        sin_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (sin, o) = Float::NAN.sin_prec(prec);
+        assert!(sin.is_nan());
+        assert_eq!(o, Equal);
+        let (sin, o) = Float::INFINITY.sin_prec(prec);
+        assert!(sin.is_nan());
+        assert_eq!(o, Equal);
+        let (sin, o) = Float::NEGATIVE_INFINITY.sin_prec(prec);
+        assert!(sin.is_nan());
+        assert_eq!(o, Equal);
+        let (sin, o) = Float::ZERO.sin_prec(prec);
+        assert_eq!(ComparableFloat(sin), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (sin, o) = Float::NEGATIVE_ZERO.sin_prec(prec);
+        assert_eq!(ComparableFloat(sin), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sin_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (sin, o) = x.clone().sin_round(rm);
+    assert!(sin.is_valid());
+    let (sin_alt, o_alt) = x.sin_round_ref(rm);
+    assert!(sin_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.sin_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sin));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_sin, rug_o) = rug_sin_round(&rug::Float::exact_from(&x), rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sin)),
+            ComparableFloatRef(&sin),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    let (sin_alt, o_alt) = x.sin_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(o_alt, o);
+
+    if sin.is_normal() {
+        assert_eq!(sin.get_prec(), Some(x.significant_bits()));
+    }
+
+    if sin.is_finite() {
+        assert!(sin.le_abs(&1u32));
+    }
+
+    let (neg_sin, neg_o) = (-&x).sin_round(-rm);
+    assert_eq!(ComparableFloat(-neg_sin), ComparableFloat(sin.clone()));
+    assert_eq!(neg_o.reverse(), o);
+
+    match rm {
+        Floor => assert_ne!(o, Greater),
+        Ceiling => assert_ne!(o, Less),
+        Down => {
+            if sin.is_sign_positive() {
+                assert_ne!(o, Greater);
+            } else {
+                assert_ne!(o, Less);
+            }
+        }
+        Up => {
+            if sin.is_sign_positive() {
+                assert_ne!(o, Less);
+            } else {
+                assert_ne!(o, Greater);
+            }
+        }
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+}
+
+#[test]
+fn sin_round_properties() {
+    float_rounding_mode_pair_gen_var_25().test_properties(|(x, rm)| {
+        sin_round_properties_helper(x, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_rounding_mode_pair_gen_var_25().test_properties_with_config(&config, |(x, rm)| {
+        sin_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (sin, o) = Float::NAN.sin_round(rm);
+        assert!(sin.is_nan());
+        assert_eq!(o, Equal);
+        let (sin, o) = Float::INFINITY.sin_round(rm);
+        assert!(sin.is_nan());
+        assert_eq!(o, Equal);
+        let (sin, o) = Float::NEGATIVE_INFINITY.sin_round(rm);
+        assert!(sin.is_nan());
+        assert_eq!(o, Equal);
+        let (sin, o) = Float::ZERO.sin_round(rm);
+        assert_eq!(ComparableFloat(sin), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (sin, o) = Float::NEGATIVE_ZERO.sin_round(rm);
+        assert_eq!(ComparableFloat(sin), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sin_properties_helper(x: Float) {
+    let sin = x.clone().sin();
+    assert!(sin.is_valid());
+
+    let sin_alt = (&x).sin();
+    assert!(sin_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+
+    let mut x_alt = x.clone();
+    x_alt.sin_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sin));
+
+    let rug_sin = rug_sin(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_sin)),
+        ComparableFloatRef(&sin),
+    );
+
+    assert_eq!(
+        ComparableFloatRef(&x.sin_round_ref(Nearest).0),
+        ComparableFloatRef(&sin)
+    );
+
+    if sin.is_finite() {
+        assert!(sin.le_abs(&1u32));
+    }
+}
+
+#[test]
+fn sin_properties() {
+    float_gen().test_properties(|x| {
+        sin_properties_helper(x);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_gen().test_properties_with_config(&config, |x| {
+        sin_properties_helper(x);
+    });
+
+    float_gen_var_6().test_properties(|x| {
+        sin_properties_helper(x);
+    });
+
+    float_gen_var_7().test_properties(|x| {
+        sin_properties_helper(x);
+    });
+
+    float_gen_var_8().test_properties(|x| {
+        sin_properties_helper(x);
+    });
+
+    float_gen_var_11().test_properties(|x| {
+        sin_properties_helper(x);
+    });
+}
@@ -0,0 +1,2188 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{Cos, Sin, SinCos};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{NaN, NegativeZero, One, Zero};
+use malachite_base::num::comparison::traits::PartialOrdAbs;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::{
+    rounding_mode_gen, unsigned_gen_var_11, unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::test_util::arithmetic::sin_cos::{
+    rug_sin_cos, rug_sin_cos_prec, rug_sin_cos_prec_round, rug_sin_cos_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_gen_var_6, float_gen_var_7, float_gen_var_8, float_gen_var_11,
+    float_rounding_mode_pair_gen_var_25, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_14,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_nz::platform::Limb;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_sin_cos() {
+    let test = |s, s_hex, out_sin: &str, out_sin_hex: &str, out_cos: &str, out_cos_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sin, cos) = x.clone().sin_cos();
+        assert!(sin.is_valid());
+        assert!(cos.is_valid());
+
+        assert_eq!(sin.to_string(), out_sin);
+        assert_eq!(to_hex_string(&sin), out_sin_hex);
+        assert_eq!(cos.to_string(), out_cos);
+        assert_eq!(to_hex_string(&cos), out_cos_hex);
+
+        let (sin_alt, cos_alt) = (&x).sin_cos();
+        assert!(sin_alt.is_valid());
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+
+        assert_eq!(ComparableFloatRef(&(&x).sin()), ComparableFloatRef(&sin));
+        assert_eq!(ComparableFloatRef(&(&x).cos()), ComparableFloatRef(&cos));
+
+        let (rug_sin, rug_cos) = rug_sin_cos(&rug::Float::exact_from(&x));
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sin)),
+            ComparableFloatRef(&sin),
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos),
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "NaN", "NaN", "NaN", "NaN");
+    test("-Infinity", "-Infinity", "NaN", "NaN", "NaN", "NaN");
+    test("0.0", "0x0.0", "0.0", "0x0.0", "1.0", "0x1.0#1");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1", "0.5", "0x0.8#1");
+    test("-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", "0.5", "0x0.8#1");
+    test("2.0", "0x2.0#1", "1.0", "0x1.0#1", "-0.5", "-0x0.8#1");
+    test("0.5", "0x0.8#1", "0.5", "0x0.8#1", "1.0", "0x1.0#1");
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.461",
+        "-0x0.76#7",
+        "-0.89",
+        "-0x0.e4#7",
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        "0.461",
+        "0x0.76#7",
+        "-0.89",
+        "-0x0.e4#7",
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "0.9877659459927356",
+        "0x0.fcde3aa2242c58#53",
+        "0.15594369476537437",
+        "0x0.27ebed0d08e166#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.2246467991473532e-16",
+        "0x8.d313198a2e038E-14#53",
+        "-1.0",
+        "-0x1.0000000000000#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-1.2246467991473532e-16",
+        "-0x8.d313198a2e038E-14#53",
+        "-1.0",
+        "-0x1.0000000000000#53",
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "1.0",
+        "0x1.0#1",
+    );
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        "-0.5",
+        "-0x0.8#1",
+        "-1.0",
+        "-0x1.0#1",
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        "1.0",
+        "0x1.0#1",
+        "-0.2",
+        "-0x0.4#1",
+    );
+    test(
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        "1.0",
+        "0x1.0000000000000#53",
+        "6.123233995736766e-17",
+        "0x4.69898cc51701cE-14#53",
+    );
+}
+
+#[test]
+fn test_sin_cos_prec() {
+    let test = |s,
+                s_hex,
+                prec: u64,
+                out_sin: &str,
+                out_sin_hex: &str,
+                out_cos: &str,
+                out_cos_hex: &str,
+                o_out_sin: Ordering,
+                o_out_cos: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sin, cos, o_sin, o_cos) = x.clone().sin_cos_prec(prec);
+        assert!(sin.is_valid());
+        assert!(cos.is_valid());
+
+        assert_eq!(sin.to_string(), out_sin);
+        assert_eq!(to_hex_string(&sin), out_sin_hex);
+        assert_eq!(cos.to_string(), out_cos);
+        assert_eq!(to_hex_string(&cos), out_cos_hex);
+        assert_eq!(o_sin, o_out_sin);
+        assert_eq!(o_cos, o_out_cos);
+
+        let (sin_alt, cos_alt, o_sin_alt, o_cos_alt) = x.sin_cos_prec_ref(prec);
+        assert!(sin_alt.is_valid());
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_sin_alt, o_out_sin);
+        assert_eq!(o_cos_alt, o_out_cos);
+
+        let (rug_sin, rug_cos, rug_o_sin, rug_o_cos) =
+            rug_sin_cos_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sin)),
+            ComparableFloatRef(&sin),
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos),
+        );
+        assert_eq!(rug_o_sin, o_sin);
+        assert_eq!(rug_o_cos, o_cos);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", "NaN", "NaN", Equal, Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", "NaN", "NaN", Equal, Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", "NaN", "NaN", Equal, Equal);
+    test(
+        "Infinity", "Infinity", 1, "NaN", "NaN", "NaN", "NaN", Equal, Equal,
+    );
+    test(
+        "Infinity", "Infinity", 10, "NaN", "NaN", "NaN", "NaN", Equal, Equal,
+    );
+    test(
+        "Infinity", "Infinity", 100, "NaN", "NaN", "NaN", "NaN", Equal, Equal,
+    );
+    test(
+        "0.0", "0x0.0", 1, "0.0", "0x0.0", "1.0", "0x1.0#1", Equal, Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.000#10",
+        Equal,
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", "1.0", "0x1.0#1", Equal, Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.000#10",
+        Equal,
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+        Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", "0.5", "0x0.8#1", Greater, Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        "0.842",
+        "0x0.d78#10",
+        "0.54",
+        "0x0.8a4#10",
+        Greater,
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.84147098480789650665250232163",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Less,
+        Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 1, "-1.0", "-0x1.0#1", "0.5", "0x0.8#1", Less, Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-0.842",
+        "-0x0.d78#10",
+        "0.54",
+        "0x0.8a4#10",
+        Less,
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-0.84147098480789650665250232163",
+        "-0x0.d76aa47848677020c6e9e909c#100",
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "1.0e-16",
+        "0x8.0E-14#1",
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "1.225e-16",
+        "0x8.d4E-14#10",
+        "-1.0",
+        "-0x1.000#10",
+        Greater,
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1.224646799147353177226065932275e-16",
+        "0x8.d313198a2e03707344a40938E-14#100",
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Less,
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        1,
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "1.0",
+        "0x1.0#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        "9.09e-13",
+        "0x1.000E-10#10",
+        "1.0",
+        "0x1.000#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        100,
+        "9.094947017729282379150389371137e-13",
+        "0xf.fffffffffffffffffffd5555E-11#100",
+        "0.9999999999999999999999995864097",
+        "0x0.ffffffffffffffffffff80000#100",
+        Less,
+        Less,
+    );
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        1,
+        "-0.5",
+        "-0x0.8#1",
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+        Less,
+    );
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        10,
+        "-0.4619",
+        "-0x0.764#10",
+        "-0.887",
+        "-0x0.e30#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        100,
+        "-0.4619865795138349446673032422649",
+        "-0x0.7644c0a233ec513f1b30c876e8#100",
+        "-0.886886915197821070108942264506",
+        "-0x0.e30b055806643a836f3c82f0f#100",
+        Less,
+        Less,
+    );
+}
+
+#[test]
+fn sin_cos_prec_fail() {
+    assert_panic!(Float::NAN.sin_cos_prec(0));
+    assert_panic!(Float::NAN.sin_cos_prec_ref(0));
+}
+
+#[test]
+fn test_sin_cos_round() {
+    let test = |s,
+                s_hex,
+                rm,
+                out_sin: &str,
+                out_sin_hex: &str,
+                out_cos: &str,
+                out_cos_hex: &str,
+                o_out_sin: Ordering,
+                o_out_cos: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sin, cos, o_sin, o_cos) = x.clone().sin_cos_round(rm);
+        assert!(sin.is_valid());
+        assert!(cos.is_valid());
+
+        assert_eq!(sin.to_string(), out_sin);
+        assert_eq!(to_hex_string(&sin), out_sin_hex);
+        assert_eq!(cos.to_string(), out_cos);
+        assert_eq!(to_hex_string(&cos), out_cos_hex);
+        assert_eq!(o_sin, o_out_sin);
+        assert_eq!(o_cos, o_out_cos);
+
+        let (sin_alt, cos_alt, o_sin_alt, o_cos_alt) = x.sin_cos_round_ref(rm);
+        assert!(sin_alt.is_valid());
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_sin_alt, o_out_sin);
+        assert_eq!(o_cos_alt, o_out_cos);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sin, rug_cos, rug_o_sin, rug_o_cos) =
+                rug_sin_cos_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sin)),
+                ComparableFloatRef(&sin),
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos),
+            );
+            assert_eq!(rug_o_sin, o_sin);
+            assert_eq!(rug_o_cos, o_cos);
+        }
+    };
+    test(
+        "NaN", "NaN", Floor, "NaN", "NaN", "NaN", "NaN", Equal, Equal,
+    );
+    test(
+        "NaN", "NaN", Ceiling, "NaN", "NaN", "NaN", "NaN", Equal, Equal,
+    );
+    test("NaN", "NaN", Down, "NaN", "NaN", "NaN", "NaN", Equal, Equal);
+    test("NaN", "NaN", Up, "NaN", "NaN", "NaN", "NaN", Equal, Equal);
+    test(
+        "NaN", "NaN", Nearest, "NaN", "NaN", "NaN", "NaN", Equal, Equal,
+    );
+    test(
+        "NaN", "NaN", Exact, "NaN", "NaN", "NaN", "NaN", Equal, Equal,
+    );
+    test(
+        "0.0", "0x0.0", Floor, "0.0", "0x0.0", "1.0", "0x1.0#1", Equal, Equal,
+    );
+    test(
+        "0.0", "0x0.0", Ceiling, "0.0", "0x0.0", "1.0", "0x1.0#1", Equal, Equal,
+    );
+    test(
+        "0.0", "0x0.0", Down, "0.0", "0x0.0", "1.0", "0x1.0#1", Equal, Equal,
+    );
+    test(
+        "0.0", "0x0.0", Up, "0.0", "0x0.0", "1.0", "0x1.0#1", Equal, Equal,
+    );
+    test(
+        "0.0", "0x0.0", Nearest, "0.0", "0x0.0", "1.0", "0x1.0#1", Equal, Equal,
+    );
+    test(
+        "0.0", "0x0.0", Exact, "0.0", "0x0.0", "1.0", "0x1.0#1", Equal, Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", Floor, "-0.0", "-0x0.0", "1.0", "0x1.0#1", Equal, Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", Ceiling, "-0.0", "-0x0.0", "1.0", "0x1.0#1", Equal, Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", Down, "-0.0", "-0x0.0", "1.0", "0x1.0#1", Equal, Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", Up, "-0.0", "-0x0.0", "1.0", "0x1.0#1", Equal, Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", Nearest, "-0.0", "-0x0.0", "1.0", "0x1.0#1", Equal, Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", Exact, "-0.0", "-0x0.0", "1.0", "0x1.0#1", Equal, Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", Floor, "0.5", "0x0.8#1", "0.5", "0x0.8#1", Less, Less,
+    );
+    test(
+        "1.0", "0x1.0#1", Ceiling, "1.0", "0x1.0#1", "1.0", "0x1.0#1", Greater, Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", Down, "0.5", "0x0.8#1", "0.5", "0x0.8#1", Less, Less,
+    );
+    test(
+        "1.0", "0x1.0#1", Up, "1.0", "0x1.0#1", "1.0", "0x1.0#1", Greater, Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", Nearest, "1.0", "0x1.0#1", "0.5", "0x0.8#1", Greater, Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", Floor, "-1.0", "-0x1.0#1", "0.5", "0x0.8#1", Less, Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", Ceiling, "-0.5", "-0x0.8#1", "1.0", "0x1.0#1", Greater, Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", Down, "-0.5", "-0x0.8#1", "0.5", "0x0.8#1", Greater, Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", Up, "-1.0", "-0x1.0#1", "1.0", "0x1.0#1", Less, Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", Nearest, "-1.0", "-0x1.0#1", "0.5", "0x0.8#1", Less, Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Floor,
+        "-0.461",
+        "-0x0.76#7",
+        "-0.89",
+        "-0x0.e4#7",
+        Less,
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Ceiling,
+        "-0.457",
+        "-0x0.75#7",
+        "-0.883",
+        "-0x0.e2#7",
+        Greater,
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Down,
+        "-0.457",
+        "-0x0.75#7",
+        "-0.883",
+        "-0x0.e2#7",
+        Greater,
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Up,
+        "-0.461",
+        "-0x0.76#7",
+        "-0.89",
+        "-0x0.e4#7",
+        Less,
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Nearest,
+        "-0.461",
+        "-0x0.76#7",
+        "-0.89",
+        "-0x0.e4#7",
+        Less,
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        Floor,
+        "0.9877659459927355",
+        "0x0.fcde3aa2242c50#53",
+        "0.15594369476537437",
+        "0x0.27ebed0d08e166#53",
+        Less,
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        Ceiling,
+        "0.9877659459927356",
+        "0x0.fcde3aa2242c58#53",
+        "0.1559436947653744",
+        "0x0.27ebed0d08e168#53",
+        Greater,
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        Down,
+        "0.9877659459927355",
+        "0x0.fcde3aa2242c50#53",
+        "0.15594369476537437",
+        "0x0.27ebed0d08e166#53",
+        Less,
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        Up,
+        "0.9877659459927356",
+        "0x0.fcde3aa2242c58#53",
+        "0.1559436947653744",
+        "0x0.27ebed0d08e168#53",
+        Greater,
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        Nearest,
+        "0.9877659459927356",
+        "0x0.fcde3aa2242c58#53",
+        "0.15594369476537437",
+        "0x0.27ebed0d08e166#53",
+        Greater,
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Floor,
+        "1.224646799147353e-16",
+        "0x8.d313198a2e030E-14#53",
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Less,
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ceiling,
+        "1.2246467991473532e-16",
+        "0x8.d313198a2e038E-14#53",
+        "-0.9999999999999999",
+        "-0x0.fffffffffffff8#53",
+        Greater,
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Down,
+        "1.224646799147353e-16",
+        "0x8.d313198a2e030E-14#53",
+        "-0.9999999999999999",
+        "-0x0.fffffffffffff8#53",
+        Less,
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Up,
+        "1.2246467991473532e-16",
+        "0x8.d313198a2e038E-14#53",
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Greater,
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Nearest,
+        "1.2246467991473532e-16",
+        "0x8.d313198a2e038E-14#53",
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Greater,
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Floor,
+        "-1.2246467991473532e-16",
+        "-0x8.d313198a2e038E-14#53",
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Less,
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Ceiling,
+        "-1.224646799147353e-16",
+        "-0x8.d313198a2e030E-14#53",
+        "-0.9999999999999999",
+        "-0x0.fffffffffffff8#53",
+        Greater,
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Down,
+        "-1.224646799147353e-16",
+        "-0x8.d313198a2e030E-14#53",
+        "-0.9999999999999999",
+        "-0x0.fffffffffffff8#53",
+        Greater,
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Up,
+        "-1.2246467991473532e-16",
+        "-0x8.d313198a2e038E-14#53",
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Less,
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Nearest,
+        "-1.2246467991473532e-16",
+        "-0x8.d313198a2e038E-14#53",
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Less,
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Floor,
+        "5.0e-13",
+        "0x8.0E-11#1",
+        "0.5",
+        "0x0.8#1",
+        Less,
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Ceiling,
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "1.0",
+        "0x1.0#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Down,
+        "5.0e-13",
+        "0x8.0E-11#1",
+        "0.5",
+        "0x0.8#1",
+        Less,
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Up,
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "1.0",
+        "0x1.0#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Nearest,
+        "9.0e-13",
+        "0x1.0E-10#1",
+        "1.0",
+        "0x1.0#1",
+        Greater,
+        Greater,
+    );
+}
+
+#[test]
+fn sin_cos_round_fail() {
+    assert_panic!(Float::ONE.sin_cos_round(Exact));
+    assert_panic!(Float::ONE.sin_cos_round_ref(Exact));
+}
+
+#[test]
+fn test_sin_cos_prec_round() {
+    let test = |s,
+                s_hex,
+                prec: u64,
+                rm,
+                out_sin: &str,
+                out_sin_hex: &str,
+                out_cos: &str,
+                out_cos_hex: &str,
+                o_out_sin: Ordering,
+                o_out_cos: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sin, cos, o_sin, o_cos) = x.clone().sin_cos_prec_round(prec, rm);
+        assert!(sin.is_valid());
+        assert!(cos.is_valid());
+
+        assert_eq!(sin.to_string(), out_sin);
+        assert_eq!(to_hex_string(&sin), out_sin_hex);
+        assert_eq!(cos.to_string(), out_cos);
+        assert_eq!(to_hex_string(&cos), out_cos_hex);
+        assert_eq!(o_sin, o_out_sin);
+        assert_eq!(o_cos, o_out_cos);
+
+        let (sin_alt, cos_alt, o_sin_alt, o_cos_alt) = x.sin_cos_prec_round_ref(prec, rm);
+        assert!(sin_alt.is_valid());
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_sin_alt, o_out_sin);
+        assert_eq!(o_cos_alt, o_out_cos);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sin, rug_cos, rug_o_sin, rug_o_cos) =
+                rug_sin_cos_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sin)),
+                ComparableFloatRef(&sin),
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos),
+            );
+            assert_eq!(rug_o_sin, o_sin);
+            assert_eq!(rug_o_cos, o_cos);
+        }
+    };
+    test(
+        "NaN", "NaN", 1, Floor, "NaN", "NaN", "NaN", "NaN", Equal, Equal,
+    );
+    test(
+        "NaN", "NaN", 1, Ceiling, "NaN", "NaN", "NaN", "NaN", Equal, Equal,
+    );
+    test(
+        "NaN", "NaN", 1, Down, "NaN", "NaN", "NaN", "NaN", Equal, Equal,
+    );
+    test(
+        "NaN", "NaN", 1, Up, "NaN", "NaN", "NaN", "NaN", Equal, Equal,
+    );
+    test(
+        "NaN", "NaN", 1, Nearest, "NaN", "NaN", "NaN", "NaN", Equal, Equal,
+    );
+    test(
+        "NaN", "NaN", 1, Exact, "NaN", "NaN", "NaN", "NaN", Equal, Equal,
+    );
+    test(
+        "NaN", "NaN", 10, Floor, "NaN", "NaN", "NaN", "NaN", Equal, Equal,
+    );
+    test(
+        "NaN", "NaN", 10, Ceiling, "NaN", "NaN", "NaN", "NaN", Equal, Equal,
+    );
+    test(
+        "NaN", "NaN", 10, Down, "NaN", "NaN", "NaN", "NaN", Equal, Equal,
+    );
+    test(
+        "NaN", "NaN", 10, Up, "NaN", "NaN", "NaN", "NaN", Equal, Equal,
+    );
+    test(
+        "NaN", "NaN", 10, Nearest, "NaN", "NaN", "NaN", "NaN", Equal, Equal,
+    );
+    test(
+        "NaN", "NaN", 10, Exact, "NaN", "NaN", "NaN", "NaN", Equal, Equal,
+    );
+    test(
+        "NaN", "NaN", 100, Floor, "NaN", "NaN", "NaN", "NaN", Equal, Equal,
+    );
+    test(
+        "NaN", "NaN", 100, Ceiling, "NaN", "NaN", "NaN", "NaN", Equal, Equal,
+    );
+    test(
+        "NaN", "NaN", 100, Down, "NaN", "NaN", "NaN", "NaN", Equal, Equal,
+    );
+    test(
+        "NaN", "NaN", 100, Up, "NaN", "NaN", "NaN", "NaN", Equal, Equal,
+    );
+    test(
+        "NaN", "NaN", 100, Nearest, "NaN", "NaN", "NaN", "NaN", Equal, Equal,
+    );
+    test(
+        "NaN", "NaN", 100, Exact, "NaN", "NaN", "NaN", "NaN", Equal, Equal,
+    );
+    test(
+        "0.0", "0x0.0", 1, Floor, "0.0", "0x0.0", "1.0", "0x1.0#1", Equal, Equal,
+    );
+    test(
+        "0.0", "0x0.0", 1, Ceiling, "0.0", "0x0.0", "1.0", "0x1.0#1", Equal, Equal,
+    );
+    test(
+        "0.0", "0x0.0", 1, Down, "0.0", "0x0.0", "1.0", "0x1.0#1", Equal, Equal,
+    );
+    test(
+        "0.0", "0x0.0", 1, Up, "0.0", "0x0.0", "1.0", "0x1.0#1", Equal, Equal,
+    );
+    test(
+        "0.0", "0x0.0", 1, Nearest, "0.0", "0x0.0", "1.0", "0x1.0#1", Equal, Equal,
+    );
+    test(
+        "0.0", "0x0.0", 1, Exact, "0.0", "0x0.0", "1.0", "0x1.0#1", Equal, Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        Floor,
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.000#10",
+        Equal,
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        Ceiling,
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.000#10",
+        Equal,
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        Down,
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.000#10",
+        Equal,
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        Up,
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.000#10",
+        Equal,
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.000#10",
+        Equal,
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        Exact,
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.000#10",
+        Equal,
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        Floor,
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        Ceiling,
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        Down,
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        Up,
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        Exact,
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", 1, Floor, "-0.0", "-0x0.0", "1.0", "0x1.0#1", Equal, Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", 1, Ceiling, "-0.0", "-0x0.0", "1.0", "0x1.0#1", Equal, Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", 1, Down, "-0.0", "-0x0.0", "1.0", "0x1.0#1", Equal, Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", 1, Up, "-0.0", "-0x0.0", "1.0", "0x1.0#1", Equal, Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", 1, Nearest, "-0.0", "-0x0.0", "1.0", "0x1.0#1", Equal, Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", 1, Exact, "-0.0", "-0x0.0", "1.0", "0x1.0#1", Equal, Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        Floor,
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.000#10",
+        Equal,
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        Ceiling,
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.000#10",
+        Equal,
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        Down,
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.000#10",
+        Equal,
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        Up,
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.000#10",
+        Equal,
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        Nearest,
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.000#10",
+        Equal,
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        Exact,
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.000#10",
+        Equal,
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        Floor,
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        Ceiling,
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        Down,
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        Up,
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        Nearest,
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        Exact,
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+        Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", 1, Floor, "0.5", "0x0.8#1", "0.5", "0x0.8#1", Less, Less,
+    );
+    test(
+        "1.0", "0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", "1.0", "0x1.0#1", Greater, Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", 1, Down, "0.5", "0x0.8#1", "0.5", "0x0.8#1", Less, Less,
+    );
+    test(
+        "1.0", "0x1.0#1", 1, Up, "1.0", "0x1.0#1", "1.0", "0x1.0#1", Greater, Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", 1, Nearest, "1.0", "0x1.0#1", "0.5", "0x0.8#1", Greater, Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Floor,
+        "0.841",
+        "0x0.d74#10",
+        "0.54",
+        "0x0.8a4#10",
+        Less,
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.842",
+        "0x0.d78#10",
+        "0.541",
+        "0x0.8a8#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Down,
+        "0.841",
+        "0x0.d74#10",
+        "0.54",
+        "0x0.8a4#10",
+        Less,
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Up,
+        "0.842",
+        "0x0.d78#10",
+        "0.541",
+        "0x0.8a8#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "0.842",
+        "0x0.d78#10",
+        "0.54",
+        "0x0.8a4#10",
+        Greater,
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Floor,
+        "0.84147098480789650665250232163",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        "0.5403023058681397174009366074426",
+        "0x0.8a51407da8345c91c2466d976#100",
+        Less,
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Ceiling,
+        "0.841470984807896506652502321631",
+        "0x0.d76aa47848677020c6e9e909d#100",
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Down,
+        "0.84147098480789650665250232163",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        "0.5403023058681397174009366074426",
+        "0x0.8a51407da8345c91c2466d976#100",
+        Less,
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Up,
+        "0.841470984807896506652502321631",
+        "0x0.d76aa47848677020c6e9e909d#100",
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Nearest,
+        "0.84147098480789650665250232163",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Less,
+        Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 1, Floor, "-1.0", "-0x1.0#1", "0.5", "0x0.8#1", Less, Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 1, Ceiling, "-0.5", "-0x0.8#1", "1.0", "0x1.0#1", Greater, Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 1, Down, "-0.5", "-0x0.8#1", "0.5", "0x0.8#1", Greater, Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 1, Up, "-1.0", "-0x1.0#1", "1.0", "0x1.0#1", Less, Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 1, Nearest, "-1.0", "-0x1.0#1", "0.5", "0x0.8#1", Less, Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "-0.842",
+        "-0x0.d78#10",
+        "0.54",
+        "0x0.8a4#10",
+        Less,
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "-0.841",
+        "-0x0.d74#10",
+        "0.541",
+        "0x0.8a8#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Down,
+        "-0.841",
+        "-0x0.d74#10",
+        "0.54",
+        "0x0.8a4#10",
+        Greater,
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Up,
+        "-0.842",
+        "-0x0.d78#10",
+        "0.541",
+        "0x0.8a8#10",
+        Less,
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "-0.842",
+        "-0x0.d78#10",
+        "0.54",
+        "0x0.8a4#10",
+        Less,
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Floor,
+        "-0.841470984807896506652502321631",
+        "-0x0.d76aa47848677020c6e9e909d#100",
+        "0.5403023058681397174009366074426",
+        "0x0.8a51407da8345c91c2466d976#100",
+        Less,
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Ceiling,
+        "-0.84147098480789650665250232163",
+        "-0x0.d76aa47848677020c6e9e909c#100",
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Down,
+        "-0.84147098480789650665250232163",
+        "-0x0.d76aa47848677020c6e9e909c#100",
+        "0.5403023058681397174009366074426",
+        "0x0.8a51407da8345c91c2466d976#100",
+        Greater,
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Up,
+        "-0.841470984807896506652502321631",
+        "-0x0.d76aa47848677020c6e9e909d#100",
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Less,
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Nearest,
+        "-0.84147098480789650665250232163",
+        "-0x0.d76aa47848677020c6e9e909c#100",
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Floor,
+        "1.0e-16",
+        "0x8.0E-14#1",
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Ceiling,
+        "2.0e-16",
+        "0x1.0E-13#1",
+        "-0.5",
+        "-0x0.8#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Down,
+        "1.0e-16",
+        "0x8.0E-14#1",
+        "-0.5",
+        "-0x0.8#1",
+        Less,
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Up,
+        "2.0e-16",
+        "0x1.0E-13#1",
+        "-1.0",
+        "-0x1.0#1",
+        Greater,
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Nearest,
+        "1.0e-16",
+        "0x8.0E-14#1",
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "1.223e-16",
+        "0x8.d0E-14#10",
+        "-1.0",
+        "-0x1.000#10",
+        Less,
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "1.225e-16",
+        "0x8.d4E-14#10",
+        "-0.999",
+        "-0x0.ffc#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Down,
+        "1.223e-16",
+        "0x8.d0E-14#10",
+        "-0.999",
+        "-0x0.ffc#10",
+        Less,
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Up,
+        "1.225e-16",
+        "0x8.d4E-14#10",
+        "-1.0",
+        "-0x1.000#10",
+        Greater,
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "1.225e-16",
+        "0x8.d4E-14#10",
+        "-1.0",
+        "-0x1.000#10",
+        Greater,
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Floor,
+        "1.224646799147353177226065932275e-16",
+        "0x8.d313198a2e03707344a40938E-14#100",
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Less,
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Ceiling,
+        "1.224646799147353177226065932277e-16",
+        "0x8.d313198a2e03707344a40939E-14#100",
+        "-0.999999999999999999999999999999",
+        "-0x0.fffffffffffffffffffffffff#100",
+        Greater,
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Down,
+        "1.224646799147353177226065932275e-16",
+        "0x8.d313198a2e03707344a40938E-14#100",
+        "-0.999999999999999999999999999999",
+        "-0x0.fffffffffffffffffffffffff#100",
+        Less,
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Up,
+        "1.224646799147353177226065932277e-16",
+        "0x8.d313198a2e03707344a40939E-14#100",
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Greater,
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Nearest,
+        "1.224646799147353177226065932275e-16",
+        "0x8.d313198a2e03707344a40938E-14#100",
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Less,
+        Less,
+    );
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        1,
+        Floor,
+        "-0.5",
+        "-0x0.8#1",
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+        Less,
+    );
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        1,
+        Ceiling,
+        "-0.2",
+        "-0x0.4#1",
+        "-0.5",
+        "-0x0.8#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        1,
+        Down,
+        "-0.2",
+        "-0x0.4#1",
+        "-0.5",
+        "-0x0.8#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        1,
+        Up,
+        "-0.5",
+        "-0x0.8#1",
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+        Less,
+    );
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        1,
+        Nearest,
+        "-0.5",
+        "-0x0.8#1",
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+        Less,
+    );
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        10,
+        Floor,
+        "-0.4624",
+        "-0x0.766#10",
+        "-0.888",
+        "-0x0.e34#10",
+        Less,
+        Less,
+    );
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        10,
+        Ceiling,
+        "-0.4619",
+        "-0x0.764#10",
+        "-0.887",
+        "-0x0.e30#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        10,
+        Down,
+        "-0.4619",
+        "-0x0.764#10",
+        "-0.887",
+        "-0x0.e30#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        10,
+        Up,
+        "-0.4624",
+        "-0x0.766#10",
+        "-0.888",
+        "-0x0.e34#10",
+        Less,
+        Less,
+    );
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        10,
+        Nearest,
+        "-0.4619",
+        "-0x0.764#10",
+        "-0.887",
+        "-0x0.e30#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        100,
+        Floor,
+        "-0.4619865795138349446673032422649",
+        "-0x0.7644c0a233ec513f1b30c876e8#100",
+        "-0.886886915197821070108942264506",
+        "-0x0.e30b055806643a836f3c82f0f#100",
+        Less,
+        Less,
+    );
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        100,
+        Ceiling,
+        "-0.4619865795138349446673032422645",
+        "-0x0.7644c0a233ec513f1b30c876e0#100",
+        "-0.886886915197821070108942264505",
+        "-0x0.e30b055806643a836f3c82f0e#100",
+        Greater,
+        Greater,
+    );
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        100,
+        Down,
+        "-0.4619865795138349446673032422645",
+        "-0x0.7644c0a233ec513f1b30c876e0#100",
+        "-0.886886915197821070108942264505",
+        "-0x0.e30b055806643a836f3c82f0e#100",
+        Greater,
+        Greater,
+    );
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        100,
+        Up,
+        "-0.4619865795138349446673032422649",
+        "-0x0.7644c0a233ec513f1b30c876e8#100",
+        "-0.886886915197821070108942264506",
+        "-0x0.e30b055806643a836f3c82f0f#100",
+        Less,
+        Less,
+    );
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        100,
+        Nearest,
+        "-0.4619865795138349446673032422649",
+        "-0x0.7644c0a233ec513f1b30c876e8#100",
+        "-0.886886915197821070108942264506",
+        "-0x0.e30b055806643a836f3c82f0f#100",
+        Less,
+        Less,
+    );
+}
+
+#[test]
+fn sin_cos_prec_round_fail() {
+    assert_panic!(Float::ONE.sin_cos_prec_round(0, Floor));
+    assert_panic!(Float::ONE.sin_cos_prec_round_ref(0, Floor));
+    assert_panic!(Float::ONE.sin_cos_prec_round(1, Exact));
+    assert_panic!(Float::ONE.sin_cos_prec_round_ref(1, Exact));
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sin_cos_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (sin, cos, o_sin, o_cos) = x.clone().sin_cos_prec_round(prec, rm);
+    assert!(sin.is_valid());
+    assert!(cos.is_valid());
+    let (sin_alt, cos_alt, o_sin_alt, o_cos_alt) = x.sin_cos_prec_round_ref(prec, rm);
+    assert!(sin_alt.is_valid());
+    assert!(cos_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_sin_alt, o_sin);
+    assert_eq!(o_cos_alt, o_cos);
+
+    let (sin_alt, o_sin_alt) = x.sin_prec_round_ref(prec, rm);
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(o_sin_alt, o_sin);
+    let (cos_alt, o_cos_alt) = x.cos_prec_round_ref(prec, rm);
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_cos_alt, o_cos);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_sin, rug_cos, rug_o_sin, rug_o_cos) =
+            rug_sin_cos_prec_round(&rug::Float::exact_from(&x), prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sin)),
+            ComparableFloatRef(&sin),
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos),
+        );
+        assert_eq!(rug_o_sin, o_sin);
+        assert_eq!(rug_o_cos, o_cos);
+    }
+
+    if sin.is_normal() {
+        assert_eq!(sin.get_prec(), Some(prec));
+    }
+    if cos.is_normal() {
+        assert_eq!(cos.get_prec(), Some(prec));
+    }
+
+    let (neg_sin, neg_cos, neg_o_sin, neg_o_cos) = (-&x).sin_cos_prec_round(prec, -rm);
+    assert_eq!(ComparableFloat(-neg_sin), ComparableFloat(sin.clone()));
+    assert_eq!(neg_o_sin.reverse(), o_sin);
+    let (neg_cos_alt, neg_o_cos_alt) = (-&x).cos_prec_round(prec, -rm);
+    assert_eq!(ComparableFloat(neg_cos), ComparableFloat(neg_cos_alt));
+    assert_eq!(neg_o_cos, neg_o_cos_alt);
+
+    match rm {
+        Floor => {
+            assert_ne!(o_sin, Greater);
+            assert_ne!(o_cos, Greater);
+        }
+        Ceiling => {
+            assert_ne!(o_sin, Less);
+            assert_ne!(o_cos, Less);
+        }
+        Exact => {
+            assert_eq!(o_sin, Equal);
+            assert_eq!(o_cos, Equal);
+        }
+        _ => {}
+    }
+}
+
+#[test]
+fn sin_cos_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties(|(x, prec, rm)| {
+        sin_cos_prec_round_properties_helper(x, prec, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties_with_config(
+        &config,
+        |(x, prec, rm)| {
+            sin_cos_prec_round_properties_helper(x, prec, rm);
+        },
+    );
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (sin, cos, o_sin, o_cos) = Float::NAN.sin_cos_prec_round(prec, rm);
+        assert!(sin.is_nan());
+        assert!(cos.is_nan());
+        assert_eq!(o_sin, Equal);
+        assert_eq!(o_cos, Equal);
+        let (sin, cos, o_sin, o_cos) = Float::ZERO.sin_cos_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(sin), ComparableFloat(Float::ZERO));
+        assert_eq!(ComparableFloat(cos), ComparableFloat(Float::one_prec(prec)));
+        assert_eq!(o_sin, Equal);
+        assert_eq!(o_cos, Equal);
+        let (sin, cos, o_sin, o_cos) = Float::NEGATIVE_ZERO.sin_cos_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(sin), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(ComparableFloat(cos), ComparableFloat(Float::one_prec(prec)));
+        assert_eq!(o_sin, Equal);
+        assert_eq!(o_cos, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sin_cos_prec_properties_helper(x: Float, prec: u64) {
+    let (sin, cos, o_sin, o_cos) = x.clone().sin_cos_prec(prec);
+    assert!(sin.is_valid());
+    assert!(cos.is_valid());
+    let (sin_alt, cos_alt, o_sin_alt, o_cos_alt) = x.sin_cos_prec_ref(prec);
+    assert!(sin_alt.is_valid());
+    assert!(cos_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_sin_alt, o_sin);
+    assert_eq!(o_cos_alt, o_cos);
+
+    let (sin_alt, o_sin_alt) = x.sin_prec_ref(prec);
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(o_sin_alt, o_sin);
+    let (cos_alt, o_cos_alt) = x.cos_prec_ref(prec);
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_cos_alt, o_cos);
+
+    let (rug_sin, rug_cos, rug_o_sin, rug_o_cos) =
+        rug_sin_cos_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_sin)),
+        ComparableFloatRef(&sin),
+    );
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_cos)),
+        ComparableFloatRef(&cos),
+    );
+    assert_eq!(rug_o_sin, o_sin);
+    assert_eq!(rug_o_cos, o_cos);
+
+    let (sin_alt, cos_alt, o_sin_alt, o_cos_alt) = x.sin_cos_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_sin_alt, o_sin);
+    assert_eq!(o_cos_alt, o_cos);
+}
+
+#[test]
+fn sin_cos_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        sin_cos_prec_properties_helper(x, prec);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_pair_gen_var_1().test_properties_with_config(&config, |(x, prec)| {
+        sin_cos_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (sin, cos, o_sin, o_cos) = Float::NAN.sin_cos_prec(prec);
+        assert!(sin.is_nan());
+        assert!(cos.is_nan());
+        assert_eq!(o_sin, Equal);
+        assert_eq!(o_cos, Equal);
+        let (sin, cos, o_sin, o_cos) = Float::ZERO.sin_cos_prec(prec);
+        assert_eq!(ComparableFloat(sin), ComparableFloat(Float::ZERO));
+        assert_eq!(ComparableFloat(cos), ComparableFloat(Float::one_prec(prec)));
+        assert_eq!(o_sin, Equal);
+        assert_eq!(o_cos, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sin_cos_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (sin, cos, o_sin, o_cos) = x.clone().sin_cos_round(rm);
+    assert!(sin.is_valid());
+    assert!(cos.is_valid());
+    let (sin_alt, cos_alt, o_sin_alt, o_cos_alt) = x.sin_cos_round_ref(rm);
+    assert!(sin_alt.is_valid());
+    assert!(cos_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_sin_alt, o_sin);
+    assert_eq!(o_cos_alt, o_cos);
+
+    let (sin_alt, o_sin_alt) = x.sin_round_ref(rm);
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(o_sin_alt, o_sin);
+    let (cos_alt, o_cos_alt) = x.cos_round_ref(rm);
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_cos_alt, o_cos);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_sin, rug_cos, rug_o_sin, rug_o_cos) =
+            rug_sin_cos_round(&rug::Float::exact_from(&x), rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sin)),
+            ComparableFloatRef(&sin),
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos),
+        );
+        assert_eq!(rug_o_sin, o_sin);
+        assert_eq!(rug_o_cos, o_cos);
+    }
+
+    let (sin_alt, cos_alt, o_sin_alt, o_cos_alt) =
+        x.sin_cos_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_sin_alt, o_sin);
+    assert_eq!(o_cos_alt, o_cos);
+}
+
+#[test]
+fn sin_cos_round_properties() {
+    float_rounding_mode_pair_gen_var_25().test_properties(|(x, rm)| {
+        sin_cos_round_properties_helper(x, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_rounding_mode_pair_gen_var_25().test_properties_with_config(&config, |(x, rm)| {
+        sin_cos_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (sin, cos, o_sin, o_cos) = Float::NAN.sin_cos_round(rm);
+        assert!(sin.is_nan());
+        assert!(cos.is_nan());
+        assert_eq!(o_sin, Equal);
+        assert_eq!(o_cos, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sin_cos_properties_helper(x: Float) {
+    let (sin, cos) = x.clone().sin_cos();
+    assert!(sin.is_valid());
+    assert!(cos.is_valid());
+
+    let (sin_alt, cos_alt) = (&x).sin_cos();
+    assert!(sin_alt.is_valid());
+    assert!(cos_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+
+    assert_eq!(ComparableFloatRef(&(&x).sin()), ComparableFloatRef(&sin));
+    assert_eq!(ComparableFloatRef(&(&x).cos()), ComparableFloatRef(&cos));
+
+    let (rug_sin, rug_cos) = rug_sin_cos(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_sin)),
+        ComparableFloatRef(&sin),
+    );
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_cos)),
+        ComparableFloatRef(&cos),
+    );
+
+    if sin.is_finite() {
+        assert!(sin.le_abs(&1u32));
+        assert!(cos.le_abs(&1u32));
+    }
+}
+
+#[test]
+fn sin_cos_properties() {
+    float_gen().test_properties(|x| {
+        sin_cos_properties_helper(x);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_gen().test_properties_with_config(&config, |x| {
+        sin_cos_properties_helper(x);
+    });
+
+    float_gen_var_6().test_properties(|x| {
+        sin_cos_properties_helper(x);
+    });
+
+    float_gen_var_7().test_properties(|x| {
+        sin_cos_properties_helper(x);
+    });
+
+    float_gen_var_8().test_properties(|x| {
+        sin_cos_properties_helper(x);
+    });
+
+    float_gen_var_11().test_properties(|x| {
+        sin_cos_properties_helper(x);
+    });
+}
@@ -0,0 +1,913 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{Sqrt, SqrtAssign, Square};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::conversion::traits::RoundingFrom;
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::{
+    primitive_float_gen, rounding_mode_gen, unsigned_gen_var_11,
+    unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::test_util::arithmetic::sqrt::{
+    rug_sqrt, rug_sqrt_prec, rug_sqrt_prec_round, rug_sqrt_round,
+};
+use malachite_float::test_util::common::{
+    emulate_primitive_float_fn, parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_gen_var_6, float_gen_var_7, float_gen_var_8, float_gen_var_11,
+    float_rounding_mode_pair_gen_var_23, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_12,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_nz::platform::Limb;
+use malachite_q::Rational;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_sqrt() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let sqrt = x.clone().sqrt();
+        assert!(sqrt.is_valid());
+
+        assert_eq!(sqrt.to_string(), out);
+        assert_eq!(to_hex_string(&sqrt), out_hex);
+
+        let sqrt_alt = (&x).sqrt();
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt), ComparableFloatRef(&sqrt_alt));
+
+        let mut sqrt_alt = x.clone();
+        sqrt_alt.sqrt_assign();
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt), ComparableFloatRef(&sqrt_alt));
+
+        let rug_sqrt = rug_sqrt(&rug::Float::exact_from(&x));
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sqrt)),
+            ComparableFloatRef(&sqrt),
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1");
+    test("-1.0", "-0x1.0#1", "NaN", "NaN");
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+    );
+    test("2.0", "0x2.0#1", "1.0", "0x1.0#1");
+    test("4.0", "0x4.0#1", "2.0", "0x2.0#1");
+    test("9.0", "0x9.0#4", "3.0", "0x3.0#4");
+    test("123.0", "0x7b.0#7", "11.1", "0xb.2#7");
+    test("-123.0", "-0x7b.0#7", "NaN", "NaN");
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "1.189207115002721",
+        "0x1.306fe0a31b715#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.7724538509055159",
+        "0x1.c5bf891b4ef6a#53",
+    );
+    test("1.0e12", "0x1.0E+10#1", "1.0e6", "0x1.0E+5#1");
+    test("1.0e-13", "0x2.0E-11#1", "2.0e-7", "0x4.0E-6#1");
+    test("4.0139e7", "0x2.6478E+6#15", "6335.5", "0x18bf.8#15");
+    test(
+        "1.4904942e-19",
+        "0x2.bfddbE-16#22",
+        "3.860692e-10",
+        "0x1.a87cd0E-8#22",
+    );
+    test(
+        "4.850638409455617268748732e-12",
+        "0x5.5555555555555552aaabE-10#83",
+        "2.2024164931855230884602517e-6",
+        "0x0.000024f34e8b2066389a39e230#83",
+    );
+    test(
+        "9.4039548064414545106e-38",
+        "0x1.ffffffffe0000000E-31#64",
+        "3.0665868333444358071e-19",
+        "0x5.a827999fa1b1e758E-16#64",
+    );
+    test(
+        "1.809457589959748038781206513903043742e-25",
+        "0x3.800000000000000000000000000000E-21#121",
+        "4.25377196140054978239248695480972549e-13",
+        "0x7.7bba845a0fd248155725fd3e136938E-11#121",
+    );
+}
+
+#[test]
+fn test_sqrt_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sqrt, o) = x.clone().sqrt_prec(prec);
+        assert!(sqrt.is_valid());
+
+        assert_eq!(sqrt.to_string(), out);
+        assert_eq!(to_hex_string(&sqrt), out_hex);
+        assert_eq!(o, o_out);
+
+        let (sqrt_alt, o_alt) = x.sqrt_prec_ref(prec);
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt), ComparableFloatRef(&sqrt_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sqrt_alt = x.clone();
+        let o_alt = sqrt_alt.sqrt_prec_assign(prec);
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt), ComparableFloatRef(&sqrt_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_sqrt, rug_o) = rug_sqrt_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sqrt)),
+            ComparableFloatRef(&sqrt),
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", 10, "1.0", "0x1.000#10", Equal);
+    test("-1.0", "-0x1.0#1", 1, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", 1, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", 10, "1.414", "0x1.6a0#10", Less);
+    test("123.0", "0x7b.0#7", 1, "8.0", "0x8.0#1", Less);
+    test("123.0", "0x7b.0#7", 10, "11.09", "0xb.18#10", Greater);
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        10,
+        "1.189",
+        "0x1.308#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "1.771",
+        "0x1.c58#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1.77245385090551599275151910314",
+        "0x1.c5bf891b4ef6a7fc7dc11ccfa#100",
+        Greater,
+    );
+    test("9.0", "0x9.0#4", 1, "4.0", "0x4.0#1", Greater);
+    test(
+        "9.0",
+        "0x9.0#4",
+        100,
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.4904942e-19",
+        "0x2.bfddbE-16#22",
+        10,
+        "3.861e-10",
+        "0x1.a88E-8#10",
+        Greater,
+    );
+}
+
+#[test]
+fn sqrt_prec_fail() {
+    assert_panic!(Float::NAN.sqrt_prec(0));
+    assert_panic!(Float::NAN.sqrt_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.sqrt_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_sqrt_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sqrt, o) = x.clone().sqrt_round(rm);
+        assert!(sqrt.is_valid());
+
+        assert_eq!(sqrt.to_string(), out);
+        assert_eq!(to_hex_string(&sqrt), out_hex);
+        assert_eq!(o, o_out);
+
+        let (sqrt_alt, o_alt) = x.sqrt_round_ref(rm);
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt), ComparableFloatRef(&sqrt_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sqrt_alt = x.clone();
+        let o_alt = sqrt_alt.sqrt_round_assign(rm);
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt), ComparableFloatRef(&sqrt_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sqrt, rug_o) = rug_sqrt_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sqrt)),
+                ComparableFloatRef(&sqrt),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", Floor, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", Exact, "1.0", "0x1.0#1", Equal);
+    test("-1.0", "-0x1.0#1", Floor, "NaN", "NaN", Equal);
+    test("9.0", "0x9.0#4", Exact, "3.0", "0x3.0#4", Equal);
+    test("2.0", "0x2.0#1", Floor, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("2.0", "0x2.0#1", Down, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", Up, "2.0", "0x2.0#1", Greater);
+    test("2.0", "0x2.0#1", Nearest, "1.0", "0x1.0#1", Less);
+    test("123.0", "0x7b.0#7", Floor, "11.0", "0xb.0#7", Less);
+    test("123.0", "0x7b.0#7", Ceiling, "11.1", "0xb.2#7", Greater);
+    test("123.0", "0x7b.0#7", Down, "11.0", "0xb.0#7", Less);
+    test("123.0", "0x7b.0#7", Up, "11.1", "0xb.2#7", Greater);
+    test("123.0", "0x7b.0#7", Nearest, "11.1", "0xb.2#7", Greater);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Floor,
+        "1.7724538509055159",
+        "0x1.c5bf891b4ef6a#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ceiling,
+        "1.7724538509055161",
+        "0x1.c5bf891b4ef6b#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Down,
+        "1.7724538509055159",
+        "0x1.c5bf891b4ef6a#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Up,
+        "1.7724538509055161",
+        "0x1.c5bf891b4ef6b#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Nearest,
+        "1.7724538509055159",
+        "0x1.c5bf891b4ef6a#53",
+        Less,
+    );
+    test(
+        "1.4904942e-19",
+        "0x2.bfddbE-16#22",
+        Floor,
+        "3.860692e-10",
+        "0x1.a87cd0E-8#22",
+        Less,
+    );
+    test(
+        "1.4904942e-19",
+        "0x2.bfddbE-16#22",
+        Ceiling,
+        "3.860693e-10",
+        "0x1.a87cd8E-8#22",
+        Greater,
+    );
+    test(
+        "1.4904942e-19",
+        "0x2.bfddbE-16#22",
+        Nearest,
+        "3.860692e-10",
+        "0x1.a87cd0E-8#22",
+        Less,
+    );
+}
+
+#[test]
+fn sqrt_round_fail() {
+    const THREE: Float = Float::const_from_unsigned(3);
+    assert_panic!(THREE.sqrt_round(Exact));
+    assert_panic!(THREE.sqrt_round_ref(Exact));
+    assert_panic!({
+        let mut x = THREE;
+        x.sqrt_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_sqrt_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sqrt, o) = x.clone().sqrt_prec_round(prec, rm);
+        assert!(sqrt.is_valid());
+
+        assert_eq!(sqrt.to_string(), out);
+        assert_eq!(to_hex_string(&sqrt), out_hex);
+        assert_eq!(o, o_out);
+
+        let (sqrt_alt, o_alt) = x.sqrt_prec_round_ref(prec, rm);
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt), ComparableFloatRef(&sqrt_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sqrt_alt = x.clone();
+        let o_alt = sqrt_alt.sqrt_prec_round_assign(prec, rm);
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt), ComparableFloatRef(&sqrt_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sqrt, rug_o) = rug_sqrt_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sqrt)),
+                ComparableFloatRef(&sqrt),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test(
+        "Infinity", "Infinity", 1, Floor, "Infinity", "Infinity", Equal,
+    );
+    test("-Infinity", "-Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, Floor, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", 10, Exact, "1.0", "0x1.000#10", Equal);
+    test("-1.0", "-0x1.0#1", 1, Floor, "NaN", "NaN", Equal);
+    test("9.0", "0x9.0#4", 2, Exact, "3.0", "0x3.0#2", Equal);
+    test("9.0", "0x9.0#4", 1, Floor, "2.0", "0x2.0#1", Less);
+    test("9.0", "0x9.0#4", 1, Ceiling, "4.0", "0x4.0#1", Greater);
+    test("9.0", "0x9.0#4", 1, Nearest, "4.0", "0x4.0#1", Greater);
+    test("2.0", "0x2.0#1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("2.0", "0x2.0#1", 1, Down, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("2.0", "0x2.0#1", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", 10, Floor, "1.414", "0x1.6a0#10", Less);
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        Ceiling,
+        "1.416",
+        "0x1.6a8#10",
+        Greater,
+    );
+    test("2.0", "0x2.0#1", 10, Down, "1.414", "0x1.6a0#10", Less);
+    test("2.0", "0x2.0#1", 10, Up, "1.416", "0x1.6a8#10", Greater);
+    test("2.0", "0x2.0#1", 10, Nearest, "1.414", "0x1.6a0#10", Less);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "1.771",
+        "0x1.c58#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "1.773",
+        "0x1.c60#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Down,
+        "1.771",
+        "0x1.c58#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Up,
+        "1.773",
+        "0x1.c60#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "1.771",
+        "0x1.c58#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Floor,
+        "1.772453850905515992751519103138",
+        "0x1.c5bf891b4ef6a7fc7dc11ccf8#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Ceiling,
+        "1.77245385090551599275151910314",
+        "0x1.c5bf891b4ef6a7fc7dc11ccfa#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Nearest,
+        "1.77245385090551599275151910314",
+        "0x1.c5bf891b4ef6a7fc7dc11ccfa#100",
+        Greater,
+    );
+}
+
+#[test]
+fn sqrt_prec_round_fail() {
+    const THREE: Float = Float::const_from_unsigned(3);
+    assert_panic!(Float::one_prec(1).sqrt_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).sqrt_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.sqrt_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(THREE.sqrt_prec_round(1, Exact));
+    assert_panic!(THREE.sqrt_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = THREE;
+        x.sqrt_prec_round_assign(1, Exact)
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sqrt_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (sqrt, o) = x.clone().sqrt_prec_round(prec, rm);
+    assert!(sqrt.is_valid());
+    let (sqrt_alt, o_alt) = x.sqrt_prec_round_ref(prec, rm);
+    assert!(sqrt_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.sqrt_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sqrt));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_sqrt, rug_o) = rug_sqrt_prec_round(&rug::Float::exact_from(&x), prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sqrt)),
+            ComparableFloatRef(&sqrt),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if o == Equal && sqrt.is_finite() && sqrt != 0 {
+        assert_eq!(
+            ComparableFloatRef(&sqrt.square_prec_round_ref(x.significant_bits(), Exact).0),
+            ComparableFloatRef(&x)
+        );
+    }
+
+    if sqrt.is_finite() && x.is_finite() {
+        if sqrt.is_normal() {
+            assert_eq!(sqrt.get_prec(), Some(prec));
+        }
+        // Squaring is monotone on nonnegative numbers, so comparing squares compares square roots
+        let r_x = Rational::exact_from(&x);
+        assert_eq!(
+            Rational::exact_from(&sqrt).square().partial_cmp(&r_x),
+            Some(o)
+        );
+        if o == Less {
+            let mut next = sqrt.clone();
+            next.increment();
+            assert!(Rational::exact_from(&next).square() > r_x);
+        } else if o == Greater {
+            let mut next = sqrt.clone();
+            next.decrement();
+            assert!(Rational::exact_from(&next).square() < r_x);
+        }
+    } else {
+        assert_eq!(o, Equal);
+    }
+
+    match rm {
+        Floor | Down => assert_ne!(o, Greater),
+        Ceiling | Up => assert_ne!(o, Less),
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.sqrt_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(sqrt.clone()));
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.sqrt_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn sqrt_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_12().test_properties(|(x, prec, rm)| {
+        sqrt_prec_round_properties_helper(x, prec, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_rounding_mode_triple_gen_var_12().test_properties_with_config(
+        &config,
+        |(x, prec, rm)| {
+            sqrt_prec_round_properties_helper(x, prec, rm);
+        },
+    );
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    config.insert("mean_small_n", 2048);
+    float_unsigned_rounding_mode_triple_gen_var_12().test_properties_with_config(
+        &config,
+        |(x, prec, rm)| {
+            sqrt_prec_round_properties_helper(x, prec, rm);
+        },
+    );
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (sqrt, o) = Float::NAN.sqrt_prec_round(prec, rm);
+        assert!(sqrt.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(
+            Float::INFINITY.sqrt_prec_round(prec, rm),
+            (Float::INFINITY, Equal)
+        );
+        let (sqrt, o) = Float::NEGATIVE_INFINITY.sqrt_prec_round(prec, rm);
+        assert!(sqrt.is_nan());
+        assert_eq!(o, Equal);
+        let (sqrt, o) = Float::ZERO.sqrt_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(sqrt), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (sqrt, o) = Float::NEGATIVE_ZERO.sqrt_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(sqrt), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+        assert_eq!(
+            Float::ONE.sqrt_prec_round(prec, rm),
+            (Float::one_prec(prec), Equal)
+        );
+        let (sqrt, o) = Float::NEGATIVE_ONE.sqrt_prec_round(prec, rm);
+        assert!(sqrt.is_nan());
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sqrt_prec_properties_helper(x: Float, prec: u64) {
+    let (sqrt, o) = x.clone().sqrt_prec(prec);
+    assert!(sqrt.is_valid());
+    let (sqrt_alt, o_alt) = x.sqrt_prec_ref(prec);
+    assert!(sqrt_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.sqrt_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sqrt));
+    assert_eq!(o_alt, o);
+
+    let (rug_sqrt, rug_o) = rug_sqrt_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_sqrt)),
+        ComparableFloatRef(&sqrt),
+    );
+    assert_eq!(rug_o, o);
+
+    let (sqrt_alt, o_alt) = x.sqrt_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+    assert_eq!(o_alt, o);
+
+    if sqrt.is_finite() && x.is_finite() {
+        if sqrt.is_normal() {
+            assert_eq!(sqrt.get_prec(), Some(prec));
+        }
+        let r_x = Rational::exact_from(&x);
+        assert_eq!(
+            Rational::exact_from(&sqrt).square().partial_cmp(&r_x),
+            Some(o)
+        );
+    } else {
+        assert_eq!(o, Equal);
+    }
+}
+
+#[test]
+fn sqrt_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        sqrt_prec_properties_helper(x, prec);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_pair_gen_var_1().test_properties_with_config(&config, |(x, prec)| {
+        sqrt_prec_properties_helper(x, prec);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    config.insert("mean_small_n", 2048);
+    float_unsigned_pair_gen_var_1().test_properties_with_config(&config, |(x, prec)| {
+        sqrt_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (sqrt, o) = Float::NAN.sqrt_prec(prec);
+        assert!(sqrt.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(Float::INFINITY.sqrt_prec(prec), (Float::INFINITY, Equal));
+        let (sqrt, o) = Float::NEGATIVE_INFINITY.sqrt_prec(prec);
+        assert!(sqrt.is_nan());
+        assert_eq!(o, Equal);
+        let (sqrt, o) = Float::ZERO.sqrt_prec(prec);
+        assert_eq!(ComparableFloat(sqrt), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (sqrt, o) = Float::NEGATIVE_ZERO.sqrt_prec(prec);
+        assert_eq!(ComparableFloat(sqrt), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+        assert_eq!(Float::ONE.sqrt_prec(prec), (Float::one_prec(prec), Equal));
+        let (sqrt, o) = Float::NEGATIVE_ONE.sqrt_prec(prec);
+        assert!(sqrt.is_nan());
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sqrt_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (sqrt, o) = x.clone().sqrt_round(rm);
+    assert!(sqrt.is_valid());
+    let (sqrt_alt, o_alt) = x.sqrt_round_ref(rm);
+    assert!(sqrt_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.sqrt_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sqrt));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_sqrt, rug_o) = rug_sqrt_round(&rug::Float::exact_from(&x), rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sqrt)),
+            ComparableFloatRef(&sqrt),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    let (sqrt_alt, o_alt) = x.sqrt_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+    assert_eq!(o_alt, o);
+
+    if sqrt.is_finite() && x.is_finite() {
+        if sqrt.is_normal() {
+            assert_eq!(sqrt.get_prec(), Some(x.significant_bits()));
+        }
+        let r_x = Rational::exact_from(&x);
+        assert_eq!(
+            Rational::exact_from(&sqrt).square().partial_cmp(&r_x),
+            Some(o)
+        );
+        if o == Less {
+            let mut next = sqrt.clone();
+            next.increment();
+            assert!(Rational::exact_from(&next).square() > r_x);
+        } else if o == Greater {
+            let mut next = sqrt.clone();
+            next.decrement();
+            assert!(Rational::exact_from(&next).square() < r_x);
+        }
+    } else {
+        assert_eq!(o, Equal);
+    }
+
+    match rm {
+        Floor | Down => assert_ne!(o, Greater),
+        Ceiling | Up => assert_ne!(o, Less),
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.sqrt_round_ref(rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(sqrt.clone()));
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.sqrt_round_ref(Exact));
+    }
+}
+
+#[test]
+fn sqrt_round_properties() {
+    float_rounding_mode_pair_gen_var_23().test_properties(|(x, rm)| {
+        sqrt_round_properties_helper(x, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_rounding_mode_pair_gen_var_23().test_properties_with_config(&config, |(x, rm)| {
+        sqrt_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (sqrt, o) = Float::NAN.sqrt_round(rm);
+        assert!(sqrt.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(Float::INFINITY.sqrt_round(rm), (Float::INFINITY, Equal));
+        let (sqrt, o) = Float::NEGATIVE_INFINITY.sqrt_round(rm);
+        assert!(sqrt.is_nan());
+        assert_eq!(o, Equal);
+        let (sqrt, o) = Float::ZERO.sqrt_round(rm);
+        assert_eq!(ComparableFloat(sqrt), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (sqrt, o) = Float::NEGATIVE_ZERO.sqrt_round(rm);
+        assert_eq!(ComparableFloat(sqrt), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+        assert_eq!(Float::ONE.sqrt_round(rm), (Float::ONE, Equal));
+        let (sqrt, o) = Float::NEGATIVE_ONE.sqrt_round(rm);
+        assert!(sqrt.is_nan());
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn sqrt_properties_helper_2<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_gen::<T>().test_properties(|x| {
+        let sqrt_1 = x.sqrt();
+        let sqrt_2 = emulate_primitive_float_fn(|x, prec| x.sqrt_prec(prec).0, x);
+        assert_eq!(NiceFloat(sqrt_1), NiceFloat(sqrt_2));
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sqrt_properties_helper(x: Float) {
+    let sqrt = x.clone().sqrt();
+    assert!(sqrt.is_valid());
+
+    let sqrt_alt = (&x).sqrt();
+    assert!(sqrt_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+
+    let mut x_alt = x.clone();
+    x_alt.sqrt_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sqrt));
+
+    let rug_sqrt = rug_sqrt(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_sqrt)),
+        ComparableFloatRef(&sqrt),
+    );
+
+    assert_eq!(
+        ComparableFloatRef(&x.sqrt_round_ref(Nearest).0),
+        ComparableFloatRef(&sqrt)
+    );
+
+    if sqrt.is_normal() && x.is_finite() {
+        assert_eq!(sqrt.get_prec(), Some(x.significant_bits()));
+    }
+
+    if x < 0u32 {
+        assert!(sqrt.is_nan());
+    }
+}
+
+#[test]
+fn sqrt_properties() {
+    float_gen().test_properties(|x| {
+        sqrt_properties_helper(x);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_gen().test_properties_with_config(&config, |x| {
+        sqrt_properties_helper(x);
+    });
+
+    float_gen_var_6().test_properties(|x| {
+        sqrt_properties_helper(x);
+    });
+
+    float_gen_var_7().test_properties(|x| {
+        sqrt_properties_helper(x);
+    });
+
+    float_gen_var_8().test_properties(|x| {
+        sqrt_properties_helper(x);
+    });
+
+    float_gen_var_11().test_properties(|x| {
+        sqrt_properties_helper(x);
+    });
+
+    apply_fn_to_primitive_floats!(sqrt_properties_helper_2);
+}
@@ -0,0 +1,1423 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{Tan, TanAssign};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::{
+    rounding_mode_gen, unsigned_gen_var_11, unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::test_util::arithmetic::tan::{
+    rug_tan, rug_tan_prec, rug_tan_prec_round, rug_tan_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_gen_var_6, float_gen_var_7, float_gen_var_8, float_gen_var_11,
+    float_rounding_mode_pair_gen_var_25, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_14,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_nz::platform::Limb;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_tan() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let tan = x.clone().tan();
+        assert!(tan.is_valid());
+
+        assert_eq!(tan.to_string(), out);
+        assert_eq!(to_hex_string(&tan), out_hex);
+
+        let tan_alt = (&x).tan();
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+
+        let mut tan_alt = x.clone();
+        tan_alt.tan_assign();
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+
+        let rug_tan = rug_tan(&rug::Float::exact_from(&x));
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_tan)),
+            ComparableFloatRef(&tan),
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "NaN", "NaN");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "2.0", "0x2.0#1");
+    test("-1.0", "-0x1.0#1", "-2.0", "-0x2.0#1");
+    test("2.0", "0x2.0#1", "-2.0", "-0x2.0#1");
+    test("0.5", "0x0.8#1", "0.5", "0x0.8#1");
+    test("-0.5", "-0x0.8#1", "-0.5", "-0x0.8#1");
+    test("123.0", "0x7b.0#7", "0.516", "0x0.84#7");
+    test("-123.0", "-0x7b.0#7", "-0.516", "-0x0.84#7");
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "6.3341191670421955",
+        "0x6.5588d56f69b9c#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.2246467991473532e-16",
+        "-0x8.d313198a2e038E-14#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "1.2246467991473532e-16",
+        "0x8.d313198a2e038E-14#53",
+    );
+    test("9.0e-13", "0x1.0E-10#1", "9.0e-13", "0x1.0E-10#1");
+    test("-9.0e-13", "-0x1.0E-10#1", "-9.0e-13", "-0x1.0E-10#1");
+    test("1000.0", "0x3e8.0#10", "1.471", "0x1.788#10");
+    test("4.0e9", "0x1.0E+8#1", "0.5", "0x0.8#1");
+    test("-4.0e9", "-0x1.0E+8#1", "-0.5", "-0x0.8#1");
+    test("3.0e120", "0x1.0E+100#1", "-0.1", "-0x0.2#1");
+    test("1.0e1204", "0x1.0E+1000#1", "-4.0", "-0x4.0#1");
+}
+
+#[test]
+fn test_tan_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (tan, o) = x.clone().tan_prec(prec);
+        assert!(tan.is_valid());
+
+        assert_eq!(tan.to_string(), out);
+        assert_eq!(to_hex_string(&tan), out_hex);
+        assert_eq!(o, o_out);
+
+        let (tan_alt, o_alt) = x.tan_prec_ref(prec);
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut tan_alt = x.clone();
+        let o_alt = tan_alt.tan_prec_assign(prec);
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_tan, rug_o) = rug_tan_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_tan)),
+            ComparableFloatRef(&tan),
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 100, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 100, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 100, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 100, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, "1.557", "0x1.8e8#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.557407724654902230506974807459",
+        "0x1.8eb245cbee3a5b8acc7d41324#100",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", 10, "-1.557", "-0x1.8e8#10", Greater);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-1.557407724654902230506974807459",
+        "-0x1.8eb245cbee3a5b8acc7d41324#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "-1.0e-16",
+        "-0x8.0E-14#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "-1.225e-16",
+        "-0x8.d4E-14#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "-1.224646799147353177226065932275e-16",
+        "-0x8.d313198a2e03707344a40938E-14#100",
+        Greater,
+    );
+    test("9.0e-13", "0x1.0E-10#1", 1, "9.0e-13", "0x1.0E-10#1", Less);
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        10,
+        "9.09e-13",
+        "0x1.000E-10#10",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        100,
+        "9.09494701772928237915039313273e-13",
+        "0x1.0000000000000000000055556E-10#100",
+        Greater,
+    );
+    test("4.0e9", "0x1.0E+8#1", 1, "0.5", "0x0.8#1", Less);
+    test("4.0e9", "0x1.0E+8#1", 10, "0.5205", "0x0.854#10", Less);
+    test(
+        "4.0e9",
+        "0x1.0E+8#1",
+        100,
+        "0.520908101807757922033695499518",
+        "0x0.855a3bbd7c5aaf8b1e7a6f7aa#100",
+        Less,
+    );
+    test("1.0e1204", "0x1.0E+1000#1", 1, "-4.0", "-0x4.0#1", Greater);
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        10,
+        "-4.18",
+        "-0x4.2e#10",
+        Greater,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        100,
+        "-4.179688805958844052197662404338",
+        "-0x4.2e0015e90cef36f474284ac60#100",
+        Less,
+    );
+}
+
+#[test]
+fn tan_prec_fail() {
+    assert_panic!(Float::NAN.tan_prec(0));
+    assert_panic!(Float::NAN.tan_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.tan_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_tan_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (tan, o) = x.clone().tan_round(rm);
+        assert!(tan.is_valid());
+
+        assert_eq!(tan.to_string(), out);
+        assert_eq!(to_hex_string(&tan), out_hex);
+        assert_eq!(o, o_out);
+
+        let (tan_alt, o_alt) = x.tan_round_ref(rm);
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut tan_alt = x.clone();
+        let o_alt = tan_alt.tan_round_assign(rm);
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_tan, rug_o) = rug_tan_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_tan)),
+                ComparableFloatRef(&tan),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Down, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Up, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Nearest, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", Floor, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", Down, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", Up, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "2.0", "0x2.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Floor, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "-1.0", "-0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "-1.0", "-0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Up, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", Nearest, "-2.0", "-0x2.0#1", Less);
+    test("2.0", "0x2.0#1", Floor, "-4.0", "-0x4.0#1", Less);
+    test("2.0", "0x2.0#1", Ceiling, "-2.0", "-0x2.0#1", Greater);
+    test("2.0", "0x2.0#1", Down, "-2.0", "-0x2.0#1", Greater);
+    test("2.0", "0x2.0#1", Up, "-4.0", "-0x4.0#1", Less);
+    test("2.0", "0x2.0#1", Nearest, "-2.0", "-0x2.0#1", Greater);
+    test("0.5", "0x0.8#1", Floor, "0.5", "0x0.8#1", Less);
+    test("0.5", "0x0.8#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("0.5", "0x0.8#1", Down, "0.5", "0x0.8#1", Less);
+    test("0.5", "0x0.8#1", Up, "1.0", "0x1.0#1", Greater);
+    test("0.5", "0x0.8#1", Nearest, "0.5", "0x0.8#1", Less);
+    test("-0.5", "-0x0.8#1", Floor, "-1.0", "-0x1.0#1", Less);
+    test("-0.5", "-0x0.8#1", Ceiling, "-0.5", "-0x0.8#1", Greater);
+    test("-0.5", "-0x0.8#1", Down, "-0.5", "-0x0.8#1", Greater);
+    test("-0.5", "-0x0.8#1", Up, "-1.0", "-0x1.0#1", Less);
+    test("-0.5", "-0x0.8#1", Nearest, "-0.5", "-0x0.8#1", Greater);
+    test("123.0", "0x7b.0#7", Floor, "0.516", "0x0.84#7", Less);
+    test("123.0", "0x7b.0#7", Ceiling, "0.523", "0x0.86#7", Greater);
+    test("123.0", "0x7b.0#7", Down, "0.516", "0x0.84#7", Less);
+    test("123.0", "0x7b.0#7", Up, "0.523", "0x0.86#7", Greater);
+    test("123.0", "0x7b.0#7", Nearest, "0.516", "0x0.84#7", Less);
+    test("-123.0", "-0x7b.0#7", Floor, "-0.523", "-0x0.86#7", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Ceiling,
+        "-0.516",
+        "-0x0.84#7",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", Down, "-0.516", "-0x0.84#7", Greater);
+    test("-123.0", "-0x7b.0#7", Up, "-0.523", "-0x0.86#7", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Nearest,
+        "-0.516",
+        "-0x0.84#7",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        Floor,
+        "6.3341191670421955",
+        "0x6.5588d56f69b9c#53",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        Ceiling,
+        "6.334119167042196",
+        "0x6.5588d56f69ba0#53",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        Down,
+        "6.3341191670421955",
+        "0x6.5588d56f69b9c#53",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        Up,
+        "6.334119167042196",
+        "0x6.5588d56f69ba0#53",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        Nearest,
+        "6.3341191670421955",
+        "0x6.5588d56f69b9c#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Floor,
+        "-1.2246467991473532e-16",
+        "-0x8.d313198a2e038E-14#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ceiling,
+        "-1.224646799147353e-16",
+        "-0x8.d313198a2e030E-14#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Down,
+        "-1.224646799147353e-16",
+        "-0x8.d313198a2e030E-14#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Up,
+        "-1.2246467991473532e-16",
+        "-0x8.d313198a2e038E-14#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Nearest,
+        "-1.2246467991473532e-16",
+        "-0x8.d313198a2e038E-14#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Floor,
+        "1.224646799147353e-16",
+        "0x8.d313198a2e030E-14#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Ceiling,
+        "1.2246467991473532e-16",
+        "0x8.d313198a2e038E-14#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Down,
+        "1.224646799147353e-16",
+        "0x8.d313198a2e030E-14#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Up,
+        "1.2246467991473532e-16",
+        "0x8.d313198a2e038E-14#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Nearest,
+        "1.2246467991473532e-16",
+        "0x8.d313198a2e038E-14#53",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Floor,
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Ceiling,
+        "2.0e-12",
+        "0x2.0E-10#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Down,
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Less,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Up,
+        "2.0e-12",
+        "0x2.0E-10#1",
+        Greater,
+    );
+    test(
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Nearest,
+        "9.0e-13",
+        "0x1.0E-10#1",
+        Less,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Floor,
+        "-2.0e-12",
+        "-0x2.0E-10#1",
+        Less,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Ceiling,
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Greater,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Down,
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Greater,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Up,
+        "-2.0e-12",
+        "-0x2.0E-10#1",
+        Less,
+    );
+    test(
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Nearest,
+        "-9.0e-13",
+        "-0x1.0E-10#1",
+        Greater,
+    );
+    test("1000.0", "0x3e8.0#10", Floor, "1.469", "0x1.780#10", Less);
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Ceiling,
+        "1.471",
+        "0x1.788#10",
+        Greater,
+    );
+    test("1000.0", "0x3e8.0#10", Down, "1.469", "0x1.780#10", Less);
+    test("1000.0", "0x3e8.0#10", Up, "1.471", "0x1.788#10", Greater);
+    test(
+        "1000.0",
+        "0x3e8.0#10",
+        Nearest,
+        "1.471",
+        "0x1.788#10",
+        Greater,
+    );
+    test("4.0e9", "0x1.0E+8#1", Floor, "0.5", "0x0.8#1", Less);
+    test("4.0e9", "0x1.0E+8#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("4.0e9", "0x1.0E+8#1", Down, "0.5", "0x0.8#1", Less);
+    test("4.0e9", "0x1.0E+8#1", Up, "1.0", "0x1.0#1", Greater);
+    test("4.0e9", "0x1.0E+8#1", Nearest, "0.5", "0x0.8#1", Less);
+    test("-4.0e9", "-0x1.0E+8#1", Floor, "-1.0", "-0x1.0#1", Less);
+    test(
+        "-4.0e9",
+        "-0x1.0E+8#1",
+        Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Greater,
+    );
+    test("-4.0e9", "-0x1.0E+8#1", Down, "-0.5", "-0x0.8#1", Greater);
+    test("-4.0e9", "-0x1.0E+8#1", Up, "-1.0", "-0x1.0#1", Less);
+    test(
+        "-4.0e9",
+        "-0x1.0E+8#1",
+        Nearest,
+        "-0.5",
+        "-0x0.8#1",
+        Greater,
+    );
+    test("3.0e120", "0x1.0E+100#1", Floor, "-0.2", "-0x0.4#1", Less);
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        Ceiling,
+        "-0.1",
+        "-0x0.2#1",
+        Greater,
+    );
+    test("3.0e120", "0x1.0E+100#1", Down, "-0.1", "-0x0.2#1", Greater);
+    test("3.0e120", "0x1.0E+100#1", Up, "-0.2", "-0x0.4#1", Less);
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        Nearest,
+        "-0.1",
+        "-0x0.2#1",
+        Greater,
+    );
+    test("1.0e1204", "0x1.0E+1000#1", Floor, "-8.0", "-0x8.0#1", Less);
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        Ceiling,
+        "-4.0",
+        "-0x4.0#1",
+        Greater,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        Down,
+        "-4.0",
+        "-0x4.0#1",
+        Greater,
+    );
+    test("1.0e1204", "0x1.0E+1000#1", Up, "-8.0", "-0x8.0#1", Less);
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        Nearest,
+        "-4.0",
+        "-0x4.0#1",
+        Greater,
+    );
+}
+
+#[test]
+fn tan_round_fail() {
+    const THREE: Float = Float::const_from_unsigned(3);
+    assert_panic!(THREE.tan_round(Exact));
+    assert_panic!(THREE.tan_round_ref(Exact));
+    assert_panic!({
+        let mut x = THREE;
+        x.tan_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_tan_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (tan, o) = x.clone().tan_prec_round(prec, rm);
+        assert!(tan.is_valid());
+
+        assert_eq!(tan.to_string(), out);
+        assert_eq!(to_hex_string(&tan), out_hex);
+        assert_eq!(o, o_out);
+
+        let (tan_alt, o_alt) = x.tan_prec_round_ref(prec, rm);
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut tan_alt = x.clone();
+        let o_alt = tan_alt.tan_prec_round_assign(prec, rm);
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_tan, rug_o) = rug_tan_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_tan)),
+                ComparableFloatRef(&tan),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, Floor, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Exact, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, Exact, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 100, Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Exact, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Exact, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 100, Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, Floor, "1.557", "0x1.8e8#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "1.559",
+        "0x1.8f0#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Down, "1.557", "0x1.8e8#10", Less);
+    test("1.0", "0x1.0#1", 10, Up, "1.559", "0x1.8f0#10", Greater);
+    test("1.0", "0x1.0#1", 10, Nearest, "1.557", "0x1.8e8#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Floor,
+        "1.557407724654902230506974807458",
+        "0x1.8eb245cbee3a5b8acc7d41322#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Ceiling,
+        "1.557407724654902230506974807459",
+        "0x1.8eb245cbee3a5b8acc7d41324#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Down,
+        "1.557407724654902230506974807458",
+        "0x1.8eb245cbee3a5b8acc7d41322#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Up,
+        "1.557407724654902230506974807459",
+        "0x1.8eb245cbee3a5b8acc7d41324#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Nearest,
+        "1.557407724654902230506974807459",
+        "0x1.8eb245cbee3a5b8acc7d41324#100",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, Floor, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "-1.0", "-0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Down, "-1.0", "-0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Up, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", 10, Floor, "-1.559", "-0x1.8f0#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "-1.557",
+        "-0x1.8e8#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Down,
+        "-1.557",
+        "-0x1.8e8#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 10, Up, "-1.559", "-0x1.8f0#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "-1.557",
+        "-0x1.8e8#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Floor,
+        "-1.557407724654902230506974807459",
+        "-0x1.8eb245cbee3a5b8acc7d41324#100",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Ceiling,
+        "-1.557407724654902230506974807458",
+        "-0x1.8eb245cbee3a5b8acc7d41322#100",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Down,
+        "-1.557407724654902230506974807458",
+        "-0x1.8eb245cbee3a5b8acc7d41322#100",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Up,
+        "-1.557407724654902230506974807459",
+        "-0x1.8eb245cbee3a5b8acc7d41324#100",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Nearest,
+        "-1.557407724654902230506974807459",
+        "-0x1.8eb245cbee3a5b8acc7d41324#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Floor,
+        "-2.0e-16",
+        "-0x1.0E-13#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Ceiling,
+        "-1.0e-16",
+        "-0x8.0E-14#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Down,
+        "-1.0e-16",
+        "-0x8.0E-14#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Up,
+        "-2.0e-16",
+        "-0x1.0E-13#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Nearest,
+        "-1.0e-16",
+        "-0x8.0E-14#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "-1.225e-16",
+        "-0x8.d4E-14#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "-1.223e-16",
+        "-0x8.d0E-14#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Down,
+        "-1.223e-16",
+        "-0x8.d0E-14#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Up,
+        "-1.225e-16",
+        "-0x8.d4E-14#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "-1.225e-16",
+        "-0x8.d4E-14#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Floor,
+        "-1.224646799147353177226065932277e-16",
+        "-0x8.d313198a2e03707344a40939E-14#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Ceiling,
+        "-1.224646799147353177226065932275e-16",
+        "-0x8.d313198a2e03707344a40938E-14#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Down,
+        "-1.224646799147353177226065932275e-16",
+        "-0x8.d313198a2e03707344a40938E-14#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Up,
+        "-1.224646799147353177226065932277e-16",
+        "-0x8.d313198a2e03707344a40939E-14#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        Nearest,
+        "-1.224646799147353177226065932275e-16",
+        "-0x8.d313198a2e03707344a40938E-14#100",
+        Greater,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        1,
+        Floor,
+        "-8.0",
+        "-0x8.0#1",
+        Less,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        1,
+        Ceiling,
+        "-4.0",
+        "-0x4.0#1",
+        Greater,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        1,
+        Down,
+        "-4.0",
+        "-0x4.0#1",
+        Greater,
+    );
+    test("1.0e1204", "0x1.0E+1000#1", 1, Up, "-8.0", "-0x8.0#1", Less);
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        1,
+        Nearest,
+        "-4.0",
+        "-0x4.0#1",
+        Greater,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        10,
+        Floor,
+        "-4.19",
+        "-0x4.30#10",
+        Less,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        10,
+        Ceiling,
+        "-4.18",
+        "-0x4.2e#10",
+        Greater,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        10,
+        Down,
+        "-4.18",
+        "-0x4.2e#10",
+        Greater,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        10,
+        Up,
+        "-4.19",
+        "-0x4.30#10",
+        Less,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        10,
+        Nearest,
+        "-4.18",
+        "-0x4.2e#10",
+        Greater,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        100,
+        Floor,
+        "-4.179688805958844052197662404338",
+        "-0x4.2e0015e90cef36f474284ac60#100",
+        Less,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        100,
+        Ceiling,
+        "-4.179688805958844052197662404332",
+        "-0x4.2e0015e90cef36f474284ac58#100",
+        Greater,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        100,
+        Down,
+        "-4.179688805958844052197662404332",
+        "-0x4.2e0015e90cef36f474284ac58#100",
+        Greater,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        100,
+        Up,
+        "-4.179688805958844052197662404338",
+        "-0x4.2e0015e90cef36f474284ac60#100",
+        Less,
+    );
+    test(
+        "1.0e1204",
+        "0x1.0E+1000#1",
+        100,
+        Nearest,
+        "-4.179688805958844052197662404338",
+        "-0x4.2e0015e90cef36f474284ac60#100",
+        Less,
+    );
+}
+
+#[test]
+fn tan_prec_round_fail() {
+    const THREE: Float = Float::const_from_unsigned(3);
+    assert_panic!(Float::one_prec(1).tan_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).tan_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.tan_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(THREE.tan_prec_round(1, Exact));
+    assert_panic!(THREE.tan_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = THREE;
+        x.tan_prec_round_assign(1, Exact)
+    });
+}
+
+fn tan_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (tan, o) = x.clone().tan_prec_round(prec, rm);
+    assert!(tan.is_valid());
+    let (tan_alt, o_alt) = x.tan_prec_round_ref(prec, rm);
+    assert!(tan_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.tan_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&tan));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_tan, rug_o) = rug_tan_prec_round(&rug::Float::exact_from(&x), prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_tan)),
+            ComparableFloatRef(&tan),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if tan.is_normal() {
+        assert_eq!(tan.get_prec(), Some(prec));
+    }
+
+    let (neg_tan, neg_o) = (-&x).tan_prec_round(prec, -rm);
+    assert_eq!(ComparableFloat(-neg_tan), ComparableFloat(tan.clone()));
+    assert_eq!(neg_o.reverse(), o);
+
+    match rm {
+        Floor => assert_ne!(o, Greater),
+        Ceiling => assert_ne!(o, Less),
+        Down => {
+            if tan.is_sign_positive() {
+                assert_ne!(o, Greater);
+            } else {
+                assert_ne!(o, Less);
+            }
+        }
+        Up => {
+            if tan.is_sign_positive() {
+                assert_ne!(o, Less);
+            } else {
+                assert_ne!(o, Greater);
+            }
+        }
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.tan_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(tan.clone()));
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.tan_prec_round(prec, Exact));
+    }
+}
+
+#[test]
+fn tan_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties(|(x, prec, rm)| {
+        tan_prec_round_properties_helper(x, prec, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties_with_config(
+        &config,
+        |(x, prec, rm)| {
+            tan_prec_round_properties_helper(x, prec, rm);
+        },
+    );
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (tan, o) = Float::NAN.tan_prec_round(prec, rm);
+        assert!(tan.is_nan());
+        assert_eq!(o, Equal);
+        let (tan, o) = Float::INFINITY.tan_prec_round(prec, rm);
+        assert!(tan.is_nan());
+        assert_eq!(o, Equal);
+        let (tan, o) = Float::NEGATIVE_INFINITY.tan_prec_round(prec, rm);
+        assert!(tan.is_nan());
+        assert_eq!(o, Equal);
+        let (tan, o) = Float::ZERO.tan_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(tan), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (tan, o) = Float::NEGATIVE_ZERO.tan_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(tan), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn tan_prec_properties_helper(x: Float, prec: u64) {
+    let (tan, o) = x.clone().tan_prec(prec);
+    assert!(tan.is_valid());
+    let (tan_alt, o_alt) = x.tan_prec_ref(prec);
+    assert!(tan_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.tan_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&tan));
+    assert_eq!(o_alt, o);
+
+    let (rug_tan, rug_o) = rug_tan_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_tan)),
+        ComparableFloatRef(&tan),
+    );
+    assert_eq!(rug_o, o);
+
+    let (tan_alt, o_alt) = x.tan_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+    assert_eq!(o_alt, o);
+
+    if tan.is_normal() {
+        assert_eq!(tan.get_prec(), Some(prec));
+    }
+
+    let (neg_tan, neg_o) = (-&x).tan_prec(prec);
+    assert_eq!(ComparableFloat(-neg_tan), ComparableFloat(tan.clone()));
+    assert_eq!(neg_o.reverse(), o);
+}
+
+#[test]
+fn tan_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        tan_prec_properties_helper(x, prec);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_pair_gen_var_1().test_properties_with_config(&config, |(x, prec)| {
+        tan_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (tan, o) = Float::NAN.tan_prec(prec);
+        assert!(tan.is_nan());
+        assert_eq!(o, Equal);
+        let (tan, o) = Float::INFINITY.tan_prec(prec);
+        assert!(tan.is_nan());
+        assert_eq!(o, Equal);
+        let (tan, o) = Float::NEGATIVE_INFINITY.tan_prec(prec);
+        assert!(tan.is_nan());
+        assert_eq!(o, Equal);
+        let (tan, o) = Float::ZERO.tan_prec(prec);
+        assert_eq!(ComparableFloat(tan), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (tan, o) = Float::NEGATIVE_ZERO.tan_prec(prec);
+        assert_eq!(ComparableFloat(tan), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn tan_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (tan, o) = x.clone().tan_round(rm);
+    assert!(tan.is_valid());
+    let (tan_alt, o_alt) = x.tan_round_ref(rm);
+    assert!(tan_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.tan_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&tan));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_tan, rug_o) = rug_tan_round(&rug::Float::exact_from(&x), rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_tan)),
+            ComparableFloatRef(&tan),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    let (tan_alt, o_alt) = x.tan_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+    assert_eq!(o_alt, o);
+
+    if tan.is_normal() {
+        assert_eq!(tan.get_prec(), Some(x.significant_bits()));
+    }
+
+    let (neg_tan, neg_o) = (-&x).tan_round(-rm);
+    assert_eq!(ComparableFloat(-neg_tan), ComparableFloat(tan.clone()));
+    assert_eq!(neg_o.reverse(), o);
+
+    match rm {
+        Floor => assert_ne!(o, Greater),
+        Ceiling => assert_ne!(o, Less),
+        Down => {
+            if tan.is_sign_positive() {
+                assert_ne!(o, Greater);
+            } else {
+                assert_ne!(o, Less);
+            }
+        }
+        Up => {
+            if tan.is_sign_positive() {
+                assert_ne!(o, Less);
+            } else {
+                assert_ne!(o, Greater);
+            }
+        }
+        Exact => assert_eq!(o, Equal),
+        _ => {}
+    }
+}
+
+#[test]
+fn tan_round_properties() {
+    float_rounding_mode_pair_gen_var_25().test_properties(|(x, rm)| {
+        tan_round_properties_helper(x, rm);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_rounding_mode_pair_gen_var_25().test_properties_with_config(&config, |(x, rm)| {
+        tan_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (tan, o) = Float::NAN.tan_round(rm);
+        assert!(tan.is_nan());
+        assert_eq!(o, Equal);
+        let (tan, o) = Float::INFINITY.tan_round(rm);
+        assert!(tan.is_nan());
+        assert_eq!(o, Equal);
+        let (tan, o) = Float::NEGATIVE_INFINITY.tan_round(rm);
+        assert!(tan.is_nan());
+        assert_eq!(o, Equal);
+        let (tan, o) = Float::ZERO.tan_round(rm);
+        assert_eq!(ComparableFloat(tan), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (tan, o) = Float::NEGATIVE_ZERO.tan_round(rm);
+        assert_eq!(ComparableFloat(tan), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn tan_properties_helper(x: Float) {
+    let tan = x.clone().tan();
+    assert!(tan.is_valid());
+
+    let tan_alt = (&x).tan();
+    assert!(tan_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+
+    let mut x_alt = x.clone();
+    x_alt.tan_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&tan));
+
+    let rug_tan = rug_tan(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_tan)),
+        ComparableFloatRef(&tan),
+    );
+
+    assert_eq!(
+        ComparableFloatRef(&x.tan_round_ref(Nearest).0),
+        ComparableFloatRef(&tan)
+    );
+}
+
+#[test]
+fn tan_properties() {
+    float_gen().test_properties(|x| {
+        tan_properties_helper(x);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_gen().test_properties_with_config(&config, |x| {
+        tan_properties_helper(x);
+    });
+
+    float_gen_var_6().test_properties(|x| {
+        tan_properties_helper(x);
+    });
+
+    float_gen_var_7().test_properties(|x| {
+        tan_properties_helper(x);
+    });
+
+    float_gen_var_8().test_properties(|x| {
+        tan_properties_helper(x);
+    });
+
+    float_gen_var_11().test_properties(|x| {
+        tan_properties_helper(x);
+    });
+}
@@ -0,0 +1,287 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::IsPowerOf2;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::test_util::generators::{
+    unsigned_gen_var_11, unsigned_rounding_mode_pair_gen_var_4,
+};
+use malachite_float::test_util::common::{rug_round_try_from_rounding_mode, to_hex_string};
+use malachite_float::test_util::constants::e::*;
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering::{self, *};
+use std::panic::catch_unwind;
+
+fn test_e_prec_helper(prec: u64, out: &str, out_hex: &str, out_o: Ordering) {
+    let (x, o) = Float::e_prec(prec);
+    assert!(x.is_valid());
+    assert_eq!(x.to_string(), out);
+    assert_eq!(to_hex_string(&x), out_hex);
+    assert_eq!(o, out_o);
+
+    let (rug_x, rug_o) = rug_e_prec(prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_x)),
+        ComparableFloatRef(&x)
+    );
+    assert_eq!(rug_o, o);
+}
+
+#[test]
+pub fn test_e_prec() {
+    test_e_prec_helper(1, "2.0", "0x2.0#1", Less);
+    test_e_prec_helper(2, "3.0", "0x3.0#2", Greater);
+    test_e_prec_helper(3, "2.5", "0x2.8#3", Less);
+    test_e_prec_helper(4, "2.8", "0x2.c#4", Greater);
+    test_e_prec_helper(5, "2.8", "0x2.c#5", Greater);
+    test_e_prec_helper(6, "2.7", "0x2.b#6", Less);
+    test_e_prec_helper(7, "2.72", "0x2.b8#7", Greater);
+    test_e_prec_helper(8, "2.72", "0x2.b8#8", Greater);
+    test_e_prec_helper(9, "2.72", "0x2.b8#9", Greater);
+    test_e_prec_helper(10, "2.719", "0x2.b8#10", Greater);
+    test_e_prec_helper(
+        100,
+        "2.718281828459045235360287471351",
+        "0x2.b7e151628aed2a6abf7158808#100",
+        Less,
+    );
+    test_e_prec_helper(
+        1000,
+        "2.7182818284590452353602874713526624977572470936999595749669676277240766303535475945713821\
+        7852516642742746639193200305992181741359662904357290033429526059563073813232862794349076323\
+        3829880753195251019011573834187930702154089149934884167509244761460668082264800168477411853\
+        7423454424371075390777449920695",
+        "0x2.b7e151628aed2a6abf7158809cf4f3c762e7160f38b4da56a784d9045190cfef324e7738926cfbe5f4bf8d\
+        8d8c31d763da06c80abb1185eb4f7c7b5757f5958490cfd47d7c19bb42158d9554f7b46bced55c4d79fd5f24d66\
+        13c31c3839a2ddf8a9a276bcfbfa1c877c56284dab79cd4c2b3293d20e9e5eaf02ac60acc#1000",
+        Less,
+    );
+
+    let e_f32 = Float::e_prec(u64::from(f32::MANTISSA_DIGITS)).0;
+    assert_eq!(e_f32.to_string(), "2.7182817");
+    assert_eq!(to_hex_string(&e_f32), "0x2.b7e150#24");
+    assert_eq!(e_f32, std::f32::consts::E);
+
+    let e_f64 = Float::e_prec(u64::from(f64::MANTISSA_DIGITS)).0;
+    assert_eq!(e_f64.to_string(), "2.7182818284590451");
+    assert_eq!(to_hex_string(&e_f64), "0x2.b7e151628aed2#53");
+    assert_eq!(e_f64, std::f64::consts::E);
+}
+
+fn test_e_prec_round_helper(
+    prec: u64,
+    rm: RoundingMode,
+    out: &str,
+    out_hex: &str,
+    out_o: Ordering,
+) {
+    let (x, o) = Float::e_prec_round(prec, rm);
+    assert!(x.is_valid());
+    assert_eq!(x.to_string(), out);
+    assert_eq!(to_hex_string(&x), out_hex);
+    assert_eq!(o, out_o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_x, rug_o) = rug_e_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_x)),
+            ComparableFloatRef(&x)
+        );
+        assert_eq!(rug_o, o);
+    }
+}
+
+#[test]
+pub fn test_e_prec_round() {
+    test_e_prec_round_helper(1, Floor, "2.0", "0x2.0#1", Less);
+    test_e_prec_round_helper(1, Ceiling, "4.0", "0x4.0#1", Greater);
+    test_e_prec_round_helper(1, Down, "2.0", "0x2.0#1", Less);
+    test_e_prec_round_helper(1, Up, "4.0", "0x4.0#1", Greater);
+    test_e_prec_round_helper(1, Nearest, "2.0", "0x2.0#1", Less);
+
+    test_e_prec_round_helper(2, Floor, "2.0", "0x2.0#2", Less);
+    test_e_prec_round_helper(2, Ceiling, "3.0", "0x3.0#2", Greater);
+    test_e_prec_round_helper(2, Down, "2.0", "0x2.0#2", Less);
+    test_e_prec_round_helper(2, Up, "3.0", "0x3.0#2", Greater);
+    test_e_prec_round_helper(2, Nearest, "3.0", "0x3.0#2", Greater);
+
+    test_e_prec_round_helper(3, Floor, "2.5", "0x2.8#3", Less);
+    test_e_prec_round_helper(3, Ceiling, "3.0", "0x3.0#3", Greater);
+    test_e_prec_round_helper(3, Down, "2.5", "0x2.8#3", Less);
+    test_e_prec_round_helper(3, Up, "3.0", "0x3.0#3", Greater);
+    test_e_prec_round_helper(3, Nearest, "2.5", "0x2.8#3", Less);
+
+    test_e_prec_round_helper(4, Floor, "2.5", "0x2.8#4", Less);
+    test_e_prec_round_helper(4, Ceiling, "2.8", "0x2.c#4", Greater);
+    test_e_prec_round_helper(4, Down, "2.5", "0x2.8#4", Less);
+    test_e_prec_round_helper(4, Up, "2.8", "0x2.c#4", Greater);
+    test_e_prec_round_helper(4, Nearest, "2.8", "0x2.c#4", Greater);
+
+    test_e_prec_round_helper(5, Floor, "2.6", "0x2.a#5", Less);
+    test_e_prec_round_helper(5, Ceiling, "2.8", "0x2.c#5", Greater);
+    test_e_prec_round_helper(5, Down, "2.6", "0x2.a#5", Less);
+    test_e_prec_round_helper(5, Up, "2.8", "0x2.c#5", Greater);
+    test_e_prec_round_helper(5, Nearest, "2.8", "0x2.c#5", Greater);
+
+    test_e_prec_round_helper(6, Floor, "2.7", "0x2.b#6", Less);
+    test_e_prec_round_helper(6, Ceiling, "2.75", "0x2.c#6", Greater);
+    test_e_prec_round_helper(6, Down, "2.7", "0x2.b#6", Less);
+    test_e_prec_round_helper(6, Up, "2.75", "0x2.c#6", Greater);
+    test_e_prec_round_helper(6, Nearest, "2.7", "0x2.b#6", Less);
+
+    test_e_prec_round_helper(7, Floor, "2.69", "0x2.b0#7", Less);
+    test_e_prec_round_helper(7, Ceiling, "2.72", "0x2.b8#7", Greater);
+    test_e_prec_round_helper(7, Down, "2.69", "0x2.b0#7", Less);
+    test_e_prec_round_helper(7, Up, "2.72", "0x2.b8#7", Greater);
+    test_e_prec_round_helper(7, Nearest, "2.72", "0x2.b8#7", Greater);
+
+    test_e_prec_round_helper(8, Floor, "2.7", "0x2.b4#8", Less);
+    test_e_prec_round_helper(8, Ceiling, "2.72", "0x2.b8#8", Greater);
+    test_e_prec_round_helper(8, Down, "2.7", "0x2.b4#8", Less);
+    test_e_prec_round_helper(8, Up, "2.72", "0x2.b8#8", Greater);
+    test_e_prec_round_helper(8, Nearest, "2.72", "0x2.b8#8", Greater);
+
+    test_e_prec_round_helper(9, Floor, "2.71", "0x2.b6#9", Less);
+    test_e_prec_round_helper(9, Ceiling, "2.72", "0x2.b8#9", Greater);
+    test_e_prec_round_helper(9, Down, "2.71", "0x2.b6#9", Less);
+    test_e_prec_round_helper(9, Up, "2.72", "0x2.b8#9", Greater);
+    test_e_prec_round_helper(9, Nearest, "2.72", "0x2.b8#9", Greater);
+
+    test_e_prec_round_helper(10, Floor, "2.715", "0x2.b7#10", Less);
+    test_e_prec_round_helper(10, Ceiling, "2.719", "0x2.b8#10", Greater);
+    test_e_prec_round_helper(10, Down, "2.715", "0x2.b7#10", Less);
+    test_e_prec_round_helper(10, Up, "2.719", "0x2.b8#10", Greater);
+    test_e_prec_round_helper(10, Nearest, "2.719", "0x2.b8#10", Greater);
+
+    test_e_prec_round_helper(
+        100,
+        Floor,
+        "2.718281828459045235360287471351",
+        "0x2.b7e151628aed2a6abf7158808#100",
+        Less,
+    );
+    test_e_prec_round_helper(
+        100,
+        Ceiling,
+        "2.718281828459045235360287471354",
+        "0x2.b7e151628aed2a6abf715880c#100",
+        Greater,
+    );
+    test_e_prec_round_helper(
+        100,
+        Down,
+        "2.718281828459045235360287471351",
+        "0x2.b7e151628aed2a6abf7158808#100",
+        Less,
+    );
+    test_e_prec_round_helper(
+        100,
+        Up,
+        "2.718281828459045235360287471354",
+        "0x2.b7e151628aed2a6abf715880c#100",
+        Greater,
+    );
+    test_e_prec_round_helper(
+        100,
+        Nearest,
+        "2.718281828459045235360287471351",
+        "0x2.b7e151628aed2a6abf7158808#100",
+        Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn e_prec_round_fail_1() {
+    Float::e_prec_round(0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn e_prec_round_fail_2() {
+    Float::e_prec_round(1, Exact);
+}
+
+#[test]
+#[should_panic]
+fn e_prec_round_fail_3() {
+    Float::e_prec_round(1000, Exact);
+}
+
+#[test]
+fn e_prec_properties() {
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (e, o) = Float::e_prec(prec);
+        assert!(e.is_valid());
+        assert_eq!(e.get_prec(), Some(prec));
+        assert_ne!(o, Equal);
+        if o == Less {
+            let (e_alt, o_alt) = Float::e_prec_round(prec, Ceiling);
+            let mut next_upper = e.clone();
+            next_upper.increment();
+            if !next_upper.is_power_of_2() {
+                assert_eq!(ComparableFloat(e_alt), ComparableFloat(next_upper));
+                assert_eq!(o_alt, Greater);
+            }
+        } else if !e.is_power_of_2() {
+            let (e_alt, o_alt) = Float::e_prec_round(prec, Floor);
+            let mut next_lower = e.clone();
+            next_lower.decrement();
+            assert_eq!(ComparableFloat(e_alt), ComparableFloat(next_lower));
+            assert_eq!(o_alt, Less);
+        }
+        let (e_alt, o_alt) = Float::e_prec_round(prec, Nearest);
+        assert_eq!(ComparableFloatRef(&e_alt), ComparableFloatRef(&e));
+        assert_eq!(o_alt, o);
+
+        let (rug_e, rug_o) = rug_e_prec(prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_e)),
+            ComparableFloatRef(&e)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn e_prec_round_properties() {
+    unsigned_rounding_mode_pair_gen_var_4().test_properties(|(prec, rm)| {
+        let (e, o) = Float::e_prec_round(prec, rm);
+        assert!(e.is_valid());
+        assert_eq!(e.get_prec(), Some(prec));
+        assert_ne!(o, Equal);
+        if o == Less {
+            let (e_alt, o_alt) = Float::e_prec_round(prec, Ceiling);
+            let mut next_upper = e.clone();
+            next_upper.increment();
+            if !next_upper.is_power_of_2() {
+                assert_eq!(ComparableFloat(e_alt), ComparableFloat(next_upper));
+                assert_eq!(o_alt, Greater);
+            }
+        } else if !e.is_power_of_2() {
+            let (e_alt, o_alt) = Float::e_prec_round(prec, Floor);
+            let mut next_lower = e.clone();
+            next_lower.decrement();
+            assert_eq!(ComparableFloat(e_alt), ComparableFloat(next_lower));
+            assert_eq!(o_alt, Less);
+        }
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_e, rug_o) = rug_e_prec_round(prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_e)),
+                ComparableFloatRef(&e)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        assert_panic!(Float::e_prec_round(prec, Exact));
+    });
+}
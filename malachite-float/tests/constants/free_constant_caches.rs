@@ -0,0 +1,80 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::test_util::generators::unsigned_gen_var_11;
+use malachite_float::constants::{FixedPointCache, free_constant_caches};
+use malachite_float::{ComparableFloat, Float};
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+fn constants(prec: u64) -> [(ComparableFloat, Ordering); 5] {
+    [
+        Float::catalan_prec(prec),
+        Float::e_prec(prec),
+        Float::euler_gamma_prec(prec),
+        Float::ln_2_prec(prec),
+        Float::pi_prec(prec),
+    ]
+    .map(|(x, o)| (ComparableFloat(x), o))
+}
+
+#[test]
+fn test_free_constant_caches() {
+    let high = constants(1000);
+    let low = constants(100);
+    free_constant_caches();
+    // Computing at a low precision first, then a high one, exercises both a cache miss after a hit
+    // and a cache that grows.
+    assert_eq!(constants(100), low);
+    assert_eq!(constants(1000), high);
+    free_constant_caches();
+    assert_eq!(constants(1000), high);
+    free_constant_caches();
+    free_constant_caches();
+    assert_eq!(constants(100), low);
+}
+
+#[test]
+fn test_fixed_point_cache() {
+    let cache = FixedPointCache::new();
+    let mut evaluations = 0;
+    let mut get = |cache: &FixedPointCache, scale| {
+        cache.get(scale, |scale| {
+            evaluations += 1;
+            Natural::from(0xabcdu32) << (scale - 16)
+        })
+    };
+    assert_eq!(get(&cache, 100), Natural::from(0xabcdu32) << 84);
+    // A second call at the same scale, or a smaller one, hits the cache
+    assert_eq!(get(&cache, 100), Natural::from(0xabcdu32) << 84);
+    assert_eq!(get(&cache, 20), Natural::from(0xabcdu32) << 4);
+    // A larger scale misses
+    assert_eq!(get(&cache, 200), Natural::from(0xabcdu32) << 184);
+    assert_eq!(get(&cache, 150), Natural::from(0xabcdu32) << 134);
+    cache.clear();
+    assert_eq!(get(&cache, 150), Natural::from(0xabcdu32) << 134);
+    assert_eq!(evaluations, 3);
+
+    // A panicking evaluation doesn't leave the cache locked
+    let cache = FixedPointCache::new();
+    assert!(catch_unwind(AssertUnwindSafe(|| cache.get(10, |_| panic!()))).is_err());
+    cache.get(10, |_| Natural::ZERO);
+    assert_eq!(cache.get(10, |_| unreachable!()), Natural::ZERO);
+    cache.clear();
+}
+
+#[test]
+fn free_constant_caches_properties() {
+    unsigned_gen_var_11().test_properties(|prec| {
+        let xs = constants(prec);
+        free_constant_caches();
+        assert_eq!(constants(prec), xs);
+    });
+}
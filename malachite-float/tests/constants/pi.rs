@@ -0,0 +1,287 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::IsPowerOf2;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::test_util::generators::{
+    unsigned_gen_var_11, unsigned_rounding_mode_pair_gen_var_4,
+};
+use malachite_float::test_util::common::{rug_round_try_from_rounding_mode, to_hex_string};
+use malachite_float::test_util::constants::pi::*;
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering::{self, *};
+use std::panic::catch_unwind;
+
+fn test_pi_prec_helper(prec: u64, out: &str, out_hex: &str, out_o: Ordering) {
+    let (x, o) = Float::pi_prec(prec);
+    assert!(x.is_valid());
+    assert_eq!(x.to_string(), out);
+    assert_eq!(to_hex_string(&x), out_hex);
+    assert_eq!(o, out_o);
+
+    let (rug_x, rug_o) = rug_pi_prec(prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_x)),
+        ComparableFloatRef(&x)
+    );
+    assert_eq!(rug_o, o);
+}
+
+#[test]
+pub fn test_pi_prec() {
+    test_pi_prec_helper(1, "4.0", "0x4.0#1", Greater);
+    test_pi_prec_helper(2, "3.0", "0x3.0#2", Less);
+    test_pi_prec_helper(3, "3.0", "0x3.0#3", Less);
+    test_pi_prec_helper(4, "3.2", "0x3.4#4", Greater);
+    test_pi_prec_helper(5, "3.1", "0x3.2#5", Less);
+    test_pi_prec_helper(6, "3.12", "0x3.2#6", Less);
+    test_pi_prec_helper(7, "3.16", "0x3.28#7", Greater);
+    test_pi_prec_helper(8, "3.14", "0x3.24#8", Less);
+    test_pi_prec_helper(9, "3.14", "0x3.24#9", Less);
+    test_pi_prec_helper(10, "3.141", "0x3.24#10", Less);
+    test_pi_prec_helper(
+        100,
+        "3.141592653589793238462643383279",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Less,
+    );
+    test_pi_prec_helper(
+        1000,
+        "3.1415926535897932384626433832795028841971693993751058209749445923078164062862089986280348\
+        2534211706798214808651328230664709384460955058223172535940812848111745028410270193852110555\
+        9644622948954930381964428810975665933446128475648233786783165271201909145648566923460348610\
+        4543266482133936072602491412736",
+        "0x3.243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89452821e638d01377be5466\
+        cf34e90c6cc0ac29b7c97c50dd3f84d5b5b54709179216d5d98979fb1bd1310ba698dfb5ac2ffd72dbd01adfb7b\
+        8e1afed6a267e96ba7c9045f12c7f9924a19947b3916cf70801f2e2858efc16636920d870#1000",
+        Less,
+    );
+
+    let pi_f32 = Float::pi_prec(u64::from(f32::MANTISSA_DIGITS)).0;
+    assert_eq!(pi_f32.to_string(), "3.1415927");
+    assert_eq!(to_hex_string(&pi_f32), "0x3.243f6c#24");
+    assert_eq!(pi_f32, std::f32::consts::PI);
+
+    let pi_f64 = Float::pi_prec(u64::from(f64::MANTISSA_DIGITS)).0;
+    assert_eq!(pi_f64.to_string(), "3.1415926535897931");
+    assert_eq!(to_hex_string(&pi_f64), "0x3.243f6a8885a30#53");
+    assert_eq!(pi_f64, std::f64::consts::PI);
+}
+
+fn test_pi_prec_round_helper(
+    prec: u64,
+    rm: RoundingMode,
+    out: &str,
+    out_hex: &str,
+    out_o: Ordering,
+) {
+    let (x, o) = Float::pi_prec_round(prec, rm);
+    assert!(x.is_valid());
+    assert_eq!(x.to_string(), out);
+    assert_eq!(to_hex_string(&x), out_hex);
+    assert_eq!(o, out_o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_x, rug_o) = rug_pi_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_x)),
+            ComparableFloatRef(&x)
+        );
+        assert_eq!(rug_o, o);
+    }
+}
+
+#[test]
+pub fn test_pi_prec_round() {
+    test_pi_prec_round_helper(1, Floor, "2.0", "0x2.0#1", Less);
+    test_pi_prec_round_helper(1, Ceiling, "4.0", "0x4.0#1", Greater);
+    test_pi_prec_round_helper(1, Down, "2.0", "0x2.0#1", Less);
+    test_pi_prec_round_helper(1, Up, "4.0", "0x4.0#1", Greater);
+    test_pi_prec_round_helper(1, Nearest, "4.0", "0x4.0#1", Greater);
+
+    test_pi_prec_round_helper(2, Floor, "3.0", "0x3.0#2", Less);
+    test_pi_prec_round_helper(2, Ceiling, "4.0", "0x4.0#2", Greater);
+    test_pi_prec_round_helper(2, Down, "3.0", "0x3.0#2", Less);
+    test_pi_prec_round_helper(2, Up, "4.0", "0x4.0#2", Greater);
+    test_pi_prec_round_helper(2, Nearest, "3.0", "0x3.0#2", Less);
+
+    test_pi_prec_round_helper(3, Floor, "3.0", "0x3.0#3", Less);
+    test_pi_prec_round_helper(3, Ceiling, "3.5", "0x3.8#3", Greater);
+    test_pi_prec_round_helper(3, Down, "3.0", "0x3.0#3", Less);
+    test_pi_prec_round_helper(3, Up, "3.5", "0x3.8#3", Greater);
+    test_pi_prec_round_helper(3, Nearest, "3.0", "0x3.0#3", Less);
+
+    test_pi_prec_round_helper(4, Floor, "3.0", "0x3.0#4", Less);
+    test_pi_prec_round_helper(4, Ceiling, "3.2", "0x3.4#4", Greater);
+    test_pi_prec_round_helper(4, Down, "3.0", "0x3.0#4", Less);
+    test_pi_prec_round_helper(4, Up, "3.2", "0x3.4#4", Greater);
+    test_pi_prec_round_helper(4, Nearest, "3.2", "0x3.4#4", Greater);
+
+    test_pi_prec_round_helper(5, Floor, "3.1", "0x3.2#5", Less);
+    test_pi_prec_round_helper(5, Ceiling, "3.2", "0x3.4#5", Greater);
+    test_pi_prec_round_helper(5, Down, "3.1", "0x3.2#5", Less);
+    test_pi_prec_round_helper(5, Up, "3.2", "0x3.4#5", Greater);
+    test_pi_prec_round_helper(5, Nearest, "3.1", "0x3.2#5", Less);
+
+    test_pi_prec_round_helper(6, Floor, "3.12", "0x3.2#6", Less);
+    test_pi_prec_round_helper(6, Ceiling, "3.19", "0x3.3#6", Greater);
+    test_pi_prec_round_helper(6, Down, "3.12", "0x3.2#6", Less);
+    test_pi_prec_round_helper(6, Up, "3.19", "0x3.3#6", Greater);
+    test_pi_prec_round_helper(6, Nearest, "3.12", "0x3.2#6", Less);
+
+    test_pi_prec_round_helper(7, Floor, "3.12", "0x3.20#7", Less);
+    test_pi_prec_round_helper(7, Ceiling, "3.16", "0x3.28#7", Greater);
+    test_pi_prec_round_helper(7, Down, "3.12", "0x3.20#7", Less);
+    test_pi_prec_round_helper(7, Up, "3.16", "0x3.28#7", Greater);
+    test_pi_prec_round_helper(7, Nearest, "3.16", "0x3.28#7", Greater);
+
+    test_pi_prec_round_helper(8, Floor, "3.14", "0x3.24#8", Less);
+    test_pi_prec_round_helper(8, Ceiling, "3.16", "0x3.28#8", Greater);
+    test_pi_prec_round_helper(8, Down, "3.14", "0x3.24#8", Less);
+    test_pi_prec_round_helper(8, Up, "3.16", "0x3.28#8", Greater);
+    test_pi_prec_round_helper(8, Nearest, "3.14", "0x3.24#8", Less);
+
+    test_pi_prec_round_helper(9, Floor, "3.14", "0x3.24#9", Less);
+    test_pi_prec_round_helper(9, Ceiling, "3.15", "0x3.26#9", Greater);
+    test_pi_prec_round_helper(9, Down, "3.14", "0x3.24#9", Less);
+    test_pi_prec_round_helper(9, Up, "3.15", "0x3.26#9", Greater);
+    test_pi_prec_round_helper(9, Nearest, "3.14", "0x3.24#9", Less);
+
+    test_pi_prec_round_helper(10, Floor, "3.141", "0x3.24#10", Less);
+    test_pi_prec_round_helper(10, Ceiling, "3.145", "0x3.25#10", Greater);
+    test_pi_prec_round_helper(10, Down, "3.141", "0x3.24#10", Less);
+    test_pi_prec_round_helper(10, Up, "3.145", "0x3.25#10", Greater);
+    test_pi_prec_round_helper(10, Nearest, "3.141", "0x3.24#10", Less);
+
+    test_pi_prec_round_helper(
+        100,
+        Floor,
+        "3.141592653589793238462643383279",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Less,
+    );
+    test_pi_prec_round_helper(
+        100,
+        Ceiling,
+        "3.141592653589793238462643383282",
+        "0x3.243f6a8885a308d313198a2e4#100",
+        Greater,
+    );
+    test_pi_prec_round_helper(
+        100,
+        Down,
+        "3.141592653589793238462643383279",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Less,
+    );
+    test_pi_prec_round_helper(
+        100,
+        Up,
+        "3.141592653589793238462643383282",
+        "0x3.243f6a8885a308d313198a2e4#100",
+        Greater,
+    );
+    test_pi_prec_round_helper(
+        100,
+        Nearest,
+        "3.141592653589793238462643383279",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn pi_prec_round_fail_1() {
+    Float::pi_prec_round(0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn pi_prec_round_fail_2() {
+    Float::pi_prec_round(1, Exact);
+}
+
+#[test]
+#[should_panic]
+fn pi_prec_round_fail_3() {
+    Float::pi_prec_round(1000, Exact);
+}
+
+#[test]
+fn pi_prec_properties() {
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (pi, o) = Float::pi_prec(prec);
+        assert!(pi.is_valid());
+        assert_eq!(pi.get_prec(), Some(prec));
+        assert_ne!(o, Equal);
+        if o == Less {
+            let (pi_alt, o_alt) = Float::pi_prec_round(prec, Ceiling);
+            let mut next_upper = pi.clone();
+            next_upper.increment();
+            if !next_upper.is_power_of_2() {
+                assert_eq!(ComparableFloat(pi_alt), ComparableFloat(next_upper));
+                assert_eq!(o_alt, Greater);
+            }
+        } else if !pi.is_power_of_2() {
+            let (pi_alt, o_alt) = Float::pi_prec_round(prec, Floor);
+            let mut next_lower = pi.clone();
+            next_lower.decrement();
+            assert_eq!(ComparableFloat(pi_alt), ComparableFloat(next_lower));
+            assert_eq!(o_alt, Less);
+        }
+        let (pi_alt, o_alt) = Float::pi_prec_round(prec, Nearest);
+        assert_eq!(ComparableFloatRef(&pi_alt), ComparableFloatRef(&pi));
+        assert_eq!(o_alt, o);
+
+        let (rug_pi, rug_o) = rug_pi_prec(prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_pi)),
+            ComparableFloatRef(&pi)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn pi_prec_round_properties() {
+    unsigned_rounding_mode_pair_gen_var_4().test_properties(|(prec, rm)| {
+        let (pi, o) = Float::pi_prec_round(prec, rm);
+        assert!(pi.is_valid());
+        assert_eq!(pi.get_prec(), Some(prec));
+        assert_ne!(o, Equal);
+        if o == Less {
+            let (pi_alt, o_alt) = Float::pi_prec_round(prec, Ceiling);
+            let mut next_upper = pi.clone();
+            next_upper.increment();
+            if !next_upper.is_power_of_2() {
+                assert_eq!(ComparableFloat(pi_alt), ComparableFloat(next_upper));
+                assert_eq!(o_alt, Greater);
+            }
+        } else if !pi.is_power_of_2() {
+            let (pi_alt, o_alt) = Float::pi_prec_round(prec, Floor);
+            let mut next_lower = pi.clone();
+            next_lower.decrement();
+            assert_eq!(ComparableFloat(pi_alt), ComparableFloat(next_lower));
+            assert_eq!(o_alt, Less);
+        }
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_pi, rug_o) = rug_pi_prec_round(prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_pi)),
+                ComparableFloatRef(&pi)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        assert_panic!(Float::pi_prec_round(prec, Exact));
+    });
+}
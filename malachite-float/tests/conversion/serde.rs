@@ -0,0 +1,169 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::strings::string_is_subset;
+use malachite_base::test_util::generators::string_gen;
+use malachite_float::conversion::serde::{CompactFloat, CompactFloatRef};
+use malachite_float::test_util::common::parse_hex_string;
+use malachite_float::test_util::generators::float_gen;
+use malachite_float::{ComparableFloat, Float};
+
+#[test]
+fn test_serde() {
+    let test = |s_hex, out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(serde_json::to_string(&x).unwrap(), out);
+        assert_eq!(
+            ComparableFloat(serde_json::from_str::<Float>(out).unwrap()),
+            ComparableFloat(x)
+        );
+    };
+    test("NaN", "\"NaN\"");
+    test("Infinity", "\"Infinity\"");
+    test("-Infinity", "\"-Infinity\"");
+    test("0x0.0", "\"0x0.0\"");
+    test("-0x0.0", "\"-0x0.0\"");
+
+    test("0x1.0#1", "\"0x1.0#1\"");
+    test(
+        "0x1.0000000000000000000000000#100",
+        "\"0x1.0000000000000000000000000#100\"",
+    );
+    test("0x0.8#1", "\"0x0.8#1\"");
+    test("0x0.55555555555554#53", "\"0x0.55555555555554#53\"");
+    test("0x1.6a09e667f3bcd#53", "\"0x1.6a09e667f3bcd#53\"");
+    test("0x1.0E+250#1", "\"0x1.0E+250#1\"");
+    test("0x1.0E-250#1", "\"0x1.0E-250#1\"");
+    test("0x4.0E+268435455#1", "\"0x4.0E+268435455#1\"");
+    test("0x2.0E-268435456#1", "\"0x2.0E-268435456#1\"");
+    test("0x1.0E-268435456#1", "\"0x1.0E-268435456#1\"");
+
+    test("-0x1.0#1", "\"-0x1.0#1\"");
+    test(
+        "-0x1.0000000000000000000000000#100",
+        "\"-0x1.0000000000000000000000000#100\"",
+    );
+    test("-0x0.55555555555554#53", "\"-0x0.55555555555554#53\"");
+    test("-0x1.0E+250#1", "\"-0x1.0E+250#1\"");
+}
+
+#[test]
+fn test_serde_fail() {
+    let test = |s| {
+        assert!(serde_json::from_str::<Float>(s).is_err());
+    };
+    test("\"\"");
+    test("\"nan\"");
+    test("\"1.0\"");
+    test("\"0x1.0\"");
+    test("\"0x1.0#\"");
+    test("\"0x1.0#0\"");
+    test("\"0x1.0#-1\"");
+    test("\"0xg.0#1\"");
+    test("\"0x1.8#1\"");
+    test("\"0x1.0#1000000000000\"");
+    test("\"0x1.0E+#1\"");
+    test("\"0x1.0E+2147483647#1\"");
+    test("\"0x8.0E+268435455#1\"");
+    test("\"0x1.0E+268435456#1\"");
+    test("\"-0x1.0E+268435456#1\"");
+    test("\"0x0.8E-268435456#1\"");
+    test("\"0x1.0E-268435460#1\"");
+    test("\"-0x1.0E-268435460#1\"");
+    test("\"--0x1.0#1\"");
+    test("1.0");
+    test("null");
+}
+
+#[test]
+fn serde_properties() {
+    float_gen().test_properties(|x| {
+        let s = serde_json::to_string(&x).unwrap();
+        assert_eq!(
+            ComparableFloat(serde_json::from_str::<Float>(&s).unwrap()),
+            ComparableFloat(x)
+        );
+        assert!(string_is_subset(&s, "\"#+-.0123456789EINabcdefintxy"));
+    });
+
+    string_gen().test_properties(|s| {
+        let _n: Result<Float, _> = serde_json::from_str(&s);
+    });
+}
+
+#[test]
+fn test_compact_serde() {
+    let test = |s_hex, out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(serde_json::to_string(&CompactFloatRef(&x)).unwrap(), out);
+        assert_eq!(
+            serde_json::to_string(&CompactFloat(x.clone())).unwrap(),
+            out
+        );
+        assert_eq!(
+            ComparableFloat(serde_json::from_str::<CompactFloat>(out).unwrap().0),
+            ComparableFloat(x)
+        );
+    };
+    test("NaN", "\"NaN\"");
+    test("Infinity", "{\"Infinity\":true}");
+    test("-Infinity", "{\"Infinity\":false}");
+    test("0x0.0", "{\"Zero\":true}");
+    test("-0x0.0", "{\"Zero\":false}");
+
+    test("0x1.0#1", "{\"Finite\":[true,1,1,[1]]}");
+    test(
+        "0x1.0000000000000000000000000#100",
+        "{\"Finite\":[true,1,100,[0,0,0,0,0,0,0,0,0,0,0,0,8]]}",
+    );
+    test("0x0.8#1", "{\"Finite\":[true,0,1,[1]]}");
+    test(
+        "0x1.6a09e667f3bcd#53",
+        "{\"Finite\":[true,1,53,[205,59,127,102,158,160,22]]}",
+    );
+    test("0x1.0E+250#1", "{\"Finite\":[true,1001,1,[1]]}");
+    test(
+        "0x2.0E-268435456#1",
+        "{\"Finite\":[true,-1073741822,1,[1]]}",
+    );
+    test("-0x1.0#1", "{\"Finite\":[false,1,1,[1]]}");
+    test("-0x1.8#2", "{\"Finite\":[false,1,2,[3]]}");
+}
+
+#[test]
+fn test_compact_serde_fail() {
+    let test = |s| {
+        assert!(serde_json::from_str::<CompactFloat>(s).is_err());
+    };
+    test("\"nan\"");
+    test("{\"Finite\":[true,1,0,[]]}");
+    test("{\"Finite\":[true,1,1,[]]}");
+    test("{\"Finite\":[true,1,1,[2]]}");
+    test("{\"Finite\":[true,1,2,[1]]}");
+    test("{\"Finite\":[true,1,1,[1,0]]}");
+    test("{\"Finite\":[true,1073741824,1,[1]]}");
+    test("{\"Finite\":[true,-1073741824,1,[1]]}");
+    test("{\"Zero\":null}");
+    test("\"0x1.0#1\"");
+    test("null");
+}
+
+#[test]
+fn compact_serde_properties() {
+    float_gen().test_properties(|x| {
+        let s = serde_json::to_string(&CompactFloatRef(&x)).unwrap();
+        let y = serde_json::from_str::<CompactFloat>(&s).unwrap().0;
+        assert!(y.is_valid());
+        assert_eq!(ComparableFloat(y), ComparableFloat(x));
+        assert!(string_is_subset(&s, "\"-,0123456789:FINZaefilnorstuy[]{}"));
+    });
+
+    string_gen().test_properties(|s| {
+        let _n: Result<CompactFloat, _> = serde_json::from_str(&s);
+    });
+}
@@ -62,20 +62,38 @@ extern crate malachite_base;
 
 pub mod arithmetic {
     pub mod abs;
+    pub mod acos;
     pub mod add;
+    pub mod asin;
+    pub mod atan;
+    pub mod atan2;
+    pub mod cos;
     pub mod div;
+    pub mod exp;
+    pub mod exp10;
+    pub mod exp2;
+    pub mod expm1;
     pub mod is_power_of_2;
+    pub mod ln;
+    pub mod ln1p;
+    pub mod log10;
+    pub mod log2;
     pub mod mul;
     pub mod neg;
     pub mod power_of_2;
     pub mod reciprocal;
+    pub mod reciprocal_sqrt;
     pub mod shl;
     pub mod shl_round;
     pub mod shr;
     pub mod shr_round;
     pub mod sign;
+    pub mod sin;
+    pub mod sin_cos;
+    pub mod sqrt;
     pub mod square;
     pub mod sub;
+    pub mod tan;
 }
 pub mod basic {
     pub mod classification;
@@ -108,6 +126,12 @@ pub mod comparison {
     pub mod partial_eq_rational;
 }
 pub mod constants {
+    pub mod catalan;
+    pub mod e;
+    pub mod euler_gamma;
+    pub mod free_constant_caches;
+    pub mod ln_2;
+    pub mod pi;
     pub mod prime_constant;
     pub mod thue_morse_constant;
 }
@@ -125,6 +149,7 @@ pub mod conversion {
     pub mod primitive_float_from_float;
     pub mod primitive_int_from_float;
     pub mod rational_from_float;
+    pub mod serde;
 }
 pub mod exhaustive {
     pub mod exhaustive_finite_floats;
@@ -150,4 +175,5 @@ pub mod random {
     pub mod striped_random_non_positive_finite_floats;
     pub mod striped_random_nonzero_finite_floats;
     pub mod striped_random_positive_finite_floats;
+    pub mod uniform_float;
 }
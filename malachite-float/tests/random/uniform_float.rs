@@ -0,0 +1,144 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use itertools::Itertools;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::RoundingFrom;
+use malachite_base::random::EXAMPLE_SEED;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_float::random::UniformFloat;
+use malachite_float::{ComparableFloat, Float};
+use rand::Rng;
+
+fn uniform_float_helper(prec: u64, rm: RoundingMode, expected_values: &[&str]) {
+    let d = UniformFloat::new_round(prec, rm);
+    let xs = EXAMPLE_SEED
+        .get_rng()
+        .sample_iter(d)
+        .take(10)
+        .map(|x| ComparableFloat(x).to_string())
+        .collect_vec();
+    assert_eq!(xs, expected_values);
+    let mut rng = EXAMPLE_SEED.get_rng();
+    for _ in 0..1000 {
+        let x = rng.sample(d);
+        assert!(x.is_valid());
+        assert_eq!(x.get_prec(), Some(prec));
+        assert!(x > Float::ZERO);
+        assert!(x <= Float::ONE);
+    }
+}
+
+#[test]
+fn test_uniform_float() {
+    // The number after the '#' is the precision.
+    uniform_float_helper(
+        1,
+        Nearest,
+        &["0.5#1", "0.5#1", "1.0#1", "1.0#1", "1.0#1", "0.1#1", "0.2#1", "0.2#1", "0.5#1", "0.5#1"],
+    );
+    uniform_float_helper(
+        10,
+        Nearest,
+        &[
+            "0.585#10",
+            "0.4492#10",
+            "0.612#10",
+            "0.984#10",
+            "0.652#10",
+            "0.2383#10",
+            "0.1826#10",
+            "0.1506#10",
+            "0.3252#10",
+            "0.3633#10",
+        ],
+    );
+    uniform_float_helper(
+        10,
+        Floor,
+        &[
+            "0.585#10",
+            "0.614#10",
+            "0.978#10",
+            "0.852#10",
+            "0.9795#10",
+            "0.651#10",
+            "0.2102#10",
+            "0.1772#10",
+            "0.626#10",
+            "0.3159#10",
+        ],
+    );
+    uniform_float_helper(
+        10,
+        Ceiling,
+        &[
+            "0.586#10",
+            "0.615#10",
+            "0.9785#10",
+            "0.853#10",
+            "0.98#10",
+            "0.652#10",
+            "0.2104#10",
+            "0.1775#10",
+            "0.627#10",
+            "0.3164#10",
+        ],
+    );
+}
+
+#[test]
+fn test_uniform_float_rounding() {
+    for prec in [1, 2, 10, 64, 100, 1000] {
+        let mut rng_floor = EXAMPLE_SEED.get_rng();
+        let mut rng_down = EXAMPLE_SEED.get_rng();
+        let mut rng_ceiling = EXAMPLE_SEED.get_rng();
+        let mut rng_up = EXAMPLE_SEED.get_rng();
+        for _ in 0..100 {
+            // Floor and Ceiling results are adjacent, since the rounded real is never exact
+            let x = rng_floor.sample(UniformFloat::new_round(prec, Floor));
+            let y = rng_ceiling.sample(UniformFloat::new_round(prec, Ceiling));
+            assert!(x < 1u32);
+            assert_eq!(
+                ComparableFloat(x.clone()),
+                ComparableFloat(rng_down.sample(UniformFloat::new_round(prec, Down)))
+            );
+            assert_eq!(
+                ComparableFloat(y.clone()),
+                ComparableFloat(rng_up.sample(UniformFloat::new_round(prec, Up)))
+            );
+            let mut x_plus = x;
+            x_plus.increment();
+            assert_eq!(x_plus, y);
+        }
+    }
+}
+
+#[test]
+fn test_uniform_float_mean() {
+    // Rounding to nearest doesn't change the mean by much
+    let mut rng = EXAMPLE_SEED.get_rng();
+    let mut sum = 0.0;
+    for _ in 0..10000 {
+        sum += f64::rounding_from(&rng.sample(UniformFloat::new(10)), Nearest).0;
+    }
+    let mean = sum / 10000.0;
+    assert!((mean - 0.5).abs() < 0.01);
+}
+
+#[test]
+#[should_panic]
+fn uniform_float_fail_1() {
+    UniformFloat::new(0);
+}
+
+#[test]
+#[should_panic]
+fn uniform_float_fail_2() {
+    UniformFloat::new_round(10, Exact);
+}
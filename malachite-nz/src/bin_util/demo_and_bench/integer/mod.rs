@@ -13,6 +13,7 @@ pub(crate) fn register(runner: &mut Runner) {
     basic::register(runner);
     comparison::register(runner);
     conversion::register(runner);
+    factorization::register(runner);
     logic::register(runner);
 }
 
@@ -20,4 +21,5 @@ mod arithmetic;
 mod basic;
 mod comparison;
 mod conversion;
+mod factorization;
 mod logic;
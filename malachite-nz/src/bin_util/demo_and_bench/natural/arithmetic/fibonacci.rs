@@ -0,0 +1,150 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Fibonacci, Lucas};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::test_util::bench::bucketers::unsigned_direct_bucketer;
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::natural::arithmetic::fibonacci::{fibonacci_naive, lucas_naive};
+use rug::Complete;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_fibonacci);
+    register_demo!(runner, demo_fibonacci2);
+    register_demo!(runner, demo_lucas);
+
+    register_bench!(runner, benchmark_fibonacci_algorithms);
+    register_bench!(runner, benchmark_fibonacci_library_comparison);
+    register_bench!(runner, benchmark_fibonacci2_library_comparison);
+    register_bench!(runner, benchmark_lucas_algorithms);
+    register_bench!(runner, benchmark_lucas_library_comparison);
+}
+
+fn demo_fibonacci(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5().get(gm, config).take(limit) {
+        println!("F_{} = {}", n, Natural::fibonacci(n));
+    }
+}
+
+fn demo_fibonacci2(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5().get(gm, config).take(limit) {
+        println!("(F_{}, F_{} - 1) = {:?}", n, n, Natural::fibonacci2(n));
+    }
+}
+
+fn demo_lucas(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5().get(gm, config).take(limit) {
+        println!("L_{} = {}", n, Natural::lucas(n));
+    }
+}
+
+fn benchmark_fibonacci_algorithms(gm: GenMode, config: &GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural.fibonacci(u64)",
+        BenchmarkType::Algorithms,
+        unsigned_gen_var_5().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("default", &mut |n| no_out!(Natural::fibonacci(n))),
+            ("using fibonacci2", &mut |n| no_out!(Natural::fibonacci2(n))),
+            ("naive", &mut |n| no_out!(fibonacci_naive(n))),
+        ],
+    );
+}
+
+fn benchmark_fibonacci_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.fibonacci(u64)",
+        BenchmarkType::LibraryComparison,
+        unsigned_gen_var_5().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("Malachite", &mut |n| no_out!(Natural::fibonacci(n))),
+            ("rug", &mut |n| {
+                no_out!(rug::Integer::fibonacci(u32::exact_from(n)).complete())
+            }),
+        ],
+    );
+}
+
+fn benchmark_fibonacci2_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.fibonacci2(u64)",
+        BenchmarkType::LibraryComparison,
+        unsigned_gen_var_5().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("Malachite", &mut |n| no_out!(Natural::fibonacci2(n))),
+            ("rug", &mut |n| {
+                no_out!(rug::Integer::fibonacci_2(u32::exact_from(n)).complete())
+            }),
+        ],
+    );
+}
+
+fn benchmark_lucas_algorithms(gm: GenMode, config: &GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural.lucas(u64)",
+        BenchmarkType::Algorithms,
+        unsigned_gen_var_5().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("default", &mut |n| no_out!(Natural::lucas(n))),
+            ("naive", &mut |n| no_out!(lucas_naive(n))),
+        ],
+    );
+}
+
+fn benchmark_lucas_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.lucas(u64)",
+        BenchmarkType::LibraryComparison,
+        unsigned_gen_var_5().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("Malachite", &mut |n| no_out!(Natural::lucas(n))),
+            ("rug", &mut |n| {
+                no_out!(rug::Integer::lucas(u32::exact_from(n)).complete())
+            }),
+        ],
+    );
+}
@@ -15,6 +15,7 @@ pub(crate) fn register(runner: &mut Runner) {
     binomial_coefficient::register(runner);
     checked_sub::register(runner);
     checked_sub_mul::register(runner);
+    chinese_remainder::register(runner);
     coprime_with::register(runner);
     div::register(runner);
     div_exact::register(runner);
@@ -26,6 +27,7 @@ pub(crate) fn register(runner: &mut Runner) {
     eq_mod_power_of_2::register(runner);
     extended_gcd::register(runner);
     factorial::register(runner);
+    fibonacci::register(runner);
     gcd::register(runner);
     is_power_of_2::register(runner);
     kronecker_symbol::register(runner);
@@ -53,6 +55,7 @@ pub(crate) fn register(runner: &mut Runner) {
     mod_power_of_2_sub::register(runner);
     mod_shl::register(runner);
     mod_shr::register(runner);
+    mod_sqrt::register(runner);
     mod_square::register(runner);
     mod_sub::register(runner);
     mul::register(runner);
@@ -84,6 +87,7 @@ mod add_mul;
 mod binomial_coefficient;
 mod checked_sub;
 mod checked_sub_mul;
+mod chinese_remainder;
 mod coprime_with;
 mod div;
 mod div_exact;
@@ -95,6 +99,7 @@ mod eq_mod;
 mod eq_mod_power_of_2;
 mod extended_gcd;
 mod factorial;
+mod fibonacci;
 mod gcd;
 mod is_power_of_2;
 mod kronecker_symbol;
@@ -122,6 +127,7 @@ mod mod_power_of_2_square;
 mod mod_power_of_2_sub;
 mod mod_shl;
 mod mod_shr;
+mod mod_sqrt;
 mod mod_square;
 mod mod_sub;
 mod mul;
@@ -0,0 +1,130 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{ModPrimePowerSqrt, ModSqrt};
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::pair_2_natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_pair_gen_var_16;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_mod_sqrt);
+    register_demo!(runner, demo_natural_mod_sqrt_val_ref);
+    register_demo!(runner, demo_natural_mod_sqrt_ref_val);
+    register_demo!(runner, demo_natural_mod_sqrt_ref_ref);
+    register_demo!(runner, demo_natural_mod_prime_power_sqrt);
+
+    register_bench!(runner, benchmark_natural_mod_sqrt_evaluation_strategy);
+    register_bench!(runner, benchmark_natural_mod_prime_power_sqrt);
+}
+
+fn demo_natural_mod_sqrt(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (n, p) in natural_pair_gen_var_16().get(gm, config).take(limit) {
+        let n_old = n.clone();
+        let p_old = p.clone();
+        if let Some(root) = n.mod_sqrt(p) {
+            println!("√{n_old} ≡ {root} mod {p_old}");
+        } else {
+            println!("{n_old} is not a square mod {p_old}");
+        }
+    }
+}
+
+fn demo_natural_mod_sqrt_val_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (n, p) in natural_pair_gen_var_16().get(gm, config).take(limit) {
+        let n_old = n.clone();
+        if let Some(root) = n.mod_sqrt(&p) {
+            println!("√{n_old} ≡ {root} mod {p}");
+        } else {
+            println!("{n_old} is not a square mod {p}");
+        }
+    }
+}
+
+fn demo_natural_mod_sqrt_ref_val(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (n, p) in natural_pair_gen_var_16().get(gm, config).take(limit) {
+        let p_old = p.clone();
+        if let Some(root) = (&n).mod_sqrt(p) {
+            println!("√{n} ≡ {root} mod {p_old}");
+        } else {
+            println!("{n} is not a square mod {p_old}");
+        }
+    }
+}
+
+fn demo_natural_mod_sqrt_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (n, p) in natural_pair_gen_var_16().get(gm, config).take(limit) {
+        if let Some(root) = (&n).mod_sqrt(&p) {
+            println!("√{n} ≡ {root} mod {p}");
+        } else {
+            println!("{n} is not a square mod {p}");
+        }
+    }
+}
+
+fn demo_natural_mod_prime_power_sqrt(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (n, p) in natural_pair_gen_var_16().get(gm, config).take(limit) {
+        if let Some(root) = (&n).mod_prime_power_sqrt(&p, 2) {
+            println!("√{n} ≡ {root} mod {p}^2");
+        } else {
+            println!("{n} is not a square mod {p}^2");
+        }
+    }
+}
+
+fn benchmark_natural_mod_sqrt_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.mod_sqrt(Natural)",
+        BenchmarkType::EvaluationStrategy,
+        natural_pair_gen_var_16().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_natural_bit_bucketer("p"),
+        &mut [
+            ("Natural.mod_sqrt(Natural)", &mut |(n, p)| {
+                no_out!(n.mod_sqrt(p))
+            }),
+            ("Natural.mod_sqrt(&Natural)", &mut |(n, p)| {
+                no_out!(n.mod_sqrt(&p))
+            }),
+            ("(&Natural).mod_sqrt(Natural)", &mut |(n, p)| {
+                no_out!((&n).mod_sqrt(p))
+            }),
+            ("(&Natural).mod_sqrt(&Natural)", &mut |(n, p)| {
+                no_out!((&n).mod_sqrt(&p))
+            }),
+        ],
+    );
+}
+
+fn benchmark_natural_mod_prime_power_sqrt(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.mod_prime_power_sqrt(Natural, 2)",
+        BenchmarkType::Single,
+        natural_pair_gen_var_16().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_natural_bit_bucketer("p"),
+        &mut [("Malachite", &mut |(n, p)| {
+            no_out!(n.mod_prime_power_sqrt(p, 2))
+        })],
+    );
+}
@@ -0,0 +1,78 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::factorization::traits::Factor;
+use malachite_base::test_util::bench::bucketers::pair_1_vec_len_bucketer;
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_vec_gen_var_1;
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_gen_var_2;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_factor);
+    register_demo!(runner, demo_natural_factor_product_of_u32s);
+
+    register_bench!(runner, benchmark_natural_factor);
+    register_bench!(runner, benchmark_natural_factor_product_of_u32s);
+}
+
+fn demo_natural_factor(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in natural_gen_var_2().get(gm, config).take(limit) {
+        println!("{}.factor() = {:?}", n, n.factor());
+    }
+}
+
+fn product_of_u32s(xs: &[u32]) -> Natural {
+    xs.iter()
+        .filter(|&&x| x != 0)
+        .fold(Natural::from(1u32), |acc, &x| acc * Natural::from(x))
+}
+
+fn demo_natural_factor_product_of_u32s(gm: GenMode, config: &GenConfig, limit: usize) {
+    for xs in unsigned_vec_gen_var_1::<u32>().get(gm, config).take(limit) {
+        let n = product_of_u32s(&xs);
+        println!("{}.factor() = {:?}", n, n.factor());
+    }
+}
+
+fn benchmark_natural_factor(gm: GenMode, config: &GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural.factor()",
+        BenchmarkType::Single,
+        natural_gen_var_2().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &natural_bit_bucketer("n"),
+        &mut [("Malachite", &mut |n| no_out!(n.factor()))],
+    );
+}
+
+fn benchmark_natural_factor_product_of_u32s(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.factor()",
+        BenchmarkType::Single,
+        unsigned_vec_gen_var_1::<u32>().get(gm, config).map(|xs| {
+            let n = product_of_u32s(&xs);
+            (xs, n)
+        }),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_vec_len_bucketer("xs"),
+        &mut [("Malachite", &mut |(_, n)| no_out!(n.factor()))],
+    );
+}
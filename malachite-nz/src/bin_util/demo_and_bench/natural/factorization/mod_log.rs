@@ -0,0 +1,95 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::factorization::traits::ModLog;
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_triple_gen_var_25;
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::triple_3_natural_bit_bucketer;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_mod_log);
+    register_demo!(runner, demo_natural_mod_log_ref_ref_ref);
+
+    register_bench!(runner, benchmark_natural_mod_log_evaluation_strategy);
+}
+
+fn demo_natural_mod_log(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, g, m) in unsigned_triple_gen_var_25::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x = Natural::from(x);
+        let g = Natural::from(g);
+        let m = Natural::from(m);
+        let x_old = x.clone();
+        let g_old = g.clone();
+        let m_old = m.clone();
+        println!(
+            "{}.mod_log({}, {}) = {:?}",
+            x_old,
+            g_old,
+            m_old,
+            x.mod_log(g, m)
+        );
+    }
+}
+
+fn demo_natural_mod_log_ref_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, g, m) in unsigned_triple_gen_var_25::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x = Natural::from(x);
+        let g = Natural::from(g);
+        let m = Natural::from(m);
+        println!(
+            "(&{}).mod_log(&{}, &{}) = {:?}",
+            x,
+            g,
+            m,
+            (&x).mod_log(&g, &m)
+        );
+    }
+}
+
+fn benchmark_natural_mod_log_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.mod_log(Natural, Natural)",
+        BenchmarkType::EvaluationStrategy,
+        unsigned_triple_gen_var_25::<u64>()
+            .get(gm, config)
+            .map(|(x, g, m)| (Natural::from(x), Natural::from(g), Natural::from(m))),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_natural_bit_bucketer("m"),
+        &mut [
+            ("Natural.mod_log(Natural, Natural)", &mut |(x, g, m)| {
+                no_out!(x.mod_log(g, m))
+            }),
+            ("Natural.mod_log(&Natural, &Natural)", &mut |(x, g, m)| {
+                no_out!(x.mod_log(&g, &m))
+            }),
+            ("(&Natural).mod_log(Natural, Natural)", &mut |(x, g, m)| {
+                no_out!((&x).mod_log(g, m))
+            }),
+            (
+                "(&Natural).mod_log(&Natural, &Natural)",
+                &mut |(x, g, m)| no_out!((&x).mod_log(&g, &m)),
+            ),
+        ],
+    );
+}
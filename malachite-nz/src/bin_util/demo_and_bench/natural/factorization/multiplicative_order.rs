@@ -0,0 +1,93 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::factorization::traits::MultiplicativeOrder;
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_pair_gen_var_16;
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::pair_2_natural_bit_bucketer;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_multiplicative_order);
+    register_demo!(runner, demo_natural_multiplicative_order_ref_ref);
+
+    register_bench!(
+        runner,
+        benchmark_natural_multiplicative_order_evaluation_strategy
+    );
+}
+
+fn demo_natural_multiplicative_order(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, m) in unsigned_pair_gen_var_16::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x = Natural::from(x);
+        let m = Natural::from(m);
+        let x_old = x.clone();
+        let m_old = m.clone();
+        println!(
+            "{}.multiplicative_order({}) = {:?}",
+            x_old,
+            m_old,
+            x.multiplicative_order(m)
+        );
+    }
+}
+
+fn demo_natural_multiplicative_order_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, m) in unsigned_pair_gen_var_16::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x = Natural::from(x);
+        let m = Natural::from(m);
+        println!(
+            "(&{}).multiplicative_order(&{}) = {:?}",
+            x,
+            m,
+            (&x).multiplicative_order(&m)
+        );
+    }
+}
+
+fn benchmark_natural_multiplicative_order_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.multiplicative_order(Natural)",
+        BenchmarkType::EvaluationStrategy,
+        unsigned_pair_gen_var_16::<u64>()
+            .get(gm, config)
+            .map(|(x, m)| (Natural::from(x), Natural::from(m))),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_natural_bit_bucketer("m"),
+        &mut [
+            ("Natural.multiplicative_order(Natural)", &mut |(x, m)| {
+                no_out!(x.multiplicative_order(m))
+            }),
+            ("Natural.multiplicative_order(&Natural)", &mut |(x, m)| {
+                no_out!(x.multiplicative_order(&m))
+            }),
+            ("(&Natural).multiplicative_order(Natural)", &mut |(x, m)| {
+                no_out!((&x).multiplicative_order(m))
+            }),
+            (
+                "(&Natural).multiplicative_order(&Natural)",
+                &mut |(x, m)| no_out!((&x).multiplicative_order(&m)),
+            ),
+        ],
+    );
+}
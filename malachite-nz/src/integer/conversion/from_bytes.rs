@@ -0,0 +1,190 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::Integer;
+use crate::natural::Natural;
+use crate::natural::conversion::from_bytes::{limbs_from_bytes_be, limbs_from_bytes_le};
+use crate::platform::Limb;
+use core::mem::size_of;
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::Zero;
+
+const LIMB_BYTES: usize = size_of::<Limb>();
+
+// Given the limbs of a negative two's complement number, converted from `byte_len` bytes, sets the
+// bits of the most significant limb that weren't covered by the bytes, so that the sign is extended
+// to the whole limb.
+//
+// # Worst-case complexity
+// Constant time and additional memory.
+const fn limbs_sign_extend_negative_in_place(xs: &mut [Limb], byte_len: usize) {
+    let remainder = byte_len % LIMB_BYTES;
+    if remainder != 0 {
+        *xs.last_mut().unwrap() |= Limb::MAX << (remainder << 3);
+    }
+}
+
+impl Integer {
+    /// Converts a sign and a slice of bytes to an [`Integer`]. The bytes, in ascending order, are
+    /// the [`Integer`]'s absolute value, and the sign indicates whether the [`Integer`] should be
+    /// non-negative. If the bytes represent zero, then the [`Integer`] will be non-negative
+    /// regardless of the sign.
+    ///
+    /// The bytes are copied directly into [limbs](crate#limbs), and the result does not depend on
+    /// the size of a [`Limb`](crate#limbs).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from_sign_and_bytes_le(true, &[]), 0);
+    /// assert_eq!(Integer::from_sign_and_bytes_le(false, &[]), 0);
+    /// assert_eq!(Integer::from_sign_and_bytes_le(false, &[123]), -123);
+    /// assert_eq!(
+    ///     Integer::from_sign_and_bytes_le(false, &[0, 16, 165, 212, 232]),
+    ///     -1000000000000i64
+    /// );
+    /// ```
+    #[inline]
+    pub fn from_sign_and_bytes_le(sign: bool, bytes: &[u8]) -> Integer {
+        Integer::from_sign_and_abs(sign, Natural::from_bytes_le(bytes))
+    }
+
+    /// Converts a sign and a slice of bytes to an [`Integer`]. The bytes, in descending order, are
+    /// the [`Integer`]'s absolute value, and the sign indicates whether the [`Integer`] should be
+    /// non-negative. If the bytes represent zero, then the [`Integer`] will be non-negative
+    /// regardless of the sign.
+    ///
+    /// The bytes are copied directly into [limbs](crate#limbs), and the result does not depend on
+    /// the size of a [`Limb`](crate#limbs).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from_sign_and_bytes_be(true, &[]), 0);
+    /// assert_eq!(Integer::from_sign_and_bytes_be(false, &[]), 0);
+    /// assert_eq!(Integer::from_sign_and_bytes_be(false, &[123]), -123);
+    /// assert_eq!(
+    ///     Integer::from_sign_and_bytes_be(false, &[232, 212, 165, 16, 0]),
+    ///     -1000000000000i64
+    /// );
+    /// ```
+    #[inline]
+    pub fn from_sign_and_bytes_be(sign: bool, bytes: &[u8]) -> Integer {
+        Integer::from_sign_and_abs(sign, Natural::from_bytes_be(bytes))
+    }
+
+    /// Converts a slice of bytes to an [`Integer`], in ascending order, so that less significant
+    /// bytes have lower indices in the input slice.
+    ///
+    /// The bytes are in two's complement, and the most significant bit of the bytes indicates the
+    /// sign; if the bit is zero, the [`Integer`] is non-negative, and if the bit is one it is
+    /// negative. If the slice is empty, zero is returned.
+    ///
+    /// The bytes are copied directly into [limbs](crate#limbs), and the result does not depend on
+    /// the size of a [`Limb`](crate#limbs).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from_twos_complement_bytes_le(&[]), 0);
+    /// assert_eq!(Integer::from_twos_complement_bytes_le(&[123]), 123);
+    /// assert_eq!(Integer::from_twos_complement_bytes_le(&[133]), -123);
+    /// assert_eq!(Integer::from_twos_complement_bytes_le(&[128, 0]), 128);
+    /// assert_eq!(
+    ///     Integer::from_twos_complement_bytes_le(&[0, 16, 165, 212, 232, 0]),
+    ///     1000000000000u64
+    /// );
+    /// assert_eq!(
+    ///     Integer::from_twos_complement_bytes_le(&[0, 240, 90, 43, 23, 255]),
+    ///     -1000000000000i64
+    /// );
+    /// ```
+    pub fn from_twos_complement_bytes_le(bytes: &[u8]) -> Integer {
+        match bytes {
+            &[] => Integer::ZERO,
+            &[.., last] if !last.get_highest_bit() => Integer::from(Natural::from_bytes_le(bytes)),
+            bytes => {
+                let mut xs = limbs_from_bytes_le(bytes);
+                limbs_sign_extend_negative_in_place(&mut xs, bytes.len());
+                Integer::from_owned_twos_complement_limbs_asc(xs)
+            }
+        }
+    }
+
+    /// Converts a slice of bytes to an [`Integer`], in descending order, so that less significant
+    /// bytes have higher indices in the input slice.
+    ///
+    /// The bytes are in two's complement, and the most significant bit of the bytes indicates the
+    /// sign; if the bit is zero, the [`Integer`] is non-negative, and if the bit is one it is
+    /// negative. If the slice is empty, zero is returned.
+    ///
+    /// The bytes are copied directly into [limbs](crate#limbs), and the result does not depend on
+    /// the size of a [`Limb`](crate#limbs).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from_twos_complement_bytes_be(&[]), 0);
+    /// assert_eq!(Integer::from_twos_complement_bytes_be(&[123]), 123);
+    /// assert_eq!(Integer::from_twos_complement_bytes_be(&[133]), -123);
+    /// assert_eq!(Integer::from_twos_complement_bytes_be(&[0, 128]), 128);
+    /// assert_eq!(
+    ///     Integer::from_twos_complement_bytes_be(&[0, 232, 212, 165, 16, 0]),
+    ///     1000000000000u64
+    /// );
+    /// assert_eq!(
+    ///     Integer::from_twos_complement_bytes_be(&[255, 23, 43, 90, 240, 0]),
+    ///     -1000000000000i64
+    /// );
+    /// ```
+    pub fn from_twos_complement_bytes_be(bytes: &[u8]) -> Integer {
+        match bytes {
+            &[] => Integer::ZERO,
+            &[first, ..] if !first.get_highest_bit() => {
+                Integer::from(Natural::from_bytes_be(bytes))
+            }
+            bytes => {
+                let mut xs = limbs_from_bytes_be(bytes);
+                limbs_sign_extend_negative_in_place(&mut xs, bytes.len());
+                Integer::from_owned_twos_complement_limbs_asc(xs)
+            }
+        }
+    }
+}
@@ -9,6 +9,9 @@
 /// An implementation of the [`From`] trait for converting a [`bool`] to an
 /// [`Integer`](crate::integer::Integer).
 pub mod from_bool;
+/// Functions for constructing an [`Integer`](crate::integer::Integer) from bytes, either as a sign
+/// and an absolute value or in two's complement.
+pub mod from_bytes;
 /// Functions for converting a [`Natural`](crate::natural::Natural) to an
 /// [`Integer`](crate::integer::Integer), and an implementation of the [`From`] trait.
 pub mod from_natural;
@@ -471,6 +474,9 @@ pub mod serde;
 /// Implementations of traits for converting [`Integer`](crate::integer::Integer)s to and from
 /// [`String`]s.
 pub mod string;
+/// Functions for extracting bytes from an [`Integer`](crate::integer::Integer), either as a sign
+/// and an absolute value or in two's complement.
+pub mod to_bytes;
 /// Functions for extracting two's complement [`Limb`](crate#limbs)s from an
 /// [`Integer`](crate::integer::Integer).
 pub mod to_twos_complement_limbs;
@@ -8,9 +8,13 @@
 
 use crate::integer::{Integer, SerdeInteger};
 use crate::natural::Natural;
+#[cfg(feature = "serde")]
+use crate::natural::conversion::serde::{CompactNatural, CompactNaturalRef};
 use alloc::string::String;
 use core::convert::TryFrom;
 use malachite_base::num::conversion::traits::FromStringBase;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
 
 impl From<Integer> for SerdeInteger {
     #[inline]
@@ -50,3 +54,64 @@ impl TryFrom<SerdeInteger> for Integer {
         }
     }
 }
+
+/// A wrapper around an [`Integer`] that is serialized compactly.
+///
+/// A `CompactInteger` is serialized as a pair: a `bool` that is `true` if and only if the
+/// [`Integer`] is non-negative, followed by its absolute value in the format of a
+/// [`CompactNatural`](crate::natural::conversion::serde::CompactNatural). Like that format, this
+/// one does not depend on the [`Limb`](crate#limbs) size. Deserialization rejects negative zero,
+/// so every [`Integer`] has exactly one encoding.
+///
+/// To serialize an [`Integer`] without taking ownership of it, use [`CompactIntegerRef`].
+///
+/// # Examples
+/// ```
+/// use malachite_nz::integer::conversion::serde::CompactInteger;
+/// use malachite_nz::integer::Integer;
+///
+/// let x = CompactInteger(Integer::from(-1000));
+/// assert_eq!(serde_json::to_string(&x).unwrap(), "[false,[232,3]]");
+/// assert_eq!(
+///     serde_json::from_str::<CompactInteger>("[false,[232,3]]").unwrap(),
+///     x
+/// );
+/// ```
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct CompactInteger(pub Integer);
+
+/// A wrapper around a reference to an [`Integer`] that is serialized compactly, in the same format
+/// as a [`CompactInteger`].
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CompactIntegerRef<'a>(pub &'a Integer);
+
+#[cfg(feature = "serde")]
+impl Serialize for CompactIntegerRef<'_> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.0.sign, CompactNaturalRef(&self.0.abs)).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for CompactInteger {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        CompactIntegerRef(&self.0).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for CompactInteger {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (sign, CompactNatural(abs)): (bool, _) = Deserialize::deserialize(deserializer)?;
+        if !sign && abs == 0u32 {
+            return Err(D::Error::custom("negative zero is not a valid Integer"));
+        }
+        Ok(CompactInteger(Integer { sign, abs }))
+    }
+}
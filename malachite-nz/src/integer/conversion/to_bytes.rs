@@ -0,0 +1,193 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::Integer;
+use crate::platform::Limb;
+use alloc::vec::Vec;
+use core::mem::size_of;
+use malachite_base::num::conversion::traits::ExactFrom;
+
+// Given the bytes of a two's complement number, in ascending order, removes the most significant
+// bytes that only extend the sign of the bytes below them.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+fn bytes_trim_sign_extension_le(bytes: &mut Vec<u8>) {
+    while let &[.., penultimate, last] = &bytes[..] {
+        if (last == 0 && penultimate < 0x80) || (last == u8::MAX && penultimate >= 0x80) {
+            bytes.pop();
+        } else {
+            break;
+        }
+    }
+}
+
+impl Integer {
+    /// Returns the sign and the bytes of the absolute value of an [`Integer`]. The sign is `true`
+    /// if and only if the [`Integer`] is non-negative, and the bytes are in ascending order, so
+    /// that less-significant bytes have lower indices in the output vector.
+    ///
+    /// There are no trailing zero bytes; in particular, zero is converted to an empty vector.
+    ///
+    /// The [limbs](crate#limbs) are copied directly into bytes, and the result does not depend on
+    /// the size of a [`Limb`](crate#limbs).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::ZERO.to_sign_and_bytes_le(), (true, vec![]));
+    /// assert_eq!(Integer::from(-123).to_sign_and_bytes_le(), (false, vec![123]));
+    /// assert_eq!(
+    ///     (-Integer::from(10u32).pow(12)).to_sign_and_bytes_le(),
+    ///     (false, vec![0, 16, 165, 212, 232])
+    /// );
+    /// ```
+    #[inline]
+    pub fn to_sign_and_bytes_le(&self) -> (bool, Vec<u8>) {
+        (self.sign, self.abs.to_bytes_le())
+    }
+
+    /// Returns the sign and the bytes of the absolute value of an [`Integer`]. The sign is `true`
+    /// if and only if the [`Integer`] is non-negative, and the bytes are in descending order, so
+    /// that less-significant bytes have higher indices in the output vector.
+    ///
+    /// There are no leading zero bytes; in particular, zero is converted to an empty vector.
+    ///
+    /// The [limbs](crate#limbs) are copied directly into bytes, and the result does not depend on
+    /// the size of a [`Limb`](crate#limbs).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::ZERO.to_sign_and_bytes_be(), (true, vec![]));
+    /// assert_eq!(Integer::from(-123).to_sign_and_bytes_be(), (false, vec![123]));
+    /// assert_eq!(
+    ///     (-Integer::from(10u32).pow(12)).to_sign_and_bytes_be(),
+    ///     (false, vec![232, 212, 165, 16, 0])
+    /// );
+    /// ```
+    #[inline]
+    pub fn to_sign_and_bytes_be(&self) -> (bool, Vec<u8>) {
+        (self.sign, self.abs.to_bytes_be())
+    }
+
+    /// Returns the bytes of an [`Integer`], in ascending order, so that less significant bytes
+    /// have lower indices in the output vector.
+    ///
+    /// The bytes are in two's complement, and the most significant bit of the bytes indicates the
+    /// sign; if the bit is zero, the [`Integer`] is non-negative, and if the bit is one it is
+    /// negative. There are no trailing zero bytes if the [`Integer`] is positive or trailing
+    /// `u8::MAX` bytes if the [`Integer`] is negative, except as necessary to include the correct
+    /// sign bit. Zero is a special case: it contains no bytes.
+    ///
+    /// The [limbs](crate#limbs) are copied directly into bytes, and the result does not depend on
+    /// the size of a [`Limb`](crate#limbs).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert!(Integer::ZERO.to_twos_complement_bytes_le().is_empty());
+    /// assert_eq!(Integer::from(123).to_twos_complement_bytes_le(), &[123]);
+    /// assert_eq!(Integer::from(-123).to_twos_complement_bytes_le(), &[133]);
+    /// // Sign-extension for a non-negative `Integer`
+    /// assert_eq!(Integer::from(128).to_twos_complement_bytes_le(), &[128, 0]);
+    /// // Sign-extension for a negative `Integer`
+    /// assert_eq!(Integer::from(-129).to_twos_complement_bytes_le(), &[127, 255]);
+    /// assert_eq!(
+    ///     (-Integer::from(10u32).pow(12)).to_twos_complement_bytes_le(),
+    ///     &[0, 240, 90, 43, 23, 255]
+    /// );
+    /// ```
+    pub fn to_twos_complement_bytes_le(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            usize::exact_from(self.twos_complement_limb_count()) * size_of::<Limb>(),
+        );
+        for x in self.twos_complement_limbs() {
+            bytes.extend_from_slice(&x.to_le_bytes());
+        }
+        bytes_trim_sign_extension_le(&mut bytes);
+        bytes
+    }
+
+    /// Returns the bytes of an [`Integer`], in descending order, so that less significant bytes
+    /// have higher indices in the output vector.
+    ///
+    /// The bytes are in two's complement, and the most significant bit of the bytes indicates the
+    /// sign; if the bit is zero, the [`Integer`] is non-negative, and if the bit is one it is
+    /// negative. There are no leading zero bytes if the [`Integer`] is positive or leading
+    /// `u8::MAX` bytes if the [`Integer`] is negative, except as necessary to include the correct
+    /// sign bit. Zero is a special case: it contains no bytes.
+    ///
+    /// The [limbs](crate#limbs) are copied directly into bytes, and the result does not depend on
+    /// the size of a [`Limb`](crate#limbs).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert!(Integer::ZERO.to_twos_complement_bytes_be().is_empty());
+    /// assert_eq!(Integer::from(123).to_twos_complement_bytes_be(), &[123]);
+    /// assert_eq!(Integer::from(-123).to_twos_complement_bytes_be(), &[133]);
+    /// // Sign-extension for a non-negative `Integer`
+    /// assert_eq!(Integer::from(128).to_twos_complement_bytes_be(), &[0, 128]);
+    /// // Sign-extension for a negative `Integer`
+    /// assert_eq!(Integer::from(-129).to_twos_complement_bytes_be(), &[255, 127]);
+    /// assert_eq!(
+    ///     (-Integer::from(10u32).pow(12)).to_twos_complement_bytes_be(),
+    ///     &[255, 23, 43, 90, 240, 0]
+    /// );
+    /// ```
+    pub fn to_twos_complement_bytes_be(&self) -> Vec<u8> {
+        let mut bytes = self.to_twos_complement_bytes_le();
+        bytes.reverse();
+        bytes
+    }
+}
@@ -0,0 +1,116 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::Integer;
+use malachite_base::num::arithmetic::traits::{Pow, PowerOf2};
+use malachite_base::num::basic::traits::NegativeOne;
+use malachite_base::num::factorization::traits::{ExpressAsPower, IsPerfectPower};
+
+impl IsPerfectPower for Integer {
+    type Output = Option<(Integer, u64)>;
+
+    /// Determines whether an [`Integer`] is a perfect power; that is, whether it is equal to $b^e$
+    /// for some integers $b$ and $e$, with $e > 1$. If it is, `Some((b, e))` is returned, with $e$
+    /// as large as possible; otherwise, `None` is returned.
+    ///
+    /// Non-negative [`Integer`]s are handled like [`Natural`](crate::natural::Natural)s; in
+    /// particular, `Some((0, 2))` and `Some((1, 2))` are returned for 0 and 1. A negative
+    /// [`Integer`] can only be a perfect power with an odd exponent, so if its absolute value is
+    /// $b^e$, with $e = 2^k m$ and $m$ odd, the result is $(-b^{2^k}, m)$, or `None` if $m = 1$.
+    ///
+    /// Since $-1$ is a $k$th power for every odd $k$, `Some((-1, 3))` is returned for it. This
+    /// differs from the primitive signed integer implementations, which return `None` for $-1$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::factorization::traits::IsPerfectPower;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(
+    ///     Integer::from(0).is_perfect_power().to_debug_string(),
+    ///     "Some((0, 2))"
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(64).is_perfect_power().to_debug_string(),
+    ///     "Some((2, 6))"
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(-64).is_perfect_power().to_debug_string(),
+    ///     "Some((-4, 3))"
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(-1).is_perfect_power().to_debug_string(),
+    ///     "Some((-1, 3))"
+    /// );
+    /// assert_eq!(Integer::from(-16).is_perfect_power(), None);
+    /// assert_eq!(Integer::from(10).is_perfect_power(), None);
+    /// ```
+    fn is_perfect_power(&self) -> Option<(Integer, u64)> {
+        if self.sign {
+            self.abs
+                .is_perfect_power()
+                .map(|(base, exp)| (Integer::from(base), exp))
+        } else if self.abs == 1u32 {
+            Some((Integer::NEGATIVE_ONE, 3))
+        } else {
+            let (base, exp) = self.abs.is_perfect_power()?;
+            let twos = exp.trailing_zeros();
+            let odd_exp = exp >> twos;
+            if odd_exp == 1 {
+                None
+            } else {
+                Some((
+                    -Integer::from(base.pow(u64::power_of_2(twos.into()))),
+                    odd_exp,
+                ))
+            }
+        }
+    }
+}
+
+impl ExpressAsPower for Integer {
+    /// Expresses an [`Integer`] as a power $b^e$, using the greatest possible exponent.
+    ///
+    /// If the [`Integer`] is a perfect power, the result is the same as that of
+    /// [`is_perfect_power`](IsPerfectPower::is_perfect_power); otherwise, `(self, 1)` is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::factorization::traits::ExpressAsPower;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(
+    ///     Integer::from(-64).express_as_power().to_debug_string(),
+    ///     "(-4, 3)"
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(-16).express_as_power().to_debug_string(),
+    ///     "(-16, 1)"
+    /// );
+    /// ```
+    #[inline]
+    fn express_as_power(&self) -> (Integer, u64) {
+        self.is_perfect_power().unwrap_or_else(|| (self.clone(), 1))
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::Integer;
+use malachite_base::num::factorization::traits::IsPrime;
+
+impl Integer {
+    /// Determines whether an [`Integer`] is probably prime, performing extra Miller-Rabin rounds.
+    ///
+    /// Negative [`Integer`]s are never considered prime. For non-negative [`Integer`]s, this
+    /// function behaves like [`Natural::probably_prime`](crate::natural::Natural::probably_prime).
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O((m + 1) n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `reps`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(5).probably_prime(10), true);
+    /// assert_eq!(Integer::from(-5).probably_prime(10), false);
+    /// assert_eq!(
+    ///     (Integer::from(2).pow(127) - Integer::from(1)).probably_prime(10),
+    ///     true
+    /// );
+    /// ```
+    #[inline]
+    pub fn probably_prime(&self, reps: u64) -> bool {
+        self.sign && self.abs.probably_prime(reps)
+    }
+}
+
+impl IsPrime for Integer {
+    /// Tests whether an [`Integer`] is prime.
+    ///
+    /// Negative [`Integer`]s are never considered prime. For non-negative [`Integer`]s, the
+    /// [`Natural`](crate::natural::Natural) implementation is used; see its documentation for
+    /// details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::factorization::traits::IsPrime;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(5).is_prime(), true);
+    /// assert_eq!(Integer::from(6).is_prime(), false);
+    /// assert_eq!(Integer::from(-5).is_prime(), false);
+    /// assert_eq!(
+    ///     (Integer::from(2).pow(127) - Integer::from(1)).is_prime(),
+    ///     true
+    /// );
+    /// ```
+    #[inline]
+    fn is_prime(&self) -> bool {
+        self.sign && self.abs.is_prime()
+    }
+}
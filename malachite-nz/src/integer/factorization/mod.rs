@@ -0,0 +1,16 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+/// Implementations of [`IsPerfectPower`](malachite_base::num::factorization::traits::IsPerfectPower)
+/// and [`ExpressAsPower`](malachite_base::num::factorization::traits::ExpressAsPower), traits for
+/// testing whether a number is a perfect power and for expressing it as one.
+pub mod is_perfect_power;
+/// An implementation of [`IsPrime`](malachite_base::num::factorization::traits::IsPrime), a trait
+/// for testing whether a number is prime, and the related function
+/// [`Integer::probably_prime`](crate::integer::Integer::probably_prime).
+pub mod is_prime;
@@ -10,9 +10,9 @@ use crate::integer::Integer;
 use crate::natural::Natural;
 use crate::natural::random::{
     RandomNaturalsLessThan, StripedRandomNaturalInclusiveRange, get_random_natural_less_than,
-    get_random_natural_with_bits, get_striped_random_natural_from_inclusive_range,
-    get_striped_random_natural_with_bits, random_naturals_less_than,
-    striped_random_natural_inclusive_range,
+    get_random_natural_less_than_with_rng, get_random_natural_with_bits,
+    get_striped_random_natural_from_inclusive_range, get_striped_random_natural_with_bits,
+    random_naturals_less_than, striped_random_natural_inclusive_range,
 };
 use malachite_base::bools::random::{RandomBools, random_bools};
 use malachite_base::num::arithmetic::traits::{PowerOf2, UnsignedAbs};
@@ -31,6 +31,9 @@ use malachite_base::num::random::{
     RandomPrimitiveInts, VariableRangeGenerator, random_primitive_ints,
 };
 use malachite_base::random::Seed;
+use rand::Rng;
+use rand::distributions::Distribution;
+use rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformSampler};
 
 /// Generates random [`Integer`]s, given an iterator of random signed bit lengths.
 ///
@@ -879,6 +882,134 @@ pub fn get_uniform_random_integer_from_inclusive_range(
     get_uniform_random_integer_from_range(limbs, a, b + Integer::ONE)
 }
 
+/// A distribution that uniformly samples [`Integer`]s from an interval.
+///
+/// This implements [`rand`]'s [`Distribution`] and [`UniformSampler`] traits, so it may be used
+/// with any [`Rng`]; for example, via `rng.sample(UniformInteger::new(a, b))`. Since [`Integer`]
+/// implements [`SampleUniform`], `rng.gen_range(a..b)` works too.
+///
+/// Sampling takes expected $O(n)$ time and additional memory, where $n$ is the number of bits in
+/// the width of the interval.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct UniformInteger {
+    a: Integer,
+    width: Natural,
+}
+
+impl UniformInteger {
+    /// Creates a distribution that uniformly samples [`Integer`]s from the half-open interval $[a,
+    /// b)$.
+    ///
+    /// $$
+    /// P(x) = \\begin{cases}
+    ///     \frac{1}{b-a} & \text{if} \\quad a \leq x < b, \\\\
+    ///     0 & \\text{otherwise}.
+    /// \\end{cases}
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(a.significant_bits(),
+    /// b.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if $a \geq b$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::random::EXAMPLE_SEED;
+    /// use malachite_nz::integer::random::UniformInteger;
+    /// use malachite_nz::integer::Integer;
+    /// use rand::Rng;
+    ///
+    /// let mut rng = EXAMPLE_SEED.get_rng();
+    /// let d = UniformInteger::new(Integer::from(-10), Integer::from(100));
+    /// assert_eq!(rng.sample(&d), 77);
+    /// ```
+    pub fn new(a: Integer, b: Integer) -> UniformInteger {
+        assert!(a < b);
+        let width = Natural::exact_from(b - &a);
+        UniformInteger { a, width }
+    }
+
+    /// Creates a distribution that uniformly samples [`Integer`]s from the closed interval $[a,
+    /// b]$.
+    ///
+    /// $$
+    /// P(x) = \\begin{cases}
+    ///     \frac{1}{b-a+1} & \text{if} \\quad a \leq x \leq b, \\\\
+    ///     0 & \\text{otherwise}.
+    /// \\end{cases}
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(a.significant_bits(),
+    /// b.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if $a > b$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::random::EXAMPLE_SEED;
+    /// use malachite_nz::integer::random::UniformInteger;
+    /// use malachite_nz::integer::Integer;
+    /// use rand::Rng;
+    ///
+    /// let mut rng = EXAMPLE_SEED.get_rng();
+    /// let d = UniformInteger::new_inclusive(Integer::from(-10), Integer::from(99));
+    /// assert_eq!(rng.sample(&d), 77);
+    /// ```
+    #[inline]
+    pub fn new_inclusive(a: Integer, b: Integer) -> UniformInteger {
+        assert!(a <= b);
+        UniformInteger::new(a, b + Integer::ONE)
+    }
+}
+
+impl Distribution<Integer> for UniformInteger {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Integer {
+        &self.a + Integer::from(get_random_natural_less_than_with_rng(rng, &self.width))
+    }
+}
+
+impl UniformSampler for UniformInteger {
+    type X = Integer;
+
+    #[inline]
+    fn new<B1: SampleBorrow<Integer>, B2: SampleBorrow<Integer>>(
+        low: B1,
+        high: B2,
+    ) -> UniformInteger {
+        UniformInteger::new(low.borrow().clone(), high.borrow().clone())
+    }
+
+    #[inline]
+    fn new_inclusive<B1: SampleBorrow<Integer>, B2: SampleBorrow<Integer>>(
+        low: B1,
+        high: B2,
+    ) -> UniformInteger {
+        UniformInteger::new_inclusive(low.borrow().clone(), high.borrow().clone())
+    }
+
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Integer {
+        Distribution::sample(self, rng)
+    }
+}
+
+impl SampleUniform for Integer {
+    type Sampler = UniformInteger;
+}
+
 fn signed_significant_bits(a: &Integer) -> (u64, i64) {
     let unsigned_bits = a.significant_bits();
     let bits = if *a >= 0 {
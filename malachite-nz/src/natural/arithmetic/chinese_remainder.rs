@@ -0,0 +1,203 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::{
+    ChineseRemainder, DivExact, DivisibleBy, Gcd, ModInverse, ModMul, ModNeg, Square,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+
+// If there are at least this many congruences, the subproduct tree algorithm is tried first.
+const CHINESE_REMAINDER_TREE_THRESHOLD: usize = 16;
+
+// Combines x = r1 mod m1 and x = r2 mod m2 into a single congruence x = r mod lcm(m1, m2), or
+// returns `None` if the two congruences are inconsistent. The residues must be reduced.
+fn combine_congruences(
+    r1: Natural,
+    m1: Natural,
+    r2: &Natural,
+    m2: &Natural,
+) -> Option<(Natural, Natural)> {
+    let g = (&m1).gcd(m2);
+    let (diff, diff_is_negative) = if *r2 >= r1 {
+        (r2 - &r1, false)
+    } else {
+        (&r1 - r2, true)
+    };
+    if !(&diff).divisible_by(&g) {
+        return None;
+    }
+    let m1_reduced = (&m1).div_exact(&g);
+    let m2_reduced = m2.div_exact(&g);
+    let lcm = &m1_reduced * m2;
+    if m2_reduced == 1u32 {
+        return Some((r1, lcm));
+    }
+    // x = r1 + m1 k, where (m1 / g) k = (r2 - r1) / g mod (m2 / g)
+    let mut diff = diff.div_exact(g) % &m2_reduced;
+    if diff_is_negative {
+        diff = diff.mod_neg(&m2_reduced);
+    }
+    let k = diff.mod_mul(
+        (m1_reduced % &m2_reduced).mod_inverse(&m2_reduced).unwrap(),
+        &m2_reduced,
+    );
+    Some((r1 + m1 * k, lcm))
+}
+
+pub_test! {chinese_remainder_pairwise(
+    congruences: &[(Natural, Natural)]
+) -> Option<(Natural, Natural)> {
+    let mut r = Natural::ZERO;
+    let mut m = Natural::ONE;
+    for (r_i, m_i) in congruences {
+        (r, m) = combine_congruences(r, m, r_i, m_i)?;
+    }
+    Some((r, m))
+}}
+
+// Combines congruences with pairwise coprime moduli using a subproduct tree. Let M be the product
+// of the moduli. First, M mod m_i^2 is computed for every i using a remainder tree; dividing it by
+// m_i gives M / m_i mod m_i. The solution is then the sum of r_i (M / m_i)^(-1) (M / m_i) mod m_i,
+// which is accumulated by walking back up the tree.
+//
+// Returns `None` if the moduli turn out not to be pairwise coprime; in that case some M / m_i is not
+// invertible mod m_i. The congruences must be nonempty.
+pub_test! {chinese_remainder_subproduct_tree(
+    congruences: &[(Natural, Natural)]
+) -> Option<(Natural, Natural)> {
+    let mut tree: Vec<Vec<Natural>> = Vec::new();
+    tree.push(congruences.iter().map(|(_, m)| m.clone()).collect());
+    while tree.last().unwrap().len() > 1 {
+        let level = tree.last().unwrap();
+        let next: Vec<Natural> = level
+            .chunks(2)
+            .map(|pair| {
+                if pair.len() == 2 {
+                    &pair[0] * &pair[1]
+                } else {
+                    pair[0].clone()
+                }
+            })
+            .collect();
+        tree.push(next);
+    }
+    let product = tree.last().unwrap()[0].clone();
+    // Remainder tree: M mod P^2 for every node P.
+    let mut remainders = Vec::from([product.clone()]);
+    for level in tree.iter().rev().skip(1) {
+        remainders = level
+            .iter()
+            .enumerate()
+            .map(|(i, m)| &remainders[i >> 1] % m.square())
+            .collect();
+    }
+    // At the leaves, the values r_i (M / m_i)^(-1) mod m_i.
+    let mut values = Vec::with_capacity(congruences.len());
+    for ((r, m), remainder) in congruences.iter().zip(remainders) {
+        if *m == 1u32 {
+            values.push(Natural::ZERO);
+            continue;
+        }
+        let cofactor = remainder.div_exact(m);
+        if cofactor == 0u32 {
+            return None;
+        }
+        values.push(r.mod_mul(cofactor.mod_inverse(m)?, m));
+    }
+    // Going up the tree, the value at a node with children L and R is value(L) P(R) + value(R) P(L),
+    // reduced mod P(L) P(R).
+    for (level, parents) in tree.iter().zip(tree.iter().skip(1)) {
+        values = values
+            .chunks(2)
+            .zip(level.chunks(2))
+            .zip(parents)
+            .map(|((vs, ms), p)| {
+                if vs.len() == 2 {
+                    (&vs[0] * &ms[1] + &vs[1] * &ms[0]) % p
+                } else {
+                    vs[0].clone()
+                }
+            })
+            .collect();
+    }
+    Some((values.pop().unwrap(), product))
+}}
+
+impl ChineseRemainder for Natural {
+    /// Combines a list of congruences $x \equiv r_i \mod m_i$ into a single congruence $x \equiv r
+    /// \mod m$, where $m = \operatorname{lcm}(m_1, m_2, \ldots, m_k)$ and $0 \leq r < m$.
+    ///
+    /// The moduli don't need to be pairwise coprime. If the congruences are inconsistent, meaning
+    /// that no $x$ satisfies all of them, `None` is returned. Otherwise, `Some((r, m))` is
+    /// returned. An empty list of congruences produces `Some((0, 1))`.
+    ///
+    /// If there are many congruences, a subproduct tree is used to combine them all at once; this
+    /// works when the moduli are pairwise coprime. Otherwise, the congruences are combined one at a
+    /// time, using the extended GCD algorithm.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^3 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of the `significant_bits`
+    /// of the moduli.
+    ///
+    /// # Panics
+    /// Panics if any modulus is zero or if any residue is greater than or equal to its modulus.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{ChineseRemainder, Pow};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::chinese_remainder([]),
+    ///     Some((Natural::from(0u32), Natural::from(1u32)))
+    /// );
+    /// assert_eq!(
+    ///     Natural::chinese_remainder([
+    ///         (Natural::from(2u32), Natural::from(3u32)),
+    ///         (Natural::from(3u32), Natural::from(5u32)),
+    ///         (Natural::from(2u32), Natural::from(7u32))
+    ///     ]),
+    ///     Some((Natural::from(23u32), Natural::from(105u32)))
+    /// );
+    /// assert_eq!(
+    ///     Natural::chinese_remainder([
+    ///         (Natural::from(3u32), Natural::from(4u32)),
+    ///         (Natural::from(4u32), Natural::from(6u32))
+    ///     ]),
+    ///     None
+    /// );
+    /// let (r, m) = Natural::chinese_remainder([
+    ///     (Natural::from(1u32), Natural::from(10u32).pow(20)),
+    ///     (Natural::from(2u32), Natural::from(3u32).pow(40)),
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(r.to_string(), "991254697425374721400000000000000000001");
+    /// assert_eq!(m.to_string(), "1215766545905692880100000000000000000000");
+    /// ```
+    fn chinese_remainder<I: IntoIterator<Item = (Natural, Natural)>>(
+        congruences: I,
+    ) -> Option<(Natural, Natural)> {
+        let congruences: Vec<(Natural, Natural)> = congruences.into_iter().collect();
+        for (r, m) in &congruences {
+            assert_ne!(*m, 0u32);
+            assert!(r < m, "residue must be reduced, but {r} >= {m}");
+        }
+        if congruences.len() >= CHINESE_REMAINDER_TREE_THRESHOLD {
+            if let Some(result) = chinese_remainder_subproduct_tree(&congruences) {
+                return Some(result);
+            }
+        }
+        chinese_remainder_pairwise(&congruences)
+    }
+}
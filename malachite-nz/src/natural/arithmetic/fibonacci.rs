@@ -0,0 +1,217 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{Fibonacci, Lucas, Parity, Square};
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::logic::traits::SignificantBits;
+
+// The largest $n$ such that $F_n$ fits in a `u128`.
+const FIBONACCI_TABLE_LIMIT: u64 = 186;
+
+// The largest $n$ such that $L_n$ fits in a `u128`.
+const LUCAS_TABLE_LIMIT: u64 = 184;
+
+// Returns $(F_n, F_{n-1})$.
+//
+// Starting from a small $k$ read off the table, this repeatedly doubles $k$, possibly adding one,
+// using the identities
+// - $F_{2k-1} = F_k^2 + F_{k-1}^2$,
+// - $F_{2k+1} = 4F_k^2 - F_{k-1}^2 + 2(-1)^k$, and
+// - $F_{2k} = F_{2k+1} - F_{2k-1}$,
+// so that each step costs two squarings.
+//
+// This is equivalent to `mpn_fib2_ui` from `mpn/generic/fib2_ui.c`, GMP 6.2.1.
+fn fibonacci2_helper(n: u64) -> (Natural, Natural) {
+    if n <= FIBONACCI_TABLE_LIMIT {
+        let (f, g) = u128::fibonacci2(n);
+        return (Natural::from(f), Natural::from(g));
+    }
+    let mut shift = n.significant_bits() - 7;
+    while n >> (shift - 1) <= FIBONACCI_TABLE_LIMIT {
+        shift -= 1;
+    }
+    let (f, g) = u128::fibonacci2(n >> shift);
+    let mut f = Natural::from(f);
+    let mut g = Natural::from(g);
+    while shift != 0 {
+        // (f, g) = (F_k, F_{k - 1}), where k = n >> shift
+        let k_odd = (n >> shift).odd();
+        shift -= 1;
+        let f_squared = f.square();
+        let g_squared = g.square();
+        // F_{2k - 1}
+        let f_2k_m_1 = &f_squared + &g_squared;
+        // F_{2k + 1}
+        let mut f_2k_p_1 = (f_squared << 2u64) - g_squared;
+        if k_odd {
+            f_2k_p_1 -= Natural::TWO;
+        } else {
+            f_2k_p_1 += Natural::TWO;
+        }
+        // F_{2k}
+        let f_2k = &f_2k_p_1 - &f_2k_m_1;
+        (f, g) = if (n >> shift).odd() {
+            (f_2k_p_1, f_2k)
+        } else {
+            (f_2k, f_2k_m_1)
+        };
+    }
+    (f, g)
+}
+
+impl Fibonacci for Natural {
+    /// Computes the $n$th Fibonacci number.
+    ///
+    /// $$
+    /// f(n) = F_n,
+    /// $$
+    /// where $F_0 = 0$, $F_1 = 1$, and $F_n = F_{n-1} + F_{n-2}$.
+    ///
+    /// $F_n = O(\varphi^n)$, where $\varphi = (1 + \sqrt{5})/2$ is the golden ratio.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Fibonacci;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::fibonacci(0), 0);
+    /// assert_eq!(Natural::fibonacci(1), 1);
+    /// assert_eq!(Natural::fibonacci(2), 1);
+    /// assert_eq!(Natural::fibonacci(3), 2);
+    /// assert_eq!(Natural::fibonacci(10), 55);
+    /// assert_eq!(
+    ///     Natural::fibonacci(200).to_string(),
+    ///     "280571172992510140037611932413038677189525"
+    /// );
+    /// ```
+    ///
+    /// This is equivalent to `mpz_fib_ui` from `mpz/fib_ui.c`, GMP 6.2.1.
+    fn fibonacci(n: u64) -> Natural {
+        if n <= FIBONACCI_TABLE_LIMIT {
+            return Natural::from(u128::fibonacci(n));
+        }
+        let k = n >> 1;
+        let (f, g) = fibonacci2_helper(k);
+        if n.odd() {
+            // F_{2k + 1} = (2F_k + F_{k - 1})(2F_k - F_{k - 1}) + 2(-1)^k
+            let f = f << 1u64;
+            let mut x = (&f + &g) * (f - g);
+            if k.odd() {
+                x -= Natural::TWO;
+            } else {
+                x += Natural::TWO;
+            }
+            x
+        } else {
+            // F_{2k} = F_k(F_k + 2F_{k - 1})
+            (&f + (g << 1u64)) * f
+        }
+    }
+
+    /// Computes the $n$th and $(n-1)$th Fibonacci numbers.
+    ///
+    /// $F_{-1}$ is defined to be 1, so that $F_1 = F_0 + F_{-1}$.
+    ///
+    /// $$
+    /// f(n) = (F_n, F_{n-1}).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Fibonacci;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::fibonacci2(0).to_debug_string(), "(0, 1)");
+    /// assert_eq!(Natural::fibonacci2(1).to_debug_string(), "(1, 0)");
+    /// assert_eq!(Natural::fibonacci2(10).to_debug_string(), "(55, 34)");
+    /// assert_eq!(
+    ///     Natural::fibonacci2(100).to_debug_string(),
+    ///     "(354224848179261915075, 218922995834555169026)"
+    /// );
+    /// ```
+    ///
+    /// This is equivalent to `mpz_fib2_ui` from `mpz/fib2_ui.c`, GMP 6.2.1.
+    #[inline]
+    fn fibonacci2(n: u64) -> (Natural, Natural) {
+        fibonacci2_helper(n)
+    }
+}
+
+impl Lucas for Natural {
+    /// Computes the $n$th Lucas number.
+    ///
+    /// $$
+    /// f(n) = L_n,
+    /// $$
+    /// where $L_0 = 2$, $L_1 = 1$, and $L_n = L_{n-1} + L_{n-2}$.
+    ///
+    /// $L_n = O(\varphi^n)$, where $\varphi = (1 + \sqrt{5})/2$ is the golden ratio.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Lucas;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::lucas(0), 2);
+    /// assert_eq!(Natural::lucas(1), 1);
+    /// assert_eq!(Natural::lucas(2), 3);
+    /// assert_eq!(Natural::lucas(3), 4);
+    /// assert_eq!(Natural::lucas(10), 123);
+    /// assert_eq!(
+    ///     Natural::lucas(200).to_string(),
+    ///     "627376215338105766356982006981782561278127"
+    /// );
+    /// ```
+    ///
+    /// This is equivalent to `mpz_lucnum_ui` from `mpz/lucnum_ui.c`, GMP 6.2.1.
+    fn lucas(n: u64) -> Natural {
+        if n <= LUCAS_TABLE_LIMIT {
+            return Natural::from(u128::lucas(n));
+        }
+        let k = n >> 1;
+        let (f, g) = fibonacci2_helper(k);
+        // L_k = F_k + 2F_{k - 1}
+        let l = &f + (&g << 1u64);
+        if n.odd() {
+            // L_{2k + 1} = L_k L_{k + 1} - (-1)^k, where L_{k + 1} = 3F_k + F_{k - 1}
+            let mut x = l * (f * Natural::from(3u32) + g);
+            if k.odd() {
+                x += Natural::ONE;
+            } else {
+                x -= Natural::ONE;
+            }
+            x
+        } else {
+            // L_{2k} = L_k^2 - 2(-1)^k
+            let mut x = l.square();
+            if k.odd() {
+                x += Natural::TWO;
+            } else {
+                x -= Natural::TWO;
+            }
+            x
+        }
+    }
+}
@@ -27,6 +27,10 @@ pub mod checked_sub;
 /// trait for subtracting the product of two numbers from another number, and checking whether the
 /// result is representable.
 pub mod checked_sub_mul;
+/// An implementation of
+/// [`ChineseRemainder`](malachite_base::num::arithmetic::traits::ChineseRemainder), a trait for
+/// combining a list of congruences using the Chinese remainder theorem.
+pub mod chinese_remainder;
 /// Implementations of [`CoprimeWith`](malachite_base::num::arithmetic::traits::CoprimeWith), a
 /// trait for determining whether two numbers are coprime.
 pub mod coprime_with;
@@ -74,6 +78,10 @@ pub mod eq_mod_power_of_2;
 /// [`Multifactorial`](malachite_base::num::arithmetic::traits::Multifactorial), and
 /// [`Subfactorial`](malachite_base::num::arithmetic::traits::Subfactorial).
 pub mod factorial;
+/// Implementations of [`Fibonacci`](malachite_base::num::arithmetic::traits::Fibonacci) and
+/// [`Lucas`](malachite_base::num::arithmetic::traits::Lucas), traits for computing Fibonacci and
+/// Lucas numbers.
+pub mod fibonacci;
 #[cfg(feature = "float_helpers")]
 pub mod float_add;
 #[cfg(feature = "float_helpers")]
@@ -516,6 +524,10 @@ pub mod mod_shl;
 /// assert_eq!(x, 7436663564915145u64);
 /// ```
 pub mod mod_shr;
+/// Implementations of [`ModSqrt`](malachite_base::num::arithmetic::traits::ModSqrt) and
+/// [`ModPrimePowerSqrt`](malachite_base::num::arithmetic::traits::ModPrimePowerSqrt), traits for
+/// finding square roots of a number modulo a prime or a prime power.
+pub mod mod_sqrt;
 /// Implementations of traits for squaring a number modulo another number.
 ///
 /// The traits are [`ModSquare`](malachite_base::num::arithmetic::traits::ModSquare),
@@ -0,0 +1,656 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::InnerNatural::Small;
+use crate::natural::Natural;
+use core::cmp::min;
+use malachite_base::num::arithmetic::traits::{
+    CheckedPow, DivExact, DivisibleBy, LegendreSymbol, ModAdd, ModInverse, ModMul, ModPow,
+    ModPowerOf2, ModPowerOf2Mul, ModPowerOf2Square, ModPowerOf2Sub, ModPrimePowerSqrt, ModShl,
+    ModSqrt, ModSquare, ModSub, Parity, Pow, PowerOf2,
+};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+
+// Finds a square root of x mod p, where p is an odd prime, p - 1 = 2^s q with q odd and s > 1, and
+// x is a nonzero quadratic residue mod p, using the Tonelli-Shanks algorithm.
+fn mod_sqrt_tonelli_shanks(x: &Natural, p: &Natural, p_minus_1: &Natural, s: u64) -> Natural {
+    let q = p_minus_1 >> s;
+    // Find a quadratic non-residue.
+    let mut z = Natural::TWO;
+    while (&z).legendre_symbol(p) != -1 {
+        z += Natural::ONE;
+    }
+    let mut m = s;
+    let mut c = z.mod_pow(&q, p);
+    let mut t = x.mod_pow(&q, p);
+    let mut r = x.mod_pow((q + Natural::ONE) >> 1u32, p);
+    while t != 1u32 {
+        // Find the least i such that t^(2^i) = 1.
+        let mut i = 1;
+        let mut t_pow = (&t).mod_square(p);
+        while t_pow != 1u32 {
+            i += 1;
+            if i == m {
+                // This can only happen if p is not prime. Confirm this by failing an assertion,
+                // which will produce an appropriate error message.
+                assert!(p.is_prime());
+                unreachable!()
+            }
+            t_pow = t_pow.mod_square(p);
+        }
+        let mut b = c;
+        for _ in i + 1..m {
+            b = b.mod_square(p);
+        }
+        r = r.mod_mul(&b, p);
+        c = b.mod_square(p);
+        t = t.mod_mul(&c, p);
+        m = i;
+    }
+    r
+}
+
+// Finds a square root of x mod p, where p is an odd prime and x is a nonzero quadratic residue mod
+// p, using Cipolla's algorithm. An a is found such that a^2 - x is a quadratic non-residue, and
+// (a + w)^((p + 1) / 2) is computed in the field F_p(w), where w^2 = a^2 - x.
+fn mod_sqrt_cipolla(x: &Natural, p: &Natural) -> Natural {
+    let mut a = Natural::ONE;
+    let w = loop {
+        let w = (&a).mod_square(p).mod_sub(x, p);
+        if (&w).legendre_symbol(p) == -1 {
+            break w;
+        }
+        a += Natural::ONE;
+    };
+    let e: Natural = (p + Natural::ONE) >> 1u32;
+    // (r_0 + r_1 w), starting at 1
+    let mut r_0 = Natural::ONE;
+    let mut r_1 = Natural::ZERO;
+    for i in (0..e.significant_bits()).rev() {
+        // (r_0 + r_1 w)^2 = (r_0^2 + r_1^2 w^2) + 2 r_0 r_1 w
+        let new_r_0 = (&r_0)
+            .mod_square(p)
+            .mod_add((&r_1).mod_square(p).mod_mul(&w, p), p);
+        r_1 = r_0.mod_mul(r_1, p).mod_shl(1u64, p);
+        r_0 = new_r_0;
+        if e.get_bit(i) {
+            // (r_0 + r_1 w)(a + w) = (a r_0 + r_1 w^2) + (r_0 + a r_1) w
+            let new_r_0 = (&r_0).mod_mul(&a, p).mod_add((&r_1).mod_mul(&w, p), p);
+            r_1 = r_1.mod_mul(&a, p).mod_add(r_0, p);
+            r_0 = new_r_0;
+        }
+    }
+    r_0
+}
+
+// Finds the smallest square root of x mod p, where p is an odd prime and 0 < x < p.
+fn mod_sqrt_odd_prime(x: &Natural, p: &Natural) -> Option<Natural> {
+    if let (Natural(Small(small_x)), Natural(Small(small_p))) = (x, p) {
+        return small_x.mod_sqrt(*small_p).map(Natural::from);
+    }
+    if x.legendre_symbol(p) != 1 {
+        return None;
+    }
+    let p_minus_1 = p - Natural::ONE;
+    let s = p_minus_1.trailing_zeros().unwrap();
+    let r = if s == 1 {
+        // p is 3 mod 4, so x^((p + 1) / 4) is a root.
+        x.mod_pow((p + Natural::ONE) >> 2u32, p)
+    } else if s * s > p.significant_bits() << 3 {
+        // Tonelli-Shanks takes O(s^2) multiplications, so when s is large, Cipolla's algorithm is
+        // faster.
+        mod_sqrt_cipolla(x, p)
+    } else {
+        mod_sqrt_tonelli_shanks(x, p, &p_minus_1, s)
+    };
+    let r_neg = p - &r;
+    Some(min(r, r_neg))
+}
+
+fn mod_sqrt_prime(x: &Natural, p: &Natural) -> Option<Natural> {
+    assert!(x < p, "x must be reduced mod p, but {x} >= {p}");
+    if *x == 0u32 || *p == 2u32 {
+        Some(x.clone())
+    } else {
+        mod_sqrt_odd_prime(x, p)
+    }
+}
+
+// Finds the smallest square root of x mod 2^k, where x is odd and less than 2^k.
+fn mod_power_of_2_sqrt_odd(x: &Natural, k: u64) -> Option<Natural> {
+    match k {
+        1 => Some(Natural::ONE),
+        2 => {
+            if *x == 1u32 {
+                Some(Natural::ONE)
+            } else {
+                None
+            }
+        }
+        _ => {
+            if x.mod_power_of_2(3) != 1u32 {
+                return None;
+            }
+            // Find y = x^(-1/2) mod 2^k using Newton's method: y <- y (3 - x y^2) / 2. Since x = 1
+            // mod 8, y = 1 is a good starting point. The intermediate computation is done mod
+            // 2^(k + 1) so that the division by 2 is exact.
+            let three = Natural::from(3u32);
+            let mut y = Natural::ONE;
+            loop {
+                let xy2 = x.mod_power_of_2_mul((&y).mod_power_of_2_square(k + 1), k + 1);
+                if (&xy2).mod_power_of_2(k) == 1u32 {
+                    break;
+                }
+                y = y.mod_power_of_2_mul((&three).mod_power_of_2_sub(xy2, k + 1) >> 1, k);
+            }
+            let r = x.mod_power_of_2_mul(y, k).mod_power_of_2(k - 1);
+            // The roots are r, -r, r + 2^(k - 1), and -r + 2^(k - 1).
+            let r_neg = Natural::power_of_2(k - 1) - &r;
+            Some(min(r, r_neg))
+        }
+    }
+}
+
+// Finds the smallest square root of x mod p^k, where p is an odd prime, k > 0, x is less than p^k,
+// and x is not divisible by p. A root mod p is lifted using Newton's method (Hensel lifting).
+fn mod_odd_prime_power_sqrt_unit(x: &Natural, p: &Natural, k: u64) -> Option<Natural> {
+    let mut r = mod_sqrt_odd_prime(&(x % p), p)?;
+    if k == 1 {
+        return Some(r);
+    }
+    let pk = p.pow(k);
+    loop {
+        let r_squared = (&r).mod_square(&pk);
+        if r_squared == *x {
+            break;
+        }
+        // r <- r - (r^2 - x) / (2r)
+        let inverse = (&r).mod_shl(1u64, &pk).mod_inverse(&pk).unwrap();
+        let delta = r_squared.mod_sub(x, &pk).mod_mul(inverse, &pk);
+        r = r.mod_sub(delta, &pk);
+    }
+    let r_neg = &pk - &r;
+    Some(min(r, r_neg))
+}
+
+fn mod_prime_power_sqrt(x: &Natural, p: &Natural, k: u64) -> Option<Natural> {
+    assert_ne!(k, 0);
+    if let (Natural(Small(small_x)), Natural(Small(small_p))) = (x, p) {
+        if CheckedPow::checked_pow(*small_p, k).is_some() {
+            return small_x.mod_prime_power_sqrt(*small_p, k).map(Natural::from);
+        }
+    }
+    let pk = p.pow(k);
+    assert!(*x < pk, "x must be reduced mod p^k, but {x} >= {pk}");
+    if *x == 0u32 {
+        return Some(Natural::ZERO);
+    }
+    // x = p^v u, where u is not divisible by p
+    let (v, u) = if *p == 2u32 {
+        let v = x.trailing_zeros().unwrap();
+        (v, x >> v)
+    } else {
+        let mut v = 0;
+        let mut u = x.clone();
+        while (&u).divisible_by(p) {
+            u = u.div_exact(p);
+            v += 1;
+        }
+        (v, u)
+    };
+    if v.odd() {
+        return None;
+    }
+    // If x = p^(2j) u, then the roots of x mod p^k are p^j times the roots of u mod p^(k - 2j), and
+    // the smallest root of x is p^j times the smallest root of u.
+    let k = k - v;
+    let root = if *p == 2u32 {
+        mod_power_of_2_sqrt_odd(&u, k)
+    } else {
+        mod_odd_prime_power_sqrt_unit(&u, p, k)
+    }?;
+    Some(root * p.pow(v >> 1))
+}
+
+impl ModSqrt<Natural> for Natural {
+    type Output = Natural;
+
+    /// Computes a square root of a [`Natural`] modulo a prime [`Natural`] $p$. The input must be
+    /// already reduced modulo $p$. Both [`Natural`]s are taken by value.
+    ///
+    /// Returns `None` if $x$ is not a quadratic residue modulo $p$. Otherwise, there are at most
+    /// two square roots, and the smaller one is returned.
+    ///
+    /// If $p$ is not prime, this function's behavior is unspecified. Since primality checking can
+    /// be expensive, $p$ is not tested for primality.
+    ///
+    /// $f(x, p) = \min \\{ y : 0 \leq y < p \text{ and } y^2 \equiv x \mod p \\}$, or `None` if
+    /// the set is empty.
+    ///
+    /// Either the Tonelli-Shanks algorithm or Cipolla's algorithm is used, depending on the power
+    /// of 2 dividing $p - 1$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `p`, and possibly panics if `p` is not prime.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{ModSqrt, Pow};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(2u32).mod_sqrt(Natural::from(7u32)),
+    ///     Some(Natural::from(3u32))
+    /// );
+    /// assert_eq!(Natural::from(3u32).mod_sqrt(Natural::from(7u32)), None);
+    /// assert_eq!(
+    ///     Natural::from(2u32)
+    ///         .mod_sqrt(Natural::from(2u32).pow(127) - Natural::from(1u32))
+    ///         .unwrap()
+    ///         .to_string(),
+    ///     "18446744073709551616"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_sqrt(self, p: Natural) -> Option<Natural> {
+        mod_sqrt_prime(&self, &p)
+    }
+}
+
+impl<'a> ModSqrt<&'a Natural> for Natural {
+    type Output = Natural;
+
+    /// Computes a square root of a [`Natural`] modulo a prime [`Natural`] $p$. The input must be
+    /// already reduced modulo $p$. The first [`Natural`] is taken by value and the second by
+    /// reference.
+    ///
+    /// Returns `None` if $x$ is not a quadratic residue modulo $p$. Otherwise, there are at most
+    /// two square roots, and the smaller one is returned.
+    ///
+    /// If $p$ is not prime, this function's behavior is unspecified. Since primality checking can
+    /// be expensive, $p$ is not tested for primality.
+    ///
+    /// $f(x, p) = \min \\{ y : 0 \leq y < p \text{ and } y^2 \equiv x \mod p \\}$, or `None` if
+    /// the set is empty.
+    ///
+    /// Either the Tonelli-Shanks algorithm or Cipolla's algorithm is used, depending on the power
+    /// of 2 dividing $p - 1$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `p`, and possibly panics if `p` is not prime.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{ModSqrt, Pow};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(2u32).mod_sqrt(&Natural::from(7u32)),
+    ///     Some(Natural::from(3u32))
+    /// );
+    /// assert_eq!(Natural::from(3u32).mod_sqrt(&Natural::from(7u32)), None);
+    /// assert_eq!(
+    ///     Natural::from(2u32)
+    ///         .mod_sqrt(&(Natural::from(2u32).pow(127) - Natural::from(1u32)))
+    ///         .unwrap()
+    ///         .to_string(),
+    ///     "18446744073709551616"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_sqrt(self, p: &'a Natural) -> Option<Natural> {
+        mod_sqrt_prime(&self, p)
+    }
+}
+
+impl ModSqrt<Natural> for &Natural {
+    type Output = Natural;
+
+    /// Computes a square root of a [`Natural`] modulo a prime [`Natural`] $p$. The input must be
+    /// already reduced modulo $p$. The first [`Natural`] is taken by reference and the second by
+    /// value.
+    ///
+    /// Returns `None` if $x$ is not a quadratic residue modulo $p$. Otherwise, there are at most
+    /// two square roots, and the smaller one is returned.
+    ///
+    /// If $p$ is not prime, this function's behavior is unspecified. Since primality checking can
+    /// be expensive, $p$ is not tested for primality.
+    ///
+    /// $f(x, p) = \min \\{ y : 0 \leq y < p \text{ and } y^2 \equiv x \mod p \\}$, or `None` if
+    /// the set is empty.
+    ///
+    /// Either the Tonelli-Shanks algorithm or Cipolla's algorithm is used, depending on the power
+    /// of 2 dividing $p - 1$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `p`, and possibly panics if `p` is not prime.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{ModSqrt, Pow};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(2u32)).mod_sqrt(Natural::from(7u32)),
+    ///     Some(Natural::from(3u32))
+    /// );
+    /// assert_eq!((&Natural::from(3u32)).mod_sqrt(Natural::from(7u32)), None);
+    /// assert_eq!(
+    ///     (&Natural::from(2u32))
+    ///         .mod_sqrt(Natural::from(2u32).pow(127) - Natural::from(1u32))
+    ///         .unwrap()
+    ///         .to_string(),
+    ///     "18446744073709551616"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_sqrt(self, p: Natural) -> Option<Natural> {
+        mod_sqrt_prime(self, &p)
+    }
+}
+
+impl ModSqrt<&Natural> for &Natural {
+    type Output = Natural;
+
+    /// Computes a square root of a [`Natural`] modulo a prime [`Natural`] $p$. The input must be
+    /// already reduced modulo $p$. Both [`Natural`]s are taken by reference.
+    ///
+    /// Returns `None` if $x$ is not a quadratic residue modulo $p$. Otherwise, there are at most
+    /// two square roots, and the smaller one is returned.
+    ///
+    /// If $p$ is not prime, this function's behavior is unspecified. Since primality checking can
+    /// be expensive, $p$ is not tested for primality.
+    ///
+    /// $f(x, p) = \min \\{ y : 0 \leq y < p \text{ and } y^2 \equiv x \mod p \\}$, or `None` if
+    /// the set is empty.
+    ///
+    /// Either the Tonelli-Shanks algorithm or Cipolla's algorithm is used, depending on the power
+    /// of 2 dividing $p - 1$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `p`, and possibly panics if `p` is not prime.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{ModSqrt, Pow};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(2u32)).mod_sqrt(&Natural::from(7u32)),
+    ///     Some(Natural::from(3u32))
+    /// );
+    /// assert_eq!((&Natural::from(3u32)).mod_sqrt(&Natural::from(7u32)), None);
+    /// assert_eq!(
+    ///     (&Natural::from(2u32))
+    ///         .mod_sqrt(&(Natural::from(2u32).pow(127) - Natural::from(1u32)))
+    ///         .unwrap()
+    ///         .to_string(),
+    ///     "18446744073709551616"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_sqrt(self, p: &Natural) -> Option<Natural> {
+        mod_sqrt_prime(self, p)
+    }
+}
+
+impl ModPrimePowerSqrt<Natural> for Natural {
+    type Output = Natural;
+
+    /// Computes a square root of a [`Natural`] modulo a prime power $p^k$. The input must be
+    /// already reduced modulo $p^k$. Both [`Natural`]s are taken by value.
+    ///
+    /// Returns `None` if $x$ is not a quadratic residue modulo $p^k$. Otherwise, the smallest
+    /// square root is returned.
+    ///
+    /// If $p$ is not prime, this function's behavior is unspecified. Since primality checking can
+    /// be expensive, $p$ is not tested for primality.
+    ///
+    /// $f(x, p, k) = \min \\{ y : 0 \leq y < p^k \text{ and } y^2 \equiv x \mod p^k \\}$, or
+    /// `None` if the set is empty.
+    ///
+    /// A square root modulo $p$ is found and then lifted to a square root modulo $p^k$ using
+    /// Hensel lifting.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `k * p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `k` is zero or if `self` is greater than or equal to $p^k$. Possibly panics if `p`
+    /// is not prime.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPrimePowerSqrt;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(2u32).mod_prime_power_sqrt(Natural::from(7u32), 2),
+    ///     Some(Natural::from(10u32))
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(3u32).mod_prime_power_sqrt(Natural::from(2u32), 3),
+    ///     None
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(17u32)
+    ///         .mod_prime_power_sqrt(Natural::from(2u32), 100)
+    ///         .unwrap()
+    ///         .to_string(),
+    ///     "217788382556221841343574235415"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_prime_power_sqrt(self, p: Natural, k: u64) -> Option<Natural> {
+        mod_prime_power_sqrt(&self, &p, k)
+    }
+}
+
+impl<'a> ModPrimePowerSqrt<&'a Natural> for Natural {
+    type Output = Natural;
+
+    /// Computes a square root of a [`Natural`] modulo a prime power $p^k$. The input must be
+    /// already reduced modulo $p^k$. The first [`Natural`] is taken by value and the second by
+    /// reference.
+    ///
+    /// Returns `None` if $x$ is not a quadratic residue modulo $p^k$. Otherwise, the smallest
+    /// square root is returned.
+    ///
+    /// If $p$ is not prime, this function's behavior is unspecified. Since primality checking can
+    /// be expensive, $p$ is not tested for primality.
+    ///
+    /// $f(x, p, k) = \min \\{ y : 0 \leq y < p^k \text{ and } y^2 \equiv x \mod p^k \\}$, or
+    /// `None` if the set is empty.
+    ///
+    /// A square root modulo $p$ is found and then lifted to a square root modulo $p^k$ using
+    /// Hensel lifting.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `k * p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `k` is zero or if `self` is greater than or equal to $p^k$. Possibly panics if `p`
+    /// is not prime.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPrimePowerSqrt;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(2u32).mod_prime_power_sqrt(&Natural::from(7u32), 2),
+    ///     Some(Natural::from(10u32))
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(3u32).mod_prime_power_sqrt(&Natural::from(2u32), 3),
+    ///     None
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(17u32)
+    ///         .mod_prime_power_sqrt(&Natural::from(2u32), 100)
+    ///         .unwrap()
+    ///         .to_string(),
+    ///     "217788382556221841343574235415"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_prime_power_sqrt(self, p: &'a Natural, k: u64) -> Option<Natural> {
+        mod_prime_power_sqrt(&self, p, k)
+    }
+}
+
+impl ModPrimePowerSqrt<Natural> for &Natural {
+    type Output = Natural;
+
+    /// Computes a square root of a [`Natural`] modulo a prime power $p^k$. The input must be
+    /// already reduced modulo $p^k$. The first [`Natural`] is taken by reference and the second by
+    /// value.
+    ///
+    /// Returns `None` if $x$ is not a quadratic residue modulo $p^k$. Otherwise, the smallest
+    /// square root is returned.
+    ///
+    /// If $p$ is not prime, this function's behavior is unspecified. Since primality checking can
+    /// be expensive, $p$ is not tested for primality.
+    ///
+    /// $f(x, p, k) = \min \\{ y : 0 \leq y < p^k \text{ and } y^2 \equiv x \mod p^k \\}$, or
+    /// `None` if the set is empty.
+    ///
+    /// A square root modulo $p$ is found and then lifted to a square root modulo $p^k$ using
+    /// Hensel lifting.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `k * p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `k` is zero or if `self` is greater than or equal to $p^k$. Possibly panics if `p`
+    /// is not prime.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPrimePowerSqrt;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(2u32)).mod_prime_power_sqrt(Natural::from(7u32), 2),
+    ///     Some(Natural::from(10u32))
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(3u32)).mod_prime_power_sqrt(Natural::from(2u32), 3),
+    ///     None
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(17u32))
+    ///         .mod_prime_power_sqrt(Natural::from(2u32), 100)
+    ///         .unwrap()
+    ///         .to_string(),
+    ///     "217788382556221841343574235415"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_prime_power_sqrt(self, p: Natural, k: u64) -> Option<Natural> {
+        mod_prime_power_sqrt(self, &p, k)
+    }
+}
+
+impl ModPrimePowerSqrt<&Natural> for &Natural {
+    type Output = Natural;
+
+    /// Computes a square root of a [`Natural`] modulo a prime power $p^k$. The input must be
+    /// already reduced modulo $p^k$. Both [`Natural`]s are taken by reference.
+    ///
+    /// Returns `None` if $x$ is not a quadratic residue modulo $p^k$. Otherwise, the smallest
+    /// square root is returned.
+    ///
+    /// If $p$ is not prime, this function's behavior is unspecified. Since primality checking can
+    /// be expensive, $p$ is not tested for primality.
+    ///
+    /// $f(x, p, k) = \min \\{ y : 0 \leq y < p^k \text{ and } y^2 \equiv x \mod p^k \\}$, or
+    /// `None` if the set is empty.
+    ///
+    /// A square root modulo $p$ is found and then lifted to a square root modulo $p^k$ using
+    /// Hensel lifting.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `k * p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `k` is zero or if `self` is greater than or equal to $p^k$. Possibly panics if `p`
+    /// is not prime.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPrimePowerSqrt;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(2u32)).mod_prime_power_sqrt(&Natural::from(7u32), 2),
+    ///     Some(Natural::from(10u32))
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(3u32)).mod_prime_power_sqrt(&Natural::from(2u32), 3),
+    ///     None
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(17u32))
+    ///         .mod_prime_power_sqrt(&Natural::from(2u32), 100)
+    ///         .unwrap()
+    ///         .to_string(),
+    ///     "217788382556221841343574235415"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_prime_power_sqrt(self, p: &Natural, k: u64) -> Option<Natural> {
+        mod_prime_power_sqrt(self, p, k)
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use crate::platform::Limb;
+use alloc::vec::Vec;
+use core::mem::size_of;
+
+const LIMB_BYTES: usize = size_of::<Limb>();
+
+// Given bytes in ascending order, returns the limbs of the number they represent, in ascending
+// order. The output may have trailing zero limbs.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+pub_crate_test! {limbs_from_bytes_le(bytes: &[u8]) -> Vec<Limb> {
+    let mut chunks = bytes.chunks_exact(LIMB_BYTES);
+    let mut xs = Vec::with_capacity(bytes.len().div_ceil(LIMB_BYTES));
+    for chunk in &mut chunks {
+        xs.push(Limb::from_le_bytes(chunk.try_into().unwrap()));
+    }
+    let remainder = chunks.remainder();
+    if !remainder.is_empty() {
+        let mut chunk = [0; LIMB_BYTES];
+        chunk[..remainder.len()].copy_from_slice(remainder);
+        xs.push(Limb::from_le_bytes(chunk));
+    }
+    xs
+}}
+
+// Given bytes in descending order, returns the limbs of the number they represent, in ascending
+// order. The output may have trailing zero limbs.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+pub_crate_test! {limbs_from_bytes_be(bytes: &[u8]) -> Vec<Limb> {
+    let mut chunks = bytes.rchunks_exact(LIMB_BYTES);
+    let mut xs = Vec::with_capacity(bytes.len().div_ceil(LIMB_BYTES));
+    for chunk in &mut chunks {
+        xs.push(Limb::from_be_bytes(chunk.try_into().unwrap()));
+    }
+    let remainder = chunks.remainder();
+    if !remainder.is_empty() {
+        let mut chunk = [0; LIMB_BYTES];
+        chunk[LIMB_BYTES - remainder.len()..].copy_from_slice(remainder);
+        xs.push(Limb::from_be_bytes(chunk));
+    }
+    xs
+}}
+
+impl Natural {
+    /// Converts a slice of bytes to a [`Natural`].
+    ///
+    /// The bytes are in ascending order, so that less-significant bytes have lower indices in the
+    /// input slice. Trailing zero bytes are allowed and ignored.
+    ///
+    /// The bytes are copied directly into [limbs](crate#limbs), so this function is faster than
+    /// [`from_power_of_2_digits_asc`](malachite_base::num::conversion::traits::PowerOf2Digits::from_power_of_2_digits_asc)
+    /// with a `log_base` of 8, and it gives the same result regardless of the size of a
+    /// [`Limb`](crate#limbs).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from_bytes_le(&[]), 0);
+    /// assert_eq!(Natural::from_bytes_le(&[123]), 123);
+    /// assert_eq!(Natural::from_bytes_le(&[123, 0, 0]), 123);
+    /// assert_eq!(
+    ///     Natural::from_bytes_le(&[0, 16, 165, 212, 232]),
+    ///     1000000000000u64
+    /// );
+    /// ```
+    pub fn from_bytes_le(bytes: &[u8]) -> Natural {
+        Natural::from_owned_limbs_asc(limbs_from_bytes_le(bytes))
+    }
+
+    /// Converts a slice of bytes to a [`Natural`].
+    ///
+    /// The bytes are in descending order, so that less-significant bytes have higher indices in
+    /// the input slice. Leading zero bytes are allowed and ignored.
+    ///
+    /// The bytes are copied directly into [limbs](crate#limbs), so this function is faster than
+    /// [`from_power_of_2_digits_desc`](malachite_base::num::conversion::traits::PowerOf2Digits::from_power_of_2_digits_desc)
+    /// with a `log_base` of 8, and it gives the same result regardless of the size of a
+    /// [`Limb`](crate#limbs).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from_bytes_be(&[]), 0);
+    /// assert_eq!(Natural::from_bytes_be(&[123]), 123);
+    /// assert_eq!(Natural::from_bytes_be(&[0, 0, 123]), 123);
+    /// assert_eq!(
+    ///     Natural::from_bytes_be(&[232, 212, 165, 16, 0]),
+    ///     1000000000000u64
+    /// );
+    /// ```
+    pub fn from_bytes_be(bytes: &[u8]) -> Natural {
+        Natural::from_owned_limbs_asc(limbs_from_bytes_be(bytes))
+    }
+}
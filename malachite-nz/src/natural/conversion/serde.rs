@@ -8,8 +8,19 @@
 
 use crate::natural::{Natural, SerdeNatural};
 use alloc::string::String;
+#[cfg(feature = "serde")]
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use core::cmp::min;
 use core::convert::TryFrom;
+#[cfg(feature = "serde")]
+use core::fmt;
 use malachite_base::num::conversion::traits::FromStringBase;
+#[cfg(feature = "serde")]
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{Error, SeqAccess, Unexpected, Visitor},
+};
 
 impl From<Natural> for SerdeNatural {
     #[inline]
@@ -31,3 +42,94 @@ impl TryFrom<SerdeNatural> for Natural {
         }
     }
 }
+
+/// A wrapper around a [`Natural`] that is serialized compactly.
+///
+/// The default serialization of a [`Natural`] is a hexadecimal string, which is readable but large
+/// and slow to produce. A `CompactNatural` is instead serialized as a byte string holding the
+/// [`Natural`]'s bytes in little-endian order, with no trailing zero bytes; so zero is serialized
+/// as an empty byte string. Binary formats such as bincode or postcard store this as a length
+/// followed by the bytes themselves. The encoding does not depend on the [`Limb`](crate#limbs)
+/// size, so data serialized by a build using `32_bit_limbs` can be deserialized by one that
+/// doesn't, and vice versa.
+///
+/// Deserialization rejects byte strings with trailing zero bytes, so every [`Natural`] has exactly
+/// one encoding.
+///
+/// To serialize a [`Natural`] without taking ownership of it, use [`CompactNaturalRef`].
+///
+/// # Examples
+/// ```
+/// use malachite_nz::natural::conversion::serde::CompactNatural;
+/// use malachite_nz::natural::Natural;
+///
+/// let x = CompactNatural(Natural::from(1000u32));
+/// assert_eq!(serde_json::to_string(&x).unwrap(), "[232,3]");
+/// assert_eq!(
+///     serde_json::from_str::<CompactNatural>("[232,3]").unwrap(),
+///     x
+/// );
+/// ```
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct CompactNatural(pub Natural);
+
+/// A wrapper around a reference to a [`Natural`] that is serialized compactly, in the same format
+/// as a [`CompactNatural`].
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CompactNaturalRef<'a>(pub &'a Natural);
+
+#[cfg(feature = "serde")]
+impl Serialize for CompactNaturalRef<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.0.to_bytes_le())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for CompactNatural {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        CompactNaturalRef(&self.0).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for CompactNatural {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_bytes(BytesVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+struct BytesVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = CompactNatural;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("the little-endian bytes of a Natural, with no trailing zeros")
+    }
+
+    fn visit_bytes<E: Error>(self, bytes: &[u8]) -> Result<CompactNatural, E> {
+        if bytes.last() == Some(&0) {
+            return Err(E::invalid_value(Unexpected::Bytes(bytes), &self));
+        }
+        Ok(CompactNatural(Natural::from_bytes_le(bytes)))
+    }
+
+    // Human-readable formats, such as JSON, serialize bytes as a sequence of numbers
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<CompactNatural, A::Error> {
+        // Don't trust the size hint for more than 1 MiB of preallocation
+        let mut bytes = Vec::with_capacity(min(seq.size_hint().unwrap_or(0), 1 << 20));
+        while let Some(byte) = seq.next_element::<u8>()? {
+            bytes.push(byte);
+        }
+        self.visit_bytes(&bytes)
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::InnerNatural::{Large, Small};
+use crate::natural::Natural;
+use crate::platform::Limb;
+use alloc::vec::Vec;
+use core::mem::size_of;
+use core::slice;
+
+const LIMB_BYTES: usize = size_of::<Limb>();
+
+// Given limbs in ascending order, returns the bytes of the number they represent, in ascending
+// order. There are no trailing zero bytes.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+pub_crate_test! {limbs_to_bytes_le(xs: &[Limb]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(xs.len() * LIMB_BYTES);
+    for x in xs {
+        bytes.extend_from_slice(&x.to_le_bytes());
+    }
+    while bytes.last() == Some(&0) {
+        bytes.pop();
+    }
+    bytes
+}}
+
+// Given limbs in ascending order, returns the bytes of the number they represent, in descending
+// order. There are no leading zero bytes.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+pub_crate_test! {limbs_to_bytes_be(xs: &[Limb]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(xs.len() * LIMB_BYTES);
+    for x in xs.iter().rev() {
+        if bytes.is_empty() {
+            let leading_zero_bytes = (x.leading_zeros() >> 3) as usize;
+            bytes.extend_from_slice(&x.to_be_bytes()[leading_zero_bytes..]);
+        } else {
+            bytes.extend_from_slice(&x.to_be_bytes());
+        }
+    }
+    bytes
+}}
+
+impl Natural {
+    /// Returns the bytes of a [`Natural`], in ascending order, so that less-significant bytes have
+    /// lower indices in the output vector.
+    ///
+    /// There are no trailing zero bytes; in particular, zero is converted to an empty vector.
+    ///
+    /// The [limbs](crate#limbs) are copied directly into bytes, so this function is faster than
+    /// [`to_power_of_2_digits_asc`](malachite_base::num::conversion::traits::PowerOf2Digits::to_power_of_2_digits_asc)
+    /// with a `log_base` of 8, and it gives the same result regardless of the size of a
+    /// [`Limb`](crate#limbs).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!(Natural::ZERO.to_bytes_le().is_empty());
+    /// assert_eq!(Natural::from(123u32).to_bytes_le(), &[123]);
+    /// assert_eq!(
+    ///     Natural::from(10u32).pow(12).to_bytes_le(),
+    ///     &[0, 16, 165, 212, 232]
+    /// );
+    /// ```
+    pub fn to_bytes_le(&self) -> Vec<u8> {
+        match self {
+            Natural(Small(x)) => limbs_to_bytes_le(slice::from_ref(x)),
+            Natural(Large(xs)) => limbs_to_bytes_le(xs),
+        }
+    }
+
+    /// Returns the bytes of a [`Natural`], in descending order, so that less-significant bytes have
+    /// higher indices in the output vector.
+    ///
+    /// There are no leading zero bytes; in particular, zero is converted to an empty vector.
+    ///
+    /// The [limbs](crate#limbs) are copied directly into bytes, so this function is faster than
+    /// [`to_power_of_2_digits_desc`](malachite_base::num::conversion::traits::PowerOf2Digits::to_power_of_2_digits_desc)
+    /// with a `log_base` of 8, and it gives the same result regardless of the size of a
+    /// [`Limb`](crate#limbs).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!(Natural::ZERO.to_bytes_be().is_empty());
+    /// assert_eq!(Natural::from(123u32).to_bytes_be(), &[123]);
+    /// assert_eq!(
+    ///     Natural::from(10u32).pow(12).to_bytes_be(),
+    ///     &[232, 212, 165, 16, 0]
+    /// );
+    /// ```
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        match self {
+            Natural(Small(x)) => limbs_to_bytes_be(slice::from_ref(x)),
+            Natural(Large(xs)) => limbs_to_bytes_be(xs),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{Lcm, Pow, PowerOf2};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::{CarmichaelLambda, Factor};
+
+impl CarmichaelLambda for Natural {
+    type Output = Natural;
+
+    /// Computes Carmichael's function of a [`Natural`]; that is, the smallest positive $k$ such
+    /// that $x^k \equiv 1 \mod n$ for every $x$ coprime to $n$. This is the exponent of the
+    /// multiplicative group of integers modulo $n$.
+    ///
+    /// $$
+    /// \lambda(n) = \operatorname{lcm}(\lambda(p_1^{e_1}), \ldots, \lambda(p_k^{e_k})),
+    /// $$
+    /// where $n = \prod_{i=1}^k p_i^{e_i}$ is the prime factorization of $n$, $\lambda(p^e) =
+    /// p^{e - 1}(p - 1)$ for odd $p$, $\lambda(2) = 1$, $\lambda(4) = 2$, and $\lambda(2^e) =
+    /// 2^{e - 2}$ for $e \geq 3$.
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by factoring `self`; see
+    /// [`Natural::factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::factorization::traits::CarmichaelLambda;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(36u32).carmichael_lambda(), 6);
+    /// assert_eq!(
+    ///     Natural::from(10u32).pow(30).carmichael_lambda().to_string(),
+    ///     "50000000000000000000000000000"
+    /// );
+    /// assert_eq!(
+    ///     (Natural::from(2u32).pow(64) + Natural::from(1u32))
+    ///         .carmichael_lambda()
+    ///         .to_string(),
+    ///     "72057331223781120"
+    /// );
+    /// ```
+    fn carmichael_lambda(&self) -> Natural {
+        self.factor()
+            .into_iter()
+            .fold(Natural::ONE, |lambda, (p, e)| {
+                let lambda_prime_power = if p == 2u32 {
+                    // lambda(2) = 1, lambda(4) = 2, and lambda(2^e) = 2^(e - 2) for e >= 3
+                    Natural::power_of_2(if e < 3 { e - 1 } else { e - 2 })
+                } else {
+                    (&p).pow(e - 1) * (p - Natural::ONE)
+                };
+                lambda.lcm(lambda_prime_power)
+            })
+    }
+}
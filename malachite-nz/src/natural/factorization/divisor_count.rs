@@ -0,0 +1,49 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::{DivisorCount, Factor};
+
+impl DivisorCount for Natural {
+    type Output = Natural;
+
+    /// Counts the positive divisors of a [`Natural`].
+    ///
+    /// $$
+    /// d(n) = \prod_{i=1}^k (e_i + 1),
+    /// $$
+    /// where $n = \prod_{i=1}^k p_i^{e_i}$ is the prime factorization of $n$.
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by factoring `self`; see
+    /// [`Natural::factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::factorization::traits::DivisorCount;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(36u32).divisor_count(), 9);
+    /// assert_eq!(Natural::from(10u32).pow(30).divisor_count(), 961);
+    /// assert_eq!(
+    ///     (Natural::from(2u32).pow(127) - Natural::from(1u32)).divisor_count(),
+    ///     2
+    /// );
+    /// ```
+    fn divisor_count(&self) -> Natural {
+        // d(p_1^e_1 ... p_k^e_k) = (e_1 + 1) ... (e_k + 1)
+        self.factor()
+            .into_iter()
+            .fold(Natural::ONE, |d, (_, e)| d * Natural::from(e + 1))
+    }
+}
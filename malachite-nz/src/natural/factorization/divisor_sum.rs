@@ -0,0 +1,60 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{DivExact, Pow};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::{DivisorSum, Factor};
+
+impl DivisorSum for Natural {
+    type Output = Natural;
+
+    /// Computes the sum of the positive divisors of a [`Natural`].
+    ///
+    /// $$
+    /// \sigma(n) = \prod_{i=1}^k \sum_{j=0}^{e_i} p_i^j = \prod_{i=1}^k \frac{p_i^{e_i + 1} -
+    /// 1}{p_i - 1},
+    /// $$
+    /// where $n = \prod_{i=1}^k p_i^{e_i}$ is the prime factorization of $n$.
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by factoring `self`; see
+    /// [`Natural::factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::factorization::traits::DivisorSum;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(36u32).divisor_sum(), 91);
+    /// assert_eq!(
+    ///     Natural::from(10u32).pow(30).divisor_sum().to_string(),
+    ///     "2499999998835846781730114984557"
+    /// );
+    /// assert_eq!(
+    ///     (Natural::from(2u32).pow(64) + Natural::from(1u32))
+    ///         .divisor_sum()
+    ///         .to_string(),
+    ///     "18446811354131136516"
+    /// );
+    /// ```
+    fn divisor_sum(&self) -> Natural {
+        // sigma(p_1^e_1 ... p_k^e_k) = (p_1^(e_1 + 1) - 1) / (p_1 - 1) ... (p_k^(e_k + 1) - 1) /
+        // (p_k - 1)
+        self.factor()
+            .into_iter()
+            .fold(Natural::ONE, |sigma, (p, e)| {
+                let q = &p - Natural::ONE;
+                sigma * (p.pow(e + 1) - Natural::ONE).div_exact(q)
+            })
+    }
+}
@@ -0,0 +1,63 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use alloc::vec::{IntoIter, Vec};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::{Divisors, Factor};
+
+impl Divisors for Natural {
+    type I = IntoIter<Natural>;
+
+    /// Returns an iterator that generates all the positive divisors of a [`Natural`], in ascending
+    /// order.
+    ///
+    /// The divisors are all generated, and sorted, before the iterator is returned, so this
+    /// function should only be used on [`Natural`]s with a manageable number of divisors.
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by factoring `self` (see [`Natural::factor`](Factor::factor))
+    /// and by sorting the divisors.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use itertools::Itertools;
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::factorization::traits::Divisors;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(36u32).divisors().collect_vec().to_debug_string(),
+    ///     "[1, 2, 3, 4, 6, 9, 12, 18, 36]"
+    /// );
+    /// assert_eq!(
+    ///     (Natural::from(2u32).pow(64) + Natural::from(1u32))
+    ///         .divisors()
+    ///         .collect_vec()
+    ///         .to_debug_string(),
+    ///     "[1, 274177, 67280421310721, 18446744073709551617]"
+    /// );
+    /// ```
+    fn divisors(&self) -> IntoIter<Natural> {
+        let mut divisors: Vec<Natural> = alloc::vec![Natural::ONE];
+        for (p, e) in self.factor() {
+            // Multiply each divisor found so far by p, p^2, ..., p^e.
+            let len = divisors.len();
+            for i in 0..usize::try_from(e).unwrap() * len {
+                let d = &divisors[i] * &p;
+                divisors.push(d);
+            }
+        }
+        divisors.sort_unstable();
+        divisors.into_iter()
+    }
+}
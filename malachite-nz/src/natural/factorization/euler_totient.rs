@@ -0,0 +1,56 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::{EulerTotient, Factor};
+
+impl EulerTotient for Natural {
+    type Output = Natural;
+
+    /// Computes Euler's totient function of a [`Natural`]; that is, the number of integers in $[1,
+    /// n]$ that are coprime to $n$.
+    ///
+    /// $$
+    /// \varphi(n) = \prod_{i=1}^k p_i^{e_i - 1}(p_i - 1),
+    /// $$
+    /// where $n = \prod_{i=1}^k p_i^{e_i}$ is the prime factorization of $n$.
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by factoring `self`; see
+    /// [`Natural::factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::factorization::traits::EulerTotient;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(36u32).euler_totient(), 12);
+    /// assert_eq!(
+    ///     Natural::from(10u32).pow(30).euler_totient().to_string(),
+    ///     "400000000000000000000000000000"
+    /// );
+    /// assert_eq!(
+    ///     (Natural::from(2u32).pow(64) + Natural::from(1u32))
+    ///         .euler_totient()
+    ///         .to_string(),
+    ///     "18446676793287966720"
+    /// );
+    /// ```
+    fn euler_totient(&self) -> Natural {
+        // phi(p_1^e_1 ... p_k^e_k) = p_1^(e_1 - 1) (p_1 - 1) ... p_k^(e_k - 1) (p_k - 1)
+        self.factor().into_iter().fold(Natural::ONE, |phi, (p, e)| {
+            phi * (&p).pow(e - 1) * (p - Natural::ONE)
+        })
+    }
+}
@@ -0,0 +1,462 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use crate::platform::Limb;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::{max, min};
+use malachite_base::num::arithmetic::traits::{
+    AbsDiff, CheckedRoot, DivExact, DivMod, Gcd, ModAdd, ModInverse, ModPow, ModSub,
+};
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::conversion::traits::{ConvertibleFrom, ExactFrom};
+use malachite_base::num::factorization::traits::{Factor, IsPrime, Primes};
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+
+// Primes less than this are removed by trial division.
+const TRIAL_DIVISION_LIMIT: Limb = 1 << 12;
+
+// The maximum number of iterations of Pollard's rho algorithm that are attempted before moving on
+// to the other methods.
+const RHO_MAX_ITERS: u64 = 1 << 16;
+
+// The stage-1 bound of Pollard's $p-1$ method.
+const P_MINUS_1_B1: Limb = 100000;
+
+// Stage-1 bounds and numbers of curves for the elliptic curve method. These are the usual choices
+// for finding factors of up to 15, 20, 25, 30, 35, 40, and 45 digits. The stage-2 bound is always
+// `ECM_B2_MULTIPLIER` times the stage-1 bound. If all of these curves fail, the last stage-1 bound
+// is repeatedly tripled.
+const ECM_SCHEDULE: [(Limb, u64); 7] = [
+    (2000, 25),
+    (11000, 90),
+    (50000, 300),
+    (250000, 700),
+    (1000000, 1800),
+    (3000000, 5100),
+    (11000000, 10600),
+];
+
+const ECM_B2_MULTIPLIER: Limb = 100;
+
+// Returns the largest power of the prime $p$ that is less than or equal to $b$, where $p \leq b$.
+const fn largest_power_at_most(p: Limb, b: Limb) -> Limb {
+    let mut q = p;
+    while let Some(next) = q.checked_mul(p) {
+        if next > b {
+            break;
+        }
+        q = next;
+    }
+    q
+}
+
+// If $g$ is a nontrivial factor of $n$, returns it.
+fn nontrivial_factor(g: Natural, n: &Natural) -> Option<Natural> {
+    if g != 1u32 && g != *n { Some(g) } else { None }
+}
+
+// Returns $xy \bmod n$.
+//
+// For the moduli that occur during factorization, multiplying and then reducing is faster than
+// `mod_mul` or `mod_square`.
+#[inline]
+fn mul_mod(x: &Natural, y: &Natural, n: &Natural) -> Natural {
+    (x * y) % n
+}
+
+// Attempts to find a nontrivial factor of the odd composite $n$ using Brent's variant of Pollard's
+// rho algorithm with the map $x \mapsto x^2 + c$, performing at most roughly `max_iters` iterations.
+// Products of differences are accumulated so that only one gcd is computed for every 128
+// iterations.
+fn pollard_rho_brent(n: &Natural, c: Limb, max_iters: u64) -> Option<Natural> {
+    const BATCH_SIZE: u64 = 128;
+    let c = Natural::from(c);
+    let f = |x: &Natural| mul_mod(x, x, n).mod_add(&c, n);
+    let mut y = Natural::TWO;
+    let mut x = y.clone();
+    let mut ys = y.clone();
+    let mut q = Natural::ONE;
+    let mut g = Natural::ONE;
+    let mut r = 1u64;
+    let mut iters = 0u64;
+    while g == 1u32 {
+        x.clone_from(&y);
+        for _ in 0..r {
+            y = f(&y);
+        }
+        let mut k = 0;
+        while k < r && g == 1u32 {
+            ys.clone_from(&y);
+            for _ in 0..min(BATCH_SIZE, r - k) {
+                y = f(&y);
+                q = mul_mod(&q, &(&x).abs_diff(&y), n);
+            }
+            g = (&q).gcd(n);
+            k += BATCH_SIZE;
+        }
+        iters += r;
+        if g == 1u32 && iters > max_iters {
+            return None;
+        }
+        r <<= 1;
+    }
+    if g == *n {
+        // The batch overshot; redo its iterations one at a time.
+        loop {
+            ys = f(&ys);
+            g = (&x).abs_diff(&ys).gcd(n);
+            if g != 1u32 {
+                break;
+            }
+        }
+    }
+    nontrivial_factor(g, n)
+}
+
+// Attempts to find a nontrivial factor of $n$ using stage 1 of Pollard's $p-1$ method with bound
+// $B_1$. This succeeds if $n$ has a prime factor $p$ such that $p-1$ is $B_1$-powersmooth.
+fn pollard_p_minus_1(n: &Natural, b1: Limb) -> Option<Natural> {
+    let mut a = Natural::TWO;
+    for p in Limb::primes_less_than_or_equal_to(&b1) {
+        a = a.mod_pow(Natural::from(largest_power_at_most(p, b1)), n);
+    }
+    if a == 0u32 {
+        return None;
+    }
+    nontrivial_factor((a - Natural::ONE).gcd(n), n)
+}
+
+// A point on a Montgomery curve $By^2 = x^3 + Ax^2 + x$ modulo $n$, in projective $(X : Z)$
+// coordinates, with $y$ omitted.
+#[derive(Clone)]
+struct MontgomeryPoint {
+    x: Natural,
+    z: Natural,
+}
+
+// Arithmetic on the Montgomery curve with parameter $a_{24} = (A + 2)/4$, modulo $n$.
+struct MontgomeryCurve<'a> {
+    n: &'a Natural,
+    a24: Natural,
+}
+
+impl MontgomeryCurve<'_> {
+    // Returns $2P$.
+    fn double(&self, p: &MontgomeryPoint) -> MontgomeryPoint {
+        let n = self.n;
+        let sum = (&p.x).mod_add(&p.z, n);
+        let sum = mul_mod(&sum, &sum, n);
+        let difference = (&p.x).mod_sub(&p.z, n);
+        let difference = mul_mod(&difference, &difference, n);
+        let t = (&sum).mod_sub(&difference, n);
+        let z = mul_mod(&mul_mod(&self.a24, &t, n).mod_add(&difference, n), &t, n);
+        MontgomeryPoint {
+            x: mul_mod(&sum, &difference, n),
+            z,
+        }
+    }
+
+    // Returns $P + Q$, given $P - Q$.
+    fn add(
+        &self,
+        p: &MontgomeryPoint,
+        q: &MontgomeryPoint,
+        difference: &MontgomeryPoint,
+    ) -> MontgomeryPoint {
+        let n = self.n;
+        let u = mul_mod(&(&p.x).mod_sub(&p.z, n), &(&q.x).mod_add(&q.z, n), n);
+        let v = mul_mod(&(&p.x).mod_add(&p.z, n), &(&q.x).mod_sub(&q.z, n), n);
+        let sum = (&u).mod_add(&v, n);
+        let difference_2 = u.mod_sub(v, n);
+        MontgomeryPoint {
+            x: mul_mod(&difference.z, &mul_mod(&sum, &sum, n), n),
+            z: mul_mod(&difference.x, &mul_mod(&difference_2, &difference_2, n), n),
+        }
+    }
+
+    // Returns $kP$, where $k > 0$, using the Montgomery ladder.
+    fn multiply(&self, p: &MontgomeryPoint, k: Limb) -> MontgomeryPoint {
+        let mut r_0 = p.clone();
+        let mut r_1 = self.double(p);
+        for i in (0..k.significant_bits() - 1).rev() {
+            if k.get_bit(i) {
+                r_0 = self.add(&r_1, &r_0, p);
+                r_1 = self.double(&r_1);
+            } else {
+                r_1 = self.add(&r_0, &r_1, p);
+                r_0 = self.double(&r_0);
+            }
+        }
+        r_0
+    }
+}
+
+// The giant step size used in stage 2 of the elliptic curve method.
+const ECM_STAGE_2_D: Limb = 210;
+
+// Attempts to find a nontrivial factor of $n$ using Lenstra's elliptic curve method with a single
+// curve, using Suyama's parametrization with parameter $\sigma$, stage-1 bound $B_1$, and stage-2
+// bound $B_2$. $B_1$ must be at least `ECM_STAGE_2_D`.
+//
+// Stage 2 uses the baby-step giant-step continuation. Let $Q$ be the result of stage 1. Each prime
+// $q$ with $B_1 < q \leq B_2$ is written as $kD \pm j$, where $D$ is `ECM_STAGE_2_D` and $0 < j <
+// D/2$; then $qQ$ is the point at infinity modulo a prime $p$ exactly when $kDQ$ and $jQ$ have the
+// same $x$-coordinate modulo $p$. The cross-differences of the projective coordinates are
+// multiplied together, and a single gcd is taken at the end.
+fn ecm_one_curve(n: &Natural, sigma: Limb, b1: Limb, b2: Limb) -> Option<Natural> {
+    let sigma = Natural::from(sigma);
+    let u = mul_mod(&sigma, &sigma, n).mod_sub(Natural::from(5u32), n);
+    let v = (sigma << 2u32) % n;
+    let u_cubed = mul_mod(&mul_mod(&u, &u, n), &u, n);
+    let v_minus_u = (&v).mod_sub(&u, n);
+    // a24 = (v - u)^3 (3u + v) / (16 u^3 v)
+    let numerator = mul_mod(
+        &mul_mod(&mul_mod(&v_minus_u, &v_minus_u, n), &v_minus_u, n),
+        &(&u).mod_add(&u, n).mod_add(&u, n).mod_add(&v, n),
+        n,
+    );
+    let denominator = (mul_mod(&u_cubed, &v, n) << 4u32) % n;
+    let inverse = match (&denominator).mod_inverse(n) {
+        Some(inverse) => inverse,
+        None => return nontrivial_factor(denominator.gcd(n), n),
+    };
+    let curve = MontgomeryCurve {
+        n,
+        a24: mul_mod(&numerator, &inverse, n),
+    };
+    let mut q = MontgomeryPoint {
+        x: u_cubed,
+        z: mul_mod(&mul_mod(&v, &v, n), &v, n),
+    };
+    // Stage 1
+    for p in Limb::primes_less_than_or_equal_to(&b1) {
+        q = curve.multiply(&q, largest_power_at_most(p, b1));
+    }
+    let g = (&q.z).gcd(n);
+    if g != 1u32 {
+        return nontrivial_factor(g, n);
+    }
+    // Stage 2. First compute the baby steps jQ for odd j < D/2.
+    let q_2 = curve.double(&q);
+    let mut baby_steps = vec![q.clone(), curve.add(&q_2, &q, &q)];
+    for i in 2..usize::exact_from(ECM_STAGE_2_D >> 2) {
+        let next = curve.add(&baby_steps[i - 1], &q_2, &baby_steps[i - 2]);
+        baby_steps.push(next);
+    }
+    let q_d = curve.multiply(&q, ECM_STAGE_2_D);
+    let half_d = ECM_STAGE_2_D >> 1;
+    // Choose the first giant step kD so that its window [kD - D/2, kD + D/2] reaches down to B1.
+    let mut k = max((b1 + half_d) / ECM_STAGE_2_D, 1);
+    let mut previous_giant_step = if k == 1 {
+        None
+    } else {
+        Some(curve.multiply(&q, (k - 1) * ECM_STAGE_2_D))
+    };
+    let mut giant_step = curve.multiply(&q, k * ECM_STAGE_2_D);
+    let mut product = Natural::ONE;
+    for p in Limb::primes_less_than_or_equal_to(&b2) {
+        if p <= b1 {
+            continue;
+        }
+        while p > k * ECM_STAGE_2_D + half_d {
+            // When k = 1, the giant step is DQ itself, so the next one is its double.
+            let next = match &previous_giant_step {
+                Some(previous_giant_step) => curve.add(&giant_step, &q_d, previous_giant_step),
+                None => curve.double(&giant_step),
+            };
+            previous_giant_step = Some(giant_step);
+            giant_step = next;
+            k += 1;
+        }
+        let baby_step = &baby_steps[usize::exact_from(p.abs_diff(k * ECM_STAGE_2_D) >> 1)];
+        let cross_difference = mul_mod(&giant_step.x, &baby_step.z, n)
+            .mod_sub(mul_mod(&baby_step.x, &giant_step.z, n), n);
+        product = mul_mod(&product, &cross_difference, n);
+    }
+    nontrivial_factor(product.gcd(n), n)
+}
+
+// Returns a nontrivial factor of $n$, which must be composite, odd, larger than a `u64`, and free of
+// prime factors less than `TRIAL_DIVISION_LIMIT`.
+fn find_nontrivial_factor(n: &Natural) -> Natural {
+    if let Some(f) = pollard_rho_brent(n, 1, RHO_MAX_ITERS) {
+        return f;
+    }
+    if let Some(f) = pollard_p_minus_1(n, P_MINUS_1_B1) {
+        return f;
+    }
+    let mut sigma = 6;
+    for &(b1, curves) in &ECM_SCHEDULE {
+        for _ in 0..curves {
+            if let Some(f) = ecm_one_curve(n, sigma, b1, b1 * ECM_B2_MULTIPLIER) {
+                return f;
+            }
+            sigma += 1;
+        }
+    }
+    let mut b1 = ECM_SCHEDULE.last().unwrap().0;
+    loop {
+        b1 = b1.saturating_mul(3);
+        if let Some(f) = ecm_one_curve(n, sigma, b1, b1.saturating_mul(ECM_B2_MULTIPLIER)) {
+            return f;
+        }
+        sigma += 1;
+    }
+}
+
+// If $n$ is a perfect $p$th power for some prime $p$, returns the $p$th root of $n$ and $p$.
+fn prime_root(n: &Natural) -> Option<(Natural, u64)> {
+    u64::primes_less_than_or_equal_to(&n.significant_bits())
+        .find_map(|p| n.checked_root(p).map(|r| (r, p)))
+}
+
+impl Factor for &Natural {
+    type FACTORS = Vec<(Natural, u64)>;
+
+    /// Returns the prime factorization of a [`Natural`]. The return value is a [`Vec`] of pairs
+    /// $(p,e)$, where the $p$ is prime and $e$ is the exponent of $p$. The primes are in ascending
+    /// order.
+    ///
+    /// [`Natural`]s that fit in a `u64` are factored using the `u64` implementation. For larger
+    /// [`Natural`]s, small prime factors are first removed by trial division. Each remaining
+    /// composite cofactor is split by, in order, checking whether it is a perfect power, Brent's
+    /// variant of Pollard's rho algorithm, Pollard's $p-1$ method, and Lenstra's elliptic curve
+    /// method with increasing bounds.
+    ///
+    /// # Worst-case complexity
+    /// The running time depends mostly on the size of the second-largest prime factor $p$ of
+    /// `self`, and is expected to be $O(\exp((\sqrt{2}+o(1))\sqrt{\log p \log\log p}) M(n))$, where
+    /// $M(n)$ is the time needed to multiply two numbers with $n$ bits and $n$ is
+    /// `self.significant_bits()`. Factoring numbers whose two largest prime factors both have more
+    /// than about 40 decimal digits is likely to be very slow.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::factorization::traits::Factor;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!((&Natural::from(1u32)).factor().to_debug_string(), "[]");
+    /// assert_eq!(
+    ///     (&Natural::from(360u32)).factor().to_debug_string(),
+    ///     "[(2, 3), (3, 2), (5, 1)]"
+    /// );
+    /// // 2^128 + 1
+    /// assert_eq!(
+    ///     (&Natural::from_str("340282366920938463463374607431768211457").unwrap())
+    ///         .factor()
+    ///         .to_debug_string(),
+    ///     "[(59649589127497217, 1), (5704689200685129054721, 1)]"
+    /// );
+    /// ```
+    fn factor(&self) -> Vec<(Natural, u64)> {
+        assert_ne!(**self, 0u32);
+        if u64::convertible_from(*self) {
+            return u64::exact_from(*self)
+                .factor()
+                .into_iter()
+                .map(|(p, e)| (Natural::from(p), u64::from(e)))
+                .collect();
+        }
+        let mut factors = Vec::new();
+        let mut n = (*self).clone();
+        let twos = n.trailing_zeros().unwrap();
+        if twos != 0 {
+            factors.push((Natural::TWO, twos));
+            n >>= twos;
+        }
+        for p in Limb::primes_less_than(&TRIAL_DIVISION_LIMIT).skip(1) {
+            let p = Natural::from(p);
+            let mut e = 0;
+            loop {
+                let (q, r) = (&n).div_mod(&p);
+                if r != 0u32 {
+                    break;
+                }
+                n = q;
+                e += 1;
+            }
+            if e != 0 {
+                factors.push((p, e));
+            }
+        }
+        let mut composites = vec![(n, 1)];
+        while let Some((n, e)) = composites.pop() {
+            if n == 1u32 {
+                continue;
+            }
+            if u64::convertible_from(&n) {
+                factors.extend(
+                    u64::exact_from(&n)
+                        .factor()
+                        .into_iter()
+                        .map(|(p, f)| (Natural::from(p), e * u64::from(f))),
+                );
+            } else if n.is_prime() {
+                factors.push((n, e));
+            } else if let Some((r, p)) = prime_root(&n) {
+                composites.push((r, e * p));
+            } else {
+                let f = find_nontrivial_factor(&n);
+                let g = (&n).div_exact(&f);
+                composites.push((f, e));
+                composites.push((g, e));
+            }
+        }
+        factors.sort_unstable();
+        let mut merged: Vec<(Natural, u64)> = Vec::with_capacity(factors.len());
+        for (p, e) in factors {
+            match merged.last_mut() {
+                Some((last_p, last_e)) if *last_p == p => *last_e += e,
+                _ => merged.push((p, e)),
+            }
+        }
+        merged
+    }
+}
+
+impl Factor for Natural {
+    type FACTORS = Vec<(Natural, u64)>;
+
+    /// Returns the prime factorization of a [`Natural`]. The return value is a [`Vec`] of pairs
+    /// $(p,e)$, where the $p$ is prime and $e$ is the exponent of $p$. The primes are in ascending
+    /// order.
+    ///
+    /// See the implementation for `&Natural` for details about the algorithm.
+    ///
+    /// # Worst-case complexity
+    /// The running time depends mostly on the size of the second-largest prime factor $p$ of
+    /// `self`, and is expected to be $O(\exp((\sqrt{2}+o(1))\sqrt{\log p \log\log p}) M(n))$, where
+    /// $M(n)$ is the time needed to multiply two numbers with $n$ bits and $n$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::factorization::traits::Factor;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(360u32).factor().to_debug_string(),
+    ///     "[(2, 3), (3, 2), (5, 1)]"
+    /// );
+    /// ```
+    #[inline]
+    fn factor(&self) -> Vec<(Natural, u64)> {
+        (&self).factor()
+    }
+}
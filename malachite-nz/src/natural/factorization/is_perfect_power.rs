@@ -0,0 +1,206 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::InnerNatural::{Large, Small};
+use crate::natural::Natural;
+use crate::natural::arithmetic::mod_op::limbs_mod_limb;
+use crate::platform::Limb;
+use malachite_base::num::arithmetic::traits::{CheckedRoot, CheckedSqrt, ModPow};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::{
+    ExpressAsPower, Factor, IsPerfectPower, IsPrime, IsSquare, Primes,
+};
+use malachite_base::num::logic::traits::SignificantBits;
+
+// The number of primes $q \equiv 1 \pmod p$ that are used to rule out $p$th powers before a $p$th
+// root is computed.
+const RESIDUE_TEST_COUNT: usize = 4;
+
+fn natural_mod_limb(n: &Natural, q: Limb) -> Limb {
+    match n {
+        Natural(Small(small)) => small % q,
+        Natural(Large(xs)) => limbs_mod_limb(xs, q),
+    }
+}
+
+// Returns `false` if `n` is definitely not a `p`th power, where `p` is an odd prime. If `n` is a
+// `p`th power and $q \equiv 1 \pmod p$ is prime, then $n^{(q-1)/p} \equiv 0$ or $1 \pmod q$; this
+// is checked for several such $q$.
+#[allow(clippy::useless_conversion)]
+fn might_be_odd_prime_power(n: &Natural, p: u64) -> bool {
+    let p = Limb::exact_from(p);
+    let mut q: Limb = 1;
+    let mut tests = 0;
+    while tests < RESIDUE_TEST_COUNT {
+        q = if let Some(q) = p.checked_mul(2).and_then(|p2| q.checked_add(p2)) {
+            q
+        } else {
+            break;
+        };
+        if !q.is_prime() {
+            continue;
+        }
+        let r = natural_mod_limb(n, q);
+        if r != 0 && r.mod_pow(u64::from((q - 1) / p), q) != 1 {
+            return false;
+        }
+        tests += 1;
+    }
+    true
+}
+
+// Given $n \geq 2$, returns $(b, e)$ such that $n = b^e$ and $e$ is as large as possible. If $n$ is
+// not a perfect power, $e$ is 1.
+fn express_as_power_helper(n: &Natural) -> (Natural, u64) {
+    if let Ok(n) = u64::try_from(n) {
+        return if let Some((base, exp)) = n.is_perfect_power() {
+            (Natural::from(base), u64::from(exp))
+        } else {
+            (Natural::from(n), 1)
+        };
+    }
+    let mut base = n.clone();
+    let mut exp = 1;
+    let twos = n.trailing_zeros().unwrap();
+    if twos != 0 {
+        // If $n = b^e$, then $e$ divides the exponent of 2 in $n$, so only the prime factors of that
+        // exponent need to be tried.
+        for (p, _) in twos.factor() {
+            while let Some(root) = (&base).checked_root(p) {
+                base = root;
+                exp *= p;
+            }
+        }
+        return (base, exp);
+    }
+    while base.is_square() {
+        base = (&base).checked_sqrt().unwrap();
+        exp <<= 1;
+    }
+    for p in u64::primes().skip(1) {
+        if let Ok(small_base) = u64::try_from(&base) {
+            let (small_base, small_exp) = express_as_power_helper(&Natural::from(small_base));
+            return (small_base, exp * small_exp);
+        }
+        // Since `base` is odd, its `p`th root is at least 3, so `base` must have more than `p`
+        // bits.
+        if p >= base.significant_bits() {
+            break;
+        }
+        while might_be_odd_prime_power(&base, p) {
+            if let Some(root) = (&base).checked_root(p) {
+                base = root;
+                exp *= p;
+            } else {
+                break;
+            }
+        }
+    }
+    (base, exp)
+}
+
+impl IsPerfectPower for Natural {
+    type Output = Option<(Natural, u64)>;
+
+    /// Determines whether a [`Natural`] is a perfect power; that is, whether it is equal to $b^e$
+    /// for some integers $b$ and $e$, with $e > 1$. If it is, `Some((b, e))` is returned, with $e$
+    /// as large as possible; otherwise, `None` is returned.
+    ///
+    /// Since 0 and 1 are $k$th powers for every $k$, for them `Some((0, 2))` and `Some((1, 2))`
+    /// are returned, consistent with the primitive integer implementations. Unlike those, the
+    /// exponent is a `u64`.
+    ///
+    /// The implementation only computes $p$th roots (using
+    /// [`CheckedRoot`](malachite_base::num::arithmetic::traits::CheckedRoot)) for primes $p$ that
+    /// are not ruled out by cheaper residue tests.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::factorization::traits::IsPerfectPower;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(0u32).is_perfect_power().to_debug_string(),
+    ///     "Some((0, 2))"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(1u32).is_perfect_power().to_debug_string(),
+    ///     "Some((1, 2))"
+    /// );
+    /// assert_eq!(Natural::from(10u32).is_perfect_power(), None);
+    /// assert_eq!(
+    ///     Natural::from(64u32).is_perfect_power().to_debug_string(),
+    ///     "Some((2, 6))"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(10u32).pow(100).is_perfect_power().to_debug_string(),
+    ///     "Some((10, 100))"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(15u32).pow(21).is_perfect_power().to_debug_string(),
+    ///     "Some((15, 21))"
+    /// );
+    /// assert_eq!(
+    ///     (Natural::from(3u32).pow(101) + Natural::from(1u32)).is_perfect_power(),
+    ///     None
+    /// );
+    /// ```
+    fn is_perfect_power(&self) -> Option<(Natural, u64)> {
+        if *self == 0u32 {
+            return Some((Natural::ZERO, 2));
+        } else if *self == 1u32 {
+            return Some((Natural::ONE, 2));
+        }
+        let (base, exp) = express_as_power_helper(self);
+        if exp == 1 { None } else { Some((base, exp)) }
+    }
+}
+
+impl ExpressAsPower for Natural {
+    /// Expresses a [`Natural`] as a power $b^e$, using the greatest possible exponent.
+    ///
+    /// If the [`Natural`] is a perfect power, the result is the same as that of
+    /// [`is_perfect_power`](IsPerfectPower::is_perfect_power); otherwise, `(self, 1)` is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::factorization::traits::ExpressAsPower;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(10u32).express_as_power().to_debug_string(),
+    ///     "(10, 1)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(64u32).express_as_power().to_debug_string(),
+    ///     "(2, 6)"
+    /// );
+    /// ```
+    #[inline]
+    fn express_as_power(&self) -> (Natural, u64) {
+        self.is_perfect_power().unwrap_or_else(|| (self.clone(), 1))
+    }
+}
@@ -0,0 +1,285 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::InnerNatural::{Large, Small};
+use crate::natural::Natural;
+use crate::natural::arithmetic::mod_op::limbs_mod_limb;
+use crate::platform::Limb;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::{
+    JacobiSymbol, ModAdd, ModMul, ModPow, ModSquare, ModSub, Parity,
+};
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::factorization::traits::{IsPrime, IsSquare, Primes};
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+
+// All primes less than this are used for trial division before any probable prime tests are run.
+const TRIAL_DIVISION_LIMIT: Limb = 1000;
+
+// Returns whether `xs`, the limbs of a `Natural` with at least two limbs, are divisible by any odd
+// prime less than `TRIAL_DIVISION_LIMIT`. Since such a `Natural` is larger than all of these
+// primes, divisibility implies that it is composite.
+//
+// The primes are grouped so that the product of each group fits in a `Limb`; then only one pass
+// over the limbs is needed per group.
+fn limbs_has_small_odd_prime_factor(xs: &[Limb]) -> bool {
+    let mut group: Vec<Limb> = Vec::new();
+    let mut product: Limb = 1;
+    let has_factor = |group: &[Limb], product: Limb| {
+        let r = limbs_mod_limb(xs, product);
+        group.iter().any(|&p| r % p == 0)
+    };
+    for p in Limb::primes_less_than(&TRIAL_DIVISION_LIMIT).skip(1) {
+        if let Some(new_product) = product.checked_mul(p) {
+            product = new_product;
+        } else {
+            if has_factor(&group, product) {
+                return true;
+            }
+            group.clear();
+            product = p;
+        }
+        group.push(p);
+    }
+    has_factor(&group, product)
+}
+
+// Returns whether $n$ is a strong probable prime to base $a$, given $n - 1 = 2^s d$ with $d$ odd.
+fn is_strong_probable_prime(
+    n: &Natural,
+    n_minus_1: &Natural,
+    d: &Natural,
+    s: u64,
+    a: &Natural,
+) -> bool {
+    let mut y = a.mod_pow(d, n);
+    if y == 1u32 || y == *n_minus_1 {
+        return true;
+    }
+    for _ in 1..s {
+        y = y.mod_square(n);
+        if y == *n_minus_1 {
+            return true;
+        } else if y == 1u32 {
+            return false;
+        }
+    }
+    false
+}
+
+// Returns $x/2 \pmod n$, where $n$ is odd and $x < n$.
+fn half_mod(x: Natural, n: &Natural) -> Natural {
+    if x.odd() { (x + n) >> 1 } else { x >> 1 }
+}
+
+// Returns whether $n$ is a strong Lucas probable prime, using Selfridge's method A to choose the
+// parameters: $D$ is the first element of $5, -7, 9, -11, 13, \ldots$ for which the Jacobi symbol
+// $(D/n)$ is $-1$, $P = 1$, and $Q = (1 - D)/4$.
+//
+// $n$ must be odd, must have at least two limbs, and must not be a perfect square (otherwise a
+// suitable $D$ would not exist).
+fn limbs_is_strong_lucas_probable_prime(n: &Natural, xs: &[Limb]) -> bool {
+    let n_mod_4_is_1 = xs[0] & 3 == 1;
+    let mut d_abs: Limb = 5;
+    let mut d_negative = false;
+    loop {
+        // Since d_abs and n are odd and positive, (d_abs/n) = (n/d_abs), unless both are 3 mod 4.
+        let mut j = limbs_mod_limb(xs, d_abs).jacobi_symbol(d_abs);
+        if d_abs & 3 == 3 && !n_mod_4_is_1 {
+            j = -j;
+        }
+        // (-1/n) is 1 if n is 1 mod 4 and -1 otherwise.
+        if d_negative && !n_mod_4_is_1 {
+            j = -j;
+        }
+        if j == -1 {
+            break;
+        } else if j == 0 {
+            // n is larger than d_abs, so it has a nontrivial factor.
+            return false;
+        }
+        d_abs += 2;
+        d_negative = !d_negative;
+    }
+    // 4Q = 1 - D
+    let d = if d_negative {
+        n - Natural::from(d_abs)
+    } else {
+        Natural::from(d_abs)
+    };
+    let q = if d_negative {
+        Natural::from((d_abs + 1) >> 2)
+    } else {
+        n - Natural::from((d_abs - 1) >> 2)
+    };
+    // n + 1 = 2^s k, with k odd
+    let n_plus_1 = n + Natural::ONE;
+    let s = n_plus_1.trailing_zeros().unwrap();
+    let k = n_plus_1 >> s;
+    // Compute U_k, V_k, and Q^k by scanning the bits of k from most to least significant.
+    let mut u = Natural::ONE;
+    let mut v = Natural::ONE;
+    let mut q_k = q.clone();
+    for i in (0..k.significant_bits() - 1).rev() {
+        // U_{2j} = U_j V_j, V_{2j} = V_j^2 - 2Q^j
+        u = u.mod_mul(&v, n);
+        v = v.mod_square(n).mod_sub((&q_k << 1u32) % n, n);
+        q_k = q_k.mod_square(n);
+        if k.get_bit(i) {
+            // U_{j+1} = (PU_j + V_j)/2, V_{j+1} = (DU_j + PV_j)/2
+            let new_u = half_mod((&u).mod_add(&v, n), n);
+            v = half_mod((&d).mod_mul(u, n).mod_add(v, n), n);
+            u = new_u;
+            q_k = q_k.mod_mul(&q, n);
+        }
+    }
+    if u == 0u32 || v == 0u32 {
+        return true;
+    }
+    // V_{2^r k} for 0 < r < s
+    for _ in 1..s {
+        v = v.mod_square(n).mod_sub((&q_k << 1u32) % n, n);
+        if v == 0u32 {
+            return true;
+        }
+        q_k = q_k.mod_square(n);
+    }
+    false
+}
+
+// Returns whether $n$, which has at least two limbs, passes trial division and a strong probable
+// prime test to base 2. If so, also returns $n - 1$, $d$, and $s$, where $n - 1 = 2^s d$ and $d$ is
+// odd.
+fn limbs_passes_trial_division_and_base_2_test(
+    n: &Natural,
+    xs: &[Limb],
+) -> Option<(Natural, Natural, u64)> {
+    if xs[0].even() || limbs_has_small_odd_prime_factor(xs) {
+        return None;
+    }
+    let n_minus_1 = n - Natural::ONE;
+    let s = n_minus_1.trailing_zeros().unwrap();
+    let d = &n_minus_1 >> s;
+    if is_strong_probable_prime(n, &n_minus_1, &d, s, &Natural::TWO) {
+        Some((n_minus_1, d, s))
+    } else {
+        None
+    }
+}
+
+// This is the Baillie-PSW test: trial division, a strong probable prime test to base 2, and a
+// strong Lucas probable prime test. `n` must have at least two limbs.
+fn limbs_is_prime_bpsw(n: &Natural, xs: &[Limb]) -> Option<(Natural, Natural, u64)> {
+    let result = limbs_passes_trial_division_and_base_2_test(n, xs)?;
+    if !n.is_square() && limbs_is_strong_lucas_probable_prime(n, xs) {
+        Some(result)
+    } else {
+        None
+    }
+}
+
+impl Natural {
+    /// Determines whether a [`Natural`] is probably prime, performing extra Miller-Rabin rounds.
+    ///
+    /// First, the same test as [`is_prime`](IsPrime::is_prime) is performed. If that test
+    /// succeeds, `reps` further strong probable prime (Miller-Rabin) tests are run, using the odd
+    /// primes $3, 5, 7, 11, \ldots$ as bases.
+    ///
+    /// If `false` is returned, the [`Natural`] is definitely composite (or less than 2). If `true`
+    /// is returned and the [`Natural`] is less than $2^{64}$, it is definitely prime; otherwise, it
+    /// is prime with overwhelming likelihood, since no composite number is known to pass the
+    /// Baillie-PSW test.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O((m + 1) n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `reps`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(5u32).probably_prime(10), true);
+    /// assert_eq!(Natural::from(6u32).probably_prime(10), false);
+    /// assert_eq!(
+    ///     (Natural::from(2u32).pow(127) - Natural::from(1u32)).probably_prime(10),
+    ///     true
+    /// );
+    /// assert_eq!(
+    ///     (Natural::from(2u32).pow(128) + Natural::from(1u32)).probably_prime(10),
+    ///     false
+    /// );
+    /// ```
+    pub fn probably_prime(&self, reps: u64) -> bool {
+        match self {
+            Natural(Small(small)) => small.is_prime(),
+            Natural(Large(xs)) => {
+                if let Some((n_minus_1, d, s)) = limbs_is_prime_bpsw(self, xs) {
+                    Limb::primes()
+                        .skip(1)
+                        .take(usize::try_from(reps).unwrap_or(usize::MAX))
+                        .all(|p| {
+                            is_strong_probable_prime(self, &n_minus_1, &d, s, &Natural::from(p))
+                        })
+                } else {
+                    false
+                }
+            }
+        }
+    }
+}
+
+impl IsPrime for Natural {
+    /// Tests whether a [`Natural`] is prime.
+    ///
+    /// [`Natural`]s that fit in a [`Limb`] are tested using the primitive integer implementation,
+    /// which is deterministic. Larger [`Natural`]s are tested using trial division by small primes
+    /// followed by the Baillie-PSW test, which consists of a strong probable prime test to base 2
+    /// and a strong Lucas probable prime test. The Baillie-PSW test is known to be correct for all
+    /// inputs less than $2^{64}$, and no composite number is known to pass it.
+    ///
+    /// To run additional Miller-Rabin rounds, use [`Natural::probably_prime`].
+    ///
+    /// If you want to generate many small primes, try using
+    /// [`Natural::primes`][malachite_base::num::factorization::traits::Primes::primes] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::factorization::traits::IsPrime;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(5u32).is_prime(), true);
+    /// assert_eq!(Natural::from(6u32).is_prime(), false);
+    /// assert_eq!(
+    ///     (Natural::from(2u32).pow(127) - Natural::from(1u32)).is_prime(),
+    ///     true
+    /// );
+    /// assert_eq!(
+    ///     (Natural::from(2u32).pow(128) + Natural::from(1u32)).is_prime(),
+    ///     false
+    /// );
+    /// ```
+    fn is_prime(&self) -> bool {
+        match self {
+            Natural(Small(small)) => small.is_prime(),
+            Natural(Large(xs)) => limbs_is_prime_bpsw(self, xs).is_some(),
+        }
+    }
+}
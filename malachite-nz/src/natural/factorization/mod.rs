@@ -6,8 +6,52 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
+/// An implementation of
+/// [`CarmichaelLambda`](malachite_base::num::factorization::traits::CarmichaelLambda), a trait for
+/// computing Carmichael's function of a number.
+pub mod carmichael_lambda;
+/// An implementation of [`DivisorCount`](malachite_base::num::factorization::traits::DivisorCount),
+/// a trait for counting the positive divisors of a number.
+pub mod divisor_count;
+/// An implementation of [`DivisorSum`](malachite_base::num::factorization::traits::DivisorSum), a
+/// trait for computing the sum of the positive divisors of a number.
+pub mod divisor_sum;
+/// An implementation of [`Divisors`](malachite_base::num::factorization::traits::Divisors), a trait
+/// for producing iterators of the positive divisors of a number.
+pub mod divisors;
+/// An implementation of [`EulerTotient`](malachite_base::num::factorization::traits::EulerTotient),
+/// a trait for computing Euler's totient function of a number.
+pub mod euler_totient;
+/// An implementation of [`Factor`](malachite_base::num::factorization::traits::Factor), a trait
+/// for computing the prime factorization of a number.
+pub mod factor;
+/// Implementations of
+/// [`IsPerfectPower`](malachite_base::num::factorization::traits::IsPerfectPower) and
+/// [`ExpressAsPower`](malachite_base::num::factorization::traits::ExpressAsPower), traits for
+/// testing whether a number is a perfect power and for expressing it as one.
+pub mod is_perfect_power;
+/// An implementation of [`IsPrime`](malachite_base::num::factorization::traits::IsPrime), a trait
+/// for testing whether a number is prime, and the related function
+/// [`Natural::probably_prime`](crate::natural::Natural::probably_prime).
+pub mod is_prime;
 /// An implementation of [`IsSquare`](malachite_base::num::factorization::traits::IsSquare), a trait for testing if a number if a perfect square.
 pub mod is_square;
+/// An implementation of [`ModLog`](malachite_base::num::factorization::traits::ModLog), a trait for
+/// computing discrete logarithms.
+pub mod mod_log;
+/// An implementation of [`Moebius`](malachite_base::num::factorization::traits::Moebius), a trait
+/// for computing the Möbius function of a number.
+pub mod moebius;
+/// An implementation of
+/// [`MultiplicativeOrder`](malachite_base::num::factorization::traits::MultiplicativeOrder), a
+/// trait for computing the multiplicative order of a number modulo another number.
+pub mod multiplicative_order;
+/// An implementation of [`NextPrime`](malachite_base::num::factorization::traits::NextPrime), a
+/// trait for finding the smallest prime greater than a number.
+pub mod next_prime;
+/// An implementation of [`PrevPrime`](malachite_base::num::factorization::traits::PrevPrime), a
+/// trait for finding the largest prime less than a number.
+pub mod prev_prime;
 /// An implementation of [`Primes`](malachite_base::num::factorization::traits::Primes), a trait for
 /// generating prime numbers.
 ///
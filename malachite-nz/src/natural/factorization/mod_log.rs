@@ -0,0 +1,796 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::InnerNatural::Small;
+use crate::natural::Natural;
+use crate::natural::factorization::multiplicative_order::multiplicative_order_with_factors;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::{
+    CeilingSqrt, ChineseRemainder, CoprimeWith, DivExact, DivisibleBy, Gcd, ModAddAssign,
+    ModInverse, ModMul, ModMulAssign, ModPow, ModShlAssign, ModSquareAssign, ModSub, Pow,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::{SaturatingFrom, WrappingFrom};
+use malachite_base::num::factorization::traits::ModLog;
+
+// Above this many baby steps, the baby-step giant-step algorithm would use too much memory, so
+// Pollard's rho algorithm is used instead.
+const MAX_BABY_STEPS: u64 = 1 << 16;
+
+// The number of walks that Pollard's rho algorithm tries before concluding that there is no
+// logarithm, and the number of steps in each walk, as a multiple of the square root of the order.
+const RHO_MAX_WALKS: u64 = 8;
+const RHO_STEPS_MULTIPLIER: u64 = 16;
+
+// Finds the k in [0, q) such that g^k = x mod m, where g has order q mod m, using the baby-step
+// giant-step algorithm. Returns `None` if there is no such k. `steps` is the ceiling of the square
+// root of q.
+fn mod_log_baby_step_giant_step(
+    x: &Natural,
+    g: &Natural,
+    q: &Natural,
+    m: &Natural,
+    steps: &Natural,
+    steps_u64: u64,
+) -> Option<Natural> {
+    // Baby steps: g^j for 0 <= j < steps
+    let mut baby_steps = Vec::new();
+    let mut power = Natural::ONE;
+    for j in 0..steps_u64 {
+        let next = (&power).mod_mul(g, m);
+        baby_steps.push((power, j));
+        power = next;
+    }
+    baby_steps.sort_unstable();
+    // Giant steps: x g^(-steps i) for 0 <= i < steps
+    let giant_step = g.mod_pow(q - steps, m);
+    let mut y = x.clone();
+    for i in 0..steps_u64 {
+        if let Ok(index) = baby_steps.binary_search_by(|(z, _)| z.cmp(&y)) {
+            return Some(Natural::from(i) * steps + Natural::from(baby_steps[index].1));
+        }
+        y = y.mod_mul(&giant_step, m);
+    }
+    None
+}
+
+// An element z = g^a x^b of the walk in Pollard's rho algorithm, stored as (z, a, b), with a and b
+// reduced mod q.
+type RhoPoint = (Natural, Natural, Natural);
+
+// Moves to the next element of the walk, which is z x, z^2, or z g, depending on z mod 3.
+fn rho_step((z, a, b): &mut RhoPoint, x: &Natural, g: &Natural, q: &Natural, m: &Natural) {
+    match u64::wrapping_from(&*z) % 3 {
+        0 => {
+            z.mod_mul_assign(x, m);
+            b.mod_add_assign(Natural::ONE, q);
+        }
+        1 => {
+            z.mod_square_assign(m);
+            a.mod_shl_assign(1, q);
+            b.mod_shl_assign(1, q);
+        }
+        _ => {
+            z.mod_mul_assign(g, m);
+            a.mod_add_assign(Natural::ONE, q);
+        }
+    }
+}
+
+// Finds the k in [0, q) such that g^k = x mod m, where g has prime order q mod m, using Pollard's
+// rho algorithm for logarithms. Returns `None` if there is no such k. `steps` is the ceiling of the
+// square root of q.
+//
+// Unlike the baby-step giant-step algorithm, this uses a constant amount of memory. It is
+// probabilistic: each walk finds the logarithm within $O(\sqrt q)$ steps with overwhelming
+// probability, and if several walks fail, x is assumed not to be a power of g.
+fn mod_log_pollard_rho(
+    x: &Natural,
+    g: &Natural,
+    q: &Natural,
+    m: &Natural,
+    steps: &Natural,
+) -> Option<Natural> {
+    if *x == 1u32 {
+        return Some(Natural::ZERO);
+    }
+    // The exponents of x may only be reduced mod q if the order of x divides q
+    if x.mod_pow(q, m) != 1u32 {
+        return None;
+    }
+    let max_steps = u64::saturating_from(&(steps * Natural::from(RHO_STEPS_MULTIPLIER)));
+    for start in 1..=RHO_MAX_WALKS {
+        let a = Natural::from(start);
+        let mut tortoise = (g.mod_pow(&a, m).mod_mul(x, m), a, Natural::ONE);
+        let mut hare = tortoise.clone();
+        let mut found = false;
+        for _ in 0..max_steps {
+            rho_step(&mut tortoise, x, g, q, m);
+            rho_step(&mut hare, x, g, q, m);
+            rho_step(&mut hare, x, g, q, m);
+            if tortoise.0 == hare.0 {
+                found = true;
+                break;
+            }
+        }
+        if !found {
+            continue;
+        }
+        // g^a_t x^b_t = g^a_h x^b_h, so x^(b_h - b_t) = g^(a_t - a_h)
+        let b_diff = hare.2.mod_sub(tortoise.2, q);
+        if b_diff == 0u32 {
+            continue;
+        }
+        let k = tortoise
+            .1
+            .mod_sub(hare.1, q)
+            .mod_mul(b_diff.mod_inverse(q).unwrap(), q);
+        return if g.mod_pow(&k, m) == *x {
+            Some(k)
+        } else {
+            None
+        };
+    }
+    None
+}
+
+// Finds the k in [0, q) such that g^k = x mod m, where g has prime order q mod m. Returns `None` if
+// there is no such k.
+fn mod_log_prime_order(x: &Natural, g: &Natural, q: &Natural, m: &Natural) -> Option<Natural> {
+    let steps = q.ceiling_sqrt();
+    match u64::try_from(&steps) {
+        Ok(steps_u64) if steps_u64 <= MAX_BABY_STEPS => {
+            mod_log_baby_step_giant_step(x, g, q, m, &steps, steps_u64)
+        }
+        _ => mod_log_pollard_rho(x, g, q, m, &steps),
+    }
+}
+
+// Finds the smallest k such that g^k = x mod m, where x and g are coprime to m and m > 1, using the
+// Pohlig-Hellman algorithm.
+fn mod_log_coprime(x: &Natural, g: &Natural, m: &Natural) -> Option<Natural> {
+    let (order, factors) = multiplicative_order_with_factors(g, m);
+    let mut congruences = Vec::with_capacity(factors.len());
+    for (q, e) in factors {
+        // Work in the subgroup of order q^e.
+        let qe = (&q).pow(e);
+        let cofactor = (&order).div_exact(&qe);
+        let g_sub = g.mod_pow(&cofactor, m);
+        let x_sub = x.mod_pow(&cofactor, m);
+        // gamma has order q.
+        let gamma = (&g_sub).mod_pow((&qe).div_exact(&q), m);
+        // Find the base-q digits of the logarithm, one at a time.
+        let mut k = Natural::ZERO;
+        let mut q_power = Natural::ONE;
+        for i in (0..e).rev() {
+            let y = (&g_sub)
+                .mod_pow(&qe - &k, m)
+                .mod_mul(&x_sub, m)
+                .mod_pow((&q).pow(i), m);
+            k += mod_log_prime_order(&y, &gamma, &q, m)? * &q_power;
+            if i != 0 {
+                q_power *= &q;
+            }
+        }
+        congruences.push((k, qe));
+    }
+    let k = Natural::chinese_remainder(congruences).unwrap().0;
+    // If x is not in the subgroup generated by g, the result is wrong.
+    if g.mod_pow(&k, m) == *x {
+        Some(k)
+    } else {
+        None
+    }
+}
+
+fn mod_log(x: &Natural, g: &Natural, m: &Natural) -> Option<Natural> {
+    assert!(x < m, "x must be reduced mod m, but {x} >= {m}");
+    assert!(g < m, "g must be reduced mod m, but {g} >= {m}");
+    if let (Natural(Small(x)), Natural(Small(g)), Natural(Small(m))) = (x, g, m) {
+        return x.mod_log(*g, *m).map(Natural::from);
+    }
+    // While g and m are not coprime, g^k = x mod m for k > 0 is equivalent to (g / d) g^(k - 1) =
+    // x / d mod m / d, where d = gcd(g, m).
+    let mut x = x.clone();
+    let mut m = m.clone();
+    let mut offset = Natural::ZERO;
+    let mut multiplier = Natural::ONE;
+    loop {
+        let d = g.gcd(&m);
+        if d == 1u32 {
+            break;
+        }
+        if x == multiplier {
+            return Some(offset);
+        }
+        if !(&x).divisible_by(&d) {
+            return None;
+        }
+        x /= &d;
+        m /= &d;
+        offset += Natural::ONE;
+        multiplier = (multiplier % &m).mod_mul((g / &d) % &m, &m);
+    }
+    if m == 1u32 {
+        return Some(offset);
+    }
+    // Now solve multiplier g^k = x mod m. The multiplier is coprime to m.
+    let x = x.mod_mul(multiplier.mod_inverse(&m).unwrap(), &m);
+    if !(&x).coprime_with(&m) {
+        return None;
+    }
+    Some(mod_log_coprime(&x, &(g % &m), &m)? + offset)
+}
+
+impl ModLog<Natural, Natural> for Natural {
+    type Output = Natural;
+
+    /// Computes the discrete logarithm of a [`Natural`] to a given [`Natural`] base, modulo a third
+    /// [`Natural`] $m$. The first two [`Natural`]s must be already reduced modulo $m$. All three
+    /// [`Natural`]s are taken by value.
+    ///
+    /// Returns the smallest $k \geq 0$ such that $g^k \equiv x \mod m$, where $g$ is the base, or
+    /// `None` if there is no such $k$.
+    ///
+    /// $$
+    /// f(x, g, m) = \min \\{ k : k \geq 0 \text{ and } g^k \equiv x \mod m \\}.
+    /// $$
+    ///
+    /// First, common factors of $g$ and $m$ are removed. Then the multiplicative order of $g$ is
+    /// computed and factored, and the Pohlig-Hellman algorithm reduces the problem to logarithms in
+    /// groups of prime order. These are solved with the baby-step giant-step algorithm, or, when
+    /// the order is too large for that algorithm's table, with Pollard's rho algorithm, which uses
+    /// constant memory.
+    ///
+    /// # Worst-case complexity
+    /// The running time is exponential in `m.significant_bits()`. Apart from factoring $m$ and the
+    /// order of $g$, it is dominated by the square root of the largest prime factor of the order.
+    ///
+    /// # Panics
+    /// Panics if `self` or `base` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_base::num::factorization::traits::ModLog;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(6u32)
+    ///         .mod_log(Natural::from(3u32), Natural::from(7u32))
+    ///         .to_debug_string(),
+    ///     "Some(3)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(3u32)
+    ///         .mod_log(Natural::from(2u32), Natural::from(7u32))
+    ///         .to_debug_string(),
+    ///     "None"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from_str("12025050231696925086731743046088503371")
+    ///         .unwrap()
+    ///         .mod_log(
+    ///             Natural::from(3u32),
+    ///             Natural::from(2u32).pow(127) - Natural::ONE
+    ///         )
+    ///         .to_debug_string(),
+    ///     "Some(100000000000000000000)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from_str("933895331391691459636928060001")
+    ///         .unwrap()
+    ///         .mod_log(Natural::from(7u32), Natural::from(10u32).pow(30))
+    ///         .to_debug_string(),
+    ///     "Some(100)"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_log(self, base: Natural, m: Natural) -> Option<Natural> {
+        mod_log(&self, &base, &m)
+    }
+}
+
+impl<'a> ModLog<Natural, &'a Natural> for Natural {
+    type Output = Natural;
+
+    /// Computes the discrete logarithm of a [`Natural`] to a given [`Natural`] base, modulo a third
+    /// [`Natural`] $m$. The first two [`Natural`]s must be already reduced modulo $m$. The first
+    /// two [`Natural`]s are taken by value and the third by reference.
+    ///
+    /// Returns the smallest $k \geq 0$ such that $g^k \equiv x \mod m$, where $g$ is the base, or
+    /// `None` if there is no such $k$.
+    ///
+    /// $$
+    /// f(x, g, m) = \min \\{ k : k \geq 0 \text{ and } g^k \equiv x \mod m \\}.
+    /// $$
+    ///
+    /// First, common factors of $g$ and $m$ are removed. Then the multiplicative order of $g$ is
+    /// computed and factored, and the Pohlig-Hellman algorithm reduces the problem to logarithms in
+    /// groups of prime order. These are solved with the baby-step giant-step algorithm, or, when
+    /// the order is too large for that algorithm's table, with Pollard's rho algorithm, which uses
+    /// constant memory.
+    ///
+    /// # Worst-case complexity
+    /// The running time is exponential in `m.significant_bits()`. Apart from factoring $m$ and the
+    /// order of $g$, it is dominated by the square root of the largest prime factor of the order.
+    ///
+    /// # Panics
+    /// Panics if `self` or `base` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_base::num::factorization::traits::ModLog;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(6u32)
+    ///         .mod_log(Natural::from(3u32), &Natural::from(7u32))
+    ///         .to_debug_string(),
+    ///     "Some(3)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(3u32)
+    ///         .mod_log(Natural::from(2u32), &Natural::from(7u32))
+    ///         .to_debug_string(),
+    ///     "None"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from_str("12025050231696925086731743046088503371")
+    ///         .unwrap()
+    ///         .mod_log(
+    ///             Natural::from(3u32),
+    ///             &(Natural::from(2u32).pow(127) - Natural::ONE)
+    ///         )
+    ///         .to_debug_string(),
+    ///     "Some(100000000000000000000)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from_str("933895331391691459636928060001")
+    ///         .unwrap()
+    ///         .mod_log(Natural::from(7u32), &Natural::from(10u32).pow(30))
+    ///         .to_debug_string(),
+    ///     "Some(100)"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_log(self, base: Natural, m: &'a Natural) -> Option<Natural> {
+        mod_log(&self, &base, m)
+    }
+}
+
+impl<'a> ModLog<&'a Natural, Natural> for Natural {
+    type Output = Natural;
+
+    /// Computes the discrete logarithm of a [`Natural`] to a given [`Natural`] base, modulo a third
+    /// [`Natural`] $m$. The first two [`Natural`]s must be already reduced modulo $m$. The first
+    /// and third [`Natural`]s are taken by value and the second by reference.
+    ///
+    /// Returns the smallest $k \geq 0$ such that $g^k \equiv x \mod m$, where $g$ is the base, or
+    /// `None` if there is no such $k$.
+    ///
+    /// $$
+    /// f(x, g, m) = \min \\{ k : k \geq 0 \text{ and } g^k \equiv x \mod m \\}.
+    /// $$
+    ///
+    /// First, common factors of $g$ and $m$ are removed. Then the multiplicative order of $g$ is
+    /// computed and factored, and the Pohlig-Hellman algorithm reduces the problem to logarithms in
+    /// groups of prime order. These are solved with the baby-step giant-step algorithm, or, when
+    /// the order is too large for that algorithm's table, with Pollard's rho algorithm, which uses
+    /// constant memory.
+    ///
+    /// # Worst-case complexity
+    /// The running time is exponential in `m.significant_bits()`. Apart from factoring $m$ and the
+    /// order of $g$, it is dominated by the square root of the largest prime factor of the order.
+    ///
+    /// # Panics
+    /// Panics if `self` or `base` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_base::num::factorization::traits::ModLog;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(6u32)
+    ///         .mod_log(&Natural::from(3u32), Natural::from(7u32))
+    ///         .to_debug_string(),
+    ///     "Some(3)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(3u32)
+    ///         .mod_log(&Natural::from(2u32), Natural::from(7u32))
+    ///         .to_debug_string(),
+    ///     "None"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from_str("12025050231696925086731743046088503371")
+    ///         .unwrap()
+    ///         .mod_log(
+    ///             &Natural::from(3u32),
+    ///             Natural::from(2u32).pow(127) - Natural::ONE
+    ///         )
+    ///         .to_debug_string(),
+    ///     "Some(100000000000000000000)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from_str("933895331391691459636928060001")
+    ///         .unwrap()
+    ///         .mod_log(&Natural::from(7u32), Natural::from(10u32).pow(30))
+    ///         .to_debug_string(),
+    ///     "Some(100)"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_log(self, base: &'a Natural, m: Natural) -> Option<Natural> {
+        mod_log(&self, base, &m)
+    }
+}
+
+impl<'a, 'b> ModLog<&'a Natural, &'b Natural> for Natural {
+    type Output = Natural;
+
+    /// Computes the discrete logarithm of a [`Natural`] to a given [`Natural`] base, modulo a third
+    /// [`Natural`] $m$. The first two [`Natural`]s must be already reduced modulo $m$. The first
+    /// [`Natural`] is taken by value and the second and third by reference.
+    ///
+    /// Returns the smallest $k \geq 0$ such that $g^k \equiv x \mod m$, where $g$ is the base, or
+    /// `None` if there is no such $k$.
+    ///
+    /// $$
+    /// f(x, g, m) = \min \\{ k : k \geq 0 \text{ and } g^k \equiv x \mod m \\}.
+    /// $$
+    ///
+    /// First, common factors of $g$ and $m$ are removed. Then the multiplicative order of $g$ is
+    /// computed and factored, and the Pohlig-Hellman algorithm reduces the problem to logarithms in
+    /// groups of prime order. These are solved with the baby-step giant-step algorithm, or, when
+    /// the order is too large for that algorithm's table, with Pollard's rho algorithm, which uses
+    /// constant memory.
+    ///
+    /// # Worst-case complexity
+    /// The running time is exponential in `m.significant_bits()`. Apart from factoring $m$ and the
+    /// order of $g$, it is dominated by the square root of the largest prime factor of the order.
+    ///
+    /// # Panics
+    /// Panics if `self` or `base` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_base::num::factorization::traits::ModLog;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(6u32)
+    ///         .mod_log(&Natural::from(3u32), &Natural::from(7u32))
+    ///         .to_debug_string(),
+    ///     "Some(3)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(3u32)
+    ///         .mod_log(&Natural::from(2u32), &Natural::from(7u32))
+    ///         .to_debug_string(),
+    ///     "None"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from_str("12025050231696925086731743046088503371")
+    ///         .unwrap()
+    ///         .mod_log(
+    ///             &Natural::from(3u32),
+    ///             &(Natural::from(2u32).pow(127) - Natural::ONE)
+    ///         )
+    ///         .to_debug_string(),
+    ///     "Some(100000000000000000000)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from_str("933895331391691459636928060001")
+    ///         .unwrap()
+    ///         .mod_log(&Natural::from(7u32), &Natural::from(10u32).pow(30))
+    ///         .to_debug_string(),
+    ///     "Some(100)"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_log(self, base: &'a Natural, m: &'b Natural) -> Option<Natural> {
+        mod_log(&self, base, m)
+    }
+}
+
+impl ModLog<Natural, Natural> for &Natural {
+    type Output = Natural;
+
+    /// Computes the discrete logarithm of a [`Natural`] to a given [`Natural`] base, modulo a third
+    /// [`Natural`] $m$. The first two [`Natural`]s must be already reduced modulo $m$. The first
+    /// [`Natural`] is taken by reference and the second and third by value.
+    ///
+    /// Returns the smallest $k \geq 0$ such that $g^k \equiv x \mod m$, where $g$ is the base, or
+    /// `None` if there is no such $k$.
+    ///
+    /// $$
+    /// f(x, g, m) = \min \\{ k : k \geq 0 \text{ and } g^k \equiv x \mod m \\}.
+    /// $$
+    ///
+    /// First, common factors of $g$ and $m$ are removed. Then the multiplicative order of $g$ is
+    /// computed and factored, and the Pohlig-Hellman algorithm reduces the problem to logarithms in
+    /// groups of prime order. These are solved with the baby-step giant-step algorithm, or, when
+    /// the order is too large for that algorithm's table, with Pollard's rho algorithm, which uses
+    /// constant memory.
+    ///
+    /// # Worst-case complexity
+    /// The running time is exponential in `m.significant_bits()`. Apart from factoring $m$ and the
+    /// order of $g$, it is dominated by the square root of the largest prime factor of the order.
+    ///
+    /// # Panics
+    /// Panics if `self` or `base` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_base::num::factorization::traits::ModLog;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(6u32))
+    ///         .mod_log(Natural::from(3u32), Natural::from(7u32))
+    ///         .to_debug_string(),
+    ///     "Some(3)"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(3u32))
+    ///         .mod_log(Natural::from(2u32), Natural::from(7u32))
+    ///         .to_debug_string(),
+    ///     "None"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from_str("12025050231696925086731743046088503371").unwrap())
+    ///         .mod_log(
+    ///             Natural::from(3u32),
+    ///             Natural::from(2u32).pow(127) - Natural::ONE
+    ///         )
+    ///         .to_debug_string(),
+    ///     "Some(100000000000000000000)"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from_str("933895331391691459636928060001").unwrap())
+    ///         .mod_log(Natural::from(7u32), Natural::from(10u32).pow(30))
+    ///         .to_debug_string(),
+    ///     "Some(100)"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_log(self, base: Natural, m: Natural) -> Option<Natural> {
+        mod_log(self, &base, &m)
+    }
+}
+
+impl ModLog<Natural, &Natural> for &Natural {
+    type Output = Natural;
+
+    /// Computes the discrete logarithm of a [`Natural`] to a given [`Natural`] base, modulo a third
+    /// [`Natural`] $m$. The first two [`Natural`]s must be already reduced modulo $m$. The first
+    /// and third [`Natural`]s are taken by reference and the second by value.
+    ///
+    /// Returns the smallest $k \geq 0$ such that $g^k \equiv x \mod m$, where $g$ is the base, or
+    /// `None` if there is no such $k$.
+    ///
+    /// $$
+    /// f(x, g, m) = \min \\{ k : k \geq 0 \text{ and } g^k \equiv x \mod m \\}.
+    /// $$
+    ///
+    /// First, common factors of $g$ and $m$ are removed. Then the multiplicative order of $g$ is
+    /// computed and factored, and the Pohlig-Hellman algorithm reduces the problem to logarithms in
+    /// groups of prime order. These are solved with the baby-step giant-step algorithm, or, when
+    /// the order is too large for that algorithm's table, with Pollard's rho algorithm, which uses
+    /// constant memory.
+    ///
+    /// # Worst-case complexity
+    /// The running time is exponential in `m.significant_bits()`. Apart from factoring $m$ and the
+    /// order of $g$, it is dominated by the square root of the largest prime factor of the order.
+    ///
+    /// # Panics
+    /// Panics if `self` or `base` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_base::num::factorization::traits::ModLog;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(6u32))
+    ///         .mod_log(Natural::from(3u32), &Natural::from(7u32))
+    ///         .to_debug_string(),
+    ///     "Some(3)"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(3u32))
+    ///         .mod_log(Natural::from(2u32), &Natural::from(7u32))
+    ///         .to_debug_string(),
+    ///     "None"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from_str("12025050231696925086731743046088503371").unwrap())
+    ///         .mod_log(
+    ///             Natural::from(3u32),
+    ///             &(Natural::from(2u32).pow(127) - Natural::ONE)
+    ///         )
+    ///         .to_debug_string(),
+    ///     "Some(100000000000000000000)"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from_str("933895331391691459636928060001").unwrap())
+    ///         .mod_log(Natural::from(7u32), &Natural::from(10u32).pow(30))
+    ///         .to_debug_string(),
+    ///     "Some(100)"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_log(self, base: Natural, m: &Natural) -> Option<Natural> {
+        mod_log(self, &base, m)
+    }
+}
+
+impl ModLog<&Natural, Natural> for &Natural {
+    type Output = Natural;
+
+    /// Computes the discrete logarithm of a [`Natural`] to a given [`Natural`] base, modulo a third
+    /// [`Natural`] $m$. The first two [`Natural`]s must be already reduced modulo $m$. The first
+    /// two [`Natural`]s are taken by reference and the third by value.
+    ///
+    /// Returns the smallest $k \geq 0$ such that $g^k \equiv x \mod m$, where $g$ is the base, or
+    /// `None` if there is no such $k$.
+    ///
+    /// $$
+    /// f(x, g, m) = \min \\{ k : k \geq 0 \text{ and } g^k \equiv x \mod m \\}.
+    /// $$
+    ///
+    /// First, common factors of $g$ and $m$ are removed. Then the multiplicative order of $g$ is
+    /// computed and factored, and the Pohlig-Hellman algorithm reduces the problem to logarithms in
+    /// groups of prime order. These are solved with the baby-step giant-step algorithm, or, when
+    /// the order is too large for that algorithm's table, with Pollard's rho algorithm, which uses
+    /// constant memory.
+    ///
+    /// # Worst-case complexity
+    /// The running time is exponential in `m.significant_bits()`. Apart from factoring $m$ and the
+    /// order of $g$, it is dominated by the square root of the largest prime factor of the order.
+    ///
+    /// # Panics
+    /// Panics if `self` or `base` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_base::num::factorization::traits::ModLog;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(6u32))
+    ///         .mod_log(&Natural::from(3u32), Natural::from(7u32))
+    ///         .to_debug_string(),
+    ///     "Some(3)"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(3u32))
+    ///         .mod_log(&Natural::from(2u32), Natural::from(7u32))
+    ///         .to_debug_string(),
+    ///     "None"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from_str("12025050231696925086731743046088503371").unwrap())
+    ///         .mod_log(
+    ///             &Natural::from(3u32),
+    ///             Natural::from(2u32).pow(127) - Natural::ONE
+    ///         )
+    ///         .to_debug_string(),
+    ///     "Some(100000000000000000000)"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from_str("933895331391691459636928060001").unwrap())
+    ///         .mod_log(&Natural::from(7u32), Natural::from(10u32).pow(30))
+    ///         .to_debug_string(),
+    ///     "Some(100)"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_log(self, base: &Natural, m: Natural) -> Option<Natural> {
+        mod_log(self, base, &m)
+    }
+}
+
+impl ModLog<&Natural, &Natural> for &Natural {
+    type Output = Natural;
+
+    /// Computes the discrete logarithm of a [`Natural`] to a given [`Natural`] base, modulo a third
+    /// [`Natural`] $m$. The first two [`Natural`]s must be already reduced modulo $m$. All three
+    /// [`Natural`]s are taken by reference.
+    ///
+    /// Returns the smallest $k \geq 0$ such that $g^k \equiv x \mod m$, where $g$ is the base, or
+    /// `None` if there is no such $k$.
+    ///
+    /// $$
+    /// f(x, g, m) = \min \\{ k : k \geq 0 \text{ and } g^k \equiv x \mod m \\}.
+    /// $$
+    ///
+    /// First, common factors of $g$ and $m$ are removed. Then the multiplicative order of $g$ is
+    /// computed and factored, and the Pohlig-Hellman algorithm reduces the problem to logarithms in
+    /// groups of prime order. These are solved with the baby-step giant-step algorithm, or, when
+    /// the order is too large for that algorithm's table, with Pollard's rho algorithm, which uses
+    /// constant memory.
+    ///
+    /// # Worst-case complexity
+    /// The running time is exponential in `m.significant_bits()`. Apart from factoring $m$ and the
+    /// order of $g$, it is dominated by the square root of the largest prime factor of the order.
+    ///
+    /// # Panics
+    /// Panics if `self` or `base` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_base::num::factorization::traits::ModLog;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(6u32))
+    ///         .mod_log(&Natural::from(3u32), &Natural::from(7u32))
+    ///         .to_debug_string(),
+    ///     "Some(3)"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(3u32))
+    ///         .mod_log(&Natural::from(2u32), &Natural::from(7u32))
+    ///         .to_debug_string(),
+    ///     "None"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from_str("12025050231696925086731743046088503371").unwrap())
+    ///         .mod_log(
+    ///             &Natural::from(3u32),
+    ///             &(Natural::from(2u32).pow(127) - Natural::ONE)
+    ///         )
+    ///         .to_debug_string(),
+    ///     "Some(100000000000000000000)"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from_str("933895331391691459636928060001").unwrap())
+    ///         .mod_log(&Natural::from(7u32), &Natural::from(10u32).pow(30))
+    ///         .to_debug_string(),
+    ///     "Some(100)"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_log(self, base: &Natural, m: &Natural) -> Option<Natural> {
+        mod_log(self, base, m)
+    }
+}
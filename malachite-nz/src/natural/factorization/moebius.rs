@@ -0,0 +1,58 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use malachite_base::num::factorization::traits::{Factor, Moebius};
+
+impl Moebius for Natural {
+    /// Computes the Möbius function of a [`Natural`].
+    ///
+    /// $$
+    /// \mu(n) = \\begin{cases}
+    ///     0 & \text{if} \\quad p^2 \mid n \text{ for some prime } p, \\\\
+    ///     (-1)^k & \text{otherwise},
+    /// \\end{cases}
+    /// $$
+    /// where $k$ is the number of distinct prime factors of $n$.
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by factoring `self`; see
+    /// [`Natural::factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::factorization::traits::Moebius;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(30u32).moebius(), -1);
+    /// assert_eq!(Natural::from(36u32).moebius(), 0);
+    /// assert_eq!(Natural::from(10u32).pow(30).moebius(), 0);
+    /// assert_eq!(
+    ///     (Natural::from(2u32).pow(127) - Natural::from(1u32)).moebius(),
+    ///     -1
+    /// );
+    /// assert_eq!(
+    ///     (Natural::from(2u32).pow(64) + Natural::from(1u32)).moebius(),
+    ///     1
+    /// );
+    /// ```
+    fn moebius(&self) -> i8 {
+        let mut mu = 1;
+        for (_, e) in self.factor() {
+            if e > 1 {
+                return 0;
+            }
+            mu = -mu;
+        }
+        mu
+    }
+}
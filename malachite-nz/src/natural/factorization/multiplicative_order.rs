@@ -0,0 +1,313 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::InnerNatural::Small;
+use crate::natural::Natural;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::{CoprimeWith, DivExact, ModPow, Pow};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::{Factor, MultiplicativeOrder};
+
+// Returns the prime factorization of phi(m), the order of the multiplicative group mod m. m must be
+// greater than 1.
+fn totient_factors(m: &Natural) -> Vec<(Natural, u64)> {
+    // phi(p_1^e_1 ... p_k^e_k) = p_1^(e_1 - 1) (p_1 - 1) ... p_k^(e_k - 1) (p_k - 1)
+    let mut factors: Vec<(Natural, u64)> = Vec::new();
+    for (p, e) in m.factor() {
+        if p != 2u32 {
+            factors.extend((&p - Natural::ONE).factor());
+        }
+        if e > 1 {
+            factors.push((p, e - 1));
+        }
+    }
+    factors.sort_unstable();
+    let mut merged: Vec<(Natural, u64)> = Vec::with_capacity(factors.len());
+    for (p, e) in factors {
+        match merged.last_mut() {
+            Some((q, f)) if *q == p => *f += e,
+            _ => merged.push((p, e)),
+        }
+    }
+    merged
+}
+
+// Returns the multiplicative order of x mod m, together with its prime factorization. x must be
+// coprime to m, and m must be greater than 1.
+pub(crate) fn multiplicative_order_with_factors(
+    x: &Natural,
+    m: &Natural,
+) -> (Natural, Vec<(Natural, u64)>) {
+    let mut factors = totient_factors(m);
+    let mut order: Natural = factors.iter().map(|(p, e)| p.pow(*e)).product();
+    // Remove each prime factor from the order for as long as x^order is still 1.
+    for (p, e) in &mut factors {
+        while *e != 0 {
+            let smaller = (&order).div_exact(&*p);
+            if x.mod_pow(&smaller, m) != 1u32 {
+                break;
+            }
+            order = smaller;
+            *e -= 1;
+        }
+    }
+    factors.retain(|(_, e)| *e != 0);
+    (order, factors)
+}
+
+fn multiplicative_order(x: &Natural, m: &Natural) -> Option<Natural> {
+    assert!(x < m, "x must be reduced mod m, but {x} >= {m}");
+    match (x, m) {
+        (Natural(Small(x)), Natural(Small(m))) => x.multiplicative_order(*m).map(Natural::from),
+        _ => {
+            if x.coprime_with(m) {
+                Some(multiplicative_order_with_factors(x, m).0)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+impl MultiplicativeOrder<Natural> for Natural {
+    type Output = Natural;
+
+    /// Computes the multiplicative order of a [`Natural`] modulo another [`Natural`] $m$. The input
+    /// must be already reduced modulo $m$. Both [`Natural`]s are taken by value.
+    ///
+    /// The multiplicative order of $x$ is the smallest positive integer $k$ such that $x^k \equiv 1
+    /// \mod m$. It exists if and only if $x$ and $m$ are coprime; if they aren't, `None` is
+    /// returned.
+    ///
+    /// $$
+    /// f(x, m) = \min \\{ k : k > 0 \text{ and } x^k \equiv 1 \mod m \\}.
+    /// $$
+    ///
+    /// The order divides $\varphi(m)$, so $m$ and $p - 1$, for each prime factor $p$ of $m$, are
+    /// factored. Then prime factors are removed from $\varphi(m)$ for as long as the result is
+    /// still a multiple of the order.
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by factoring $m$ and $p - 1$ for each prime factor $p$ of $m$;
+    /// see [`Natural::factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::factorization::traits::MultiplicativeOrder;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(2u32).multiplicative_order(Natural::from(7u32)).to_debug_string(),
+    ///     "Some(3)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(2u32).multiplicative_order(Natural::from(8u32)).to_debug_string(),
+    ///     "None"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(2u32)
+    ///         .multiplicative_order(Natural::from(2u32).pow(127) - Natural::from(1u32))
+    ///         .to_debug_string(),
+    ///     "Some(127)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(3u32)
+    ///         .multiplicative_order(Natural::from(10u32).pow(30))
+    ///         .to_debug_string(),
+    ///     "Some(50000000000000000000000000000)"
+    /// );
+    /// ```
+    #[inline]
+    fn multiplicative_order(self, m: Natural) -> Option<Natural> {
+        multiplicative_order(&self, &m)
+    }
+}
+
+impl<'a> MultiplicativeOrder<&'a Natural> for Natural {
+    type Output = Natural;
+
+    /// Computes the multiplicative order of a [`Natural`] modulo another [`Natural`] $m$. The input
+    /// must be already reduced modulo $m$. The first [`Natural`] is taken by value and the second
+    /// by reference.
+    ///
+    /// The multiplicative order of $x$ is the smallest positive integer $k$ such that $x^k \equiv 1
+    /// \mod m$. It exists if and only if $x$ and $m$ are coprime; if they aren't, `None` is
+    /// returned.
+    ///
+    /// $$
+    /// f(x, m) = \min \\{ k : k > 0 \text{ and } x^k \equiv 1 \mod m \\}.
+    /// $$
+    ///
+    /// The order divides $\varphi(m)$, so $m$ and $p - 1$, for each prime factor $p$ of $m$, are
+    /// factored. Then prime factors are removed from $\varphi(m)$ for as long as the result is
+    /// still a multiple of the order.
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by factoring $m$ and $p - 1$ for each prime factor $p$ of $m$;
+    /// see [`Natural::factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::factorization::traits::MultiplicativeOrder;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(2u32).multiplicative_order(&Natural::from(7u32)).to_debug_string(),
+    ///     "Some(3)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(2u32).multiplicative_order(&Natural::from(8u32)).to_debug_string(),
+    ///     "None"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(2u32)
+    ///         .multiplicative_order(&(Natural::from(2u32).pow(127) - Natural::from(1u32)))
+    ///         .to_debug_string(),
+    ///     "Some(127)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(3u32)
+    ///         .multiplicative_order(&Natural::from(10u32).pow(30))
+    ///         .to_debug_string(),
+    ///     "Some(50000000000000000000000000000)"
+    /// );
+    /// ```
+    #[inline]
+    fn multiplicative_order(self, m: &'a Natural) -> Option<Natural> {
+        multiplicative_order(&self, m)
+    }
+}
+
+impl MultiplicativeOrder<Natural> for &Natural {
+    type Output = Natural;
+
+    /// Computes the multiplicative order of a [`Natural`] modulo another [`Natural`] $m$. The input
+    /// must be already reduced modulo $m$. The first [`Natural`] is taken by reference and the
+    /// second by value.
+    ///
+    /// The multiplicative order of $x$ is the smallest positive integer $k$ such that $x^k \equiv 1
+    /// \mod m$. It exists if and only if $x$ and $m$ are coprime; if they aren't, `None` is
+    /// returned.
+    ///
+    /// $$
+    /// f(x, m) = \min \\{ k : k > 0 \text{ and } x^k \equiv 1 \mod m \\}.
+    /// $$
+    ///
+    /// The order divides $\varphi(m)$, so $m$ and $p - 1$, for each prime factor $p$ of $m$, are
+    /// factored. Then prime factors are removed from $\varphi(m)$ for as long as the result is
+    /// still a multiple of the order.
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by factoring $m$ and $p - 1$ for each prime factor $p$ of $m$;
+    /// see [`Natural::factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::factorization::traits::MultiplicativeOrder;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(2u32)).multiplicative_order(Natural::from(7u32)).to_debug_string(),
+    ///     "Some(3)"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(2u32)).multiplicative_order(Natural::from(8u32)).to_debug_string(),
+    ///     "None"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(2u32))
+    ///         .multiplicative_order(Natural::from(2u32).pow(127) - Natural::from(1u32))
+    ///         .to_debug_string(),
+    ///     "Some(127)"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(3u32))
+    ///         .multiplicative_order(Natural::from(10u32).pow(30))
+    ///         .to_debug_string(),
+    ///     "Some(50000000000000000000000000000)"
+    /// );
+    /// ```
+    #[inline]
+    fn multiplicative_order(self, m: Natural) -> Option<Natural> {
+        multiplicative_order(self, &m)
+    }
+}
+
+impl MultiplicativeOrder<&Natural> for &Natural {
+    type Output = Natural;
+
+    /// Computes the multiplicative order of a [`Natural`] modulo another [`Natural`] $m$. The input
+    /// must be already reduced modulo $m$. Both [`Natural`]s are taken by reference.
+    ///
+    /// The multiplicative order of $x$ is the smallest positive integer $k$ such that $x^k \equiv 1
+    /// \mod m$. It exists if and only if $x$ and $m$ are coprime; if they aren't, `None` is
+    /// returned.
+    ///
+    /// $$
+    /// f(x, m) = \min \\{ k : k > 0 \text{ and } x^k \equiv 1 \mod m \\}.
+    /// $$
+    ///
+    /// The order divides $\varphi(m)$, so $m$ and $p - 1$, for each prime factor $p$ of $m$, are
+    /// factored. Then prime factors are removed from $\varphi(m)$ for as long as the result is
+    /// still a multiple of the order.
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by factoring $m$ and $p - 1$ for each prime factor $p$ of $m$;
+    /// see [`Natural::factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::factorization::traits::MultiplicativeOrder;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(2u32)).multiplicative_order(&Natural::from(7u32)).to_debug_string(),
+    ///     "Some(3)"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(2u32)).multiplicative_order(&Natural::from(8u32)).to_debug_string(),
+    ///     "None"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(2u32))
+    ///         .multiplicative_order(&(Natural::from(2u32).pow(127) - Natural::from(1u32)))
+    ///         .to_debug_string(),
+    ///     "Some(127)"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(3u32))
+    ///         .multiplicative_order(&Natural::from(10u32).pow(30))
+    ///         .to_debug_string(),
+    ///     "Some(50000000000000000000000000000)"
+    /// );
+    /// ```
+    #[inline]
+    fn multiplicative_order(self, m: &Natural) -> Option<Natural> {
+        multiplicative_order(self, m)
+    }
+}
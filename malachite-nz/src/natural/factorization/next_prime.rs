@@ -0,0 +1,106 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::InnerNatural::{Large, Small};
+use crate::natural::Natural;
+use crate::natural::arithmetic::mod_op::limbs_mod_limb;
+use crate::platform::Limb;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::Parity;
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::factorization::traits::{IsPrime, NextPrime, Primes};
+
+// Odd primes less than this are used to rule out candidates before they are tested for primality.
+const SIEVE_LIMIT: Limb = 1000;
+
+// Returns the first prime in the sequence $n, n + 2, n + 4, \ldots$ (or $n, n - 2, n - 4, \ldots$,
+// if `increasing` is `false`). $n$ must be odd and greater than `SIEVE_LIMIT`, and, if
+// `increasing` is `false`, the sequence must contain a prime.
+//
+// The residues of the candidates modulo small odd primes are tracked, so that most composite
+// candidates are discarded without any multiple-precision arithmetic.
+pub(crate) fn first_prime_in_progression(mut n: Natural, increasing: bool) -> Natural {
+    let mut residues: Vec<(Limb, Limb)> = Limb::primes_less_than(&SIEVE_LIMIT)
+        .skip(1)
+        .map(|p| {
+            let r = match &n {
+                Natural(Small(small)) => small % p,
+                Natural(Large(xs)) => limbs_mod_limb(xs, p),
+            };
+            (p, r)
+        })
+        .collect();
+    loop {
+        if residues.iter().all(|&(_, r)| r != 0) && n.is_prime() {
+            return n;
+        }
+        if increasing {
+            n += Natural::TWO;
+            for (p, r) in &mut residues {
+                *r += 2;
+                if *r >= *p {
+                    *r -= *p;
+                }
+            }
+        } else {
+            n -= Natural::TWO;
+            for (p, r) in &mut residues {
+                *r = if *r >= 2 { *r - 2 } else { *r + *p - 2 };
+            }
+        }
+    }
+}
+
+impl NextPrime for Natural {
+    type Output = Natural;
+
+    /// Finds the smallest prime greater than a [`Natural`].
+    ///
+    /// [`Natural`]s that fit in a `u64` are handled using the primitive integer implementation.
+    /// For larger [`Natural`]s, odd candidates are checked for divisibility by small primes, and
+    /// the remaining ones are tested using [`is_prime`](IsPrime::is_prime).
+    ///
+    /// $$
+    /// f(n) = \min\\{p : p > n \text{ and } p \text{ is prime}\\}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::factorization::traits::NextPrime;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(0u32).next_prime(), 2);
+    /// assert_eq!(Natural::from(100u32).next_prime(), 101);
+    /// assert_eq!(
+    ///     Natural::from(u64::MAX).next_prime().to_string(),
+    ///     "18446744073709551629"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(2u32).pow(100).next_prime().to_string(),
+    ///     "1267650600228229401496703205653"
+    /// );
+    /// ```
+    fn next_prime(&self) -> Natural {
+        if let Some(p) = u64::try_from(self).ok().and_then(|n| n.next_prime()) {
+            return Natural::from(p);
+        }
+        let mut candidate = self + Natural::ONE;
+        if candidate.even() {
+            candidate += Natural::ONE;
+        }
+        first_prime_in_progression(candidate, true)
+    }
+}
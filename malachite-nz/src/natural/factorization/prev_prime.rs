@@ -0,0 +1,61 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use crate::natural::factorization::next_prime::first_prime_in_progression;
+use malachite_base::num::arithmetic::traits::Parity;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::PrevPrime;
+
+impl PrevPrime for Natural {
+    type Output = Option<Natural>;
+
+    /// Finds the greatest prime less than a [`Natural`]. If there is no such prime (that is, if
+    /// the [`Natural`] is less than or equal to 2), `None` is returned.
+    ///
+    /// [`Natural`]s that fit in a `u64` are handled using the primitive integer implementation.
+    /// For larger [`Natural`]s, odd candidates are checked for divisibility by small primes, and
+    /// the remaining ones are tested using
+    /// [`is_prime`](malachite_base::num::factorization::traits::IsPrime::is_prime).
+    ///
+    /// $$
+    /// f(n) = \max\\{p : p < n \text{ and } p \text{ is prime}\\}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::factorization::traits::PrevPrime;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(2u32).prev_prime(), None);
+    /// assert_eq!(Natural::from(100u32).prev_prime().to_debug_string(), "Some(97)");
+    /// assert_eq!(
+    ///     Natural::from(2u32).pow(100).prev_prime().to_debug_string(),
+    ///     "Some(1267650600228229401496703205361)"
+    /// );
+    /// ```
+    fn prev_prime(&self) -> Option<Natural> {
+        if let Ok(n) = u64::try_from(self) {
+            return n.prev_prime().map(Natural::from);
+        }
+        let mut candidate = self - Natural::ONE;
+        if candidate.even() {
+            candidate -= Natural::ONE;
+        }
+        Some(first_prime_in_progression(candidate, false))
+    }
+}
@@ -9,6 +9,7 @@
 use crate::natural::Natural;
 use crate::natural::arithmetic::mod_power_of_2::limbs_slice_mod_power_of_2_in_place;
 use crate::natural::logic::bit_access::limbs_slice_set_bit;
+use core::iter::repeat_with;
 use itertools::Itertools;
 use malachite_base::num::arithmetic::traits::{
     CeilingLogBase2, PowerOf2, RoundToMultipleOfPowerOf2, ShrRound,
@@ -25,8 +26,57 @@ use malachite_base::num::random::geometric::{
 };
 use malachite_base::num::random::striped::{StripedBitSource, get_striped_unsigned_vec};
 use malachite_base::num::random::{RandomPrimitiveInts, random_primitive_ints};
-use malachite_base::random::Seed;
+use malachite_base::random::{RngCore, Seed};
 use malachite_base::rounding_modes::RoundingMode::*;
+use rand::Rng;
+use rand::distributions::Distribution;
+use rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformSampler};
+
+// Generates a random `Natural` with up to `bits` bits from a stream of `u64` limbs.
+fn get_random_natural_with_up_to_bits_helper<I: Iterator<Item = u64>>(
+    xs: &mut I,
+    bits: u64,
+) -> Natural {
+    if bits == 0 {
+        return Natural::ZERO;
+    }
+    #[cfg(feature = "32_bit_limbs")]
+    let mut xs = iterator_to_bit_chunks(
+        xs.take(usize::exact_from(bits.shr_round(u64::LOG_WIDTH, Ceiling).0)),
+        u64::WIDTH,
+        u32::WIDTH,
+    )
+    .map(Option::unwrap)
+    .collect_vec();
+    #[cfg(not(feature = "32_bit_limbs"))]
+    let mut xs = xs
+        .take(usize::exact_from(bits.shr_round(u64::LOG_WIDTH, Ceiling).0))
+        .collect_vec();
+    limbs_slice_mod_power_of_2_in_place(&mut xs, bits);
+    Natural::from_owned_limbs_asc(xs)
+}
+
+// Generates a random `Natural` with exactly `bits` bits from a stream of `u64` limbs.
+fn get_random_natural_with_bits_helper<I: Iterator<Item = u64>>(xs: &mut I, bits: u64) -> Natural {
+    if bits == 0 {
+        return Natural::ZERO;
+    }
+    #[cfg(feature = "32_bit_limbs")]
+    let mut xs = iterator_to_bit_chunks(
+        xs.take(usize::exact_from(bits.shr_round(u64::LOG_WIDTH, Ceiling).0)),
+        u64::WIDTH,
+        u32::WIDTH,
+    )
+    .map(Option::unwrap)
+    .collect_vec();
+    #[cfg(not(feature = "32_bit_limbs"))]
+    let mut xs = xs
+        .take(usize::exact_from(bits.shr_round(u64::LOG_WIDTH, Ceiling).0))
+        .collect_vec();
+    limbs_slice_mod_power_of_2_in_place(&mut xs, bits);
+    limbs_slice_set_bit(&mut xs, bits - 1);
+    Natural::from_owned_limbs_asc(xs)
+}
 
 /// Generates a random [`Natural`] with a given maximum bit length.
 ///
@@ -60,23 +110,7 @@ use malachite_base::rounding_modes::RoundingMode::*;
 /// );
 /// ```
 pub fn get_random_natural_with_up_to_bits(xs: &mut RandomPrimitiveInts<u64>, bits: u64) -> Natural {
-    if bits == 0 {
-        return Natural::ZERO;
-    }
-    #[cfg(feature = "32_bit_limbs")]
-    let mut xs = iterator_to_bit_chunks(
-        xs.take(usize::exact_from(bits.shr_round(u64::LOG_WIDTH, Ceiling).0)),
-        u64::WIDTH,
-        u32::WIDTH,
-    )
-    .map(Option::unwrap)
-    .collect_vec();
-    #[cfg(not(feature = "32_bit_limbs"))]
-    let mut xs = xs
-        .take(usize::exact_from(bits.shr_round(u64::LOG_WIDTH, Ceiling).0))
-        .collect_vec();
-    limbs_slice_mod_power_of_2_in_place(&mut xs, bits);
-    Natural::from_owned_limbs_asc(xs)
+    get_random_natural_with_up_to_bits_helper(xs, bits)
 }
 
 /// Generates a random [`Natural`] with a given bit length.
@@ -110,24 +144,84 @@ pub fn get_random_natural_with_up_to_bits(xs: &mut RandomPrimitiveInts<u64>, bit
 /// );
 /// ```
 pub fn get_random_natural_with_bits(xs: &mut RandomPrimitiveInts<u64>, bits: u64) -> Natural {
-    if bits == 0 {
-        return Natural::ZERO;
-    }
-    #[cfg(feature = "32_bit_limbs")]
-    let mut xs = iterator_to_bit_chunks(
-        xs.take(usize::exact_from(bits.shr_round(u64::LOG_WIDTH, Ceiling).0)),
-        u64::WIDTH,
-        u32::WIDTH,
-    )
-    .map(Option::unwrap)
-    .collect_vec();
-    #[cfg(not(feature = "32_bit_limbs"))]
-    let mut xs = xs
-        .take(usize::exact_from(bits.shr_round(u64::LOG_WIDTH, Ceiling).0))
-        .collect_vec();
-    limbs_slice_mod_power_of_2_in_place(&mut xs, bits);
-    limbs_slice_set_bit(&mut xs, bits - 1);
-    Natural::from_owned_limbs_asc(xs)
+    get_random_natural_with_bits_helper(xs, bits)
+}
+
+/// Generates a random [`Natural`] with a given maximum bit length, using a caller-supplied random
+/// number generator.
+///
+/// Any [`RngCore`] may be used: for example, a reproducible PRNG or the operating system's
+/// cryptographically secure RNG. The [`Natural`] is chosen uniformly from $[0, 2^b)$; [`Natural`]s
+/// with bit lengths smaller than the maximum may also be generated.
+///
+/// $$
+/// P(n) = \\begin{cases}
+///     \frac{1}{2^b} & \text{if} \\quad 0 \\leq n < 2^b, \\\\
+///     0 & \\text{otherwise}.
+/// \\end{cases}
+/// $$
+///
+/// # Expected complexity
+/// $T(n) = O(n)$
+///
+/// $M(n) = O(n)$
+///
+/// where $T$ is time, $M$ is additional memory, and `n` is `bits`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::random::EXAMPLE_SEED;
+/// use malachite_nz::natural::random::get_random_natural_with_up_to_bits_with_rng;
+///
+/// assert_eq!(
+///     get_random_natural_with_up_to_bits_with_rng(&mut EXAMPLE_SEED.get_rng(), 100).to_string(),
+///     "976558340558744279591984426865"
+/// );
+/// ```
+pub fn get_random_natural_with_up_to_bits_with_rng<R: RngCore + ?Sized>(
+    rng: &mut R,
+    bits: u64,
+) -> Natural {
+    get_random_natural_with_up_to_bits_helper(&mut repeat_with(|| rng.next_u64()), bits)
+}
+
+/// Generates a random [`Natural`] with a given bit length, using a caller-supplied random number
+/// generator.
+///
+/// Any [`RngCore`] may be used: for example, a reproducible PRNG or the operating system's
+/// cryptographically secure RNG. The [`Natural`] is 0 if $b$ is 0, or else chosen uniformly from
+/// $[2^{b-1}, 2^b)$.
+///
+/// $$
+/// P(n) = \\begin{cases}
+///     1 & \text{if} \\quad b = n = 0, \\\\
+///     \frac{1}{2^{b-1}} & \text{if} \\quad b \neq 0 \\ \text{and} \\ 2^{b-1} \leq n < 2^b, \\\\
+///     0 & \\text{otherwise}.
+/// \\end{cases}
+/// $$
+///
+/// # Expected complexity
+/// $T(n) = O(n)$
+///
+/// $M(n) = O(n)$
+///
+/// where $T$ is time, $M$ is additional memory, and `n` is `bits`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::random::EXAMPLE_SEED;
+/// use malachite_nz::natural::random::get_random_natural_with_bits_with_rng;
+///
+/// assert_eq!(
+///     get_random_natural_with_bits_with_rng(&mut EXAMPLE_SEED.get_rng(), 100).to_string(),
+///     "976558340558744279591984426865"
+/// );
+/// ```
+pub fn get_random_natural_with_bits_with_rng<R: RngCore + ?Sized>(
+    rng: &mut R,
+    bits: u64,
+) -> Natural {
+    get_random_natural_with_bits_helper(&mut repeat_with(|| rng.next_u64()), bits)
 }
 
 /// Generates a striped random [`Natural`] with a given maximum bit length.
@@ -532,6 +626,21 @@ pub fn random_naturals_less_than(seed: Seed, limit: Natural) -> RandomNaturalsLe
     }
 }
 
+// Generates a random `Natural` less than `limit` from a stream of `u64` limbs.
+fn get_random_natural_less_than_helper<I: Iterator<Item = u64>>(
+    limbs: &mut I,
+    limit: &Natural,
+) -> Natural {
+    assert_ne!(*limit, 0);
+    let bits = limit.ceiling_log_base_2();
+    loop {
+        let x = get_random_natural_with_up_to_bits_helper(limbs, bits);
+        if x < *limit {
+            return x;
+        }
+    }
+}
+
 /// Generates a random [`Natural`] less than a given limit.
 ///
 /// The [`Natural`] is chosen uniformly from $[0, \ell)$, where $\ell$ is the provided limit.
@@ -572,14 +681,55 @@ pub fn get_random_natural_less_than(
     limbs: &mut RandomPrimitiveInts<u64>,
     limit: &Natural,
 ) -> Natural {
-    assert_ne!(*limit, 0);
-    let bits = limit.ceiling_log_base_2();
-    loop {
-        let x = get_random_natural_with_up_to_bits(limbs, bits);
-        if x < *limit {
-            return x;
-        }
-    }
+    get_random_natural_less_than_helper(limbs, limit)
+}
+
+/// Generates a random [`Natural`] less than a given limit, using a caller-supplied random number
+/// generator.
+///
+/// Any [`RngCore`] may be used: for example, a reproducible PRNG or the operating system's
+/// cryptographically secure RNG. The [`Natural`] is chosen uniformly from $[0, \ell)$, where $\ell$
+/// is the provided limit.
+///
+/// $$
+/// P(n) = \\begin{cases}
+///     1/\ell & \text{if} 0\leq n<\ell, \\\\
+///     0 & \\text{otherwise},
+/// \\end{cases}
+/// $$
+/// where $\ell$ is `limit`.
+///
+/// # Expected complexity
+/// $T(n) = O(n)$
+///
+/// $M(n) = O(n)$
+///
+/// where $T$ is time, $M$ is additional memory, and `n` is `limit.significant_bits()`.
+///
+/// # Panics
+/// Panics if `limit` is 0.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::arithmetic::traits::Pow;
+/// use malachite_base::random::EXAMPLE_SEED;
+/// use malachite_nz::natural::random::get_random_natural_less_than_with_rng;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(
+///     get_random_natural_less_than_with_rng(
+///         &mut EXAMPLE_SEED.get_rng(),
+///         &Natural::from(10u32).pow(20)
+///     )
+///     .to_string(),
+///     "27702062732568241671"
+/// );
+/// ```
+pub fn get_random_natural_less_than_with_rng<R: RngCore + ?Sized>(
+    rng: &mut R,
+    limit: &Natural,
+) -> Natural {
+    get_random_natural_less_than_helper(&mut repeat_with(|| rng.next_u64()), limit)
 }
 
 /// Uniformly generates random [`Natural`]s in an interval.
@@ -699,6 +849,226 @@ pub fn uniform_random_natural_inclusive_range(
     uniform_random_natural_range(seed, a, b + Natural::ONE)
 }
 
+/// A distribution that uniformly samples [`Natural`]s from an interval.
+///
+/// This implements [`rand`]'s [`Distribution`] and [`UniformSampler`] traits, so it may be used
+/// with any [`Rng`]; for example, via `rng.sample(UniformNatural::new(a, b))`. Since [`Natural`]
+/// implements [`SampleUniform`], `rng.gen_range(a..b)` works too.
+///
+/// Sampling takes expected $O(n)$ time and additional memory, where $n$ is the number of bits in
+/// the width of the interval.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct UniformNatural {
+    a: Natural,
+    width: Natural,
+}
+
+impl UniformNatural {
+    /// Creates a distribution that uniformly samples [`Natural`]s from the half-open interval $[a,
+    /// b)$.
+    ///
+    /// $$
+    /// P(x) = \\begin{cases}
+    ///     \frac{1}{b-a} & \text{if} \\quad a \leq x < b, \\\\
+    ///     0 & \\text{otherwise}.
+    /// \\end{cases}
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `b.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if $a \geq b$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::random::EXAMPLE_SEED;
+    /// use malachite_nz::natural::random::UniformNatural;
+    /// use malachite_nz::natural::Natural;
+    /// use rand::Rng;
+    ///
+    /// let mut rng = EXAMPLE_SEED.get_rng();
+    /// let d = UniformNatural::new(Natural::from(10u32), Natural::from(100u32));
+    /// assert_eq!(rng.sample(&d), 97);
+    /// ```
+    pub fn new(a: Natural, b: Natural) -> UniformNatural {
+        assert!(a < b);
+        let width = b - &a;
+        UniformNatural { a, width }
+    }
+
+    /// Creates a distribution that uniformly samples [`Natural`]s from the closed interval $[a,
+    /// b]$.
+    ///
+    /// $$
+    /// P(x) = \\begin{cases}
+    ///     \frac{1}{b-a+1} & \text{if} \\quad a \leq x \leq b, \\\\
+    ///     0 & \\text{otherwise}.
+    /// \\end{cases}
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `b.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if $a > b$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::random::EXAMPLE_SEED;
+    /// use malachite_nz::natural::random::UniformNatural;
+    /// use malachite_nz::natural::Natural;
+    /// use rand::Rng;
+    ///
+    /// let mut rng = EXAMPLE_SEED.get_rng();
+    /// let d = UniformNatural::new_inclusive(Natural::from(10u32), Natural::from(99u32));
+    /// assert_eq!(rng.sample(&d), 97);
+    /// ```
+    #[inline]
+    pub fn new_inclusive(a: Natural, b: Natural) -> UniformNatural {
+        assert!(a <= b);
+        UniformNatural::new(a, b + Natural::ONE)
+    }
+
+    /// Creates a distribution that uniformly samples [`Natural`]s less than a positive `limit`.
+    ///
+    /// $$
+    /// P(x) = \\begin{cases}
+    ///     \frac{1}{\\ell} & \text{if} \\quad x < \\ell, \\\\
+    ///     0 & \\text{otherwise}.
+    /// \\end{cases}
+    /// $$
+    /// where $\ell$ is `limit`.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if `limit` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::random::EXAMPLE_SEED;
+    /// use malachite_nz::natural::random::UniformNatural;
+    /// use malachite_nz::natural::Natural;
+    /// use rand::Rng;
+    ///
+    /// let mut rng = EXAMPLE_SEED.get_rng();
+    /// let d = UniformNatural::less_than(Natural::from(10u32));
+    /// assert_eq!(rng.sample(&d), 1);
+    /// ```
+    #[inline]
+    pub fn less_than(limit: Natural) -> UniformNatural {
+        assert_ne!(limit, 0);
+        UniformNatural {
+            a: Natural::ZERO,
+            width: limit,
+        }
+    }
+}
+
+impl Distribution<Natural> for UniformNatural {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Natural {
+        &self.a + get_random_natural_less_than_with_rng(rng, &self.width)
+    }
+}
+
+impl UniformSampler for UniformNatural {
+    type X = Natural;
+
+    #[inline]
+    fn new<B1: SampleBorrow<Natural>, B2: SampleBorrow<Natural>>(
+        low: B1,
+        high: B2,
+    ) -> UniformNatural {
+        UniformNatural::new(low.borrow().clone(), high.borrow().clone())
+    }
+
+    #[inline]
+    fn new_inclusive<B1: SampleBorrow<Natural>, B2: SampleBorrow<Natural>>(
+        low: B1,
+        high: B2,
+    ) -> UniformNatural {
+        UniformNatural::new_inclusive(low.borrow().clone(), high.borrow().clone())
+    }
+
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Natural {
+        Distribution::sample(self, rng)
+    }
+}
+
+impl SampleUniform for Natural {
+    type Sampler = UniformNatural;
+}
+
+/// A distribution that samples [`Natural`]s with a given bit length.
+///
+/// The [`Natural`] is 0 if the bit length $b$ is 0, or else chosen uniformly from $[2^{b-1},
+/// 2^b)$. See [`get_random_natural_with_bits`].
+///
+/// This implements [`rand`]'s [`Distribution`] trait, so it may be used with any [`Rng`].
+///
+/// # Examples
+/// ```
+/// use malachite_base::random::EXAMPLE_SEED;
+/// use malachite_nz::natural::random::UniformNaturalWithBits;
+/// use rand::Rng;
+///
+/// let mut rng = EXAMPLE_SEED.get_rng();
+/// assert_eq!(
+///     rng.sample(UniformNaturalWithBits(100)).to_string(),
+///     "976558340558744279591984426865"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct UniformNaturalWithBits(pub u64);
+
+impl Distribution<Natural> for UniformNaturalWithBits {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Natural {
+        get_random_natural_with_bits_with_rng(rng, self.0)
+    }
+}
+
+/// A distribution that uniformly samples [`Natural`]s with at most a given number of bits.
+///
+/// The [`Natural`] is chosen uniformly from $[0, 2^b)$, where $b$ is the maximum bit length. See
+/// [`get_random_natural_with_up_to_bits`].
+///
+/// This implements [`rand`]'s [`Distribution`] trait, so it may be used with any [`Rng`].
+///
+/// # Examples
+/// ```
+/// use malachite_base::random::EXAMPLE_SEED;
+/// use malachite_nz::natural::random::UniformNaturalWithUpToBits;
+/// use rand::Rng;
+///
+/// let mut rng = EXAMPLE_SEED.get_rng();
+/// assert_eq!(
+///     rng.sample(UniformNaturalWithUpToBits(100)).to_string(),
+///     "976558340558744279591984426865"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct UniformNaturalWithUpToBits(pub u64);
+
+impl Distribution<Natural> for UniformNaturalWithUpToBits {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Natural {
+        get_random_natural_with_up_to_bits_with_rng(rng, self.0)
+    }
+}
+
 /// Generates random [`Natural`]s greater than or equal to a lower bound.
 #[derive(Clone, Debug)]
 pub struct RandomNaturalRangeToInfinity {
@@ -107,6 +107,7 @@ use malachite_base::num::exhaustive::{
     primitive_int_increasing_inclusive_range, primitive_int_increasing_range,
 };
 use malachite_base::num::factorization::prime_sieve::n_to_bit;
+use malachite_base::num::factorization::traits::IsPrime;
 use malachite_base::num::iterators::{bit_distributor_sequence, ruler_sequence};
 use malachite_base::num::logic::traits::{
     BitAccess, BitConvertible, LeadingZeros, SignificantBits,
@@ -1138,6 +1139,10 @@ pub fn exhaustive_natural_pair_gen_var_15() -> It<(Natural, Natural)> {
     ))
 }
 
+pub fn exhaustive_natural_pair_gen_var_16() -> It<(Natural, Natural)> {
+    Box::new(exhaustive_ordered_unique_pairs(exhaustive_naturals()).filter(|(_, p)| p.is_prime()))
+}
+
 // -- (Natural, Natural, bool) --
 
 pub fn exhaustive_natural_natural_bool_triple_gen_var_1() -> It<(Natural, Natural, bool)> {
@@ -2212,6 +2217,14 @@ pub fn exhaustive_natural_vec_gen() -> It<Vec<Natural>> {
     Box::new(exhaustive_vecs(exhaustive_naturals()))
 }
 
+// -- Vec<(Natural, Natural)> --
+
+pub fn exhaustive_natural_pair_vec_gen_var_1() -> It<Vec<(Natural, Natural)>> {
+    Box::new(exhaustive_vecs(exhaustive_ordered_unique_pairs(
+        exhaustive_naturals(),
+    )))
+}
+
 // -- (Vec<Natural>, Integer)
 
 pub fn exhaustive_natural_vec_integer_pair_gen_var_1() -> It<(Vec<Natural>, Integer)> {
@@ -1439,6 +1439,15 @@ pub fn natural_pair_gen_var_15_rm() -> Generator<((rug::Integer, u32), (Natural,
     )
 }
 
+// All pairs of `Natural`s where the second is prime and the first is smaller than the second.
+pub fn natural_pair_gen_var_16() -> Generator<(Natural, Natural)> {
+    Generator::new(
+        &exhaustive_natural_pair_gen_var_16,
+        &random_natural_pair_gen_var_16,
+        &special_random_natural_pair_gen_var_16,
+    )
+}
+
 // -- (Natural, Natural, bool) --
 
 // All `(Natural, Natural, bool)` where the second `Natural` is positive.
@@ -2530,6 +2539,18 @@ pub fn natural_vec_gen_nrm() -> Generator<(Vec<BigUint>, Vec<rug::Integer>, Vec<
     )
 }
 
+// -- Vec<(Natural, Natural)> --
+
+// All `Vec`s of pairs of `Natural`s where, in each pair, the first `Natural` is smaller than the
+// second.
+pub fn natural_pair_vec_gen_var_1() -> Generator<Vec<(Natural, Natural)>> {
+    Generator::new(
+        &exhaustive_natural_pair_vec_gen_var_1,
+        &random_natural_pair_vec_gen_var_1,
+        &special_random_natural_pair_vec_gen_var_1,
+    )
+}
+
 // -- (Vec<Natural>, Integer) --
 
 // All `(Vec<Natural>, Integer)` where the `Natural`s are positive.
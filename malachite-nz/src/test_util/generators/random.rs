@@ -97,6 +97,7 @@ use malachite_base::num::conversion::traits::{
     ConvertibleFrom, ExactFrom, SaturatingFrom, ToSci, WrappingFrom,
 };
 use malachite_base::num::factorization::prime_sieve::n_to_bit;
+use malachite_base::num::factorization::traits::IsPrime;
 use malachite_base::num::logic::traits::{
     BitAccess, BitConvertible, LeadingZeros, SignificantBits,
 };
@@ -2055,6 +2056,17 @@ pub fn random_natural_pair_gen_var_15(config: &GenConfig) -> It<(Natural, Natura
     ))
 }
 
+pub fn random_natural_pair_gen_var_16(config: &GenConfig) -> It<(Natural, Natural)> {
+    Box::new(
+        random_ordered_unique_pairs(random_naturals(
+            EXAMPLE_SEED,
+            config.get_or("mean_bits_n", 64),
+            config.get_or("mean_bits_d", 1),
+        ))
+        .filter(|(_, p)| p.is_prime()),
+    )
+}
+
 // -- (Natural, Natural, bool) --
 
 pub fn random_natural_natural_bool_triple_gen_var_1(
@@ -4021,6 +4033,23 @@ pub fn random_natural_vec_gen(config: &GenConfig) -> It<Vec<Natural>> {
     ))
 }
 
+// -- Vec<(Natural, Natural)> --
+
+pub fn random_natural_pair_vec_gen_var_1(config: &GenConfig) -> It<Vec<(Natural, Natural)>> {
+    Box::new(random_vecs(
+        EXAMPLE_SEED,
+        &|seed| {
+            random_ordered_unique_pairs(random_naturals(
+                seed,
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            ))
+        },
+        config.get_or("mean_len_n", 4),
+        config.get_or("mean_len_d", 1),
+    ))
+}
+
 // -- (Vec<Natural>, Integer> --
 
 pub fn random_natural_vec_integer_pair_gen_var_1(
@@ -0,0 +1,27 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use malachite_base::num::basic::traits::{One, Two, Zero};
+
+fn linear_recurrence_naive(n: u64, mut x: Natural, mut y: Natural) -> Natural {
+    for _ in 0..n {
+        let z = &x + &y;
+        x = y;
+        y = z;
+    }
+    x
+}
+
+pub fn fibonacci_naive(n: u64) -> Natural {
+    linear_recurrence_naive(n, Natural::ZERO, Natural::ONE)
+}
+
+pub fn lucas_naive(n: u64) -> Natural {
+    linear_recurrence_naive(n, Natural::TWO, Natural::ONE)
+}
@@ -0,0 +1,98 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use itertools::Itertools;
+use malachite_base::test_util::generators::unsigned_vec_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use num::BigInt;
+
+#[test]
+fn test_from_sign_and_bytes() {
+    let test = |sign, xs: &[u8], out| {
+        let x = Integer::from_sign_and_bytes_le(sign, xs);
+        assert_eq!(x.to_string(), out);
+        assert!(x.is_valid());
+
+        let ys = xs.iter().copied().rev().collect_vec();
+        let x = Integer::from_sign_and_bytes_be(sign, &ys);
+        assert_eq!(x.to_string(), out);
+        assert!(x.is_valid());
+    };
+    test(true, &[], "0");
+    test(false, &[], "0");
+    test(false, &[0, 0], "0");
+    test(true, &[123], "123");
+    test(false, &[123], "-123");
+    test(false, &[123, 0, 0], "-123");
+    test(true, &[0, 16, 165, 212, 232], "1000000000000");
+    test(false, &[0, 16, 165, 212, 232], "-1000000000000");
+    test(false, &[0, 0, 0, 0, 0, 0, 0, 0, 1], "-18446744073709551616");
+}
+
+#[test]
+fn test_from_twos_complement_bytes() {
+    let test = |xs: &[u8], out| {
+        let x = Integer::from_twos_complement_bytes_le(xs);
+        assert_eq!(x.to_string(), out);
+        assert!(x.is_valid());
+
+        let ys = xs.iter().copied().rev().collect_vec();
+        let x = Integer::from_twos_complement_bytes_be(&ys);
+        assert_eq!(x.to_string(), out);
+        assert!(x.is_valid());
+    };
+    test(&[], "0");
+    test(&[0], "0");
+    test(&[0, 0, 0], "0");
+    test(&[123], "123");
+    test(&[133], "-123");
+    test(&[128], "-128");
+    test(&[128, 0], "128");
+    test(&[127, 255], "-129");
+    test(&[255], "-1");
+    test(&[255; 20], "-1");
+    test(&[0, 16, 165, 212, 232, 0], "1000000000000");
+    test(&[0, 240, 90, 43, 23, 255], "-1000000000000");
+    test(&[0, 240, 90, 43, 23, 255, 255, 255, 255], "-1000000000000");
+    test(&[255, 255, 255, 255, 0], "4294967295");
+    test(&[1, 0, 0, 0, 255], "-4294967295");
+    test(&[0, 0, 0, 0, 0, 0, 0, 128], "-9223372036854775808");
+    test(&[0, 0, 0, 0, 0, 0, 0, 128, 0], "9223372036854775808");
+    test(&[0, 0, 0, 0, 0, 0, 0, 0, 255], "-18446744073709551616");
+}
+
+#[test]
+fn from_sign_and_bytes_properties() {
+    unsigned_vec_gen::<u8>().test_properties(|xs| {
+        let ys = xs.iter().copied().rev().collect_vec();
+        let abs = Natural::from_bytes_le(&xs);
+        for sign in [false, true] {
+            let x = Integer::from_sign_and_bytes_le(sign, &xs);
+            assert!(x.is_valid());
+            assert_eq!(Integer::from_sign_and_bytes_be(sign, &ys), x);
+            assert_eq!(x, Integer::from_sign_and_abs_ref(sign, &abs));
+        }
+    });
+}
+
+#[test]
+fn from_twos_complement_bytes_properties() {
+    unsigned_vec_gen::<u8>().test_properties(|xs| {
+        let x = Integer::from_twos_complement_bytes_le(&xs);
+        assert!(x.is_valid());
+        assert_eq!(Integer::from(&BigInt::from_signed_bytes_le(&xs)), x);
+        let ys = xs.iter().copied().rev().collect_vec();
+        assert_eq!(Integer::from_twos_complement_bytes_be(&ys), x);
+        if let Some(&last) = xs.last() {
+            assert_eq!(last >= 0x80, x < 0);
+        } else {
+            assert_eq!(x, 0);
+        }
+    });
+}
@@ -9,6 +9,7 @@
 use malachite_base::strings::string_is_subset;
 use malachite_base::test_util::generators::{string_gen, string_gen_var_9};
 use malachite_nz::integer::Integer;
+use malachite_nz::integer::conversion::serde::{CompactInteger, CompactIntegerRef};
 use malachite_nz::test_util::generators::integer_gen;
 use std::str::FromStr;
 
@@ -70,3 +71,55 @@ fn serde_properties() {
         let _n: Integer = serde_json::from_str(&s).unwrap();
     });
 }
+
+#[test]
+fn test_compact_serde() {
+    let test = |n, out| {
+        let x = Integer::from_str(n).unwrap();
+        assert_eq!(serde_json::to_string(&CompactIntegerRef(&x)).unwrap(), out);
+        assert_eq!(
+            serde_json::to_string(&CompactInteger(x.clone())).unwrap(),
+            out
+        );
+        assert_eq!(
+            serde_json::from_str::<CompactInteger>(out).unwrap(),
+            CompactInteger(x)
+        );
+    };
+    test("0", "[true,[]]");
+    test("100", "[true,[100]]");
+    test("-100", "[false,[100]]");
+    test("4294967296", "[true,[0,0,0,0,1]]");
+    test("-4294967296", "[false,[0,0,0,0,1]]");
+    test("-18446744073709551616", "[false,[0,0,0,0,0,0,0,0,1]]");
+}
+
+#[test]
+fn test_compact_serde_fail() {
+    let test = |s| {
+        assert!(serde_json::from_str::<CompactInteger>(s).is_err());
+    };
+    test("[false,[]]");
+    test("[true,[1,0]]");
+    test("[true]");
+    test("[1,[1]]");
+    test("[true,[1],[1]]");
+    test("\"-0x1\"");
+    test("null");
+}
+
+#[test]
+fn compact_serde_properties() {
+    integer_gen().test_properties(|x| {
+        let s = serde_json::to_string(&CompactIntegerRef(&x)).unwrap();
+        assert_eq!(
+            serde_json::from_str::<CompactInteger>(&s).unwrap(),
+            CompactInteger(x)
+        );
+        assert!(string_is_subset(&s, "[],0123456789aeflrstu"));
+    });
+
+    string_gen().test_properties(|s| {
+        let _n: Result<CompactInteger, _> = serde_json::from_str(&s);
+    });
+}
@@ -0,0 +1,102 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_gen;
+use num::BigInt;
+use std::str::FromStr;
+
+#[test]
+fn test_to_sign_and_bytes() {
+    let test = |n, sign, out_le: &[u8], out_be: &[u8]| {
+        let x = Integer::from_str(n).unwrap();
+        assert_eq!(x.to_sign_and_bytes_le(), (sign, out_le.to_vec()));
+        assert_eq!(x.to_sign_and_bytes_be(), (sign, out_be.to_vec()));
+    };
+    test("0", true, &[], &[]);
+    test("123", true, &[123], &[123]);
+    test("-123", false, &[123], &[123]);
+    test(
+        "1000000000000",
+        true,
+        &[0, 16, 165, 212, 232],
+        &[232, 212, 165, 16, 0],
+    );
+    test(
+        "-1000000000000",
+        false,
+        &[0, 16, 165, 212, 232],
+        &[232, 212, 165, 16, 0],
+    );
+}
+
+#[test]
+fn test_to_twos_complement_bytes() {
+    let test = |n, out_le: &[u8]| {
+        let x = Integer::from_str(n).unwrap();
+        assert_eq!(x.to_twos_complement_bytes_le(), out_le);
+        let mut out_be = out_le.to_vec();
+        out_be.reverse();
+        assert_eq!(x.to_twos_complement_bytes_be(), out_be);
+    };
+    test("0", &[]);
+    test("123", &[123]);
+    test("-123", &[133]);
+    test("127", &[127]);
+    test("128", &[128, 0]);
+    test("-128", &[128]);
+    test("-129", &[127, 255]);
+    test("-1", &[255]);
+    test("1000000000000", &[0, 16, 165, 212, 232, 0]);
+    test("-1000000000000", &[0, 240, 90, 43, 23, 255]);
+    test("4294967295", &[255, 255, 255, 255, 0]);
+    test("-4294967295", &[1, 0, 0, 0, 255]);
+    test("-4294967296", &[0, 0, 0, 0, 255]);
+    test(
+        "9223372036854775807",
+        &[255, 255, 255, 255, 255, 255, 255, 127],
+    );
+    test("9223372036854775808", &[0, 0, 0, 0, 0, 0, 0, 128, 0]);
+    test("-9223372036854775808", &[0, 0, 0, 0, 0, 0, 0, 128]);
+    test(
+        "-9223372036854775809",
+        &[255, 255, 255, 255, 255, 255, 255, 127, 255],
+    );
+    test("-18446744073709551616", &[0, 0, 0, 0, 0, 0, 0, 0, 255]);
+}
+
+#[test]
+fn to_sign_and_bytes_properties() {
+    integer_gen().test_properties(|x| {
+        let (sign, xs) = x.to_sign_and_bytes_le();
+        assert_eq!(sign, x >= 0);
+        assert_eq!(xs, x.unsigned_abs_ref().to_bytes_le());
+        assert_eq!(Integer::from_sign_and_bytes_le(sign, &xs), x);
+        let (sign_be, ys) = x.to_sign_and_bytes_be();
+        assert_eq!(sign_be, sign);
+        assert_eq!(Integer::from_sign_and_bytes_be(sign, &ys), x);
+    });
+}
+
+#[test]
+fn to_twos_complement_bytes_properties() {
+    integer_gen().test_properties(|x| {
+        let xs = x.to_twos_complement_bytes_le();
+        assert_eq!(Integer::from_twos_complement_bytes_le(&xs), x);
+        if x == 0 {
+            assert!(xs.is_empty());
+        } else {
+            assert_eq!(BigInt::from(&x).to_signed_bytes_le(), xs);
+            assert_eq!(*xs.last().unwrap() >= 0x80, x < 0);
+        }
+        let mut ys = x.to_twos_complement_bytes_be();
+        assert_eq!(Integer::from_twos_complement_bytes_be(&ys), x);
+        ys.reverse();
+        assert_eq!(ys, xs);
+    });
+}
@@ -0,0 +1,103 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+use malachite_base::num::arithmetic::traits::{Parity, Pow};
+use malachite_base::num::factorization::traits::{ExpressAsPower, IsPerfectPower};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::signed_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{integer_gen, natural_gen};
+use std::str::FromStr;
+
+#[test]
+fn test_is_perfect_power() {
+    let test = |s, out| {
+        let n = Integer::from_str(s).unwrap();
+        let result = n.is_perfect_power();
+        assert_eq!(result.to_debug_string(), out);
+        assert_eq!(
+            n.express_as_power(),
+            result.clone().unwrap_or_else(|| (n.clone(), 1))
+        );
+        assert_eq!(rug::Integer::from(&n).is_perfect_power(), result.is_some());
+    };
+    test("0", "Some((0, 2))");
+    test("1", "Some((1, 2))");
+    test("-1", "Some((-1, 3))");
+    test("2", "None");
+    test("-2", "None");
+    test("4", "Some((2, 2))");
+    test("-4", "None");
+    test("-8", "Some((-2, 3))");
+    test("64", "Some((2, 6))");
+    test("-64", "Some((-4, 3))");
+    test("-1728", "Some((-12, 3))");
+    // -2^64
+    test("-18446744073709551616", "None");
+    // -2^63
+    test("-9223372036854775808", "Some((-2, 63))");
+    // -6^30
+    test("-221073919720733357899776", "Some((-36, 15))");
+    // -3^41
+    test("-36472996377170786403", "Some((-3, 41))");
+    // -10^40
+    test(
+        "-10000000000000000000000000000000000000000",
+        "Some((-100000000, 5))",
+    );
+}
+
+#[test]
+fn is_perfect_power_properties() {
+    integer_gen().test_properties(|n| {
+        let result = n.is_perfect_power();
+        assert_eq!(
+            n.express_as_power(),
+            result.clone().unwrap_or_else(|| (n.clone(), 1))
+        );
+        assert_eq!(rug::Integer::from(&n).is_perfect_power(), result.is_some());
+        if let Some((base, exp)) = result {
+            assert!(exp > 1);
+            assert_eq!((&base).pow(exp), n);
+            if n < 0 {
+                assert!(exp.odd());
+            }
+        }
+        if n < 0 {
+            if let Some((base, exp)) = (-&n).is_perfect_power() {
+                if exp.odd() {
+                    assert_eq!(n.is_perfect_power(), Some((-base, exp)));
+                }
+            }
+        }
+    });
+
+    natural_gen().test_properties(|n| {
+        assert_eq!(
+            Integer::from(&n).is_perfect_power(),
+            n.is_perfect_power()
+                .map(|(base, exp)| (Integer::from(base), exp))
+        );
+    });
+
+    signed_gen::<i64>().test_properties(|i| {
+        // The primitive implementations return `None` for -1
+        if i != -1 {
+            assert_eq!(
+                Integer::from(i).is_perfect_power(),
+                i.is_perfect_power()
+                    .map(|(base, exp)| (Integer::from(base), u64::from(exp)))
+            );
+        }
+        let (base, exp) = i.express_as_power();
+        assert_eq!(
+            Integer::from(i).express_as_power(),
+            (Integer::from(base), exp)
+        );
+    });
+}
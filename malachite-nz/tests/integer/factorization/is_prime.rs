@@ -0,0 +1,46 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::UnsignedAbs;
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::{integer_gen, natural_gen};
+use std::str::FromStr;
+
+fn test_helper(s: &str, out: bool) {
+    let n = Integer::from_str(s).unwrap();
+    assert_eq!(n.is_prime(), out);
+    assert_eq!(n.probably_prime(10), out);
+}
+
+#[test]
+fn test_is_prime() {
+    test_helper("0", false);
+    test_helper("1", false);
+    test_helper("-1", false);
+    test_helper("2", true);
+    test_helper("-2", false);
+    test_helper("97", true);
+    test_helper("-97", false);
+    test_helper("170141183460469231731687303715884105727", true);
+    test_helper("-170141183460469231731687303715884105727", false);
+    test_helper("340282366920938463463374607431768211457", false);
+}
+
+#[test]
+fn is_prime_properties() {
+    integer_gen().test_properties(|n| {
+        let is_prime = n.is_prime();
+        assert_eq!(n.probably_prime(5), is_prime);
+        assert_eq!(is_prime, n > 0u32 && n.unsigned_abs().is_prime());
+    });
+
+    natural_gen().test_properties(|n| {
+        assert_eq!(Integer::from(&n).is_prime(), n.is_prime());
+    });
+}
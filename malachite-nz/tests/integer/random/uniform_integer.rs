@@ -0,0 +1,114 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use itertools::Itertools;
+use malachite_base::random::EXAMPLE_SEED;
+use malachite_nz::integer::Integer;
+use malachite_nz::integer::random::{
+    UniformInteger, uniform_random_integer_inclusive_range, uniform_random_integer_range,
+};
+use rand::Rng;
+use std::str::FromStr;
+
+fn uniform_integer_helper(a: &str, b: &str, expected_values: &[&str]) {
+    let a = Integer::from_str(a).unwrap();
+    let b = Integer::from_str(b).unwrap();
+    let d = UniformInteger::new(a.clone(), b.clone());
+    let xs = EXAMPLE_SEED
+        .get_rng()
+        .sample_iter(&d)
+        .take(20)
+        .map(|x| x.to_string())
+        .collect_vec();
+    assert_eq!(xs, expected_values);
+
+    // The distribution samples the same values as the corresponding iterator
+    let mut rng = EXAMPLE_SEED.get_rng();
+    assert!(
+        uniform_random_integer_range(EXAMPLE_SEED, a.clone(), b.clone())
+            .take(100)
+            .all(|x| rng.sample(&d) == x)
+    );
+    let mut rng = EXAMPLE_SEED.get_rng();
+    assert!(
+        uniform_random_integer_range(EXAMPLE_SEED, a.clone(), b.clone())
+            .take(100)
+            .all(|x| rng.gen_range(a.clone()..b.clone()) == x)
+    );
+    let b = b - Integer::from(1);
+    let d = UniformInteger::new_inclusive(a.clone(), b.clone());
+    let mut rng = EXAMPLE_SEED.get_rng();
+    assert!(
+        uniform_random_integer_inclusive_range(EXAMPLE_SEED, a.clone(), b.clone())
+            .take(100)
+            .all(|x| rng.sample(&d) == x)
+    );
+    let mut rng = EXAMPLE_SEED.get_rng();
+    assert!(
+        uniform_random_integer_inclusive_range(EXAMPLE_SEED, a.clone(), b.clone())
+            .take(100)
+            .all(|x| rng.gen_range(a.clone()..=b.clone()) == x)
+    );
+}
+
+#[test]
+fn test_uniform_integer() {
+    uniform_integer_helper("-5", "-4", &["-5"; 20]);
+    uniform_integer_helper(
+        "-1000",
+        "1000",
+        &[
+            "905", "-913", "-907", "-371", "543", "1", "650", "384", "-758", "515", "-916", "-845",
+            "-58", "373", "46", "238", "-253", "-359", "139", "-865",
+        ],
+    );
+    uniform_integer_helper(
+        "-1000000000000000000000",
+        "1000000000000000000000",
+        &[
+            "616515526727435677553",
+            "946163446398361609735",
+            "931765208451720654450",
+            "987437928348770367730",
+            "-488364078506915200940",
+            "718032171441240456110",
+            "590764949064086244374",
+            "-971032604170089102613",
+            "-864217878162306368397",
+            "-532155562187074688739",
+            "799883200219202785464",
+            "308075575531169831974",
+            "19290828601620225158",
+            "870184922642309372775",
+            "-764151244696376579171",
+            "622206652789566705415",
+            "506145300281917964655",
+            "-36382140198916348905",
+            "-855717556489551575208",
+            "-862466440032674933444",
+        ],
+    );
+}
+
+#[test]
+#[should_panic]
+fn uniform_integer_fail_1() {
+    UniformInteger::new(Integer::from(-1), Integer::from(-1));
+}
+
+#[test]
+#[should_panic]
+fn uniform_integer_fail_2() {
+    UniformInteger::new(Integer::from(1), Integer::from(-1));
+}
+
+#[test]
+#[should_panic]
+fn uniform_integer_new_inclusive_fail() {
+    UniformInteger::new_inclusive(Integer::from(1), Integer::from(-1));
+}
@@ -130,6 +130,7 @@ pub mod integer {
     pub mod conversion {
         pub mod clone;
         pub mod from_bool;
+        pub mod from_bytes;
         pub mod from_natural;
         pub mod from_primitive_float;
         pub mod from_primitive_int;
@@ -146,6 +147,7 @@ pub mod integer {
             pub mod to_sci;
             pub mod to_string;
         }
+        pub mod to_bytes;
         pub mod to_twos_complement_limbs;
     }
     pub mod exhaustive {
@@ -163,6 +165,10 @@ pub mod integer {
         pub mod integer_increasing_range;
         pub mod integer_increasing_range_to_infinity;
     }
+    pub mod factorization {
+        pub mod is_perfect_power;
+        pub mod is_prime;
+    }
     pub mod logic {
         pub mod and;
         pub mod assign_bit;
@@ -214,6 +220,7 @@ pub mod integer {
         pub mod striped_random_negative_integers;
         pub mod striped_random_nonzero_integers;
         pub mod striped_random_positive_integers;
+        pub mod uniform_integer;
         pub mod uniform_random_integer_inclusive_range;
         pub mod uniform_random_integer_range;
     }
@@ -226,6 +233,7 @@ pub mod natural {
         pub mod binomial_coefficient;
         pub mod checked_sub;
         pub mod checked_sub_mul;
+        pub mod chinese_remainder;
         pub mod coprime_with;
         pub mod div;
         pub mod div_exact;
@@ -237,6 +245,7 @@ pub mod natural {
         pub mod eq_mod_power_of_2;
         pub mod extended_gcd;
         pub mod factorial;
+        pub mod fibonacci;
         pub mod gcd;
         pub mod is_power_of_2;
         pub mod kronecker_symbol;
@@ -264,6 +273,7 @@ pub mod natural {
         pub mod mod_power_of_2_sub;
         pub mod mod_shl;
         pub mod mod_shr;
+        pub mod mod_sqrt;
         pub mod mod_square;
         pub mod mod_sub;
         pub mod mul;
@@ -317,6 +327,7 @@ pub mod natural {
             pub mod to_power_of_2_digits;
         }
         pub mod from_bool;
+        pub mod from_bytes;
         pub mod from_limbs;
         pub mod from_primitive_float;
         pub mod from_primitive_int;
@@ -335,6 +346,7 @@ pub mod natural {
             pub mod to_sci;
             pub mod to_string;
         }
+        pub mod to_bytes;
         pub mod to_limbs;
     }
     pub mod exhaustive {
@@ -345,7 +357,20 @@ pub mod natural {
         pub mod exhaustive_positive_naturals;
     }
     pub mod factorization {
+        pub mod carmichael_lambda;
+        pub mod divisor_count;
+        pub mod divisor_sum;
+        pub mod divisors;
+        pub mod euler_totient;
+        pub mod factor;
+        pub mod is_perfect_power;
+        pub mod is_prime;
         pub mod is_square;
+        pub mod mod_log;
+        pub mod moebius;
+        pub mod multiplicative_order;
+        pub mod next_prime;
+        pub mod prev_prime;
         pub mod primes;
     }
     pub mod logic {
@@ -391,6 +416,7 @@ pub mod natural {
         pub mod striped_random_natural_range_to_infinity;
         pub mod striped_random_naturals;
         pub mod striped_random_positive_naturals;
+        pub mod uniform_natural;
         pub mod uniform_random_natural_inclusive_range;
         pub mod uniform_random_natural_range;
     }
@@ -0,0 +1,187 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{ChineseRemainder, Gcd, Lcm, Pow};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::factorization::traits::NextPrime;
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::unsigned_pair_vec_gen_var_1;
+use malachite_nz::natural::Natural;
+use malachite_nz::natural::arithmetic::chinese_remainder::{
+    chinese_remainder_pairwise, chinese_remainder_subproduct_tree,
+};
+use malachite_nz::platform::Limb;
+use malachite_nz::test_util::generators::{natural_pair_gen_var_8, natural_pair_vec_gen_var_1};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_chinese_remainder() {
+    let test = |congruences: &[(&str, &str)], out| {
+        let congruences: Vec<(Natural, Natural)> = congruences
+            .iter()
+            .map(|&(r, m)| (Natural::from_str(r).unwrap(), Natural::from_str(m).unwrap()))
+            .collect();
+        let result = Natural::chinese_remainder(congruences.clone());
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.is_none_or(|(r, m)| r.is_valid() && m.is_valid()));
+        assert_eq!(
+            chinese_remainder_pairwise(&congruences).to_debug_string(),
+            out
+        );
+    };
+    test(&[], "Some((0, 1))");
+    test(&[("0", "1")], "Some((0, 1))");
+    test(&[("3", "7")], "Some((3, 7))");
+    test(&[("2", "3"), ("3", "5"), ("2", "7")], "Some((23, 105))");
+    test(&[("3", "4"), ("5", "6")], "Some((11, 12))");
+    test(&[("3", "4"), ("4", "6")], "None");
+    test(&[("1", "6"), ("3", "10"), ("13", "15")], "Some((13, 30))");
+    test(&[("1", "6"), ("3", "10"), ("8", "15")], "None");
+    test(
+        &[("1", "1000000007"), ("2", "998244353"), ("3", "1000000009")],
+        "Some((126879769030076278993425315, 998244368971909710889394239))",
+    );
+    test(
+        &[("1", "100000000000000000000"), ("2", "12157665459056928801")],
+        "Some((991254697425374721400000000000000000001, \
+        1215766545905692880100000000000000000000))",
+    );
+    test(
+        &[("1", "100000000000000000000"), ("2", "12157665459056928800")],
+        "None",
+    );
+}
+
+#[test]
+fn test_chinese_remainder_many_moduli() {
+    // Pairwise coprime moduli, so the subproduct tree is used
+    let mut p = Natural::from(10u32).pow(20);
+    let mut congruences = Vec::new();
+    for i in 0u32..20 {
+        p = p.next_prime();
+        let r = Natural::from(i * i + 1) % &p;
+        congruences.push((r, p.clone()));
+    }
+    let (r, m) = Natural::chinese_remainder(congruences.clone()).unwrap();
+    assert!(r.is_valid());
+    assert!(m.is_valid());
+    assert_eq!(
+        r.to_string(),
+        "430842431898012546793528014518240963847463108665389701881393229317444527431694765882785358\
+        279778002028357465686454410232900954945697457471704386096347102478174771329720942019833018\
+        813117162039991871274926825308213977232370570368537175847565936754052136454642418857087441\
+        124240033068394469566649432096841023219485524625949692042218437648488131137829822894059592\
+        4045026830401533936387820976737774783948"
+    );
+    assert_eq!(
+        m.to_string(),
+        "100000000000000008834000000000000365644360000000009422802617400000169453192794207002258825\
+        564767581863140183788535845603989629916550569157257533457213070958399516858614949533141312\
+        094053615678804072114398413400668550389798606915721388735279145504801102795980062625728532\
+        560761719839559001702025904717418459024658170691898237054431919427161830313313631681429695\
+        46075275567082014239795130643827726984771"
+    );
+    assert_eq!(
+        chinese_remainder_subproduct_tree(&congruences),
+        Some((r.clone(), m.clone()))
+    );
+    assert_eq!(chinese_remainder_pairwise(&congruences), Some((r, m)));
+
+    // Moduli that are not pairwise coprime, so the subproduct tree can't be used
+    let x = Natural::from(10u32).pow(30) + Natural::from(12345u32);
+    let congruences: Vec<(Natural, Natural)> = (2u32..22)
+        .map(|m| {
+            let m = Natural::from(m);
+            (&x % &m, m)
+        })
+        .collect();
+    assert_eq!(chinese_remainder_subproduct_tree(&congruences), None);
+    assert_eq!(
+        Natural::chinese_remainder(congruences.clone()).to_debug_string(),
+        "Some((32724025, 232792560))"
+    );
+
+    // Inconsistent congruences
+    let mut congruences = congruences;
+    congruences[19].0 = (&congruences[19].0 + Natural::ONE) % Natural::from(21u32);
+    assert_eq!(Natural::chinese_remainder(congruences), None);
+}
+
+#[test]
+fn chinese_remainder_fail() {
+    assert_panic!(Natural::chinese_remainder([(Natural::ZERO, Natural::ZERO)]));
+    assert_panic!(Natural::chinese_remainder([(
+        Natural::from(3u32),
+        Natural::from(3u32)
+    )]));
+}
+
+#[test]
+fn chinese_remainder_properties() {
+    natural_pair_vec_gen_var_1().test_properties(|congruences| {
+        let result = Natural::chinese_remainder(congruences.clone());
+        assert!(
+            result
+                .as_ref()
+                .is_none_or(|(r, m)| r.is_valid() && m.is_valid())
+        );
+        assert_eq!(chinese_remainder_pairwise(&congruences), result);
+        if !congruences.is_empty() {
+            if let Some(tree_result) = chinese_remainder_subproduct_tree(&congruences) {
+                assert_eq!(Some(tree_result), result);
+            }
+        }
+        let mut reversed = congruences.clone();
+        reversed.reverse();
+        assert_eq!(Natural::chinese_remainder(reversed), result);
+        let lcm = congruences
+            .iter()
+            .fold(Natural::ONE, |m, (_, m_i)| m.lcm(m_i));
+        if let Some((r, m)) = result {
+            assert_eq!(m, lcm);
+            assert!(r < m);
+            for (r_i, m_i) in &congruences {
+                assert_eq!(&r % m_i, *r_i);
+            }
+            let mut extended = congruences;
+            extended.push((r.clone(), m.clone()));
+            assert_eq!(Natural::chinese_remainder(extended), Some((r, m)));
+        } else {
+            // Some pair of congruences must be inconsistent.
+            assert!(congruences.iter().enumerate().any(|(i, (r_i, m_i))| {
+                congruences[i + 1..].iter().any(|(r_j, m_j)| {
+                    let g = m_i.gcd(m_j);
+                    r_i % &g != r_j % g
+                })
+            }));
+        }
+    });
+
+    natural_pair_gen_var_8().test_properties(|(r, m)| {
+        assert_eq!(
+            Natural::chinese_remainder([(r.clone(), m.clone())]),
+            Some((r.clone(), m.clone()))
+        );
+        assert_eq!(
+            Natural::chinese_remainder([(r.clone(), m.clone()), (Natural::ZERO, Natural::ONE)]),
+            Some((r, m))
+        );
+    });
+
+    unsigned_pair_vec_gen_var_1::<Limb>().test_properties(|congruences| {
+        assert_eq!(
+            Natural::chinese_remainder(
+                congruences
+                    .iter()
+                    .map(|&(r, m)| (Natural::from(r), Natural::from(m)))
+            ),
+            Limb::chinese_remainder(congruences).map(|(r, m)| (Natural::from(r), Natural::from(m)))
+        );
+    });
+}
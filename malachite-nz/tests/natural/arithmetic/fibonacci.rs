@@ -0,0 +1,197 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{
+    CheckedFibonacci, CheckedLucas, Fibonacci, Lucas, Parity, Square,
+};
+use malachite_base::num::basic::traits::Two;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::{unsigned_gen_var_5, unsigned_pair_gen_var_44};
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use malachite_nz::test_util::natural::arithmetic::fibonacci::{fibonacci_naive, lucas_naive};
+use rug::Complete;
+
+#[test]
+fn test_fibonacci() {
+    fn test(n: u64, out: &str) {
+        let f = Natural::fibonacci(n);
+        assert!(f.is_valid());
+        assert_eq!(f.to_string(), out);
+        assert_eq!(fibonacci_naive(n).to_string(), out);
+        assert_eq!(
+            rug::Integer::fibonacci(u32::exact_from(n))
+                .complete()
+                .to_string(),
+            out
+        );
+    }
+    test(0, "0");
+    test(1, "1");
+    test(2, "1");
+    test(3, "2");
+    test(4, "3");
+    test(5, "5");
+    test(10, "55");
+    test(93, "12200160415121876738");
+    test(186, "332825110087067562321196029789634457848");
+    test(187, "538522340430300790495419781092981030533");
+    test(188, "871347450517368352816615810882615488381");
+    test(200, "280571172992510140037611932413038677189525");
+    test(
+        500,
+        "139423224561697880139724382870407283950070256587697307264108962948325571622863290691557658\
+        876222521294125",
+    );
+}
+
+#[test]
+fn test_fibonacci2() {
+    fn test(n: u64, out: &str) {
+        let (f, g) = Natural::fibonacci2(n);
+        assert!(f.is_valid());
+        assert!(g.is_valid());
+        assert_eq!((&f, &g).to_debug_string(), out);
+        let (rf, rg) = rug::Integer::fibonacci_2(u32::exact_from(n)).complete();
+        assert_eq!(
+            (rf.to_string(), rg.to_string()),
+            (f.to_string(), g.to_string())
+        );
+    }
+    test(0, "(0, 1)");
+    test(1, "(1, 0)");
+    test(2, "(1, 1)");
+    test(3, "(2, 1)");
+    test(10, "(55, 34)");
+    test(
+        187,
+        "(538522340430300790495419781092981030533, 332825110087067562321196029789634457848)",
+    );
+    test(
+        200,
+        "(280571172992510140037611932413038677189525, 173402521172797813159685037284371942044301)",
+    );
+}
+
+#[test]
+fn test_lucas() {
+    fn test(n: u64, out: &str) {
+        let l = Natural::lucas(n);
+        assert!(l.is_valid());
+        assert_eq!(l.to_string(), out);
+        assert_eq!(lucas_naive(n).to_string(), out);
+        assert_eq!(
+            rug::Integer::lucas(u32::exact_from(n))
+                .complete()
+                .to_string(),
+            out
+        );
+    }
+    test(0, "2");
+    test(1, "1");
+    test(2, "3");
+    test(3, "4");
+    test(4, "7");
+    test(10, "123");
+    test(92, "16860207025497407047");
+    test(184, "284266580942632122201475224120405260207");
+    test(185, "459952989830901896468168308275922343011");
+    test(186, "744219570773534018669643532396327603218");
+    test(200, "627376215338105766356982006981782561278127");
+    test(201, "1015116040150328233272520834523487973612876");
+}
+
+#[test]
+fn fibonacci_properties() {
+    unsigned_gen_var_5().test_properties(|n| {
+        let f = Natural::fibonacci(n);
+        assert!(f.is_valid());
+        assert_eq!(fibonacci_naive(n), f);
+        assert_eq!(
+            Natural::exact_from(&rug::Integer::fibonacci(u32::exact_from(n)).complete()),
+            f
+        );
+        let (f_alt, g) = Natural::fibonacci2(n);
+        assert_eq!(f_alt, f);
+        if n != 0 {
+            assert_eq!(Natural::fibonacci(n - 1), g);
+        }
+        assert_eq!(Natural::fibonacci(n + 2), Natural::fibonacci(n + 1) + &f);
+        // F_{2n} = F_n L_n
+        assert_eq!(Natural::fibonacci(n << 1), &f * Natural::lucas(n));
+        // F_{2n + 1} = F_{n + 1}^2 + F_n^2
+        assert_eq!(
+            Natural::fibonacci((n << 1) + 1),
+            Natural::fibonacci(n + 1).square() + f.square()
+        );
+    });
+
+    unsigned_pair_gen_var_44::<u64>().test_properties(|(m, n)| {
+        // F_{m + n} = F_m F_{n + 1} + F_{m - 1} F_n
+        let (f_m, f_m_1) = Natural::fibonacci2(m);
+        assert_eq!(
+            Natural::fibonacci(m + n),
+            f_m * Natural::fibonacci(n + 1) + f_m_1 * Natural::fibonacci(n)
+        );
+    });
+
+    unsigned_gen_var_5().test_properties(|n| {
+        if let Some(f) = Limb::checked_fibonacci(n) {
+            assert_eq!(Natural::fibonacci(n), f);
+            let (f, g) = Limb::fibonacci2(n);
+            assert_eq!(Natural::fibonacci2(n), (Natural::from(f), Natural::from(g)));
+        }
+    });
+}
+
+#[test]
+fn fibonacci2_properties() {
+    unsigned_gen_var_5().test_properties(|n| {
+        let (f, g) = Natural::fibonacci2(n);
+        assert!(f.is_valid());
+        assert!(g.is_valid());
+        let (rf, rg) = rug::Integer::fibonacci_2(u32::exact_from(n)).complete();
+        assert_eq!(Natural::exact_from(&rf), f);
+        assert_eq!(Natural::exact_from(&rg), g);
+        assert_eq!(Natural::fibonacci(n), f);
+        // F_{n + 1} = F_n + F_{n - 1}
+        assert_eq!(Natural::fibonacci(n + 1), f + g);
+    });
+}
+
+#[test]
+fn lucas_properties() {
+    unsigned_gen_var_5().test_properties(|n| {
+        let l = Natural::lucas(n);
+        assert!(l.is_valid());
+        assert_eq!(lucas_naive(n), l);
+        assert_eq!(
+            Natural::exact_from(&rug::Integer::lucas(u32::exact_from(n)).complete()),
+            l
+        );
+        // L_{n + 1} = F_n + F_{n + 2}
+        assert_eq!(
+            Natural::lucas(n + 1),
+            Natural::fibonacci(n) + Natural::fibonacci(n + 2)
+        );
+        // L_{2n} = L_n^2 - 2(-1)^n
+        let l_2n = Natural::lucas(n << 1);
+        if n.even() {
+            assert_eq!(l_2n + Natural::TWO, l.square());
+        } else {
+            assert_eq!(l_2n, l.square() + Natural::TWO);
+        }
+    });
+
+    unsigned_gen_var_5().test_properties(|n| {
+        if let Some(l) = Limb::checked_lucas(n) {
+            assert_eq!(Natural::lucas(n), l);
+        }
+    });
+}
@@ -0,0 +1,224 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{
+    LegendreSymbol, ModIsReduced, ModNeg, ModPrimePowerSqrt, ModSqrt, ModSquare, Pow,
+};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::unsigned_pair_gen_var_51;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use malachite_nz::test_util::generators::natural_pair_gen_var_16;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_sqrt() {
+    let test = |s, t, out| {
+        let n = Natural::from_str(s).unwrap();
+        let p = Natural::from_str(t).unwrap();
+
+        let result = n.clone().mod_sqrt(p.clone());
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.is_none_or(|n| n.is_valid()));
+
+        let result = n.clone().mod_sqrt(&p);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.is_none_or(|n| n.is_valid()));
+
+        let result = (&n).mod_sqrt(p.clone());
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.is_none_or(|n| n.is_valid()));
+
+        let result = (&n).mod_sqrt(&p);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.is_none_or(|n| n.is_valid()));
+    };
+    test("0", "2", "Some(0)");
+    test("1", "2", "Some(1)");
+    test("0", "7", "Some(0)");
+    test("2", "7", "Some(3)");
+    test("3", "7", "None");
+    test("10", "13", "Some(6)");
+    test("2", "17", "Some(6)");
+    test("3", "18446744069414584321", "Some(281474976579584)");
+    test("7", "18446744069414584321", "None");
+    // p = 3 mod 4
+    test(
+        "2",
+        "170141183460469231731687303715884105727",
+        "Some(18446744073709551616)",
+    );
+    test("3", "170141183460469231731687303715884105727", "None");
+    test(
+        "1000000000000000000000000000000",
+        "170141183460469231731687303715884105727",
+        "Some(1000000000000000)",
+    );
+    // p = 5 mod 8
+    test(
+        "3",
+        "57896044618658097711785492504343953926634992332820282019728792003956564819949",
+        "Some(15029839470433391022265175636939773287626296101036845499088079275986334742835)",
+    );
+    test(
+        "2",
+        "57896044618658097711785492504343953926634992332820282019728792003956564819949",
+        "None",
+    );
+    // p = 165 * 2^100 + 1, for which Cipolla's algorithm is used
+    test(
+        "2",
+        "209162349037657851246956028887041",
+        "Some(2708877323875654048997667164873)",
+    );
+    test(
+        "7",
+        "209162349037657851246956028887041",
+        "Some(59938732704164335071816963885447)",
+    );
+}
+
+#[test]
+fn mod_sqrt_fail() {
+    assert_panic!(Natural::ZERO.mod_sqrt(Natural::ZERO));
+    assert_panic!(Natural::from(7u32).mod_sqrt(Natural::from(7u32)));
+    assert_panic!(Natural::ZERO.mod_sqrt(&Natural::ZERO));
+    assert_panic!(Natural::from(7u32).mod_sqrt(&Natural::from(7u32)));
+    assert_panic!((&Natural::ZERO).mod_sqrt(Natural::ZERO));
+    assert_panic!((&Natural::from(7u32)).mod_sqrt(Natural::from(7u32)));
+    assert_panic!((&Natural::ZERO).mod_sqrt(&Natural::ZERO));
+    assert_panic!((&Natural::from(7u32)).mod_sqrt(&Natural::from(7u32)));
+}
+
+#[test]
+fn test_mod_prime_power_sqrt() {
+    let test = |s, t, k, out| {
+        let n = Natural::from_str(s).unwrap();
+        let p = Natural::from_str(t).unwrap();
+
+        let result = n.clone().mod_prime_power_sqrt(p.clone(), k);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.is_none_or(|n| n.is_valid()));
+
+        let result = n.clone().mod_prime_power_sqrt(&p, k);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.is_none_or(|n| n.is_valid()));
+
+        let result = (&n).mod_prime_power_sqrt(p.clone(), k);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.is_none_or(|n| n.is_valid()));
+
+        let result = (&n).mod_prime_power_sqrt(&p, k);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.is_none_or(|n| n.is_valid()));
+    };
+    test("2", "7", 1, "Some(3)");
+    test("2", "7", 2, "Some(10)");
+    test("36", "3", 4, "Some(6)");
+    test("18", "3", 3, "None");
+    test("17", "2", 5, "Some(7)");
+    test("3", "2", 3, "None");
+    test("7", "3", 50, "Some(306907132484664018333032)");
+    test("2", "3", 50, "None");
+    test("6", "5", 40, "Some(3435339781991853745885426766)");
+    test("2", "7", 30, "Some(9738858034492596758892959)");
+    test("1053", "3", 60, "Some(1656156296139233895318418191)");
+    test("17", "2", 100, "Some(217788382556221841343574235415)");
+    test("33", "2", 90, "Some(287498710076644086679509103)");
+    test("3", "2", 100, "None");
+    test("1125899906842624", "2", 100, "Some(33554432)");
+    test("38280596832649216", "2", 100, "None");
+}
+
+#[test]
+fn mod_prime_power_sqrt_fail() {
+    assert_panic!(Natural::ZERO.mod_prime_power_sqrt(Natural::TWO, 0));
+    assert_panic!(Natural::from(8u32).mod_prime_power_sqrt(Natural::TWO, 3));
+    assert_panic!(Natural::ZERO.mod_prime_power_sqrt(&Natural::TWO, 0));
+    assert_panic!(Natural::from(8u32).mod_prime_power_sqrt(&Natural::TWO, 3));
+    assert_panic!((&Natural::ZERO).mod_prime_power_sqrt(Natural::TWO, 0));
+    assert_panic!((&Natural::from(8u32)).mod_prime_power_sqrt(Natural::TWO, 3));
+    assert_panic!((&Natural::ZERO).mod_prime_power_sqrt(&Natural::TWO, 0));
+    assert_panic!((&Natural::from(8u32)).mod_prime_power_sqrt(&Natural::TWO, 3));
+}
+
+#[test]
+fn mod_sqrt_properties() {
+    natural_pair_gen_var_16().test_properties(|(n, p)| {
+        assert!(n.mod_is_reduced(&p));
+        let result_val_val = n.clone().mod_sqrt(p.clone());
+        let result_val_ref = n.clone().mod_sqrt(&p);
+        let result_ref_val = (&n).mod_sqrt(p.clone());
+        let result = (&n).mod_sqrt(&p);
+        assert!(result.as_ref().is_none_or(Natural::is_valid));
+        assert!(result_val_val.as_ref().is_none_or(Natural::is_valid));
+        assert!(result_val_ref.as_ref().is_none_or(Natural::is_valid));
+        assert!(result_ref_val.as_ref().is_none_or(Natural::is_valid));
+        assert_eq!(result_val_val, result);
+        assert_eq!(result_val_ref, result);
+        assert_eq!(result_ref_val, result);
+
+        assert_eq!((&n).mod_prime_power_sqrt(&p, 1), result);
+        if let Some(root) = result {
+            assert!(root.mod_is_reduced(&p));
+            assert_eq!((&root).mod_square(&p), n);
+            let root_neg = (&root).mod_neg(&p);
+            assert!(root <= root_neg);
+            assert_eq!(root_neg.mod_square(&p), n);
+        } else {
+            assert!(p > 2u32);
+            assert_eq!((&n).legendre_symbol(&p), -1);
+        }
+        let square = (&n).mod_square(&p);
+        let root = square.mod_sqrt(&p).unwrap();
+        assert!(root == n || root == n.mod_neg(&p));
+    });
+
+    unsigned_pair_gen_var_51::<Limb>().test_properties(|(n, p)| {
+        assert_eq!(
+            Natural::from(n).mod_sqrt(Natural::from(p)),
+            n.mod_sqrt(p).map(Natural::from)
+        );
+    });
+}
+
+#[test]
+fn mod_prime_power_sqrt_properties() {
+    natural_pair_gen_var_16().test_properties(|(n, p)| {
+        for k in 1..4 {
+            let pk = (&p).pow(k);
+            let result = (&n).mod_prime_power_sqrt(&p, k);
+            if let Some(root) = &result {
+                assert!(root.is_valid());
+                assert!(root.mod_is_reduced(&pk));
+                assert_eq!(root.mod_square(&pk), n);
+                assert!(*root <= root.mod_neg(&pk));
+            }
+            let square = (&n).mod_square(&pk);
+            let root = square.mod_prime_power_sqrt(&p, k).unwrap();
+            assert!(root <= n);
+        }
+    });
+
+    unsigned_pair_gen_var_51::<Limb>().test_properties(|(n, p)| {
+        let n = Natural::from(n);
+        let p = Natural::from(p);
+        assert_eq!(
+            Natural::ZERO.mod_prime_power_sqrt(&p, 3),
+            Some(Natural::ZERO)
+        );
+        assert_eq!(Natural::ONE.mod_prime_power_sqrt(&p, 3), Some(Natural::ONE));
+        // n^2 p^2 mod p^4 has the root n p
+        let p_2 = (&p).pow(2);
+        let x = (&n).mod_square(&p_2) * &p_2;
+        let root = x.mod_prime_power_sqrt(&p, 4).unwrap();
+        assert!(root <= n * p);
+    });
+}
@@ -0,0 +1,99 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use itertools::Itertools;
+use malachite_base::num::conversion::traits::PowerOf2Digits;
+use malachite_base::slices::slice_test_zero;
+use malachite_base::test_util::generators::unsigned_vec_gen;
+use malachite_nz::natural::Natural;
+
+#[test]
+fn test_from_bytes_le() {
+    let test = |xs: &[u8], out| {
+        let x = Natural::from_bytes_le(xs);
+        assert_eq!(x.to_string(), out);
+        assert!(x.is_valid());
+    };
+    test(&[], "0");
+    test(&[0], "0");
+    test(&[0, 0, 0], "0");
+    test(&[123], "123");
+    test(&[123, 0], "123");
+    test(&[123, 0, 0, 0, 0, 0, 0, 0, 0, 0], "123");
+    test(&[0, 16, 165, 212, 232], "1000000000000");
+    test(&[0, 16, 165, 212, 232, 0, 0, 0, 0], "1000000000000");
+    test(&[255, 255, 255, 255], "4294967295");
+    test(&[0, 0, 0, 0, 1], "4294967296");
+    test(&[255; 8], "18446744073709551615");
+    test(&[0, 0, 0, 0, 0, 0, 0, 0, 1], "18446744073709551616");
+    test(
+        &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17],
+        "5806146055028818284759215385528282317313",
+    );
+}
+
+#[test]
+fn test_from_bytes_be() {
+    let test = |xs: &[u8], out| {
+        let x = Natural::from_bytes_be(xs);
+        assert_eq!(x.to_string(), out);
+        assert!(x.is_valid());
+    };
+    test(&[], "0");
+    test(&[0], "0");
+    test(&[0, 0, 0], "0");
+    test(&[123], "123");
+    test(&[0, 123], "123");
+    test(&[0, 0, 0, 0, 0, 0, 0, 0, 0, 123], "123");
+    test(&[232, 212, 165, 16, 0], "1000000000000");
+    test(&[0, 0, 0, 0, 232, 212, 165, 16, 0], "1000000000000");
+    test(&[255, 255, 255, 255], "4294967295");
+    test(&[1, 0, 0, 0, 0], "4294967296");
+    test(&[255; 8], "18446744073709551615");
+    test(&[1, 0, 0, 0, 0, 0, 0, 0, 0], "18446744073709551616");
+    test(
+        &[17, 16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1],
+        "5806146055028818284759215385528282317313",
+    );
+}
+
+#[test]
+fn from_bytes_le_properties() {
+    unsigned_vec_gen::<u8>().test_properties(|xs| {
+        let x = Natural::from_bytes_le(&xs);
+        assert!(x.is_valid());
+        assert_eq!(
+            Natural::from_power_of_2_digits_asc(8, xs.iter().copied()).unwrap(),
+            x
+        );
+        assert_eq!(
+            Natural::from_bytes_be(&xs.iter().copied().rev().collect_vec()),
+            x
+        );
+        if xs.last().is_some_and(|&b| b != 0) {
+            assert_eq!(x.to_bytes_le(), xs);
+        }
+        assert_eq!(slice_test_zero(&xs), x == 0);
+    });
+}
+
+#[test]
+fn from_bytes_be_properties() {
+    unsigned_vec_gen::<u8>().test_properties(|xs| {
+        let x = Natural::from_bytes_be(&xs);
+        assert!(x.is_valid());
+        assert_eq!(
+            Natural::from_power_of_2_digits_desc(8, xs.iter().copied()).unwrap(),
+            x
+        );
+        if xs.first().is_some_and(|&b| b != 0) {
+            assert_eq!(x.to_bytes_be(), xs);
+        }
+        assert_eq!(slice_test_zero(&xs), x == 0);
+    });
+}
@@ -10,7 +10,10 @@ use malachite_base::strings::string_is_subset;
 use malachite_base::test_util::generators::{string_gen, string_gen_var_8};
 use malachite_nz::integer::Integer;
 use malachite_nz::natural::Natural;
+use malachite_nz::natural::conversion::serde::{CompactNatural, CompactNaturalRef};
 use malachite_nz::test_util::generators::natural_gen;
+use serde::Deserialize;
+use serde::de::value::{self, BytesDeserializer};
 use std::str::FromStr;
 
 #[test]
@@ -59,3 +62,67 @@ fn serde_properties() {
         assert_eq!(n, i);
     });
 }
+
+#[test]
+fn test_compact_serde() {
+    let test = |n, out| {
+        let x = Natural::from_str(n).unwrap();
+        assert_eq!(serde_json::to_string(&CompactNaturalRef(&x)).unwrap(), out);
+        assert_eq!(
+            serde_json::to_string(&CompactNatural(x.clone())).unwrap(),
+            out
+        );
+        assert_eq!(
+            serde_json::from_str::<CompactNatural>(out).unwrap(),
+            CompactNatural(x)
+        );
+    };
+    test("0", "[]");
+    test("100", "[100]");
+    test("1000", "[232,3]");
+    test("4294967295", "[255,255,255,255]");
+    test("4294967296", "[0,0,0,0,1]");
+    test("18446744073709551615", "[255,255,255,255,255,255,255,255]");
+    test("18446744073709551616", "[0,0,0,0,0,0,0,0,1]");
+    test(
+        "1000000000000000000000000",
+        "[0,0,0,161,237,204,206,27,194,211]",
+    );
+}
+
+#[test]
+fn test_compact_serde_fail() {
+    let test = |s| {
+        assert!(serde_json::from_str::<CompactNatural>(s).is_err());
+    };
+    test("[0]");
+    test("[1,0]");
+    test("[256]");
+    test("[-1]");
+    test("1");
+    test("null");
+}
+
+#[test]
+fn compact_serde_properties() {
+    natural_gen().test_properties(|x| {
+        let s = serde_json::to_string(&CompactNaturalRef(&x)).unwrap();
+        assert_eq!(
+            serde_json::from_str::<CompactNatural>(&s).unwrap(),
+            CompactNatural(x.clone())
+        );
+        assert!(string_is_subset(&s, "[],0123456789"));
+
+        // binary formats pass the bytes directly
+        let bytes: Vec<u8> = serde_json::from_str(&s).unwrap();
+        let deserializer = BytesDeserializer::<value::Error>::new(&bytes);
+        assert_eq!(
+            CompactNatural::deserialize(deserializer).unwrap(),
+            CompactNatural(x)
+        );
+    });
+
+    string_gen().test_properties(|s| {
+        let _n: Result<CompactNatural, _> = serde_json::from_str(&s);
+    });
+}
@@ -0,0 +1,80 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::conversion::traits::PowerOf2Digits;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen;
+use num::BigUint;
+use std::str::FromStr;
+
+#[test]
+fn test_to_bytes_le() {
+    let test = |n, out: &[u8]| {
+        let x = Natural::from_str(n).unwrap();
+        assert_eq!(x.to_bytes_le(), out);
+    };
+    test("0", &[]);
+    test("123", &[123]);
+    test("1000000000000", &[0, 16, 165, 212, 232]);
+    test("4294967295", &[255, 255, 255, 255]);
+    test("4294967296", &[0, 0, 0, 0, 1]);
+    test("18446744073709551615", &[255; 8]);
+    test("18446744073709551616", &[0, 0, 0, 0, 0, 0, 0, 0, 1]);
+    test(
+        "5806146055028818284759215385528282317313",
+        &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17],
+    );
+}
+
+#[test]
+fn test_to_bytes_be() {
+    let test = |n, out: &[u8]| {
+        let x = Natural::from_str(n).unwrap();
+        assert_eq!(x.to_bytes_be(), out);
+    };
+    test("0", &[]);
+    test("123", &[123]);
+    test("1000000000000", &[232, 212, 165, 16, 0]);
+    test("4294967295", &[255, 255, 255, 255]);
+    test("4294967296", &[1, 0, 0, 0, 0]);
+    test("18446744073709551615", &[255; 8]);
+    test("18446744073709551616", &[1, 0, 0, 0, 0, 0, 0, 0, 0]);
+    test(
+        "5806146055028818284759215385528282317313",
+        &[17, 16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1],
+    );
+}
+
+#[test]
+fn to_bytes_le_properties() {
+    natural_gen().test_properties(|x| {
+        let xs = x.to_bytes_le();
+        assert_eq!(xs, PowerOf2Digits::<u8>::to_power_of_2_digits_asc(&x, 8));
+        assert_eq!(Natural::from_bytes_le(&xs), x);
+        if x != 0 {
+            assert_eq!(BigUint::from(&x).to_bytes_le(), xs);
+            assert_ne!(*xs.last().unwrap(), 0);
+        }
+        let mut ys = x.to_bytes_be();
+        ys.reverse();
+        assert_eq!(ys, xs);
+    });
+}
+
+#[test]
+fn to_bytes_be_properties() {
+    natural_gen().test_properties(|x| {
+        let xs = x.to_bytes_be();
+        assert_eq!(xs, PowerOf2Digits::<u8>::to_power_of_2_digits_desc(&x, 8));
+        assert_eq!(Natural::from_bytes_be(&xs), x);
+        if x != 0 {
+            assert_eq!(BigUint::from(&x).to_bytes_be(), xs);
+            assert_ne!(xs[0], 0);
+        }
+    });
+}
@@ -0,0 +1,68 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{CoprimeWith, DivisibleBy, Lcm};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::factorization::traits::{CarmichaelLambda, EulerTotient};
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen};
+use malachite_nz::natural::Natural;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_carmichael_lambda() {
+    let test = |s, out| {
+        let n = Natural::from_str(s).unwrap();
+        let lambda = n.carmichael_lambda();
+        assert!(lambda.is_valid());
+        assert_eq!(lambda.to_string(), out);
+    };
+    test("1", "1");
+    test("2", "1");
+    test("8", "2");
+    test("36", "6");
+    test("1000000007", "1000000006");
+    test("18446744073709551615", "17153064960");
+    test("18446744073709551616", "4611686018427387904");
+    test("18446744073709551617", "72057331223781120");
+    test("1089261790808475313373184", "90771815900706276114432");
+    test(
+        "1000000000000000000000000000000",
+        "50000000000000000000000000000",
+    );
+    test("32589158477190044730", "16576560");
+    // 2^127 - 1
+    test(
+        "170141183460469231731687303715884105727",
+        "170141183460469231731687303715884105726",
+    );
+}
+
+#[test]
+fn carmichael_lambda_fail() {
+    assert_panic!(Natural::ZERO.carmichael_lambda());
+}
+
+#[test]
+fn carmichael_lambda_properties() {
+    unsigned_gen_var_1::<u64>().test_properties(|u| {
+        assert_eq!(Natural::from(u).carmichael_lambda(), u.carmichael_lambda());
+    });
+
+    unsigned_pair_gen::<u64, u16>().test_properties(|(m, n)| {
+        if m != 0 && n != 0 && m.coprime_with(u64::from(n)) {
+            let m = Natural::from(m);
+            let n = Natural::from(n);
+            let mn = &m * &n;
+            let lambda = mn.carmichael_lambda();
+            assert!(lambda.is_valid());
+            assert!(mn.euler_totient().divisible_by(&lambda));
+            assert_eq!(lambda, m.carmichael_lambda().lcm(n.carmichael_lambda()));
+        }
+    });
+}
@@ -0,0 +1,60 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::CoprimeWith;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::factorization::traits::DivisorCount;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen};
+use malachite_nz::natural::Natural;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_divisor_count() {
+    let test = |s, out| {
+        let n = Natural::from_str(s).unwrap();
+        let d = n.divisor_count();
+        assert!(d.is_valid());
+        assert_eq!(d.to_string(), out);
+    };
+    test("1", "1");
+    test("2", "2");
+    test("36", "9");
+    test("1000000007", "2");
+    test("18446744073709551615", "128");
+    test("18446744073709551616", "65");
+    test("18446744073709551617", "4");
+    test("1089261790808475313373184", "715");
+    test("1000000000000000000000000000000", "961");
+    test("32589158477190044730", "65536");
+    // 2^127 - 1
+    test("170141183460469231731687303715884105727", "2");
+}
+
+#[test]
+fn divisor_count_fail() {
+    assert_panic!(Natural::ZERO.divisor_count());
+}
+
+#[test]
+fn divisor_count_properties() {
+    unsigned_gen_var_1::<u64>().test_properties(|u| {
+        assert_eq!(Natural::from(u).divisor_count(), u.divisor_count());
+    });
+
+    unsigned_pair_gen::<u64, u16>().test_properties(|(m, n)| {
+        // d is multiplicative
+        if m != 0 && n != 0 && m.coprime_with(u64::from(n)) {
+            let m = Natural::from(m);
+            let n = Natural::from(n);
+            let d = (&m * &n).divisor_count();
+            assert!(d.is_valid());
+            assert_eq!(d, m.divisor_count() * n.divisor_count());
+        }
+    });
+}
@@ -0,0 +1,72 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::CoprimeWith;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::factorization::traits::DivisorSum;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen};
+use malachite_nz::natural::Natural;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_divisor_sum() {
+    let test = |s, out| {
+        let n = Natural::from_str(s).unwrap();
+        let sigma = n.divisor_sum();
+        assert!(sigma.is_valid());
+        assert_eq!(sigma.to_string(), out);
+    };
+    test("1", "1");
+    test("2", "3");
+    test("36", "91");
+    test("1000000007", "1000000008");
+    test("18446744073709551615", "31421980989189888768");
+    test("18446744073709551616", "36893488147419103231");
+    test("18446744073709551617", "18446811354131136516");
+    test("1089261790808475313373184", "3267766925681352230479363");
+    test(
+        "1000000000000000000000000000000",
+        "2499999998835846781730114984557",
+    );
+    test("32589158477190044730", "146095655504943513600");
+    // 2^127 - 1
+    test(
+        "170141183460469231731687303715884105727",
+        "170141183460469231731687303715884105728",
+    );
+}
+
+#[test]
+fn divisor_sum_fail() {
+    assert_panic!(Natural::ZERO.divisor_sum());
+}
+
+#[test]
+fn divisor_sum_properties() {
+    unsigned_gen_var_1::<u64>().test_properties(|u| {
+        let sigma = Natural::from(u).divisor_sum();
+        assert!(sigma >= u);
+        if let Some(sigma_alt) = u.divisor_sum() {
+            assert_eq!(sigma, sigma_alt);
+        } else {
+            assert!(sigma > u64::MAX);
+        }
+    });
+
+    unsigned_pair_gen::<u64, u16>().test_properties(|(m, n)| {
+        // sigma is multiplicative
+        if m != 0 && n != 0 && m.coprime_with(u64::from(n)) {
+            let m = Natural::from(m);
+            let n = Natural::from(n);
+            let sigma = (&m * &n).divisor_sum();
+            assert!(sigma.is_valid());
+            assert_eq!(sigma, m.divisor_sum() * n.divisor_sum());
+        }
+    });
+}
@@ -0,0 +1,81 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use itertools::Itertools;
+use malachite_base::num::arithmetic::traits::DivisibleBy;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::factorization::traits::{DivisorCount, DivisorSum, Divisors};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen};
+use malachite_nz::natural::Natural;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_divisors() {
+    let test = |s, out| {
+        let divisors = Natural::from_str(s).unwrap().divisors().collect_vec();
+        assert!(divisors.iter().all(Natural::is_valid));
+        assert_eq!(divisors.to_debug_string(), out);
+    };
+    test("1", "[1]");
+    test("2", "[1, 2]");
+    test("36", "[1, 2, 3, 4, 6, 9, 12, 18, 36]");
+    test("1000000007", "[1, 1000000007]");
+    test(
+        "18446744073709551617",
+        "[1, 274177, 67280421310721, 18446744073709551617]",
+    );
+    // 2^128 - 2
+    test(
+        "340282366920938463463374607431768211454",
+        "[1, 2, 170141183460469231731687303715884105727, \
+        340282366920938463463374607431768211454]",
+    );
+    // 2^127 - 1
+    test(
+        "170141183460469231731687303715884105727",
+        "[1, 170141183460469231731687303715884105727]",
+    );
+}
+
+#[test]
+fn divisors_fail() {
+    assert_panic!(Natural::ZERO.divisors());
+}
+
+#[test]
+fn divisors_properties() {
+    unsigned_gen_var_1::<u64>().test_properties(|u| {
+        assert_eq!(
+            Natural::from(u).divisors().collect_vec(),
+            u.divisors().map(Natural::from).collect_vec()
+        );
+    });
+
+    unsigned_pair_gen::<u64, u16>().test_properties(|(m, n)| {
+        if m != 0 && n != 0 {
+            let n = Natural::from(m) * Natural::from(n);
+            let divisors = n.divisors().collect_vec();
+            assert_eq!(divisors[0], 1u32);
+            assert_eq!(*divisors.last().unwrap(), n);
+            assert!(divisors.iter().tuple_windows().all(|(a, b)| a < b));
+            for d in &divisors {
+                assert!(d.is_valid());
+                assert!((&n).divisible_by(d));
+            }
+            assert_eq!(Natural::from(divisors.len()), n.divisor_count());
+            assert_eq!(
+                divisors.into_iter().fold(Natural::ZERO, |sum, d| sum + d),
+                n.divisor_sum()
+            );
+        }
+    });
+
+    assert_eq!(Natural::ONE.divisors().count(), 1);
+}
@@ -0,0 +1,65 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::CoprimeWith;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::factorization::traits::EulerTotient;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen};
+use malachite_nz::natural::Natural;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_euler_totient() {
+    let test = |s, out| {
+        let n = Natural::from_str(s).unwrap();
+        let phi = n.euler_totient();
+        assert!(phi.is_valid());
+        assert_eq!(phi.to_string(), out);
+    };
+    test("1", "1");
+    test("2", "1");
+    test("36", "12");
+    test("1000000007", "1000000006");
+    test("18446744073709551615", "9208981628670443520");
+    test("18446744073709551617", "18446676793287966720");
+    test("1089261790808475313373184", "363087263602825104457728");
+    test(
+        "1000000000000000000000000000000",
+        "400000000000000000000000000000",
+    );
+    test("32589158477190044730", "4434961926979584000");
+    // 2^127 - 1
+    test(
+        "170141183460469231731687303715884105727",
+        "170141183460469231731687303715884105726",
+    );
+}
+
+#[test]
+fn euler_totient_fail() {
+    assert_panic!(Natural::ZERO.euler_totient());
+}
+
+#[test]
+fn euler_totient_properties() {
+    unsigned_gen_var_1::<u64>().test_properties(|u| {
+        assert_eq!(Natural::from(u).euler_totient(), u.euler_totient());
+    });
+
+    unsigned_pair_gen::<u64, u16>().test_properties(|(m, n)| {
+        // phi is multiplicative
+        if m != 0 && n != 0 && m.coprime_with(u64::from(n)) {
+            let m = Natural::from(m);
+            let n = Natural::from(n);
+            let phi = (&m * &n).euler_totient();
+            assert!(phi.is_valid());
+            assert_eq!(phi, m.euler_totient() * n.euler_totient());
+        }
+    });
+}
@@ -0,0 +1,154 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use itertools::Itertools;
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::factorization::traits::{Factor, IsPrime};
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_vec_gen_var_1};
+use malachite_nz::natural::Natural;
+use std::str::FromStr;
+
+fn check_factorization(n: &Natural, factors: &[(Natural, u64)]) {
+    let mut product = Natural::ONE;
+    for (p, e) in factors {
+        assert!(p.is_prime());
+        assert_ne!(*e, 0);
+        product *= p.pow(*e);
+    }
+    assert_eq!(product, *n);
+    assert!(factors.windows(2).all(|w| w[0].0 < w[1].0));
+}
+
+#[test]
+fn test_factor() {
+    let test = |s: &str, out: &[(&str, u64)]| {
+        let n = Natural::from_str(s).unwrap();
+        let factors = n.factor();
+        let factors_alt = (&n).factor();
+        assert_eq!(factors, factors_alt);
+        assert_eq!(
+            factors
+                .iter()
+                .map(|(p, e)| (p.to_string(), *e))
+                .collect_vec(),
+            out.iter().map(|&(p, e)| (p.to_string(), e)).collect_vec()
+        );
+        check_factorization(&n, &factors);
+    };
+    test("1", &[]);
+    test("2", &[("2", 1)]);
+    test("360", &[("2", 3), ("3", 2), ("5", 1)]);
+    test("4294967297", &[("641", 1), ("6700417", 1)]);
+    // 2^64 - 1
+    test(
+        "18446744073709551615",
+        &[("3", 1), ("5", 1), ("17", 1), ("257", 1), ("641", 1), ("65537", 1), ("6700417", 1)],
+    );
+    // 2^64 + 1
+    test(
+        "18446744073709551617",
+        &[("274177", 1), ("67280421310721", 1)],
+    );
+    // 2^64
+    test("18446744073709551616", &[("2", 64)]);
+    // 2^127 - 1
+    test(
+        "170141183460469231731687303715884105727",
+        &[("170141183460469231731687303715884105727", 1)],
+    );
+    // 2^128 - 1
+    test(
+        "340282366920938463463374607431768211455",
+        &[
+            ("3", 1),
+            ("5", 1),
+            ("17", 1),
+            ("257", 1),
+            ("641", 1),
+            ("65537", 1),
+            ("274177", 1),
+            ("6700417", 1),
+            ("67280421310721", 1),
+        ],
+    );
+    // 2^128 + 1
+    test(
+        "340282366920938463463374607431768211457",
+        &[("59649589127497217", 1), ("5704689200685129054721", 1)],
+    );
+    // 2^96 + 1
+    test(
+        "79228162514264337593543950337",
+        &[("641", 1), ("6700417", 1), ("18446744069414584321", 1)],
+    );
+    // 10^30 + 1
+    test(
+        "1000000000000000000000000000001",
+        &[
+            ("61", 1),
+            ("101", 1),
+            ("3541", 1),
+            ("9901", 1),
+            ("27961", 1),
+            ("4188901", 1),
+            ("39526741", 1),
+        ],
+    );
+    // product of two 41- and 42-bit primes
+    test(
+        "2417851639291930512195989",
+        &[("1099511627791", 1), ("2199023255579", 1)],
+    );
+    // p * q^2, with p > 2^70 and q > 2^20
+    test(
+        "1298091545852105891899113981609161",
+        &[("1048583", 2), ("1180591620717411303449", 1)],
+    );
+    // p^3 * q^2, with p > 10^12 and q > 10^15
+    test(
+        "1000000000117074000004571659369059656822173004395852747000081207711",
+        &[("1000000000039", 3), ("1000000000000037", 2)],
+    );
+}
+
+#[test]
+#[should_panic]
+fn factor_fail() {
+    Natural::ZERO.factor();
+}
+
+#[test]
+fn factor_properties() {
+    unsigned_gen_var_1::<u64>().test_properties(|u| {
+        let factors = Natural::from(u).factor();
+        assert_eq!(
+            factors,
+            u.factor()
+                .into_iter()
+                .map(|(p, e)| (Natural::from(p), u64::from(e)))
+                .collect_vec()
+        );
+    });
+
+    unsigned_vec_gen_var_1::<u16>().test_properties(|xs| {
+        let n = xs
+            .iter()
+            .filter(|&&x| x != 0)
+            .fold(Natural::ONE, |acc, &x| acc * Natural::from(x));
+        let factors = n.factor();
+        check_factorization(&n, &factors);
+        for &x in &xs {
+            if x > 1 {
+                for (p, _) in x.factor() {
+                    assert!(factors.iter().any(|(q, _)| *q == p));
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,126 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+use malachite_base::num::arithmetic::traits::{CheckedRoot, Pow};
+use malachite_base::num::factorization::traits::{ExpressAsPower, IsPerfectPower};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::unsigned_gen;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{natural_gen, natural_unsigned_pair_gen_var_7};
+use std::str::FromStr;
+
+#[test]
+fn test_is_perfect_power() {
+    let test = |s, out| {
+        let n = Natural::from_str(s).unwrap();
+        let result = n.is_perfect_power();
+        assert_eq!(result.to_debug_string(), out);
+        assert_eq!(
+            n.express_as_power(),
+            result.clone().unwrap_or_else(|| (n.clone(), 1))
+        );
+        assert_eq!(rug::Integer::from(&n).is_perfect_power(), result.is_some());
+    };
+    test("0", "Some((0, 2))");
+    test("1", "Some((1, 2))");
+    test("2", "None");
+    test("4", "Some((2, 2))");
+    test("8", "Some((2, 3))");
+    test("10", "None");
+    test("64", "Some((2, 6))");
+    test("1728", "Some((12, 3))");
+    test("18446744073709551615", "None");
+    // 2^64
+    test("18446744073709551616", "Some((2, 64))");
+    // (2^32 + 1)^2
+    test("18446744082299486209", "Some((4294967297, 2))");
+    // 2^64 * 3
+    test("55340232221128654848", "None");
+    // 6^30
+    test("221073919720733357899776", "Some((6, 30))");
+    // 3^41
+    test("36472996377170786403", "Some((3, 41))");
+    // 3^41 + 1
+    test("36472996377170786404", "None");
+    // 10^40
+    test(
+        "10000000000000000000000000000000000000000",
+        "Some((10, 40))",
+    );
+    // (10^20 + 1)^3
+    test(
+        "1000000000000000000030000000000000000000300000000000000000001",
+        "Some((100000000000000000001, 3))",
+    );
+    // (10^20 + 1)^3 + 1
+    test(
+        "1000000000000000000030000000000000000000300000000000000000002",
+        "None",
+    );
+    // 7^60
+    test(
+        "508021860739623365322188197652216501772434524836001",
+        "Some((7, 60))",
+    );
+    // p^2, where p = 2^100 + 277 is prime
+    test(
+        "1606938044258990275541962093043441035048642082211966411156409",
+        "Some((1267650600228229401496703205653, 2))",
+    );
+
+    assert_eq!(
+        Natural::from(15u32)
+            .pow(1001)
+            .is_perfect_power()
+            .to_debug_string(),
+        "Some((15, 1001))"
+    );
+    assert_eq!(
+        (Natural::from(15u32).pow(1001) + Natural::from(2u32)).is_perfect_power(),
+        None
+    );
+}
+
+#[test]
+fn is_perfect_power_properties() {
+    natural_gen().test_properties(|n| {
+        let result = n.is_perfect_power();
+        assert_eq!(
+            n.express_as_power(),
+            result.clone().unwrap_or_else(|| (n.clone(), 1))
+        );
+        assert_eq!(rug::Integer::from(&n).is_perfect_power(), result.is_some());
+        if let Some((base, exp)) = result {
+            assert!(exp > 1);
+            assert_eq!((&base).pow(exp), n);
+            if base > 1u32 {
+                assert_eq!(base.is_perfect_power(), None);
+            }
+        }
+    });
+
+    natural_unsigned_pair_gen_var_7::<u64>().test_properties(|(x, exp)| {
+        let n = (&x).pow(exp);
+        let (base, n_exp) = n.is_perfect_power().unwrap_or((n.clone(), 1));
+        if x > 1u32 {
+            assert_eq!(n_exp % exp, 0);
+            assert_eq!((&base).pow(n_exp / exp), x);
+        }
+        if exp > 1 {
+            assert!(n.is_perfect_power().is_some());
+            assert_eq!((&n).checked_root(exp), Some(x));
+        }
+    });
+
+    unsigned_gen::<u64>().test_properties(|u| {
+        assert_eq!(
+            Natural::from(u).is_perfect_power(),
+            u.is_perfect_power()
+                .map(|(base, exp)| (Natural::from(base), u64::from(exp)))
+        );
+    });
+}
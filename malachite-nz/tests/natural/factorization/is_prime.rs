@@ -0,0 +1,89 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::test_util::generators::{unsigned_gen, unsigned_pair_gen_var_27};
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use malachite_nz::test_util::generators::{natural_gen, natural_pair_gen_var_1};
+use std::str::FromStr;
+
+fn test_helper(s: &str, out: bool) {
+    let n = Natural::from_str(s).unwrap();
+    assert_eq!(n.is_prime(), out);
+    assert_eq!(n.probably_prime(0), out);
+    assert_eq!(n.probably_prime(10), out);
+    assert_eq!(
+        rug::Integer::from(&n).is_probably_prime(30) != rug::integer::IsPrime::No,
+        out
+    );
+}
+
+#[test]
+fn test_is_prime() {
+    test_helper("0", false);
+    test_helper("1", false);
+    test_helper("2", true);
+    test_helper("3", true);
+    test_helper("4", false);
+    test_helper("5", true);
+    test_helper("6", false);
+    test_helper("97", true);
+    test_helper("4294967291", true);
+    test_helper("4294967297", false);
+    test_helper("18446744073709551557", true);
+    test_helper("18446744073709551615", false);
+    // 2^64 + 1
+    test_helper("18446744073709551617", false);
+    test_helper("18446744073709551629", true);
+    // square of a prime
+    test_helper("340282366920938463942989953348216553641", false);
+    // 2^89 - 1
+    test_helper("618970019642690137449562111", true);
+    // 2^127 - 1
+    test_helper("170141183460469231731687303715884105727", true);
+    // 2^128 + 1
+    test_helper("340282366920938463463374607431768211457", false);
+    // strong pseudoprimes to bases 2 through 37
+    test_helper("318665857834031151167461", false);
+    test_helper("3317044064679887385961981", false);
+    // Carmichael number
+    test_helper("1543267864443420616877677640751301", false);
+
+    assert!((Natural::from(2u32).pow(521) - Natural::from(1u32)).is_prime());
+    assert!(!(Natural::from(2u32).pow(523) - Natural::from(1u32)).is_prime());
+}
+
+#[test]
+fn is_prime_properties() {
+    natural_gen().test_properties(|n| {
+        let is_prime = n.is_prime();
+        assert_eq!(n.probably_prime(5), is_prime);
+        assert_eq!(
+            rug::Integer::from(&n).is_probably_prime(30) != rug::integer::IsPrime::No,
+            is_prime
+        );
+    });
+
+    natural_pair_gen_var_1().test_properties(|(x, y)| {
+        assert!(!(x * y).is_prime());
+    });
+
+    unsigned_gen::<Limb>().test_properties(|u| {
+        assert_eq!(Natural::from(u).is_prime(), u.is_prime());
+    });
+
+    unsigned_pair_gen_var_27::<Limb>().test_properties(|(u, v)| {
+        let n = Natural::from(u) * Natural::from(v);
+        assert_eq!(
+            n.is_prime(),
+            u == 1 && v.is_prime() || v == 1 && u.is_prime()
+        );
+    });
+}
@@ -0,0 +1,126 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::ModPow;
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::factorization::traits::{ModLog, MultiplicativeOrder};
+use malachite_base::test_util::generators::unsigned_triple_gen_var_25;
+use malachite_nz::natural::Natural;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_log() {
+    let test = |r, s, t, out: Option<&str>| {
+        let x = Natural::from_str(r).unwrap();
+        let g = Natural::from_str(s).unwrap();
+        let m = Natural::from_str(t).unwrap();
+        let out = out.map(|out| Natural::from_str(out).unwrap());
+
+        assert_eq!(x.clone().mod_log(g.clone(), m.clone()), out);
+        assert_eq!(x.clone().mod_log(g.clone(), &m), out);
+        assert_eq!(x.clone().mod_log(&g, m.clone()), out);
+        assert_eq!(x.clone().mod_log(&g, &m), out);
+        assert_eq!((&x).mod_log(g.clone(), m.clone()), out);
+        assert_eq!((&x).mod_log(g.clone(), &m), out);
+        assert_eq!((&x).mod_log(&g, m.clone()), out);
+        assert_eq!((&x).mod_log(&g, &m), out);
+    };
+    test("0", "0", "1", Some("0"));
+    test("1", "3", "7", Some("0"));
+    test("6", "3", "7", Some("3"));
+    test("3", "2", "7", None);
+    test("0", "2", "8", Some("3"));
+    test("12345", "5", "1000000007", Some("775972150"));
+    test(
+        "10",
+        "3",
+        "18446744073709551557",
+        Some("4388222717587606556"),
+    );
+    // 2^100
+    test(
+        "191790081699546223634423999221",
+        "5",
+        "1267650600228229401496703205376",
+        Some("123456789"),
+    );
+    test("3", "2", "1267650600228229401496703205376", None);
+    test(
+        "933895331391691459636928060001",
+        "7",
+        "1000000000000000000000000000000",
+        Some("100"),
+    );
+    test("100000", "10", "1000000000000000000000000000000", Some("5"));
+    test("0", "10", "1000000000000000000000000000000", Some("30"));
+    test("1000", "100", "1000000000000000000000000000000", None);
+    // 2^64 * 3^40
+    test(
+        "135473247693758910535066684037296816128",
+        "6",
+        "224269343257001716702690972139746492416",
+        Some("50"),
+    );
+    // 2^127 - 1
+    test(
+        "12025050231696925086731743046088503371",
+        "3",
+        "170141183460469231731687303715884105727",
+        Some("100000000000000000000"),
+    );
+    // The order of 43 has a prime factor too large for the baby-step giant-step algorithm's table
+    test(
+        "123456789123456789",
+        "43",
+        "170141183460469231731687303715884105727",
+        Some("12039558893631102814644227172925711536"),
+    );
+    test("3", "9", "170141183460469231731687303715884105727", None);
+}
+
+#[test]
+fn mod_log_fail() {
+    assert_panic!(Natural::ZERO.mod_log(Natural::ZERO, Natural::ZERO));
+    assert_panic!(Natural::TWO.mod_log(Natural::ONE, Natural::TWO));
+    assert_panic!(Natural::ONE.mod_log(Natural::TWO, Natural::TWO));
+    assert_panic!(Natural::TWO.mod_log(&Natural::ONE, &Natural::TWO));
+    assert_panic!((&Natural::ONE).mod_log(Natural::TWO, &Natural::TWO));
+    assert_panic!((&Natural::ONE).mod_log(&Natural::TWO, Natural::TWO));
+}
+
+#[test]
+fn mod_log_properties() {
+    unsigned_triple_gen_var_25::<u32>().test_properties(|(x, g, m)| {
+        let log = x.mod_log(g, m);
+        let x = Natural::from(x);
+        let g = Natural::from(g);
+        let m = Natural::from(m);
+        let log_alt = (&x).mod_log(&g, &m);
+        assert_eq!(log_alt, log.map(Natural::from));
+        assert_eq!((&x).mod_log(&g, m.clone()), log_alt);
+        assert_eq!((&x).mod_log(g.clone(), &m), log_alt);
+        assert_eq!((&x).mod_log(g.clone(), m.clone()), log_alt);
+        assert_eq!(x.clone().mod_log(&g, &m), log_alt);
+        assert_eq!(x.clone().mod_log(&g, m.clone()), log_alt);
+        assert_eq!(x.clone().mod_log(g.clone(), &m), log_alt);
+        assert_eq!(x.clone().mod_log(g.clone(), m.clone()), log_alt);
+
+        // Multiply the modulus by 2^64 to exercise multi-limb arithmetic.
+        let big_m = &m << 64;
+        let big_g = &g + &m * Natural::from(12345u32);
+        let y = (&big_g).mod_pow(&x, &big_m);
+        let log = (&y).mod_log(&big_g, &big_m).unwrap();
+        assert!(log <= x);
+        assert_eq!((&big_g).mod_pow(&log, &big_m), y);
+        if let Some(order) = (&big_g).multiplicative_order(&big_m) {
+            assert!(log < order);
+        }
+        assert_eq!(Natural::ONE.mod_log(&big_g, &big_m), Some(Natural::ZERO));
+    });
+}
@@ -0,0 +1,55 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::CoprimeWith;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::factorization::traits::Moebius;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen};
+use malachite_nz::natural::Natural;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_moebius() {
+    let test = |s, out| {
+        assert_eq!(Natural::from_str(s).unwrap().moebius(), out);
+    };
+    test("1", 1);
+    test("2", -1);
+    test("36", 0);
+    test("1000000007", -1);
+    test("18446744073709551615", -1);
+    test("18446744073709551616", 0);
+    test("18446744073709551617", 1);
+    test("1089261790808475313373184", 0);
+    test("1000000000000000000000000000000", 0);
+    test("32589158477190044730", 1);
+    // 2^127 - 1
+    test("170141183460469231731687303715884105727", -1);
+}
+
+#[test]
+fn moebius_fail() {
+    assert_panic!(Natural::ZERO.moebius());
+}
+
+#[test]
+fn moebius_properties() {
+    unsigned_gen_var_1::<u64>().test_properties(|u| {
+        assert_eq!(Natural::from(u).moebius(), u.moebius());
+    });
+
+    unsigned_pair_gen::<u64, u16>().test_properties(|(m, n)| {
+        // mu is multiplicative
+        if m != 0 && n != 0 && m.coprime_with(u64::from(n)) {
+            let m = Natural::from(m);
+            let n = Natural::from(n);
+            assert_eq!((&m * &n).moebius(), m.moebius() * n.moebius());
+        }
+    });
+}
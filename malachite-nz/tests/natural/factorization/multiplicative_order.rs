@@ -0,0 +1,112 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{CoprimeWith, DivisibleBy, ModInverse, ModPow};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::factorization::traits::{Factor, MultiplicativeOrder};
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen_var_16};
+use malachite_nz::natural::Natural;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_multiplicative_order() {
+    let test = |s, t, out: Option<&str>| {
+        let x = Natural::from_str(s).unwrap();
+        let m = Natural::from_str(t).unwrap();
+        let out = out.map(|out| Natural::from_str(out).unwrap());
+
+        assert_eq!(x.clone().multiplicative_order(m.clone()), out);
+        assert_eq!(x.clone().multiplicative_order(&m), out);
+        assert_eq!((&x).multiplicative_order(m.clone()), out);
+        assert_eq!((&x).multiplicative_order(&m), out);
+    };
+    test("0", "1", Some("1"));
+    test("0", "5", None);
+    test("1", "5", Some("1"));
+    test("2", "7", Some("3"));
+    test("3", "7", Some("6"));
+    test("2", "8", None);
+    test("5", "12", Some("2"));
+    test("7", "18446744073709551629", Some("4611686018427387907"));
+    test("3", "18446744073709551616", Some("4611686018427387904"));
+    test(
+        "12347",
+        "1089261790808475313373184",
+        Some("30257271966902092038144"),
+    );
+    // 2^100
+    test(
+        "100000000000000000001",
+        "1267650600228229401496703205376",
+        Some("1208925819614629174706176"),
+    );
+    test(
+        "3",
+        "1000000000000000000000000000000",
+        Some("50000000000000000000000000000"),
+    );
+    test("6", "1000000000000000000000000000000", None);
+    // 2^127 - 1
+    test("2", "170141183460469231731687303715884105727", Some("127"));
+    test(
+        "3",
+        "170141183460469231731687303715884105727",
+        Some("56713727820156410577229101238628035242"),
+    );
+}
+
+#[test]
+fn multiplicative_order_fail() {
+    assert_panic!(Natural::ZERO.multiplicative_order(Natural::ZERO));
+    assert_panic!(Natural::from(5u32).multiplicative_order(Natural::from(5u32)));
+    assert_panic!(Natural::from(5u32).multiplicative_order(&Natural::from(5u32)));
+    assert_panic!((&Natural::from(5u32)).multiplicative_order(Natural::from(5u32)));
+    assert_panic!((&Natural::from(5u32)).multiplicative_order(&Natural::from(5u32)));
+}
+
+#[test]
+fn multiplicative_order_properties() {
+    unsigned_pair_gen_var_16::<u32>().test_properties(|(x, m)| {
+        let order = x.multiplicative_order(m);
+        let x = Natural::from(x);
+        let m = Natural::from(m);
+        let order_alt = (&x).multiplicative_order(&m);
+        assert_eq!(order_alt, order.map(Natural::from));
+        assert_eq!((&x).multiplicative_order(m.clone()), order_alt);
+        assert_eq!(x.clone().multiplicative_order(&m), order_alt);
+        assert_eq!(x.clone().multiplicative_order(m.clone()), order_alt);
+
+        // Multiply the modulus by 2^64 to exercise multi-limb arithmetic. Since big_x is congruent
+        // to x mod m, the new order is a multiple of the old one.
+        let big_m = &m << 64;
+        let big_x = &x + &m * Natural::from(12345u32);
+        let big_order = (&big_x).multiplicative_order(&big_m);
+        assert_eq!(big_order.is_some(), (&big_x).coprime_with(&big_m));
+        if let Some(big_order) = big_order {
+            assert_eq!((&big_x).mod_pow(&big_order, &big_m), 1u32);
+            for (p, _) in (&big_order).factor() {
+                assert_ne!((&big_x).mod_pow(&big_order / p, &big_m), 1u32);
+            }
+            assert!((&big_order).divisible_by(order_alt.unwrap()));
+            assert_eq!(
+                (&big_x)
+                    .mod_inverse(&big_m)
+                    .unwrap()
+                    .multiplicative_order(&big_m),
+                Some(big_order)
+            );
+        }
+    });
+
+    unsigned_gen_var_1::<u32>().test_properties(|m| {
+        let m = Natural::from(m) << 64;
+        assert_eq!(Natural::ONE.multiplicative_order(&m), Some(Natural::ONE));
+        assert_eq!(Natural::ZERO.multiplicative_order(&m), None);
+    });
+}
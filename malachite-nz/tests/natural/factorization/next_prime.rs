@@ -0,0 +1,82 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::{IsPrime, NextPrime, PrevPrime};
+use malachite_base::test_util::generators::unsigned_gen;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen;
+use std::str::FromStr;
+
+#[test]
+fn test_next_prime() {
+    let test = |s, out| {
+        let n = Natural::from_str(s).unwrap();
+        let p = n.next_prime();
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+        assert_eq!(rug::Integer::from(&n).next_prime().to_string(), out);
+    };
+    test("0", "2");
+    test("1", "2");
+    test("2", "3");
+    test("100", "101");
+    test("4294967291", "4294967311");
+    test("18446744073709551556", "18446744073709551557");
+    test("18446744073709551557", "18446744073709551629");
+    test("18446744073709551615", "18446744073709551629");
+    test("18446744073709551616", "18446744073709551629");
+    test("18446744073709551629", "18446744073709551653");
+    // 2^100
+    test(
+        "1267650600228229401496703205376",
+        "1267650600228229401496703205653",
+    );
+    // 2^127 - 2
+    test(
+        "170141183460469231731687303715884105726",
+        "170141183460469231731687303715884105727",
+    );
+    // 10^50
+    test(
+        "100000000000000000000000000000000000000000000000000",
+        "100000000000000000000000000000000000000000000000151",
+    );
+}
+
+#[test]
+fn next_prime_properties() {
+    natural_gen().test_properties(|n| {
+        let p = n.next_prime();
+        assert!(p.is_valid());
+        assert!(p > n);
+        assert!(p.is_prime());
+        assert_eq!(Natural::exact_from(&rug::Integer::from(&n).next_prime()), p);
+        assert!(p.prev_prime().is_none_or(|q| q <= n));
+        if n.is_prime() {
+            assert_eq!(p.prev_prime(), Some(n));
+        }
+    });
+
+    unsigned_gen::<u64>().test_properties(|u| {
+        if let Some(p) = u.next_prime() {
+            assert_eq!(Natural::from(u).next_prime(), p);
+        } else {
+            assert!(Natural::from(u).next_prime() > Natural::from(u64::MAX));
+        }
+    });
+
+    natural_gen().test_properties(|n| {
+        let p = n.next_prime();
+        let mut m = n + Natural::ONE;
+        while m < p {
+            assert!(!m.is_prime());
+            m += Natural::ONE;
+        }
+    });
+}
@@ -0,0 +1,81 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::{IsPrime, NextPrime, PrevPrime};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::unsigned_gen;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen;
+use std::str::FromStr;
+
+#[test]
+fn test_prev_prime() {
+    let test = |s, out| {
+        let n = Natural::from_str(s).unwrap();
+        let p = n.prev_prime();
+        assert!(p.as_ref().is_none_or(Natural::is_valid));
+        assert_eq!(p.to_debug_string(), out);
+        if n > 2u32 {
+            assert_eq!(
+                format!("Some({})", rug::Integer::from(&n).prev_prime()),
+                out
+            );
+        }
+    };
+    test("0", "None");
+    test("1", "None");
+    test("2", "None");
+    test("3", "Some(2)");
+    test("100", "Some(97)");
+    test("4294967311", "Some(4294967291)");
+    test("18446744073709551615", "Some(18446744073709551557)");
+    test("18446744073709551616", "Some(18446744073709551557)");
+    test("18446744073709551629", "Some(18446744073709551557)");
+    test("18446744073709551630", "Some(18446744073709551629)");
+    // 2^100
+    test(
+        "1267650600228229401496703205376",
+        "Some(1267650600228229401496703205361)",
+    );
+    // 2^127
+    test(
+        "170141183460469231731687303715884105728",
+        "Some(170141183460469231731687303715884105727)",
+    );
+    // 10^50
+    test(
+        "100000000000000000000000000000000000000000000000000",
+        "Some(99999999999999999999999999999999999999999999999943)",
+    );
+}
+
+#[test]
+fn prev_prime_properties() {
+    natural_gen().test_properties(|n| {
+        let p = n.prev_prime();
+        if let Some(p) = p {
+            assert!(p.is_valid());
+            assert!(p < n);
+            assert!(p.is_prime());
+            assert_eq!(Natural::exact_from(&rug::Integer::from(&n).prev_prime()), p);
+            assert!(p.next_prime() >= n);
+            if n.is_prime() {
+                assert_eq!(p.next_prime(), n);
+            }
+        } else {
+            assert!(n <= 2u32);
+        }
+    });
+
+    unsigned_gen::<u64>().test_properties(|u| {
+        assert_eq!(
+            Natural::from(u).prev_prime(),
+            u.prev_prime().map(Natural::from)
+        );
+    });
+}
@@ -12,13 +12,21 @@ use malachite_base::num::random::random_primitive_ints;
 use malachite_base::random::EXAMPLE_SEED;
 use malachite_base::strings::ToDebugString;
 use malachite_nz::natural::Natural;
-use malachite_nz::natural::random::get_random_natural_less_than;
+use malachite_nz::natural::random::{
+    get_random_natural_less_than, get_random_natural_less_than_with_rng,
+};
 use std::str::FromStr;
 
 fn get_random_natural_less_than_helper(limit: &str, out: &str) {
+    let limit = Natural::from_str(limit).unwrap();
     let mut xs = random_primitive_ints(EXAMPLE_SEED);
     let xs = (0..10)
-        .map(|_| get_random_natural_less_than(&mut xs, &Natural::from_str(limit).unwrap()))
+        .map(|_| get_random_natural_less_than(&mut xs, &limit))
+        .collect_vec();
+    assert_eq!(xs.to_debug_string(), out);
+    let mut rng = EXAMPLE_SEED.get_rng();
+    let xs = (0..10)
+        .map(|_| get_random_natural_less_than_with_rng(&mut rng, &limit))
         .collect_vec();
     assert_eq!(xs.to_debug_string(), out);
 }
@@ -60,3 +68,9 @@ fn test_get_random_natural_less_than() {
 fn get_random_natural_less_than_fail() {
     get_random_natural_less_than(&mut random_primitive_ints(EXAMPLE_SEED), &Natural::ZERO);
 }
+
+#[test]
+#[should_panic]
+fn get_random_natural_less_than_with_rng_fail() {
+    get_random_natural_less_than_with_rng(&mut EXAMPLE_SEED.get_rng(), &Natural::ZERO);
+}
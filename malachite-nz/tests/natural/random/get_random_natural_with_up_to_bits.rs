@@ -10,7 +10,9 @@ use itertools::Itertools;
 use malachite_base::num::random::random_primitive_ints;
 use malachite_base::random::EXAMPLE_SEED;
 use malachite_base::strings::ToDebugString;
-use malachite_nz::natural::random::get_random_natural_with_up_to_bits;
+use malachite_nz::natural::random::{
+    get_random_natural_with_up_to_bits, get_random_natural_with_up_to_bits_with_rng,
+};
 
 fn get_random_natural_with_up_to_bits_helper(bits: u64, out: &str) {
     let mut xs = random_primitive_ints(EXAMPLE_SEED);
@@ -18,6 +20,11 @@ fn get_random_natural_with_up_to_bits_helper(bits: u64, out: &str) {
         .map(|_| get_random_natural_with_up_to_bits(&mut xs, bits))
         .collect_vec();
     assert_eq!(xs.to_debug_string(), out);
+    let mut rng = EXAMPLE_SEED.get_rng();
+    let xs = (0..10)
+        .map(|_| get_random_natural_with_up_to_bits_with_rng(&mut rng, bits))
+        .collect_vec();
+    assert_eq!(xs.to_debug_string(), out);
 }
 
 #[test]
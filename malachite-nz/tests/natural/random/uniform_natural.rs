@@ -0,0 +1,173 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use itertools::Itertools;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::random::EXAMPLE_SEED;
+use malachite_nz::natural::Natural;
+use malachite_nz::natural::random::{
+    UniformNatural, UniformNaturalWithBits, UniformNaturalWithUpToBits,
+    get_random_natural_with_bits_with_rng, get_random_natural_with_up_to_bits_with_rng,
+    random_naturals_less_than, uniform_random_natural_inclusive_range,
+    uniform_random_natural_range,
+};
+use rand::Rng;
+use std::str::FromStr;
+
+fn uniform_natural_helper(a: &str, b: &str, expected_values: &[&str]) {
+    let a = Natural::from_str(a).unwrap();
+    let b = Natural::from_str(b).unwrap();
+    let d = UniformNatural::new(a.clone(), b.clone());
+    let xs = EXAMPLE_SEED
+        .get_rng()
+        .sample_iter(&d)
+        .take(20)
+        .map(|x| x.to_string())
+        .collect_vec();
+    assert_eq!(xs, expected_values);
+
+    // The distribution samples the same values as the corresponding iterator
+    let mut rng = EXAMPLE_SEED.get_rng();
+    assert!(
+        uniform_random_natural_range(EXAMPLE_SEED, a.clone(), b.clone())
+            .take(100)
+            .all(|x| rng.sample(&d) == x)
+    );
+    let mut rng = EXAMPLE_SEED.get_rng();
+    assert!(
+        uniform_random_natural_range(EXAMPLE_SEED, a.clone(), b.clone())
+            .take(100)
+            .all(|x| rng.gen_range(a.clone()..b.clone()) == x)
+    );
+    let b = b - Natural::from(1u32);
+    let d = UniformNatural::new_inclusive(a.clone(), b.clone());
+    let mut rng = EXAMPLE_SEED.get_rng();
+    assert!(
+        uniform_random_natural_inclusive_range(EXAMPLE_SEED, a.clone(), b.clone())
+            .take(100)
+            .all(|x| rng.sample(&d) == x)
+    );
+    let mut rng = EXAMPLE_SEED.get_rng();
+    assert!(
+        uniform_random_natural_inclusive_range(EXAMPLE_SEED, a.clone(), b.clone())
+            .take(100)
+            .all(|x| rng.gen_range(a.clone()..=b.clone()) == x)
+    );
+}
+
+#[test]
+fn test_uniform_natural() {
+    uniform_natural_helper("0", "1", &["0"; 20]);
+    uniform_natural_helper(
+        "1950",
+        "2019",
+        &[
+            "1957", "1977", "1996", "1972", "1951", "1957", "1979", "1975", "2006", "1988", "1956",
+            "2005", "1979", "1962", "1957", "1973", "2002", "1957", "2010", "1957",
+        ],
+    );
+    uniform_natural_helper(
+        "0",
+        "1000000000000000000000",
+        &[
+            "435923906010024374129",
+            "992222257019096516701",
+            "765571825680950306311",
+            "751173587734309351026",
+            "806846307631359064306",
+            "511635921493084799060",
+            "537440550723829152686",
+            "410173328346674940950",
+            "28967395829910897387",
+            "135782121837693631603",
+            "467844437812925311261",
+            "619291579501791482040",
+            "127483954813758528550",
+            "689593301924898069351",
+            "235848755303623420829",
+            "441615032072155401991",
+            "325553679564506661231",
+            "963617859801083651095",
+            "144282443510448424792",
+            "137533559967325066556",
+        ],
+    );
+}
+
+#[test]
+#[should_panic]
+fn uniform_natural_fail_1() {
+    UniformNatural::new(Natural::from(1u32), Natural::from(1u32));
+}
+
+#[test]
+#[should_panic]
+fn uniform_natural_fail_2() {
+    UniformNatural::new(Natural::from(2u32), Natural::from(1u32));
+}
+
+#[test]
+#[should_panic]
+fn uniform_natural_new_inclusive_fail() {
+    UniformNatural::new_inclusive(Natural::from(2u32), Natural::from(1u32));
+}
+
+#[test]
+fn test_uniform_natural_less_than() {
+    let d = UniformNatural::less_than(Natural::from(10u32));
+    let xs = EXAMPLE_SEED
+        .get_rng()
+        .sample_iter(&d)
+        .take(10)
+        .map(|x| x.to_string())
+        .collect_vec();
+    assert_eq!(xs, &["1", "7", "5", "7", "9", "2", "8", "2", "4", "6"]);
+    let limit = Natural::from_str("1000000000000000000000").unwrap();
+    let d = UniformNatural::less_than(limit.clone());
+    let mut rng = EXAMPLE_SEED.get_rng();
+    assert!(
+        random_naturals_less_than(EXAMPLE_SEED, limit)
+            .take(100)
+            .all(|x| rng.sample(&d) == x)
+    );
+}
+
+#[test]
+#[should_panic]
+fn uniform_natural_less_than_fail() {
+    UniformNatural::less_than(Natural::from(0u32));
+}
+
+#[test]
+fn test_uniform_natural_with_bits() {
+    for bits in [0, 1, 2, 10, 64, 100, 1000] {
+        let mut rng_1 = EXAMPLE_SEED.get_rng();
+        let mut rng_2 = EXAMPLE_SEED.get_rng();
+        for _ in 0..100 {
+            let x = rng_1.sample(UniformNaturalWithBits(bits));
+            assert_eq!(x.significant_bits(), bits);
+            assert_eq!(x, get_random_natural_with_bits_with_rng(&mut rng_2, bits));
+        }
+    }
+}
+
+#[test]
+fn test_uniform_natural_with_up_to_bits() {
+    for bits in [0, 1, 2, 10, 64, 100, 1000] {
+        let mut rng_1 = EXAMPLE_SEED.get_rng();
+        let mut rng_2 = EXAMPLE_SEED.get_rng();
+        for _ in 0..100 {
+            let x = rng_1.sample(UniformNaturalWithUpToBits(bits));
+            assert!(x.significant_bits() <= bits);
+            assert_eq!(
+                x,
+                get_random_natural_with_up_to_bits_with_rng(&mut rng_2, bits)
+            );
+        }
+    }
+}
@@ -0,0 +1,558 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Rational;
+use crate::arithmetic::traits::{BestApproximations, SimplestRationalInInterval};
+use crate::conversion::continued_fraction::to_continued_fraction::RationalContinuedFraction;
+use crate::conversion::traits::ContinuedFraction;
+use core::cmp::{max, min};
+use core::mem::swap;
+use malachite_base::num::arithmetic::traits::{
+    Abs, AbsDiff, AddMulAssign, CheckedSub, UnsignedAbs,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::natural::Natural;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Size {
+    Numerator,
+    Denominator,
+    Height,
+}
+
+// Returns the largest `m` no greater than `limit` such that `offset + m * step <= max`, or `None`
+// if there is no such `m`.
+fn max_multiplier(
+    offset: &Natural,
+    step: &Natural,
+    max: &Natural,
+    limit: &Natural,
+) -> Option<Natural> {
+    let room = max.checked_sub(offset)?;
+    Some(if *step == 0u32 {
+        limit.clone()
+    } else {
+        min(room / step, limit.clone())
+    })
+}
+
+/// An iterator that produces the best approximations of a [`Rational`], subject to a bound on
+/// their size.
+///
+/// See [`BestApproximations`] for more information.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RationalBestApproximations {
+    // whether the approximated `Rational` is non-negative
+    sign: bool,
+    // the absolute value of the approximated `Rational`
+    x: Rational,
+    size: Size,
+    max_size: Natural,
+    cf: RationalContinuedFraction,
+    // The fractions on the Stern-Brocot path of `x` come in blocks. The fractions in the current
+    // block are `(previous_numerator + m * numerator) / (previous_denominator + m * denominator)`,
+    // for `m` up to `term`, and the block ends with the next convergent.
+    previous_numerator: Natural,
+    previous_denominator: Natural,
+    numerator: Natural,
+    denominator: Natural,
+    term: Natural,
+    // the next fraction in the current block that is closer to `x` than all the fractions before it
+    current_numerator: Natural,
+    current_denominator: Natural,
+    // the number of fractions left to produce in the current block
+    remaining: Natural,
+    // whether the size bound cuts the current block short, so that no later block can be reached
+    truncated: bool,
+    // the best approximation found so far, which is only produced once it is known that no other
+    // fraction of the same size is closer to `x`
+    pending: Option<(Natural, Natural)>,
+    done: bool,
+}
+
+impl RationalBestApproximations {
+    fn new(x: Rational, size: Size, max_size: Natural) -> RationalBestApproximations {
+        let sign = x.sign;
+        let x = x.abs();
+        let (floor, cf) = (&x).continued_fraction();
+        let mut approximations = RationalBestApproximations {
+            sign,
+            x,
+            size,
+            max_size,
+            cf,
+            previous_numerator: Natural::ZERO,
+            previous_denominator: Natural::ONE,
+            numerator: Natural::ONE,
+            denominator: Natural::ZERO,
+            term: floor.unsigned_abs(),
+            current_numerator: Natural::ZERO,
+            current_denominator: Natural::ZERO,
+            remaining: Natural::ZERO,
+            truncated: false,
+            pending: None,
+            done: false,
+        };
+        // The first block consists of the integers from 0 to floor(x), each closer to x than the
+        // previous one.
+        approximations.start_block(Natural::ZERO);
+        approximations
+    }
+
+    fn size<'a>(&self, numerator: &'a Natural, denominator: &'a Natural) -> &'a Natural {
+        match self.size {
+            Size::Numerator => numerator,
+            Size::Denominator => denominator,
+            Size::Height => max(numerator, denominator),
+        }
+    }
+
+    // Prepares to produce the fractions in the current block starting from the `m`th, clipping the
+    // block to the size bound.
+    fn start_block(&mut self, mut m: Natural) {
+        let m_end = match self.size {
+            Size::Numerator => max_multiplier(
+                &self.previous_numerator,
+                &self.numerator,
+                &self.max_size,
+                &self.term,
+            ),
+            Size::Denominator => max_multiplier(
+                &self.previous_denominator,
+                &self.denominator,
+                &self.max_size,
+                &self.term,
+            ),
+            Size::Height => max_multiplier(
+                &self.previous_numerator,
+                &self.numerator,
+                &self.max_size,
+                &self.term,
+            )
+            .and_then(|m_end| {
+                max_multiplier(
+                    &self.previous_denominator,
+                    &self.denominator,
+                    &self.max_size,
+                    &m_end,
+                )
+            }),
+        };
+        let Some(m_end) = m_end.filter(|m_end| *m_end >= m) else {
+            self.remaining = Natural::ZERO;
+            self.truncated = true;
+            return;
+        };
+        self.truncated = m_end < self.term;
+        // If all fractions in the block have the same size, only the last one, which is closest to
+        // `x`, can be a best approximation.
+        if match self.size {
+            Size::Numerator => self.numerator == 0u32,
+            Size::Denominator => self.denominator == 0u32,
+            Size::Height => false,
+        } {
+            m = m_end.clone();
+        }
+        self.remaining = m_end - &m + Natural::ONE;
+        self.current_numerator = &self.previous_numerator + &self.numerator * &m;
+        self.current_denominator = &self.previous_denominator + &self.denominator * m;
+    }
+
+    // Moves on to the next block, returning false if there is none.
+    fn next_block(&mut self) -> bool {
+        let Some(term) = self.cf.next() else {
+            return false;
+        };
+        self.previous_numerator
+            .add_mul_assign(&self.numerator, &self.term);
+        self.previous_denominator
+            .add_mul_assign(&self.denominator, &self.term);
+        swap(&mut self.numerator, &mut self.previous_numerator);
+        swap(&mut self.denominator, &mut self.previous_denominator);
+        self.term = term;
+        // The fractions in the new block approach `x` from the opposite side from the convergent
+        // `p / q` that ended the previous block, getting closer as `m` increases. With `P / Q` the
+        // convergent before `p / q`, the `m`th fraction is closer to `x` than `p / q` is iff
+        // `2 * m * |x * q - p| * q > |x * Q - P| * q - |x * q - p| * Q`.
+        let n = self.x.numerator_ref();
+        let d = self.x.denominator_ref();
+        let previous_error =
+            (n * &self.previous_denominator).abs_diff(d * &self.previous_numerator);
+        let error = (n * &self.denominator).abs_diff(d * &self.numerator);
+        let lhs = previous_error * &self.denominator;
+        let rhs = &error * &self.previous_denominator;
+        let m = if lhs <= rhs {
+            Natural::ONE
+        } else {
+            (lhs - rhs) / ((error << 1) * &self.denominator) + Natural::ONE
+        };
+        self.start_block(m);
+        true
+    }
+
+    // Returns the next fraction on the Stern-Brocot path of `x` that is closer to `x` than all the
+    // fractions before it.
+    fn next_candidate(&mut self) -> Option<(Natural, Natural)> {
+        while self.remaining == 0u32 {
+            if self.truncated || !self.next_block() {
+                return None;
+            }
+        }
+        self.remaining -= Natural::ONE;
+        let candidate = (
+            self.current_numerator.clone(),
+            self.current_denominator.clone(),
+        );
+        self.current_numerator += &self.numerator;
+        self.current_denominator += &self.denominator;
+        Some(candidate)
+    }
+
+    fn to_rational(&self, (numerator, denominator): (Natural, Natural)) -> Rational {
+        Rational {
+            sign: self.sign || numerator == 0u32,
+            numerator,
+            denominator,
+        }
+    }
+}
+
+impl Iterator for RationalBestApproximations {
+    type Item = Rational;
+
+    fn next(&mut self) -> Option<Rational> {
+        if self.done {
+            return None;
+        }
+        loop {
+            let Some((numerator, denominator)) = self.next_candidate() else {
+                self.done = true;
+                return self.pending.take().map(|p| self.to_rational(p));
+            };
+            // Candidates never get smaller, and each one is closer to `x` than the last, so a
+            // candidate of the same size as the pending approximation replaces it.
+            let same_size = self
+                .pending
+                .as_ref()
+                .is_none_or(|(pn, pd)| self.size(pn, pd) == self.size(&numerator, &denominator));
+            let previous = self.pending.replace((numerator, denominator));
+            if !same_size {
+                return previous.map(|p| self.to_rational(p));
+            }
+        }
+    }
+}
+
+fn best_approximations_within_tolerance_helper(
+    x: Rational,
+    tolerance: &Rational,
+) -> RationalBestApproximations {
+    assert!(*tolerance >= 0u32, "tolerance cannot be negative");
+    // The last approximation produced is the best one whose denominator does not exceed that of
+    // the simplest `Rational` within the tolerance, so it is within the tolerance as well.
+    let x_abs = (&x).abs();
+    let simplest = Rational::simplest_rational_in_closed_interval(
+        &(&x_abs - tolerance),
+        &(&x_abs + tolerance),
+    );
+    RationalBestApproximations::new(x, Size::Denominator, simplest.into_denominator())
+}
+
+impl BestApproximations for Rational {
+    type Approximations = RationalBestApproximations;
+
+    /// Returns the best approximations of a [`Rational`] whose numerators have absolute value no
+    /// greater than a specified maximum, taking the [`Rational`] by value.
+    ///
+    /// The approximations are produced in order of increasing numerator magnitude, each closer to
+    /// $x$ than the one before. A [`Rational`] $p/q$ is included iff $|p| \leq N$, every
+    /// [`Rational`] $p'/q'$ with $|p'| < |p|$ is farther from $x$ than $p/q$, and no [`Rational`]
+    /// $p'/q'$ with $|p'| = |p|$ is closer. If two such [`Rational`]s are equally close to $x$,
+    /// only the one with the smaller denominator is included. The last approximation is the
+    /// closest [`Rational`] to $x$ whose numerator has absolute value at most $N$.
+    ///
+    /// The approximations are all convergents or semiconvergents of $x$. If $x$ is negative, they
+    /// are the negatives of the approximations of $|x|$.
+    ///
+    /// # Worst-case complexity per iteration
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// max_numerator.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use itertools::Itertools;
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::arithmetic::traits::BestApproximations;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Rational::exact_from(std::f64::consts::PI)
+    ///         .best_approximations_with_max_numerator(&Natural::from(100u32))
+    ///         .collect_vec()
+    ///         .to_debug_string(),
+    ///     "[0, 1, 2, 3, 13/4, 16/5, 19/6, 22/7]"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(-7, 3)
+    ///         .best_approximations_with_max_numerator(&Natural::from(5u32))
+    ///         .collect_vec()
+    ///         .to_debug_string(),
+    ///     "[0, -1, -2, -5/2]"
+    /// );
+    /// ```
+    fn best_approximations_with_max_numerator(
+        self,
+        max_numerator: &Natural,
+    ) -> RationalBestApproximations {
+        RationalBestApproximations::new(self, Size::Numerator, max_numerator.clone())
+    }
+
+    /// Returns the best approximations of a [`Rational`] whose heights are no greater than a
+    /// specified maximum, taking the [`Rational`] by value. The height of $p/q$, with $p$ and $q$
+    /// relatively prime, is $\max(|p|, q)$.
+    ///
+    /// The approximations are produced in order of increasing height, each closer to $x$ than the
+    /// one before. A [`Rational`] $r$ is included iff its height is at most $H$, every [`Rational`]
+    /// of smaller height is farther from $x$ than $r$, and no [`Rational`] of the same height is
+    /// closer. If two such [`Rational`]s are equally close to $x$, only the one with the smaller
+    /// numerator and denominator is included. The last approximation is the closest [`Rational`] to
+    /// $x$ whose height is at most $H$.
+    ///
+    /// The approximations are all convergents or semiconvergents of $x$. If $x$ is negative, they
+    /// are the negatives of the approximations of $|x|$.
+    ///
+    /// # Worst-case complexity per iteration
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// max_height.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `max_height` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use itertools::Itertools;
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::arithmetic::traits::BestApproximations;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Rational::exact_from(std::f64::consts::PI)
+    ///         .best_approximations_with_max_height(&Natural::from(100u32))
+    ///         .collect_vec()
+    ///         .to_debug_string(),
+    ///     "[1, 2, 3, 13/4, 16/5, 19/6, 22/7]"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(1, 7)
+    ///         .best_approximations_with_max_height(&Natural::from(5u32))
+    ///         .collect_vec()
+    ///         .to_debug_string(),
+    ///     "[0, 1/4, 1/5]"
+    /// );
+    /// ```
+    fn best_approximations_with_max_height(
+        self,
+        max_height: &Natural,
+    ) -> RationalBestApproximations {
+        assert_ne!(*max_height, 0u32);
+        RationalBestApproximations::new(self, Size::Height, max_height.clone())
+    }
+
+    /// Returns the best approximations of a [`Rational`], with respect to the denominator, up to
+    /// and including the first one that is within a specified tolerance of the [`Rational`],
+    /// taking the [`Rational`] by value.
+    ///
+    /// The approximations are produced in order of increasing denominator, each closer to $x$ than
+    /// the one before. A [`Rational`] $p/q$ is included iff every [`Rational`] with a denominator
+    /// smaller than $q$ is farther from $x$ than $p/q$, no [`Rational`] with denominator $q$ is
+    /// closer, and none of the approximations before it are within $\varepsilon$ of $x$. The last
+    /// approximation is the closest [`Rational`] to $x$ among those with the smallest denominator
+    /// in the interval $[x - \varepsilon, x + \varepsilon]$, so its denominator is that of
+    /// [`Rational::simplest_rational_in_closed_interval`] on this interval. If $\varepsilon = 0$,
+    /// all the best approximations of $x$ are produced, and the last one is $x$ itself.
+    ///
+    /// The approximations are all convergents or semiconvergents of $x$. If $x$ is negative, they
+    /// are the negatives of the approximations of $|x|$.
+    ///
+    /// # Worst-case complexity per iteration
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// tolerance.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `tolerance` is negative.
+    ///
+    /// # Examples
+    /// ```
+    /// use itertools::Itertools;
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::arithmetic::traits::BestApproximations;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Rational::exact_from(std::f64::consts::PI)
+    ///         .best_approximations_within_tolerance(&Rational::from_signeds(1, 1000))
+    ///         .collect_vec()
+    ///         .to_debug_string(),
+    ///     "[3, 13/4, 16/5, 19/6, 22/7, 179/57, 201/64]"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(-7, 3)
+    ///         .best_approximations_within_tolerance(&Rational::ZERO)
+    ///         .collect_vec()
+    ///         .to_debug_string(),
+    ///     "[-2, -5/2, -7/3]"
+    /// );
+    /// ```
+    fn best_approximations_within_tolerance(
+        self,
+        tolerance: &Rational,
+    ) -> RationalBestApproximations {
+        best_approximations_within_tolerance_helper(self, tolerance)
+    }
+}
+
+impl BestApproximations for &Rational {
+    type Approximations = RationalBestApproximations;
+
+    /// Returns the best approximations of a [`Rational`] whose numerators have absolute value no
+    /// greater than a specified maximum, taking the [`Rational`] by reference.
+    ///
+    /// See [`Rational::best_approximations_with_max_numerator`] for more information.
+    ///
+    /// # Worst-case complexity per iteration
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// max_numerator.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use itertools::Itertools;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::arithmetic::traits::BestApproximations;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-7, 3))
+    ///         .best_approximations_with_max_numerator(&Natural::from(5u32))
+    ///         .collect_vec()
+    ///         .to_debug_string(),
+    ///     "[0, -1, -2, -5/2]"
+    /// );
+    /// ```
+    #[inline]
+    fn best_approximations_with_max_numerator(
+        self,
+        max_numerator: &Natural,
+    ) -> RationalBestApproximations {
+        self.clone()
+            .best_approximations_with_max_numerator(max_numerator)
+    }
+
+    /// Returns the best approximations of a [`Rational`] whose heights are no greater than a
+    /// specified maximum, taking the [`Rational`] by reference.
+    ///
+    /// See [`Rational::best_approximations_with_max_height`] for more information.
+    ///
+    /// # Worst-case complexity per iteration
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// max_height.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `max_height` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use itertools::Itertools;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::arithmetic::traits::BestApproximations;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(1, 7))
+    ///         .best_approximations_with_max_height(&Natural::from(5u32))
+    ///         .collect_vec()
+    ///         .to_debug_string(),
+    ///     "[0, 1/4, 1/5]"
+    /// );
+    /// ```
+    #[inline]
+    fn best_approximations_with_max_height(
+        self,
+        max_height: &Natural,
+    ) -> RationalBestApproximations {
+        self.clone().best_approximations_with_max_height(max_height)
+    }
+
+    /// Returns the best approximations of a [`Rational`], with respect to the denominator, up to
+    /// and including the first one that is within a specified tolerance of the [`Rational`],
+    /// taking the [`Rational`] by reference.
+    ///
+    /// See [`Rational::best_approximations_within_tolerance`] for more information.
+    ///
+    /// # Worst-case complexity per iteration
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// tolerance.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `tolerance` is negative.
+    ///
+    /// # Examples
+    /// ```
+    /// use itertools::Itertools;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::arithmetic::traits::BestApproximations;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-7, 3))
+    ///         .best_approximations_within_tolerance(&Rational::ZERO)
+    ///         .collect_vec()
+    ///         .to_debug_string(),
+    ///     "[-2, -5/2, -7/3]"
+    /// );
+    /// ```
+    #[inline]
+    fn best_approximations_within_tolerance(
+        self,
+        tolerance: &Rational,
+    ) -> RationalBestApproximations {
+        self.clone().best_approximations_within_tolerance(tolerance)
+    }
+}
@@ -0,0 +1,1335 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{
+    CeilingDivAssignMod, CeilingDivMod, DivAssignMod, DivAssignRem, DivMod, DivRem, UnsignedAbs,
+};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+
+// Writes $x$ and $y$ over a common denominator $d$, returning $(n, m, d)$ with $x = n/d$ and
+// $y = m/d$. Then $x/y = n/m$, and $x - qy = (n - qm)/d$ for any integer $q$, so rounding $x/y$
+// and finding the corresponding remainder reduces to `Integer` division.
+pub(crate) fn to_common_denominator(x: &Rational, y: &Rational) -> (Integer, Integer, Natural) {
+    (
+        Integer::from_sign_and_abs(x.sign, &x.numerator * &y.denominator),
+        Integer::from_sign_and_abs(y.sign, &y.numerator * &x.denominator),
+        &x.denominator * &y.denominator,
+    )
+}
+
+// Returns $r/d$, given an `Integer` $r$ and a positive `Natural` $d$.
+pub(crate) fn over_denominator(r: Integer, d: Natural) -> Rational {
+    Rational::from_sign_and_naturals(r >= 0u32, r.unsigned_abs(), d)
+}
+
+impl DivMod<Rational> for Rational {
+    type DivOutput = Integer;
+    type ModOutput = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking both by value and returning the
+    /// quotient and remainder. The quotient is rounded towards negative infinity, and the remainder
+    /// has the same sign as the second [`Rational`].
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $0 \leq |r| < |y|$, where $q$ is an
+    /// integer.
+    ///
+    /// $$
+    /// f(x, y) = \left ( \left \lfloor \frac{x}{y} \right \rfloor, \space
+    /// x - y\left \lfloor \frac{x}{y} \right \rfloor \right ).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivMod;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::Rational;
+    ///
+    /// // 7 * 4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(21, 2)
+    ///         .div_mod(Rational::from_signeds(4, 3))
+    ///         .to_debug_string(),
+    ///     "(7, 7/6)"
+    /// );
+    ///
+    /// // -8 * -4/3 + -1/6 = 21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(21, 2)
+    ///         .div_mod(Rational::from_signeds(-4, 3))
+    ///         .to_debug_string(),
+    ///     "(-8, -1/6)"
+    /// );
+    ///
+    /// // -8 * 4/3 + 1/6 = -21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(-21, 2)
+    ///         .div_mod(Rational::from_signeds(4, 3))
+    ///         .to_debug_string(),
+    ///     "(-8, 1/6)"
+    /// );
+    ///
+    /// // 7 * -4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(-21, 2)
+    ///         .div_mod(Rational::from_signeds(-4, 3))
+    ///         .to_debug_string(),
+    ///     "(7, -7/6)"
+    /// );
+    /// ```
+    #[inline]
+    fn div_mod(self, other: Rational) -> (Integer, Rational) {
+        (&self).div_mod(&other)
+    }
+}
+
+impl DivMod<&Rational> for Rational {
+    type DivOutput = Integer;
+    type ModOutput = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking the first by value and the second by
+    /// reference and returning the quotient and remainder. The quotient is rounded towards negative
+    /// infinity, and the remainder has the same sign as the second [`Rational`].
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $0 \leq |r| < |y|$, where $q$ is an
+    /// integer.
+    ///
+    /// $$
+    /// f(x, y) = \left ( \left \lfloor \frac{x}{y} \right \rfloor, \space
+    /// x - y\left \lfloor \frac{x}{y} \right \rfloor \right ).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivMod;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::Rational;
+    ///
+    /// // 7 * 4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(21, 2)
+    ///         .div_mod(&Rational::from_signeds(4, 3))
+    ///         .to_debug_string(),
+    ///     "(7, 7/6)"
+    /// );
+    ///
+    /// // -8 * -4/3 + -1/6 = 21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(21, 2)
+    ///         .div_mod(&Rational::from_signeds(-4, 3))
+    ///         .to_debug_string(),
+    ///     "(-8, -1/6)"
+    /// );
+    ///
+    /// // -8 * 4/3 + 1/6 = -21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(-21, 2)
+    ///         .div_mod(&Rational::from_signeds(4, 3))
+    ///         .to_debug_string(),
+    ///     "(-8, 1/6)"
+    /// );
+    ///
+    /// // 7 * -4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(-21, 2)
+    ///         .div_mod(&Rational::from_signeds(-4, 3))
+    ///         .to_debug_string(),
+    ///     "(7, -7/6)"
+    /// );
+    /// ```
+    #[inline]
+    fn div_mod(self, other: &Rational) -> (Integer, Rational) {
+        (&self).div_mod(other)
+    }
+}
+
+impl DivMod<Rational> for &Rational {
+    type DivOutput = Integer;
+    type ModOutput = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking the first by reference and the second
+    /// by value and returning the quotient and remainder. The quotient is rounded towards negative
+    /// infinity, and the remainder has the same sign as the second [`Rational`].
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $0 \leq |r| < |y|$, where $q$ is an
+    /// integer.
+    ///
+    /// $$
+    /// f(x, y) = \left ( \left \lfloor \frac{x}{y} \right \rfloor, \space
+    /// x - y\left \lfloor \frac{x}{y} \right \rfloor \right ).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivMod;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::Rational;
+    ///
+    /// // 7 * 4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(21, 2))
+    ///         .div_mod(Rational::from_signeds(4, 3))
+    ///         .to_debug_string(),
+    ///     "(7, 7/6)"
+    /// );
+    ///
+    /// // -8 * -4/3 + -1/6 = 21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(21, 2))
+    ///         .div_mod(Rational::from_signeds(-4, 3))
+    ///         .to_debug_string(),
+    ///     "(-8, -1/6)"
+    /// );
+    ///
+    /// // -8 * 4/3 + 1/6 = -21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-21, 2))
+    ///         .div_mod(Rational::from_signeds(4, 3))
+    ///         .to_debug_string(),
+    ///     "(-8, 1/6)"
+    /// );
+    ///
+    /// // 7 * -4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-21, 2))
+    ///         .div_mod(Rational::from_signeds(-4, 3))
+    ///         .to_debug_string(),
+    ///     "(7, -7/6)"
+    /// );
+    /// ```
+    #[inline]
+    fn div_mod(self, other: Rational) -> (Integer, Rational) {
+        self.div_mod(&other)
+    }
+}
+
+impl DivMod<&Rational> for &Rational {
+    type DivOutput = Integer;
+    type ModOutput = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking both by reference and returning the
+    /// quotient and remainder. The quotient is rounded towards negative infinity, and the remainder
+    /// has the same sign as the second [`Rational`].
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $0 \leq |r| < |y|$, where $q$ is an
+    /// integer.
+    ///
+    /// $$
+    /// f(x, y) = \left ( \left \lfloor \frac{x}{y} \right \rfloor, \space
+    /// x - y\left \lfloor \frac{x}{y} \right \rfloor \right ).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivMod;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::Rational;
+    ///
+    /// // 7 * 4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(21, 2))
+    ///         .div_mod(&Rational::from_signeds(4, 3))
+    ///         .to_debug_string(),
+    ///     "(7, 7/6)"
+    /// );
+    ///
+    /// // -8 * -4/3 + -1/6 = 21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(21, 2))
+    ///         .div_mod(&Rational::from_signeds(-4, 3))
+    ///         .to_debug_string(),
+    ///     "(-8, -1/6)"
+    /// );
+    ///
+    /// // -8 * 4/3 + 1/6 = -21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-21, 2))
+    ///         .div_mod(&Rational::from_signeds(4, 3))
+    ///         .to_debug_string(),
+    ///     "(-8, 1/6)"
+    /// );
+    ///
+    /// // 7 * -4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-21, 2))
+    ///         .div_mod(&Rational::from_signeds(-4, 3))
+    ///         .to_debug_string(),
+    ///     "(7, -7/6)"
+    /// );
+    /// ```
+    fn div_mod(self, other: &Rational) -> (Integer, Rational) {
+        let (n, m, d) = to_common_denominator(self, other);
+        let (q, r) = n.div_mod(m);
+        (q, over_denominator(r, d))
+    }
+}
+
+impl DivAssignMod<Rational> for Rational {
+    type ModOutput = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`] in place, taking the [`Rational`] on the
+    /// right-hand side by value and returning the remainder. The quotient is rounded towards
+    /// negative infinity, and the remainder has the same sign as the second [`Rational`]. The
+    /// quotient, which is an integer, replaces the first [`Rational`].
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $0 \leq |r| < |y|$, where $q$ is an
+    /// integer.
+    ///
+    /// $$
+    /// f(x, y) = x - y\left \lfloor \frac{x}{y} \right \rfloor,
+    /// $$
+    /// $$
+    /// x \gets \left \lfloor \frac{x}{y} \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivAssignMod;
+    /// use malachite_q::Rational;
+    ///
+    /// // 7 * 4/3 + 7/6 = 21/2
+    /// let mut x = Rational::from_signeds(21, 2);
+    /// assert_eq!(
+    ///     x.div_assign_mod(Rational::from_signeds(4, 3)).to_string(),
+    ///     "7/6"
+    /// );
+    /// assert_eq!(x, 7);
+    ///
+    /// // -8 * -4/3 + -1/6 = 21/2
+    /// let mut x = Rational::from_signeds(21, 2);
+    /// assert_eq!(
+    ///     x.div_assign_mod(Rational::from_signeds(-4, 3)).to_string(),
+    ///     "-1/6"
+    /// );
+    /// assert_eq!(x, -8);
+    ///
+    /// // -8 * 4/3 + 1/6 = -21/2
+    /// let mut x = Rational::from_signeds(-21, 2);
+    /// assert_eq!(
+    ///     x.div_assign_mod(Rational::from_signeds(4, 3)).to_string(),
+    ///     "1/6"
+    /// );
+    /// assert_eq!(x, -8);
+    ///
+    /// // 7 * -4/3 + -7/6 = -21/2
+    /// let mut x = Rational::from_signeds(-21, 2);
+    /// assert_eq!(
+    ///     x.div_assign_mod(Rational::from_signeds(-4, 3)).to_string(),
+    ///     "-7/6"
+    /// );
+    /// assert_eq!(x, 7);
+    /// ```
+    fn div_assign_mod(&mut self, other: Rational) -> Rational {
+        let (q, r) = (&*self).div_mod(other);
+        *self = Rational::from(q);
+        r
+    }
+}
+
+impl DivAssignMod<&Rational> for Rational {
+    type ModOutput = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`] in place, taking the [`Rational`] on the
+    /// right-hand side by reference and returning the remainder. The quotient is rounded towards
+    /// negative infinity, and the remainder has the same sign as the second [`Rational`]. The
+    /// quotient, which is an integer, replaces the first [`Rational`].
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $0 \leq |r| < |y|$, where $q$ is an
+    /// integer.
+    ///
+    /// $$
+    /// f(x, y) = x - y\left \lfloor \frac{x}{y} \right \rfloor,
+    /// $$
+    /// $$
+    /// x \gets \left \lfloor \frac{x}{y} \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivAssignMod;
+    /// use malachite_q::Rational;
+    ///
+    /// // 7 * 4/3 + 7/6 = 21/2
+    /// let mut x = Rational::from_signeds(21, 2);
+    /// assert_eq!(
+    ///     x.div_assign_mod(&Rational::from_signeds(4, 3)).to_string(),
+    ///     "7/6"
+    /// );
+    /// assert_eq!(x, 7);
+    ///
+    /// // -8 * -4/3 + -1/6 = 21/2
+    /// let mut x = Rational::from_signeds(21, 2);
+    /// assert_eq!(
+    ///     x.div_assign_mod(&Rational::from_signeds(-4, 3)).to_string(),
+    ///     "-1/6"
+    /// );
+    /// assert_eq!(x, -8);
+    ///
+    /// // -8 * 4/3 + 1/6 = -21/2
+    /// let mut x = Rational::from_signeds(-21, 2);
+    /// assert_eq!(
+    ///     x.div_assign_mod(&Rational::from_signeds(4, 3)).to_string(),
+    ///     "1/6"
+    /// );
+    /// assert_eq!(x, -8);
+    ///
+    /// // 7 * -4/3 + -7/6 = -21/2
+    /// let mut x = Rational::from_signeds(-21, 2);
+    /// assert_eq!(
+    ///     x.div_assign_mod(&Rational::from_signeds(-4, 3)).to_string(),
+    ///     "-7/6"
+    /// );
+    /// assert_eq!(x, 7);
+    /// ```
+    fn div_assign_mod(&mut self, other: &Rational) -> Rational {
+        let (q, r) = (&*self).div_mod(other);
+        *self = Rational::from(q);
+        r
+    }
+}
+
+impl DivRem<Rational> for Rational {
+    type DivOutput = Integer;
+    type RemOutput = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking both by value and returning the
+    /// quotient and remainder. The quotient is rounded towards zero and the remainder has the same
+    /// sign as the first [`Rational`].
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $0 \leq |r| < |y|$, where $q$ is an
+    /// integer.
+    ///
+    /// $$
+    /// f(x, y) = \left ( \operatorname{sgn}(xy) \left \lfloor \left | \frac{x}{y} \right |
+    /// \right \rfloor, \space
+    /// x - y \operatorname{sgn}(xy)
+    /// \left \lfloor \left | \frac{x}{y} \right | \right \rfloor \right ).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivRem;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::Rational;
+    ///
+    /// // 7 * 4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(21, 2)
+    ///         .div_rem(Rational::from_signeds(4, 3))
+    ///         .to_debug_string(),
+    ///     "(7, 7/6)"
+    /// );
+    ///
+    /// // -7 * -4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(21, 2)
+    ///         .div_rem(Rational::from_signeds(-4, 3))
+    ///         .to_debug_string(),
+    ///     "(-7, 7/6)"
+    /// );
+    ///
+    /// // -7 * 4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(-21, 2)
+    ///         .div_rem(Rational::from_signeds(4, 3))
+    ///         .to_debug_string(),
+    ///     "(-7, -7/6)"
+    /// );
+    ///
+    /// // 7 * -4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(-21, 2)
+    ///         .div_rem(Rational::from_signeds(-4, 3))
+    ///         .to_debug_string(),
+    ///     "(7, -7/6)"
+    /// );
+    /// ```
+    #[inline]
+    fn div_rem(self, other: Rational) -> (Integer, Rational) {
+        (&self).div_rem(&other)
+    }
+}
+
+impl DivRem<&Rational> for Rational {
+    type DivOutput = Integer;
+    type RemOutput = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking the first by value and the second by
+    /// reference and returning the quotient and remainder. The quotient is rounded towards zero and
+    /// the remainder has the same sign as the first [`Rational`].
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $0 \leq |r| < |y|$, where $q$ is an
+    /// integer.
+    ///
+    /// $$
+    /// f(x, y) = \left ( \operatorname{sgn}(xy) \left \lfloor \left | \frac{x}{y} \right |
+    /// \right \rfloor, \space
+    /// x - y \operatorname{sgn}(xy)
+    /// \left \lfloor \left | \frac{x}{y} \right | \right \rfloor \right ).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivRem;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::Rational;
+    ///
+    /// // 7 * 4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(21, 2)
+    ///         .div_rem(&Rational::from_signeds(4, 3))
+    ///         .to_debug_string(),
+    ///     "(7, 7/6)"
+    /// );
+    ///
+    /// // -7 * -4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(21, 2)
+    ///         .div_rem(&Rational::from_signeds(-4, 3))
+    ///         .to_debug_string(),
+    ///     "(-7, 7/6)"
+    /// );
+    ///
+    /// // -7 * 4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(-21, 2)
+    ///         .div_rem(&Rational::from_signeds(4, 3))
+    ///         .to_debug_string(),
+    ///     "(-7, -7/6)"
+    /// );
+    ///
+    /// // 7 * -4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(-21, 2)
+    ///         .div_rem(&Rational::from_signeds(-4, 3))
+    ///         .to_debug_string(),
+    ///     "(7, -7/6)"
+    /// );
+    /// ```
+    #[inline]
+    fn div_rem(self, other: &Rational) -> (Integer, Rational) {
+        (&self).div_rem(other)
+    }
+}
+
+impl DivRem<Rational> for &Rational {
+    type DivOutput = Integer;
+    type RemOutput = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking the first by reference and the second
+    /// by value and returning the quotient and remainder. The quotient is rounded towards zero and
+    /// the remainder has the same sign as the first [`Rational`].
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $0 \leq |r| < |y|$, where $q$ is an
+    /// integer.
+    ///
+    /// $$
+    /// f(x, y) = \left ( \operatorname{sgn}(xy) \left \lfloor \left | \frac{x}{y} \right |
+    /// \right \rfloor, \space
+    /// x - y \operatorname{sgn}(xy)
+    /// \left \lfloor \left | \frac{x}{y} \right | \right \rfloor \right ).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivRem;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::Rational;
+    ///
+    /// // 7 * 4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(21, 2))
+    ///         .div_rem(Rational::from_signeds(4, 3))
+    ///         .to_debug_string(),
+    ///     "(7, 7/6)"
+    /// );
+    ///
+    /// // -7 * -4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(21, 2))
+    ///         .div_rem(Rational::from_signeds(-4, 3))
+    ///         .to_debug_string(),
+    ///     "(-7, 7/6)"
+    /// );
+    ///
+    /// // -7 * 4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-21, 2))
+    ///         .div_rem(Rational::from_signeds(4, 3))
+    ///         .to_debug_string(),
+    ///     "(-7, -7/6)"
+    /// );
+    ///
+    /// // 7 * -4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-21, 2))
+    ///         .div_rem(Rational::from_signeds(-4, 3))
+    ///         .to_debug_string(),
+    ///     "(7, -7/6)"
+    /// );
+    /// ```
+    #[inline]
+    fn div_rem(self, other: Rational) -> (Integer, Rational) {
+        self.div_rem(&other)
+    }
+}
+
+impl DivRem<&Rational> for &Rational {
+    type DivOutput = Integer;
+    type RemOutput = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking both by reference and returning the
+    /// quotient and remainder. The quotient is rounded towards zero and the remainder has the same
+    /// sign as the first [`Rational`].
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $0 \leq |r| < |y|$, where $q$ is an
+    /// integer.
+    ///
+    /// $$
+    /// f(x, y) = \left ( \operatorname{sgn}(xy) \left \lfloor \left | \frac{x}{y} \right |
+    /// \right \rfloor, \space
+    /// x - y \operatorname{sgn}(xy)
+    /// \left \lfloor \left | \frac{x}{y} \right | \right \rfloor \right ).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivRem;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::Rational;
+    ///
+    /// // 7 * 4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(21, 2))
+    ///         .div_rem(&Rational::from_signeds(4, 3))
+    ///         .to_debug_string(),
+    ///     "(7, 7/6)"
+    /// );
+    ///
+    /// // -7 * -4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(21, 2))
+    ///         .div_rem(&Rational::from_signeds(-4, 3))
+    ///         .to_debug_string(),
+    ///     "(-7, 7/6)"
+    /// );
+    ///
+    /// // -7 * 4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-21, 2))
+    ///         .div_rem(&Rational::from_signeds(4, 3))
+    ///         .to_debug_string(),
+    ///     "(-7, -7/6)"
+    /// );
+    ///
+    /// // 7 * -4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-21, 2))
+    ///         .div_rem(&Rational::from_signeds(-4, 3))
+    ///         .to_debug_string(),
+    ///     "(7, -7/6)"
+    /// );
+    /// ```
+    fn div_rem(self, other: &Rational) -> (Integer, Rational) {
+        let (n, m, d) = to_common_denominator(self, other);
+        let (q, r) = n.div_rem(m);
+        (q, over_denominator(r, d))
+    }
+}
+
+impl DivAssignRem<Rational> for Rational {
+    type RemOutput = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`] in place, taking the [`Rational`] on the
+    /// right-hand side by value and returning the remainder. The quotient is rounded towards zero
+    /// and the remainder has the same sign as the first [`Rational`]. The quotient, which is an
+    /// integer, replaces the first [`Rational`].
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $0 \leq |r| < |y|$, where $q$ is an
+    /// integer.
+    ///
+    /// $$
+    /// f(x, y) = x - y \operatorname{sgn}(xy)
+    /// \left \lfloor \left | \frac{x}{y} \right | \right \rfloor,
+    /// $$
+    /// $$
+    /// x \gets \operatorname{sgn}(xy) \left \lfloor \left | \frac{x}{y} \right |
+    /// \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivAssignRem;
+    /// use malachite_q::Rational;
+    ///
+    /// // 7 * 4/3 + 7/6 = 21/2
+    /// let mut x = Rational::from_signeds(21, 2);
+    /// assert_eq!(
+    ///     x.div_assign_rem(Rational::from_signeds(4, 3)).to_string(),
+    ///     "7/6"
+    /// );
+    /// assert_eq!(x, 7);
+    ///
+    /// // -7 * -4/3 + 7/6 = 21/2
+    /// let mut x = Rational::from_signeds(21, 2);
+    /// assert_eq!(
+    ///     x.div_assign_rem(Rational::from_signeds(-4, 3)).to_string(),
+    ///     "7/6"
+    /// );
+    /// assert_eq!(x, -7);
+    ///
+    /// // -7 * 4/3 + -7/6 = -21/2
+    /// let mut x = Rational::from_signeds(-21, 2);
+    /// assert_eq!(
+    ///     x.div_assign_rem(Rational::from_signeds(4, 3)).to_string(),
+    ///     "-7/6"
+    /// );
+    /// assert_eq!(x, -7);
+    ///
+    /// // 7 * -4/3 + -7/6 = -21/2
+    /// let mut x = Rational::from_signeds(-21, 2);
+    /// assert_eq!(
+    ///     x.div_assign_rem(Rational::from_signeds(-4, 3)).to_string(),
+    ///     "-7/6"
+    /// );
+    /// assert_eq!(x, 7);
+    /// ```
+    fn div_assign_rem(&mut self, other: Rational) -> Rational {
+        let (q, r) = (&*self).div_rem(other);
+        *self = Rational::from(q);
+        r
+    }
+}
+
+impl DivAssignRem<&Rational> for Rational {
+    type RemOutput = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`] in place, taking the [`Rational`] on the
+    /// right-hand side by reference and returning the remainder. The quotient is rounded towards
+    /// zero and the remainder has the same sign as the first [`Rational`]. The quotient, which is
+    /// an integer, replaces the first [`Rational`].
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $0 \leq |r| < |y|$, where $q$ is an
+    /// integer.
+    ///
+    /// $$
+    /// f(x, y) = x - y \operatorname{sgn}(xy)
+    /// \left \lfloor \left | \frac{x}{y} \right | \right \rfloor,
+    /// $$
+    /// $$
+    /// x \gets \operatorname{sgn}(xy) \left \lfloor \left | \frac{x}{y} \right |
+    /// \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivAssignRem;
+    /// use malachite_q::Rational;
+    ///
+    /// // 7 * 4/3 + 7/6 = 21/2
+    /// let mut x = Rational::from_signeds(21, 2);
+    /// assert_eq!(
+    ///     x.div_assign_rem(&Rational::from_signeds(4, 3)).to_string(),
+    ///     "7/6"
+    /// );
+    /// assert_eq!(x, 7);
+    ///
+    /// // -7 * -4/3 + 7/6 = 21/2
+    /// let mut x = Rational::from_signeds(21, 2);
+    /// assert_eq!(
+    ///     x.div_assign_rem(&Rational::from_signeds(-4, 3)).to_string(),
+    ///     "7/6"
+    /// );
+    /// assert_eq!(x, -7);
+    ///
+    /// // -7 * 4/3 + -7/6 = -21/2
+    /// let mut x = Rational::from_signeds(-21, 2);
+    /// assert_eq!(
+    ///     x.div_assign_rem(&Rational::from_signeds(4, 3)).to_string(),
+    ///     "-7/6"
+    /// );
+    /// assert_eq!(x, -7);
+    ///
+    /// // 7 * -4/3 + -7/6 = -21/2
+    /// let mut x = Rational::from_signeds(-21, 2);
+    /// assert_eq!(
+    ///     x.div_assign_rem(&Rational::from_signeds(-4, 3)).to_string(),
+    ///     "-7/6"
+    /// );
+    /// assert_eq!(x, 7);
+    /// ```
+    fn div_assign_rem(&mut self, other: &Rational) -> Rational {
+        let (q, r) = (&*self).div_rem(other);
+        *self = Rational::from(q);
+        r
+    }
+}
+
+impl CeilingDivMod<Rational> for Rational {
+    type DivOutput = Integer;
+    type ModOutput = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking both by value and returning the
+    /// quotient and remainder. The quotient is rounded towards positive infinity and the remainder
+    /// has the opposite sign as the second [`Rational`].
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $0 \leq |r| < |y|$, where $q$ is an
+    /// integer.
+    ///
+    /// $$
+    /// f(x, y) = \left ( \left \lceil \frac{x}{y} \right \rceil, \space
+    /// x - y\left \lceil \frac{x}{y} \right \rceil \right ).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CeilingDivMod;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::Rational;
+    ///
+    /// // 8 * 4/3 + -1/6 = 21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(21, 2)
+    ///         .ceiling_div_mod(Rational::from_signeds(4, 3))
+    ///         .to_debug_string(),
+    ///     "(8, -1/6)"
+    /// );
+    ///
+    /// // -7 * -4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(21, 2)
+    ///         .ceiling_div_mod(Rational::from_signeds(-4, 3))
+    ///         .to_debug_string(),
+    ///     "(-7, 7/6)"
+    /// );
+    ///
+    /// // -7 * 4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(-21, 2)
+    ///         .ceiling_div_mod(Rational::from_signeds(4, 3))
+    ///         .to_debug_string(),
+    ///     "(-7, -7/6)"
+    /// );
+    ///
+    /// // 8 * -4/3 + 1/6 = -21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(-21, 2)
+    ///         .ceiling_div_mod(Rational::from_signeds(-4, 3))
+    ///         .to_debug_string(),
+    ///     "(8, 1/6)"
+    /// );
+    /// ```
+    #[inline]
+    fn ceiling_div_mod(self, other: Rational) -> (Integer, Rational) {
+        (&self).ceiling_div_mod(&other)
+    }
+}
+
+impl CeilingDivMod<&Rational> for Rational {
+    type DivOutput = Integer;
+    type ModOutput = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking the first by value and the second by
+    /// reference and returning the quotient and remainder. The quotient is rounded towards positive
+    /// infinity and the remainder has the opposite sign as the second [`Rational`].
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $0 \leq |r| < |y|$, where $q$ is an
+    /// integer.
+    ///
+    /// $$
+    /// f(x, y) = \left ( \left \lceil \frac{x}{y} \right \rceil, \space
+    /// x - y\left \lceil \frac{x}{y} \right \rceil \right ).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CeilingDivMod;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::Rational;
+    ///
+    /// // 8 * 4/3 + -1/6 = 21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(21, 2)
+    ///         .ceiling_div_mod(&Rational::from_signeds(4, 3))
+    ///         .to_debug_string(),
+    ///     "(8, -1/6)"
+    /// );
+    ///
+    /// // -7 * -4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(21, 2)
+    ///         .ceiling_div_mod(&Rational::from_signeds(-4, 3))
+    ///         .to_debug_string(),
+    ///     "(-7, 7/6)"
+    /// );
+    ///
+    /// // -7 * 4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(-21, 2)
+    ///         .ceiling_div_mod(&Rational::from_signeds(4, 3))
+    ///         .to_debug_string(),
+    ///     "(-7, -7/6)"
+    /// );
+    ///
+    /// // 8 * -4/3 + 1/6 = -21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(-21, 2)
+    ///         .ceiling_div_mod(&Rational::from_signeds(-4, 3))
+    ///         .to_debug_string(),
+    ///     "(8, 1/6)"
+    /// );
+    /// ```
+    #[inline]
+    fn ceiling_div_mod(self, other: &Rational) -> (Integer, Rational) {
+        (&self).ceiling_div_mod(other)
+    }
+}
+
+impl CeilingDivMod<Rational> for &Rational {
+    type DivOutput = Integer;
+    type ModOutput = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking the first by reference and the second
+    /// by value and returning the quotient and remainder. The quotient is rounded towards positive
+    /// infinity and the remainder has the opposite sign as the second [`Rational`].
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $0 \leq |r| < |y|$, where $q$ is an
+    /// integer.
+    ///
+    /// $$
+    /// f(x, y) = \left ( \left \lceil \frac{x}{y} \right \rceil, \space
+    /// x - y\left \lceil \frac{x}{y} \right \rceil \right ).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CeilingDivMod;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::Rational;
+    ///
+    /// // 8 * 4/3 + -1/6 = 21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(21, 2))
+    ///         .ceiling_div_mod(Rational::from_signeds(4, 3))
+    ///         .to_debug_string(),
+    ///     "(8, -1/6)"
+    /// );
+    ///
+    /// // -7 * -4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(21, 2))
+    ///         .ceiling_div_mod(Rational::from_signeds(-4, 3))
+    ///         .to_debug_string(),
+    ///     "(-7, 7/6)"
+    /// );
+    ///
+    /// // -7 * 4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-21, 2))
+    ///         .ceiling_div_mod(Rational::from_signeds(4, 3))
+    ///         .to_debug_string(),
+    ///     "(-7, -7/6)"
+    /// );
+    ///
+    /// // 8 * -4/3 + 1/6 = -21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-21, 2))
+    ///         .ceiling_div_mod(Rational::from_signeds(-4, 3))
+    ///         .to_debug_string(),
+    ///     "(8, 1/6)"
+    /// );
+    /// ```
+    #[inline]
+    fn ceiling_div_mod(self, other: Rational) -> (Integer, Rational) {
+        self.ceiling_div_mod(&other)
+    }
+}
+
+impl CeilingDivMod<&Rational> for &Rational {
+    type DivOutput = Integer;
+    type ModOutput = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking both by reference and returning the
+    /// quotient and remainder. The quotient is rounded towards positive infinity and the remainder
+    /// has the opposite sign as the second [`Rational`].
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $0 \leq |r| < |y|$, where $q$ is an
+    /// integer.
+    ///
+    /// $$
+    /// f(x, y) = \left ( \left \lceil \frac{x}{y} \right \rceil, \space
+    /// x - y\left \lceil \frac{x}{y} \right \rceil \right ).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CeilingDivMod;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::Rational;
+    ///
+    /// // 8 * 4/3 + -1/6 = 21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(21, 2))
+    ///         .ceiling_div_mod(&Rational::from_signeds(4, 3))
+    ///         .to_debug_string(),
+    ///     "(8, -1/6)"
+    /// );
+    ///
+    /// // -7 * -4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(21, 2))
+    ///         .ceiling_div_mod(&Rational::from_signeds(-4, 3))
+    ///         .to_debug_string(),
+    ///     "(-7, 7/6)"
+    /// );
+    ///
+    /// // -7 * 4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-21, 2))
+    ///         .ceiling_div_mod(&Rational::from_signeds(4, 3))
+    ///         .to_debug_string(),
+    ///     "(-7, -7/6)"
+    /// );
+    ///
+    /// // 8 * -4/3 + 1/6 = -21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-21, 2))
+    ///         .ceiling_div_mod(&Rational::from_signeds(-4, 3))
+    ///         .to_debug_string(),
+    ///     "(8, 1/6)"
+    /// );
+    /// ```
+    fn ceiling_div_mod(self, other: &Rational) -> (Integer, Rational) {
+        let (n, m, d) = to_common_denominator(self, other);
+        let (q, r) = n.ceiling_div_mod(m);
+        (q, over_denominator(r, d))
+    }
+}
+
+impl CeilingDivAssignMod<Rational> for Rational {
+    type ModOutput = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`] in place, taking the [`Rational`] on the
+    /// right-hand side by value and returning the remainder. The quotient is rounded towards
+    /// positive infinity and the remainder has the opposite sign as the second [`Rational`]. The
+    /// quotient, which is an integer, replaces the first [`Rational`].
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $0 \leq |r| < |y|$, where $q$ is an
+    /// integer.
+    ///
+    /// $$
+    /// f(x, y) = x - y\left \lceil \frac{x}{y} \right \rceil,
+    /// $$
+    /// $$
+    /// x \gets \left \lceil \frac{x}{y} \right \rceil.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CeilingDivAssignMod;
+    /// use malachite_q::Rational;
+    ///
+    /// // 8 * 4/3 + -1/6 = 21/2
+    /// let mut x = Rational::from_signeds(21, 2);
+    /// assert_eq!(
+    ///     x.ceiling_div_assign_mod(Rational::from_signeds(4, 3)).to_string(),
+    ///     "-1/6"
+    /// );
+    /// assert_eq!(x, 8);
+    ///
+    /// // -7 * -4/3 + 7/6 = 21/2
+    /// let mut x = Rational::from_signeds(21, 2);
+    /// assert_eq!(
+    ///     x.ceiling_div_assign_mod(Rational::from_signeds(-4, 3)).to_string(),
+    ///     "7/6"
+    /// );
+    /// assert_eq!(x, -7);
+    ///
+    /// // -7 * 4/3 + -7/6 = -21/2
+    /// let mut x = Rational::from_signeds(-21, 2);
+    /// assert_eq!(
+    ///     x.ceiling_div_assign_mod(Rational::from_signeds(4, 3)).to_string(),
+    ///     "-7/6"
+    /// );
+    /// assert_eq!(x, -7);
+    ///
+    /// // 8 * -4/3 + 1/6 = -21/2
+    /// let mut x = Rational::from_signeds(-21, 2);
+    /// assert_eq!(
+    ///     x.ceiling_div_assign_mod(Rational::from_signeds(-4, 3)).to_string(),
+    ///     "1/6"
+    /// );
+    /// assert_eq!(x, 8);
+    /// ```
+    fn ceiling_div_assign_mod(&mut self, other: Rational) -> Rational {
+        let (q, r) = (&*self).ceiling_div_mod(other);
+        *self = Rational::from(q);
+        r
+    }
+}
+
+impl CeilingDivAssignMod<&Rational> for Rational {
+    type ModOutput = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`] in place, taking the [`Rational`] on the
+    /// right-hand side by reference and returning the remainder. The quotient is rounded towards
+    /// positive infinity and the remainder has the opposite sign as the second [`Rational`]. The
+    /// quotient, which is an integer, replaces the first [`Rational`].
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $0 \leq |r| < |y|$, where $q$ is an
+    /// integer.
+    ///
+    /// $$
+    /// f(x, y) = x - y\left \lceil \frac{x}{y} \right \rceil,
+    /// $$
+    /// $$
+    /// x \gets \left \lceil \frac{x}{y} \right \rceil.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CeilingDivAssignMod;
+    /// use malachite_q::Rational;
+    ///
+    /// // 8 * 4/3 + -1/6 = 21/2
+    /// let mut x = Rational::from_signeds(21, 2);
+    /// assert_eq!(
+    ///     x.ceiling_div_assign_mod(&Rational::from_signeds(4, 3)).to_string(),
+    ///     "-1/6"
+    /// );
+    /// assert_eq!(x, 8);
+    ///
+    /// // -7 * -4/3 + 7/6 = 21/2
+    /// let mut x = Rational::from_signeds(21, 2);
+    /// assert_eq!(
+    ///     x.ceiling_div_assign_mod(&Rational::from_signeds(-4, 3)).to_string(),
+    ///     "7/6"
+    /// );
+    /// assert_eq!(x, -7);
+    ///
+    /// // -7 * 4/3 + -7/6 = -21/2
+    /// let mut x = Rational::from_signeds(-21, 2);
+    /// assert_eq!(
+    ///     x.ceiling_div_assign_mod(&Rational::from_signeds(4, 3)).to_string(),
+    ///     "-7/6"
+    /// );
+    /// assert_eq!(x, -7);
+    ///
+    /// // 8 * -4/3 + 1/6 = -21/2
+    /// let mut x = Rational::from_signeds(-21, 2);
+    /// assert_eq!(
+    ///     x.ceiling_div_assign_mod(&Rational::from_signeds(-4, 3)).to_string(),
+    ///     "1/6"
+    /// );
+    /// assert_eq!(x, 8);
+    /// ```
+    fn ceiling_div_assign_mod(&mut self, other: &Rational) -> Rational {
+        let (q, r) = (&*self).ceiling_div_mod(other);
+        *self = Rational::from(q);
+        r
+    }
+}
@@ -0,0 +1,316 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{Gcd, GcdAssign, Lcm, LcmAssign};
+
+impl Gcd<Rational> for Rational {
+    type Output = Rational;
+
+    /// Computes the GCD (greatest common divisor) of two [`Rational`]s, taking both by value.
+    ///
+    /// The GCD of $x$ and $y$ is the largest non-negative [`Rational`] $g$ such that $x/g$ and
+    /// $y/g$ are both integers. If $x = a/b$ and $y = c/d$ in lowest terms, then $\gcd(x, y) =
+    /// \gcd(a, c)/\operatorname{lcm}(b, d)$. The GCD of 0 and $y$ is $|y|$; in particular, $\gcd(0,
+    /// 0) = 0$.
+    ///
+    /// $$
+    /// f(x, y) = \gcd(x, y).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Gcd;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Rational::from_signeds(1, 2)
+    ///         .gcd(Rational::from_signeds(1, 3))
+    ///         .to_string(),
+    ///     "1/6"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(-2, 3)
+    ///         .gcd(Rational::from_signeds(4, 9))
+    ///         .to_string(),
+    ///     "2/9"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from(6)
+    ///         .gcd(Rational::from_signeds(15, 2))
+    ///         .to_string(),
+    ///     "3/2"
+    /// );
+    /// ```
+    #[inline]
+    fn gcd(mut self, other: Rational) -> Rational {
+        self.gcd_assign(other);
+        self
+    }
+}
+
+impl Gcd<&Rational> for Rational {
+    type Output = Rational;
+
+    /// Computes the GCD (greatest common divisor) of two [`Rational`]s, taking the first by value
+    /// and the second by reference.
+    ///
+    /// The GCD of $x$ and $y$ is the largest non-negative [`Rational`] $g$ such that $x/g$ and
+    /// $y/g$ are both integers. If $x = a/b$ and $y = c/d$ in lowest terms, then $\gcd(x, y) =
+    /// \gcd(a, c)/\operatorname{lcm}(b, d)$. The GCD of 0 and $y$ is $|y|$; in particular, $\gcd(0,
+    /// 0) = 0$.
+    ///
+    /// $$
+    /// f(x, y) = \gcd(x, y).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Gcd;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Rational::from_signeds(1, 2)
+    ///         .gcd(&Rational::from_signeds(1, 3))
+    ///         .to_string(),
+    ///     "1/6"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(-2, 3)
+    ///         .gcd(&Rational::from_signeds(4, 9))
+    ///         .to_string(),
+    ///     "2/9"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from(6)
+    ///         .gcd(&Rational::from_signeds(15, 2))
+    ///         .to_string(),
+    ///     "3/2"
+    /// );
+    /// ```
+    #[inline]
+    fn gcd(mut self, other: &Rational) -> Rational {
+        self.gcd_assign(other);
+        self
+    }
+}
+
+impl Gcd<Rational> for &Rational {
+    type Output = Rational;
+
+    /// Computes the GCD (greatest common divisor) of two [`Rational`]s, taking the first by
+    /// reference and the second by value.
+    ///
+    /// The GCD of $x$ and $y$ is the largest non-negative [`Rational`] $g$ such that $x/g$ and
+    /// $y/g$ are both integers. If $x = a/b$ and $y = c/d$ in lowest terms, then $\gcd(x, y) =
+    /// \gcd(a, c)/\operatorname{lcm}(b, d)$. The GCD of 0 and $y$ is $|y|$; in particular, $\gcd(0,
+    /// 0) = 0$.
+    ///
+    /// $$
+    /// f(x, y) = \gcd(x, y).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Gcd;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(1, 2))
+    ///         .gcd(Rational::from_signeds(1, 3))
+    ///         .to_string(),
+    ///     "1/6"
+    /// );
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-2, 3))
+    ///         .gcd(Rational::from_signeds(4, 9))
+    ///         .to_string(),
+    ///     "2/9"
+    /// );
+    /// assert_eq!(
+    ///     (&Rational::from(6))
+    ///         .gcd(Rational::from_signeds(15, 2))
+    ///         .to_string(),
+    ///     "3/2"
+    /// );
+    /// ```
+    #[inline]
+    fn gcd(self, mut other: Rational) -> Rational {
+        other.gcd_assign(self);
+        other
+    }
+}
+
+impl Gcd<&Rational> for &Rational {
+    type Output = Rational;
+
+    /// Computes the GCD (greatest common divisor) of two [`Rational`]s, taking both by reference.
+    ///
+    /// The GCD of $x$ and $y$ is the largest non-negative [`Rational`] $g$ such that $x/g$ and
+    /// $y/g$ are both integers. If $x = a/b$ and $y = c/d$ in lowest terms, then $\gcd(x, y) =
+    /// \gcd(a, c)/\operatorname{lcm}(b, d)$. The GCD of 0 and $y$ is $|y|$; in particular, $\gcd(0,
+    /// 0) = 0$.
+    ///
+    /// $$
+    /// f(x, y) = \gcd(x, y).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Gcd;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(1, 2))
+    ///         .gcd(&Rational::from_signeds(1, 3))
+    ///         .to_string(),
+    ///     "1/6"
+    /// );
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-2, 3))
+    ///         .gcd(&Rational::from_signeds(4, 9))
+    ///         .to_string(),
+    ///     "2/9"
+    /// );
+    /// assert_eq!(
+    ///     (&Rational::from(6))
+    ///         .gcd(&Rational::from_signeds(15, 2))
+    ///         .to_string(),
+    ///     "3/2"
+    /// );
+    /// ```
+    fn gcd(self, other: &Rational) -> Rational {
+        Rational {
+            sign: true,
+            numerator: (&self.numerator).gcd(&other.numerator),
+            denominator: (&self.denominator).lcm(&other.denominator),
+        }
+    }
+}
+
+impl GcdAssign<Rational> for Rational {
+    /// Replaces a [`Rational`] by its GCD (greatest common divisor) with another [`Rational`],
+    /// taking the [`Rational`] on the right-hand side by value.
+    ///
+    /// The GCD of $x$ and $y$ is the largest non-negative [`Rational`] $g$ such that $x/g$ and
+    /// $y/g$ are both integers. If $x = a/b$ and $y = c/d$ in lowest terms, then $\gcd(x, y) =
+    /// \gcd(a, c)/\operatorname{lcm}(b, d)$. The GCD of 0 and $y$ is $|y|$; in particular, $\gcd(0,
+    /// 0) = 0$.
+    ///
+    /// $$
+    /// x \gets \gcd(x, y).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::GcdAssign;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = Rational::from_signeds(1, 2);
+    /// x.gcd_assign(Rational::from_signeds(1, 3));
+    /// assert_eq!(x.to_string(), "1/6");
+    ///
+    /// let mut x = Rational::from_signeds(-2, 3);
+    /// x.gcd_assign(Rational::from_signeds(4, 9));
+    /// assert_eq!(x.to_string(), "2/9");
+    ///
+    /// let mut x = Rational::from(6);
+    /// x.gcd_assign(Rational::from_signeds(15, 2));
+    /// assert_eq!(x.to_string(), "3/2");
+    /// ```
+    fn gcd_assign(&mut self, other: Rational) {
+        self.sign = true;
+        self.numerator.gcd_assign(other.numerator);
+        self.denominator.lcm_assign(other.denominator);
+    }
+}
+
+impl GcdAssign<&Rational> for Rational {
+    /// Replaces a [`Rational`] by its GCD (greatest common divisor) with another [`Rational`],
+    /// taking the [`Rational`] on the right-hand side by reference.
+    ///
+    /// The GCD of $x$ and $y$ is the largest non-negative [`Rational`] $g$ such that $x/g$ and
+    /// $y/g$ are both integers. If $x = a/b$ and $y = c/d$ in lowest terms, then $\gcd(x, y) =
+    /// \gcd(a, c)/\operatorname{lcm}(b, d)$. The GCD of 0 and $y$ is $|y|$; in particular, $\gcd(0,
+    /// 0) = 0$.
+    ///
+    /// $$
+    /// x \gets \gcd(x, y).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::GcdAssign;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = Rational::from_signeds(1, 2);
+    /// x.gcd_assign(&Rational::from_signeds(1, 3));
+    /// assert_eq!(x.to_string(), "1/6");
+    ///
+    /// let mut x = Rational::from_signeds(-2, 3);
+    /// x.gcd_assign(&Rational::from_signeds(4, 9));
+    /// assert_eq!(x.to_string(), "2/9");
+    ///
+    /// let mut x = Rational::from(6);
+    /// x.gcd_assign(&Rational::from_signeds(15, 2));
+    /// assert_eq!(x.to_string(), "3/2");
+    /// ```
+    fn gcd_assign(&mut self, other: &Rational) {
+        self.sign = true;
+        self.numerator.gcd_assign(&other.numerator);
+        self.denominator.lcm_assign(&other.denominator);
+    }
+}
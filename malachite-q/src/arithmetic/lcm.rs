@@ -0,0 +1,310 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{Gcd, GcdAssign, Lcm, LcmAssign};
+
+impl Lcm<Rational> for Rational {
+    type Output = Rational;
+
+    /// Computes the LCM (least common multiple) of two [`Rational`]s, taking both by value.
+    ///
+    /// The LCM of $x$ and $y$ is the smallest non-negative [`Rational`] that is an integer multiple
+    /// of both $x$ and $y$. If $x = a/b$ and $y = c/d$ in lowest terms, then $\operatorname{lcm}(x,
+    /// y) = \operatorname{lcm}(a, c)/\gcd(b, d)$. If either $x$ or $y$ is 0, the LCM is 0.
+    ///
+    /// $$
+    /// f(x, y) = \operatorname{lcm}(x, y).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Lcm;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Rational::from_signeds(1, 2)
+    ///         .lcm(Rational::from_signeds(1, 3))
+    ///         .to_string(),
+    ///     "1"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(-2, 3)
+    ///         .lcm(Rational::from_signeds(4, 9))
+    ///         .to_string(),
+    ///     "4/3"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from(6)
+    ///         .lcm(Rational::from_signeds(15, 2))
+    ///         .to_string(),
+    ///     "30"
+    /// );
+    /// ```
+    #[inline]
+    fn lcm(mut self, other: Rational) -> Rational {
+        self.lcm_assign(other);
+        self
+    }
+}
+
+impl Lcm<&Rational> for Rational {
+    type Output = Rational;
+
+    /// Computes the LCM (least common multiple) of two [`Rational`]s, taking the first by value and
+    /// the second by reference.
+    ///
+    /// The LCM of $x$ and $y$ is the smallest non-negative [`Rational`] that is an integer multiple
+    /// of both $x$ and $y$. If $x = a/b$ and $y = c/d$ in lowest terms, then $\operatorname{lcm}(x,
+    /// y) = \operatorname{lcm}(a, c)/\gcd(b, d)$. If either $x$ or $y$ is 0, the LCM is 0.
+    ///
+    /// $$
+    /// f(x, y) = \operatorname{lcm}(x, y).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Lcm;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Rational::from_signeds(1, 2)
+    ///         .lcm(&Rational::from_signeds(1, 3))
+    ///         .to_string(),
+    ///     "1"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(-2, 3)
+    ///         .lcm(&Rational::from_signeds(4, 9))
+    ///         .to_string(),
+    ///     "4/3"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from(6)
+    ///         .lcm(&Rational::from_signeds(15, 2))
+    ///         .to_string(),
+    ///     "30"
+    /// );
+    /// ```
+    #[inline]
+    fn lcm(mut self, other: &Rational) -> Rational {
+        self.lcm_assign(other);
+        self
+    }
+}
+
+impl Lcm<Rational> for &Rational {
+    type Output = Rational;
+
+    /// Computes the LCM (least common multiple) of two [`Rational`]s, taking the first by reference
+    /// and the second by value.
+    ///
+    /// The LCM of $x$ and $y$ is the smallest non-negative [`Rational`] that is an integer multiple
+    /// of both $x$ and $y$. If $x = a/b$ and $y = c/d$ in lowest terms, then $\operatorname{lcm}(x,
+    /// y) = \operatorname{lcm}(a, c)/\gcd(b, d)$. If either $x$ or $y$ is 0, the LCM is 0.
+    ///
+    /// $$
+    /// f(x, y) = \operatorname{lcm}(x, y).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Lcm;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(1, 2))
+    ///         .lcm(Rational::from_signeds(1, 3))
+    ///         .to_string(),
+    ///     "1"
+    /// );
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-2, 3))
+    ///         .lcm(Rational::from_signeds(4, 9))
+    ///         .to_string(),
+    ///     "4/3"
+    /// );
+    /// assert_eq!(
+    ///     (&Rational::from(6))
+    ///         .lcm(Rational::from_signeds(15, 2))
+    ///         .to_string(),
+    ///     "30"
+    /// );
+    /// ```
+    #[inline]
+    fn lcm(self, mut other: Rational) -> Rational {
+        other.lcm_assign(self);
+        other
+    }
+}
+
+impl Lcm<&Rational> for &Rational {
+    type Output = Rational;
+
+    /// Computes the LCM (least common multiple) of two [`Rational`]s, taking both by reference.
+    ///
+    /// The LCM of $x$ and $y$ is the smallest non-negative [`Rational`] that is an integer multiple
+    /// of both $x$ and $y$. If $x = a/b$ and $y = c/d$ in lowest terms, then $\operatorname{lcm}(x,
+    /// y) = \operatorname{lcm}(a, c)/\gcd(b, d)$. If either $x$ or $y$ is 0, the LCM is 0.
+    ///
+    /// $$
+    /// f(x, y) = \operatorname{lcm}(x, y).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Lcm;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(1, 2))
+    ///         .lcm(&Rational::from_signeds(1, 3))
+    ///         .to_string(),
+    ///     "1"
+    /// );
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-2, 3))
+    ///         .lcm(&Rational::from_signeds(4, 9))
+    ///         .to_string(),
+    ///     "4/3"
+    /// );
+    /// assert_eq!(
+    ///     (&Rational::from(6))
+    ///         .lcm(&Rational::from_signeds(15, 2))
+    ///         .to_string(),
+    ///     "30"
+    /// );
+    /// ```
+    fn lcm(self, other: &Rational) -> Rational {
+        Rational {
+            sign: true,
+            numerator: (&self.numerator).lcm(&other.numerator),
+            denominator: (&self.denominator).gcd(&other.denominator),
+        }
+    }
+}
+
+impl LcmAssign<Rational> for Rational {
+    /// Replaces a [`Rational`] by its LCM (least common multiple) with another [`Rational`], taking
+    /// the [`Rational`] on the right-hand side by value.
+    ///
+    /// The LCM of $x$ and $y$ is the smallest non-negative [`Rational`] that is an integer multiple
+    /// of both $x$ and $y$. If $x = a/b$ and $y = c/d$ in lowest terms, then $\operatorname{lcm}(x,
+    /// y) = \operatorname{lcm}(a, c)/\gcd(b, d)$. If either $x$ or $y$ is 0, the LCM is 0.
+    ///
+    /// $$
+    /// x \gets \operatorname{lcm}(x, y).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::LcmAssign;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = Rational::from_signeds(1, 2);
+    /// x.lcm_assign(Rational::from_signeds(1, 3));
+    /// assert_eq!(x.to_string(), "1");
+    ///
+    /// let mut x = Rational::from_signeds(-2, 3);
+    /// x.lcm_assign(Rational::from_signeds(4, 9));
+    /// assert_eq!(x.to_string(), "4/3");
+    ///
+    /// let mut x = Rational::from(6);
+    /// x.lcm_assign(Rational::from_signeds(15, 2));
+    /// assert_eq!(x.to_string(), "30");
+    /// ```
+    fn lcm_assign(&mut self, other: Rational) {
+        self.sign = true;
+        self.numerator.lcm_assign(other.numerator);
+        self.denominator.gcd_assign(other.denominator);
+    }
+}
+
+impl LcmAssign<&Rational> for Rational {
+    /// Replaces a [`Rational`] by its LCM (least common multiple) with another [`Rational`], taking
+    /// the [`Rational`] on the right-hand side by reference.
+    ///
+    /// The LCM of $x$ and $y$ is the smallest non-negative [`Rational`] that is an integer multiple
+    /// of both $x$ and $y$. If $x = a/b$ and $y = c/d$ in lowest terms, then $\operatorname{lcm}(x,
+    /// y) = \operatorname{lcm}(a, c)/\gcd(b, d)$. If either $x$ or $y$ is 0, the LCM is 0.
+    ///
+    /// $$
+    /// x \gets \operatorname{lcm}(x, y).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::LcmAssign;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = Rational::from_signeds(1, 2);
+    /// x.lcm_assign(&Rational::from_signeds(1, 3));
+    /// assert_eq!(x.to_string(), "1");
+    ///
+    /// let mut x = Rational::from_signeds(-2, 3);
+    /// x.lcm_assign(&Rational::from_signeds(4, 9));
+    /// assert_eq!(x.to_string(), "4/3");
+    ///
+    /// let mut x = Rational::from(6);
+    /// x.lcm_assign(&Rational::from_signeds(15, 2));
+    /// assert_eq!(x.to_string(), "30");
+    /// ```
+    fn lcm_assign(&mut self, other: &Rational) {
+        self.sign = true;
+        self.numerator.lcm_assign(&other.numerator);
+        self.denominator.gcd_assign(&other.denominator);
+    }
+}
@@ -18,6 +18,10 @@ pub mod add;
 /// [`ApproximateAssign`](traits::ApproximateAssign), traits for approximating a
 /// [`Rational`](super::Rational) by a [`Rational`](super::Rational) with a bounded denominator.
 pub mod approximate;
+/// An implementation of [`BestApproximations`](traits::BestApproximations), a trait for finding
+/// the best approximations of a [`Rational`](super::Rational) subject to a bound on their size or
+/// distance.
+pub mod best_approximations;
 /// Implementations of [`Ceiling`](malachite_base::num::arithmetic::traits::Ceiling) and
 /// [`CeilingAssign`](malachite_base::num::arithmetic::traits::CeilingAssign), traits for taking the
 /// ceiling of a number.
@@ -27,13 +31,33 @@ pub mod ceiling;
 pub mod denominators_in_closed_interval;
 /// Division of [`Rational`](super::Rational)s.
 pub mod div;
+/// Implementations of traits for simultaneously finding the quotient and remainder of two
+/// [`Rational`](super::Rational)s, subject to various rounding rules. The quotient is always an
+/// [`Integer`](malachite_nz::integer::Integer).
+///
+/// These are the traits:
+///
+/// | rounding          | by value or reference           | by mutable reference (assignment)      |
+/// |-------------------|---------------------------------|----------------------------------------|
+/// | towards $-\infty$ | [`DivMod`](malachite_base::num::arithmetic::traits::DivMod) | [`DivAssignMod`](malachite_base::num::arithmetic::traits::DivAssignMod) |
+/// | towards 0         | [`DivRem`](malachite_base::num::arithmetic::traits::DivRem) | [`DivAssignRem`](malachite_base::num::arithmetic::traits::DivAssignRem) |
+/// | towards $\infty$  | [`CeilingDivMod`](malachite_base::num::arithmetic::traits::CeilingDivMod) | [`CeilingDivAssignMod`](malachite_base::num::arithmetic::traits::CeilingDivAssignMod) |
+pub mod div_mod;
 /// Implementations of [`Floor`](malachite_base::num::arithmetic::traits::Floor) and
 /// [`FloorAssign`](malachite_base::num::arithmetic::traits::FloorAssign), traits for taking the
 /// floor of a number.
 pub mod floor;
+/// Implementations of [`Gcd`](malachite_base::num::arithmetic::traits::Gcd) and
+/// [`GcdAssign`](malachite_base::num::arithmetic::traits::GcdAssign), traits for computing the GCD
+/// (greatest common divisor) of two numbers.
+pub mod gcd;
 /// An implementation of [`IsPowerOf2`](malachite_base::num::arithmetic::traits::IsPowerOf2), a
 /// trait for determining whether a number is an integer power of 2.
 pub mod is_power_of_2;
+/// Implementations of [`Lcm`](malachite_base::num::arithmetic::traits::Lcm) and
+/// [`LcmAssign`](malachite_base::num::arithmetic::traits::LcmAssign), traits for computing the LCM
+/// (least common multiple) of two numbers.
+pub mod lcm;
 /// Implementations of traits for taking the base-$b$ logarithm of a number.
 ///
 /// The traits are [`FloorLogBase`](malachite_base::num::arithmetic::traits::FloorLogBase),
@@ -53,6 +77,18 @@ pub mod log_base_2;
 /// [`CeilingLogBasePowerOf2`](malachite_base::num::arithmetic::traits::CeilingLogBasePowerOf2), and
 /// [`CheckedLogBasePowerOf2`](malachite_base::num::arithmetic::traits::CheckedLogBasePowerOf2).
 pub mod log_base_power_of_2;
+/// Implementations of traits for finding the remainder of two [`Rational`](super::Rational)s,
+/// subject to various rounding rules.
+///
+/// These are the traits:
+///
+/// | rounding          | by value or reference      | by mutable reference (assignment)      |
+/// |-------------------|----------------------------|----------------------------------------|
+/// | towards $-\infty$ | [`Mod`](malachite_base::num::arithmetic::traits::Mod)       | [`ModAssign`](malachite_base::num::arithmetic::traits::ModAssign)       |
+/// | towards $\infty$  | [`CeilingMod`](malachite_base::num::arithmetic::traits::CeilingMod) | [`CeilingModAssign`](malachite_base::num::arithmetic::traits::CeilingModAssign) |
+///
+/// The [`Rem`](core::ops::Rem) trait in the standard library rounds towards 0.
+pub mod mod_op;
 /// Multiplication of [`Rational`](super::Rational)s.
 pub mod mul;
 /// Negation of [`Rational`](super::Rational)s.
@@ -0,0 +1,1058 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Rational;
+use crate::arithmetic::div_mod::{over_denominator, to_common_denominator};
+use core::ops::{Rem, RemAssign};
+use malachite_base::num::arithmetic::traits::{CeilingMod, CeilingModAssign, Mod, ModAssign};
+
+impl Mod<Rational> for Rational {
+    type Output = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking both by value and returning just the
+    /// remainder. The remainder has the same sign as the second [`Rational`].
+    ///
+    /// If the quotient were computed, the quotient and remainder would satisfy $x = qy + r$ and $0
+    /// \leq |r| < |y|$, where $q$ is an integer.
+    ///
+    /// $$
+    /// f(x, y) = x - y\left \lfloor \frac{x}{y} \right \rfloor.
+    /// $$
+    ///
+    /// This function is called `mod_op` rather than `mod` because `mod` is a Rust keyword.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Mod;
+    /// use malachite_q::Rational;
+    ///
+    /// // 7 * 4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(21, 2).mod_op(Rational::from_signeds(4, 3)).to_string(),
+    ///     "7/6"
+    /// );
+    ///
+    /// // -8 * -4/3 + -1/6 = 21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(21, 2).mod_op(Rational::from_signeds(-4, 3)).to_string(),
+    ///     "-1/6"
+    /// );
+    ///
+    /// // -8 * 4/3 + 1/6 = -21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(-21, 2).mod_op(Rational::from_signeds(4, 3)).to_string(),
+    ///     "1/6"
+    /// );
+    ///
+    /// // 7 * -4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(-21, 2).mod_op(Rational::from_signeds(-4, 3)).to_string(),
+    ///     "-7/6"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_op(self, other: Rational) -> Rational {
+        (&self).mod_op(&other)
+    }
+}
+
+impl Mod<&Rational> for Rational {
+    type Output = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking the first by value and the second by
+    /// reference and returning just the remainder. The remainder has the same sign as the second
+    /// [`Rational`].
+    ///
+    /// If the quotient were computed, the quotient and remainder would satisfy $x = qy + r$ and $0
+    /// \leq |r| < |y|$, where $q$ is an integer.
+    ///
+    /// $$
+    /// f(x, y) = x - y\left \lfloor \frac{x}{y} \right \rfloor.
+    /// $$
+    ///
+    /// This function is called `mod_op` rather than `mod` because `mod` is a Rust keyword.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Mod;
+    /// use malachite_q::Rational;
+    ///
+    /// // 7 * 4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(21, 2).mod_op(&Rational::from_signeds(4, 3)).to_string(),
+    ///     "7/6"
+    /// );
+    ///
+    /// // -8 * -4/3 + -1/6 = 21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(21, 2).mod_op(&Rational::from_signeds(-4, 3)).to_string(),
+    ///     "-1/6"
+    /// );
+    ///
+    /// // -8 * 4/3 + 1/6 = -21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(-21, 2).mod_op(&Rational::from_signeds(4, 3)).to_string(),
+    ///     "1/6"
+    /// );
+    ///
+    /// // 7 * -4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(-21, 2).mod_op(&Rational::from_signeds(-4, 3)).to_string(),
+    ///     "-7/6"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_op(self, other: &Rational) -> Rational {
+        (&self).mod_op(other)
+    }
+}
+
+impl Mod<Rational> for &Rational {
+    type Output = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking the first by reference and the second
+    /// by value and returning just the remainder. The remainder has the same sign as the second
+    /// [`Rational`].
+    ///
+    /// If the quotient were computed, the quotient and remainder would satisfy $x = qy + r$ and $0
+    /// \leq |r| < |y|$, where $q$ is an integer.
+    ///
+    /// $$
+    /// f(x, y) = x - y\left \lfloor \frac{x}{y} \right \rfloor.
+    /// $$
+    ///
+    /// This function is called `mod_op` rather than `mod` because `mod` is a Rust keyword.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Mod;
+    /// use malachite_q::Rational;
+    ///
+    /// // 7 * 4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(21, 2)).mod_op(Rational::from_signeds(4, 3)).to_string(),
+    ///     "7/6"
+    /// );
+    ///
+    /// // -8 * -4/3 + -1/6 = 21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(21, 2)).mod_op(Rational::from_signeds(-4, 3)).to_string(),
+    ///     "-1/6"
+    /// );
+    ///
+    /// // -8 * 4/3 + 1/6 = -21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-21, 2)).mod_op(Rational::from_signeds(4, 3)).to_string(),
+    ///     "1/6"
+    /// );
+    ///
+    /// // 7 * -4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-21, 2)).mod_op(Rational::from_signeds(-4, 3)).to_string(),
+    ///     "-7/6"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_op(self, other: Rational) -> Rational {
+        self.mod_op(&other)
+    }
+}
+
+impl Mod<&Rational> for &Rational {
+    type Output = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking both by reference and returning just
+    /// the remainder. The remainder has the same sign as the second [`Rational`].
+    ///
+    /// If the quotient were computed, the quotient and remainder would satisfy $x = qy + r$ and $0
+    /// \leq |r| < |y|$, where $q$ is an integer.
+    ///
+    /// $$
+    /// f(x, y) = x - y\left \lfloor \frac{x}{y} \right \rfloor.
+    /// $$
+    ///
+    /// This function is called `mod_op` rather than `mod` because `mod` is a Rust keyword.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Mod;
+    /// use malachite_q::Rational;
+    ///
+    /// // 7 * 4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(21, 2)).mod_op(&Rational::from_signeds(4, 3)).to_string(),
+    ///     "7/6"
+    /// );
+    ///
+    /// // -8 * -4/3 + -1/6 = 21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(21, 2)).mod_op(&Rational::from_signeds(-4, 3)).to_string(),
+    ///     "-1/6"
+    /// );
+    ///
+    /// // -8 * 4/3 + 1/6 = -21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-21, 2)).mod_op(&Rational::from_signeds(4, 3)).to_string(),
+    ///     "1/6"
+    /// );
+    ///
+    /// // 7 * -4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-21, 2)).mod_op(&Rational::from_signeds(-4, 3)).to_string(),
+    ///     "-7/6"
+    /// );
+    /// ```
+    fn mod_op(self, other: &Rational) -> Rational {
+        let (n, m, d) = to_common_denominator(self, other);
+        over_denominator(n.mod_op(m), d)
+    }
+}
+
+impl ModAssign<Rational> for Rational {
+    /// Divides a [`Rational`] by another [`Rational`], taking the second [`Rational`] by value and
+    /// replacing the first by the remainder. The remainder has the same sign as the second
+    /// [`Rational`].
+    ///
+    /// If the quotient were computed, the quotient and remainder would satisfy $x = qy + r$ and $0
+    /// \leq |r| < |y|$, where $q$ is an integer.
+    ///
+    /// $$
+    /// x \gets x - y\left \lfloor \frac{x}{y} \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModAssign;
+    /// use malachite_q::Rational;
+    ///
+    /// // 7 * 4/3 + 7/6 = 21/2
+    /// let mut x = Rational::from_signeds(21, 2);
+    /// x.mod_assign(Rational::from_signeds(4, 3));
+    /// assert_eq!(x.to_string(), "7/6");
+    ///
+    /// // -8 * -4/3 + -1/6 = 21/2
+    /// let mut x = Rational::from_signeds(21, 2);
+    /// x.mod_assign(Rational::from_signeds(-4, 3));
+    /// assert_eq!(x.to_string(), "-1/6");
+    ///
+    /// // -8 * 4/3 + 1/6 = -21/2
+    /// let mut x = Rational::from_signeds(-21, 2);
+    /// x.mod_assign(Rational::from_signeds(4, 3));
+    /// assert_eq!(x.to_string(), "1/6");
+    ///
+    /// // 7 * -4/3 + -7/6 = -21/2
+    /// let mut x = Rational::from_signeds(-21, 2);
+    /// x.mod_assign(Rational::from_signeds(-4, 3));
+    /// assert_eq!(x.to_string(), "-7/6");
+    /// ```
+    #[inline]
+    fn mod_assign(&mut self, other: Rational) {
+        *self = (&*self).mod_op(other);
+    }
+}
+
+impl ModAssign<&Rational> for Rational {
+    /// Divides a [`Rational`] by another [`Rational`], taking the second [`Rational`] by reference
+    /// and replacing the first by the remainder. The remainder has the same sign as the second
+    /// [`Rational`].
+    ///
+    /// If the quotient were computed, the quotient and remainder would satisfy $x = qy + r$ and $0
+    /// \leq |r| < |y|$, where $q$ is an integer.
+    ///
+    /// $$
+    /// x \gets x - y\left \lfloor \frac{x}{y} \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModAssign;
+    /// use malachite_q::Rational;
+    ///
+    /// // 7 * 4/3 + 7/6 = 21/2
+    /// let mut x = Rational::from_signeds(21, 2);
+    /// x.mod_assign(&Rational::from_signeds(4, 3));
+    /// assert_eq!(x.to_string(), "7/6");
+    ///
+    /// // -8 * -4/3 + -1/6 = 21/2
+    /// let mut x = Rational::from_signeds(21, 2);
+    /// x.mod_assign(&Rational::from_signeds(-4, 3));
+    /// assert_eq!(x.to_string(), "-1/6");
+    ///
+    /// // -8 * 4/3 + 1/6 = -21/2
+    /// let mut x = Rational::from_signeds(-21, 2);
+    /// x.mod_assign(&Rational::from_signeds(4, 3));
+    /// assert_eq!(x.to_string(), "1/6");
+    ///
+    /// // 7 * -4/3 + -7/6 = -21/2
+    /// let mut x = Rational::from_signeds(-21, 2);
+    /// x.mod_assign(&Rational::from_signeds(-4, 3));
+    /// assert_eq!(x.to_string(), "-7/6");
+    /// ```
+    #[inline]
+    fn mod_assign(&mut self, other: &Rational) {
+        *self = (&*self).mod_op(other);
+    }
+}
+
+impl Rem<Rational> for Rational {
+    type Output = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking both by value and returning just the
+    /// remainder. The remainder has the same sign as the first [`Rational`].
+    ///
+    /// If the quotient were computed, the quotient and remainder would satisfy $x = qy + r$ and $0
+    /// \leq |r| < |y|$, where $q$ is an integer.
+    ///
+    /// $$
+    /// f(x, y) = x - y \operatorname{sgn}(xy)
+    ///     \left \lfloor \left | \frac{x}{y} \right | \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// // 7 * 4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     (Rational::from_signeds(21, 2) % Rational::from_signeds(4, 3)).to_string(),
+    ///     "7/6"
+    /// );
+    ///
+    /// // -7 * -4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     (Rational::from_signeds(21, 2) % Rational::from_signeds(-4, 3)).to_string(),
+    ///     "7/6"
+    /// );
+    ///
+    /// // -7 * 4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     (Rational::from_signeds(-21, 2) % Rational::from_signeds(4, 3)).to_string(),
+    ///     "-7/6"
+    /// );
+    ///
+    /// // 7 * -4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     (Rational::from_signeds(-21, 2) % Rational::from_signeds(-4, 3)).to_string(),
+    ///     "-7/6"
+    /// );
+    /// ```
+    #[inline]
+    fn rem(self, other: Rational) -> Rational {
+        &self % &other
+    }
+}
+
+impl Rem<&Rational> for Rational {
+    type Output = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking the first by value and the second by
+    /// reference and returning just the remainder. The remainder has the same sign as the first
+    /// [`Rational`].
+    ///
+    /// If the quotient were computed, the quotient and remainder would satisfy $x = qy + r$ and $0
+    /// \leq |r| < |y|$, where $q$ is an integer.
+    ///
+    /// $$
+    /// f(x, y) = x - y \operatorname{sgn}(xy)
+    ///     \left \lfloor \left | \frac{x}{y} \right | \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// // 7 * 4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     (Rational::from_signeds(21, 2) % &Rational::from_signeds(4, 3)).to_string(),
+    ///     "7/6"
+    /// );
+    ///
+    /// // -7 * -4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     (Rational::from_signeds(21, 2) % &Rational::from_signeds(-4, 3)).to_string(),
+    ///     "7/6"
+    /// );
+    ///
+    /// // -7 * 4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     (Rational::from_signeds(-21, 2) % &Rational::from_signeds(4, 3)).to_string(),
+    ///     "-7/6"
+    /// );
+    ///
+    /// // 7 * -4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     (Rational::from_signeds(-21, 2) % &Rational::from_signeds(-4, 3)).to_string(),
+    ///     "-7/6"
+    /// );
+    /// ```
+    #[inline]
+    fn rem(self, other: &Rational) -> Rational {
+        &self % other
+    }
+}
+
+impl Rem<Rational> for &Rational {
+    type Output = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking the first by reference and the second
+    /// by value and returning just the remainder. The remainder has the same sign as the first
+    /// [`Rational`].
+    ///
+    /// If the quotient were computed, the quotient and remainder would satisfy $x = qy + r$ and $0
+    /// \leq |r| < |y|$, where $q$ is an integer.
+    ///
+    /// $$
+    /// f(x, y) = x - y \operatorname{sgn}(xy)
+    ///     \left \lfloor \left | \frac{x}{y} \right | \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// // 7 * 4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(21, 2) % Rational::from_signeds(4, 3)).to_string(),
+    ///     "7/6"
+    /// );
+    ///
+    /// // -7 * -4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(21, 2) % Rational::from_signeds(-4, 3)).to_string(),
+    ///     "7/6"
+    /// );
+    ///
+    /// // -7 * 4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-21, 2) % Rational::from_signeds(4, 3)).to_string(),
+    ///     "-7/6"
+    /// );
+    ///
+    /// // 7 * -4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-21, 2) % Rational::from_signeds(-4, 3)).to_string(),
+    ///     "-7/6"
+    /// );
+    /// ```
+    #[inline]
+    fn rem(self, other: Rational) -> Rational {
+        self % &other
+    }
+}
+
+impl Rem<&Rational> for &Rational {
+    type Output = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking both by reference and returning just
+    /// the remainder. The remainder has the same sign as the first [`Rational`].
+    ///
+    /// If the quotient were computed, the quotient and remainder would satisfy $x = qy + r$ and $0
+    /// \leq |r| < |y|$, where $q$ is an integer.
+    ///
+    /// $$
+    /// f(x, y) = x - y \operatorname{sgn}(xy)
+    ///     \left \lfloor \left | \frac{x}{y} \right | \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// // 7 * 4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(21, 2) % &Rational::from_signeds(4, 3)).to_string(),
+    ///     "7/6"
+    /// );
+    ///
+    /// // -7 * -4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(21, 2) % &Rational::from_signeds(-4, 3)).to_string(),
+    ///     "7/6"
+    /// );
+    ///
+    /// // -7 * 4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-21, 2) % &Rational::from_signeds(4, 3)).to_string(),
+    ///     "-7/6"
+    /// );
+    ///
+    /// // 7 * -4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-21, 2) % &Rational::from_signeds(-4, 3)).to_string(),
+    ///     "-7/6"
+    /// );
+    /// ```
+    fn rem(self, other: &Rational) -> Rational {
+        let (n, m, d) = to_common_denominator(self, other);
+        over_denominator(n % m, d)
+    }
+}
+
+impl RemAssign<Rational> for Rational {
+    /// Divides a [`Rational`] by another [`Rational`], taking the second [`Rational`] by value and
+    /// replacing the first by the remainder. The remainder has the same sign as the first
+    /// [`Rational`].
+    ///
+    /// If the quotient were computed, the quotient and remainder would satisfy $x = qy + r$ and $0
+    /// \leq |r| < |y|$, where $q$ is an integer.
+    ///
+    /// $$
+    /// x \gets x - y \operatorname{sgn}(xy)
+    ///     \left \lfloor \left | \frac{x}{y} \right | \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// // 7 * 4/3 + 7/6 = 21/2
+    /// let mut x = Rational::from_signeds(21, 2);
+    /// x %= Rational::from_signeds(4, 3);
+    /// assert_eq!(x.to_string(), "7/6");
+    ///
+    /// // -7 * -4/3 + 7/6 = 21/2
+    /// let mut x = Rational::from_signeds(21, 2);
+    /// x %= Rational::from_signeds(-4, 3);
+    /// assert_eq!(x.to_string(), "7/6");
+    ///
+    /// // -7 * 4/3 + -7/6 = -21/2
+    /// let mut x = Rational::from_signeds(-21, 2);
+    /// x %= Rational::from_signeds(4, 3);
+    /// assert_eq!(x.to_string(), "-7/6");
+    ///
+    /// // 7 * -4/3 + -7/6 = -21/2
+    /// let mut x = Rational::from_signeds(-21, 2);
+    /// x %= Rational::from_signeds(-4, 3);
+    /// assert_eq!(x.to_string(), "-7/6");
+    /// ```
+    #[inline]
+    fn rem_assign(&mut self, other: Rational) {
+        *self = &*self % other;
+    }
+}
+
+impl RemAssign<&Rational> for Rational {
+    /// Divides a [`Rational`] by another [`Rational`], taking the second [`Rational`] by reference
+    /// and replacing the first by the remainder. The remainder has the same sign as the first
+    /// [`Rational`].
+    ///
+    /// If the quotient were computed, the quotient and remainder would satisfy $x = qy + r$ and $0
+    /// \leq |r| < |y|$, where $q$ is an integer.
+    ///
+    /// $$
+    /// x \gets x - y \operatorname{sgn}(xy)
+    ///     \left \lfloor \left | \frac{x}{y} \right | \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// // 7 * 4/3 + 7/6 = 21/2
+    /// let mut x = Rational::from_signeds(21, 2);
+    /// x %= &Rational::from_signeds(4, 3);
+    /// assert_eq!(x.to_string(), "7/6");
+    ///
+    /// // -7 * -4/3 + 7/6 = 21/2
+    /// let mut x = Rational::from_signeds(21, 2);
+    /// x %= &Rational::from_signeds(-4, 3);
+    /// assert_eq!(x.to_string(), "7/6");
+    ///
+    /// // -7 * 4/3 + -7/6 = -21/2
+    /// let mut x = Rational::from_signeds(-21, 2);
+    /// x %= &Rational::from_signeds(4, 3);
+    /// assert_eq!(x.to_string(), "-7/6");
+    ///
+    /// // 7 * -4/3 + -7/6 = -21/2
+    /// let mut x = Rational::from_signeds(-21, 2);
+    /// x %= &Rational::from_signeds(-4, 3);
+    /// assert_eq!(x.to_string(), "-7/6");
+    /// ```
+    #[inline]
+    fn rem_assign(&mut self, other: &Rational) {
+        *self = &*self % other;
+    }
+}
+
+impl CeilingMod<Rational> for Rational {
+    type Output = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking both by value and returning just the
+    /// remainder. The remainder has the opposite sign as the second [`Rational`].
+    ///
+    /// If the quotient were computed, the quotient and remainder would satisfy $x = qy + r$ and $0
+    /// \leq |r| < |y|$, where $q$ is an integer.
+    ///
+    /// $$
+    /// f(x, y) = x - y\left \lceil \frac{x}{y} \right \rceil.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CeilingMod;
+    /// use malachite_q::Rational;
+    ///
+    /// // 8 * 4/3 + -1/6 = 21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(21, 2).ceiling_mod(Rational::from_signeds(4, 3)).to_string(),
+    ///     "-1/6"
+    /// );
+    ///
+    /// // -7 * -4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(21, 2).ceiling_mod(Rational::from_signeds(-4, 3)).to_string(),
+    ///     "7/6"
+    /// );
+    ///
+    /// // -7 * 4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(-21, 2).ceiling_mod(Rational::from_signeds(4, 3)).to_string(),
+    ///     "-7/6"
+    /// );
+    ///
+    /// // 8 * -4/3 + 1/6 = -21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(-21, 2).ceiling_mod(Rational::from_signeds(-4, 3)).to_string(),
+    ///     "1/6"
+    /// );
+    /// ```
+    #[inline]
+    fn ceiling_mod(self, other: Rational) -> Rational {
+        (&self).ceiling_mod(&other)
+    }
+}
+
+impl CeilingMod<&Rational> for Rational {
+    type Output = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking the first by value and the second by
+    /// reference and returning just the remainder. The remainder has the opposite sign as the
+    /// second [`Rational`].
+    ///
+    /// If the quotient were computed, the quotient and remainder would satisfy $x = qy + r$ and $0
+    /// \leq |r| < |y|$, where $q$ is an integer.
+    ///
+    /// $$
+    /// f(x, y) = x - y\left \lceil \frac{x}{y} \right \rceil.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CeilingMod;
+    /// use malachite_q::Rational;
+    ///
+    /// // 8 * 4/3 + -1/6 = 21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(21, 2).ceiling_mod(&Rational::from_signeds(4, 3)).to_string(),
+    ///     "-1/6"
+    /// );
+    ///
+    /// // -7 * -4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(21, 2).ceiling_mod(&Rational::from_signeds(-4, 3)).to_string(),
+    ///     "7/6"
+    /// );
+    ///
+    /// // -7 * 4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(-21, 2).ceiling_mod(&Rational::from_signeds(4, 3)).to_string(),
+    ///     "-7/6"
+    /// );
+    ///
+    /// // 8 * -4/3 + 1/6 = -21/2
+    /// assert_eq!(
+    ///     Rational::from_signeds(-21, 2).ceiling_mod(&Rational::from_signeds(-4, 3)).to_string(),
+    ///     "1/6"
+    /// );
+    /// ```
+    #[inline]
+    fn ceiling_mod(self, other: &Rational) -> Rational {
+        (&self).ceiling_mod(other)
+    }
+}
+
+impl CeilingMod<Rational> for &Rational {
+    type Output = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking the first by reference and the second
+    /// by value and returning just the remainder. The remainder has the opposite sign as the second
+    /// [`Rational`].
+    ///
+    /// If the quotient were computed, the quotient and remainder would satisfy $x = qy + r$ and $0
+    /// \leq |r| < |y|$, where $q$ is an integer.
+    ///
+    /// $$
+    /// f(x, y) = x - y\left \lceil \frac{x}{y} \right \rceil.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CeilingMod;
+    /// use malachite_q::Rational;
+    ///
+    /// // 8 * 4/3 + -1/6 = 21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(21, 2)).ceiling_mod(Rational::from_signeds(4, 3)).to_string(),
+    ///     "-1/6"
+    /// );
+    ///
+    /// // -7 * -4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(21, 2)).ceiling_mod(Rational::from_signeds(-4, 3)).to_string(),
+    ///     "7/6"
+    /// );
+    ///
+    /// // -7 * 4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-21, 2)).ceiling_mod(Rational::from_signeds(4, 3)).to_string(),
+    ///     "-7/6"
+    /// );
+    ///
+    /// // 8 * -4/3 + 1/6 = -21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-21, 2)).ceiling_mod(Rational::from_signeds(-4, 3)).to_string(),
+    ///     "1/6"
+    /// );
+    /// ```
+    #[inline]
+    fn ceiling_mod(self, other: Rational) -> Rational {
+        self.ceiling_mod(&other)
+    }
+}
+
+impl CeilingMod<&Rational> for &Rational {
+    type Output = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking both by reference and returning just
+    /// the remainder. The remainder has the opposite sign as the second [`Rational`].
+    ///
+    /// If the quotient were computed, the quotient and remainder would satisfy $x = qy + r$ and $0
+    /// \leq |r| < |y|$, where $q$ is an integer.
+    ///
+    /// $$
+    /// f(x, y) = x - y\left \lceil \frac{x}{y} \right \rceil.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CeilingMod;
+    /// use malachite_q::Rational;
+    ///
+    /// // 8 * 4/3 + -1/6 = 21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(21, 2)).ceiling_mod(&Rational::from_signeds(4, 3)).to_string(),
+    ///     "-1/6"
+    /// );
+    ///
+    /// // -7 * -4/3 + 7/6 = 21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(21, 2)).ceiling_mod(&Rational::from_signeds(-4, 3)).to_string(),
+    ///     "7/6"
+    /// );
+    ///
+    /// // -7 * 4/3 + -7/6 = -21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-21, 2)).ceiling_mod(&Rational::from_signeds(4, 3)).to_string(),
+    ///     "-7/6"
+    /// );
+    ///
+    /// // 8 * -4/3 + 1/6 = -21/2
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-21, 2)).ceiling_mod(&Rational::from_signeds(-4, 3)).to_string(),
+    ///     "1/6"
+    /// );
+    /// ```
+    fn ceiling_mod(self, other: &Rational) -> Rational {
+        let (n, m, d) = to_common_denominator(self, other);
+        over_denominator(n.ceiling_mod(m), d)
+    }
+}
+
+impl CeilingModAssign<Rational> for Rational {
+    /// Divides a [`Rational`] by another [`Rational`], taking the second [`Rational`] by value and
+    /// replacing the first by the remainder. The remainder has the opposite sign as the second
+    /// [`Rational`].
+    ///
+    /// If the quotient were computed, the quotient and remainder would satisfy $x = qy + r$ and $0
+    /// \leq |r| < |y|$, where $q$ is an integer.
+    ///
+    /// $$
+    /// x \gets x - y\left \lceil \frac{x}{y} \right \rceil.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CeilingModAssign;
+    /// use malachite_q::Rational;
+    ///
+    /// // 8 * 4/3 + -1/6 = 21/2
+    /// let mut x = Rational::from_signeds(21, 2);
+    /// x.ceiling_mod_assign(Rational::from_signeds(4, 3));
+    /// assert_eq!(x.to_string(), "-1/6");
+    ///
+    /// // -7 * -4/3 + 7/6 = 21/2
+    /// let mut x = Rational::from_signeds(21, 2);
+    /// x.ceiling_mod_assign(Rational::from_signeds(-4, 3));
+    /// assert_eq!(x.to_string(), "7/6");
+    ///
+    /// // -7 * 4/3 + -7/6 = -21/2
+    /// let mut x = Rational::from_signeds(-21, 2);
+    /// x.ceiling_mod_assign(Rational::from_signeds(4, 3));
+    /// assert_eq!(x.to_string(), "-7/6");
+    ///
+    /// // 8 * -4/3 + 1/6 = -21/2
+    /// let mut x = Rational::from_signeds(-21, 2);
+    /// x.ceiling_mod_assign(Rational::from_signeds(-4, 3));
+    /// assert_eq!(x.to_string(), "1/6");
+    /// ```
+    #[inline]
+    fn ceiling_mod_assign(&mut self, other: Rational) {
+        *self = (&*self).ceiling_mod(other);
+    }
+}
+
+impl CeilingModAssign<&Rational> for Rational {
+    /// Divides a [`Rational`] by another [`Rational`], taking the second [`Rational`] by reference
+    /// and replacing the first by the remainder. The remainder has the opposite sign as the second
+    /// [`Rational`].
+    ///
+    /// If the quotient were computed, the quotient and remainder would satisfy $x = qy + r$ and $0
+    /// \leq |r| < |y|$, where $q$ is an integer.
+    ///
+    /// $$
+    /// x \gets x - y\left \lceil \frac{x}{y} \right \rceil.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CeilingModAssign;
+    /// use malachite_q::Rational;
+    ///
+    /// // 8 * 4/3 + -1/6 = 21/2
+    /// let mut x = Rational::from_signeds(21, 2);
+    /// x.ceiling_mod_assign(&Rational::from_signeds(4, 3));
+    /// assert_eq!(x.to_string(), "-1/6");
+    ///
+    /// // -7 * -4/3 + 7/6 = 21/2
+    /// let mut x = Rational::from_signeds(21, 2);
+    /// x.ceiling_mod_assign(&Rational::from_signeds(-4, 3));
+    /// assert_eq!(x.to_string(), "7/6");
+    ///
+    /// // -7 * 4/3 + -7/6 = -21/2
+    /// let mut x = Rational::from_signeds(-21, 2);
+    /// x.ceiling_mod_assign(&Rational::from_signeds(4, 3));
+    /// assert_eq!(x.to_string(), "-7/6");
+    ///
+    /// // 8 * -4/3 + 1/6 = -21/2
+    /// let mut x = Rational::from_signeds(-21, 2);
+    /// x.ceiling_mod_assign(&Rational::from_signeds(-4, 3));
+    /// assert_eq!(x.to_string(), "1/6");
+    /// ```
+    #[inline]
+    fn ceiling_mod_assign(&mut self, other: &Rational) {
+        *self = (&*self).ceiling_mod(other);
+    }
+}
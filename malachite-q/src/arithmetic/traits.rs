@@ -46,3 +46,34 @@ pub trait DenominatorsInClosedInterval {
 
     fn denominators_in_closed_interval(a: Rational, b: Rational) -> Self::Denominators;
 }
+
+/// Returns the best rational approximations of a number, subject to a bound on their size.
+///
+/// A [`Rational`] $p/q$, with $p$ and $q$ relatively prime, is a best approximation of $x$ with
+/// respect to some measure of size if every [`Rational`] that is no larger than $p/q$ by that
+/// measure is at least as far from $x$ as $p/q$ is, and every [`Rational`] that is strictly smaller
+/// is strictly farther. Every best approximation is a convergent or a semiconvergent of $x$. If two
+/// best approximations of the same size are equally close to $x$, only the one with the smaller
+/// numerator and denominator is produced.
+///
+/// The number of best approximations may be as large as the sum of the terms of the continued
+/// fraction of $x$, so the returned iterators are lazy.
+pub trait BestApproximations {
+    type Approximations: Iterator<Item = Rational>;
+
+    /// Returns the best approximations of a number whose numerators have absolute value no greater
+    /// than a specified maximum.
+    fn best_approximations_with_max_numerator(
+        self,
+        max_numerator: &Natural,
+    ) -> Self::Approximations;
+
+    /// Returns the best approximations of a number whose heights, the larger of the absolute
+    /// values of the numerator and the denominator, are no greater than a specified maximum.
+    fn best_approximations_with_max_height(self, max_height: &Natural) -> Self::Approximations;
+
+    /// Returns the best approximations of a number, with respect to the denominator, up to and
+    /// including the first one whose distance from the number does not exceed a specified
+    /// tolerance.
+    fn best_approximations_within_tolerance(self, tolerance: &Rational) -> Self::Approximations;
+}
@@ -0,0 +1,102 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Rational;
+use malachite_nz::natural::Natural;
+
+impl Rational {
+    /// Converts a sign and the bytes of a numerator and denominator to a [`Rational`]. The bytes
+    /// are in ascending order, so that less-significant bytes have lower indices in the input
+    /// slices.
+    ///
+    /// The sign indicates whether the [`Rational`] should be non-negative. If the numerator is
+    /// zero, then the [`Rational`] will be non-negative regardless of the sign.
+    ///
+    /// The denominator may not be zero.
+    ///
+    /// The numerator and denominator may have common factors; this function reduces them.
+    ///
+    /// See [`Natural::from_bytes_le`] for how the bytes are converted.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(numerator.len(),
+    /// denominator.len())`.
+    ///
+    /// # Panics
+    /// Panics if `denominator` represents zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Rational::from_sign_and_bytes_le(true, &[4], &[6]).to_string(),
+    ///     "2/3"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_sign_and_bytes_le(false, &[232, 3], &[7]).to_string(),
+    ///     "-1000/7"
+    /// );
+    /// ```
+    pub fn from_sign_and_bytes_le(sign: bool, numerator: &[u8], denominator: &[u8]) -> Rational {
+        Rational::from_sign_and_naturals(
+            sign,
+            Natural::from_bytes_le(numerator),
+            Natural::from_bytes_le(denominator),
+        )
+    }
+
+    /// Converts a sign and the bytes of a numerator and denominator to a [`Rational`]. The bytes
+    /// are in descending order, so that less-significant bytes have higher indices in the input
+    /// slices.
+    ///
+    /// The sign indicates whether the [`Rational`] should be non-negative. If the numerator is
+    /// zero, then the [`Rational`] will be non-negative regardless of the sign.
+    ///
+    /// The denominator may not be zero.
+    ///
+    /// The numerator and denominator may have common factors; this function reduces them.
+    ///
+    /// See [`Natural::from_bytes_be`] for how the bytes are converted.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(numerator.len(),
+    /// denominator.len())`.
+    ///
+    /// # Panics
+    /// Panics if `denominator` represents zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Rational::from_sign_and_bytes_be(true, &[4], &[6]).to_string(),
+    ///     "2/3"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_sign_and_bytes_be(false, &[3, 232], &[7]).to_string(),
+    ///     "-1000/7"
+    /// );
+    /// ```
+    pub fn from_sign_and_bytes_be(sign: bool, numerator: &[u8], denominator: &[u8]) -> Rational {
+        Rational::from_sign_and_naturals(
+            sign,
+            Natural::from_bytes_be(numerator),
+            Natural::from_bytes_be(denominator),
+        )
+    }
+}
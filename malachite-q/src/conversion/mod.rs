@@ -14,6 +14,9 @@ pub mod digits;
 /// An implementation of the [`From`] trait for converting a [`bool`] to a
 /// [`Rational`](crate::Rational).
 pub mod from_bool;
+/// Functions for constructing a [`Rational`](crate::Rational) from a sign and the bytes of a
+/// numerator and denominator.
+pub mod from_bytes;
 /// Functions for converting a primitive float to a [`Rational`](crate::Rational), choosing the
 /// [`Rational`](crate::Rational) with minimal denominator that rounds to the given float.
 pub mod from_float_simplest;
@@ -490,8 +493,17 @@ pub mod primitive_float_from_rational;
 /// );
 /// ```
 pub mod primitive_int_from_rational;
+/// Implementations of traits for conversions between Python fractions and
+/// [`Rational`](crate::Rational)s using [pyo3](https://pyo3.rs/).
+pub mod pyo3;
+/// Implementations of traits for compact serialization and deserialization using
+/// [serde](https://serde.rs/).
+pub mod serde;
 /// Implementations of traits for converting [`Rational`](crate::Rational)s to and from [`String`]s.
 pub mod string;
+/// Functions for extracting the sign of a [`Rational`](crate::Rational) and the bytes of its
+/// numerator and denominator.
+pub mod to_bytes;
 /// Functions for extracting or referencing the numerator and/or denominator of a
 /// [`Rational`](crate::Rational).
 pub mod to_numerator_and_denominator;
@@ -0,0 +1,216 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "enable_pyo3")]
+
+//!
+//! A [`Rational`](crate::Rational) is converted to and from Python's `fractions.Fraction`. Any
+//! Python object with integer `numerator` and `denominator` attributes, such as an `int` or any
+//! other `numbers.Rational`, can be converted to a [`Rational`](crate::Rational).
+//!
+//! To use this enable the `enable_pyo3` feature.
+//!
+//! ## Examples
+//!
+//! Using [`Rational`](crate::Rational) to add one third to an arbitrary fraction without any loss
+//! of precision.
+//! ```rust
+//! use malachite::Rational;
+//! use pyo3::prelude::*;
+//!
+//! #[pyfunction]
+//! fn add_one_third(q: Rational) -> Rational {
+//!     q + Rational::from_signeds(1, 3)
+//! }
+//!
+//! #[pymodule]
+//! fn my_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+//!     m.add_function(wrap_pyfunction!(add_one_third, m)?)?;
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Python code:
+//! ```python
+//! from fractions import Fraction
+//! from my_module import add_one_third
+//!
+//! q = Fraction(1, 1 << 1337)
+//! value = add_one_third(q)
+//!
+//! assert q + Fraction(1, 3) == value
+//! ```
+
+use crate::Rational;
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::integer::Integer;
+use pyo3::{
+    Bound, FromPyObject, IntoPyObject, Py, PyAny, PyErr, PyResult, Python,
+    exceptions::PyZeroDivisionError, intern, sync::GILOnceCell, types::*,
+};
+
+static FRACTION_CLS: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+
+/// Returns Python's `fractions.Fraction` class, importing it on first use.
+fn fraction_cls(py: Python<'_>) -> PyResult<&Bound<'_, PyType>> {
+    FRACTION_CLS.import(py, "fractions", "Fraction")
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "enable_pyo3")))]
+impl<'source> FromPyObject<'source> for Rational {
+    fn extract_bound(ob: &Bound<'source, PyAny>) -> PyResult<Rational> {
+        // get the Python interpreter
+        let py = ob.py();
+
+        // ints are their own numerators, so they can skip the attribute lookups
+        if let Ok(long) = ob.downcast::<PyInt>() {
+            return Ok(Rational::from(long.extract::<Integer>()?));
+        }
+
+        let numerator: Integer = ob.getattr(intern!(py, "numerator"))?.extract()?;
+        let denominator: Integer = ob.getattr(intern!(py, "denominator"))?.extract()?;
+        if denominator == Integer::ZERO {
+            return Err(PyZeroDivisionError::new_err(
+                "Rational has a zero denominator",
+            ));
+        }
+        Ok(Rational::from_integers(numerator, denominator))
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "enable_pyo3")))]
+impl<'py> IntoPyObject<'py> for Rational {
+    type Target = PyAny;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        let sign = self >= 0u32;
+        let (numerator, denominator) = self.into_numerator_and_denominator();
+        fraction_cls(py)?.call1((
+            Integer::from_sign_and_abs(sign, numerator),
+            Integer::from(denominator),
+        ))
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "enable_pyo3")))]
+impl<'py> IntoPyObject<'py> for &Rational {
+    type Target = PyAny;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        fraction_cls(py)?.call1((
+            Integer::from_sign_and_abs_ref(*self >= 0u32, self.numerator_ref()),
+            Integer::from(self.denominator_ref()),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use malachite_base::num::arithmetic::traits::{Pow, PowerOf2, Reciprocal};
+    use malachite_nz::natural::Natural;
+
+    /// Prepare Python
+    fn prepare_python() {
+        pyo3::prepare_freethreaded_python();
+    }
+
+    /// Evaluates a Python expression, with the `fractions` module in scope
+    fn eval<'py>(py: Python<'py>, expr: &core::ffi::CStr) -> Bound<'py, PyAny> {
+        let locals = PyDict::new(py);
+        locals
+            .set_item("fractions", py.import("fractions").unwrap())
+            .unwrap();
+        py.eval(expr, None, Some(&locals)).unwrap()
+    }
+
+    /// - Test conversion to and from Rational
+    /// - Tests 1/n, n/(n + 1), and 2^n/3^n for many n, and their negations
+    #[test]
+    fn convert_rational() {
+        prepare_python();
+        Python::with_gil(|py| {
+            let cls = fraction_cls(py).unwrap();
+            let mut tests = Vec::new();
+            for n in 1..=300u32 {
+                tests.push(Rational::from(n).reciprocal());
+                tests.push(Rational::from_unsigneds(n, n + 1));
+                tests.push(Rational::from_naturals(
+                    Natural::power_of_2(u64::from(n)),
+                    Natural::from(3u32).pow(u64::from(n)),
+                ));
+            }
+            for rs_result in tests {
+                for rs_result in [-&rs_result, rs_result] {
+                    let py_result = cls
+                        .call1((
+                            Integer::from_sign_and_abs_ref(
+                                rs_result >= 0u32,
+                                rs_result.numerator_ref(),
+                            ),
+                            Integer::from(rs_result.denominator_ref()),
+                        ))
+                        .unwrap();
+                    // Python -> Rust
+                    assert_eq!(py_result.extract::<Rational>().unwrap(), rs_result);
+                    // Rust -> Python
+                    let converted = (&rs_result).into_pyobject(py).unwrap();
+                    assert!(converted.is_instance(cls).unwrap());
+                    assert!(py_result.eq(&converted).unwrap());
+                    assert!(py_result.eq(rs_result.into_pyobject(py).unwrap()).unwrap());
+                }
+            }
+        });
+    }
+
+    /// Test conversion from Python ints and other objects with a numerator and denominator
+    #[test]
+    fn convert_rational_like() {
+        prepare_python();
+        Python::with_gil(|py| {
+            let ob = eval(py, c"-(1 << 100)");
+            assert_eq!(
+                ob.extract::<Rational>().unwrap(),
+                -Rational::from(Integer::from(1) << 100u64)
+            );
+
+            let ob = eval(py, c"True");
+            assert_eq!(ob.extract::<Rational>().unwrap(), Rational::from(1));
+
+            let ob = eval(py, c"fractions.Fraction('-6/4')");
+            assert_eq!(
+                ob.extract::<Rational>().unwrap(),
+                Rational::from_signeds(-3, 2)
+            );
+
+            // floats have no numerator or denominator
+            let ob = eval(py, c"0.5");
+            assert!(ob.extract::<Rational>().is_err());
+        });
+    }
+
+    /// Test conversion to and from zero
+    #[test]
+    fn handle_zero() {
+        prepare_python();
+        Python::with_gil(|py| {
+            // Python -> Rust
+            let zero: Rational = eval(py, c"fractions.Fraction(0)").extract().unwrap();
+            assert_eq!(zero, Rational::ZERO);
+
+            // Rust -> Python
+            let zero = zero.into_pyobject(py).unwrap();
+            assert!(zero.eq(0u8).unwrap());
+            assert!(zero.is_instance(fraction_cls(py).unwrap()).unwrap());
+        });
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "serde")]
+
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::CoprimeWith;
+use malachite_nz::natural::conversion::serde::{CompactNatural, CompactNaturalRef};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+/// A wrapper around a [`Rational`] that is serialized compactly.
+///
+/// A `CompactRational` is serialized as a triple: a `bool` that is `true` if and only if the
+/// [`Rational`] is non-negative, followed by its numerator and denominator in the format of a
+/// [`CompactNatural`]. Like that format, this one does not depend on the
+/// [`Limb`](malachite_nz#limbs) size. Deserialization rejects zero denominators, numerators and
+/// denominators that are not coprime, and negative zero, so every [`Rational`] has exactly one
+/// encoding.
+///
+/// To serialize a [`Rational`] without taking ownership of it, use [`CompactRationalRef`].
+///
+/// # Examples
+/// ```
+/// use malachite_q::conversion::serde::CompactRational;
+/// use malachite_q::Rational;
+///
+/// let x = CompactRational(Rational::from_signeds(-1000, 3));
+/// assert_eq!(serde_json::to_string(&x).unwrap(), "[false,[232,3],[3]]");
+/// assert_eq!(
+///     serde_json::from_str::<CompactRational>("[false,[232,3],[3]]").unwrap(),
+///     x
+/// );
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct CompactRational(pub Rational);
+
+/// A wrapper around a reference to a [`Rational`] that is serialized compactly, in the same format
+/// as a [`CompactRational`].
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CompactRationalRef<'a>(pub &'a Rational);
+
+impl Serialize for CompactRationalRef<'_> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (
+            self.0.sign,
+            CompactNaturalRef(&self.0.numerator),
+            CompactNaturalRef(&self.0.denominator),
+        )
+            .serialize(serializer)
+    }
+}
+
+impl Serialize for CompactRational {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        CompactRationalRef(&self.0).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CompactRational {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (sign, CompactNatural(numerator), CompactNatural(denominator)): (bool, _, _) =
+            Deserialize::deserialize(deserializer)?;
+        if denominator == 0u32 {
+            return Err(D::Error::custom("a Rational's denominator cannot be zero"));
+        }
+        if !sign && numerator == 0u32 {
+            return Err(D::Error::custom("negative zero is not a valid Rational"));
+        }
+        if !(&numerator).coprime_with(&denominator) {
+            return Err(D::Error::custom(
+                "a Rational's numerator and denominator must be coprime",
+            ));
+        }
+        Ok(CompactRational(Rational {
+            sign,
+            numerator,
+            denominator,
+        }))
+    }
+}
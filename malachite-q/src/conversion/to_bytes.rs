@@ -0,0 +1,84 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Rational;
+use alloc::vec::Vec;
+
+impl Rational {
+    /// Returns the sign of a [`Rational`], along with the bytes of its numerator and denominator.
+    /// The sign is `true` if and only if the [`Rational`] is non-negative, and the bytes are in
+    /// ascending order, so that less-significant bytes have lower indices in the output vectors.
+    ///
+    /// There are no trailing zero bytes; in particular, a zero numerator is converted to an empty
+    /// vector.
+    ///
+    /// See [`Natural::to_bytes_le`](malachite_nz::natural::Natural::to_bytes_le) for how the bytes
+    /// are produced.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::ZERO.to_sign_and_bytes_le(), (true, vec![], vec![1]));
+    /// assert_eq!(
+    ///     Rational::from_signeds(-1000, 7).to_sign_and_bytes_le(),
+    ///     (false, vec![232, 3], vec![7])
+    /// );
+    /// ```
+    pub fn to_sign_and_bytes_le(&self) -> (bool, Vec<u8>, Vec<u8>) {
+        (
+            self.sign,
+            self.numerator.to_bytes_le(),
+            self.denominator.to_bytes_le(),
+        )
+    }
+
+    /// Returns the sign of a [`Rational`], along with the bytes of its numerator and denominator.
+    /// The sign is `true` if and only if the [`Rational`] is non-negative, and the bytes are in
+    /// descending order, so that less-significant bytes have higher indices in the output vectors.
+    ///
+    /// There are no leading zero bytes; in particular, a zero numerator is converted to an empty
+    /// vector.
+    ///
+    /// See [`Natural::to_bytes_be`](malachite_nz::natural::Natural::to_bytes_be) for how the bytes
+    /// are produced.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::ZERO.to_sign_and_bytes_be(), (true, vec![], vec![1]));
+    /// assert_eq!(
+    ///     Rational::from_signeds(-1000, 7).to_sign_and_bytes_be(),
+    ///     (false, vec![3, 232], vec![7])
+    /// );
+    /// ```
+    pub fn to_sign_and_bytes_be(&self) -> (bool, Vec<u8>, Vec<u8>) {
+        (
+            self.sign,
+            self.numerator.to_bytes_be(),
+            self.denominator.to_bytes_be(),
+        )
+    }
+}
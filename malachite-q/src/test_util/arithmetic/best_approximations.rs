@@ -0,0 +1,104 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Rational;
+use core::cmp::max;
+use malachite_base::num::arithmetic::traits::{Abs, AbsDiff, Ceiling, Floor};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::natural::Natural;
+use malachite_nz::natural::exhaustive::exhaustive_natural_inclusive_range;
+
+// Given the `Rational`s of each size, in order of increasing size, returns those that are closer to
+// `x` than all `Rational`s of smaller size and at least as close as all `Rational`s of the same
+// size. Among equally close `Rational`s of the same size, the one with the smaller denominator and
+// numerator is chosen.
+fn best_approximations_naive_helper(
+    x: &Rational,
+    groups: impl Iterator<Item = Vec<Rational>>,
+) -> Vec<Rational> {
+    let mut approximations: Vec<Rational> = Vec::new();
+    for group in groups {
+        let Some(nearest) = group.into_iter().min_by(|p, q| {
+            (x.abs_diff(p), p.denominator_ref(), p.numerator_ref()).cmp(&(
+                x.abs_diff(q),
+                q.denominator_ref(),
+                q.numerator_ref(),
+            ))
+        }) else {
+            continue;
+        };
+        if approximations
+            .last()
+            .is_none_or(|last| x.abs_diff(&nearest) < x.abs_diff(last))
+        {
+            approximations.push(nearest);
+        }
+    }
+    approximations
+}
+
+// Slow! Only use for small `max_numerator`s
+pub fn best_approximations_with_max_numerator_naive(
+    x: &Rational,
+    max_numerator: &Natural,
+) -> Vec<Rational> {
+    let x_abs = x.abs();
+    let approximations = best_approximations_naive_helper(
+        &x_abs,
+        exhaustive_natural_inclusive_range(Natural::ZERO, max_numerator.clone()).map(|n| {
+            if n == 0u32 {
+                return vec![Rational::ZERO];
+            }
+            if x_abs == 0u32 {
+                // Every nonzero `Rational` with numerator `n` is farther from 0 than 0 is.
+                return Vec::new();
+            }
+            // The `Rational`s with numerator `n` closest to `x_abs` are `n / q` where `q` is the
+            // floor or ceiling of `n / x_abs`.
+            let q = Rational::from(&n) / &x_abs;
+            let floor = Natural::exact_from((&q).floor());
+            let ceiling = Natural::exact_from(q.ceiling());
+            let mut group = vec![Rational::from_naturals(n.clone(), ceiling)];
+            if floor != 0u32 {
+                group.push(Rational::from_naturals(n.clone(), floor));
+            }
+            // Only keep the `Rational`s whose numerator is still `n` after reduction.
+            group.retain(|q| *q.numerator_ref() == n);
+            group
+        }),
+    );
+    if *x >= 0u32 {
+        approximations
+    } else {
+        approximations.into_iter().map(|q| -q).collect()
+    }
+}
+
+// Slow! Only use for small `max_height`s
+pub fn best_approximations_with_max_height_naive(
+    x: &Rational,
+    max_height: &Natural,
+) -> Vec<Rational> {
+    best_approximations_naive_helper(
+        x,
+        exhaustive_natural_inclusive_range(Natural::ONE, max_height.clone()).map(|h| {
+            let mut group = Vec::new();
+            for d in exhaustive_natural_inclusive_range(Natural::ONE, h.clone()) {
+                for n in exhaustive_natural_inclusive_range(Natural::ZERO, h.clone()) {
+                    let q = Rational::from_naturals(n, d.clone());
+                    if *max(q.numerator_ref(), q.denominator_ref()) == h {
+                        group.push(-&q);
+                        group.push(q);
+                    }
+                }
+            }
+            group
+        }),
+    )
+}
@@ -8,6 +8,7 @@
 
 pub mod add;
 pub mod approximate;
+pub mod best_approximations;
 pub mod div;
 pub mod mul;
 pub mod sign;
@@ -0,0 +1,311 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use itertools::Itertools;
+use malachite_base::num::arithmetic::traits::AbsDiff;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+use malachite_q::arithmetic::traits::{
+    Approximate, BestApproximations, SimplestRationalInInterval,
+};
+use malachite_q::test_util::arithmetic::best_approximations::{
+    best_approximations_with_max_height_naive, best_approximations_with_max_numerator_naive,
+};
+use malachite_q::test_util::generators::{
+    rational_gen_var_7, rational_natural_pair_gen_var_2, rational_natural_pair_gen_var_4,
+    rational_pair_gen_var_6,
+};
+use std::cmp::max;
+use std::str::FromStr;
+
+fn height(x: &Rational) -> Natural {
+    max(x.numerator_ref(), x.denominator_ref()).clone()
+}
+
+// Checks the properties shared by all kinds of best approximations: they have the same sign as
+// `x`, their sizes strictly increase, and their distances from `x` strictly decrease.
+fn best_approximations_properties_helper(
+    x: &Rational,
+    approximations: &[Rational],
+    size: impl Fn(&Rational) -> Natural,
+) {
+    assert!(!approximations.is_empty());
+    for a in approximations {
+        assert!(a.is_valid());
+        assert!(*a == 0u32 || (*a > 0u32) == (*x > 0u32));
+    }
+    for (a, b) in approximations.iter().tuple_windows() {
+        assert!(size(a) < size(b));
+        assert!(x.abs_diff(a) > x.abs_diff(b));
+    }
+}
+
+#[test]
+fn test_best_approximations_with_max_numerator() {
+    let test = |x, n, out| {
+        let x = Rational::from_str(x).unwrap();
+        let n = Natural::from_str(n).unwrap();
+        let approximations = x
+            .clone()
+            .best_approximations_with_max_numerator(&n)
+            .collect_vec();
+        assert_eq!(approximations.to_debug_string(), out);
+        assert_eq!(
+            (&x).best_approximations_with_max_numerator(&n)
+                .collect_vec(),
+            approximations
+        );
+    };
+    test("0", "0", "[0]");
+    test("0", "10", "[0]");
+    test("3", "0", "[0]");
+    test("3", "10", "[0, 1, 2, 3]");
+    test("7/3", "0", "[0]");
+    test("7/3", "2", "[0, 1, 2]");
+    test("7/3", "5", "[0, 1, 2, 5/2]");
+    test("7/3", "10", "[0, 1, 2, 5/2, 7/3]");
+    test("-7/3", "5", "[0, -1, -2, -5/2]");
+    test("1/4", "0", "[0]");
+    test("1/4", "1", "[0, 1/4]");
+    test("27/32", "10", "[0, 1, 3/4, 4/5, 5/6]");
+    test("884279719003555/281474976710656", "10", "[0, 1, 2, 3]");
+    test(
+        "884279719003555/281474976710656",
+        "1000",
+        "[0, 1, 2, 3, 13/4, 16/5, 19/6, 22/7, 179/57, 201/64, 223/71, 245/78, 267/85, 289/92, \
+        311/99, 333/106, 355/113]",
+    );
+}
+
+#[test]
+fn test_best_approximations_with_max_height() {
+    let test = |x, h, out| {
+        let x = Rational::from_str(x).unwrap();
+        let h = Natural::from_str(h).unwrap();
+        let approximations = x
+            .clone()
+            .best_approximations_with_max_height(&h)
+            .collect_vec();
+        assert_eq!(approximations.to_debug_string(), out);
+        assert_eq!(
+            (&x).best_approximations_with_max_height(&h).collect_vec(),
+            approximations
+        );
+    };
+    test("0", "1", "[0]");
+    test("0", "10", "[0]");
+    test("1/2", "1", "[0]");
+    test("-1/2", "1", "[0]");
+    test("1/2", "2", "[0, 1/2]");
+    test("3", "1", "[1]");
+    test("3", "10", "[1, 2, 3]");
+    test("7/3", "2", "[1, 2]");
+    test("7/3", "6", "[1, 2, 5/2]");
+    test("7/3", "7", "[1, 2, 5/2, 7/3]");
+    test("-7/3", "7", "[-1, -2, -5/2, -7/3]");
+    test("1/7", "5", "[0, 1/4, 1/5]");
+    test("27/32", "10", "[1, 3/4, 4/5, 5/6]");
+    test(
+        "884279719003555/281474976710656",
+        "1000",
+        "[1, 2, 3, 13/4, 16/5, 19/6, 22/7, 179/57, 201/64, 223/71, 245/78, 267/85, 289/92, \
+        311/99, 333/106, 355/113]",
+    );
+}
+
+#[test]
+#[should_panic]
+fn best_approximations_with_max_height_fail() {
+    Rational::ONE.best_approximations_with_max_height(&Natural::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn best_approximations_with_max_height_ref_fail() {
+    (&Rational::ONE).best_approximations_with_max_height(&Natural::ZERO);
+}
+
+#[test]
+fn test_best_approximations_within_tolerance() {
+    let test = |x, t, out| {
+        let x = Rational::from_str(x).unwrap();
+        let t = Rational::from_str(t).unwrap();
+        let approximations = x
+            .clone()
+            .best_approximations_within_tolerance(&t)
+            .collect_vec();
+        assert_eq!(approximations.to_debug_string(), out);
+        assert_eq!(
+            (&x).best_approximations_within_tolerance(&t).collect_vec(),
+            approximations
+        );
+    };
+    test("0", "0", "[0]");
+    test("0", "1", "[0]");
+    test("3", "0", "[3]");
+    test("1/2", "0", "[0, 1/2]");
+    test("1/2", "1/2", "[0]");
+    test("7/3", "0", "[2, 5/2, 7/3]");
+    test("7/3", "1", "[2]");
+    test("7/3", "1/4", "[2, 5/2]");
+    test("-7/3", "1/4", "[-2, -5/2]");
+    test("27/32", "0", "[1, 3/4, 4/5, 5/6, 11/13, 16/19, 27/32]");
+    test("27/32", "1/100", "[1, 3/4, 4/5, 5/6, 11/13]");
+    test(
+        "884279719003555/281474976710656",
+        "1/1000000",
+        "[3, 13/4, 16/5, 19/6, 22/7, 179/57, 201/64, 223/71, 245/78, 267/85, 289/92, 311/99, \
+        333/106, 355/113]",
+    );
+}
+
+#[test]
+#[should_panic]
+fn best_approximations_within_tolerance_fail() {
+    Rational::ONE.best_approximations_within_tolerance(&Rational::from_signeds(-1, 2));
+}
+
+#[test]
+#[should_panic]
+fn best_approximations_within_tolerance_ref_fail() {
+    (&Rational::ONE).best_approximations_within_tolerance(&Rational::from_signeds(-1, 2));
+}
+
+#[test]
+fn best_approximations_with_max_numerator_properties() {
+    // There may be as many best approximations as the sum of the continued fraction terms of `x`,
+    // so only `Rational`s with small numerators and denominators are used.
+    rational_natural_pair_gen_var_2().test_properties(|(x, max_numerator)| {
+        let approximations = (&x)
+            .best_approximations_with_max_numerator(&max_numerator)
+            .collect_vec();
+        assert_eq!(
+            x.clone()
+                .best_approximations_with_max_numerator(&max_numerator)
+                .collect_vec(),
+            approximations
+        );
+        best_approximations_properties_helper(&x, &approximations, |a| a.numerator_ref().clone());
+        let last = approximations.last().unwrap();
+        assert!(*last.numerator_ref() <= max_numerator);
+        assert_eq!(*last == x, *x.numerator_ref() <= max_numerator);
+        assert_eq!(
+            (-&x)
+                .best_approximations_with_max_numerator(&max_numerator)
+                .collect_vec(),
+            approximations.iter().map(|a| -a).collect_vec()
+        );
+    });
+
+    rational_natural_pair_gen_var_4().test_properties(|(x, max_numerator)| {
+        assert_eq!(
+            (&x).best_approximations_with_max_numerator(&max_numerator)
+                .collect_vec(),
+            best_approximations_with_max_numerator_naive(&x, &max_numerator)
+        );
+    });
+}
+
+#[test]
+fn best_approximations_with_max_height_properties() {
+    rational_natural_pair_gen_var_2().test_properties(|(x, max_height)| {
+        let approximations = (&x)
+            .best_approximations_with_max_height(&max_height)
+            .collect_vec();
+        assert_eq!(
+            x.clone()
+                .best_approximations_with_max_height(&max_height)
+                .collect_vec(),
+            approximations
+        );
+        best_approximations_properties_helper(&x, &approximations, height);
+        let last = approximations.last().unwrap();
+        assert!(height(last) <= max_height);
+        assert_eq!(*last == x, height(&x) <= max_height);
+        assert_eq!(
+            (-&x)
+                .best_approximations_with_max_height(&max_height)
+                .collect_vec(),
+            approximations.iter().map(|a| -a).collect_vec()
+        );
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_small_n", 16);
+    rational_natural_pair_gen_var_4().test_properties_with_config(&config, |(x, max_height)| {
+        assert_eq!(
+            (&x).best_approximations_with_max_height(&max_height)
+                .collect_vec(),
+            best_approximations_with_max_height_naive(&x, &max_height)
+        );
+    });
+}
+
+#[test]
+fn best_approximations_within_tolerance_properties() {
+    rational_pair_gen_var_6().test_properties(|(x, y)| {
+        let tolerance = y - &x;
+        let approximations = (&x)
+            .best_approximations_within_tolerance(&tolerance)
+            .collect_vec();
+        assert_eq!(
+            x.clone()
+                .best_approximations_within_tolerance(&tolerance)
+                .collect_vec(),
+            approximations
+        );
+        best_approximations_properties_helper(&x, &approximations, |a| a.denominator_ref().clone());
+        let (last, init) = approximations.split_last().unwrap();
+        assert!((&x).abs_diff(last) <= tolerance);
+        for a in init {
+            assert!((&x).abs_diff(a) > tolerance);
+        }
+        assert_eq!(
+            last.denominator_ref(),
+            Rational::simplest_rational_in_closed_interval(&(&x - &tolerance), &(&x + &tolerance))
+                .denominator_ref()
+        );
+        // Each approximation is as close to `x` as any `Rational` with a denominator no greater
+        // than its own, and no `Rational` with a denominator between those of two consecutive
+        // approximations is closer than the first.
+        for a in &approximations {
+            assert_eq!(
+                (&x).abs_diff(a),
+                (&x).abs_diff((&x).approximate(a.denominator_ref()))
+            );
+        }
+        for (a, b) in approximations.iter().tuple_windows() {
+            assert_eq!(
+                (&x).abs_diff(a),
+                (&x).abs_diff((&x).approximate(&(b.denominator_ref() - Natural::ONE)))
+            );
+        }
+        assert_eq!(
+            (-&x)
+                .best_approximations_within_tolerance(&tolerance)
+                .collect_vec(),
+            approximations.iter().map(|a| -a).collect_vec()
+        );
+    });
+
+    rational_gen_var_7().test_properties(|x| {
+        let approximations = (&x)
+            .best_approximations_within_tolerance(&Rational::ZERO)
+            .collect_vec();
+        assert_eq!(*approximations.last().unwrap(), x);
+        assert_eq!(
+            (&x).best_approximations_with_max_numerator(x.numerator_ref())
+                .last()
+                .unwrap(),
+            x
+        );
+    });
+}
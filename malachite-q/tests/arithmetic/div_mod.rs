@@ -0,0 +1,585 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{
+    Abs, Ceiling, CeilingDivAssignMod, CeilingDivMod, CeilingMod, DivAssignMod, DivAssignRem,
+    DivMod, DivRem, Floor, Mod,
+};
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_base::num::comparison::traits::PartialOrdAbs;
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::rounding_modes::RoundingMode::*;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_pair_gen_var_1;
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{
+    rational_gen, rational_gen_var_1, rational_pair_gen_var_1,
+};
+use std::str::FromStr;
+
+#[test]
+fn test_div_mod() {
+    let test = |s, t, quotient, remainder| {
+        let u = Rational::from_str(s).unwrap();
+        let v = Rational::from_str(t).unwrap();
+
+        let mut x = u.clone();
+        let r = x.div_assign_mod(v.clone());
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), quotient);
+
+        let mut x = u.clone();
+        let r = x.div_assign_mod(&v);
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), quotient);
+
+        let (q, r) = u.clone().div_mod(v.clone());
+        assert!(q.is_valid());
+        assert_eq!(q.to_string(), quotient);
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+
+        let (q, r) = u.clone().div_mod(&v);
+        assert!(q.is_valid());
+        assert_eq!(q.to_string(), quotient);
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+
+        let (q, r) = (&u).div_mod(v.clone());
+        assert!(q.is_valid());
+        assert_eq!(q.to_string(), quotient);
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+
+        let (q, r) = (&u).div_mod(&v);
+        assert!(q.is_valid());
+        assert_eq!(q.to_string(), quotient);
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+    };
+    test("0", "1/2", "0", "0");
+    test("23", "10", "2", "3");
+    test("23", "-10", "-3", "-7");
+    test("-23", "10", "-3", "7");
+    test("-23", "-10", "2", "-3");
+    test("21/2", "4/3", "7", "7/6");
+    test("21/2", "-4/3", "-8", "-1/6");
+    test("-21/2", "4/3", "-8", "1/6");
+    test("-21/2", "-4/3", "7", "-7/6");
+    test("7/3", "1/2", "4", "1/3");
+    test("1/3", "2", "0", "1/3");
+    test("-1/3", "2", "-1", "5/3");
+    test("4/5", "4/5", "1", "0");
+    test("4/5", "-4/5", "-1", "0");
+    test(
+        "22/7",
+        "1/1000000000000",
+        "3142857142857",
+        "1/7000000000000",
+    );
+}
+
+#[test]
+#[should_panic]
+fn div_assign_mod_fail() {
+    Rational::from(10).div_assign_mod(Rational::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn div_assign_mod_ref_fail() {
+    Rational::from(10).div_assign_mod(&Rational::ZERO);
+}
+
+#[test]
+#[should_panic]
+#[allow(unused_must_use)]
+fn div_mod_fail() {
+    Rational::ONE.div_mod(Rational::ZERO);
+}
+
+#[test]
+#[should_panic]
+#[allow(unused_must_use)]
+fn div_mod_val_ref_fail() {
+    Rational::ONE.div_mod(&Rational::ZERO);
+}
+
+#[test]
+#[should_panic]
+#[allow(unused_must_use)]
+fn div_mod_ref_val_fail() {
+    (&Rational::ONE).div_mod(Rational::ZERO);
+}
+
+#[test]
+#[should_panic]
+#[allow(unused_must_use)]
+fn div_mod_ref_ref_fail() {
+    (&Rational::ONE).div_mod(&Rational::ZERO);
+}
+
+#[test]
+fn test_div_rem() {
+    let test = |s, t, quotient, remainder| {
+        let u = Rational::from_str(s).unwrap();
+        let v = Rational::from_str(t).unwrap();
+
+        let mut x = u.clone();
+        let r = x.div_assign_rem(v.clone());
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), quotient);
+
+        let mut x = u.clone();
+        let r = x.div_assign_rem(&v);
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), quotient);
+
+        let (q, r) = u.clone().div_rem(v.clone());
+        assert!(q.is_valid());
+        assert_eq!(q.to_string(), quotient);
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+
+        let (q, r) = u.clone().div_rem(&v);
+        assert!(q.is_valid());
+        assert_eq!(q.to_string(), quotient);
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+
+        let (q, r) = (&u).div_rem(v.clone());
+        assert!(q.is_valid());
+        assert_eq!(q.to_string(), quotient);
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+
+        let (q, r) = (&u).div_rem(&v);
+        assert!(q.is_valid());
+        assert_eq!(q.to_string(), quotient);
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+    };
+    test("0", "1/2", "0", "0");
+    test("23", "10", "2", "3");
+    test("23", "-10", "-2", "3");
+    test("-23", "10", "-2", "-3");
+    test("-23", "-10", "2", "-3");
+    test("21/2", "4/3", "7", "7/6");
+    test("21/2", "-4/3", "-7", "7/6");
+    test("-21/2", "4/3", "-7", "-7/6");
+    test("-21/2", "-4/3", "7", "-7/6");
+    test("7/3", "1/2", "4", "1/3");
+    test("1/3", "2", "0", "1/3");
+    test("-1/3", "2", "0", "-1/3");
+    test("4/5", "4/5", "1", "0");
+    test("4/5", "-4/5", "-1", "0");
+    test(
+        "22/7",
+        "1/1000000000000",
+        "3142857142857",
+        "1/7000000000000",
+    );
+}
+
+#[test]
+#[should_panic]
+fn div_assign_rem_fail() {
+    Rational::from(10).div_assign_rem(Rational::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn div_assign_rem_ref_fail() {
+    Rational::from(10).div_assign_rem(&Rational::ZERO);
+}
+
+#[test]
+#[should_panic]
+#[allow(unused_must_use)]
+fn div_rem_fail() {
+    Rational::ONE.div_rem(Rational::ZERO);
+}
+
+#[test]
+#[should_panic]
+#[allow(unused_must_use)]
+fn div_rem_val_ref_fail() {
+    Rational::ONE.div_rem(&Rational::ZERO);
+}
+
+#[test]
+#[should_panic]
+#[allow(unused_must_use)]
+fn div_rem_ref_val_fail() {
+    (&Rational::ONE).div_rem(Rational::ZERO);
+}
+
+#[test]
+#[should_panic]
+#[allow(unused_must_use)]
+fn div_rem_ref_ref_fail() {
+    (&Rational::ONE).div_rem(&Rational::ZERO);
+}
+
+#[test]
+fn test_ceiling_div_mod() {
+    let test = |s, t, quotient, remainder| {
+        let u = Rational::from_str(s).unwrap();
+        let v = Rational::from_str(t).unwrap();
+
+        let mut x = u.clone();
+        let r = x.ceiling_div_assign_mod(v.clone());
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), quotient);
+
+        let mut x = u.clone();
+        let r = x.ceiling_div_assign_mod(&v);
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), quotient);
+
+        let (q, r) = u.clone().ceiling_div_mod(v.clone());
+        assert!(q.is_valid());
+        assert_eq!(q.to_string(), quotient);
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+
+        let (q, r) = u.clone().ceiling_div_mod(&v);
+        assert!(q.is_valid());
+        assert_eq!(q.to_string(), quotient);
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+
+        let (q, r) = (&u).ceiling_div_mod(v.clone());
+        assert!(q.is_valid());
+        assert_eq!(q.to_string(), quotient);
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+
+        let (q, r) = (&u).ceiling_div_mod(&v);
+        assert!(q.is_valid());
+        assert_eq!(q.to_string(), quotient);
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+    };
+    test("0", "1/2", "0", "0");
+    test("23", "10", "3", "-7");
+    test("23", "-10", "-2", "3");
+    test("-23", "10", "-2", "-3");
+    test("-23", "-10", "3", "7");
+    test("21/2", "4/3", "8", "-1/6");
+    test("21/2", "-4/3", "-7", "7/6");
+    test("-21/2", "4/3", "-7", "-7/6");
+    test("-21/2", "-4/3", "8", "1/6");
+    test("7/3", "1/2", "5", "-1/6");
+    test("1/3", "2", "1", "-5/3");
+    test("-1/3", "2", "0", "-1/3");
+    test("4/5", "4/5", "1", "0");
+    test("4/5", "-4/5", "-1", "0");
+    test(
+        "22/7",
+        "1/1000000000000",
+        "3142857142858",
+        "-3/3500000000000",
+    );
+}
+
+#[test]
+#[should_panic]
+fn ceiling_div_assign_mod_fail() {
+    Rational::from(10).ceiling_div_assign_mod(Rational::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn ceiling_div_assign_mod_ref_fail() {
+    Rational::from(10).ceiling_div_assign_mod(&Rational::ZERO);
+}
+
+#[test]
+#[should_panic]
+#[allow(unused_must_use)]
+fn ceiling_div_mod_fail() {
+    Rational::ONE.ceiling_div_mod(Rational::ZERO);
+}
+
+#[test]
+#[should_panic]
+#[allow(unused_must_use)]
+fn ceiling_div_mod_val_ref_fail() {
+    Rational::ONE.ceiling_div_mod(&Rational::ZERO);
+}
+
+#[test]
+#[should_panic]
+#[allow(unused_must_use)]
+fn ceiling_div_mod_ref_val_fail() {
+    (&Rational::ONE).ceiling_div_mod(Rational::ZERO);
+}
+
+#[test]
+#[should_panic]
+#[allow(unused_must_use)]
+fn ceiling_div_mod_ref_ref_fail() {
+    (&Rational::ONE).ceiling_div_mod(&Rational::ZERO);
+}
+
+fn div_mod_properties_helper(x: Rational, y: Rational) {
+    let mut mut_x = x.clone();
+    let r = mut_x.div_assign_mod(&y);
+    assert!(mut_x.is_valid());
+    assert!(r.is_valid());
+    let q = Integer::exact_from(mut_x);
+
+    let mut mut_x = x.clone();
+    let r_alt = mut_x.div_assign_mod(y.clone());
+    assert!(mut_x.is_valid());
+    assert_eq!(mut_x, q);
+    assert!(r_alt.is_valid());
+    assert_eq!(r_alt, r);
+
+    let (q_alt, r_alt) = (&x).div_mod(&y);
+    assert!(q_alt.is_valid());
+    assert_eq!(q_alt, q);
+    assert!(r_alt.is_valid());
+    assert_eq!(r_alt, r);
+
+    let (q_alt, r_alt) = (&x).div_mod(y.clone());
+    assert!(q_alt.is_valid());
+    assert_eq!(q_alt, q);
+    assert!(r_alt.is_valid());
+    assert_eq!(r_alt, r);
+
+    let (q_alt, r_alt) = x.clone().div_mod(&y);
+    assert!(q_alt.is_valid());
+    assert_eq!(q_alt, q);
+    assert!(r_alt.is_valid());
+    assert_eq!(r_alt, r);
+
+    let (q_alt, r_alt) = x.clone().div_mod(y.clone());
+    assert!(q_alt.is_valid());
+    assert_eq!(q_alt, q);
+    assert!(r_alt.is_valid());
+    assert_eq!(r_alt, r);
+
+    assert_eq!((&x / &y).floor(), q);
+    assert_eq!((&x).mod_op(&y), r);
+
+    assert!(r.lt_abs(&y));
+    assert!(r == 0u32 || (r > 0u32) == (y > 0u32));
+    assert_eq!(Rational::from(&q) * &y + &r, x);
+
+    let (neg_q, neg_r) = (-&x).div_mod(&y);
+    assert_eq!((&x).ceiling_div_mod(&y), (-neg_q, -neg_r));
+
+    let (neg_q, r) = (&x).div_mod(-&y);
+    assert_eq!(x.ceiling_div_mod(y), (-neg_q, r));
+}
+
+#[test]
+fn div_mod_properties() {
+    rational_pair_gen_var_1().test_properties(|(x, y)| div_mod_properties_helper(x, y));
+
+    rational_gen().test_properties(|x| {
+        let (q, r) = (&x).div_mod(Rational::ONE);
+        assert_eq!(q, (&x).floor());
+        assert_eq!(r, &x - Rational::from(q));
+
+        let (q, r) = (&x).div_mod(Rational::NEGATIVE_ONE);
+        assert_eq!(q, -(&x).ceiling());
+        assert_eq!(r, x + Rational::from(q));
+    });
+
+    rational_gen_var_1().test_properties(|ref x| {
+        assert_eq!(x.div_mod(x), (Integer::ONE, Rational::ZERO));
+        assert_eq!(x.div_mod(-x), (Integer::NEGATIVE_ONE, Rational::ZERO));
+        assert_eq!(Rational::ZERO.div_mod(x), (Integer::ZERO, Rational::ZERO));
+    });
+
+    integer_pair_gen_var_1().test_properties(|(x, y)| {
+        let (q, r) = (&x).div_mod(&y);
+        assert_eq!(
+            Rational::from(x).div_mod(Rational::from(y)),
+            (q, Rational::from(r))
+        );
+    });
+}
+
+fn div_rem_properties_helper(x: Rational, y: Rational) {
+    let mut mut_x = x.clone();
+    let r = mut_x.div_assign_rem(&y);
+    assert!(mut_x.is_valid());
+    assert!(r.is_valid());
+    let q = Integer::exact_from(mut_x);
+
+    let mut mut_x = x.clone();
+    let r_alt = mut_x.div_assign_rem(y.clone());
+    assert!(mut_x.is_valid());
+    assert_eq!(mut_x, q);
+    assert!(r_alt.is_valid());
+    assert_eq!(r_alt, r);
+
+    let (q_alt, r_alt) = (&x).div_rem(&y);
+    assert!(q_alt.is_valid());
+    assert_eq!(q_alt, q);
+    assert!(r_alt.is_valid());
+    assert_eq!(r_alt, r);
+
+    let (q_alt, r_alt) = (&x).div_rem(y.clone());
+    assert!(q_alt.is_valid());
+    assert_eq!(q_alt, q);
+    assert!(r_alt.is_valid());
+    assert_eq!(r_alt, r);
+
+    let (q_alt, r_alt) = x.clone().div_rem(&y);
+    assert!(q_alt.is_valid());
+    assert_eq!(q_alt, q);
+    assert!(r_alt.is_valid());
+    assert_eq!(r_alt, r);
+
+    let (q_alt, r_alt) = x.clone().div_rem(y.clone());
+    assert!(q_alt.is_valid());
+    assert_eq!(q_alt, q);
+    assert!(r_alt.is_valid());
+    assert_eq!(r_alt, r);
+
+    assert_eq!(Integer::rounding_from(&x / &y, Down).0, q);
+    assert_eq!(&x % &y, r);
+
+    assert!(r.lt_abs(&y));
+    assert!(r == 0u32 || (r > 0u32) == (x > 0u32));
+    assert_eq!(Rational::from(&q) * &y + &r, x);
+
+    assert_eq!((-&x).div_rem(&y), (-&q, -&r));
+    assert_eq!((&x).div_rem(-&y), (-q, r));
+}
+
+#[test]
+fn div_rem_properties() {
+    rational_pair_gen_var_1().test_properties(|(x, y)| div_rem_properties_helper(x, y));
+
+    rational_gen().test_properties(|x| {
+        let (q, r) = (&x).div_rem(Rational::ONE);
+        assert_eq!(q, Integer::rounding_from(&x, Down).0);
+        assert_eq!(r, &x - Rational::from(q));
+
+        let (q, r) = (&x).div_rem(Rational::NEGATIVE_ONE);
+        assert_eq!(q, -Integer::rounding_from(&x, Down).0);
+        assert_eq!(r, &x + Rational::from(q));
+    });
+
+    rational_gen_var_1().test_properties(|ref x| {
+        assert_eq!(x.div_rem(x), (Integer::ONE, Rational::ZERO));
+        assert_eq!(x.div_rem(-x), (Integer::NEGATIVE_ONE, Rational::ZERO));
+        assert_eq!(Rational::ZERO.div_rem(x), (Integer::ZERO, Rational::ZERO));
+        assert_eq!(
+            x.abs().div_rem(x.abs() + Rational::ONE),
+            (Integer::ZERO, x.abs())
+        );
+    });
+
+    integer_pair_gen_var_1().test_properties(|(x, y)| {
+        let (q, r) = (&x).div_rem(&y);
+        assert_eq!(
+            Rational::from(x).div_rem(Rational::from(y)),
+            (q, Rational::from(r))
+        );
+    });
+}
+
+fn ceiling_div_mod_properties_helper(x: Rational, y: Rational) {
+    let mut mut_x = x.clone();
+    let r = mut_x.ceiling_div_assign_mod(&y);
+    assert!(mut_x.is_valid());
+    assert!(r.is_valid());
+    let q = Integer::exact_from(mut_x);
+
+    let mut mut_x = x.clone();
+    let r_alt = mut_x.ceiling_div_assign_mod(y.clone());
+    assert!(mut_x.is_valid());
+    assert_eq!(mut_x, q);
+    assert!(r_alt.is_valid());
+    assert_eq!(r_alt, r);
+
+    let (q_alt, r_alt) = (&x).ceiling_div_mod(&y);
+    assert!(q_alt.is_valid());
+    assert_eq!(q_alt, q);
+    assert!(r_alt.is_valid());
+    assert_eq!(r_alt, r);
+
+    let (q_alt, r_alt) = (&x).ceiling_div_mod(y.clone());
+    assert!(q_alt.is_valid());
+    assert_eq!(q_alt, q);
+    assert!(r_alt.is_valid());
+    assert_eq!(r_alt, r);
+
+    let (q_alt, r_alt) = x.clone().ceiling_div_mod(&y);
+    assert!(q_alt.is_valid());
+    assert_eq!(q_alt, q);
+    assert!(r_alt.is_valid());
+    assert_eq!(r_alt, r);
+
+    let (q_alt, r_alt) = x.clone().ceiling_div_mod(y.clone());
+    assert!(q_alt.is_valid());
+    assert_eq!(q_alt, q);
+    assert!(r_alt.is_valid());
+    assert_eq!(r_alt, r);
+
+    assert_eq!((&x / &y).ceiling(), q);
+    assert_eq!((&x).ceiling_mod(&y), r);
+
+    assert!(r.lt_abs(&y));
+    assert!(r == 0u32 || (r > 0u32) != (y > 0u32));
+    assert_eq!(Rational::from(&q) * &y + &r, x);
+}
+
+#[test]
+fn ceiling_div_mod_properties() {
+    rational_pair_gen_var_1().test_properties(|(x, y)| ceiling_div_mod_properties_helper(x, y));
+
+    rational_gen().test_properties(|x| {
+        let (q, r) = (&x).ceiling_div_mod(Rational::ONE);
+        assert_eq!(q, (&x).ceiling());
+        assert_eq!(r, &x - Rational::from(q));
+
+        let (q, r) = (&x).ceiling_div_mod(Rational::NEGATIVE_ONE);
+        assert_eq!(q, -(&x).floor());
+        assert_eq!(r, x + Rational::from(q));
+    });
+
+    rational_gen_var_1().test_properties(|ref x| {
+        assert_eq!(x.ceiling_div_mod(x), (Integer::ONE, Rational::ZERO));
+        assert_eq!(
+            x.ceiling_div_mod(-x),
+            (Integer::NEGATIVE_ONE, Rational::ZERO)
+        );
+        assert_eq!(
+            Rational::ZERO.ceiling_div_mod(x),
+            (Integer::ZERO, Rational::ZERO)
+        );
+    });
+
+    integer_pair_gen_var_1().test_properties(|(x, y)| {
+        let (q, r) = (&x).ceiling_div_mod(&y);
+        assert_eq!(
+            Rational::from(x).ceiling_div_mod(Rational::from(y)),
+            (q, Rational::from(r))
+        );
+    });
+}
@@ -0,0 +1,125 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Abs, Gcd, GcdAssign, Reciprocal};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::test_util::generators::natural_pair_gen;
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{rational_gen, rational_pair_gen, rational_triple_gen};
+use std::str::FromStr;
+
+#[test]
+fn test_gcd() {
+    let test = |s, t, out| {
+        let u = Rational::from_str(s).unwrap();
+        let v = Rational::from_str(t).unwrap();
+
+        let mut n = u.clone();
+        n.gcd_assign(v.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n.gcd_assign(&v);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone().gcd(v.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).gcd(v.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone().gcd(&v);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).gcd(&v);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test("0", "0", "0");
+    test("0", "6", "6");
+    test("0", "-1/2", "1/2");
+    test("6", "0", "6");
+    test("1", "1", "1");
+    test("6", "4", "2");
+    test("-6", "-4", "2");
+    test("1/2", "1/3", "1/6");
+    test("-2/3", "4/9", "2/9");
+    test("6", "15/2", "3/2");
+    test("-3/4", "-3/4", "3/4");
+    test("22/7", "355/113", "1/791");
+    test(
+        "1000000000000/3",
+        "1000000000000000000000/7",
+        "1000000000000/21",
+    );
+}
+
+#[test]
+fn gcd_properties() {
+    rational_pair_gen().test_properties(|(x, y)| {
+        let gcd_val_val = x.clone().gcd(y.clone());
+        let gcd_val_ref = x.clone().gcd(&y);
+        let gcd_ref_val = (&x).gcd(y.clone());
+        let gcd = (&x).gcd(&y);
+        assert!(gcd_val_val.is_valid());
+        assert!(gcd_val_ref.is_valid());
+        assert!(gcd_ref_val.is_valid());
+        assert!(gcd.is_valid());
+        assert_eq!(gcd_val_val, gcd);
+        assert_eq!(gcd_val_ref, gcd);
+        assert_eq!(gcd_ref_val, gcd);
+
+        let mut mut_x = x.clone();
+        mut_x.gcd_assign(y.clone());
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, gcd);
+
+        let mut mut_x = x.clone();
+        mut_x.gcd_assign(&y);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, gcd);
+
+        assert_eq!((&y).gcd(&x), gcd);
+        assert_eq!((&x).abs().gcd((&y).abs()), gcd);
+        assert!(gcd >= 0u32);
+        assert_eq!(gcd == 0u32, x == 0u32 && y == 0u32);
+        if gcd != 0u32 {
+            assert_eq!((&x / &gcd).into_denominator(), 1u32);
+            assert_eq!((&y / &gcd).into_denominator(), 1u32);
+            assert_eq!(((&x / &gcd).gcd(&y / &gcd)), 1u32);
+        }
+    });
+
+    rational_gen().test_properties(|x| {
+        assert_eq!((&x).gcd(&x), (&x).abs());
+        assert_eq!((&x).gcd(Rational::ZERO), (&x).abs());
+        assert_eq!(Rational::ZERO.gcd(&x), (&x).abs());
+        if x != 0u32 {
+            assert_eq!(
+                (&x).gcd(Rational::ONE),
+                Rational::from(x.denominator_ref()).reciprocal()
+            );
+        }
+    });
+
+    rational_triple_gen().test_properties(|(x, y, z)| {
+        assert_eq!((&x).gcd(&y).gcd(&z), x.gcd(y.gcd(z)));
+    });
+
+    natural_pair_gen().test_properties(|(x, y)| {
+        assert_eq!(
+            Rational::from(&x).gcd(Rational::from(&y)),
+            Rational::from(x.gcd(y))
+        );
+    });
+}
@@ -0,0 +1,120 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Abs, Gcd, Lcm, LcmAssign};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::test_util::generators::natural_pair_gen;
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{rational_gen, rational_pair_gen, rational_triple_gen};
+use std::str::FromStr;
+
+#[test]
+fn test_lcm() {
+    let test = |s, t, out| {
+        let u = Rational::from_str(s).unwrap();
+        let v = Rational::from_str(t).unwrap();
+
+        let mut n = u.clone();
+        n.lcm_assign(v.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n.lcm_assign(&v);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone().lcm(v.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).lcm(v.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone().lcm(&v);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).lcm(&v);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test("0", "0", "0");
+    test("0", "6", "0");
+    test("-1/2", "0", "0");
+    test("1", "1", "1");
+    test("6", "4", "12");
+    test("-6", "-4", "12");
+    test("1/2", "1/3", "1");
+    test("-2/3", "4/9", "4/3");
+    test("6", "15/2", "30");
+    test("-3/4", "-3/4", "3/4");
+    test("22/7", "355/113", "7810");
+    test(
+        "1000000000000/3",
+        "1000000000000000000000/7",
+        "1000000000000000000000",
+    );
+}
+
+#[test]
+fn lcm_properties() {
+    rational_pair_gen().test_properties(|(x, y)| {
+        let lcm_val_val = x.clone().lcm(y.clone());
+        let lcm_val_ref = x.clone().lcm(&y);
+        let lcm_ref_val = (&x).lcm(y.clone());
+        let lcm = (&x).lcm(&y);
+        assert!(lcm_val_val.is_valid());
+        assert!(lcm_val_ref.is_valid());
+        assert!(lcm_ref_val.is_valid());
+        assert!(lcm.is_valid());
+        assert_eq!(lcm_val_val, lcm);
+        assert_eq!(lcm_val_ref, lcm);
+        assert_eq!(lcm_ref_val, lcm);
+
+        let mut mut_x = x.clone();
+        mut_x.lcm_assign(y.clone());
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, lcm);
+
+        let mut mut_x = x.clone();
+        mut_x.lcm_assign(&y);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, lcm);
+
+        assert_eq!((&y).lcm(&x), lcm);
+        assert_eq!((&x).abs().lcm((&y).abs()), lcm);
+        assert!(lcm >= 0u32);
+        assert_eq!(lcm == 0u32, x == 0u32 || y == 0u32);
+        if lcm != 0u32 {
+            assert_eq!((&lcm / &x).into_denominator(), 1u32);
+            assert_eq!((&lcm / &y).into_denominator(), 1u32);
+            assert_eq!((&lcm / &x).gcd(&lcm / &y), 1u32);
+        }
+        assert_eq!(lcm * (&x).gcd(&y), (x * y).abs());
+    });
+
+    rational_gen().test_properties(|x| {
+        assert_eq!((&x).lcm(&x), (&x).abs());
+        assert_eq!((&x).lcm(Rational::ZERO), 0u32);
+        assert_eq!(Rational::ZERO.lcm(&x), 0u32);
+        assert_eq!((&x).lcm(Rational::ONE), Rational::from(x.numerator_ref()));
+    });
+
+    rational_triple_gen().test_properties(|(x, y, z)| {
+        assert_eq!((&x).lcm(&y).lcm(&z), x.lcm(y.lcm(z)));
+    });
+
+    natural_pair_gen().test_properties(|(x, y)| {
+        assert_eq!(
+            Rational::from(&x).lcm(Rational::from(&y)),
+            Rational::from(x.lcm(y))
+        );
+    });
+}
@@ -0,0 +1,450 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{
+    CeilingDivMod, CeilingMod, CeilingModAssign, DivMod, DivRem, Floor, Mod, ModAssign,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::comparison::traits::PartialOrdAbs;
+use malachite_nz::test_util::generators::integer_pair_gen_var_1;
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{
+    rational_gen, rational_gen_var_1, rational_pair_gen_var_1,
+};
+use std::str::FromStr;
+
+#[test]
+fn test_mod() {
+    let test = |s, t, remainder| {
+        let u = Rational::from_str(s).unwrap();
+        let v = Rational::from_str(t).unwrap();
+
+        let mut x = u.clone();
+        x.mod_assign(v.clone());
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), remainder);
+
+        let mut x = u.clone();
+        x.mod_assign(&v);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), remainder);
+
+        let r = u.clone().mod_op(v.clone());
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+
+        let r = u.clone().mod_op(&v);
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+
+        let r = (&u).mod_op(v.clone());
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+
+        let r = (&u).mod_op(&v);
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+    };
+    test("0", "1/2", "0");
+    test("23", "10", "3");
+    test("23", "-10", "-7");
+    test("-23", "10", "7");
+    test("-23", "-10", "-3");
+    test("21/2", "4/3", "7/6");
+    test("21/2", "-4/3", "-1/6");
+    test("-21/2", "4/3", "1/6");
+    test("-21/2", "-4/3", "-7/6");
+    test("7/3", "1/2", "1/3");
+    test("1/3", "2", "1/3");
+    test("-1/3", "2", "5/3");
+    test("4/5", "4/5", "0");
+    test("4/5", "-4/5", "0");
+    test("22/7", "1/1000000000000", "1/7000000000000");
+}
+
+#[test]
+#[should_panic]
+fn mod_assign_fail() {
+    let mut x = Rational::ONE;
+    x.mod_assign(Rational::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn mod_assign_ref_fail() {
+    let mut x = Rational::ONE;
+    x.mod_assign(&Rational::ZERO);
+}
+
+#[allow(unused_must_use)]
+#[test]
+#[should_panic]
+fn mod_fail() {
+    Rational::ONE.mod_op(Rational::ZERO);
+}
+
+#[allow(unused_must_use)]
+#[test]
+#[should_panic]
+fn mod_val_ref_fail() {
+    Rational::ONE.mod_op(&Rational::ZERO);
+}
+
+#[allow(unused_must_use)]
+#[test]
+#[should_panic]
+fn mod_ref_val_fail() {
+    (&Rational::ONE).mod_op(Rational::ZERO);
+}
+
+#[allow(unused_must_use)]
+#[test]
+#[should_panic]
+fn mod_ref_ref_fail() {
+    (&Rational::ONE).mod_op(&Rational::ZERO);
+}
+
+#[test]
+fn test_rem() {
+    let test = |s, t, remainder| {
+        let u = Rational::from_str(s).unwrap();
+        let v = Rational::from_str(t).unwrap();
+
+        let mut x = u.clone();
+        x %= v.clone();
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), remainder);
+
+        let mut x = u.clone();
+        x %= &v;
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), remainder);
+
+        let r = u.clone() % v.clone();
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+
+        let r = u.clone() % &v;
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+
+        let r = &u % v.clone();
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+
+        let r = &u % &v;
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+    };
+    test("0", "1/2", "0");
+    test("23", "10", "3");
+    test("23", "-10", "3");
+    test("-23", "10", "-3");
+    test("-23", "-10", "-3");
+    test("21/2", "4/3", "7/6");
+    test("21/2", "-4/3", "7/6");
+    test("-21/2", "4/3", "-7/6");
+    test("-21/2", "-4/3", "-7/6");
+    test("7/3", "1/2", "1/3");
+    test("1/3", "2", "1/3");
+    test("-1/3", "2", "-1/3");
+    test("4/5", "4/5", "0");
+    test("4/5", "-4/5", "0");
+    test("22/7", "1/1000000000000", "1/7000000000000");
+}
+
+#[test]
+#[should_panic]
+fn rem_assign_fail() {
+    let mut x = Rational::ONE;
+    x %= Rational::ZERO;
+}
+
+#[test]
+#[should_panic]
+fn rem_assign_ref_fail() {
+    let mut x = Rational::ONE;
+    x %= &Rational::ZERO;
+}
+
+#[allow(clippy::no_effect, unused_must_use)]
+#[test]
+#[should_panic]
+fn rem_fail() {
+    Rational::ONE % Rational::ZERO;
+}
+
+#[allow(clippy::no_effect, unused_must_use)]
+#[test]
+#[should_panic]
+fn rem_val_ref_fail() {
+    Rational::ONE % &Rational::ZERO;
+}
+
+#[allow(clippy::no_effect, unused_must_use)]
+#[test]
+#[should_panic]
+fn rem_ref_val_fail() {
+    &Rational::ONE % Rational::ZERO;
+}
+
+#[allow(clippy::no_effect, unused_must_use)]
+#[test]
+#[should_panic]
+fn rem_ref_ref_fail() {
+    &Rational::ONE % &Rational::ZERO;
+}
+
+#[test]
+fn test_ceiling_mod() {
+    let test = |s, t, remainder| {
+        let u = Rational::from_str(s).unwrap();
+        let v = Rational::from_str(t).unwrap();
+
+        let mut x = u.clone();
+        x.ceiling_mod_assign(v.clone());
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), remainder);
+
+        let mut x = u.clone();
+        x.ceiling_mod_assign(&v);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), remainder);
+
+        let r = u.clone().ceiling_mod(v.clone());
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+
+        let r = u.clone().ceiling_mod(&v);
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+
+        let r = (&u).ceiling_mod(v.clone());
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+
+        let r = (&u).ceiling_mod(&v);
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), remainder);
+    };
+    test("0", "1/2", "0");
+    test("23", "10", "-7");
+    test("23", "-10", "3");
+    test("-23", "10", "-3");
+    test("-23", "-10", "7");
+    test("21/2", "4/3", "-1/6");
+    test("21/2", "-4/3", "7/6");
+    test("-21/2", "4/3", "-7/6");
+    test("-21/2", "-4/3", "1/6");
+    test("7/3", "1/2", "-1/6");
+    test("1/3", "2", "-5/3");
+    test("-1/3", "2", "-1/3");
+    test("4/5", "4/5", "0");
+    test("4/5", "-4/5", "0");
+    test("22/7", "1/1000000000000", "-3/3500000000000");
+}
+
+#[test]
+#[should_panic]
+fn ceiling_mod_assign_fail() {
+    let mut x = Rational::ONE;
+    x.ceiling_mod_assign(Rational::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn ceiling_mod_assign_ref_fail() {
+    let mut x = Rational::ONE;
+    x.ceiling_mod_assign(&Rational::ZERO);
+}
+
+#[allow(unused_must_use)]
+#[test]
+#[should_panic]
+fn ceiling_mod_fail() {
+    Rational::ONE.ceiling_mod(Rational::ZERO);
+}
+
+#[allow(unused_must_use)]
+#[test]
+#[should_panic]
+fn ceiling_mod_val_ref_fail() {
+    Rational::ONE.ceiling_mod(&Rational::ZERO);
+}
+
+#[allow(unused_must_use)]
+#[test]
+#[should_panic]
+fn ceiling_mod_ref_val_fail() {
+    (&Rational::ONE).ceiling_mod(Rational::ZERO);
+}
+
+#[allow(unused_must_use)]
+#[test]
+#[should_panic]
+fn ceiling_mod_ref_ref_fail() {
+    (&Rational::ONE).ceiling_mod(&Rational::ZERO);
+}
+
+#[test]
+fn mod_properties() {
+    rational_pair_gen_var_1().test_properties(|(x, y)| {
+        let mut mut_x = x.clone();
+        mut_x.mod_assign(&y);
+        assert!(mut_x.is_valid());
+        let remainder = mut_x;
+
+        let mut mut_x = x.clone();
+        mut_x.mod_assign(y.clone());
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, remainder);
+
+        let remainder_alt = (&x).mod_op(&y);
+        assert!(remainder_alt.is_valid());
+        assert_eq!(remainder_alt, remainder);
+
+        let remainder_alt = (&x).mod_op(y.clone());
+        assert!(remainder_alt.is_valid());
+        assert_eq!(remainder_alt, remainder);
+
+        let remainder_alt = x.clone().mod_op(&y);
+        assert!(remainder_alt.is_valid());
+        assert_eq!(remainder_alt, remainder);
+
+        let remainder_alt = x.clone().mod_op(y.clone());
+        assert!(remainder_alt.is_valid());
+        assert_eq!(remainder_alt, remainder);
+
+        assert_eq!((&x).div_mod(&y).1, remainder);
+        assert!(remainder.lt_abs(&y));
+        assert!(remainder == 0u32 || (remainder > 0u32) == (y > 0u32));
+        assert_eq!((&x + &y).mod_op(&y), remainder);
+        assert_eq!((-&x).mod_op(&y), -(&x).ceiling_mod(&y));
+        assert_eq!((&x).mod_op(-&y), x.ceiling_mod(y));
+    });
+
+    rational_gen().test_properties(|x| {
+        let remainder = (&x).mod_op(Rational::ONE);
+        assert_eq!(remainder, &x - Rational::from((&x).floor()));
+        assert!(remainder >= 0u32);
+        assert!(remainder < 1u32);
+    });
+
+    rational_gen_var_1().test_properties(|ref x| {
+        assert_eq!(x.mod_op(x), 0u32);
+        assert_eq!(x.mod_op(-x), 0u32);
+        assert_eq!(Rational::ZERO.mod_op(x), 0u32);
+    });
+
+    integer_pair_gen_var_1().test_properties(|(x, y)| {
+        assert_eq!(
+            Rational::from(&x).mod_op(Rational::from(&y)),
+            Rational::from(x.mod_op(y))
+        );
+    });
+}
+
+#[test]
+fn rem_properties() {
+    rational_pair_gen_var_1().test_properties(|(x, y)| {
+        let mut mut_x = x.clone();
+        mut_x %= &y;
+        assert!(mut_x.is_valid());
+        let remainder = mut_x;
+
+        let mut mut_x = x.clone();
+        mut_x %= y.clone();
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, remainder);
+
+        let remainder_alt = &x % &y;
+        assert!(remainder_alt.is_valid());
+        assert_eq!(remainder_alt, remainder);
+
+        let remainder_alt = &x % y.clone();
+        assert!(remainder_alt.is_valid());
+        assert_eq!(remainder_alt, remainder);
+
+        let remainder_alt = x.clone() % &y;
+        assert!(remainder_alt.is_valid());
+        assert_eq!(remainder_alt, remainder);
+
+        let remainder_alt = x.clone() % y.clone();
+        assert!(remainder_alt.is_valid());
+        assert_eq!(remainder_alt, remainder);
+
+        assert_eq!((&x).div_rem(&y).1, remainder);
+        assert!(remainder.lt_abs(&y));
+        assert!(remainder == 0u32 || (remainder > 0u32) == (x > 0u32));
+        assert_eq!(-&x % &y, -&remainder);
+        assert_eq!(x % -y, remainder);
+    });
+
+    rational_gen_var_1().test_properties(|ref x| {
+        assert_eq!(x % x, 0u32);
+        assert_eq!(x % -x, 0u32);
+        assert_eq!(Rational::ZERO % x, 0u32);
+    });
+
+    integer_pair_gen_var_1().test_properties(|(x, y)| {
+        assert_eq!(
+            Rational::from(&x) % Rational::from(&y),
+            Rational::from(x % y)
+        );
+    });
+}
+
+#[test]
+fn ceiling_mod_properties() {
+    rational_pair_gen_var_1().test_properties(|(x, y)| {
+        let mut mut_x = x.clone();
+        mut_x.ceiling_mod_assign(&y);
+        assert!(mut_x.is_valid());
+        let remainder = mut_x;
+
+        let mut mut_x = x.clone();
+        mut_x.ceiling_mod_assign(y.clone());
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, remainder);
+
+        let remainder_alt = (&x).ceiling_mod(&y);
+        assert!(remainder_alt.is_valid());
+        assert_eq!(remainder_alt, remainder);
+
+        let remainder_alt = (&x).ceiling_mod(y.clone());
+        assert!(remainder_alt.is_valid());
+        assert_eq!(remainder_alt, remainder);
+
+        let remainder_alt = x.clone().ceiling_mod(&y);
+        assert!(remainder_alt.is_valid());
+        assert_eq!(remainder_alt, remainder);
+
+        let remainder_alt = x.clone().ceiling_mod(y.clone());
+        assert!(remainder_alt.is_valid());
+        assert_eq!(remainder_alt, remainder);
+
+        assert_eq!((&x).ceiling_div_mod(&y).1, remainder);
+        assert!(remainder.lt_abs(&y));
+        assert!(remainder == 0u32 || (remainder > 0u32) != (y > 0u32));
+        assert_eq!((-&x).ceiling_mod(&y), -(&x).mod_op(&y));
+        assert_eq!((&x).ceiling_mod(-&y), x.mod_op(y));
+    });
+
+    rational_gen_var_1().test_properties(|ref x| {
+        assert_eq!(x.ceiling_mod(x), 0u32);
+        assert_eq!(x.ceiling_mod(-x), 0u32);
+        assert_eq!(Rational::ZERO.ceiling_mod(x), 0u32);
+    });
+
+    integer_pair_gen_var_1().test_properties(|(x, y)| {
+        assert_eq!(
+            Rational::from(&x).ceiling_mod(Rational::from(&y)),
+            Rational::from(x.ceiling_mod(y))
+        );
+    });
+}
@@ -0,0 +1,75 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use itertools::Itertools;
+use malachite_base::test_util::generators::unsigned_vec_pair_gen;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+#[test]
+fn test_from_sign_and_bytes() {
+    let test = |sign, n: &[u8], d: &[u8], out| {
+        let x = Rational::from_sign_and_bytes_le(sign, n, d);
+        assert_eq!(x.to_string(), out);
+        assert!(x.is_valid());
+
+        let n = n.iter().copied().rev().collect_vec();
+        let d = d.iter().copied().rev().collect_vec();
+        let x = Rational::from_sign_and_bytes_be(sign, &n, &d);
+        assert_eq!(x.to_string(), out);
+        assert!(x.is_valid());
+    };
+    test(true, &[], &[1], "0");
+    test(false, &[], &[1], "0");
+    test(false, &[0, 0], &[5], "0");
+    test(true, &[123], &[1], "123");
+    test(false, &[123], &[1, 0], "-123");
+    test(true, &[4], &[6], "2/3");
+    test(false, &[232, 3], &[7], "-1000/7");
+    test(true, &[1], &[0, 16, 165, 212, 232], "1/1000000000000");
+    test(
+        false,
+        &[0, 0, 0, 0, 0, 0, 0, 0, 1],
+        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+        "-1/256",
+    );
+}
+
+#[test]
+#[should_panic]
+fn from_sign_and_bytes_le_fail() {
+    Rational::from_sign_and_bytes_le(true, &[1], &[]);
+}
+
+#[test]
+#[should_panic]
+fn from_sign_and_bytes_be_fail() {
+    Rational::from_sign_and_bytes_be(true, &[1], &[0, 0]);
+}
+
+#[test]
+fn from_sign_and_bytes_properties() {
+    unsigned_vec_pair_gen::<u8>().test_properties(|(n, d)| {
+        let d_natural = Natural::from_bytes_le(&d);
+        if d_natural == 0 {
+            return;
+        }
+        let n_natural = Natural::from_bytes_le(&n);
+        let n_rev = n.iter().copied().rev().collect_vec();
+        let d_rev = d.iter().copied().rev().collect_vec();
+        for sign in [false, true] {
+            let x = Rational::from_sign_and_bytes_le(sign, &n, &d);
+            assert!(x.is_valid());
+            assert_eq!(Rational::from_sign_and_bytes_be(sign, &n_rev, &d_rev), x);
+            assert_eq!(
+                Rational::from_sign_and_naturals_ref(sign, &n_natural, &d_natural),
+                x
+            );
+        }
+    });
+}
@@ -9,6 +9,7 @@
 use malachite_base::strings::string_is_subset;
 use malachite_base::test_util::generators::string_gen;
 use malachite_q::Rational;
+use malachite_q::conversion::serde::{CompactRational, CompactRationalRef};
 use malachite_q::test_util::generators::{rational_gen, string_gen_var_11};
 use std::str::FromStr;
 
@@ -79,3 +80,56 @@ fn serde_properties() {
         let _n: Rational = serde_json::from_str(&s).unwrap();
     });
 }
+
+#[test]
+fn test_compact_serde() {
+    let test = |n, out| {
+        let x = Rational::from_str(n).unwrap();
+        assert_eq!(serde_json::to_string(&CompactRationalRef(&x)).unwrap(), out);
+        assert_eq!(
+            serde_json::to_string(&CompactRational(x.clone())).unwrap(),
+            out
+        );
+        assert_eq!(
+            serde_json::from_str::<CompactRational>(out).unwrap(),
+            CompactRational(x)
+        );
+    };
+    test("0", "[true,[],[1]]");
+    test("100", "[true,[100],[1]]");
+    test("-100", "[false,[100],[1]]");
+    test("22/7", "[true,[22],[7]]");
+    test("-22/7", "[false,[22],[7]]");
+    test("1/4294967296", "[true,[1],[0,0,0,0,1]]");
+}
+
+#[test]
+fn test_compact_serde_fail() {
+    let test = |s| {
+        assert!(serde_json::from_str::<CompactRational>(s).is_err());
+    };
+    test("[true,[1],[]]");
+    test("[true,[],[]]");
+    test("[false,[],[1]]");
+    test("[true,[2],[4]]");
+    test("[true,[],[2]]");
+    test("[true,[1],[1,0]]");
+    test("[true,[1]]");
+    test("{\"s\":true,\"n\":\"0x1\",\"d\":\"0x1\"}");
+    test("null");
+}
+
+#[test]
+fn compact_serde_properties() {
+    rational_gen().test_properties(|x| {
+        let s = serde_json::to_string(&CompactRationalRef(&x)).unwrap();
+        let y = serde_json::from_str::<CompactRational>(&s).unwrap().0;
+        assert!(y.is_valid());
+        assert_eq!(y, x);
+        assert!(string_is_subset(&s, "[],0123456789aeflrstu"));
+    });
+
+    string_gen().test_properties(|s| {
+        let _n: Result<CompactRational, _> = serde_json::from_str(&s);
+    });
+}
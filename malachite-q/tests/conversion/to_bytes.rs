@@ -0,0 +1,45 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_q::Rational;
+use malachite_q::test_util::generators::rational_gen;
+use std::str::FromStr;
+
+#[test]
+fn test_to_sign_and_bytes() {
+    let test = |s, sign, n: &[u8], d: &[u8]| {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(x.to_sign_and_bytes_le(), (sign, n.to_vec(), d.to_vec()));
+        let mut n = n.to_vec();
+        n.reverse();
+        let mut d = d.to_vec();
+        d.reverse();
+        assert_eq!(x.to_sign_and_bytes_be(), (sign, n, d));
+    };
+    test("0", true, &[], &[1]);
+    test("123", true, &[123], &[1]);
+    test("-123", false, &[123], &[1]);
+    test("2/3", true, &[2], &[3]);
+    test("-1000/7", false, &[232, 3], &[7]);
+    test("1/1000000000000", true, &[1], &[0, 16, 165, 212, 232]);
+}
+
+#[test]
+fn to_sign_and_bytes_properties() {
+    rational_gen().test_properties(|x| {
+        let (sign, n, d) = x.to_sign_and_bytes_le();
+        assert_eq!(sign, x >= 0);
+        assert_eq!(n, x.numerator_ref().to_bytes_le());
+        assert_eq!(d, x.denominator_ref().to_bytes_le());
+        assert_eq!(Rational::from_sign_and_bytes_le(sign, &n, &d), x);
+
+        let (sign_be, n, d) = x.to_sign_and_bytes_be();
+        assert_eq!(sign_be, sign);
+        assert_eq!(Rational::from_sign_and_bytes_be(sign, &n, &d), x);
+    });
+}
@@ -69,14 +69,19 @@ pub mod arithmetic {
     pub mod abs_diff;
     pub mod add;
     pub mod approximate;
+    pub mod best_approximations;
     pub mod ceiling;
     pub mod denominators_in_closed_interval;
     pub mod div;
+    pub mod div_mod;
     pub mod floor;
+    pub mod gcd;
     pub mod is_power_of_2;
+    pub mod lcm;
     pub mod log_base;
     pub mod log_base_2;
     pub mod log_base_power_of_2;
+    pub mod mod_op;
     pub mod mul;
     pub mod neg;
     pub mod next_power_of_2;
@@ -141,6 +146,7 @@ pub mod conversion {
         pub mod to_power_of_2_digits;
     }
     pub mod from_bool;
+    pub mod from_bytes;
     pub mod from_float_simplest;
     pub mod from_integer;
     pub mod from_natural;
@@ -161,6 +167,7 @@ pub mod conversion {
         pub mod to_sci;
         pub mod to_string;
     }
+    pub mod to_bytes;
     pub mod to_numerator_or_denominator;
 }
 pub mod exhaustive {